@@ -9,51 +9,82 @@
 //! - Daily metrics tracking (P0-31)
 //! - Automatic market discovery (P0-15, P0-26, P0-27)
 
-use crate::config::{AppConfig, MarketConfig, OperatingMode};
+use crate::attribution::{ClosingFill, EntrySource, TradeAttributor};
+use crate::config::{AppConfig, MarketConfig, OperatingMode, PersistenceConfig};
 use crate::edge_tracker::EdgeTracker;
 use crate::error::{AppError, AppResult};
+use crate::extension::{DomainEvent, Extension, ExtensionBus, PublishOutcome};
+use crate::journal::{journal_record, JournalFill};
+use crate::tca::TcaTracker;
 use alloy::primitives::Address;
 use chrono::Utc;
 use hip3_core::{
-    AssetId, ClientOrderId, DexId, ExitProfile, MarketKey, OrderSide, OrderState, PendingOrder,
-    Price, Size, TimeInForce,
+    AssetId, ClientOrderId, DexId, ExecutionResult, ExitProfile, MarketKey, OrderSide, OrderState,
+    OrderStrategy, PendingOrder, Price, SignalValidity, Size, SkipReason, TimeInForce,
+    TradingCalendar,
 };
 use hip3_dashboard::{DashboardState, SignalSender, SignalSnapshot};
-use hip3_detector::{CrossDurationTracker, DislocationDetector, DislocationSignal};
+use hip3_detector::{
+    CrossDurationTracker, DetectionStrategy, DislocationDetector, DislocationSignal,
+    LeadLagDetector, SideThresholds, SignalSource, SlippageEstimator, StrategyContext, UserFees,
+    DISLOCATION_STRATEGY, LEAD_LAG_STRATEGY,
+};
 use hip3_executor::{
-    ActionBudget, BatchConfig, BatchScheduler, DynWsSender, ExecutionEvent, ExecutorConfig,
-    ExecutorHandle, ExecutorLoop, HardStopLatch, InflightTracker, KeyManager, KeySource,
-    MarkPriceProvider, MarketStateCache, NonceManager, RealWsSender, RiskMonitor,
-    RiskMonitorConfig as ExecutorRiskMonitorConfig, Signer, SystemClock, TradingReadyChecker,
+    ActionBudget, BatchConfig, BatchScheduler, BuilderInfo, DynWsSender, ExecutionEvent,
+    ExecutorConfig, ExecutorHandle, ExecutorLoop, FatFingerGuard, HardStopLatch, HardStopLevel,
+    HardStopReason, HardStopRecovery, InflightTracker, KeyManager, KeyPurpose, KeySource,
+    MarkPriceProvider, MarketStateCache, MmQuoteResult, NonceManager, OrderTier, RealWsSender,
+    RestCanceller, RestExchangeClient, RetryPolicy, RiskEventLog, RiskMonitor,
+    RiskMonitorConfig as ExecutorRiskMonitorConfig, RiskMonitorSettings, SignedActionLog, Signer,
+    SimulatedWsSender, SlippageGuard, SystemClock, TradingReadyChecker, VaultRouter,
 };
 use hip3_feed::{
-    MarketEvent, MarketState, MessageParser, OracleMovementTracker, OracleTrackerHandle,
+    BboHistory, BboHistoryHandle, ClockSkewEstimator, MarketEvent, MarketState, MessageParser,
+    OracleMovementTracker, OracleTrackerHandle, ReferencePriceHandle, ReferencePriceStore,
+    RegimeClassifier, RegimeHandle,
+};
+use hip3_mm::{
+    ExchangeOrder, InventoryManager, MakerAction, MarkoutAnalytics, QuoteManager, QuoteModel,
+};
+use hip3_persistence::{
+    AuditWriter, FeedRecord, FeedWriter, FlattenClaim, FollowupRecord, FollowupWriter, FrameRecord,
+    FrameWriter, LossLimitState, LossLimitStore, MarketLossRecord, MarketWarmState, NonceSnapshot,
+    NonceStore, ParquetWriter, PendingOrderRecord, PositionJournalWriter, PositionRecord,
+    PositionState, PositionStateStore, RiskEventRecord, RiskEventWriter, SignalRecord,
+    SignalRejectRecord, SignalRejectWriter, SignedActionRecord, StateStore, TcaWriter,
+    TradeLedgerWriter, WarmState,
 };
-use hip3_mm::{InventoryManager, QuoteManager};
-use hip3_persistence::{FollowupRecord, FollowupWriter, ParquetWriter, SignalRecord};
 use hip3_position::{
-    flatten_all_positions, new_exit_watcher, new_oracle_exit_watcher, spawn_position_tracker,
-    ExitWatcherHandle, FlattenReason, MarkRegressionConfig, MarkRegressionMonitor,
-    OracleExitWatcherHandle, Position, PositionTrackerHandle, SharedFlatteningGuard,
-    TimeStopConfig as PositionTimeStopConfig, TimeStopMonitor,
+    flatten_all_positions, new_exit_watcher, new_oracle_exit_watcher, reconcile,
+    spawn_position_tracker, DriftConfirmer, ExitStrategyRegistry, ExitStrategyWatcher,
+    ExitStrategyWatcherHandle, ExitWatcherHandle, FlattenOrderBuilder, FlattenPricing,
+    FlattenReason, Flattener, HedgeHandle, HedgeLeg, HedgeManager, MarkRegressionConfig,
+    MarkRegressionMonitor, OcoAction, OcoBook, OracleExitWatcherHandle, Position,
+    PositionTrackerHandle, SharedFlatteningGuard, TimeStopConfig as PositionTimeStopConfig,
+    TimeStopMonitor, VolScaling,
 };
 use hip3_registry::{
-    validate_market_keys, ClearinghouseStateResponse, MetaClient, PerpDexsResponse,
-    PreflightChecker, RawPerpSpec, SpecCache,
+    validate_market_keys, ClearinghouseStateResponse, FeeRefresher, MetaClient, OpenOrder,
+    ParsedUserFees, PerpDexsResponse, PreflightChecker, RawPerpSpec, SpecCache,
+};
+use hip3_risk::{
+    AccountMargin, GateId, GateInputs, GateResult, LiquidationMonitor, LiquidationMonitorHandle,
+    LiquidationStop, PositionMargin, RiskError, RiskGate,
 };
-use hip3_risk::{RiskError, RiskGate};
 use hip3_telemetry::{DailyStatsReporter, Metrics};
 use hip3_ws::{
-    is_order_updates_channel, ConnectionConfig, ConnectionManager, FillPayload, OrderUpdatePayload,
-    PostResponseBody, WsMessage,
+    is_order_updates_channel, message_queue, ConnectionConfig, ConnectionManager, FillPayload,
+    FrameHook, FundingPayload, LedgerDelta, LedgerUpdatePayload, LiquidationPayload,
+    OrderUpdatePayload, PostResponseBody, UserEventPayload, WsMessage,
 };
 use parking_lot::RwLock;
 use rust_decimal::Decimal;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 use tracing::{debug, error, info, warn};
 
 /// Daily stats output interval (1 hour).
@@ -62,6 +93,27 @@ const DAILY_STATS_INTERVAL: Duration = Duration::from_secs(3600);
 /// Followup snapshot offsets in milliseconds (T+1s, T+3s, T+5s).
 const FOLLOWUP_OFFSETS_MS: [u64; 3] = [1000, 3000, 5000];
 
+/// Feed records buffered before each write (market data is high-volume).
+const FEED_BUFFER_SIZE: usize = 1000;
+
+/// Distinct market-data snapshots (channel, coin), and trade prints, queued
+/// for the main loop before the oldest is dropped (order, fill and post
+/// messages are never dropped).
+const MARKET_DATA_QUEUE_CAPACITY: usize = 1000;
+
+/// Raw frames queued for the frame recorder task before frames are dropped.
+const FRAME_CHANNEL_CAPACITY: usize = 65_536;
+
+/// How long queued entry orders are remembered for submit-time rejections (ms).
+const QUEUED_SIGNAL_RETENTION_MS: u64 = 60_000;
+
+/// How often the READY state is polled for reconnect reconciliation.
+const RECONCILE_CHECK_INTERVAL: Duration = Duration::from_millis(200);
+
+/// How often REST orders with an unknown outcome, and amendments whose
+/// batchModify timed out, are looked up.
+const REST_RECONCILE_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Get current time in milliseconds since UNIX epoch.
 ///
 /// Returns 0 if system time is before UNIX epoch (should never happen).
@@ -90,11 +142,53 @@ pub struct Application {
     config: AppConfig,
     market_state: Arc<MarketState>,
     spec_cache: Arc<SpecCache>,
-    risk_gate: RiskGate,
+    /// Hard risk gates, one per market (spread EWMA and feed state are per market).
+    risk_gates: HashMap<MarketKey, RiskGate>,
     detector: DislocationDetector,
+    /// Lead-lag detector (None if lead_lag disabled).
+    lead_lag_detector: Option<LeadLagDetector>,
+    /// Volatility regime classifier (None if detector.regime_enabled is false).
+    regime_classifier: Option<RegimeHandle>,
+    /// Reference prices for the oracle sanity check (None if disabled).
+    reference_prices: Option<ReferencePriceHandle>,
+    /// Recent BBOs for the momentum veto (None if disabled).
+    bbo_history: Option<BboHistoryHandle>,
     writer: ParquetWriter,
     /// Followup writer for signal validation snapshots.
     followup_writer: Arc<tokio::sync::Mutex<FollowupWriter>>,
+    /// Raw market-data recorder for offline replay (None if record_feed is off).
+    feed_writer: Option<FeedWriter>,
+    /// Signal/order/fill join for TCA (None if record_tca is off).
+    tca_tracker: Option<TcaTracker>,
+    /// TCA record writer (None if record_tca is off).
+    tca_writer: Option<TcaWriter>,
+    /// Submit-time rejection writer (None if the slippage guard is off).
+    signal_reject_writer: Option<SignalRejectWriter>,
+    /// Entry source and fees of open positions, for PnL attribution.
+    trade_attributor: TradeAttributor,
+    /// Trade ledger writer (None if record_trades is off).
+    trade_ledger: Option<TradeLedgerWriter>,
+    /// Position lifecycle journal writer (None if record_position_journal is off).
+    position_journal: Option<PositionJournalWriter>,
+    /// Signed actions awaiting the audit log (None if record_signed_actions is off).
+    signed_action_log: Option<SignedActionLog>,
+    /// Hash-chained signed-action writer (None if record_signed_actions is off).
+    audit_writer: Option<AuditWriter>,
+    /// Risk events awaiting the writer (None if record_risk_events is off).
+    risk_event_log: Option<RiskEventLog>,
+    /// Risk event writer (None if record_risk_events is off).
+    risk_event_writer: Option<RiskEventWriter>,
+    /// Signal ID and queue time of recent entry orders, by cloid, for
+    /// joining submit-time rejections back to their signal.
+    queued_signals: HashMap<ClientOrderId, (String, u64)>,
+    /// Warm-start state snapshot store (None if warm_state disabled).
+    warm_state_store: Option<StateStore>,
+    /// Position state snapshot store (None unless position_state enabled in Trading mode).
+    position_state_store: Option<PositionStateStore>,
+    /// Exit profile of open positions, for the position state snapshot.
+    position_profiles: RwLock<HashMap<MarketKey, ExitProfile>>,
+    /// New entries are paused until this instant after a fee tier change.
+    fee_change_pause_until: Option<Instant>,
     // P0-31: Cross duration tracking
     cross_tracker: CrossDurationTracker,
     // P0-31: Daily stats reporter (initialized after preflight)
@@ -109,15 +203,32 @@ pub struct Application {
     // Per-market threshold overrides in basis points.
     // Key: asset_idx (from MarketConfig), Value: threshold_bps
     market_threshold_map: HashMap<u32, Decimal>,
+    /// Per-market long/short thresholds by asset_idx (explicit sides only).
+    market_side_thresholds: HashMap<u32, SideThresholds>,
+    /// Underlying market hours (None unless trading_calendar.enabled).
+    trading_calendar: Option<TradingCalendar>,
     // Phase B: Trading mode components (None in Observation mode)
     /// Executor loop for batch order processing.
     executor_loop: Option<Arc<ExecutorLoop>>,
+    /// Nonce manager shared by the executor loop and REST fallback.
+    nonce_manager: Option<Arc<NonceManager<SystemClock>>>,
+    /// Last-nonce snapshot store (None unless nonce.persist in Trading mode).
+    nonce_store: Option<NonceStore>,
+    /// Loss limit snapshot store (None unless loss_limit_state.persist in
+    /// Trading mode with loss limits configured).
+    loss_limit_store: Option<LossLimitStore>,
+    /// Per-market vault routing (Trading mode; default-only otherwise).
+    vault_router: VaultRouter,
+    /// Builder code attached to every order (None if not configured).
+    builder: Option<BuilderInfo>,
     /// Position tracker handle for order/fill state management.
     position_tracker: Option<PositionTrackerHandle>,
     /// Position tracker task join handle for graceful shutdown.
     position_tracker_handle: Option<tokio::task::JoinHandle<()>>,
     /// Connection manager reference for READY check.
     connection_manager: Option<Arc<ConnectionManager>>,
+    /// Local/exchange clock skew, estimated from BBO server times.
+    clock_skew: ClockSkewEstimator,
     /// Risk event sender for RiskMonitor.
     risk_event_tx: Option<mpsc::Sender<ExecutionEvent>>,
     /// Recent signals buffer for dashboard display (last 50).
@@ -135,10 +246,31 @@ pub struct Application {
     oracle_tracker: OracleTrackerHandle,
     /// Oracle-driven exit watcher based on consecutive price movements.
     oracle_exit_watcher: Option<OracleExitWatcherHandle>,
+    /// Pluggable exit strategies per exit profile.
+    exit_strategy_watcher: Option<ExitStrategyWatcherHandle>,
+    /// Emergency de-risk selection from clearinghouse margin data.
+    liquidation_monitor: Option<LiquidationMonitorHandle>,
+    /// OCO exit pairs of open positions (None if oco disabled).
+    oco_book: Option<OcoBook>,
+    /// Hard stop latch (Trading mode only), also triggered on position drift.
+    hard_stop_latch: Option<Arc<HardStopLatch>>,
+    /// L1 hedger, books hedge fills from userFills (None = no hedging).
+    hedger: Option<HedgeHandle>,
+    /// Whether the last reconciliation breached the drift hard stop
+    /// threshold (health input of hard stop auto-recovery).
+    position_drift_breached: Arc<AtomicBool>,
+    /// Drift streaks across reconciliation passes.
+    drift_confirmer: parking_lot::Mutex<DriftConfirmer>,
     /// Edge distribution tracker for threshold calibration.
     edge_tracker: EdgeTracker,
     /// P2-3: MaxDrawdownGate for hourly drawdown control.
     max_drawdown_gate: Option<Arc<hip3_risk::MaxDrawdownGate>>,
+    /// Equity drawdown gate fed by the account value poll.
+    equity_drawdown_gate: Option<Arc<hip3_risk::EquityDrawdownGate>>,
+    /// Latency degradation gate fed by the latency samples.
+    latency_gate: Option<Arc<hip3_risk::LatencyGate>>,
+    /// Per-market risk budgets, fed realized PnL by handle_user_fill.
+    risk_budget_gate: Option<Arc<hip3_risk::RiskBudgetGate>>,
     /// P2-4: CorrelationCooldownGate for correlated close cooldown.
     correlation_cooldown_gate: Option<Arc<hip3_risk::CorrelationCooldownGate>>,
     /// P2-5: Cache of last signal edge_bps per market for dynamic exit thresholds.
@@ -147,6 +279,9 @@ pub struct Application {
     /// Sprint 4 P2-F: Cache of last signal ExitProfile per market.
     /// Populated at signal time, consumed at fill time for on_position_opened.
     last_signal_profile: RwLock<HashMap<MarketKey, ExitProfile>>,
+    /// Cache of last queued signal (side, best_px) per market.
+    /// Populated at signal time, consumed by the entry fill for slippage learning.
+    last_signal_best_px: RwLock<HashMap<MarketKey, (OrderSide, Price)>>,
     /// TiltGuardGate: consecutive loss cooldown.
     tilt_guard_gate: Option<Arc<hip3_risk::TiltGuardGate>>,
     /// ReEntryDelayGate: same-market re-entry delay.
@@ -157,12 +292,20 @@ pub struct Application {
     quote_manager: Option<QuoteManager>,
     /// MM: Inventory manager for tracking MM positions.
     mm_inventory: Option<InventoryManager>,
+    /// MM: Markout analytics of MM fills (adverse selection).
+    mm_markouts: Option<MarkoutAnalytics>,
     /// MM: Whether shutdown (cancel all + flatten) has been triggered for this weekend.
     mm_shutdown_triggered: bool,
+    /// MM: Markets inside one of their `maker.sessions` windows.
+    mm_session_markets: HashSet<MarketKey>,
     /// P3-1: Last time wick volatility stats were logged (ms).
     mm_wick_log_ms: u64,
     /// Shared guard across all exit monitors to prevent duplicate flatten requests.
     shared_flattening_guard: Option<SharedFlatteningGuard>,
+    /// Registered in-process extensions (advisory votes and annotations).
+    extensions: ExtensionBus,
+    /// Detection strategies registered in addition to the built-ins, by name.
+    custom_strategies: HashMap<String, Box<dyn DetectionStrategy + Send>>,
 }
 
 impl Application {
@@ -171,16 +314,118 @@ impl Application {
     /// Note: Markets may not be set yet. Call `run_preflight()` before `run()`.
     pub fn new(config: AppConfig) -> AppResult<Self> {
         // Initialize components
-        let market_state = Arc::new(MarketState::new());
+        let market_state = Arc::new(
+            MarketState::new().with_skew_corrected_freshness(config.risk.skew_corrected_freshness),
+        );
         let spec_cache = Arc::new(SpecCache::default());
-        let risk_gate = RiskGate::new(config.risk.clone());
-        let detector = DislocationDetector::new(config.detector.clone())?;
+        config.risk.log_gate_overrides();
+        let mut detector = DislocationDetector::new(config.detector.clone())?;
+        // Volatility regime classifier feeding per-regime threshold/size scaling
+        // (also the volatility source of the scaled time stop, stop-loss widening
+        // and the realized-vol risk gate)
+        let realized_vol_gate_enabled = !config.risk.max_realized_vol_bps.is_zero()
+            || config
+                .try_get_markets()
+                .is_some_and(|markets| markets.iter().any(|m| m.max_realized_vol_bps.is_some()));
+        let regime_classifier = (config.detector.regime_enabled
+            || config.time_stop.vol_scaling_enabled
+            || (config.exit_strategies.enabled && config.exit_strategies.stop_loss.widens())
+            || realized_vol_gate_enabled)
+            .then(|| RegimeClassifier::new_shared(config.regime.clone()));
+        if let Some(classifier) = regime_classifier
+            .as_ref()
+            .filter(|_| config.detector.regime_enabled)
+        {
+            detector = detector.with_regime_classifier(classifier.clone());
+        }
+        // Secondary reference prices for the oracle sanity check
+        let reference_prices = config
+            .detector
+            .oracle_sanity_enabled
+            .then(ReferencePriceStore::new_shared);
+        if let Some(ref store) = reference_prices {
+            detector = detector.with_reference_prices(store.clone());
+        }
+        // Short BBO history for the momentum veto
+        let bbo_history = config
+            .detector
+            .momentum_veto_enabled
+            .then(|| BboHistory::new_shared(config.detector.momentum_veto_window_ms));
+        if let Some(ref history) = bbo_history {
+            detector = detector.with_bbo_history(history.clone());
+        }
+        if config.slippage.enabled {
+            detector =
+                detector.with_slippage_estimator(SlippageEstimator::new(config.slippage.clone()));
+        }
+        let mut lead_lag_detector = config
+            .lead_lag
+            .enabled
+            .then(|| LeadLagDetector::new(config.lead_lag.clone(), &config.detector));
+        // Builder fee is paid on every order: part of the signal cost
+        let builder = Self::build_builder_info(&config)?;
+        if let Some(ref builder) = builder {
+            detector.set_builder_fee_bps(builder.fee_bps());
+            if let Some(ref mut lead_lag) = lead_lag_detector {
+                lead_lag.set_builder_fee_bps(builder.fee_bps());
+            }
+        }
         let writer =
             ParquetWriter::new(&config.persistence.data_dir, config.persistence.buffer_size);
+        let feed_writer = config
+            .persistence
+            .record_feed
+            .then(|| FeedWriter::new(&config.persistence.feed_dir, FEED_BUFFER_SIZE));
+        let tca_writer = config
+            .persistence
+            .record_tca
+            .then(|| TcaWriter::new(&config.persistence.tca_dir, config.persistence.buffer_size));
+        let tca_tracker = config.persistence.record_tca.then(TcaTracker::new);
+        let signed_action_log = config
+            .persistence
+            .record_signed_actions
+            .then(SignedActionLog::new);
+        let audit_writer = config
+            .persistence
+            .record_signed_actions
+            .then(|| AuditWriter::new(&config.persistence.audit_dir));
+        let trade_ledger = config.persistence.record_trades.then(|| {
+            TradeLedgerWriter::new(
+                &config.persistence.trades_dir,
+                config.persistence.buffer_size,
+            )
+        });
+        let position_journal = config.persistence.record_position_journal.then(|| {
+            PositionJournalWriter::new(
+                &config.persistence.journal_dir,
+                config.persistence.buffer_size,
+            )
+        });
+        let risk_event_log = config
+            .persistence
+            .record_risk_events
+            .then(|| RiskEventLog::new(config.persistence.risk_event_dedupe_secs * 1000));
+        let risk_event_writer = config.persistence.record_risk_events.then(|| {
+            RiskEventWriter::new(
+                &config.persistence.risk_events_dir,
+                config.persistence.buffer_size,
+            )
+        });
+        let signal_reject_writer = config.slippage_guard.enabled.then(|| {
+            SignalRejectWriter::new(&config.persistence.data_dir, config.persistence.buffer_size)
+        });
         let followup_writer = Arc::new(tokio::sync::Mutex::new(FollowupWriter::new(
             &config.persistence.data_dir,
             config.persistence.buffer_size,
         )));
+        let warm_state_store = config
+            .warm_state
+            .enabled
+            .then(|| StateStore::new(&config.warm_state.path));
+        let position_state_store = (config.position_state.enabled && config.executes_orders())
+            .then(|| PositionStateStore::new(&config.position_state.path));
+        let oco_book = (config.oco.enabled && config.executes_orders())
+            .then(|| OcoBook::new(config.oco.clone()));
 
         // P0-31: Cross tracker initialized, daily_stats deferred until markets known
         let cross_tracker = CrossDurationTracker::new();
@@ -201,6 +446,11 @@ impl Application {
             })
             .unwrap_or_default();
 
+        let trading_calendar = config
+            .trading_calendar
+            .enabled
+            .then(|| TradingCalendar::new(&config.trading_calendar));
+
         if !market_threshold_map.is_empty() {
             info!(
                 thresholds = ?market_threshold_map,
@@ -208,25 +458,114 @@ impl Application {
             );
         }
 
+        // Per-market long/short thresholds (take precedence over threshold_bps)
+        let market_side_thresholds: HashMap<u32, SideThresholds> = config
+            .markets
+            .as_ref()
+            .map(|markets| {
+                markets
+                    .iter()
+                    .filter(|m| m.threshold_bps_long.is_some() || m.threshold_bps_short.is_some())
+                    .map(|m| {
+                        let sided = SideThresholds {
+                            long: m.threshold_bps_long.map(Decimal::from),
+                            short: m.threshold_bps_short.map(Decimal::from),
+                        };
+                        (m.asset_idx, sided)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if !market_side_thresholds.is_empty() {
+            info!(
+                thresholds = ?market_side_thresholds,
+                "Per-market long/short thresholds configured"
+            );
+        }
+
+        // Per-market blackout windows are matched by parsing HH:MM on every tick;
+        // a typo would silently never match, so surface it now
+        for market in config.markets.iter().flatten() {
+            for window in &market.blackout_windows {
+                if window.start_time().is_none() || window.end_time().is_none() {
+                    warn!(
+                        coin = %market.coin,
+                        start = %window.start,
+                        end = %window.end,
+                        "Invalid blackout window (expected HH:MM); it will never match"
+                    );
+                } else {
+                    info!(
+                        coin = %market.coin,
+                        start = %window.start,
+                        end = %window.end,
+                        "Per-market blackout window configured"
+                    );
+                }
+            }
+        }
+
+        // Edge tracker (60s log interval), optionally closing the loop on thresholds
+        let mut edge_tracker = EdgeTracker::new(60, Decimal::from(40));
+        if config.threshold_calibration.enabled {
+            info!(
+                target_percentile = config.threshold_calibration.target_percentile,
+                min_samples = config.threshold_calibration.min_samples,
+                min_bps = %config.threshold_calibration.min_threshold_bps,
+                max_bps = %config.threshold_calibration.max_threshold_bps,
+                "Threshold auto-calibration enabled"
+            );
+            edge_tracker = edge_tracker.with_calibration(config.threshold_calibration.clone());
+        }
+
         Ok(Self {
             config,
             market_state,
             spec_cache,
-            risk_gate,
+            risk_gates: HashMap::new(),
             detector,
+            lead_lag_detector,
+            regime_classifier,
+            reference_prices,
+            bbo_history,
             writer,
             followup_writer,
+            feed_writer,
+            tca_tracker,
+            tca_writer,
+            signal_reject_writer,
+            trade_attributor: TradeAttributor::new(),
+            trade_ledger,
+            position_journal,
+            signed_action_log,
+            audit_writer,
+            risk_event_log,
+            risk_event_writer,
+            queued_signals: HashMap::new(),
+            warm_state_store,
+            position_state_store,
+            position_profiles: RwLock::new(HashMap::new()),
+            fee_change_pause_until: None,
             cross_tracker,
             daily_stats: None, // Initialized after preflight
             last_stats_output: Instant::now(),
             xyz_dex_id: None,
             gate_block_state: HashMap::new(),
             market_threshold_map,
+            market_side_thresholds,
+            trading_calendar,
             // Phase B: Initialized in Trading mode only
             executor_loop: None,
+            nonce_manager: None,
+            nonce_store: None,
+            loss_limit_store: None,
+            vault_router: VaultRouter::default(),
+            builder,
             position_tracker: None,
             position_tracker_handle: None,
             connection_manager: None,
+            clock_skew: ClockSkewEstimator::default(),
             risk_event_tx: None,
             // Dashboard: Recent signals buffer
             recent_signals: Arc::new(RwLock::new(VecDeque::with_capacity(50))),
@@ -241,15 +580,27 @@ impl Application {
             oracle_tracker,
             // Oracle-driven exit watcher (initialized in Trading mode only)
             oracle_exit_watcher: None,
-            // Edge tracker for threshold calibration (60s log interval)
-            edge_tracker: EdgeTracker::new(60, Decimal::from(40)),
+            // Exit strategy watcher (initialized in Trading mode only)
+            exit_strategy_watcher: None,
+            liquidation_monitor: None,
+            oco_book,
+            hard_stop_latch: None,
+            hedger: None,
+            position_drift_breached: Arc::new(AtomicBool::new(false)),
+            drift_confirmer: parking_lot::Mutex::new(DriftConfirmer::default()),
+            // Edge tracker for threshold calibration
+            edge_tracker,
             // P2-3/P2-4: Gates initialized in Trading mode only
             max_drawdown_gate: None,
+            equity_drawdown_gate: None,
+            latency_gate: None,
+            risk_budget_gate: None,
             correlation_cooldown_gate: None,
             // P2-5: Signal edge cache for dynamic exit thresholds
             last_signal_edge: RwLock::new(HashMap::new()),
             // Sprint 4 P2-F: Exit profile cache
             last_signal_profile: RwLock::new(HashMap::new()),
+            last_signal_best_px: RwLock::new(HashMap::new()),
             // TiltGuard and ReEntryDelay gates (initialized in Trading mode)
             tilt_guard_gate: None,
             re_entry_delay_gate: None,
@@ -258,12 +609,55 @@ impl Application {
             // MM: Initialized in Trading mode if maker.enabled
             quote_manager: None,
             mm_inventory: None,
+            mm_markouts: None,
             mm_shutdown_triggered: false,
+            mm_session_markets: HashSet::new(),
             mm_wick_log_ms: 0,
             shared_flattening_guard: None,
+            extensions: ExtensionBus::new(),
+            custom_strategies: HashMap::new(),
         })
     }
 
+    /// Register an in-process extension.
+    ///
+    /// Must be called before `run()`. See [`crate::extension`] for the
+    /// event and action contract.
+    pub fn register_extension(&mut self, extension: Arc<dyn Extension>) {
+        info!(extension = %extension.name(), "Extension registered");
+        self.extensions.register(extension);
+    }
+
+    /// Register a custom detection strategy.
+    ///
+    /// Must be called before `run()`. The strategy only runs for markets
+    /// whose `strategies` config lists its name. Built-in names cannot be
+    /// replaced.
+    pub fn register_strategy(&mut self, strategy: Box<dyn DetectionStrategy + Send>) {
+        let name = strategy.name().to_string();
+        if name == DISLOCATION_STRATEGY || name == LEAD_LAG_STRATEGY {
+            warn!(strategy = %name, "Strategy ignored: name is reserved for a built-in");
+            return;
+        }
+        info!(strategy = %name, "Detection strategy registered");
+        self.custom_strategies.insert(name, strategy);
+    }
+
+    /// Publish a domain event to registered extensions and log annotations.
+    fn publish_extension_event(&self, event: &DomainEvent) -> PublishOutcome {
+        let outcome = self.extensions.publish(event);
+        for note in &outcome.annotations {
+            info!(
+                event = event.kind(),
+                extension = %note.extension,
+                key = %note.key,
+                value = %note.value,
+                "Extension annotation"
+            );
+        }
+        outcome
+    }
+
     /// Run preflight validation and market discovery (P0-15, P0-26, P0-27).
     ///
     /// This fetches perpDexs from the exchange, populates SpecCache,
@@ -334,6 +728,12 @@ impl Application {
                 asset_idx: m.key.asset.index(),
                 coin: format!("{}:{}", dex_prefix, m.name),
                 threshold_bps: None, // Discovered markets use global threshold
+                threshold_bps_long: None,
+                threshold_bps_short: None,
+                min_cross_duration_ms: None,
+                blackout_windows: Vec::new(),
+                max_slippage_bps: None,
+                max_realized_vol_bps: None,
             })
             .collect();
 
@@ -492,11 +892,135 @@ impl Application {
         self.daily_stats = Some(DailyStatsReporter::new(market_keys));
     }
 
+    /// Lead-lag reference coins that are not already subscribed as markets.
+    fn lead_lag_reference_only_coins(&self) -> Vec<String> {
+        let Some(ref lead_lag) = self.lead_lag_detector else {
+            return Vec::new();
+        };
+        let markets = self.config.get_markets();
+        lead_lag
+            .reference_coins()
+            .into_iter()
+            .filter(|coin| !markets.iter().any(|m| m.coin.eq_ignore_ascii_case(coin)))
+            .collect()
+    }
+
+    /// Mirror the latest oracle/BBO into the executor cache for signal re-validation.
+    fn sync_executor_quote(&self, key: MarketKey) {
+        let Some(ref executor_loop) = self.executor_loop else {
+            return;
+        };
+        if let Some(snapshot) = self.market_state.get_snapshot(&key) {
+            executor_loop.executor().market_state_cache().update_quote(
+                &key,
+                snapshot.ctx.oracle.oracle_px,
+                snapshot.bbo.bid_price,
+                snapshot.bbo.ask_price,
+                current_time_ms(),
+            );
+        }
+    }
+
     /// Get the xyz DEX ID (discovered during preflight).
     fn get_dex_id(&self) -> DexId {
         self.xyz_dex_id.unwrap_or(DexId::XYZ)
     }
 
+    /// Parse the configured builder code.
+    fn build_builder_info(config: &AppConfig) -> AppResult<Option<BuilderInfo>> {
+        let Some(address) = config.builder.address.as_deref() else {
+            return Ok(None);
+        };
+        let address = Address::from_str(address).map_err(|e| {
+            AppError::Config(format!(
+                "Invalid builder address `{address}` (expected 0x...): {e}"
+            ))
+        })?;
+        Ok(Some(BuilderInfo::new(address, config.builder.fee)))
+    }
+
+    /// Check that the user approved at least the configured builder fee.
+    ///
+    /// Orders with an unapproved builder fee are rejected by the exchange,
+    /// so a missing or insufficient approval fails startup.
+    async fn verify_builder_approval(
+        &self,
+        user_address: &str,
+        builder: &BuilderInfo,
+    ) -> AppResult<()> {
+        let client = MetaClient::new(&self.config.info_url)
+            .map_err(|e| AppError::Preflight(format!("Failed to create MetaClient: {e}")))?;
+        let approved = client
+            .fetch_max_builder_fee(user_address, &builder.address)
+            .await
+            .map_err(|e| AppError::Preflight(format!("Failed to fetch maxBuilderFee: {e}")))?;
+        if approved < builder.fee {
+            return Err(AppError::Config(format!(
+                "Builder fee {} exceeds the approved maximum {approved} for builder {} \
+                 (tenths of a bp; sign approveBuilderFee first)",
+                builder.fee, builder.address
+            )));
+        }
+        info!(
+            builder = %builder.address,
+            fee = builder.fee,
+            approved,
+            "Builder fee approval verified"
+        );
+        Ok(())
+    }
+
+    /// Build the per-strategy vault router from `vault_routing`.
+    fn build_vault_router(&self, default_vault: Option<Address>) -> AppResult<VaultRouter> {
+        let parse = |addr: &str| {
+            Address::from_str(addr).map_err(|e| {
+                AppError::Config(format!(
+                    "Invalid vault_routing address `{addr}` (expected 0x...): {e}"
+                ))
+            })
+        };
+
+        let routing = &self.config.vault_routing;
+        let mut routes = BTreeMap::new();
+        for (strategy, vault) in [
+            (OrderStrategy::Taker, &routing.taker_vault),
+            (OrderStrategy::Maker, &routing.maker_vault),
+        ] {
+            if let Some(vault) = vault.as_deref() {
+                routes.insert(strategy, parse(vault)?);
+            }
+        }
+        Ok(VaultRouter::new(default_vault, routes))
+    }
+
+    /// Whether `account` is a dedicated maker account, whose fills and
+    /// positions belong to the MM inventory rather than the position tracker.
+    fn is_maker_account(&self, account: &str) -> bool {
+        self.vault_router.is_dedicated(OrderStrategy::Maker)
+            && self
+                .vault_router
+                .account(
+                    OrderStrategy::Maker,
+                    self.config.user_address.as_deref().unwrap_or_default(),
+                )
+                .eq_ignore_ascii_case(account)
+    }
+
+    /// Accounts to query for positions and open orders.
+    ///
+    /// The user address stands for the default vault; each routed vault is
+    /// queried by its own address.
+    fn trading_accounts(&self, user_address: &str) -> Vec<(String, Option<Address>)> {
+        let mut accounts = vec![(user_address.to_string(), self.vault_router.default_vault())];
+        accounts.extend(
+            self.vault_router
+                .routed_vaults()
+                .into_iter()
+                .map(|vault| (format!("{vault:#x}"), Some(vault))),
+        );
+        accounts
+    }
+
     /// Extract balance from clearinghouse state response.
     ///
     /// Tries margin_summary first, then cross_margin_summary.
@@ -517,6 +1041,53 @@ impl Application {
         Decimal::ZERO
     }
 
+    /// Poll the account value and feed the equity drawdown gate.
+    async fn poll_account_equity(&self, user_address: &str) {
+        use rust_decimal::prelude::ToPrimitive;
+
+        let Some(ref gate) = self.equity_drawdown_gate else {
+            return;
+        };
+        match self.fetch_account_equity(user_address).await {
+            Ok(equity) => {
+                gate.update(equity, current_time_ms());
+                let status = gate.status();
+                let drawdown_pct = status.drawdown_pct().unwrap_or(Decimal::ZERO);
+                Metrics::account_equity(
+                    status.equity.to_f64().unwrap_or(0.0),
+                    drawdown_pct.to_f64().unwrap_or(0.0),
+                );
+                debug!(
+                    equity = %status.equity,
+                    high_water_mark = %status.high_water_mark,
+                    drawdown_pct = %drawdown_pct,
+                    "Account equity polled"
+                );
+            }
+            Err(e) => warn!(?e, "Account equity poll failed"),
+        }
+    }
+
+    /// Total account value (L1 + xyz) across trading accounts.
+    async fn fetch_account_equity(&self, user_address: &str) -> AppResult<Decimal> {
+        let client = MetaClient::new(&self.config.info_url)
+            .map_err(|e| AppError::Executor(format!("Failed to create HTTP client: {e}")))?;
+        let dex_name = Some(self.config.xyz_pattern.as_str());
+        let mut equity = Decimal::ZERO;
+        for (address, _) in self.trading_accounts(user_address) {
+            for dex in [None, dex_name] {
+                let state = client
+                    .fetch_clearinghouse_state(&address, dex)
+                    .await
+                    .map_err(|e| {
+                        AppError::Executor(format!("Failed to fetch clearinghouseState: {e}"))
+                    })?;
+                equity += Self::extract_balance_from_state(&state);
+            }
+        }
+        Ok(equity)
+    }
+
     /// Sync positions from Hyperliquid clearinghouseState API.
     ///
     /// Called at startup to initialize PositionTracker with current positions.
@@ -530,6 +1101,7 @@ impl Application {
     async fn cancel_orphaned_orders(
         &self,
         user_address: &str,
+        strategy: OrderStrategy,
         batch_scheduler: &Arc<hip3_executor::BatchScheduler>,
     ) -> AppResult<()> {
         let dex_name = self.config.xyz_pattern.as_str();
@@ -574,7 +1146,9 @@ impl Application {
                 }
             };
 
-            let cancel = hip3_core::PendingCancel::new(market_key, order.oid, now_ms);
+            // Cancelled through the account the order rests in
+            let cancel = hip3_core::PendingCancel::new(market_key, order.oid, now_ms)
+                .with_strategy(strategy);
             match batch_scheduler.enqueue_cancel(cancel) {
                 hip3_core::EnqueueResult::Queued | hip3_core::EnqueueResult::QueuedDegraded => {
                     cancelled += 1;
@@ -609,119 +1183,220 @@ impl Application {
     /// We query both and sum the balances to get the total available:
     /// 1. L1 Perp balance (without dex param)
     /// 2. xyz balance + positions (with dex param)
+    ///
+    /// With vault routing, each routed vault is queried too. Balances are
+    /// kept per account: the tracker sizes taker entries off the taker
+    /// account's balance. Positions of a dedicated maker account belong to
+    /// the MM inventory and are not synced to the tracker.
     async fn sync_positions_from_api(
         &self,
         position_tracker: &PositionTrackerHandle,
         user_address: &str,
+        reconcile: bool,
+        restored: Option<&PositionState>,
     ) -> AppResult<()> {
         info!(user_address = %user_address, "Syncing positions from Hyperliquid API");
 
         let client = MetaClient::new(&self.config.info_url)
             .map_err(|e| AppError::Executor(format!("Failed to create HTTP client: {e}")))?;
-
-        // Step 1: Fetch L1 Perp balance (without dex param)
-        let l1_state = client
-            .fetch_clearinghouse_state(user_address, None)
-            .await
-            .map_err(|e| {
-                AppError::Executor(format!("Failed to fetch L1 clearinghouseState: {e}"))
-            })?;
-
-        // Extract L1 balance
-        let l1_balance = Self::extract_balance_from_state(&l1_state);
-
-        // Step 2: Fetch xyz state (with dex param) - for both balance AND positions
-        // BUG-005: Pass dex name to fetch perpDex positions.
-        // Without this, only L1 perp positions are returned (not xyz perpDex positions).
         let dex_name = Some(self.config.xyz_pattern.as_str());
-        let state = client
-            .fetch_clearinghouse_state(user_address, dex_name)
-            .await
-            .map_err(|e| {
-                AppError::Executor(format!("Failed to fetch xyz clearinghouseState: {e}"))
-            })?;
+        let now_ms = current_time_ms();
+        let dex_id = self.get_dex_id();
+        let taker_account = self
+            .vault_router
+            .account(OrderStrategy::Taker, user_address);
+        let mut balances = BTreeMap::new();
+        let mut remote_positions = Vec::new();
+        let mut account_margins = Vec::new();
+
+        for (address, _) in self.trading_accounts(user_address) {
+            let maker_account = self.is_maker_account(&address);
+            // Step 1: Fetch L1 Perp balance (without dex param)
+            let l1_state = client
+                .fetch_clearinghouse_state(&address, None)
+                .await
+                .map_err(|e| {
+                    AppError::Executor(format!("Failed to fetch L1 clearinghouseState: {e}"))
+                })?;
+
+            // Extract L1 balance
+            let l1_balance = Self::extract_balance_from_state(&l1_state);
+
+            // Step 2: Fetch xyz state (with dex param) - for both balance AND positions
+            // BUG-005: Pass dex name to fetch perpDex positions.
+            // Without this, only L1 perp positions are returned (not xyz perpDex positions).
+            let state = client
+                .fetch_clearinghouse_state(&address, dex_name)
+                .await
+                .map_err(|e| {
+                    AppError::Executor(format!("Failed to fetch xyz clearinghouseState: {e}"))
+                })?;
+
+            // Extract xyz balance
+            let xyz_balance = Self::extract_balance_from_state(&state);
+
+            // Total balance = L1 + xyz (funds automatically transfer between them)
+            let account_balance = l1_balance + xyz_balance;
+            info!(
+                account = %address,
+                l1_balance = %l1_balance,
+                xyz_balance = %xyz_balance,
+                account_balance = %account_balance,
+                "Fetched account balance from L1 + xyz"
+            );
+            balances.insert(address.clone(), account_balance);
+
+            // Liquidation buffer of the xyz account
+            let margin_summary = state
+                .cross_margin_summary
+                .as_ref()
+                .or(state.margin_summary.as_ref());
+            let mut account_margin = AccountMargin {
+                account: address.clone(),
+                account_value: margin_summary
+                    .and_then(|m| m.account_value_decimal().ok())
+                    .unwrap_or(Decimal::ZERO),
+                maintenance_margin: state
+                    .cross_maintenance_margin_used
+                    .as_ref()
+                    .and_then(|m| m.parse().ok())
+                    .unwrap_or(Decimal::ZERO),
+                positions: Vec::new(),
+            };
 
-        // Extract xyz balance
-        let xyz_balance = Self::extract_balance_from_state(&state);
+            for entry in &state.asset_positions {
+                let pos_data = &entry.position;
 
-        // Total balance = L1 + xyz (funds automatically transfer between them)
-        let total_balance = l1_balance + xyz_balance;
-        info!(
-            l1_balance = %l1_balance,
-            xyz_balance = %xyz_balance,
-            total_balance = %total_balance,
-            "Updating account balance from L1 + xyz"
-        );
-        position_tracker.update_balance(total_balance);
+                // Skip empty positions
+                if pos_data.is_empty() {
+                    continue;
+                }
 
-        let now_ms = current_time_ms();
-        let dex_id = self.get_dex_id();
-        let mut positions_to_sync = Vec::new();
+                // Parse coin name (e.g., "xyz:SILVER" -> MarketKey)
+                let coin = &pos_data.coin;
 
-        for entry in &state.asset_positions {
-            let pos_data = &entry.position;
+                // Find matching market in spec_cache
+                let market_key = self.coin_to_market_key(coin, dex_id);
+                let market_key = match market_key {
+                    Some(key) => key,
+                    None => {
+                        warn!(coin = %coin, "Could not find market key for position, skipping");
+                        continue;
+                    }
+                };
 
-            // Skip empty positions
-            if pos_data.is_empty() {
-                continue;
-            }
+                // Parse size and entry price
+                let size = match pos_data.size_decimal() {
+                    Ok(sz) => sz,
+                    Err(e) => {
+                        warn!(coin = %coin, ?e, "Failed to parse position size");
+                        continue;
+                    }
+                };
 
-            // Parse coin name (e.g., "xyz:SILVER" -> MarketKey)
-            let coin = &pos_data.coin;
+                let entry_price = match pos_data.entry_price_decimal() {
+                    Ok(px) => px,
+                    Err(e) => {
+                        warn!(coin = %coin, ?e, "Failed to parse entry price");
+                        continue;
+                    }
+                };
 
-            // Find matching market in spec_cache
-            let market_key = self.coin_to_market_key(coin, dex_id);
-            let market_key = match market_key {
-                Some(key) => key,
-                None => {
-                    warn!(coin = %coin, "Could not find market key for position, skipping");
-                    continue;
-                }
-            };
+                // Determine side from signed size
+                let (side, abs_size) = if size > Decimal::ZERO {
+                    (OrderSide::Buy, size)
+                } else {
+                    (OrderSide::Sell, size.abs())
+                };
 
-            // Parse size and entry price
-            let size = match pos_data.size_decimal() {
-                Ok(sz) => sz,
-                Err(e) => {
-                    warn!(coin = %coin, ?e, "Failed to parse position size");
-                    continue;
+                // Entry time and edge are not available from the API: take them
+                // from the position state snapshot if it has the same position
+                let record = restored
+                    .and_then(|state| state.position(&market_key))
+                    .filter(|record| record.side == side);
+                let mut position = Position::new(
+                    market_key,
+                    side,
+                    Size::new(abs_size),
+                    Price::new(entry_price),
+                    record.map_or(now_ms, |r| r.entry_timestamp_ms),
+                )
+                .with_entry_edge(record.and_then(|r| r.entry_edge_bps));
+                if let Some(profile) = record.and_then(|r| r.exit_profile) {
+                    self.position_profiles.write().insert(market_key, profile);
                 }
-            };
-
-            let entry_price = match pos_data.entry_price_decimal() {
-                Ok(px) => px,
-                Err(e) => {
-                    warn!(coin = %coin, ?e, "Failed to parse entry price");
-                    continue;
+                // Funding paid since open (positive = paid)
+                if let Some(since_open) = pos_data
+                    .cum_funding
+                    .as_ref()
+                    .and_then(|f| f.since_open.as_ref())
+                    .and_then(|s| s.parse::<Decimal>().ok())
+                {
+                    position.funding_usd = since_open;
                 }
-            };
 
-            // Determine side from signed size
-            let (side, abs_size) = if size > Decimal::ZERO {
-                (OrderSide::Buy, size)
-            } else {
-                (OrderSide::Sell, size.abs())
-            };
-
-            let position = Position::new(
-                market_key,
-                side,
-                Size::new(abs_size),
-                Price::new(entry_price),
-                now_ms, // Use current time as entry time (actual time not available from API)
-            );
+                let parse = |v: &Option<String>| v.as_ref().and_then(|s| s.parse::<Decimal>().ok());
+                account_margin.positions.push(PositionMargin {
+                    market: market_key,
+                    side,
+                    size: abs_size,
+                    mark_px: parse(&pos_data.position_value)
+                        .map_or(Decimal::ZERO, |value| value / abs_size),
+                    liquidation_px: parse(&pos_data.liquidation_px),
+                    unrealized_pnl: parse(&pos_data.unrealized_pnl).unwrap_or(Decimal::ZERO),
+                });
 
-            info!(
-                market = %market_key,
-                side = ?side,
-                size = %abs_size,
-                entry_price = %entry_price,
-                "Found existing position from API"
-            );
+                info!(
+                    market = %market_key,
+                    side = ?side,
+                    size = %abs_size,
+                    entry_price = %entry_price,
+                    maker_account,
+                    "Found existing position from API"
+                );
 
-            positions_to_sync.push(position);
+                if !maker_account {
+                    remote_positions.push((address.clone(), position));
+                }
+            }
+            account_margins.push(account_margin);
         }
 
+        self.update_liquidation_distance(&account_margins);
+        let held = if reconcile {
+            self.reconcile_positions(position_tracker, &taker_account, &remote_positions)
+        } else {
+            HashSet::new()
+        };
+
+        // The tracker holds the taker account only. A market with an
+        // unconfirmed drift keeps its local state until the next pass.
+        let mut positions_to_sync: Vec<Position> = remote_positions
+            .into_iter()
+            .filter(|(account, position)| {
+                account.eq_ignore_ascii_case(&taker_account) && !held.contains(&position.market)
+            })
+            .map(|(_, position)| position)
+            .collect();
+        positions_to_sync.extend(
+            position_tracker
+                .positions_snapshot()
+                .into_iter()
+                .filter(|position| held.contains(&position.market)),
+        );
+
+        let taker_balance = balances
+            .get(&taker_account)
+            .copied()
+            .unwrap_or(Decimal::ZERO);
+        info!(
+            taker_account = %taker_account,
+            taker_balance = %taker_balance,
+            balances = ?balances,
+            "Updating account balance from the taker account"
+        );
+        position_tracker.update_balance(taker_balance);
+
         info!(
             position_count = positions_to_sync.len(),
             "Syncing {} positions to PositionTracker",
@@ -762,6 +1437,37 @@ impl Application {
         Ok(())
     }
 
+    /// Resolve the coin names of correlation/netting groups to `MarketKey`s.
+    ///
+    /// Groups without a resolved market are skipped with a warning.
+    fn resolve_market_groups(
+        &self,
+        groups: &[hip3_risk::CorrelationGroupDef],
+        kind: &str,
+    ) -> Vec<hip3_risk::ResolvedCorrelationGroup> {
+        let dex_id = self.get_dex_id();
+        groups
+            .iter()
+            .filter_map(|g| {
+                let markets: std::collections::HashSet<MarketKey> = g
+                    .markets
+                    .iter()
+                    .filter_map(|coin| self.coin_to_market_key(coin, dex_id))
+                    .collect();
+                if markets.is_empty() {
+                    warn!(group = %g.name, kind, "Market group has no resolved markets, skipping");
+                    return None;
+                }
+                Some(hip3_risk::ResolvedCorrelationGroup {
+                    name: g.name.clone(),
+                    markets,
+                    weight: Decimal::try_from(g.weight).unwrap_or(Decimal::new(15, 1)),
+                    offset: g.offset.clamp(Decimal::ZERO, Decimal::ONE),
+                })
+            })
+            .collect()
+    }
+
     /// Convert coin name (e.g., "xyz:SILVER") to MarketKey.
     ///
     /// Searches spec_cache for matching market.
@@ -785,40 +1491,1034 @@ impl Application {
         None
     }
 
-    /// Run the application.
-    ///
-    /// # Panics
-    /// Panics if `run_preflight()` was not called and markets are not configured.
-    pub async fn run(mut self) -> AppResult<()> {
-        // Ensure preflight has been run
-        if !self.config.has_markets() {
-            return Err(AppError::Preflight(
-                "Markets not configured. Call run_preflight() first.".to_string(),
-            ));
+    /// Spawn the `allMids` poller feeding the oracle sanity reference.
+    fn start_reference_price_poller(&self) {
+        let Some(ref store) = self.reference_prices else {
+            return;
+        };
+        let reference = &self.config.reference_prices;
+        let dex_id = self.get_dex_id();
+        let pairs: Vec<(MarketKey, String)> = self
+            .config
+            .get_markets()
+            .iter()
+            .filter_map(|m| {
+                reference
+                    .coins
+                    .get(&m.coin)
+                    .map(|r| (MarketKey::new(dex_id, AssetId::new(m.asset_idx)), r.clone()))
+            })
+            .collect();
+        if pairs.is_empty() {
+            warn!("oracle_sanity_enabled but no market has a reference coin; check is inactive");
+            return;
         }
 
-        info!(mode = ?self.config.mode, "Starting application");
-
-        // Trading-mode config validation (fail fast before starting background tasks).
-        let (
-            trading_expected_signer_address,
-            trading_user_address,
-            trading_is_mainnet,
-            trading_vault_address,
-            trading_vault_address_str,
-        ) = if self.config.mode == OperatingMode::Trading {
-            let user_address = self.config.user_address.as_deref().ok_or_else(|| {
-                AppError::Config("Trading mode requires `user_address`".to_string())
-            })?;
+        let client = match MetaClient::new(&self.config.info_url) {
+            Ok(client) => client,
+            Err(e) => {
+                warn!(
+                    ?e,
+                    "Failed to create reference price client; check is inactive"
+                );
+                return;
+            }
+        };
+        info!(
+            markets = pairs.len(),
+            interval_ms = reference.poll_interval_ms,
+            dex = ?reference.dex,
+            "Starting reference price poller"
+        );
 
-            // Validate formatting early (used for subscriptions/account scoping).
-            Address::from_str(user_address).map_err(|e| {
-                AppError::Config(format!("Invalid `user_address` (expected 0x...): {e}"))
-            })?;
+        let store = store.clone();
+        let dex = reference.dex.clone();
+        let poll_interval = Duration::from_millis(reference.poll_interval_ms.max(100));
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(poll_interval);
+            loop {
+                interval.tick().await;
+                match client.fetch_all_mids(dex.as_deref()).await {
+                    Ok(mids) => {
+                        let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+                        for (key, coin) in &pairs {
+                            if let Some(px) = mids.get(coin) {
+                                store.update(*key, Price::new(*px), now_ms);
+                            }
+                        }
+                    }
+                    Err(e) => debug!(?e, "Reference price poll failed"),
+                }
+            }
+        });
+    }
 
-            let expected_signer_address = match self.config.signer_address.as_deref() {
-                Some(addr) => Some(Address::from_str(addr).map_err(|e| {
-                    AppError::Config(format!("Invalid `signer_address` (expected 0x...): {e}"))
+    /// Resolve the configured hedge pairs and register the L1 hedge markets
+    /// in the SpecCache (the executor formats orders from it).
+    fn build_hedger(&self) -> Option<HedgeHandle> {
+        let dex_id = self.get_dex_id();
+        let mut legs = Vec::new();
+        for pair in &self.config.hedge.pairs {
+            let Some(market) = self
+                .config
+                .get_markets()
+                .iter()
+                .find(|m| m.coin == pair.coin)
+            else {
+                warn!(coin = %pair.coin, "Hedge pair for an unconfigured market, ignored");
+                continue;
+            };
+            let hedge_market = MarketKey::new(dex_id, AssetId::new(pair.hedge_asset));
+            let spec = self.spec_cache.parse_spec(&RawPerpSpec {
+                name: pair.hedge_coin.clone(),
+                sz_decimals: pair.sz_decimals,
+                max_leverage: 1, // not used for order formatting
+                only_isolated: false,
+                tick_size: None,
+            });
+            if let Err(e) = self.spec_cache.update(hedge_market, spec) {
+                warn!(?e, hedge_coin = %pair.hedge_coin, "Hedge market spec rejected, pair ignored");
+                continue;
+            }
+            legs.push(HedgeLeg {
+                market: MarketKey::new(dex_id, AssetId::new(market.asset_idx)),
+                hedge_market,
+                hedge_coin: pair.hedge_coin.clone(),
+                sz_decimals: pair.sz_decimals,
+                ratio: pair.ratio,
+            });
+        }
+        if legs.is_empty() {
+            warn!("hedge.enabled but no usable hedge pair; hedging is inactive");
+            return None;
+        }
+        Some(Arc::new(HedgeManager::new(self.config.hedge.clone(), legs)))
+    }
+
+    /// Spawn the hedging loop: poll L1 mids, plan hedges from the current
+    /// positions and queue the hedge orders.
+    ///
+    /// Hedge positions are synced from the L1 clearinghouseState at start and
+    /// every `HEDGE_SYNC_TICKS` checks. Unwinds go on the reduce-only queue,
+    /// so they pass a hard stop; a full stop unwinds every hedge.
+    fn start_hedger(
+        &self,
+        hedger: HedgeHandle,
+        position_tracker: PositionTrackerHandle,
+        batch_scheduler: Arc<BatchScheduler>,
+        hard_stop_latch: Arc<HardStopLatch>,
+    ) {
+        const HEDGE_SYNC_TICKS: u64 = 30;
+
+        let client = match MetaClient::new(&self.config.info_url) {
+            Ok(client) => client,
+            Err(e) => {
+                warn!(
+                    ?e,
+                    "Failed to create hedge price client; hedging is inactive"
+                );
+                return;
+            }
+        };
+        let coins: Vec<(MarketKey, String)> = hedger
+            .legs()
+            .iter()
+            .map(|l| (l.hedge_market, l.hedge_coin.clone()))
+            .collect();
+        // Hedges are taker orders: all legs trade on the taker account
+        let user_address = self.config.user_address.clone().unwrap_or_default();
+        let mut accounts: HashMap<String, Vec<(MarketKey, String)>> = HashMap::new();
+        accounts.insert(
+            self.vault_router
+                .account(OrderStrategy::Taker, &user_address),
+            coins.clone(),
+        );
+        let check_interval = Duration::from_millis(hedger.config().check_interval_ms.max(100));
+        info!(
+            legs = hedger.legs().len(),
+            band_usd = %hedger.config().band_usd,
+            interval_ms = check_interval.as_millis() as u64,
+            "Starting L1 hedger"
+        );
+
+        let market_state = self.market_state.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(check_interval);
+            let mut ticks: u64 = 0;
+            loop {
+                interval.tick().await;
+                ticks += 1;
+                if ticks % HEDGE_SYNC_TICKS == 1 {
+                    for (account, markets) in &accounts {
+                        let state = match client.fetch_clearinghouse_state(account, None).await {
+                            Ok(state) => state,
+                            Err(e) => {
+                                warn!(?e, account = %account, "Hedge position sync failed");
+                                continue;
+                            }
+                        };
+                        for (market, coin) in markets {
+                            let position = state
+                                .asset_positions
+                                .iter()
+                                .map(|entry| &entry.position)
+                                .find(|p| p.coin == *coin && !p.is_empty());
+                            let (size, entry_px) = position
+                                .and_then(|p| {
+                                    Some((
+                                        p.size_decimal().ok()?,
+                                        p.entry_price_decimal().unwrap_or_default(),
+                                    ))
+                                })
+                                .unwrap_or_default();
+                            hedger.sync_position(*market, size, entry_px);
+                        }
+                    }
+                }
+
+                let mids: HashMap<MarketKey, Decimal> = match client.fetch_all_mids(None).await {
+                    Ok(all) => coins
+                        .iter()
+                        .filter_map(|(key, coin)| all.get(coin).map(|px| (*key, *px)))
+                        .collect(),
+                    Err(e) => {
+                        debug!(?e, "Hedge mid poll failed");
+                        continue;
+                    }
+                };
+
+                let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+                let orders = if hard_stop_latch.is_full_stop() {
+                    // Flatten includes the hedges
+                    hedger.plan_flatten(|market| mids.get(market).copied(), now_ms)
+                } else {
+                    let positions = position_tracker.positions_snapshot();
+                    hedger.plan(
+                        &positions,
+                        |market| {
+                            market_state
+                                .get_snapshot(market)
+                                .and_then(|s| s.bbo.spread_bps())
+                        },
+                        |market| mids.get(market).copied(),
+                        now_ms,
+                    )
+                };
+                for order in orders {
+                    let cloid = order.cloid.clone();
+                    let result = if order.reduce_only {
+                        batch_scheduler.enqueue_reduce_only(order)
+                    } else if hard_stop_latch.is_triggered() {
+                        // New hedges are entries: held back like any other
+                        debug!(cloid = %cloid, "Hedge order skipped: hard stop");
+                        hedger.on_order_done(&cloid);
+                        continue;
+                    } else {
+                        batch_scheduler.enqueue_new_order(order)
+                    };
+                    if !matches!(
+                        result,
+                        hip3_core::EnqueueResult::Queued | hip3_core::EnqueueResult::QueuedDegraded
+                    ) {
+                        warn!(cloid = %cloid, "Hedge order could not be queued");
+                        hedger.on_order_done(&cloid);
+                    }
+                }
+
+                // Hedge PnL is reported apart from xyz PnL
+                if ticks % 60 == 0 {
+                    for summary in hedger.summaries() {
+                        let unrealized = mids
+                            .get(&summary.hedge_market)
+                            .map(|mid| summary.book.unrealized_pnl(*mid));
+                        info!(
+                            hedge = %summary.hedge_coin,
+                            size = %summary.book.size,
+                            avg_px = %summary.book.avg_px,
+                            realized_pnl = %summary.book.realized_pnl,
+                            unrealized_pnl = ?unrealized,
+                            fees = %summary.book.fees,
+                            "Hedge PnL"
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    /// Spawn the user fee refresher, returning the receiver for fee updates.
+    fn start_fee_refresher(&self) -> Option<watch::Receiver<Option<ParsedUserFees>>> {
+        let fee_refresh = &self.config.fee_refresh;
+        if !fee_refresh.enabled {
+            return None;
+        }
+        let Some(user_address) = self.config.user_address.clone() else {
+            warn!("fee_refresh enabled but `user_address` is not set; using configured fees");
+            return None;
+        };
+        let client = match MetaClient::new(&self.config.info_url) {
+            Ok(client) => client,
+            Err(e) => {
+                warn!(
+                    ?e,
+                    "Failed to create fee refresh client; using configured fees"
+                );
+                return None;
+            }
+        };
+
+        info!(
+            interval_secs = fee_refresh.interval_secs,
+            pause_on_change_ms = fee_refresh.pause_on_change_ms,
+            "Starting user fee refresher"
+        );
+        let interval = Duration::from_secs(fee_refresh.interval_secs.max(60));
+        let (refresher, rx) = FeeRefresher::new(client, user_address, interval);
+        tokio::spawn(refresher.run());
+        Some(rx)
+    }
+
+    /// Swap refreshed user fees into the detectors' fee calculators.
+    ///
+    /// Runs on the event loop between ticks, so no detector check ever sees a
+    /// mix of old and new rates. A rate change optionally pauses new entries
+    /// for `pause_on_change_ms`.
+    fn apply_user_fees(&mut self, fees: ParsedUserFees) {
+        let current = self.detector.fee_calculator().user_fees();
+        if current.taker_bps == fees.taker_bps && current.maker_bps == fees.maker_bps {
+            return;
+        }
+
+        let user_fees = UserFees {
+            taker_bps: fees.taker_bps,
+            maker_bps: fees.maker_bps,
+            is_vip: fees.is_vip,
+            tier: fees.tier,
+        };
+        if let Some(ref mut lead_lag) = self.lead_lag_detector {
+            lead_lag.update_user_fees(user_fees.clone());
+        }
+        self.detector.update_user_fees(user_fees);
+
+        let pause_ms = self.config.fee_refresh.pause_on_change_ms;
+        if pause_ms > 0 {
+            self.fee_change_pause_until = Some(Instant::now() + Duration::from_millis(pause_ms));
+            info!(pause_ms, "Pausing new entries after fee tier change");
+        }
+    }
+
+    /// Seed estimators from the warm-state snapshot, if enabled and fresh.
+    fn restore_warm_state(&mut self) {
+        let Some(ref store) = self.warm_state_store else {
+            return;
+        };
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let max_age_ms = (self.config.warm_state.max_age_secs * 1000) as i64;
+        let state = match store.load(now_ms, max_age_ms) {
+            Ok(Some(state)) => state,
+            Ok(None) => return,
+            Err(e) => {
+                warn!(?e, path = %store.path().display(), "Failed to load warm state");
+                return;
+            }
+        };
+
+        for market in &state.markets {
+            if let Some(ewma) = market.spread_ewma_bps {
+                self.detector.restore_spread_ewma(market.market, ewma);
+            }
+            if let Some(ewma) = market.risk_spread_ewma_bps {
+                self.risk_gates
+                    .entry(market.market)
+                    .or_insert_with(|| RiskGate::new(self.config.risk.clone()))
+                    .restore_spread_ewma(ewma);
+            }
+            if let Some(side) = market.cross_side {
+                self.cross_tracker
+                    .restore_cross(market.market, side, market.cross_ticks);
+            }
+            if let Some(px) = market.oracle_last_px {
+                self.oracle_tracker.restore(
+                    market.market,
+                    px,
+                    market.oracle_consecutive_up,
+                    market.oracle_consecutive_down,
+                );
+            }
+        }
+    }
+
+    /// Feed a latency sample to the latency gate and publish its p95.
+    fn record_latency(&self, kind: hip3_risk::LatencyKind, latency_ms: f64, now_ms: u64) {
+        let Some(ref gate) = self.latency_gate else {
+            return;
+        };
+        gate.record(kind, latency_ms, now_ms);
+        Metrics::latency_gate(
+            kind.as_str(),
+            gate.p95(kind, now_ms),
+            gate.is_degraded_at(now_ms),
+        );
+    }
+
+    /// Persist orders the slippage guard dropped since the last call.
+    fn record_slippage_rejections(&mut self) {
+        let Some(ref executor_loop) = self.executor_loop else {
+            return;
+        };
+        let rejections = executor_loop.take_slippage_rejections();
+        if rejections.is_empty() {
+            return;
+        }
+        let timestamp_ms = chrono::Utc::now().timestamp_millis();
+        for rejection in rejections {
+            let order = &rejection.order;
+            let market_key = order.market.to_string();
+            Metrics::slippage_rejected(&market_key);
+
+            let signal_id = self
+                .queued_signals
+                .remove(&order.cloid)
+                .map(|(signal_id, _)| signal_id)
+                .unwrap_or_default();
+            let to_f64 = |d: Decimal| d.to_string().parse().unwrap_or(0.0);
+            let record = SignalRejectRecord {
+                timestamp_ms,
+                signal_id,
+                cloid: order.cloid.to_string(),
+                market_key,
+                side: order.side.to_string(),
+                reason: format!("{:?}", rejection.reason),
+                limit_px: to_f64(order.price.inner()),
+                reference_px: to_f64(rejection.breach.reference_px.inner()),
+                slippage_bps: to_f64(rejection.breach.slippage_bps),
+                max_slippage_bps: to_f64(rejection.breach.max_slippage_bps),
+            };
+            if let Some(ref mut writer) = self.signal_reject_writer {
+                if let Err(e) = writer.add_record(record) {
+                    warn!(?e, "Failed to record signal rejection");
+                }
+            }
+        }
+    }
+
+    /// Count and persist queued orders dropped at dequeue for a stale signal
+    /// since the last call.
+    fn record_stale_signal_drops(&mut self) {
+        let Some(ref executor_loop) = self.executor_loop else {
+            return;
+        };
+        let drops = executor_loop.take_stale_signal_drops();
+        if drops.is_empty() {
+            return;
+        }
+        let timestamp_ms = chrono::Utc::now().timestamp_millis();
+        for stale in drops {
+            let order = &stale.order;
+            let market_key = order.market.to_string();
+            Metrics::signal_expired(&market_key, stale.reason);
+
+            let signal_id = self
+                .queued_signals
+                .remove(&order.cloid)
+                .map(|(signal_id, _)| signal_id)
+                .unwrap_or_default();
+            let record = SignalRejectRecord {
+                timestamp_ms,
+                signal_id,
+                cloid: order.cloid.to_string(),
+                market_key,
+                side: order.side.to_string(),
+                reason: format!("{:?}({})", SkipReason::SignalExpired, stale.reason),
+                limit_px: order.price.inner().to_string().parse().unwrap_or(0.0),
+                reference_px: 0.0,
+                slippage_bps: 0.0,
+                max_slippage_bps: 0.0,
+            };
+            if let Some(ref mut writer) = self.signal_reject_writer {
+                if let Err(e) = writer.add_record(record) {
+                    warn!(?e, "Failed to record signal rejection");
+                }
+            }
+        }
+    }
+
+    /// Count the blocks observe-only gates would have made since the last
+    /// call and add them to the risk event log.
+    fn record_observed_blocks(&mut self) {
+        let Some(ref executor_loop) = self.executor_loop else {
+            return;
+        };
+        for block in executor_loop.executor().take_observed_blocks() {
+            Metrics::gate_observed_block(block.gate);
+            if let Some(ref log) = self.risk_event_log {
+                log.gate_observe_at(
+                    block.gate,
+                    block.market,
+                    &format!("{:?}", block.reason),
+                    block.timestamp_ms,
+                );
+            }
+        }
+    }
+
+    /// Persist risk events recorded since the last call.
+    fn record_risk_events(&mut self) {
+        let (Some(ref log), Some(ref mut writer)) =
+            (&self.risk_event_log, &mut self.risk_event_writer)
+        else {
+            return;
+        };
+        let events = log.take();
+        if events.is_empty() {
+            return;
+        }
+        for event in events {
+            let record = RiskEventRecord {
+                timestamp_ms: event.timestamp_ms,
+                event: event.kind.as_str().to_string(),
+                source: event.source,
+                component: event.component.map(str::to_string),
+                market_key: event.market.map(|m| m.to_string()),
+                level: event.level.map(|l| l.as_str().to_string()),
+                previous_level: event.previous_level.map(|l| l.as_str().to_string()),
+                reason: event.reason,
+                suppressed: event.suppressed,
+            };
+            if let Err(e) = writer.add_record(record) {
+                warn!(?e, "Failed to record risk event");
+            }
+        }
+        // Rare and needed after a crash: do not wait for a full buffer
+        if let Err(e) = writer.flush() {
+            warn!(?e, "Failed to flush risk events");
+        }
+    }
+
+    /// Append actions signed since the last call to the audit log.
+    fn record_signed_actions(&mut self) {
+        let (Some(ref log), Some(ref mut writer)) =
+            (&self.signed_action_log, &mut self.audit_writer)
+        else {
+            return;
+        };
+        for entry in log.take() {
+            let record = SignedActionRecord {
+                seq: 0,
+                timestamp_ms: entry.timestamp_ms,
+                post_id: entry.post_id,
+                nonce: entry.nonce,
+                action_type: entry.action_type,
+                action: entry.action,
+                vault_address: entry.vault_address.map(|a| format!("{a:#x}")),
+                action_hash: entry.action_hash.to_string(),
+                signature_r: entry.signature.r,
+                signature_s: entry.signature.s,
+                signature_v: entry.signature.v,
+                cloids: entry.cloids,
+                result: entry.result,
+                prev_hash: String::new(),
+                record_hash: String::new(),
+            };
+            if let Err(e) = writer.append(record) {
+                warn!(?e, "Failed to record signed action");
+            }
+        }
+    }
+
+    /// Resolve posts whose responses were lost in a disconnect.
+    async fn reconcile_inflight(&self, user_address: &str) {
+        let Some(ref executor_loop) = self.executor_loop else {
+            return;
+        };
+        let client = match MetaClient::new(&self.config.info_url) {
+            Ok(client) => client,
+            Err(e) => {
+                warn!(?e, "Failed to create HTTP client for reconciliation");
+                return;
+            }
+        };
+        let report = executor_loop
+            .reconcile_inflight(&client, user_address)
+            .await;
+        for (outcome, count) in report.outcomes() {
+            if count > 0 {
+                Metrics::inflight_reconciled(outcome.as_str(), count as u64);
+            }
+        }
+    }
+
+    /// Fetch the open orders of every trading account for MM
+    /// reconciliation in a background task, so the REST call never blocks
+    /// the message loop. Sends None if a fetch failed.
+    fn spawn_mm_open_orders_fetch(
+        &self,
+        user_address: &str,
+        tx: mpsc::Sender<Option<Vec<OpenOrder>>>,
+    ) {
+        let client = match MetaClient::new(&self.config.info_url) {
+            Ok(client) => client,
+            Err(e) => {
+                warn!(?e, "Failed to create HTTP client for MM reconciliation");
+                let _ = tx.try_send(None);
+                return;
+            }
+        };
+        let dex_name = self.config.xyz_pattern.clone();
+        let accounts = self.trading_accounts(user_address);
+        tokio::spawn(async move {
+            let mut open_orders = Vec::new();
+            for (account, _) in accounts {
+                match client.fetch_open_orders(&account, Some(&dex_name)).await {
+                    Ok(orders) => open_orders.extend(orders),
+                    Err(e) => {
+                        warn!(?e, "MM reconciliation: failed to fetch open orders");
+                        let _ = tx.send(None).await;
+                        return;
+                    }
+                }
+            }
+            let _ = tx.send(Some(open_orders)).await;
+        });
+    }
+
+    /// Reconcile MM quotes with the exchange's open orders on the MM
+    /// markets: cancel unknown orders (leaked quotes), re-register unacked
+    /// quotes and forget quotes gone from the book.
+    ///
+    /// Orders that aren't MM quotes are left alone: reduce-only orders,
+    /// orders pending in the position tracker (taker entries and exits) and
+    /// OCO take-profits, each recognized by its cloid.
+    fn reconcile_mm_orders(&mut self, open_orders: &[OpenOrder]) {
+        if self.quote_manager.is_none() {
+            return;
+        }
+
+        let orders: Vec<ExchangeOrder> = open_orders
+            .iter()
+            .filter_map(|order| {
+                let market = self.coin_to_market(&order.coin)?;
+                let name = self.spec_cache.get(&market).map(|s| s.name.clone())?;
+                if !self.is_mm_market(&name) {
+                    return None;
+                }
+                let side = match order.side.as_str() {
+                    "B" => OrderSide::Buy,
+                    "A" => OrderSide::Sell,
+                    _ => return None,
+                };
+                Some(ExchangeOrder {
+                    market,
+                    oid: order.oid,
+                    cloid: order.cloid.clone().map(ClientOrderId::from),
+                    side,
+                    price: Price::new(order.limit_px.parse().ok()?),
+                    size: Size::new(order.sz.parse().ok()?),
+                })
+            })
+            .collect();
+        let reduce_only: HashSet<u64> = open_orders
+            .iter()
+            .filter(|o| o.reduce_only)
+            .map(|o| o.oid)
+            .collect();
+
+        let Some(ref mut qm) = self.quote_manager else {
+            return;
+        };
+        let tracker = &self.position_tracker;
+        let oco_book = &self.oco_book;
+        let report = qm.reconcile(
+            &orders,
+            |order| {
+                if reduce_only.contains(&order.oid) {
+                    return true;
+                }
+                let Some(ref cloid) = order.cloid else {
+                    return false;
+                };
+                tracker
+                    .as_ref()
+                    .is_some_and(|t| t.get_pending_order(cloid).is_some())
+                    || oco_book
+                        .as_ref()
+                        .is_some_and(|book| book.is_take_profit(&order.market, cloid))
+            },
+            current_time_ms(),
+        );
+        info!(
+            open_orders = orders.len(),
+            unknown = report.unknown_cancels.len(),
+            registered = report.registered,
+            dropped = report.dropped,
+            "MM reconciliation complete"
+        );
+        if report.unknown_cancels.is_empty() {
+            return;
+        }
+        if let Some(ref executor_loop) = self.executor_loop {
+            let action = MakerAction::CancelOrders(report.unknown_cancels);
+            for result in executor_loop.executor().on_mm_quote(vec![action]) {
+                debug!(result = ?result, "MM reconciliation cancel result");
+            }
+        }
+    }
+
+    /// Load the position state snapshot, if enabled and fresh.
+    fn load_position_state(&self) -> Option<PositionState> {
+        let store = self.position_state_store.as_ref()?;
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let max_age_ms = (self.config.position_state.max_age_secs * 1000) as i64;
+        match store.load(now_ms, max_age_ms) {
+            Ok(state) => state,
+            Err(e) => {
+                warn!(?e, path = %store.path().display(), "Failed to load position state");
+                None
+            }
+        }
+    }
+
+    /// Re-register the snapshot's reduce-only orders that are still open on
+    /// the exchange, so exits stay blocked until they fill or are cancelled.
+    ///
+    /// Orders filled or cancelled while the bot was down are dropped (the API
+    /// sync already reflects their fills). Returns the flatten claims of the
+    /// markets with a restored order.
+    async fn restore_pending_orders(
+        &self,
+        position_tracker: &PositionTrackerHandle,
+        user_address: &str,
+        state: &PositionState,
+    ) -> AppResult<Vec<FlattenClaim>> {
+        let candidates: Vec<(&hip3_core::TrackedOrder, u64)> = state
+            .pending_orders
+            .iter()
+            .filter(|record| record.order.reduce_only)
+            .filter_map(|record| record.oid.map(|oid| (&record.order, oid)))
+            .collect();
+        if candidates.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let client = MetaClient::new(&self.config.info_url)
+            .map_err(|e| AppError::Executor(format!("Failed to create HTTP client: {e}")))?;
+        let dex_name = self.config.xyz_pattern.as_str();
+        let mut open_oids = HashSet::new();
+        for (account, _) in self.trading_accounts(user_address) {
+            let open_orders = client
+                .fetch_open_orders(&account, Some(dex_name))
+                .await
+                .map_err(|e| AppError::Executor(format!("Failed to fetch open orders: {e}")))?;
+            open_oids.extend(open_orders.iter().map(|order| order.oid));
+        }
+
+        let mut restored_markets = HashSet::new();
+        for (order, oid) in candidates {
+            if !open_oids.contains(&oid) {
+                debug!(cloid = %order.cloid, oid, "Snapshot order no longer open, dropping");
+                continue;
+            }
+            position_tracker.register_order(order.clone()).await;
+            position_tracker
+                .record_oid_mapping(order.cloid.clone(), oid)
+                .await;
+            restored_markets.insert(order.market);
+            info!(
+                market = %order.market,
+                cloid = %order.cloid,
+                oid,
+                "Restored pending reduce-only order"
+            );
+        }
+
+        Ok(state
+            .flattening
+            .iter()
+            .filter(|claim| restored_markets.contains(&claim.market))
+            .cloned()
+            .collect())
+    }
+
+    /// Register positions carried over from the position state snapshot with
+    /// the exit watchers, under their saved exit profile and entry edge.
+    fn resume_restored_positions(
+        &self,
+        position_tracker: &PositionTrackerHandle,
+        state: &PositionState,
+    ) {
+        for position in position_tracker.positions_snapshot() {
+            let Some(record) = state
+                .position(&position.market)
+                .filter(|record| record.side == position.side)
+            else {
+                continue;
+            };
+            let profile = record.exit_profile.unwrap_or(ExitProfile::Standard);
+            if let Some(ref oracle_exit) = self.oracle_exit_watcher {
+                oracle_exit.on_position_opened(
+                    position.market,
+                    position.side,
+                    record.entry_edge_bps,
+                    None,
+                    profile,
+                );
+            }
+            if let Some(ref exit_watcher) = self.exit_watcher {
+                exit_watcher.on_position_opened(position.market, profile);
+            }
+            if let Some(ref exit_strategies) = self.exit_strategy_watcher {
+                exit_strategies.on_position_opened(position.market, profile);
+            }
+            info!(market = %position.market, profile = %profile, "Resumed restored position");
+        }
+    }
+
+    /// Write positions, pending orders and flatten claims to the position
+    /// state snapshot.
+    fn save_position_state(&self) {
+        let (Some(ref store), Some(ref tracker)) =
+            (&self.position_state_store, &self.position_tracker)
+        else {
+            return;
+        };
+
+        let profiles = self.position_profiles.read();
+        let positions = tracker
+            .positions_snapshot()
+            .into_iter()
+            .map(|p| PositionRecord {
+                exit_profile: profiles.get(&p.market).copied(),
+                market: p.market,
+                side: p.side,
+                size: p.size,
+                entry_price: p.entry_price,
+                entry_timestamp_ms: p.entry_timestamp_ms,
+                entry_edge_bps: p.entry_edge_bps,
+            })
+            .collect();
+        drop(profiles);
+        let pending_orders = tracker
+            .pending_orders()
+            .into_iter()
+            .map(|order| PendingOrderRecord {
+                oid: tracker.get_oid(&order.cloid),
+                order,
+            })
+            .collect();
+        let flattening = self
+            .shared_flattening_guard
+            .as_ref()
+            .map(|guard| {
+                guard
+                    .claims()
+                    .into_iter()
+                    .map(|(market, reason)| FlattenClaim { market, reason })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let state = PositionState {
+            saved_at_ms: chrono::Utc::now().timestamp_millis(),
+            positions,
+            pending_orders,
+            flattening,
+        };
+        if let Err(e) = store.save(&state) {
+            warn!(?e, path = %store.path().display(), "Failed to save position state");
+        }
+    }
+
+    /// Restore loss limit state from the previous run and keep `store` for
+    /// saving it.
+    fn restore_loss_limits(&mut self, store: LossLimitStore) {
+        match store.load() {
+            Ok(Some(state)) => {
+                let saved_at_ms = state.saved_at_ms.max(0) as u64;
+                if let Some(ref gate) = self.max_drawdown_gate {
+                    gate.restore_loss_limits(
+                        hip3_risk::LossLimitStatus {
+                            daily_pnl_usd: state.daily_pnl_usd,
+                            weekly_pnl_usd: state.weekly_pnl_usd,
+                            daily_latched: state.daily_latched,
+                            weekly_latched: state.weekly_latched,
+                        },
+                        saved_at_ms,
+                    );
+                }
+                if let Some(ref gate) = self.risk_budget_gate {
+                    let losses: Vec<hip3_risk::MarketDailyLoss> = state
+                        .market_losses
+                        .iter()
+                        .map(|loss| hip3_risk::MarketDailyLoss {
+                            market: loss.market,
+                            pnl_usd: loss.pnl_usd,
+                            exhausted: loss.exhausted,
+                        })
+                        .collect();
+                    gate.restore_daily_losses(&losses, saved_at_ms);
+                }
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!(?e, path = %store.path().display(), "Failed to load loss limit state");
+            }
+        }
+        self.loss_limit_store = Some(store);
+        self.report_loss_limit_metrics();
+    }
+
+    /// Write the daily/weekly loss limit state and the per-market daily
+    /// losses to the loss limit snapshot.
+    fn save_loss_limits(&self) {
+        let Some(ref store) = self.loss_limit_store else {
+            return;
+        };
+        let status = self
+            .max_drawdown_gate
+            .as_ref()
+            .map(|gate| gate.loss_limit_status())
+            .unwrap_or_default();
+        let state = LossLimitState {
+            saved_at_ms: chrono::Utc::now().timestamp_millis(),
+            daily_pnl_usd: status.daily_pnl_usd,
+            weekly_pnl_usd: status.weekly_pnl_usd,
+            daily_latched: status.daily_latched,
+            weekly_latched: status.weekly_latched,
+            market_losses: self
+                .risk_budget_gate
+                .as_ref()
+                .map(|gate| {
+                    gate.daily_losses()
+                        .into_iter()
+                        .map(|loss| MarketLossRecord {
+                            market: loss.market,
+                            pnl_usd: loss.pnl_usd,
+                            exhausted: loss.exhausted,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+        if let Err(e) = store.save(&state) {
+            warn!(?e, path = %store.path().display(), "Failed to save loss limit state");
+        }
+    }
+
+    /// Write the last issued nonce to the nonce snapshot.
+    fn save_nonce(&self) {
+        let (Some(store), Some(nonce_manager)) = (&self.nonce_store, &self.nonce_manager) else {
+            return;
+        };
+        let snapshot = NonceSnapshot {
+            last_nonce: nonce_manager.last_nonce(),
+            saved_at_ms: chrono::Utc::now().timestamp_millis(),
+        };
+        if let Err(e) = store.save(&snapshot) {
+            warn!(?e, path = %store.path().display(), "Failed to save nonce");
+        }
+    }
+
+    /// Write the current estimator state to the warm-state snapshot.
+    fn save_warm_state(&self) {
+        let Some(ref store) = self.warm_state_store else {
+            return;
+        };
+
+        let mut markets: HashMap<MarketKey, MarketWarmState> = HashMap::new();
+        for (key, ewma) in self.detector.spread_ewmas() {
+            markets
+                .entry(key)
+                .or_insert_with(|| MarketWarmState::new(key))
+                .spread_ewma_bps = Some(ewma);
+        }
+        for (key, gate) in &self.risk_gates {
+            let ewma = gate.spread_ewma();
+            if !ewma.is_zero() {
+                markets
+                    .entry(*key)
+                    .or_insert_with(|| MarketWarmState::new(*key))
+                    .risk_spread_ewma_bps = Some(ewma);
+            }
+        }
+        for (key, side, ticks) in self.cross_tracker.active_crosses() {
+            let market = markets
+                .entry(key)
+                .or_insert_with(|| MarketWarmState::new(key));
+            market.cross_side = Some(side);
+            market.cross_ticks = ticks;
+        }
+        for key in self.oracle_tracker.markets() {
+            let Some(px) = self.oracle_tracker.last_price(&key) else {
+                continue;
+            };
+            let (up, down) = self.oracle_tracker.consecutive_counts(&key);
+            let market = markets
+                .entry(key)
+                .or_insert_with(|| MarketWarmState::new(key));
+            market.oracle_last_px = Some(px);
+            market.oracle_consecutive_up = up;
+            market.oracle_consecutive_down = down;
+        }
+
+        let state = WarmState {
+            saved_at_ms: chrono::Utc::now().timestamp_millis(),
+            markets: markets.into_values().collect(),
+        };
+        if let Err(e) = store.save(&state) {
+            warn!(?e, path = %store.path().display(), "Failed to save warm state");
+        }
+    }
+
+    /// Run the application.
+    ///
+    /// # Panics
+    /// Panics if `run_preflight()` was not called and markets are not configured.
+    pub async fn run(mut self) -> AppResult<()> {
+        // Ensure preflight has been run
+        if !self.config.has_markets() {
+            return Err(AppError::Preflight(
+                "Markets not configured. Call run_preflight() first.".to_string(),
+            ));
+        }
+
+        info!(mode = ?self.config.mode, "Starting application");
+
+        // Every configured strategy must be a built-in or registered
+        for name in self.config.strategies.referenced_names() {
+            let known = name == DISLOCATION_STRATEGY
+                || name == LEAD_LAG_STRATEGY
+                || self.custom_strategies.contains_key(name);
+            if !known {
+                return Err(AppError::Config(format!(
+                    "Unknown detection strategy `{name}` (register it before run())"
+                )));
+            }
+        }
+
+        self.restore_warm_state();
+        self.start_reference_price_poller();
+        let mut fee_rx = self.start_fee_refresher();
+
+        // Trading-mode config validation (fail fast before starting background tasks).
+        let (
+            trading_expected_signer_address,
+            trading_user_address,
+            trading_is_mainnet,
+            trading_vault_address,
+            trading_vault_address_str,
+        ) = if self.config.mode == OperatingMode::Trading {
+            let user_address = self.config.user_address.as_deref().ok_or_else(|| {
+                AppError::Config("Trading mode requires `user_address`".to_string())
+            })?;
+
+            // Validate formatting early (used for subscriptions/account scoping).
+            Address::from_str(user_address).map_err(|e| {
+                AppError::Config(format!("Invalid `user_address` (expected 0x...): {e}"))
+            })?;
+
+            let expected_signer_address = match self.config.signer_address.as_deref() {
+                Some(addr) => Some(Address::from_str(addr).map_err(|e| {
+                    AppError::Config(format!("Invalid `signer_address` (expected 0x...): {e}"))
                 })?),
                 None => None,
             };
@@ -863,18 +2563,57 @@ impl Application {
                 vault_address,
                 vault_address_str,
             )
+        } else if matches!(
+            self.config.mode,
+            OperatingMode::Paper | OperatingMode::Shadow
+        ) {
+            // Paper mode stays off the real account: its fills and order
+            // updates would otherwise mix into the simulated positions.
+            // Shadow mode never sends, so it needs no account either.
+            (None, None, false, None, None)
         } else {
             (None, self.config.user_address.clone(), false, None, None)
         };
 
-        // Create message channel
-        let (message_tx, mut message_rx) = mpsc::channel::<WsMessage>(1000);
+        // Builder fee approval (Trading only; Paper orders never reach the exchange)
+        if let (Some(builder), Some(user_address)) = (&self.builder, &trading_user_address) {
+            if self.config.mode == OperatingMode::Trading {
+                self.verify_builder_approval(user_address, builder).await?;
+            }
+        }
+
+        // Per-market vault routing (Trading only; Paper has no real accounts)
+        if self.config.mode == OperatingMode::Trading {
+            self.vault_router = self.build_vault_router(trading_vault_address)?;
+            if self.vault_router.has_routes() {
+                info!(
+                    default_vault = ?self.vault_router.default_vault(),
+                    routed_vaults = ?self.vault_router.routed_vaults(),
+                    "Vault routing enabled"
+                );
+            }
+        }
+
+        // Create message queue
+        let (message_tx, mut message_rx) = message_queue(MARKET_DATA_QUEUE_CAPACITY);
+        message_rx.on_drop(Arc::new(Metrics::ws_queue_dropped));
+        // Paper mode: simulated exchange responses share the WS message channel
+        let paper_message_tx =
+            (self.config.mode == OperatingMode::Paper).then(|| message_tx.clone());
 
         // Create WebSocket connection manager
         let mut ws_config: ConnectionConfig = self.config.websocket.clone().into();
         ws_config.url = self.config.ws_url.clone();
         ws_config.subscriptions = self.config.subscription_targets();
         ws_config.user_address = trading_user_address.clone();
+        ws_config.subscribe_l2_book |= self.config.detector.l2_depth_enabled;
+        ws_config.reference_coins = self.lead_lag_reference_only_coins();
+        ws_config.extra_user_addresses = self
+            .vault_router
+            .routed_vaults()
+            .iter()
+            .map(|vault| format!("{vault:#x}"))
+            .collect();
 
         info!(
             subscriptions = ?ws_config.subscriptions.iter().map(|s| &s.coin).collect::<Vec<_>>(),
@@ -883,6 +2622,12 @@ impl Application {
         );
 
         let connection_manager = Arc::new(ConnectionManager::new(ws_config, message_tx));
+        connection_manager.on_rtt(Arc::new(|connection, rtt_ms| {
+            Metrics::ws_rtt(connection, rtt_ms as f64);
+        }));
+        if self.config.persistence.record_frames {
+            connection_manager.on_frame(spawn_frame_recorder(&self.config.persistence));
+        }
         self.connection_manager = Some(connection_manager.clone());
         let connection_manager_clone = connection_manager.clone();
 
@@ -894,10 +2639,8 @@ impl Application {
         });
 
         // Trading mode initialization
-        let _tick_handle: Option<tokio::task::JoinHandle<()>> = if self.config.mode
-            == OperatingMode::Trading
-        {
-            info!("Initializing Trading mode components");
+        let _tick_handle: Option<tokio::task::JoinHandle<()>> = if self.config.executes_orders() {
+            info!(mode = ?self.config.mode, "Initializing Trading mode components");
 
             // 1. Position Tracker (actor)
             let (position_tracker, pos_join_handle) = spawn_position_tracker(100);
@@ -907,9 +2650,16 @@ impl Application {
             // 1.5. Sync positions from Hyperliquid API (P0-startup-sync)
             // Prevents stale position state after bot restart
             // Note: trading_user_address is always Some(...) in Trading mode
+            let restored_state = self.load_position_state();
+            let mut restored_flatten_claims = Vec::new();
             if let Some(ref user_addr) = trading_user_address {
                 if let Err(e) = self
-                    .sync_positions_from_api(&position_tracker, user_addr)
+                    .sync_positions_from_api(
+                        &position_tracker,
+                        user_addr,
+                        false,
+                        restored_state.as_ref(),
+                    )
                     .await
                 {
                     warn!(
@@ -917,34 +2667,78 @@ impl Application {
                         "Failed to sync positions from API, starting with empty state"
                     );
                 }
+                // Reduce-only orders still open from the previous session
+                // (the MM startup cleanup in 12.5 cancels them instead)
+                if let (Some(ref state), false) = (&restored_state, self.config.maker.enabled) {
+                    match self
+                        .restore_pending_orders(&position_tracker, user_addr, state)
+                        .await
+                    {
+                        Ok(claims) => restored_flatten_claims = claims,
+                        Err(e) => warn!(?e, "Failed to restore pending orders"),
+                    }
+                }
             }
 
             // 2. HardStopLatch and InflightTracker (shared dependencies)
-            let hard_stop_latch = Arc::new(HardStopLatch::new());
+            let mut hard_stop_latch = HardStopLatch::new();
+            // Operator pause of new entries (dashboard admin endpoints)
+            let mut entry_pause = hip3_executor::EntryPause::new();
+            if let Some(ref log) = self.risk_event_log {
+                hard_stop_latch = hard_stop_latch.with_event_log(log.clone());
+                entry_pause = entry_pause.with_event_log(log.clone());
+            }
+            let hard_stop_latch = Arc::new(hard_stop_latch);
+            self.hard_stop_latch = Some(hard_stop_latch.clone());
+            let entry_pause = Arc::new(entry_pause);
             let inflight_tracker = Arc::new(InflightTracker::new(10)); // max 10 inflight
 
             // 3. BatchScheduler (with configurable interval for latency optimization)
             let batch_config = BatchConfig {
                 interval_ms: self.config.executor.batch_interval_ms,
+                adaptive_interval: self.config.executor.adaptive_batch_interval,
+                min_interval_ms: self.config.executor.batch_interval_floor_ms,
+                idle_interval_ms: self.config.executor.batch_idle_interval_ms,
+                backlog_threshold: self.config.executor.batch_backlog_threshold,
+                strong_tier_budget: self.config.executor.strong_tier_budget,
+                weak_tier_budget: self.config.executor.weak_tier_budget,
+                quote_tier_budget: self.config.executor.quote_tier_budget,
+                starvation_ticks: self.config.executor.starvation_ticks,
                 ..BatchConfig::default()
             };
             info!(
                 interval_ms = batch_config.interval_ms,
+                adaptive = batch_config.adaptive_interval,
                 "Batch scheduler initialized"
             );
-            let batch_scheduler = Arc::new(BatchScheduler::new(
+            // ActionBudget (5.) is created first: with per-market quotas the
+            // scheduler charges it as it drains new orders.
+            let mut action_budget = ActionBudget::default();
+            let market_order_quota = self.config.executor.market_order_quota;
+            if market_order_quota > 0 {
+                action_budget = action_budget
+                    .with_market_quota(market_order_quota, self.config.executor.shared_order_pool);
+                info!(
+                    market_order_quota,
+                    shared_order_pool = self.config.executor.shared_order_pool,
+                    "Per-market action budget enabled"
+                );
+            }
+            let action_budget = Arc::new(action_budget);
+            let mut batch_scheduler = BatchScheduler::new(
                 batch_config,
                 inflight_tracker.clone(),
                 hard_stop_latch.clone(),
-            ));
+            );
+            if action_budget.has_market_quota() {
+                batch_scheduler = batch_scheduler.with_action_budget(action_budget.clone());
+            }
+            let batch_scheduler = Arc::new(batch_scheduler);
 
             // 4. TradingReadyChecker
             let (ready_checker, _ready_rx) = TradingReadyChecker::new();
             let ready_checker = Arc::new(ready_checker);
 
-            // 5. ActionBudget
-            let action_budget = Arc::new(ActionBudget::default());
-
             // 6. Executor core
             // Position limits from [position] config section.
             // Note: detector.max_notional controls per-order sizing (separate from position limits).
@@ -957,6 +2751,21 @@ impl Application {
                     self.config.position.dynamic_sizing.risk_per_market_pct,
                 )
                 .unwrap_or(Decimal::new(10, 2)), // Default 0.10 (10%)
+                min_confidence: self.config.executor.min_confidence,
+                alo_max_net_edge_bps: self.config.executor.alo_max_net_edge_bps,
+                alo_timeout_ms: self.config.executor.alo_timeout_ms,
+                strong_signal_min_net_edge_bps: self.config.executor.strong_signal_min_net_edge_bps,
+                max_add_ons: if self.config.position.scaling.enabled {
+                    self.config.position.scaling.max_add_ons
+                } else {
+                    0
+                },
+                add_on_min_edge_improvement_bps: self
+                    .config
+                    .position
+                    .scaling
+                    .min_edge_improvement_bps,
+                observe_only_gates: self.config.executor.observe_only_gates.clone(),
             };
             // P2-3: MaxDrawdownGate
             let max_drawdown_gate = Arc::new(hip3_risk::MaxDrawdownGate::new(
@@ -975,14 +2784,86 @@ impl Application {
                 action_budget.clone(),
                 executor_config,
                 Arc::new(MarketStateCache::default()),
-            );
+            )
+            .with_entry_pause(entry_pause.clone());
             if max_drawdown_gate.is_enabled() {
                 info!(
                     max_hourly_drawdown_usd = self.config.max_drawdown.max_hourly_drawdown_usd,
+                    max_daily_loss_usd = self.config.max_drawdown.max_daily_loss_usd,
+                    max_weekly_loss_usd = self.config.max_drawdown.max_weekly_loss_usd,
                     "MaxDrawdownGate enabled"
                 );
                 executor = executor.with_max_drawdown_gate(max_drawdown_gate.clone());
             }
+            // Equity drawdown from the session high-water mark
+            if self.config.equity_drawdown.enabled {
+                let gate = Arc::new(hip3_risk::EquityDrawdownGate::new(
+                    self.config.equity_drawdown.clone(),
+                ));
+                info!(
+                    max_drawdown_pct = %self.config.equity_drawdown.max_drawdown_pct,
+                    poll_interval_secs = self.config.equity_drawdown.poll_interval_secs,
+                    "EquityDrawdownGate enabled"
+                );
+                self.equity_drawdown_gate = Some(gate.clone());
+                executor = executor.with_equity_drawdown_gate(gate);
+            }
+            // p95 latency degradation (signal-to-order, WS post round trip)
+            if self.config.latency_gate.enabled {
+                let gate = Arc::new(hip3_risk::LatencyGate::new(
+                    self.config.latency_gate.clone(),
+                ));
+                info!(
+                    max_signal_to_order_p95_ms = self.config.latency_gate.max_signal_to_order_p95_ms,
+                    max_ws_round_trip_p95_ms = self.config.latency_gate.max_ws_round_trip_p95_ms,
+                    mm_spread_multiplier = %self.config.latency_gate.mm_spread_multiplier,
+                    "LatencyGate enabled"
+                );
+                self.latency_gate = Some(gate.clone());
+                executor = executor.with_latency_gate(gate);
+            }
+            // Order leak guard: open order count and cancel failures per market
+            if self.config.open_orders.enabled {
+                info!(
+                    max_open_orders_per_market = self.config.open_orders.max_open_orders_per_market,
+                    max_open_orders_total = self.config.open_orders.max_open_orders_total,
+                    max_cancel_failures = self.config.open_orders.max_cancel_failures,
+                    "OpenOrderGate enabled"
+                );
+                executor = executor.with_open_order_gate(Arc::new(hip3_risk::OpenOrderGate::new(
+                    self.config.open_orders.clone(),
+                    position_tracker.clone(),
+                )));
+            }
+            // Per-market disable after repeated exchange rejections
+            let reject_breaker = self.config.reject_breaker.enabled.then(|| {
+                Arc::new(hip3_risk::RejectBreaker::new(
+                    self.config.reject_breaker.clone(),
+                ))
+            });
+            if let Some(ref breaker) = reject_breaker {
+                info!(
+                    max_rejects = self.config.reject_breaker.max_rejects,
+                    window_secs = self.config.reject_breaker.window_secs,
+                    probe_interval_secs = self.config.reject_breaker.probe_interval_secs,
+                    "RejectBreaker enabled"
+                );
+                executor = executor.with_reject_breaker(breaker.clone());
+            }
+            // Entry slicing: large IOC entries worked as timed child slices
+            let order_slicer = self
+                .config
+                .slicing
+                .enabled
+                .then(|| hip3_executor::OrderSlicer::new_shared(self.config.slicing.clone()));
+            if let Some(ref slicer) = order_slicer {
+                info!(
+                    max_slice_notional = %self.config.slicing.max_slice_notional,
+                    duration_ms = self.config.slicing.duration_ms,
+                    "Entry slicing enabled"
+                );
+                executor = executor.with_order_slicer(slicer.clone());
+            }
             if correlation_cooldown_gate.is_enabled() {
                 info!(
                     threshold = self.config.correlation_cooldown.correlation_close_threshold,
@@ -1033,29 +2914,8 @@ impl Application {
             }
             // P3-3: CorrelationPositionGate
             if self.config.correlation_position.enabled {
-                let dex_id = self.get_dex_id();
-                let resolved_groups: Vec<hip3_risk::ResolvedCorrelationGroup> = self
-                    .config
-                    .correlation_position
-                    .groups
-                    .iter()
-                    .filter_map(|g| {
-                        let markets: std::collections::HashSet<MarketKey> = g
-                            .markets
-                            .iter()
-                            .filter_map(|coin| self.coin_to_market_key(coin, dex_id))
-                            .collect();
-                        if markets.is_empty() {
-                            warn!(group = %g.name, "Correlation group has no resolved markets, skipping");
-                            return None;
-                        }
-                        Some(hip3_risk::ResolvedCorrelationGroup {
-                            name: g.name.clone(),
-                            markets,
-                            weight: Decimal::try_from(g.weight).unwrap_or(Decimal::new(15, 1)),
-                        })
-                    })
-                    .collect();
+                let resolved_groups = self
+                    .resolve_market_groups(&self.config.correlation_position.groups, "Correlation");
 
                 if !resolved_groups.is_empty() {
                     let max_weighted =
@@ -1074,12 +2934,76 @@ impl Application {
                     executor = executor.with_correlation_position_gate(gate);
                 }
             }
+            // Net exposure limits per netting group
+            let net_exposure_gate = if self.config.netting.enabled {
+                let resolved_groups =
+                    self.resolve_market_groups(&self.config.netting.groups, "Netting");
+                let gate = Arc::new(hip3_risk::NetExposureGate::new(
+                    resolved_groups,
+                    position_tracker.clone(),
+                    &self.config.netting,
+                ));
+                info!(
+                    groups = self.config.netting.groups.len(),
+                    max_group_net = %self.config.netting.max_group_net_notional,
+                    max_total_net = %self.config.netting.max_total_net_notional,
+                    replaces_gross_total = gate.replaces_gross_total(),
+                    "NetExposureGate enabled"
+                );
+                executor = executor.with_net_exposure_gate(gate.clone());
+                Some(gate)
+            } else {
+                None
+            };
+            // Per-market notional and daily loss budgets
+            if self.config.risk_budget.enabled {
+                let dex_id = self.get_dex_id();
+                let budgets: HashMap<MarketKey, hip3_risk::MarketBudget> = self
+                    .config
+                    .risk_budget
+                    .markets
+                    .iter()
+                    .filter_map(|def| {
+                        let Some(market) = self.coin_to_market_key(&def.market, dex_id) else {
+                            warn!(market = %def.market, "Risk budget market not resolved, skipping");
+                            return None;
+                        };
+                        Some((
+                            market,
+                            hip3_risk::MarketBudget {
+                                notional_share: def
+                                    .notional_share
+                                    .clamp(Decimal::ZERO, Decimal::ONE),
+                                loss_share: def.loss_share.clamp(Decimal::ZERO, Decimal::ONE),
+                            },
+                        ))
+                    })
+                    .collect();
+                let gate = Arc::new(hip3_risk::RiskBudgetGate::new(
+                    budgets,
+                    &self.config.risk_budget,
+                    self.config.position.max_total_notional,
+                    position_tracker.clone(),
+                ));
+                info!(
+                    markets = self.config.risk_budget.markets.len(),
+                    daily_loss_budget_usd = %self.config.risk_budget.daily_loss_budget_usd,
+                    "RiskBudgetGate enabled"
+                );
+                self.risk_budget_gate = Some(gate.clone());
+                executor = executor.with_risk_budget_gate(gate);
+            }
             let executor = Arc::new(executor);
 
             // Store gate references for PnL/close reporting in handle_user_fill
             if max_drawdown_gate.is_enabled() {
                 self.max_drawdown_gate = Some(max_drawdown_gate);
             }
+            if self.config.loss_limit_state.persist
+                && (self.max_drawdown_gate.is_some() || self.risk_budget_gate.is_some())
+            {
+                self.restore_loss_limits(LossLimitStore::new(&self.config.loss_limit_state.path));
+            }
             if correlation_cooldown_gate.is_enabled() {
                 self.correlation_cooldown_gate = Some(correlation_cooldown_gate);
             }
@@ -1110,6 +3034,14 @@ impl Application {
                     use_alo = maker_config.use_alo,
                     "Market Maker enabled"
                 );
+                maker_config
+                    .validate()
+                    .map_err(|e| AppError::Config(format!("Invalid maker config: {e}")))?;
+                if !maker_config.markout_horizons_ms.is_empty() {
+                    self.mm_markouts = Some(MarkoutAnalytics::new(
+                        maker_config.markout_horizons_ms.clone(),
+                    ));
+                }
                 self.quote_manager = Some(QuoteManager::new(maker_config.clone()));
                 self.mm_inventory = Some(InventoryManager::new(maker_config.max_position_usd));
             }
@@ -1121,10 +3053,35 @@ impl Application {
                     var_name: "HIP3_TRADING_KEY".to_string(),
                 }
             });
-            let key_manager = Arc::new(
-                KeyManager::load(key_source, trading_expected_signer_address)
-                    .map_err(|e| AppError::Executor(format!("KeyManager error: {e}")))?,
-            );
+            let key_manager = if matches!(
+                self.config.mode,
+                OperatingMode::Paper | OperatingMode::Shadow
+            ) {
+                // Paper and shadow actions are signed but never leave the process
+                Arc::new(KeyManager::ephemeral())
+            } else {
+                let key_error = |e| AppError::Executor(format!("KeyManager error: {e}"));
+                let mut key_manager = KeyManager::load(key_source, trading_expected_signer_address)
+                    .map_err(key_error)?;
+                if let Some(ref var_name) = self.config.keys.maker_key_env {
+                    key_manager = key_manager
+                        .with_purpose_key(
+                            KeyPurpose::Maker,
+                            KeySource::EnvVar {
+                                var_name: var_name.clone(),
+                            },
+                        )
+                        .map_err(key_error)?;
+                }
+                for var_name in &self.config.keys.standby_key_envs {
+                    key_manager = key_manager
+                        .with_standby_key(KeySource::EnvVar {
+                            var_name: var_name.clone(),
+                        })
+                        .map_err(key_error)?;
+                }
+                Arc::new(key_manager)
+            };
 
             // 8. Signer
             let signer = Arc::new(
@@ -1133,6 +3090,8 @@ impl Application {
             );
             info!(
                 trading_address = ?signer.trading_address(),
+                maker_address = ?key_manager.address_for(KeyPurpose::Maker),
+                standby_keys = key_manager.standby_count(),
                 user_address = ?trading_user_address,
                 expected_signer_address = ?trading_expected_signer_address,
                 vault_address = ?trading_vault_address_str,
@@ -1142,6 +3101,82 @@ impl Application {
 
             // 9. NonceManager
             let nonce_manager = Arc::new(NonceManager::new(SystemClock));
+            if self.config.nonce.persist && self.config.mode == OperatingMode::Trading {
+                let store = NonceStore::new(&self.config.nonce.path);
+                match store.load() {
+                    Ok(Some(snapshot)) => {
+                        let nonce = nonce_manager
+                            .restore(snapshot.last_nonce, self.config.nonce.safety_offset_ms);
+                        info!(
+                            last_nonce = snapshot.last_nonce,
+                            nonce,
+                            safety_offset_ms = self.config.nonce.safety_offset_ms,
+                            "Nonce restored from previous run"
+                        );
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        warn!(?e, path = %store.path().display(), "Failed to load nonce");
+                    }
+                }
+                self.nonce_store = Some(store);
+            }
+            self.nonce_manager = Some(nonce_manager.clone());
+
+            // 9b. REST cancel-all fallback (Trading only; shares signer and nonces)
+            // One canceller per account; any strategy may rest orders in any market.
+            let rest_cancellers = match trading_user_address.as_deref() {
+                Some(user_address) if self.config.executor.rest_cancel_fallback => {
+                    let mut cancellers = Vec::new();
+                    for (account, vault) in self.trading_accounts(user_address) {
+                        let assets: HashMap<String, u32> = self
+                            .config
+                            .get_markets()
+                            .iter()
+                            .map(|m| (m.coin.clone(), m.asset_idx))
+                            .collect();
+                        let meta_client = MetaClient::new(&self.config.info_url).map_err(|e| {
+                            AppError::Executor(format!("Failed to create HTTP client: {e}"))
+                        })?;
+                        let canceller = RestCanceller::new(
+                            self.config.exchange_url(),
+                            meta_client,
+                            &account,
+                            Some(self.config.xyz_pattern.clone()),
+                            assets,
+                            signer.clone(),
+                            nonce_manager.clone(),
+                        )
+                        .map_err(|e| AppError::Executor(format!("RestCanceller error: {e}")))?
+                        .with_vault_address(vault);
+                        let canceller = match self.signed_action_log {
+                            Some(ref log) => canceller.with_action_log(log.clone()),
+                            None => canceller,
+                        };
+                        cancellers.push(Arc::new(canceller));
+                    }
+
+                    let hook_cancellers = cancellers.clone();
+                    connection_manager.on_disconnect(Arc::new(move || {
+                        let cancellers = hook_cancellers.clone();
+                        tokio::spawn(async move {
+                            warn!("WebSocket dropped, cancelling open orders over REST");
+                            for canceller in cancellers {
+                                if let Err(e) = canceller.cancel_all().await {
+                                    error!(?e, "REST cancel-all failed");
+                                }
+                            }
+                        });
+                    }));
+                    info!(
+                        exchange_url = %self.config.exchange_url(),
+                        accounts = cancellers.len(),
+                        "REST cancel-all fallback enabled"
+                    );
+                    cancellers
+                }
+                _ => Vec::new(),
+            };
 
             // 10. ExecutorLoop
             let mut executor_loop = ExecutorLoop::new(
@@ -1151,25 +3186,179 @@ impl Application {
                 5000,
                 self.spec_cache.clone(),
             );
-            executor_loop.set_vault_address(trading_vault_address);
+            executor_loop.set_vault_router(self.vault_router.clone());
+            if let Some(ref builder) = self.builder {
+                executor_loop.set_builder(builder.clone());
+            }
 
-            // 11. Wire WsSender
-            let ws_write_handle = connection_manager.write_handle();
-            let real_ws_sender: DynWsSender = Arc::new(RealWsSender::new(
-                ws_write_handle,
-                trading_vault_address_str.clone(),
-            ));
-            executor_loop.set_ws_sender(real_ws_sender);
+            // Submit-time slippage guard (per-market limits from markets config)
+            if self.config.slippage_guard.enabled {
+                let dex_id = self.get_dex_id();
+                let per_market = self
+                    .config
+                    .get_markets()
+                    .iter()
+                    .filter_map(|m| {
+                        m.max_slippage_bps.map(|bps| {
+                            (
+                                MarketKey::new(dex_id, AssetId::new(m.asset_idx)),
+                                Decimal::from(bps),
+                            )
+                        })
+                    })
+                    .collect();
+                executor_loop.set_slippage_guard(SlippageGuard::new(
+                    self.config.slippage_guard.clone(),
+                    per_market,
+                ));
+                info!(
+                    max_slippage_bps = %self.config.slippage_guard.max_slippage_bps,
+                    reprice = self.config.slippage_guard.reprice,
+                    "Submit-time slippage guard enabled"
+                );
+            }
+
+            // Fat-finger sanity gate (absolute bounds, last check before signing)
+            if self.config.fat_finger.enabled {
+                executor_loop
+                    .set_fat_finger_guard(FatFingerGuard::new(self.config.fat_finger.clone()));
+                info!(
+                    max_order_notional_usd = %self.config.fat_finger.max_order_notional_usd,
+                    max_price_deviation_bps = %self.config.fat_finger.max_price_deviation_bps,
+                    "Fat-finger sanity gate enabled"
+                );
+            }
+
+            // Reconnect reconciliation of posts with lost responses (Trading only)
+            if self.config.executor.reconcile_on_reconnect
+                && self.config.mode == OperatingMode::Trading
+            {
+                executor_loop.set_reconcile_on_reconnect(true);
+                info!("Inflight reconciliation on reconnect enabled");
+            }
+
+            // Timed-out amendments are looked up before any fallback cancel
+            // (Trading only; see 12.3)
+            if self.config.mode == OperatingMode::Trading {
+                executor_loop.set_lookup_timed_out_modifies(true);
+            }
+
+            // Dead man's switch: resting orders are cancelled by the exchange
+            // if the bot stops refreshing (Trading only; Paper has no account)
+            if self.config.scheduled_cancel.enabled && self.config.mode == OperatingMode::Trading {
+                executor_loop.set_scheduled_cancel(self.config.scheduled_cancel.clone());
+                info!(
+                    timeout_ms = self.config.scheduled_cancel.timeout_ms,
+                    refresh_interval_ms = self.config.scheduled_cancel.refresh_interval_ms,
+                    "Scheduled-cancel dead man's switch armed"
+                );
+            }
+
+            // Sweeper of orders whose orderUpdate was lost (Trading only: needs REST status)
+            let order_sweep_enabled =
+                self.config.order_sweep.enabled && self.config.mode == OperatingMode::Trading;
+            if order_sweep_enabled {
+                executor_loop.set_order_sweep(self.config.order_sweep.clone());
+            }
+
+            // REST order submission when WS posts are rate limited or the
+            // inflight limit holds exits back (Trading only; signs with the loop's keys)
+            if self.config.rest_fallback.enabled && self.config.mode == OperatingMode::Trading {
+                let client = RestExchangeClient::new(
+                    self.config.exchange_url(),
+                    self.config.rest_fallback.scope,
+                )
+                .map_err(|e| AppError::Executor(format!("RestExchangeClient error: {e}")))?;
+                executor_loop.set_rest_fallback(client);
+                info!(
+                    exchange_url = %self.config.exchange_url(),
+                    scope = ?self.config.rest_fallback.scope,
+                    "REST order submission fallback enabled"
+                );
+            }
+
+            if let Some(ref log) = self.signed_action_log {
+                executor_loop.set_action_log(log.clone());
+                info!(
+                    audit_dir = %self.config.persistence.audit_dir,
+                    "Signed-action audit log enabled"
+                );
+            }
+
+            // Hedging of xyz exposure on L1 perps (Trading only: needs real L1 fills)
+            let hedger = if self.config.hedge.enabled && self.config.mode == OperatingMode::Trading
+            {
+                self.build_hedger()
+            } else {
+                None
+            };
+            if let Some(ref hedger) = hedger {
+                executor_loop.set_hedger(hedger.clone());
+            }
+            self.hedger = hedger.clone();
+
+            // RiskMonitor event channel (Application/ExecutorLoop -> RiskMonitor, see step 14)
+            let (risk_event_tx, risk_event_rx) = mpsc::channel::<ExecutionEvent>(100);
+            if self.config.retry.enabled {
+                executor_loop.set_retry_policy(RetryPolicy::new(self.config.retry.clone()));
+                executor_loop.set_risk_event_tx(risk_event_tx.clone());
+                info!(
+                    max_retries = self.config.retry.max_retries,
+                    base_backoff_ms = self.config.retry.base_backoff_ms,
+                    "Rejection retry enabled"
+                );
+            }
+
+            // 11. Wire WsSender (simulated exchange in Paper mode, none in shadow mode)
+            if self.config.mode == OperatingMode::Shadow {
+                executor_loop.set_shadow(true);
+                info!("Shadow mode: signed actions are logged and never sent");
+            } else {
+                let ws_sender: DynWsSender = match paper_message_tx {
+                    Some(tx) => {
+                        let dex_id = self.get_dex_id();
+                        let markets = self.config.get_markets().iter().map(|m| {
+                            (
+                                MarketKey::new(dex_id, AssetId::new(m.asset_idx)),
+                                m.coin.clone(),
+                            )
+                        });
+                        info!(
+                            latency_ms = self.config.paper.latency_ms,
+                            fill_ratio = %self.config.paper.fill_ratio,
+                            "Paper trading: orders are simulated against the live BBO"
+                        );
+                        Arc::new(SimulatedWsSender::new(
+                            self.config.paper.clone(),
+                            executor.market_state_cache().clone(),
+                            markets,
+                            tx,
+                        ))
+                    }
+                    None => Arc::new(RealWsSender::new(connection_manager.write_handle())),
+                };
+                executor_loop.set_ws_sender(ws_sender);
+            }
 
             let executor_loop = Arc::new(executor_loop);
             self.executor_loop = Some(executor_loop.clone());
 
+            if let Some(hedger) = hedger {
+                self.start_hedger(
+                    hedger,
+                    position_tracker.clone(),
+                    batch_scheduler.clone(),
+                    hard_stop_latch.clone(),
+                );
+            }
+
             // 12. Spawn ExecutorLoop tick task (P2-7: event-driven with cooldown)
             let tick_executor_loop = executor_loop.clone();
             let notify = batch_scheduler.notify().clone();
-            let tick_interval_ms = batch_scheduler.interval().as_millis() as u64;
+            let tick_scheduler = batch_scheduler.clone();
             let tick_handle = tokio::spawn(async move {
-                let mut interval = tokio::time::interval(Duration::from_millis(tick_interval_ms));
+                let mut period = tick_scheduler.effective_interval();
+                let mut interval = tokio::time::interval(period);
                 let cooldown = Duration::from_millis(5);
                 loop {
                     // Wait for either the timer tick OR a notify signal
@@ -1182,21 +3371,125 @@ impl Application {
                             interval.reset();
                         }
                     }
-                    tick_executor_loop.tick(current_time_ms()).await;
-                }
-            });
+                    tick_executor_loop.tick(current_time_ms()).await;
+
+                    // Adaptive scheduling: re-arm the timer when queue pressure changes it
+                    let next = tick_scheduler.effective_interval();
+                    if next != period {
+                        period = next;
+                        interval =
+                            tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+                    }
+                    Metrics::batch_interval(period.as_millis() as f64);
+                    let (cancels, reduce_only, new_orders) = tick_scheduler.queue_lengths();
+                    Metrics::batch_queue_depth("cancel", cancels as f64);
+                    Metrics::batch_queue_depth(
+                        "modify",
+                        tick_scheduler.pending_modify_count() as f64,
+                    );
+                    Metrics::batch_queue_depth("reduce_only", reduce_only as f64);
+                    Metrics::batch_queue_depth("new_order", new_orders as f64);
+                    for (tier, len) in OrderTier::ALL.iter().zip(tick_scheduler.tier_lengths()) {
+                        Metrics::batch_queue_depth(tier.as_str(), len as f64);
+                    }
+                }
+            });
+
+            // 12.1. Pending order sweeper task
+            if let (true, Some(user_addr)) = (order_sweep_enabled, trading_user_address.clone()) {
+                let client = MetaClient::new(&self.config.info_url).map_err(|e| {
+                    AppError::Preflight(format!("Failed to create MetaClient: {e}"))
+                })?;
+                let sweep_executor_loop = executor_loop.clone();
+                let period =
+                    Duration::from_millis(self.config.order_sweep.sweep_interval_ms.max(1));
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(period);
+                    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                    loop {
+                        interval.tick().await;
+                        let report = sweep_executor_loop
+                            .sweep_pending_orders(&client, &user_addr, current_time_ms())
+                            .await;
+                        for (outcome, count) in report.outcomes() {
+                            if count > 0 {
+                                Metrics::orders_swept(outcome, count as u64);
+                            }
+                        }
+                    }
+                });
+                info!(
+                    pending_timeout_ms = self.config.order_sweep.pending_timeout_ms,
+                    sweep_interval_ms = self.config.order_sweep.sweep_interval_ms,
+                    "Pending order sweeper started"
+                );
+            }
+
+            // 12.2. Lookup of REST orders whose response was lost in transport
+            let rest_fallback_enabled =
+                self.config.rest_fallback.enabled && self.config.mode == OperatingMode::Trading;
+            if let (true, Some(user_addr)) = (rest_fallback_enabled, trading_user_address.clone()) {
+                let client = MetaClient::new(&self.config.info_url).map_err(|e| {
+                    AppError::Preflight(format!("Failed to create MetaClient: {e}"))
+                })?;
+                let rest_executor_loop = executor_loop.clone();
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(REST_RECONCILE_INTERVAL);
+                    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                    loop {
+                        interval.tick().await;
+                        let report = rest_executor_loop
+                            .reconcile_rest_unknown(&client, &user_addr)
+                            .await;
+                        for (outcome, count) in report.outcomes() {
+                            if count > 0 {
+                                Metrics::inflight_reconciled(outcome.as_str(), count as u64);
+                            }
+                        }
+                    }
+                });
+            }
+
+            // 12.3. Lookup of amendments whose batchModify timed out
+            if let (OperatingMode::Trading, Some(user_addr)) =
+                (self.config.mode, trading_user_address.clone())
+            {
+                let client = MetaClient::new(&self.config.info_url).map_err(|e| {
+                    AppError::Preflight(format!("Failed to create MetaClient: {e}"))
+                })?;
+                let modify_executor_loop = executor_loop.clone();
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(REST_RECONCILE_INTERVAL);
+                    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+                    loop {
+                        interval.tick().await;
+                        let report = modify_executor_loop
+                            .reconcile_timed_out_modifies(&client, &user_addr)
+                            .await;
+                        for (outcome, count) in report.outcomes() {
+                            if count > 0 {
+                                Metrics::inflight_reconciled(outcome.as_str(), count as u64);
+                            }
+                        }
+                    }
+                });
+            }
 
             // 12.5. Cancel orphaned orders from previous session (MM startup cleanup)
             if self.config.maker.enabled {
                 if let Some(ref user_addr) = trading_user_address {
-                    if let Err(e) = self
-                        .cancel_orphaned_orders(user_addr, &batch_scheduler)
-                        .await
-                    {
-                        warn!(
-                            ?e,
-                            "Failed to cancel orphaned orders, MM may create duplicates"
-                        );
+                    for (account, vault) in self.trading_accounts(user_addr) {
+                        let strategy = self.vault_router.strategy_of(vault);
+                        if let Err(e) = self
+                            .cancel_orphaned_orders(&account, strategy, &batch_scheduler)
+                            .await
+                        {
+                            warn!(
+                                ?e,
+                                account = %account,
+                                "Failed to cancel orphaned orders, MM may create duplicates"
+                            );
+                        }
                     }
                 }
             }
@@ -1227,22 +3520,35 @@ impl Application {
                 ));
 
                 // Create TimeStopConfig from app config
-                let time_stop_config = PositionTimeStopConfig::new(
+                let mut time_stop_config = PositionTimeStopConfig::new(
                     self.config.time_stop.threshold_ms,
                     self.config.time_stop.reduce_only_timeout_ms,
                 );
+                if self.config.time_stop.vol_scaling_enabled {
+                    time_stop_config = time_stop_config.with_vol_scaling(VolScaling {
+                        reference_vol_bps: self.config.time_stop.vol_reference_bps,
+                        min_threshold_ms: self.config.time_stop.min_threshold_ms,
+                        max_threshold_ms: self.config.time_stop.max_threshold_ms,
+                        min_samples: self.config.regime.min_samples,
+                    });
+                }
 
                 // Clone flatten_tx for MarkRegressionMonitor, ExitWatcher, and OracleExitWatcher
                 let mark_regression_flatten_tx = flatten_tx.clone();
                 let exit_watcher_flatten_tx = flatten_tx.clone();
                 let oracle_exit_flatten_tx = flatten_tx.clone();
+                let exit_strategy_flatten_tx = flatten_tx.clone();
+                let exit_strategy_price_provider = price_provider.clone();
 
                 // Shared guard across all exit monitors to prevent duplicate flatten requests
                 let shared_flattening_guard = SharedFlatteningGuard::new();
+                for claim in &restored_flatten_claims {
+                    shared_flattening_guard.try_claim(&claim.market, &claim.reason);
+                }
                 self.shared_flattening_guard = Some(shared_flattening_guard.clone());
 
                 // Create TimeStopMonitor
-                let time_stop_monitor = TimeStopMonitor::new(
+                let mut time_stop_monitor = TimeStopMonitor::new(
                     time_stop_config,
                     position_tracker.clone(),
                     flatten_tx,
@@ -1251,6 +3557,10 @@ impl Application {
                     self.config.time_stop.check_interval_ms,
                     Some(shared_flattening_guard.clone()),
                 );
+                if let Some(ref classifier) = self.regime_classifier {
+                    time_stop_monitor =
+                        time_stop_monitor.with_regime_classifier(classifier.clone());
+                }
 
                 // Spawn TimeStopMonitor task
                 tokio::spawn(async move {
@@ -1260,6 +3570,7 @@ impl Application {
                 info!(
                     threshold_ms = self.config.time_stop.threshold_ms,
                     slippage_bps = self.config.time_stop.slippage_bps,
+                    vol_scaling = self.config.time_stop.vol_scaling_enabled,
                     "TimeStopMonitor started"
                 );
 
@@ -1296,6 +3607,7 @@ impl Application {
                         time_decay_enabled: self.config.mark_regression.time_decay_enabled,
                         decay_start_ms: self.config.mark_regression.decay_start_ms,
                         min_decay_factor: self.config.mark_regression.min_decay_factor,
+                        break_even: self.config.mark_regression.break_even.clone(),
                     };
 
                     let mark_regression_monitor = MarkRegressionMonitor::new(
@@ -1348,13 +3660,68 @@ impl Application {
                         "OracleExitWatcher started (oracle-driven)"
                     );
                 }
+
+                // 13e. ExitStrategyWatcher for pluggable exit strategies
+                // (also runs the emergency de-risk of the liquidation monitor)
+                let exit_strategies_config = &self.config.exit_strategies;
+                if self.config.liquidation.enabled {
+                    self.liquidation_monitor = Some(Arc::new(LiquidationMonitor::new(
+                        self.config.liquidation.clone(),
+                    )));
+                }
+                if exit_strategies_config.enabled || self.liquidation_monitor.is_some() {
+                    let mut registry = if exit_strategies_config.enabled {
+                        ExitStrategyRegistry::from_config(
+                            exit_strategies_config,
+                            self.regime_classifier.clone(),
+                        )
+                    } else {
+                        ExitStrategyRegistry::new()
+                    };
+                    if let Some(ref monitor) = self.liquidation_monitor {
+                        for profile in [
+                            ExitProfile::Standard,
+                            ExitProfile::Scalper,
+                            ExitProfile::Runner,
+                        ] {
+                            registry
+                                .register(profile, Box::new(LiquidationStop::new(monitor.clone())));
+                        }
+                    }
+                    for profile in [
+                        ExitProfile::Standard,
+                        ExitProfile::Scalper,
+                        ExitProfile::Runner,
+                    ] {
+                        info!(
+                            profile = %profile,
+                            strategies = ?registry.strategy_names(profile),
+                            "Exit strategies registered"
+                        );
+                    }
+                    let watcher = Arc::new(ExitStrategyWatcher::new(
+                        registry,
+                        position_tracker.clone(),
+                        exit_strategy_flatten_tx,
+                        exit_strategy_price_provider,
+                        exit_strategies_config.slippage_bps,
+                        Some(shared_flattening_guard.clone()),
+                    ));
+                    tokio::spawn(watcher.clone().run(exit_strategies_config.tick_interval_ms));
+                    self.exit_strategy_watcher = Some(watcher);
+                }
+
+                if let Some(ref state) = restored_state {
+                    self.resume_restored_positions(&position_tracker, state);
+                }
             }
 
             // 14. RiskMonitor for risk condition monitoring
-            {
-                // Create event channel (Application -> RiskMonitor)
-                let (event_tx, event_rx) = mpsc::channel::<ExecutionEvent>(100);
-                self.risk_event_tx = Some(event_tx);
+            // (thresholds shared with the dashboard admin endpoint)
+            let risk_monitor_settings = {
+                // Event channel created with the ExecutorLoop (step 10)
+                let event_rx = risk_event_rx;
+                self.risk_event_tx = Some(risk_event_tx);
 
                 // Create executor handle channel (RiskMonitor -> Executor on HardStop)
                 let (executor_handle_tx, mut executor_handle_rx) = mpsc::channel::<String>(10);
@@ -1384,6 +3751,10 @@ impl Application {
                     max_slippage_bps: 50.0,            // Default
                     slippage_consecutive_threshold: 3, // Default
                 };
+                let mut settings = RiskMonitorSettings::new(risk_config.clone());
+                if let Some(ref log) = self.risk_event_log {
+                    settings = settings.with_event_log(log.clone());
+                }
 
                 // Create RiskMonitor
                 let risk_monitor = RiskMonitor::new(
@@ -1391,7 +3762,8 @@ impl Application {
                     hard_stop_latch.clone(),
                     executor_handle,
                     risk_config,
-                );
+                )
+                .with_settings(settings.clone());
 
                 // Spawn RiskMonitor task
                 tokio::spawn(async move {
@@ -1404,57 +3776,108 @@ impl Application {
                     max_flatten_failed = self.config.risk_monitor.max_flatten_failed,
                     "RiskMonitor started"
                 );
-            }
+                settings
+            };
 
-            // 15. HardStop Flatten Watcher
+            // 15. HardStop Flatten Watcher (full stop only; level 1 just blocks entries)
             {
                 let hard_stop_watcher_latch = hard_stop_latch.clone();
                 let hard_stop_watcher_tracker = position_tracker.clone();
                 let hard_stop_watcher_scheduler = batch_scheduler.clone();
                 let hard_stop_watcher_cache = executor_loop.executor().market_state_cache().clone();
-                let hard_stop_slippage_bps = self.config.time_stop.slippage_bps;
+                let hard_stop_escalation = self.config.flatten_escalation.clone();
+                let hard_stop_rest_cancellers = rest_cancellers.clone();
+                let hard_stop_cm = connection_manager.clone();
+                let hard_stop_flattening_guard = self.shared_flattening_guard.clone();
 
                 tokio::spawn(async move {
-                    const MAX_RETRIES: u32 = 3;
                     const RETRY_INTERVAL_MS: u64 = 1000;
                     const CHECK_INTERVAL_MS: u64 = 100;
 
-                    let mut triggered = false;
-                    let mut retry_count = 0u32;
-
+                    // The latch can be cleared and re-triggered via the admin
+                    // endpoints, so re-arm after each flatten sequence
                     loop {
-                        tokio::time::sleep(Duration::from_millis(CHECK_INTERVAL_MS)).await;
-
-                        if hard_stop_watcher_latch.is_triggered() && !triggered {
-                            triggered = true;
-                            warn!("🛑 HardStop detected, initiating flatten sequence");
-                        }
-
-                        if triggered {
-                            // Get all positions
-                            let positions = hard_stop_watcher_tracker.positions_snapshot();
+                        let mut triggered = false;
+                        let mut retry_count = 0u32;
+                        // Each retry on a market is priced more aggressively
+                        let mut flattener =
+                            Flattener::with_default().with_escalation(hard_stop_escalation.clone());
+
+                        loop {
+                            tokio::time::sleep(Duration::from_millis(CHECK_INTERVAL_MS)).await;
+                            Metrics::hard_stop_level(hard_stop_watcher_latch.level().as_u8());
+
+                            if hard_stop_watcher_latch.is_full_stop() && !triggered {
+                                triggered = true;
+                                warn!("🛑 HardStop detected, initiating flatten sequence");
+                                // WS posts cannot reach the exchange; pull resting orders over REST
+                                if !hard_stop_rest_cancellers.is_empty() && !hard_stop_cm.is_ready()
+                                {
+                                    warn!("WebSocket not ready, cancelling open orders over REST");
+                                    for canceller in &hard_stop_rest_cancellers {
+                                        if let Err(e) = canceller.cancel_all().await {
+                                            error!(?e, "REST cancel-all failed");
+                                        }
+                                    }
+                                }
+                            }
 
-                            if positions.is_empty() {
-                                info!("All positions flattened successfully (or none existed)");
+                            if triggered && !hard_stop_watcher_latch.is_full_stop() {
+                                // Cleared by an admin or auto-recovery mid-sequence:
+                                // stop flattening what may now be intended exposure
+                                info!(
+                                    retries = retry_count,
+                                    "HardStop cleared during flatten, stopping sequence"
+                                );
                                 break;
                             }
 
-                            // Create flatten requests
-                            let now_ms = current_time_ms();
-                            let flatten_requests =
-                                flatten_all_positions(&positions, FlattenReason::HardStop, now_ms);
+                            if triggered {
+                                // Get all positions (L1 hedges are flattened by the hedger loop)
+                                let positions = hard_stop_watcher_tracker.positions_snapshot();
 
-                            if flatten_requests.is_empty() {
-                                info!("No non-zero positions to flatten");
-                                break;
-                            }
+                                if positions.is_empty() {
+                                    info!("All positions flattened successfully (or none existed)");
+                                    break;
+                                }
+
+                                // Create flatten requests
+                                let now_ms = current_time_ms();
+                                let flatten_requests = flatten_all_positions(
+                                    &positions,
+                                    FlattenReason::HardStop,
+                                    now_ms,
+                                );
+
+                                if flatten_requests.is_empty() {
+                                    info!("No non-zero positions to flatten");
+                                    break;
+                                }
 
-                            // Convert to PendingOrders and enqueue
-                            for request in &flatten_requests {
-                                // Get mark price for limit price calculation
-                                let mark_price =
-                                    match hard_stop_watcher_cache.get_mark_px(&request.market) {
-                                        Some(p) => p,
+                                // Convert to PendingOrders and enqueue
+                                let mut exhausted = Vec::new();
+                                for request in &flatten_requests {
+                                    let pricing = flattener.next_pricing(&request.market);
+                                    let mark_price =
+                                        hard_stop_watcher_cache.get_mark_px(&request.market);
+                                    let far_touch = hard_stop_watcher_cache
+                                        .get_quote(&request.market)
+                                        .map(|q| match request.side {
+                                            OrderSide::Buy => q.best_ask,
+                                            OrderSide::Sell => q.best_bid,
+                                        })
+                                        .filter(|px| !px.is_zero());
+
+                                    let limit_price = match pricing.limit_price(
+                                        request.side,
+                                        mark_price,
+                                        far_touch,
+                                    ) {
+                                        Some(px) => px,
+                                        None if pricing == FlattenPricing::Exhausted => {
+                                            exhausted.push(request.market);
+                                            continue;
+                                        }
                                         None => {
                                             error!(
                                                 market = %request.market,
@@ -1463,82 +3886,135 @@ impl Application {
                                             continue;
                                         }
                                     };
+                                    if let FlattenPricing::Cross { .. } = pricing {
+                                        warn!(
+                                            market = %request.market,
+                                            attempt = flattener.attempts(&request.market),
+                                            limit_price = %limit_price,
+                                            "HardStop flatten crossing the spread"
+                                        );
+                                    }
 
-                                // Calculate limit price with slippage
-                                let slippage_multiplier = if request.side == OrderSide::Buy {
-                                    // Buy (close short): mark * (1 + slippage)
-                                    Decimal::new(10000 + hard_stop_slippage_bps as i64, 4)
-                                } else {
-                                    // Sell (close long): mark * (1 - slippage)
-                                    Decimal::new(10000 - hard_stop_slippage_bps as i64, 4)
-                                };
-                                let limit_price =
-                                    Price::new(mark_price.inner() * slippage_multiplier);
-
-                                // Create reduce-only PendingOrder
-                                let pending_order = PendingOrder {
-                                    cloid: ClientOrderId::new(),
-                                    market: request.market,
-                                    side: request.side,
-                                    price: limit_price,
-                                    size: request.size,
-                                    reduce_only: true,
-                                    created_at: now_ms,
-                                    tif: TimeInForce::ImmediateOrCancel,
-                                };
-
-                                debug!(
-                                    market = %request.market,
-                                    side = ?request.side,
-                                    size = %request.size,
-                                    limit_price = %limit_price,
-                                    "Enqueuing HardStop flatten order"
-                                );
+                                    // Create reduce-only PendingOrder
+                                    let pending_order = PendingOrder {
+                                        cloid: ClientOrderId::new(),
+                                        market: request.market,
+                                        side: request.side,
+                                        price: limit_price,
+                                        size: request.size,
+                                        reduce_only: true,
+                                        created_at: now_ms,
+                                        tif: TimeInForce::ImmediateOrCancel,
+                                        validity: None,
+                                        strategy: OrderStrategy::Taker,
+                                    };
 
-                                hard_stop_watcher_scheduler.enqueue_reduce_only(pending_order);
-                            }
+                                    debug!(
+                                        market = %request.market,
+                                        side = ?request.side,
+                                        size = %request.size,
+                                        limit_price = %limit_price,
+                                        "Enqueuing HardStop flatten order"
+                                    );
 
-                            info!(
-                                count = flatten_requests.len(),
-                                retry = retry_count,
-                                "Enqueued HardStop flatten orders"
-                            );
+                                    // Claim the guard for PnL attribution (an exit already
+                                    // in flight keeps its own reason)
+                                    if let Some(ref guard) = hard_stop_flattening_guard {
+                                        guard.try_claim(&request.market, "HardStop");
+                                    }
+                                    hard_stop_watcher_scheduler.enqueue_reduce_only(pending_order);
+                                }
+
+                                info!(
+                                    count = flatten_requests.len() - exhausted.len(),
+                                    retry = retry_count,
+                                    "Enqueued HardStop flatten orders"
+                                );
+                                retry_count += 1;
 
-                            retry_count += 1;
-                            if retry_count >= MAX_RETRIES {
-                                let remaining =
-                                    hard_stop_watcher_tracker.positions_snapshot().len();
-                                if remaining > 0 {
+                                if exhausted.len() == flatten_requests.len() {
                                     error!(
-                                            remaining = remaining,
-                                            max_retries = MAX_RETRIES,
-                                            "⚠️ CRITICAL: Positions remain after max retries. Manual intervention required."
-                                        );
+                                        remaining = exhausted.len(),
+                                        markets = ?exhausted,
+                                        retries = retry_count,
+                                        "⚠️ CRITICAL: Positions remain after flatten escalation. Manual intervention required."
+                                    );
+                                    break;
                                 }
-                                break;
+
+                                // Wait before retry
+                                tokio::time::sleep(Duration::from_millis(RETRY_INTERVAL_MS)).await;
                             }
+                        }
 
-                            // Wait before retry
-                            tokio::time::sleep(Duration::from_millis(RETRY_INTERVAL_MS)).await;
+                        info!("HardStop flatten sequence finished");
+                        while hard_stop_watcher_latch.is_full_stop() {
+                            tokio::time::sleep(Duration::from_millis(CHECK_INTERVAL_MS)).await;
+                            Metrics::hard_stop_level(hard_stop_watcher_latch.level().as_u8());
                         }
+                        info!("HardStop cleared, flatten watcher re-armed");
                     }
-
-                    info!("HardStop flatten watcher stopped");
                 });
 
                 info!("HardStop flatten watcher started");
             }
 
+            // 15b. HardStop auto-recovery (recoverable reasons only)
+            let recovery = HardStopRecovery::new(self.config.hard_stop_recovery.clone());
+            if recovery.is_enabled() {
+                let recovery_latch = hard_stop_latch.clone();
+                let recovery_cm = connection_manager.clone();
+                let recovery_drift = self.position_drift_breached.clone();
+                let interval_secs = recovery.config().check_interval_secs.max(1);
+                info!(
+                    reasons = ?recovery.reasons(),
+                    cool_off_secs = recovery.config().cool_off_secs,
+                    healthy_checks = recovery.config().healthy_checks,
+                    "HardStop auto-recovery enabled"
+                );
+
+                tokio::spawn(async move {
+                    let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+                    loop {
+                        interval.tick().await;
+                        // Healthy: feed connected and no outstanding drift breach
+                        let healthy =
+                            recovery_cm.is_ready() && !recovery_drift.load(Ordering::Relaxed);
+                        if let Some(cleared) = recovery.check(&recovery_latch, healthy) {
+                            for reason in cleared {
+                                Metrics::hard_stop_auto_recovered(reason.as_str());
+                            }
+                        }
+                    }
+                });
+            }
+
             info!("Trading mode initialized with ExecutorLoop, PositionTracker, TimeStopMonitor, MarkRegressionMonitor, RiskMonitor, and HardStop Flatten");
 
             // 16. Dashboard server (if enabled)
             if self.config.dashboard.enabled {
-                let dashboard_state = DashboardState::new(
+                let mut dashboard_state = DashboardState::new(
                     self.market_state.clone(),
                     position_tracker.clone(),
                     hard_stop_latch.clone(),
                     self.recent_signals.clone(),
                 );
+                if let Some(ref slicer) = order_slicer {
+                    dashboard_state = dashboard_state.with_order_slicer(slicer.clone());
+                }
+                dashboard_state = dashboard_state.with_key_manager(key_manager.clone());
+                dashboard_state = dashboard_state.with_entry_pause(entry_pause.clone());
+                dashboard_state =
+                    dashboard_state.with_risk_monitor_settings(risk_monitor_settings.clone());
+                if let Some(ref gate) = net_exposure_gate {
+                    dashboard_state = dashboard_state.with_net_exposure(gate.clone());
+                }
+                if let Some(ref gate) = self.max_drawdown_gate {
+                    dashboard_state = dashboard_state.with_drawdown_gate(gate.clone());
+                }
+                if let Some(ref breaker) = reject_breaker {
+                    dashboard_state = dashboard_state.with_reject_breaker(breaker.clone());
+                }
                 // Store signal sender for real-time signal push
                 self.dashboard_signal_tx = Some(dashboard_state.signal_sender());
                 // P3-4: Store dashboard state for trade reporting
@@ -1591,6 +4067,9 @@ impl Application {
         for market in self.config.get_markets() {
             parser.add_coin_mapping(market.coin.clone(), market.asset_idx);
         }
+        for coin in self.lead_lag_reference_only_coins() {
+            parser.add_reference_coin(&coin);
+        }
         info!(
             dex_id = %self.get_dex_id(),
             coin_mappings = ?self.config.get_markets().iter().map(|m| (&m.coin, m.asset_idx)).collect::<Vec<_>>(),
@@ -1616,6 +4095,49 @@ impl Application {
             None
         };
 
+        // Periodic account equity poll for the equity drawdown gate
+        let mut equity_interval = self.equity_drawdown_gate.as_ref().map(|gate| {
+            tokio::time::interval(Duration::from_secs(gate.config().poll_interval_secs.max(1)))
+        });
+
+        // Periodic warm-state snapshot (first flush after one full period, so a
+        // crash loop can't keep refreshing the age of restored state)
+        let mut warm_state_interval = self.warm_state_store.as_ref().map(|_| {
+            let period = Duration::from_secs(self.config.warm_state.flush_interval_secs.max(1));
+            tokio::time::interval_at(tokio::time::Instant::now() + period, period)
+        });
+
+        // Periodic position state snapshot
+        let mut position_state_interval = self.position_state_store.as_ref().map(|_| {
+            tokio::time::interval(Duration::from_secs(
+                self.config.position_state.flush_interval_secs.max(1),
+            ))
+        });
+
+        // Periodic last-nonce snapshot
+        let mut nonce_interval = self.nonce_store.as_ref().map(|_| {
+            tokio::time::interval(Duration::from_millis(
+                self.config.nonce.flush_interval_ms.max(100),
+            ))
+        });
+
+        // READY transitions for inflight reconciliation after a reconnect
+        let mut reconcile_interval = (self.config.executor.reconcile_on_reconnect
+            && self.config.mode == OperatingMode::Trading)
+            .then(|| tokio::time::interval(RECONCILE_CHECK_INTERVAL));
+        let mut was_ready = true;
+
+        // Periodic MM quote reconciliation against the exchange's open orders
+        let mut mm_reconcile_interval = (self.quote_manager.is_some()
+            && self.config.maker.reconcile_interval_secs > 0
+            && self.config.mode == OperatingMode::Trading)
+            .then(|| {
+                let period = Duration::from_secs(self.config.maker.reconcile_interval_secs);
+                tokio::time::interval_at(tokio::time::Instant::now() + period, period)
+            });
+        let (mm_open_orders_tx, mut mm_open_orders_rx) = mpsc::channel::<Option<Vec<OpenOrder>>>(1);
+        let mut mm_open_orders_inflight = false;
+
         loop {
             tokio::select! {
                 // Handle incoming WebSocket messages
@@ -1623,6 +4145,11 @@ impl Application {
                     if let Err(e) = self.handle_message(&parser, msg).await {
                         warn!(?e, "Message handling error");
                     }
+                    self.record_slippage_rejections();
+                    self.record_stale_signal_drops();
+                    self.record_signed_actions();
+                    self.record_observed_blocks();
+                    self.record_risk_events();
 
                     // Check for dislocations on each update
                     if let Some(signals) = self.check_dislocations().await {
@@ -1660,11 +4187,37 @@ impl Application {
                                 self.schedule_followups(&signal);
                             }
 
+                            // Extensions: advisory gate votes
+                            if !self.extensions.is_empty() {
+                                let outcome = self.publish_extension_event(
+                                    &DomainEvent::Signal(Box::new(signal.clone())),
+                                );
+                                if let Some((extension, reason)) = outcome.blocked_by {
+                                    info!(
+                                        signal_id = %signal.signal_id,
+                                        market = %signal.market_key,
+                                        extension = %extension,
+                                        reason = %reason,
+                                        "Signal dropped: blocked by extension"
+                                    );
+                                    continue;
+                                }
+                            }
+
                             // Phase B: Execute signal
-                            if self.config.mode == OperatingMode::Trading {
+                            if self.config.executes_orders() {
                                 // Gate: Check WS READY-TRADING before processing signal
+                                // (Paper and shadow modes have no orderUpdates subscription: READY-MD)
                                 if let Some(ref cm) = self.connection_manager {
-                                    if !cm.is_ready() {
+                                    let ready = if matches!(
+                                        self.config.mode,
+                                        OperatingMode::Paper | OperatingMode::Shadow
+                                    ) {
+                                        cm.is_md_ready()
+                                    } else {
+                                        cm.is_ready()
+                                    };
+                                    if !ready {
                                         warn!(
                                             market = %signal.market_key,
                                             "Signal dropped: not ready for trading"
@@ -1694,14 +4247,53 @@ impl Application {
 
                                 // Execute signal via Executor
                                 if let Some(ref executor_loop) = self.executor_loop {
+                                    // Signal TTL: lead-lag edge is priced off the reference
+                                    // market, so only the TTL applies to it
+                                    let min_edge_bps = (signal.source
+                                        == SignalSource::Dislocation)
+                                        .then(|| signal.raw_edge_bps - signal.net_edge_bps);
+                                    let validity =
+                                        signal.expires_at_ms().map(|expires_at_ms| SignalValidity {
+                                            expires_at_ms,
+                                            min_edge_bps,
+                                        });
+                                    let now_ms = current_time_ms();
                                     let result = executor_loop.executor().on_signal(
                                         &signal.market_key,
                                         signal.side,
                                         signal.best_px,
                                         rounded_size, // Use rounded size instead of suggested_size
-                                        current_time_ms(),
+                                        now_ms,
                                         signal.raw_edge_bps,
+                                        signal.net_edge_bps,
+                                        signal.confidence_score,
+                                        signal.size_multiplier,
+                                        validity,
                                     );
+                                    if let (
+                                        Some(ref log),
+                                        ExecutionResult::Rejected { reason },
+                                    ) = (&self.risk_event_log, &result)
+                                    {
+                                        log.gate_block(
+                                            &format!("{reason:?}"),
+                                            Some(signal.market_key),
+                                            "signal",
+                                        );
+                                    }
+                                    if result
+                                        == ExecutionResult::skipped(SkipReason::SignalExpired)
+                                    {
+                                        let reason = if signal.is_expired(now_ms) {
+                                            "ttl"
+                                        } else {
+                                            "edge_gone"
+                                        };
+                                        Metrics::signal_expired(
+                                            &signal.market_key.to_string(),
+                                            reason,
+                                        );
+                                    }
 
                                     // P1-4: Record signal-to-order latency
                                     let latency_ms = (chrono::Utc::now() - signal.detected_at)
@@ -1710,6 +4302,38 @@ impl Application {
                                         &signal.market_key.to_string(),
                                         latency_ms,
                                     );
+                                    self.record_latency(
+                                        hip3_risk::LatencyKind::SignalToOrder,
+                                        latency_ms,
+                                        now_ms,
+                                    );
+
+                                    // TCA: remember which signal the entry order came from
+                                    if let (
+                                        Some(ref mut tca),
+                                        ExecutionResult::Queued { cloid }
+                                        | ExecutionResult::QueuedDegraded { cloid },
+                                    ) = (&mut self.tca_tracker, &result)
+                                    {
+                                        tca.on_order_queued(cloid.clone(), &signal, now_ms as i64);
+                                    }
+
+                                    // Slippage guard: remember the signal for submit-time rejections
+                                    if let (
+                                        true,
+                                        ExecutionResult::Queued { cloid }
+                                        | ExecutionResult::QueuedDegraded { cloid },
+                                    ) = (self.config.slippage_guard.enabled, &result)
+                                    {
+                                        self.queued_signals.retain(|_, (_, queued_at)| {
+                                            now_ms.saturating_sub(*queued_at)
+                                                < QUEUED_SIGNAL_RETENTION_MS
+                                        });
+                                        self.queued_signals.insert(
+                                            cloid.clone(),
+                                            (signal.signal_id.clone(), now_ms),
+                                        );
+                                    }
 
                                     // P2-5: Cache entry edge for dynamic exit thresholds
                                     // Sprint 4 P2-F: Cache exit profile
@@ -1722,6 +4346,12 @@ impl Application {
                                             signal.market_key,
                                             signal.exit_profile,
                                         );
+                                        if self.config.slippage.enabled {
+                                            self.last_signal_best_px.write().insert(
+                                                signal.market_key,
+                                                (signal.side, signal.best_px),
+                                            );
+                                        }
                                     }
 
                                     info!(
@@ -1742,10 +4372,15 @@ impl Application {
                 // P0-31: Periodic daily stats output
                 _ = stats_interval.tick() => {
                     info!("Outputting periodic statistics summary");
+                    self.sync_mm_pnl_stats();
                     if let Some(ref stats) = self.daily_stats {
                         stats.output_daily_summary();
                     }
                     self.last_stats_output = Instant::now();
+                    // Daily latches end at the UTC day boundary without a fill
+                    self.report_loss_limit_metrics();
+                    // Picks up a weekly latch cleared via the admin endpoint
+                    self.save_loss_limits();
                 }
 
                 // P1: Periodic position resync (safety net, Trading mode only)
@@ -1759,7 +4394,9 @@ impl Application {
                         if let (Some(ref tracker), Some(ref user_addr)) =
                             (&self.position_tracker, &trading_user_address)
                         {
-                            match self.sync_positions_from_api(tracker, user_addr).await {
+                            match self
+                                .sync_positions_from_api(tracker, user_addr, true, None)
+                                .await {
                                 Ok(()) => {
                                     debug!("Periodic position resync completed");
                                 }
@@ -1771,6 +4408,104 @@ impl Application {
                     }
                 }
 
+                // Account equity poll (Trading mode only)
+                Some(_) = async {
+                    match &mut equity_interval {
+                        Some(interval) => Some(interval.tick().await),
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    if let Some(ref user_addr) = trading_user_address {
+                        self.poll_account_equity(user_addr).await;
+                    }
+                }
+
+                // Inflight reconciliation on the READY transition
+                Some(_) = async {
+                    match &mut reconcile_interval {
+                        Some(interval) => Some(interval.tick().await),
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    let ready = self
+                        .connection_manager
+                        .as_ref()
+                        .is_some_and(|cm| cm.is_ready());
+                    if ready && !was_ready {
+                        if let Some(ref user_addr) = trading_user_address {
+                            self.reconcile_inflight(user_addr).await;
+                        }
+                    }
+                    was_ready = ready;
+                }
+
+                // MM quote reconciliation
+                Some(_) = async {
+                    match &mut mm_reconcile_interval {
+                        Some(interval) => Some(interval.tick().await),
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    // Skip the tick while the previous listing is still in flight
+                    if let Some(user_addr) =
+                        trading_user_address.as_ref().filter(|_| !mm_open_orders_inflight)
+                    {
+                        mm_open_orders_inflight = true;
+                        self.spawn_mm_open_orders_fetch(user_addr, mm_open_orders_tx.clone());
+                    }
+                }
+
+                // MM reconciliation listing fetched in the background
+                Some(open_orders) = mm_open_orders_rx.recv() => {
+                    mm_open_orders_inflight = false;
+                    if let Some(open_orders) = open_orders {
+                        self.reconcile_mm_orders(&open_orders);
+                    }
+                }
+
+                // Periodic warm-state snapshot
+                Some(_) = async {
+                    match &mut warm_state_interval {
+                        Some(interval) => Some(interval.tick().await),
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.save_warm_state();
+                }
+
+                // Periodic position state snapshot
+                Some(_) = async {
+                    match &mut position_state_interval {
+                        Some(interval) => Some(interval.tick().await),
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.save_position_state();
+                }
+
+                // Periodic last-nonce snapshot
+                Some(_) = async {
+                    match &mut nonce_interval {
+                        Some(interval) => Some(interval.tick().await),
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.save_nonce();
+                }
+
+                // User fee tier refresh
+                Some(fees) = async {
+                    match &mut fee_rx {
+                        Some(rx) => match rx.changed().await {
+                            Ok(()) => rx.borrow_and_update().clone(),
+                            Err(_) => std::future::pending().await,
+                        },
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.apply_user_fees(fees);
+                }
+
                 // Handle shutdown signal
                 _ = tokio::signal::ctrl_c() => {
                     info!("Shutdown signal received");
@@ -1784,14 +4519,59 @@ impl Application {
 
         // P0-31: Output final statistics
         info!("Final statistics summary:");
+        self.sync_mm_pnl_stats();
         if let Some(ref stats) = self.daily_stats {
             stats.output_daily_summary();
         }
 
+        self.save_warm_state();
+        self.save_position_state();
+        self.save_nonce();
+        self.save_loss_limits();
+        self.record_signed_actions();
+        self.record_observed_blocks();
+        self.record_risk_events();
+
         // BUG-001 fix: Call close() instead of flush() to ensure Parquet footer is written.
         // flush() only writes row groups, close() finalizes the file with proper footer.
         self.writer.close()?;
 
+        if let Some(ref mut feed_writer) = self.feed_writer {
+            if let Err(e) = feed_writer.close() {
+                warn!(?e, "Failed to close feed writer");
+            }
+        }
+
+        if let Some(ref mut tca_writer) = self.tca_writer {
+            if let Err(e) = tca_writer.close() {
+                warn!(?e, "Failed to close TCA writer");
+            }
+        }
+
+        if let Some(ref mut trade_ledger) = self.trade_ledger {
+            if let Err(e) = trade_ledger.close() {
+                warn!(?e, "Failed to close trade ledger writer");
+            }
+        }
+
+        if let Some(ref mut position_journal) = self.position_journal {
+            if let Err(e) = position_journal.close() {
+                warn!(?e, "Failed to close position journal writer");
+            }
+        }
+
+        if let Some(ref mut risk_event_writer) = self.risk_event_writer {
+            if let Err(e) = risk_event_writer.close() {
+                warn!(?e, "Failed to close risk event writer");
+            }
+        }
+
+        if let Some(ref mut signal_reject_writer) = self.signal_reject_writer {
+            if let Err(e) = signal_reject_writer.close() {
+                warn!(?e, "Failed to close signal reject writer");
+            }
+        }
+
         // Close followup writer
         {
             let mut writer = self.followup_writer.lock().await;
@@ -1851,6 +4631,20 @@ impl Application {
                 // Handle post responses (Trading mode)
                 if channel == "post" {
                     if let Some(resp) = msg.as_post_response() {
+                        if let Some(sent_at) = self
+                            .executor_loop
+                            .as_ref()
+                            .and_then(|executor_loop| executor_loop.post_sent_at(resp.id))
+                        {
+                            let now_ms = current_time_ms();
+                            let round_trip_ms = now_ms.saturating_sub(sent_at) as f64;
+                            Metrics::ws_post_round_trip(round_trip_ms);
+                            self.record_latency(
+                                hip3_risk::LatencyKind::WsRoundTrip,
+                                round_trip_ms,
+                                now_ms,
+                            );
+                        }
                         if let Some(ref executor_loop) = self.executor_loop {
                             match resp.response {
                                 PostResponseBody::Action { ref payload } => {
@@ -1909,37 +4703,122 @@ impl Application {
                     return Ok(());
                 }
 
-                // Handle userFills (Trading mode)
-                if channel == "userFills" {
-                    if let Some(user_fills) = msg.as_user_fills() {
-                        if user_fills.is_snapshot {
-                            // IMPORTANT: Skip processing snapshot fills for position tracking.
-                            // Snapshot contains historical fills that would incorrectly rebuild
-                            // positions that were already closed. The correct position state
-                            // comes from sync_positions_from_api() (clearinghouseState API).
-                            info!(
-                                fills_count = user_fills.fills.len(),
-                                "Received userFills snapshot (skipping for position tracking)"
-                            );
-                        } else {
-                            // Process only streaming updates (non-snapshot)
-                            for fill in &user_fills.fills {
-                                self.handle_user_fill(fill);
-                            }
-                            if user_fills.fills.is_empty() {
-                                debug!("userFills update with empty fills array");
+                // Handle userFills (Trading mode)
+                if channel == "userFills" {
+                    if let Some(user_fills) = msg.as_user_fills() {
+                        if user_fills.is_snapshot {
+                            // IMPORTANT: Skip processing snapshot fills for position tracking.
+                            // Snapshot contains historical fills that would incorrectly rebuild
+                            // positions that were already closed. The correct position state
+                            // comes from sync_positions_from_api() (clearinghouseState API).
+                            info!(
+                                fills_count = user_fills.fills.len(),
+                                "Received userFills snapshot (skipping for position tracking)"
+                            );
+                        } else {
+                            // Process only streaming updates (non-snapshot)
+                            for fill in &user_fills.fills {
+                                self.handle_user_fill(fill, &user_fills.user);
+                            }
+                            if user_fills.fills.is_empty() {
+                                debug!("userFills update with empty fills array");
+                            }
+                        }
+                    } else {
+                        // Failed to parse userFills - log the raw data for debugging
+                        warn!(
+                            raw_data = ?channel_msg.data,
+                            "Failed to parse userFills message"
+                        );
+                    }
+                    return Ok(());
+                }
+
+                // Handle userFundings (Trading mode)
+                if channel == "userFundings" {
+                    if let Some(user_fundings) = msg.as_user_fundings() {
+                        // Snapshot is funding history, already in the API's cumFunding
+                        if user_fundings.is_snapshot {
+                            debug!(
+                                fundings_count = user_fundings.fundings.len(),
+                                "Received userFundings snapshot (skipping)"
+                            );
+                        } else {
+                            for funding in &user_fundings.fundings {
+                                self.handle_user_funding(funding);
+                            }
+                        }
+                    } else {
+                        warn!(
+                            raw_data = ?channel_msg.data,
+                            "Failed to parse userFundings message"
+                        );
+                    }
+                    return Ok(());
+                }
+
+                // Handle userEvents (Trading mode, websocket.subscribe_user_events)
+                if channel == "user" {
+                    match msg.as_user_event() {
+                        Some(UserEventPayload::Liquidation(liquidation)) => {
+                            self.handle_liquidation(&liquidation);
+                        }
+                        Some(UserEventPayload::Funding(funding)) => {
+                            self.handle_user_funding(&funding);
+                        }
+                        Some(UserEventPayload::NonUserCancel(cancels)) => {
+                            // Order state follows from orderUpdates
+                            for cancel in &cancels {
+                                warn!(coin = %cancel.coin, oid = cancel.oid, "Order cancelled by exchange");
+                            }
+                        }
+                        // Tracked via userFills
+                        Some(UserEventPayload::Fills(_)) => {}
+                        None => {
+                            warn!(
+                                raw_data = ?channel_msg.data,
+                                "Failed to parse userEvents message"
+                            );
+                        }
+                    }
+                    return Ok(());
+                }
+
+                // Handle deposits, withdrawals and transfers
+                if channel == "userNonFundingLedgerUpdates" {
+                    if let Some(ledger) = msg.as_ledger_updates() {
+                        // Snapshot is history, already in the synced balance
+                        if !ledger.is_snapshot {
+                            for update in &ledger.updates {
+                                self.handle_ledger_update(&ledger.user, update);
                             }
                         }
                     } else {
-                        // Failed to parse userFills - log the raw data for debugging
                         warn!(
                             raw_data = ?channel_msg.data,
-                            "Failed to parse userFills message"
+                            "Failed to parse userNonFundingLedgerUpdates message"
                         );
                     }
                     return Ok(());
                 }
 
+                // Record market data for offline replay
+                if let Some(ref mut feed_writer) = self.feed_writer {
+                    if matches!(
+                        channel.as_str(),
+                        "bbo" | "activeAssetCtx" | "l2Book" | "trades"
+                    ) {
+                        let record = FeedRecord {
+                            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+                            channel: channel.clone(),
+                            data: channel_msg.data.clone(),
+                        };
+                        if let Err(e) = feed_writer.add_record(record) {
+                            warn!(?e, "Failed to record feed message");
+                        }
+                    }
+                }
+
                 // Parse and update market state (bbo, activeAssetCtx, etc.)
                 if let Some(event) = parser
                     .parse_channel_message(channel, &channel_msg.data)
@@ -2038,6 +4917,15 @@ impl Application {
 
         let state = Self::map_order_status(status);
 
+        if !self.extensions.is_empty() {
+            self.publish_extension_event(&DomainEvent::OrderUpdate {
+                market: self.coin_to_market(coin),
+                cloid: cloid.clone(),
+                oid,
+                state,
+            });
+        }
+
         // Send Rejected event to RiskMonitor
         if state == OrderState::Rejected {
             if let Some(ref event_tx) = self.risk_event_tx {
@@ -2066,6 +4954,9 @@ impl Application {
                 if let Some(ref oracle_exit) = self.oracle_exit_watcher {
                     oracle_exit.clear_flattening(&market);
                 }
+                if let Some(ref exit_strategies) = self.exit_strategy_watcher {
+                    exit_strategies.clear_flattening(&market);
+                }
                 debug!(
                     market = %market,
                     state = ?state,
@@ -2075,6 +4966,16 @@ impl Application {
             }
         }
 
+        // OCO: record the take-profit oid, cancelling it if the stop already fired
+        if let Some(market) = self.coin_to_market(coin) {
+            let cancel = self.oco_book.as_mut().and_then(|book| {
+                book.on_order_update(market, &cloid, oid, state, current_time_ms())
+            });
+            if let Some(cancel) = cancel {
+                self.enqueue_oco_cancel(cancel);
+            }
+        }
+
         // MM: Notify quote_manager on resting/cancelled
         if self.quote_manager.is_some() {
             let mm_market = self.coin_to_market(coin);
@@ -2099,8 +5000,133 @@ impl Application {
         });
     }
 
+    /// Handle a userFundings payment: accrue it on the open position.
+    fn handle_user_funding(&mut self, funding: &FundingPayload) {
+        let Some(ref tracker) = self.position_tracker else {
+            return;
+        };
+        let Some(market) = self.coin_to_market(&funding.coin) else {
+            debug!(coin = %funding.coin, "Funding for unknown coin");
+            return;
+        };
+        let Ok(usdc) = funding.usdc.parse::<Decimal>() else {
+            warn!(coin = %funding.coin, usdc = %funding.usdc, "Invalid funding amount");
+            return;
+        };
+        // userFundings reports the USDC delta; the tracker accrues funding paid
+        let paid_usd = -usdc;
+        info!(
+            %market,
+            paid_usd = %paid_usd,
+            funding_rate = %funding.funding_rate,
+            "Funding payment"
+        );
+
+        let tracker = tracker.clone();
+        let timestamp_ms = funding.time;
+        tokio::spawn(async move {
+            tracker.funding(market, paid_usd, timestamp_ms).await;
+        });
+    }
+
+    /// Handle a liquidation of the account: full hard stop via RiskMonitor.
+    fn handle_liquidation(&self, liquidation: &LiquidationPayload) {
+        // userEvents also reports liquidations the account only took part in
+        if !self.is_trading_account(&liquidation.liquidated_user) {
+            info!(
+                lid = liquidation.lid,
+                liquidated_user = %liquidation.liquidated_user,
+                "Liquidation of another account, ignoring"
+            );
+            return;
+        }
+        error!(
+            lid = liquidation.lid,
+            liquidated_user = %liquidation.liquidated_user,
+            notional = %liquidation.liquidated_ntl_pos,
+            account_value = %liquidation.liquidated_account_value,
+            "Account liquidated"
+        );
+        let Some(ref event_tx) = self.risk_event_tx else {
+            return;
+        };
+        let event = ExecutionEvent::Liquidation {
+            notional: liquidation.liquidated_ntl_pos.parse().unwrap_or_default(),
+            account_value: liquidation
+                .liquidated_account_value
+                .parse()
+                .unwrap_or_default(),
+        };
+        let tx = event_tx.clone();
+        tokio::spawn(async move {
+            if tx.send(event).await.is_err() {
+                warn!("Failed to send Liquidation event to RiskMonitor");
+            }
+        });
+    }
+
+    /// Handle a deposit, withdrawal or transfer of `user`: shift the equity
+    /// gate's high-water mark, and the cached balance used for position
+    /// sizing until the next sync if `user` is the taker account.
+    ///
+    /// Transfers between two trading accounts arrive on both and cancel out.
+    fn handle_ledger_update(&self, user: &str, update: &LedgerUpdatePayload) {
+        if !self.is_trading_account(user) {
+            debug!(user = %user, hash = %update.hash, "Ledger update of another account, ignoring");
+            return;
+        }
+        let (usdc, incoming) = match &update.delta {
+            LedgerDelta::Deposit { usdc } => (usdc, true),
+            LedgerDelta::Withdraw { usdc } => (usdc, false),
+            LedgerDelta::InternalTransfer {
+                usdc, destination, ..
+            }
+            | LedgerDelta::SubAccountTransfer {
+                usdc, destination, ..
+            } => (usdc, destination.eq_ignore_ascii_case(user)),
+            LedgerDelta::AccountClassTransfer { usdc, to_perp } => (usdc, *to_perp),
+            LedgerDelta::Other => return,
+        };
+        let Ok(amount) = usdc.parse::<Decimal>() else {
+            warn!(hash = %update.hash, usdc = %usdc, "Invalid ledger update amount");
+            return;
+        };
+        let delta = if incoming { amount } else { -amount };
+        info!(
+            user = %user,
+            hash = %update.hash,
+            delta_usd = %delta,
+            "Account balance transfer"
+        );
+
+        if let Some(ref gate) = self.equity_drawdown_gate {
+            gate.apply_transfer(delta);
+        }
+        let user_address = self.config.user_address.as_deref().unwrap_or_default();
+        let taker_account = self
+            .vault_router
+            .account(OrderStrategy::Taker, user_address);
+        if !taker_account.eq_ignore_ascii_case(user) {
+            return;
+        }
+        if let Some(ref tracker) = self.position_tracker {
+            let balance = (tracker.get_balance() + delta).max(Decimal::ZERO);
+            tracker.update_balance(balance);
+        }
+    }
+
+    /// Whether `account` is one of the accounts the bot trades on.
+    fn is_trading_account(&self, account: &str) -> bool {
+        let user_address = self.config.user_address.as_deref().unwrap_or_default();
+        !user_address.is_empty()
+            && self
+                .trading_accounts(user_address)
+                .iter()
+                .any(|(address, _)| address.eq_ignore_ascii_case(account))
+    }
+
     /// Handle userFills message.
-    fn handle_user_fill(&mut self, fill: &FillPayload) {
+    fn handle_user_fill(&mut self, fill: &FillPayload, account: &str) {
         let coin = &fill.coin;
         let side_str = &fill.side;
         let px = &fill.px;
@@ -2116,7 +5142,29 @@ impl Application {
             "User fill received"
         );
 
-        let Some(ref tracker) = self.position_tracker else {
+        // L1 hedge fills are booked with the hedger, never as xyz positions
+        let hedge = self
+            .hedger
+            .as_ref()
+            .and_then(|hedger| Some((hedger, hedger.hedge_market(coin)?)));
+        if let Some((hedger, hedge_market)) = hedge {
+            let side = match side_str.as_str() {
+                "B" => OrderSide::Buy,
+                _ => OrderSide::Sell,
+            };
+            let cloid = fill.cloid.as_ref().map(|s| ClientOrderId::from(s.clone()));
+            hedger.on_fill(
+                hedge_market,
+                cloid.as_ref(),
+                side,
+                px.parse().map(Price::new).unwrap_or(Price::ZERO),
+                sz.parse().map(Size::new).unwrap_or(Size::ZERO),
+                fill.fee.parse().ok(),
+            );
+            return;
+        }
+
+        let Some(tracker) = self.position_tracker.clone() else {
             debug!("Fill ignored: no position tracker");
             return;
         };
@@ -2145,6 +5193,27 @@ impl Application {
         // Extract cloid from FillPayload for deduplication
         let cloid = fill.cloid.as_ref().map(|s| ClientOrderId::from(s.clone()));
 
+        if let Some(ref monitor) = self.liquidation_monitor {
+            self.report_derisk(monitor.on_fill(account, market, side, price.inner(), size.inner()));
+        }
+
+        // A dedicated maker account's fills are booked to the MM inventory only
+        if self.is_maker_account(account) {
+            self.record_mm_fill(market, side, price, size, time, cloid.as_ref(), true);
+            return;
+        }
+
+        if !self.extensions.is_empty() {
+            self.publish_extension_event(&DomainEvent::Fill {
+                market,
+                side,
+                price,
+                size,
+                timestamp_ms: time,
+                cloid: cloid.clone(),
+            });
+        }
+
         // Record oracle baseline BEFORE creating position
         // This prevents false exits when market already had consecutive moves
         // before our position was opened.
@@ -2162,7 +5231,12 @@ impl Application {
         //   - Exit triggers when delta >= threshold (correct!)
         // Phase C: Capture entry edge before position is created
         let is_new_position = !tracker.has_position(&market);
-        let entry_edge_for_position = if is_new_position {
+        // Add-ons (same-side fills with scaling enabled) carry their signal's edge too
+        let is_add_on = self.config.position.scaling.enabled
+            && tracker
+                .get_position(&market)
+                .is_some_and(|p| p.side == side);
+        let entry_edge_for_position = if is_new_position || is_add_on {
             self.last_signal_edge.read().get(&market).copied()
         } else {
             None
@@ -2178,6 +5252,7 @@ impl Application {
                 .write()
                 .remove(&market)
                 .unwrap_or(ExitProfile::Standard);
+            self.position_profiles.write().insert(market, exit_profile);
             // P3-2: Pass current oracle price for trailing stop tracking
             let entry_oracle = self
                 .market_state
@@ -2192,6 +5267,12 @@ impl Application {
                     exit_profile,
                 );
             }
+            if let Some(ref exit_watcher) = self.exit_watcher {
+                exit_watcher.on_position_opened(market, exit_profile);
+            }
+            if let Some(ref exit_strategies) = self.exit_strategy_watcher {
+                exit_strategies.on_position_opened(market, exit_profile);
+            }
         }
 
         // P2-4: Determine if this fill is from an MM quote (before record_fill removes it)
@@ -2200,48 +5281,187 @@ impl Application {
             _ => false,
         };
 
+        // Learned slippage: first taker fill on the signal's side vs its best_px
+        if !is_mm_fill {
+            let expected = {
+                let mut cache = self.last_signal_best_px.write();
+                match cache.get(&market) {
+                    Some(&(signal_side, best_px)) if signal_side == side => {
+                        cache.remove(&market);
+                        Some(best_px)
+                    }
+                    _ => None,
+                }
+            };
+            if let Some(best_px) = expected {
+                if let Some(slippage_bps) = self
+                    .detector
+                    .record_fill_slippage(market, side, best_px, price)
+                {
+                    let estimate_bps = self.detector.slippage_bps(&market);
+                    debug!(
+                        %market,
+                        %slippage_bps,
+                        %estimate_bps,
+                        "Entry fill slippage recorded"
+                    );
+                    Metrics::slippage_estimate(
+                        &market.to_string(),
+                        estimate_bps.to_string().parse().unwrap_or(0.0),
+                    );
+                }
+            }
+        }
+
+        let fee_usd: Decimal = fill.fee.parse().unwrap_or(Decimal::ZERO);
+
+        // TCA: join entry fills back to their signal
+        if let (Some(ref mut tca), Some(ref c)) = (&mut self.tca_tracker, &cloid) {
+            if let Some(record) = tca.on_fill(c, price, size, fee_usd, time as i64) {
+                debug!(
+                    signal_id = %record.signal_id,
+                    slippage_bps = record.slippage_bps,
+                    fee_bps = record.fee_bps,
+                    edge_capture_ratio = record.edge_capture_ratio,
+                    signal_to_fill_ms = record.signal_to_fill_ms,
+                    "TCA record"
+                );
+                Metrics::tca_fill(
+                    &record.market_key,
+                    record.slippage_bps,
+                    record.signal_to_fill_ms as f64,
+                    record.fee_usd,
+                );
+                if let Some(summary) = tca.summary(&market) {
+                    Metrics::tca_edge_capture_ratio(
+                        &record.market_key,
+                        summary.mean_edge_capture_ratio,
+                    );
+                }
+                if let Some(ref mut writer) = self.tca_writer {
+                    if let Err(e) = writer.add_record(record) {
+                        warn!(?e, "Failed to write TCA record");
+                    }
+                }
+            }
+        }
+
+        let entry_source = if is_mm_fill {
+            EntrySource::Mm
+        } else {
+            EntrySource::Taker
+        };
+        let position_before = tracker.get_position(&market);
+        let mut closed_trade = None;
+
         // P2-3/P2-4: Report PnL and close events when a position is being closed
         // (fill side opposite to position side = reduce-only direction)
         // P2-4: Skip taker-specific reporting for MM fills
-        if let Some(existing_pos) = tracker.get_position(&market) {
+        if let Some(existing_pos) = position_before.clone() {
             let is_closing = existing_pos.side != side;
 
             // Release shared flattening guard on any position close (taker or MM)
-            if is_closing {
-                if let Some(ref guard) = self.shared_flattening_guard {
-                    guard.release(&market);
+            // and attribute the closing fill to its entry source and exit reason
+            let exit_reason = if is_closing {
+                let is_oco_take_profit = match (&self.oco_book, &cloid) {
+                    (Some(book), Some(c)) => book.is_take_profit(&market, c),
+                    _ => false,
+                };
+                let claimed_reason = self
+                    .shared_flattening_guard
+                    .as_ref()
+                    .and_then(|guard| guard.release(&market))
+                    .or_else(|| is_oco_take_profit.then(|| OcoBook::TAKE_PROFIT_NAME.to_string()));
+                let trade = self.trade_attributor.on_close(
+                    &existing_pos,
+                    ClosingFill {
+                        price,
+                        size,
+                        fee_usd,
+                        cloid: cloid.as_ref().map(|c| c.to_string()),
+                        claimed_reason,
+                        is_mm_fill,
+                        timestamp_ms: time,
+                    },
+                );
+                info!(
+                    %market,
+                    entry_source = %trade.entry_source,
+                    exit_reason = %trade.exit_reason,
+                    gross_pnl_usd = trade.gross_pnl_usd,
+                    fees_usd = trade.fees_usd,
+                    net_pnl_usd = trade.net_pnl_usd,
+                    mae_bps = trade.mae_bps,
+                    "Trade closed"
+                );
+                if let Some(ref mut stats) = self.daily_stats {
+                    stats.record_trade(
+                        &trade.entry_source,
+                        &trade.exit_reason,
+                        trade.gross_pnl_usd,
+                        trade.fees_usd,
+                        trade.funding_usd,
+                    );
                 }
-            }
+                let exit_reason = trade.exit_reason.clone();
+                if let Some(ref mut ledger) = self.trade_ledger {
+                    if let Err(e) = ledger.add_record(trade.clone()) {
+                        warn!(?e, "Failed to write trade record");
+                    }
+                }
+                // Flip: the remainder opens a position on the other side
+                if size.inner() > existing_pos.size.inner() {
+                    self.trade_attributor
+                        .on_entry_fill(market, entry_source, Decimal::ZERO, true);
+                }
+                closed_trade = Some(trade);
+                exit_reason
+            } else {
+                self.trade_attributor
+                    .on_entry_fill(market, entry_source, fee_usd, false);
+                String::new()
+            };
+
+            // Funding paid by the closed share of the position
+            let closed_funding_usd = closed_trade.as_ref().map_or(0.0, |t| t.funding_usd);
 
             if is_closing && !is_mm_fill {
-                // P2-3: Report realized PnL estimate to MaxDrawdownGate
+                // Realized PnL of the close (net of funding), shared by the loss limits
+                let close_pnl_usd = {
+                    use rust_decimal::prelude::FromPrimitive;
+                    let per_unit = match existing_pos.side {
+                        OrderSide::Buy => price.inner() - existing_pos.entry_price.inner(),
+                        OrderSide::Sell => existing_pos.entry_price.inner() - price.inner(),
+                    };
+                    per_unit * size.inner()
+                        - Decimal::from_f64(closed_funding_usd).unwrap_or_default()
+                };
+
+                // P2-3: Report realized PnL to MaxDrawdownGate
                 if let Some(ref gate) = self.max_drawdown_gate {
                     use rust_decimal::prelude::ToPrimitive;
-                    let pnl_bps = match existing_pos.side {
-                        OrderSide::Buy => {
-                            (price.inner() - existing_pos.entry_price.inner())
-                                / existing_pos.entry_price.inner()
-                                * Decimal::from(10000)
-                        }
-                        OrderSide::Sell => {
-                            (existing_pos.entry_price.inner() - price.inner())
-                                / existing_pos.entry_price.inner()
-                                * Decimal::from(10000)
-                        }
-                    };
-                    // Convert bps to USD: pnl_usd = pnl_bps / 10000 * notional
-                    let notional = size.inner() * price.inner();
-                    let pnl_usd = pnl_bps / Decimal::from(10000) * notional;
-                    if let Some(pnl) = pnl_usd.to_f64() {
+                    if let Some(pnl) = close_pnl_usd.to_f64() {
                         gate.report_pnl(pnl);
                         debug!(
                             market = %market,
                             pnl_usd = pnl,
+                            funding_usd = closed_funding_usd,
                             cumulative = gate.cumulative_pnl_usd(),
                             "MaxDrawdownGate: reported PnL"
                         );
                     }
                 }
+                self.report_loss_limit_metrics();
+
+                // Charge the realized PnL to the market's loss budget
+                if let Some(ref gate) = self.risk_budget_gate {
+                    use rust_decimal::prelude::ToPrimitive;
+                    gate.report_pnl(&market, close_pnl_usd);
+                    if let Some(ratio) = gate.loss_used_ratio(&market).to_f64() {
+                        Metrics::risk_budget_loss_used(&market.to_string(), ratio);
+                    }
+                }
+                self.save_loss_limits();
 
                 // P2-4: Report close event to CorrelationCooldownGate
                 if let Some(ref gate) = self.correlation_cooldown_gate {
@@ -2309,10 +5529,10 @@ impl Application {
                         entry_price: existing_pos.entry_price.inner().to_f64().unwrap_or(0.0),
                         exit_price: price.inner().to_f64().unwrap_or(0.0),
                         size: size.inner().to_f64().unwrap_or(0.0),
-                        pnl: pnl_usd.to_f64().unwrap_or(0.0),
+                        pnl: pnl_usd.to_f64().unwrap_or(0.0) - closed_funding_usd,
                         pnl_bps: pnl_bps.to_f64().unwrap_or(0.0),
                         hold_time_ms: hold_time,
-                        exit_reason: String::new(), // Exit reason not available here
+                        exit_reason: exit_reason.clone(),
                         closed_at_ms: now_ms,
                     });
                 }
@@ -2359,6 +5579,34 @@ impl Application {
                     }
                 }
             }
+        } else {
+            self.trade_attributor
+                .on_entry_fill(market, entry_source, fee_usd, true);
+        }
+
+        // Position journal: every fill that changes the position
+        if let Some(ref mut journal) = self.position_journal {
+            let entry_source = match closed_trade {
+                Some(ref trade) => trade.entry_source.clone(),
+                None => self.trade_attributor.entry_source(&market).to_string(),
+            };
+            let record = journal_record(
+                position_before.as_ref(),
+                JournalFill {
+                    market,
+                    side,
+                    price,
+                    size,
+                    cloid: cloid.as_ref().map(|c| c.to_string()),
+                    timestamp_ms: time,
+                    entry_source,
+                    entry_edge_bps: entry_edge_for_position,
+                },
+                closed_trade.as_ref(),
+            );
+            if let Err(e) = journal.add_record(record) {
+                warn!(?e, "Failed to write position journal record");
+            }
         }
 
         // P2-4: Log MM close P&L separately (not sent to taker drawdown gate)
@@ -2387,9 +5635,68 @@ impl Application {
             }
         }
 
+        self.record_mm_fill(market, side, price, size, time, cloid.as_ref(), is_mm_fill);
+
+        let timestamp = time;
+        tokio::spawn(async move {
+            tracker
+                .fill(
+                    market,
+                    side,
+                    price,
+                    size,
+                    timestamp,
+                    cloid,
+                    entry_edge_for_position,
+                )
+                .await;
+        });
+
+        // Clear flattening state for this market on any fill
+        // This ensures that after a position is closed, future exits are not blocked
+        // by stale local_flattening state in ExitWatcher/OracleExitWatcher.
+        if let Some(ref exit_watcher) = self.exit_watcher {
+            exit_watcher.clear_flattening(&market);
+        }
+        if let Some(ref oracle_exit) = self.oracle_exit_watcher {
+            oracle_exit.clear_flattening(&market);
+        }
+        if let Some(ref exit_strategies) = self.exit_strategy_watcher {
+            exit_strategies.clear_flattening(&market);
+        }
+    }
+
+    /// Book a fill with the market maker: markouts (MM fills only), the MM
+    /// inventory and the quote manager's counter-orders.
+    #[allow(clippy::too_many_arguments)]
+    fn record_mm_fill(
+        &mut self,
+        market: MarketKey,
+        side: OrderSide,
+        price: Price,
+        size: Size,
+        time: u64,
+        cloid: Option<&ClientOrderId>,
+        is_mm_fill: bool,
+    ) {
+        // MM: Start markout measurement against the oracle at fill time
+        if is_mm_fill {
+            if let (Some(ref mut markouts), Some(snapshot)) = (
+                &mut self.mm_markouts,
+                self.market_state.get_snapshot(&market),
+            ) {
+                markouts.record_fill(market, side, price, snapshot.ctx.oracle.oracle_px, time);
+            }
+        }
+
         // MM: Update inventory and quote manager on fills
         if let Some(ref mut inv) = self.mm_inventory {
-            inv.record_fill(market, side, price, size);
+            // Edge vs the oracle at fill time is spread capture, the rest inventory PnL
+            let reference = self
+                .market_state
+                .get_snapshot(&market)
+                .map_or(price, |s| s.ctx.oracle.oracle_px);
+            inv.record_fill_at_reference(market, side, price, size, reference, time);
             debug!(
                 %market, ?side, %price, %size,
                 net_size = %inv.net_size(&market),
@@ -2397,45 +5704,231 @@ impl Application {
                 "MM inventory updated"
             );
         }
-        if let (Some(ref mut qm), Some(ref c)) = (&mut self.quote_manager, &cloid) {
-            let counter_action = qm.record_fill(&market, c, price, time);
-            if let Some(action) = counter_action {
-                if let Some(ref executor_loop) = self.executor_loop {
-                    let results = executor_loop.executor().on_mm_quote(vec![action]);
-                    for result in &results {
-                        debug!(result = ?result, "MM counter-order result");
-                    }
-                }
+        if let (Some(ref mut qm), Some(c)) = (&mut self.quote_manager, cloid) {
+            let counter_action = qm.record_fill(&market, c, price, time);
+            if let Some(action) = counter_action {
+                if let Some(ref executor_loop) = self.executor_loop {
+                    let results = executor_loop.executor().on_mm_quote(vec![action]);
+                    for result in &results {
+                        debug!(result = ?result, "MM counter-order result");
+                    }
+                }
+            }
+        }
+    }
+
+    /// Record liquidation distance metrics and refresh the de-risk selection.
+    fn update_liquidation_distance(&self, accounts: &[AccountMargin]) {
+        use rust_decimal::prelude::ToPrimitive;
+
+        for account in accounts {
+            let buffer_ratio = account.buffer_ratio();
+            if let Some(ratio) = buffer_ratio.and_then(|r| r.to_f64()) {
+                Metrics::liquidation_buffer_ratio(&account.account, ratio);
+            }
+            for position in &account.positions {
+                if let Some(distance) = position.distance_bps().and_then(|d| d.to_f64()) {
+                    Metrics::liquidation_distance_bps(&position.market.to_string(), distance);
+                }
+            }
+            debug!(
+                account = %account.account,
+                account_value = %account.account_value,
+                maintenance_margin = %account.maintenance_margin,
+                buffer_ratio = ?buffer_ratio,
+                "Liquidation buffer"
+            );
+        }
+
+        if let Some(ref monitor) = self.liquidation_monitor {
+            self.report_derisk(monitor.evaluate(accounts));
+        }
+    }
+
+    /// Log and count markets newly selected for de-risking.
+    fn report_derisk(&self, markets: Vec<MarketKey>) {
+        for market in markets {
+            warn!(
+                market = %market,
+                min_buffer_ratio = %self.config.liquidation.min_buffer_ratio,
+                min_distance_bps = %self.config.liquidation.min_distance_bps,
+                "Liquidation buffer low, de-risking position"
+            );
+            Metrics::derisk_triggered(&market.to_string());
+        }
+    }
+
+    /// Report drift between local and exchange positions before a resync
+    /// overwrites local state; hard stop on a large drift if configured.
+    ///
+    /// Returns the taker markets whose drift is not confirmed yet.
+    fn reconcile_positions(
+        &self,
+        position_tracker: &PositionTrackerHandle,
+        taker_account: &str,
+        remote: &[(String, Position)],
+    ) -> HashSet<MarketKey> {
+        use rust_decimal::prelude::ToPrimitive;
+
+        let config = &self.config.reconciliation;
+        let raw = reconcile(
+            taker_account,
+            &position_tracker.positions_snapshot(),
+            remote,
+            config,
+        );
+        let mut held: HashSet<MarketKey> = raw
+            .drifts
+            .iter()
+            .filter(|drift| drift.account.eq_ignore_ascii_case(taker_account))
+            .map(|drift| drift.market)
+            .collect();
+        let report = self.drift_confirmer.lock().confirm(raw, config);
+        for drift in &report.drifts {
+            if drift.account.eq_ignore_ascii_case(taker_account) {
+                held.remove(&drift.market);
+            }
+        }
+
+        let drift_notional = report.drift_notional();
+        Metrics::position_drift_notional(drift_notional.to_f64().unwrap_or(0.0));
+        let breached = config.breaches_hard_stop(&report);
+        self.position_drift_breached
+            .store(breached, Ordering::Relaxed);
+        if report.is_clean() {
+            return held;
+        }
+
+        for drift in &report.drifts {
+            warn!(
+                account = %drift.account,
+                market = %drift.market,
+                kind = %drift.kind,
+                local_size = %drift.local_size,
+                remote_size = %drift.remote_size,
+                local_entry = %drift.local_entry,
+                remote_entry = %drift.remote_entry,
+                notional = %drift.notional,
+                "Position drift between local state and exchange"
+            );
+            Metrics::position_drift(&drift.market.to_string(), drift.kind.as_str());
+        }
+
+        if breached {
+            if let Some(ref latch) = self.hard_stop_latch {
+                error!(
+                    drift_notional = %drift_notional,
+                    threshold = %config.hard_stop_drift_notional,
+                    "Position drift exceeds threshold, triggering HardStop"
+                );
+                latch.trigger_with(
+                    HardStopLevel::FullStop,
+                    HardStopReason::PositionDrift,
+                    &format!("Position drift: ${drift_notional}"),
+                );
             }
         }
+        held
+    }
 
-        let tracker = tracker.clone();
-        let timestamp = time;
-        tokio::spawn(async move {
-            tracker
-                .fill(
-                    market,
-                    side,
-                    price,
-                    size,
-                    timestamp,
-                    cloid,
-                    entry_edge_for_position,
-                )
-                .await;
-        });
+    /// Drive the OCO pair of `market` on a BBO update: place its take-profit,
+    /// or cancel it and flatten when the stop fires.
+    fn check_oco_exit(&mut self, market: MarketKey, bbo: &hip3_core::Bbo) {
+        let (Some(book), Some(tracker), Some(executor_loop)) = (
+            self.oco_book.as_mut(),
+            self.position_tracker.as_ref(),
+            self.executor_loop.as_ref(),
+        ) else {
+            return;
+        };
+        let position = tracker.get_position(&market);
+        let flattening = tracker.is_flattening(&market)
+            || self
+                .shared_flattening_guard
+                .as_ref()
+                .is_some_and(|guard| guard.is_claimed(&market));
+        let now_ms = current_time_ms();
+        let Some(action) =
+            book.on_market_update(market, position.as_ref(), bbo, flattening, now_ms)
+        else {
+            return;
+        };
 
-        // Clear flattening state for this market on any fill
-        // This ensures that after a position is closed, future exits are not blocked
-        // by stale local_flattening state in ExitWatcher/OracleExitWatcher.
-        if let Some(ref exit_watcher) = self.exit_watcher {
-            exit_watcher.clear_flattening(&market);
+        let scheduler = executor_loop.executor().batch_scheduler().clone();
+        match action {
+            OcoAction::PlaceTakeProfit(order) => {
+                info!(
+                    %market,
+                    cloid = %order.cloid,
+                    price = %order.price,
+                    size = %order.size,
+                    "OCO take-profit placed"
+                );
+                if scheduler.enqueue_reduce_only(order) == hip3_core::EnqueueResult::QueueFull {
+                    warn!(%market, "OCO take-profit not queued: reduce-only queue full");
+                }
+            }
+            OcoAction::CancelTakeProfit(cancel) => {
+                debug!(%market, oid = cancel.oid, "Cancelling OCO take-profit of closed position");
+                self.enqueue_oco_cancel(cancel);
+            }
+            OcoAction::StopTriggered { request, cancel } => {
+                if let Some(cancel) = cancel {
+                    self.enqueue_oco_cancel(cancel);
+                }
+                if let Some(ref guard) = self.shared_flattening_guard {
+                    if !guard.try_claim(&market, OcoBook::STOP_NAME) {
+                        return;
+                    }
+                }
+                let price = match request.side {
+                    OrderSide::Sell => bbo.bid_price,
+                    OrderSide::Buy => bbo.ask_price,
+                };
+                let order = FlattenOrderBuilder::create_request_order(
+                    &request,
+                    price,
+                    self.config.oco.stop_slippage_bps,
+                    now_ms,
+                );
+                warn!(
+                    %market,
+                    cloid = %order.cloid,
+                    size = %order.size,
+                    price = %price,
+                    "OCO stop triggered, flattening"
+                );
+                scheduler.enqueue_reduce_only(order);
+            }
         }
-        if let Some(ref oracle_exit) = self.oracle_exit_watcher {
-            oracle_exit.clear_flattening(&market);
+    }
+
+    /// Queue the cancel of an OCO take-profit.
+    fn enqueue_oco_cancel(&self, cancel: hip3_core::PendingCancel) {
+        let Some(ref executor_loop) = self.executor_loop else {
+            return;
+        };
+        let oid = cancel.oid;
+        if executor_loop
+            .executor()
+            .batch_scheduler()
+            .enqueue_cancel(cancel)
+            != hip3_core::EnqueueResult::Queued
+        {
+            warn!(oid, "OCO take-profit cancel failed to enqueue");
         }
     }
 
+    /// Whether a market (spec name, "GOLD" or "xyz:GOLD") is in the MM
+    /// market list. An empty list quotes every market.
+    fn is_mm_market(&self, market_name: &str) -> bool {
+        let markets = &self.config.maker.markets;
+        markets.is_empty()
+            || markets
+                .iter()
+                .any(|m| m == market_name || format!("xyz:{m}") == market_name)
+    }
+
     /// Convert coin name to MarketKey.
     fn coin_to_market(&self, coin: &str) -> Option<MarketKey> {
         let dex_id = self.get_dex_id();
@@ -2457,6 +5950,17 @@ impl Application {
                 // P0-31: Record BBO update for null rate calculation
                 Metrics::bbo_update(&key_str);
 
+                // Clock skew vs exchange time; the rest of the offset is feed delay
+                let server_time = bbo.server_time;
+                let delay_ms = server_time.map(|server_time| {
+                    let delay_ms = self.clock_skew.record(server_time, current_time_ms());
+                    Metrics::feed_latency("bbo", delay_ms as f64);
+                    if let Some(skew_ms) = self.clock_skew.skew_ms() {
+                        Metrics::clock_skew(skew_ms as f64);
+                    }
+                    delay_ms
+                });
+
                 // P0-31: Check for null BBO (bid or ask has zero price/size)
                 let is_null = bbo.bid_price.is_zero()
                     || bbo.ask_price.is_zero()
@@ -2472,8 +5976,33 @@ impl Application {
                     Metrics::spread(&key_str, spread_bps.to_string().parse().unwrap_or(0.0));
                 }
 
-                // Phase A: No server_time from WebSocket yet
-                self.market_state.update_bbo(key, bbo, None);
+                // Volatility regime sampling (throttled inside the classifier)
+                if let (Some(ref classifier), Some(mid)) =
+                    (&self.regime_classifier, bbo.mid_price())
+                {
+                    classifier.record(key, mid, current_time_ms());
+                }
+                if let Some(ref history) = self.bbo_history {
+                    history.record(key, bbo.bid_price, bbo.ask_price, current_time_ms());
+                }
+
+                // MAE: worst unrealized PnL of an open position at the exit-side BBO
+                if !is_null {
+                    if let Some(position) = self
+                        .position_tracker
+                        .as_ref()
+                        .filter(|tracker| tracker.has_position(&key))
+                        .and_then(|tracker| tracker.get_position(&key))
+                    {
+                        let exit_px = position.exit_side_price(&bbo);
+                        self.trade_attributor.on_price(&position, exit_px);
+                    }
+                }
+
+                // Delay kept with the BBO for its exchange-time age
+                self.market_state
+                    .update_bbo_with_delay(key, bbo, server_time, delay_ms);
+                self.sync_executor_quote(key);
 
                 // P0-31: Record BBO age to histogram after state update
                 if let Some(bbo_age_ms) = self.market_state.get_bbo_age_ms(&key) {
@@ -2486,6 +6015,16 @@ impl Application {
                         exit_watcher.on_market_update(key, &snapshot);
                     }
                 }
+                if let Some(ref exit_strategies) = self.exit_strategy_watcher {
+                    if let Some(snapshot) = self.market_state.get_snapshot(&key) {
+                        exit_strategies.on_market_update(key, &snapshot);
+                    }
+                }
+                if !is_null {
+                    if let Some(snapshot) = self.market_state.get_snapshot(&key) {
+                        self.check_oco_exit(key, &snapshot.bbo);
+                    }
+                }
             }
             MarketEvent::CtxUpdate { key, ctx } => {
                 let key_str = key.to_string();
@@ -2494,6 +6033,9 @@ impl Application {
                 // Use ctx.received_at as the monotonic timestamp source.
                 let mark_px = ctx.oracle.mark_px;
                 let now_ms = ctx.received_at.timestamp_millis() as u64;
+                if let Some(ref monitor) = self.liquidation_monitor {
+                    self.report_derisk(monitor.on_mark(&key, mark_px.inner()));
+                }
                 if let Some(ref executor_loop) = self.executor_loop {
                     executor_loop
                         .executor()
@@ -2503,6 +6045,7 @@ impl Application {
 
                 // P2-1: Update state first, then record metrics
                 self.market_state.update_ctx(key, ctx.clone());
+                self.sync_executor_quote(key);
 
                 // Record oracle movement for consecutive direction tracking
                 let oracle_px = ctx.oracle.oracle_px;
@@ -2524,6 +6067,11 @@ impl Application {
                         exit_watcher.on_market_update(key, &snapshot);
                     }
                 }
+                if let Some(ref exit_strategies) = self.exit_strategy_watcher {
+                    if let Some(snapshot) = self.market_state.get_snapshot(&key) {
+                        exit_strategies.on_market_update(key, &snapshot);
+                    }
+                }
 
                 // Oracle-driven exit check: check for reversal/catchup
                 if let Some(ref oracle_exit) = self.oracle_exit_watcher {
@@ -2532,8 +6080,97 @@ impl Application {
                     }
                 }
 
-                // MM: Trigger quote update on oracle change
+                // MM: Resolve due fill markouts, then trigger quote update
+                self.update_mm_markouts(key, oracle_px, mark_px, now_ms);
                 self.maybe_update_mm_quotes(key, oracle_px, mark_px, now_ms);
+
+                // Lead-lag: a traded market can also lead another one
+                if let Some(ref mut lead_lag) = self.lead_lag_detector {
+                    if let Some(market) = self
+                        .config
+                        .get_markets()
+                        .iter()
+                        .find(|m| m.asset_idx == key.asset.0)
+                    {
+                        if lead_lag.is_reference(&market.coin) {
+                            lead_lag.record_reference(&market.coin, oracle_px, now_ms);
+                        }
+                    }
+                }
+            }
+            MarketEvent::BookUpdate { key, book } => {
+                // L2 depth: consumed by the detector via MarketSnapshot::book
+                self.market_state.update_book(key, book);
+            }
+            MarketEvent::TradesUpdate { key, trades } => {
+                // Trade tape: read via MarketState::trade_stats
+                self.market_state.record_trades(key, &trades);
+            }
+            MarketEvent::ReferenceUpdate { coin, oracle_px } => {
+                if let Some(ref mut lead_lag) = self.lead_lag_detector {
+                    lead_lag.record_reference(&coin, oracle_px, current_time_ms());
+                }
+            }
+        }
+    }
+
+    /// Measure the markouts of a market's MM fills whose horizons have
+    /// passed, and record them to metrics and daily stats.
+    fn update_mm_markouts(
+        &mut self,
+        market: MarketKey,
+        oracle_px: Price,
+        mark_px: Price,
+        now_ms: u64,
+    ) {
+        let model = self.mm_quote_model(&market).as_str();
+        let Some(ref mut markouts) = self.mm_markouts else {
+            return;
+        };
+        let mid_px = self
+            .market_state
+            .get_snapshot(&market)
+            .and_then(|s| s.bbo.mid_price())
+            .unwrap_or(mark_px);
+        let samples = markouts.on_price(market, mid_px, oracle_px, now_ms);
+        if samples.is_empty() {
+            return;
+        }
+        if let Some(ref mut qm) = self.quote_manager {
+            qm.record_markouts(&samples, now_ms);
+        }
+        let market_str = market.to_string();
+        for sample in &samples {
+            let horizon = format!("{}s", sample.horizon_ms as f64 / 1000.0);
+            Metrics::mm_markout(&market_str, &horizon, model, sample.mid_bps);
+            if let Some(ref mut stats) = self.daily_stats {
+                stats.record_markout(
+                    &market_str,
+                    model,
+                    sample.horizon_ms,
+                    sample.mid_bps,
+                    sample.oracle_bps,
+                );
+            }
+            debug!(
+                %market,
+                side = ?sample.side,
+                horizon_ms = sample.horizon_ms,
+                mid_bps = format!("{:.2}", sample.mid_bps),
+                oracle_bps = format!("{:.2}", sample.oracle_bps),
+                "MM fill markout"
+            );
+        }
+        for horizon_ms in markouts.horizons_ms() {
+            if let Some(stats) = markouts.stats(&market, *horizon_ms) {
+                debug!(
+                    %market,
+                    horizon_ms,
+                    fills = stats.count,
+                    mean_mid_bps = format!("{:.2}", stats.mean_mid_bps),
+                    adverse_ratio = format!("{:.2}", stats.adverse_ratio),
+                    "MM markout stats"
+                );
             }
         }
     }
@@ -2551,14 +6188,30 @@ impl Application {
             return;
         }
 
+        // HardStop level 2: cancel all GTC quotes + flatten, over the WS as
+        // well (the watcher only cancels over REST when the WS is down)
+        if self
+            .hard_stop_latch
+            .as_ref()
+            .is_some_and(|latch| latch.is_full_stop())
+        {
+            if !self.mm_shutdown_triggered {
+                self.trigger_mm_shutdown(now_ms);
+            }
+            return;
+        }
+
+        // Session windows replace the weekend schedule below
+        let use_sessions = !self.config.maker.sessions.is_empty();
+
         // Weekend-only check
-        if self.config.maker.weekend_only && !hip3_core::is_weekend_utc() {
+        if !use_sessions && self.config.maker.weekend_only && !hip3_core::is_weekend_utc() {
             return;
         }
 
         // MM shutdown window check (Sunday 21:00 - Monday 00:00 UTC)
         // P1-8: On entering shutdown, cancel all GTC quotes + flatten positions
-        if hip3_core::is_mm_shutdown_at(chrono::Utc::now()) {
+        if !use_sessions && hip3_core::is_mm_shutdown_at(chrono::Utc::now()) {
             if !self.mm_shutdown_triggered {
                 self.trigger_mm_shutdown(now_ms);
             }
@@ -2571,22 +6224,58 @@ impl Application {
             self.mm_shutdown_triggered = false;
         }
 
-        // Check if this market is in the MM market list
         // Config uses human-readable names (e.g., "GOLD"), resolve via spec_cache
-        if !self.config.maker.markets.is_empty() {
-            let market_name = self
-                .spec_cache
-                .get(&market)
-                .map(|s| s.name.clone())
-                .unwrap_or_default();
-            // Match against both "GOLD" and "xyz:GOLD" formats
-            let matches = self
-                .config
-                .maker
-                .markets
-                .iter()
-                .any(|m| m == &market_name || format!("xyz:{m}") == market_name);
-            if !matches {
+        let market_name = self
+            .spec_cache
+            .get(&market)
+            .map(|s| s.name.clone())
+            .unwrap_or_default();
+
+        // Check if this market is in the MM market list
+        if !self.is_mm_market(&market_name) {
+            return;
+        }
+
+        // Session schedule: enter on the first update inside a window, cancel
+        // quotes and flatten on the first update after it
+        if use_sessions {
+            let asset_class = self.config.trading_calendar.asset_class(&market_name);
+            let open = hip3_mm::in_session(
+                &self.config.maker.sessions,
+                &market_name,
+                asset_class,
+                chrono::Utc::now(),
+            );
+            if !open {
+                if self.mm_session_markets.remove(&market) {
+                    let qm = self.quote_manager.as_mut().unwrap();
+                    let inv = self.mm_inventory.as_ref().unwrap();
+                    if let (Some(action), Some(ref executor_loop)) = (
+                        qm.shutdown_market(market, inv, mark_px, now_ms),
+                        &self.executor_loop,
+                    ) {
+                        executor_loop.executor().on_mm_quote(vec![action]);
+                    }
+                    info!(%market, "MM session ended: quotes cancelled, inventory flattened");
+                }
+                return;
+            }
+            if self.mm_session_markets.insert(market) {
+                info!(%market, "MM session started");
+            }
+        }
+
+        // Underlying closed (after hours, holiday): pull this market's quotes
+        if let Some(ref calendar) = self.trading_calendar {
+            let class = calendar.asset_class(&market_name);
+            if let Some(class) = class.filter(|c| !calendar.is_open(*c)) {
+                let qm = self.quote_manager.as_mut().unwrap();
+                if let (Some(action), Some(ref executor_loop)) =
+                    (qm.pull_market(market, now_ms), &self.executor_loop)
+                {
+                    info!(%market, asset_class = %class, "Underlying closed, MM quotes pulled");
+                    executor_loop.executor().on_mm_quote(vec![action]);
+                }
                 return;
             }
         }
@@ -2594,14 +6283,37 @@ impl Application {
         // Generate quote action
         let qm = self.quote_manager.as_mut().unwrap();
         let inv = self.mm_inventory.as_ref().unwrap();
-        let action = qm.on_market_update(market, oracle_px, mark_px, now_ms, inv);
+        let mut actions: Vec<MakerAction> = Vec::new();
+
+        // Amendments that did not apply fall back to cancel-replace
+        if let Some(ref executor_loop) = self.executor_loop {
+            for modify in executor_loop.executor().take_rejected_modifies() {
+                actions.extend(qm.on_modify_rejected(&modify, now_ms));
+            }
+        }
+        // Tick size for the tick-based quote hysteresis, quote model by name
+        if let Some(spec) = self.spec_cache.get(&market) {
+            qm.set_tick_size(market, spec.tick_size);
+            qm.set_lot_size(market, spec.lot_size);
+            qm.set_quote_model(market, self.config.maker.quote_model_for(&spec.name));
+        }
+        // Degraded latency widens the quotes instead of pulling them
+        if let Some(ref gate) = self.latency_gate {
+            qm.set_external_spread_multiplier(gate.spread_multiplier_at(now_ms));
+        }
+        actions.extend(qm.on_market_update(market, oracle_px, mark_px, now_ms, inv));
 
         // Execute via MM executor path
-        if let Some(action) = action {
+        if !actions.is_empty() {
             if let Some(ref executor_loop) = self.executor_loop {
-                let results = executor_loop.executor().on_mm_quote(vec![action]);
+                let results = executor_loop.executor().on_mm_quote(actions);
                 for result in &results {
                     debug!(market = %market, result = ?result, "MM quote result");
+                    if let (Some(ref log), MmQuoteResult::Rejected(reason)) =
+                        (&self.risk_event_log, result)
+                    {
+                        log.gate_block(reason, Some(market), "mm_quote");
+                    }
                 }
             }
         }
@@ -2616,6 +6328,7 @@ impl Application {
                         p99 = format!("{:.1}", stats.p99_wick_bps),
                         p100 = format!("{:.1}", stats.p100_wick_bps),
                         optimal = format!("{:.1}", stats.optimal_wick_bps),
+                        smoothed = format!("{:.1}", qm.smoothed_wick_bps(mk).unwrap_or_default()),
                         optimal_pct = stats.optimal_percentile,
                         n = stats.sample_count,
                         "P3-1 wick volatility"
@@ -2642,9 +6355,12 @@ impl Application {
 
         use rust_decimal::prelude::ToPrimitive;
         let is_weekend = hip3_core::is_weekend_utc();
-        let active = self.config.maker.enabled
-            && (!self.config.maker.weekend_only || is_weekend)
-            && !self.mm_shutdown_triggered;
+        let scheduled = if self.config.maker.sessions.is_empty() {
+            !self.config.maker.weekend_only || is_weekend
+        } else {
+            !self.mm_session_markets.is_empty()
+        };
+        let active = self.config.maker.enabled && scheduled && !self.mm_shutdown_triggered;
 
         let mut inventory = std::collections::HashMap::new();
         for (mk, market_inv) in inv.iter() {
@@ -2652,6 +6368,19 @@ impl Application {
                 inventory.insert(mk.to_string(), market_inv.net_size.to_f64().unwrap_or(0.0));
             }
         }
+        let pnl_breakdown: std::collections::HashMap<_, _> = self
+            .mm_pnl_breakdown()
+            .into_iter()
+            .map(|(mk, spread_capture_usd, inventory_pnl_usd)| {
+                (
+                    mk.to_string(),
+                    hip3_dashboard::MmPnlBreakdown {
+                        spread_capture_usd,
+                        inventory_pnl_usd,
+                    },
+                )
+            })
+            .collect();
 
         ds.update_mm_status(hip3_dashboard::MmStatus {
             enabled: self.config.maker.enabled,
@@ -2661,9 +6390,83 @@ impl Application {
             stale_halted: qm.is_stale_halted(),
             realized_pnl: inv.total_realized_pnl().to_f64().unwrap_or(0.0),
             inventory,
+            toxic_fades: qm
+                .toxic_fades(current_time_ms())
+                .into_iter()
+                .map(|(mk, until_ms)| (mk.to_string(), until_ms))
+                .collect(),
+            spread_capture_usd: pnl_breakdown.values().map(|p| p.spread_capture_usd).sum(),
+            inventory_pnl_usd: pnl_breakdown.values().map(|p| p.inventory_pnl_usd).sum(),
+            pnl_breakdown,
         });
     }
 
+    /// MM PnL per market: (market, spread capture USD, inventory PnL USD).
+    ///
+    /// Inventory is marked at the oracle, the reference of the fills; markets
+    /// with open inventory and no oracle are skipped.
+    fn mm_pnl_breakdown(&self) -> Vec<(MarketKey, f64, f64)> {
+        use rust_decimal::prelude::ToPrimitive;
+        let Some(ref inv) = self.mm_inventory else {
+            return Vec::new();
+        };
+        inv.iter()
+            .filter_map(|(mk, market_inv)| {
+                let mark = match self.market_state.get_snapshot(mk) {
+                    Some(s) => s.ctx.oracle.oracle_px,
+                    None if market_inv.net_size.is_zero() => Price::new(Decimal::ZERO),
+                    None => return None,
+                };
+                Some((
+                    *mk,
+                    market_inv.spread_capture.to_f64().unwrap_or(0.0),
+                    market_inv.inventory_pnl(mark).to_f64().unwrap_or(0.0),
+                ))
+            })
+            .collect()
+    }
+
+    /// Push the MM PnL decomposition to daily stats.
+    fn sync_mm_pnl_stats(&mut self) {
+        let breakdown: Vec<_> = self
+            .mm_pnl_breakdown()
+            .into_iter()
+            .map(|(mk, spread, inventory)| (mk, self.mm_quote_model(&mk), spread, inventory))
+            .collect();
+        if let Some(ref mut stats) = self.daily_stats {
+            for (mk, model, spread_capture_usd, inventory_pnl_usd) in breakdown {
+                stats.set_mm_pnl(
+                    &mk.to_string(),
+                    model.as_str(),
+                    spread_capture_usd,
+                    inventory_pnl_usd,
+                );
+            }
+        }
+    }
+
+    /// Quote model a market is quoted with.
+    fn mm_quote_model(&self, market: &MarketKey) -> QuoteModel {
+        self.quote_manager
+            .as_ref()
+            .map_or(self.config.maker.quote_model, |qm| qm.quote_model(market))
+    }
+
+    /// Export daily/weekly loss limit state.
+    fn report_loss_limit_metrics(&self) {
+        let Some(ref gate) = self.max_drawdown_gate else {
+            return;
+        };
+        let config = &self.config.max_drawdown;
+        let status = gate.loss_limit_status();
+        if config.max_daily_loss_usd > 0.0 {
+            Metrics::loss_limit("daily", status.daily_pnl_usd, status.daily_latched);
+        }
+        if config.max_weekly_loss_usd > 0.0 {
+            Metrics::loss_limit("weekly", status.weekly_pnl_usd, status.weekly_latched);
+        }
+    }
+
     /// P1-8: Trigger MM shutdown — cancel all GTC quotes and flatten positions.
     fn trigger_mm_shutdown(&mut self, now_ms: u64) {
         let (qm, inv) = match (self.quote_manager.as_mut(), self.mm_inventory.as_ref()) {
@@ -2696,6 +6499,14 @@ impl Application {
 
     /// Check all markets for dislocations.
     async fn check_dislocations(&mut self) -> Option<Vec<DislocationSignal>> {
+        if let Some(until) = self.fee_change_pause_until {
+            if Instant::now() < until {
+                return None;
+            }
+            self.fee_change_pause_until = None;
+            info!("Fee tier change applied, resuming entries");
+        }
+
         let mut signals = Vec::new();
         let dex_id = self.get_dex_id();
 
@@ -2707,7 +6518,8 @@ impl Application {
                 Some(s) => s,
                 None => {
                     // P0-31: Update cross tracker (no cross when market not ready)
-                    self.cross_tracker.update(key, false, None);
+                    self.cross_tracker
+                        .update(key, false, None, current_time_ms());
                     continue;
                 }
             };
@@ -2717,6 +6529,7 @@ impl Application {
 
             // Get data freshness metrics (P0-12, P0-16)
             // BUG-002 fix: oracle_age_ms removed - ctx_age_ms now covers oracle freshness
+            // In exchange time with skew-corrected freshness (see MarketState)
             let bbo_age_ms = self.market_state.get_bbo_age_ms(&key).unwrap_or(i64::MAX);
             let ctx_age_ms = self.market_state.get_ctx_age_ms(&key).unwrap_or(i64::MAX);
             let bbo_server_time = self.market_state.get_bbo_server_time(&key);
@@ -2724,17 +6537,77 @@ impl Application {
             // Update freshness metrics
             Metrics::bbo_age(&key.to_string(), bbo_age_ms as f64);
             Metrics::ctx_age(&key.to_string(), ctx_age_ms as f64);
+            if let Some(ref executor_loop) = self.executor_loop {
+                let budget = executor_loop.executor().action_budget();
+                if budget.has_market_quota() {
+                    let now_ms = current_time_ms();
+                    Metrics::action_budget_remaining(
+                        &key.to_string(),
+                        budget.remaining_for_at(&key, now_ms) as f64,
+                    );
+                    Metrics::action_budget_shared_remaining(
+                        budget.shared_remaining_at(now_ms) as f64
+                    );
+                }
+            }
 
-            // Check risk gates
-            match self.risk_gate.check_all(
-                &snapshot,
-                &spec,
-                bbo_age_ms,
-                ctx_age_ms,
-                bbo_server_time,
-                None,
-            ) {
-                Ok(_results) => {
+            let realized_vol_bps = self
+                .regime_classifier
+                .as_ref()
+                .and_then(|classifier| classifier.realized_vol_bps(&key));
+            let risk_gate = self
+                .risk_gates
+                .entry(key)
+                .or_insert_with(|| RiskGate::new(self.config.risk.clone()));
+            let gate_inputs = GateInputs {
+                realized_vol_bps,
+                max_realized_vol_bps: market.max_realized_vol_bps.map(Decimal::from),
+                calendar: self
+                    .trading_calendar
+                    .as_ref()
+                    .map(|calendar| (calendar, calendar.asset_class(&market.coin))),
+                now_ms: current_time_ms() as i64,
+            };
+
+            // Check risk gates (against this market's own spread/feed history)
+            let gate_result = risk_gate
+                .check_all(
+                    &snapshot,
+                    &spec,
+                    bbo_age_ms,
+                    ctx_age_ms,
+                    bbo_server_time,
+                    None,
+                    &gate_inputs,
+                )
+                .map(|results| {
+                    // Realized volatility reduces size when elevated
+                    results
+                        .into_iter()
+                        .find_map(|(id, result)| match result {
+                            GateResult::ReduceSize { factor, reason }
+                                if id == GateId::RealizedVol =>
+                            {
+                                tracing::debug!(%key, %factor, %reason, "Entry size reduced");
+                                Some(factor)
+                            }
+                            _ => None,
+                        })
+                        .unwrap_or(Decimal::ONE)
+                });
+            for block in risk_gate.take_observed_blocks() {
+                Metrics::gate_observed_block(block.gate.as_str());
+                if let Some(ref log) = self.risk_event_log {
+                    log.gate_observe_at(
+                        block.gate.as_str(),
+                        Some(key),
+                        &block.reason,
+                        block.timestamp_ms,
+                    );
+                }
+            }
+            match gate_result {
+                Ok(size_factor) => {
                     // BUG-003 fix: Clear block state when gates pass
                     // P1-3: Record block duration before clearing
                     let cleared: Vec<_> = self
@@ -2764,8 +6637,23 @@ impl Application {
                         self.edge_tracker.record_edge(key, buy_edge, sell_edge);
                     }
 
-                    // Look up per-market threshold override
-                    let threshold_override = self.market_threshold_map.get(&key.asset.0).copied();
+                    // Look up per-market threshold override (auto-calibrated value
+                    // takes precedence over the static config map)
+                    let threshold_override = self
+                        .edge_tracker
+                        .calibrated_threshold(&key)
+                        .or_else(|| self.market_threshold_map.get(&key.asset.0).copied());
+
+                    // Explicit long/short thresholds win over the symmetric override
+                    let sided = self
+                        .market_side_thresholds
+                        .get(&key.asset.0)
+                        .copied()
+                        .unwrap_or_default();
+                    let thresholds = SideThresholds {
+                        long: sided.long.or(threshold_override),
+                        short: sided.short.or(threshold_override),
+                    };
 
                     // Get oracle age for quote lag gate
                     let oracle_age_ms = self.market_state.get_oracle_age_ms(&key);
@@ -2776,10 +6664,17 @@ impl Application {
                         spread_ewma * self.detector.config().spread_threshold_multiplier;
                     let eff_threshold = match threshold_override {
                         Some(ovr) => ovr.max(adaptive_th),
-                        None => adaptive_th,
+                        None => self.detector.total_cost_bps(&key).max(adaptive_th),
                     };
                     self.edge_tracker
                         .record_threshold_info(key, spread_ewma, eff_threshold);
+                    Metrics::effective_threshold(
+                        &key.to_string(),
+                        eff_threshold.to_string().parse().unwrap_or(0.0),
+                    );
+                    if let Some(ref ds) = self.dashboard_state {
+                        ds.update_effective_threshold(key, eff_threshold);
+                    }
 
                     // Sprint 3 P2-E: Skip markets disabled by health tracker
                     if let Some(ref tracker) = self.market_health_tracker {
@@ -2792,22 +6687,90 @@ impl Application {
                         }
                     }
 
-                    // All gates passed, check for dislocation
-                    if let Some(signal) = self.detector.check(
+                    // Per-market blackout (fixings, venue opens): silence this market only
+                    if let Some(window) = market.active_blackout(Utc::now().time()) {
+                        tracing::debug!(
+                            %key,
+                            start = %window.start,
+                            end = %window.end,
+                            "Market skipped: blackout window"
+                        );
+                        continue;
+                    }
+
+                    // All gates passed, run the market's detection strategies.
+                    // Every strategy sees the tick (lead-lag needs its target
+                    // history current); the first signal in priority order wins.
+                    let ctx = StrategyContext {
                         key,
-                        &snapshot,
-                        threshold_override,
-                        Some(&self.oracle_tracker),
+                        coin: &market.coin,
+                        snapshot: &snapshot,
+                        spec: &spec,
+                        thresholds,
+                        oracle_tracker: Some(self.oracle_tracker.as_ref()),
                         oracle_age_ms,
-                    ) {
+                        now_ms: current_time_ms(),
+                    };
+                    let mut detected = None;
+                    for name in self.config.strategies.for_coin(&market.coin) {
+                        let signal = match name.as_str() {
+                            DISLOCATION_STRATEGY => self.detector.detect(&ctx),
+                            LEAD_LAG_STRATEGY => self
+                                .lead_lag_detector
+                                .as_mut()
+                                .and_then(|ll| ll.detect(&ctx)),
+                            other => self
+                                .custom_strategies
+                                .get_mut(other)
+                                .and_then(|s| s.detect(&ctx)),
+                        };
+                        if detected.is_none() {
+                            if let Some(ref s) = signal {
+                                tracing::debug!(
+                                    %key,
+                                    strategy = %name,
+                                    side = %s.side,
+                                    "Strategy fired"
+                                );
+                            }
+                            detected = signal;
+                        }
+                    }
+                    if let Some(mut signal) = detected {
                         // P0-31: Cross detected - record cross count and update tracker
                         let side = signal.side;
                         Metrics::cross_detected(&key.to_string(), &side.to_string());
-                        self.cross_tracker.update(key, true, Some(side));
+                        self.cross_tracker.update(key, true, Some(side), ctx.now_ms);
+
+                        // Trade only crosses that have persisted long enough
+                        let cross_duration_ms = self.cross_tracker.duration_ms(&key, ctx.now_ms);
+                        let min_cross_duration_ms = market
+                            .min_cross_duration_ms
+                            .unwrap_or(self.detector.config().min_cross_duration_ms);
+                        if cross_duration_ms < min_cross_duration_ms {
+                            tracing::debug!(
+                                %key,
+                                %side,
+                                cross_duration_ms,
+                                min_cross_duration_ms,
+                                "Signal held: cross too short"
+                            );
+                            continue;
+                        }
+                        signal.cross_duration_ms = cross_duration_ms;
+                        // Dedup only what is emitted: a held signal must be
+                        // re-detected on the next tick to keep the cross alive
+                        if signal.source == SignalSource::Dislocation {
+                            self.detector.record_signal(&signal);
+                        }
+                        if size_factor < Decimal::ONE {
+                            signal.suggested_size =
+                                Size::new(signal.suggested_size.inner() * size_factor);
+                        }
                         signals.push(signal);
                     } else {
                         // P0-31: No cross this tick
-                        self.cross_tracker.update(key, false, None);
+                        self.cross_tracker.update(key, false, None, ctx.now_ms);
                     }
                 }
                 Err(e) => {
@@ -2841,7 +6804,8 @@ impl Application {
 
                     // Always record metrics (no spam, just counters)
                     Metrics::gate_blocked(&gate_name, &key.to_string());
-                    self.cross_tracker.update(key, false, None);
+                    self.cross_tracker
+                        .update(key, false, None, current_time_ms());
                 }
             }
         }
@@ -2857,7 +6821,7 @@ impl Application {
             let sell_count = signals.iter().filter(|s| s.side == OrderSide::Sell).count();
 
             if buy_count >= max_sim {
-                signals.sort_by(|a, b| b.raw_edge_bps.cmp(&a.raw_edge_bps));
+                signals.sort_by_key(|s| std::cmp::Reverse(s.raw_edge_bps));
                 let top_buys: Vec<_> = signals
                     .iter()
                     .filter(|s| s.side == OrderSide::Buy)
@@ -2876,7 +6840,7 @@ impl Application {
                 signals = [top_buys, sells].concat();
             }
             if sell_count >= max_sim {
-                signals.sort_by(|a, b| b.raw_edge_bps.cmp(&a.raw_edge_bps));
+                signals.sort_by_key(|s| std::cmp::Reverse(s.raw_edge_bps));
                 let top_sells: Vec<_> = signals
                     .iter()
                     .filter(|s| s.side == OrderSide::Sell)
@@ -2933,6 +6897,15 @@ impl Application {
             .to_string()
             .parse()
             .unwrap_or(0.0);
+        let confidence = signal.confidence_score.to_string().parse().unwrap_or(0.0);
+        let threshold_bps = signal.threshold_bps.to_string().parse().unwrap_or(0.0);
+        let book_imbalance = signal.book_imbalance.to_string().parse().unwrap_or(0.0);
+        let features = signal
+            .features
+            .named()
+            .iter()
+            .map(|(name, v)| (name.to_string(), v.to_string().parse().unwrap_or(0.0)))
+            .collect();
 
         let record = SignalRecord {
             timestamp_ms,
@@ -2945,6 +6918,12 @@ impl Application {
             best_size,
             suggested_size,
             signal_id: signal.signal_id.clone(),
+            source: signal.source.to_string(),
+            confidence,
+            features,
+            threshold_bps,
+            book_imbalance,
+            cross_duration_ms: signal.cross_duration_ms,
         };
 
         // Add to recent signals buffer (for dashboard)
@@ -3025,6 +7004,55 @@ impl Application {
     }
 }
 
+/// Spawn the raw frame recorder and return the hook feeding it.
+///
+/// Frames are written on their own task so the WebSocket message loop never
+/// waits on disk; if the queue is full, frames are dropped and counted.
+fn spawn_frame_recorder(config: &PersistenceConfig) -> FrameHook {
+    let (frame_tx, mut frame_rx) = mpsc::channel::<FrameRecord>(FRAME_CHANNEL_CAPACITY);
+    let mut writer = FrameWriter::new(&config.frames_dir, FEED_BUFFER_SIZE)
+        .with_max_file_bytes(config.frames_max_file_mb * 1024 * 1024);
+    info!(dir = %config.frames_dir, "Recording raw WebSocket frames");
+
+    tokio::spawn(async move {
+        let mut flush_interval = tokio::time::interval(Duration::from_secs(1));
+        loop {
+            tokio::select! {
+                frame = frame_rx.recv() => {
+                    let Some(frame) = frame else { break };
+                    if let Err(e) = writer.add_record(frame) {
+                        warn!(?e, "Failed to write frame record");
+                    }
+                }
+                _ = flush_interval.tick() => {
+                    if let Err(e) = writer.flush() {
+                        warn!(?e, "Failed to flush frame records");
+                    }
+                }
+            }
+        }
+        if let Err(e) = writer.close() {
+            warn!(?e, "Failed to close frame writer");
+        }
+    });
+
+    let dropped = AtomicU64::new(0);
+    Arc::new(move |connection, text| {
+        let frame = FrameRecord {
+            timestamp_ms: current_time_ms() as i64,
+            connection: connection.to_string(),
+            text: text.to_string(),
+        };
+        if frame_tx.try_send(frame).is_err() {
+            Metrics::frame_recorder_dropped();
+            let dropped = dropped.fetch_add(1, Ordering::Relaxed) + 1;
+            if dropped % 1000 == 1 {
+                warn!(dropped, "Frame recorder queue full, dropping frames");
+            }
+        }
+    })
+}
+
 /// Capture a followup snapshot after delay.
 ///
 /// Called from spawned tasks to record market state at T+N ms after signal.
@@ -3162,6 +7190,8 @@ async fn capture_followup(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use hip3_core::{AssetCtx, Bbo, OracleData};
+    use rust_decimal_macros::dec;
 
     /// Helper to create a minimal config for testing.
     fn test_config_with_markets(markets: Vec<MarketConfig>) -> AppConfig {
@@ -3172,6 +7202,119 @@ mod tests {
         }
     }
 
+    /// A signal held by min_cross_duration must not be deduped away: the
+    /// held tick records nothing, so the next tick extends the cross and fires.
+    #[tokio::test]
+    async fn test_held_signal_fires_with_dedup() {
+        let mut config = test_config_with_markets(vec![MarketConfig {
+            coin: "BTC".to_string(),
+            asset_idx: 0,
+            threshold_bps: None,
+            threshold_bps_long: None,
+            threshold_bps_short: None,
+            min_cross_duration_ms: Some(20),
+            blackout_windows: Vec::new(),
+            max_slippage_bps: None,
+            max_realized_vol_bps: None,
+        }]);
+        config.detector.signal_dedup_enabled = true;
+        config.detector.oracle_direction_filter = false;
+        config.detector.min_oracle_change_bps = Decimal::ZERO;
+        config.detector.min_consecutive_oracle_moves = 0;
+        let mut app = Application::new(config).unwrap();
+
+        // Ask 100 bps under the oracle, mark at mid (no divergence)
+        let key = MarketKey::new(app.get_dex_id(), AssetId::new(0));
+        let bbo = Bbo::new(
+            Price::new(dec!(49490)),
+            Size::new(dec!(1)),
+            Price::new(dec!(49500)),
+            Size::new(dec!(1)),
+        );
+        let oracle = OracleData::new(Price::new(dec!(50000)), Price::new(dec!(49495)));
+        let feed = |app: &Application| {
+            app.market_state.update_bbo(key, bbo.clone(), None);
+            app.market_state
+                .update_ctx(key, AssetCtx::new(oracle.clone(), dec!(0.0001)));
+        };
+
+        feed(&app);
+        let held = app.check_dislocations().await.unwrap_or_default();
+        assert!(held.is_empty(), "cross too short: held");
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        feed(&app);
+        let fired = app.check_dislocations().await.unwrap_or_default();
+        assert_eq!(fired.len(), 1, "held cross fires once long enough");
+        assert_eq!(fired[0].side, OrderSide::Buy);
+
+        // Emitted: the same oracle is deduped from now on
+        feed(&app);
+        let deduped = app.check_dislocations().await.unwrap_or_default();
+        assert!(deduped.is_empty());
+    }
+
+    /// A spread shock and a time regression on one market must not touch
+    /// another market's RiskGate.
+    #[tokio::test]
+    async fn test_risk_gate_state_is_per_market() {
+        let market = |coin: &str, asset_idx: u32| MarketConfig {
+            coin: coin.to_string(),
+            asset_idx,
+            threshold_bps: None,
+            threshold_bps_long: None,
+            threshold_bps_short: None,
+            min_cross_duration_ms: None,
+            blackout_windows: Vec::new(),
+            max_slippage_bps: None,
+            max_realized_vol_bps: None,
+        };
+        let config = test_config_with_markets(vec![market("BTC", 0), market("ETH", 1)]);
+        let mut app = Application::new(config).unwrap();
+        let btc = MarketKey::new(app.get_dex_id(), AssetId::new(0));
+        let eth = MarketKey::new(app.get_dex_id(), AssetId::new(1));
+
+        // Mark at mid, so only the spread and the server time vary
+        let feed = |app: &Application, key: MarketKey, bid, ask, server_time| {
+            let bbo = Bbo::new(
+                Price::new(bid),
+                Size::new(dec!(1)),
+                Price::new(ask),
+                Size::new(dec!(1)),
+            );
+            let mid = (bid + ask) / dec!(2);
+            app.market_state.update_bbo(key, bbo, Some(server_time));
+            app.market_state.update_ctx(
+                key,
+                AssetCtx::new(
+                    OracleData::new(Price::new(mid), Price::new(mid)),
+                    dec!(0.0001),
+                ),
+            );
+        };
+
+        feed(&app, btc, dec!(49995), dec!(50005), 2_000);
+        feed(&app, eth, dec!(2999), dec!(3001), 2_000);
+        let _ = app.check_dislocations().await;
+        let eth_ewma = app.risk_gates[&eth].spread_ewma();
+        assert!(!eth_ewma.is_zero());
+
+        // BTC spread blows out
+        feed(&app, btc, dec!(49000), dec!(51000), 3_000);
+        feed(&app, eth, dec!(2999), dec!(3001), 3_000);
+        let _ = app.check_dislocations().await;
+        assert!(app.risk_gates[&btc].spread_ewma() > eth_ewma);
+        assert_eq!(app.risk_gates[&eth].spread_ewma(), eth_ewma);
+
+        // BTC server time goes backwards
+        feed(&app, btc, dec!(49995), dec!(50005), 1_000);
+        feed(&app, eth, dec!(2999), dec!(3001), 4_000);
+        let _ = app.check_dislocations().await;
+        assert!(app.risk_gates[&btc].has_critical_block());
+        assert!(!app.risk_gates[&eth].has_critical_block());
+        assert_eq!(app.risk_gates[&eth].spread_ewma(), eth_ewma);
+    }
+
     /// Test coin_to_market with full match.
     #[test]
     fn test_coin_to_market_full_match() {
@@ -3180,11 +7323,23 @@ mod tests {
                 coin: "BTC".to_string(),
                 asset_idx: 0,
                 threshold_bps: None,
+                threshold_bps_long: None,
+                threshold_bps_short: None,
+                min_cross_duration_ms: None,
+                blackout_windows: Vec::new(),
+                max_slippage_bps: None,
+                max_realized_vol_bps: None,
             },
             MarketConfig {
                 coin: "ETH".to_string(),
                 asset_idx: 1,
                 threshold_bps: None,
+                threshold_bps_long: None,
+                threshold_bps_short: None,
+                min_cross_duration_ms: None,
+                blackout_windows: Vec::new(),
+                max_slippage_bps: None,
+                max_realized_vol_bps: None,
             },
         ];
         let config = test_config_with_markets(markets);
@@ -3208,6 +7363,12 @@ mod tests {
             coin: "xyz:AAPL".to_string(),
             asset_idx: 10,
             threshold_bps: None,
+            threshold_bps_long: None,
+            threshold_bps_short: None,
+            min_cross_duration_ms: None,
+            blackout_windows: Vec::new(),
+            max_slippage_bps: None,
+            max_realized_vol_bps: None,
         }];
         let config = test_config_with_markets(markets);
         let app = Application::new(config).unwrap();
@@ -3226,6 +7387,12 @@ mod tests {
             coin: "BTC".to_string(),
             asset_idx: 0,
             threshold_bps: None,
+            threshold_bps_long: None,
+            threshold_bps_short: None,
+            min_cross_duration_ms: None,
+            blackout_windows: Vec::new(),
+            max_slippage_bps: None,
+            max_realized_vol_bps: None,
         }];
         let config = test_config_with_markets(markets);
         let app = Application::new(config).unwrap();
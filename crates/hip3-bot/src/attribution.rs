@@ -0,0 +1,336 @@
+//! PnL attribution of closed trades.
+//!
+//! [`TradeAttributor`] remembers how each open position was entered (taker
+//! signal or MM quote) and the fees paid on the way in. Every closing fill
+//! becomes a [`TradeRecord`] carrying the entry source, the exit reason,
+//! gross PnL, entry plus exit fees, and funding.
+//!
+//! The exit reason is the one the winning exit monitor claimed on the
+//! `SharedFlatteningGuard` (HardStop claims it too). The guard is released
+//! on the first closing fill, so the reason is kept for the remaining fills
+//! of the same close. Closes no monitor claimed are attributed to the MM
+//! quote that filled, or to a manual close.
+//!
+//! The max adverse excursion (MAE) is the worst unrealized PnL seen between
+//! entry and exit, fed from every BBO update at the exit-side price and
+//! from the closing fills themselves.
+//!
+//! Funding comes from the position itself (`Position::funding_usd`, accrued
+//! by `PositionTracker` from userFundings), pro rata to the closed size.
+
+use hip3_core::{MarketKey, OrderSide, Price, Size};
+use hip3_persistence::TradeRecord;
+use hip3_position::{exit_side_pnl_bps, Position};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+/// How a position was entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntrySource {
+    /// Taker order from a dislocation signal.
+    Taker,
+    /// Filled MM quote.
+    Mm,
+}
+
+impl EntrySource {
+    /// Label used in the ledger and daily stats.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Taker => "taker",
+            Self::Mm => "mm",
+        }
+    }
+}
+
+/// Attribution state of one open position.
+#[derive(Debug, Clone)]
+struct OpenEntry {
+    source: EntrySource,
+    /// Entry fees not yet attributed to a closing fill (USD).
+    entry_fees_usd: Decimal,
+    /// Exit reason of the close in progress.
+    exit_reason: Option<String>,
+    /// Worst unrealized PnL so far (bps, <= 0).
+    mae_bps: Decimal,
+    /// Worst unrealized PnL so far (USD, <= 0).
+    mae_usd: Decimal,
+}
+
+/// A fill that reduces an open position.
+#[derive(Debug, Clone)]
+pub struct ClosingFill {
+    /// Fill price.
+    pub price: Price,
+    /// Fill size.
+    pub size: Size,
+    /// Fee paid (USD, negative = rebate).
+    pub fee_usd: Decimal,
+    /// Client order ID of the closing order, if known.
+    pub cloid: Option<String>,
+    /// Exit reason released from the shared flattening guard.
+    pub claimed_reason: Option<String>,
+    /// Whether the fill came from an MM quote.
+    pub is_mm_fill: bool,
+    /// Exchange fill time (Unix ms).
+    pub timestamp_ms: u64,
+}
+
+/// Per-market entry source and fee bookkeeping for PnL attribution.
+#[derive(Debug, Default)]
+pub struct TradeAttributor {
+    entries: HashMap<MarketKey, OpenEntry>,
+}
+
+impl TradeAttributor {
+    /// Create an empty attributor.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a fill that opens or adds to a position.
+    ///
+    /// A new position forgets whatever was left of a previous one (e.g. one
+    /// closed by a resync without a closing fill).
+    pub fn on_entry_fill(
+        &mut self,
+        market: MarketKey,
+        source: EntrySource,
+        fee_usd: Decimal,
+        is_new_position: bool,
+    ) {
+        if is_new_position {
+            self.entries.remove(&market);
+        }
+        let entry = self.entries.entry(market).or_insert(OpenEntry {
+            source,
+            entry_fees_usd: Decimal::ZERO,
+            exit_reason: None,
+            mae_bps: Decimal::ZERO,
+            mae_usd: Decimal::ZERO,
+        });
+        entry.entry_fees_usd += fee_usd;
+    }
+
+    /// Entry source label of the open position in `market`.
+    #[must_use]
+    pub fn entry_source(&self, market: &MarketKey) -> &'static str {
+        self.entries
+            .get(market)
+            .map_or("unknown", |e| e.source.as_str())
+    }
+
+    /// Update the MAE of the open position with its current exit-side price.
+    pub fn on_price(&mut self, position: &Position, exit_px: Price) {
+        let Some(entry) = self.entries.get_mut(&position.market) else {
+            return;
+        };
+        let (pnl_bps, pnl_usd) = unrealized_pnl(position, exit_px);
+        entry.mae_bps = entry.mae_bps.min(pnl_bps);
+        entry.mae_usd = entry.mae_usd.min(pnl_usd);
+    }
+
+    /// Attribute a closing fill of `position` (the position before the fill).
+    pub fn on_close(&mut self, position: &Position, fill: ClosingFill) -> TradeRecord {
+        let market = position.market;
+        let closed = fill.size.inner().min(position.size.inner());
+        let fully_closed = closed >= position.size.inner();
+
+        let entry = self.entries.get_mut(&market);
+        let entry_source = entry
+            .as_ref()
+            .map_or("unknown", |e| e.source.as_str())
+            .to_string();
+        let stored_reason = entry.as_ref().and_then(|e| e.exit_reason.clone());
+        let exit_reason = fill.claimed_reason.or(stored_reason).unwrap_or_else(|| {
+            if fill.is_mm_fill {
+                "MmQuote".to_string()
+            } else {
+                "Manual".to_string()
+            }
+        });
+
+        // MAE including the closing fill itself
+        let (exit_pnl_bps, exit_pnl_usd) = unrealized_pnl(position, fill.price);
+        let (mae_bps, mae_usd) = entry
+            .as_ref()
+            .map_or((Decimal::ZERO, Decimal::ZERO), |e| (e.mae_bps, e.mae_usd));
+        let mae_bps = mae_bps.min(exit_pnl_bps);
+        let mae_usd = mae_usd.min(exit_pnl_usd);
+
+        // Entry fees of the closed share of the position
+        let entry_fees = match entry {
+            Some(entry) => {
+                let share = if position.size.is_zero() {
+                    Decimal::ONE
+                } else {
+                    closed / position.size.inner()
+                };
+                let fees = entry.entry_fees_usd * share;
+                entry.entry_fees_usd -= fees;
+                entry.exit_reason = Some(exit_reason.clone());
+                entry.mae_bps = mae_bps;
+                entry.mae_usd = mae_usd;
+                fees
+            }
+            None => Decimal::ZERO,
+        };
+        if fully_closed {
+            self.entries.remove(&market);
+        }
+
+        let entry_px = position.entry_price.inner();
+        let exit_px = fill.price.inner();
+        let gross_pnl = match position.side {
+            OrderSide::Buy => (exit_px - entry_px) * closed,
+            OrderSide::Sell => (entry_px - exit_px) * closed,
+        };
+        let gross_pnl_bps = if entry_px.is_zero() || closed.is_zero() {
+            Decimal::ZERO
+        } else {
+            gross_pnl / (entry_px * closed) * Decimal::from(10000)
+        };
+        let fees = entry_fees + fill.fee_usd;
+        let funding = if position.size.is_zero() {
+            position.funding_usd
+        } else {
+            position.funding_usd * closed / position.size.inner()
+        };
+        let f = |d: Decimal| d.to_f64().unwrap_or(0.0);
+
+        TradeRecord {
+            market_key: market.to_string(),
+            side: match position.side {
+                OrderSide::Buy => "long".to_string(),
+                OrderSide::Sell => "short".to_string(),
+            },
+            entry_source,
+            exit_reason,
+            cloid: fill.cloid,
+            opened_at_ms: position.entry_timestamp_ms,
+            closed_at_ms: fill.timestamp_ms,
+            hold_time_ms: fill
+                .timestamp_ms
+                .saturating_sub(position.entry_timestamp_ms),
+            entry_px: f(entry_px),
+            exit_px: f(exit_px),
+            size: f(closed),
+            notional_usd: f(exit_px * closed),
+            gross_pnl_usd: f(gross_pnl),
+            gross_pnl_bps: f(gross_pnl_bps),
+            fees_usd: f(fees),
+            funding_usd: f(funding),
+            net_pnl_usd: f(gross_pnl - fees - funding),
+            mae_bps: f(mae_bps),
+            mae_usd: f(mae_usd),
+        }
+    }
+}
+
+/// Unrealized PnL of the whole position at `exit_px`: (bps, USD).
+fn unrealized_pnl(position: &Position, exit_px: Price) -> (Decimal, Decimal) {
+    let Some(pnl_bps) = exit_side_pnl_bps(position, exit_px) else {
+        return (Decimal::ZERO, Decimal::ZERO);
+    };
+    let notional = position.entry_price.inner() * position.size.inner();
+    (pnl_bps, pnl_bps / Decimal::from(10000) * notional)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+
+    fn market() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn closing(price: Decimal, size: Decimal, claimed_reason: Option<&str>) -> ClosingFill {
+        ClosingFill {
+            price: Price::new(price),
+            size: Size::new(size),
+            fee_usd: dec!(0.05),
+            cloid: None,
+            claimed_reason: claimed_reason.map(str::to_string),
+            is_mm_fill: false,
+            timestamp_ms: 11_000,
+        }
+    }
+
+    #[test]
+    fn test_partial_closes_share_reason_and_entry_fees() {
+        let mut attributor = TradeAttributor::new();
+        attributor.on_entry_fill(market(), EntrySource::Taker, dec!(0.2), true);
+        let mut position = Position::new(
+            market(),
+            OrderSide::Buy,
+            Size::new(dec!(2)),
+            Price::new(dec!(100)),
+            1_000,
+        );
+        position.funding_usd = dec!(0.4);
+
+        let first = attributor.on_close(&position, closing(dec!(101), dec!(1), Some("TimeStop")));
+        assert_eq!(first.entry_source, "taker");
+        assert_eq!(first.exit_reason, "TimeStop");
+        assert_eq!(first.hold_time_ms, 10_000);
+        assert!((first.gross_pnl_usd - 1.0).abs() < 1e-9);
+        assert!((first.gross_pnl_bps - 100.0).abs() < 1e-9);
+        assert!((first.fees_usd - 0.15).abs() < 1e-9);
+        assert!((first.funding_usd - 0.2).abs() < 1e-9);
+        assert_eq!((first.mae_bps, first.mae_usd), (0.0, 0.0));
+        assert!((first.net_pnl_usd - 0.65).abs() < 1e-9);
+
+        // Dipped to -2% before the close; the exits were better
+        attributor.on_price(&position, Price::new(dec!(98)));
+        attributor.on_price(&position, Price::new(dec!(99.5)));
+
+        // Second fill of the same close: guard already released
+        let remainder = Position {
+            size: Size::new(dec!(1)),
+            funding_usd: dec!(0.2),
+            ..position
+        };
+        let second = attributor.on_close(&remainder, closing(dec!(99), dec!(1), None));
+        assert_eq!(second.exit_reason, "TimeStop");
+        assert!((second.mae_bps + 200.0).abs() < 1e-9);
+        assert!((second.mae_usd + 4.0).abs() < 1e-9);
+        assert!((second.gross_pnl_usd + 1.0).abs() < 1e-9);
+        assert!((second.fees_usd - 0.15).abs() < 1e-9);
+        assert!(attributor.entries.is_empty());
+    }
+
+    #[test]
+    fn test_unclaimed_close_reasons() {
+        let mut attributor = TradeAttributor::new();
+        attributor.on_entry_fill(market(), EntrySource::Mm, dec!(-0.01), true);
+        let position = Position::new(
+            market(),
+            OrderSide::Sell,
+            Size::new(dec!(1)),
+            Price::new(dec!(100)),
+            1_000,
+        );
+
+        let mut fill = closing(dec!(99), dec!(1), None);
+        fill.is_mm_fill = true;
+        let trade = attributor.on_close(&position, fill);
+        assert_eq!(trade.entry_source, "mm");
+        assert_eq!(trade.exit_reason, "MmQuote");
+        assert_eq!(trade.side, "short");
+        assert_eq!(trade.mae_bps, 0.0);
+        assert!((trade.fees_usd - 0.04).abs() < 1e-9);
+
+        // Position the attributor never saw open (e.g. from a resync)
+        let trade = attributor.on_close(&position, closing(dec!(101), dec!(1), None));
+        assert_eq!(trade.entry_source, "unknown");
+        assert_eq!(trade.exit_reason, "Manual");
+        // The closing fill itself is the worst price seen
+        assert!((trade.mae_bps + 100.0).abs() < 1e-9);
+        assert!((trade.fees_usd - 0.05).abs() < 1e-9);
+    }
+}
@@ -110,8 +110,10 @@ async fn main() -> Result<()> {
         action_type: "order".to_string(),
         orders: Some(vec![order]),
         cancels: None,
+        modifies: None,
         grouping: Some("na".to_string()),
         builder: None,
+        time: None,
     };
 
     // Use current timestamp as nonce (standard SDK behavior)
@@ -1,16 +1,19 @@
 //! Application configuration.
 
 use crate::error::{AppError, AppResult};
+use chrono::NaiveTime;
 use hip3_dashboard::DashboardConfig;
-use hip3_detector::DetectorConfig;
+use hip3_detector::{DetectorConfig, LeadLagConfig, SlippageConfig, StrategyConfig};
 use hip3_mm::MakerConfig;
+use hip3_registry::FeeRefreshConfig;
 use hip3_risk::{
-    BurstSignalConfig, CorrelationCooldownConfig, CorrelationPositionConfig, MarketHealthConfig,
-    MaxDrawdownConfig, RiskGateConfig,
+    BlackoutWindow, BurstSignalConfig, CorrelationCooldownConfig, CorrelationPositionConfig,
+    MarketHealthConfig, MaxDrawdownConfig, RiskGateConfig,
 };
 use hip3_ws::{ConnectionConfig, SubscriptionTarget};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 
 /// Operating mode.
@@ -22,6 +25,11 @@ pub enum OperatingMode {
     Observation,
     /// Phase B: Live trading enabled.
     Trading,
+    /// Full trading pipeline against a simulated exchange (no real orders).
+    Paper,
+    /// Full executor pipeline up to the signed action, which is logged with
+    /// its predicted outcome instead of sent (no real orders).
+    Shadow,
 }
 
 /// Market configuration with coin symbol mapping.
@@ -37,6 +45,35 @@ pub struct MarketConfig {
     /// threshold_bps = taker_fee + slippage + min_edge
     #[serde(default)]
     pub threshold_bps: Option<u32>,
+    /// Per-market BUY threshold in bps. Overrides `threshold_bps` for longs.
+    #[serde(default)]
+    pub threshold_bps_long: Option<u32>,
+    /// Per-market SELL threshold in bps. Overrides `threshold_bps` for shorts.
+    #[serde(default)]
+    pub threshold_bps_short: Option<u32>,
+    /// Per-market minimum cross duration (ms). Overrides
+    /// `detector.min_cross_duration_ms`.
+    #[serde(default)]
+    pub min_cross_duration_ms: Option<u64>,
+    /// Per-market detection blackout windows (UTC), e.g. around FX fixings
+    /// or the COMEX open. Applied on top of `risk_gate.blackout_windows`.
+    #[serde(default)]
+    pub blackout_windows: Vec<BlackoutWindow>,
+    /// Per-market max slippage of the limit price at submit time (bps).
+    /// Overrides `slippage_guard.max_slippage_bps`.
+    #[serde(default)]
+    pub max_slippage_bps: Option<u32>,
+    /// Per-market realized volatility threshold (bps). Overrides
+    /// `risk.max_realized_vol_bps`.
+    #[serde(default)]
+    pub max_realized_vol_bps: Option<u32>,
+}
+
+impl MarketConfig {
+    /// The blackout window containing `time` (UTC), if any.
+    pub fn active_blackout(&self, time: NaiveTime) -> Option<&BlackoutWindow> {
+        self.blackout_windows.iter().find(|w| w.contains(time))
+    }
 }
 
 /// Time stop configuration for automatic position exit.
@@ -54,6 +91,20 @@ pub struct TimeStopConfig {
     /// Slippage tolerance for flatten orders (bps). Default: 50 (0.5%).
     #[serde(default = "default_slippage_bps")]
     pub slippage_bps: u64,
+    /// Scale the threshold with each market's realized volatility
+    /// (from the regime classifier). Default: false.
+    #[serde(default)]
+    pub vol_scaling_enabled: bool,
+    /// Realized volatility (bps) at which `threshold_ms` applies unchanged;
+    /// higher volatility shortens it, lower extends it. Default: 15.
+    #[serde(default = "default_time_stop_vol_reference_bps")]
+    pub vol_reference_bps: Decimal,
+    /// Floor of the volatility-scaled threshold (ms). Default: 10,000.
+    #[serde(default = "default_time_stop_min_threshold_ms")]
+    pub min_threshold_ms: u64,
+    /// Ceiling of the volatility-scaled threshold (ms). Default: 90,000.
+    #[serde(default = "default_time_stop_max_threshold_ms")]
+    pub max_threshold_ms: u64,
 }
 
 fn default_time_stop_threshold_ms() -> u64 {
@@ -72,6 +123,18 @@ fn default_slippage_bps() -> u64 {
     50
 }
 
+fn default_time_stop_vol_reference_bps() -> Decimal {
+    Decimal::from(15)
+}
+
+fn default_time_stop_min_threshold_ms() -> u64 {
+    10_000
+}
+
+fn default_time_stop_max_threshold_ms() -> u64 {
+    90_000
+}
+
 impl Default for TimeStopConfig {
     fn default() -> Self {
         Self {
@@ -79,6 +142,10 @@ impl Default for TimeStopConfig {
             reduce_only_timeout_ms: default_reduce_only_timeout_ms(),
             check_interval_ms: default_check_interval_ms(),
             slippage_bps: default_slippage_bps(),
+            vol_scaling_enabled: false,
+            vol_reference_bps: default_time_stop_vol_reference_bps(),
+            min_threshold_ms: default_time_stop_min_threshold_ms(),
+            max_threshold_ms: default_time_stop_max_threshold_ms(),
         }
     }
 }
@@ -169,6 +236,12 @@ pub struct MarkRegressionConfig {
     /// Minimum decay factor (0.0-1.0). Default: 0.2.
     #[serde(default = "default_mark_regression_min_decay_factor")]
     pub min_decay_factor: f64,
+
+    // --- Break-even stop ---
+    /// Flatten on regression to entry plus fees once PnL reached the
+    /// profile's activation threshold. Default: disabled.
+    #[serde(default)]
+    pub break_even: hip3_position::BreakEvenConfig,
 }
 
 fn default_mark_regression_quick_profit_min_holding_ms() -> u64 {
@@ -234,6 +307,7 @@ impl Default for MarkRegressionConfig {
             time_decay_enabled: false,
             decay_start_ms: default_mark_regression_decay_start_ms(),
             min_decay_factor: default_mark_regression_min_decay_factor(),
+            break_even: hip3_position::BreakEvenConfig::default(),
         }
     }
 }
@@ -273,16 +347,133 @@ pub struct ExecutorConfig {
     /// Default: 20ms (optimized from original 100ms for edge erosion reduction).
     #[serde(default = "default_batch_interval_ms")]
     pub batch_interval_ms: u64,
+    /// Minimum signal confidence (0.0-1.0) the executor accepts.
+    /// Default: 0 (disabled).
+    #[serde(default)]
+    pub min_confidence: Decimal,
+    /// Signals with net edge at or below this (bps) enter with a resting
+    /// ALO order at the passive touch instead of IOC.
+    /// Default: 0 (IOC only).
+    #[serde(default)]
+    pub alo_max_net_edge_bps: Decimal,
+    /// Cancel a resting ALO entry after this long (ms).
+    /// Default: 2000.
+    #[serde(default = "default_alo_timeout_ms")]
+    pub alo_timeout_ms: u64,
+    /// New orders per market per budget interval before the market draws on
+    /// the shared pool. Default: 0 (global budget only).
+    #[serde(default)]
+    pub market_order_quota: u32,
+    /// Overflow orders per budget interval shared by all markets once their
+    /// own quota is spent. Default: 20.
+    #[serde(default = "default_shared_order_pool")]
+    pub shared_order_pool: u32,
+    /// Scale the batch interval with queue pressure: slow ticks while idle,
+    /// down to `batch_interval_floor_ms` under backlog. Default: false.
+    #[serde(default)]
+    pub adaptive_batch_interval: bool,
+    /// Fastest adaptive batch interval (ms). Default: 5.
+    #[serde(default = "default_batch_interval_floor_ms")]
+    pub batch_interval_floor_ms: u64,
+    /// Adaptive batch interval while all queues are empty (ms). Default: 250.
+    #[serde(default = "default_batch_idle_interval_ms")]
+    pub batch_idle_interval_ms: u64,
+    /// Queued items at which the adaptive interval reaches the floor. Default: 10.
+    #[serde(default = "default_batch_backlog_threshold")]
+    pub batch_backlog_threshold: usize,
+    /// Cancel all open orders over REST when the WebSocket drops or HardStop
+    /// fires while it is down. Default: false.
+    #[serde(default)]
+    pub rest_cancel_fallback: bool,
+    /// Hold posts whose responses were lost in a disconnect and resolve them
+    /// against the exchange's order status on reconnect. Default: false.
+    #[serde(default)]
+    pub reconcile_on_reconnect: bool,
+    /// Signals with net edge (bps) at or above this are batched ahead of
+    /// weaker ones. Default: 0 (every signal in the strong tier).
+    #[serde(default)]
+    pub strong_signal_min_net_edge_bps: Decimal,
+    /// Maximum strong-signal orders per batch. Default: 50.
+    #[serde(default = "default_strong_tier_budget")]
+    pub strong_tier_budget: usize,
+    /// Maximum weak-signal orders (slices, retries) per batch. Default: 50.
+    #[serde(default = "default_weak_tier_budget")]
+    pub weak_tier_budget: usize,
+    /// Maximum MM quote orders per batch. Default: 25.
+    #[serde(default = "default_quote_tier_budget")]
+    pub quote_tier_budget: usize,
+    /// Batches a waiting order tier may be passed over before it is served
+    /// ahead of the others (reduce-only exits still go first).
+    /// Default: 10 (0 = strict priority).
+    #[serde(default = "default_starvation_ticks")]
+    pub starvation_ticks: u32,
+    /// Gates that only record the blocks they would make instead of
+    /// rejecting (see `hip3_executor::OBSERVABLE_GATES`). Default: none.
+    #[serde(default)]
+    pub observe_only_gates: Vec<String>,
 }
 
 fn default_batch_interval_ms() -> u64 {
     20 // Optimized from 100ms - reduces average latency from 50ms to 10ms
 }
 
+fn default_alo_timeout_ms() -> u64 {
+    2_000
+}
+
+fn default_shared_order_pool() -> u32 {
+    20
+}
+
+fn default_batch_interval_floor_ms() -> u64 {
+    5
+}
+
+fn default_batch_idle_interval_ms() -> u64 {
+    250
+}
+
+fn default_batch_backlog_threshold() -> usize {
+    10
+}
+
+fn default_strong_tier_budget() -> usize {
+    50
+}
+
+fn default_weak_tier_budget() -> usize {
+    50
+}
+
+fn default_quote_tier_budget() -> usize {
+    25
+}
+
+fn default_starvation_ticks() -> u32 {
+    10
+}
+
 impl Default for ExecutorConfig {
     fn default() -> Self {
         Self {
             batch_interval_ms: default_batch_interval_ms(),
+            min_confidence: Decimal::ZERO,
+            alo_max_net_edge_bps: Decimal::ZERO,
+            alo_timeout_ms: default_alo_timeout_ms(),
+            market_order_quota: 0,
+            shared_order_pool: default_shared_order_pool(),
+            adaptive_batch_interval: false,
+            batch_interval_floor_ms: default_batch_interval_floor_ms(),
+            batch_idle_interval_ms: default_batch_idle_interval_ms(),
+            batch_backlog_threshold: default_batch_backlog_threshold(),
+            rest_cancel_fallback: false,
+            reconcile_on_reconnect: false,
+            strong_signal_min_net_edge_bps: Decimal::ZERO,
+            strong_tier_budget: default_strong_tier_budget(),
+            weak_tier_budget: default_weak_tier_budget(),
+            quote_tier_budget: default_quote_tier_budget(),
+            starvation_ticks: default_starvation_ticks(),
+            observe_only_gates: Vec::new(),
         }
     }
 }
@@ -313,6 +504,41 @@ impl Default for DynamicSizingConfig {
     }
 }
 
+/// Position scaling (pyramiding) configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScalingConfig {
+    /// Whether add-on entries into open positions are allowed. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Add-on entries per market on top of the initial one. Default: 2.
+    #[serde(default = "default_max_add_ons")]
+    pub max_add_ons: usize,
+
+    /// Edge (bps) an add-on signal needs above the position's entry edge.
+    /// Default: 5
+    #[serde(default = "default_add_on_min_edge_improvement_bps")]
+    pub min_edge_improvement_bps: Decimal,
+}
+
+fn default_max_add_ons() -> usize {
+    2
+}
+
+fn default_add_on_min_edge_improvement_bps() -> Decimal {
+    Decimal::from(5)
+}
+
+impl Default for ScalingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_add_ons: default_max_add_ons(),
+            min_edge_improvement_bps: default_add_on_min_edge_improvement_bps(),
+        }
+    }
+}
+
 /// Position management configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PositionConfig {
@@ -340,6 +566,10 @@ pub struct PositionConfig {
     /// Dynamic sizing configuration based on account balance.
     #[serde(default)]
     pub dynamic_sizing: DynamicSizingConfig,
+
+    /// Add-on entries into open positions, capped by max_notional_per_market.
+    #[serde(default)]
+    pub scaling: ScalingConfig,
 }
 
 fn default_max_concurrent_positions() -> usize {
@@ -366,6 +596,68 @@ impl Default for PositionConfig {
             max_notional_per_market: default_max_notional_per_market(),
             position_resync_interval_secs: default_position_resync_interval_secs(),
             dynamic_sizing: DynamicSizingConfig::default(),
+            scaling: ScalingConfig::default(),
+        }
+    }
+}
+
+/// Threshold auto-calibration from EdgeTracker edge percentiles.
+///
+/// At the end of each EdgeTracker period, markets with enough positive edge
+/// samples get their entry threshold reset to the target percentile of those
+/// samples, clamped to the configured bounds. The calibrated value replaces
+/// the static per-market `threshold_bps` for that market.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThresholdCalibrationConfig {
+    /// Whether auto-calibration is enabled. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Percentile of observed positive edges used as threshold (0-100).
+    /// Default: 95
+    #[serde(default = "default_calibration_percentile")]
+    pub target_percentile: f64,
+
+    /// Minimum edge samples in a period before a market is recalibrated.
+    /// Default: 50
+    #[serde(default = "default_calibration_min_samples")]
+    pub min_samples: usize,
+
+    /// Lower bound for calibrated thresholds (bps).
+    /// Default: 10
+    #[serde(default = "default_calibration_min_threshold_bps")]
+    pub min_threshold_bps: Decimal,
+
+    /// Upper bound for calibrated thresholds (bps).
+    /// Default: 100
+    #[serde(default = "default_calibration_max_threshold_bps")]
+    pub max_threshold_bps: Decimal,
+}
+
+fn default_calibration_percentile() -> f64 {
+    95.0
+}
+
+fn default_calibration_min_samples() -> usize {
+    50
+}
+
+fn default_calibration_min_threshold_bps() -> Decimal {
+    Decimal::from(10)
+}
+
+fn default_calibration_max_threshold_bps() -> Decimal {
+    Decimal::from(100)
+}
+
+impl Default for ThresholdCalibrationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_percentile: default_calibration_percentile(),
+            min_samples: default_calibration_min_samples(),
+            min_threshold_bps: default_calibration_min_threshold_bps(),
+            max_threshold_bps: default_calibration_max_threshold_bps(),
         }
     }
 }
@@ -397,9 +689,39 @@ pub struct AppConfig {
     /// Detector configuration.
     #[serde(default)]
     pub detector: DetectorConfig,
+    /// Cross-market lead-lag detection (reference market as leading indicator).
+    #[serde(default)]
+    pub lead_lag: LeadLagConfig,
+    /// Detection strategy selection (default and per-market, in priority order).
+    #[serde(default)]
+    pub strategies: StrategyConfig,
+    /// Per-market threshold auto-calibration from observed edges.
+    #[serde(default)]
+    pub threshold_calibration: ThresholdCalibrationConfig,
+    /// Per-market slippage learned from fills.
+    #[serde(default)]
+    pub slippage: SlippageConfig,
     /// Persistence configuration.
     #[serde(default)]
     pub persistence: PersistenceConfig,
+    /// Warm-start state snapshot configuration.
+    #[serde(default)]
+    pub warm_state: WarmStateConfig,
+    /// Position state snapshot for crash recovery (Trading mode only).
+    #[serde(default)]
+    pub position_state: PositionStateConfig,
+    /// Nonce persistence across restarts (Trading mode only).
+    #[serde(default)]
+    pub nonce: NonceConfig,
+    /// Loss limit persistence across restarts (Trading mode only).
+    #[serde(default)]
+    pub loss_limit_state: LossLimitStateConfig,
+    /// Reference prices for the oracle sanity check.
+    #[serde(default)]
+    pub reference_prices: ReferencePriceConfig,
+    /// Periodic user fee tier refresh.
+    #[serde(default)]
+    pub fee_refresh: FeeRefreshConfig,
     /// Telemetry configuration.
     #[serde(default)]
     pub telemetry: TelemetryConfig,
@@ -412,21 +734,63 @@ pub struct AppConfig {
     /// Oracle movement tracking configuration.
     #[serde(default)]
     pub oracle_tracking: Option<hip3_feed::OracleTrackerConfig>,
+    /// Volatility regime classification (used when `detector.regime_enabled`).
+    #[serde(default)]
+    pub regime: hip3_feed::RegimeConfig,
     /// Oracle-driven exit configuration (Trading mode only).
     #[serde(default)]
     pub oracle_exit: Option<hip3_position::OracleExitConfig>,
+    /// Hedging of xyz exposure on L1 perps (Trading mode only).
+    #[serde(default)]
+    pub hedge: hip3_position::HedgeConfig,
+    /// Pluggable exit strategies per exit profile (Trading mode only).
+    #[serde(default)]
+    pub exit_strategies: hip3_position::ExitStrategiesConfig,
+    /// OCO exits: resting take-profit linked to a synthetic stop (Trading mode only).
+    #[serde(default)]
+    pub oco: hip3_position::OcoConfig,
+    /// Drift checks of local positions on the periodic resync (Trading mode only).
+    #[serde(default)]
+    pub reconciliation: hip3_position::ReconcileConfig,
+    /// Escalating pricing of HardStop flatten retries (Trading mode only).
+    #[serde(default)]
+    pub flatten_escalation: hip3_position::FlattenEscalationConfig,
     /// Risk monitor configuration (Trading mode only).
     #[serde(default)]
     pub risk_monitor: RiskMonitorConfig,
+    /// Auto-recovery of hard stops whose condition has cleared (Trading mode only).
+    #[serde(default)]
+    pub hard_stop_recovery: hip3_executor::HardStopRecoveryConfig,
     /// P2-3: MaxDrawdown gate configuration.
     #[serde(default)]
     pub max_drawdown: MaxDrawdownConfig,
+    /// Equity drawdown gate on the polled account value.
+    #[serde(default)]
+    pub equity_drawdown: hip3_risk::EquityDrawdownConfig,
+    /// Entry block (and MM spread widening) while p95 latency is degraded.
+    #[serde(default)]
+    pub latency_gate: hip3_risk::LatencyGateConfig,
+    /// New order block on open order count and cancel failures per market.
+    #[serde(default)]
+    pub open_orders: hip3_risk::OpenOrderGateConfig,
+    /// Per-market trading disable after repeated exchange rejections.
+    #[serde(default)]
+    pub reject_breaker: hip3_risk::RejectBreakerConfig,
+    /// Underlying market hours per asset class (entries and MM quoting).
+    #[serde(default)]
+    pub trading_calendar: hip3_core::TradingCalendarConfig,
     /// P2-4: Correlation cooldown gate configuration.
     #[serde(default)]
     pub correlation_cooldown: CorrelationCooldownConfig,
     /// P3-3: Correlation-weighted position limit configuration.
     #[serde(default)]
     pub correlation_position: CorrelationPositionConfig,
+    /// Net exposure limits per underlying/correlation group.
+    #[serde(default)]
+    pub netting: hip3_risk::NettingConfig,
+    /// Per-market shares of the notional limit and daily loss budget.
+    #[serde(default)]
+    pub risk_budget: hip3_risk::RiskBudgetConfig,
     /// Burst signal rate limiting configuration.
     #[serde(default)]
     pub burst_signal: BurstSignalConfig,
@@ -439,9 +803,36 @@ pub struct AppConfig {
     /// Sprint 3 P2-E: Market health tracker configuration.
     #[serde(default)]
     pub market_health: MarketHealthConfig,
+    /// Liquidation distance monitor and emergency de-risk (Trading mode only).
+    #[serde(default)]
+    pub liquidation: hip3_risk::LiquidationConfig,
     /// Executor configuration (Trading mode only).
     #[serde(default)]
     pub executor: ExecutorConfig,
+    /// Slicing of large entries into timed child IOCs (Trading mode only).
+    #[serde(default)]
+    pub slicing: hip3_executor::SlicingConfig,
+    /// Retry of transiently rejected orders (Trading mode only).
+    #[serde(default)]
+    pub retry: hip3_executor::RetryConfig,
+    /// Simulated exchange for Paper mode.
+    #[serde(default)]
+    pub paper: hip3_executor::PaperConfig,
+    /// Submit-time slippage guard of new IOC orders.
+    #[serde(default)]
+    pub slippage_guard: hip3_executor::SlippageGuardConfig,
+    /// Absolute per-order notional and price sanity gate.
+    #[serde(default)]
+    pub fat_finger: hip3_executor::FatFingerConfig,
+    /// Scheduled-cancel dead man's switch (Trading mode only).
+    #[serde(default)]
+    pub scheduled_cancel: hip3_executor::ScheduledCancelConfig,
+    /// Sweeper of orders stuck pending without an orderUpdate (Trading mode only).
+    #[serde(default)]
+    pub order_sweep: hip3_executor::OrderSweepConfig,
+    /// REST submission of orders when the WS path is saturated (Trading mode only).
+    #[serde(default)]
+    pub rest_fallback: hip3_executor::RestFallbackConfig,
     /// Dashboard configuration.
     #[serde(default)]
     pub dashboard: DashboardConfig,
@@ -468,11 +859,68 @@ pub struct AppConfig {
     /// If Some, trading mode will load the private key from environment variable.
     #[serde(default)]
     pub private_key: Option<String>,
+    /// Additional signing keys (dedicated maker key, standby keys).
+    #[serde(default)]
+    pub keys: SignerKeysConfig,
+    /// Per-market vault (sub-account) routing.
+    #[serde(default)]
+    pub vault_routing: VaultRoutingConfig,
+    /// Builder code (fee share) attached to every order.
+    #[serde(default)]
+    pub builder: BuilderConfig,
     /// Market making configuration (weekend MM strategy).
     #[serde(default)]
     pub maker: MakerConfig,
 }
 
+/// Additional agent keys, each loaded from an environment variable.
+///
+/// Standby keys are promoted in order via the dashboard's
+/// `POST /api/admin/rotate-key` endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SignerKeysConfig {
+    /// Env var with a dedicated key for resting orders and amendments.
+    /// None = sign them with the trading key.
+    #[serde(default)]
+    pub maker_key_env: Option<String>,
+    /// Env vars with hot-standby keys for rotation.
+    #[serde(default)]
+    pub standby_key_envs: Vec<String>,
+}
+
+/// Routes order strategies to vault sub-accounts, overriding `vault_address`.
+///
+/// Routing is per strategy: an order's amendments and cancels go through the
+/// account the order was placed on. A maker account no other strategy
+/// trades on keeps its own books: its fills and positions belong to the MM
+/// inventory, not the taker position tracker.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VaultRoutingConfig {
+    /// Vault for market-making quotes and MM flattens. Taker entries never
+    /// route here. None = `vault_address`.
+    #[serde(default)]
+    pub maker_vault: Option<String>,
+    /// Vault for taker entries, exits, hedges and risk flattens.
+    /// None = `vault_address`.
+    #[serde(default)]
+    pub taker_vault: Option<String>,
+}
+
+/// Builder code attached to every order.
+///
+/// The builder fee is added to the detector's costs so net edge stays
+/// correct. In Trading mode the user's approval of the fee is checked at
+/// startup (`approveBuilderFee` must have been signed beforehand).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuilderConfig {
+    /// Builder address (0x...). None = no builder code.
+    #[serde(default)]
+    pub address: Option<String>,
+    /// Builder fee in tenths of a basis point (10 = 1 bp). Default: 0.
+    #[serde(default)]
+    pub fee: u64,
+}
+
 fn default_info_url() -> String {
     "https://api.hyperliquid.xyz/info".to_string()
 }
@@ -490,6 +938,38 @@ pub struct WsConfig {
     pub reconnect_base_delay_ms: u64,
     /// Heartbeat interval (ms).
     pub heartbeat_interval_ms: u64,
+    /// Subscribe to l2Book per market for book depth consumers. Also
+    /// subscribed when `detector.l2_depth_enabled` is set.
+    #[serde(default)]
+    pub subscribe_l2_book: bool,
+    /// Subscribe to the public trades channel per market (trade tape:
+    /// volume, VWAP, aggressor imbalance).
+    #[serde(default)]
+    pub subscribe_trades: bool,
+    /// Subscribe to userEvents and non-funding ledger updates (Trading
+    /// mode): liquidations hard-stop, funding accrues on positions, and
+    /// transfers adjust the sizing balance. Replaces userFundings.
+    #[serde(default)]
+    pub subscribe_user_events: bool,
+    /// Ping on every heartbeat check to sample round-trip time
+    /// (`hip3_ws_rtt_ms`), not only on idle connections.
+    #[serde(default)]
+    pub rtt_probe: bool,
+    /// Run a hot-standby connection alongside the primary; posts fail over
+    /// to it when the primary is reconnecting or silent.
+    #[serde(default)]
+    pub dual_connection: bool,
+    /// Standby endpoint (default: same as the primary).
+    #[serde(default)]
+    pub standby_url: Option<String>,
+    /// Carry posts and order/fill/funding subscriptions on a dedicated
+    /// connection, so market-data floods and reconnects don't touch them.
+    /// With `dual_connection`, the order-flow connection has its own standby.
+    #[serde(default)]
+    pub split_order_flow: bool,
+    /// Order-flow endpoint (default: same as the primary).
+    #[serde(default)]
+    pub order_flow_url: Option<String>,
 }
 
 impl Default for WsConfig {
@@ -498,6 +978,14 @@ impl Default for WsConfig {
             max_reconnect_attempts: 0,
             reconnect_base_delay_ms: 1000,
             heartbeat_interval_ms: 45000,
+            subscribe_l2_book: false,
+            subscribe_trades: false,
+            subscribe_user_events: false,
+            rtt_probe: false,
+            dual_connection: false,
+            standby_url: None,
+            split_order_flow: false,
+            order_flow_url: None,
         }
     }
 }
@@ -511,8 +999,19 @@ impl From<WsConfig> for ConnectionConfig {
             reconnect_max_delay_ms: 60000,
             heartbeat_interval_ms: cfg.heartbeat_interval_ms,
             heartbeat_timeout_ms: 10000,
-            subscriptions: Vec::new(), // Set separately from markets
-            user_address: None,        // Set separately for Trading mode
+            subscriptions: Vec::new(),        // Set separately from markets
+            user_address: None,               // Set separately for Trading mode
+            reference_coins: Vec::new(),      // Set separately from lead_lag config
+            extra_user_addresses: Vec::new(), // Set separately from vault routing
+            // Also enabled by the detector config
+            subscribe_l2_book: cfg.subscribe_l2_book,
+            subscribe_trades: cfg.subscribe_trades,
+            subscribe_user_events: cfg.subscribe_user_events,
+            rtt_probe: cfg.rtt_probe,
+            dual_connection: cfg.dual_connection,
+            standby_url: cfg.standby_url,
+            split_order_flow: cfg.split_order_flow,
+            order_flow_url: cfg.order_flow_url,
         }
     }
 }
@@ -546,6 +1045,22 @@ impl AppConfig {
         self.mode == OperatingMode::Observation
     }
 
+    /// REST `/exchange` endpoint, derived from `info_url`.
+    pub fn exchange_url(&self) -> String {
+        match self.info_url.strip_suffix("/info") {
+            Some(base) => format!("{base}/exchange"),
+            None => format!("{}/exchange", self.info_url.trim_end_matches('/')),
+        }
+    }
+
+    /// Check if the executor pipeline runs (Trading, Paper or Shadow mode).
+    pub fn executes_orders(&self) -> bool {
+        matches!(
+            self.mode,
+            OperatingMode::Trading | OperatingMode::Paper | OperatingMode::Shadow
+        )
+    }
+
     /// Build subscription targets from market configuration.
     ///
     /// # Panics
@@ -600,6 +1115,96 @@ pub struct PersistenceConfig {
     pub data_dir: String,
     /// Buffer size before flush.
     pub buffer_size: usize,
+    /// Record raw market-data messages for `hip3-bot replay`. Default: false.
+    #[serde(default)]
+    pub record_feed: bool,
+    /// Directory for recorded feed files.
+    #[serde(default = "default_feed_dir")]
+    pub feed_dir: String,
+    /// Record every inbound WebSocket frame verbatim (with receive time and
+    /// connection) for byte-exact incident replay. Default: false.
+    #[serde(default)]
+    pub record_frames: bool,
+    /// Directory for recorded frame files.
+    #[serde(default = "default_frames_dir")]
+    pub frames_dir: String,
+    /// Frame file size after which the next file is started (MB).
+    /// Default: 256.
+    #[serde(default = "default_frames_max_file_mb")]
+    pub frames_max_file_mb: u64,
+    /// Record transaction cost analysis for entry fills and export TCA
+    /// metrics. Default: false.
+    #[serde(default)]
+    pub record_tca: bool,
+    /// Directory for TCA record files.
+    #[serde(default = "default_tca_dir")]
+    pub tca_dir: String,
+    /// Record every signed action in a hash-chained audit log. Default: false.
+    #[serde(default)]
+    pub record_signed_actions: bool,
+    /// Directory for signed-action audit files.
+    #[serde(default = "default_audit_dir")]
+    pub audit_dir: String,
+    /// Record every closing fill in the trade ledger. Default: false.
+    #[serde(default)]
+    pub record_trades: bool,
+    /// Directory for trade ledger files.
+    #[serde(default = "default_trades_dir")]
+    pub trades_dir: String,
+    /// Journal every position open, scale-in, partial close, and close.
+    /// Default: false.
+    #[serde(default)]
+    pub record_position_journal: bool,
+    /// Directory for position journal files.
+    #[serde(default = "default_journal_dir")]
+    pub journal_dir: String,
+    /// Record gate blocks, hard stops, recoveries and entry pauses.
+    /// Default: false.
+    #[serde(default)]
+    pub record_risk_events: bool,
+    /// Directory for risk event files.
+    #[serde(default = "default_risk_events_dir")]
+    pub risk_events_dir: String,
+    /// Repeats of a gate block in one market within this window are counted
+    /// instead of recorded (seconds). Default: 60.
+    #[serde(default = "default_risk_event_dedupe_secs")]
+    pub risk_event_dedupe_secs: u64,
+}
+
+fn default_feed_dir() -> String {
+    "./data/feed".to_string()
+}
+
+fn default_frames_dir() -> String {
+    "./data/frames".to_string()
+}
+
+fn default_frames_max_file_mb() -> u64 {
+    256
+}
+
+fn default_tca_dir() -> String {
+    "./data/tca".to_string()
+}
+
+fn default_audit_dir() -> String {
+    "./data/audit".to_string()
+}
+
+fn default_trades_dir() -> String {
+    "./data/trades".to_string()
+}
+
+fn default_journal_dir() -> String {
+    "./data/journal".to_string()
+}
+
+fn default_risk_events_dir() -> String {
+    "./data/risk_events".to_string()
+}
+
+fn default_risk_event_dedupe_secs() -> u64 {
+    60
 }
 
 impl Default for PersistenceConfig {
@@ -607,6 +1212,221 @@ impl Default for PersistenceConfig {
         Self {
             data_dir: "./data/signals".to_string(),
             buffer_size: 100,
+            record_feed: false,
+            feed_dir: default_feed_dir(),
+            record_frames: false,
+            frames_dir: default_frames_dir(),
+            frames_max_file_mb: default_frames_max_file_mb(),
+            record_tca: false,
+            tca_dir: default_tca_dir(),
+            record_signed_actions: false,
+            audit_dir: default_audit_dir(),
+            record_trades: false,
+            trades_dir: default_trades_dir(),
+            record_position_journal: false,
+            journal_dir: default_journal_dir(),
+            record_risk_events: false,
+            risk_events_dir: default_risk_events_dir(),
+            risk_event_dedupe_secs: default_risk_event_dedupe_secs(),
+        }
+    }
+}
+
+/// Secondary reference prices for the detector's oracle sanity check.
+///
+/// Polled from the info endpoint's `allMids` and matched to markets via
+/// `coins`. Only used when `detector.oracle_sanity_enabled` is set.
+///
+/// ```toml
+/// [reference_prices]
+/// coins = { "xyz:GOLD" = "PAXG" }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReferencePriceConfig {
+    /// Poll interval (ms). Default: 1000.
+    #[serde(default = "default_reference_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+    /// DEX to query (None = L1 perps).
+    #[serde(default)]
+    pub dex: Option<String>,
+    /// Market coin → reference coin in the `allMids` response.
+    #[serde(default)]
+    pub coins: HashMap<String, String>,
+}
+
+fn default_reference_poll_interval_ms() -> u64 {
+    1_000
+}
+
+impl Default for ReferencePriceConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval_ms: default_reference_poll_interval_ms(),
+            dex: None,
+            coins: HashMap::new(),
+        }
+    }
+}
+
+/// Warm-start state snapshot configuration.
+///
+/// Periodically saves spread EWMAs, cross durations and oracle move counts,
+/// and reloads them on startup so gates don't start cold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarmStateConfig {
+    /// Whether to save and restore warm state. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Snapshot file path.
+    #[serde(default = "default_warm_state_path")]
+    pub path: String,
+    /// Flush interval (seconds). Default: 60.
+    #[serde(default = "default_warm_state_flush_interval_secs")]
+    pub flush_interval_secs: u64,
+    /// Maximum snapshot age to restore (seconds). Default: 600.
+    #[serde(default = "default_warm_state_max_age_secs")]
+    pub max_age_secs: u64,
+}
+
+fn default_warm_state_path() -> String {
+    "./data/state/warm_state.json".to_string()
+}
+
+fn default_warm_state_flush_interval_secs() -> u64 {
+    60
+}
+
+fn default_warm_state_max_age_secs() -> u64 {
+    600 // 10 min
+}
+
+impl Default for WarmStateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default_warm_state_path(),
+            flush_interval_secs: default_warm_state_flush_interval_secs(),
+            max_age_secs: default_warm_state_max_age_secs(),
+        }
+    }
+}
+
+/// Position state snapshot configuration.
+///
+/// Periodically saves per-position metadata (entry time, entry edge, exit
+/// profile), pending reduce-only orders and flatten claims, and merges them
+/// with the API position sync on startup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionStateConfig {
+    /// Whether to save and restore position state. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Snapshot file path.
+    #[serde(default = "default_position_state_path")]
+    pub path: String,
+    /// Flush interval (seconds). Default: 5.
+    #[serde(default = "default_position_state_flush_interval_secs")]
+    pub flush_interval_secs: u64,
+    /// Maximum snapshot age to restore (seconds). Default: 3600.
+    #[serde(default = "default_position_state_max_age_secs")]
+    pub max_age_secs: u64,
+}
+
+fn default_position_state_path() -> String {
+    "./data/state/positions.json".to_string()
+}
+
+fn default_position_state_flush_interval_secs() -> u64 {
+    5
+}
+
+fn default_position_state_max_age_secs() -> u64 {
+    3600 // 1 hour
+}
+
+impl Default for PositionStateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default_position_state_path(),
+            flush_interval_secs: default_position_state_flush_interval_secs(),
+            max_age_secs: default_position_state_max_age_secs(),
+        }
+    }
+}
+
+/// Nonce persistence configuration.
+///
+/// Periodically saves the last used nonce and restores it on startup, so a
+/// fast restart doesn't reuse nonces the exchange has already seen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NonceConfig {
+    /// Whether to save and restore the last nonce. Default: false.
+    #[serde(default)]
+    pub persist: bool,
+    /// Snapshot file path.
+    #[serde(default = "default_nonce_path")]
+    pub path: String,
+    /// Flush interval (ms). Default: 1000.
+    #[serde(default = "default_nonce_flush_interval_ms")]
+    pub flush_interval_ms: u64,
+    /// Added to the restored nonce to cover nonces issued after the last
+    /// flush (ms). Should exceed `flush_interval_ms`. Default: 5000.
+    #[serde(default = "default_nonce_safety_offset_ms")]
+    pub safety_offset_ms: u64,
+}
+
+fn default_nonce_path() -> String {
+    "./data/state/nonce.json".to_string()
+}
+
+fn default_nonce_flush_interval_ms() -> u64 {
+    1000
+}
+
+fn default_nonce_safety_offset_ms() -> u64 {
+    5000
+}
+
+impl Default for NonceConfig {
+    fn default() -> Self {
+        Self {
+            persist: false,
+            path: default_nonce_path(),
+            flush_interval_ms: default_nonce_flush_interval_ms(),
+            safety_offset_ms: default_nonce_safety_offset_ms(),
+        }
+    }
+}
+
+/// Loss limit persistence configuration.
+///
+/// Saves the daily/weekly loss limit state and the per-market daily loss
+/// budgets on every realized close and restores them on startup, so a
+/// restart doesn't lift a latched limit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LossLimitStateConfig {
+    /// Whether to save and restore loss limit state. Default: true.
+    #[serde(default = "default_loss_limit_state_persist")]
+    pub persist: bool,
+    /// Snapshot file path.
+    #[serde(default = "default_loss_limit_state_path")]
+    pub path: String,
+}
+
+fn default_loss_limit_state_persist() -> bool {
+    true
+}
+
+fn default_loss_limit_state_path() -> String {
+    "./data/state/loss_limits.json".to_string()
+}
+
+impl Default for LossLimitStateConfig {
+    fn default() -> Self {
+        Self {
+            persist: default_loss_limit_state_persist(),
+            path: default_loss_limit_state_path(),
         }
     }
 }
@@ -640,19 +1460,51 @@ impl Default for AppConfig {
             websocket: WsConfig::default(),
             risk: RiskGateConfig::default(),
             detector: DetectorConfig::default(),
+            lead_lag: LeadLagConfig::default(),
+            strategies: StrategyConfig::default(),
+            threshold_calibration: ThresholdCalibrationConfig::default(),
+            slippage: SlippageConfig::default(),
             persistence: PersistenceConfig::default(),
+            warm_state: WarmStateConfig::default(),
+            position_state: PositionStateConfig::default(),
+            nonce: NonceConfig::default(),
+            loss_limit_state: LossLimitStateConfig::default(),
+            reference_prices: ReferencePriceConfig::default(),
+            fee_refresh: FeeRefreshConfig::default(),
             telemetry: TelemetryConfig::default(),
             time_stop: TimeStopConfig::default(),
             mark_regression: MarkRegressionConfig::default(),
+            hedge: hip3_position::HedgeConfig::default(),
+            exit_strategies: hip3_position::ExitStrategiesConfig::default(),
+            oco: hip3_position::OcoConfig::default(),
+            reconciliation: hip3_position::ReconcileConfig::default(),
+            flatten_escalation: hip3_position::FlattenEscalationConfig::default(),
             risk_monitor: RiskMonitorConfig::default(),
+            hard_stop_recovery: hip3_executor::HardStopRecoveryConfig::default(),
             max_drawdown: MaxDrawdownConfig::default(),
+            equity_drawdown: hip3_risk::EquityDrawdownConfig::default(),
+            latency_gate: hip3_risk::LatencyGateConfig::default(),
+            open_orders: hip3_risk::OpenOrderGateConfig::default(),
+            reject_breaker: hip3_risk::RejectBreakerConfig::default(),
+            trading_calendar: hip3_core::TradingCalendarConfig::default(),
             correlation_cooldown: CorrelationCooldownConfig::default(),
             correlation_position: CorrelationPositionConfig::default(),
+            netting: hip3_risk::NettingConfig::default(),
+            risk_budget: hip3_risk::RiskBudgetConfig::default(),
             burst_signal: BurstSignalConfig::default(),
             tilt_guard: hip3_risk::TiltGuardConfig::default(),
             re_entry_delay: hip3_risk::ReEntryDelayConfig::default(),
             market_health: MarketHealthConfig::default(),
+            liquidation: hip3_risk::LiquidationConfig::default(),
             executor: ExecutorConfig::default(),
+            slicing: hip3_executor::SlicingConfig::default(),
+            retry: hip3_executor::RetryConfig::default(),
+            paper: hip3_executor::PaperConfig::default(),
+            scheduled_cancel: hip3_executor::ScheduledCancelConfig::default(),
+            order_sweep: hip3_executor::OrderSweepConfig::default(),
+            rest_fallback: hip3_executor::RestFallbackConfig::default(),
+            slippage_guard: hip3_executor::SlippageGuardConfig::default(),
+            fat_finger: hip3_executor::FatFingerConfig::default(),
             dashboard: DashboardConfig::default(),
             position: PositionConfig::default(),
             user_address: None,
@@ -660,7 +1512,11 @@ impl Default for AppConfig {
             is_mainnet: None,
             vault_address: None,
             private_key: None,
+            keys: SignerKeysConfig::default(),
+            vault_routing: VaultRoutingConfig::default(),
+            builder: BuilderConfig::default(),
             oracle_tracking: None,
+            regime: hip3_feed::RegimeConfig::default(),
             oracle_exit: None,
             maker: MakerConfig::default(),
         }
@@ -675,6 +1531,7 @@ mod tests {
     fn test_default_config() {
         let config = AppConfig::default();
         assert!(config.is_observation_mode());
+        assert!(!config.executes_orders());
         // Markets are None by default (auto-discovery)
         assert!(config.markets.is_none());
         assert!(!config.has_markets());
@@ -687,11 +1544,34 @@ mod tests {
             asset_idx: 0,
             coin: "BTC".to_string(),
             threshold_bps: None,
+            threshold_bps_long: None,
+            threshold_bps_short: None,
+            min_cross_duration_ms: None,
+            blackout_windows: Vec::new(),
+            max_slippage_bps: None,
+            max_realized_vol_bps: None,
         }]);
         assert!(config.has_markets());
         assert_eq!(config.get_markets().len(), 1);
     }
 
+    #[test]
+    fn test_market_blackout_windows() {
+        let market: MarketConfig = toml::from_str(
+            r#"
+            asset_idx = 110026
+            coin = "xyz:GOLD"
+            blackout_windows = [{ start = "13:15", end = "13:35" }]
+            "#,
+        )
+        .unwrap();
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        assert!(market.active_blackout(at(13, 20)).is_some());
+        assert!(market.active_blackout(at(13, 35)).is_none());
+        assert!(market.active_blackout(at(9, 0)).is_none());
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = AppConfig::default();
@@ -699,4 +1579,45 @@ mod tests {
         assert!(toml_str.contains("mode"));
         assert!(toml_str.contains("xyz_pattern"));
     }
+
+    #[test]
+    fn test_paper_mode_executes_orders() {
+        let mode =
+            toml::from_str::<HashMap<String, OperatingMode>>("mode = \"paper\"").unwrap()["mode"];
+        let config = AppConfig {
+            mode,
+            ..Default::default()
+        };
+        assert!(config.executes_orders());
+        assert!(!config.is_observation_mode());
+        assert_eq!(config.paper.latency_ms, 50);
+    }
+
+    #[test]
+    fn test_shadow_mode_executes_orders() {
+        let mode =
+            toml::from_str::<HashMap<String, OperatingMode>>("mode = \"shadow\"").unwrap()["mode"];
+        let config = AppConfig {
+            mode,
+            ..Default::default()
+        };
+        assert_eq!(mode, OperatingMode::Shadow);
+        assert!(config.executes_orders());
+        assert!(!config.is_observation_mode());
+    }
+
+    #[test]
+    fn test_exchange_url_from_info_url() {
+        let mut config = AppConfig::default();
+        assert_eq!(
+            config.exchange_url(),
+            "https://api.hyperliquid.xyz/exchange"
+        );
+        config.info_url = "https://api.hyperliquid-testnet.xyz/".to_string();
+        assert_eq!(
+            config.exchange_url(),
+            "https://api.hyperliquid-testnet.xyz/exchange"
+        );
+        assert!(!config.executor.rest_cancel_fallback);
+    }
 }
@@ -13,7 +13,12 @@
 //! - Whether threshold is appropriately calibrated
 //! - Market activity patterns over time
 //! - Edge percentiles (P50/P75/P90/P99) for adaptive threshold tuning
+//!
+//! With [`ThresholdCalibrationConfig`] enabled, the tracker also closes the
+//! loop: at the end of each period it recomputes per-market thresholds from
+//! the target percentile, which the app feeds back into the detector.
 
+use crate::config::ThresholdCalibrationConfig;
 use hip3_core::MarketKey;
 use rust_decimal::Decimal;
 use std::collections::HashMap;
@@ -109,6 +114,10 @@ pub struct EdgeTracker {
     log_interval: Duration,
     /// Threshold for comparison logging.
     threshold_bps: Decimal,
+    /// Auto-calibration settings (None = disabled).
+    calibration: Option<ThresholdCalibrationConfig>,
+    /// Latest calibrated threshold per market (persists across periods).
+    calibrated: HashMap<MarketKey, Decimal>,
 }
 
 impl EdgeTracker {
@@ -123,9 +132,23 @@ impl EdgeTracker {
             last_log: Instant::now(),
             log_interval: Duration::from_secs(log_interval_secs),
             threshold_bps,
+            calibration: None,
+            calibrated: HashMap::new(),
         }
     }
 
+    /// Enable threshold auto-calibration.
+    #[must_use]
+    pub fn with_calibration(mut self, config: ThresholdCalibrationConfig) -> Self {
+        self.calibration = Some(config);
+        self
+    }
+
+    /// Latest calibrated threshold for a market, if any.
+    pub fn calibrated_threshold(&self, key: &MarketKey) -> Option<Decimal> {
+        self.calibrated.get(key).copied()
+    }
+
     /// Record an edge observation for a market.
     ///
     /// Called on each market check, regardless of whether edge exceeds threshold.
@@ -172,6 +195,7 @@ impl EdgeTracker {
         }
 
         self.log_stats();
+        self.recalibrate();
         self.reset();
         self.last_log = Instant::now();
         true
@@ -243,6 +267,44 @@ impl EdgeTracker {
         );
     }
 
+    /// Recompute calibrated thresholds from this period's samples.
+    ///
+    /// Markets below `min_samples` keep their previous calibrated value.
+    fn recalibrate(&mut self) {
+        let Some(ref cal) = self.calibration else {
+            return;
+        };
+
+        for (key, stats) in &self.stats {
+            if stats.edge_samples.len() < cal.min_samples {
+                continue;
+            }
+            let mut sorted = stats.edge_samples.clone();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let Some(p) = stats.percentile(&sorted, cal.target_percentile) else {
+                continue;
+            };
+            let Ok(raw) = Decimal::try_from(p) else {
+                continue;
+            };
+            let threshold = raw
+                .round_dp(1)
+                .max(cal.min_threshold_bps)
+                .min(cal.max_threshold_bps);
+            let previous = self.calibrated.insert(*key, threshold);
+
+            info!(
+                market = %key,
+                percentile = cal.target_percentile,
+                raw_bps = %raw.round_dp(1),
+                threshold_bps = %threshold,
+                previous_bps = ?previous,
+                samples = stats.edge_samples.len(),
+                "EdgeTracker: Threshold recalibrated"
+            );
+        }
+    }
+
     /// Reset all statistics for a new period.
     fn reset(&mut self) {
         for stats in self.stats.values_mut() {
@@ -289,9 +351,9 @@ mod tests {
 
         // With 10 samples [5,10,15,20,25,30,35,40,45,50]
         // P50 = index 4.5 -> 30 (rounded)
-        assert!(p50 >= 25.0 && p50 <= 30.0, "P50={}", p50);
-        assert!(p75 >= 35.0 && p75 <= 40.0, "P75={}", p75);
-        assert!(p90 >= 45.0 && p90 <= 50.0, "P90={}", p90);
+        assert!((25.0..=30.0).contains(&p50), "P50={}", p50);
+        assert!((35.0..=40.0).contains(&p75), "P75={}", p75);
+        assert!((45.0..=50.0).contains(&p90), "P90={}", p90);
         assert!((p99 - 50.0).abs() < 1.0, "P99={}", p99);
     }
 
@@ -301,7 +363,7 @@ mod tests {
         let key = MarketKey::new(DexId::XYZ, AssetId::new(0));
 
         // No data recorded
-        assert!(tracker.stats.get(&key).is_none());
+        assert!(!tracker.stats.contains_key(&key));
     }
 
     #[test]
@@ -319,4 +381,33 @@ mod tests {
         assert_eq!(stats.spread_ewma_bps, dec!(22));
         assert_eq!(stats.effective_threshold_bps, dec!(33));
     }
+
+    #[test]
+    fn test_recalibration_uses_percentile_with_bounds() {
+        let cal = ThresholdCalibrationConfig {
+            enabled: true,
+            target_percentile: 90.0,
+            min_samples: 10,
+            min_threshold_bps: dec!(10),
+            max_threshold_bps: dec!(30),
+        };
+        let mut tracker = EdgeTracker::new(60, dec!(40)).with_calibration(cal);
+        let key = MarketKey::new(DexId::XYZ, AssetId::new(0));
+        let sparse = MarketKey::new(DexId::XYZ, AssetId::new(1));
+
+        // 5..=50 bps: P90 = 45 → clamped to 30
+        for i in 1..=10 {
+            tracker.record_edge(key, Decimal::from(i * 5), Decimal::ZERO);
+        }
+        // Too few samples to calibrate
+        tracker.record_edge(sparse, dec!(20), Decimal::ZERO);
+
+        tracker.recalibrate();
+        assert_eq!(tracker.calibrated_threshold(&key), Some(dec!(30)));
+        assert_eq!(tracker.calibrated_threshold(&sparse), None);
+
+        // Calibrated value survives the period reset
+        tracker.reset();
+        assert_eq!(tracker.calibrated_threshold(&key), Some(dec!(30)));
+    }
 }
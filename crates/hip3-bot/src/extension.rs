@@ -0,0 +1,342 @@
+//! In-process extension point for private strategy add-ons.
+//!
+//! Extensions are registered on [`Application`](crate::Application) before
+//! `run()` and receive every domain event the main loop publishes (signals,
+//! order state changes, fills). They reply with advisory actions only:
+//!
+//! - [`AdvisoryAction::GateVote`]: vote on whether the signal that produced
+//!   the event may be executed. A single `Block` vote drops the signal.
+//! - [`AdvisoryAction::Annotate`]: attach a key/value note that is logged
+//!   alongside the event.
+//!
+//! Extensions never get direct access to the executor, so the built-in risk
+//! gates and HardStop remain authoritative; an extension can only make the
+//! bot *more* conservative.
+//!
+//! # Usage
+//!
+//! ```ignore
+//! struct MyFilter;
+//!
+//! impl Extension for MyFilter {
+//!     fn name(&self) -> &str {
+//!         "my_filter"
+//!     }
+//!
+//!     fn on_event(&self, event: &DomainEvent) -> Vec<AdvisoryAction> {
+//!         match event {
+//!             DomainEvent::Signal(s) if s.raw_edge_bps < dec!(50) => {
+//!                 vec![AdvisoryAction::block("edge below private floor")]
+//!             }
+//!             _ => Vec::new(),
+//!         }
+//!     }
+//! }
+//!
+//! let mut app = Application::new(config)?;
+//! app.register_extension(Arc::new(MyFilter));
+//! ```
+//!
+//! Handlers run synchronously on the main event loop, so they must be cheap
+//! and must not block. Anything heavier should hand the event off to its own
+//! task via a channel.
+
+use hip3_core::{ClientOrderId, MarketKey, OrderSide, OrderState, Price, Size};
+use hip3_detector::DislocationSignal;
+use std::sync::Arc;
+
+/// Domain event published to registered extensions.
+#[derive(Debug, Clone)]
+pub enum DomainEvent {
+    /// A dislocation signal passed detection and is about to be executed.
+    Signal(Box<DislocationSignal>),
+    /// An order changed state (from the orderUpdates channel).
+    OrderUpdate {
+        /// Market, if the coin could be resolved.
+        market: Option<MarketKey>,
+        /// Client order ID.
+        cloid: ClientOrderId,
+        /// Exchange order ID.
+        oid: u64,
+        /// Mapped order state.
+        state: OrderState,
+    },
+    /// A fill was received (from the userFills channel).
+    Fill {
+        /// Filled market.
+        market: MarketKey,
+        /// Fill side.
+        side: OrderSide,
+        /// Fill price.
+        price: Price,
+        /// Fill size.
+        size: Size,
+        /// Exchange fill timestamp (ms).
+        timestamp_ms: u64,
+        /// Client order ID, if present.
+        cloid: Option<ClientOrderId>,
+    },
+}
+
+impl DomainEvent {
+    /// Short event name for logging.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Signal(_) => "signal",
+            Self::OrderUpdate { .. } => "order_update",
+            Self::Fill { .. } => "fill",
+        }
+    }
+}
+
+/// Extension vote on a signal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GateVote {
+    /// No objection.
+    Allow,
+    /// Drop the signal.
+    Block {
+        /// Human-readable reason (logged).
+        reason: String,
+    },
+}
+
+/// Advisory action returned by an extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdvisoryAction {
+    /// Vote on the signal carried by the event.
+    /// Ignored for events other than [`DomainEvent::Signal`].
+    GateVote(GateVote),
+    /// Attach a key/value annotation to the event.
+    Annotate {
+        /// Annotation key.
+        key: String,
+        /// Annotation value.
+        value: String,
+    },
+}
+
+impl AdvisoryAction {
+    /// Convenience constructor for a blocking vote.
+    pub fn block(reason: impl Into<String>) -> Self {
+        Self::GateVote(GateVote::Block {
+            reason: reason.into(),
+        })
+    }
+
+    /// Convenience constructor for an annotation.
+    pub fn annotate(key: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::Annotate {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// In-process extension subscribed to the domain event bus.
+pub trait Extension: Send + Sync {
+    /// Stable name used in logs and block attributions.
+    fn name(&self) -> &str;
+
+    /// Handle a domain event and return advisory actions (possibly empty).
+    fn on_event(&self, event: &DomainEvent) -> Vec<AdvisoryAction>;
+}
+
+/// Annotation attributed to the extension that produced it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Annotation {
+    /// Extension name.
+    pub extension: String,
+    /// Annotation key.
+    pub key: String,
+    /// Annotation value.
+    pub value: String,
+}
+
+/// Aggregated result of publishing one event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PublishOutcome {
+    /// First blocking vote as (extension name, reason).
+    pub blocked_by: Option<(String, String)>,
+    /// All annotations in registration order.
+    pub annotations: Vec<Annotation>,
+}
+
+impl PublishOutcome {
+    /// Whether any extension voted to block.
+    pub fn is_blocked(&self) -> bool {
+        self.blocked_by.is_some()
+    }
+}
+
+/// Fan-out bus over registered extensions.
+#[derive(Default)]
+pub struct ExtensionBus {
+    extensions: Vec<Arc<dyn Extension>>,
+}
+
+impl ExtensionBus {
+    /// Create an empty bus.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an extension. Extensions are invoked in registration order.
+    pub fn register(&mut self, extension: Arc<dyn Extension>) {
+        self.extensions.push(extension);
+    }
+
+    /// Whether no extensions are registered.
+    ///
+    /// Callers use this to skip building events on the hot path.
+    pub fn is_empty(&self) -> bool {
+        self.extensions.is_empty()
+    }
+
+    /// Number of registered extensions.
+    pub fn len(&self) -> usize {
+        self.extensions.len()
+    }
+
+    /// Publish an event to all extensions and aggregate their actions.
+    ///
+    /// Every extension sees every event, even after one has blocked, so
+    /// that annotations and internal state stay consistent.
+    pub fn publish(&self, event: &DomainEvent) -> PublishOutcome {
+        let mut outcome = PublishOutcome::default();
+        let is_signal = matches!(event, DomainEvent::Signal(_));
+
+        for ext in &self.extensions {
+            for action in ext.on_event(event) {
+                match action {
+                    AdvisoryAction::GateVote(GateVote::Block { reason }) if is_signal => {
+                        if outcome.blocked_by.is_none() {
+                            outcome.blocked_by = Some((ext.name().to_string(), reason));
+                        }
+                    }
+                    AdvisoryAction::GateVote(_) => {}
+                    AdvisoryAction::Annotate { key, value } => {
+                        outcome.annotations.push(Annotation {
+                            extension: ext.name().to_string(),
+                            key,
+                            value,
+                        });
+                    }
+                }
+            }
+        }
+
+        outcome
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use hip3_detector::{FeeCalculator, SignalStrength};
+    use rust_decimal_macros::dec;
+
+    struct Fixed {
+        name: &'static str,
+        actions: Vec<AdvisoryAction>,
+    }
+
+    impl Extension for Fixed {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn on_event(&self, _event: &DomainEvent) -> Vec<AdvisoryAction> {
+            self.actions.clone()
+        }
+    }
+
+    fn fill_event() -> DomainEvent {
+        DomainEvent::Fill {
+            market: MarketKey::new(DexId::XYZ, AssetId::new(0)),
+            side: OrderSide::Buy,
+            price: Price::new(dec!(100)),
+            size: Size::new(dec!(1)),
+            timestamp_ms: 1_000,
+            cloid: None,
+        }
+    }
+
+    fn signal_event() -> DomainEvent {
+        DomainEvent::Signal(Box::new(DislocationSignal::new(
+            MarketKey::new(DexId::XYZ, AssetId::new(0)),
+            OrderSide::Buy,
+            dec!(30),
+            dec!(20),
+            SignalStrength::Medium,
+            Size::new(dec!(1)),
+            Price::new(dec!(100)),
+            Price::new(dec!(99.7)),
+            Size::new(dec!(5)),
+            FeeCalculator::with_defaults().metadata(),
+            dec!(0),
+            dec!(0.5),
+        )))
+    }
+
+    #[test]
+    fn test_empty_bus_publishes_nothing() {
+        let bus = ExtensionBus::new();
+        assert!(bus.is_empty());
+        assert_eq!(bus.publish(&fill_event()), PublishOutcome::default());
+    }
+
+    #[test]
+    fn test_annotations_collected_in_registration_order() {
+        let mut bus = ExtensionBus::new();
+        bus.register(Arc::new(Fixed {
+            name: "a",
+            actions: vec![AdvisoryAction::annotate("k1", "v1")],
+        }));
+        bus.register(Arc::new(Fixed {
+            name: "b",
+            actions: vec![AdvisoryAction::annotate("k2", "v2")],
+        }));
+
+        let outcome = bus.publish(&fill_event());
+        assert_eq!(bus.len(), 2);
+        assert_eq!(outcome.annotations.len(), 2);
+        assert_eq!(outcome.annotations[0].extension, "a");
+        assert_eq!(outcome.annotations[1].key, "k2");
+    }
+
+    #[test]
+    fn test_block_vote_ignored_for_non_signal_events() {
+        let mut bus = ExtensionBus::new();
+        bus.register(Arc::new(Fixed {
+            name: "blocker",
+            actions: vec![AdvisoryAction::block("no")],
+        }));
+
+        assert!(!bus.publish(&fill_event()).is_blocked());
+    }
+
+    #[test]
+    fn test_first_block_vote_wins_on_signal() {
+        let mut bus = ExtensionBus::new();
+        bus.register(Arc::new(Fixed {
+            name: "allow",
+            actions: vec![AdvisoryAction::GateVote(GateVote::Allow)],
+        }));
+        bus.register(Arc::new(Fixed {
+            name: "first",
+            actions: vec![AdvisoryAction::block("r1")],
+        }));
+        bus.register(Arc::new(Fixed {
+            name: "second",
+            actions: vec![AdvisoryAction::block("r2")],
+        }));
+
+        let outcome = bus.publish(&signal_event());
+        assert_eq!(
+            outcome.blocked_by,
+            Some(("first".to_string(), "r1".to_string()))
+        );
+    }
+}
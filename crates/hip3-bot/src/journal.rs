@@ -0,0 +1,205 @@
+//! Position lifecycle journaling.
+//!
+//! Turns a fill and the position it applies to into a
+//! [`PositionJournalRecord`]. The position after the fill is derived the
+//! same way `PositionTracker` applies fills (weighted average entry on
+//! scale-in, fill price as the entry of a flipped position), since the
+//! tracker applies the fill asynchronously.
+
+use hip3_core::{MarketKey, OrderSide, Price, Size};
+use hip3_persistence::{PositionEvent, PositionJournalRecord, TradeRecord};
+use hip3_position::Position;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// A fill to journal.
+#[derive(Debug, Clone)]
+pub struct JournalFill {
+    /// Market of the fill.
+    pub market: MarketKey,
+    /// Fill side.
+    pub side: OrderSide,
+    /// Fill price.
+    pub price: Price,
+    /// Fill size.
+    pub size: Size,
+    /// Client order ID of the filled order, if known.
+    pub cloid: Option<String>,
+    /// Exchange fill time (Unix ms).
+    pub timestamp_ms: u64,
+    /// How the position was entered (taker, mm).
+    pub entry_source: String,
+    /// Signal edge (bps) if the fill opens a position from a signal.
+    pub entry_edge_bps: Option<Decimal>,
+}
+
+/// Build the journal record of `fill` applied to `before`.
+///
+/// `trade` is the attributed closing trade of a reducing fill; it supplies
+/// the exit reason and realized PnL.
+#[must_use]
+pub fn journal_record(
+    before: Option<&Position>,
+    fill: JournalFill,
+    trade: Option<&TradeRecord>,
+) -> PositionJournalRecord {
+    let fill_size = fill.size.inner();
+    let event = PositionEvent::classify(
+        before.map(|p| (p.side, p.size.inner())),
+        fill.side,
+        fill_size,
+    );
+    let size_before = before.map_or(Decimal::ZERO, |p| p.size.inner());
+
+    let (side, size_after, entry_px, entry_edge_bps) = match (event, before) {
+        (PositionEvent::Scale, Some(p)) => {
+            let size_after = size_before + fill_size;
+            let entry_px =
+                (size_before * p.entry_price.inner() + fill_size * fill.price.inner()) / size_after;
+            (p.side, size_after, entry_px, p.entry_edge_bps)
+        }
+        (PositionEvent::PartialClose, Some(p)) => (
+            p.side,
+            size_before - fill_size,
+            p.entry_price.inner(),
+            p.entry_edge_bps,
+        ),
+        (PositionEvent::Close, Some(p)) => (
+            p.side,
+            Decimal::ZERO,
+            p.entry_price.inner(),
+            p.entry_edge_bps,
+        ),
+        (PositionEvent::Flip, _) => (fill.side, fill_size - size_before, fill.price.inner(), None),
+        _ => (
+            fill.side,
+            fill_size,
+            fill.price.inner(),
+            fill.entry_edge_bps,
+        ),
+    };
+
+    let f = |d: Decimal| d.to_f64().unwrap_or(0.0);
+    let trade = trade.filter(|_| event.is_reducing());
+    PositionJournalRecord {
+        timestamp_ms: fill.timestamp_ms,
+        event,
+        market_key: fill.market.to_string(),
+        side: match side {
+            OrderSide::Buy => "long".to_string(),
+            OrderSide::Sell => "short".to_string(),
+        },
+        cloid: fill.cloid,
+        entry_source: fill.entry_source,
+        fill_px: f(fill.price.inner()),
+        fill_size: f(fill_size),
+        size_before: f(size_before),
+        size_after: f(size_after),
+        entry_px: f(entry_px),
+        entry_edge_bps: entry_edge_bps.and_then(|e| e.to_f64()),
+        exit_reason: trade.map(|t| t.exit_reason.clone()),
+        realized_pnl_usd: trade.map(|t| t.gross_pnl_usd),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+
+    fn market() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn fill(side: OrderSide, price: Decimal, size: Decimal) -> JournalFill {
+        JournalFill {
+            market: market(),
+            side,
+            price: Price::new(price),
+            size: Size::new(size),
+            cloid: Some("0xabc".to_string()),
+            timestamp_ms: 2_000,
+            entry_source: "taker".to_string(),
+            entry_edge_bps: Some(dec!(40)),
+        }
+    }
+
+    #[test]
+    fn test_lifecycle_records() {
+        let open = journal_record(None, fill(OrderSide::Buy, dec!(100), dec!(1)), None);
+        assert_eq!(open.event, PositionEvent::Open);
+        assert_eq!((open.size_after, open.entry_px), (1.0, 100.0));
+        assert_eq!(open.entry_edge_bps, Some(40.0));
+
+        let mut position = Position::new(
+            market(),
+            OrderSide::Buy,
+            Size::new(dec!(1)),
+            Price::new(dec!(100)),
+            1_000,
+        );
+        position.entry_edge_bps = Some(dec!(40));
+        let scale = journal_record(
+            Some(&position),
+            fill(OrderSide::Buy, dec!(103), dec!(2)),
+            None,
+        );
+        assert_eq!(scale.event, PositionEvent::Scale);
+        assert_eq!((scale.size_after, scale.entry_px), (3.0, 102.0));
+        assert_eq!(scale.exit_reason, None);
+
+        let flip = journal_record(
+            Some(&position),
+            fill(OrderSide::Sell, dec!(99), dec!(3)),
+            None,
+        );
+        assert_eq!(flip.event, PositionEvent::Flip);
+        assert_eq!(flip.side, "short");
+        assert_eq!((flip.size_after, flip.entry_px), (2.0, 99.0));
+        assert_eq!(flip.entry_edge_bps, None);
+    }
+
+    #[test]
+    fn test_close_carries_trade_attribution() {
+        let position = Position::new(
+            market(),
+            OrderSide::Sell,
+            Size::new(dec!(2)),
+            Price::new(dec!(100)),
+            1_000,
+        );
+        let trade = TradeRecord {
+            market_key: "xyz:0".to_string(),
+            side: "short".to_string(),
+            entry_source: "taker".to_string(),
+            exit_reason: "TimeStop".to_string(),
+            cloid: None,
+            opened_at_ms: 1_000,
+            closed_at_ms: 2_000,
+            hold_time_ms: 1_000,
+            entry_px: 100.0,
+            exit_px: 101.0,
+            size: 2.0,
+            notional_usd: 202.0,
+            gross_pnl_usd: -2.0,
+            gross_pnl_bps: -100.0,
+            fees_usd: 0.1,
+            funding_usd: 0.0,
+            net_pnl_usd: -2.1,
+            mae_bps: -100.0,
+            mae_usd: -2.0,
+        };
+        let close = journal_record(
+            Some(&position),
+            fill(OrderSide::Buy, dec!(101), dec!(2)),
+            Some(&trade),
+        );
+        assert_eq!(close.event, PositionEvent::Close);
+        assert_eq!(close.side, "short");
+        assert_eq!((close.size_before, close.size_after), (2.0, 0.0));
+        assert_eq!(close.entry_px, 100.0);
+        assert_eq!(close.exit_reason.as_deref(), Some("TimeStop"));
+        assert_eq!(close.realized_pnl_usd, Some(-2.0));
+    }
+}
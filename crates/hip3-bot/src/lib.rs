@@ -6,12 +6,24 @@
 //! - Risk gate checks
 //! - Dislocation detection
 //! - Signal recording (Phase A) / Execution (Phase B)
+//! - Offline detector replay over recorded feed data
+//! - Transaction cost analysis of entry fills
+//! - PnL attribution of closed trades
+//! - Position lifecycle journaling
 
 pub mod app;
+pub mod attribution;
 pub mod config;
 pub mod edge_tracker;
 pub mod error;
+pub mod extension;
+pub mod journal;
+pub mod replay;
+pub mod tca;
 
 pub use app::Application;
 pub use config::AppConfig;
 pub use error::{AppError, AppResult};
+pub use extension::{
+    AdvisoryAction, DomainEvent, Extension, ExtensionBus, GateVote, PublishOutcome,
+};
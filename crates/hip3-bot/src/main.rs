@@ -2,10 +2,19 @@
 //!
 //! Phase A: Observation mode (signal detection and recording only)
 //! Phase B: Execution mode (IOC taker with risk gates)
+//! `replay`: Detector dry-run over recorded feed data
+//! `verify-audit`: Hash-chain and action-hash check of signed-action audit files
 
+use alloy::primitives::Address;
 use anyhow::Result;
-use clap::Parser;
-use tracing::info;
+use clap::{Parser, Subcommand};
+use hip3_persistence::{
+    verify_audit_file, FeedReader, FeedRecord, FrameReader, SignedActionRecord,
+};
+use hip3_ws::{message_queue, ConnectionManager, WsMessage};
+use std::io::Write;
+use std::path::Path;
+use tracing::{debug, info, warn};
 
 /// HIP-3 Oracle/Mark Dislocation Taker Bot
 #[derive(Parser, Debug)]
@@ -14,6 +23,31 @@ struct Args {
     /// Configuration file path (can also be set via HIP3_CONFIG env var)
     #[arg(short, long)]
     config: Option<String>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Replay recorded feed files through the detector and report the
+    /// signals that would have fired (no connections, no orders).
+    Replay {
+        /// Recorded feed files (feed_YYYY-MM-DD.jsonl) or raw frame files
+        /// (frames_YYYY-MM-DD_NNN.jsonl), replayed in order.
+        #[arg(required = true)]
+        inputs: Vec<String>,
+        /// Write every replayed signal to this JSON Lines file.
+        #[arg(long)]
+        signals_out: Option<String>,
+    },
+    /// Verify signed-action audit files: the record hash chain and each
+    /// action hash re-derived from the recorded action.
+    VerifyAudit {
+        /// Audit files (signed_actions_YYYY-MM-DD.jsonl).
+        #[arg(required = true)]
+        inputs: Vec<String>,
+    },
 }
 
 #[tokio::main]
@@ -29,6 +63,11 @@ async fn main() -> Result<()> {
 
     info!("Starting HIP-3 Bot v{}", env!("CARGO_PKG_VERSION"));
 
+    // Audit verification needs no configuration
+    if let Some(Command::VerifyAudit { ref inputs }) = args.command {
+        return run_verify_audit(inputs);
+    }
+
     // Determine config path: CLI arg > HIP3_CONFIG env var > default
     let config_path = args
         .config
@@ -41,6 +80,14 @@ async fn main() -> Result<()> {
     let config = hip3_bot::AppConfig::from_file(&config_path)?;
     info!(?config.mode, info_url = %config.info_url, "Configuration loaded");
 
+    if let Some(Command::Replay {
+        inputs,
+        signals_out,
+    }) = args.command
+    {
+        return run_replay(&config, &inputs, signals_out.as_deref());
+    }
+
     // Create application
     let mut app = hip3_bot::Application::new(config)?;
 
@@ -54,3 +101,120 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Replay feed files and print a per-market signal summary.
+fn run_replay(
+    config: &hip3_bot::AppConfig,
+    inputs: &[String],
+    signals_out: Option<&str>,
+) -> Result<()> {
+    let mut replayer = hip3_bot::replay::Replayer::new(config)?;
+    // Frames go through the connection's inbound path: both links' copies
+    // (when a standby was running) are deduplicated as they were live
+    let (message_tx, mut message_rx) = message_queue(1);
+    let connections = ConnectionManager::for_replay(message_tx);
+    for input in inputs {
+        let is_frames = Path::new(input)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("frames_"));
+        if is_frames {
+            info!(input = %input, "Replaying frame file");
+            let mut reader = FrameReader::open(input)?;
+            for frame in reader.by_ref() {
+                let received_ms = frame.timestamp_ms.max(0) as u64;
+                if let Err(e) =
+                    connections.replay_frame(&frame.connection, &frame.text, received_ms)
+                {
+                    debug!(?e, "Skipping unparseable frame");
+                    continue;
+                }
+                while let Some(msg) = message_rx.try_recv() {
+                    if let WsMessage::Channel(channel_msg) = msg {
+                        replayer.process(&FeedRecord {
+                            timestamp_ms: frame.timestamp_ms,
+                            channel: channel_msg.channel,
+                            data: channel_msg.data,
+                        });
+                    }
+                }
+            }
+            if reader.skipped() > 0 {
+                warn!(input = %input, skipped = reader.skipped(), "Skipped unreadable frame lines");
+            }
+            continue;
+        }
+        info!(input = %input, "Replaying feed file");
+        let mut reader = FeedReader::open(input)?;
+        for record in reader.by_ref() {
+            replayer.process(&record);
+        }
+        if reader.skipped() > 0 {
+            warn!(input = %input, skipped = reader.skipped(), "Skipped unreadable feed lines");
+        }
+    }
+    let report = replayer.finish();
+
+    if let Some(path) = signals_out {
+        let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        for signal in &report.signals {
+            writeln!(file, "{}", serde_json::to_string(signal)?)?;
+        }
+        file.flush()?;
+        info!(path = %path, signals = report.signals.len(), "Wrote replayed signals");
+    }
+
+    println!(
+        "records={} parse_errors={} signals={}",
+        report.records,
+        report.parse_errors,
+        report.signals.len()
+    );
+    println!(
+        "{:<16} {:<5} {:>7} {:>14} {:>14}",
+        "market", "side", "signals", "mean_edge_bps", "mean_thr_bps"
+    );
+    for ((coin, side), summary) in report.summary() {
+        println!(
+            "{:<16} {:<5} {:>7} {:>14} {:>14}",
+            coin, side, summary.count, summary.mean_edge_bps, summary.mean_threshold_bps
+        );
+    }
+    Ok(())
+}
+
+/// Verify audit files, failing if any record does not check out.
+fn run_verify_audit(inputs: &[String]) -> Result<()> {
+    let mut invalid = 0;
+    for input in inputs {
+        let result = verify_audit_file(Path::new(input), derive_action_hash)?;
+        match result.failure {
+            None => println!("{input}: OK ({} records)", result.records),
+            Some((line, reason)) => {
+                invalid += 1;
+                println!(
+                    "{input}: FAILED at line {line}: {reason} ({} valid records before)",
+                    result.records
+                );
+            }
+        }
+    }
+    if invalid > 0 {
+        anyhow::bail!(
+            "{invalid} of {} audit files failed verification",
+            inputs.len()
+        );
+    }
+    Ok(())
+}
+
+/// Re-derive a record's action hash from its recorded action.
+fn derive_action_hash(record: &SignedActionRecord) -> Option<String> {
+    let vault_address = match record.vault_address {
+        Some(ref vault) => Some(vault.parse::<Address>().ok()?),
+        None => None,
+    };
+    hip3_executor::action_hash_from_json(&record.action, record.nonce, vault_address)
+        .ok()
+        .map(|hash| hash.to_string())
+}
@@ -0,0 +1,355 @@
+//! Detector dry-run over recorded market data.
+//!
+//! Feeds a recorded feed file (see `persistence.record_feed`; raw frame files
+//! from `persistence.record_frames` are converted by the caller) back through
+//! `MessageParser` → `MarketState` → `DislocationDetector` with the config
+//! under test, and reports every signal that would have fired.
+//!
+//! Risk gates are not evaluated: their freshness checks compare against the
+//! wall clock, which is meaningless for recorded data. Oracle age for the
+//! quote-lag gate and the snapshot time seen by the detector are derived
+//! from record timestamps instead.
+
+use crate::config::AppConfig;
+use crate::error::{AppError, AppResult};
+use hip3_core::{AssetId, DexId, MarketKey, MarketSpec, OrderSide, Price};
+use hip3_detector::{DetectionStrategy, DislocationDetector, SideThresholds, StrategyContext};
+use hip3_feed::{
+    BboHistory, BboHistoryHandle, MarketEvent, MarketState, MessageParser, OracleMovementTracker,
+};
+use hip3_persistence::FeedRecord;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use tracing::debug;
+
+/// A signal the detector would have emitted.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplaySignal {
+    /// Record time that triggered the signal (Unix ms).
+    pub timestamp_ms: i64,
+    /// Market coin symbol.
+    pub coin: String,
+    /// Signal side.
+    pub side: OrderSide,
+    /// Raw edge (bps).
+    pub raw_edge_bps: Decimal,
+    /// Threshold the edge was judged against (bps).
+    pub threshold_bps: Decimal,
+    /// Edge after costs (bps).
+    pub net_edge_bps: Decimal,
+    /// Strength bucket size multiplier.
+    pub size_multiplier: Decimal,
+}
+
+/// Per market/side signal totals.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReplaySummary {
+    /// Signals fired.
+    pub count: usize,
+    /// Mean raw edge (bps).
+    pub mean_edge_bps: Decimal,
+    /// Mean threshold (bps).
+    pub mean_threshold_bps: Decimal,
+}
+
+/// Result of a replay run.
+#[derive(Debug, Clone, Default)]
+pub struct ReplayReport {
+    /// Records read.
+    pub records: usize,
+    /// Records the parser rejected (unknown coin, malformed payload).
+    pub parse_errors: usize,
+    /// Signals in record order.
+    pub signals: Vec<ReplaySignal>,
+}
+
+impl ReplayReport {
+    /// Signal totals by (coin, side).
+    pub fn summary(&self) -> BTreeMap<(String, String), ReplaySummary> {
+        let mut summary: BTreeMap<(String, String), ReplaySummary> = BTreeMap::new();
+        for signal in &self.signals {
+            let entry = summary
+                .entry((signal.coin.clone(), signal.side.to_string()))
+                .or_default();
+            entry.count += 1;
+            entry.mean_edge_bps += signal.raw_edge_bps;
+            entry.mean_threshold_bps += signal.threshold_bps;
+        }
+        for entry in summary.values_mut() {
+            let n = Decimal::from(entry.count);
+            entry.mean_edge_bps = (entry.mean_edge_bps / n).round_dp(2);
+            entry.mean_threshold_bps = (entry.mean_threshold_bps / n).round_dp(2);
+        }
+        summary
+    }
+}
+
+/// Replays feed records through a fresh detector built from `config`.
+pub struct Replayer {
+    parser: MessageParser,
+    market_state: MarketState,
+    detector: DislocationDetector,
+    oracle_tracker: OracleMovementTracker,
+    /// Recent BBOs for the momentum veto (None if disabled).
+    bbo_history: Option<BboHistoryHandle>,
+    /// Market key → (coin, thresholds).
+    markets: HashMap<MarketKey, (String, SideThresholds)>,
+    /// Last oracle price and the record time it changed.
+    oracle_changed: HashMap<MarketKey, (Price, i64)>,
+    report: ReplayReport,
+}
+
+impl Replayer {
+    /// Build a replayer for the markets listed in `config`.
+    pub fn new(config: &AppConfig) -> AppResult<Self> {
+        let markets_config = config.try_get_markets().ok_or_else(|| {
+            AppError::Config("Replay requires `markets` to be listed in the config".to_string())
+        })?;
+
+        // Configured asset IDs encode the perp DEX (100000 + dex * 10000 + index)
+        let dex = markets_config
+            .iter()
+            .find_map(|market| AssetId::new(market.asset_idx).perp_dex_idx())
+            .map_or(DexId::XYZ, DexId::new);
+        let mut parser = MessageParser::new();
+        parser.set_dex_id(dex);
+        let mut markets = HashMap::new();
+        for market in markets_config {
+            parser.add_coin_mapping(market.coin.clone(), market.asset_idx);
+            let symmetric = market.threshold_bps.map(Decimal::from);
+            let thresholds = SideThresholds {
+                long: market.threshold_bps_long.map(Decimal::from).or(symmetric),
+                short: market.threshold_bps_short.map(Decimal::from).or(symmetric),
+            };
+            let key = MarketKey::new(dex, AssetId::new(market.asset_idx));
+            markets.insert(key, (market.coin.clone(), thresholds));
+        }
+
+        let mut detector = DislocationDetector::new(config.detector.clone())?;
+        let bbo_history = config
+            .detector
+            .momentum_veto_enabled
+            .then(|| BboHistory::new_shared(config.detector.momentum_veto_window_ms));
+        if let Some(ref history) = bbo_history {
+            detector = detector.with_bbo_history(history.clone());
+        }
+
+        Ok(Self {
+            parser,
+            market_state: MarketState::new(),
+            detector,
+            oracle_tracker: OracleMovementTracker::new(
+                config.oracle_tracking.clone().unwrap_or_default(),
+            ),
+            bbo_history,
+            markets,
+            oracle_changed: HashMap::new(),
+            report: ReplayReport::default(),
+        })
+    }
+
+    /// Apply one record and run detection for the market it touched.
+    pub fn process(&mut self, record: &FeedRecord) {
+        self.report.records += 1;
+        let event = match self
+            .parser
+            .parse_channel_message(&record.channel, &record.data)
+        {
+            Ok(Some(event)) => event,
+            Ok(None) => return,
+            Err(e) => {
+                debug!(?e, channel = %record.channel, "Replay: record rejected by parser");
+                self.report.parse_errors += 1;
+                return;
+            }
+        };
+
+        let key = match event {
+            MarketEvent::BboUpdate { key, bbo } => {
+                if let Some(ref history) = self.bbo_history {
+                    let ts = record.timestamp_ms.max(0) as u64;
+                    history.record(key, bbo.bid_price, bbo.ask_price, ts);
+                }
+                self.market_state.update_bbo(key, bbo, None);
+                key
+            }
+            MarketEvent::CtxUpdate { key, ctx } => {
+                let oracle_px = ctx.oracle.oracle_px;
+                self.market_state.update_ctx(key, ctx);
+                self.oracle_tracker.record_move_at(
+                    key,
+                    oracle_px,
+                    record.timestamp_ms.max(0) as u64,
+                );
+                let changed = self
+                    .oracle_changed
+                    .get(&key)
+                    .map_or(true, |(px, _)| *px != oracle_px);
+                if changed {
+                    self.oracle_changed
+                        .insert(key, (oracle_px, record.timestamp_ms));
+                }
+                key
+            }
+            MarketEvent::BookUpdate { key, book } => {
+                self.market_state.update_book(key, book);
+                return;
+            }
+            MarketEvent::TradesUpdate { key, trades } => {
+                self.market_state.record_trades(key, &trades);
+                return;
+            }
+            MarketEvent::ReferenceUpdate { .. } => return,
+        };
+        self.detect(key, record.timestamp_ms);
+    }
+
+    fn detect(&mut self, key: MarketKey, timestamp_ms: i64) {
+        let Some((coin, thresholds)) = self.markets.get(&key) else {
+            return;
+        };
+        let Some(mut snapshot) = self.market_state.get_snapshot(&key) else {
+            return;
+        };
+        // Time-dependent checks (edge decay, funding) run on record time
+        if let Some(ts) = chrono::DateTime::from_timestamp_millis(timestamp_ms) {
+            snapshot.timestamp = ts;
+        }
+        let spec = MarketSpec::default();
+        let oracle_age_ms = self
+            .oracle_changed
+            .get(&key)
+            .map(|(_, changed_ms)| timestamp_ms - changed_ms);
+        let ctx = StrategyContext {
+            key,
+            coin,
+            snapshot: &snapshot,
+            spec: &spec,
+            thresholds: *thresholds,
+            oracle_tracker: Some(&self.oracle_tracker),
+            oracle_age_ms,
+            now_ms: timestamp_ms.max(0) as u64,
+        };
+        if let Some(signal) = self.detector.detect(&ctx) {
+            self.detector.record_signal(&signal);
+            self.report.signals.push(ReplaySignal {
+                timestamp_ms,
+                coin: coin.clone(),
+                side: signal.side,
+                raw_edge_bps: signal.raw_edge_bps,
+                threshold_bps: signal.threshold_bps,
+                net_edge_bps: signal.net_edge_bps,
+                size_multiplier: signal.size_multiplier,
+            });
+        }
+    }
+
+    /// Finish the run and return the report.
+    pub fn finish(self) -> ReplayReport {
+        self.report
+    }
+}
+
+/// Replay `records` against `config`.
+pub fn replay(
+    config: &AppConfig,
+    records: impl IntoIterator<Item = FeedRecord>,
+) -> AppResult<ReplayReport> {
+    let mut replayer = Replayer::new(config)?;
+    for record in records {
+        replayer.process(&record);
+    }
+    Ok(replayer.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MarketConfig;
+    use hip3_detector::DetectorConfig;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn config() -> AppConfig {
+        let mut config = AppConfig {
+            detector: DetectorConfig {
+                taker_fee_bps: dec!(4),
+                slippage_bps: dec!(2),
+                min_edge_bps: dec!(4),
+                oracle_direction_filter: false,
+                min_oracle_change_bps: dec!(0),
+                min_consecutive_oracle_moves: 0,
+                signal_dedup_enabled: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        config.set_discovered_markets(vec![MarketConfig {
+            asset_idx: 110026,
+            coin: "xyz:GOLD".to_string(),
+            threshold_bps: None,
+            threshold_bps_long: None,
+            threshold_bps_short: None,
+            min_cross_duration_ms: None,
+            blackout_windows: Vec::new(),
+            max_slippage_bps: None,
+            max_realized_vol_bps: None,
+        }]);
+        config
+    }
+
+    fn ctx(ts: i64, oracle: &str) -> FeedRecord {
+        FeedRecord {
+            timestamp_ms: ts,
+            channel: "activeAssetCtx".to_string(),
+            data: json!({
+                "coin": "xyz:GOLD",
+                "ctx": {"oraclePx": oracle, "markPx": oracle, "funding": "0", "openInterest": "100"}
+            }),
+        }
+    }
+
+    fn bbo(ts: i64, bid: &str, ask: &str) -> FeedRecord {
+        FeedRecord {
+            timestamp_ms: ts,
+            channel: "bbo".to_string(),
+            data: json!({
+                "coin": "xyz:GOLD",
+                "time": ts,
+                "bbo": [{"px": bid, "sz": "50", "n": 3}, {"px": ask, "sz": "50", "n": 3}]
+            }),
+        }
+    }
+
+    #[test]
+    fn test_replay_reports_signals() {
+        let records = vec![
+            ctx(1_000, "2650"),
+            bbo(1_010, "2649", "2650.5"),
+            // Ask 60 bps under the oracle
+            bbo(1_020, "2632", "2634"),
+            FeedRecord {
+                timestamp_ms: 1_030,
+                channel: "bbo".to_string(),
+                data: json!({"coin": "xyz:UNKNOWN", "bbo": [null, null]}),
+            },
+        ];
+
+        let report = replay(&config(), records).unwrap();
+        assert_eq!(report.records, 4);
+        assert_eq!(report.parse_errors, 1);
+        assert_eq!(report.signals.len(), 1);
+        let signal = &report.signals[0];
+        assert_eq!(signal.timestamp_ms, 1_020);
+        assert_eq!(signal.side, OrderSide::Buy);
+        assert!(signal.raw_edge_bps > signal.threshold_bps);
+
+        let summary = report.summary();
+        let buy = &summary[&("xyz:GOLD".to_string(), "buy".to_string())];
+        assert_eq!(buy.count, 1);
+        assert_eq!(buy.mean_edge_bps, signal.raw_edge_bps.round_dp(2));
+        assert_eq!(summary.len(), 1);
+        assert!(buy.mean_threshold_bps > dec!(0));
+    }
+}
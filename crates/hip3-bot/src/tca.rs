@@ -0,0 +1,283 @@
+//! Transaction cost analysis for signal entries.
+//!
+//! Each queued entry order is remembered with the signal that produced it.
+//! When a fill for that cloid arrives, [`TcaTracker`] joins the two into a
+//! [`TcaRecord`]: slippage against the signal's best price, fee paid,
+//! signal-to-queue and signal-to-fill latency, and how much of the signal
+//! edge survived. Running per-market averages back the exported metrics.
+//!
+//! Only the order queued directly for the signal is joined; later children
+//! of a sliced entry and MM quotes carry cloids the tracker has never seen.
+
+use hip3_core::{ClientOrderId, MarketKey, OrderSide, Price, Size};
+use hip3_detector::DislocationSignal;
+use hip3_persistence::TcaRecord;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+/// Entry orders with no fill after this long are forgotten (ms).
+const ORDER_TTL_MS: i64 = 60_000;
+
+/// Signal context for a queued entry order.
+#[derive(Debug, Clone)]
+struct TrackedOrder {
+    signal_id: String,
+    market: MarketKey,
+    side: OrderSide,
+    oracle_px: Price,
+    best_px: Price,
+    raw_edge_bps: Decimal,
+    signal_timestamp_ms: i64,
+    queued_at_ms: i64,
+}
+
+/// Running TCA averages for one market.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TcaSummary {
+    /// Fills joined to a signal.
+    pub fills: u64,
+    /// Mean slippage vs signal best_px (bps).
+    pub mean_slippage_bps: f64,
+    /// Mean fee (bps of notional).
+    pub mean_fee_bps: f64,
+    /// Mean signal-to-fill latency (ms).
+    pub mean_signal_to_fill_ms: f64,
+    /// Mean edge capture ratio.
+    pub mean_edge_capture_ratio: f64,
+    /// Total fees paid (USD).
+    pub fees_usd: f64,
+}
+
+impl TcaSummary {
+    fn record(&mut self, record: &TcaRecord) {
+        self.fills += 1;
+        let n = self.fills as f64;
+        let update = |mean: &mut f64, value: f64| *mean += (value - *mean) / n;
+        update(&mut self.mean_slippage_bps, record.slippage_bps);
+        update(&mut self.mean_fee_bps, record.fee_bps);
+        update(
+            &mut self.mean_signal_to_fill_ms,
+            record.signal_to_fill_ms as f64,
+        );
+        update(&mut self.mean_edge_capture_ratio, record.edge_capture_ratio);
+        self.fees_usd += record.fee_usd;
+    }
+}
+
+/// Joins signals, entry orders and fills into TCA records.
+#[derive(Debug, Default)]
+pub struct TcaTracker {
+    /// Queued entry orders awaiting fills, by cloid.
+    orders: HashMap<ClientOrderId, TrackedOrder>,
+    /// Running averages per market.
+    summaries: HashMap<MarketKey, TcaSummary>,
+}
+
+impl TcaTracker {
+    /// Create an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember the signal behind a queued entry order.
+    pub fn on_order_queued(
+        &mut self,
+        cloid: ClientOrderId,
+        signal: &DislocationSignal,
+        queued_at_ms: i64,
+    ) {
+        self.prune(queued_at_ms);
+        self.orders.insert(
+            cloid,
+            TrackedOrder {
+                signal_id: signal.signal_id.clone(),
+                market: signal.market_key,
+                side: signal.side,
+                oracle_px: signal.oracle_px,
+                best_px: signal.best_px,
+                raw_edge_bps: signal.raw_edge_bps,
+                signal_timestamp_ms: signal.detected_at.timestamp_millis(),
+                queued_at_ms,
+            },
+        );
+    }
+
+    /// Join a fill to its signal.
+    ///
+    /// Returns None for fills of orders the tracker did not queue. Partial
+    /// fills each produce a record; the order stays tracked until it expires.
+    pub fn on_fill(
+        &mut self,
+        cloid: &ClientOrderId,
+        price: Price,
+        size: Size,
+        fee_usd: Decimal,
+        fill_timestamp_ms: i64,
+    ) -> Option<TcaRecord> {
+        let order = self.orders.get(cloid)?;
+        if order.best_px.is_zero() || price.is_zero() {
+            return None;
+        }
+        let bps = Decimal::from(10_000);
+
+        // Positive = filled worse than the signal's best price
+        let slippage_bps = match order.side {
+            OrderSide::Buy => (price.inner() - order.best_px.inner()) / order.best_px.inner(),
+            OrderSide::Sell => (order.best_px.inner() - price.inner()) / order.best_px.inner(),
+        } * bps;
+        let notional = price.inner() * size.inner();
+        let fee_bps = if notional.is_zero() {
+            Decimal::ZERO
+        } else {
+            fee_usd / notional * bps
+        };
+        let captured_edge_bps = order.raw_edge_bps - slippage_bps - fee_bps;
+        let edge_capture_ratio = if order.raw_edge_bps > Decimal::ZERO {
+            captured_edge_bps / order.raw_edge_bps
+        } else {
+            Decimal::ZERO
+        };
+
+        let f = |d: Decimal| d.round_dp(4).to_f64().unwrap_or(0.0);
+        let record = TcaRecord {
+            signal_id: order.signal_id.clone(),
+            cloid: cloid.to_string(),
+            market_key: order.market.to_string(),
+            side: order.side.to_string(),
+            signal_timestamp_ms: order.signal_timestamp_ms,
+            queued_at_ms: order.queued_at_ms,
+            fill_timestamp_ms,
+            oracle_px: f(order.oracle_px.inner()),
+            signal_best_px: f(order.best_px.inner()),
+            fill_px: f(price.inner()),
+            fill_size: f(size.inner()),
+            notional_usd: f(notional),
+            signal_edge_bps: f(order.raw_edge_bps),
+            slippage_bps: f(slippage_bps),
+            fee_usd: f(fee_usd),
+            fee_bps: f(fee_bps),
+            captured_edge_bps: f(captured_edge_bps),
+            edge_capture_ratio: f(edge_capture_ratio),
+            signal_to_queue_ms: order.queued_at_ms - order.signal_timestamp_ms,
+            signal_to_fill_ms: fill_timestamp_ms - order.signal_timestamp_ms,
+        };
+        self.summaries
+            .entry(order.market)
+            .or_default()
+            .record(&record);
+        Some(record)
+    }
+
+    /// Running averages for a market.
+    pub fn summary(&self, market: &MarketKey) -> Option<&TcaSummary> {
+        self.summaries.get(market)
+    }
+
+    /// Number of entry orders still awaiting fills.
+    pub fn tracked_orders(&self) -> usize {
+        self.orders.len()
+    }
+
+    fn prune(&mut self, now_ms: i64) {
+        self.orders
+            .retain(|_, order| now_ms - order.queued_at_ms < ORDER_TTL_MS);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use hip3_core::{AssetId, DexId};
+    use hip3_detector::{FeeMetadata, SignalStrength};
+    use rust_decimal_macros::dec;
+
+    fn market() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn signal(side: OrderSide, best_px: Decimal) -> DislocationSignal {
+        let mut signal = DislocationSignal::new(
+            market(),
+            side,
+            dec!(60),
+            dec!(50),
+            SignalStrength::Strong,
+            Size::new(dec!(0.01)),
+            Price::new(dec!(2650)),
+            Price::new(best_px),
+            Size::new(dec!(1)),
+            FeeMetadata::default(),
+            dec!(0),
+            dec!(1),
+        );
+        signal.detected_at = Utc.timestamp_millis_opt(1_000).unwrap();
+        signal
+    }
+
+    #[test]
+    fn test_fill_joined_to_signal() {
+        let mut tracker = TcaTracker::new();
+        let cloid = ClientOrderId::new();
+        tracker.on_order_queued(cloid.clone(), &signal(OrderSide::Buy, dec!(2500)), 1_002);
+
+        // Bought 2 bps through best_px; fee 0.01125 on $25.005 = 4.5 bps
+        let record = tracker
+            .on_fill(
+                &cloid,
+                Price::new(dec!(2500.5)),
+                Size::new(dec!(0.01)),
+                dec!(0.01125225),
+                1_150,
+            )
+            .unwrap();
+        assert_eq!(record.slippage_bps, 2.0);
+        assert_eq!(record.fee_bps, 4.5);
+        assert_eq!(record.captured_edge_bps, 53.5);
+        assert_eq!(record.edge_capture_ratio, 0.8917);
+        assert_eq!(record.signal_to_queue_ms, 2);
+        assert_eq!(record.signal_to_fill_ms, 150);
+
+        let summary = tracker.summary(&market()).unwrap();
+        assert_eq!(summary.fills, 1);
+        assert_eq!(summary.mean_slippage_bps, 2.0);
+
+        assert!(tracker
+            .on_fill(
+                &ClientOrderId::new(),
+                Price::new(dec!(2500)),
+                Size::new(dec!(0.01)),
+                dec!(0),
+                1_200,
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_sell_price_improvement_and_expiry() {
+        let mut tracker = TcaTracker::new();
+        let cloid = ClientOrderId::new();
+        tracker.on_order_queued(cloid.clone(), &signal(OrderSide::Sell, dec!(2800)), 1_000);
+
+        // Sold above the signal bid: negative slippage
+        let record = tracker
+            .on_fill(
+                &cloid,
+                Price::new(dec!(2801.4)),
+                Size::new(dec!(0.01)),
+                dec!(0),
+                1_100,
+            )
+            .unwrap();
+        assert_eq!(record.slippage_bps, -5.0);
+        assert_eq!(record.captured_edge_bps, 65.0);
+
+        tracker.on_order_queued(
+            ClientOrderId::new(),
+            &signal(OrderSide::Buy, dec!(2500)),
+            1_000 + ORDER_TTL_MS,
+        );
+        assert_eq!(tracker.tracked_orders(), 1);
+    }
+}
@@ -8,10 +8,9 @@
 mod integration;
 use integration::common::mock_ws::MockWsServer;
 
-use hip3_ws::{ConnectionConfig, ConnectionManager, WsMessage};
+use hip3_ws::{message_queue, ConnectionConfig, ConnectionManager};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::mpsc;
 use tokio::time::timeout;
 
 /// Test that ConnectionManager can connect to a WebSocket server.
@@ -28,7 +27,7 @@ async fn test_ws_connects_to_server() {
     };
 
     // Create message channel
-    let (message_tx, _message_rx) = mpsc::channel::<WsMessage>(100);
+    let (message_tx, _message_rx) = message_queue(100);
 
     // Create connection manager
     let manager = Arc::new(ConnectionManager::new(config, message_tx));
@@ -78,7 +77,7 @@ async fn test_ws_sends_subscriptions() {
     };
 
     // Create message channel
-    let (message_tx, _message_rx) = mpsc::channel::<WsMessage>(100);
+    let (message_tx, _message_rx) = message_queue(100);
 
     let manager = Arc::new(ConnectionManager::new(config, message_tx));
 
@@ -158,7 +157,7 @@ async fn test_ws_respects_max_reconnect_attempts() {
     };
 
     // Create message channel
-    let (message_tx, _message_rx) = mpsc::channel::<WsMessage>(100);
+    let (message_tx, _message_rx) = message_queue(100);
 
     let manager = Arc::new(ConnectionManager::new(config, message_tx));
 
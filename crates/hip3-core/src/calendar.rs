@@ -0,0 +1,236 @@
+//! Trading calendar per asset class.
+//!
+//! HIP-3 perps trade around the clock, but their oracles follow an
+//! underlying market with its own hours. [`TradingCalendar`] tells whether
+//! the underlying of an [`AssetClass`] is open: regular weekly hours in US
+//! Eastern time (DST-adjusted) minus configured exchange holidays.
+//!
+//! Unlike [`crate::is_weekend_utc`], this knows that equities close every
+//! weekday at 16:00 ET and that metals take a daily maintenance break.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// Asset class of a market's underlying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetClass {
+    /// US equities and indices: Mon–Fri 09:30–16:00 ET.
+    Equities,
+    /// Metals (COMEX Globex): Sun 18:00 – Fri 17:00 ET, daily break
+    /// 17:00–18:00 ET.
+    Metals,
+    /// FX: Sun 17:00 – Fri 17:00 ET.
+    Fx,
+    /// Crypto: always open.
+    Crypto,
+}
+
+impl AssetClass {
+    /// Label for logs and metrics.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Equities => "equities",
+            Self::Metals => "metals",
+            Self::Fx => "fx",
+            Self::Crypto => "crypto",
+        }
+    }
+
+    /// Whether the underlying is within its regular weekly hours at `dt`
+    /// (holidays not considered).
+    #[must_use]
+    pub fn is_regular_open_at(self, dt: DateTime<Utc>) -> bool {
+        let et = us_eastern(dt);
+        let minute = et.hour() * 60 + et.minute();
+        let weekday = et.weekday();
+        match self {
+            Self::Equities => {
+                !matches!(weekday, Weekday::Sat | Weekday::Sun)
+                    && (9 * 60 + 30..16 * 60).contains(&minute)
+            }
+            Self::Metals => match weekday {
+                Weekday::Sat => false,
+                Weekday::Sun => minute >= 18 * 60,
+                Weekday::Fri => minute < 17 * 60,
+                _ => !(17 * 60..18 * 60).contains(&minute),
+            },
+            Self::Fx => match weekday {
+                Weekday::Sat => false,
+                Weekday::Sun => minute >= 17 * 60,
+                Weekday::Fri => minute < 17 * 60,
+                _ => true,
+            },
+            Self::Crypto => true,
+        }
+    }
+}
+
+impl std::fmt::Display for AssetClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Exchange holiday: the listed asset classes are closed for the whole
+/// US Eastern calendar day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketHoliday {
+    /// Holiday date (US Eastern), e.g. "2026-12-25".
+    pub date: NaiveDate,
+    /// Asset classes closed on the date.
+    pub asset_classes: Vec<AssetClass>,
+}
+
+/// Trading calendar configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TradingCalendarConfig {
+    /// Block entries and pull MM quotes of markets whose underlying is
+    /// closed. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Asset class per market, by coin name without the dex prefix
+    /// (e.g. GOLD = "metals"). Unlisted markets are not restricted.
+    #[serde(default)]
+    pub markets: HashMap<String, AssetClass>,
+    /// Exchange holidays.
+    #[serde(default)]
+    pub holidays: Vec<MarketHoliday>,
+}
+
+impl TradingCalendarConfig {
+    /// Asset class of a market by coin name ("GOLD" or "xyz:GOLD"), whether
+    /// or not the calendar is enabled.
+    #[must_use]
+    pub fn asset_class(&self, coin: &str) -> Option<AssetClass> {
+        lookup_asset_class(&self.markets, coin)
+    }
+}
+
+/// Open/closed state of each asset class's underlying market.
+#[derive(Debug, Clone, Default)]
+pub struct TradingCalendar {
+    /// Asset class per coin name (without dex prefix).
+    markets: HashMap<String, AssetClass>,
+    /// Holiday dates (US Eastern) per asset class.
+    holidays: HashMap<AssetClass, HashSet<NaiveDate>>,
+}
+
+impl TradingCalendar {
+    /// Create a calendar from its configuration.
+    #[must_use]
+    pub fn new(config: &TradingCalendarConfig) -> Self {
+        let mut holidays: HashMap<AssetClass, HashSet<NaiveDate>> = HashMap::new();
+        for holiday in &config.holidays {
+            for class in &holiday.asset_classes {
+                holidays.entry(*class).or_default().insert(holiday.date);
+            }
+        }
+        Self {
+            markets: config.markets.clone(),
+            holidays,
+        }
+    }
+
+    /// Asset class of a market by coin name ("GOLD" or "xyz:GOLD").
+    #[must_use]
+    pub fn asset_class(&self, coin: &str) -> Option<AssetClass> {
+        lookup_asset_class(&self.markets, coin)
+    }
+
+    /// Whether the underlying of `class` is open at `dt`.
+    #[must_use]
+    pub fn is_open_at(&self, class: AssetClass, dt: DateTime<Utc>) -> bool {
+        class.is_regular_open_at(dt) && !self.is_holiday(class, us_eastern(dt).date())
+    }
+
+    /// Whether the underlying of `class` is open now.
+    #[must_use]
+    pub fn is_open(&self, class: AssetClass) -> bool {
+        self.is_open_at(class, Utc::now())
+    }
+
+    /// Whether `date` (US Eastern) is a holiday of `class`.
+    #[must_use]
+    pub fn is_holiday(&self, class: AssetClass, date: NaiveDate) -> bool {
+        self.holidays
+            .get(&class)
+            .is_some_and(|dates| dates.contains(&date))
+    }
+}
+
+/// Asset class of `coin` in `markets`, ignoring the dex prefix.
+fn lookup_asset_class(markets: &HashMap<String, AssetClass>, coin: &str) -> Option<AssetClass> {
+    let name = coin.rsplit_once(':').map_or(coin, |(_, name)| name);
+    markets.get(name).copied()
+}
+
+/// US Eastern local time at `dt`.
+///
+/// DST runs from the second Sunday of March 02:00 local (07:00 UTC) to the
+/// first Sunday of November 02:00 local (06:00 UTC).
+fn us_eastern(dt: DateTime<Utc>) -> NaiveDateTime {
+    let year = dt.year();
+    let dst_start = NaiveDate::from_weekday_of_month_opt(year, 3, Weekday::Sun, 2)
+        .and_then(|d| d.and_hms_opt(7, 0, 0));
+    let dst_end = NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Sun, 1)
+        .and_then(|d| d.and_hms_opt(6, 0, 0));
+    let utc = dt.naive_utc();
+    let is_dst =
+        matches!((dst_start, dst_end), (Some(start), Some(end)) if start <= utc && utc < end);
+    utc - Duration::hours(if is_dst { 4 } else { 5 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_regular_hours_follow_us_dst() {
+        // 2026-02-09 is Monday (EST, UTC-5): equities 14:30–21:00 UTC
+        assert!(!AssetClass::Equities.is_regular_open_at(utc(2026, 2, 9, 14, 29)));
+        assert!(AssetClass::Equities.is_regular_open_at(utc(2026, 2, 9, 14, 30)));
+        assert!(!AssetClass::Equities.is_regular_open_at(utc(2026, 2, 9, 21, 0)));
+        // 2026-07-06 is Monday (EDT, UTC-4): equities 13:30–20:00 UTC
+        assert!(AssetClass::Equities.is_regular_open_at(utc(2026, 7, 6, 13, 30)));
+        assert!(!AssetClass::Equities.is_regular_open_at(utc(2026, 7, 6, 20, 0)));
+
+        // Metals daily break 17:00–18:00 ET (22:00–23:00 UTC in winter)
+        assert!(!AssetClass::Metals.is_regular_open_at(utc(2026, 2, 10, 22, 30)));
+        assert!(AssetClass::Metals.is_regular_open_at(utc(2026, 2, 10, 23, 0)));
+        // FX trades through the same hour, closes Friday 17:00 ET
+        assert!(AssetClass::Fx.is_regular_open_at(utc(2026, 2, 10, 22, 30)));
+        assert!(!AssetClass::Fx.is_regular_open_at(utc(2026, 2, 13, 22, 0)));
+        // Sunday reopen: FX 17:00 ET, metals 18:00 ET
+        assert!(AssetClass::Fx.is_regular_open_at(utc(2026, 2, 15, 22, 0)));
+        assert!(!AssetClass::Metals.is_regular_open_at(utc(2026, 2, 15, 22, 0)));
+        assert!(AssetClass::Crypto.is_regular_open_at(utc(2026, 2, 14, 12, 0)));
+    }
+
+    #[test]
+    fn test_market_classes_and_holidays() {
+        let calendar = TradingCalendar::new(&TradingCalendarConfig {
+            enabled: true,
+            markets: HashMap::from([("GOLD".to_string(), AssetClass::Metals)]),
+            holidays: vec![MarketHoliday {
+                date: NaiveDate::from_ymd_opt(2026, 7, 3).unwrap(),
+                asset_classes: vec![AssetClass::Equities],
+            }],
+        });
+        assert_eq!(calendar.asset_class("xyz:GOLD"), Some(AssetClass::Metals));
+        assert_eq!(calendar.asset_class("AAPL"), None);
+        // 2026-07-03 is Friday, 15:00 UTC = 11:00 EDT
+        assert!(!calendar.is_open_at(AssetClass::Equities, utc(2026, 7, 3, 15, 0)));
+        assert!(calendar.is_open_at(AssetClass::Metals, utc(2026, 7, 3, 15, 0)));
+        assert!(calendar.is_open_at(AssetClass::Equities, utc(2026, 7, 2, 15, 0)));
+    }
+}
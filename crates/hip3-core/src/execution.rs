@@ -6,10 +6,11 @@
 //! - Action batching for SDK compliance
 //! - Execution results and error handling
 
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use crate::market::MarketKey;
-use crate::order::{ClientOrderId, OrderSide, TimeInForce};
+use crate::order::{ClientOrderId, OrderSide, OrderStrategy, TimeInForce};
 use crate::{Price, Size};
 
 // ============================================================================
@@ -38,6 +39,25 @@ pub struct PendingOrder {
     /// Time-in-force. Defaults to IOC for backward compatibility with taker strategy.
     #[serde(default)]
     pub tif: TimeInForce,
+    /// Staleness bounds of the originating signal, re-checked when the order
+    /// leaves the queue. None for orders not driven by a signal.
+    #[serde(default)]
+    pub validity: Option<SignalValidity>,
+    /// Strategy the order belongs to; decides the account it is routed to.
+    #[serde(default)]
+    pub strategy: OrderStrategy,
+}
+
+/// Staleness bounds for a signal, checked when it reaches the executor and
+/// again when its order is dequeued for submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignalValidity {
+    /// Signal expiry (Unix milliseconds).
+    pub expires_at_ms: u64,
+    /// Minimum edge (bps) the latest quote must still show for the signal
+    /// side. None skips the edge re-check (e.g. signals not priced off the
+    /// oracle).
+    pub min_edge_bps: Option<Decimal>,
 }
 
 impl PendingOrder {
@@ -61,6 +81,8 @@ impl PendingOrder {
             reduce_only,
             created_at,
             tif: TimeInForce::default(), // IOC
+            validity: None,
+            strategy: OrderStrategy::default(),
         }
     }
 
@@ -86,8 +108,24 @@ impl PendingOrder {
             reduce_only,
             created_at,
             tif,
+            validity: None,
+            strategy: OrderStrategy::default(),
         }
     }
+
+    /// Attach the originating signal's staleness bounds.
+    #[must_use]
+    pub fn with_validity(mut self, validity: Option<SignalValidity>) -> Self {
+        self.validity = validity;
+        self
+    }
+
+    /// Set the strategy the order belongs to.
+    #[must_use]
+    pub fn with_strategy(mut self, strategy: OrderStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
 }
 
 /// Pending cancel request waiting to be submitted to the exchange.
@@ -101,6 +139,10 @@ pub struct PendingCancel {
     pub oid: u64,
     /// Creation timestamp (Unix milliseconds).
     pub created_at: u64,
+    /// Strategy of the order being cancelled; the cancel goes through the
+    /// account that order rests in.
+    #[serde(default)]
+    pub strategy: OrderStrategy,
 }
 
 impl PendingCancel {
@@ -111,8 +153,49 @@ impl PendingCancel {
             market,
             oid,
             created_at,
+            strategy: OrderStrategy::default(),
         }
     }
+
+    /// Set the strategy of the order being cancelled.
+    #[must_use]
+    pub fn with_strategy(mut self, strategy: OrderStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+}
+
+/// Pending amendment of a resting order, waiting to be submitted.
+///
+/// Moves a resting order to a new price/size in one action instead of a
+/// cancel plus a new order. The amended order carries a fresh client order
+/// ID (`order.cloid`); `replaces` is the cloid of the order being amended.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingModify {
+    /// Exchange order ID of the resting order.
+    pub oid: u64,
+    /// Client order ID of the resting order.
+    pub replaces: ClientOrderId,
+    /// The order as it should rest after the amendment.
+    pub order: PendingOrder,
+}
+
+impl PendingModify {
+    /// Create a new pending amendment.
+    #[must_use]
+    pub fn new(oid: u64, replaces: ClientOrderId, order: PendingOrder) -> Self {
+        Self {
+            oid,
+            replaces,
+            order,
+        }
+    }
+
+    /// Target market.
+    #[must_use]
+    pub fn market(&self) -> MarketKey {
+        self.order.market
+    }
 }
 
 // ============================================================================
@@ -180,6 +263,9 @@ pub struct TrackedOrder {
     /// Time-in-force.
     #[serde(default)]
     pub tif: TimeInForce,
+    /// Strategy the order belongs to.
+    #[serde(default)]
+    pub strategy: OrderStrategy,
 }
 
 impl TrackedOrder {
@@ -200,6 +286,7 @@ impl TrackedOrder {
             created_at: pending.created_at,
             updated_at: pending.created_at,
             tif: pending.tif,
+            strategy: pending.strategy,
         }
     }
 
@@ -223,13 +310,15 @@ impl TrackedOrder {
 /// Batch of actions to submit to the exchange.
 ///
 /// SDK specification requires one action type per tick, so we separate
-/// orders and cancels into distinct batches.
+/// orders, cancels and amendments into distinct batches.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ActionBatch {
     /// Batch of new orders to submit.
     Orders(Vec<PendingOrder>),
     /// Batch of cancel requests to submit.
     Cancels(Vec<PendingCancel>),
+    /// Batch of order amendments to submit.
+    Modifies(Vec<PendingModify>),
 }
 
 impl ActionBatch {
@@ -239,6 +328,7 @@ impl ActionBatch {
         match self {
             Self::Orders(orders) => orders.is_empty(),
             Self::Cancels(cancels) => cancels.is_empty(),
+            Self::Modifies(modifies) => modifies.is_empty(),
         }
     }
 
@@ -248,6 +338,7 @@ impl ActionBatch {
         match self {
             Self::Orders(orders) => orders.len(),
             Self::Cancels(cancels) => cancels.len(),
+            Self::Modifies(modifies) => modifies.len(),
         }
     }
 }
@@ -308,6 +399,29 @@ pub enum RejectReason {
     TiltGuard,
     /// Same-market re-entry delay active.
     ReEntryDelay,
+    /// Signal confidence below the executor minimum.
+    LowConfidence,
+    /// Limit price too far through the mark/oracle at submit time.
+    SlippageExceeded,
+    /// Net exposure limit of a netting group or the portfolio exceeded.
+    NetExposureLimit,
+    /// Daily (UTC day) loss limit hit.
+    DailyLossLimit,
+    /// Weekly (UTC week) loss limit hit, awaiting manual clear.
+    WeeklyLossLimit,
+    /// Account equity drawdown from the session high-water mark exceeded
+    /// (or equity unknown).
+    EquityDrawdown,
+    /// Market's notional or daily loss budget exhausted.
+    RiskBudgetExhausted,
+    /// New entries paused by the operator.
+    EntriesPaused,
+    /// p95 signal-to-order or WebSocket round-trip latency above its limit.
+    LatencyDegraded,
+    /// Too many open orders or recent cancel failures in the market.
+    OpenOrderLimit,
+    /// Market disabled after repeated exchange rejections.
+    RejectBreakerOpen,
 }
 
 /// Reason for skipping signal processing.
@@ -321,6 +435,8 @@ pub enum SkipReason {
     BudgetExhausted,
     /// Market is currently being flattened (reduce-only order pending).
     FlattenInProgress,
+    /// Signal outlived its TTL or its edge vanished before execution.
+    SignalExpired,
 }
 
 /// Result of processing a trading signal via `on_signal()`.
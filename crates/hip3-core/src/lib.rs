@@ -6,6 +6,7 @@
 //! - `MarketSpec`: Market specifications (tick size, lot size, fees)
 //! - `Side`, `OrderType`: Trading enums
 
+pub mod calendar;
 pub mod decimal;
 pub mod error;
 pub mod execution;
@@ -14,17 +15,20 @@ pub mod order;
 pub mod trading_session;
 pub mod types;
 
+pub use calendar::{AssetClass, MarketHoliday, TradingCalendar, TradingCalendarConfig};
 pub use decimal::{Price, Size};
 pub use error::{CoreError, Result};
 pub use market::{AssetId, DexId, MarketKey, MarketSpec, HIP3_MAX_SIG_FIGS};
-pub use order::{ClientOrderId, ExitProfile, OrderSide, OrderType, TimeInForce};
+pub use order::{ClientOrderId, ExitProfile, OrderSide, OrderStrategy, OrderType, TimeInForce};
 pub use trading_session::{
     current_session, is_mm_shutdown_at, is_weekend_at, is_weekend_utc, session_at, TradingSession,
 };
-pub use types::{AssetCtx, Bbo, BboState, MarketSnapshot, OracleData};
+pub use types::{
+    AssetCtx, Bbo, BboState, BookLevel, L2Book, MarketSnapshot, OracleData, TradePrint,
+};
 
 // Execution types
 pub use execution::{
-    ActionBatch, EnqueueResult, ExecutionResult, OrderState, PendingCancel, PendingOrder,
-    RejectReason, SkipReason, TrackedOrder,
+    ActionBatch, EnqueueResult, ExecutionResult, OrderState, PendingCancel, PendingModify,
+    PendingOrder, RejectReason, SignalValidity, SkipReason, TrackedOrder,
 };
@@ -54,6 +54,13 @@ impl AssetId {
     pub fn index(&self) -> u32 {
         self.0
     }
+
+    /// Perp DEX index encoded in a HIP-3 asset ID (None below 100000).
+    pub fn perp_dex_idx(&self) -> Option<u16> {
+        self.0
+            .checked_sub(100_000)
+            .map(|rest| (rest / 10_000) as u16)
+    }
 }
 
 impl fmt::Display for AssetId {
@@ -371,6 +378,14 @@ mod tests {
         assert_eq!(key.to_string(), "xyz:0");
     }
 
+    #[test]
+    fn test_asset_id_perp_dex_idx() {
+        // xyz:SILVER (perpDexId=1, index=27)
+        assert_eq!(AssetId::new(110027).perp_dex_idx(), Some(1));
+        assert_eq!(AssetId::new(100000).perp_dex_idx(), Some(0));
+        assert_eq!(AssetId::new(27).perp_dex_idx(), None);
+    }
+
     #[test]
     fn test_market_key_equality() {
         let key1 = MarketKey::from_indices(0, 1);
@@ -86,6 +86,36 @@ impl fmt::Display for TimeInForce {
     }
 }
 
+/// Strategy an order belongs to.
+///
+/// Decides the account (vault) the order is routed to: maker quotes and
+/// taker entries can trade from different sub-accounts.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStrategy {
+    /// Taker entries and exits, hedges and risk flattens.
+    #[default]
+    Taker,
+    /// Market-making quotes, their amendments and cancels, and MM flattens.
+    Maker,
+}
+
+impl OrderStrategy {
+    /// All strategies.
+    pub const ALL: [Self; 2] = [Self::Taker, Self::Maker];
+}
+
+impl fmt::Display for OrderStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Taker => write!(f, "taker"),
+            Self::Maker => write!(f, "maker"),
+        }
+    }
+}
+
 /// Client order ID for idempotency.
 ///
 /// CRITICAL: Every order must have a unique cloid to prevent
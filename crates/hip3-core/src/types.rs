@@ -8,6 +8,7 @@
 use crate::{Price, Size};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// BBO state for P0-14 (null side detection).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -63,6 +64,9 @@ pub struct Bbo {
     pub ask_size: Size,
     /// Timestamp when this BBO was received.
     pub received_at: DateTime<Utc>,
+    /// Exchange server time of the BBO (Unix ms), if the feed carries it.
+    #[serde(default)]
+    pub server_time: Option<i64>,
 }
 
 impl Bbo {
@@ -74,9 +78,16 @@ impl Bbo {
             ask_price,
             ask_size,
             received_at: Utc::now(),
+            server_time: None,
         }
     }
 
+    /// Attach the exchange server time (Unix ms).
+    pub fn with_server_time(mut self, server_time: Option<i64>) -> Self {
+        self.server_time = server_time;
+        self
+    }
+
     /// Calculate mid price: (bid + ask) / 2.
     ///
     /// Returns None if BBO state is not Valid.
@@ -242,6 +253,118 @@ impl AssetCtx {
     }
 }
 
+/// Single L2 order book level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookLevel {
+    /// Level price.
+    pub price: Price,
+    /// Aggregate size resting at this price.
+    pub size: Size,
+}
+
+impl BookLevel {
+    /// Create a new book level.
+    pub fn new(price: Price, size: Size) -> Self {
+        Self { price, size }
+    }
+}
+
+/// L2 order book (top N levels per side).
+///
+/// Bids are sorted best-first (descending), asks best-first (ascending).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct L2Book {
+    /// Bid levels, best (highest) first.
+    pub bids: Vec<BookLevel>,
+    /// Ask levels, best (lowest) first.
+    pub asks: Vec<BookLevel>,
+    /// Timestamp when this book was received.
+    pub received_at: DateTime<Utc>,
+    /// Exchange time of the snapshot (ms), when provided.
+    #[serde(default)]
+    pub server_time: Option<i64>,
+}
+
+impl L2Book {
+    /// Create a new L2 book.
+    pub fn new(bids: Vec<BookLevel>, asks: Vec<BookLevel>) -> Self {
+        Self {
+            bids,
+            asks,
+            received_at: Utc::now(),
+            server_time: None,
+        }
+    }
+
+    /// Set the exchange time of the snapshot (ms).
+    #[must_use]
+    pub fn with_server_time(mut self, server_time: Option<i64>) -> Self {
+        self.server_time = server_time;
+        self
+    }
+
+    /// Mid of the best bid and ask, if both sides have a level.
+    pub fn mid(&self) -> Option<Price> {
+        let bid = self.bids.first()?.price.inner();
+        let ask = self.asks.first()?.price.inner();
+        Some(Price::new((bid + ask) / rust_decimal::Decimal::TWO))
+    }
+
+    /// Size and notional a taker on `side` can trade within `within_bps` of
+    /// the best price on that side.
+    pub fn depth_within_bps(
+        &self,
+        side: crate::OrderSide,
+        within_bps: rust_decimal::Decimal,
+    ) -> (Size, rust_decimal::Decimal) {
+        let levels = self.take_levels(side);
+        let Some(best) = levels.first().map(|l| l.price.inner()) else {
+            return (Size::ZERO, rust_decimal::Decimal::ZERO);
+        };
+        let limit = best * within_bps / rust_decimal::Decimal::from(10000);
+        levels
+            .iter()
+            .take_while(|l| (l.price.inner() - best).abs() <= limit)
+            .fold(
+                (Size::ZERO, rust_decimal::Decimal::ZERO),
+                |(size, notional), l| {
+                    (
+                        Size::new(size.inner() + l.size.inner()),
+                        notional + l.size.inner() * l.price.inner(),
+                    )
+                },
+            )
+    }
+
+    /// Levels a taker on `side` would consume (Buy takes asks, Sell takes bids).
+    pub fn take_levels(&self, side: crate::OrderSide) -> &[BookLevel] {
+        match side {
+            crate::OrderSide::Buy => &self.asks,
+            crate::OrderSide::Sell => &self.bids,
+        }
+    }
+
+    /// Get age in milliseconds.
+    pub fn age_ms(&self) -> i64 {
+        (Utc::now() - self.received_at).num_milliseconds()
+    }
+}
+
+/// Public trade print (trades channel).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradePrint {
+    /// Trade price.
+    pub price: Price,
+    /// Trade size.
+    pub size: Size,
+    /// Aggressor (taker) side.
+    pub aggressor: crate::OrderSide,
+    /// Exchange trade time (Unix ms).
+    pub time_ms: u64,
+    /// Exchange trade id.
+    pub tid: u64,
+}
+
 /// Combined market state snapshot.
 ///
 /// Contains all real-time data needed for trading decisions.
@@ -253,6 +376,10 @@ pub struct MarketSnapshot {
     pub ctx: AssetCtx,
     /// Snapshot timestamp.
     pub timestamp: DateTime<Utc>,
+    /// L2 order book, if subscribed and received. Shared with the market
+    /// state so taking a snapshot does not copy the levels.
+    #[serde(default)]
+    pub book: Option<Arc<L2Book>>,
 }
 
 impl MarketSnapshot {
@@ -262,9 +389,17 @@ impl MarketSnapshot {
             bbo,
             ctx,
             timestamp: Utc::now(),
+            book: None,
         }
     }
 
+    /// Attach an L2 book to the snapshot.
+    #[must_use]
+    pub fn with_book(mut self, book: Option<Arc<L2Book>>) -> Self {
+        self.book = book;
+        self
+    }
+
     /// Get BBO state (P0-14).
     pub fn bbo_state(&self) -> BboState {
         self.bbo.state()
@@ -310,6 +445,22 @@ mod tests {
     use super::*;
     use rust_decimal_macros::dec;
 
+    #[test]
+    fn test_l2_book_take_levels() {
+        let book = L2Book::new(
+            vec![BookLevel::new(Price::new(dec!(99)), Size::new(dec!(1)))],
+            vec![
+                BookLevel::new(Price::new(dec!(101)), Size::new(dec!(2))),
+                BookLevel::new(Price::new(dec!(102)), Size::new(dec!(3))),
+            ],
+        );
+        assert_eq!(book.take_levels(crate::OrderSide::Buy).len(), 2);
+        assert_eq!(
+            book.take_levels(crate::OrderSide::Sell)[0].price.inner(),
+            dec!(99)
+        );
+    }
+
     #[test]
     fn test_bbo_mid_price() {
         let bbo = Bbo::new(
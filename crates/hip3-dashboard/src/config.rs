@@ -23,6 +23,12 @@ pub struct DashboardConfig {
     /// Basic auth password (empty = disabled).
     #[serde(default)]
     pub password: String,
+    /// Bearer token for the admin endpoints (empty = basic auth credentials).
+    ///
+    /// Set it when scripts or alerting need to hit the kill switch without
+    /// the dashboard password.
+    #[serde(default)]
+    pub admin_token: String,
 }
 
 fn default_enabled() -> bool {
@@ -50,6 +56,7 @@ impl Default for DashboardConfig {
             max_connections: default_max_connections(),
             username: String::new(),
             password: String::new(),
+            admin_token: String::new(),
         }
     }
 }
@@ -59,4 +66,9 @@ impl DashboardConfig {
     pub fn auth_enabled(&self) -> bool {
         !self.username.is_empty() && !self.password.is_empty()
     }
+
+    /// Check if the admin endpoints are available (token or basic auth set).
+    pub fn admin_enabled(&self) -> bool {
+        !self.admin_token.is_empty() || self.auth_enabled()
+    }
 }
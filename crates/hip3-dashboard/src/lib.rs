@@ -5,6 +5,7 @@
 //!
 //! - REST API for fetching current state
 //! - WebSocket for real-time updates (100ms interval)
+//! - Admin endpoint for signing-key rotation (requires basic auth)
 //! - Static HTML dashboard UI
 //!
 //! # Architecture
@@ -29,6 +30,7 @@
 //! │  │  GET /          → Static HTML/JS                          │  │
 //! │  │  GET /api/snapshot → JSON state                           │  │
 //! │  │  GET /ws        → WebSocket upgrade                       │  │
+//! │  │  POST /api/admin/rotate-key → Signing key rotation        │  │
 //! │  └───────────────────────────────────────────────────────────┘  │
 //! └───────────────────────────────────────────────────────────────────┘
 //! ```
@@ -65,6 +67,7 @@ pub use config::DashboardConfig;
 pub use server::run_server;
 pub use state::{DashboardState, SignalSender};
 pub use types::{
-    CompletedTrade, DashboardMessage, DashboardSnapshot, MarketDataSnapshot, MmStatus, PnlSummary,
-    PositionSnapshot, RiskAlertType, RiskStatus, SignalSnapshot,
+    CompletedTrade, DashboardMessage, DashboardSnapshot, MarketDataSnapshot, MmPnlBreakdown,
+    MmStatus, ParentOrderSnapshot, PnlSummary, PositionSnapshot, RiskAlertType, RiskStatus,
+    SignalSnapshot,
 };
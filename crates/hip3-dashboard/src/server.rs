@@ -8,10 +8,12 @@ use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
 use axum::extract::State;
 use axum::http::{header, HeaderMap, StatusCode};
 use axum::response::{Html, IntoResponse, Json, Response};
-use axum::routing::get;
+use axum::routing::{get, post};
 use axum::Router;
 use futures_util::stream::StreamExt;
 use futures_util::SinkExt;
+use hip3_executor::{HardStopLevel, KeyPurpose, RiskMonitorConfig, RiskMonitorConfigUpdate};
+use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast;
 use tracing::{debug, info, warn};
 
@@ -94,6 +96,20 @@ pub fn create_router(state: AppState) -> Router {
         .route("/", get(serve_index))
         .route("/api/snapshot", get(get_snapshot))
         .route("/ws", get(ws_handler))
+        .route("/api/admin/rotate-key", post(rotate_key))
+        .route(
+            "/api/admin/clear-weekly-loss-limit",
+            post(clear_weekly_loss_limit),
+        )
+        .route("/api/admin/hard-stop", post(trigger_hard_stop))
+        .route("/api/admin/hard-stop/clear", post(clear_hard_stop))
+        .route("/api/admin/pause-entries", post(pause_entries))
+        .route("/api/admin/resume-entries", post(resume_entries))
+        .route("/api/admin/risk-monitor", post(update_risk_monitor))
+        .route(
+            "/api/admin/reject-breaker/clear",
+            post(clear_reject_breaker),
+        )
         .with_state(state)
 }
 
@@ -123,6 +139,239 @@ async fn get_snapshot(
     Ok(Json(snapshot))
 }
 
+/// Kill-switch or pause request.
+#[derive(Debug, Deserialize)]
+struct AdminReasonRequest {
+    /// Operator-supplied reason, logged and shown on the dashboard.
+    reason: String,
+}
+
+/// Hard stop trigger request.
+#[derive(Debug, Deserialize)]
+struct HardStopRequest {
+    /// Operator-supplied reason, logged and shown on the dashboard.
+    reason: String,
+    /// 1 = block new entries, 2 = full stop (default).
+    #[serde(default = "default_hard_stop_level")]
+    level: u8,
+}
+
+fn default_hard_stop_level() -> u8 {
+    2
+}
+
+/// RiskMonitor threshold update request.
+#[derive(Debug, Deserialize)]
+struct RiskMonitorUpdateRequest {
+    /// Operator-supplied reason, recorded with the changes.
+    reason: String,
+    /// Thresholds to change; omitted ones are kept.
+    #[serde(flatten)]
+    update: RiskMonitorConfigUpdate,
+}
+
+/// Rejection breaker clear request.
+#[derive(Debug, Deserialize)]
+struct RejectBreakerClearRequest {
+    /// Operator-supplied reason, logged with the clear.
+    reason: String,
+    /// Market to clear ("dex:asset", as in logs); omitted = all tripped markets.
+    #[serde(default)]
+    market: Option<String>,
+}
+
+/// Signing-key rotation request.
+#[derive(Debug, Deserialize)]
+struct RotateKeyRequest {
+    /// Purpose whose key is replaced by the next standby key.
+    purpose: KeyPurpose,
+}
+
+/// Signing-key rotation result.
+#[derive(Debug, Serialize)]
+struct RotateKeyResponse {
+    purpose: KeyPurpose,
+    /// Address of the newly active key.
+    address: String,
+    /// Standby keys left after the rotation.
+    standby_remaining: usize,
+}
+
+/// Rotate a signing key to the next standby key.
+///
+/// Subsequent actions for the purpose are signed with the new key.
+async fn rotate_key(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<RotateKeyRequest>,
+) -> Result<Json<RotateKeyResponse>, Response> {
+    check_admin_auth(&headers, &state.config)?;
+    let Some(key_manager) = state.dashboard_state.key_manager() else {
+        return Err((StatusCode::NOT_FOUND, "No signing keys").into_response());
+    };
+
+    match key_manager.rotate(request.purpose) {
+        Ok(address) => {
+            info!(purpose = %request.purpose, ?address, "Signing key rotated via admin endpoint");
+            Ok(Json(RotateKeyResponse {
+                purpose: request.purpose,
+                address: format!("{address:#x}"),
+                standby_remaining: key_manager.standby_count(),
+            }))
+        }
+        Err(e) => {
+            warn!(purpose = %request.purpose, error = %e, "Signing key rotation failed");
+            Err((StatusCode::CONFLICT, e.to_string()).into_response())
+        }
+    }
+}
+
+/// Clear the weekly loss limit latch so entries can resume.
+async fn clear_weekly_loss_limit(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<StatusCode, Response> {
+    check_admin_auth(&headers, &state.config)?;
+    let Some(gate) = state.dashboard_state.drawdown_gate() else {
+        return Err((StatusCode::NOT_FOUND, "No loss limits configured").into_response());
+    };
+
+    gate.clear_weekly_latch();
+    info!("Weekly loss limit cleared via admin endpoint");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Trigger the hard stop.
+///
+/// Level 1 rejects new entries; level 2 (default) also flattens open
+/// positions. The latch only escalates.
+async fn trigger_hard_stop(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<HardStopRequest>,
+) -> Result<StatusCode, Response> {
+    check_admin_auth(&headers, &state.config)?;
+    let Some(latch) = state.dashboard_state.hard_stop_latch() else {
+        return Err((StatusCode::NOT_FOUND, "No hard stop in observation mode").into_response());
+    };
+    let level = match request.level {
+        1 => HardStopLevel::PauseEntries,
+        2 => HardStopLevel::FullStop,
+        _ => return Err((StatusCode::BAD_REQUEST, "level must be 1 or 2").into_response()),
+    };
+
+    warn!(reason = %request.reason, ?level, "HardStop triggered via admin endpoint");
+    latch.trigger_level(level, &format!("Admin: {}", request.reason));
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Clear the hard stop after review so trading can resume.
+async fn clear_hard_stop(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<StatusCode, Response> {
+    check_admin_auth(&headers, &state.config)?;
+    let Some(latch) = state.dashboard_state.hard_stop_latch() else {
+        return Err((StatusCode::NOT_FOUND, "No hard stop in observation mode").into_response());
+    };
+
+    warn!(
+        previous_reason = ?latch.trigger_reason(),
+        "HardStop cleared via admin endpoint"
+    );
+    latch.reset();
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Pause new entries; exits, stops and cancels keep running.
+async fn pause_entries(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<AdminReasonRequest>,
+) -> Result<StatusCode, Response> {
+    check_admin_auth(&headers, &state.config)?;
+    let Some(pause) = state.dashboard_state.entry_pause() else {
+        return Err((StatusCode::NOT_FOUND, "No entry pause switch").into_response());
+    };
+
+    pause.pause(&request.reason);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Resume new entries after a pause.
+async fn resume_entries(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<StatusCode, Response> {
+    check_admin_auth(&headers, &state.config)?;
+    let Some(pause) = state.dashboard_state.entry_pause() else {
+        return Err((StatusCode::NOT_FOUND, "No entry pause switch").into_response());
+    };
+
+    pause.resume();
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Update RiskMonitor thresholds without a restart.
+///
+/// Counters (cumulative PnL, loss streaks) are kept, so a tightened
+/// threshold trips on the next event that checks it. Returns the thresholds
+/// now in effect; an update without fields just reads them.
+async fn update_risk_monitor(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<RiskMonitorUpdateRequest>,
+) -> Result<Json<RiskMonitorConfig>, Response> {
+    check_admin_auth(&headers, &state.config)?;
+    let Some(settings) = state.dashboard_state.risk_monitor_settings() else {
+        return Err((StatusCode::NOT_FOUND, "No RiskMonitor").into_response());
+    };
+
+    match settings.update(&request.update, &format!("Admin: {}", request.reason)) {
+        Ok(config) => Ok(Json(config)),
+        Err(e) => {
+            warn!(error = %e, "RiskMonitor threshold update refused");
+            Err((StatusCode::BAD_REQUEST, e.to_string()).into_response())
+        }
+    }
+}
+
+/// Re-enable markets disabled by the rejection breaker after review.
+///
+/// Returns the markets cleared.
+async fn clear_reject_breaker(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<RejectBreakerClearRequest>,
+) -> Result<Json<Vec<String>>, Response> {
+    check_admin_auth(&headers, &state.config)?;
+    let Some(breaker) = state.dashboard_state.reject_breaker() else {
+        return Err((StatusCode::NOT_FOUND, "No rejection breaker").into_response());
+    };
+
+    let tripped: Vec<_> = breaker
+        .tripped_markets()
+        .into_iter()
+        .filter(|market| {
+            request
+                .market
+                .as_ref()
+                .map_or(true, |m| *m == market.to_string())
+        })
+        .collect();
+    if request.market.is_some() && tripped.is_empty() {
+        return Err((StatusCode::NOT_FOUND, "Market not tripped").into_response());
+    }
+
+    let cleared: Vec<String> = tripped
+        .iter()
+        .filter(|market| breaker.clear(market))
+        .map(ToString::to_string)
+        .collect();
+    warn!(reason = %request.reason, ?cleared, "RejectBreaker cleared via admin endpoint");
+    Ok(Json(cleared))
+}
+
 /// WebSocket upgrade handler.
 async fn ws_handler(
     State(state): State<AppState>,
@@ -242,6 +491,45 @@ async fn handle_ws_connection(socket: WebSocket, state: AppState) {
     );
 }
 
+/// Authorize an admin request.
+///
+/// With `admin_token` set, requires `Authorization: Bearer <token>`;
+/// otherwise requires the basic auth credentials. Admin endpoints are
+/// disabled (403) when neither is configured.
+// Same error type as the handlers so they can use `?`
+#[allow(clippy::result_large_err)]
+fn check_admin_auth(headers: &HeaderMap, config: &DashboardConfig) -> Result<(), Response> {
+    if !config.admin_enabled() {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "Admin endpoints require an admin token or basic auth",
+        )
+            .into_response());
+    }
+    let authorized = if config.admin_token.is_empty() {
+        check_basic_auth(headers, config)
+    } else {
+        headers
+            .get(header::AUTHORIZATION)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|s| s.strip_prefix("Bearer "))
+            .is_some_and(|token| constant_time_eq(token.as_bytes(), config.admin_token.as_bytes()))
+    };
+    if authorized {
+        Ok(())
+    } else {
+        Err(unauthorized_response())
+    }
+}
+
+/// Compare secrets without an early exit on the first differing byte.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 /// Check basic authentication.
 fn check_basic_auth(headers: &HeaderMap, config: &DashboardConfig) -> bool {
     let auth_header = match headers.get(header::AUTHORIZATION) {
@@ -358,3 +646,32 @@ pub async fn run_server(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(auth: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::AUTHORIZATION, auth.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_admin_auth() {
+        let mut config = DashboardConfig::default();
+        // Neither token nor basic auth: admin endpoints disabled
+        assert!(check_admin_auth(&headers("Bearer x"), &config).is_err());
+
+        // Basic auth credentials ("admin:secret")
+        config.username = "admin".to_string();
+        config.password = "secret".to_string();
+        assert!(check_admin_auth(&headers("Basic YWRtaW46c2VjcmV0"), &config).is_ok());
+
+        // Token takes over once set
+        config.admin_token = "t0ken".to_string();
+        assert!(check_admin_auth(&headers("Bearer t0ken"), &config).is_ok());
+        assert!(check_admin_auth(&headers("Bearer t0kem"), &config).is_err());
+        assert!(check_admin_auth(&headers("Basic YWRtaW46c2VjcmV0"), &config).is_err());
+    }
+}
@@ -13,14 +13,16 @@ use tokio::sync::mpsc;
 
 use hip3_core::types::MarketSnapshot;
 use hip3_core::{MarketKey, OrderSide};
-use hip3_executor::HardStopLatch;
+use hip3_executor::{EntryPause, HardStopLatch, KeyManager, OrderSlicer, RiskMonitorSettings};
 use hip3_feed::MarketState;
 use hip3_persistence::SignalRecord;
 use hip3_position::PositionTrackerHandle;
+use hip3_risk::{MaxDrawdownGate, NetExposureGate, RejectBreaker};
 
 use crate::types::{
-    CompletedTrade, DashboardSnapshot, MarketDataSnapshot, MarketPnlStats, MmStatus, PnlSummary,
-    PositionSnapshot, RiskStatus, SignalSnapshot,
+    CompletedTrade, DashboardSnapshot, HardStopHistorySnapshot, LossLimitSnapshot,
+    MarketDataSnapshot, MarketPnlStats, MmStatus, NetExposureSnapshot, ParentOrderSnapshot,
+    PnlSummary, PositionSnapshot, RiskStatus, SignalSnapshot,
 };
 
 /// Sender type for pushing signals in real-time to the dashboard.
@@ -46,6 +48,8 @@ pub struct DashboardState {
     recent_signals: Arc<RwLock<VecDeque<SignalRecord>>>,
     /// Gate block state (market, gate) -> blocked.
     gate_block_state: Arc<RwLock<HashMap<(MarketKey, String), bool>>>,
+    /// Effective entry threshold per market (bps).
+    effective_thresholds: Arc<RwLock<HashMap<MarketKey, Decimal>>>,
     /// Whether running in observation mode (limited features).
     observation_mode: bool,
     /// Signal sender for real-time signal push (cloneable for external use).
@@ -56,6 +60,20 @@ pub struct DashboardState {
     completed_trades: Arc<RwLock<VecDeque<CompletedTrade>>>,
     /// P2-8: Market making status (updated from app.rs).
     mm_status: Arc<RwLock<Option<MmStatus>>>,
+    /// Sliced entry parent orders (None if slicing disabled).
+    order_slicer: Option<Arc<OrderSlicer>>,
+    /// Signing keys for the admin rotation endpoint (None unless attached).
+    key_manager: Option<Arc<KeyManager>>,
+    /// Net exposure view (None if netting disabled).
+    net_exposure_gate: Option<Arc<NetExposureGate>>,
+    /// Drawdown and daily/weekly loss limits (None unless attached).
+    drawdown_gate: Option<Arc<MaxDrawdownGate>>,
+    /// Operator entry pause switch (None unless attached).
+    entry_pause: Option<Arc<EntryPause>>,
+    /// RiskMonitor thresholds (None unless attached).
+    risk_monitor_settings: Option<RiskMonitorSettings>,
+    /// Per-market rejection breaker (None unless attached).
+    reject_breaker: Option<Arc<RejectBreaker>>,
 }
 
 impl DashboardState {
@@ -74,11 +92,19 @@ impl DashboardState {
             hard_stop_latch: Some(hard_stop_latch),
             recent_signals,
             gate_block_state: Arc::new(RwLock::new(HashMap::new())),
+            effective_thresholds: Arc::new(RwLock::new(HashMap::new())),
             observation_mode: false,
             signal_tx,
             signal_rx: Arc::new(tokio::sync::Mutex::new(Some(signal_rx))),
             completed_trades: Arc::new(RwLock::new(VecDeque::new())),
             mm_status: Arc::new(RwLock::new(None)),
+            order_slicer: None,
+            key_manager: None,
+            net_exposure_gate: None,
+            drawdown_gate: None,
+            entry_pause: None,
+            risk_monitor_settings: None,
+            reject_breaker: None,
         }
     }
 
@@ -100,14 +126,105 @@ impl DashboardState {
             hard_stop_latch: None,
             recent_signals,
             gate_block_state: Arc::new(RwLock::new(HashMap::new())),
+            effective_thresholds: Arc::new(RwLock::new(HashMap::new())),
             observation_mode: true,
             signal_tx,
             signal_rx: Arc::new(tokio::sync::Mutex::new(Some(signal_rx))),
             completed_trades: Arc::new(RwLock::new(VecDeque::new())),
             mm_status: Arc::new(RwLock::new(None)),
+            order_slicer: None,
+            key_manager: None,
+            net_exposure_gate: None,
+            drawdown_gate: None,
+            entry_pause: None,
+            risk_monitor_settings: None,
+            reject_breaker: None,
         }
     }
 
+    /// Attach the order slicer so parent orders show on the dashboard.
+    #[must_use]
+    pub fn with_order_slicer(mut self, slicer: Arc<OrderSlicer>) -> Self {
+        self.order_slicer = Some(slicer);
+        self
+    }
+
+    /// Attach the key manager so keys can be rotated via the admin endpoint.
+    #[must_use]
+    pub fn with_key_manager(mut self, key_manager: Arc<KeyManager>) -> Self {
+        self.key_manager = Some(key_manager);
+        self
+    }
+
+    /// Attach the net exposure gate so per-group net exposure shows on the dashboard.
+    #[must_use]
+    pub fn with_net_exposure(mut self, gate: Arc<NetExposureGate>) -> Self {
+        self.net_exposure_gate = Some(gate);
+        self
+    }
+
+    /// Attach the drawdown gate so loss limits show on the dashboard and the
+    /// weekly latch can be cleared via the admin endpoint.
+    #[must_use]
+    pub fn with_drawdown_gate(mut self, gate: Arc<MaxDrawdownGate>) -> Self {
+        self.drawdown_gate = Some(gate);
+        self
+    }
+
+    /// Attach the entry pause switch so entries can be paused via the admin
+    /// endpoints.
+    #[must_use]
+    pub fn with_entry_pause(mut self, pause: Arc<EntryPause>) -> Self {
+        self.entry_pause = Some(pause);
+        self
+    }
+
+    /// Entry pause switch (if attached).
+    pub fn entry_pause(&self) -> Option<&Arc<EntryPause>> {
+        self.entry_pause.as_ref()
+    }
+
+    /// Attach the RiskMonitor thresholds so they can be updated via the
+    /// admin endpoint.
+    #[must_use]
+    pub fn with_risk_monitor_settings(mut self, settings: RiskMonitorSettings) -> Self {
+        self.risk_monitor_settings = Some(settings);
+        self
+    }
+
+    /// Attach the rejection breaker so tripped markets can be cleared via
+    /// the admin endpoint.
+    #[must_use]
+    pub fn with_reject_breaker(mut self, breaker: Arc<RejectBreaker>) -> Self {
+        self.reject_breaker = Some(breaker);
+        self
+    }
+
+    /// Rejection breaker (if attached).
+    pub fn reject_breaker(&self) -> Option<&Arc<RejectBreaker>> {
+        self.reject_breaker.as_ref()
+    }
+
+    /// RiskMonitor thresholds (if attached).
+    pub fn risk_monitor_settings(&self) -> Option<&RiskMonitorSettings> {
+        self.risk_monitor_settings.as_ref()
+    }
+
+    /// Hard stop latch (None in Observation mode).
+    pub fn hard_stop_latch(&self) -> Option<&Arc<HardStopLatch>> {
+        self.hard_stop_latch.as_ref()
+    }
+
+    /// Drawdown gate for clearing the weekly loss latch (if attached).
+    pub fn drawdown_gate(&self) -> Option<&Arc<MaxDrawdownGate>> {
+        self.drawdown_gate.as_ref()
+    }
+
+    /// Key manager for signing-key rotation (if attached).
+    pub fn key_manager(&self) -> Option<&Arc<KeyManager>> {
+        self.key_manager.as_ref()
+    }
+
     /// Get a clone of the signal sender for external use.
     ///
     /// Use this to send signals in real-time to the dashboard.
@@ -129,6 +246,13 @@ impl DashboardState {
         state.insert((market, gate), blocked);
     }
 
+    /// Update effective entry threshold (called from main bot loop).
+    pub fn update_effective_threshold(&self, market: MarketKey, threshold_bps: Decimal) {
+        self.effective_thresholds
+            .write()
+            .insert(market, threshold_bps);
+    }
+
     /// Collect a full snapshot of the current state.
     pub fn collect_snapshot(&self) -> DashboardSnapshot {
         let timestamp_ms = Utc::now().timestamp_millis();
@@ -156,7 +280,13 @@ impl DashboardState {
         let pnl_summary = self.collect_pnl_summary(&positions);
 
         // P2-8: Collect MM status
-        let mm_status = self.mm_status.read().clone();
+        let mm_status = self.mm_status.read().clone().map(Box::new);
+
+        // Sliced entry parent orders
+        let parent_orders = self.collect_parent_orders();
+
+        // Net exposure per netting group
+        let net_exposure = self.collect_net_exposure();
 
         DashboardSnapshot {
             timestamp_ms,
@@ -167,9 +297,60 @@ impl DashboardState {
             recent_signals,
             pnl_summary,
             mm_status,
+            parent_orders,
+            net_exposure,
         }
     }
 
+    /// Collect net exposure per netting group (empty if netting disabled or
+    /// a mark price is unavailable).
+    fn collect_net_exposure(&self) -> Vec<NetExposureSnapshot> {
+        let Some(ref gate) = self.net_exposure_gate else {
+            return Vec::new();
+        };
+        let Some(exposure) = gate.exposure(|market| {
+            self.market_state
+                .get_ctx(market)
+                .map(|ctx| ctx.oracle.mark_px)
+        }) else {
+            return Vec::new();
+        };
+        exposure
+            .groups
+            .into_iter()
+            .map(|g| NetExposureSnapshot {
+                group: g.name,
+                long_notional: g.long_notional,
+                short_notional: g.short_notional,
+                net_notional: g.net_notional,
+            })
+            .collect()
+    }
+
+    /// Collect sliced entry parent orders (empty if slicing disabled).
+    fn collect_parent_orders(&self) -> Vec<ParentOrderSnapshot> {
+        let Some(ref slicer) = self.order_slicer else {
+            return Vec::new();
+        };
+        let now_ms = Utc::now().timestamp_millis() as u64;
+        slicer
+            .snapshot()
+            .into_iter()
+            .map(|parent| ParentOrderSnapshot {
+                id: parent.id,
+                market_key: parent.market.to_string(),
+                side: parent.side.to_string(),
+                state: parent.state.as_str().to_string(),
+                limit_price: parent.limit_price.inner(),
+                total_size: parent.total_size.inner(),
+                sent_size: parent.sent_size.inner(),
+                slices_sent: parent.slices_sent,
+                slices_total: parent.slices_total,
+                age_ms: now_ms.saturating_sub(parent.created_at),
+            })
+            .collect()
+    }
+
     /// Collect market data snapshots.
     fn collect_markets(&self) -> HashMap<String, MarketDataSnapshot> {
         let mut markets = HashMap::new();
@@ -227,6 +408,7 @@ impl DashboardState {
         // Get ages
         let bbo_age_ms = self.market_state.get_bbo_age_ms(market_key);
         let oracle_age_ms = self.market_state.get_oracle_age_ms(market_key);
+        let effective_threshold_bps = self.effective_thresholds.read().get(market_key).copied();
 
         MarketDataSnapshot {
             market_key: market_key.to_string(),
@@ -241,6 +423,7 @@ impl DashboardState {
             sell_edge_bps,
             bbo_age_ms,
             oracle_age_ms,
+            effective_threshold_bps,
         }
     }
 
@@ -309,15 +492,37 @@ impl DashboardState {
     /// Collect risk status (limited info in Observation mode).
     fn collect_risk_status(&self) -> RiskStatus {
         // In Observation mode, return safe defaults
-        let (hard_stop_triggered, hard_stop_reason, hard_stop_elapsed_ms) =
-            match &self.hard_stop_latch {
-                Some(latch) => (
-                    latch.is_triggered(),
-                    latch.trigger_reason(),
-                    latch.elapsed_since_trigger().map(|d| d.as_millis() as u64),
-                ),
-                None => (false, None, None), // Observation mode
-            };
+        let (hard_stop_level, hard_stop_reason, hard_stop_elapsed_ms) = match &self.hard_stop_latch
+        {
+            Some(latch) => (
+                latch.level().as_u8(),
+                latch.trigger_reason(),
+                latch.elapsed_since_trigger().map(|d| d.as_millis() as u64),
+            ),
+            None => (0, None, None), // Observation mode
+        };
+        let hard_stop_triggered = hard_stop_level > 0;
+        let hard_stop_history = self
+            .hard_stop_latch
+            .as_ref()
+            .map(|latch| {
+                latch
+                    .history()
+                    .into_iter()
+                    .rev() // Newest first
+                    .take(20) // Limit for dashboard
+                    .map(|entry| HardStopHistorySnapshot {
+                        triggered_at_ms: entry.triggered_at_ms,
+                        level: entry.level.as_u8(),
+                        kind: entry.kind.as_str().to_string(),
+                        source: entry.source.to_string(),
+                        reason: entry.reason,
+                        cleared_at_ms: entry.cleared_at_ms,
+                        cleared_by: entry.cleared_by.map(str::to_string),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
 
         // Collect gate blocks
         let gate_blocks = {
@@ -329,19 +534,43 @@ impl DashboardState {
                 .collect()
         };
 
+        // Daily/weekly loss limits
+        let loss_limits = self.drawdown_gate.as_ref().map(|gate| {
+            let status = gate.loss_limit_status();
+            LossLimitSnapshot {
+                daily_pnl_usd: status.daily_pnl_usd,
+                weekly_pnl_usd: status.weekly_pnl_usd,
+                daily_latched: status.daily_latched,
+                weekly_latched: status.weekly_latched,
+            }
+        });
+        let loss_latched = loss_limits
+            .as_ref()
+            .is_some_and(|l| l.daily_latched || l.weekly_latched);
+
+        let (entries_paused, entries_paused_reason) = match &self.entry_pause {
+            Some(pause) if pause.is_paused() => (true, pause.reason()),
+            _ => (false, None),
+        };
+
         // In Observation mode, trading is not applicable (show as allowed for display)
-        let trading_allowed = !hard_stop_triggered;
+        let trading_allowed = !hard_stop_triggered && !loss_latched && !entries_paused;
 
         RiskStatus {
             hard_stop_triggered,
+            hard_stop_level,
             hard_stop_reason: if self.observation_mode && hard_stop_reason.is_none() {
                 Some("Observation mode - trading disabled".to_string())
             } else {
                 hard_stop_reason
             },
             hard_stop_elapsed_ms,
+            hard_stop_history,
             gate_blocks,
+            entries_paused,
+            entries_paused_reason,
             trading_allowed,
+            loss_limits,
         }
     }
 
@@ -415,7 +644,7 @@ impl DashboardState {
             }
         }
         let mut market_stats: Vec<MarketPnlStats> = market_map.into_values().collect();
-        market_stats.sort_by(|a, b| b.trade_count.cmp(&a.trade_count));
+        market_stats.sort_by_key(|s| std::cmp::Reverse(s.trade_count));
 
         // Current unrealized PnL from open positions.
         let current_unrealized_pnl: f64 = positions
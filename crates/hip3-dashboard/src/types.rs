@@ -26,7 +26,13 @@ pub struct DashboardSnapshot {
     pub pnl_summary: PnlSummary,
     /// P2-8: Market making status (None if MM not configured).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mm_status: Option<MmStatus>,
+    pub mm_status: Option<Box<MmStatus>>,
+    /// Sliced entry parent orders (active first, then recently finished).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parent_orders: Vec<ParentOrderSnapshot>,
+    /// Net exposure per netting group (empty if netting disabled).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub net_exposure: Vec<NetExposureSnapshot>,
 }
 
 /// Market data snapshot for a single market.
@@ -58,6 +64,8 @@ pub struct MarketDataSnapshot {
     pub bbo_age_ms: Option<i64>,
     /// Oracle age in milliseconds.
     pub oracle_age_ms: Option<i64>,
+    /// Live entry threshold in basis points (None until first detector check).
+    pub effective_threshold_bps: Option<Decimal>,
 }
 
 /// Position snapshot.
@@ -81,19 +89,102 @@ pub struct PositionSnapshot {
     pub hold_time_ms: u64,
 }
 
+/// Sliced entry parent order snapshot.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParentOrderSnapshot {
+    /// Parent order id.
+    pub id: u64,
+    /// Market key.
+    pub market_key: String,
+    /// Side: "buy" or "sell".
+    pub side: String,
+    /// State: "active", "completed", "edge_lost" or "stopped".
+    pub state: String,
+    /// Limit price shared by every child.
+    pub limit_price: Decimal,
+    /// Total size to work.
+    pub total_size: Decimal,
+    /// Size sent so far.
+    pub sent_size: Decimal,
+    /// Children sent so far.
+    pub slices_sent: u32,
+    /// Planned number of children.
+    pub slices_total: u32,
+    /// Time since the parent started (milliseconds).
+    pub age_ms: u64,
+}
+
+/// Net exposure of one netting group (or ungrouped market).
+#[derive(Debug, Clone, Serialize)]
+pub struct NetExposureSnapshot {
+    /// Group name (market key for an ungrouped market).
+    pub group: String,
+    /// Long notional (USD).
+    pub long_notional: Decimal,
+    /// Short notional (USD).
+    pub short_notional: Decimal,
+    /// Net notional after offsetting (USD).
+    pub net_notional: Decimal,
+}
+
 /// Risk status summary.
 #[derive(Debug, Clone, Serialize)]
 pub struct RiskStatus {
-    /// Hard stop latch triggered.
+    /// Hard stop latch triggered (any level).
     pub hard_stop_triggered: bool,
+    /// Hard stop level (0 = clear, 1 = entries blocked, 2 = full stop).
+    pub hard_stop_level: u8,
     /// Hard stop reason (if triggered).
     pub hard_stop_reason: Option<String>,
     /// Time since hard stop trigger (milliseconds).
     pub hard_stop_elapsed_ms: Option<u64>,
+    /// Recent hard stop triggers and clears (newest first).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hard_stop_history: Vec<HardStopHistorySnapshot>,
     /// Active gate blocks (gate name -> reason).
     pub gate_blocks: HashMap<String, String>,
+    /// New entries paused by the operator.
+    pub entries_paused: bool,
+    /// Pause reason (if paused).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entries_paused_reason: Option<String>,
     /// Overall trading allowed status.
     pub trading_allowed: bool,
+    /// Daily/weekly loss limits (None if not configured).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loss_limits: Option<LossLimitSnapshot>,
+}
+
+/// One hard stop trigger and its clear.
+#[derive(Debug, Clone, Serialize)]
+pub struct HardStopHistorySnapshot {
+    /// Trigger time (Unix milliseconds).
+    pub triggered_at_ms: i64,
+    /// Level after the trigger (1 = entries blocked, 2 = full stop).
+    pub level: u8,
+    /// Kind of condition (e.g. "rejections").
+    pub kind: String,
+    /// Component that raised it (e.g. "risk_monitor").
+    pub source: String,
+    /// Trigger detail.
+    pub reason: String,
+    /// Clear time (Unix milliseconds), None while latched.
+    pub cleared_at_ms: Option<i64>,
+    /// How it was cleared: "operator" or "auto_recovery".
+    pub cleared_by: Option<String>,
+}
+
+/// Daily and weekly loss limit state.
+#[derive(Debug, Clone, Serialize)]
+pub struct LossLimitSnapshot {
+    /// Realized PnL of the current UTC day (USD).
+    pub daily_pnl_usd: f64,
+    /// Realized PnL of the current UTC week (USD).
+    pub weekly_pnl_usd: f64,
+    /// Daily limit hit; entries paused until the next UTC day.
+    pub daily_latched: bool,
+    /// Weekly limit hit; entries paused until cleared via the admin endpoint.
+    pub weekly_latched: bool,
 }
 
 /// Signal snapshot for display.
@@ -122,6 +213,7 @@ pub struct SignalSnapshot {
 }
 
 /// WebSocket message types (tagged enum for type safety).
+// Snapshots are only built once per connect, so their size doesn't matter.
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum DashboardMessage {
@@ -240,6 +332,25 @@ pub struct MmStatus {
     pub realized_pnl: f64,
     /// Per-market MM inventory (market -> net size in base units).
     pub inventory: HashMap<String, f64>,
+    /// Markets faded for toxic flow (market -> fade end, Unix ms).
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub toxic_fades: HashMap<String, u64>,
+    /// Total spread capture: edge at fill vs the oracle (USD).
+    pub spread_capture_usd: f64,
+    /// Total inventory PnL: oracle drift on held inventory (USD).
+    pub inventory_pnl_usd: f64,
+    /// Per-market PnL decomposition.
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub pnl_breakdown: HashMap<String, MmPnlBreakdown>,
+}
+
+/// MM PnL of one market, split into spread capture and inventory PnL.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct MmPnlBreakdown {
+    /// Edge at fill vs the oracle (USD).
+    pub spread_capture_usd: f64,
+    /// Oracle drift on held inventory (USD).
+    pub inventory_pnl_usd: f64,
 }
 
 /// Risk alert types.
@@ -267,14 +378,21 @@ mod tests {
             pending_orders: 0,
             risk: RiskStatus {
                 hard_stop_triggered: false,
+                hard_stop_level: 0,
                 hard_stop_reason: None,
                 hard_stop_elapsed_ms: None,
+                hard_stop_history: vec![],
                 gate_blocks: HashMap::new(),
+                entries_paused: false,
+                entries_paused_reason: None,
                 trading_allowed: true,
+                loss_limits: None,
             },
             recent_signals: vec![],
             pnl_summary: PnlSummary::default(),
             mm_status: None,
+            parent_orders: vec![],
+            net_exposure: vec![],
         };
 
         let json = serde_json::to_string(&snapshot).unwrap();
@@ -282,6 +400,9 @@ mod tests {
         assert!(json.contains("\"trading_allowed\":true"));
         // mm_status is None, should be omitted
         assert!(!json.contains("\"mm_status\""));
+        // No sliced entries, should be omitted
+        assert!(!json.contains("\"parent_orders\""));
+        assert!(!json.contains("\"net_exposure\""));
     }
 
     #[test]
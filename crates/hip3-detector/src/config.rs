@@ -1,6 +1,8 @@
 //! Detector configuration.
 
+use crate::signal::{SignalStrength, StrengthBucket};
 use chrono::Timelike;
+use hip3_feed::VolRegime;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -139,11 +141,14 @@ pub struct DetectorConfig {
     /// When enabled, a multi-factor confidence score (0.0-1.0) adjusts sizing:
     ///   `final_size = base_size * (0.5 + 0.5 * confidence)`
     ///
-    /// Factors: edge magnitude (0.3), oracle velocity (0.2),
-    /// consecutive moves (0.2), book depth (0.15), spread tightness (0.15).
+    /// Factors and their weights are set by `confidence_weights`.
     #[serde(default)]
     pub confidence_sizing: bool,
 
+    /// Per-feature weights for the confidence score (P3-1).
+    #[serde(default)]
+    pub confidence_weights: ConfidenceWeights,
+
     // ---- Sprint 2: Oracle-Quote Baseline Tracker ----
     /// Enable oracle-quote baseline tracking (Sprint 2).
     ///
@@ -244,6 +249,17 @@ pub struct DetectorConfig {
     #[serde(default = "default_short_threshold_mult")]
     pub short_threshold_mult: Decimal,
 
+    /// Base threshold (bps) for BUY signals, replacing the fee-derived
+    /// total cost. Per-market overrides take precedence.
+    #[serde(default)]
+    pub threshold_bps_long: Option<Decimal>,
+
+    /// Base threshold (bps) for SELL signals, replacing the fee-derived
+    /// total cost. Per-market overrides take precedence. Composes with
+    /// `short_side_throttle`.
+    #[serde(default)]
+    pub threshold_bps_short: Option<Decimal>,
+
     // ---- Structural Improvement: Velocity Weight (Item 6) ----
     /// Enable continuous velocity weighting on threshold.
     ///
@@ -299,6 +315,184 @@ pub struct DetectorConfig {
     /// Sizing multiplier during US active hours (16:00-21:00 UTC).
     #[serde(default = "default_us_active_sizing_mult")]
     pub us_active_sizing_mult: Decimal,
+
+    // ---- L2 Depth-Weighted Detection ----
+    /// Enable depth-weighted detection from the l2Book feed.
+    ///
+    /// A 1-lot stale quote at the top of book can cross oracle with no real
+    /// size behind it. When enabled, the detector walks book levels on the
+    /// take side, counts only levels that individually clear the threshold
+    /// (up to `max_notional`), and uses the VWAP edge and fillable size.
+    /// Signals are skipped when no fresh book is available.
+    /// The bot subscribes to l2Book automatically when this is set.
+    #[serde(default)]
+    pub l2_depth_enabled: bool,
+
+    /// Minimum notional (USD) that must cross the threshold in L2 mode.
+    #[serde(default = "default_l2_min_fillable_notional")]
+    pub l2_min_fillable_notional: Decimal,
+
+    /// Maximum L2 book age in ms; older books are ignored (0 = no limit).
+    #[serde(default = "default_l2_max_book_age_ms")]
+    pub l2_max_book_age_ms: i64,
+
+    // ---- Funding-Adjusted Edge ----
+    /// Subtract expected funding from net edge.
+    ///
+    /// Uses the market's current hourly funding rate and counts settlements
+    /// that fall within `funding_max_hold_ms` of detection. Funding the
+    /// position would receive is not credited. Signals whose net edge drops
+    /// to zero or below are skipped.
+    #[serde(default)]
+    pub funding_adjust_enabled: bool,
+
+    /// Maximum expected hold time (ms) used for the funding estimate.
+    /// Should match the position time stop.
+    #[serde(default = "default_funding_max_hold_ms")]
+    pub funding_max_hold_ms: u64,
+
+    // ---- Signal TTL ----
+    /// Signal time-to-live in ms (0 = no TTL).
+    ///
+    /// When set, the executor drops signals older than this and re-checks
+    /// the edge against the latest quote before queuing.
+    #[serde(default)]
+    pub signal_ttl_ms: u64,
+
+    // ---- Volatility Regime ----
+    /// Scale threshold and size by the market's volatility regime.
+    /// Requires a `RegimeClassifier` attached to the detector.
+    #[serde(default)]
+    pub regime_enabled: bool,
+
+    /// Threshold multiplier in the calm regime.
+    #[serde(default = "default_regime_calm_threshold_mult")]
+    pub regime_calm_threshold_mult: Decimal,
+
+    /// Threshold multiplier in the turbulent regime.
+    /// Higher = demand more edge while the book is being swept.
+    #[serde(default = "default_regime_turbulent_threshold_mult")]
+    pub regime_turbulent_threshold_mult: Decimal,
+
+    /// Max notional in the calm regime (0 = use `max_notional`).
+    #[serde(default)]
+    pub regime_calm_max_notional: Decimal,
+
+    /// Max notional in the turbulent regime (0 = use `max_notional`).
+    #[serde(default)]
+    pub regime_turbulent_max_notional: Decimal,
+
+    // ---- Book Imbalance Filter ----
+    /// Require the resting queue to lean in the trade direction.
+    ///
+    /// Imbalance = (bid_size - ask_size) / (bid_size + ask_size). Buys need
+    /// imbalance >= `imbalance_min` (thin asks), sells need
+    /// imbalance <= -`imbalance_min` (thin bids).
+    #[serde(default)]
+    pub imbalance_filter_enabled: bool,
+
+    /// Minimum imbalance in the trade direction (-1 .. 1).
+    #[serde(default = "default_imbalance_min")]
+    pub imbalance_min: Decimal,
+
+    /// Book levels summed per side (1 = BBO only). Deeper levels are used
+    /// only when a fresh L2 book is available; otherwise falls back to BBO.
+    #[serde(default = "default_imbalance_levels")]
+    pub imbalance_levels: usize,
+
+    // ---- Oracle Sanity Check ----
+    /// Suppress signals when the oracle disagrees with a secondary reference.
+    ///
+    /// Requires a `ReferencePriceStore` attached to the detector. Markets
+    /// without a fresh reference price are not checked (fail open).
+    #[serde(default)]
+    pub oracle_sanity_enabled: bool,
+
+    /// Maximum |oracle - reference| / reference in bps.
+    #[serde(default = "default_oracle_sanity_max_deviation_bps")]
+    pub oracle_sanity_max_deviation_bps: Decimal,
+
+    /// Reference prices older than this are ignored (ms, 0 = no limit).
+    #[serde(default = "default_oracle_sanity_max_age_ms")]
+    pub oracle_sanity_max_age_ms: u64,
+
+    // ---- Cross Duration Filter ----
+    /// Minimum time a cross must persist before it is traded (ms, 0 = off).
+    ///
+    /// Filters one-tick flickers. Overridable per market via
+    /// `MarketConfig::min_cross_duration_ms`.
+    #[serde(default)]
+    pub min_cross_duration_ms: u64,
+
+    // ---- Strength Buckets ----
+    /// Excess-edge buckets mapping to signal strength and size multiplier.
+    ///
+    /// Defaults reproduce the fixed Weak/Medium/Strong split (5/15 bps) at
+    /// 1x size. Larger multipliers are still bounded by the executor's
+    /// position caps.
+    #[serde(default = "default_strength_buckets")]
+    pub strength_buckets: Vec<StrengthBucket>,
+
+    // ---- Edge Decay ----
+    /// Discount edge by the time since the oracle last moved.
+    ///
+    /// The BBO lagging a fresh oracle move is the edge; a dislocation that
+    /// has survived for seconds is more likely informed flow. Full edge up to
+    /// `edge_decay_grace_ms`, decaying linearly to zero at
+    /// `edge_decay_max_age_ms`, suppressed beyond. Requires the oracle
+    /// movement tracker; markets with no recorded move are not penalized.
+    #[serde(default)]
+    pub edge_decay_enabled: bool,
+
+    /// Time after an oracle move with no decay (ms).
+    #[serde(default = "default_edge_decay_grace_ms")]
+    pub edge_decay_grace_ms: u64,
+
+    /// Time after an oracle move at which edge reaches zero (ms).
+    #[serde(default = "default_edge_decay_max_age_ms")]
+    pub edge_decay_max_age_ms: u64,
+
+    // ---- Momentum Veto ----
+    /// Skip signals while the quote is already moving toward the oracle.
+    ///
+    /// If the ask (buy) has risen, or the bid (sell) has fallen, by more
+    /// than `momentum_veto_bps` within `momentum_veto_window_ms`, the MM is
+    /// catching up and we would be chasing. Requires a `BboHistory`
+    /// attached to the detector.
+    #[serde(default)]
+    pub momentum_veto_enabled: bool,
+
+    /// Quote move toward the oracle that vetoes a signal (bps).
+    #[serde(default = "default_momentum_veto_bps")]
+    pub momentum_veto_bps: Decimal,
+
+    /// Lookback for the quote move (ms).
+    #[serde(default = "default_momentum_veto_window_ms")]
+    pub momentum_veto_window_ms: u64,
+}
+
+fn default_momentum_veto_bps() -> Decimal {
+    Decimal::from(3) // 3 bps
+}
+
+fn default_momentum_veto_window_ms() -> u64 {
+    500 // 500ms
+}
+
+fn default_edge_decay_grace_ms() -> u64 {
+    1_000 // 1s
+}
+
+fn default_edge_decay_max_age_ms() -> u64 {
+    5_000 // 5s
+}
+
+fn default_strength_buckets() -> Vec<StrengthBucket> {
+    vec![
+        StrengthBucket::new(Decimal::ZERO, SignalStrength::Weak, Decimal::ONE),
+        StrengthBucket::new(Decimal::from(5), SignalStrength::Medium, Decimal::ONE),
+        StrengthBucket::new(Decimal::from(15), SignalStrength::Strong, Decimal::ONE),
+    ]
 }
 
 fn default_min_order_notional() -> Decimal {
@@ -389,6 +583,81 @@ fn default_us_active_sizing_mult() -> Decimal {
     Decimal::new(75, 2) // 0.75x sizing during US active hours
 }
 
+fn default_l2_min_fillable_notional() -> Decimal {
+    Decimal::from(50) // $50 crossing depth
+}
+
+fn default_l2_max_book_age_ms() -> i64 {
+    2000 // 2s
+}
+
+fn default_funding_max_hold_ms() -> u64 {
+    30_000 // 30s, matches the default time stop
+}
+
+fn default_regime_calm_threshold_mult() -> Decimal {
+    Decimal::ONE // calm markets use the base threshold
+}
+
+fn default_regime_turbulent_threshold_mult() -> Decimal {
+    Decimal::new(15, 1) // 1.5x
+}
+
+fn default_imbalance_min() -> Decimal {
+    Decimal::new(2, 1) // 0.2 = 60/40 queue split
+}
+
+fn default_imbalance_levels() -> usize {
+    1 // BBO only
+}
+
+fn default_oracle_sanity_max_deviation_bps() -> Decimal {
+    Decimal::from(50) // 50 bps
+}
+
+fn default_oracle_sanity_max_age_ms() -> u64 {
+    5_000 // 5s
+}
+
+/// Per-feature weights for the confidence score.
+///
+/// The score is the weighted mean of the feature factors (each 0.0-1.0), so
+/// weights need not sum to 1. A zero weight drops the feature. The defaults
+/// reproduce the original fixed weighting; cross duration and book imbalance
+/// are opt-in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfidenceWeights {
+    /// Edge magnitude.
+    pub edge: Decimal,
+    /// Oracle velocity.
+    pub velocity: Decimal,
+    /// Consecutive oracle moves.
+    pub consecutive: Decimal,
+    /// Book depth (liquidity factor).
+    pub book_depth: Decimal,
+    /// Spread tightness vs total cost.
+    pub spread: Decimal,
+    /// Number of consecutive ticks the cross has persisted.
+    pub cross_duration: Decimal,
+    /// BBO size imbalance in the trade direction.
+    pub book_imbalance: Decimal,
+}
+
+impl Default for ConfidenceWeights {
+    fn default() -> Self {
+        Self {
+            edge: Decimal::new(30, 2),        // 0.30
+            velocity: Decimal::new(20, 2),    // 0.20
+            consecutive: Decimal::new(20, 2), // 0.20
+            book_depth: Decimal::new(15, 2),  // 0.15
+            spread: Decimal::new(15, 2),      // 0.15
+            cross_duration: Decimal::ZERO,    // Disabled by default
+            book_imbalance: Decimal::ZERO,    // Disabled by default
+        }
+    }
+}
+
 impl Default for DetectorConfig {
     fn default() -> Self {
         Self {
@@ -411,6 +680,7 @@ impl Default for DetectorConfig {
             spread_threshold_multiplier: default_spread_threshold_multiplier(), // 1.5x
             spread_ewma_alpha: default_spread_ewma_alpha(),             // 0.05
             confidence_sizing: false,                                   // Disabled by default
+            confidence_weights: ConfidenceWeights::default(),           // Original P3-1 weights
             baseline_tracking: false,                                   // Disabled by default
             baseline_alpha: default_baseline_alpha(),                   // 0.001
             baseline_min_samples: default_baseline_min_samples(),       // 100
@@ -423,6 +693,8 @@ impl Default for DetectorConfig {
             max_entry_spread_bps: Decimal::ZERO,                        // 0 = disabled
             short_side_throttle: false,                                 // Disabled by default
             short_threshold_mult: default_short_threshold_mult(),       // 1.5x
+            threshold_bps_long: None,                                   // Use total cost
+            threshold_bps_short: None,                                  // Use total cost
             velocity_weight_enabled: false,                             // Disabled by default
             velocity_reference_bps: default_velocity_reference_bps(),   // 10 bps
             correlation_filter_enabled: false,                          // Disabled by default
@@ -432,6 +704,31 @@ impl Default for DetectorConfig {
             us_active_threshold_mult: default_us_active_threshold_mult(), // 1.5x
             market_open_sizing_mult: default_market_open_sizing_mult(), // 0.5x
             us_active_sizing_mult: default_us_active_sizing_mult(),     // 0.75x
+            l2_depth_enabled: false,                                    // Disabled by default
+            l2_min_fillable_notional: default_l2_min_fillable_notional(), // $50
+            l2_max_book_age_ms: default_l2_max_book_age_ms(),           // 2s
+            funding_adjust_enabled: false,                              // Disabled by default
+            funding_max_hold_ms: default_funding_max_hold_ms(),         // 30s
+            signal_ttl_ms: 0,                                           // No TTL
+            regime_enabled: false,                                      // Disabled by default
+            regime_calm_threshold_mult: default_regime_calm_threshold_mult(), // 1.0x
+            regime_turbulent_threshold_mult: default_regime_turbulent_threshold_mult(), // 1.5x
+            regime_calm_max_notional: Decimal::ZERO,                    // Use max_notional
+            regime_turbulent_max_notional: Decimal::ZERO,               // Use max_notional
+            imbalance_filter_enabled: false,                            // Disabled by default
+            imbalance_min: default_imbalance_min(),                     // 0.2
+            imbalance_levels: default_imbalance_levels(),               // BBO only
+            oracle_sanity_enabled: false,                               // Disabled by default
+            oracle_sanity_max_deviation_bps: default_oracle_sanity_max_deviation_bps(), // 50 bps
+            oracle_sanity_max_age_ms: default_oracle_sanity_max_age_ms(), // 5s
+            min_cross_duration_ms: 0,                                   // Disabled by default
+            strength_buckets: default_strength_buckets(),               // Weak/Medium/Strong at 1x
+            edge_decay_enabled: false,                                  // Disabled by default
+            edge_decay_grace_ms: default_edge_decay_grace_ms(),         // 1s
+            edge_decay_max_age_ms: default_edge_decay_max_age_ms(),     // 5s
+            momentum_veto_enabled: false,                               // Disabled by default
+            momentum_veto_bps: default_momentum_veto_bps(),             // 3 bps
+            momentum_veto_window_ms: default_momentum_veto_window_ms(), // 500ms
         }
     }
 }
@@ -443,6 +740,8 @@ impl DetectorConfig {
     /// - min_book_notional >= normal_book_notional
     /// - min_book_notional < 0
     /// - normal_book_notional <= 0
+    /// - any strength bucket size_multiplier <= 0
+    /// - edge_decay_max_age_ms <= edge_decay_grace_ms (when enabled)
     pub fn validate(&self) -> Result<(), String> {
         // min must be less than normal
         if self.min_book_notional >= self.normal_book_notional {
@@ -468,6 +767,25 @@ impl DetectorConfig {
             ));
         }
 
+        // Strength buckets must scale size up or down, never to zero
+        if let Some(bucket) = self
+            .strength_buckets
+            .iter()
+            .find(|b| b.size_multiplier <= Decimal::ZERO)
+        {
+            return Err(format!(
+                "strength bucket at {} bps has non-positive size_multiplier ({})",
+                bucket.min_excess_bps, bucket.size_multiplier
+            ));
+        }
+
+        if self.edge_decay_enabled && self.edge_decay_max_age_ms <= self.edge_decay_grace_ms {
+            return Err(format!(
+                "edge_decay_max_age_ms ({}) must be greater than edge_decay_grace_ms ({})",
+                self.edge_decay_max_age_ms, self.edge_decay_grace_ms
+            ));
+        }
+
         Ok(())
     }
 
@@ -523,6 +841,32 @@ impl DetectorConfig {
         (Decimal::ONE, Decimal::ONE)
     }
 
+    /// Threshold multiplier and max notional for a volatility regime.
+    ///
+    /// Returns `(1.0, max_notional)` when regime scaling is disabled.
+    pub fn regime_params(&self, regime: VolRegime) -> (Decimal, Decimal) {
+        if !self.regime_enabled {
+            return (Decimal::ONE, self.max_notional);
+        }
+        let (mult, max_notional) = match regime {
+            VolRegime::Calm => (
+                self.regime_calm_threshold_mult,
+                self.regime_calm_max_notional,
+            ),
+            VolRegime::Normal => (Decimal::ONE, Decimal::ZERO),
+            VolRegime::Turbulent => (
+                self.regime_turbulent_threshold_mult,
+                self.regime_turbulent_max_notional,
+            ),
+        };
+        let max_notional = if max_notional.is_zero() {
+            self.max_notional
+        } else {
+            max_notional.min(self.max_notional)
+        };
+        (mult, max_notional)
+    }
+
     /// Calculate velocity-based sizing multiplier (P2-1).
     ///
     /// Returns a multiplier in range [1.0, velocity_multiplier_cap].
@@ -659,4 +1003,37 @@ mod tests {
         let result = config.validate();
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_regime_params() {
+        let config = DetectorConfig {
+            max_notional: dec!(100),
+            regime_turbulent_max_notional: dec!(40),
+            regime_calm_max_notional: dec!(500),
+            ..Default::default()
+        };
+        // Disabled: base values regardless of regime
+        assert_eq!(
+            config.regime_params(VolRegime::Turbulent),
+            (Decimal::ONE, dec!(100))
+        );
+
+        let config = DetectorConfig {
+            regime_enabled: true,
+            ..config
+        };
+        assert_eq!(
+            config.regime_params(VolRegime::Turbulent),
+            (dec!(1.5), dec!(40))
+        );
+        assert_eq!(
+            config.regime_params(VolRegime::Normal),
+            (Decimal::ONE, dec!(100))
+        );
+        // Per-regime cap never exceeds max_notional
+        assert_eq!(
+            config.regime_params(VolRegime::Calm),
+            (Decimal::ONE, dec!(100))
+        );
+    }
 }
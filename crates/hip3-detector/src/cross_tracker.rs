@@ -1,7 +1,7 @@
 //! Cross duration tracker for P0-31.
 //!
-//! Tracks how long a dislocation (oracle cross) persists in ticks.
-//! When a cross ends, emits the duration metric.
+//! Tracks how long a dislocation (oracle cross) persists in ticks and
+//! wall-clock time. When a cross ends, emits the duration metric.
 
 use hip3_core::{MarketKey, OrderSide};
 use hip3_telemetry::Metrics;
@@ -16,6 +16,9 @@ struct CrossState {
     side: Option<OrderSide>,
     /// Number of ticks the cross has persisted.
     tick_count: u64,
+    /// Time the cross started (Unix ms). None for a restored cross until
+    /// its first live tick.
+    started_ms: Option<u64>,
 }
 
 /// Tracker for cross duration across all markets.
@@ -38,7 +41,14 @@ impl CrossDurationTracker {
     /// - `key`: The market key
     /// - `is_crossing`: Whether a cross was detected this tick
     /// - `side`: The side of the cross (if any)
-    pub fn update(&mut self, key: MarketKey, is_crossing: bool, side: Option<OrderSide>) {
+    /// - `now_ms`: Current time (Unix ms)
+    pub fn update(
+        &mut self,
+        key: MarketKey,
+        is_crossing: bool,
+        side: Option<OrderSide>,
+        now_ms: u64,
+    ) {
         // First, check if we need to emit a duration metric (before modifying state)
         let emit_data: Option<(String, u64, Option<OrderSide>)> = {
             let state = self.states.get(&key);
@@ -67,17 +77,20 @@ impl CrossDurationTracker {
             if state.is_crossing && state.side == side {
                 // Continue existing cross
                 state.tick_count += 1;
+                state.started_ms.get_or_insert(now_ms);
             } else {
                 // Start new cross (either first cross or side changed)
                 state.is_crossing = true;
                 state.side = side;
                 state.tick_count = 1;
+                state.started_ms = Some(now_ms);
             }
         } else {
             // No cross this tick - reset state
             state.is_crossing = false;
             state.side = None;
             state.tick_count = 0;
+            state.started_ms = None;
         }
     }
 
@@ -93,6 +106,42 @@ impl CrossDurationTracker {
         }
     }
 
+    /// Active crosses as (market, side, tick_count), for warm-state snapshots.
+    pub fn active_crosses(&self) -> Vec<(MarketKey, OrderSide, u64)> {
+        self.states
+            .iter()
+            .filter(|(_, s)| s.is_crossing)
+            .filter_map(|(key, s)| s.side.map(|side| (*key, side, s.tick_count)))
+            .collect()
+    }
+
+    /// Restore an active cross from a warm-state snapshot.
+    ///
+    /// The next matching tick continues the count instead of starting at 1.
+    /// Wall-clock duration restarts from that tick, since the cross may not
+    /// have persisted while the bot was down.
+    pub fn restore_cross(&mut self, key: MarketKey, side: OrderSide, tick_count: u64) {
+        self.states.insert(
+            key,
+            CrossState {
+                is_crossing: tick_count > 0,
+                side: Some(side),
+                tick_count,
+                started_ms: None,
+            },
+        );
+    }
+
+    /// How long the active cross has persisted (ms), or 0 if none.
+    pub fn duration_ms(&self, key: &MarketKey, now_ms: u64) -> u64 {
+        self.states
+            .get(key)
+            .filter(|s| s.is_crossing)
+            .and_then(|s| s.started_ms)
+            .map(|start| now_ms.saturating_sub(start))
+            .unwrap_or(0)
+    }
+
     /// Get current tick count for a market (for testing/debugging).
     pub fn current_tick_count(&self, key: &MarketKey) -> u64 {
         self.states.get(key).map(|s| s.tick_count).unwrap_or(0)
@@ -120,11 +169,11 @@ mod tests {
         let key = test_key();
 
         // Tick 1: Cross detected
-        tracker.update(key, true, Some(OrderSide::Buy));
+        tracker.update(key, true, Some(OrderSide::Buy), 0);
         assert_eq!(tracker.current_tick_count(&key), 1);
 
         // Tick 2: No cross (ends cross, emits duration=1)
-        tracker.update(key, false, None);
+        tracker.update(key, false, None, 0);
         assert_eq!(tracker.current_tick_count(&key), 0);
     }
 
@@ -134,19 +183,19 @@ mod tests {
         let key = test_key();
 
         // Tick 1: Cross detected
-        tracker.update(key, true, Some(OrderSide::Buy));
+        tracker.update(key, true, Some(OrderSide::Buy), 0);
         assert_eq!(tracker.current_tick_count(&key), 1);
 
         // Tick 2: Cross continues
-        tracker.update(key, true, Some(OrderSide::Buy));
+        tracker.update(key, true, Some(OrderSide::Buy), 0);
         assert_eq!(tracker.current_tick_count(&key), 2);
 
         // Tick 3: Cross continues
-        tracker.update(key, true, Some(OrderSide::Buy));
+        tracker.update(key, true, Some(OrderSide::Buy), 0);
         assert_eq!(tracker.current_tick_count(&key), 3);
 
         // Tick 4: No cross (ends cross, emits duration=3)
-        tracker.update(key, false, None);
+        tracker.update(key, false, None, 0);
         assert_eq!(tracker.current_tick_count(&key), 0);
     }
 
@@ -156,12 +205,50 @@ mod tests {
         let key = test_key();
 
         // Tick 1-2: Buy cross
-        tracker.update(key, true, Some(OrderSide::Buy));
-        tracker.update(key, true, Some(OrderSide::Buy));
+        tracker.update(key, true, Some(OrderSide::Buy), 0);
+        tracker.update(key, true, Some(OrderSide::Buy), 0);
         assert_eq!(tracker.current_tick_count(&key), 2);
 
         // Tick 3: Side changes to sell (ends buy, starts new sell)
-        tracker.update(key, true, Some(OrderSide::Sell));
+        tracker.update(key, true, Some(OrderSide::Sell), 0);
         assert_eq!(tracker.current_tick_count(&key), 1);
     }
+
+    #[test]
+    fn test_restore_cross_continues_count() {
+        let mut tracker = CrossDurationTracker::new();
+        let key = test_key();
+
+        tracker.restore_cross(key, OrderSide::Sell, 4);
+        assert_eq!(tracker.active_crosses(), vec![(key, OrderSide::Sell, 4)]);
+
+        tracker.update(key, true, Some(OrderSide::Sell), 0);
+        assert_eq!(tracker.current_tick_count(&key), 5);
+    }
+
+    #[test]
+    fn test_duration_ms() {
+        let mut tracker = CrossDurationTracker::new();
+        let key = test_key();
+        assert_eq!(tracker.duration_ms(&key, 1_000), 0);
+
+        tracker.update(key, true, Some(OrderSide::Buy), 1_000);
+        assert_eq!(tracker.duration_ms(&key, 1_000), 0);
+        tracker.update(key, true, Some(OrderSide::Buy), 1_250);
+        assert_eq!(tracker.duration_ms(&key, 1_250), 250);
+
+        // Side change restarts the clock
+        tracker.update(key, true, Some(OrderSide::Sell), 1_400);
+        assert_eq!(tracker.duration_ms(&key, 1_500), 100);
+
+        tracker.update(key, false, None, 1_600);
+        assert_eq!(tracker.duration_ms(&key, 1_600), 0);
+
+        // Restored cross starts timing at its first live tick
+        tracker.restore_cross(key, OrderSide::Buy, 3);
+        assert_eq!(tracker.duration_ms(&key, 2_000), 0);
+        tracker.update(key, true, Some(OrderSide::Buy), 2_000);
+        tracker.update(key, true, Some(OrderSide::Buy), 2_300);
+        assert_eq!(tracker.duration_ms(&key, 2_300), 300);
+    }
 }
@@ -16,12 +16,16 @@
 
 use crate::config::DetectorConfig;
 use crate::error::DetectorError;
-use crate::fee::{FeeCalculator, UserFees};
-use crate::signal::{DislocationSignal, SignalStrength};
+use crate::fee::{FeeCalculator, FundingCost, UserFees};
+use crate::signal::{DislocationSignal, SignalFeatures, SignalStrength};
+use crate::slippage::SlippageEstimator;
 use hip3_core::types::MarketSnapshot;
 use hip3_core::ExitProfile;
-use hip3_core::{MarketKey, OrderSide, Price, Size};
-use hip3_feed::{MoveDirection, OracleMovementTracker};
+use hip3_core::{BookLevel, MarketKey, OrderSide, Price, Size};
+use hip3_feed::{
+    BboHistoryHandle, MoveDirection, OracleMovementTracker, ReferencePriceHandle, RegimeHandle,
+};
+use hip3_telemetry::Metrics;
 use rust_decimal::Decimal;
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -47,6 +51,23 @@ struct OracleMovement {
     change_bps: Decimal,
 }
 
+/// Result of walking L2 levels that individually clear the cost threshold.
+#[derive(Debug, Clone, Copy)]
+struct DepthFill {
+    /// Volume-weighted average price across counted levels.
+    vwap_px: Price,
+    /// Price of the deepest counted level (IOC limit that sweeps all of them).
+    worst_px: Price,
+    /// Total size across counted levels (capped at max_notional).
+    fillable_size: Size,
+    /// Fillable notional in USD.
+    fillable_notional: Decimal,
+    /// Edge of the VWAP vs oracle in basis points.
+    edge_bps: Decimal,
+    /// Number of levels counted.
+    levels: usize,
+}
+
 /// Per-market oracle-quote baseline state (Sprint 2).
 ///
 /// Tracks the structural gap between oracle and quote mid-price using EWMA.
@@ -81,6 +102,33 @@ impl OracleQuoteBaseline {
     }
 }
 
+/// Per-side threshold overrides in bps (None = detector default for that side).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SideThresholds {
+    /// Threshold for buy (long entry) signals.
+    pub long: Option<Decimal>,
+    /// Threshold for sell (short entry) signals.
+    pub short: Option<Decimal>,
+}
+
+impl SideThresholds {
+    /// Same override for both sides.
+    pub fn symmetric(bps: Option<Decimal>) -> Self {
+        Self {
+            long: bps,
+            short: bps,
+        }
+    }
+
+    /// Override for a given order side.
+    pub fn for_side(&self, side: OrderSide) -> Option<Decimal> {
+        match side {
+            OrderSide::Buy => self.long,
+            OrderSide::Sell => self.short,
+        }
+    }
+}
+
 /// Dislocation detector.
 ///
 /// Strategy: Enter when best price crosses oracle with edge > (FEE + SLIP + EDGE).
@@ -104,8 +152,20 @@ pub struct DislocationDetector {
     spread_ewma: RefCell<HashMap<MarketKey, Decimal>>,
     /// Sprint 2: Per-market oracle-quote baseline tracker.
     oracle_baselines: RefCell<HashMap<MarketKey, OracleQuoteBaseline>>,
-    /// Item 7: Signal dedup - tracks oracle price of last generated signal per (market, side).
+    /// Item 7: Signal dedup - tracks oracle price of last emitted signal per (market, side).
+    /// Written by [`record_signal`](Self::record_signal), not by `check()`, so a
+    /// signal the caller holds back (e.g. min cross duration) is re-detected.
     last_signaled_oracle: RefCell<HashMap<(MarketKey, OrderSide), Price>>,
+    /// Consecutive ticks each (market, side) has been crossed (confidence feature).
+    cross_ticks: RefCell<HashMap<(MarketKey, OrderSide), u32>>,
+    /// Volatility regime source (None = every market treated as Normal).
+    regime_classifier: Option<RegimeHandle>,
+    /// Learned per-market slippage (None = static `slippage_bps`).
+    slippage_estimator: Option<RefCell<SlippageEstimator>>,
+    /// Secondary reference prices for the oracle sanity check.
+    reference_prices: Option<ReferencePriceHandle>,
+    /// Recent BBOs for the momentum veto.
+    bbo_history: Option<BboHistoryHandle>,
 }
 
 impl DislocationDetector {
@@ -129,6 +189,11 @@ impl DislocationDetector {
             spread_ewma: RefCell::new(HashMap::new()),
             oracle_baselines: RefCell::new(HashMap::new()),
             last_signaled_oracle: RefCell::new(HashMap::new()),
+            cross_ticks: RefCell::new(HashMap::new()),
+            regime_classifier: None,
+            slippage_estimator: None,
+            reference_prices: None,
+            bbo_history: None,
         })
     }
 
@@ -152,6 +217,11 @@ impl DislocationDetector {
             spread_ewma: RefCell::new(HashMap::new()),
             oracle_baselines: RefCell::new(HashMap::new()),
             last_signaled_oracle: RefCell::new(HashMap::new()),
+            cross_ticks: RefCell::new(HashMap::new()),
+            regime_classifier: None,
+            slippage_estimator: None,
+            reference_prices: None,
+            bbo_history: None,
         })
     }
 
@@ -160,6 +230,130 @@ impl DislocationDetector {
         self.fee_calculator.update_user_fees(user_fees);
     }
 
+    /// Charge a builder fee (bps) on every signal's costs.
+    pub fn set_builder_fee_bps(&mut self, builder_fee_bps: Decimal) {
+        self.fee_calculator.set_builder_fee_bps(builder_fee_bps);
+    }
+
+    /// Attach a volatility regime classifier (used when `regime_enabled`).
+    #[must_use]
+    pub fn with_regime_classifier(mut self, classifier: RegimeHandle) -> Self {
+        self.regime_classifier = Some(classifier);
+        self
+    }
+
+    /// Learn slippage per market from fills instead of using `slippage_bps`.
+    #[must_use]
+    pub fn with_slippage_estimator(mut self, estimator: SlippageEstimator) -> Self {
+        self.slippage_estimator = Some(RefCell::new(estimator));
+        self
+    }
+
+    /// Attach reference prices (used when `oracle_sanity_enabled`).
+    #[must_use]
+    pub fn with_reference_prices(mut self, reference_prices: ReferencePriceHandle) -> Self {
+        self.reference_prices = Some(reference_prices);
+        self
+    }
+
+    /// Attach BBO history (used when `momentum_veto_enabled`).
+    #[must_use]
+    pub fn with_bbo_history(mut self, bbo_history: BboHistoryHandle) -> Self {
+        self.bbo_history = Some(bbo_history);
+        self
+    }
+
+    /// Whether the signal's oracle agrees with the reference price.
+    ///
+    /// Passes when the check is disabled or no fresh reference exists.
+    fn passes_oracle_sanity(&self, signal: &DislocationSignal) -> bool {
+        if !self.config.oracle_sanity_enabled {
+            return true;
+        }
+        let key = signal.market_key;
+        let oracle = signal.oracle_px;
+        let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+        let Some(reference) = self
+            .reference_prices
+            .as_ref()
+            .and_then(|r| r.fresh(&key, now_ms, self.config.oracle_sanity_max_age_ms))
+        else {
+            return true;
+        };
+        if oracle.is_zero() {
+            return true;
+        }
+
+        let deviation_bps =
+            (oracle.inner() - reference.inner()).abs() / reference.inner() * Decimal::from(10000);
+        if deviation_bps > self.config.oracle_sanity_max_deviation_bps {
+            tracing::warn!(
+                %key,
+                side = ?signal.side,
+                raw_edge_bps = %signal.raw_edge_bps,
+                oracle = %oracle,
+                reference = %reference,
+                deviation_bps = %deviation_bps,
+                max_deviation_bps = %self.config.oracle_sanity_max_deviation_bps,
+                "Signal suppressed: oracle deviates from reference price"
+            );
+            Metrics::oracle_sanity_rejected(&key.to_string());
+            return false;
+        }
+        true
+    }
+
+    /// Feed an entry fill to the slippage estimator (no-op if not attached).
+    ///
+    /// Returns the observed slippage in bps.
+    pub fn record_fill_slippage(
+        &self,
+        key: MarketKey,
+        side: OrderSide,
+        expected_px: Price,
+        fill_px: Price,
+    ) -> Option<Decimal> {
+        self.slippage_estimator
+            .as_ref()?
+            .borrow_mut()
+            .record(key, side, expected_px, fill_px)
+    }
+
+    /// Slippage used for a market: learned estimate if warmed up, else static.
+    pub fn slippage_bps(&self, key: &MarketKey) -> Decimal {
+        self.slippage_estimator
+            .as_ref()
+            .and_then(|e| e.borrow().estimate_bps(key))
+            .unwrap_or_else(|| self.fee_calculator.slippage_bps())
+    }
+
+    /// Total cost (fee + slippage + min edge) for a market.
+    pub fn total_cost_bps(&self, key: &MarketKey) -> Decimal {
+        self.fee_calculator
+            .total_cost_bps_with_slippage(self.slippage_bps(key))
+    }
+
+    /// Threshold multiplier and max notional for the market's current regime.
+    fn regime_params(&self, key: &MarketKey) -> (Decimal, Decimal) {
+        let regime = self
+            .regime_classifier
+            .as_ref()
+            .map(|c| c.regime(key))
+            .unwrap_or_default();
+        self.config.regime_params(regime)
+    }
+
+    /// Cap size so its notional at mid stays within `max_notional` (1% buffer).
+    fn cap_notional(size: Size, snapshot: &MarketSnapshot, max_notional: Decimal) -> Size {
+        match snapshot.bbo.mid_price() {
+            Some(mid) if !mid.is_zero() => {
+                let max_size = max_notional * Decimal::new(99, 2) / mid.inner();
+                Size::new(size.inner().min(max_size))
+            }
+            _ => size,
+        }
+    }
+
     /// Get current fee calculator.
     pub fn fee_calculator(&self) -> &FeeCalculator {
         &self.fee_calculator
@@ -331,6 +525,27 @@ impl DislocationDetector {
             .unwrap_or(Decimal::ZERO)
     }
 
+    /// Spread EWMAs for all markets seen so far (for warm-state snapshots).
+    pub fn spread_ewmas(&self) -> Vec<(MarketKey, Decimal)> {
+        self.spread_ewma
+            .borrow()
+            .iter()
+            .filter(|(_, ewma)| !ewma.is_zero())
+            .map(|(key, ewma)| (*key, *ewma))
+            .collect()
+    }
+
+    /// Seed a market's spread EWMA from a warm-state snapshot.
+    ///
+    /// Ignored if the market already has an EWMA.
+    pub fn restore_spread_ewma(&self, key: MarketKey, ewma_bps: Decimal) {
+        let mut ewma_map = self.spread_ewma.borrow_mut();
+        let ewma = ewma_map.entry(key).or_insert(Decimal::ZERO);
+        if ewma.is_zero() {
+            *ewma = ewma_bps;
+        }
+    }
+
     /// Check for dislocation opportunity.
     ///
     /// Returns Some(signal) if a valid opportunity is detected.
@@ -351,6 +566,28 @@ impl DislocationDetector {
         threshold_override_bps: Option<Decimal>,
         oracle_tracker: Option<&OracleMovementTracker>,
         oracle_age_ms: Option<i64>,
+    ) -> Option<DislocationSignal> {
+        self.check_sided(
+            key,
+            snapshot,
+            SideThresholds::symmetric(threshold_override_bps),
+            oracle_tracker,
+            oracle_age_ms,
+        )
+    }
+
+    /// Check for dislocation opportunity with separate long/short thresholds.
+    ///
+    /// Same as [`check`](Self::check), but each side's override falls back
+    /// to `threshold_bps_long` / `threshold_bps_short` from the config, then
+    /// to fee_calculator's total_cost_bps.
+    pub fn check_sided(
+        &self,
+        key: MarketKey,
+        snapshot: &MarketSnapshot,
+        thresholds: SideThresholds,
+        oracle_tracker: Option<&OracleMovementTracker>,
+        oracle_age_ms: Option<i64>,
     ) -> Option<DislocationSignal> {
         // Get oracle movement (direction + change amount) for filtering
         let oracle = snapshot.ctx.oracle.oracle_px;
@@ -359,47 +596,53 @@ impl DislocationDetector {
         // P2-2: Update spread EWMA and compute adaptive threshold
         let adaptive_threshold = self.update_spread_ewma(key, snapshot);
 
-        // Effective threshold: max(explicit_override or fee_cost, adaptive_spread_threshold)
-        let effective_threshold = match (threshold_override_bps, adaptive_threshold) {
-            (Some(override_bps), Some(spread_th)) => Some(override_bps.max(spread_th)),
-            (Some(override_bps), None) => Some(override_bps),
-            (None, Some(spread_th)) => {
-                let base = self.fee_calculator.total_cost_bps();
-                if spread_th > base {
-                    Some(spread_th)
-                } else {
-                    None // Use fee_calculator default
+        // Track how long each side has been crossed (confidence feature)
+        self.update_cross_ticks(key, snapshot);
+
+        // Effective threshold per side: max(explicit_override or fee_cost, adaptive_spread_threshold)
+        let long_override = thresholds.long.or(self.config.threshold_bps_long);
+        let short_override = thresholds.short.or(self.config.threshold_bps_short);
+        let effective_threshold =
+            |override_bps: Option<Decimal>| match (override_bps, adaptive_threshold) {
+                (Some(override_bps), Some(spread_th)) => Some(override_bps.max(spread_th)),
+                (Some(override_bps), None) => Some(override_bps),
+                (None, Some(spread_th)) => {
+                    let base = self.total_cost_bps(&key);
+                    if spread_th > base {
+                        Some(spread_th)
+                    } else {
+                        None // Use fee_calculator default
+                    }
                 }
-            }
-            (None, None) => None,
-        };
+                (None, None) => None,
+            };
 
         // Check buy opportunity: ask below oracle
         if let Some(signal) = self.check_buy(
             key,
             snapshot,
-            effective_threshold,
+            effective_threshold(long_override),
             movement,
             oracle_tracker,
             oracle_age_ms,
         ) {
             // Sprint 2: Update baseline AFTER signal check to avoid self-contamination
             self.update_oracle_baseline(key, snapshot);
-            return Some(signal);
+            return self.passes_oracle_sanity(&signal).then_some(signal);
         }
 
         // Check sell opportunity: bid above oracle
         if let Some(signal) = self.check_sell(
             key,
             snapshot,
-            effective_threshold,
+            effective_threshold(short_override),
             movement,
             oracle_tracker,
             oracle_age_ms,
         ) {
             // Sprint 2: Update baseline AFTER signal check
             self.update_oracle_baseline(key, snapshot);
-            return Some(signal);
+            return self.passes_oracle_sanity(&signal).then_some(signal);
         }
 
         // Sprint 2: Update baseline even when no signal generated (unbiased estimate)
@@ -471,13 +714,19 @@ impl DislocationDetector {
             return None;
         }
 
-        // Use per-market threshold if provided, otherwise use FeeCalculator's total_cost
-        let base_cost =
-            threshold_override_bps.unwrap_or_else(|| self.fee_calculator.total_cost_bps());
+        // Edge decay: a dislocation that outlives the oracle move loses value
+        let edge_decay_factor =
+            self.edge_decay_factor(key, snapshot, OrderSide::Buy, oracle_tracker)?;
+        let raw_edge_bps = raw_edge_bps * edge_decay_factor;
+
+        // Use per-market threshold if provided, otherwise fee + (learned) slippage + min edge
+        let base_cost = threshold_override_bps.unwrap_or_else(|| self.total_cost_bps(&key));
 
         // Sprint 4 P2-G: Apply session-aware threshold multiplier
         let (session_threshold_mult, session_sizing_mult) = self.config.session_multipliers();
-        let total_cost = base_cost * session_threshold_mult;
+        // Volatility regime: demand more edge (and cap size) in turbulent markets
+        let (regime_threshold_mult, regime_max_notional) = self.regime_params(&key);
+        let total_cost = base_cost * session_threshold_mult * regime_threshold_mult;
 
         // Item 6: Velocity weight - adjust threshold based on oracle velocity
         let total_cost = if self.config.velocity_weight_enabled {
@@ -499,10 +748,51 @@ impl DislocationDetector {
             total_cost
         };
 
-        let net_edge_bps = raw_edge_bps - total_cost;
-
         // Check if edge is sufficient
-        let strength = SignalStrength::from_edge(raw_edge_bps, total_cost)?;
+        let buckets = &self.config.strength_buckets;
+        let (strength, size_multiplier) =
+            SignalStrength::from_buckets(raw_edge_bps, total_cost, buckets)?;
+
+        // L2 depth: re-price the signal over all levels that clear the threshold
+        let depth = if self.config.l2_depth_enabled {
+            Some(self.check_depth(key, snapshot, OrderSide::Buy, total_cost)?)
+        } else {
+            None
+        };
+        let (raw_edge_bps, strength, size_multiplier) = match depth {
+            Some(d) => {
+                let edge_bps = d.edge_bps * edge_decay_factor;
+                let (strength, size_multiplier) =
+                    SignalStrength::from_buckets(edge_bps, total_cost, buckets)?;
+                (edge_bps, strength, size_multiplier)
+            }
+            None => (raw_edge_bps, strength, size_multiplier),
+        };
+        // Funding-adjusted edge: a settlement inside the hold can eat the edge
+        let funding = self.funding_cost(snapshot, OrderSide::Buy);
+        let net_edge_bps = raw_edge_bps - total_cost - funding.cost_bps;
+        if funding.cost_bps > Decimal::ZERO && net_edge_bps <= Decimal::ZERO {
+            tracing::debug!(
+                %key,
+                side = "buy",
+                raw_edge_bps = %raw_edge_bps,
+                funding_rate = %funding.funding_rate,
+                funding_cost_bps = %funding.cost_bps,
+                "Signal skipped: edge does not cover expected funding"
+            );
+            return None;
+        }
+
+        // Book imbalance filter: only take the side whose queue is thin
+        let book_imbalance = self.book_imbalance(snapshot);
+        if !self.imbalance_allows(key, OrderSide::Buy, book_imbalance) {
+            return None;
+        }
+
+        // Momentum veto: don't chase a quote that is already repricing
+        if !self.momentum_allows(key, snapshot, OrderSide::Buy) {
+            return None;
+        }
 
         // Oracle Direction Filter: Buy only when oracle is rising (stale ask)
         // This filters out signals caused by oracle lagging in downtrend
@@ -636,8 +926,13 @@ impl DislocationDetector {
             .map(|t| t.consecutive(&key, MoveDirection::Up))
             .unwrap_or(0);
         // First pass: get liquidity_factor from calculate_size with confidence=1.0
-        let (preliminary_size, liquidity_factor) =
-            self.calculate_size(snapshot, OrderSide::Buy, velocity_multiplier, Decimal::ONE);
+        let (preliminary_size, liquidity_factor) = self.calculate_size(
+            snapshot,
+            OrderSide::Buy,
+            velocity_multiplier,
+            Decimal::ONE,
+            depth.as_ref(),
+        );
         if preliminary_size.is_zero() {
             tracing::debug!(
                 %key,
@@ -648,13 +943,15 @@ impl DislocationDetector {
             return None;
         }
 
-        let confidence = self.confidence_score(
+        let (confidence, features) = self.confidence_score(
+            key,
+            OrderSide::Buy,
+            snapshot,
             raw_edge_bps,
             total_cost,
             effective_velocity_bps,
             consecutive_up,
             liquidity_factor,
-            &key,
         );
 
         // Sprint 3 P2-D: Confidence Entry Gate
@@ -688,6 +985,7 @@ impl DislocationDetector {
             OrderSide::Buy,
             velocity_multiplier,
             confidence_multiplier,
+            depth.as_ref(),
         );
 
         // Sprint 4 P2-G: Apply session sizing multiplier
@@ -697,6 +995,7 @@ impl DislocationDetector {
         } else {
             suggested_size
         };
+        let suggested_size = Self::cap_notional(suggested_size, snapshot, regime_max_notional);
 
         // Skip signal if size is zero (low liquidity)
         if suggested_size.is_zero() {
@@ -710,7 +1009,10 @@ impl DislocationDetector {
         }
 
         // P0-24: Generate fee metadata for audit trail
-        let fee_metadata = self.fee_calculator.metadata();
+        let fee_metadata = self
+            .fee_calculator
+            .metadata_with_slippage(self.slippage_bps(&key))
+            .with_funding(&funding);
 
         info!(
             %key,
@@ -734,6 +1036,17 @@ impl DislocationDetector {
             "Dislocation detected (P0-24: HIP-3 2x fee applied)"
         );
 
+        // L2 depth: never size beyond what actually crosses, and use the
+        // deepest counted level as the IOC limit so the order can sweep it
+        let (suggested_size, limit_px, book_size) = match depth {
+            Some(d) => (
+                Size::new(suggested_size.inner().min(d.fillable_size.inner())),
+                d.worst_px,
+                d.fillable_size,
+            ),
+            None => (suggested_size, ask, ask_size),
+        };
+
         let mut signal = DislocationSignal::new(
             key,
             OrderSide::Buy,
@@ -742,8 +1055,8 @@ impl DislocationDetector {
             strength,
             suggested_size,
             oracle,
-            ask,
-            ask_size,
+            limit_px,
+            book_size,
             fee_metadata,
             effective_velocity_bps,
             confidence,
@@ -751,13 +1064,12 @@ impl DislocationDetector {
         signal.baseline_gap_bps = baseline_gap_bps;
         signal.edge_above_baseline_bps = edge_above_baseline_bps;
         signal.exit_profile = exit_profile;
-
-        // Item 7: Record oracle price for dedup on next check
-        if self.config.signal_dedup_enabled {
-            self.last_signaled_oracle
-                .borrow_mut()
-                .insert((key, OrderSide::Buy), oracle);
-        }
+        signal.features = features;
+        signal.threshold_bps = total_cost;
+        signal.book_imbalance = book_imbalance;
+        signal.size_multiplier = size_multiplier;
+        signal.edge_decay_factor = edge_decay_factor;
+        signal.ttl_ms = self.config.signal_ttl_ms;
 
         Some(signal)
     }
@@ -826,13 +1138,19 @@ impl DislocationDetector {
             return None;
         }
 
-        // Use per-market threshold if provided, otherwise use FeeCalculator's total_cost
-        let base_cost =
-            threshold_override_bps.unwrap_or_else(|| self.fee_calculator.total_cost_bps());
+        // Edge decay: a dislocation that outlives the oracle move loses value
+        let edge_decay_factor =
+            self.edge_decay_factor(key, snapshot, OrderSide::Sell, oracle_tracker)?;
+        let raw_edge_bps = raw_edge_bps * edge_decay_factor;
+
+        // Use per-market threshold if provided, otherwise fee + (learned) slippage + min edge
+        let base_cost = threshold_override_bps.unwrap_or_else(|| self.total_cost_bps(&key));
 
         // Sprint 4 P2-G: Apply session-aware threshold multiplier
         let (session_threshold_mult, session_sizing_mult) = self.config.session_multipliers();
-        let total_cost = base_cost * session_threshold_mult;
+        // Volatility regime: demand more edge (and cap size) in turbulent markets
+        let (regime_threshold_mult, regime_max_notional) = self.regime_params(&key);
+        let total_cost = base_cost * session_threshold_mult * regime_threshold_mult;
 
         // Item 5: Short-side throttle - raise SELL threshold
         let total_cost = if self.config.short_side_throttle {
@@ -860,10 +1178,51 @@ impl DislocationDetector {
             total_cost
         };
 
-        let net_edge_bps = raw_edge_bps - total_cost;
-
         // Check if edge is sufficient
-        let strength = SignalStrength::from_edge(raw_edge_bps, total_cost)?;
+        let buckets = &self.config.strength_buckets;
+        let (strength, size_multiplier) =
+            SignalStrength::from_buckets(raw_edge_bps, total_cost, buckets)?;
+
+        // L2 depth: re-price the signal over all levels that clear the threshold
+        let depth = if self.config.l2_depth_enabled {
+            Some(self.check_depth(key, snapshot, OrderSide::Sell, total_cost)?)
+        } else {
+            None
+        };
+        let (raw_edge_bps, strength, size_multiplier) = match depth {
+            Some(d) => {
+                let edge_bps = d.edge_bps * edge_decay_factor;
+                let (strength, size_multiplier) =
+                    SignalStrength::from_buckets(edge_bps, total_cost, buckets)?;
+                (edge_bps, strength, size_multiplier)
+            }
+            None => (raw_edge_bps, strength, size_multiplier),
+        };
+        // Funding-adjusted edge: a settlement inside the hold can eat the edge
+        let funding = self.funding_cost(snapshot, OrderSide::Sell);
+        let net_edge_bps = raw_edge_bps - total_cost - funding.cost_bps;
+        if funding.cost_bps > Decimal::ZERO && net_edge_bps <= Decimal::ZERO {
+            tracing::debug!(
+                %key,
+                side = "sell",
+                raw_edge_bps = %raw_edge_bps,
+                funding_rate = %funding.funding_rate,
+                funding_cost_bps = %funding.cost_bps,
+                "Signal skipped: edge does not cover expected funding"
+            );
+            return None;
+        }
+
+        // Book imbalance filter: only take the side whose queue is thin
+        let book_imbalance = self.book_imbalance(snapshot);
+        if !self.imbalance_allows(key, OrderSide::Sell, book_imbalance) {
+            return None;
+        }
+
+        // Momentum veto: don't chase a quote that is already repricing
+        if !self.momentum_allows(key, snapshot, OrderSide::Sell) {
+            return None;
+        }
 
         // Oracle Direction Filter: Sell only when oracle is falling (stale bid)
         // This filters out signals caused by oracle lagging in uptrend
@@ -996,8 +1355,13 @@ impl DislocationDetector {
         let consecutive_down = oracle_tracker
             .map(|t| t.consecutive(&key, MoveDirection::Down))
             .unwrap_or(0);
-        let (preliminary_size, liquidity_factor) =
-            self.calculate_size(snapshot, OrderSide::Sell, velocity_multiplier, Decimal::ONE);
+        let (preliminary_size, liquidity_factor) = self.calculate_size(
+            snapshot,
+            OrderSide::Sell,
+            velocity_multiplier,
+            Decimal::ONE,
+            depth.as_ref(),
+        );
         if preliminary_size.is_zero() {
             tracing::debug!(
                 %key,
@@ -1008,13 +1372,15 @@ impl DislocationDetector {
             return None;
         }
 
-        let confidence = self.confidence_score(
+        let (confidence, features) = self.confidence_score(
+            key,
+            OrderSide::Sell,
+            snapshot,
             raw_edge_bps,
             total_cost,
             effective_velocity_bps,
             consecutive_down,
             liquidity_factor,
-            &key,
         );
 
         // Sprint 3 P2-D: Confidence Entry Gate
@@ -1046,6 +1412,7 @@ impl DislocationDetector {
             OrderSide::Sell,
             velocity_multiplier,
             confidence_multiplier,
+            depth.as_ref(),
         );
 
         // Sprint 4 P2-G: Apply session sizing multiplier
@@ -1055,6 +1422,7 @@ impl DislocationDetector {
         } else {
             suggested_size
         };
+        let suggested_size = Self::cap_notional(suggested_size, snapshot, regime_max_notional);
 
         // Skip signal if size is zero (low liquidity)
         if suggested_size.is_zero() {
@@ -1068,7 +1436,10 @@ impl DislocationDetector {
         }
 
         // P0-24: Generate fee metadata for audit trail
-        let fee_metadata = self.fee_calculator.metadata();
+        let fee_metadata = self
+            .fee_calculator
+            .metadata_with_slippage(self.slippage_bps(&key))
+            .with_funding(&funding);
 
         info!(
             %key,
@@ -1092,6 +1463,17 @@ impl DislocationDetector {
             "Dislocation detected (P0-24: HIP-3 2x fee applied)"
         );
 
+        // L2 depth: never size beyond what actually crosses, and use the
+        // deepest counted level as the IOC limit so the order can sweep it
+        let (suggested_size, limit_px, book_size) = match depth {
+            Some(d) => (
+                Size::new(suggested_size.inner().min(d.fillable_size.inner())),
+                d.worst_px,
+                d.fillable_size,
+            ),
+            None => (suggested_size, bid, bid_size),
+        };
+
         let mut signal = DislocationSignal::new(
             key,
             OrderSide::Sell,
@@ -1100,8 +1482,8 @@ impl DislocationDetector {
             strength,
             suggested_size,
             oracle,
-            bid,
-            bid_size,
+            limit_px,
+            book_size,
             fee_metadata,
             effective_velocity_bps,
             confidence,
@@ -1109,13 +1491,12 @@ impl DislocationDetector {
         signal.baseline_gap_bps = baseline_gap_bps;
         signal.edge_above_baseline_bps = edge_above_baseline_bps;
         signal.exit_profile = exit_profile;
-
-        // Item 7: Record oracle price for dedup on next check
-        if self.config.signal_dedup_enabled {
-            self.last_signaled_oracle
-                .borrow_mut()
-                .insert((key, OrderSide::Sell), oracle);
-        }
+        signal.features = features;
+        signal.threshold_bps = total_cost;
+        signal.book_imbalance = book_imbalance;
+        signal.size_multiplier = size_multiplier;
+        signal.edge_decay_factor = edge_decay_factor;
+        signal.ttl_ms = self.config.signal_ttl_ms;
 
         Some(signal)
     }
@@ -1150,23 +1531,51 @@ impl DislocationDetector {
         }
     }
 
+    /// Update consecutive crossed-tick counters for both sides of a market.
+    fn update_cross_ticks(&self, key: MarketKey, snapshot: &MarketSnapshot) {
+        let oracle = snapshot.ctx.oracle.oracle_px;
+        let tradeable = snapshot.is_tradeable() && !oracle.is_zero();
+        let buy_crossed = tradeable && snapshot.bbo.ask_price < oracle;
+        let sell_crossed = tradeable && snapshot.bbo.bid_price > oracle;
+
+        let mut ticks = self.cross_ticks.borrow_mut();
+        for (side, crossed) in [
+            (OrderSide::Buy, buy_crossed),
+            (OrderSide::Sell, sell_crossed),
+        ] {
+            if crossed {
+                *ticks.entry((key, side)).or_insert(0) += 1;
+            } else {
+                ticks.remove(&(key, side));
+            }
+        }
+    }
+
     /// P3-1: Calculate multi-factor confidence score (0.0-1.0).
     ///
-    /// Factors and weights:
+    /// The score is the weighted mean of these factors, using
+    /// `confidence_weights` (defaults in parentheses):
     /// - Edge magnitude (0.30): larger edge = higher confidence
     /// - Oracle velocity (0.20): faster oracle move = more reliable dislocation
     /// - Consecutive moves (0.20): more consecutive moves = stronger trend
     /// - Book depth (0.15): deeper book = more reliable fill
     /// - Spread tightness (0.15): tighter spread = healthier market
+    /// - Cross duration (0): cross persisted over several ticks = not a flicker
+    /// - Book imbalance (0): BBO queue leaning in the trade direction
+    ///
+    /// Returns the score together with the feature vector behind it.
+    #[allow(clippy::too_many_arguments)]
     fn confidence_score(
         &self,
+        key: MarketKey,
+        side: OrderSide,
+        snapshot: &MarketSnapshot,
         raw_edge_bps: Decimal,
         total_cost_bps: Decimal,
         velocity_bps: Decimal,
         consecutive_moves: u32,
         liquidity_factor: Decimal,
-        key: &MarketKey,
-    ) -> Decimal {
+    ) -> (Decimal, SignalFeatures) {
         // 1. Edge factor: linear from 0 at threshold to 1 at 3x threshold
         let edge_excess = raw_edge_bps - total_cost_bps;
         let edge_factor = if edge_excess <= Decimal::ZERO {
@@ -1192,7 +1601,7 @@ impl DislocationDetector {
 
         // 5. Spread tightness: use spread_ewma relative to total_cost
         //    Tight spread (spread < cost) = 1.0, wide spread (spread > 3x cost) = 0.0
-        let spread_ewma = self.spread_ewma(key);
+        let spread_ewma = self.spread_ewma(&key);
         let spread_factor = if spread_ewma.is_zero() || total_cost_bps.is_zero() {
             Decimal::new(5, 1) // 0.5 default when no data
         } else {
@@ -1207,15 +1616,260 @@ impl DislocationDetector {
             }
         };
 
-        // Weighted sum
-        let score = edge_factor * Decimal::new(30, 2)       // 0.30
-            + velocity_factor * Decimal::new(20, 2)          // 0.20
-            + consecutive_factor * Decimal::new(20, 2)       // 0.20
-            + book_factor * Decimal::new(15, 2)              // 0.15
-            + spread_factor * Decimal::new(15, 2); // 0.15
+        // 6. Cross duration: same scale as consecutive moves
+        let cross_ticks = self
+            .cross_ticks
+            .borrow()
+            .get(&(key, side))
+            .copied()
+            .unwrap_or(0);
+        let cross_factor = Decimal::from(cross_ticks.min(3)) / Decimal::from(3);
+
+        // 7. Book imbalance: -1 (all ask) .. +1 (all bid), mapped so 1.0 favors the side
+        let bid_size = snapshot.bbo.bid_size.inner();
+        let ask_size = snapshot.bbo.ask_size.inner();
+        let imbalance = if (bid_size + ask_size).is_zero() {
+            Decimal::ZERO
+        } else {
+            (bid_size - ask_size) / (bid_size + ask_size)
+        };
+        let signed_imbalance = match side {
+            OrderSide::Buy => imbalance,
+            OrderSide::Sell => -imbalance,
+        };
+        let imbalance_factor = (Decimal::ONE + signed_imbalance) / Decimal::TWO;
+
+        // Weighted mean over configured weights
+        let w = &self.config.confidence_weights;
+        let weight_sum = w.edge
+            + w.velocity
+            + w.consecutive
+            + w.book_depth
+            + w.spread
+            + w.cross_duration
+            + w.book_imbalance;
+        let score = if weight_sum <= Decimal::ZERO {
+            Decimal::ZERO
+        } else {
+            (edge_factor * w.edge
+                + velocity_factor * w.velocity
+                + consecutive_factor * w.consecutive
+                + book_factor * w.book_depth
+                + spread_factor * w.spread
+                + cross_factor * w.cross_duration
+                + imbalance_factor * w.book_imbalance)
+                / weight_sum
+        };
+
+        let features = SignalFeatures {
+            edge: edge_factor,
+            velocity: velocity_factor,
+            consecutive: consecutive_factor,
+            book_depth: book_factor,
+            spread: spread_factor,
+            cross_duration: cross_factor,
+            book_imbalance: imbalance_factor,
+            edge_excess_bps: edge_excess,
+            velocity_bps,
+            consecutive_moves,
+            cross_ticks,
+            spread_bps: snapshot.bbo.spread_bps().unwrap_or(Decimal::ZERO),
+            spread_ewma_bps: spread_ewma,
+            imbalance,
+        };
 
         // Clamp to [0.0, 1.0]
-        score.min(Decimal::ONE).max(Decimal::ZERO)
+        (score.min(Decimal::ONE).max(Decimal::ZERO), features)
+    }
+
+    /// Walk L2 levels on the take side and accumulate those whose own edge
+    /// clears `total_cost_bps`, up to `max_notional`.
+    ///
+    /// Returns None if no fresh book is available or no level clears the threshold.
+    fn depth_fill(
+        &self,
+        snapshot: &MarketSnapshot,
+        side: OrderSide,
+        total_cost_bps: Decimal,
+    ) -> Option<DepthFill> {
+        let book = snapshot.book.as_ref()?;
+        if self.config.l2_max_book_age_ms > 0 && book.age_ms() > self.config.l2_max_book_age_ms {
+            return None;
+        }
+
+        let oracle = snapshot.ctx.oracle.oracle_px.inner();
+        if oracle.is_zero() {
+            return None;
+        }
+        let edge_of = |px: Decimal| -> Decimal {
+            let diff = match side {
+                OrderSide::Buy => oracle - px,
+                OrderSide::Sell => px - oracle,
+            };
+            diff / oracle * Decimal::from(10000)
+        };
+
+        let mut filled_size = Decimal::ZERO;
+        let mut filled_notional = Decimal::ZERO;
+        let mut worst_px = Decimal::ZERO;
+        let mut levels = 0;
+
+        for level in book.take_levels(side) {
+            let px = level.price.inner();
+            if px <= Decimal::ZERO || level.size.inner() <= Decimal::ZERO {
+                continue;
+            }
+            // Levels are best-first, so the first one that fails ends the walk
+            if edge_of(px) < total_cost_bps {
+                break;
+            }
+            let remaining = self.config.max_notional - filled_notional;
+            if remaining <= Decimal::ZERO {
+                break;
+            }
+            let take = level.size.inner().min(remaining / px);
+            filled_size += take;
+            filled_notional += take * px;
+            worst_px = px;
+            levels += 1;
+        }
+
+        if filled_size.is_zero() {
+            return None;
+        }
+
+        let vwap = filled_notional / filled_size;
+        Some(DepthFill {
+            vwap_px: Price::new(vwap),
+            worst_px: Price::new(worst_px),
+            fillable_size: Size::new(filled_size),
+            fillable_notional: filled_notional,
+            edge_bps: edge_of(vwap),
+            levels,
+        })
+    }
+
+    /// L2 depth gate: require enough crossing depth before emitting a signal.
+    fn check_depth(
+        &self,
+        key: MarketKey,
+        snapshot: &MarketSnapshot,
+        side: OrderSide,
+        total_cost_bps: Decimal,
+    ) -> Option<DepthFill> {
+        let depth = self.depth_fill(snapshot, side, total_cost_bps);
+        match depth {
+            Some(d) if d.fillable_notional >= self.config.l2_min_fillable_notional => {
+                tracing::debug!(
+                    %key,
+                    ?side,
+                    vwap_px = %d.vwap_px,
+                    worst_px = %d.worst_px,
+                    depth_edge_bps = %d.edge_bps,
+                    fillable_notional = %d.fillable_notional,
+                    levels = d.levels,
+                    "L2 depth accepted"
+                );
+                Some(d)
+            }
+            _ => {
+                tracing::debug!(
+                    %key,
+                    ?side,
+                    fillable_notional = ?depth.map(|d| d.fillable_notional),
+                    min_fillable_notional = %self.config.l2_min_fillable_notional,
+                    has_book = snapshot.book.is_some(),
+                    "Signal skipped: insufficient L2 depth crossing oracle"
+                );
+                None
+            }
+        }
+    }
+
+    /// Signed size imbalance: (bid - ask) / (bid + ask), in -1 .. 1.
+    ///
+    /// Sums the top `imbalance_levels` of a fresh L2 book when more than one
+    /// level is configured, otherwise uses BBO sizes.
+    fn book_imbalance(&self, snapshot: &MarketSnapshot) -> Decimal {
+        let levels = self.config.imbalance_levels;
+        let book = snapshot.book.as_ref().filter(|b| {
+            levels > 1
+                && (self.config.l2_max_book_age_ms <= 0
+                    || b.age_ms() <= self.config.l2_max_book_age_ms)
+        });
+        let (bid_size, ask_size) = match book {
+            Some(book) => {
+                let sum = |side: &[BookLevel]| -> Decimal {
+                    side.iter().take(levels).map(|l| l.size.inner()).sum()
+                };
+                (sum(&book.bids), sum(&book.asks))
+            }
+            None => (snapshot.bbo.bid_size.inner(), snapshot.bbo.ask_size.inner()),
+        };
+
+        let total = bid_size + ask_size;
+        if total.is_zero() {
+            Decimal::ZERO
+        } else {
+            (bid_size - ask_size) / total
+        }
+    }
+
+    /// Whether the book imbalance allows a signal on `side`.
+    fn imbalance_allows(&self, key: MarketKey, side: OrderSide, imbalance: Decimal) -> bool {
+        if !self.config.imbalance_filter_enabled {
+            return true;
+        }
+        let directional = match side {
+            OrderSide::Buy => imbalance,
+            OrderSide::Sell => -imbalance,
+        };
+        if directional < self.config.imbalance_min {
+            tracing::debug!(
+                %key,
+                ?side,
+                imbalance = %imbalance,
+                imbalance_min = %self.config.imbalance_min,
+                "Signal skipped: book imbalance against trade side"
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Whether the taker-side quote is not already moving toward the oracle.
+    ///
+    /// Buy signals need the ask below the oracle; a rising ask means the MM
+    /// is catching up. Likewise a falling bid for sells. Passes when the
+    /// veto is disabled or there is not enough history.
+    fn momentum_allows(&self, key: MarketKey, snapshot: &MarketSnapshot, side: OrderSide) -> bool {
+        if !self.config.momentum_veto_enabled {
+            return true;
+        }
+        let now_ms = snapshot.timestamp.timestamp_millis().max(0) as u64;
+        let Some(move_bps) = self
+            .bbo_history
+            .as_ref()
+            .and_then(|h| h.move_bps(&key, side, self.config.momentum_veto_window_ms, now_ms))
+        else {
+            return true;
+        };
+        let toward_oracle_bps = match side {
+            OrderSide::Buy => move_bps,
+            OrderSide::Sell => -move_bps,
+        };
+        if toward_oracle_bps > self.config.momentum_veto_bps {
+            tracing::debug!(
+                %key,
+                ?side,
+                toward_oracle_bps = %toward_oracle_bps,
+                veto_bps = %self.config.momentum_veto_bps,
+                window_ms = self.config.momentum_veto_window_ms,
+                "Signal skipped: quote already moving toward oracle"
+            );
+            return false;
+        }
+        true
     }
 
     /// Calculate liquidity adjustment factor (0.0 ~ 1.0).
@@ -1241,6 +1895,8 @@ impl DislocationDetector {
     ///
     /// size = clamp(alpha * liquidity_factor * velocity_multiplier * confidence_multiplier * top_of_book_size, min_notional, max_notional) / mid_price
     ///
+    /// When `depth` is given (L2 mode), the fillable depth replaces top-of-book size.
+    ///
     /// Returns `(Size, liquidity_factor)`. Size::ZERO if liquidity is below minimum threshold.
     fn calculate_size(
         &self,
@@ -1248,6 +1904,7 @@ impl DislocationDetector {
         side: OrderSide,
         velocity_multiplier: Decimal,
         confidence_multiplier: Decimal,
+        depth: Option<&DepthFill>,
     ) -> (Size, Decimal) {
         // P0-14: mid_price() now returns Option<Price>
         let mid = match snapshot.bbo.mid_price() {
@@ -1257,9 +1914,10 @@ impl DislocationDetector {
 
         // Side-aware book size and price
         // Buy: take liquidity from ask side, Sell: from bid side
-        let (book_size, book_price) = match side {
-            OrderSide::Buy => (snapshot.bbo.ask_size, snapshot.bbo.ask_price),
-            OrderSide::Sell => (snapshot.bbo.bid_size, snapshot.bbo.bid_price),
+        let (book_size, book_price) = match (depth, side) {
+            (Some(d), _) => (d.fillable_size, d.vwap_px),
+            (None, OrderSide::Buy) => (snapshot.bbo.ask_size, snapshot.bbo.ask_price),
+            (None, OrderSide::Sell) => (snapshot.bbo.bid_size, snapshot.bbo.bid_price),
         };
 
         // Calculate book notional using side's price (not mid)
@@ -1326,6 +1984,69 @@ impl DislocationDetector {
         (clamped_size, liquidity_factor)
     }
 
+    /// Edge multiplier for the time since the market's last oracle move.
+    ///
+    /// 1 within `edge_decay_grace_ms`, falling linearly to 0 at
+    /// `edge_decay_max_age_ms`. Returns None (suppress) from then on.
+    /// Without a tracker or a recorded move the edge is left alone.
+    fn edge_decay_factor(
+        &self,
+        key: MarketKey,
+        snapshot: &MarketSnapshot,
+        side: OrderSide,
+        oracle_tracker: Option<&OracleMovementTracker>,
+    ) -> Option<Decimal> {
+        if !self.config.edge_decay_enabled {
+            return Some(Decimal::ONE);
+        }
+        let now_ms = snapshot.timestamp.timestamp_millis().max(0) as u64;
+        let Some(age_ms) = oracle_tracker.and_then(|t| t.move_age_ms(&key, now_ms)) else {
+            return Some(Decimal::ONE);
+        };
+        let grace_ms = self.config.edge_decay_grace_ms;
+        let max_age_ms = self.config.edge_decay_max_age_ms;
+        if age_ms <= grace_ms {
+            return Some(Decimal::ONE);
+        }
+        if age_ms >= max_age_ms {
+            tracing::debug!(
+                %key,
+                %side,
+                oracle_move_age_ms = age_ms,
+                max_age_ms,
+                "Signal skipped: dislocation outlived the oracle move"
+            );
+            return None;
+        }
+        Some(Decimal::from(max_age_ms - age_ms) / Decimal::from(max_age_ms - grace_ms))
+    }
+
+    /// Expected funding cost over the configured hold (zero when disabled).
+    fn funding_cost(&self, snapshot: &MarketSnapshot, side: OrderSide) -> FundingCost {
+        if !self.config.funding_adjust_enabled {
+            return FundingCost::default();
+        }
+        let now_ms = snapshot.timestamp.timestamp_millis().max(0) as u64;
+        FundingCost::estimate(
+            snapshot.ctx.funding_rate,
+            side,
+            now_ms,
+            self.config.funding_max_hold_ms,
+        )
+    }
+
+    /// Item 7: Record an emitted signal for dedup.
+    ///
+    /// Later checks of the same (market, side) return None until the oracle
+    /// price changes. Call only for signals that are actually traded.
+    pub fn record_signal(&self, signal: &DislocationSignal) {
+        if self.config.signal_dedup_enabled {
+            self.last_signaled_oracle
+                .borrow_mut()
+                .insert((signal.market_key, signal.side), signal.oracle_px);
+        }
+    }
+
     /// Get current configuration.
     pub fn config(&self) -> &DetectorConfig {
         &self.config
@@ -1335,7 +2056,8 @@ impl DislocationDetector {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use hip3_core::{AssetCtx, AssetId, Bbo, DexId, OracleData, Price, Size};
+    use crate::config::ConfidenceWeights;
+    use hip3_core::{AssetCtx, AssetId, Bbo, BookLevel, DexId, L2Book, OracleData, Price, Size};
     use rust_decimal_macros::dec;
 
     fn test_key() -> MarketKey {
@@ -1471,7 +2193,7 @@ mod tests {
         // Result should be 0.0198 (clamped to max)
         let snapshot = make_snapshot(dec!(50000), dec!(49990), dec!(50010));
         let (size, _lf) =
-            detector.calculate_size(&snapshot, OrderSide::Buy, Decimal::ONE, Decimal::ONE);
+            detector.calculate_size(&snapshot, OrderSide::Buy, Decimal::ONE, Decimal::ONE, None);
 
         assert_eq!(size.inner(), dec!(0.0198));
     }
@@ -1684,7 +2406,7 @@ mod tests {
         );
 
         let (size, _lf) =
-            detector.calculate_size(&snapshot, OrderSide::Buy, Decimal::ONE, Decimal::ONE);
+            detector.calculate_size(&snapshot, OrderSide::Buy, Decimal::ONE, Decimal::ONE, None);
 
         // Expected: approximately 0.00275 (within 1% tolerance due to price-based calculation)
         let expected = dec!(0.00275);
@@ -1723,7 +2445,7 @@ mod tests {
         );
 
         let (size, _lf) =
-            detector.calculate_size(&snapshot, OrderSide::Sell, Decimal::ONE, Decimal::ONE);
+            detector.calculate_size(&snapshot, OrderSide::Sell, Decimal::ONE, Decimal::ONE, None);
 
         // Expected: approximately 0.00275 (similar to buy but using bid_price)
         let expected = dec!(0.00275);
@@ -1757,7 +2479,7 @@ mod tests {
             make_snapshot_with_size(dec!(50000), dec!(49990), dec!(50010), dec!(0.2), dec!(0.2));
 
         let (size, _lf) =
-            detector.calculate_size(&snapshot, OrderSide::Buy, Decimal::ONE, Decimal::ONE);
+            detector.calculate_size(&snapshot, OrderSide::Buy, Decimal::ONE, Decimal::ONE, None);
 
         // Expected: 0.2 * 0.10 * 1.0 = 0.02
         assert_eq!(size.inner(), dec!(0.02));
@@ -2730,4 +3452,474 @@ mod tests {
             "Low confidence sell signal should be blocked by entry gate"
         );
     }
+
+    #[test]
+    fn test_confidence_features_and_weights() {
+        // Only book imbalance contributes to the score
+        let config = DetectorConfig {
+            taker_fee_bps: dec!(4),
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(4),
+            oracle_direction_filter: false,
+            min_oracle_change_bps: dec!(0),
+            signal_dedup_enabled: false,
+            confidence_weights: ConfidenceWeights {
+                edge: dec!(0),
+                velocity: dec!(0),
+                consecutive: dec!(0),
+                book_depth: dec!(0),
+                spread: dec!(0),
+                cross_duration: dec!(0),
+                book_imbalance: dec!(2),
+            },
+            ..Default::default()
+        };
+        let detector = DislocationDetector::new(config).unwrap();
+        let key = test_key();
+
+        // Bid queue 3x the ask: imbalance 0.5 → buy factor 0.75
+        let snapshot =
+            make_snapshot_with_size(dec!(50000), dec!(49900), dec!(49940), dec!(300), dec!(100));
+        let signal = detector.check(key, &snapshot, None, None, None).unwrap();
+        assert_eq!(signal.features.imbalance, dec!(0.5));
+        assert_eq!(signal.features.book_imbalance, dec!(0.75));
+        assert_eq!(signal.confidence_score, dec!(0.75));
+        assert_eq!(signal.features.cross_ticks, 1);
+
+        // Cross persists → tick count grows; resets once it clears
+        let signal = detector.check(key, &snapshot, None, None, None).unwrap();
+        assert_eq!(signal.features.cross_ticks, 2);
+        let cleared = make_snapshot(dec!(50000), dec!(49990), dec!(50010));
+        assert!(detector.check(key, &cleared, None, None, None).is_none());
+        let signal = detector.check(key, &snapshot, None, None, None).unwrap();
+        assert_eq!(signal.features.cross_ticks, 1);
+    }
+
+    // ---- L2 Depth-Weighted Detection ----
+
+    fn l2_detector() -> DislocationDetector {
+        let user_fees = UserFees {
+            taker_bps: dec!(2), // 4 bps effective
+            ..Default::default()
+        };
+        let config = DetectorConfig {
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(4), // total cost = 10 bps
+            oracle_direction_filter: false,
+            min_oracle_change_bps: dec!(0),
+            l2_depth_enabled: true,
+            ..Default::default()
+        };
+        DislocationDetector::with_user_fees(config, user_fees).unwrap()
+    }
+
+    fn with_asks(snapshot: MarketSnapshot, asks: &[(Decimal, Decimal)]) -> MarketSnapshot {
+        let asks = asks
+            .iter()
+            .map(|(px, sz)| BookLevel::new(Price::new(*px), Size::new(*sz)))
+            .collect();
+        snapshot.with_book(Some(std::sync::Arc::new(L2Book::new(Vec::new(), asks))))
+    }
+
+    #[test]
+    fn test_l2_depth_requires_book() {
+        let detector = l2_detector();
+        let snapshot = make_snapshot(dec!(50000), dec!(49920), dec!(49940));
+
+        assert!(detector
+            .check(test_key(), &snapshot, None, None, None)
+            .is_none());
+    }
+
+    #[test]
+    fn test_l2_depth_weighted_signal() {
+        let detector = l2_detector();
+        // Threshold price = 49950. First two levels cross, third does not.
+        let snapshot = with_asks(
+            make_snapshot_with_size(dec!(50000), dec!(49920), dec!(49940), dec!(1), dec!(0.001)),
+            &[
+                (dec!(49940), dec!(0.001)),
+                (dec!(49945), dec!(0.01)),
+                (dec!(49990), dec!(5)),
+            ],
+        );
+
+        let signal = detector
+            .check(test_key(), &snapshot, None, None, None)
+            .expect("depth crosses oracle");
+
+        assert_eq!(signal.best_px.inner(), dec!(49945)); // deepest crossing level
+        assert_eq!(signal.book_size.inner(), dec!(0.011));
+        assert!(signal.raw_edge_bps > dec!(11) && signal.raw_edge_bps < dec!(12));
+        assert!(signal.suggested_size.inner() <= dec!(0.011));
+    }
+
+    #[test]
+    fn test_l2_depth_insufficient_fillable_notional() {
+        let detector = l2_detector();
+        // Only ~$25 crosses the threshold (< $50 default)
+        let snapshot = with_asks(
+            make_snapshot(dec!(50000), dec!(49920), dec!(49940)),
+            &[(dec!(49940), dec!(0.0005)), (dec!(49990), dec!(5))],
+        );
+
+        assert!(detector
+            .check(test_key(), &snapshot, None, None, None)
+            .is_none());
+    }
+
+    #[test]
+    fn test_funding_adjusted_edge() {
+        let user_fees = UserFees {
+            taker_bps: dec!(2), // 4 bps effective
+            ..Default::default()
+        };
+        let config = DetectorConfig {
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(4), // total cost = 10 bps
+            oracle_direction_filter: false,
+            min_oracle_change_bps: dec!(0),
+            funding_adjust_enabled: true,
+            funding_max_hold_ms: 15 * 60 * 1000,
+            signal_dedup_enabled: false,
+            ..Default::default()
+        };
+        let detector = DislocationDetector::with_user_fees(config, user_fees).unwrap();
+
+        // Raw edge 12 bps, detected 10 minutes before a funding settlement
+        let mut snapshot = make_snapshot(dec!(50000), dec!(49920), dec!(49940));
+        snapshot.timestamp = chrono::DateTime::from_timestamp_millis(50 * 60 * 1000).unwrap();
+
+        // 0.01%/h funding: 1 bps cost → net 1 bps
+        snapshot.ctx.funding_rate = dec!(0.0001);
+        let signal = detector
+            .check(test_key(), &snapshot, None, None, None)
+            .expect("edge still covers funding");
+        assert_eq!(signal.net_edge_bps, dec!(1));
+        assert_eq!(signal.fee_metadata.funding_events, 1);
+        assert_eq!(signal.fee_metadata.funding_cost_bps, dec!(1));
+
+        // 0.03%/h funding: 3 bps cost → net negative, skipped
+        snapshot.ctx.funding_rate = dec!(0.0003);
+        assert!(detector
+            .check(test_key(), &snapshot, None, None, None)
+            .is_none());
+    }
+
+    #[test]
+    fn test_regime_scales_threshold() {
+        use hip3_feed::{RegimeClassifier, RegimeConfig, VolRegime};
+
+        let user_fees = UserFees {
+            taker_bps: dec!(2), // 4 bps effective
+            ..Default::default()
+        };
+        let config = DetectorConfig {
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(4), // total cost = 10 bps
+            oracle_direction_filter: false,
+            min_oracle_change_bps: dec!(0),
+            signal_dedup_enabled: false,
+            regime_enabled: true,
+            regime_turbulent_threshold_mult: dec!(1.5),
+            ..Default::default()
+        };
+        let classifier = RegimeClassifier::new_shared(RegimeConfig {
+            min_samples: 5,
+            ..Default::default()
+        });
+        let detector = DislocationDetector::with_user_fees(config, user_fees)
+            .unwrap()
+            .with_regime_classifier(classifier.clone());
+
+        // Raw edge 12 bps clears 10 bps in the normal regime
+        let snapshot = make_snapshot(dec!(50000), dec!(49920), dec!(49940));
+        assert!(detector
+            .check(test_key(), &snapshot, None, None, None)
+            .is_some());
+
+        // Choppy tape → turbulent → threshold 15 bps
+        for (i, px) in [dec!(100), dec!(101), dec!(100), dec!(101), dec!(100)]
+            .into_iter()
+            .enumerate()
+        {
+            classifier.record(test_key(), Price::new(px), i as u64 * 1_000);
+        }
+        assert_eq!(classifier.regime(&test_key()), VolRegime::Turbulent);
+        assert!(detector
+            .check(test_key(), &snapshot, None, None, None)
+            .is_none());
+    }
+
+    #[test]
+    fn test_asymmetric_side_thresholds() {
+        let user_fees = UserFees {
+            taker_bps: dec!(2), // 4 bps effective
+            ..Default::default()
+        };
+        let config = DetectorConfig {
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(4), // total cost = 10 bps
+            oracle_direction_filter: false,
+            min_oracle_change_bps: dec!(0),
+            signal_dedup_enabled: false,
+            threshold_bps_short: Some(dec!(20)),
+            ..Default::default()
+        };
+        let detector = DislocationDetector::with_user_fees(config, user_fees).unwrap();
+
+        // Long: 12 bps edge clears the 10 bps total cost
+        let buy = make_snapshot(dec!(50000), dec!(49920), dec!(49940));
+        let signal = detector.check(test_key(), &buy, None, None, None).unwrap();
+        assert_eq!(signal.side, OrderSide::Buy);
+        assert_eq!(signal.threshold_bps, dec!(10));
+
+        // Short: 12 bps edge blocked by the 20 bps config threshold
+        let sell = make_snapshot(dec!(50000), dec!(50060), dec!(50080));
+        assert!(detector
+            .check(test_key(), &sell, None, None, None)
+            .is_none());
+
+        // Per-market short override takes precedence over the config
+        let thresholds = SideThresholds {
+            long: None,
+            short: Some(dec!(8)),
+        };
+        let signal = detector
+            .check_sided(test_key(), &sell, thresholds, None, None)
+            .unwrap();
+        assert_eq!(signal.side, OrderSide::Sell);
+        assert_eq!(signal.threshold_bps, dec!(8));
+    }
+
+    #[test]
+    fn test_learned_slippage_raises_cost() {
+        use crate::slippage::SlippageConfig;
+
+        let user_fees = UserFees {
+            taker_bps: dec!(2), // 4 bps effective
+            ..Default::default()
+        };
+        let config = DetectorConfig {
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(4), // total cost = 10 bps
+            oracle_direction_filter: false,
+            min_oracle_change_bps: dec!(0),
+            signal_dedup_enabled: false,
+            ..Default::default()
+        };
+        let detector = DislocationDetector::with_user_fees(config, user_fees)
+            .unwrap()
+            .with_slippage_estimator(SlippageEstimator::new(SlippageConfig {
+                enabled: true,
+                min_samples: 2,
+                ..Default::default()
+            }));
+
+        // Raw edge 12 bps clears the static 10 bps
+        let snapshot = make_snapshot(dec!(50000), dec!(49920), dec!(49940));
+        assert!(detector
+            .check(test_key(), &snapshot, None, None, None)
+            .is_some());
+
+        // Two buys filled 5 bps through best ask → cost 4 + 5 + 4 = 13 bps
+        let best = Price::new(dec!(100));
+        for _ in 0..2 {
+            detector.record_fill_slippage(
+                test_key(),
+                OrderSide::Buy,
+                best,
+                Price::new(dec!(100.05)),
+            );
+        }
+        assert_eq!(detector.slippage_bps(&test_key()), dec!(5));
+        assert_eq!(detector.total_cost_bps(&test_key()), dec!(13));
+        assert!(detector
+            .check(test_key(), &snapshot, None, None, None)
+            .is_none());
+    }
+
+    #[test]
+    fn test_book_imbalance_filter() {
+        let config = DetectorConfig {
+            taker_fee_bps: dec!(4),
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(4),
+            oracle_direction_filter: false,
+            min_oracle_change_bps: dec!(0),
+            signal_dedup_enabled: false,
+            imbalance_filter_enabled: true,
+            imbalance_min: dec!(0.2),
+            imbalance_levels: 3,
+            ..Default::default()
+        };
+        let detector = DislocationDetector::new(config).unwrap();
+        let key = test_key();
+
+        // Bid queue 3x the ask: imbalance 0.5 → buy allowed and recorded
+        let snapshot =
+            make_snapshot_with_size(dec!(50000), dec!(49900), dec!(49940), dec!(300), dec!(100));
+        let signal = detector.check(key, &snapshot, None, None, None).unwrap();
+        assert_eq!(signal.book_imbalance, dec!(0.5));
+
+        // Ask-heavy BBO: imbalance -0.5 → buy blocked
+        let snapshot =
+            make_snapshot_with_size(dec!(50000), dec!(49900), dec!(49940), dec!(100), dec!(300));
+        assert!(detector.check(key, &snapshot, None, None, None).is_none());
+
+        // Bid-heavy BBO but deep asks behind it: L2 levels override the BBO
+        let mut snapshot =
+            make_snapshot_with_size(dec!(50000), dec!(49900), dec!(49940), dec!(300), dec!(100));
+        snapshot.book = Some(std::sync::Arc::new(L2Book::new(
+            vec![BookLevel::new(
+                Price::new(dec!(49900)),
+                Size::new(dec!(300)),
+            )],
+            vec![
+                BookLevel::new(Price::new(dec!(49940)), Size::new(dec!(100))),
+                BookLevel::new(Price::new(dec!(49950)), Size::new(dec!(400))),
+                BookLevel::new(Price::new(dec!(49960)), Size::new(dec!(400))),
+            ],
+        )));
+        assert!(detector.check(key, &snapshot, None, None, None).is_none());
+    }
+
+    #[test]
+    fn test_oracle_sanity_suppresses_phantom_edge() {
+        use hip3_feed::ReferencePriceStore;
+
+        let config = DetectorConfig {
+            taker_fee_bps: dec!(4),
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(4),
+            oracle_direction_filter: false,
+            min_oracle_change_bps: dec!(0),
+            signal_dedup_enabled: false,
+            oracle_sanity_enabled: true,
+            oracle_sanity_max_deviation_bps: dec!(50),
+            ..Default::default()
+        };
+        let reference = ReferencePriceStore::new_shared();
+        let detector = DislocationDetector::new(config)
+            .unwrap()
+            .with_reference_prices(reference.clone());
+        let key = test_key();
+        let snapshot = make_snapshot(dec!(50000), dec!(49900), dec!(49940));
+
+        // No reference yet: check passes
+        assert!(detector.check(key, &snapshot, None, None, None).is_some());
+
+        // Reference agrees with the oracle (10 bps apart)
+        let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+        reference.update(key, Price::new(dec!(49950)), now_ms);
+        assert!(detector.check(key, &snapshot, None, None, None).is_some());
+
+        // Reference near the book: oracle is 100 bps off → suppressed
+        reference.update(key, Price::new(dec!(49500)), now_ms);
+        assert!(detector.check(key, &snapshot, None, None, None).is_none());
+    }
+
+    #[test]
+    fn test_edge_decay_after_oracle_move() {
+        let config = DetectorConfig {
+            taker_fee_bps: dec!(4),
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(4),
+            oracle_direction_filter: false,
+            min_oracle_change_bps: dec!(0),
+            min_consecutive_oracle_moves: 0,
+            signal_dedup_enabled: false,
+            edge_decay_enabled: true,
+            edge_decay_grace_ms: 1_000,
+            edge_decay_max_age_ms: 5_000,
+            ..Default::default()
+        };
+        let detector = DislocationDetector::new(config).unwrap();
+        let tracker = OracleMovementTracker::new(Default::default());
+        let key = test_key();
+        tracker.record_move_at(key, Price::new(dec!(49900)), 0);
+        tracker.record_move_at(key, Price::new(dec!(50000)), 10_000);
+
+        // Ask 40 bps under the oracle; threshold is 10 bps
+        let mut snapshot = make_snapshot(dec!(50000), dec!(49700), dec!(49800));
+        let at = |ms: i64| chrono::DateTime::from_timestamp_millis(ms).unwrap();
+
+        // Within grace: full edge
+        snapshot.timestamp = at(10_500);
+        let signal = detector
+            .check(key, &snapshot, None, Some(&tracker), None)
+            .unwrap();
+        assert_eq!(signal.edge_decay_factor, dec!(1));
+        assert_eq!(signal.raw_edge_bps, dec!(40));
+
+        // Halfway through the decay window: half the edge
+        snapshot.timestamp = at(13_000);
+        let signal = detector
+            .check(key, &snapshot, None, Some(&tracker), None)
+            .unwrap();
+        assert_eq!(signal.edge_decay_factor, dec!(0.5));
+        assert_eq!(signal.raw_edge_bps, dec!(20));
+
+        // Decayed below threshold (40 * 0.125 = 5 bps)
+        snapshot.timestamp = at(14_500);
+        assert!(detector
+            .check(key, &snapshot, None, Some(&tracker), None)
+            .is_none());
+
+        // Past max age: suppressed
+        snapshot.timestamp = at(16_000);
+        assert!(detector
+            .check(key, &snapshot, None, Some(&tracker), None)
+            .is_none());
+
+        // No tracker: fail open
+        assert!(detector.check(key, &snapshot, None, None, None).is_some());
+    }
+
+    #[test]
+    fn test_momentum_veto_skips_chasing() {
+        use hip3_feed::BboHistory;
+
+        let config = DetectorConfig {
+            taker_fee_bps: dec!(4),
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(4),
+            oracle_direction_filter: false,
+            min_oracle_change_bps: dec!(0),
+            signal_dedup_enabled: false,
+            momentum_veto_enabled: true,
+            momentum_veto_bps: dec!(3),
+            momentum_veto_window_ms: 500,
+            ..Default::default()
+        };
+        let history = BboHistory::new_shared(1_000);
+        let detector = DislocationDetector::new(config)
+            .unwrap()
+            .with_bbo_history(history.clone());
+        let key = test_key();
+        let mut snapshot = make_snapshot(dec!(50000), dec!(49700), dec!(49800));
+        snapshot.timestamp = chrono::DateTime::from_timestamp_millis(10_400).unwrap();
+
+        let record = |bid, ask, ms| history.record(key, Price::new(bid), Price::new(ask), ms);
+
+        // No history: passes
+        assert!(detector.check(key, &snapshot, None, None, None).is_some());
+
+        // Ask drifting up 2 bps: under the veto
+        record(dec!(49690), dec!(49790), 10_000);
+        record(dec!(49700), dec!(49800), 10_400);
+        assert!(detector.check(key, &snapshot, None, None, None).is_some());
+
+        // Ask up 10 bps in 400ms: MM is catching up
+        history.clear(&key);
+        record(dec!(49650), dec!(49750), 10_000);
+        record(dec!(49700), dec!(49800), 10_400);
+        assert!(detector.check(key, &snapshot, None, None, None).is_none());
+
+        // A falling ask moves away from the oracle: no veto
+        history.clear(&key);
+        record(dec!(49750), dec!(49850), 10_000);
+        record(dec!(49700), dec!(49800), 10_400);
+        assert!(detector.check(key, &snapshot, None, None, None).is_some());
+    }
 }
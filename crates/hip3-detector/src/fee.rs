@@ -3,6 +3,7 @@
 //! Implements HIP-3-specific 2x taker fee multiplier and fee metadata tracking.
 //! All fee calculations are performed with explicit audit trail for transparency.
 
+use hip3_core::OrderSide;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -12,6 +13,9 @@ pub const HIP3_FEE_MULTIPLIER: Decimal = Decimal::TWO;
 /// Default HIP-3 base taker fee in basis points (before 2x multiplier).
 pub const DEFAULT_BASE_TAKER_FEE_BPS: Decimal = Decimal::TWO; // 2 bps base
 
+/// Funding settlement interval (Hyperliquid pays funding hourly).
+pub const FUNDING_INTERVAL_MS: u64 = 3_600_000;
+
 /// User fee tier from exchange API.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserFees {
@@ -82,6 +86,18 @@ pub struct FeeMetadata {
     pub min_edge_bps: Decimal,
     /// Total cost = effective_taker_fee + slippage + min_edge (bps).
     pub total_cost_bps: Decimal,
+    /// Hourly funding rate at detection (signed fraction, positive = longs pay).
+    #[serde(default)]
+    pub funding_rate: Decimal,
+    /// Funding settlements expected within the max hold time.
+    #[serde(default)]
+    pub funding_events: u32,
+    /// Expected funding cost subtracted from net edge (bps, never negative).
+    #[serde(default)]
+    pub funding_cost_bps: Decimal,
+    /// Builder fee charged on top of the exchange fee (bps, no HIP-3 multiplier).
+    #[serde(default)]
+    pub builder_fee_bps: Decimal,
 }
 
 impl FeeMetadata {
@@ -99,9 +115,30 @@ impl FeeMetadata {
             slippage_bps,
             min_edge_bps,
             total_cost_bps,
+            funding_rate: Decimal::ZERO,
+            funding_events: 0,
+            funding_cost_bps: Decimal::ZERO,
+            builder_fee_bps: Decimal::ZERO,
         }
     }
 
+    /// Add the builder fee to the total cost.
+    #[must_use]
+    pub fn with_builder_fee(mut self, builder_fee_bps: Decimal) -> Self {
+        self.builder_fee_bps = builder_fee_bps;
+        self.total_cost_bps += builder_fee_bps;
+        self
+    }
+
+    /// Attach the funding adjustment applied to net edge.
+    #[must_use]
+    pub fn with_funding(mut self, funding: &FundingCost) -> Self {
+        self.funding_rate = funding.funding_rate;
+        self.funding_events = funding.events;
+        self.funding_cost_bps = funding.cost_bps;
+        self
+    }
+
     /// Create from UserFees and config parameters.
     pub fn from_user_fees(
         user_fees: &UserFees,
@@ -127,6 +164,41 @@ impl Default for FeeMetadata {
     }
 }
 
+/// Expected funding cost for a position opened now and held up to a max time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FundingCost {
+    /// Hourly funding rate used (signed fraction).
+    pub funding_rate: Decimal,
+    /// Funding settlements crossed during the hold.
+    pub events: u32,
+    /// Cost in bps for the given side. Funding received is not credited.
+    pub cost_bps: Decimal,
+}
+
+impl FundingCost {
+    /// Estimate funding paid when entering `side` at `now_ms` and holding
+    /// for at most `max_hold_ms`.
+    ///
+    /// Counts hourly settlement timestamps in `(now_ms, now_ms + max_hold_ms]`.
+    /// Longs pay a positive rate, shorts pay a negative one.
+    pub fn estimate(funding_rate: Decimal, side: OrderSide, now_ms: u64, max_hold_ms: u64) -> Self {
+        let end_ms = now_ms.saturating_add(max_hold_ms);
+        let events = (end_ms / FUNDING_INTERVAL_MS - now_ms / FUNDING_INTERVAL_MS) as u32;
+        let signed_rate = match side {
+            OrderSide::Buy => funding_rate,
+            OrderSide::Sell => -funding_rate,
+        };
+        let cost_bps =
+            (signed_rate * Decimal::from(10000) * Decimal::from(events)).max(Decimal::ZERO);
+
+        Self {
+            funding_rate,
+            events,
+            cost_bps,
+        }
+    }
+}
+
 /// HIP-3 fee calculator.
 ///
 /// Manages fee calculation with HIP-3 2x multiplier and user-specific rates.
@@ -138,6 +210,8 @@ pub struct FeeCalculator {
     slippage_bps: Decimal,
     /// Minimum required edge in basis points.
     min_edge_bps: Decimal,
+    /// Builder fee in basis points (0 = no builder code).
+    builder_fee_bps: Decimal,
 }
 
 impl FeeCalculator {
@@ -147,6 +221,7 @@ impl FeeCalculator {
             user_fees,
             slippage_bps,
             min_edge_bps,
+            builder_fee_bps: Decimal::ZERO,
         }
     }
 
@@ -156,9 +231,23 @@ impl FeeCalculator {
             user_fees: UserFees::default(),
             slippage_bps: Decimal::from(2),
             min_edge_bps: Decimal::from(5),
+            builder_fee_bps: Decimal::ZERO,
         }
     }
 
+    /// Set the builder fee charged on every order (bps).
+    ///
+    /// The builder fee is paid on top of the exchange fee and is not
+    /// subject to the HIP-3 multiplier.
+    pub fn set_builder_fee_bps(&mut self, builder_fee_bps: Decimal) {
+        self.builder_fee_bps = builder_fee_bps;
+    }
+
+    /// Get the builder fee in basis points.
+    pub fn builder_fee_bps(&self) -> Decimal {
+        self.builder_fee_bps
+    }
+
     /// Update user fees (e.g., after REST fetch).
     pub fn update_user_fees(&mut self, user_fees: UserFees) {
         self.user_fees = user_fees;
@@ -174,14 +263,30 @@ impl FeeCalculator {
         self.user_fees.taker_bps * HIP3_FEE_MULTIPLIER
     }
 
-    /// Calculate total cost (fee + slippage + min edge).
+    /// Get the static slippage estimate in basis points.
+    pub fn slippage_bps(&self) -> Decimal {
+        self.slippage_bps
+    }
+
+    /// Calculate total cost (fee + builder fee + slippage + min edge).
     pub fn total_cost_bps(&self) -> Decimal {
-        self.effective_taker_fee_bps() + self.slippage_bps + self.min_edge_bps
+        self.total_cost_bps_with_slippage(self.slippage_bps)
+    }
+
+    /// Calculate total cost using the given slippage instead of the static one.
+    pub fn total_cost_bps_with_slippage(&self, slippage_bps: Decimal) -> Decimal {
+        self.effective_taker_fee_bps() + self.builder_fee_bps + slippage_bps + self.min_edge_bps
     }
 
     /// Generate fee metadata for audit trail.
     pub fn metadata(&self) -> FeeMetadata {
-        FeeMetadata::from_user_fees(&self.user_fees, self.slippage_bps, self.min_edge_bps)
+        self.metadata_with_slippage(self.slippage_bps)
+    }
+
+    /// Generate fee metadata using the given slippage instead of the static one.
+    pub fn metadata_with_slippage(&self, slippage_bps: Decimal) -> FeeMetadata {
+        FeeMetadata::from_user_fees(&self.user_fees, slippage_bps, self.min_edge_bps)
+            .with_builder_fee(self.builder_fee_bps)
     }
 
     /// Calculate buy threshold multiplier.
@@ -250,6 +355,20 @@ mod tests {
         assert_eq!(calc.sell_threshold(), dec!(1.0011));
     }
 
+    #[test]
+    fn test_builder_fee_in_total_cost() {
+        let mut calc = FeeCalculator::with_defaults();
+        calc.set_builder_fee_bps(dec!(1));
+
+        // Total cost: 4 + 1 + 2 + 5 = 12 bps (no 2x on the builder fee)
+        assert_eq!(calc.total_cost_bps(), dec!(12));
+        assert_eq!(calc.net_edge_bps(dec!(20)), dec!(8));
+
+        let metadata = calc.metadata();
+        assert_eq!(metadata.builder_fee_bps, dec!(1));
+        assert_eq!(metadata.total_cost_bps, dec!(12));
+    }
+
     #[test]
     fn test_fee_calculator_with_vip_fees() {
         let vip_fees = UserFees {
@@ -336,4 +455,31 @@ mod tests {
         let calc = FeeCalculator::new(fees, dec!(0), dec!(0));
         assert_eq!(calc.effective_taker_fee_bps(), effective_bps);
     }
+
+    #[test]
+    fn test_funding_cost_crosses_settlement() {
+        // 10 minutes before the hour, 15 minute hold → one settlement
+        let now_ms = 50 * 60 * 1000;
+        let hold_ms = 15 * 60 * 1000;
+
+        // 0.01%/h: long pays 1 bps, short receives (not credited)
+        let long = FundingCost::estimate(dec!(0.0001), OrderSide::Buy, now_ms, hold_ms);
+        assert_eq!(long.events, 1);
+        assert_eq!(long.cost_bps, dec!(1));
+        let short = FundingCost::estimate(dec!(0.0001), OrderSide::Sell, now_ms, hold_ms);
+        assert_eq!(short.cost_bps, Decimal::ZERO);
+
+        // Negative rate: short pays
+        let short = FundingCost::estimate(dec!(-0.0002), OrderSide::Sell, now_ms, hold_ms);
+        assert_eq!(short.cost_bps, dec!(2));
+
+        // Hold ends before the hour → no settlement
+        let none = FundingCost::estimate(dec!(0.0001), OrderSide::Buy, now_ms, 5 * 60 * 1000);
+        assert_eq!(none.events, 0);
+        assert_eq!(none.cost_bps, Decimal::ZERO);
+
+        let metadata = FeeMetadata::default().with_funding(&long);
+        assert_eq!(metadata.funding_events, 1);
+        assert_eq!(metadata.funding_cost_bps, dec!(1));
+    }
 }
@@ -0,0 +1,409 @@
+//! Cross-market lead-lag detection.
+//!
+//! Uses a reference market (e.g. L1 BTC/ETH, or another xyz asset) as a
+//! leading indicator for a target market. When the reference oracle has moved
+//! at least `min_reference_move_bps` within `window_ms` and the target mid has
+//! followed less than `max_target_follow_ratio` of the expected move, the
+//! target BBO is treated as stale and a signal is emitted against it.
+//!
+//! Fair value for the target is its mid at the start of the window, shifted by
+//! `beta × reference move`. Edge is measured against that fair value instead
+//! of the target oracle, and must clear the same fee + slippage cost as the
+//! main detector. Signals are tagged with [`SignalSource::LeadLag`].
+
+use crate::config::DetectorConfig;
+use crate::fee::{FeeCalculator, UserFees};
+use crate::signal::{DislocationSignal, SignalSource, SignalStrength};
+use hip3_core::{MarketKey, MarketSnapshot, OrderSide, Price, Size};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use tracing::debug;
+
+/// Reference → target pairing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeadLagPair {
+    /// Leading coin (e.g. "BTC" or "xyz:NVDA").
+    pub reference: String,
+    /// Lagging traded coin (must be one of the configured markets).
+    pub target: String,
+}
+
+/// Configuration for lead-lag detection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeadLagConfig {
+    /// Enable lead-lag detection (default: false).
+    #[serde(default)]
+    pub enabled: bool,
+    /// Reference → target pairs.
+    #[serde(default)]
+    pub pairs: Vec<LeadLagPair>,
+    /// Lookback window for reference and target moves (ms).
+    #[serde(default = "default_window_ms")]
+    pub window_ms: u64,
+    /// Minimum absolute reference oracle move within the window (bps).
+    #[serde(default = "default_min_reference_move_bps")]
+    pub min_reference_move_bps: Decimal,
+    /// Expected target move per bps of reference move.
+    #[serde(default = "default_beta")]
+    pub beta: Decimal,
+    /// Target is considered repriced once it has moved this fraction of the
+    /// expected move (0.3 = 30%).
+    #[serde(default = "default_max_target_follow_ratio")]
+    pub max_target_follow_ratio: Decimal,
+}
+
+fn default_window_ms() -> u64 {
+    3000 // 3s
+}
+
+fn default_min_reference_move_bps() -> Decimal {
+    Decimal::from(15)
+}
+
+fn default_beta() -> Decimal {
+    Decimal::ONE
+}
+
+fn default_max_target_follow_ratio() -> Decimal {
+    Decimal::new(3, 1) // 0.3
+}
+
+impl Default for LeadLagConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            pairs: Vec::new(),
+            window_ms: default_window_ms(),
+            min_reference_move_bps: default_min_reference_move_bps(),
+            beta: default_beta(),
+            max_target_follow_ratio: default_max_target_follow_ratio(),
+        }
+    }
+}
+
+/// Lead-lag detector.
+///
+/// Keeps short price histories for reference coins (fed via
+/// [`record_reference`](Self::record_reference)) and target markets (fed via
+/// [`check`](Self::check)).
+pub struct LeadLagDetector {
+    config: LeadLagConfig,
+    fee_calculator: FeeCalculator,
+    sizing_alpha: Decimal,
+    max_notional: Decimal,
+    min_order_notional: Decimal,
+    signal_ttl_ms: u64,
+    /// Target coin (uppercase) → reference coin (uppercase).
+    target_to_reference: HashMap<String, String>,
+    /// Reference oracle history: (timestamp_ms, price).
+    references: HashMap<String, VecDeque<(u64, Decimal)>>,
+    /// Target mid history: (timestamp_ms, mid).
+    targets: HashMap<MarketKey, VecDeque<(u64, Decimal)>>,
+    /// Reference sample timestamp that last produced a signal per target.
+    last_fired: HashMap<MarketKey, u64>,
+}
+
+impl LeadLagDetector {
+    /// Create a detector, sharing fee and sizing settings with the main detector.
+    pub fn new(config: LeadLagConfig, detector_config: &DetectorConfig) -> Self {
+        let user_fees = UserFees::from_effective_taker_bps(detector_config.taker_fee_bps);
+        let fee_calculator = FeeCalculator::new(
+            user_fees,
+            detector_config.slippage_bps,
+            detector_config.min_edge_bps,
+        );
+        let target_to_reference = config
+            .pairs
+            .iter()
+            .map(|p| (p.target.to_uppercase(), p.reference.to_uppercase()))
+            .collect();
+
+        Self {
+            config,
+            fee_calculator,
+            sizing_alpha: detector_config.sizing_alpha,
+            max_notional: detector_config.max_notional,
+            min_order_notional: detector_config.min_order_notional,
+            signal_ttl_ms: detector_config.signal_ttl_ms,
+            target_to_reference,
+            references: HashMap::new(),
+            targets: HashMap::new(),
+            last_fired: HashMap::new(),
+        }
+    }
+
+    /// Update user fees (e.g., after a fee tier change).
+    pub fn update_user_fees(&mut self, user_fees: UserFees) {
+        self.fee_calculator.update_user_fees(user_fees);
+    }
+
+    /// Charge a builder fee (bps) on every signal's costs.
+    pub fn set_builder_fee_bps(&mut self, builder_fee_bps: Decimal) {
+        self.fee_calculator.set_builder_fee_bps(builder_fee_bps);
+    }
+
+    /// Distinct reference coins across all pairs.
+    pub fn reference_coins(&self) -> Vec<String> {
+        let mut coins: Vec<String> = self
+            .config
+            .pairs
+            .iter()
+            .map(|p| p.reference.clone())
+            .collect();
+        coins.sort();
+        coins.dedup();
+        coins
+    }
+
+    /// Whether `coin` is a reference for any pair.
+    pub fn is_reference(&self, coin: &str) -> bool {
+        let coin = coin.to_uppercase();
+        self.target_to_reference.values().any(|r| *r == coin)
+    }
+
+    /// Record a reference oracle price.
+    pub fn record_reference(&mut self, coin: &str, price: Price, now_ms: u64) {
+        let window_ms = self.config.window_ms;
+        let history = self.references.entry(coin.to_uppercase()).or_default();
+        push_sample(history, now_ms, price.inner(), window_ms);
+    }
+
+    /// Record the target mid and check for a lagging target.
+    ///
+    /// Call on every tick for configured targets so the mid history stays
+    /// current, even when no signal is expected.
+    pub fn check(
+        &mut self,
+        key: MarketKey,
+        target_coin: &str,
+        snapshot: &MarketSnapshot,
+        now_ms: u64,
+    ) -> Option<DislocationSignal> {
+        let reference = self.target_to_reference.get(&target_coin.to_uppercase())?;
+        let mid = snapshot.bbo.mid_price()?.inner();
+        if mid.is_zero() {
+            return None;
+        }
+
+        let window_ms = self.config.window_ms;
+        let target_history = self.targets.entry(key).or_default();
+        push_sample(target_history, now_ms, mid, window_ms);
+        let (_, target_start) = *target_history.front()?;
+
+        let ref_history = self.references.get(reference)?;
+        let (ref_start_ms, ref_start) = *ref_history.front()?;
+        let (ref_last_ms, ref_last) = *ref_history.back()?;
+        if ref_start_ms == ref_last_ms
+            || ref_start.is_zero()
+            || now_ms.saturating_sub(ref_last_ms) > window_ms
+        {
+            return None;
+        }
+
+        let bps = Decimal::from(10000);
+        let ref_move_bps = (ref_last - ref_start) / ref_start * bps;
+        if ref_move_bps.abs() < self.config.min_reference_move_bps {
+            return None;
+        }
+
+        let expected_move_bps = self.config.beta * ref_move_bps;
+        if expected_move_bps.is_zero() {
+            return None;
+        }
+        let target_move_bps = (mid - target_start) / target_start * bps;
+        let follow_ratio = target_move_bps / expected_move_bps;
+        if follow_ratio >= self.config.max_target_follow_ratio {
+            return None;
+        }
+
+        // One signal per reference tick
+        if self.last_fired.get(&key) == Some(&ref_last_ms) {
+            return None;
+        }
+
+        let fair = target_start * (Decimal::ONE + expected_move_bps / bps);
+        if fair.is_zero() {
+            return None;
+        }
+        let (side, best_px, book_size, raw_edge_bps) = if expected_move_bps > Decimal::ZERO {
+            let ask = snapshot.bbo.ask_price;
+            let edge = (fair - ask.inner()) / fair * bps;
+            (OrderSide::Buy, ask, snapshot.bbo.ask_size, edge)
+        } else {
+            let bid = snapshot.bbo.bid_price;
+            let edge = (bid.inner() - fair) / fair * bps;
+            (OrderSide::Sell, bid, snapshot.bbo.bid_size, edge)
+        };
+
+        let total_cost = self.fee_calculator.total_cost_bps();
+        let strength = SignalStrength::from_edge(raw_edge_bps, total_cost)?;
+        let size = self.calculate_size(book_size, best_px);
+        if size.is_zero() {
+            return None;
+        }
+
+        debug!(
+            market = %key,
+            %reference,
+            %ref_move_bps,
+            %target_move_bps,
+            %follow_ratio,
+            %fair,
+            %raw_edge_bps,
+            "Lead-lag: target lagging reference"
+        );
+        self.last_fired.insert(key, ref_last_ms);
+
+        let confidence = (Decimal::ONE - follow_ratio).clamp(Decimal::ZERO, Decimal::ONE);
+        let mut signal = DislocationSignal::new(
+            key,
+            side,
+            raw_edge_bps,
+            raw_edge_bps - total_cost,
+            strength,
+            size,
+            snapshot.ctx.oracle.oracle_px,
+            best_px,
+            book_size,
+            self.fee_calculator.metadata(),
+            ref_move_bps.abs(),
+            confidence,
+        );
+        signal.source = SignalSource::LeadLag;
+        signal.ttl_ms = self.signal_ttl_ms;
+        signal.threshold_bps = total_cost;
+        Some(signal)
+    }
+
+    /// Alpha-scaled top-of-book size clamped to [min_order_notional, max_notional].
+    fn calculate_size(&self, book_size: Size, px: Price) -> Size {
+        if px.is_zero() {
+            return Size::ZERO;
+        }
+        let alpha_size = book_size.inner() * self.sizing_alpha;
+        // 1% buffer, matching the main detector
+        let max_size = self.max_notional * Decimal::new(99, 2) / px.inner();
+        let min_size = self.min_order_notional / px.inner();
+        Size::new(alpha_size.min(max_size).max(min_size))
+    }
+}
+
+/// Append a sample and drop those older than the window (keeping at least one).
+fn push_sample(
+    history: &mut VecDeque<(u64, Decimal)>,
+    now_ms: u64,
+    value: Decimal,
+    window_ms: u64,
+) {
+    history.push_back((now_ms, value));
+    let cutoff = now_ms.saturating_sub(window_ms);
+    while history.len() > 1 && history.front().is_some_and(|(ts, _)| *ts < cutoff) {
+        history.pop_front();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetCtx, AssetId, Bbo, DexId, OracleData};
+    use rust_decimal_macros::dec;
+
+    fn test_key() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn make_snapshot(bid: Decimal, ask: Decimal) -> MarketSnapshot {
+        let bbo = Bbo::new(
+            Price::new(bid),
+            Size::new(dec!(10)),
+            Price::new(ask),
+            Size::new(dec!(10)),
+        );
+        let oracle_data = OracleData::new(Price::new(dec!(100)), Price::new(dec!(100)));
+        MarketSnapshot::new(bbo, AssetCtx::new(oracle_data, dec!(0.0001)))
+    }
+
+    fn make_detector() -> LeadLagDetector {
+        let config = LeadLagConfig {
+            enabled: true,
+            pairs: vec![LeadLagPair {
+                reference: "BTC".to_string(),
+                target: "xyz:MSTR".to_string(),
+            }],
+            ..Default::default()
+        };
+        let detector_config = DetectorConfig {
+            taker_fee_bps: dec!(4),
+            slippage_bps: dec!(2),
+            min_edge_bps: dec!(0),
+            ..Default::default()
+        };
+        LeadLagDetector::new(config, &detector_config)
+    }
+
+    #[test]
+    fn test_reference_coins() {
+        let detector = make_detector();
+        assert_eq!(detector.reference_coins(), vec!["BTC".to_string()]);
+        assert!(detector.is_reference("btc"));
+        assert!(!detector.is_reference("xyz:MSTR"));
+    }
+
+    #[test]
+    fn test_lagging_target_emits_buy() {
+        let mut detector = make_detector();
+        let key = test_key();
+        let snapshot = make_snapshot(dec!(99.99), dec!(100.01));
+
+        detector.record_reference("BTC", Price::new(dec!(100000)), 1_000);
+        assert!(detector.check(key, "xyz:MSTR", &snapshot, 1_000).is_none());
+
+        // BTC +30 bps, target unchanged: fair = 100.30, ask 100.01 → ~29 bps
+        detector.record_reference("BTC", Price::new(dec!(100300)), 2_000);
+        let signal = detector
+            .check(key, "xyz:MSTR", &snapshot, 2_000)
+            .expect("lagging target should signal");
+        assert_eq!(signal.side, OrderSide::Buy);
+        assert_eq!(signal.source, SignalSource::LeadLag);
+        assert_eq!(signal.best_px.inner(), dec!(100.01));
+        assert!(signal.raw_edge_bps > dec!(28));
+        assert!(signal.net_edge_bps > Decimal::ZERO);
+
+        // Same reference tick does not fire twice
+        assert!(detector.check(key, "xyz:MSTR", &snapshot, 2_100).is_none());
+    }
+
+    #[test]
+    fn test_repriced_target_no_signal() {
+        let mut detector = make_detector();
+        let key = test_key();
+
+        detector.record_reference("BTC", Price::new(dec!(100000)), 1_000);
+        detector.check(
+            key,
+            "xyz:MSTR",
+            &make_snapshot(dec!(99.99), dec!(100.01)),
+            1_000,
+        );
+
+        // BTC -30 bps, target already down ~25 bps (> 30% of expected)
+        detector.record_reference("BTC", Price::new(dec!(99700)), 2_000);
+        let repriced = make_snapshot(dec!(99.74), dec!(99.76));
+        assert!(detector.check(key, "xyz:MSTR", &repriced, 2_000).is_none());
+    }
+
+    #[test]
+    fn test_small_reference_move_no_signal() {
+        let mut detector = make_detector();
+        let key = test_key();
+        let snapshot = make_snapshot(dec!(99.99), dec!(100.01));
+
+        detector.record_reference("BTC", Price::new(dec!(100000)), 1_000);
+        detector.check(key, "xyz:MSTR", &snapshot, 1_000);
+
+        // BTC +10 bps: below the 15 bps minimum
+        detector.record_reference("BTC", Price::new(dec!(100100)), 2_000);
+        assert!(detector.check(key, "xyz:MSTR", &snapshot, 2_000).is_none());
+    }
+}
@@ -5,17 +5,35 @@
 //!
 //! Implements P0-24: HIP-3 2x fee calculation with audit trail.
 //! Implements P0-31: Cross duration tracking for Phase A DoD.
+//!
+//! [`LeadLagDetector`] is a secondary detector that trades the target market
+//! when a reference market has moved and the target BBO is still stale.
+//!
+//! Both implement [`DetectionStrategy`], which lets the bot select and
+//! compose entry logic per market.
 
 pub mod config;
 pub mod cross_tracker;
 pub mod detector;
 pub mod error;
 pub mod fee;
+pub mod lead_lag;
 pub mod signal;
+pub mod slippage;
+pub mod strategy;
 
-pub use config::DetectorConfig;
+pub use config::{ConfidenceWeights, DetectorConfig};
 pub use cross_tracker::CrossDurationTracker;
-pub use detector::DislocationDetector;
+pub use detector::{DislocationDetector, SideThresholds};
 pub use error::{DetectorError, DetectorResult};
-pub use fee::{FeeCalculator, FeeMetadata, UserFees, HIP3_FEE_MULTIPLIER};
-pub use signal::{DislocationSignal, ExitProfile, SignalStrength};
+pub use fee::{
+    FeeCalculator, FeeMetadata, FundingCost, UserFees, FUNDING_INTERVAL_MS, HIP3_FEE_MULTIPLIER,
+};
+pub use lead_lag::{LeadLagConfig, LeadLagDetector, LeadLagPair};
+pub use signal::{
+    DislocationSignal, ExitProfile, SignalFeatures, SignalSource, SignalStrength, StrengthBucket,
+};
+pub use slippage::{SlippageConfig, SlippageEstimator};
+pub use strategy::{
+    DetectionStrategy, StrategyConfig, StrategyContext, DISLOCATION_STRATEGY, LEAD_LAG_STRATEGY,
+};
@@ -36,6 +36,143 @@ impl SignalStrength {
             Some(Self::Strong)
         }
     }
+
+    /// Classify edge using configured buckets, returning the strength and
+    /// its size multiplier.
+    ///
+    /// The bucket with the highest `min_excess_bps` not above the excess
+    /// edge wins. Falls back to [`Self::from_edge`] (multiplier 1) when no
+    /// bucket matches.
+    pub fn from_buckets(
+        edge_bps: Decimal,
+        threshold_bps: Decimal,
+        buckets: &[StrengthBucket],
+    ) -> Option<(Self, Decimal)> {
+        if edge_bps < threshold_bps {
+            return None;
+        }
+
+        let excess = edge_bps - threshold_bps;
+        match buckets
+            .iter()
+            .filter(|b| b.min_excess_bps <= excess)
+            .max_by_key(|b| b.min_excess_bps)
+        {
+            Some(bucket) => Some((bucket.strength, bucket.size_multiplier)),
+            None => Some((Self::from_edge(edge_bps, threshold_bps)?, Decimal::ONE)),
+        }
+    }
+}
+
+/// Edge range → strength → size multiplier.
+///
+/// A bucket covers excess edge (edge above threshold) from `min_excess_bps`
+/// up to the next bucket's minimum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StrengthBucket {
+    /// Lower bound of excess edge over threshold (bps, inclusive).
+    pub min_excess_bps: Decimal,
+    /// Strength assigned to signals in this bucket.
+    pub strength: SignalStrength,
+    /// Multiplier applied to the order size by the executor.
+    pub size_multiplier: Decimal,
+}
+
+impl StrengthBucket {
+    /// Create a bucket.
+    pub fn new(
+        min_excess_bps: Decimal,
+        strength: SignalStrength,
+        size_multiplier: Decimal,
+    ) -> Self {
+        Self {
+            min_excess_bps,
+            strength,
+            size_multiplier,
+        }
+    }
+}
+
+/// Which detector produced a signal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignalSource {
+    /// Oracle/BBO dislocation on the market itself.
+    #[default]
+    Dislocation,
+    /// Reference market moved and the target BBO has not repriced yet.
+    LeadLag,
+    /// Strategy registered outside this crate.
+    Custom,
+}
+
+impl std::fmt::Display for SignalSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dislocation => write!(f, "dislocation"),
+            Self::LeadLag => write!(f, "lead_lag"),
+            Self::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+/// Confidence features captured at detection time (P3-1).
+///
+/// The normalized factors (0.0-1.0) are the inputs to `confidence_score`;
+/// the raw values are kept alongside so the full vector can be exported
+/// for offline model training.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignalFeatures {
+    /// Edge factor: 0 at threshold, 1 at 3x threshold.
+    pub edge: Decimal,
+    /// Oracle velocity factor: 0 at min change, 1 at 4x min change.
+    pub velocity: Decimal,
+    /// Consecutive oracle moves factor: moves / 3, capped at 1.
+    pub consecutive: Decimal,
+    /// Book depth factor (liquidity factor).
+    pub book_depth: Decimal,
+    /// Spread tightness factor: 1 when spread EWMA <= cost, 0 at 3x cost.
+    pub spread: Decimal,
+    /// Cross duration factor: ticks crossed / 3, capped at 1.
+    pub cross_duration: Decimal,
+    /// Book imbalance factor: 1 when the BBO queue favors the trade side.
+    pub book_imbalance: Decimal,
+    /// Raw edge minus threshold (bps).
+    pub edge_excess_bps: Decimal,
+    /// Oracle velocity (bps).
+    pub velocity_bps: Decimal,
+    /// Consecutive oracle moves in the trade direction.
+    pub consecutive_moves: u32,
+    /// Consecutive ticks this side has been crossed, including this one.
+    pub cross_ticks: u32,
+    /// Current BBO spread (bps).
+    pub spread_bps: Decimal,
+    /// Spread EWMA (bps).
+    pub spread_ewma_bps: Decimal,
+    /// Signed BBO imbalance: (bid_size - ask_size) / (bid_size + ask_size).
+    pub imbalance: Decimal,
+}
+
+impl SignalFeatures {
+    /// Named feature values, in a stable order, for export.
+    pub fn named(&self) -> [(&'static str, Decimal); 14] {
+        [
+            ("edge", self.edge),
+            ("velocity", self.velocity),
+            ("consecutive", self.consecutive),
+            ("book_depth", self.book_depth),
+            ("spread", self.spread),
+            ("cross_duration", self.cross_duration),
+            ("book_imbalance", self.book_imbalance),
+            ("edge_excess_bps", self.edge_excess_bps),
+            ("velocity_bps", self.velocity_bps),
+            ("consecutive_moves", Decimal::from(self.consecutive_moves)),
+            ("cross_ticks", Decimal::from(self.cross_ticks)),
+            ("spread_bps", self.spread_bps),
+            ("spread_ewma_bps", self.spread_ewma_bps),
+            ("imbalance", self.imbalance),
+        ]
+    }
 }
 
 /// A detected dislocation opportunity.
@@ -88,6 +225,42 @@ pub struct DislocationSignal {
     /// Determines exit_against_moves, trailing stop params, and time_stop.
     #[serde(default)]
     pub exit_profile: ExitProfile,
+    /// Detector that produced this signal.
+    #[serde(default)]
+    pub source: SignalSource,
+    /// Feature vector behind `confidence_score`.
+    #[serde(default)]
+    pub features: SignalFeatures,
+    /// Time-to-live from `detected_at` in ms (0 = never expires).
+    #[serde(default)]
+    pub ttl_ms: u64,
+    /// Entry threshold (bps) the raw edge was judged against, after all
+    /// side, session and regime adjustments.
+    #[serde(default)]
+    pub threshold_bps: Decimal,
+    /// Signed book size imbalance at detection,
+    /// (bid_size - ask_size) / (bid_size + ask_size).
+    #[serde(default)]
+    pub book_imbalance: Decimal,
+    /// How long the cross had persisted when the signal was emitted (ms).
+    #[serde(default)]
+    pub cross_duration_ms: u64,
+    /// Size multiplier from the signal's strength bucket, applied by the
+    /// executor before position caps.
+    #[serde(default = "default_size_multiplier")]
+    pub size_multiplier: Decimal,
+    /// Edge decay factor applied for the time since the last oracle move
+    /// (1 = no decay).
+    #[serde(default = "default_edge_decay_factor")]
+    pub edge_decay_factor: Decimal,
+}
+
+fn default_size_multiplier() -> Decimal {
+    Decimal::ONE
+}
+
+fn default_edge_decay_factor() -> Decimal {
+    Decimal::ONE
 }
 
 impl DislocationSignal {
@@ -128,9 +301,30 @@ impl DislocationSignal {
             baseline_gap_bps: Decimal::ZERO,
             edge_above_baseline_bps: Decimal::ZERO,
             exit_profile: ExitProfile::default(),
+            source: SignalSource::default(),
+            features: SignalFeatures::default(),
+            ttl_ms: 0,
+            threshold_bps: Decimal::ZERO,
+            book_imbalance: Decimal::ZERO,
+            cross_duration_ms: 0,
+            size_multiplier: Decimal::ONE,
+            edge_decay_factor: Decimal::ONE,
         }
     }
 
+    /// Expiry timestamp (Unix ms), or None if the signal has no TTL.
+    pub fn expires_at_ms(&self) -> Option<u64> {
+        if self.ttl_ms == 0 {
+            return None;
+        }
+        Some(self.detected_at.timestamp_millis() as u64 + self.ttl_ms)
+    }
+
+    /// Check whether the signal has outlived its TTL.
+    pub fn is_expired(&self, now_ms: u64) -> bool {
+        self.expires_at_ms().is_some_and(|exp| now_ms > exp)
+    }
+
     /// Get expected PnL in basis points (simplified).
     pub fn expected_pnl_bps(&self) -> Decimal {
         self.net_edge_bps
@@ -145,6 +339,8 @@ impl DislocationSignal {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fee::FeeCalculator;
+    use hip3_core::{AssetId, DexId};
     use rust_decimal_macros::dec;
 
     #[test]
@@ -165,4 +361,62 @@ mod tests {
             Some(SignalStrength::Strong)
         );
     }
+
+    #[test]
+    fn test_signal_strength_buckets() {
+        let threshold = dec!(10);
+        let buckets = [
+            StrengthBucket::new(dec!(20), SignalStrength::Strong, dec!(2)),
+            StrengthBucket::new(dec!(0), SignalStrength::Weak, dec!(0.5)),
+            StrengthBucket::new(dec!(8), SignalStrength::Medium, dec!(1)),
+        ];
+
+        assert!(SignalStrength::from_buckets(dec!(9), threshold, &buckets).is_none());
+        assert_eq!(
+            SignalStrength::from_buckets(dec!(12), threshold, &buckets),
+            Some((SignalStrength::Weak, dec!(0.5)))
+        );
+        assert_eq!(
+            SignalStrength::from_buckets(dec!(18), threshold, &buckets),
+            Some((SignalStrength::Medium, dec!(1)))
+        );
+        assert_eq!(
+            SignalStrength::from_buckets(dec!(45), threshold, &buckets),
+            Some((SignalStrength::Strong, dec!(2)))
+        );
+
+        // No matching bucket: fixed classification at 1x
+        assert_eq!(
+            SignalStrength::from_buckets(dec!(30), threshold, &[]),
+            Some((SignalStrength::Strong, Decimal::ONE))
+        );
+    }
+
+    #[test]
+    fn test_signal_ttl() {
+        let mut signal = DislocationSignal::new(
+            MarketKey::new(DexId::XYZ, AssetId::new(0)),
+            OrderSide::Buy,
+            dec!(30),
+            dec!(20),
+            SignalStrength::Medium,
+            Size::new(dec!(1)),
+            Price::new(dec!(100)),
+            Price::new(dec!(99.7)),
+            Size::new(dec!(5)),
+            FeeCalculator::with_defaults().metadata(),
+            dec!(0),
+            dec!(0.5),
+        );
+        let detected_ms = signal.detected_at.timestamp_millis() as u64;
+
+        // No TTL: never expires
+        assert_eq!(signal.expires_at_ms(), None);
+        assert!(!signal.is_expired(detected_ms + 1_000_000));
+
+        signal.ttl_ms = 500;
+        assert_eq!(signal.expires_at_ms(), Some(detected_ms + 500));
+        assert!(!signal.is_expired(detected_ms + 500));
+        assert!(signal.is_expired(detected_ms + 501));
+    }
 }
@@ -0,0 +1,201 @@
+//! Per-market slippage learned from our own fills.
+//!
+//! The static `slippage_bps` in [`DetectorConfig`](crate::DetectorConfig) is
+//! a guess shared by every market. [`SlippageEstimator`] replaces it with an
+//! EWMA of the slippage actually paid: for each entry fill, the fill price is
+//! compared with the best price the signal was generated from.
+//!
+//! - Buy: `(fill_px - best_px) / best_px * 10000`
+//! - Sell: `(best_px - fill_px) / best_px * 10000`
+//!
+//! Positive values are adverse. Price improvement is kept in the EWMA but the
+//! estimate used for costs is floored at zero and capped at `max_bps`.
+
+use hip3_core::{MarketKey, OrderSide, Price};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::debug;
+
+/// Configuration for learned slippage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlippageConfig {
+    /// Use learned slippage in place of `detector.slippage_bps`.
+    #[serde(default)]
+    pub enabled: bool,
+    /// EWMA smoothing factor per fill.
+    #[serde(default = "default_ewma_alpha")]
+    pub ewma_alpha: Decimal,
+    /// Fills required before the estimate replaces the static value.
+    #[serde(default = "default_min_samples")]
+    pub min_samples: u32,
+    /// Upper bound on the estimate (bps).
+    #[serde(default = "default_max_bps")]
+    pub max_bps: Decimal,
+}
+
+fn default_ewma_alpha() -> Decimal {
+    Decimal::new(2, 1) // 0.2
+}
+
+fn default_min_samples() -> u32 {
+    5
+}
+
+fn default_max_bps() -> Decimal {
+    Decimal::from(20)
+}
+
+impl Default for SlippageConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ewma_alpha: default_ewma_alpha(),
+            min_samples: default_min_samples(),
+            max_bps: default_max_bps(),
+        }
+    }
+}
+
+/// Per-market slippage EWMA.
+#[derive(Debug, Clone, Copy, Default)]
+struct SlippageState {
+    ewma_bps: Decimal,
+    samples: u32,
+}
+
+/// Learns per-market slippage from fills.
+#[derive(Debug)]
+pub struct SlippageEstimator {
+    config: SlippageConfig,
+    states: HashMap<MarketKey, SlippageState>,
+}
+
+impl SlippageEstimator {
+    /// Create a new estimator.
+    pub fn new(config: SlippageConfig) -> Self {
+        Self {
+            config,
+            states: HashMap::new(),
+        }
+    }
+
+    /// Record a fill against the signal's best price.
+    ///
+    /// Returns the observed slippage in bps, or `None` if `expected_px` is zero.
+    pub fn record(
+        &mut self,
+        key: MarketKey,
+        side: OrderSide,
+        expected_px: Price,
+        fill_px: Price,
+    ) -> Option<Decimal> {
+        if expected_px.is_zero() {
+            return None;
+        }
+        let diff = match side {
+            OrderSide::Buy => fill_px.inner() - expected_px.inner(),
+            OrderSide::Sell => expected_px.inner() - fill_px.inner(),
+        };
+        let slippage_bps = diff / expected_px.inner() * Decimal::from(10000);
+
+        let state = self.states.entry(key).or_default();
+        if state.samples == 0 {
+            state.ewma_bps = slippage_bps;
+        } else {
+            let alpha = self.config.ewma_alpha;
+            state.ewma_bps = alpha * slippage_bps + (Decimal::ONE - alpha) * state.ewma_bps;
+        }
+        state.samples = state.samples.saturating_add(1);
+
+        debug!(
+            market = %key,
+            %side,
+            slippage_bps = %slippage_bps,
+            ewma_bps = %state.ewma_bps,
+            samples = state.samples,
+            "Recorded fill slippage"
+        );
+        Some(slippage_bps)
+    }
+
+    /// Learned slippage for cost calculation, once `min_samples` fills exist.
+    ///
+    /// Clamped to `[0, max_bps]`.
+    pub fn estimate_bps(&self, key: &MarketKey) -> Option<Decimal> {
+        self.states
+            .get(key)
+            .filter(|s| s.samples >= self.config.min_samples)
+            .map(|s| s.ewma_bps.max(Decimal::ZERO).min(self.config.max_bps))
+    }
+
+    /// Number of fills recorded for a market.
+    pub fn samples(&self, key: &MarketKey) -> u32 {
+        self.states.get(key).map(|s| s.samples).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+
+    fn key() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn config() -> SlippageConfig {
+        SlippageConfig {
+            enabled: true,
+            ewma_alpha: dec!(0.5),
+            min_samples: 2,
+            max_bps: dec!(20),
+        }
+    }
+
+    #[test]
+    fn test_side_aware_slippage() {
+        let mut est = SlippageEstimator::new(config());
+        let best = Price::new(dec!(100));
+
+        // Buy filled 3 bps above best ask: adverse
+        let bps = est.record(key(), OrderSide::Buy, best, Price::new(dec!(100.03)));
+        assert_eq!(bps, Some(dec!(3)));
+
+        // Sell filled 1 bp above best bid: improvement
+        let bps = est.record(key(), OrderSide::Sell, best, Price::new(dec!(100.01)));
+        assert_eq!(bps, Some(dec!(-1)));
+    }
+
+    #[test]
+    fn test_estimate_requires_min_samples() {
+        let mut est = SlippageEstimator::new(config());
+        let best = Price::new(dec!(100));
+
+        est.record(key(), OrderSide::Buy, best, Price::new(dec!(100.04)));
+        assert_eq!(est.estimate_bps(&key()), None);
+
+        // EWMA(0.5): 4 → 0.5*2 + 0.5*4 = 3
+        est.record(key(), OrderSide::Buy, best, Price::new(dec!(100.02)));
+        assert_eq!(est.samples(&key()), 2);
+        assert_eq!(est.estimate_bps(&key()), Some(dec!(3)));
+    }
+
+    #[test]
+    fn test_estimate_clamped() {
+        let mut est = SlippageEstimator::new(config());
+        let best = Price::new(dec!(100));
+
+        // Consistent price improvement → floored at 0
+        est.record(key(), OrderSide::Buy, best, Price::new(dec!(99.98)));
+        est.record(key(), OrderSide::Buy, best, Price::new(dec!(99.98)));
+        assert_eq!(est.estimate_bps(&key()), Some(dec!(0)));
+
+        // Huge slippage → capped at max_bps
+        for _ in 0..10 {
+            est.record(key(), OrderSide::Buy, best, Price::new(dec!(101)));
+        }
+        assert_eq!(est.estimate_bps(&key()), Some(dec!(20)));
+    }
+}
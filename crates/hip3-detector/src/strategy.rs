@@ -0,0 +1,173 @@
+//! Pluggable detection strategies.
+//!
+//! A [`DetectionStrategy`] turns one market tick into an optional signal.
+//! The built-in detectors implement it:
+//!
+//! | Name          | Implementation                                   |
+//! |---------------|--------------------------------------------------|
+//! | `dislocation` | [`DislocationDetector`] (oracle/BBO cross)       |
+//! | `lead_lag`    | [`LeadLagDetector`] (reference market lead)      |
+//!
+//! Additional strategies can be registered with the bot under their own
+//! name. [`StrategyConfig`] selects which strategies run for each market and
+//! in what priority: every selected strategy sees every tick (so stateful
+//! strategies keep their history current), and the first one to return a
+//! signal wins.
+
+use crate::detector::{DislocationDetector, SideThresholds};
+use crate::lead_lag::LeadLagDetector;
+use crate::signal::DislocationSignal;
+use hip3_core::types::MarketSnapshot;
+use hip3_core::{MarketKey, MarketSpec};
+use hip3_feed::OracleMovementTracker;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Name of the built-in oracle/BBO dislocation strategy.
+pub const DISLOCATION_STRATEGY: &str = "dislocation";
+/// Name of the built-in lead-lag strategy.
+pub const LEAD_LAG_STRATEGY: &str = "lead_lag";
+
+/// Per-tick input to a detection strategy.
+#[derive(Clone, Copy)]
+pub struct StrategyContext<'a> {
+    /// Market being evaluated.
+    pub key: MarketKey,
+    /// Market coin symbol.
+    pub coin: &'a str,
+    /// Latest market snapshot (risk gates already passed).
+    pub snapshot: &'a MarketSnapshot,
+    /// Market spec (tick/lot size, fees).
+    pub spec: &'a MarketSpec,
+    /// Per-market long/short threshold overrides in bps.
+    pub thresholds: SideThresholds,
+    /// Oracle movement tracker.
+    pub oracle_tracker: Option<&'a OracleMovementTracker>,
+    /// Oracle age in ms.
+    pub oracle_age_ms: Option<i64>,
+    /// Current time (Unix ms).
+    pub now_ms: u64,
+}
+
+/// Entry logic that can be selected per market.
+pub trait DetectionStrategy {
+    /// Stable name used in config and logs.
+    fn name(&self) -> &str;
+
+    /// Evaluate one tick.
+    fn detect(&mut self, ctx: &StrategyContext<'_>) -> Option<DislocationSignal>;
+}
+
+impl DetectionStrategy for DislocationDetector {
+    fn name(&self) -> &str {
+        DISLOCATION_STRATEGY
+    }
+
+    fn detect(&mut self, ctx: &StrategyContext<'_>) -> Option<DislocationSignal> {
+        self.check_sided(
+            ctx.key,
+            ctx.snapshot,
+            ctx.thresholds,
+            ctx.oracle_tracker,
+            ctx.oracle_age_ms,
+        )
+    }
+}
+
+impl DetectionStrategy for LeadLagDetector {
+    fn name(&self) -> &str {
+        LEAD_LAG_STRATEGY
+    }
+
+    fn detect(&mut self, ctx: &StrategyContext<'_>) -> Option<DislocationSignal> {
+        self.check(ctx.key, ctx.coin, ctx.snapshot, ctx.now_ms)
+    }
+}
+
+/// Strategy selection per market.
+///
+/// ```toml
+/// [strategies]
+/// default = ["dislocation", "lead_lag"]
+///
+/// [strategies.per_market]
+/// NVDA = ["lead_lag"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyConfig {
+    /// Strategies for markets without an override, in priority order.
+    #[serde(default = "default_strategies")]
+    pub default: Vec<String>,
+    /// Per-market overrides keyed by coin (case-insensitive).
+    #[serde(default)]
+    pub per_market: HashMap<String, Vec<String>>,
+}
+
+fn default_strategies() -> Vec<String> {
+    vec![
+        DISLOCATION_STRATEGY.to_string(),
+        LEAD_LAG_STRATEGY.to_string(),
+    ]
+}
+
+impl Default for StrategyConfig {
+    fn default() -> Self {
+        Self {
+            default: default_strategies(),
+            per_market: HashMap::new(),
+        }
+    }
+}
+
+impl StrategyConfig {
+    /// Strategies selected for a market, in priority order.
+    pub fn for_coin(&self, coin: &str) -> &[String] {
+        self.per_market
+            .iter()
+            .find(|(c, _)| c.eq_ignore_ascii_case(coin))
+            .map(|(_, names)| names.as_slice())
+            .unwrap_or(&self.default)
+    }
+
+    /// All strategy names referenced by the config.
+    pub fn referenced_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .default
+            .iter()
+            .chain(self.per_market.values().flatten())
+            .map(String::as_str)
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strategy_selection_per_market() {
+        let config = StrategyConfig {
+            default: vec!["dislocation".to_string()],
+            per_market: HashMap::from([(
+                "nvda".to_string(),
+                vec!["lead_lag".to_string(), "my_model".to_string()],
+            )]),
+        };
+
+        assert_eq!(config.for_coin("BTC"), ["dislocation"]);
+        assert_eq!(config.for_coin("NVDA"), ["lead_lag", "my_model"]);
+        assert_eq!(
+            config.referenced_names(),
+            ["dislocation", "lead_lag", "my_model"]
+        );
+    }
+
+    #[test]
+    fn test_default_keeps_builtin_order() {
+        let config = StrategyConfig::default();
+        assert_eq!(config.for_coin("ANY"), ["dislocation", "lead_lag"]);
+    }
+}
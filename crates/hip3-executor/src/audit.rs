@@ -0,0 +1,178 @@
+//! Signed-action audit trail.
+//!
+//! Every action signed by `ExecutorLoop` or `RestCanceller` is recorded in
+//! a shared [`SignedActionLog`] together with what happened to it (sent,
+//! rate limited, REST result, ...). The application drains the log into the
+//! hash-chained audit files of `hip3-persistence`.
+//!
+//! The action is kept as JSON; [`action_hash_from_json`] re-derives the hash
+//! the signature commits to, so a recorded action can be checked against
+//! the exchange's view of it.
+
+use crate::signer::{compute_action_hash, SignerError, SigningInput};
+use crate::ws_sender::{ActionSignature, SignedAction};
+use alloy::primitives::{Address, B256};
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tracing::warn;
+
+/// One signed action and its outcome.
+#[derive(Debug, Clone)]
+pub struct SignedActionAudit {
+    /// Time the outcome was recorded (Unix ms).
+    pub timestamp_ms: i64,
+    /// Post ID of the action.
+    pub post_id: u64,
+    /// Nonce the action was signed with.
+    pub nonce: u64,
+    /// Action type (order, cancel, batchModify, scheduleCancel).
+    pub action_type: String,
+    /// The action exactly as signed.
+    pub action: serde_json::Value,
+    /// Vault the action was signed for.
+    pub vault_address: Option<Address>,
+    /// Hash the signature commits to.
+    pub action_hash: B256,
+    /// The signature.
+    pub signature: ActionSignature,
+    /// Client order IDs of the orders in the action.
+    pub cloids: Vec<String>,
+    /// What happened to the action.
+    pub result: String,
+}
+
+/// Shared buffer of signed actions, drained by the application.
+#[derive(Debug, Clone, Default)]
+pub struct SignedActionLog {
+    entries: Arc<Mutex<Vec<SignedActionAudit>>>,
+}
+
+impl SignedActionLog {
+    /// Create an empty log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a signed action and its outcome.
+    pub fn record(&self, signed: &SignedAction, cloids: Vec<String>, result: impl Into<String>) {
+        let input = SigningInput {
+            action: signed.action.clone(),
+            nonce: signed.nonce,
+            vault_address: signed.vault_address,
+            expires_after: None,
+        };
+        let (action, action_hash) =
+            match (serde_json::to_value(&signed.action), input.action_hash()) {
+                (Ok(action), Ok(hash)) => (action, hash),
+                (action, hash) => {
+                    // Cannot happen for an action that was just signed
+                    warn!(
+                        post_id = signed.post_id,
+                        action_error = ?action.as_ref().err(),
+                        hash_error = ?hash.as_ref().err(),
+                        "Signed action could not be encoded for the audit log"
+                    );
+                    (action.unwrap_or_default(), hash.unwrap_or_default())
+                }
+            };
+
+        self.entries.lock().push(SignedActionAudit {
+            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+            post_id: signed.post_id,
+            nonce: signed.nonce,
+            action_type: signed.action.action_type.clone(),
+            action,
+            vault_address: signed.vault_address,
+            action_hash,
+            signature: signed.signature.clone(),
+            cloids,
+            result: result.into(),
+        });
+    }
+
+    /// Drain the entries recorded since the last call.
+    pub fn take(&self) -> Vec<SignedActionAudit> {
+        std::mem::take(&mut *self.entries.lock())
+    }
+}
+
+/// Re-derive the action hash of an action recorded as JSON.
+///
+/// # Errors
+/// Returns `SignerError::SerializationFailed` if the action cannot be encoded.
+pub fn action_hash_from_json(
+    action: &serde_json::Value,
+    nonce: u64,
+    vault_address: Option<Address>,
+) -> Result<B256, SignerError> {
+    compute_action_hash(action, nonce, vault_address, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::{Action, BuilderInfo, CancelWire, OrderTypeWire, OrderWire};
+
+    fn signed(action: Action, vault_address: Option<Address>) -> SignedAction {
+        SignedAction {
+            action,
+            nonce: 1_700_000_000_123,
+            signature: ActionSignature {
+                r: "0x01".to_string(),
+                s: "0x02".to_string(),
+                v: 27,
+            },
+            post_id: 7,
+            vault_address,
+        }
+    }
+
+    #[test]
+    fn test_json_hash_matches_signed_hash() {
+        let order = Action {
+            action_type: "order".to_string(),
+            orders: Some(vec![OrderWire {
+                asset: 110026,
+                is_buy: true,
+                limit_px: "31.25".to_string(),
+                sz: "0.5".to_string(),
+                reduce_only: false,
+                order_type: OrderTypeWire::ioc(),
+                cloid: Some("0x0123456789abcdef0123456789abcdef".to_string()),
+            }]),
+            cancels: None,
+            modifies: None,
+            grouping: Some("na".to_string()),
+            builder: Some(BuilderInfo::new(Address::repeat_byte(0x11), 10)),
+            time: None,
+        };
+        let cancel = Action {
+            action_type: "cancel".to_string(),
+            orders: None,
+            cancels: Some(vec![CancelWire {
+                asset: 110026,
+                oid: 123_456_789_012,
+            }]),
+            modifies: None,
+            grouping: None,
+            builder: None,
+            time: None,
+        };
+
+        let log = SignedActionLog::new();
+        log.record(&signed(order, None), vec!["0xabc".to_string()], "sent");
+        let vault = Some(Address::repeat_byte(0xab));
+        log.record(&signed(cancel, vault), Vec::new(), "rate_limited");
+
+        let entries = log.take();
+        assert_eq!(entries.len(), 2);
+        assert!(log.take().is_empty());
+        for entry in entries {
+            assert_ne!(entry.action_hash, B256::ZERO);
+            let derived =
+                action_hash_from_json(&entry.action, entry.nonce, entry.vault_address).unwrap();
+            assert_eq!(derived, entry.action_hash, "{}", entry.action_type);
+        }
+    }
+}
@@ -3,7 +3,10 @@
 //! This module implements the batch scheduler that manages order queues
 //! and rate-limits submissions to the exchange SDK. Key features:
 //!
-//! - Three-tier priority queuing (cancels > reduce_only > new_orders)
+//! - Three-tier priority queuing (cancels > reduce_only > new_orders),
+//!   with order amendments sent once no reduce_only exit is waiting
+//! - New orders tiered by latency priority (strong signals > weak signals >
+//!   MM quotes) with per-tier budgets and starvation protection
 //! - Inflight order tracking with atomic operations
 //! - High watermark degraded mode
 //! - HardStop integration for emergency position closing
@@ -11,9 +14,12 @@
 //! # SDK Constraints
 //!
 //! The exchange SDK requires:
-//! - One action type per tick (orders OR cancels, not both)
+//! - One action type per tick (orders, cancels OR modifies)
 //! - Maximum batch sizes per request
 //! - Rate limiting via tick intervals
+//!
+//! Each batch goes out as a single signed action (per vault), so a tick
+//! costs one post against the WS rate limit however many orders it carries.
 
 use std::collections::VecDeque;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -24,10 +30,12 @@ use parking_lot::Mutex;
 use tracing::{debug, warn};
 
 use hip3_core::{
-    ActionBatch, ClientOrderId, EnqueueResult, MarketKey, PendingCancel, PendingOrder,
+    ActionBatch, ClientOrderId, EnqueueResult, MarketKey, PendingCancel, PendingModify,
+    PendingOrder,
 };
 
 // Import HardStopLatch from risk module (extended implementation)
+use crate::executor::ActionBudget;
 use crate::risk::HardStopLatch;
 
 // ============================================================================
@@ -139,14 +147,48 @@ pub struct BatchConfig {
     pub max_orders_per_batch: usize,
     /// Maximum cancels per batch submission.
     pub max_cancels_per_batch: usize,
+    /// Maximum amendments per batch submission.
+    pub max_modifies_per_batch: usize,
     /// High watermark for inflight orders (triggers degraded mode).
     pub inflight_high_watermark: u32,
     /// Capacity of the cancel queue.
     pub cancel_queue_capacity: usize,
+    /// Capacity of the amendment queue.
+    pub modify_queue_capacity: usize,
     /// Capacity of the reduce_only order queue.
     pub reduce_only_queue_capacity: usize,
     /// Capacity of the new order queue.
     pub new_order_queue_capacity: usize,
+    /// Scale the tick interval with queue pressure
+    /// (see [`BatchScheduler::effective_interval`]).
+    pub adaptive_interval: bool,
+    /// Adaptive floor (ms), reached once `backlog_threshold` items are queued.
+    pub min_interval_ms: u64,
+    /// Adaptive tick interval while every queue is empty (ms).
+    pub idle_interval_ms: u64,
+    /// Queued items at which the adaptive interval bottoms out at the floor.
+    pub backlog_threshold: usize,
+    /// Maximum strong-signal orders per batch.
+    pub strong_tier_budget: usize,
+    /// Maximum weak-signal orders (slices, retries) per batch.
+    pub weak_tier_budget: usize,
+    /// Maximum MM quote orders per batch.
+    pub quote_tier_budget: usize,
+    /// Order batches a waiting tier may be passed over before it is served
+    /// ahead of the other new-order tiers (0 = strict priority).
+    pub starvation_ticks: u32,
+}
+
+impl BatchConfig {
+    /// Per-batch order budget of a tier.
+    #[must_use]
+    pub fn tier_budget(&self, tier: OrderTier) -> usize {
+        match tier {
+            OrderTier::Strong => self.strong_tier_budget,
+            OrderTier::Weak => self.weak_tier_budget,
+            OrderTier::Quote => self.quote_tier_budget,
+        }
+    }
 }
 
 impl Default for BatchConfig {
@@ -155,14 +197,76 @@ impl Default for BatchConfig {
             interval_ms: 100,
             max_orders_per_batch: 50,
             max_cancels_per_batch: 50,
+            max_modifies_per_batch: 50,
             inflight_high_watermark: 80,
             cancel_queue_capacity: 200,
+            modify_queue_capacity: 200,
             reduce_only_queue_capacity: 500,
             new_order_queue_capacity: 1000,
+            adaptive_interval: false,
+            min_interval_ms: 5,
+            idle_interval_ms: 250,
+            backlog_threshold: 10,
+            strong_tier_budget: 50,
+            weak_tier_budget: 50,
+            quote_tier_budget: 25,
+            starvation_ticks: 10,
+        }
+    }
+}
+
+// ============================================================================
+// OrderTier
+// ============================================================================
+
+/// Latency priority of a new (non-reduce-only) order.
+///
+/// All tiers rank below reduce-only exits, which always fill a batch first,
+/// so no amount of quoting can delay a flatten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderTier {
+    /// Signal entries at or above the executor's strong-edge threshold.
+    Strong,
+    /// Other signal entries, slices and retries.
+    Weak,
+    /// Market-making quotes.
+    Quote,
+}
+
+impl OrderTier {
+    /// All tiers, highest priority first.
+    pub const ALL: [OrderTier; 3] = [OrderTier::Strong, OrderTier::Weak, OrderTier::Quote];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Metric label.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderTier::Strong => "strong",
+            OrderTier::Weak => "weak",
+            OrderTier::Quote => "quote",
         }
     }
 }
 
+/// New-order queues, one per tier, with starvation counters.
+#[derive(Debug, Default)]
+struct TieredQueue {
+    queues: [VecDeque<PendingOrder>; 3],
+    /// Consecutive order batches in which each tier had orders waiting but
+    /// got no slot.
+    starved: [u32; 3],
+}
+
+impl TieredQueue {
+    fn len(&self) -> usize {
+        self.queues.iter().map(VecDeque::len).sum()
+    }
+}
+
 // ============================================================================
 // BatchScheduler
 // ============================================================================
@@ -172,15 +276,16 @@ impl Default for BatchConfig {
 /// The scheduler implements a three-tier priority system:
 /// 1. **Cancels** - Always processed first (highest priority)
 /// 2. **Reduce-only orders** - Processed before new orders
-/// 3. **New orders** - Lowest priority
+/// 3. **New orders** - Lowest priority, tiered by [`OrderTier`]
 ///
 /// # Priority Rules
 ///
 /// - SDK allows only one action type per tick
 /// - If cancels are pending, only cancels are sent
-/// - When cancel queue is empty, orders are sent
+/// - When cancel queue is empty, pending amendments are sent
+/// - When both are empty, orders are sent
 /// - In high watermark mode, only reduce_only orders are sent
-/// - In HardStop mode, new_orders are skipped entirely
+/// - In HardStop mode, new_orders and amendments are skipped entirely
 ///
 /// # Thread Safety
 ///
@@ -192,10 +297,12 @@ pub struct BatchScheduler {
     interval: Duration,
     /// Queue of pending cancel requests (highest priority).
     pending_cancels: Mutex<VecDeque<PendingCancel>>,
+    /// Queue of pending order amendments (sent before orders).
+    pending_modifies: Mutex<VecDeque<PendingModify>>,
     /// Queue of pending reduce-only orders (medium priority).
     pending_reduce_only: Mutex<VecDeque<PendingOrder>>,
-    /// Queue of pending new orders (lowest priority).
-    pending_new_orders: Mutex<VecDeque<PendingOrder>>,
+    /// Queues of pending new orders per tier (lowest priority).
+    pending_new_orders: Mutex<TieredQueue>,
     /// Shared inflight order tracker.
     inflight_tracker: Arc<InflightTracker>,
     /// Scheduler configuration.
@@ -206,6 +313,9 @@ pub struct BatchScheduler {
     /// Signalled when a new item is enqueued so the executor loop
     /// can wake up immediately instead of waiting for the next tick.
     notify: Arc<tokio::sync::Notify>,
+    /// Budget charged per market as new orders are drained (None = the
+    /// executor charges it at enqueue time).
+    action_budget: Option<Arc<ActionBudget>>,
 }
 
 impl BatchScheduler {
@@ -224,32 +334,79 @@ impl BatchScheduler {
         Self {
             interval: Duration::from_millis(config.interval_ms),
             pending_cancels: Mutex::new(VecDeque::with_capacity(config.cancel_queue_capacity)),
+            pending_modifies: Mutex::new(VecDeque::with_capacity(config.modify_queue_capacity)),
             pending_reduce_only: Mutex::new(VecDeque::with_capacity(
                 config.reduce_only_queue_capacity,
             )),
-            pending_new_orders: Mutex::new(VecDeque::with_capacity(
-                config.new_order_queue_capacity,
-            )),
+            pending_new_orders: Mutex::new(TieredQueue::default()),
             inflight_tracker,
             config,
             hard_stop_latch,
             notify: Arc::new(tokio::sync::Notify::new()),
+            action_budget: None,
         }
     }
 
+    /// Charge `budget` per market when draining new orders.
+    ///
+    /// Orders from a market whose quota and the shared pool are both spent
+    /// stay queued (in order) until the next budget interval. Covers every
+    /// new-order source, including MM quotes and retries.
+    #[must_use]
+    pub fn with_action_budget(mut self, budget: Arc<ActionBudget>) -> Self {
+        self.action_budget = Some(budget);
+        self
+    }
+
+    /// Whether new orders are charged against the action budget on drain.
+    #[must_use]
+    pub fn meters_action_budget(&self) -> bool {
+        self.action_budget.is_some()
+    }
+
     /// Get the tick interval.
     #[must_use]
     pub fn interval(&self) -> Duration {
         self.interval
     }
 
+    /// Tick interval for the current queue pressure.
+    ///
+    /// Fixed at `interval_ms` unless `adaptive_interval` is set. Adaptive
+    /// mode ticks at `idle_interval_ms` with empty queues and shrinks
+    /// linearly from `interval_ms` to `min_interval_ms` as the backlog grows
+    /// to `backlog_threshold`. Newly queued work still wakes the loop early
+    /// through [`notify`](Self::notify).
+    #[must_use]
+    pub fn effective_interval(&self) -> Duration {
+        if !self.config.adaptive_interval {
+            return self.interval;
+        }
+        let base = self.config.interval_ms;
+        let depth = self.queue_depth();
+        if depth == 0 {
+            return Duration::from_millis(self.config.idle_interval_ms.max(base));
+        }
+        let floor = self.config.min_interval_ms.min(base);
+        let threshold = self.config.backlog_threshold.max(1);
+        let pressure = depth.min(threshold) as u64;
+        Duration::from_millis(base - (base - floor) * pressure / threshold as u64)
+    }
+
     /// Get the Notify handle for event-driven wakeup (P2-7).
     #[must_use]
     pub fn notify(&self) -> &Arc<tokio::sync::Notify> {
         &self.notify
     }
 
-    /// Enqueue a new order.
+    /// Enqueue a new order in the weak tier.
+    ///
+    /// See [`enqueue_new_order_at`](Self::enqueue_new_order_at).
+    pub fn enqueue_new_order(&self, order: PendingOrder) -> EnqueueResult {
+        self.enqueue_new_order_at(order, OrderTier::Weak)
+    }
+
+    /// Enqueue a new order in a priority tier.
     ///
     /// New orders have the lowest priority and are subject to:
     /// - Inflight limit check (rejected if at limit)
@@ -261,7 +418,7 @@ impl BatchScheduler {
     /// - `QueuedDegraded` - Queued but system is in degraded mode
     /// - `QueueFull` - Queue capacity exceeded
     /// - `InflightFull` - Too many in-flight orders
-    pub fn enqueue_new_order(&self, order: PendingOrder) -> EnqueueResult {
+    pub fn enqueue_new_order_at(&self, order: PendingOrder, tier: OrderTier) -> EnqueueResult {
         let inflight = self.inflight_tracker.current();
         let limit = self.inflight_tracker.limit();
 
@@ -276,22 +433,23 @@ impl BatchScheduler {
             return EnqueueResult::InflightFull;
         }
 
-        let mut queue = self.pending_new_orders.lock();
+        let mut tiers = self.pending_new_orders.lock();
 
-        // Check queue capacity
-        if queue.len() >= self.config.new_order_queue_capacity {
+        // Check queue capacity (shared by all tiers)
+        let queue_len = tiers.len();
+        if queue_len >= self.config.new_order_queue_capacity {
             debug!(
                 cloid = %order.cloid,
-                queue_len = queue.len(),
+                queue_len,
                 capacity = self.config.new_order_queue_capacity,
                 "New order rejected: queue full"
             );
             return EnqueueResult::QueueFull;
         }
 
-        queue.push_back(order);
+        tiers.queues[tier.index()].push_back(order);
         // Drop lock before notify
-        drop(queue);
+        drop(tiers);
 
         // P2-7: Wake executor loop immediately
         self.notify.notify_one();
@@ -410,15 +568,63 @@ impl BatchScheduler {
         EnqueueResult::Queued
     }
 
+    /// Enqueue an order amendment.
+    ///
+    /// Amendments are subject to the same inflight limit as new orders, since
+    /// each one places a replacement order on the book.
+    ///
+    /// # Returns
+    /// - `Queued` - Successfully queued for submission
+    /// - `QueuedDegraded` - Queued but system is in degraded mode
+    /// - `QueueFull` - Queue capacity exceeded
+    /// - `InflightFull` - Too many in-flight orders
+    pub fn enqueue_modify(&self, modify: PendingModify) -> EnqueueResult {
+        let inflight = self.inflight_tracker.current();
+        if inflight >= self.inflight_tracker.limit() {
+            debug!(
+                oid = modify.oid,
+                inflight, "Modify rejected: inflight at limit"
+            );
+            return EnqueueResult::InflightFull;
+        }
+
+        let mut queue = self.pending_modifies.lock();
+        if queue.len() >= self.config.modify_queue_capacity {
+            debug!(
+                oid = modify.oid,
+                queue_len = queue.len(),
+                capacity = self.config.modify_queue_capacity,
+                "Modify rejected: queue full"
+            );
+            return EnqueueResult::QueueFull;
+        }
+
+        queue.push_back(modify);
+        drop(queue);
+
+        // P2-7: Wake executor loop immediately
+        self.notify.notify_one();
+
+        if inflight >= self.config.inflight_high_watermark {
+            EnqueueResult::QueuedDegraded
+        } else {
+            EnqueueResult::Queued
+        }
+    }
+
     /// Process one tick and return the next action batch.
     ///
     /// This method is called periodically by the execution loop. It:
     /// 1. Returns `None` if at inflight limit
     /// 2. Returns `Cancels` batch if cancels are pending
-    /// 3. Returns `Orders` batch otherwise (reduce_only + new_orders)
+    /// 3. Returns `Modifies` batch if amendments are pending
+    /// 4. Returns `Orders` batch otherwise (reduce_only + new_orders)
     ///
     /// # Priority Rules
     /// - Cancels always take priority over orders
+    /// - Reduce-only orders fill the batch before any new order
+    /// - New orders follow tier order, each tier capped by its budget; a
+    ///   tier passed over for `starvation_ticks` batches is served first
     /// - In high watermark mode, only reduce_only orders are processed
     /// - In HardStop mode, new_orders are skipped entirely
     ///
@@ -447,12 +653,25 @@ impl BatchScheduler {
             }
         }
 
-        // Priority 2: Orders (reduce_only + new_orders)
-        let mut orders = Vec::new();
-        let max_orders = self.config.max_orders_per_batch;
         let is_high_watermark = inflight >= self.config.inflight_high_watermark;
         let is_hard_stop = self.hard_stop_latch.is_triggered();
 
+        // Priority 2: Amendments (behind exits, held back like new orders)
+        let has_exits = !self.pending_reduce_only.lock().is_empty();
+        if !has_exits && !is_high_watermark && !is_hard_stop {
+            let mut modifies = self.pending_modifies.lock();
+            if !modifies.is_empty() {
+                let batch_size = modifies.len().min(self.config.max_modifies_per_batch);
+                let batch: Vec<_> = modifies.drain(..batch_size).collect();
+                debug!(batch_size = batch.len(), "tick: returning modify batch");
+                return Some(ActionBatch::Modifies(batch));
+            }
+        }
+
+        // Priority 3: Orders (reduce_only + new_orders)
+        let mut orders = Vec::new();
+        let max_orders = self.config.max_orders_per_batch;
+
         // First, drain reduce_only orders
         {
             let mut reduce_only = self.pending_reduce_only.lock();
@@ -463,12 +682,55 @@ impl BatchScheduler {
             }
         }
 
-        // Then, drain new_orders (unless in high watermark or hard stop mode)
+        // Then, drain new_orders by tier (unless in high watermark or hard stop mode)
         if !is_high_watermark && !is_hard_stop {
-            let mut new_orders = self.pending_new_orders.lock();
-            while orders.len() < max_orders && !new_orders.is_empty() {
-                if let Some(order) = new_orders.pop_front() {
+            let mut tiers = self.pending_new_orders.lock();
+            let TieredQueue { queues, starved } = &mut *tiers;
+
+            // Starved tiers go first (still behind exits), then strict priority
+            let starvation_ticks = self.config.starvation_ticks;
+            let is_starved =
+                |tier: OrderTier| starvation_ticks > 0 && starved[tier.index()] >= starvation_ticks;
+            let service_order: Vec<OrderTier> = OrderTier::ALL
+                .into_iter()
+                .filter(|&tier| is_starved(tier))
+                .chain(OrderTier::ALL.into_iter().filter(|&tier| !is_starved(tier)))
+                .collect();
+
+            for tier in service_order {
+                let queue = &mut queues[tier.index()];
+                let tier_budget = self.config.tier_budget(tier);
+                let mut taken = 0;
+                let mut deferred = Vec::new();
+                while taken < tier_budget && orders.len() < max_orders {
+                    let Some(order) = queue.pop_front() else {
+                        break;
+                    };
+                    if let Some(ref budget) = self.action_budget {
+                        if !budget.consume_for(&order.market) {
+                            deferred.push(order);
+                            continue;
+                        }
+                    }
                     orders.push(order);
+                    taken += 1;
+                }
+                if !deferred.is_empty() {
+                    debug!(
+                        tier = tier.as_str(),
+                        deferred = deferred.len(),
+                        "tick: market budget spent, deferring new_orders"
+                    );
+                    for order in deferred.into_iter().rev() {
+                        queue.push_front(order);
+                    }
+                }
+
+                let counter = &mut starved[tier.index()];
+                if taken == 0 && !queue.is_empty() {
+                    *counter += 1;
+                } else {
+                    *counter = 0;
                 }
             }
         } else if is_hard_stop {
@@ -509,9 +771,12 @@ impl BatchScheduler {
     /// Vector of (ClientOrderId, MarketKey) pairs for dropped orders.
     #[must_use]
     pub fn drop_new_orders(&self) -> Vec<(ClientOrderId, MarketKey)> {
-        let mut queue = self.pending_new_orders.lock();
-        let dropped: Vec<_> = queue
-            .drain(..)
+        let mut tiers = self.pending_new_orders.lock();
+        tiers.starved = [0; 3];
+        let dropped: Vec<_> = tiers
+            .queues
+            .iter_mut()
+            .flat_map(|queue| queue.drain(..))
             .map(|order| (order.cloid, order.market))
             .collect();
 
@@ -525,6 +790,28 @@ impl BatchScheduler {
         dropped
     }
 
+    /// Drop all pending amendments (HardStop cleanup).
+    ///
+    /// Returns the dropped amendments so their replacement orders can be
+    /// released.
+    #[must_use]
+    pub fn drop_modifies(&self) -> Vec<PendingModify> {
+        let dropped: Vec<_> = self.pending_modifies.lock().drain(..).collect();
+        if !dropped.is_empty() {
+            warn!(
+                count = dropped.len(),
+                "HardStop: dropped all pending amendments"
+            );
+        }
+        dropped
+    }
+
+    /// Number of pending amendments.
+    #[must_use]
+    pub fn pending_modify_count(&self) -> usize {
+        self.pending_modifies.lock().len()
+    }
+
     /// Requeue failed reduce-only orders at the front of the queue.
     ///
     /// Called when reduce_only orders fail to execute and need to be
@@ -549,6 +836,41 @@ impl BatchScheduler {
         }
     }
 
+    /// Take queued reduce-only orders while the inflight limit blocks `tick`.
+    ///
+    /// Returns up to `max_orders_per_batch` orders for submission outside the
+    /// WS path, or nothing if batches can still be sent.
+    #[must_use]
+    pub fn take_blocked_reduce_only(&self) -> Vec<PendingOrder> {
+        if self.inflight_tracker.current() < self.inflight_tracker.limit() {
+            return Vec::new();
+        }
+        let mut queue = self.pending_reduce_only.lock();
+        let count = queue.len().min(self.config.max_orders_per_batch);
+        queue.drain(..count).collect()
+    }
+
+    /// Whether an order (or the amended order of an amendment) is queued.
+    #[must_use]
+    pub fn is_queued(&self, cloid: &ClientOrderId) -> bool {
+        self.pending_reduce_only
+            .lock()
+            .iter()
+            .any(|o| &o.cloid == cloid)
+            || self
+                .pending_new_orders
+                .lock()
+                .queues
+                .iter()
+                .flatten()
+                .any(|o| &o.cloid == cloid)
+            || self
+                .pending_modifies
+                .lock()
+                .iter()
+                .any(|m| &m.order.cloid == cloid)
+    }
+
     /// Get the current queue lengths for monitoring.
     #[must_use]
     pub fn queue_lengths(&self) -> (usize, usize, usize) {
@@ -557,6 +879,20 @@ impl BatchScheduler {
         let new_orders = self.pending_new_orders.lock().len();
         (cancels, reduce_only, new_orders)
     }
+
+    /// Pending new orders per tier, in [`OrderTier::ALL`] order.
+    #[must_use]
+    pub fn tier_lengths(&self) -> [usize; 3] {
+        let tiers = self.pending_new_orders.lock();
+        OrderTier::ALL.map(|tier| tiers.queues[tier.index()].len())
+    }
+
+    /// Total items waiting in all queues (cancels, amendments and orders).
+    #[must_use]
+    pub fn queue_depth(&self) -> usize {
+        let (cancels, reduce_only, new_orders) = self.queue_lengths();
+        cancels + self.pending_modify_count() + reduce_only + new_orders
+    }
 }
 
 // ============================================================================
@@ -715,6 +1051,114 @@ mod tests {
         assert!(matches!(batch, ActionBatch::Cancels(_)));
     }
 
+    #[test]
+    fn test_modify_priority() {
+        let config = BatchConfig::default();
+        let inflight = Arc::new(InflightTracker::new(100));
+        let hard_stop = Arc::new(HardStopLatch::new());
+        let scheduler = BatchScheduler::new(config, inflight, Arc::clone(&hard_stop));
+
+        let modify = PendingModify::new(123, ClientOrderId::new(), sample_pending_order(false));
+        scheduler.enqueue_new_order(sample_pending_order(false));
+        assert_eq!(scheduler.enqueue_modify(modify), EnqueueResult::Queued);
+        scheduler.enqueue_cancel(sample_pending_cancel());
+
+        // Cancels, then amendments, then new orders
+        assert!(matches!(scheduler.tick(), Some(ActionBatch::Cancels(_))));
+        assert!(matches!(scheduler.tick(), Some(ActionBatch::Modifies(m)) if m[0].oid == 123));
+        assert!(matches!(scheduler.tick(), Some(ActionBatch::Orders(_))));
+
+        // HardStop holds amendments back
+        let modify = PendingModify::new(124, ClientOrderId::new(), sample_pending_order(false));
+        scheduler.enqueue_modify(modify);
+        hard_stop.trigger("test: hard stop triggered");
+        assert!(scheduler.tick().is_none());
+        assert_eq!(scheduler.drop_modifies().len(), 1);
+        assert_eq!(scheduler.pending_modify_count(), 0);
+    }
+
+    #[test]
+    fn test_reduce_only_goes_before_modifies() {
+        let config = BatchConfig::default();
+        let inflight = Arc::new(InflightTracker::new(100));
+        let scheduler = BatchScheduler::new(config, inflight, Arc::new(HardStopLatch::new()));
+
+        // A flood of quote amendments, then one flatten
+        for oid in 0..20 {
+            let modify = PendingModify::new(oid, ClientOrderId::new(), sample_pending_order(false));
+            assert_eq!(scheduler.enqueue_modify(modify), EnqueueResult::Queued);
+        }
+        let exit = sample_pending_order(true);
+        let exit_cloid = exit.cloid.clone();
+        scheduler.enqueue_reduce_only(exit);
+
+        let Some(ActionBatch::Orders(orders)) = scheduler.tick() else {
+            panic!("reduce_only batch expected first");
+        };
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].cloid, exit_cloid);
+        assert!(matches!(scheduler.tick(), Some(ActionBatch::Modifies(_))));
+    }
+
+    #[test]
+    fn test_adaptive_interval_follows_queue_depth() {
+        let config = BatchConfig {
+            interval_ms: 50,
+            adaptive_interval: true,
+            min_interval_ms: 10,
+            idle_interval_ms: 200,
+            backlog_threshold: 4,
+            ..BatchConfig::default()
+        };
+        let scheduler = BatchScheduler::new(
+            config,
+            Arc::new(InflightTracker::new(100)),
+            Arc::new(HardStopLatch::new()),
+        );
+
+        // Idle: slow tick
+        assert_eq!(scheduler.effective_interval(), Duration::from_millis(200));
+
+        // Backlog shrinks the interval toward the floor
+        scheduler.enqueue_new_order(sample_pending_order(false));
+        assert_eq!(scheduler.effective_interval(), Duration::from_millis(40));
+        scheduler.enqueue_cancel(sample_pending_cancel());
+        assert_eq!(scheduler.effective_interval(), Duration::from_millis(30));
+        for asset in 1..=4 {
+            scheduler.enqueue_reduce_only(sample_pending_order_with_asset(true, asset));
+        }
+        assert_eq!(scheduler.queue_depth(), 6);
+        assert_eq!(scheduler.effective_interval(), Duration::from_millis(10));
+
+        // Fixed interval when adaptive scheduling is off
+        assert_eq!(
+            default_scheduler().effective_interval(),
+            Duration::from_millis(100)
+        );
+    }
+
+    #[test]
+    fn test_market_budget_defers_on_drain() {
+        let budget = Arc::new(ActionBudget::new(100, 60_000).with_market_quota(2, 1));
+        let scheduler = default_scheduler().with_action_budget(budget.clone());
+        assert!(scheduler.meters_action_budget());
+
+        for _ in 0..4 {
+            scheduler.enqueue_new_order(sample_pending_order_with_asset(false, 1));
+        }
+        scheduler.enqueue_new_order(sample_pending_order_with_asset(false, 2));
+
+        // Market 1: two from its quota, one from the pool, the fourth waits
+        let Some(ActionBatch::Orders(orders)) = scheduler.tick() else {
+            panic!("expected order batch");
+        };
+        let assets: Vec<u32> = orders.iter().map(|o| o.market.asset.0).collect();
+        assert_eq!(assets, vec![1, 1, 1, 2]);
+        assert_eq!(scheduler.queue_lengths().2, 1);
+        assert!(scheduler.tick().is_none());
+        assert_eq!(scheduler.queue_lengths().2, 1);
+    }
+
     // Test 7: Orders only - when cancel queue is empty, OrderBatch is returned
     #[test]
     fn test_orders_only() {
@@ -750,6 +1194,26 @@ mod tests {
         assert!(scheduler.tick().is_none());
     }
 
+    // Reduce-only orders blocked by the inflight limit can be taken out
+    #[test]
+    fn test_take_blocked_reduce_only() {
+        let inflight = Arc::new(InflightTracker::new(1));
+        let scheduler = BatchScheduler::new(
+            BatchConfig::default(),
+            Arc::clone(&inflight),
+            Arc::new(HardStopLatch::new()),
+        );
+        scheduler.enqueue_reduce_only(sample_pending_order(true));
+
+        // Not blocked: left for tick()
+        assert!(scheduler.take_blocked_reduce_only().is_empty());
+
+        inflight.increment();
+        assert!(scheduler.tick().is_none());
+        assert_eq!(scheduler.take_blocked_reduce_only().len(), 1);
+        assert_eq!(scheduler.queue_lengths().1, 0);
+    }
+
     // Test 9: requeue_reduce_only - failed orders go to front of queue
     // Note: Uses unique markets to avoid BUG-004 deduplication
     #[test]
@@ -1031,4 +1495,136 @@ mod tests {
         let (_, reduce_only_len, _) = scheduler.queue_lengths();
         assert_eq!(reduce_only_len, 1, "New order after drain should be queued");
     }
+
+    fn tier_of(batch: &[PendingOrder], cloids: &[ClientOrderId]) -> usize {
+        batch.iter().filter(|o| cloids.contains(&o.cloid)).count()
+    }
+
+    #[test]
+    fn test_quote_flood_never_delays_exit_or_signal() {
+        let scheduler = default_scheduler();
+
+        for _ in 0..100 {
+            scheduler.enqueue_new_order_at(sample_pending_order(false), OrderTier::Quote);
+        }
+        let exit = sample_pending_order(true);
+        let exit_cloid = exit.cloid.clone();
+        scheduler.enqueue_reduce_only(exit);
+        let signal = sample_pending_order(false);
+        let signal_cloid = signal.cloid.clone();
+        scheduler.enqueue_new_order_at(signal, OrderTier::Strong);
+
+        let Some(ActionBatch::Orders(batch)) = scheduler.tick() else {
+            panic!("expected order batch");
+        };
+        assert_eq!(batch[0].cloid, exit_cloid);
+        assert_eq!(batch[1].cloid, signal_cloid);
+        // Quotes capped by their budget (25)
+        assert_eq!(batch.len(), 27);
+        assert_eq!(scheduler.tier_lengths(), [0, 0, 75]);
+    }
+
+    #[test]
+    fn test_tier_priority_order() {
+        let scheduler = default_scheduler();
+
+        let weak = sample_pending_order(false);
+        let weak_cloid = weak.cloid.clone();
+        let quote = sample_pending_order(false);
+        let strong = sample_pending_order(false);
+        let strong_cloid = strong.cloid.clone();
+        scheduler.enqueue_new_order_at(quote, OrderTier::Quote);
+        scheduler.enqueue_new_order(weak);
+        scheduler.enqueue_new_order_at(strong, OrderTier::Strong);
+        assert_eq!(scheduler.queue_lengths().2, 3);
+
+        let Some(ActionBatch::Orders(batch)) = scheduler.tick() else {
+            panic!("expected order batch");
+        };
+        assert_eq!(batch[0].cloid, strong_cloid);
+        assert_eq!(batch[1].cloid, weak_cloid);
+        assert_eq!(batch.len(), 3);
+    }
+
+    #[test]
+    fn test_starved_tier_is_served_first() {
+        let config = BatchConfig {
+            max_orders_per_batch: 4,
+            starvation_ticks: 2,
+            ..BatchConfig::default()
+        };
+        let scheduler = BatchScheduler::new(
+            config,
+            Arc::new(InflightTracker::new(100)),
+            Arc::new(HardStopLatch::new()),
+        );
+
+        for _ in 0..20 {
+            scheduler.enqueue_new_order_at(sample_pending_order(false), OrderTier::Strong);
+        }
+        let quote = sample_pending_order(false);
+        let quote_cloids = vec![quote.cloid.clone()];
+        scheduler.enqueue_new_order_at(quote, OrderTier::Quote);
+
+        // Passed over for two batches
+        for _ in 0..2 {
+            let Some(ActionBatch::Orders(batch)) = scheduler.tick() else {
+                panic!("expected order batch");
+            };
+            assert_eq!(tier_of(&batch, &quote_cloids), 0);
+        }
+
+        // Third batch serves the starved quote ahead of the strong tier
+        let Some(ActionBatch::Orders(batch)) = scheduler.tick() else {
+            panic!("expected order batch");
+        };
+        assert_eq!(batch[0].cloid, quote_cloids[0]);
+        assert_eq!(batch.len(), 4);
+        assert_eq!(scheduler.tier_lengths(), [9, 0, 0]);
+    }
+
+    #[test]
+    fn test_starvation_does_not_preempt_exits() {
+        let config = BatchConfig {
+            max_orders_per_batch: 2,
+            starvation_ticks: 1,
+            ..BatchConfig::default()
+        };
+        let scheduler = BatchScheduler::new(
+            config,
+            Arc::new(InflightTracker::new(100)),
+            Arc::new(HardStopLatch::new()),
+        );
+
+        scheduler.enqueue_new_order_at(sample_pending_order(false), OrderTier::Quote);
+        for i in 0..4 {
+            scheduler.enqueue_reduce_only(sample_pending_order_with_asset(true, i));
+        }
+
+        for _ in 0..2 {
+            let Some(ActionBatch::Orders(batch)) = scheduler.tick() else {
+                panic!("expected order batch");
+            };
+            assert!(batch.iter().all(|o| o.reduce_only));
+        }
+        let Some(ActionBatch::Orders(batch)) = scheduler.tick() else {
+            panic!("expected order batch");
+        };
+        assert_eq!(batch.len(), 1);
+        assert!(!batch[0].reduce_only);
+    }
+
+    #[test]
+    fn test_is_queued() {
+        let scheduler = default_scheduler();
+        let order = sample_pending_order(false);
+        let cloid = order.cloid.clone();
+
+        scheduler.enqueue_new_order_at(order, OrderTier::Quote);
+        assert!(scheduler.is_queued(&cloid));
+        assert!(!scheduler.is_queued(&ClientOrderId::new()));
+
+        let _ = scheduler.tick();
+        assert!(!scheduler.is_queued(&cloid));
+    }
 }
@@ -19,6 +19,9 @@ pub enum ExecutorError {
 
     #[error("MarketSpec not found for market: {0}")]
     MarketSpecNotFound(MarketKey),
+
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
 }
 
 pub type ExecutorResult<T> = Result<T, ExecutorError>;
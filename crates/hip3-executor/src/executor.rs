@@ -9,40 +9,56 @@
 //! # Gate Check Order (Strict)
 //!
 //! 1.  HardStop               → Rejected(HardStop)
-//!     1b. MaxDrawdown (P2-3)     → Rejected(MaxDrawdown)
+//!     1a. EntryPause             → Rejected(EntriesPaused)
+//!     1b. MaxDrawdown (P2-3)     → Rejected(MaxDrawdown / DailyLossLimit / WeeklyLossLimit)
+//!     1b. EquityDrawdown         → Rejected(EquityDrawdown)
+//!     1b. Latency                → Rejected(LatencyDegraded)
 //!     1c. CorrelationCooldown    → Rejected(CorrelationCooldown)
+//!     1f. OpenOrders             → Rejected(OpenOrderLimit)
+//!     1f. RejectBreaker          → Rejected(RejectBreakerOpen)
 //! 2.  READY-TRADING          → Rejected(NotReady)
 //! 3.  MaxPositionPerMarket   → Rejected(MaxPositionPerMarket)
+//!     3b. RiskBudget             → Rejected(RiskBudgetExhausted)
 //! 4.  MaxPositionTotal       → Rejected(MaxPositionTotal)
+//!     4b. NetExposure            → Rejected(NetExposureLimit)
 //! 5.  MaxConcurrentPositions → Rejected(MaxConcurrentPositions)
 //! 6.  FlattenInProgress      → Skipped(FlattenInProgress)
 //! 7.  has_position           → Skipped(AlreadyHasPosition)
 //! 8.  PendingOrder           → Skipped(PendingOrderExists)
 //! 9.  ActionBudget           → Skipped(BudgetExhausted)
 //! 10. (all passed)           → try_mark_pending_market + enqueue
+//!
+//! Gates in [`OBSERVABLE_GATES`] can run observe-only
+//! (`ExecutorConfig::observe_only_gates`): their blocks are recorded, not
+//! enforced, to measure a threshold before turning it on.
 
 use std::cell::Cell;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
 use std::sync::Arc;
 
 use dashmap::DashMap;
+use parking_lot::Mutex;
 use rust_decimal::Decimal;
 use tracing::{debug, info, trace, warn};
 
 use hip3_core::{
-    ClientOrderId, EnqueueResult, ExecutionResult, MarketKey, OrderSide, PendingCancel,
-    PendingOrder, Price, RejectReason, Size, SkipReason, TrackedOrder,
+    ClientOrderId, EnqueueResult, ExecutionResult, MarketKey, OrderSide, OrderStrategy,
+    PendingCancel, PendingModify, PendingOrder, Price, RejectReason, SignalValidity, Size,
+    SkipReason, TimeInForce, TrackedOrder,
 };
 use hip3_mm::MakerAction;
 use hip3_position::PositionTrackerHandle;
 use hip3_risk::{
-    BurstSignalGate, CorrelationCooldownGate, CorrelationPositionGate, MaxDrawdownGate,
-    ReEntryDelayGate, TiltGuardGate,
+    BurstSignalGate, CorrelationCooldownGate, CorrelationPositionGate, EquityDrawdownGate,
+    LatencyGate, MaxDrawdownGate, NetExposureGate, OpenOrderGate, ReEntryDelayGate, RejectBreaker,
+    RiskBudgetGate, TiltGuardGate,
 };
 
-use crate::batch::BatchScheduler;
+use crate::batch::{BatchScheduler, OrderTier};
 use crate::ready::TradingReadyChecker;
-use crate::risk::HardStopLatch;
+use crate::risk::{EntryPause, HardStopLatch};
+use crate::slicer::{OrderSlicer, ParentOrderState};
 
 // ============================================================================
 // MmQuoteResult
@@ -102,8 +118,9 @@ impl Default for PostIdGenerator {
 /// Action budget for rate limiting new order submissions.
 ///
 /// Provides a simple interval-based budget that resets after each interval.
-/// The full implementation will be in hip3-risk crate with more sophisticated
-/// budget management (per-market, daily limits, etc.).
+/// Optionally split per market (see [`ActionBudget::with_market_quota`]):
+/// each market draws from its own quota first, then from a shared overflow
+/// pool, so one busy market cannot use up the whole interval.
 #[derive(Debug)]
 pub struct ActionBudget {
     /// Maximum orders per interval.
@@ -114,6 +131,35 @@ pub struct ActionBudget {
     interval_start_ms: AtomicU64,
     /// Interval duration in milliseconds.
     interval_ms: u64,
+    /// Per-market quotas (None = global budget only).
+    markets: Option<MarketBudget>,
+}
+
+/// Per-market quota plus shared overflow pool.
+#[derive(Debug)]
+struct MarketBudget {
+    /// Orders per market per interval before drawing on the pool.
+    quota: u32,
+    /// Overflow orders per interval shared by all markets.
+    shared_pool: u32,
+    state: Mutex<MarketBudgetState>,
+}
+
+#[derive(Debug, Default)]
+struct MarketBudgetState {
+    interval_start_ms: u64,
+    used: HashMap<MarketKey, u32>,
+    shared_used: u32,
+}
+
+impl MarketBudgetState {
+    fn roll(&mut self, now_ms: u64, interval_ms: u64) {
+        if now_ms.saturating_sub(self.interval_start_ms) > interval_ms {
+            self.interval_start_ms = now_ms;
+            self.used.clear();
+            self.shared_used = 0;
+        }
+    }
 }
 
 impl ActionBudget {
@@ -129,9 +175,28 @@ impl ActionBudget {
             current_count: AtomicU32::new(0),
             interval_start_ms: AtomicU64::new(0),
             interval_ms,
+            markets: None,
         }
     }
 
+    /// Split the budget into a per-market `quota` plus a `shared_pool`
+    /// overflow. The global `max_orders` cap still applies on top.
+    #[must_use]
+    pub fn with_market_quota(mut self, quota: u32, shared_pool: u32) -> Self {
+        self.markets = Some(MarketBudget {
+            quota,
+            shared_pool,
+            state: Mutex::new(MarketBudgetState::default()),
+        });
+        self
+    }
+
+    /// Whether per-market quotas are enabled.
+    #[must_use]
+    pub fn has_market_quota(&self) -> bool {
+        self.markets.is_some()
+    }
+
     /// Check if a new order can be sent within budget.
     ///
     /// Also resets the interval if it has expired.
@@ -230,6 +295,94 @@ impl ActionBudget {
         let current = self.current_count.load(Ordering::Acquire);
         self.max_orders.saturating_sub(current)
     }
+
+    /// Check if `market` can send a new order within its quota, the shared
+    /// pool and the global budget.
+    pub fn can_send_new_order_for(&self, market: &MarketKey) -> bool {
+        self.can_send_new_order_for_at(market, now_unix_ms())
+    }
+
+    /// Check if `market` can send a new order at the given timestamp.
+    pub fn can_send_new_order_for_at(&self, market: &MarketKey, now_ms: u64) -> bool {
+        if !self.can_send_new_order_at(now_ms) {
+            return false;
+        }
+        match self.markets {
+            Some(ref m) => {
+                let mut state = m.state.lock();
+                state.roll(now_ms, self.interval_ms);
+                state.used.get(market).copied().unwrap_or(0) < m.quota
+                    || state.shared_used < m.shared_pool
+            }
+            None => true,
+        }
+    }
+
+    /// Consume one order from `market`'s quota, falling back to the shared
+    /// pool. Without per-market quotas this is [`consume`](Self::consume).
+    pub fn consume_for(&self, market: &MarketKey) -> bool {
+        self.consume_for_at(market, now_unix_ms())
+    }
+
+    /// Consume one order for `market` at the given timestamp.
+    pub fn consume_for_at(&self, market: &MarketKey, now_ms: u64) -> bool {
+        let Some(ref m) = self.markets else {
+            return self.consume_at(now_ms);
+        };
+        let mut state = m.state.lock();
+        state.roll(now_ms, self.interval_ms);
+        let used = state.used.get(market).copied().unwrap_or(0);
+        let from_quota = used < m.quota;
+        if !from_quota && state.shared_used >= m.shared_pool {
+            return false;
+        }
+        if !self.consume_at(now_ms) {
+            return false;
+        }
+        if from_quota {
+            state.used.insert(*market, used + 1);
+        } else {
+            state.shared_used += 1;
+        }
+        true
+    }
+
+    /// Orders left in `market`'s own quota this interval.
+    ///
+    /// Without per-market quotas this is the global remaining budget.
+    #[must_use]
+    pub fn remaining_for_at(&self, market: &MarketKey, now_ms: u64) -> u32 {
+        match self.markets {
+            Some(ref m) => {
+                let mut state = m.state.lock();
+                state.roll(now_ms, self.interval_ms);
+                m.quota
+                    .saturating_sub(state.used.get(market).copied().unwrap_or(0))
+            }
+            None => self.remaining(),
+        }
+    }
+
+    /// Orders left in the shared overflow pool this interval (0 without
+    /// per-market quotas).
+    #[must_use]
+    pub fn shared_remaining_at(&self, now_ms: u64) -> u32 {
+        match self.markets {
+            Some(ref m) => {
+                let mut state = m.state.lock();
+                state.roll(now_ms, self.interval_ms);
+                m.shared_pool.saturating_sub(state.shared_used)
+            }
+            None => 0,
+        }
+    }
+}
+
+fn now_unix_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
 }
 
 impl Default for ActionBudget {
@@ -260,6 +413,26 @@ pub struct ExecutorConfig {
     /// Risk percentage per market for dynamic sizing (0.0 - 1.0).
     /// Used to calculate: dynamic_max = account_balance * risk_per_market_pct
     pub risk_per_market_pct: Decimal,
+    /// Minimum signal confidence (0.0-1.0) to accept a signal.
+    /// 0 disables the filter.
+    pub min_confidence: Decimal,
+    /// Entries whose net edge (bps) is at or below this rest as ALO at the
+    /// passive touch instead of crossing with IOC. 0 disables ALO entries.
+    pub alo_max_net_edge_bps: Decimal,
+    /// Resting ALO entries unfilled after this long are cancelled (ms).
+    pub alo_timeout_ms: u64,
+    /// Signals with net edge (bps) at or above this are queued in the strong
+    /// tier, the rest in the weak tier. 0 puts every signal in the strong tier.
+    pub strong_signal_min_net_edge_bps: Decimal,
+    /// Add-on entries allowed per market on top of the initial one
+    /// (position scaling). 0 disables scaling.
+    pub max_add_ons: usize,
+    /// An add-on needs an edge this much above the position's entry edge (bps).
+    pub add_on_min_edge_improvement_bps: Decimal,
+    /// Gates that only observe: their blocks are recorded
+    /// ([`Executor::take_observed_blocks`]) but nothing is rejected.
+    /// Names from [`OBSERVABLE_GATES`]; others are ignored.
+    pub observe_only_gates: Vec<String>,
 }
 
 impl Default for ExecutorConfig {
@@ -270,10 +443,50 @@ impl Default for ExecutorConfig {
             max_concurrent_positions: 5,
             dynamic_sizing_enabled: false,
             risk_per_market_pct: Decimal::new(10, 2), // 0.10 = 10%
+            min_confidence: Decimal::ZERO,            // Disabled
+            alo_max_net_edge_bps: Decimal::ZERO,      // IOC only
+            alo_timeout_ms: 2_000,                    // 2s
+            strong_signal_min_net_edge_bps: Decimal::ZERO, // Every signal is strong
+            max_add_ons: 0,                           // No scaling
+            add_on_min_edge_improvement_bps: Decimal::ZERO,
+            observe_only_gates: Vec::new(),
         }
     }
 }
 
+/// Gates that can run observe-only (`ExecutorConfig::observe_only_gates`).
+///
+/// HardStop, EntryPause and the position limits always enforce.
+pub const OBSERVABLE_GATES: [&str; 11] = [
+    "MaxDrawdown",
+    "EquityDrawdown",
+    "Latency",
+    "CorrelationCooldown",
+    "BurstSignal",
+    "TiltGuard",
+    "ReEntryDelay",
+    "OpenOrders",
+    "MinConfidence",
+    "RiskBudget",
+    "NetExposure",
+];
+
+/// Observed blocks kept until drained; later ones are dropped.
+const MAX_OBSERVED_BLOCKS: usize = 10_000;
+
+/// A block an observe-only gate would have made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObservedBlock {
+    /// Time of the block (Unix ms).
+    pub timestamp_ms: i64,
+    /// Gate name (one of [`OBSERVABLE_GATES`]).
+    pub gate: &'static str,
+    /// Market of the signal or MM quote (None = all markets).
+    pub market: Option<MarketKey>,
+    /// Reason the gate would have rejected with.
+    pub reason: RejectReason,
+}
+
 // ============================================================================
 // MarketStateCache
 // ============================================================================
@@ -287,12 +500,45 @@ pub struct MarketState {
     pub updated_at: u64,
 }
 
+/// Latest oracle price and top of book, used to re-validate signal edge.
+#[derive(Debug, Clone)]
+pub struct QuoteState {
+    /// Oracle price.
+    pub oracle_px: Price,
+    /// Best bid price.
+    pub best_bid: Price,
+    /// Best ask price.
+    pub best_ask: Price,
+    /// Last update timestamp (Unix milliseconds).
+    pub updated_at: u64,
+}
+
+impl QuoteState {
+    /// Edge in bps for the given side against the oracle.
+    ///
+    /// Buy: (oracle - ask) / oracle. Sell: (bid - oracle) / oracle.
+    /// Returns None if the oracle price is zero.
+    #[must_use]
+    pub fn edge_bps(&self, side: OrderSide) -> Option<Decimal> {
+        let oracle = self.oracle_px.inner();
+        if oracle.is_zero() {
+            return None;
+        }
+        let diff = match side {
+            OrderSide::Buy => oracle - self.best_ask.inner(),
+            OrderSide::Sell => self.best_bid.inner() - oracle,
+        };
+        Some(diff / oracle * Decimal::from(10000))
+    }
+}
+
 /// Thread-safe cache for market state.
 ///
 /// Used for quick mark price lookups during signal processing.
 #[derive(Debug, Default)]
 pub struct MarketStateCache {
     states: DashMap<MarketKey, MarketState>,
+    quotes: DashMap<MarketKey, QuoteState>,
 }
 
 impl MarketStateCache {
@@ -301,6 +547,7 @@ impl MarketStateCache {
     pub fn new() -> Self {
         Self {
             states: DashMap::new(),
+            quotes: DashMap::new(),
         }
     }
 
@@ -315,6 +562,32 @@ impl MarketStateCache {
         );
     }
 
+    /// Update the oracle price and top of book for a market.
+    pub fn update_quote(
+        &self,
+        market: &MarketKey,
+        oracle_px: Price,
+        best_bid: Price,
+        best_ask: Price,
+        now_ms: u64,
+    ) {
+        self.quotes.insert(
+            *market,
+            QuoteState {
+                oracle_px,
+                best_bid,
+                best_ask,
+                updated_at: now_ms,
+            },
+        );
+    }
+
+    /// Get the latest quote for a market.
+    #[must_use]
+    pub fn get_quote(&self, market: &MarketKey) -> Option<QuoteState> {
+        self.quotes.get(market).map(|q| q.clone())
+    }
+
     /// Get the mark price for a market.
     #[must_use]
     pub fn get_mark_px(&self, market: &MarketKey) -> Option<Price> {
@@ -330,11 +603,13 @@ impl MarketStateCache {
     /// Remove a market from the cache.
     pub fn remove(&self, market: &MarketKey) {
         self.states.remove(market);
+        self.quotes.remove(market);
     }
 
     /// Clear all cached market states.
     pub fn clear(&self) {
         self.states.clear();
+        self.quotes.clear();
     }
 
     /// Get the number of cached markets.
@@ -350,6 +625,20 @@ impl MarketStateCache {
     }
 }
 
+// ============================================================================
+// AloEntry
+// ============================================================================
+
+/// A taker-signal entry resting on the book as ALO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct AloEntry {
+    market: MarketKey,
+    /// When the order was queued (Unix milliseconds).
+    placed_at: u64,
+    /// Whether the timeout cancel has been enqueued.
+    cancel_sent: bool,
+}
+
 // ============================================================================
 // Executor
 // ============================================================================
@@ -361,12 +650,20 @@ impl MarketStateCache {
 /// # Gate Check Order (Strict)
 ///
 /// 1. HardStop               → Rejected(HardStop)
-///    1b. MaxDrawdown         → Rejected(MaxDrawdown)
+///    1a. EntryPause          → Rejected(EntriesPaused)
+///    1b. MaxDrawdown         → Rejected(MaxDrawdown / DailyLossLimit / WeeklyLossLimit)
+///    1b. EquityDrawdown      → Rejected(EquityDrawdown)
+///    1b. Latency             → Rejected(LatencyDegraded)
 ///    1c. CorrelationCooldown → Rejected(CorrelationCooldown)
 ///    1d. BurstSignal         → Rejected(BurstSignal)
+///    1f. OpenOrders          → Rejected(OpenOrderLimit)
+///    1f. RejectBreaker       → Rejected(RejectBreakerOpen)
+///    1h. SignalTtl           → Skipped(SignalExpired)
 /// 2. (READY-TRADING)        → Handled by bot via `connection_manager.is_ready()`
 /// 3. MaxPositionPerMarket   → Rejected(MaxPositionPerMarket)
+///    3b. RiskBudget          → Rejected(RiskBudgetExhausted)
 /// 4. MaxPositionTotal       → Rejected(MaxPositionTotal)
+///    4b. NetExposure         → Rejected(NetExposureLimit)
 /// 5. MaxConcurrentPositions → Rejected(MaxConcurrentPositions)
 /// 6. FlattenInProgress      → Skipped(FlattenInProgress)
 /// 7. has_position           → Skipped(AlreadyHasPosition)
@@ -396,18 +693,42 @@ pub struct Executor {
     config: ExecutorConfig,
     /// Market state cache for mark price lookups.
     market_state_cache: Arc<MarketStateCache>,
+    /// Operator pause of new entries (optional, None = no pause switch).
+    entry_pause: Option<Arc<EntryPause>>,
     /// P2-3: MaxDrawdownGate (optional, None = disabled).
     max_drawdown_gate: Option<Arc<MaxDrawdownGate>>,
+    /// EquityDrawdownGate: account equity drawdown (optional, None = disabled).
+    equity_drawdown_gate: Option<Arc<EquityDrawdownGate>>,
+    /// LatencyGate: p95 latency degradation (optional, None = disabled).
+    latency_gate: Option<Arc<LatencyGate>>,
     /// P2-4: CorrelationCooldownGate (optional, None = disabled).
     correlation_cooldown_gate: Option<Arc<CorrelationCooldownGate>>,
     /// P3-3: CorrelationPositionGate (optional, None = disabled).
     correlation_position_gate: Option<Arc<CorrelationPositionGate>>,
+    /// NetExposureGate: net exposure limits per netting group (optional, None = disabled).
+    net_exposure_gate: Option<Arc<NetExposureGate>>,
+    /// RiskBudgetGate: per-market notional and daily loss budgets (optional, None = disabled).
+    risk_budget_gate: Option<Arc<RiskBudgetGate>>,
     /// BurstSignalGate: per-market signal rate limiting (optional, None = disabled).
     burst_signal_gate: Option<Arc<BurstSignalGate>>,
     /// TiltGuardGate: consecutive loss cooldown (optional, None = disabled).
     tilt_guard_gate: Option<Arc<TiltGuardGate>>,
     /// ReEntryDelayGate: same-market re-entry delay (optional, None = disabled).
     re_entry_delay_gate: Option<Arc<ReEntryDelayGate>>,
+    /// OpenOrderGate: open order count and cancel failures (optional, None = disabled).
+    open_order_gate: Option<Arc<OpenOrderGate>>,
+    /// RejectBreaker: per-market disable after repeated rejections (optional, None = disabled).
+    reject_breaker: Option<Arc<RejectBreaker>>,
+    /// Signal entries resting as ALO, swept for fill/timeout.
+    alo_entries: DashMap<ClientOrderId, AloEntry>,
+    /// Splits large IOC entries into timed child slices (optional, None = disabled).
+    order_slicer: Option<Arc<OrderSlicer>>,
+    /// MM amendments that were not applied, awaiting cancel-replace fallback.
+    rejected_modifies: Mutex<Vec<PendingModify>>,
+    /// Gates running observe-only (from `config.observe_only_gates`).
+    observe_only_gates: Vec<&'static str>,
+    /// Blocks of observe-only gates, awaiting the caller.
+    observed_blocks: Mutex<Vec<ObservedBlock>>,
 }
 
 impl Executor {
@@ -422,6 +743,19 @@ impl Executor {
         config: ExecutorConfig,
         market_state_cache: Arc<MarketStateCache>,
     ) -> Self {
+        let mut observe_only_gates = Vec::new();
+        for name in &config.observe_only_gates {
+            match OBSERVABLE_GATES.iter().find(|gate| *gate == name) {
+                Some(gate) => {
+                    warn!(
+                        gate,
+                        "Gate runs observe-only: blocks are recorded, not enforced"
+                    );
+                    observe_only_gates.push(*gate);
+                }
+                None => warn!(gate = %name, "Gate cannot run observe-only, ignored"),
+            }
+        }
         Self {
             position_tracker,
             batch_scheduler,
@@ -430,15 +764,34 @@ impl Executor {
             action_budget,
             config,
             market_state_cache,
+            entry_pause: None,
             max_drawdown_gate: None,
+            equity_drawdown_gate: None,
+            latency_gate: None,
             correlation_cooldown_gate: None,
             correlation_position_gate: None,
+            net_exposure_gate: None,
+            risk_budget_gate: None,
             burst_signal_gate: None,
             tilt_guard_gate: None,
             re_entry_delay_gate: None,
+            open_order_gate: None,
+            reject_breaker: None,
+            alo_entries: DashMap::new(),
+            order_slicer: None,
+            rejected_modifies: Mutex::new(Vec::new()),
+            observe_only_gates,
+            observed_blocks: Mutex::new(Vec::new()),
         }
     }
 
+    /// Set the operator entry pause switch.
+    #[must_use]
+    pub fn with_entry_pause(mut self, pause: Arc<EntryPause>) -> Self {
+        self.entry_pause = Some(pause);
+        self
+    }
+
     /// Set the MaxDrawdownGate (P2-3).
     #[must_use]
     pub fn with_max_drawdown_gate(mut self, gate: Arc<MaxDrawdownGate>) -> Self {
@@ -446,6 +799,20 @@ impl Executor {
         self
     }
 
+    /// Set the EquityDrawdownGate (account equity drawdown).
+    #[must_use]
+    pub fn with_equity_drawdown_gate(mut self, gate: Arc<EquityDrawdownGate>) -> Self {
+        self.equity_drawdown_gate = Some(gate);
+        self
+    }
+
+    /// Set the LatencyGate (p95 latency degradation).
+    #[must_use]
+    pub fn with_latency_gate(mut self, gate: Arc<LatencyGate>) -> Self {
+        self.latency_gate = Some(gate);
+        self
+    }
+
     /// Set the CorrelationCooldownGate (P2-4).
     #[must_use]
     pub fn with_correlation_cooldown_gate(mut self, gate: Arc<CorrelationCooldownGate>) -> Self {
@@ -460,6 +827,20 @@ impl Executor {
         self
     }
 
+    /// Set the NetExposureGate (net exposure limits per netting group).
+    #[must_use]
+    pub fn with_net_exposure_gate(mut self, gate: Arc<NetExposureGate>) -> Self {
+        self.net_exposure_gate = Some(gate);
+        self
+    }
+
+    /// Set the RiskBudgetGate (per-market notional and daily loss budgets).
+    #[must_use]
+    pub fn with_risk_budget_gate(mut self, gate: Arc<RiskBudgetGate>) -> Self {
+        self.risk_budget_gate = Some(gate);
+        self
+    }
+
     /// Set the BurstSignalGate (per-market signal rate limiting).
     #[must_use]
     pub fn with_burst_signal_gate(mut self, gate: Arc<BurstSignalGate>) -> Self {
@@ -481,6 +862,27 @@ impl Executor {
         self
     }
 
+    /// Set the OpenOrderGate (open order count and cancel failures).
+    #[must_use]
+    pub fn with_open_order_gate(mut self, gate: Arc<OpenOrderGate>) -> Self {
+        self.open_order_gate = Some(gate);
+        self
+    }
+
+    /// Set the RejectBreaker (per-market disable after repeated rejections).
+    #[must_use]
+    pub fn with_reject_breaker(mut self, breaker: Arc<RejectBreaker>) -> Self {
+        self.reject_breaker = Some(breaker);
+        self
+    }
+
+    /// Set the OrderSlicer (large entry slicing).
+    #[must_use]
+    pub fn with_order_slicer(mut self, slicer: Arc<OrderSlicer>) -> Self {
+        self.order_slicer = Some(slicer);
+        self
+    }
+
     /// Get a reference to the MaxDrawdownGate.
     #[must_use]
     pub fn max_drawdown_gate(&self) -> Option<&Arc<MaxDrawdownGate>> {
@@ -493,6 +895,18 @@ impl Executor {
         self.correlation_cooldown_gate.as_ref()
     }
 
+    /// Get a reference to the OpenOrderGate.
+    #[must_use]
+    pub fn open_order_gate(&self) -> Option<&Arc<OpenOrderGate>> {
+        self.open_order_gate.as_ref()
+    }
+
+    /// Get a reference to the RejectBreaker.
+    #[must_use]
+    pub fn reject_breaker(&self) -> Option<&Arc<RejectBreaker>> {
+        self.reject_breaker.as_ref()
+    }
+
     /// Calculate the effective maximum notional per market.
     ///
     /// When dynamic sizing is enabled:
@@ -517,6 +931,29 @@ impl Executor {
         std::cmp::min(self.config.max_notional_per_market, dynamic_max)
     }
 
+    /// Whether a signal scales into the open position in `market`.
+    ///
+    /// Requires scaling to be enabled, a position on the same side with add-ons
+    /// left, and an edge above the position's entry edge by at least
+    /// `add_on_min_edge_improvement_bps`. Positions of unknown entry edge
+    /// (e.g., synced from the API) are never scaled. The per-market cap still
+    /// applies at Gate 3.
+    fn is_scaling_add_on(&self, market: &MarketKey, side: OrderSide, edge_bps: Decimal) -> bool {
+        if self.config.max_add_ons == 0 {
+            return false;
+        }
+        let Some(position) = self.position_tracker.get_position(market) else {
+            return false;
+        };
+        let Some(entry_edge) = position.entry_edge_bps else {
+            return false;
+        };
+        position.side == side
+            && position.add_on_count() < self.config.max_add_ons
+            && edge_bps >= entry_edge + self.config.add_on_min_edge_improvement_bps
+            && edge_bps > entry_edge
+    }
+
     /// Process a trading signal.
     ///
     /// Runs through all gate checks and queues the order if all pass.
@@ -524,12 +961,21 @@ impl Executor {
     /// # Gate Order (Strict)
     ///
     /// 1.  HardStop               → Rejected::HardStop
-    ///     1b. MaxDrawdown (P2-3)     → Rejected::MaxDrawdown
+    ///     1a. EntryPause             → Rejected::EntriesPaused
+    ///     1b. MaxDrawdown (P2-3)     → Rejected::MaxDrawdown / DailyLossLimit / WeeklyLossLimit
+    ///     1b. EquityDrawdown         → Rejected::EquityDrawdown
+    ///     1b. Latency                → Rejected::LatencyDegraded
     ///     1c. CorrelationCooldown    → Rejected::CorrelationCooldown
     ///     1d. BurstSignal            → Rejected::BurstSignal
+    ///     1f. OpenOrders             → Rejected::OpenOrderLimit
+    ///     1f. RejectBreaker          → Rejected::RejectBreakerOpen
+    ///     1g. MinConfidence          → Rejected::LowConfidence
+    ///     1h. SignalTtl              → Skipped::SignalExpired
     /// 2.  (READY-TRADING)        → Handled by bot, not checked here
     /// 3.  MaxPositionPerMarket   → Rejected::MaxPositionPerMarket
+    ///     3b. RiskBudget             → Rejected::RiskBudgetExhausted
     /// 4.  MaxPositionTotal       → Rejected::MaxPositionTotal
+    ///     4b. NetExposure            → Rejected::NetExposureLimit
     /// 5.  MaxConcurrentPositions → Rejected::MaxConcurrentPositions
     /// 6.  FlattenInProgress      → Skipped::FlattenInProgress
     /// 7.  has_position           → Skipped::AlreadyHasPosition
@@ -542,12 +988,30 @@ impl Executor {
     /// The caller (bot) must verify `connection_manager.is_ready()` before calling
     /// this method to ensure WebSocket READY-TRADING state.
     ///
+    /// `size_multiplier` comes from the signal's strength bucket and is applied
+    /// before Gate 3, so the scaled size is still capped by position limits.
+    ///
+    /// With `max_add_ons` set, a signal stronger than the entry edge of an
+    /// open position on the same side passes Gates 5 and 7 as an add-on
+    /// (see [`Executor::is_scaling_add_on`]).
+    ///
+    /// `net_edge_bps` selects the order type: at or below
+    /// `alo_max_net_edge_bps` the entry rests as ALO at the passive touch
+    /// (saving the taker fee), otherwise it crosses with IOC at `price`.
+    /// Resting entries are cancelled by [`Executor::sweep_alo_entries`].
+    ///
+    /// With an [`OrderSlicer`] attached, IOC entries above its slice notional
+    /// send only the first child here; the rest go out from
+    /// [`Executor::tick_slices`]. A market with an active parent counts as
+    /// having a pending order at Gate 8.
+    ///
     /// # Returns
     ///
     /// - `ExecutionResult::Queued` - Order successfully queued
     /// - `ExecutionResult::QueuedDegraded` - Order queued in degraded mode
     /// - `ExecutionResult::Rejected` - Order rejected by gate check
     /// - `ExecutionResult::Skipped` - Signal intentionally skipped
+    #[allow(clippy::too_many_arguments)]
     pub fn on_signal(
         &self,
         market: &MarketKey,
@@ -556,6 +1020,10 @@ impl Executor {
         size: Size,
         now_ms: u64,
         edge_bps: Decimal,
+        net_edge_bps: Decimal,
+        confidence: Decimal,
+        size_multiplier: Decimal,
+        validity: Option<SignalValidity>,
     ) -> ExecutionResult {
         // Gate 1: HardStop
         if self.hard_stop_latch.is_triggered() {
@@ -563,21 +1031,46 @@ impl Executor {
             return ExecutionResult::rejected(RejectReason::HardStop);
         }
 
-        // Gate 1b (P2-3): MaxDrawdown — block new entries when hourly drawdown exceeded
+        // Gate 1a: EntryPause — operator paused new entries (exits unaffected)
+        if self.entry_pause.as_ref().is_some_and(|p| p.is_paused()) {
+            debug!(market = %market, "Signal rejected: entries paused");
+            return ExecutionResult::rejected(RejectReason::EntriesPaused);
+        }
+
+        // Gate 1b (P2-3): MaxDrawdown — block new entries when hourly drawdown
+        // exceeded or a daily/weekly loss limit is latched
         if let Some(ref gate) = self.max_drawdown_gate {
-            if let Err(reason) = gate.check() {
+            if let Some(reason) = self.enforced("MaxDrawdown", Some(*market), gate.check()) {
                 debug!(
                     market = %market,
+                    reason = ?reason,
                     pnl_usd = gate.cumulative_pnl_usd(),
                     "Signal rejected: MaxDrawdown gate"
                 );
                 return ExecutionResult::rejected(reason);
             }
         }
+        if let Some(ref gate) = self.equity_drawdown_gate {
+            if let Some(reason) = self.enforced("EquityDrawdown", Some(*market), gate.check()) {
+                debug!(
+                    market = %market,
+                    equity = %gate.status().equity,
+                    "Signal rejected: EquityDrawdown gate"
+                );
+                return ExecutionResult::rejected(reason);
+            }
+        }
+        if let Some(ref gate) = self.latency_gate {
+            if let Some(reason) = self.enforced("Latency", Some(*market), gate.check()) {
+                debug!(market = %market, "Signal rejected: Latency gate");
+                return ExecutionResult::rejected(reason);
+            }
+        }
 
         // Gate 1c (P2-4): CorrelationCooldown — block after correlated mass close
         if let Some(ref gate) = self.correlation_cooldown_gate {
-            if let Err(reason) = gate.check() {
+            if let Some(reason) = self.enforced("CorrelationCooldown", Some(*market), gate.check())
+            {
                 debug!(
                     market = %market,
                     "Signal rejected: CorrelationCooldown gate"
@@ -589,7 +1082,8 @@ impl Executor {
         // Gate 1d: BurstSignal — per-market signal rate limiting (check only)
         // record() is called after all gates pass, so only actual trades count.
         if let Some(ref gate) = self.burst_signal_gate {
-            if let Err(reason) = gate.check(market, edge_bps) {
+            let check = gate.check(market, edge_bps);
+            if let Some(reason) = self.enforced("BurstSignal", Some(*market), check) {
                 debug!(
                     market = %market,
                     "Signal rejected: BurstSignal gate"
@@ -600,7 +1094,7 @@ impl Executor {
 
         // Gate 1e: TiltGuard — block entries during consecutive loss cooldown
         if let Some(ref gate) = self.tilt_guard_gate {
-            if let Err(reason) = gate.check() {
+            if let Some(reason) = self.enforced("TiltGuard", Some(*market), gate.check()) {
                 debug!(
                     market = %market,
                     "Signal rejected: TiltGuard cooldown"
@@ -611,7 +1105,7 @@ impl Executor {
 
         // Gate 1f: ReEntryDelay — block re-entry into same market too soon after close
         if let Some(ref gate) = self.re_entry_delay_gate {
-            if let Err(reason) = gate.check(market) {
+            if let Some(reason) = self.enforced("ReEntryDelay", Some(*market), gate.check(market)) {
                 debug!(
                     market = %market,
                     "Signal rejected: ReEntryDelay"
@@ -620,12 +1114,76 @@ impl Executor {
             }
         }
 
+        // Gate 1f: OpenOrders — block while the market has too many open orders
+        // or recent cancel failures (order leak guard)
+        if let Some(ref gate) = self.open_order_gate {
+            let check = gate.check_at(market, now_ms);
+            if let Some(reason) = self.enforced("OpenOrders", Some(*market), check) {
+                debug!(market = %market, "Signal rejected: OpenOrders gate");
+                return ExecutionResult::rejected(reason);
+            }
+        }
+
+        // Gate 1f: RejectBreaker — block a market that keeps rejecting orders
+        if let Some(ref breaker) = self.reject_breaker {
+            let check = breaker.check_at(market, now_ms);
+            if let Some(reason) = self.enforced("RejectBreaker", Some(*market), check) {
+                debug!(market = %market, "Signal rejected: RejectBreaker open");
+                return ExecutionResult::rejected(reason);
+            }
+        }
+
+        // Gate 1g: MinConfidence — drop low-confidence signals
+        let check =
+            if !self.config.min_confidence.is_zero() && confidence < self.config.min_confidence {
+                Err(RejectReason::LowConfidence)
+            } else {
+                Ok(())
+            };
+        if let Some(reason) = self.enforced("MinConfidence", Some(*market), check) {
+            debug!(
+                market = %market,
+                %confidence,
+                min_confidence = %self.config.min_confidence,
+                "Signal rejected: confidence below minimum"
+            );
+            return ExecutionResult::rejected(reason);
+        }
+
+        // Gate 1h: SignalTtl — drop signals that aged out or whose edge vanished
+        // between detection and execution
+        if let Some(stale) = validity.and_then(|v| self.signal_staleness(market, side, &v, now_ms))
+        {
+            debug!(
+                market = %market,
+                signal_edge_bps = %edge_bps,
+                reason = stale,
+                "Signal skipped: stale before execution"
+            );
+            return ExecutionResult::skipped(SkipReason::SignalExpired);
+        }
+
         // Gate 2: READY-TRADING - Handled by bot via connection_manager.is_ready()
         // TradingReadyChecker's 4 flags are not wired in current implementation.
         // The bot checks WS READY-TRADING (bbo + assetCtx + orderUpdates subscriptions)
         // before calling on_signal, so we skip this check here to avoid duplication.
         // To restore: if !self.ready_checker.is_ready() { return Rejected(NotReady); }
 
+        // Strength sizing: scale before the position gates so caps still apply
+        let size = if size_multiplier > Decimal::ZERO && size_multiplier != Decimal::ONE {
+            let scaled = Size::new(size.inner() * size_multiplier);
+            debug!(
+                market = %market,
+                original_size = %size,
+                scaled_size = %scaled,
+                %size_multiplier,
+                "Order size scaled by signal strength"
+            );
+            scaled
+        } else {
+            size
+        };
+
         // Gate 3 (was Gate 3): MaxPositionPerMarket
         // MUST fail closed: if mark_px unavailable, reject order
         let mark_px = match self.market_state_cache.get_mark_px(market) {
@@ -713,6 +1271,20 @@ impl Executor {
             );
         }
 
+        // Gate 3b: RiskBudget
+        // Market's share of the total notional limit and of the daily loss budget
+        if let Some(ref gate) = self.risk_budget_gate {
+            let check = gate.check(market, new_order_notional, mark_px);
+            if let Some(reason) = self.enforced("RiskBudget", Some(*market), check) {
+                debug!(
+                    market = %market,
+                    new_order_notional = %new_order_notional,
+                    "Signal rejected by RiskBudgetGate"
+                );
+                return ExecutionResult::rejected(reason);
+            }
+        }
+
         // Gate 4: MaxPositionTotal
         // Includes positions + pending (excluding reduce_only)
         // MUST fail closed: if any mark_px unavailable, reject order
@@ -726,7 +1298,12 @@ impl Executor {
         let projected_total = total_portfolio_notional + new_order_notional;
 
         // Compare using Decimal (no f64 conversion)
-        if projected_total >= self.config.max_notional_total {
+        // Skipped when the netting config applies the total limit to net exposure
+        let net_replaces_gross = self
+            .net_exposure_gate
+            .as_ref()
+            .is_some_and(|gate| gate.replaces_gross_total());
+        if !net_replaces_gross && projected_total >= self.config.max_notional_total {
             debug!(
                 market = %market,
                 projected_total = %projected_total,
@@ -736,11 +1313,32 @@ impl Executor {
             return ExecutionResult::rejected(RejectReason::MaxPositionTotal);
         }
 
+        // Gate 4b: NetExposure
+        // Long and short exposure within a netting group offset each other
+        if let Some(ref gate) = self.net_exposure_gate {
+            let cache = &self.market_state_cache;
+            let check = gate.check(market, side, new_order_notional, |m| cache.get_mark_px(m));
+            if let Some(reason) = self.enforced("NetExposure", Some(*market), check) {
+                debug!(
+                    market = %market,
+                    reason = ?reason,
+                    "Signal rejected by NetExposureGate"
+                );
+                return ExecutionResult::rejected(reason);
+            }
+        }
+
+        // Position scaling: a stronger signal on the side of an open position
+        // adds to it instead of being skipped at Gate 7
+        let is_add_on = self.is_scaling_add_on(market, side, edge_bps);
+
         // Gate 5: MaxConcurrentPositions (with optional P3-3 correlation weighting)
         // Block new positions if already at max concurrent positions limit.
         // Note: This check is before has_position so it only blocks NEW market entries.
         // Existing positions are handled by Gate 6 (AlreadyHasPosition skip).
-        if let Some(ref gate) = self.correlation_position_gate {
+        if is_add_on {
+            // An add-on does not open a new position
+        } else if let Some(ref gate) = self.correlation_position_gate {
             // P3-3: Use correlation-weighted position counting.
             if let Err(reason) = gate.check(market, side) {
                 debug!(
@@ -771,20 +1369,33 @@ impl Executor {
             return ExecutionResult::skipped(SkipReason::FlattenInProgress);
         }
 
-        // Gate 7: has_position
-        if self.position_tracker.has_position(market) {
+        // Gate 7: has_position (unless scaling into it)
+        if !is_add_on && self.position_tracker.has_position(market) {
             trace!(market = %market, "Signal skipped: Already has position");
             return ExecutionResult::skipped(SkipReason::AlreadyHasPosition);
         }
 
-        // Gate 8: PendingOrder (atomic mark)
-        if !self.position_tracker.try_mark_pending_market(market) {
+        // Gate 8: PendingOrder (atomic mark); a parent still being sliced counts
+        if self
+            .order_slicer
+            .as_ref()
+            .is_some_and(|slicer| slicer.has_active(market))
+        {
+            trace!(market = %market, "Signal skipped: Sliced entry in progress");
+            return ExecutionResult::skipped(SkipReason::PendingOrderExists);
+        }
+        let marked = if is_add_on {
+            self.position_tracker.try_mark_pending_add_on(market)
+        } else {
+            self.position_tracker.try_mark_pending_market(market)
+        };
+        if !marked {
             trace!(market = %market, "Signal skipped: Pending order exists");
             return ExecutionResult::skipped(SkipReason::PendingOrderExists);
         }
 
         // Gate 9: ActionBudget
-        if !self.action_budget.can_send_new_order() {
+        if !self.action_budget.can_send_new_order_for(market) {
             // Rollback: unmark pending market since we won't queue the order
             self.position_tracker.unmark_pending_market(market);
             trace!(
@@ -795,8 +1406,8 @@ impl Executor {
             return ExecutionResult::skipped(SkipReason::BudgetExhausted);
         }
 
-        // Consume budget
-        if !self.action_budget.consume() {
+        // Consume budget (unless the scheduler meters it when draining)
+        if !self.batch_scheduler.meters_action_budget() && !self.action_budget.consume_for(market) {
             // Race condition: budget exhausted between check and consume
             self.position_tracker.unmark_pending_market(market);
             return ExecutionResult::skipped(SkipReason::BudgetExhausted);
@@ -809,27 +1420,53 @@ impl Executor {
 
         // All gates passed - create and queue order
         let cloid = ClientOrderId::new();
-        let order = PendingOrder::new(
+        let (tif, price) = self.entry_order_type(market, side, price, net_edge_bps);
+        let slices = match self.order_slicer {
+            Some(ref slicer) if tif == TimeInForce::ImmediateOrCancel => {
+                slicer.slice_count(new_order_notional)
+            }
+            _ => 1,
+        };
+        let order_size = if slices > 1 {
+            Size::new(size.inner() / Decimal::from(slices))
+        } else {
+            size
+        };
+        let order = PendingOrder::with_tif(
             cloid.clone(),
             *market,
             side,
             price,
-            size,
+            order_size,
             false, // reduce_only
             now_ms,
-        );
-
-        match self.batch_scheduler.enqueue_new_order(order.clone()) {
+            tif,
+        )
+        .with_validity(validity);
+        // Slices re-check the live edge against the signal's floor (or any edge at all)
+        let slice_min_edge = validity
+            .and_then(|v| v.min_edge_bps)
+            .unwrap_or(Decimal::ZERO);
+
+        let tier = self.signal_tier(net_edge_bps);
+        match self
+            .batch_scheduler
+            .enqueue_new_order_at(order.clone(), tier)
+        {
             EnqueueResult::Queued => {
+                self.start_parent_order(&order, size, slices, slice_min_edge);
                 let tracked = TrackedOrder::from_pending(order);
                 self.try_register_order(tracked, &cloid);
-                debug!(cloid = %cloid, market = %market, "Order queued");
+                self.track_alo_entry(&cloid, market, tif, now_ms);
+                debug!(cloid = %cloid, market = %market, %tif, "Order queued");
                 ExecutionResult::queued(cloid)
             }
             EnqueueResult::QueuedDegraded => {
+                self.start_parent_order(&order, size, slices, slice_min_edge);
                 let tracked = TrackedOrder::from_pending(order);
                 self.try_register_order(tracked, &cloid);
-                debug!(cloid = %cloid, market = %market, "Order queued (degraded mode)");
+                self.track_alo_entry(&cloid, market, tif, now_ms);
+                debug!(cloid = %cloid, market = %market, %tif, "Order queued (degraded mode)");
                 ExecutionResult::queued_degraded(cloid)
             }
             EnqueueResult::QueueFull => {
@@ -845,39 +1482,349 @@ impl Executor {
         }
     }
 
-    /// Process MM quote actions (place/cancel/replace).
+    /// Batch scheduler tier of a signal entry.
+    fn signal_tier(&self, net_edge_bps: Decimal) -> OrderTier {
+        let threshold = self.config.strong_signal_min_net_edge_bps;
+        if threshold <= Decimal::ZERO || net_edge_bps >= threshold {
+            OrderTier::Strong
+        } else {
+            OrderTier::Weak
+        }
+    }
+
+    /// Choose IOC or ALO for a signal entry, and its limit price.
     ///
-    /// This is the MM-specific executor path that bypasses taker-only gates:
-    /// - Gate 1d (BurstSignal): bypassed — MM requotes are normal
-    /// - Gate 5 (MaxConcurrentPositions): bypassed — MM quotes across markets
-    /// - Gate 7 (AlreadyHasPosition): bypassed — MM holds positions while quoting
-    /// - Gate 8 (PendingOrder): bypassed — MM has multiple orders per market
-    /// - Gate 9 (ActionBudget): bypassed — MM has its own requote interval
+    /// ALO rests at our side's touch (best bid for buys, best ask for sells)
+    /// so it cannot cross. Falls back to IOC at `price` when ALO entries are
+    /// disabled, the edge is large enough to pay the taker fee, or no quote
+    /// is cached.
+    fn entry_order_type(
+        &self,
+        market: &MarketKey,
+        side: OrderSide,
+        price: Price,
+        net_edge_bps: Decimal,
+    ) -> (TimeInForce, Price) {
+        let max_alo_edge = self.config.alo_max_net_edge_bps;
+        if max_alo_edge <= Decimal::ZERO || net_edge_bps > max_alo_edge {
+            return (TimeInForce::ImmediateOrCancel, price);
+        }
+        let touch = self
+            .market_state_cache
+            .get_quote(market)
+            .map(|q| match side {
+                OrderSide::Buy => q.best_bid,
+                OrderSide::Sell => q.best_ask,
+            })
+            .filter(|px| !px.is_zero());
+        match touch {
+            Some(touch) => (TimeInForce::AddLiquidityOnly, touch),
+            None => (TimeInForce::ImmediateOrCancel, price),
+        }
+    }
+
+    /// Why a signal's staleness bounds no longer hold (`"ttl"` or
+    /// `"edge_gone"`, the `signal_expired` metric labels), or None if they do.
     ///
-    /// Gates that still apply:
-    /// - Gate 1 (HardStop): emergency stop applies to all
-    /// - Gate 1b (MaxDrawdown): drawdown limit applies to all
-    pub fn on_mm_quote(&self, actions: Vec<MakerAction>) -> Vec<MmQuoteResult> {
-        let mut results = Vec::new();
+    /// Checked at enqueue and again when the order is dequeued for
+    /// submission. With no quote cached there is nothing to compare the
+    /// edge against, so only the TTL applies.
+    pub fn signal_staleness(
+        &self,
+        market: &MarketKey,
+        side: OrderSide,
+        validity: &SignalValidity,
+        now_ms: u64,
+    ) -> Option<&'static str> {
+        if now_ms > validity.expires_at_ms {
+            return Some("ttl");
+        }
+        let min_edge = validity.min_edge_bps?;
+        let current_edge = self
+            .market_state_cache
+            .get_quote(market)
+            .and_then(|q| q.edge_bps(side))?;
+        (current_edge <= min_edge).then_some("edge_gone")
+    }
 
-        // Gate 1: HardStop — block all MM activity
-        if self.hard_stop_latch.is_triggered() {
-            debug!("MM quotes rejected: HardStop triggered");
-            results.push(MmQuoteResult::Rejected("HardStop".into()));
-            return results;
+    /// Start timing an ALO entry (no-op for IOC).
+    fn track_alo_entry(
+        &self,
+        cloid: &ClientOrderId,
+        market: &MarketKey,
+        tif: TimeInForce,
+        now_ms: u64,
+    ) {
+        if tif != TimeInForce::AddLiquidityOnly {
+            return;
         }
+        self.alo_entries.insert(
+            cloid.clone(),
+            AloEntry {
+                market: *market,
+                placed_at: now_ms,
+                cancel_sent: false,
+            },
+        );
+    }
 
-        // Gate 1b: MaxDrawdown — block new entries when drawdown exceeded
-        if let Some(ref gate) = self.max_drawdown_gate {
-            if gate.check().is_err() {
-                debug!("MM quotes rejected: MaxDrawdown gate");
-                results.push(MmQuoteResult::Rejected("MaxDrawdown".into()));
-                return results;
+    /// Advance resting ALO entries: forget finished ones, cancel timed-out ones.
+    ///
+    /// An entry is finished once the position tracker no longer holds it as
+    /// pending (filled, cancelled or rejected). Timed-out entries are
+    /// cancelled once their oid is known; until the exchange acknowledges
+    /// the order there is nothing to cancel yet. A partial fill keeps its
+    /// position; only the remainder is cancelled.
+    ///
+    /// Returns the number of cancels enqueued.
+    pub fn sweep_alo_entries(&self, now_ms: u64) -> usize {
+        let timeout_ms = self.config.alo_timeout_ms;
+        let mut cancels = 0;
+        self.alo_entries.retain(|cloid, entry| {
+            if self.position_tracker.get_pending_order(cloid).is_none() {
+                trace!(cloid = %cloid, market = %entry.market, "ALO entry finished");
+                return false;
+            }
+            if entry.cancel_sent || now_ms.saturating_sub(entry.placed_at) < timeout_ms {
+                return true;
+            }
+            let Some(oid) = self.position_tracker.get_oid(cloid) else {
+                return true;
+            };
+            let cancel = PendingCancel::new(entry.market, oid, now_ms);
+            if self.batch_scheduler.enqueue_cancel(cancel) == EnqueueResult::Queued {
+                debug!(
+                    cloid = %cloid,
+                    market = %entry.market,
+                    oid,
+                    age_ms = now_ms.saturating_sub(entry.placed_at),
+                    "ALO entry timed out, cancel queued"
+                );
+                entry.cancel_sent = true;
+                cancels += 1;
+            } else {
+                warn!(cloid = %cloid, oid, "ALO entry cancel failed to enqueue");
             }
+            true
+        });
+        cancels
+    }
+
+    /// Open a parent order for a sliced entry whose first child was queued.
+    fn start_parent_order(
+        &self,
+        first_child: &PendingOrder,
+        total_size: Size,
+        slices: u32,
+        min_edge_bps: Decimal,
+    ) {
+        if slices <= 1 {
+            return;
+        }
+        let Some(ref slicer) = self.order_slicer else {
+            return;
+        };
+        if let Some(id) = slicer.start(first_child, total_size, slices, min_edge_bps) {
+            info!(
+                parent_id = id,
+                market = %first_child.market,
+                side = ?first_child.side,
+                total_size = %total_size,
+                slices,
+                "Entry sliced into parent order"
+            );
         }
+    }
 
-        for action in actions {
-            match action {
+    /// Send the next child of every sliced parent that is due.
+    ///
+    /// Before each child the live quote edge is re-checked against the
+    /// parent's minimum; a parent whose edge is gone (or cannot be confirmed
+    /// because no quote is cached) is abandoned. HardStop and a flatten on
+    /// the market stop parents outright. Children consume the action budget
+    /// like any new order; when it is exhausted the child waits for a later
+    /// tick.
+    ///
+    /// Returns the number of children queued.
+    pub fn tick_slices(&self, now_ms: u64) -> usize {
+        let Some(ref slicer) = self.order_slicer else {
+            return 0;
+        };
+        if self.hard_stop_latch.is_triggered() {
+            let stopped = slicer.finish_all(ParentOrderState::Stopped, now_ms);
+            if stopped > 0 {
+                warn!(stopped, "HardStop: sliced parent orders stopped");
+            }
+            return 0;
+        }
+
+        let mut sent = 0;
+        for parent in slicer.due(now_ms) {
+            let market = parent.market;
+            if self.position_tracker.is_flattening(&market) {
+                debug!(
+                    parent_id = parent.id,
+                    market = %market,
+                    "Parent order stopped: flatten in progress"
+                );
+                slicer.finish(&market, ParentOrderState::Stopped, now_ms);
+                continue;
+            }
+            let current_edge = self
+                .market_state_cache
+                .get_quote(&market)
+                .and_then(|q| q.edge_bps(parent.side));
+            if !current_edge.is_some_and(|edge| edge > parent.min_edge_bps) {
+                debug!(
+                    parent_id = parent.id,
+                    market = %market,
+                    current_edge = ?current_edge,
+                    min_edge = %parent.min_edge_bps,
+                    slices_sent = parent.slices_sent,
+                    slices_total = parent.slices_total,
+                    "Parent order abandoned: edge lost"
+                );
+                slicer.finish(&market, ParentOrderState::EdgeLost, now_ms);
+                continue;
+            }
+            let budget_ok = if self.batch_scheduler.meters_action_budget() {
+                self.action_budget.can_send_new_order_for(&market)
+            } else {
+                self.action_budget.consume_for(&market)
+            };
+            if !budget_ok {
+                trace!(
+                    parent_id = parent.id,
+                    market = %market,
+                    "Slice deferred: budget exhausted"
+                );
+                continue;
+            }
+
+            let size = parent.next_slice_size();
+            let cloid = ClientOrderId::new();
+            let order = PendingOrder::new(
+                cloid.clone(),
+                market,
+                parent.side,
+                parent.limit_price,
+                size,
+                false, // reduce_only
+                now_ms,
+            );
+            match self.batch_scheduler.enqueue_new_order(order.clone()) {
+                EnqueueResult::Queued | EnqueueResult::QueuedDegraded => {
+                    self.try_register_order(TrackedOrder::from_pending(order), &cloid);
+                    slicer.record_slice(&market, size, now_ms);
+                    debug!(
+                        cloid = %cloid,
+                        parent_id = parent.id,
+                        market = %market,
+                        slice = parent.slices_sent + 1,
+                        slices_total = parent.slices_total,
+                        "Slice queued"
+                    );
+                    sent += 1;
+                }
+                EnqueueResult::QueueFull | EnqueueResult::InflightFull => {
+                    debug!(
+                        parent_id = parent.id,
+                        market = %market,
+                        "Slice deferred: queue full"
+                    );
+                }
+            }
+        }
+        sent
+    }
+
+    /// Number of signal entries currently resting as ALO.
+    #[must_use]
+    pub fn alo_entry_count(&self) -> usize {
+        self.alo_entries.len()
+    }
+
+    /// Process MM quote actions (place/cancel/replace).
+    ///
+    /// This is the MM-specific executor path that bypasses taker-only gates:
+    /// - Gate 1d (BurstSignal): bypassed — MM requotes are normal
+    /// - Gate 5 (MaxConcurrentPositions): bypassed — MM quotes across markets
+    /// - Gate 7 (AlreadyHasPosition): bypassed — MM holds positions while quoting
+    /// - Gate 8 (PendingOrder): bypassed — MM has multiple orders per market
+    /// - Gate 9 (ActionBudget): bypassed — MM has its own requote interval
+    ///
+    /// Gates that still apply:
+    /// - Gate 1 (HardStop): full stop blocks all MM activity
+    /// - Gate 1 (HardStop level 1), Gate 1a (EntryPause): only cancels and
+    ///   flattens go through
+    /// - Gate 1b (MaxDrawdown, EquityDrawdown): drawdown limits apply to all
+    /// - Gate 1f (OpenOrders): actions of a blocked market are reduced to
+    ///   their cancels
+    ///
+    /// Gate 1b (Latency) does not block quotes; the bot widens MM spreads
+    /// instead (`LatencyGate::spread_multiplier_at`).
+    pub fn on_mm_quote(&self, actions: Vec<MakerAction>) -> Vec<MmQuoteResult> {
+        let mut results = Vec::new();
+
+        // Gate 1: HardStop (either level) / 1a: entries blocked — pull quotes
+        // and flatten, place nothing
+        let entries_blocked = if self.hard_stop_latch.is_triggered() {
+            Some("HardStop")
+        } else if self.entry_pause.as_ref().is_some_and(|p| p.is_paused()) {
+            Some("EntriesPaused")
+        } else {
+            None
+        };
+        let actions = if let Some(reason) = entries_blocked {
+            Self::strip_mm_entries(actions, reason, &mut results)
+        } else {
+            // Gate 1b: MaxDrawdown — block new entries when drawdown exceeded
+            if let Some(ref gate) = self.max_drawdown_gate {
+                if self.enforced("MaxDrawdown", None, gate.check()).is_some() {
+                    debug!("MM quotes rejected: MaxDrawdown gate");
+                    results.push(MmQuoteResult::Rejected("MaxDrawdown".into()));
+                    return results;
+                }
+            }
+            if let Some(ref gate) = self.equity_drawdown_gate {
+                if self
+                    .enforced("EquityDrawdown", None, gate.check())
+                    .is_some()
+                {
+                    debug!("MM quotes rejected: EquityDrawdown gate");
+                    results.push(MmQuoteResult::Rejected("EquityDrawdown".into()));
+                    return results;
+                }
+            }
+            actions
+        };
+
+        // Gate 1f: OpenOrders — strip new quotes of markets over the cap
+        let actions = match self.open_order_gate {
+            Some(ref gate) if gate.is_enabled() => self.strip_blocked_markets(
+                "OpenOrders",
+                "OpenOrderLimit",
+                actions,
+                &mut results,
+                |market| gate.check(market),
+            ),
+            _ => actions,
+        };
+
+        // Gate 1f: RejectBreaker — strip new quotes of markets that keep rejecting
+        let actions = match self.reject_breaker {
+            Some(ref breaker) if breaker.is_enabled() => self.strip_blocked_markets(
+                "RejectBreaker",
+                "RejectBreakerOpen",
+                actions,
+                &mut results,
+                |market| breaker.check(market),
+            ),
+            _ => actions,
+        };
+
+        for action in actions {
+            match action {
                 MakerAction::PlaceOrders(orders) => {
                     for order in orders {
                         let result = self.enqueue_mm_order(order);
@@ -904,6 +1851,12 @@ impl Executor {
                         results.push(result);
                     }
                 }
+                MakerAction::Modify(modifies) => {
+                    for modify in modifies {
+                        let result = self.enqueue_mm_modify(modify);
+                        results.push(result);
+                    }
+                }
                 MakerAction::FlattenAll {
                     cancels,
                     flatten_orders,
@@ -913,6 +1866,7 @@ impl Executor {
                     }
                     for order in flatten_orders {
                         // Flatten uses reduce-only path (higher priority)
+                        let order = order.with_strategy(OrderStrategy::Maker);
                         let cloid = order.cloid.clone();
                         match self.batch_scheduler.enqueue_reduce_only(order.clone()) {
                             EnqueueResult::Queued | EnqueueResult::InflightFull => {
@@ -937,12 +1891,89 @@ impl Executor {
         results
     }
 
+    /// Drop placements and amendments from MM actions while new entries are
+    /// blocked, keeping cancels and flattens.
+    ///
+    /// One `Rejected(reason)` result is pushed if anything was dropped.
+    fn strip_mm_entries(
+        actions: Vec<MakerAction>,
+        reason: &str,
+        results: &mut Vec<MmQuoteResult>,
+    ) -> Vec<MakerAction> {
+        let mut dropped = 0usize;
+        let kept = actions
+            .into_iter()
+            .filter_map(|action| match action {
+                MakerAction::CancelOrders(_) | MakerAction::FlattenAll { .. } => Some(action),
+                MakerAction::CancelAndReplace { cancels, .. } => {
+                    dropped += 1;
+                    Some(MakerAction::CancelOrders(cancels))
+                }
+                MakerAction::PlaceOrders(_) | MakerAction::Modify(_) => {
+                    dropped += 1;
+                    None
+                }
+            })
+            .collect();
+        if dropped > 0 {
+            debug!(
+                reason,
+                dropped, "MM quotes rejected: entries blocked, cancels only"
+            );
+            results.push(MmQuoteResult::Rejected(reason.into()));
+        }
+        kept
+    }
+
+    /// Reduce actions of markets blocked by a per-market gate (`check`) to
+    /// their cancels and flattens.
+    fn strip_blocked_markets(
+        &self,
+        gate: &'static str,
+        reason: &str,
+        actions: Vec<MakerAction>,
+        results: &mut Vec<MmQuoteResult>,
+        check: impl Fn(&MarketKey) -> Result<(), RejectReason>,
+    ) -> Vec<MakerAction> {
+        let mut blocked_markets: HashMap<MarketKey, bool> = HashMap::new();
+        let mut kept = Vec::with_capacity(actions.len());
+        let mut blocked = Vec::new();
+        for action in actions {
+            let market = match &action {
+                MakerAction::PlaceOrders(orders)
+                | MakerAction::CancelAndReplace {
+                    new_orders: orders, ..
+                } => orders.first().map(|o| o.market),
+                MakerAction::Modify(modifies) => modifies.first().map(|m| m.order.market),
+                MakerAction::CancelOrders(_) | MakerAction::FlattenAll { .. } => None,
+            };
+            let is_blocked = market.is_some_and(|market| {
+                *blocked_markets
+                    .entry(market)
+                    .or_insert_with(|| self.enforced(gate, Some(market), check(&market)).is_some())
+            });
+            if is_blocked {
+                blocked.push(action);
+            } else {
+                kept.push(action);
+            }
+        }
+        if !blocked.is_empty() {
+            kept.extend(Self::strip_mm_entries(blocked, reason, results));
+        }
+        kept
+    }
+
     /// Enqueue a single MM order (GTC/ALO).
     fn enqueue_mm_order(&self, order: PendingOrder) -> MmQuoteResult {
+        let order = order.with_strategy(OrderStrategy::Maker);
         let cloid = order.cloid.clone();
         let market = order.market;
 
-        match self.batch_scheduler.enqueue_new_order(order.clone()) {
+        match self
+            .batch_scheduler
+            .enqueue_new_order_at(order.clone(), OrderTier::Quote)
+        {
             EnqueueResult::Queued => {
                 let tracked = TrackedOrder::from_pending(order);
                 self.try_register_order(tracked, &cloid);
@@ -965,8 +1996,83 @@ impl Executor {
         }
     }
 
+    /// Enqueue an amendment of a resting MM quote.
+    ///
+    /// The amended order is tracked under its new cloid. Amendments that
+    /// cannot be queued are handed back via [`Self::take_rejected_modifies`].
+    fn enqueue_mm_modify(&self, mut modify: PendingModify) -> MmQuoteResult {
+        modify.order.strategy = OrderStrategy::Maker;
+        let cloid = modify.order.cloid.clone();
+        let order = modify.order.clone();
+
+        match self.batch_scheduler.enqueue_modify(modify.clone()) {
+            EnqueueResult::Queued | EnqueueResult::QueuedDegraded => {
+                let tracked = TrackedOrder::from_pending(order);
+                self.try_register_order(tracked, &cloid);
+                debug!(cloid = %cloid, oid = modify.oid, "MM modify queued");
+                MmQuoteResult::Queued(cloid)
+            }
+            EnqueueResult::QueueFull => {
+                debug!(cloid = %cloid, oid = modify.oid, "MM modify rejected: queue full");
+                self.reject_modify(modify);
+                MmQuoteResult::Rejected("QueueFull".into())
+            }
+            EnqueueResult::InflightFull => {
+                debug!(cloid = %cloid, oid = modify.oid, "MM modify rejected: inflight full");
+                self.reject_modify(modify);
+                MmQuoteResult::Rejected("InflightFull".into())
+            }
+        }
+    }
+
+    /// Record an amendment that was not applied.
+    pub(crate) fn reject_modify(&self, modify: PendingModify) {
+        self.rejected_modifies.lock().push(modify);
+    }
+
+    /// Drain amendments that were not applied (queue full, send failure,
+    /// timeout or exchange rejection) so the caller can fall back to
+    /// cancel-replace.
+    pub fn take_rejected_modifies(&self) -> Vec<PendingModify> {
+        std::mem::take(&mut *self.rejected_modifies.lock())
+    }
+
+    /// Reject reason of a gate check, unless the gate runs observe-only.
+    ///
+    /// Blocks of observe-only gates are recorded for
+    /// [`Self::take_observed_blocks`] and reported as passing.
+    fn enforced(
+        &self,
+        gate: &'static str,
+        market: Option<MarketKey>,
+        check: Result<(), RejectReason>,
+    ) -> Option<RejectReason> {
+        let reason = check.err()?;
+        if !self.observe_only_gates.contains(&gate) {
+            return Some(reason);
+        }
+        debug!(gate, market = ?market, ?reason, "Gate block observed (observe-only)");
+        let mut observed = self.observed_blocks.lock();
+        if observed.len() < MAX_OBSERVED_BLOCKS {
+            observed.push(ObservedBlock {
+                timestamp_ms: chrono::Utc::now().timestamp_millis(),
+                gate,
+                market,
+                reason,
+            });
+        }
+        None
+    }
+
+    /// Drain the blocks observe-only gates would have made since the last
+    /// call.
+    pub fn take_observed_blocks(&self) -> Vec<ObservedBlock> {
+        std::mem::take(&mut *self.observed_blocks.lock())
+    }
+
     /// Enqueue a cancel for an MM quote.
     fn enqueue_mm_cancel(&self, cancel: PendingCancel) {
+        let cancel = cancel.with_strategy(OrderStrategy::Maker);
         let oid = cancel.oid;
         match self.batch_scheduler.enqueue_cancel(cancel) {
             EnqueueResult::Queued => {
@@ -1049,6 +2155,14 @@ impl Executor {
             self.position_tracker.remove_order(cloid).await;
         }
 
+        // Queued amendments never reached the exchange; the original
+        // orders stay tracked and are cancelled with the rest.
+        for modify in self.batch_scheduler.drop_modifies() {
+            self.position_tracker
+                .remove_order(modify.order.cloid.clone())
+                .await;
+        }
+
         // Note: Position flattening would be triggered via Flattener
         // which is separate from this method
     }
@@ -1160,6 +2274,12 @@ impl Executor {
         &self.market_state_cache
     }
 
+    /// Get the action budget.
+    #[must_use]
+    pub fn action_budget(&self) -> &Arc<ActionBudget> {
+        &self.action_budget
+    }
+
     /// Get the ready checker.
     #[must_use]
     pub fn ready_checker(&self) -> &Arc<TradingReadyChecker> {
@@ -1177,7 +2297,7 @@ impl Executor {
 mod tests {
     use super::*;
     use crate::batch::{BatchConfig, InflightTracker};
-    use hip3_core::{AssetId, DexId};
+    use hip3_core::{AssetId, DexId, OrderState};
     use hip3_position::spawn_position_tracker;
     use rust_decimal_macros::dec;
 
@@ -1253,6 +2373,10 @@ mod tests {
             Size::new(dec!(0.001)),
             1234567890,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
 
         assert!(matches!(
@@ -1263,6 +2387,147 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_on_signal_low_confidence() {
+        let (mut executor, _pt) = setup_executor();
+        executor.config.min_confidence = dec!(0.5);
+
+        let result = executor.on_signal(
+            &sample_market(),
+            OrderSide::Buy,
+            Price::new(dec!(50000)),
+            Size::new(dec!(0.001)),
+            1234567890,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            dec!(0.3),
+            Decimal::ONE,
+            None,
+        );
+
+        assert!(matches!(
+            result,
+            ExecutionResult::Rejected {
+                reason: RejectReason::LowConfidence
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_observe_only_gate_records_instead_of_rejecting() {
+        let (mut executor, _pt) = setup_executor();
+        let market = sample_market();
+        executor.config.min_confidence = dec!(0.5);
+        executor.observe_only_gates = vec!["MinConfidence"];
+        executor
+            .market_state_cache
+            .update(&market, Price::new(dec!(50000)), 1234567890);
+
+        let result = executor.on_signal(
+            &market,
+            OrderSide::Buy,
+            Price::new(dec!(50000)),
+            Size::new(dec!(0.001)),
+            1234567890,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            dec!(0.3),
+            Decimal::ONE,
+            None,
+        );
+
+        assert!(result.is_queued());
+        let observed = executor.take_observed_blocks();
+        assert_eq!(observed.len(), 1);
+        assert_eq!(observed[0].gate, "MinConfidence");
+        assert_eq!(observed[0].market, Some(market));
+        assert_eq!(observed[0].reason, RejectReason::LowConfidence);
+        assert!(executor.take_observed_blocks().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_on_signal_expired() {
+        let (executor, _pt) = setup_executor();
+        let market = sample_market();
+        executor
+            .market_state_cache
+            .update(&market, Price::new(dec!(50000)), 1234567890);
+
+        let signal = |now_ms: u64, validity: SignalValidity| {
+            executor.on_signal(
+                &market,
+                OrderSide::Buy,
+                Price::new(dec!(49900)),
+                Size::new(dec!(0.0005)),
+                now_ms,
+                dec!(20),
+                Decimal::ZERO,
+                Decimal::ONE,
+                Decimal::ONE,
+                Some(validity),
+            )
+        };
+        let expired = ExecutionResult::Skipped {
+            reason: SkipReason::SignalExpired,
+        };
+
+        // TTL elapsed
+        let validity = SignalValidity {
+            expires_at_ms: 1_000,
+            min_edge_bps: None,
+        };
+        assert_eq!(signal(1_001, validity), expired);
+
+        // Within TTL, but the ask moved back up to the oracle: edge gone
+        executor.market_state_cache.update_quote(
+            &market,
+            Price::new(dec!(50000)),
+            Price::new(dec!(49990)),
+            Price::new(dec!(50000)),
+            1_000,
+        );
+        let validity = SignalValidity {
+            expires_at_ms: 2_000,
+            min_edge_bps: Some(dec!(10)),
+        };
+        assert_eq!(signal(1_500, validity), expired);
+
+        // Edge still there: 20 bps > 10 bps
+        executor.market_state_cache.update_quote(
+            &market,
+            Price::new(dec!(50000)),
+            Price::new(dec!(49890)),
+            Price::new(dec!(49900)),
+            1_200,
+        );
+        assert!(signal(1_500, validity).is_queued());
+
+        // The queued order carries the bounds so they hold again at dequeue
+        let Some(hip3_core::ActionBatch::Orders(orders)) = executor.batch_scheduler().tick() else {
+            panic!("expected an order batch");
+        };
+        assert_eq!(orders[0].validity, Some(validity));
+        assert_eq!(
+            executor.signal_staleness(&market, OrderSide::Buy, &validity, 1_600),
+            None
+        );
+        assert_eq!(
+            executor.signal_staleness(&market, OrderSide::Buy, &validity, 2_001),
+            Some("ttl")
+        );
+        executor.market_state_cache.update_quote(
+            &market,
+            Price::new(dec!(50000)),
+            Price::new(dec!(49990)),
+            Price::new(dec!(50000)),
+            1_600,
+        );
+        assert_eq!(
+            executor.signal_staleness(&market, OrderSide::Buy, &validity, 1_700),
+            Some("edge_gone")
+        );
+    }
+
     #[tokio::test]
     async fn test_on_signal_queued() {
         let (executor, _pt) = setup_executor();
@@ -1282,12 +2547,84 @@ mod tests {
             Size::new(dec!(0.0005)),
             1234567890,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
 
         assert!(
             matches!(result, ExecutionResult::Queued { .. }),
             "Expected Queued, got: {result:?}"
         );
+        let Some(hip3_core::ActionBatch::Orders(orders)) = executor.batch_scheduler().tick() else {
+            panic!("expected the entry order");
+        };
+        assert_eq!(orders[0].strategy, OrderStrategy::Taker);
+    }
+
+    #[tokio::test]
+    async fn test_mm_actions_routed_as_maker() {
+        let (executor, _pt) = setup_executor();
+        let market = sample_market();
+        let quote = PendingOrder::with_tif(
+            ClientOrderId::new(),
+            market,
+            OrderSide::Buy,
+            Price::new(dec!(49990)),
+            Size::new(dec!(0.0005)),
+            false,
+            1234567890,
+            TimeInForce::AddLiquidityOnly,
+        );
+
+        executor.on_mm_quote(vec![MakerAction::CancelAndReplace {
+            cancels: vec![PendingCancel::new(market, 7, 1234567890)],
+            new_orders: vec![quote],
+        }]);
+
+        let Some(hip3_core::ActionBatch::Cancels(cancels)) = executor.batch_scheduler().tick()
+        else {
+            panic!("expected the quote cancel");
+        };
+        assert_eq!(cancels[0].strategy, OrderStrategy::Maker);
+        let Some(hip3_core::ActionBatch::Orders(orders)) = executor.batch_scheduler().tick() else {
+            panic!("expected the quote");
+        };
+        assert_eq!(orders[0].strategy, OrderStrategy::Maker);
+    }
+
+    #[tokio::test]
+    async fn test_mm_cancels_pass_full_stop() {
+        let (executor, _pt) = setup_executor();
+        let market = sample_market();
+        let quote = PendingOrder::with_tif(
+            ClientOrderId::new(),
+            market,
+            OrderSide::Buy,
+            Price::new(dec!(49990)),
+            Size::new(dec!(0.0005)),
+            false,
+            1234567890,
+            TimeInForce::AddLiquidityOnly,
+        );
+        executor.hard_stop_latch.trigger("test: full stop");
+        assert!(executor.hard_stop_latch.is_full_stop());
+
+        let results = executor.on_mm_quote(vec![MakerAction::CancelAndReplace {
+            cancels: vec![PendingCancel::new(market, 7, 1234567890)],
+            new_orders: vec![quote],
+        }]);
+
+        assert!(results
+            .iter()
+            .any(|r| matches!(r, MmQuoteResult::Rejected(reason) if reason == "HardStop")));
+        let Some(hip3_core::ActionBatch::Cancels(cancels)) = executor.batch_scheduler().tick()
+        else {
+            panic!("expected the quote cancel");
+        };
+        assert_eq!(cancels[0].oid, 7);
+        assert!(executor.batch_scheduler().tick().is_none());
     }
 
     #[tokio::test]
@@ -1309,6 +2646,10 @@ mod tests {
             Size::new(dec!(0.0004)),
             1234567890,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
         assert!(matches!(result1, ExecutionResult::Queued { .. }));
 
@@ -1323,6 +2664,10 @@ mod tests {
             Size::new(dec!(0.0004)),
             1234567891,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
         assert!(
             matches!(
@@ -1357,6 +2702,10 @@ mod tests {
             Size::new(dec!(0.0005)),
             1234567890,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
         assert!(matches!(result1, ExecutionResult::Queued { .. }));
 
@@ -1369,6 +2718,10 @@ mod tests {
             Size::new(dec!(0.01)),
             1234567890,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
         assert!(matches!(result2, ExecutionResult::Queued { .. }));
     }
@@ -1429,12 +2782,288 @@ mod tests {
             Size::new(dec!(0.002)),
             1234567890,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
 
         // Size is scaled from 0.002 to 0.001 ($50), so order is queued
         assert!(result.is_queued());
     }
 
+    #[tokio::test]
+    async fn test_strength_size_multiplier_capped() {
+        let (executor, _pt) = setup_executor();
+        let market = sample_market();
+        executor
+            .market_state_cache
+            .update(&market, Price::new(dec!(50000)), 1234567890);
+
+        let queued_size = |executor: &Executor| match executor.batch_scheduler.tick() {
+            Some(hip3_core::ActionBatch::Orders(orders)) => orders[0].size,
+            other => panic!("Expected orders batch, got: {other:?}"),
+        };
+
+        // $10 order at 2x → $20
+        let result = executor.on_signal(
+            &market,
+            OrderSide::Buy,
+            Price::new(dec!(50000)),
+            Size::new(dec!(0.0002)),
+            1234567890,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            dec!(2),
+            None,
+        );
+        assert!(result.is_queued());
+        assert_eq!(queued_size(&executor), Size::new(dec!(0.0004)));
+
+        // $25 order at 4x → $100, capped to the $50 per-market limit
+        let other = sample_market_2();
+        executor
+            .market_state_cache
+            .update(&other, Price::new(dec!(50000)), 1234567890);
+        let result = executor.on_signal(
+            &other,
+            OrderSide::Buy,
+            Price::new(dec!(50000)),
+            Size::new(dec!(0.0005)),
+            1234567890,
+            Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            dec!(4),
+            None,
+        );
+        assert!(result.is_queued());
+        assert_eq!(queued_size(&executor), Size::new(dec!(0.001)));
+    }
+
+    #[tokio::test]
+    async fn test_alo_entry_lifecycle() {
+        let (mut executor, pt) = setup_executor();
+        executor.config.alo_max_net_edge_bps = dec!(5);
+        executor.config.alo_timeout_ms = 1_000;
+        let market = sample_market();
+        executor
+            .market_state_cache
+            .update(&market, Price::new(dec!(50000)), 1_000);
+        executor.market_state_cache.update_quote(
+            &market,
+            Price::new(dec!(50000)),
+            Price::new(dec!(49890)),
+            Price::new(dec!(49900)),
+            1_000,
+        );
+        let signal = |executor: &Executor, market: &MarketKey, net_edge_bps| {
+            executor.on_signal(
+                market,
+                OrderSide::Buy,
+                Price::new(dec!(49900)),
+                Size::new(dec!(0.0005)),
+                1_000,
+                dec!(20),
+                net_edge_bps,
+                Decimal::ONE,
+                Decimal::ONE,
+                None,
+            )
+        };
+        let queued_order = |executor: &Executor| match executor.batch_scheduler.tick() {
+            Some(hip3_core::ActionBatch::Orders(orders)) => orders[0].clone(),
+            other => panic!("Expected orders batch, got: {other:?}"),
+        };
+
+        // Thin edge: rest as ALO at the best bid
+        let cloid = match signal(&executor, &market, dec!(3)) {
+            ExecutionResult::Queued { cloid } => cloid,
+            other => panic!("Expected queued, got: {other:?}"),
+        };
+        let order = queued_order(&executor);
+        assert_eq!(order.tif, TimeInForce::AddLiquidityOnly);
+        assert_eq!(order.price, Price::new(dec!(49890)));
+        assert_eq!(executor.alo_entry_count(), 1);
+
+        // Before the timeout, or before the oid is known: nothing to cancel
+        assert_eq!(executor.sweep_alo_entries(1_500), 0);
+        assert_eq!(executor.sweep_alo_entries(2_500), 0);
+
+        // Resting with an oid and timed out: cancel once
+        pt.record_oid_mapping(cloid.clone(), 777).await;
+        assert_eq!(executor.sweep_alo_entries(2_500), 1);
+        assert_eq!(executor.sweep_alo_entries(2_600), 0);
+        match executor.batch_scheduler.tick() {
+            Some(hip3_core::ActionBatch::Cancels(cancels)) => assert_eq!(cancels[0].oid, 777),
+            other => panic!("Expected cancels batch, got: {other:?}"),
+        }
+
+        // Cancel confirmed: entry is finished
+        pt.order_update(cloid, OrderState::Cancelled, Size::ZERO, Some(777))
+            .await;
+        executor.sweep_alo_entries(2_700);
+        assert_eq!(executor.alo_entry_count(), 0);
+
+        // Edge large enough to pay the taker fee: IOC at the signal price
+        let other = sample_market_2();
+        executor
+            .market_state_cache
+            .update(&other, Price::new(dec!(50000)), 1_000);
+        assert!(signal(&executor, &other, dec!(8)).is_queued());
+        let order = queued_order(&executor);
+        assert_eq!(order.tif, TimeInForce::ImmediateOrCancel);
+        assert_eq!(order.price, Price::new(dec!(49900)));
+        assert_eq!(executor.alo_entry_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sliced_entry_parent_lifecycle() {
+        let (executor, _pt) = setup_executor();
+        let slicer = OrderSlicer::new_shared(crate::slicer::SlicingConfig {
+            enabled: true,
+            max_slice_notional: dec!(20),
+            duration_ms: 900,
+        });
+        let executor = executor.with_order_slicer(slicer.clone());
+        let market = sample_market();
+        executor
+            .market_state_cache
+            .update(&market, Price::new(dec!(50000)), 1_000);
+        // Ask 20 bps under the oracle
+        executor.market_state_cache.update_quote(
+            &market,
+            Price::new(dec!(50000)),
+            Price::new(dec!(49890)),
+            Price::new(dec!(49900)),
+            1_000,
+        );
+        let signal = |executor: &Executor| {
+            executor.on_signal(
+                &market,
+                OrderSide::Buy,
+                Price::new(dec!(49900)),
+                Size::new(dec!(0.0009)),
+                1_000,
+                dec!(20),
+                dec!(10),
+                Decimal::ONE,
+                Decimal::ONE,
+                Some(SignalValidity {
+                    expires_at_ms: 5_000,
+                    min_edge_bps: Some(dec!(5)),
+                }),
+            )
+        };
+        let queued_size = |executor: &Executor| match executor.batch_scheduler.tick() {
+            Some(hip3_core::ActionBatch::Orders(orders)) => orders[0].size,
+            other => panic!("Expected orders batch, got: {other:?}"),
+        };
+
+        // $45 entry → 3 children of $15; the first goes out immediately
+        assert!(signal(&executor).is_queued());
+        assert_eq!(queued_size(&executor), Size::new(dec!(0.0003)));
+        assert!(slicer.has_active(&market));
+        assert_eq!(
+            signal(&executor),
+            ExecutionResult::skipped(SkipReason::PendingOrderExists)
+        );
+
+        // Next child is due 300ms later
+        assert_eq!(executor.tick_slices(1_200), 0);
+        assert_eq!(executor.tick_slices(1_300), 1);
+        assert_eq!(queued_size(&executor), Size::new(dec!(0.0003)));
+
+        executor.market_state_cache.update_quote(
+            &market,
+            Price::new(dec!(50000)),
+            Price::new(dec!(49960)),
+            Price::new(dec!(49980)),
+            1_500,
+        );
+        // Ask catches up to 4 bps under the oracle, below the signal's 5 bps
+        // floor: the parent is abandoned
+        assert_eq!(executor.tick_slices(1_600), 0);
+        assert!(!slicer.has_active(&market));
+        let parent = &slicer.snapshot()[0];
+        assert_eq!(parent.state, ParentOrderState::EdgeLost);
+        assert_eq!(parent.slices_sent, 2);
+        assert_eq!(parent.sent_size, Size::new(dec!(0.0006)));
+    }
+
+    #[tokio::test]
+    async fn test_scaling_add_on_needs_stronger_signal() {
+        let (mut executor, pt) = setup_executor();
+        executor.config.max_add_ons = 1;
+        executor.config.add_on_min_edge_improvement_bps = dec!(5);
+        let market = sample_market();
+        executor
+            .market_state_cache
+            .update(&market, Price::new(dec!(50000)), 1_000);
+
+        // $10 long opened on a 10 bps signal
+        pt.fill(
+            market,
+            OrderSide::Buy,
+            Price::new(dec!(50000)),
+            Size::new(dec!(0.0002)),
+            1_000,
+            None,
+            Some(dec!(10)),
+        )
+        .await;
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+
+        let signal = |executor: &Executor, side: OrderSide, edge_bps: Decimal| {
+            executor.on_signal(
+                &market,
+                side,
+                Price::new(dec!(50000)),
+                Size::new(dec!(0.0002)),
+                2_000,
+                edge_bps,
+                edge_bps,
+                Decimal::ONE,
+                Decimal::ONE,
+                None,
+            )
+        };
+
+        // Not enough stronger, or against the position: skipped as before
+        assert_eq!(
+            signal(&executor, OrderSide::Buy, dec!(12)),
+            ExecutionResult::skipped(SkipReason::AlreadyHasPosition)
+        );
+        assert_eq!(
+            signal(&executor, OrderSide::Sell, dec!(30)),
+            ExecutionResult::skipped(SkipReason::AlreadyHasPosition)
+        );
+        // 15 bps: add-on queued
+        assert!(signal(&executor, OrderSide::Buy, dec!(15)).is_queued());
+
+        // Once the add-on filled, the limit of one add-on is reached
+        pt.fill(
+            market,
+            OrderSide::Buy,
+            Price::new(dec!(50000)),
+            Size::new(dec!(0.0002)),
+            2_000,
+            None,
+            Some(dec!(15)),
+        )
+        .await;
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        let position = pt.get_position(&market).unwrap();
+        assert_eq!(position.add_on_count(), 1);
+        assert_eq!(position.entry_edge_bps, Some(dec!(10)));
+        assert_eq!(position.tranches[1].entry_edge_bps, Some(dec!(15)));
+        assert_eq!(
+            signal(&executor, OrderSide::Buy, dec!(40)),
+            ExecutionResult::skipped(SkipReason::AlreadyHasPosition)
+        );
+    }
+
     #[tokio::test]
     async fn test_max_position_per_market_rejected_no_capacity() {
         let (executor, pt) = setup_executor();
@@ -1469,6 +3098,10 @@ mod tests {
             Size::new(dec!(0.001)),
             1234567891,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
 
         assert!(matches!(
@@ -1505,6 +3138,10 @@ mod tests {
             Size::new(dec!(0.0009)),
             1234567890,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
         assert!(matches!(result1, ExecutionResult::Queued { .. }));
 
@@ -1517,6 +3154,10 @@ mod tests {
             Size::new(dec!(0.015)),
             1234567891,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
         assert!(matches!(result2, ExecutionResult::Queued { .. }));
 
@@ -1529,6 +3170,10 @@ mod tests {
             Size::new(dec!(0.02)),
             1234567892,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
 
         assert!(
@@ -1555,6 +3200,10 @@ mod tests {
             Size::new(dec!(0.001)),
             1234567890,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
 
         assert!(matches!(
@@ -1601,6 +3250,10 @@ mod tests {
             Size::new(dec!(0.0005)),
             1234567890,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
 
         assert!(matches!(
@@ -1633,6 +3286,10 @@ mod tests {
             Size::new(dec!(0.01)),
             1234567890,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
         assert!(matches!(result1, ExecutionResult::Queued { .. }));
 
@@ -1649,6 +3306,10 @@ mod tests {
             Size::new(dec!(0.0005)),
             1234567891,
             Decimal::ZERO,
+            Decimal::ZERO,
+            Decimal::ONE,
+            Decimal::ONE,
+            None,
         );
 
         assert!(matches!(
@@ -1750,6 +3411,33 @@ mod tests {
         assert_eq!(budget.remaining(), 2);
     }
 
+    #[test]
+    fn test_action_budget_market_quota_and_shared_pool() {
+        let budget = ActionBudget::new(10, 1000).with_market_quota(2, 1);
+        let a = MarketKey::new(DexId::XYZ, AssetId::new(1));
+        let b = MarketKey::new(DexId::XYZ, AssetId::new(2));
+
+        assert!(budget.consume_for_at(&a, 0));
+        assert!(budget.consume_for_at(&a, 0));
+        assert_eq!(budget.remaining_for_at(&a, 0), 0);
+        // Quota spent: overflow from the shared pool
+        assert!(budget.consume_for_at(&a, 0));
+        assert_eq!(budget.shared_remaining_at(0), 0);
+        assert!(!budget.can_send_new_order_for_at(&a, 0));
+        assert!(!budget.consume_for_at(&a, 0));
+
+        // Another market still has its own quota
+        assert!(budget.can_send_new_order_for_at(&b, 0));
+        assert!(budget.consume_for_at(&b, 0));
+        assert_eq!(budget.remaining_for_at(&b, 0), 1);
+        assert_eq!(budget.remaining(), 6);
+
+        // Everything resets with the interval
+        assert_eq!(budget.remaining_for_at(&a, 1001), 2);
+        assert_eq!(budget.shared_remaining_at(1001), 1);
+        assert!(budget.consume_for_at(&a, 1001));
+    }
+
     #[test]
     fn test_action_budget_default() {
         let budget = ActionBudget::default();
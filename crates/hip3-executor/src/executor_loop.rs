@@ -4,24 +4,52 @@
 //! - Handles request timeouts
 //! - Collects batches from the scheduler
 //! - Applies HardStop filtering
-//! - Signs and sends orders
-
+//! - Drops or reprices orders exceeding their max slippage (see [`crate::slippage_guard`])
+//! - Drops orders with an absurd notional or price (see [`crate::fat_finger`])
+//! - Splits batches by vault (see [`crate::vault_router`]), signs and sends orders
+//! - Retries transient rejections (see [`crate::retry`])
+//! - Refreshes the scheduled-cancel dead man's switch (see [`crate::scheduled_cancel`])
+//! - Reconciles posts whose responses were lost in a disconnect (see [`crate::reconcile`])
+//! - Sweeps orders whose orderUpdate never arrived (see [`crate::order_sweep`])
+//! - Submits orders over REST when the WS path is saturated (see [`crate::rest_fallback`])
+//! - Logs signed actions instead of sending them in shadow mode (see [`crate::shadow`])
+//! - Records every signed action for the audit log (see [`crate::audit`])
+
+use std::collections::BTreeMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 use alloy::primitives::Address;
 use dashmap::DashMap;
-use tokio::sync::oneshot;
-use tracing::{debug, info, trace, warn};
+use tokio::sync::{mpsc, oneshot};
+use tracing::{debug, error, info, trace, warn};
 
+use crate::audit::SignedActionLog;
 use crate::error::ExecutorError;
 use crate::executor::Executor;
-use crate::nonce::{NonceManager, SystemClock};
-use crate::signer::{Action, CancelWire, OrderWire, Signer, SigningInput};
+use crate::fat_finger::FatFingerGuard;
+use crate::nonce::{is_nonce_error, NonceManager, SystemClock};
+use crate::order_sweep::{OrderSweepConfig, OrderSweeper, SweepReport};
+use crate::real_ws_sender::post_request_json;
+use crate::reconcile::{response_status, ReconcileOutcome, ReconcileReport};
+use crate::rest_fallback::{RestExchangeClient, RestSubmissions};
+use crate::retry::{RetryDecision, RetryPolicy};
+use crate::risk::ExecutionEvent;
+use crate::scheduled_cancel::{ScheduledCancel, ScheduledCancelConfig};
+use crate::shadow::predict_outcome;
+use crate::signer::{
+    Action, BuilderInfo, CancelWire, KeyPurpose, ModifyWire, OrderWire, Signer, SigningInput,
+};
+use crate::slippage_guard::{SlippageGuard, SlippageRejection};
+use crate::vault_router::VaultRouter;
 use crate::ws_sender::{ActionSignature, DynWsSender, SendResult, SignedAction};
-use hip3_core::{ActionBatch, ClientOrderId, MarketKey, OrderState, PendingOrder, Price, Size};
-use hip3_registry::SpecCache;
+use hip3_core::{
+    ActionBatch, ClientOrderId, EnqueueResult, MarketKey, OrderSide, OrderState, PendingModify,
+    PendingOrder, Price, RejectReason, Size, TimeInForce,
+};
+use hip3_position::HedgeHandle;
+use hip3_registry::{MetaClient, OrderStatusInfo, SpecCache};
 use hip3_ws::OrderResponseStatus;
 
 // ============================================================================
@@ -108,6 +136,24 @@ impl PostRequestManager {
         }
     }
 
+    /// Post IDs of requests that were sent and await a response.
+    #[must_use]
+    pub fn sent_post_ids(&self) -> Vec<u64> {
+        let mut post_ids: Vec<u64> = self
+            .pending
+            .iter()
+            .filter(|entry| entry.value().sent)
+            .map(|entry| *entry.key())
+            .collect();
+        post_ids.sort_unstable();
+        post_ids
+    }
+
+    /// Allocate a post ID for a post not tracked as a pending request.
+    pub fn allocate_post_id(&self) -> u64 {
+        self.next_post_id.fetch_add(1, Ordering::SeqCst)
+    }
+
     /// Create a new pending request.
     ///
     /// Returns the post ID and a receiver for the result.
@@ -140,6 +186,15 @@ impl PostRequestManager {
         }
     }
 
+    /// Send time of a sent request (Unix milliseconds).
+    #[must_use]
+    pub fn sent_at(&self, post_id: u64) -> Option<u64> {
+        self.pending
+            .get(&post_id)
+            .filter(|r| r.sent)
+            .map(|r| r.sent_at)
+    }
+
     /// Complete a request with success.
     pub fn complete_ok(&self, post_id: u64) {
         if let Some((_, mut request)) = self.pending.remove(&post_id) {
@@ -198,6 +253,16 @@ impl PostRequestManager {
         self.pending.get(&post_id).map(|r| r.batch.clone())
     }
 
+    /// Whether an order (or amended order) is part of a pending request.
+    #[must_use]
+    pub fn has_order(&self, cloid: &ClientOrderId) -> bool {
+        self.pending.iter().any(|entry| match &entry.value().batch {
+            ActionBatch::Orders(orders) => orders.iter().any(|o| &o.cloid == cloid),
+            ActionBatch::Modifies(modifies) => modifies.iter().any(|m| &m.order.cloid == cloid),
+            ActionBatch::Cancels(_) => false,
+        })
+    }
+
     /// Cancel all pending requests.
     ///
     /// Returns a list of all batches that were pending.
@@ -246,12 +311,46 @@ pub struct ExecutorLoop {
     ws_sender: Option<DynWsSender>,
     /// Manager for pending requests.
     post_request_manager: PostRequestManager,
-    /// Optional vault/active_pool address to include in signing and post payload.
-    vault_address: Option<Address>,
+    /// Vault/active_pool address per strategy for signing and post payload.
+    vault_router: VaultRouter,
     /// Tick interval.
     interval: Duration,
     /// Market spec cache for price/size precision formatting.
     spec_cache: Arc<SpecCache>,
+    /// Retry schedule for transient rejections (None = rejected orders die).
+    retry_policy: Option<RetryPolicy>,
+    /// Channel to the RiskMonitor for retried rejections.
+    risk_event_tx: Option<mpsc::Sender<ExecutionEvent>>,
+    /// Scheduled-cancel dead man's switch (None = not armed).
+    scheduled_cancel: Option<ScheduledCancel>,
+    /// Hold sent requests while disconnected for reconciliation on READY.
+    reconcile_on_reconnect: bool,
+    /// Look up timed-out amendments by amended cloid instead of releasing them.
+    lookup_timed_out_modifies: bool,
+    /// Amendments whose batchModify timed out, awaiting the lookup.
+    timed_out_modifies: parking_lot::Mutex<Vec<PendingModify>>,
+    /// Submit-time slippage guard (None = no check).
+    slippage_guard: Option<SlippageGuard>,
+    /// Orders dropped by the slippage guard, drained by the application.
+    slippage_rejections: parking_lot::Mutex<Vec<SlippageRejection>>,
+    /// Orders dropped at dequeue for a stale signal, drained by the application.
+    stale_signal_drops: parking_lot::Mutex<Vec<StaleSignalDrop>>,
+    /// Fat-finger sanity gate, the last check before signing (None = no check).
+    fat_finger_guard: Option<FatFingerGuard>,
+    /// Builder code attached to every order action (None = no builder fee).
+    builder: Option<BuilderInfo>,
+    /// Sweeper of orders stuck pending (None = not armed).
+    order_sweeper: Option<OrderSweeper>,
+    /// REST submission of orders the WS path cannot take (None = no fallback).
+    rest_fallback: Option<RestExchangeClient>,
+    /// REST submissions in flight, answered, or with an unknown outcome.
+    rest_submissions: Arc<parking_lot::Mutex<RestSubmissions>>,
+    /// Log signed actions instead of sending them.
+    shadow: bool,
+    /// Audit trail of signed actions (None = not recorded).
+    action_log: Option<SignedActionLog>,
+    /// L1 hedger whose order results bypass the position tracker (None = no hedging).
+    hedger: Option<HedgeHandle>,
 }
 
 impl ExecutorLoop {
@@ -271,8 +370,25 @@ impl ExecutorLoop {
             signer,
             ws_sender: None,
             post_request_manager: PostRequestManager::new(timeout_ms),
-            vault_address: None,
+            vault_router: VaultRouter::default(),
             spec_cache,
+            retry_policy: None,
+            risk_event_tx: None,
+            scheduled_cancel: None,
+            reconcile_on_reconnect: false,
+            lookup_timed_out_modifies: false,
+            timed_out_modifies: parking_lot::Mutex::new(Vec::new()),
+            slippage_guard: None,
+            slippage_rejections: parking_lot::Mutex::new(Vec::new()),
+            stale_signal_drops: parking_lot::Mutex::new(Vec::new()),
+            fat_finger_guard: None,
+            builder: None,
+            order_sweeper: None,
+            rest_fallback: None,
+            rest_submissions: Arc::default(),
+            shadow: false,
+            action_log: None,
+            hedger: None,
         }
     }
 
@@ -293,8 +409,25 @@ impl ExecutorLoop {
             signer,
             ws_sender: Some(ws_sender),
             post_request_manager: PostRequestManager::new(timeout_ms),
-            vault_address: None,
+            vault_router: VaultRouter::default(),
             spec_cache,
+            retry_policy: None,
+            risk_event_tx: None,
+            scheduled_cancel: None,
+            reconcile_on_reconnect: false,
+            lookup_timed_out_modifies: false,
+            timed_out_modifies: parking_lot::Mutex::new(Vec::new()),
+            slippage_guard: None,
+            slippage_rejections: parking_lot::Mutex::new(Vec::new()),
+            stale_signal_drops: parking_lot::Mutex::new(Vec::new()),
+            fat_finger_guard: None,
+            builder: None,
+            order_sweeper: None,
+            rest_fallback: None,
+            rest_submissions: Arc::default(),
+            shadow: false,
+            action_log: None,
+            hedger: None,
         }
     }
 
@@ -305,7 +438,99 @@ impl ExecutorLoop {
 
     /// Set the vault/active_pool address used for signing and post payload.
     pub fn set_vault_address(&mut self, vault_address: Option<Address>) {
-        self.vault_address = vault_address;
+        self.vault_router = VaultRouter::new(vault_address, BTreeMap::new());
+    }
+
+    /// Route order strategies to different vault/active_pool addresses.
+    pub fn set_vault_router(&mut self, vault_router: VaultRouter) {
+        self.vault_router = vault_router;
+    }
+
+    /// Enable retries of transiently rejected orders.
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = Some(retry_policy);
+    }
+
+    /// Set the RiskMonitor channel that receives every rejected attempt.
+    pub fn set_risk_event_tx(&mut self, tx: mpsc::Sender<ExecutionEvent>) {
+        self.risk_event_tx = Some(tx);
+    }
+
+    /// Arm the scheduled-cancel dead man's switch.
+    pub fn set_scheduled_cancel(&mut self, config: ScheduledCancelConfig) {
+        self.scheduled_cancel = Some(ScheduledCancel::new(config));
+    }
+
+    /// Hold sent requests while the WebSocket is down instead of timing
+    /// them out, for [`Self::reconcile_inflight`] on reconnect.
+    pub fn set_reconcile_on_reconnect(&mut self, enabled: bool) {
+        self.reconcile_on_reconnect = enabled;
+    }
+
+    /// Hold amendments whose batchModify timed out for
+    /// [`Self::reconcile_timed_out_modifies`] instead of handing them to the
+    /// cancel-replace fallback right away: the amended order may already
+    /// rest under its new cloid.
+    pub fn set_lookup_timed_out_modifies(&mut self, enabled: bool) {
+        self.lookup_timed_out_modifies = enabled;
+    }
+
+    /// Check new IOC orders against their max slippage before submission.
+    pub fn set_slippage_guard(&mut self, guard: SlippageGuard) {
+        self.slippage_guard = Some(guard);
+    }
+
+    /// Drop new orders and amendments with an absurd notional or price.
+    pub fn set_fat_finger_guard(&mut self, guard: FatFingerGuard) {
+        self.fat_finger_guard = Some(guard);
+    }
+
+    /// Attach a builder code to every order action.
+    ///
+    /// The user must have approved at least `builder.fee` for the builder,
+    /// otherwise the exchange rejects the orders.
+    pub fn set_builder(&mut self, builder: BuilderInfo) {
+        self.builder = Some(builder);
+    }
+
+    /// Arm the sweeper of orders stuck pending without an orderUpdate.
+    pub fn set_order_sweep(&mut self, config: OrderSweepConfig) {
+        self.order_sweeper = Some(OrderSweeper::new(config));
+    }
+
+    /// Submit orders over REST when the WS post is rate limited or the
+    /// inflight limit holds back reduce-only exits.
+    ///
+    /// The client's scope selects which orders may fall back.
+    pub fn set_rest_fallback(&mut self, client: RestExchangeClient) {
+        self.rest_fallback = Some(client);
+    }
+
+    /// Stop short of sending: log each signed action's wire payload and
+    /// predicted outcome, then release its batch (see [`crate::shadow`]).
+    pub fn set_shadow(&mut self, enabled: bool) {
+        self.shadow = enabled;
+    }
+
+    /// Record every signed action and its outcome in `log`.
+    pub fn set_action_log(&mut self, log: SignedActionLog) {
+        self.action_log = Some(log);
+    }
+
+    /// Book results of the hedger's orders with the hedger instead of the
+    /// position tracker.
+    pub fn set_hedger(&mut self, hedger: HedgeHandle) {
+        self.hedger = Some(hedger);
+    }
+
+    /// Drain orders dropped by the slippage guard since the last call.
+    pub fn take_slippage_rejections(&self) -> Vec<SlippageRejection> {
+        std::mem::take(&mut *self.slippage_rejections.lock())
+    }
+
+    /// Drain orders dropped at dequeue for a stale signal since the last call.
+    pub fn take_stale_signal_drops(&self) -> Vec<StaleSignalDrop> {
+        std::mem::take(&mut *self.stale_signal_drops.lock())
     }
 
     /// Get the tick interval.
@@ -317,21 +542,42 @@ impl ExecutorLoop {
     /// Process one tick of the execution loop.
     ///
     /// This method:
-    /// 1. Checks for and handles timeouts
+    /// 1. Checks for and handles timeouts, sweeps resting ALO entries and
+    ///    queues due entry slices and order retries
     /// 2. Collects the next batch from the scheduler
     /// 3. Applies HardStop filtering (drops new orders, keeps reduce_only)
-    /// 4. Signs the action and sends via WebSocket
+    /// 4. Splits the batch by vault, signs each action and sends via WebSocket
     /// 5. Marks as sent only after successful send
     ///
-    /// Returns the post ID if a batch was queued, or None.
+    /// The scheduled-cancel dead man's switch, if armed, is refreshed first.
+    ///
+    /// Returns the post ID of the last batch queued, or None.
     pub async fn tick(&self, now_ms: u64) -> Option<u64> {
+        self.refresh_scheduled_cancel(now_ms).await;
+
+        // Apply the responses of REST submissions
+        self.apply_rest_responses().await;
+
         // 1. Handle timeouts
         self.handle_timeouts(now_ms).await;
 
+        // Cancel ALO entries that rested too long (cancels go out this tick)
+        self.executor.sweep_alo_entries(now_ms);
+
+        // Queue due child slices of sliced entries
+        self.executor.tick_slices(now_ms);
+
+        // Re-enqueue rejected orders whose backoff has elapsed
+        self.requeue_due_retries(now_ms).await;
+
         // 2. Collect batch from scheduler
         let batch = match self.executor.batch_scheduler().tick() {
             Some(batch) => batch,
-            None => return None,
+            None => {
+                // Exits held back by the inflight limit go out over REST
+                self.submit_blocked_exits().await;
+                return None;
+            }
         };
 
         // 3. Apply HardStop filtering
@@ -362,7 +608,261 @@ impl ExecutorLoop {
             batch => batch,
         };
 
-        // 4. Convert batch to action (may fail if SpecCache not ready)
+        // 3a. Drop signal entries that went stale while queued
+        let batch = self.apply_signal_validity(batch, now_ms).await?;
+
+        // 3b. Submit-time slippage guard
+        let batch = self.apply_slippage_guard(batch).await?;
+
+        // 3c. Fat-finger sanity gate (after any repricing)
+        let batch = self.apply_fat_finger_guard(batch).await?;
+
+        // 4. Split by vault (each action carries one vault address) and send
+        let mut last_post_id = None;
+        for (vault_address, batch) in self.vault_router.split(batch) {
+            if let Some(post_id) = self.send_batch(batch, vault_address, now_ms).await {
+                last_post_id = Some(post_id);
+            }
+        }
+        last_post_id
+    }
+
+    /// Drop new orders whose signal expired or whose edge vanished between
+    /// enqueue and dequeue.
+    ///
+    /// Returns None if every order was dropped.
+    async fn apply_signal_validity(&self, batch: ActionBatch, now_ms: u64) -> Option<ActionBatch> {
+        let ActionBatch::Orders(orders) = batch else {
+            return Some(batch);
+        };
+        let (dropped, kept): (Vec<_>, Vec<_>) = orders.into_iter().partition(|order| {
+            let Some(ref validity) = order.validity else {
+                return false;
+            };
+            let Some(reason) =
+                self.executor
+                    .signal_staleness(&order.market, order.side, validity, now_ms)
+            else {
+                return false;
+            };
+            debug!(
+                cloid = %order.cloid,
+                market = %order.market,
+                reason,
+                "Queued order dropped: signal went stale"
+            );
+            self.stale_signal_drops.lock().push(StaleSignalDrop {
+                order: order.clone(),
+                reason,
+            });
+            true
+        });
+        if !dropped.is_empty() {
+            self.cleanup_dropped_orders(dropped).await;
+        }
+        (!kept.is_empty()).then_some(ActionBatch::Orders(kept))
+    }
+
+    /// Drop or reprice new IOC orders whose limit price pays through the
+    /// latest mark (oracle if no mark) by more than the market's limit.
+    ///
+    /// Returns None if every order was dropped.
+    async fn apply_slippage_guard(&self, batch: ActionBatch) -> Option<ActionBatch> {
+        let (Some(guard), ActionBatch::Orders(orders)) = (&self.slippage_guard, &batch) else {
+            return Some(batch);
+        };
+        let cache = self.executor.market_state_cache();
+
+        let mut kept = Vec::with_capacity(orders.len());
+        let mut dropped = Vec::new();
+        for mut order in orders.iter().cloned() {
+            if !SlippageGuard::applies_to(&order) {
+                kept.push(order);
+                continue;
+            }
+            let reference = cache
+                .get_mark_px(&order.market)
+                .or_else(|| cache.get_quote(&order.market).map(|q| q.oracle_px));
+            let Some(breach) = reference.and_then(|px| guard.check(&order, px)) else {
+                kept.push(order);
+                continue;
+            };
+
+            let spec = self.spec_cache.get(&order.market);
+            if let (true, Some(spec)) = (guard.reprice(), spec) {
+                // Round toward the reference so the tick cannot break the limit
+                let is_buy = order.side == OrderSide::Buy;
+                let price = spec.round_price_for_order(breach.capped_px, !is_buy);
+                info!(
+                    cloid = %order.cloid,
+                    market = %order.market,
+                    from = %order.price,
+                    to = %price,
+                    slippage_bps = %breach.slippage_bps,
+                    "Order repriced to max slippage"
+                );
+                order.price = price;
+                kept.push(order);
+                continue;
+            }
+
+            warn!(
+                cloid = %order.cloid,
+                market = %order.market,
+                limit_px = %order.price,
+                reference_px = %breach.reference_px,
+                slippage_bps = %breach.slippage_bps,
+                max_slippage_bps = %breach.max_slippage_bps,
+                "Order dropped: slippage exceeded"
+            );
+            self.slippage_rejections.lock().push(SlippageRejection {
+                order: order.clone(),
+                reason: RejectReason::SlippageExceeded,
+                breach,
+            });
+            dropped.push(order);
+        }
+
+        if !dropped.is_empty() {
+            self.cleanup_dropped_orders(dropped).await;
+        }
+        (!kept.is_empty()).then_some(ActionBatch::Orders(kept))
+    }
+
+    /// Drop new orders and amendments that break the fat-finger bounds.
+    ///
+    /// Returns None if every order was dropped.
+    async fn apply_fat_finger_guard(&self, batch: ActionBatch) -> Option<ActionBatch> {
+        let Some(ref guard) = self.fat_finger_guard else {
+            return Some(batch);
+        };
+        let cache = self.executor.market_state_cache();
+        let breach_of = |order: &PendingOrder| {
+            if !FatFingerGuard::applies_to(order) {
+                return None;
+            }
+            let reference = cache
+                .get_mark_px(&order.market)
+                .or_else(|| cache.get_quote(&order.market).map(|q| q.oracle_px));
+            let breach = guard.check(order, reference)?;
+            error!(
+                cloid = %order.cloid,
+                market = %order.market,
+                side = ?order.side,
+                limit_px = %order.price,
+                size = %order.size,
+                bound = breach.as_str(),
+                detail = ?breach,
+                "🚨 Order dropped by fat-finger gate"
+            );
+            Some(breach)
+        };
+
+        match batch {
+            ActionBatch::Orders(orders) => {
+                let (dropped, kept): (Vec<_>, Vec<_>) =
+                    orders.into_iter().partition(|o| breach_of(o).is_some());
+                if !dropped.is_empty() {
+                    self.cleanup_dropped_orders(dropped).await;
+                }
+                (!kept.is_empty()).then_some(ActionBatch::Orders(kept))
+            }
+            ActionBatch::Modifies(modifies) => {
+                let (dropped, kept): (Vec<_>, Vec<_>) = modifies
+                    .into_iter()
+                    .partition(|m| breach_of(&m.order).is_some());
+                if !dropped.is_empty() {
+                    self.release_modifies(dropped).await;
+                }
+                (!kept.is_empty()).then_some(ActionBatch::Modifies(kept))
+            }
+            batch => Some(batch),
+        }
+    }
+
+    /// Push the scheduled cancel forward for every account, when due.
+    ///
+    /// Skipped while HardStop is triggered or the WebSocket is down, so the
+    /// exchange pulls resting orders once the schedule lapses.
+    async fn refresh_scheduled_cancel(&self, now_ms: u64) {
+        let Some(ref schedule) = self.scheduled_cancel else {
+            return;
+        };
+        let Some(ref ws_sender) = self.ws_sender else {
+            return;
+        };
+        if !schedule.is_due(now_ms)
+            || self.executor.hard_stop_latch().is_full_stop()
+            || !ws_sender.is_ready()
+        {
+            return;
+        }
+
+        let deadline = schedule.deadline(now_ms);
+        let mut vaults = vec![self.vault_router.default_vault()];
+        vaults.extend(self.vault_router.routed_vaults().into_iter().map(Some));
+
+        for vault_address in vaults {
+            let action = Action::schedule_cancel(Some(deadline));
+            let nonce = self.nonce_manager.next();
+            let signing_input = SigningInput {
+                action: action.clone(),
+                nonce,
+                vault_address,
+                expires_after: None,
+            };
+            let signature = match self
+                .signer
+                .sign_action_as(KeyPurpose::Taker, signing_input)
+                .await
+            {
+                Ok(sig) => sig,
+                Err(e) => {
+                    warn!(error = ?e, "Failed to sign scheduled cancel");
+                    return;
+                }
+            };
+
+            let post_id = self.post_request_manager.allocate_post_id();
+            let signed_action = SignedAction {
+                action,
+                nonce,
+                signature: ActionSignature {
+                    r: format!("0x{}", hex::encode(signature.r().to_be_bytes::<32>())),
+                    s: format!("0x{}", hex::encode(signature.s().to_be_bytes::<32>())),
+                    v: 27 + signature.v() as u8,
+                },
+                post_id,
+                vault_address,
+            };
+            let audit_copy = self.action_log.as_ref().map(|_| signed_action.clone());
+            let result = ws_sender.send(signed_action).await;
+            if let Some(ref signed_action) = audit_copy {
+                self.record_action(signed_action, None, send_outcome(&result));
+            }
+            match result {
+                SendResult::Sent => schedule.on_sent(post_id, now_ms),
+                result => {
+                    warn!(post_id, ?result, "Failed to send scheduled cancel");
+                    return;
+                }
+            }
+        }
+
+        schedule.on_refreshed(now_ms);
+        trace!(deadline, "Scheduled cancel refreshed");
+    }
+
+    /// Sign and send one batch for a vault.
+    ///
+    /// Returns the post ID if the batch was sent, or None.
+    async fn send_batch(
+        &self,
+        batch: ActionBatch,
+        vault_address: Option<Address>,
+        now_ms: u64,
+    ) -> Option<u64> {
+        // Convert batch to action (may fail if SpecCache not ready)
         let action = match self.batch_to_action(&batch) {
             Ok(action) => action,
             Err(e) => {
@@ -373,22 +873,23 @@ impl ExecutorLoop {
             }
         };
 
-        // 5. Create request (only after action build succeeds)
+        // Create request (only after action build succeeds)
         let (post_id, _rx) = self
             .post_request_manager
             .create_request(batch.clone(), now_ms);
 
-        // 6. Generate nonce and sign
+        // Generate nonce and sign
         let nonce = self.nonce_manager.next();
 
         let signing_input = SigningInput {
             action: action.clone(),
             nonce,
-            vault_address: self.vault_address,
+            vault_address,
             expires_after: None,
         };
 
-        let signature = match self.signer.sign_action(signing_input).await {
+        let purpose = key_purpose(&batch);
+        let signature = match self.signer.sign_action_as(purpose, signing_input).await {
             Ok(sig) => sig,
             Err(e) => {
                 warn!(post_id, error = ?e, "Failed to sign action");
@@ -398,7 +899,7 @@ impl ExecutorLoop {
             }
         };
 
-        // 7. Create signed action
+        // Create signed action
         // Note: signature.v() returns y_parity (0 or 1), SDK wire requires 27 or 28
         let signed_action = SignedAction {
             action,
@@ -409,17 +910,30 @@ impl ExecutorLoop {
                 v: 27 + signature.v() as u8, // Convert y_parity (0/1) to recovery id (27/28)
             },
             post_id,
+            vault_address,
         };
 
-        // 8. Send via WebSocket (if sender is configured)
+        if self.shadow {
+            self.log_shadow_action(&signed_action, &batch);
+            self.record_action(&signed_action, Some(&batch), "shadow");
+            self.release_shadow_batch(post_id, batch).await;
+            return None;
+        }
+
+        // Send via WebSocket (if sender is configured)
         if let Some(ref ws_sender) = self.ws_sender {
             if !ws_sender.is_ready() {
                 debug!(post_id, "WebSocket not ready, skipping send");
+                self.record_action(&signed_action, Some(&batch), "not_ready");
                 self.handle_send_failure(post_id, batch).await;
                 return None;
             }
 
+            let audit_copy = self.action_log.as_ref().map(|_| signed_action.clone());
             let send_result = ws_sender.send(signed_action).await;
+            if let Some(ref signed_action) = audit_copy {
+                self.record_action(signed_action, Some(&batch), send_outcome(&send_result));
+            }
 
             match send_result {
                 SendResult::Sent => {
@@ -428,6 +942,12 @@ impl ExecutorLoop {
                     self.executor.batch_scheduler().on_batch_sent();
                     trace!(post_id, "Batch sent successfully");
                 }
+                SendResult::RateLimited if self.rest_fallback.is_some() => {
+                    warn!(post_id, "Send rate limited, falling back to REST");
+                    self.handle_rate_limited(post_id, batch, vault_address)
+                        .await;
+                    return None;
+                }
                 SendResult::Disconnected | SendResult::RateLimited => {
                     warn!(post_id, result = ?send_result, "Send failed (retryable)");
                     self.handle_send_failure(post_id, batch).await;
@@ -442,6 +962,7 @@ impl ExecutorLoop {
         } else {
             // No WsSender configured - mark as sent for testing purposes
             trace!(post_id, "No WsSender configured, simulating send");
+            self.record_action(&signed_action, Some(&batch), "simulated");
             self.post_request_manager.mark_sent(post_id, now_ms);
             self.executor.batch_scheduler().on_batch_sent();
         }
@@ -449,6 +970,311 @@ impl ExecutorLoop {
         Some(post_id)
     }
 
+    /// Record a signed action in the audit trail, if enabled.
+    fn record_action(
+        &self,
+        signed_action: &SignedAction,
+        batch: Option<&ActionBatch>,
+        outcome: impl Into<String>,
+    ) {
+        let Some(ref log) = self.action_log else {
+            return;
+        };
+        let cloids = match batch {
+            Some(ActionBatch::Orders(orders)) => {
+                orders.iter().map(|o| o.cloid.to_string()).collect()
+            }
+            Some(ActionBatch::Modifies(modifies)) => {
+                modifies.iter().map(|m| m.order.cloid.to_string()).collect()
+            }
+            Some(ActionBatch::Cancels(_)) | None => Vec::new(),
+        };
+        log.record(signed_action, cloids, outcome);
+    }
+
+    /// Log the wire payload of an unsent action and the predicted outcome
+    /// of each of its orders.
+    fn log_shadow_action(&self, signed_action: &SignedAction, batch: &ActionBatch) {
+        let post_id = signed_action.post_id;
+        match post_request_json(signed_action) {
+            Ok(payload) => info!(post_id, %payload, "Shadow: action not sent"),
+            Err(e) => warn!(post_id, error = %e, "Shadow: failed to serialize action"),
+        }
+
+        let orders: Vec<&PendingOrder> = match batch {
+            ActionBatch::Orders(orders) => orders.iter().collect(),
+            ActionBatch::Modifies(modifies) => modifies.iter().map(|m| &m.order).collect(),
+            ActionBatch::Cancels(_) => Vec::new(),
+        };
+        let quotes = self.executor.market_state_cache();
+        for order in orders {
+            info!(
+                post_id,
+                cloid = %order.cloid,
+                market = %order.market,
+                side = ?order.side,
+                price = %order.price,
+                size = %order.size,
+                tif = ?order.tif,
+                reduce_only = order.reduce_only,
+                predicted = %predict_outcome(order, quotes),
+                "Shadow: predicted order outcome"
+            );
+        }
+    }
+
+    /// Release a batch that was signed but not sent in shadow mode.
+    ///
+    /// Orders are dropped from the position tracker (reduce-only included,
+    /// since no fill will ever arrive), cancels are discarded and amendments
+    /// handed back like a failed send.
+    async fn release_shadow_batch(&self, post_id: u64, batch: ActionBatch) {
+        self.post_request_manager.remove(post_id);
+        match batch {
+            ActionBatch::Orders(orders) => self.cleanup_dropped_orders(orders).await,
+            ActionBatch::Cancels(_) => {}
+            ActionBatch::Modifies(modifies) => self.release_modifies(modifies).await,
+        }
+    }
+
+    /// Handle a rate-limited WS send with the REST fallback configured.
+    ///
+    /// Orders covered by the fallback scope are submitted over REST; the
+    /// rest of the batch is handled as a failed send.
+    async fn handle_rate_limited(
+        &self,
+        post_id: u64,
+        batch: ActionBatch,
+        vault_address: Option<Address>,
+    ) {
+        let Some(ref client) = self.rest_fallback else {
+            self.handle_send_failure(post_id, batch).await;
+            return;
+        };
+        let ActionBatch::Orders(orders) = batch else {
+            self.handle_send_failure(post_id, batch).await;
+            return;
+        };
+
+        let scope = client.scope();
+        let (covered, uncovered): (Vec<_>, Vec<_>) =
+            orders.into_iter().partition(|o| scope.covers(o));
+        self.handle_send_failure(post_id, ActionBatch::Orders(uncovered))
+            .await;
+        if !covered.is_empty() {
+            self.submit_over_rest(client, covered, vault_address).await;
+        }
+    }
+
+    /// Submit reduce-only orders held back by the inflight limit over REST.
+    async fn submit_blocked_exits(&self) {
+        let Some(ref client) = self.rest_fallback else {
+            return;
+        };
+        let orders = self.executor.batch_scheduler().take_blocked_reduce_only();
+        if orders.is_empty() {
+            return;
+        }
+
+        warn!(
+            count = orders.len(),
+            "Inflight limit reached, submitting exits over REST"
+        );
+        for (vault_address, batch) in self.vault_router.split(ActionBatch::Orders(orders)) {
+            if let ActionBatch::Orders(orders) = batch {
+                self.submit_over_rest(client, orders, vault_address).await;
+            }
+        }
+    }
+
+    /// Sign an order action and post it over REST on its own task.
+    ///
+    /// The request bypasses the post request manager and the inflight
+    /// counter; its response is applied on a later tick (see
+    /// [`Self::apply_rest_responses`]).
+    async fn submit_over_rest(
+        &self,
+        client: &RestExchangeClient,
+        orders: Vec<PendingOrder>,
+        vault_address: Option<Address>,
+    ) {
+        let batch = ActionBatch::Orders(orders);
+        let action = match self.batch_to_action(&batch) {
+            Ok(action) => action,
+            Err(e) => {
+                warn!(error = %e, "Failed to build REST fallback action");
+                self.handle_batch_conversion_failure(batch).await;
+                return;
+            }
+        };
+
+        let nonce = self.nonce_manager.next();
+        let signing_input = SigningInput {
+            action: action.clone(),
+            nonce,
+            vault_address,
+            expires_after: None,
+        };
+        let signature = match self
+            .signer
+            .sign_action_as(key_purpose(&batch), signing_input)
+            .await
+        {
+            Ok(sig) => sig,
+            Err(e) => {
+                warn!(error = ?e, "Failed to sign REST fallback action");
+                self.handle_batch_conversion_failure(batch).await;
+                return;
+            }
+        };
+
+        // Post ID only correlates the log lines; no WS response will follow
+        let post_id = self.post_request_manager.allocate_post_id();
+        let signed_action = SignedAction {
+            action,
+            nonce,
+            signature: ActionSignature {
+                r: format!("0x{}", hex::encode(signature.r().to_be_bytes::<32>())),
+                s: format!("0x{}", hex::encode(signature.s().to_be_bytes::<32>())),
+                v: 27 + signature.v() as u8,
+            },
+            post_id,
+            vault_address,
+        };
+
+        let ActionBatch::Orders(orders) = batch else {
+            return;
+        };
+        self.rest_submissions
+            .lock()
+            .inflight
+            .insert(post_id, (signed_action.clone(), orders));
+
+        let client = client.clone();
+        let submissions = self.rest_submissions.clone();
+        let notify = self.executor.batch_scheduler().notify().clone();
+        tokio::spawn(async move {
+            let result = client.post(&signed_action).await;
+            submissions.lock().done.push((post_id, result));
+            // Apply the response on the next tick
+            notify.notify_one();
+        });
+    }
+
+    /// Apply the responses of finished REST submissions.
+    ///
+    /// On a transport failure the outcome is unknown: the orders are held
+    /// for [`Self::reconcile_rest_unknown`]. If the exchange rejected the
+    /// action, reduce-only orders are requeued and new orders dropped, as
+    /// for a failed WS send.
+    async fn apply_rest_responses(&self) {
+        let done = std::mem::take(&mut self.rest_submissions.lock().done);
+        for (post_id, result) in done {
+            let inflight = self.rest_submissions.lock().inflight.remove(&post_id);
+            let Some((signed_action, orders)) = inflight else {
+                continue;
+            };
+            let batch = ActionBatch::Orders(orders);
+            let outcome = match result {
+                Ok(_) => "rest_ok".to_string(),
+                Err(ref e) => format!("rest_error: {e}"),
+            };
+            self.record_action(&signed_action, Some(&batch), outcome);
+            let ActionBatch::Orders(orders) = batch else {
+                continue;
+            };
+            match result {
+                Ok(statuses) => {
+                    info!(post_id, count = orders.len(), "Orders submitted over REST");
+                    self.apply_order_statuses(&orders, &statuses).await;
+                }
+                Err(ExecutorError::ConnectionError(e)) => {
+                    warn!(
+                        post_id,
+                        count = orders.len(),
+                        error = %e,
+                        "REST order submission outcome unknown, reconciling"
+                    );
+                    self.rest_submissions.lock().unknown.extend(orders);
+                }
+                Err(e) => {
+                    warn!(post_id, error = %e, "REST order submission failed");
+                    self.handle_batch_conversion_failure(ActionBatch::Orders(orders))
+                        .await;
+                }
+            }
+        }
+    }
+
+    /// Resolve REST orders whose outcome was lost in transport.
+    ///
+    /// Each order is looked up by cloid in the account it was routed to:
+    /// known orders are confirmed with their exchange status, unknown
+    /// reduce-only orders queued again and unknown new orders dropped.
+    /// Orders whose lookup fails are kept for the next call.
+    pub async fn reconcile_rest_unknown(
+        &self,
+        client: &MetaClient,
+        user_address: &str,
+    ) -> ReconcileReport {
+        let mut report = ReconcileReport::default();
+        let orders = std::mem::take(&mut self.rest_submissions.lock().unknown);
+        let mut unresolved = Vec::new();
+        for order in orders {
+            let account = self.vault_router.account(order.strategy, user_address);
+            match client
+                .fetch_order_status(&account, order.cloid.as_ref())
+                .await
+            {
+                Ok(remote) => {
+                    let status = response_status(&order, remote.as_ref());
+                    report.record(self.resolve_unknown_order(order, status).await);
+                }
+                Err(e) => {
+                    warn!(cloid = %order.cloid, error = %e, "Order status lookup failed");
+                    report.unresolved += 1;
+                    unresolved.push(order);
+                }
+            }
+        }
+        self.rest_submissions.lock().unknown.extend(unresolved);
+
+        if report != ReconcileReport::default() {
+            info!(
+                confirmed = report.confirmed,
+                resubmitted = report.resubmitted,
+                expired = report.expired,
+                unresolved = report.unresolved,
+                "REST orders with unknown outcome reconciled"
+            );
+        }
+        report
+    }
+
+    /// Apply the exchange status of an order whose response was lost:
+    /// confirm it, or, if the exchange does not know it, queue a reduce-only
+    /// order again and drop a new one.
+    async fn resolve_unknown_order(
+        &self,
+        order: PendingOrder,
+        status: Option<OrderResponseStatus>,
+    ) -> ReconcileOutcome {
+        match status {
+            Some(status) => {
+                self.apply_order_status(&status, &order).await;
+                ReconcileOutcome::Confirmed
+            }
+            None if order.reduce_only => {
+                let _ = self.executor.batch_scheduler().enqueue_reduce_only(order);
+                ReconcileOutcome::Resubmitted
+            }
+            None => {
+                self.cleanup_dropped_orders(vec![order]).await;
+                ReconcileOutcome::Expired
+            }
+        }
+    }
+
     /// Convert an ActionBatch to a signable Action.
     ///
     /// Returns `Err(ExecutorError::MarketSpecNotFound)` if spec is not found
@@ -474,8 +1300,10 @@ impl ExecutorLoop {
                     action_type: "order".to_string(),
                     orders: Some(order_wires),
                     cancels: None,
+                    modifies: None,
                     grouping: Some("na".to_string()),
-                    builder: None,
+                    builder: self.builder.clone(),
+                    time: None,
                 })
             }
             ActionBatch::Cancels(cancels) => {
@@ -492,8 +1320,35 @@ impl ExecutorLoop {
                     action_type: "cancel".to_string(),
                     orders: None,
                     cancels: Some(cancel_wires),
+                    modifies: None,
+                    grouping: None,
+                    builder: None,
+                    time: None,
+                })
+            }
+            ActionBatch::Modifies(modifies) => {
+                let mut modify_wires = Vec::with_capacity(modifies.len());
+                for modify in modifies {
+                    let market = modify.market();
+                    let spec = self.spec_cache.get(&market).ok_or_else(|| {
+                        warn!(
+                            market = %market,
+                            oid = modify.oid,
+                            "MarketSpec not found, failing batch"
+                        );
+                        ExecutorError::MarketSpecNotFound(market)
+                    })?;
+                    modify_wires.push(ModifyWire::from_pending_modify(modify, &spec));
+                }
+
+                Ok(Action {
+                    action_type: "batchModify".to_string(),
+                    orders: None,
+                    cancels: None,
+                    modifies: Some(modify_wires),
                     grouping: None,
                     builder: None,
+                    time: None,
                 })
             }
         }
@@ -527,6 +1382,10 @@ impl ExecutorLoop {
                     let _ = self.executor.batch_scheduler().enqueue_cancel(cancel);
                 }
             }
+            ActionBatch::Modifies(modifies) => {
+                // Quotes may be stale by the next tick: fall back to cancel-replace
+                self.release_modifies(modifies).await;
+            }
         }
     }
 
@@ -563,6 +1422,9 @@ impl ExecutorLoop {
                     let _ = self.executor.batch_scheduler().enqueue_cancel(cancel);
                 }
             }
+            ActionBatch::Modifies(modifies) => {
+                self.release_modifies(modifies).await;
+            }
         }
     }
 
@@ -571,6 +1433,11 @@ impl ExecutorLoop {
     /// For orders: cleanup new_orders, requeue reduce_only (must be retried).
     /// For cancels: requeue all (idempotent operation).
     async fn handle_timeouts(&self, now_ms: u64) {
+        // Responses may be lost in the disconnect; reconciliation resolves them
+        if self.reconcile_on_reconnect && self.ws_sender.as_ref().is_some_and(|ws| !ws.is_ready()) {
+            return;
+        }
+
         let timed_out = self.post_request_manager.check_timeouts(now_ms);
 
         for (post_id, batch) in timed_out {
@@ -607,6 +1474,14 @@ impl ExecutorLoop {
                         let _ = self.executor.batch_scheduler().enqueue_cancel(cancel);
                     }
                 }
+                ActionBatch::Modifies(modifies) if self.lookup_timed_out_modifies => {
+                    // Outcome unknown: the amended order may already rest
+                    // under its new cloid, so it stays tracked until the lookup
+                    self.timed_out_modifies.lock().extend(modifies);
+                }
+                ActionBatch::Modifies(modifies) => {
+                    self.release_modifies(modifies).await;
+                }
             }
         }
     }
@@ -625,8 +1500,31 @@ impl ExecutorLoop {
         }
     }
 
+    /// Release amendments that were not applied.
+    ///
+    /// The amended orders are dropped from the position tracker and the
+    /// amendments handed back to the executor for cancel-replace fallback.
+    async fn release_modifies(&self, modifies: Vec<PendingModify>) {
+        for modify in modifies {
+            self.executor
+                .position_tracker()
+                .remove_order(modify.order.cloid.clone())
+                .await;
+            self.executor.reject_modify(modify);
+        }
+    }
+
+    /// Send time of a post awaiting its response (Unix milliseconds).
+    #[must_use]
+    pub fn post_sent_at(&self, post_id: u64) -> Option<u64> {
+        self.post_request_manager.sent_at(post_id)
+    }
+
     /// Complete a request with success.
     pub fn on_response_ok(&self, post_id: u64) {
+        if self.take_scheduled_cancel_response(post_id) {
+            return;
+        }
         self.post_request_manager.complete_ok(post_id);
         self.executor.batch_scheduler().on_batch_complete();
     }
@@ -640,107 +1538,63 @@ impl ExecutorLoop {
     ///
     /// This is critical for IOC orders that may fill immediately without
     /// a subsequent orderUpdate message from WebSocket.
+    ///
+    /// For amendments, an applied status also retires the original order and
+    /// an error hands the amendment back for cancel-replace fallback.
+    ///
+    /// Every action packs a whole batch (up to `max_*_per_batch` items), so
+    /// statuses are fanned out by position to the batch's orders, amendments
+    /// or cancels.
     pub async fn on_response_with_statuses(
         &self,
         post_id: u64,
         statuses: Vec<OrderResponseStatus>,
     ) {
         // Get the batch for this post_id to map statuses to orders
-        let batch = self.post_request_manager.get(post_id);
-
-        if let Some(ActionBatch::Orders(orders)) = batch {
-            // Process each status in order (1:1 mapping with orders)
-            for (status, order) in statuses.iter().zip(orders.iter()) {
-                let cloid = &order.cloid;
-
-                match status {
-                    OrderResponseStatus::Filled {
-                        oid,
-                        total_sz,
-                        avg_px,
-                    } => {
-                        // Order was immediately filled - update tracker
-                        debug!(
-                            cloid = %cloid,
-                            oid = oid,
-                            total_sz = %total_sz,
-                            avg_px = %avg_px,
-                            "Order immediately filled (from post response)"
-                        );
-
-                        // 1. Update ORDER state (terminal)
-                        self.executor
-                            .position_tracker()
-                            .order_update(cloid.clone(), OrderState::Filled, order.size, Some(*oid))
-                            .await;
-
-                        // 2. Update POSITION state directly (don't rely on userFills)
-                        // Parse fill size and price from response
-                        let fill_price = avg_px
-                            .parse::<rust_decimal::Decimal>()
-                            .map(Price::new)
-                            .unwrap_or(order.price);
-                        let fill_size = total_sz
-                            .parse::<rust_decimal::Decimal>()
-                            .map(Size::new)
-                            .unwrap_or(order.size);
-
-                        info!(
-                            cloid = %cloid,
-                            market = %order.market,
-                            side = ?order.side,
-                            fill_price = %fill_price,
-                            fill_size = %fill_size,
-                            "Position updated from post response fill"
-                        );
-
-                        self.executor
-                            .position_tracker()
-                            .fill(
-                                order.market,
-                                order.side,
-                                fill_price,
-                                fill_size,
-                                chrono::Utc::now().timestamp_millis() as u64,
-                                Some(cloid.clone()), // cloid for deduplication
-                                None, // entry_edge_bps (not available from post response)
-                            )
-                            .await;
-                    }
-                    OrderResponseStatus::Error { message } => {
-                        // Order was rejected - release pending
-                        warn!(
-                            cloid = %cloid,
-                            error = %message,
-                            "Order rejected (from post response)"
-                        );
-                        self.executor
-                            .position_tracker()
-                            .order_update(cloid.clone(), OrderState::Rejected, order.size, None)
-                            .await;
-                    }
-                    OrderResponseStatus::Resting { oid } => {
-                        // Order is on order book - wait for orderUpdate
-                        debug!(
-                            cloid = %cloid,
-                            oid = oid,
-                            "Order resting on book (from post response)"
-                        );
-                        // Record oid mapping for later use
+        match self.post_request_manager.get(post_id) {
+            Some(ActionBatch::Orders(orders)) => {
+                self.apply_order_statuses(&orders, &statuses).await;
+            }
+            Some(ActionBatch::Modifies(modifies)) => {
+                // 1:1 mapping with amendments
+                for (status, modify) in statuses.iter().zip(modifies) {
+                    self.record_order_outcome(status, &modify.order);
+                    let applied = self.apply_order_status(status, &modify.order).await;
+                    if applied {
                         self.executor
                             .position_tracker()
-                            .record_oid_mapping(cloid.clone(), *oid)
+                            .remove_order(modify.replaces)
                             .await;
+                    } else {
+                        self.executor.reject_modify(modify);
                     }
-                    OrderResponseStatus::Success => {
-                        // ALO order accepted - OID will arrive via orderUpdate
+                }
+            }
+            Some(ActionBatch::Cancels(cancels)) => {
+                // 1:1 mapping with cancels; the orderUpdate confirms the cancel
+                for (status, cancel) in statuses.iter().zip(cancels) {
+                    if let OrderResponseStatus::Error { message } = status {
                         debug!(
-                            cloid = %cloid,
-                            "ALO order accepted (OID pending via orderUpdate)"
+                            post_id,
+                            oid = cancel.oid,
+                            market = %cancel.market,
+                            reason = %message,
+                            "Cancel rejected (usually already filled or cancelled)"
                         );
+                        // Already cancelled or filled means the order is gone;
+                        // any other error may leave it resting on the exchange
+                        let benign =
+                            message.contains("already canceled") || message.contains("filled");
+                        if let Some(gate) = self.executor.open_order_gate().filter(|_| !benign) {
+                            gate.record_cancel_failure(
+                                &cancel.market,
+                                chrono::Utc::now().timestamp_millis() as u64,
+                            );
+                        }
                     }
                 }
             }
+            None => {}
         }
 
         // Complete the request as normal
@@ -748,12 +1602,623 @@ impl ExecutorLoop {
         self.executor.batch_scheduler().on_batch_complete();
     }
 
+    /// Apply the statuses of an order action (1:1 mapping with orders).
+    ///
+    /// Rejected orders go through the retry policy first.
+    async fn apply_order_statuses(
+        &self,
+        orders: &[PendingOrder],
+        statuses: &[OrderResponseStatus],
+    ) {
+        for (status, order) in statuses.iter().zip(orders) {
+            self.record_order_outcome(status, order);
+            if let OrderResponseStatus::Error { message } = status {
+                // Hedge orders are re-planned by the hedger, never retried
+                if !self.is_hedge_order(&order.cloid) && self.schedule_retry(order, message) {
+                    // Still pending: keep it tracked until the retry resolves
+                    continue;
+                }
+            } else if let Some(ref policy) = self.retry_policy {
+                policy.clear(&order.cloid);
+            }
+            self.apply_order_status(status, order).await;
+        }
+    }
+
+    /// Report an order's exchange accept/reject to the RejectBreaker.
+    fn record_order_outcome(&self, status: &OrderResponseStatus, order: &PendingOrder) {
+        let Some(breaker) = self.executor.reject_breaker() else {
+            return;
+        };
+        match status {
+            OrderResponseStatus::Error { message } => breaker.record_reject(
+                &order.market,
+                message,
+                chrono::Utc::now().timestamp_millis() as u64,
+            ),
+            _ => breaker.record_accept(&order.market),
+        }
+    }
+
+    /// Run a rejected order through the retry policy.
+    ///
+    /// Every rejected attempt is reported to the RiskMonitor. Returns true if
+    /// the order was scheduled for another attempt.
+    fn schedule_retry(&self, order: &PendingOrder, reason: &str) -> bool {
+        let Some(ref policy) = self.retry_policy else {
+            return false;
+        };
+
+        if let Some(ref tx) = self.risk_event_tx {
+            let event = ExecutionEvent::Rejected {
+                cloid: order.cloid.clone(),
+                reason: reason.to_string(),
+            };
+            if tx.try_send(event).is_err() {
+                warn!(cloid = %order.cloid, "Failed to send Rejected event to RiskMonitor");
+            }
+        }
+
+        let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+        match policy.on_reject(order, reason, now_ms) {
+            RetryDecision::Retry {
+                attempt,
+                backoff_ms,
+            } => {
+                info!(
+                    cloid = %order.cloid,
+                    market = %order.market,
+                    attempt,
+                    backoff_ms,
+                    reason,
+                    "Transient rejection, retry scheduled"
+                );
+                true
+            }
+            RetryDecision::GiveUp { class, retries } => {
+                if retries > 0 {
+                    warn!(
+                        cloid = %order.cloid,
+                        market = %order.market,
+                        retries,
+                        ?class,
+                        reason,
+                        "Order rejected after retries, giving up"
+                    );
+                }
+                false
+            }
+        }
+    }
+
+    /// Re-enqueue retried orders whose backoff has elapsed.
+    ///
+    /// The price is re-quantized against the current spec before the order
+    /// goes out again. New-order retries are dropped under HardStop.
+    async fn requeue_due_retries(&self, now_ms: u64) {
+        let Some(ref policy) = self.retry_policy else {
+            return;
+        };
+
+        for mut order in policy.take_due(now_ms) {
+            if !order.reduce_only && self.executor.hard_stop_latch().is_triggered() {
+                debug!(cloid = %order.cloid, "HardStop: dropping scheduled retry");
+                policy.clear(&order.cloid);
+                self.cleanup_dropped_orders(vec![order]).await;
+                continue;
+            }
+
+            if let Some(spec) = self.spec_cache.get(&order.market) {
+                order.price = spec.round_price_for_order(order.price, order.side == OrderSide::Buy);
+            }
+
+            let scheduler = self.executor.batch_scheduler();
+            let queued = if order.reduce_only {
+                scheduler.enqueue_reduce_only(order.clone()) != EnqueueResult::QueueFull
+            } else {
+                matches!(
+                    scheduler.enqueue_new_order(order.clone()),
+                    EnqueueResult::Queued | EnqueueResult::QueuedDegraded
+                )
+            };
+
+            if queued {
+                debug!(cloid = %order.cloid, price = %order.price, "Retry enqueued");
+            } else {
+                warn!(cloid = %order.cloid, "Retry dropped: queue full");
+                policy.clear(&order.cloid);
+                self.executor
+                    .position_tracker()
+                    .order_update(order.cloid.clone(), OrderState::Rejected, order.size, None)
+                    .await;
+            }
+        }
+    }
+
+    /// Apply one post response status to the order it belongs to.
+    ///
+    /// Returns false if the exchange rejected the order.
+    async fn apply_order_status(&self, status: &OrderResponseStatus, order: &PendingOrder) -> bool {
+        let cloid = &order.cloid;
+        if self.is_hedge_order(cloid) {
+            return self.apply_hedge_status(status, order);
+        }
+
+        match status {
+            OrderResponseStatus::Filled {
+                oid,
+                total_sz,
+                avg_px,
+            } => {
+                // Order was immediately filled - update tracker
+                debug!(
+                    cloid = %cloid,
+                    oid = oid,
+                    total_sz = %total_sz,
+                    avg_px = %avg_px,
+                    "Order immediately filled (from post response)"
+                );
+
+                // 1. Update ORDER state (terminal)
+                self.executor
+                    .position_tracker()
+                    .order_update(cloid.clone(), OrderState::Filled, order.size, Some(*oid))
+                    .await;
+
+                // 2. Update POSITION state directly (don't rely on userFills)
+                // Parse fill size and price from response
+                let fill_price = avg_px
+                    .parse::<rust_decimal::Decimal>()
+                    .map(Price::new)
+                    .unwrap_or(order.price);
+                let fill_size = total_sz
+                    .parse::<rust_decimal::Decimal>()
+                    .map(Size::new)
+                    .unwrap_or(order.size);
+
+                info!(
+                    cloid = %cloid,
+                    market = %order.market,
+                    side = ?order.side,
+                    fill_price = %fill_price,
+                    fill_size = %fill_size,
+                    "Position updated from post response fill"
+                );
+
+                // totalSz is cumulative: userFills of the same order are not applied twice
+                self.executor
+                    .position_tracker()
+                    .order_filled(
+                        order.market,
+                        order.side,
+                        fill_price,
+                        fill_size,
+                        chrono::Utc::now().timestamp_millis() as u64,
+                        cloid.clone(),
+                    )
+                    .await;
+                true
+            }
+            OrderResponseStatus::Error { message } => {
+                // Order was rejected - release pending
+                warn!(
+                    cloid = %cloid,
+                    error = %message,
+                    "Order rejected (from post response)"
+                );
+                self.executor
+                    .position_tracker()
+                    .order_update(cloid.clone(), OrderState::Rejected, order.size, None)
+                    .await;
+                false
+            }
+            OrderResponseStatus::Resting { oid } => {
+                // Order is on order book - wait for orderUpdate
+                debug!(
+                    cloid = %cloid,
+                    oid = oid,
+                    "Order resting on book (from post response)"
+                );
+                // Record oid mapping for later use
+                self.executor
+                    .position_tracker()
+                    .record_oid_mapping(cloid.clone(), *oid)
+                    .await;
+                true
+            }
+            OrderResponseStatus::Success => {
+                // ALO order accepted - OID will arrive via orderUpdate
+                debug!(
+                    cloid = %cloid,
+                    "ALO order accepted (OID pending via orderUpdate)"
+                );
+                true
+            }
+        }
+    }
+
+    /// Whether an order was placed by the hedger.
+    fn is_hedge_order(&self, cloid: &ClientOrderId) -> bool {
+        self.hedger
+            .as_ref()
+            .is_some_and(|hedger| hedger.is_hedge_order(cloid))
+    }
+
+    /// Apply a post response status to a hedge order.
+    ///
+    /// Hedge orders are IOC: any status other than a fill ends the order.
+    /// Fills are booked from `userFills`, which also releases the order.
+    fn apply_hedge_status(&self, status: &OrderResponseStatus, order: &PendingOrder) -> bool {
+        let Some(ref hedger) = self.hedger else {
+            return false;
+        };
+        match status {
+            OrderResponseStatus::Filled { total_sz, .. } => {
+                debug!(cloid = %order.cloid, market = %order.market, total_sz = %total_sz,
+                    "Hedge order filled (booked from userFills)");
+                true
+            }
+            OrderResponseStatus::Error { message } => {
+                warn!(cloid = %order.cloid, market = %order.market, error = %message,
+                    "Hedge order rejected");
+                hedger.on_order_done(&order.cloid);
+                false
+            }
+            OrderResponseStatus::Resting { .. } | OrderResponseStatus::Success => {
+                hedger.on_order_done(&order.cloid);
+                true
+            }
+        }
+    }
+
     /// Complete a request with rejection.
+    ///
+    /// Rejected amendments are handed back for cancel-replace fallback; the
+    /// amended orders are released from the position tracker in the
+    /// background. A nonce rejection resyncs the nonce manager so the next
+    /// action is not rejected for the same reason.
     pub fn on_response_rejected(&self, post_id: u64, reason: String) {
+        if is_nonce_error(&reason) {
+            let nonce = self.nonce_manager.resync();
+            warn!(post_id, nonce, reason = %reason, "Nonce rejected, resynced nonce manager");
+        }
+        if self.take_scheduled_cancel_response(post_id) {
+            warn!(post_id, reason = %reason, "Scheduled cancel rejected");
+            return;
+        }
+        if let Some(ActionBatch::Modifies(modifies)) = self.post_request_manager.get(post_id) {
+            let tracker = self.executor.position_tracker().clone();
+            let cloids: Vec<_> = modifies.iter().map(|m| m.order.cloid.clone()).collect();
+            tokio::spawn(async move {
+                for cloid in cloids {
+                    tracker.remove_order(cloid).await;
+                }
+            });
+            for modify in modifies {
+                self.executor.reject_modify(modify);
+            }
+        }
         self.post_request_manager.complete_rejected(post_id, reason);
         self.executor.batch_scheduler().on_batch_complete();
     }
 
+    /// Resolve sent requests whose responses may have been lost.
+    ///
+    /// Called on the READY transition after a reconnect. Each order is looked
+    /// up by cloid in the account it was routed to (`user_address` for the
+    /// personal account): known orders are confirmed with their exchange
+    /// status, unknown reduce-only orders and cancels are queued again, and
+    /// unknown new orders are dropped. Amendments are resolved like
+    /// timed-out ones (see [`Self::reconcile_timed_out_modifies`]). Order
+    /// requests whose lookup fails stay pending for the regular timeout.
+    pub async fn reconcile_inflight(
+        &self,
+        client: &MetaClient,
+        user_address: &str,
+    ) -> ReconcileReport {
+        let mut report = ReconcileReport::default();
+
+        for post_id in self.post_request_manager.sent_post_ids() {
+            let Some(batch) = self.post_request_manager.get(post_id) else {
+                continue;
+            };
+
+            // Look up every order before touching any state
+            let mut statuses = Vec::new();
+            if let ActionBatch::Orders(ref orders) = batch {
+                let mut failed = false;
+                for order in orders {
+                    let account = self.vault_router.account(order.strategy, user_address);
+                    match client
+                        .fetch_order_status(&account, order.cloid.as_ref())
+                        .await
+                    {
+                        Ok(remote) => statuses.push(response_status(order, remote.as_ref())),
+                        Err(e) => {
+                            warn!(post_id, cloid = %order.cloid, error = %e, "Order status lookup failed");
+                            failed = true;
+                            break;
+                        }
+                    }
+                }
+                if failed {
+                    report.unresolved += 1;
+                    continue;
+                }
+            }
+
+            // A response may have arrived during the lookups
+            if self.post_request_manager.remove(post_id).is_none() {
+                continue;
+            }
+            self.executor.batch_scheduler().on_batch_complete();
+
+            match batch {
+                ActionBatch::Orders(orders) => {
+                    for (order, status) in orders.into_iter().zip(statuses) {
+                        report.record(self.resolve_unknown_order(order, status).await);
+                    }
+                }
+                ActionBatch::Cancels(cancels) => {
+                    // Idempotent: a cancel of an already-cancelled order is harmless
+                    for cancel in cancels {
+                        let _ = self.executor.batch_scheduler().enqueue_cancel(cancel);
+                        report.record(ReconcileOutcome::Resubmitted);
+                    }
+                }
+                ActionBatch::Modifies(modifies) => {
+                    self.timed_out_modifies.lock().extend(modifies);
+                }
+            }
+        }
+        self.resolve_timed_out_modifies(client, user_address, &mut report)
+            .await;
+
+        if report != ReconcileReport::default() {
+            info!(
+                confirmed = report.confirmed,
+                resubmitted = report.resubmitted,
+                expired = report.expired,
+                unresolved = report.unresolved,
+                "Inflight posts reconciled after reconnect"
+            );
+        }
+        report
+    }
+
+    /// Resolve amendments whose batchModify timed out.
+    ///
+    /// Each amended order is looked up by its new cloid: if the exchange
+    /// knows it, the amendment went through and the original order is
+    /// retired; if not, the amendment is released for cancel-replace
+    /// fallback. Amendments whose lookup fails are kept for the next call.
+    pub async fn reconcile_timed_out_modifies(
+        &self,
+        client: &MetaClient,
+        user_address: &str,
+    ) -> ReconcileReport {
+        let mut report = ReconcileReport::default();
+        self.resolve_timed_out_modifies(client, user_address, &mut report)
+            .await;
+        if report != ReconcileReport::default() {
+            info!(
+                confirmed = report.confirmed,
+                expired = report.expired,
+                unresolved = report.unresolved,
+                "Timed-out amendments reconciled"
+            );
+        }
+        report
+    }
+
+    async fn resolve_timed_out_modifies(
+        &self,
+        client: &MetaClient,
+        user_address: &str,
+        report: &mut ReconcileReport,
+    ) {
+        let modifies = std::mem::take(&mut *self.timed_out_modifies.lock());
+        let mut unresolved = Vec::new();
+        for modify in modifies {
+            let account = self
+                .vault_router
+                .account(modify.order.strategy, user_address);
+            match client
+                .fetch_order_status(&account, modify.order.cloid.as_ref())
+                .await
+            {
+                Ok(Some(remote)) => {
+                    if let Some(status) = response_status(&modify.order, Some(&remote)) {
+                        self.apply_order_status(&status, &modify.order).await;
+                    }
+                    self.executor
+                        .position_tracker()
+                        .remove_order(modify.replaces)
+                        .await;
+                    report.record(ReconcileOutcome::Confirmed);
+                }
+                Ok(None) => {
+                    self.release_modifies(vec![modify]).await;
+                    report.record(ReconcileOutcome::Expired);
+                }
+                Err(e) => {
+                    warn!(cloid = %modify.order.cloid, error = %e, "Amended order status lookup failed");
+                    report.unresolved += 1;
+                    unresolved.push(modify);
+                }
+            }
+        }
+        self.timed_out_modifies.lock().extend(unresolved);
+    }
+
+    /// Resolve orders pending longer than the sweep timeout.
+    ///
+    /// Orders still queued, in flight or waiting for a retry are left to
+    /// their own lifecycle. For the rest the exchange's order status (by
+    /// cloid, on the order's routed account) is applied to the position
+    /// tracker; see [`crate::order_sweep`]. Does nothing unless armed with
+    /// [`Self::set_order_sweep`].
+    pub async fn sweep_pending_orders(
+        &self,
+        client: &MetaClient,
+        user_address: &str,
+        now_ms: u64,
+    ) -> SweepReport {
+        let mut report = SweepReport::default();
+        let Some(ref sweeper) = self.order_sweeper else {
+            return report;
+        };
+        let tracker = self.executor.position_tracker();
+
+        let cloids: Vec<ClientOrderId> = tracker
+            .pending_orders_snapshot_iter()
+            .map(|entry| entry.key().clone())
+            .collect();
+        let pending: Vec<(ClientOrderId, u64)> = cloids
+            .into_iter()
+            .filter(|cloid| !self.is_order_in_lifecycle(cloid))
+            .filter_map(|cloid| {
+                let created_at = tracker.get_pending_order(&cloid)?.created_at;
+                Some((cloid, created_at))
+            })
+            .collect();
+
+        for cloid in sweeper.due(pending, now_ms) {
+            let Some(order) = tracker.get_pending_order(&cloid).map(|o| o.clone()) else {
+                continue;
+            };
+            let account = self.vault_router.account(order.strategy, user_address);
+            let remote = match client.fetch_order_status(&account, cloid.as_ref()).await {
+                Ok(remote) => remote,
+                Err(e) => {
+                    warn!(cloid = %cloid, error = %e, "Order status lookup failed");
+                    report.unresolved += 1;
+                    continue;
+                }
+            };
+
+            // Resolved or picked up again during the lookup
+            if tracker.get_pending_order(&cloid).is_none() || self.is_order_in_lifecycle(&cloid) {
+                continue;
+            }
+            let pending = PendingOrder::with_tif(
+                cloid.clone(),
+                order.market,
+                order.side,
+                order.price,
+                order.size,
+                order.reduce_only,
+                order.created_at,
+                order.tif,
+            )
+            .with_strategy(order.strategy);
+            match remote {
+                None => {
+                    warn!(cloid = %cloid, market = %order.market, "Stale pending order unknown to exchange, dropping");
+                    self.cleanup_dropped_orders(vec![pending]).await;
+                    report.expired += 1;
+                }
+                Some(remote) if matches!(remote.status.as_str(), "open" | "triggered") => {
+                    tracker.record_oid_mapping(cloid.clone(), remote.oid).await;
+                    sweeper.on_open(cloid, now_ms);
+                    report.open += 1;
+                }
+                Some(remote) => {
+                    info!(
+                        cloid = %cloid,
+                        market = %order.market,
+                        status = %remote.status,
+                        "Stale pending order resolved from exchange status"
+                    );
+                    self.apply_swept_status(&pending, &remote).await;
+                    report.resolved += 1;
+                }
+            }
+        }
+
+        if report != SweepReport::default() {
+            info!(
+                open = report.open,
+                resolved = report.resolved,
+                expired = report.expired,
+                unresolved = report.unresolved,
+                "Pending orders swept"
+            );
+        }
+        report
+    }
+
+    /// Whether an order is queued, in flight or waiting for a retry.
+    fn is_order_in_lifecycle(&self, cloid: &ClientOrderId) -> bool {
+        self.executor.batch_scheduler().is_queued(cloid)
+            || self.post_request_manager.has_order(cloid)
+            || self.rest_submissions.lock().has_order(cloid)
+            || self
+                .timed_out_modifies
+                .lock()
+                .iter()
+                .any(|m| m.order.cloid == *cloid)
+            || self
+                .retry_policy
+                .as_ref()
+                .is_some_and(|policy| policy.is_scheduled(cloid))
+    }
+
+    /// Apply a terminal exchange status to a swept order.
+    async fn apply_swept_status(&self, order: &PendingOrder, remote: &OrderStatusInfo) {
+        if remote.status == "filled" {
+            if let Some(status) = response_status(order, Some(remote)) {
+                self.apply_order_status(&status, order).await;
+            }
+            return;
+        }
+
+        // Cancelled or rejected, possibly after a partial fill
+        let filled = match (
+            remote.orig_sz.parse::<rust_decimal::Decimal>(),
+            remote.sz.parse::<rust_decimal::Decimal>(),
+        ) {
+            (Ok(orig), Ok(remaining)) => Size::new(orig - remaining),
+            _ => Size::ZERO,
+        };
+        let tracker = self.executor.position_tracker();
+        if !filled.is_zero() {
+            let fill_price = remote
+                .limit_px
+                .parse::<rust_decimal::Decimal>()
+                .map(Price::new)
+                .unwrap_or(order.price);
+            tracker
+                .order_filled(
+                    order.market,
+                    order.side,
+                    fill_price,
+                    filled,
+                    chrono::Utc::now().timestamp_millis() as u64,
+                    order.cloid.clone(),
+                )
+                .await;
+        }
+        let state = if remote.status.to_lowercase().ends_with("rejected") {
+            OrderState::Rejected
+        } else {
+            OrderState::Cancelled
+        };
+        tracker
+            .order_update(order.cloid.clone(), state, filled, Some(remote.oid))
+            .await;
+    }
+
+    /// Consume the response to a scheduled-cancel refresh.
+    ///
+    /// Refreshes are not batches, so they must not touch the scheduler's
+    /// inflight accounting.
+    fn take_scheduled_cancel_response(&self, post_id: u64) -> bool {
+        self.scheduled_cancel
+            .as_ref()
+            .is_some_and(|schedule| schedule.take_response(post_id))
+    }
+
     /// Get the post request manager for testing.
     #[must_use]
     pub fn post_request_manager(&self) -> &PostRequestManager {
@@ -773,6 +2238,44 @@ impl ExecutorLoop {
     }
 }
 
+/// Audit log label of a WS send result.
+fn send_outcome(result: &SendResult) -> String {
+    match result {
+        SendResult::Sent => "sent".to_string(),
+        SendResult::RateLimited => "rate_limited".to_string(),
+        SendResult::Disconnected => "disconnected".to_string(),
+        SendResult::Error(e) => format!("error: {e}"),
+    }
+}
+
+/// Key purpose a batch is signed with.
+///
+/// Batches of resting orders (ALO/GTC) and amendments are maker actions;
+/// IOC orders, mixed batches and cancels are signed with the taker key.
+fn key_purpose(batch: &ActionBatch) -> KeyPurpose {
+    match batch {
+        ActionBatch::Orders(orders)
+            if !orders.is_empty()
+                && orders
+                    .iter()
+                    .all(|o| o.tif != TimeInForce::ImmediateOrCancel) =>
+        {
+            KeyPurpose::Maker
+        }
+        ActionBatch::Modifies(_) => KeyPurpose::Maker,
+        _ => KeyPurpose::Taker,
+    }
+}
+
+/// Queued order dropped at dequeue because its signal went stale.
+#[derive(Debug, Clone)]
+pub struct StaleSignalDrop {
+    /// The dropped order.
+    pub order: PendingOrder,
+    /// `"ttl"` or `"edge_gone"` (see [`Executor::signal_staleness`]).
+    pub reason: &'static str,
+}
+
 /// Helper struct for tracking dropped orders during cleanup.
 #[derive(Debug)]
 pub struct DroppedOrder {
@@ -785,7 +2288,16 @@ pub struct DroppedOrder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use hip3_core::{AssetId, DexId, OrderSide, PendingCancel, Price, Size};
+    use crate::batch::{BatchConfig, BatchScheduler, InflightTracker};
+    use crate::executor::{ActionBudget, ExecutorConfig, MarketStateCache};
+    use crate::ready::TradingReadyChecker;
+    use crate::risk::HardStopLatch;
+    use crate::signer::KeyManager;
+    use hip3_core::{
+        AssetId, DexId, MarketSpec, OrderSide, PendingCancel, Price, SignalValidity, Size,
+        TrackedOrder,
+    };
+    use hip3_position::spawn_position_tracker;
     use rust_decimal_macros::dec;
 
     fn sample_market() -> MarketKey {
@@ -916,6 +2428,35 @@ mod tests {
         assert_eq!(timed_out.len(), 1);
     }
 
+    #[test]
+    fn test_post_request_manager_sent_post_ids() {
+        let manager = PostRequestManager::new(5000);
+
+        let (first, _rx1) =
+            manager.create_request(ActionBatch::Orders(vec![sample_pending_order(false)]), 1000);
+        let (second, _rx2) =
+            manager.create_request(ActionBatch::Cancels(vec![sample_pending_cancel()]), 1000);
+        assert!(manager.sent_post_ids().is_empty());
+
+        manager.mark_sent(second, 1000);
+        manager.mark_sent(first, 1000);
+        assert_eq!(manager.sent_post_ids(), vec![first, second]);
+    }
+
+    #[test]
+    fn test_post_request_manager_has_order() {
+        let manager = PostRequestManager::new(5000);
+        let order = sample_pending_order(false);
+        let cloid = order.cloid.clone();
+
+        let (post_id, _rx) = manager.create_request(ActionBatch::Orders(vec![order]), 1000);
+        assert!(manager.has_order(&cloid));
+        assert!(!manager.has_order(&ClientOrderId::new()));
+
+        manager.remove(post_id);
+        assert!(!manager.has_order(&cloid));
+    }
+
     #[test]
     fn test_post_request_manager_cancel_all() {
         let manager = PostRequestManager::new(5000);
@@ -950,4 +2491,141 @@ mod tests {
         let non_existent = manager.get(999);
         assert!(non_existent.is_none());
     }
+
+    #[test]
+    fn test_key_purpose() {
+        let mut resting = sample_pending_order(false);
+        resting.tif = TimeInForce::AddLiquidityOnly;
+
+        assert_eq!(
+            key_purpose(&ActionBatch::Orders(vec![resting.clone()])),
+            KeyPurpose::Maker
+        );
+        assert_eq!(
+            key_purpose(&ActionBatch::Orders(vec![
+                resting,
+                sample_pending_order(false)
+            ])),
+            KeyPurpose::Taker
+        );
+        assert_eq!(
+            key_purpose(&ActionBatch::Cancels(vec![sample_pending_cancel()])),
+            KeyPurpose::Taker
+        );
+    }
+
+    /// Executor loop without a WS sender (sends are simulated). The spec of
+    /// `sample_market()` is cached, so its orders build and go out.
+    fn sample_executor_loop() -> ExecutorLoop {
+        let (position_tracker, _join) = spawn_position_tracker(100);
+        let hard_stop = Arc::new(HardStopLatch::new());
+        let batch_scheduler = Arc::new(BatchScheduler::new(
+            BatchConfig::default(),
+            Arc::new(InflightTracker::new(100)),
+            hard_stop.clone(),
+        ));
+        let (ready_checker, _rx) = TradingReadyChecker::new();
+        let executor = Arc::new(Executor::new(
+            position_tracker,
+            batch_scheduler,
+            Arc::new(ready_checker),
+            hard_stop,
+            Arc::new(ActionBudget::default()),
+            ExecutorConfig::default(),
+            Arc::new(MarketStateCache::new()),
+        ));
+        let signer = Signer::new(Arc::new(KeyManager::ephemeral()), false).unwrap();
+        let spec_cache = SpecCache::default();
+        spec_cache
+            .update(sample_market(), MarketSpec::default())
+            .unwrap();
+        ExecutorLoop::new(
+            executor,
+            Arc::new(NonceManager::new(SystemClock)),
+            Arc::new(signer),
+            5000,
+            Arc::new(spec_cache),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_tick_drops_order_whose_ttl_ran_out_in_queue() {
+        let executor_loop = sample_executor_loop();
+        let position_tracker = executor_loop.executor().position_tracker();
+        let batch_scheduler = executor_loop.executor().batch_scheduler();
+
+        // Signal still valid when enqueued, expired by the time the loop ticks
+        let order = sample_pending_order(false).with_validity(Some(SignalValidity {
+            expires_at_ms: 1_000,
+            min_edge_bps: None,
+        }));
+        position_tracker
+            .register_order(TrackedOrder::from_pending(order.clone()))
+            .await;
+        batch_scheduler.enqueue_new_order(order);
+        assert_eq!(position_tracker.pending_order_count(), 1);
+
+        assert_eq!(executor_loop.tick(2_000).await, None);
+        assert_eq!(position_tracker.pending_order_count(), 0);
+        assert!(batch_scheduler.tick().is_none());
+
+        let drops = executor_loop.take_stale_signal_drops();
+        assert_eq!(drops.len(), 1);
+        assert_eq!(drops[0].reason, "ttl");
+        assert!(executor_loop.take_stale_signal_drops().is_empty());
+    }
+
+    /// Send a batchModify at t=0 that never gets a response.
+    async fn send_unanswered_modify(executor_loop: &ExecutorLoop) -> ClientOrderId {
+        let modify = PendingModify::new(7, ClientOrderId::new(), sample_pending_order(false));
+        let amended = modify.order.cloid.clone();
+        executor_loop
+            .executor()
+            .position_tracker()
+            .register_order(TrackedOrder::from_pending(modify.order.clone()))
+            .await;
+        let (post_id, _rx) = executor_loop
+            .post_request_manager
+            .create_request(ActionBatch::Modifies(vec![modify]), 0);
+        executor_loop.post_request_manager.mark_sent(post_id, 0);
+        executor_loop.executor().batch_scheduler().on_batch_sent();
+        amended
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_modify_held_for_lookup() {
+        let mut executor_loop = sample_executor_loop();
+        executor_loop.set_lookup_timed_out_modifies(true);
+        let amended = send_unanswered_modify(&executor_loop).await;
+
+        // The amended order may rest: it stays tracked, no fallback cancel yet
+        executor_loop.tick(10_000).await;
+        assert!(executor_loop.executor().take_rejected_modifies().is_empty());
+        assert_eq!(
+            executor_loop
+                .executor()
+                .position_tracker()
+                .pending_order_count(),
+            1
+        );
+        assert!(executor_loop.is_order_in_lifecycle(&amended));
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_modify_released_without_lookup() {
+        let executor_loop = sample_executor_loop();
+        let amended = send_unanswered_modify(&executor_loop).await;
+
+        executor_loop.tick(10_000).await;
+        let released = executor_loop.executor().take_rejected_modifies();
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].order.cloid, amended);
+        assert_eq!(
+            executor_loop
+                .executor()
+                .position_tracker()
+                .pending_order_count(),
+            0
+        );
+    }
 }
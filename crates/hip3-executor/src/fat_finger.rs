@@ -0,0 +1,189 @@
+//! Fat-finger sanity gate of single orders.
+//!
+//! The last check before signing: every new order or amendment whose
+//! notional exceeds an absolute cap, or whose limit price is further from
+//! the mark (oracle when no mark is cached) than an absolute bound, is
+//! dropped. The bounds are absolute on purpose and independent of the
+//! sizing and risk limits elsewhere, so a sizing bug or a mistyped limit
+//! cannot get an absurd order out.
+//!
+//! Reduce-only orders are never dropped: exits must go out, and they cannot
+//! grow exposure.
+
+use hip3_core::{PendingOrder, Price};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the fat-finger sanity gate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FatFingerConfig {
+    /// Whether orders are checked before submission. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Maximum notional of a single order (USD). 0 = no cap.
+    /// Default: 10,000.
+    #[serde(default = "default_max_order_notional_usd")]
+    pub max_order_notional_usd: Decimal,
+    /// Maximum distance of the limit price from the mark, in either
+    /// direction (bps). 0 = no bound. Default: 1,000.
+    #[serde(default = "default_max_price_deviation_bps")]
+    pub max_price_deviation_bps: Decimal,
+}
+
+fn default_max_order_notional_usd() -> Decimal {
+    Decimal::from(10_000)
+}
+
+fn default_max_price_deviation_bps() -> Decimal {
+    Decimal::from(1_000)
+}
+
+impl Default for FatFingerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_order_notional_usd: default_max_order_notional_usd(),
+            max_price_deviation_bps: default_max_price_deviation_bps(),
+        }
+    }
+}
+
+/// Bound an order broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FatFingerBreach {
+    /// Order notional (size × limit price) over the cap.
+    Notional {
+        /// Order notional (USD).
+        notional: Decimal,
+        /// Cap (USD).
+        max_notional: Decimal,
+    },
+    /// Limit price too far from the reference price.
+    PriceDeviation {
+        /// Distance from the reference (bps, absolute).
+        deviation_bps: Decimal,
+        /// Bound (bps).
+        max_deviation_bps: Decimal,
+        /// Reference price the deviation was measured against.
+        reference_px: Price,
+    },
+}
+
+impl FatFingerBreach {
+    /// Short label of the broken bound.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Notional { .. } => "notional",
+            Self::PriceDeviation { .. } => "price_deviation",
+        }
+    }
+}
+
+/// Absolute per-order notional and price deviation check.
+#[derive(Debug, Clone)]
+pub struct FatFingerGuard {
+    config: FatFingerConfig,
+}
+
+impl FatFingerGuard {
+    /// Create a guard from config.
+    #[must_use]
+    pub fn new(config: FatFingerConfig) -> Self {
+        Self { config }
+    }
+
+    /// Whether the order is subject to the check.
+    #[must_use]
+    pub fn applies_to(order: &PendingOrder) -> bool {
+        !order.reduce_only
+    }
+
+    /// Check an order against the bounds.
+    ///
+    /// The price bound is skipped when no reference price is known; the
+    /// notional cap always applies. Returns None if the order is sane.
+    #[must_use]
+    pub fn check(
+        &self,
+        order: &PendingOrder,
+        reference_px: Option<Price>,
+    ) -> Option<FatFingerBreach> {
+        let max_notional = self.config.max_order_notional_usd;
+        let notional = order.size.inner() * order.price.inner();
+        if max_notional > Decimal::ZERO && notional > max_notional {
+            return Some(FatFingerBreach::Notional {
+                notional,
+                max_notional,
+            });
+        }
+
+        let max_deviation_bps = self.config.max_price_deviation_bps;
+        let reference_px = reference_px.filter(|px| !px.inner().is_zero())?;
+        if max_deviation_bps <= Decimal::ZERO {
+            return None;
+        }
+        let reference = reference_px.inner();
+        let deviation_bps =
+            (order.price.inner() - reference).abs() / reference * Decimal::from(10_000);
+        (deviation_bps > max_deviation_bps).then_some(FatFingerBreach::PriceDeviation {
+            deviation_bps,
+            max_deviation_bps,
+            reference_px,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, ClientOrderId, DexId, MarketKey, OrderSide, Size};
+    use rust_decimal_macros::dec;
+
+    fn order(px: Decimal, size: Decimal, reduce_only: bool) -> PendingOrder {
+        PendingOrder::new(
+            ClientOrderId::new(),
+            MarketKey::new(DexId::XYZ, AssetId::new(0)),
+            OrderSide::Buy,
+            Price::new(px),
+            Size::new(size),
+            reduce_only,
+            0,
+        )
+    }
+
+    #[test]
+    fn test_notional_cap_and_price_deviation() {
+        let guard = FatFingerGuard::new(FatFingerConfig {
+            enabled: true,
+            max_order_notional_usd: dec!(1000),
+            max_price_deviation_bps: dec!(500),
+        });
+        let mark = Some(Price::new(dec!(100)));
+
+        assert!(guard
+            .check(&order(dec!(101), dec!(9), false), mark)
+            .is_none());
+        assert_eq!(
+            guard.check(&order(dec!(100), dec!(11), false), mark),
+            Some(FatFingerBreach::Notional {
+                notional: dec!(1100),
+                max_notional: dec!(1000),
+            })
+        );
+
+        // 6% below the mark is as suspicious as 6% above
+        let breach = guard.check(&order(dec!(94), dec!(1), false), mark).unwrap();
+        assert_eq!(breach.as_str(), "price_deviation");
+        // No reference: only the notional cap applies
+        assert!(guard
+            .check(&order(dec!(94), dec!(1), false), None)
+            .is_none());
+
+        assert!(!FatFingerGuard::applies_to(&order(
+            dec!(100),
+            dec!(50),
+            true
+        )));
+    }
+}
@@ -0,0 +1,246 @@
+//! Auto-recovery of hard stops whose condition has cleared.
+//!
+//! Some hard stops (rejection bursts, slippage streaks, position drift) are
+//! caused by conditions that go away on their own, yet the latch keeps
+//! trading halted until an operator resets it. [`HardStopRecovery`] clears
+//! such a latch once it has been triggered for `cool_off_secs` and the bot
+//! has then passed `healthy_checks` consecutive health checks.
+//!
+//! Only reasons listed in `reasons` that also allow it
+//! ([`HardStopReason::may_auto_recover`]) recover; a latch hit by any other
+//! reason, e.g. a loss limit, stays latched until a manual reset.
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::risk::{HardStopLatch, HardStopReason};
+
+/// Configuration for hard stop auto-recovery.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HardStopRecoveryConfig {
+    /// Enable auto-recovery. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Reasons that may self-clear. Reasons that never auto-recover
+    /// (losses, flatten failures, manual) are ignored.
+    /// Default: rejections, slippage, position_drift.
+    #[serde(default = "default_reasons")]
+    pub reasons: Vec<HardStopReason>,
+    /// Minimum time since the trigger before health checks count (seconds).
+    /// Default: 300.
+    #[serde(default = "default_cool_off_secs")]
+    pub cool_off_secs: u64,
+    /// Consecutive healthy checks after the cool-off needed to clear.
+    /// Default: 6.
+    #[serde(default = "default_healthy_checks")]
+    pub healthy_checks: u32,
+    /// Health check interval (seconds). Default: 10.
+    #[serde(default = "default_check_interval_secs")]
+    pub check_interval_secs: u64,
+}
+
+fn default_reasons() -> Vec<HardStopReason> {
+    vec![
+        HardStopReason::Rejections,
+        HardStopReason::Slippage,
+        HardStopReason::PositionDrift,
+    ]
+}
+
+fn default_cool_off_secs() -> u64 {
+    300
+}
+
+fn default_healthy_checks() -> u32 {
+    6
+}
+
+fn default_check_interval_secs() -> u64 {
+    10
+}
+
+impl Default for HardStopRecoveryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            reasons: default_reasons(),
+            cool_off_secs: default_cool_off_secs(),
+            healthy_checks: default_healthy_checks(),
+            check_interval_secs: default_check_interval_secs(),
+        }
+    }
+}
+
+/// Clears a recoverable hard stop after a cool-off and a run of healthy
+/// checks.
+#[derive(Debug)]
+pub struct HardStopRecovery {
+    config: HardStopRecoveryConfig,
+    /// Reasons that may self-clear (config filtered by `may_auto_recover`).
+    reasons: Vec<HardStopReason>,
+    /// Consecutive healthy checks since the cool-off ended.
+    healthy_streak: Mutex<u32>,
+}
+
+impl HardStopRecovery {
+    /// Create from config, dropping reasons that never auto-recover.
+    #[must_use]
+    pub fn new(config: HardStopRecoveryConfig) -> Self {
+        let mut reasons = Vec::new();
+        for &reason in &config.reasons {
+            if !reason.may_auto_recover() {
+                warn!(%reason, "HardStop reason never auto-recovers, ignored");
+            } else if !reasons.contains(&reason) {
+                reasons.push(reason);
+            }
+        }
+        Self {
+            config,
+            reasons,
+            healthy_streak: Mutex::new(0),
+        }
+    }
+
+    /// Check if auto-recovery is enabled.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled && !self.reasons.is_empty()
+    }
+
+    /// Recovery configuration.
+    #[must_use]
+    pub fn config(&self) -> &HardStopRecoveryConfig {
+        &self.config
+    }
+
+    /// Reasons that may self-clear.
+    #[must_use]
+    pub fn reasons(&self) -> &[HardStopReason] {
+        &self.reasons
+    }
+
+    /// Run one health check against `latch`.
+    ///
+    /// `healthy` is the caller's verdict on the conditions behind the
+    /// recoverable reasons. Returns the cleared reasons when this check
+    /// clears the latch.
+    pub fn check(&self, latch: &HardStopLatch, healthy: bool) -> Option<Vec<HardStopReason>> {
+        let mut streak = self.healthy_streak.lock();
+        if !self.is_enabled() || !latch.is_triggered() {
+            *streak = 0;
+            return None;
+        }
+
+        let triggered = latch.reasons();
+        if !triggered.iter().all(|r| self.reasons.contains(r)) {
+            *streak = 0;
+            return None;
+        }
+        let cool_off_secs = self.config.cool_off_secs;
+        if latch
+            .elapsed_since_trigger()
+            .map_or(true, |elapsed| elapsed.as_secs() < cool_off_secs)
+        {
+            *streak = 0;
+            return None;
+        }
+        if !healthy {
+            if *streak > 0 {
+                debug!(
+                    streak = *streak,
+                    "HardStop recovery: unhealthy check, streak reset"
+                );
+            }
+            *streak = 0;
+            return None;
+        }
+
+        *streak += 1;
+        debug!(
+            streak = *streak,
+            required = self.config.healthy_checks,
+            reasons = ?triggered,
+            "HardStop recovery: healthy check"
+        );
+        if *streak < self.config.healthy_checks {
+            return None;
+        }
+
+        *streak = 0;
+        latch.try_auto_reset(&self.reasons)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risk::HardStopLevel;
+
+    fn recovery(healthy_checks: u32) -> HardStopRecovery {
+        HardStopRecovery::new(HardStopRecoveryConfig {
+            enabled: true,
+            reasons: vec![
+                HardStopReason::PositionDrift,
+                HardStopReason::CumulativeLoss,
+            ],
+            cool_off_secs: 0,
+            healthy_checks,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_recovers_after_consecutive_healthy_checks() {
+        let recovery = recovery(3);
+        // Loss reasons are dropped from the config
+        assert_eq!(recovery.reasons(), &[HardStopReason::PositionDrift]);
+
+        let latch = HardStopLatch::new();
+        latch.trigger_with(
+            HardStopLevel::FullStop,
+            HardStopReason::PositionDrift,
+            "drift",
+        );
+
+        assert!(recovery.check(&latch, true).is_none());
+        assert!(recovery.check(&latch, true).is_none());
+        // Unhealthy check restarts the count
+        assert!(recovery.check(&latch, false).is_none());
+        assert!(recovery.check(&latch, true).is_none());
+        assert!(recovery.check(&latch, true).is_none());
+        assert_eq!(
+            recovery.check(&latch, true),
+            Some(vec![HardStopReason::PositionDrift])
+        );
+        assert!(!latch.is_triggered());
+    }
+
+    #[test]
+    fn test_loss_stops_and_cool_off_stay_latched() {
+        let recovery = recovery(1);
+        let latch = HardStopLatch::new();
+        latch.trigger_with(
+            HardStopLevel::FullStop,
+            HardStopReason::CumulativeLoss,
+            "loss",
+        );
+        assert!(recovery.check(&latch, true).is_none());
+        assert!(latch.is_full_stop());
+
+        let cooling = HardStopRecovery::new(HardStopRecoveryConfig {
+            enabled: true,
+            cool_off_secs: 3600,
+            healthy_checks: 1,
+            ..Default::default()
+        });
+        let latch = HardStopLatch::new();
+        latch.trigger_with(
+            HardStopLevel::PauseEntries,
+            HardStopReason::Rejections,
+            "rejections",
+        );
+        assert!(cooling.check(&latch, true).is_none());
+        assert!(latch.is_triggered());
+    }
+}
@@ -13,7 +13,22 @@
 //! - [`ActionBudget`]: Rate limiting for new order submissions
 //! - [`PostIdGenerator`]: Unique post_id generation for WS correlation
 //! - [`HardStopLatch`]: Circuit breaker for emergency trading halt
+//! - [`HardStopRecovery`]: Auto-recovery of hard stops whose condition has cleared
 //! - [`RiskMonitor`]: Real-time risk monitoring and threshold checking
+//! - [`OrderSlicer`]: Parent-order slicing of large entries into child IOCs
+//! - [`RetryPolicy`]: Backoff retries of transiently rejected orders
+//! - [`SimulatedWsSender`]: Paper-trading sender filling against the live BBO
+//! - [`RestCanceller`]: REST cancel-all fallback for when the WebSocket is down
+//! - [`VaultRouter`]: Per-strategy vault (sub-account) routing of actions
+//! - [`ScheduledCancel`]: Dead man's switch via the exchange's scheduled cancel
+//! - [`ReconcileReport`]: Inflight post reconciliation after a WebSocket reconnect
+//! - [`SlippageGuard`]: Submit-time maximum slippage check of limit prices
+//! - [`FatFingerGuard`]: Absolute per-order notional and price sanity gate
+//! - [`OrderSweeper`]: Status checks of orders stuck pending without an orderUpdate
+//! - [`RestExchangeClient`]: REST order submission when the WS path is saturated
+//! - [`ShadowOutcome`]: Predicted outcome of actions signed but not sent in shadow mode
+//! - [`SignedActionLog`]: Audit trail of every signed action and its outcome
+//! - [`RiskEventLog`]: Audit trail of gate blocks, hard stops and entry pauses
 //!
 //! # Gate Checks (in `Executor::on_signal`)
 //!
@@ -26,23 +41,46 @@
 //! 7. ActionBudget -> Skipped::BudgetExhausted
 //! 8. (all passed) -> try_mark_pending_market + enqueue
 
+pub mod audit;
 pub mod batch;
 pub mod error;
 pub mod executor;
 pub mod executor_loop;
+pub mod fat_finger;
+pub mod hard_stop_recovery;
 pub mod nonce;
+pub mod order_sweep;
+pub mod paper;
 pub mod price_provider;
 pub mod ready;
 pub mod real_ws_sender;
+pub mod reconcile;
+pub mod rest_cancel;
+pub mod rest_fallback;
+pub mod retry;
 pub mod risk;
+pub mod risk_events;
+pub mod scheduled_cancel;
+pub mod shadow;
 pub mod signer;
+pub mod slicer;
+pub mod slippage_guard;
+pub mod vault_router;
 pub mod ws_sender;
 
+// Signed-action audit
+pub use audit::{action_hash_from_json, SignedActionAudit, SignedActionLog};
+
 // Batch scheduling
-pub use batch::{BatchConfig, BatchScheduler, InflightTracker};
+pub use batch::{BatchConfig, BatchScheduler, InflightTracker, OrderTier};
 
 // Risk management
-pub use risk::{ExecutionEvent, ExecutorHandle, HardStopLatch, RiskMonitor, RiskMonitorConfig};
+pub use hard_stop_recovery::{HardStopRecovery, HardStopRecoveryConfig};
+pub use risk::{
+    EntryPause, ExecutionEvent, ExecutorHandle, HardStopHistoryEntry, HardStopLatch, HardStopLevel,
+    HardStopReason, RiskMonitor, RiskMonitorConfig, RiskMonitorConfigUpdate, RiskMonitorSettings,
+};
+pub use risk_events::{RiskEvent, RiskEventKind, RiskEventLog};
 
 // Error types
 pub use error::{ExecutorError, ExecutorResult};
@@ -50,7 +88,7 @@ pub use error::{ExecutorError, ExecutorResult};
 // Executor and related types
 pub use executor::{
     ActionBudget, Executor, ExecutorConfig, MarketState, MarketStateCache, MmQuoteResult,
-    PostIdGenerator,
+    ObservedBlock, PostIdGenerator, QuoteState, OBSERVABLE_GATES,
 };
 
 // Price provider for TimeStopMonitor
@@ -58,21 +96,58 @@ pub use price_provider::MarkPriceProvider;
 
 // Executor loop
 pub use executor_loop::{
-    DroppedOrder, ExecutorLoop, PendingRequest, PostRequestManager, PostResult,
+    DroppedOrder, ExecutorLoop, PendingRequest, PostRequestManager, PostResult, StaleSignalDrop,
 };
 
 // Nonce management
-pub use nonce::{Clock, NonceError, NonceManager, SystemClock};
+pub use nonce::{is_nonce_error, Clock, NonceError, NonceManager, SystemClock};
 
 // Ready checker
 pub use ready::TradingReadyChecker;
 
 // Signing
 pub use signer::{
-    Action, BuilderInfo, CancelWire, KeyError, KeyManager, KeySource, LimitOrderType,
-    OrderTypeWire, OrderWire, PhantomAgent, Signer, SignerError, SigningInput, TriggerOrderType,
+    Action, BuilderInfo, CancelWire, KeyError, KeyManager, KeyPurpose, KeySource, LimitOrderType,
+    ModifyWire, OrderTypeWire, OrderWire, PhantomAgent, Signer, SignerError, SigningInput,
+    TriggerOrderType,
 };
 
+// Pending order sweeper
+pub use order_sweep::{OrderSweepConfig, OrderSweeper, SweepReport};
+
+// Paper trading
+pub use paper::{PaperConfig, SimulatedWsSender};
+
+// Reconnect reconciliation
+pub use reconcile::{ReconcileOutcome, ReconcileReport};
+
+// REST cancel fallback
+pub use rest_cancel::{RestCancelReport, RestCanceller};
+
+// REST order submission fallback
+pub use rest_fallback::{RestExchangeClient, RestFallbackConfig, RestFallbackScope};
+
+// Rejection retries
+pub use retry::{classify_reject, RejectClass, RetryConfig, RetryDecision, RetryPolicy};
+
+// Dead man's switch
+pub use scheduled_cancel::{ScheduledCancel, ScheduledCancelConfig};
+
+// Shadow execution
+pub use shadow::{predict_outcome, ShadowOutcome};
+
+// Entry slicing
+pub use slicer::{OrderSlicer, ParentOrder, ParentOrderState, SlicingConfig};
+
+// Submit-time slippage guard
+pub use slippage_guard::{SlippageBreach, SlippageGuard, SlippageGuardConfig, SlippageRejection};
+
+// Fat-finger sanity gate
+pub use fat_finger::{FatFingerBreach, FatFingerConfig, FatFingerGuard};
+
+// Vault routing
+pub use vault_router::VaultRouter;
+
 // WebSocket sender
 pub use real_ws_sender::RealWsSender;
 pub use ws_sender::{
@@ -2,6 +2,9 @@
 //!
 //! Provides unique, monotonically increasing nonces that track server time
 //! while maintaining ordering guarantees even under clock drift.
+//!
+//! The counter can be restored from a persisted value on startup and is
+//! resynced when the exchange rejects an action for its nonce.
 
 use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 
@@ -15,6 +18,15 @@ pub enum NonceError {
     TimeDriftTooLarge(i64),
 }
 
+/// Exchange error messages that indicate a bad nonce.
+const NONCE_ERRORS: &[&str] = &["Invalid nonce", "invalid nonce", "nonce too low"];
+
+/// Whether an exchange error was caused by the action's nonce.
+#[must_use]
+pub fn is_nonce_error(reason: &str) -> bool {
+    NONCE_ERRORS.iter().any(|e| reason.contains(e))
+}
+
 /// Trait for obtaining current time, enabling testability.
 pub trait Clock: Send + Sync {
     /// Returns current time in milliseconds since Unix epoch.
@@ -61,6 +73,8 @@ impl<C: Clock> NonceManager<C> {
     const DRIFT_WARN_THRESHOLD_MS: i64 = 2000;
     /// Threshold for error on time drift (5 seconds).
     const DRIFT_ERROR_THRESHOLD_MS: i64 = 5000;
+    /// Jump ahead of the current nonce when resyncing (1 second).
+    const RESYNC_GAP_MS: u64 = 1000;
 
     /// Creates a new `NonceManager` with the given clock.
     ///
@@ -160,6 +174,32 @@ impl<C: Clock> NonceManager<C> {
         Ok(())
     }
 
+    /// Restores the counter from the last nonce of a previous run.
+    ///
+    /// The counter is moved to at least `last_nonce + safety_offset_ms`, so
+    /// nonces issued after the last save are not reused. Never moves the
+    /// counter backwards. Returns the resulting counter value.
+    pub fn restore(&self, last_nonce: u64, safety_offset_ms: u64) -> u64 {
+        self.fast_forward_counter(last_nonce.saturating_add(safety_offset_ms));
+        self.last_nonce()
+    }
+
+    /// Jumps the counter ahead after the exchange rejected a nonce.
+    ///
+    /// The counter moves to `max(last_nonce, approx_server_time) + 1s`.
+    /// Returns the resulting counter value.
+    pub fn resync(&self) -> u64 {
+        let base = self.last_nonce().max(self.approx_server_time_ms());
+        self.fast_forward_counter(base.saturating_add(Self::RESYNC_GAP_MS));
+        self.last_nonce()
+    }
+
+    /// Returns the last issued nonce (or the initial counter value).
+    #[must_use]
+    pub fn last_nonce(&self) -> u64 {
+        self.counter.load(Ordering::Acquire)
+    }
+
     /// Fast-forwards the counter to at least the given value.
     fn fast_forward_counter(&self, min_value: u64) {
         loop {
@@ -442,4 +482,36 @@ mod tests {
             "initial counter should be based on current time"
         );
     }
+
+    #[test]
+    fn test_restore_skips_past_persisted_nonce() {
+        let clock = MockClock::new(BASE_TIME);
+        let manager = NonceManager::new(clock);
+
+        // Previous run issued nonces ahead of local time
+        assert_eq!(manager.restore(BASE_TIME + 200, 5000), BASE_TIME + 5200);
+        assert_eq!(manager.next(), BASE_TIME + 5201);
+
+        // A stale persisted nonce never moves the counter backwards
+        assert_eq!(manager.restore(BASE_TIME - 60_000, 5000), BASE_TIME + 5201);
+    }
+
+    #[test]
+    fn test_resync_jumps_ahead() {
+        let clock = MockClock::new(BASE_TIME);
+        let manager = NonceManager::new(clock);
+
+        let before = manager.next();
+        let after = manager.resync();
+        assert_eq!(after, before + 1000);
+        assert!(manager.next() > after);
+    }
+
+    #[test]
+    fn test_is_nonce_error() {
+        assert!(is_nonce_error(
+            "Invalid nonce: duplicate nonce 1700000000000"
+        ));
+        assert!(!is_nonce_error("Insufficient margin to place order."));
+    }
 }
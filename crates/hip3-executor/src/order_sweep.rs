@@ -0,0 +1,178 @@
+//! Sweeper for orders stuck in the position tracker.
+//!
+//! An order stays pending in `PositionTracker` until an orderUpdate (or the
+//! post response) moves it to a terminal state. If that message is lost,
+//! the order blocks new entries for its market forever. [`OrderSweeper`]
+//! picks out orders pending longer than `pending_timeout_ms` that are no
+//! longer queued, in flight or waiting for a retry; `ExecutorLoop` then asks
+//! the exchange for their status (`orderStatus` by cloid) and applies it:
+//!
+//! - **Open**: the order is live (e.g. a resting quote); it is checked again
+//!   after another timeout
+//! - **Filled / cancelled / rejected**: the fill (if any) and the terminal
+//!   state are applied as if the orderUpdate had arrived
+//! - **Unknown**: the order never reached the exchange and is dropped
+
+use hip3_core::ClientOrderId;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for the pending order sweeper.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderSweepConfig {
+    /// Whether stale pending orders are swept. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Age after which a pending order's status is checked (ms).
+    /// Default: 30,000.
+    #[serde(default = "default_pending_timeout_ms")]
+    pub pending_timeout_ms: u64,
+    /// How often the sweep runs (ms). Default: 5,000.
+    #[serde(default = "default_sweep_interval_ms")]
+    pub sweep_interval_ms: u64,
+}
+
+fn default_pending_timeout_ms() -> u64 {
+    30_000
+}
+
+fn default_sweep_interval_ms() -> u64 {
+    5_000
+}
+
+impl Default for OrderSweepConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            pending_timeout_ms: default_pending_timeout_ms(),
+            sweep_interval_ms: default_sweep_interval_ms(),
+        }
+    }
+}
+
+/// Counts of a sweep pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SweepReport {
+    /// Orders the exchange still has open.
+    pub open: usize,
+    /// Orders moved to a terminal state from their exchange status.
+    pub resolved: usize,
+    /// Orders unknown to the exchange, dropped.
+    pub expired: usize,
+    /// Orders left pending because the lookup failed.
+    pub unresolved: usize,
+}
+
+impl SweepReport {
+    /// Count per outcome label, for metrics.
+    #[must_use]
+    pub fn outcomes(&self) -> [(&'static str, usize); 4] {
+        [
+            ("open", self.open),
+            ("resolved", self.resolved),
+            ("expired", self.expired),
+            ("unresolved", self.unresolved),
+        ]
+    }
+}
+
+/// Selects pending orders due for a status check.
+#[derive(Debug)]
+pub struct OrderSweeper {
+    config: OrderSweepConfig,
+    /// Last status check per order still known to be open.
+    checked: Mutex<HashMap<ClientOrderId, u64>>,
+}
+
+impl OrderSweeper {
+    /// Create a sweeper from config.
+    #[must_use]
+    pub fn new(config: OrderSweepConfig) -> Self {
+        Self {
+            config,
+            checked: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get the configuration.
+    #[must_use]
+    pub fn config(&self) -> &OrderSweepConfig {
+        &self.config
+    }
+
+    /// Orders (cloid, creation time) that are due for a status check.
+    ///
+    /// An order is due once it is `pending_timeout_ms` old and, if it was
+    /// found open before, `pending_timeout_ms` after that check. Checks of
+    /// orders no longer pending are forgotten.
+    pub fn due(
+        &self,
+        pending: impl IntoIterator<Item = (ClientOrderId, u64)>,
+        now_ms: u64,
+    ) -> Vec<ClientOrderId> {
+        let timeout_ms = self.config.pending_timeout_ms;
+        let mut checked = self.checked.lock();
+        let mut still_pending = HashMap::with_capacity(checked.len());
+        let mut due = Vec::new();
+        for (cloid, created_at) in pending {
+            let since = match checked.get(&cloid) {
+                Some(&checked_at) => {
+                    still_pending.insert(cloid.clone(), checked_at);
+                    checked_at.max(created_at)
+                }
+                None => created_at,
+            };
+            if now_ms.saturating_sub(since) >= timeout_ms {
+                due.push(cloid);
+            }
+        }
+        *checked = still_pending;
+        due
+    }
+
+    /// Record that an order was found open on the exchange.
+    pub fn on_open(&self, cloid: ClientOrderId, now_ms: u64) {
+        self.checked.lock().insert(cloid, now_ms);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sweeper() -> OrderSweeper {
+        OrderSweeper::new(OrderSweepConfig {
+            enabled: true,
+            pending_timeout_ms: 10_000,
+            sweep_interval_ms: 1_000,
+        })
+    }
+
+    #[test]
+    fn test_due_after_timeout() {
+        let sweeper = sweeper();
+        let old = ClientOrderId::new();
+        let young = ClientOrderId::new();
+
+        let due = sweeper.due([(old.clone(), 0), (young.clone(), 5_000)], 10_000);
+        assert_eq!(due, vec![old]);
+    }
+
+    #[test]
+    fn test_open_order_rechecked_after_timeout() {
+        let sweeper = sweeper();
+        let cloid = ClientOrderId::new();
+
+        sweeper.on_open(cloid.clone(), 10_000);
+        assert!(sweeper.due([(cloid.clone(), 0)], 19_999).is_empty());
+        assert_eq!(
+            sweeper.due([(cloid.clone(), 0)], 20_000),
+            vec![cloid.clone()]
+        );
+
+        // Forgotten once no longer pending
+        assert!(sweeper.due([], 20_000).is_empty());
+        assert_eq!(sweeper.due([(cloid.clone(), 0)], 20_000), vec![cloid]);
+    }
+}
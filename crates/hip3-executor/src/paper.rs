@@ -0,0 +1,665 @@
+//! Paper trading against the live book.
+//!
+//! [`SimulatedWsSender`] stands in for [`RealWsSender`](crate::RealWsSender)
+//! in `OperatingMode::Paper`. Instead of posting signed actions it evaluates
+//! them against the latest BBO in [`MarketStateCache`] after a configurable
+//! latency, and feeds the outcome back through the bot's regular message
+//! channel as synthetic `post` responses, `orderUpdates` and `userFills`.
+//! The rest of the pipeline (executor loop, position tracker, exits, risk)
+//! runs unchanged.
+//!
+//! # Fill model
+//!
+//! - IOC: fills at the touch (ask for buys, bid for sells) when the limit
+//!   crosses it, for `fill_ratio` of the size; otherwise rejected with the
+//!   exchange's no-match error.
+//! - ALO: rejected if it would cross, otherwise rests.
+//! - GTC: fills like an IOC if crossing, otherwise rests.
+//! - Reduce-only orders are clipped to the simulated position.
+//!
+//! Resting orders are held until canceled or modified; they are never
+//! matched against later quotes, so passive fills are not simulated.
+
+use crate::executor::MarketStateCache;
+use crate::signer::{Action, OrderTypeWire, OrderWire};
+use crate::ws_sender::{BoxFuture, SendResult, SignedAction, WsSender};
+use hip3_core::MarketKey;
+use hip3_ws::{ChannelMessage, MessageSender, WsMessage};
+use parking_lot::Mutex;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Configuration for paper trading.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperConfig {
+    /// Simulated delay between send and exchange evaluation (ms). Default: 50.
+    #[serde(default = "default_latency_ms")]
+    pub latency_ms: u64,
+    /// Fraction of a crossing order's size that fills, in (0, 1]. Default: 1.
+    #[serde(default = "default_fill_ratio")]
+    pub fill_ratio: Decimal,
+    /// Taker fee charged on simulated fills (bps). Default: 4.5.
+    #[serde(default = "default_fee_bps")]
+    pub fee_bps: Decimal,
+}
+
+fn default_latency_ms() -> u64 {
+    50
+}
+
+fn default_fill_ratio() -> Decimal {
+    Decimal::ONE
+}
+
+fn default_fee_bps() -> Decimal {
+    Decimal::new(45, 1)
+}
+
+impl Default for PaperConfig {
+    fn default() -> Self {
+        Self {
+            latency_ms: default_latency_ms(),
+            fill_ratio: default_fill_ratio(),
+            fee_bps: default_fee_bps(),
+        }
+    }
+}
+
+/// An order resting on the simulated book.
+#[derive(Debug, Clone)]
+struct RestingOrder {
+    asset: u32,
+    cloid: Option<String>,
+    is_buy: bool,
+    limit_px: String,
+    sz: String,
+}
+
+/// Mutable simulator state.
+#[derive(Debug, Default)]
+struct BookState {
+    next_oid: u64,
+    next_tid: u64,
+    resting: HashMap<u64, RestingOrder>,
+    /// Signed simulated position per asset.
+    positions: HashMap<u32, Decimal>,
+}
+
+/// Messages produced by one simulated order.
+#[derive(Default)]
+struct OrderOutcome {
+    updates: Vec<Value>,
+    fills: Vec<Value>,
+}
+
+/// Simulated exchange matching orders against the cached BBO.
+struct PaperExchange {
+    config: PaperConfig,
+    quotes: Arc<MarketStateCache>,
+    /// Wire asset index → (market, coin).
+    markets: HashMap<u32, (MarketKey, String)>,
+    state: Mutex<BookState>,
+}
+
+impl PaperExchange {
+    /// Evaluate an action and return the messages the exchange would send.
+    fn execute(&self, action: &Action, post_id: u64, now_ms: u64) -> Vec<WsMessage> {
+        let mut state = self.state.lock();
+        let mut outcome = OrderOutcome::default();
+
+        let (response_type, statuses) = match action.action_type.as_str() {
+            "order" => {
+                let statuses = action
+                    .orders
+                    .iter()
+                    .flatten()
+                    .map(|order| self.place(&mut state, order, now_ms, &mut outcome))
+                    .collect();
+                ("order", statuses)
+            }
+            "cancel" => {
+                let statuses = action
+                    .cancels
+                    .iter()
+                    .flatten()
+                    .map(|cancel| match state.resting.remove(&cancel.oid) {
+                        Some(resting) => {
+                            outcome.updates.push(self.order_update(
+                                &resting,
+                                cancel.oid,
+                                &resting.sz,
+                                "canceled",
+                                now_ms,
+                            ));
+                            json!("success")
+                        }
+                        None => json!({
+                            "error": format!(
+                                "Order was never placed, already canceled, or filled. asset={}",
+                                cancel.asset
+                            )
+                        }),
+                    })
+                    .collect();
+                ("cancel", statuses)
+            }
+            "batchModify" => {
+                let statuses = action
+                    .modifies
+                    .iter()
+                    .flatten()
+                    .map(|modify| match state.resting.remove(&modify.oid) {
+                        Some(resting) => {
+                            outcome.updates.push(self.order_update(
+                                &resting,
+                                modify.oid,
+                                &resting.sz,
+                                "canceled",
+                                now_ms,
+                            ));
+                            self.place(&mut state, &modify.order, now_ms, &mut outcome)
+                        }
+                        None => json!({"error": "Cannot modify canceled or filled order"}),
+                    })
+                    .collect();
+                ("order", statuses)
+            }
+            other => {
+                debug!(action_type = %other, "Paper: action acknowledged without simulation");
+                ("default", Vec::new())
+            }
+        };
+
+        let mut messages = vec![channel_message(
+            "post",
+            json!({
+                "id": post_id,
+                "response": {
+                    "type": "action",
+                    "payload": {
+                        "status": "ok",
+                        "response": {"type": response_type, "data": {"statuses": statuses}}
+                    }
+                }
+            }),
+        )];
+        if !outcome.updates.is_empty() {
+            messages.push(channel_message(
+                "orderUpdates",
+                Value::Array(outcome.updates),
+            ));
+        }
+        if !outcome.fills.is_empty() {
+            messages.push(channel_message(
+                "userFills",
+                json!({"user": "paper", "fills": outcome.fills}),
+            ));
+        }
+        messages
+    }
+
+    /// Simulate one order and return its post-response status.
+    fn place(
+        &self,
+        state: &mut BookState,
+        order: &OrderWire,
+        now_ms: u64,
+        outcome: &mut OrderOutcome,
+    ) -> Value {
+        let error = |msg: &str| json!({"error": format!("{msg} asset={}", order.asset)});
+
+        let tif = match &order.order_type {
+            OrderTypeWire::Limit { limit } => limit.tif.as_str(),
+            OrderTypeWire::Trigger { .. } => {
+                return error("Paper trading does not simulate trigger orders.")
+            }
+        };
+        let (Ok(limit_px), Ok(sz)) = (
+            order.limit_px.parse::<Decimal>(),
+            order.sz.parse::<Decimal>(),
+        ) else {
+            return error("Order has invalid price or size.");
+        };
+        let Some((market, _)) = self.markets.get(&order.asset) else {
+            return error("Paper trading has no market for this asset.");
+        };
+        let Some(quote) = self.quotes.get_quote(market) else {
+            return error("Paper trading has no quote for this asset.");
+        };
+
+        let touch = if order.is_buy {
+            quote.best_ask
+        } else {
+            quote.best_bid
+        };
+        let crosses = !touch.is_zero()
+            && if order.is_buy {
+                limit_px >= touch.inner()
+            } else {
+                limit_px <= touch.inner()
+            };
+
+        let position = state
+            .positions
+            .get(&order.asset)
+            .copied()
+            .unwrap_or_default();
+        let mut sz = sz;
+        if order.reduce_only {
+            let reducible = if order.is_buy == (position < Decimal::ZERO) {
+                position.abs()
+            } else {
+                Decimal::ZERO
+            };
+            if reducible.is_zero() {
+                return error("Reduce only order would increase position.");
+            }
+            sz = sz.min(reducible);
+        }
+
+        match tif {
+            "Alo" if crosses => {
+                return error(&format!(
+                    "Post only order would have immediately matched, bbo was {}@{}.",
+                    quote.best_bid, quote.best_ask
+                ))
+            }
+            "Ioc" if !crosses => {
+                return error("Order could not immediately match against any resting orders.")
+            }
+            _ => {}
+        }
+
+        state.next_oid += 1;
+        let oid = state.next_oid;
+
+        if !crosses {
+            let resting = RestingOrder {
+                asset: order.asset,
+                cloid: order.cloid.clone(),
+                is_buy: order.is_buy,
+                limit_px: order.limit_px.clone(),
+                sz: order.sz.clone(),
+            };
+            outcome
+                .updates
+                .push(self.order_update(&resting, oid, &resting.sz, "open", now_ms));
+            state.resting.insert(oid, resting);
+            return json!({"resting": {"oid": oid}});
+        }
+
+        let fill_sz = (sz * self.config.fill_ratio)
+            .round_dp_with_strategy(sz.scale(), RoundingStrategy::ToZero)
+            .normalize();
+        if fill_sz.is_zero() {
+            return error("Order could not immediately match against any resting orders.");
+        }
+        let fill_px = touch.inner().normalize();
+        let signed = if order.is_buy { fill_sz } else { -fill_sz };
+        state.positions.insert(order.asset, position + signed);
+        state.next_tid += 1;
+
+        let coin = self.coin(order.asset);
+        let filled = RestingOrder {
+            asset: order.asset,
+            cloid: order.cloid.clone(),
+            is_buy: order.is_buy,
+            limit_px: order.limit_px.clone(),
+            sz: order.sz.clone(),
+        };
+        outcome
+            .updates
+            .push(self.order_update(&filled, oid, "0", "filled", now_ms));
+        outcome.fills.push(json!({
+            "coin": coin,
+            "side": side_code(order.is_buy),
+            "px": fill_px.to_string(),
+            "sz": fill_sz.to_string(),
+            "time": now_ms,
+            "tid": state.next_tid,
+            "fee": (fill_px * fill_sz * self.config.fee_bps / Decimal::from(10_000))
+                .round_dp(6)
+                .normalize()
+                .to_string(),
+            "startPosition": position.normalize().to_string(),
+            "dir": fill_direction(position, order.is_buy),
+            "closedPnl": "0",
+            "oid": oid,
+            "cloid": order.cloid,
+            "crossed": true,
+            "feeToken": "USDC",
+        }));
+
+        json!({
+            "filled": {
+                "totalSz": fill_sz.to_string(),
+                "avgPx": fill_px.to_string(),
+                "oid": oid,
+            }
+        })
+    }
+
+    /// Build an `orderUpdates` element.
+    fn order_update(
+        &self,
+        order: &RestingOrder,
+        oid: u64,
+        remaining_sz: &str,
+        status: &str,
+        now_ms: u64,
+    ) -> Value {
+        json!({
+            "order": {
+                "cloid": order.cloid,
+                "oid": oid,
+                "coin": self.coin(order.asset),
+                "side": side_code(order.is_buy),
+                "limitPx": order.limit_px,
+                "sz": remaining_sz,
+                "origSz": order.sz,
+                "timestamp": now_ms,
+            },
+            "status": status,
+            "statusTimestamp": now_ms,
+        })
+    }
+
+    fn coin(&self, asset: u32) -> String {
+        self.markets
+            .get(&asset)
+            .map_or_else(|| asset.to_string(), |(_, coin)| coin.clone())
+    }
+}
+
+fn side_code(is_buy: bool) -> &'static str {
+    if is_buy {
+        "B"
+    } else {
+        "A"
+    }
+}
+
+fn fill_direction(position: Decimal, is_buy: bool) -> &'static str {
+    match (is_buy, position.is_sign_negative() && !position.is_zero()) {
+        (true, true) => "Close Short",
+        (true, false) => "Open Long",
+        (false, _) if position > Decimal::ZERO => "Close Long",
+        (false, _) => "Open Short",
+    }
+}
+
+fn channel_message(channel: &str, data: Value) -> WsMessage {
+    WsMessage::Channel(ChannelMessage {
+        channel: channel.to_string(),
+        data,
+    })
+}
+
+/// `WsSender` that simulates the exchange instead of posting to it.
+///
+/// `send()` returns immediately; the simulated responses arrive on
+/// `message_tx` after the configured latency, like real ones would.
+pub struct SimulatedWsSender {
+    exchange: Arc<PaperExchange>,
+    message_tx: MessageSender,
+}
+
+impl SimulatedWsSender {
+    /// Create a simulator for `markets` (market, coin), quoting from `quotes`
+    /// and delivering responses on `message_tx`.
+    pub fn new(
+        config: PaperConfig,
+        quotes: Arc<MarketStateCache>,
+        markets: impl IntoIterator<Item = (MarketKey, String)>,
+        message_tx: MessageSender,
+    ) -> Self {
+        let markets = markets
+            .into_iter()
+            .map(|(key, coin)| (key.asset.0, (key, coin)))
+            .collect();
+        Self {
+            exchange: Arc::new(PaperExchange {
+                config,
+                quotes,
+                markets,
+                state: Mutex::new(BookState::default()),
+            }),
+            message_tx,
+        }
+    }
+
+    /// Number of orders resting on the simulated book.
+    #[must_use]
+    pub fn resting_count(&self) -> usize {
+        self.exchange.state.lock().resting.len()
+    }
+
+    /// Simulated signed position for a market.
+    #[must_use]
+    pub fn position(&self, market: &MarketKey) -> Decimal {
+        self.exchange
+            .state
+            .lock()
+            .positions
+            .get(&market.asset.0)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+impl WsSender for SimulatedWsSender {
+    fn send(&self, action: SignedAction) -> BoxFuture<'_, SendResult> {
+        Box::pin(async move {
+            let exchange = Arc::clone(&self.exchange);
+            let tx = self.message_tx.clone();
+            tokio::spawn(async move {
+                let latency_ms = exchange.config.latency_ms;
+                if latency_ms > 0 {
+                    tokio::time::sleep(Duration::from_millis(latency_ms)).await;
+                }
+                let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+                let messages = exchange.execute(&action.action, action.post_id, now_ms);
+                for message in messages {
+                    if tx.send(message).is_err() {
+                        warn!(post_id = action.post_id, "Paper: message channel closed");
+                        return;
+                    }
+                }
+            });
+            SendResult::Sent
+        })
+    }
+
+    fn is_ready(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::CancelWire;
+    use hip3_core::{AssetId, DexId, Price};
+    use hip3_ws::message_queue;
+    use rust_decimal_macros::dec;
+
+    fn key() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(110026))
+    }
+
+    fn exchange(fill_ratio: Decimal) -> PaperExchange {
+        let quotes = Arc::new(MarketStateCache::new());
+        quotes.update_quote(
+            &key(),
+            Price::new(dec!(100)),
+            Price::new(dec!(99.9)),
+            Price::new(dec!(100.1)),
+            0,
+        );
+        PaperExchange {
+            config: PaperConfig {
+                fill_ratio,
+                ..Default::default()
+            },
+            quotes,
+            markets: HashMap::from([(110026, (key(), "xyz:GOLD".to_string()))]),
+            state: Mutex::new(BookState::default()),
+        }
+    }
+
+    fn order(is_buy: bool, px: &str, sz: &str, order_type: OrderTypeWire) -> Action {
+        Action {
+            action_type: "order".to_string(),
+            orders: Some(vec![OrderWire {
+                asset: 110026,
+                is_buy,
+                limit_px: px.to_string(),
+                sz: sz.to_string(),
+                reduce_only: false,
+                order_type,
+                cloid: Some("0x01".to_string()),
+            }]),
+            cancels: None,
+            modifies: None,
+            grouping: Some("na".to_string()),
+            builder: None,
+            time: None,
+        }
+    }
+
+    fn data(message: &WsMessage) -> &Value {
+        match message {
+            WsMessage::Channel(c) => &c.data,
+            WsMessage::Pong(_) => panic!("unexpected pong"),
+        }
+    }
+
+    fn statuses(messages: &[WsMessage]) -> Vec<hip3_ws::OrderResponseStatus> {
+        let response = messages[0].as_post_response().expect("post response");
+        match response.response {
+            hip3_ws::PostResponseBody::Action { payload } => payload.parse_statuses(),
+            hip3_ws::PostResponseBody::Error { payload } => panic!("error response: {payload}"),
+        }
+    }
+
+    #[test]
+    fn test_ioc_fills_at_touch_with_partial_ratio() {
+        let exchange = exchange(dec!(0.5));
+        let messages =
+            exchange.execute(&order(true, "100.2", "1.5", OrderTypeWire::ioc()), 7, 1_000);
+
+        assert_eq!(messages[0].as_post_response().unwrap().id, 7);
+        match &statuses(&messages)[0] {
+            hip3_ws::OrderResponseStatus::Filled {
+                total_sz, avg_px, ..
+            } => {
+                assert_eq!(total_sz, "0.7");
+                assert_eq!(avg_px, "100.1");
+            }
+            other => panic!("expected fill, got {other:?}"),
+        }
+        assert_eq!(messages[1].as_order_updates().updates[0].status, "filled");
+        let fills = messages[2].as_user_fills().unwrap().fills;
+        assert_eq!(fills[0].cloid.as_deref(), Some("0x01"));
+        assert_eq!(fills[0].dir, "Open Long");
+        assert_eq!(exchange.state.lock().positions[&110026], dec!(0.7));
+
+        // Reduce-only sell is clipped to the 0.7 long; fill_ratio 0.5 → 0.3
+        let mut close = order(false, "99", "5", OrderTypeWire::ioc());
+        close.orders.as_mut().unwrap()[0].reduce_only = true;
+        let messages = exchange.execute(&close, 8, 1_100);
+        let fills = messages[2].as_user_fills().unwrap().fills;
+        assert_eq!(fills[0].sz, "0.3");
+        assert_eq!(fills[0].px, "99.9");
+        assert_eq!(fills[0].dir, "Close Long");
+        assert_eq!(fills[0].start_position, "0.7");
+    }
+
+    #[test]
+    fn test_non_crossing_orders_rejected_or_rest() {
+        let exchange = exchange(dec!(1));
+
+        let messages = exchange.execute(&order(true, "100", "1", OrderTypeWire::ioc()), 1, 0);
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(
+            &statuses(&messages)[0],
+            hip3_ws::OrderResponseStatus::Error { message }
+                if message.starts_with("Order could not immediately match")
+        ));
+
+        let messages = exchange.execute(&order(false, "99.95", "1", OrderTypeWire::alo()), 2, 0);
+        let hip3_ws::OrderResponseStatus::Resting { oid } = statuses(&messages)[0] else {
+            panic!("expected resting");
+        };
+        assert_eq!(messages[1].as_order_updates().updates[0].status, "open");
+
+        let messages = exchange.execute(&order(false, "99.9", "1", OrderTypeWire::alo()), 3, 0);
+        assert!(matches!(
+            &statuses(&messages)[0],
+            hip3_ws::OrderResponseStatus::Error { message }
+                if message.starts_with("Post only order would have immediately matched")
+        ));
+
+        let cancel = Action {
+            action_type: "cancel".to_string(),
+            orders: None,
+            cancels: Some(vec![CancelWire { asset: 110026, oid }]),
+            modifies: None,
+            grouping: None,
+            builder: None,
+            time: None,
+        };
+        let messages = exchange.execute(&cancel, 4, 0);
+        assert!(matches!(
+            statuses(&messages)[0],
+            hip3_ws::OrderResponseStatus::Success
+        ));
+        assert_eq!(data(&messages[1])[0]["status"], "canceled");
+        assert!(exchange.state.lock().resting.is_empty());
+
+        // Second cancel of the same oid fails like the exchange does
+        let messages = exchange.execute(&cancel, 5, 0);
+        assert!(matches!(
+            statuses(&messages)[0],
+            hip3_ws::OrderResponseStatus::Error { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_delivers_after_latency() {
+        let (tx, mut rx) = message_queue(16);
+        let ex = exchange(dec!(1));
+        let sender = SimulatedWsSender {
+            exchange: Arc::new(PaperExchange {
+                config: PaperConfig {
+                    latency_ms: 1,
+                    ..Default::default()
+                },
+                ..ex
+            }),
+            message_tx: tx,
+        };
+        let action = SignedAction {
+            action: order(false, "99", "2", OrderTypeWire::ioc()),
+            nonce: 1,
+            signature: crate::ws_sender::ActionSignature {
+                r: "0x0".to_string(),
+                s: "0x0".to_string(),
+                v: 27,
+            },
+            post_id: 42,
+            vault_address: None,
+        };
+
+        assert!(sender.send(action).await.is_success());
+        let first = rx.recv().await.unwrap();
+        assert_eq!(first.as_post_response().unwrap().id, 42);
+        assert_eq!(sender.position(&key()), dec!(-2));
+        assert_eq!(sender.resting_count(), 0);
+    }
+}
@@ -11,6 +11,10 @@ use hip3_ws::{PostError, PostPayload, PostRequest, SignaturePayload, WsWriteHand
 /// This implementation converts `SignedAction` to the Hyperliquid post request
 /// format and sends it via `WsWriteHandle`.
 ///
+/// The post payload's `vaultAddress` is the one the action was signed for
+/// (`SignedAction::vault_address`), so per-market vault routing needs no
+/// sender-side state.
+///
 /// # Response Handling
 ///
 /// `send()` only confirms that the request was queued for sending (fire-and-forget).
@@ -18,51 +22,23 @@ use hip3_ws::{PostError, PostPayload, PostRequest, SignaturePayload, WsWriteHand
 /// executor's `on_response_ok` / `on_response_rejected` methods.
 pub struct RealWsSender {
     handle: WsWriteHandle,
-    vault_address: Option<String>,
 }
 
 impl RealWsSender {
     /// Create a new RealWsSender with the given write handle.
-    pub fn new(handle: WsWriteHandle, vault_address: Option<String>) -> Self {
-        Self {
-            handle,
-            vault_address,
-        }
+    pub fn new(handle: WsWriteHandle) -> Self {
+        Self { handle }
     }
 }
 
 impl WsSender for RealWsSender {
     fn send(&self, action: SignedAction) -> BoxFuture<'_, SendResult> {
         Box::pin(async move {
-            // Convert SignedAction to PostRequest JSON
-            let action_value = match serde_json::to_value(&action.action) {
-                Ok(v) => v,
-                Err(e) => {
-                    tracing::error!(error = ?e, "Action serialization failed");
-                    return SendResult::Error(format!("Action serialization: {e}"));
-                }
-            };
-
-            let payload = PostPayload {
-                action: action_value,
-                nonce: action.nonce,
-                signature: SignaturePayload {
-                    r: action.signature.r.clone(),
-                    s: action.signature.s.clone(),
-                    // Hyperliquid uses integer v (27 or 28) per Python SDK
-                    v: action.signature.v,
-                },
-                // vaultAddress is omitted (not serialized) when None for personal trading
-                vault_address: self.vault_address.clone(),
-            };
-
-            let request = PostRequest::new(action.post_id, "action".to_string(), payload);
-
-            let json = match serde_json::to_string(&request) {
+            let json = match post_request_json(&action) {
                 Ok(j) => j,
                 Err(e) => {
-                    tracing::error!(error = ?e, "PostRequest serialization failed");
-                    return SendResult::Error(format!("PostRequest serialization: {e}"));
+                    tracing::error!(error = %e, "Post request serialization failed");
+                    return SendResult::Error(e);
                 }
             };
 
@@ -81,6 +57,31 @@ impl WsSender for RealWsSender {
     }
 }
 
+/// Serialize a signed action to the wire JSON of a WS post request.
+///
+/// # Errors
+/// Returns a description of the failure if the action cannot be serialized.
+pub(crate) fn post_request_json(action: &SignedAction) -> Result<String, String> {
+    let action_value =
+        serde_json::to_value(&action.action).map_err(|e| format!("Action serialization: {e}"))?;
+
+    let payload = PostPayload {
+        action: action_value,
+        nonce: action.nonce,
+        signature: SignaturePayload {
+            r: action.signature.r.clone(),
+            s: action.signature.s.clone(),
+            // Hyperliquid uses integer v (27 or 28) per Python SDK
+            v: action.signature.v,
+        },
+        // vaultAddress is omitted (not serialized) when None for personal trading
+        vault_address: action.vault_address.map(|a| format!("{a:#x}")),
+    };
+
+    let request = PostRequest::new(action.post_id, "action".to_string(), payload);
+    serde_json::to_string(&request).map_err(|e| format!("PostRequest serialization: {e}"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,7 +106,7 @@ mod tests {
         subscriptions.handle_message("orderUpdates:user:test");
 
         let handle = WsWriteHandle::new(tx, rate_limiter, state, subscriptions);
-        (RealWsSender::new(handle, None), rx)
+        (RealWsSender::new(handle), rx)
     }
 
     fn sample_signed_action() -> SignedAction {
@@ -114,8 +115,10 @@ mod tests {
                 action_type: "order".to_string(),
                 orders: Some(vec![]),
                 cancels: None,
+                modifies: None,
                 grouping: Some("na".to_string()),
                 builder: None,
+                time: None,
             },
             nonce: 12345,
             signature: ActionSignature {
@@ -124,6 +127,7 @@ mod tests {
                 v: 27,
             },
             post_id: 1,
+            vault_address: None,
         }
     }
 
@@ -150,6 +154,23 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_send_includes_signed_vault_address() {
+        let (sender, mut rx) = create_test_sender();
+        let mut action = sample_signed_action();
+        action.vault_address = Some(alloy::primitives::Address::repeat_byte(0xab));
+
+        assert!(sender.send(action).await.is_success());
+        let hip3_ws::WsOutbound::Post { payload, .. } = rx.recv().await.unwrap() else {
+            panic!("expected Post message");
+        };
+        let parsed: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(
+            parsed["request"]["payload"]["vaultAddress"],
+            format!("0x{}", "ab".repeat(20))
+        );
+    }
+
     #[tokio::test]
     async fn test_is_ready() {
         let (sender, _rx) = create_test_sender();
@@ -164,7 +185,7 @@ mod tests {
         let subscriptions = Arc::new(SubscriptionManager::new());
 
         let handle = WsWriteHandle::new(tx, rate_limiter, state, subscriptions);
-        let sender = RealWsSender::new(handle, None);
+        let sender = RealWsSender::new(handle);
 
         assert!(!sender.is_ready());
 
@@ -185,7 +206,7 @@ mod tests {
         subscriptions.handle_message("activeAssetCtx:perp:0");
 
         let handle = WsWriteHandle::new(tx, rate_limiter, state, subscriptions);
-        let sender = RealWsSender::new(handle, None);
+        let sender = RealWsSender::new(handle);
 
         assert!(!sender.is_ready());
     }
@@ -0,0 +1,200 @@
+//! Inflight post reconciliation after a WebSocket reconnect.
+//!
+//! Posts sent right before a disconnect may have reached the exchange while
+//! their responses were lost. With reconciliation armed, `ExecutorLoop`
+//! holds such requests instead of timing them out while the WebSocket is
+//! down, and on the READY transition the application asks the exchange
+//! about every order in them (`orderStatus` by cloid):
+//!
+//! - **Confirmed**: the exchange knows the order; its status is applied as
+//!   if it had come back in the post response
+//! - **Resubmitted**: the post never arrived and must go out again
+//!   (reduce-only orders, cancels)
+//! - **Expired**: the post never arrived and is dropped (new orders are
+//!   regenerated from signals, amendments fall back to cancel-replace)
+
+use hip3_core::PendingOrder;
+use hip3_registry::OrderStatusInfo;
+use hip3_ws::OrderResponseStatus;
+use rust_decimal::Decimal;
+
+/// How an inflight item was resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconcileOutcome {
+    /// The exchange processed it.
+    Confirmed,
+    /// It never arrived and was queued again.
+    Resubmitted,
+    /// It never arrived and was dropped.
+    Expired,
+}
+
+impl ReconcileOutcome {
+    /// Metric label.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReconcileOutcome::Confirmed => "confirmed",
+            ReconcileOutcome::Resubmitted => "resubmitted",
+            ReconcileOutcome::Expired => "expired",
+        }
+    }
+}
+
+/// Counts of a reconciliation pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReconcileReport {
+    /// Items the exchange processed.
+    pub confirmed: usize,
+    /// Items queued again.
+    pub resubmitted: usize,
+    /// Items dropped.
+    pub expired: usize,
+    /// Requests left pending because the lookup failed (timeouts handle them).
+    pub unresolved: usize,
+}
+
+impl ReconcileReport {
+    /// Count one resolved item.
+    pub fn record(&mut self, outcome: ReconcileOutcome) {
+        match outcome {
+            ReconcileOutcome::Confirmed => self.confirmed += 1,
+            ReconcileOutcome::Resubmitted => self.resubmitted += 1,
+            ReconcileOutcome::Expired => self.expired += 1,
+        }
+    }
+
+    /// Count per outcome, for metrics.
+    #[must_use]
+    pub fn outcomes(&self) -> [(ReconcileOutcome, usize); 3] {
+        [
+            (ReconcileOutcome::Confirmed, self.confirmed),
+            (ReconcileOutcome::Resubmitted, self.resubmitted),
+            (ReconcileOutcome::Expired, self.expired),
+        ]
+    }
+}
+
+/// Post-response status equivalent to an order's exchange status.
+///
+/// Returns None if the exchange does not know the order. An order that
+/// ended (canceled, rejected, ...) after a partial fill is reported as
+/// filled for the filled part. The average fill price is not part of
+/// `orderStatus`, so fills are booked at the limit price until the next
+/// position resync.
+#[must_use]
+pub fn response_status(
+    order: &PendingOrder,
+    remote: Option<&OrderStatusInfo>,
+) -> Option<OrderResponseStatus> {
+    let remote = remote?;
+    let filled = match (
+        remote.orig_sz.parse::<Decimal>(),
+        remote.sz.parse::<Decimal>(),
+    ) {
+        (Ok(orig), Ok(remaining)) => Some(orig - remaining),
+        _ => None,
+    };
+    let fill = |total_sz: String| OrderResponseStatus::Filled {
+        oid: remote.oid,
+        total_sz,
+        avg_px: remote.limit_px.clone(),
+    };
+    let status = match remote.status.as_str() {
+        "open" | "triggered" => OrderResponseStatus::Resting { oid: remote.oid },
+        "filled" => fill(filled.map_or_else(|| order.size.to_string(), |sz| sz.to_string())),
+        other => match filled {
+            Some(sz) if sz.is_sign_positive() && !sz.is_zero() => fill(sz.to_string()),
+            _ => OrderResponseStatus::Error {
+                message: other.to_string(),
+            },
+        },
+    };
+    Some(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, ClientOrderId, DexId, MarketKey, OrderSide, Price, Size};
+    use rust_decimal_macros::dec;
+
+    fn order() -> PendingOrder {
+        PendingOrder::new(
+            ClientOrderId::new(),
+            MarketKey::new(DexId::XYZ, AssetId::new(0)),
+            OrderSide::Buy,
+            Price::new(dec!(100)),
+            Size::new(dec!(1)),
+            false,
+            0,
+        )
+    }
+
+    fn remote(status: &str, sz: &str) -> OrderStatusInfo {
+        OrderStatusInfo {
+            oid: 42,
+            status: status.to_string(),
+            limit_px: "100.5".to_string(),
+            sz: sz.to_string(),
+            orig_sz: "1.0".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_response_status() {
+        let order = order();
+        assert!(response_status(&order, None).is_none());
+
+        assert!(matches!(
+            response_status(&order, Some(&remote("open", "1.0"))),
+            Some(OrderResponseStatus::Resting { oid: 42 })
+        ));
+
+        let Some(OrderResponseStatus::Filled {
+            total_sz, avg_px, ..
+        }) = response_status(&order, Some(&remote("filled", "0.0")))
+        else {
+            panic!("expected fill");
+        };
+        assert_eq!(total_sz, "1.0");
+        assert_eq!(avg_px, "100.5");
+
+        assert!(matches!(
+            response_status(&order, Some(&remote("canceled", "1.0"))),
+            Some(OrderResponseStatus::Error { .. })
+        ));
+    }
+
+    #[test]
+    fn test_response_status_canceled_after_partial_fill() {
+        let order = order();
+
+        // IOC filled 0.4 of 1.0, the rest canceled
+        let Some(OrderResponseStatus::Filled { total_sz, .. }) =
+            response_status(&order, Some(&remote("canceled", "0.6")))
+        else {
+            panic!("expected the partial fill");
+        };
+        assert_eq!(total_sz, "0.4");
+
+        assert!(matches!(
+            response_status(&order, Some(&remote("marginCanceled", "0.9"))),
+            Some(OrderResponseStatus::Filled { .. })
+        ));
+        assert!(matches!(
+            response_status(&order, Some(&remote("rejected", "1.0"))),
+            Some(OrderResponseStatus::Error { .. })
+        ));
+    }
+
+    #[test]
+    fn test_report_counts() {
+        let mut report = ReconcileReport::default();
+        report.record(ReconcileOutcome::Confirmed);
+        report.record(ReconcileOutcome::Expired);
+        report.record(ReconcileOutcome::Expired);
+        assert_eq!(report.outcomes()[0], (ReconcileOutcome::Confirmed, 1));
+        assert_eq!(report.outcomes()[2], (ReconcileOutcome::Expired, 2));
+    }
+}
@@ -0,0 +1,325 @@
+//! Emergency REST cancel-all.
+//!
+//! Cancels normally go out as WS posts through the batch scheduler. When the
+//! WebSocket is down that path is gone, yet resting GTC quotes and
+//! reduce-only orders stay live on the book. [`RestCanceller`] is the
+//! fallback: it lists the account's open orders through the info API, signs
+//! one `cancel` action with the regular [`Signer`] and posts it to the
+//! `/exchange` endpoint over HTTP.
+//!
+//! It shares the `NonceManager` with `ExecutorLoop`, so REST and WS actions
+//! never reuse a nonce.
+
+use crate::audit::SignedActionLog;
+use crate::error::{ExecutorError, ExecutorResult};
+use crate::nonce::{NonceManager, SystemClock};
+use crate::signer::{Action, CancelWire, Signer, SigningInput};
+use crate::ws_sender::{ActionSignature, SignedAction};
+use alloy::primitives::Address;
+use hip3_registry::{MetaClient, OpenOrder};
+use hip3_ws::{ActionResponsePayload, OrderResponseStatus, PostPayload, SignaturePayload};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// HTTP timeout for the exchange request.
+const EXCHANGE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of a REST cancel-all run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RestCancelReport {
+    /// Open orders listed by the exchange.
+    pub open_orders: usize,
+    /// Orders the exchange confirmed cancelled.
+    pub cancelled: usize,
+    /// Cancels the exchange rejected (usually already filled or cancelled).
+    pub failed: usize,
+    /// Open orders on coins outside the configured markets (left alone).
+    pub skipped: usize,
+}
+
+/// Cancels every open order over REST, bypassing the WebSocket.
+pub struct RestCanceller {
+    http: reqwest::Client,
+    exchange_url: String,
+    meta_client: MetaClient,
+    user_address: String,
+    dex: Option<String>,
+    /// Coin → asset index for the markets we trade.
+    assets: HashMap<String, u32>,
+    signer: Arc<Signer>,
+    nonce_manager: Arc<NonceManager<SystemClock>>,
+    vault_address: Option<Address>,
+    /// Audit trail of signed actions (None = not recorded).
+    action_log: Option<SignedActionLog>,
+    /// Single-flight guard: concurrent triggers share one run.
+    running: AtomicBool,
+}
+
+impl RestCanceller {
+    /// Create a canceller for `user_address`'s orders on `assets`.
+    ///
+    /// # Errors
+    /// Returns `ExecutorError::ConnectionError` if the HTTP client cannot be built.
+    pub fn new(
+        exchange_url: impl Into<String>,
+        meta_client: MetaClient,
+        user_address: impl Into<String>,
+        dex: Option<String>,
+        assets: HashMap<String, u32>,
+        signer: Arc<Signer>,
+        nonce_manager: Arc<NonceManager<SystemClock>>,
+    ) -> ExecutorResult<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(EXCHANGE_TIMEOUT)
+            .build()
+            .map_err(|e| ExecutorError::ConnectionError(format!("HTTP client: {e}")))?;
+        Ok(Self {
+            http,
+            exchange_url: exchange_url.into(),
+            meta_client,
+            user_address: user_address.into(),
+            dex,
+            assets,
+            signer,
+            nonce_manager,
+            vault_address: None,
+            action_log: None,
+            running: AtomicBool::new(false),
+        })
+    }
+
+    /// Sign for a vault/active_pool address, as `ExecutorLoop` does.
+    #[must_use]
+    pub fn with_vault_address(mut self, vault_address: Option<Address>) -> Self {
+        self.vault_address = vault_address;
+        self
+    }
+
+    /// Record the signed cancel action in `log`.
+    #[must_use]
+    pub fn with_action_log(mut self, log: SignedActionLog) -> Self {
+        self.action_log = Some(log);
+        self
+    }
+
+    /// Cancel every open order on the configured markets.
+    ///
+    /// Returns `Ok(None)` if another run is already in progress.
+    ///
+    /// # Errors
+    /// Returns an error if open orders cannot be listed, signing fails, or
+    /// the exchange request fails or is rejected as a whole.
+    pub async fn cancel_all(&self) -> ExecutorResult<Option<RestCancelReport>> {
+        if self.running.swap(true, Ordering::AcqRel) {
+            return Ok(None);
+        }
+        let result = self.run().await;
+        self.running.store(false, Ordering::Release);
+        result.map(Some)
+    }
+
+    async fn run(&self) -> ExecutorResult<RestCancelReport> {
+        let open_orders = self
+            .meta_client
+            .fetch_open_orders(&self.user_address, self.dex.as_deref())
+            .await
+            .map_err(|e| ExecutorError::ConnectionError(format!("openOrders: {e}")))?;
+
+        let (cancels, skipped) = build_cancels(&open_orders, &self.assets);
+        let mut report = RestCancelReport {
+            open_orders: open_orders.len(),
+            skipped,
+            ..Default::default()
+        };
+        if cancels.is_empty() {
+            info!(skipped, "REST cancel-all: no open orders to cancel");
+            return Ok(report);
+        }
+
+        let action = Action {
+            action_type: "cancel".to_string(),
+            orders: None,
+            cancels: Some(cancels),
+            modifies: None,
+            grouping: None,
+            builder: None,
+            time: None,
+        };
+        let nonce = self.nonce_manager.next();
+        let signature = self
+            .signer
+            .sign_action(SigningInput {
+                action: action.clone(),
+                nonce,
+                vault_address: self.vault_address,
+                expires_after: None,
+            })
+            .await
+            .map_err(|e| ExecutorError::SubmissionFailed(format!("Signing: {e}")))?;
+
+        let signed = SignedAction {
+            action,
+            nonce,
+            signature: ActionSignature {
+                r: format!("0x{}", hex::encode(signature.r().to_be_bytes::<32>())),
+                s: format!("0x{}", hex::encode(signature.s().to_be_bytes::<32>())),
+                v: 27 + signature.v() as u8,
+            },
+            // Not a WS post
+            post_id: 0,
+            vault_address: self.vault_address,
+        };
+
+        let result = self.post(&signed).await;
+        if let Some(ref log) = self.action_log {
+            let outcome = match result {
+                Ok(_) => "rest_ok".to_string(),
+                Err(ref e) => format!("rest_error: {e}"),
+            };
+            log.record(&signed, Vec::new(), outcome);
+        }
+
+        let (cancelled, failed) = result?;
+        report.cancelled = cancelled;
+        report.failed = failed;
+        if failed > 0 {
+            warn!(cancelled, failed, "REST cancel-all: some cancels rejected");
+        } else {
+            info!(cancelled, "REST cancel-all complete");
+        }
+        Ok(report)
+    }
+
+    /// Post the signed cancel action; returns (cancelled, failed).
+    async fn post(&self, signed: &SignedAction) -> ExecutorResult<(usize, usize)> {
+        let payload = PostPayload {
+            action: serde_json::to_value(&signed.action)
+                .map_err(|e| ExecutorError::SubmissionFailed(format!("Serialization: {e}")))?,
+            nonce: signed.nonce,
+            signature: SignaturePayload {
+                r: signed.signature.r.clone(),
+                s: signed.signature.s.clone(),
+                v: signed.signature.v,
+            },
+            vault_address: signed.vault_address.map(|a| format!("{a:#x}")),
+        };
+
+        let response = self
+            .http
+            .post(&self.exchange_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| ExecutorError::ConnectionError(format!("Exchange request: {e}")))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ExecutorError::ConnectionError(format!(
+                "HTTP {status}: {body}"
+            )));
+        }
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| ExecutorError::ConnectionError(format!("Exchange response: {e}")))?;
+        parse_cancel_response(&body)
+    }
+}
+
+/// Map open orders to cancel wires, counting orders on unknown coins.
+fn build_cancels(
+    open_orders: &[OpenOrder],
+    assets: &HashMap<String, u32>,
+) -> (Vec<CancelWire>, usize) {
+    let mut skipped = 0;
+    let cancels = open_orders
+        .iter()
+        .filter_map(|order| match assets.get(&order.coin) {
+            Some(&asset) => Some(CancelWire {
+                asset,
+                oid: order.oid,
+            }),
+            None => {
+                skipped += 1;
+                None
+            }
+        })
+        .collect();
+    (cancels, skipped)
+}
+
+/// Count confirmed and rejected cancels in an `/exchange` response.
+fn parse_cancel_response(body: &serde_json::Value) -> ExecutorResult<(usize, usize)> {
+    if body.get("status").and_then(|s| s.as_str()) != Some("ok") {
+        return Err(ExecutorError::OrderRejected(format!(
+            "Cancel-all rejected: {}",
+            body.get("response").unwrap_or(body)
+        )));
+    }
+    let payload: ActionResponsePayload = serde_json::from_value(body.clone())
+        .map_err(|e| ExecutorError::SubmissionFailed(format!("Cancel response: {e}")))?;
+    let statuses = payload.parse_statuses();
+    let cancelled = statuses
+        .iter()
+        .filter(|s| matches!(s, OrderResponseStatus::Success))
+        .count();
+    Ok((cancelled, statuses.len() - cancelled))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn open_order(coin: &str, oid: u64) -> OpenOrder {
+        OpenOrder {
+            coin: coin.to_string(),
+            limit_px: "100".to_string(),
+            oid,
+            side: "B".to_string(),
+            sz: "1".to_string(),
+            timestamp: 0,
+            cloid: None,
+            reduce_only: false,
+        }
+    }
+
+    #[test]
+    fn test_build_cancels_skips_unknown_coins() {
+        let assets = HashMap::from([("xyz:GOLD".to_string(), 110026)]);
+        let orders = [
+            open_order("xyz:GOLD", 1),
+            open_order("BTC", 2),
+            open_order("xyz:GOLD", 3),
+        ];
+
+        let (cancels, skipped) = build_cancels(&orders, &assets);
+        assert_eq!(skipped, 1);
+        assert_eq!(cancels.len(), 2);
+        assert_eq!((cancels[1].asset, cancels[1].oid), (110026, 3));
+    }
+
+    #[test]
+    fn test_parse_cancel_response() {
+        let ok = json!({
+            "status": "ok",
+            "response": {
+                "type": "cancel",
+                "data": {"statuses": [
+                    "success",
+                    {"error": "Order was never placed, already canceled, or filled. asset=110026"}
+                ]}
+            }
+        });
+        assert_eq!(parse_cancel_response(&ok).unwrap(), (1, 1));
+
+        let err = json!({"status": "err", "response": "User or API Wallet does not exist."});
+        assert!(matches!(
+            parse_cancel_response(&err),
+            Err(ExecutorError::OrderRejected(msg)) if msg.contains("does not exist")
+        ));
+    }
+}
@@ -0,0 +1,225 @@
+//! REST order submission fallback.
+//!
+//! Orders normally go out as WS posts. When the WS post rate limit is hit or
+//! the inflight cap is reached, reduce-only exits wait (or new orders are
+//! dropped) until the WS path frees up. [`RestExchangeClient`] submits the
+//! same signed `order` action to the `/exchange` endpoint over HTTP instead,
+//! for the orders covered by [`RestFallbackScope`].
+//!
+//! Actions are signed by `ExecutorLoop` with its own `Signer` and
+//! `NonceManager`, so REST and WS submissions never reuse a nonce. Requests
+//! are posted on their own task and tracked in [`RestSubmissions`] so the
+//! tick never waits on HTTP.
+
+use crate::error::{ExecutorError, ExecutorResult};
+use crate::ws_sender::SignedAction;
+use hip3_core::{ClientOrderId, PendingOrder};
+use hip3_ws::{ActionResponsePayload, OrderResponseStatus, PostPayload, SignaturePayload};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// HTTP timeout for the exchange request.
+const EXCHANGE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Orders eligible for REST submission.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestFallbackScope {
+    /// Reduce-only exits only.
+    #[default]
+    ExitsOnly,
+    /// Every order, including new entries.
+    All,
+}
+
+impl RestFallbackScope {
+    /// Whether the order may be submitted over REST.
+    #[must_use]
+    pub fn covers(self, order: &PendingOrder) -> bool {
+        match self {
+            Self::ExitsOnly => order.reduce_only,
+            Self::All => true,
+        }
+    }
+}
+
+/// Configuration for the REST order submission fallback.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RestFallbackConfig {
+    /// Whether orders fall back to REST when the WS path is saturated.
+    /// Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Orders eligible for the fallback. Default: exits_only.
+    #[serde(default)]
+    pub scope: RestFallbackScope,
+}
+
+/// Submits signed order actions to the exchange over HTTP.
+#[derive(Debug, Clone)]
+pub struct RestExchangeClient {
+    http: reqwest::Client,
+    exchange_url: String,
+    scope: RestFallbackScope,
+}
+
+impl RestExchangeClient {
+    /// Create a client posting to `exchange_url`.
+    ///
+    /// # Errors
+    /// Returns `ExecutorError::ConnectionError` if the HTTP client cannot be built.
+    pub fn new(exchange_url: impl Into<String>, scope: RestFallbackScope) -> ExecutorResult<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(EXCHANGE_TIMEOUT)
+            .build()
+            .map_err(|e| ExecutorError::ConnectionError(format!("HTTP client: {e}")))?;
+        Ok(Self {
+            http,
+            exchange_url: exchange_url.into(),
+            scope,
+        })
+    }
+
+    /// Orders eligible for REST submission.
+    #[must_use]
+    pub fn scope(&self) -> RestFallbackScope {
+        self.scope
+    }
+
+    /// Post a signed action and return its per-order statuses.
+    ///
+    /// # Errors
+    /// Returns `ExecutorError::ConnectionError` if the request fails (the
+    /// outcome is unknown) and `ExecutorError::OrderRejected` if the exchange
+    /// rejects the action as a whole.
+    pub async fn post(&self, signed: &SignedAction) -> ExecutorResult<Vec<OrderResponseStatus>> {
+        let payload = PostPayload {
+            action: serde_json::to_value(&signed.action)
+                .map_err(|e| ExecutorError::SubmissionFailed(format!("Serialization: {e}")))?,
+            nonce: signed.nonce,
+            signature: SignaturePayload {
+                r: signed.signature.r.clone(),
+                s: signed.signature.s.clone(),
+                v: signed.signature.v,
+            },
+            vault_address: signed.vault_address.map(|a| format!("{a:#x}")),
+        };
+
+        let response = self
+            .http
+            .post(&self.exchange_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| ExecutorError::ConnectionError(format!("Exchange request: {e}")))?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ExecutorError::ConnectionError(format!(
+                "HTTP {status}: {body}"
+            )));
+        }
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| ExecutorError::ConnectionError(format!("Exchange response: {e}")))?;
+        parse_order_response(&body)
+    }
+}
+
+/// REST order submissions between `ExecutorLoop` ticks.
+///
+/// Responses are applied on the tick after they arrive. Orders of a request
+/// that failed in transport may have reached the exchange, so they are held
+/// as unknown until looked up by cloid, never resent or dropped blindly.
+#[derive(Debug, Default)]
+pub(crate) struct RestSubmissions {
+    /// Post ID → signed action and orders of requests in flight.
+    pub(crate) inflight: HashMap<u64, (SignedAction, Vec<PendingOrder>)>,
+    /// Post ID and response of finished requests not yet applied.
+    pub(crate) done: Vec<(u64, ExecutorResult<Vec<OrderResponseStatus>>)>,
+    /// Orders whose outcome is unknown, awaiting reconciliation.
+    pub(crate) unknown: Vec<PendingOrder>,
+}
+
+impl RestSubmissions {
+    /// Whether an order is in flight or awaiting reconciliation.
+    pub(crate) fn has_order(&self, cloid: &ClientOrderId) -> bool {
+        self.inflight
+            .values()
+            .flat_map(|(_, orders)| orders)
+            .chain(&self.unknown)
+            .any(|order| order.cloid == *cloid)
+    }
+}
+
+/// Per-order statuses of an `/exchange` order response.
+fn parse_order_response(body: &serde_json::Value) -> ExecutorResult<Vec<OrderResponseStatus>> {
+    if body.get("status").and_then(|s| s.as_str()) != Some("ok") {
+        return Err(ExecutorError::OrderRejected(format!(
+            "Order action rejected: {}",
+            body.get("response").unwrap_or(body)
+        )));
+    }
+    let payload: ActionResponsePayload = serde_json::from_value(body.clone())
+        .map_err(|e| ExecutorError::SubmissionFailed(format!("Order response: {e}")))?;
+    Ok(payload.parse_statuses())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, ClientOrderId, DexId, MarketKey, OrderSide, Price, Size};
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    #[test]
+    fn test_scope_covers() {
+        let mut order = PendingOrder::new(
+            ClientOrderId::new(),
+            MarketKey::new(DexId::XYZ, AssetId::new(0)),
+            OrderSide::Sell,
+            Price::new(dec!(100)),
+            Size::new(dec!(1)),
+            true,
+            0,
+        );
+        assert!(RestFallbackScope::ExitsOnly.covers(&order));
+        order.reduce_only = false;
+        assert!(!RestFallbackScope::ExitsOnly.covers(&order));
+        assert!(RestFallbackScope::All.covers(&order));
+
+        let config: RestFallbackConfig = serde_json::from_value(json!({"enabled": true})).unwrap();
+        assert_eq!(config.scope, RestFallbackScope::ExitsOnly);
+        let config: RestFallbackConfig = serde_json::from_value(json!({"scope": "all"})).unwrap();
+        assert_eq!(config.scope, RestFallbackScope::All);
+    }
+
+    #[test]
+    fn test_parse_order_response() {
+        let ok = json!({
+            "status": "ok",
+            "response": {
+                "type": "order",
+                "data": {"statuses": [
+                    {"filled": {"totalSz": "1.0", "avgPx": "100.5", "oid": 42}},
+                    {"error": "Order could not immediately match against any resting orders."}
+                ]}
+            }
+        });
+        let statuses = parse_order_response(&ok).unwrap();
+        assert_eq!(statuses.len(), 2);
+        assert!(matches!(
+            statuses[0],
+            OrderResponseStatus::Filled { oid: 42, .. }
+        ));
+        assert!(matches!(statuses[1], OrderResponseStatus::Error { .. }));
+
+        let err = json!({"status": "err", "response": "Too many requests"});
+        assert!(matches!(
+            parse_order_response(&err),
+            Err(ExecutorError::OrderRejected(msg)) if msg.contains("Too many")
+        ));
+    }
+}
@@ -0,0 +1,310 @@
+//! Rejection-aware order retry.
+//!
+//! Some exchange rejections are about the order as sent, not about the
+//! account: a price off the tick grid after a spec change, an ALO price
+//! that crossed for a moment. [`RetryPolicy`] classifies reject reasons and
+//! schedules transient ones for another attempt with exponential backoff.
+//! Account-level rejections (margin, reduce-only, minimum notional) are
+//! never retried.
+//!
+//! The policy only keeps the schedule; `ExecutorLoop` re-quantizes the
+//! price against the current spec and re-enqueues due orders each tick.
+
+use hip3_core::{ClientOrderId, PendingOrder};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Reject reasons worth another attempt (status codes and response messages).
+const TRANSIENT_REJECTS: &[&str] = &[
+    "tickRejected",
+    "Price must be divisible by tick size",
+    "Order has invalid price",
+    "badAloPxRejected",
+    "Post only order would have immediately matched",
+    "oracleRejected",
+    "Order price cannot be more than 80% away from the reference price",
+];
+
+/// Configuration for retrying rejected orders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// Whether transient rejections are retried. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Retries per order after the first attempt. Default: 2.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Backoff before the first retry (ms), doubled per retry. Default: 100.
+    #[serde(default = "default_base_backoff_ms")]
+    pub base_backoff_ms: u64,
+    /// Backoff cap (ms). Default: 2000.
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+}
+
+fn default_max_retries() -> u32 {
+    2
+}
+
+fn default_base_backoff_ms() -> u64 {
+    100
+}
+
+fn default_max_backoff_ms() -> u64 {
+    2_000
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_retries: default_max_retries(),
+            base_backoff_ms: default_base_backoff_ms(),
+            max_backoff_ms: default_max_backoff_ms(),
+        }
+    }
+}
+
+/// Reject reason classification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectClass {
+    /// The same order may succeed if sent again.
+    Transient,
+    /// Retrying cannot help.
+    Permanent,
+}
+
+/// Classify an exchange reject reason.
+///
+/// Unknown reasons are treated as permanent.
+#[must_use]
+pub fn classify_reject(reason: &str) -> RejectClass {
+    if TRANSIENT_REJECTS.iter().any(|r| reason.contains(r)) {
+        RejectClass::Transient
+    } else {
+        RejectClass::Permanent
+    }
+}
+
+/// What to do with a rejected order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// Scheduled for another attempt.
+    Retry {
+        /// Retry number (1 = first retry).
+        attempt: u32,
+        /// Delay before the retry is enqueued (ms).
+        backoff_ms: u64,
+    },
+    /// Not retried.
+    GiveUp {
+        /// Why the order was rejected.
+        class: RejectClass,
+        /// Retries already spent on this order.
+        retries: u32,
+    },
+}
+
+/// An order waiting out its backoff.
+#[derive(Debug, Clone)]
+struct ScheduledRetry {
+    due_ms: u64,
+    order: PendingOrder,
+}
+
+/// Retry schedule for rejected orders.
+///
+/// Retries keep the original cloid: a rejected order never reached the book,
+/// so the id is still unused and position tracking stays on one entry.
+pub struct RetryPolicy {
+    config: RetryConfig,
+    /// Retries spent per cloid.
+    retries: Mutex<HashMap<ClientOrderId, u32>>,
+    /// Orders waiting out their backoff.
+    scheduled: Mutex<Vec<ScheduledRetry>>,
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy.
+    #[must_use]
+    pub fn new(config: RetryConfig) -> Self {
+        Self {
+            config,
+            retries: Mutex::new(HashMap::new()),
+            scheduled: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Get the configuration.
+    #[must_use]
+    pub fn config(&self) -> &RetryConfig {
+        &self.config
+    }
+
+    /// Backoff before retry number `attempt` (1-based).
+    #[must_use]
+    pub fn backoff_ms(&self, attempt: u32) -> u64 {
+        let shift = attempt.saturating_sub(1).min(20);
+        self.config
+            .base_backoff_ms
+            .saturating_mul(1 << shift)
+            .min(self.config.max_backoff_ms)
+    }
+
+    /// Decide on a rejected order, scheduling it if it is to be retried.
+    pub fn on_reject(&self, order: &PendingOrder, reason: &str, now_ms: u64) -> RetryDecision {
+        let class = classify_reject(reason);
+        let mut retries = self.retries.lock();
+        let spent = retries.get(&order.cloid).copied().unwrap_or(0);
+
+        if class == RejectClass::Permanent || spent >= self.config.max_retries {
+            retries.remove(&order.cloid);
+            return RetryDecision::GiveUp {
+                class,
+                retries: spent,
+            };
+        }
+
+        let attempt = spent + 1;
+        retries.insert(order.cloid.clone(), attempt);
+        let backoff_ms = self.backoff_ms(attempt);
+        self.scheduled.lock().push(ScheduledRetry {
+            due_ms: now_ms + backoff_ms,
+            order: order.clone(),
+        });
+        RetryDecision::Retry {
+            attempt,
+            backoff_ms,
+        }
+    }
+
+    /// Remove and return orders whose backoff has elapsed.
+    pub fn take_due(&self, now_ms: u64) -> Vec<PendingOrder> {
+        let mut scheduled = self.scheduled.lock();
+        let mut due = Vec::new();
+        scheduled.retain(|s| {
+            if s.due_ms <= now_ms {
+                due.push(s.order.clone());
+                false
+            } else {
+                true
+            }
+        });
+        due
+    }
+
+    /// Forget an order once it was accepted or abandoned.
+    pub fn clear(&self, cloid: &ClientOrderId) {
+        self.retries.lock().remove(cloid);
+    }
+
+    /// Whether an order is waiting out its backoff.
+    #[must_use]
+    pub fn is_scheduled(&self, cloid: &ClientOrderId) -> bool {
+        self.scheduled
+            .lock()
+            .iter()
+            .any(|s| &s.order.cloid == cloid)
+    }
+
+    /// Number of orders waiting out their backoff.
+    #[must_use]
+    pub fn scheduled_count(&self) -> usize {
+        self.scheduled.lock().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId, MarketKey, OrderSide, Price, Size};
+    use rust_decimal_macros::dec;
+
+    fn order() -> PendingOrder {
+        PendingOrder::new(
+            ClientOrderId::new(),
+            MarketKey::new(DexId::XYZ, AssetId::new(0)),
+            OrderSide::Buy,
+            Price::new(dec!(100.005)),
+            Size::new(dec!(1)),
+            false,
+            0,
+        )
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new(RetryConfig {
+            enabled: true,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_classify_reject() {
+        assert_eq!(classify_reject("tickRejected"), RejectClass::Transient);
+        assert_eq!(
+            classify_reject("Price must be divisible by tick size. asset=110026"),
+            RejectClass::Transient
+        );
+        assert_eq!(
+            classify_reject("perpMarginRejected"),
+            RejectClass::Permanent
+        );
+        assert_eq!(
+            classify_reject("Insufficient margin to place order."),
+            RejectClass::Permanent
+        );
+        assert_eq!(classify_reject("something new"), RejectClass::Permanent);
+    }
+
+    #[test]
+    fn test_transient_retried_with_backoff_until_exhausted() {
+        let policy = policy();
+        let order = order();
+
+        assert_eq!(
+            policy.on_reject(&order, "tickRejected", 1_000),
+            RetryDecision::Retry {
+                attempt: 1,
+                backoff_ms: 100
+            }
+        );
+        assert!(policy.take_due(1_050).is_empty());
+        assert_eq!(policy.take_due(1_100).len(), 1);
+
+        assert_eq!(
+            policy.on_reject(&order, "tickRejected", 2_000),
+            RetryDecision::Retry {
+                attempt: 2,
+                backoff_ms: 200
+            }
+        );
+        assert_eq!(policy.take_due(2_200).len(), 1);
+
+        assert_eq!(
+            policy.on_reject(&order, "tickRejected", 3_000),
+            RetryDecision::GiveUp {
+                class: RejectClass::Transient,
+                retries: 2
+            }
+        );
+        assert_eq!(policy.scheduled_count(), 0);
+    }
+
+    #[test]
+    fn test_permanent_not_retried_and_backoff_capped() {
+        let policy = policy();
+        assert_eq!(
+            policy.on_reject(&order(), "perpMarginRejected", 0),
+            RetryDecision::GiveUp {
+                class: RejectClass::Permanent,
+                retries: 0
+            }
+        );
+        assert_eq!(policy.scheduled_count(), 0);
+        assert_eq!(policy.backoff_ms(5), 1_600);
+        assert_eq!(policy.backoff_ms(6), 2_000);
+        assert_eq!(policy.backoff_ms(40), 2_000);
+    }
+}
@@ -2,54 +2,216 @@
 //!
 //! This module provides:
 //! - `HardStopLatch`: Circuit breaker for emergency trading halt
+//! - `HardStopReason`: Kind of condition that triggered the latch
+//! - `HardStopHistoryEntry`: One trigger in the latch's stop/clear timeline
+//! - `EntryPause`: Operator pause of new entries (exits keep running)
+//!
+//! Both report their changes to an optional [`RiskEventLog`].
 //! - `ExecutionEvent`: Events for risk monitoring
 //! - `RiskMonitor`: Background task for monitoring risk conditions
 //! - `RiskMonitorConfig`: Configuration for risk thresholds
+//! - `RiskMonitorSettings`: Runtime-updatable thresholds shared with the
+//!   admin endpoint
 
 use std::collections::VecDeque;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use parking_lot::Mutex;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
 use hip3_core::{ClientOrderId, MarketKey, Price, Size};
 
+use crate::error::{ExecutorError, ExecutorResult};
+use crate::risk_events::{RiskEventKind, RiskEventLog};
+
+/// Maximum entries kept in the [`HardStopLatch`] history.
+const MAX_HARD_STOP_HISTORY: usize = 200;
+
 // ============================================================================
 // HardStopLatch
 // ============================================================================
 
+/// Severity level of the hard stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum HardStopLevel {
+    /// Normal operation.
+    Clear = 0,
+    /// Level 1: new entries blocked; exits, reduce-only orders and MM quote
+    /// cancels still go out.
+    PauseEntries = 1,
+    /// Level 2: everything cancelled and open positions flattened.
+    FullStop = 2,
+}
+
+impl HardStopLevel {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Clear,
+            1 => Self::PauseEntries,
+            _ => Self::FullStop,
+        }
+    }
+
+    /// Numeric level (0 = clear, 1 = pause entries, 2 = full stop).
+    #[must_use]
+    pub fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Label for logs and records.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Clear => "clear",
+            Self::PauseEntries => "pause_entries",
+            Self::FullStop => "full_stop",
+        }
+    }
+}
+
+/// Kind of condition that triggered the hard stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HardStopReason {
+    /// Cumulative realized loss over the limit.
+    CumulativeLoss,
+    /// Too many consecutive losing trades.
+    ConsecutiveLosses,
+    /// Too many failed flatten attempts.
+    FlattenFailed,
+    /// Too many exchange rejections per hour.
+    Rejections,
+    /// Consecutive trades with excessive slippage.
+    Slippage,
+    /// Local positions drifted from the exchange.
+    PositionDrift,
+    /// Operator or unclassified trigger.
+    Manual,
+    /// The exchange liquidated the account.
+    Liquidation,
+}
+
+impl HardStopReason {
+    /// Label for logs and metrics.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::CumulativeLoss => "cumulative_loss",
+            Self::ConsecutiveLosses => "consecutive_losses",
+            Self::FlattenFailed => "flatten_failed",
+            Self::Rejections => "rejections",
+            Self::Slippage => "slippage",
+            Self::PositionDrift => "position_drift",
+            Self::Manual => "manual",
+            Self::Liquidation => "liquidation",
+        }
+    }
+
+    /// Whether a stop for this reason may clear itself once the condition is
+    /// gone. Loss-based, flatten-failure and manual stops always need an
+    /// operator reset.
+    #[must_use]
+    pub fn may_auto_recover(self) -> bool {
+        matches!(
+            self,
+            Self::Rejections | Self::Slippage | Self::PositionDrift
+        )
+    }
+
+    /// Component that raises stops of this kind.
+    #[must_use]
+    pub fn source(self) -> &'static str {
+        match self {
+            Self::CumulativeLoss
+            | Self::ConsecutiveLosses
+            | Self::FlattenFailed
+            | Self::Rejections
+            | Self::Slippage
+            | Self::Liquidation => "risk_monitor",
+            Self::PositionDrift => "position_reconciler",
+            Self::Manual => "operator",
+        }
+    }
+}
+
+impl std::fmt::Display for HardStopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One trigger in the [`HardStopLatch`] history.
+///
+/// Recorded when a trigger raises the level or adds a new
+/// [`HardStopReason`]; repeats of a known breach are not recorded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HardStopHistoryEntry {
+    /// Trigger time (Unix ms).
+    pub triggered_at_ms: i64,
+    /// Latch level after the trigger.
+    pub level: HardStopLevel,
+    /// Kind of condition.
+    pub kind: HardStopReason,
+    /// Component that raised it (see [`HardStopReason::source`]).
+    pub source: &'static str,
+    /// Trigger detail.
+    pub reason: String,
+    /// Time the latch was cleared (Unix ms), None while latched.
+    pub cleared_at_ms: Option<i64>,
+    /// How it was cleared: "operator" or "auto_recovery".
+    pub cleared_by: Option<&'static str>,
+}
+
 /// Hard stop latch for emergency circuit breaker.
 ///
-/// When triggered, the system enters an emergency mode where:
+/// The latch is tiered (see [`HardStopLevel`]). At any triggered level:
 /// - New orders are rejected/dropped
 /// - Only reduce_only orders are processed (to close positions)
 /// - Cancels continue to be processed
 ///
-/// Once triggered, the latch remains triggered until manually reset.
-/// This prevents accidental resumption of trading after an emergency.
+/// At [`HardStopLevel::FullStop`] open positions are also flattened and
+/// MM quoting stops entirely.
+///
+/// The level only escalates; once triggered, the latch remains triggered
+/// until manually reset. This prevents accidental resumption of trading
+/// after an emergency. The only exception is `HardStopRecovery`, which may
+/// clear a latch whose every [`HardStopReason`] allows auto-recovery.
 ///
 /// # Example
 /// ```
-/// use hip3_executor::HardStopLatch;
+/// use hip3_executor::{HardStopLatch, HardStopLevel};
 ///
 /// let latch = HardStopLatch::new();
 /// assert!(!latch.is_triggered());
 ///
-/// latch.trigger("Test trigger");
+/// latch.trigger_level(HardStopLevel::PauseEntries, "Rejections");
 /// assert!(latch.is_triggered());
+/// assert!(!latch.is_full_stop());
+///
+/// latch.trigger("Test trigger");
+/// assert!(latch.is_full_stop());
 /// assert!(latch.trigger_reason().is_some());
 /// ```
 #[derive(Debug)]
 pub struct HardStopLatch {
-    /// Whether the hard stop has been triggered.
-    triggered: AtomicBool,
-    /// Reason for triggering (set on first trigger only).
+    /// Current [`HardStopLevel`] as u8.
+    level: AtomicU8,
+    /// Reason for the current level (set when the level is reached).
     trigger_reason: Mutex<Option<String>>,
-    /// Time when triggered (set on first trigger only).
+    /// Kinds of every trigger since the last reset.
+    reasons: Mutex<Vec<HardStopReason>>,
+    /// Time of the first trigger.
     trigger_time: Mutex<Option<Instant>>,
+    /// Stop/clear timeline, oldest first.
+    history: Mutex<VecDeque<HardStopHistoryEntry>>,
+    /// Audit trail of triggers and clears (optional).
+    event_log: Option<RiskEventLog>,
 }
 
 impl HardStopLatch {
@@ -57,35 +219,117 @@ impl HardStopLatch {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            triggered: AtomicBool::new(false),
+            level: AtomicU8::new(HardStopLevel::Clear.as_u8()),
             trigger_reason: Mutex::new(None),
+            reasons: Mutex::new(Vec::new()),
             trigger_time: Mutex::new(None),
+            history: Mutex::new(VecDeque::new()),
+            event_log: None,
         }
     }
 
-    /// Check if the hard stop has been triggered.
+    /// Record triggers and clears in `log`.
+    #[must_use]
+    pub fn with_event_log(mut self, log: RiskEventLog) -> Self {
+        self.event_log = Some(log);
+        self
+    }
+
+    /// Current level.
+    #[must_use]
+    pub fn level(&self) -> HardStopLevel {
+        HardStopLevel::from_u8(self.level.load(Ordering::Acquire))
+    }
+
+    /// Check if the hard stop has been triggered at any level
+    /// (new entries blocked).
     #[must_use]
     pub fn is_triggered(&self) -> bool {
-        self.triggered.load(Ordering::Acquire)
+        self.level() != HardStopLevel::Clear
     }
 
-    /// Trigger the hard stop.
+    /// Check if the hard stop is at [`HardStopLevel::FullStop`]
+    /// (cancel everything and flatten).
+    #[must_use]
+    pub fn is_full_stop(&self) -> bool {
+        self.level() == HardStopLevel::FullStop
+    }
+
+    /// Trigger a full stop.
     ///
-    /// Once triggered, new orders will be rejected and only
-    /// reduce_only orders will be processed.
+    /// Once triggered, new orders will be rejected, only reduce_only orders
+    /// will be processed and open positions are flattened.
     ///
-    /// If already triggered, the reason and time are NOT updated.
+    /// If already at full stop, the reason and time are NOT updated.
     /// This preserves the original trigger context.
     pub fn trigger(&self, reason: &str) {
-        // Use swap to detect if we're the first to trigger
-        if !self.triggered.swap(true, Ordering::AcqRel) {
-            // We're the first to trigger - record reason and time
-            *self.trigger_reason.lock() = Some(reason.to_string());
-            *self.trigger_time.lock() = Some(Instant::now());
-            error!(reason, "🛑 HARD STOP TRIGGERED");
+        self.trigger_level(HardStopLevel::FullStop, reason);
+    }
+
+    /// Raise the latch to `level` as a [`HardStopReason::Manual`] stop.
+    ///
+    /// Lower or equal levels are ignored, so the reason is that of the
+    /// first trigger at the current level.
+    pub fn trigger_level(&self, level: HardStopLevel, reason: &str) {
+        self.trigger_with(level, HardStopReason::Manual, reason);
+    }
+
+    /// Raise the latch to `level` for `kind`.
+    ///
+    /// `kind` is recorded even when the level does not change, so a latch
+    /// only auto-recovers if every condition that hit it allows it, and the
+    /// event log sees every breach.
+    pub fn trigger_with(&self, level: HardStopLevel, kind: HardStopReason, reason: &str) {
+        if level == HardStopLevel::Clear {
+            return;
+        }
+        let (previous, new_kind) = {
+            let mut reasons = self.reasons.lock();
+            let new_kind = !reasons.contains(&kind);
+            if new_kind {
+                reasons.push(kind);
+            }
+            let previous =
+                HardStopLevel::from_u8(self.level.fetch_max(level.as_u8(), Ordering::AcqRel));
+            (previous, new_kind)
+        };
+        if let Some(ref log) = self.event_log {
+            log.hard_stop_trigger(kind, level.max(previous), previous, reason);
+        }
+        if new_kind || level > previous {
+            let mut history = self.history.lock();
+            if history.len() >= MAX_HARD_STOP_HISTORY {
+                history.pop_front();
+            }
+            history.push_back(HardStopHistoryEntry {
+                triggered_at_ms: chrono::Utc::now().timestamp_millis(),
+                level: level.max(previous),
+                kind,
+                source: kind.source(),
+                reason: reason.to_string(),
+                cleared_at_ms: None,
+                cleared_by: None,
+            });
+        }
+        if level <= previous {
+            return;
+        }
+        *self.trigger_reason.lock() = Some(reason.to_string());
+        self.trigger_time.lock().get_or_insert_with(Instant::now);
+        match level {
+            HardStopLevel::FullStop => {
+                error!(reason, %kind, ?previous, "🛑 HARD STOP TRIGGERED")
+            }
+            _ => error!(reason, %kind, ?level, "⏸️ HARD STOP: new entries blocked"),
         }
     }
 
+    /// Kinds of every trigger since the last reset.
+    #[must_use]
+    pub fn reasons(&self) -> Vec<HardStopReason> {
+        self.reasons.lock().clone()
+    }
+
     /// Get the trigger reason (if triggered).
     #[must_use]
     pub fn trigger_reason(&self) -> Option<String> {
@@ -98,6 +342,12 @@ impl HardStopLatch {
         self.trigger_time.lock().map(|t| t.elapsed())
     }
 
+    /// Stop/clear timeline, oldest first (last 200 triggers).
+    #[must_use]
+    pub fn history(&self) -> Vec<HardStopHistoryEntry> {
+        self.history.lock().iter().cloned().collect()
+    }
+
     /// Reset the hard stop latch.
     ///
     /// Only call this after the emergency condition has been resolved
@@ -106,10 +356,62 @@ impl HardStopLatch {
     /// # Warning
     /// This should only be called by a human operator, not automatically.
     pub fn reset(&self) {
-        self.triggered.store(false, Ordering::Release);
+        let previous = self.level();
+        let reason = self.trigger_reason();
+        self.clear(&mut self.reasons.lock(), "operator");
+        if previous != HardStopLevel::Clear {
+            if let Some(ref log) = self.event_log {
+                log.hard_stop_cleared(
+                    RiskEventKind::HardStopReset,
+                    previous,
+                    reason.as_deref().unwrap_or_default(),
+                );
+            }
+        }
+        warn!("⚠️ HardStop RESET by operator - normal operation resumed");
+    }
+
+    /// Clear the latch if every trigger since the last reset has a kind in
+    /// `allowed`.
+    ///
+    /// Returns the cleared kinds, or `None` if the latch is not triggered or
+    /// a trigger kind is not allowed. A trigger racing with this call either
+    /// lands before the check (and is judged by it) or after the clear.
+    pub fn try_auto_reset(&self, allowed: &[HardStopReason]) -> Option<Vec<HardStopReason>> {
+        let mut reasons = self.reasons.lock();
+        if !self.is_triggered() || !reasons.iter().all(|r| allowed.contains(r)) {
+            return None;
+        }
+        let cleared = reasons.clone();
+        let reason = self.trigger_reason();
+        let previous = self.level();
+        self.clear(&mut reasons, "auto_recovery");
+        if let Some(ref log) = self.event_log {
+            let kinds: Vec<&str> = cleared.iter().map(|r| r.as_str()).collect();
+            log.hard_stop_cleared(RiskEventKind::HardStopRecovered, previous, &kinds.join(","));
+        }
+        warn!(
+            ?cleared,
+            previous_reason = ?reason,
+            "♻️ HardStop AUTO-RECOVERED - normal operation resumed"
+        );
+        Some(cleared)
+    }
+
+    fn clear(&self, reasons: &mut Vec<HardStopReason>, cleared_by: &'static str) {
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        for entry in self.history.lock().iter_mut().rev() {
+            if entry.cleared_at_ms.is_some() {
+                break;
+            }
+            entry.cleared_at_ms = Some(now_ms);
+            entry.cleared_by = Some(cleared_by);
+        }
+        reasons.clear();
+        self.level
+            .store(HardStopLevel::Clear.as_u8(), Ordering::Release);
         *self.trigger_reason.lock() = None;
         *self.trigger_time.lock() = None;
-        warn!("⚠️ HardStop RESET by operator - normal operation resumed");
     }
 }
 
@@ -119,6 +421,74 @@ impl Default for HardStopLatch {
     }
 }
 
+// ============================================================================
+// EntryPause
+// ============================================================================
+
+/// Operator switch that pauses new entries.
+///
+/// Unlike [`HardStopLatch`], pausing does not flatten positions: exits,
+/// stops and cancels keep running, only new entries (signals and MM quotes)
+/// are rejected until resumed.
+#[derive(Debug, Default)]
+pub struct EntryPause {
+    /// Whether new entries are paused.
+    paused: AtomicBool,
+    /// Reason given when pausing.
+    reason: Mutex<Option<String>>,
+    /// Audit trail of pauses and resumes (optional).
+    event_log: Option<RiskEventLog>,
+}
+
+impl EntryPause {
+    /// Create a new switch (not paused).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record pauses and resumes in `log`.
+    #[must_use]
+    pub fn with_event_log(mut self, log: RiskEventLog) -> Self {
+        self.event_log = Some(log);
+        self
+    }
+
+    /// Check if new entries are paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Acquire)
+    }
+
+    /// Pause new entries.
+    pub fn pause(&self, reason: &str) {
+        *self.reason.lock() = Some(reason.to_string());
+        self.paused.store(true, Ordering::Release);
+        if let Some(ref log) = self.event_log {
+            log.entry_pause(true, reason);
+        }
+        warn!(reason, "⏸️ New entries PAUSED by operator");
+    }
+
+    /// Resume new entries.
+    pub fn resume(&self) {
+        let was_paused = self.paused.swap(false, Ordering::AcqRel);
+        let reason = self.reason.lock().take();
+        if was_paused {
+            if let Some(ref log) = self.event_log {
+                log.entry_pause(false, reason.as_deref().unwrap_or_default());
+            }
+        }
+        warn!("▶️ New entries RESUMED by operator");
+    }
+
+    /// Pause reason (if paused).
+    #[must_use]
+    pub fn reason(&self) -> Option<String> {
+        self.reason.lock().clone()
+    }
+}
+
 // ============================================================================
 // ExecutionEvent
 // ============================================================================
@@ -177,6 +547,14 @@ pub enum ExecutionEvent {
         /// Actual edge in basis points.
         actual_edge_bps: f64,
     },
+
+    /// The exchange liquidated the account (userEvents).
+    Liquidation {
+        /// Notional of the liquidated positions (USD).
+        notional: Decimal,
+        /// Account value at liquidation (USD).
+        account_value: Decimal,
+    },
 }
 
 // ============================================================================
@@ -184,7 +562,7 @@ pub enum ExecutionEvent {
 // ============================================================================
 
 /// Configuration for risk monitoring thresholds.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct RiskMonitorConfig {
     /// Maximum cumulative loss before HardStop (e.g., $20).
     pub max_cumulative_loss: Decimal,
@@ -213,6 +591,137 @@ impl Default for RiskMonitorConfig {
     }
 }
 
+impl RiskMonitorConfig {
+    /// Check the thresholds can be applied.
+    pub fn validate(&self) -> ExecutorResult<()> {
+        if self.max_cumulative_loss <= Decimal::ZERO {
+            return Err(ExecutorError::InvalidConfig(
+                "max_cumulative_loss must be positive".to_string(),
+            ));
+        }
+        if !self.max_slippage_bps.is_finite() || self.max_slippage_bps <= 0.0 {
+            return Err(ExecutorError::InvalidConfig(
+                "max_slippage_bps must be positive".to_string(),
+            ));
+        }
+        // Only the last 10 slippage measurements are kept
+        if !(1..=10).contains(&self.slippage_consecutive_threshold) {
+            return Err(ExecutorError::InvalidConfig(
+                "slippage_consecutive_threshold must be between 1 and 10".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Partial update of [`RiskMonitorConfig`]; unset fields keep their value.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RiskMonitorConfigUpdate {
+    /// New maximum cumulative loss.
+    #[serde(default)]
+    pub max_cumulative_loss: Option<Decimal>,
+    /// New maximum consecutive losses.
+    #[serde(default)]
+    pub max_consecutive_losses: Option<u32>,
+    /// New maximum flatten failures.
+    #[serde(default)]
+    pub max_flatten_failed: Option<u32>,
+    /// New maximum rejected orders per hour.
+    #[serde(default)]
+    pub max_rejected_per_hour: Option<u32>,
+    /// New maximum slippage (bps).
+    #[serde(default)]
+    pub max_slippage_bps: Option<f64>,
+    /// New consecutive high-slippage trade count.
+    #[serde(default)]
+    pub slippage_consecutive_threshold: Option<u32>,
+}
+
+/// [`RiskMonitorConfig`] shared by the [`RiskMonitor`] and the admin
+/// endpoint, so thresholds can be tightened without a restart.
+///
+/// The monitor reads the thresholds on every event; counters are kept, so a
+/// tightened threshold trips on the next event that checks it.
+#[derive(Debug, Clone)]
+pub struct RiskMonitorSettings {
+    config: Arc<Mutex<RiskMonitorConfig>>,
+    /// Audit trail of threshold changes (optional).
+    event_log: Option<RiskEventLog>,
+}
+
+impl RiskMonitorSettings {
+    /// Create settings starting from `config`.
+    #[must_use]
+    pub fn new(config: RiskMonitorConfig) -> Self {
+        Self {
+            config: Arc::new(Mutex::new(config)),
+            event_log: None,
+        }
+    }
+
+    /// Record threshold changes in `log`.
+    #[must_use]
+    pub fn with_event_log(mut self, log: RiskEventLog) -> Self {
+        self.event_log = Some(log);
+        self
+    }
+
+    /// Current thresholds.
+    #[must_use]
+    pub fn get(&self) -> RiskMonitorConfig {
+        self.config.lock().clone()
+    }
+
+    /// Apply `update` if the resulting thresholds are valid.
+    ///
+    /// Returns the new thresholds. `reason` is recorded with the changes.
+    pub fn update(
+        &self,
+        update: &RiskMonitorConfigUpdate,
+        reason: &str,
+    ) -> ExecutorResult<RiskMonitorConfig> {
+        let mut config = self.config.lock();
+        let mut next = config.clone();
+        let mut changes = Vec::new();
+        macro_rules! apply {
+            ($($field:ident),*) => {$(
+                if let Some(value) = update.$field {
+                    if value != next.$field {
+                        changes.push(format!(
+                            "{}: {} -> {}",
+                            stringify!($field),
+                            next.$field,
+                            value
+                        ));
+                        next.$field = value;
+                    }
+                }
+            )*};
+        }
+        apply!(
+            max_cumulative_loss,
+            max_consecutive_losses,
+            max_flatten_failed,
+            max_rejected_per_hour,
+            max_slippage_bps,
+            slippage_consecutive_threshold
+        );
+        next.validate()?;
+        if changes.is_empty() {
+            return Ok(next);
+        }
+
+        *config = next.clone();
+        drop(config);
+        let changes = changes.join(", ");
+        warn!(%changes, reason, "RiskMonitor thresholds updated");
+        if let Some(ref log) = self.event_log {
+            log.risk_config_changed(&format!("{changes} ({reason})"));
+        }
+        Ok(next)
+    }
+}
+
 // ============================================================================
 // RiskMonitor
 // ============================================================================
@@ -239,17 +748,19 @@ impl ExecutorHandle {
 /// Background task for monitoring risk conditions.
 ///
 /// The `RiskMonitor` receives `ExecutionEvent`s and tracks various
-/// risk metrics. When thresholds are exceeded, it triggers HardStop.
+/// risk metrics. When thresholds are exceeded, it triggers HardStop at a
+/// level matching the severity, escalating to a full stop when a level 1
+/// condition keeps worsening.
 ///
 /// # Monitored Conditions
 ///
-/// | Trigger | Threshold |
-/// |---------|-----------|
-/// | Cumulative loss | > $20 |
-/// | Consecutive losses | > 5 |
-/// | Flatten failures | > 3 |
-/// | Rejected orders | > 10/hour |
-/// | Slippage | > 50 bps for 3 consecutive |
+/// | Trigger | Threshold | Level |
+/// |---------|-----------|-------|
+/// | Cumulative loss | > $20 | FullStop |
+/// | Flatten failures | > 3 | FullStop |
+/// | Consecutive losses | > 5 (> 10) | PauseEntries (FullStop) |
+/// | Rejected orders | > 10/hour (> 20/hour) | PauseEntries (FullStop) |
+/// | Slippage | > 50 bps for 3 consecutive | PauseEntries |
 pub struct RiskMonitor {
     /// Event receiver.
     event_rx: mpsc::Receiver<ExecutionEvent>,
@@ -272,8 +783,8 @@ pub struct RiskMonitor {
     /// Recent slippage measurements.
     slippage_history: VecDeque<f64>,
 
-    /// Configuration.
-    config: RiskMonitorConfig,
+    /// Thresholds (updatable at runtime).
+    settings: RiskMonitorSettings,
 }
 
 impl RiskMonitor {
@@ -301,10 +812,17 @@ impl RiskMonitor {
             rejected_count_hourly: 0,
             rejected_reset_time: Instant::now(),
             slippage_history: VecDeque::with_capacity(10),
-            config,
+            settings: RiskMonitorSettings::new(config),
         }
     }
 
+    /// Use shared `settings` instead of the fixed startup config.
+    #[must_use]
+    pub fn with_settings(mut self, settings: RiskMonitorSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
     /// Run the risk monitoring loop.
     ///
     /// Processes events until the channel is closed. When a threshold
@@ -313,9 +831,9 @@ impl RiskMonitor {
         info!("RiskMonitor started");
 
         while let Some(event) = self.event_rx.recv().await {
-            if let Some(reason) = self.process_event(event) {
+            if let Some((level, kind, reason)) = self.process_event(event) {
                 // HardStop triggered
-                self.hard_stop_latch.trigger(&reason);
+                self.hard_stop_latch.trigger_with(level, kind, &reason);
                 self.executor_handle.on_hard_stop(&reason).await;
                 // Continue processing events for logging/metrics
             }
@@ -326,8 +844,12 @@ impl RiskMonitor {
 
     /// Process a single event and check for HardStop conditions.
     ///
-    /// Returns `Some(reason)` if HardStop should be triggered.
-    fn process_event(&mut self, event: ExecutionEvent) -> Option<String> {
+    /// Returns `Some((level, kind, reason))` if HardStop should be triggered.
+    fn process_event(
+        &mut self,
+        event: ExecutionEvent,
+    ) -> Option<(HardStopLevel, HardStopReason, String)> {
+        let config = self.settings.get();
         match event {
             ExecutionEvent::PositionClosed { realized_pnl, .. } => {
                 self.cumulative_pnl += realized_pnl;
@@ -339,18 +861,28 @@ impl RiskMonitor {
                 }
 
                 // Check cumulative loss threshold
-                if self.cumulative_pnl < -self.config.max_cumulative_loss {
-                    return Some(format!(
-                        "Cumulative loss exceeded: {} (threshold: -{})",
-                        self.cumulative_pnl, self.config.max_cumulative_loss
+                if self.cumulative_pnl < -config.max_cumulative_loss {
+                    return Some((
+                        HardStopLevel::FullStop,
+                        HardStopReason::CumulativeLoss,
+                        format!(
+                            "Cumulative loss exceeded: {} (threshold: -{})",
+                            self.cumulative_pnl, config.max_cumulative_loss
+                        ),
                     ));
                 }
 
                 // Check consecutive loss threshold
-                if self.consecutive_losses > self.config.max_consecutive_losses {
-                    return Some(format!(
-                        "Consecutive losses exceeded: {} (threshold: {})",
-                        self.consecutive_losses, self.config.max_consecutive_losses
+                if self.consecutive_losses > config.max_consecutive_losses {
+                    let level =
+                        Self::escalated(self.consecutive_losses, config.max_consecutive_losses);
+                    return Some((
+                        level,
+                        HardStopReason::ConsecutiveLosses,
+                        format!(
+                            "Consecutive losses exceeded: {} (threshold: {})",
+                            self.consecutive_losses, config.max_consecutive_losses
+                        ),
                     ));
                 }
             }
@@ -359,10 +891,14 @@ impl RiskMonitor {
                 self.flatten_failed_count += 1;
                 error!(?market, reason, "Flatten failed");
 
-                if self.flatten_failed_count > self.config.max_flatten_failed {
-                    return Some(format!(
-                        "Flatten failed count exceeded: {} (threshold: {})",
-                        self.flatten_failed_count, self.config.max_flatten_failed
+                if self.flatten_failed_count > config.max_flatten_failed {
+                    return Some((
+                        HardStopLevel::FullStop,
+                        HardStopReason::FlattenFailed,
+                        format!(
+                            "Flatten failed count exceeded: {} (threshold: {})",
+                            self.flatten_failed_count, config.max_flatten_failed
+                        ),
                     ));
                 }
             }
@@ -397,10 +933,16 @@ impl RiskMonitor {
                 self.rejected_count_hourly += 1;
                 warn!(?cloid, reason, "Order rejected");
 
-                if self.rejected_count_hourly > self.config.max_rejected_per_hour {
-                    return Some(format!(
-                        "Rejected count exceeded: {}/hour (threshold: {})",
-                        self.rejected_count_hourly, self.config.max_rejected_per_hour
+                if self.rejected_count_hourly > config.max_rejected_per_hour {
+                    let level =
+                        Self::escalated(self.rejected_count_hourly, config.max_rejected_per_hour);
+                    return Some((
+                        level,
+                        HardStopReason::Rejections,
+                        format!(
+                            "Rejected count exceeded: {}/hour (threshold: {})",
+                            self.rejected_count_hourly, config.max_rejected_per_hour
+                        ),
                     ));
                 }
             }
@@ -419,24 +961,42 @@ impl RiskMonitor {
                 }
 
                 // Check for consecutive high slippage
-                let threshold = self.config.slippage_consecutive_threshold as usize;
+                let threshold = config.slippage_consecutive_threshold as usize;
                 if self.slippage_history.len() >= threshold {
                     let consecutive_high = self
                         .slippage_history
                         .iter()
                         .rev()
                         .take(threshold)
-                        .all(|&s| s > self.config.max_slippage_bps);
+                        .all(|&s| s > config.max_slippage_bps);
 
                     if consecutive_high {
-                        return Some(format!(
-                            "Slippage exceeded {} bps for {} consecutive trades",
-                            self.config.max_slippage_bps, threshold
+                        return Some((
+                            HardStopLevel::PauseEntries,
+                            HardStopReason::Slippage,
+                            format!(
+                                "Slippage exceeded {} bps for {} consecutive trades",
+                                config.max_slippage_bps, threshold
+                            ),
                         ));
                     }
                 }
             }
 
+            ExecutionEvent::Liquidation {
+                notional,
+                account_value,
+            } => {
+                // Local positions and risk state no longer describe the account
+                return Some((
+                    HardStopLevel::FullStop,
+                    HardStopReason::Liquidation,
+                    format!(
+                        "Account liquidated: notional {notional}, account value {account_value}"
+                    ),
+                ));
+            }
+
             ExecutionEvent::Fill { .. } => {
                 // Fills are tracked for metrics but don't trigger HardStop directly
                 // PnL impact is captured via PositionClosed
@@ -446,6 +1006,16 @@ impl RiskMonitor {
         None
     }
 
+    /// Level for a count past its threshold: pause entries, escalating to a
+    /// full stop at twice the threshold.
+    fn escalated(count: u32, threshold: u32) -> HardStopLevel {
+        if count > threshold.saturating_mul(2) {
+            HardStopLevel::FullStop
+        } else {
+            HardStopLevel::PauseEntries
+        }
+    }
+
     /// Get current risk metrics for monitoring.
     #[must_use]
     pub fn metrics(&self) -> RiskMetrics {
@@ -493,6 +1063,20 @@ mod tests {
     // HardStopLatch Tests
     // ========================================================================
 
+    #[test]
+    fn test_entry_pause_resume() {
+        let pause = EntryPause::new();
+        assert!(!pause.is_paused());
+
+        pause.pause("maintenance");
+        assert!(pause.is_paused());
+        assert_eq!(pause.reason().as_deref(), Some("maintenance"));
+
+        pause.resume();
+        assert!(!pause.is_paused());
+        assert!(pause.reason().is_none());
+    }
+
     #[test]
     fn test_hard_stop_latch_initial_state() {
         let latch = HardStopLatch::new();
@@ -536,6 +1120,83 @@ mod tests {
         assert!(latch.elapsed_since_trigger().is_none());
     }
 
+    #[test]
+    fn test_hard_stop_latch_history() {
+        let latch = HardStopLatch::new();
+        latch.trigger_with(
+            HardStopLevel::PauseEntries,
+            HardStopReason::Rejections,
+            "rejections",
+        );
+        // A repeat of a known breach at the same level is not recorded
+        latch.trigger_with(
+            HardStopLevel::PauseEntries,
+            HardStopReason::Rejections,
+            "rejections again",
+        );
+        latch.trigger_with(
+            HardStopLevel::FullStop,
+            HardStopReason::PositionDrift,
+            "drift",
+        );
+        latch.try_auto_reset(&[HardStopReason::Rejections, HardStopReason::PositionDrift]);
+        latch.trigger_level(HardStopLevel::PauseEntries, "Admin: maintenance");
+
+        let history = latch.history();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].source, "risk_monitor");
+        assert_eq!(history[1].level, HardStopLevel::FullStop);
+        assert_eq!(history[1].source, "position_reconciler");
+        assert!(history[..2]
+            .iter()
+            .all(|e| e.cleared_at_ms.is_some() && e.cleared_by == Some("auto_recovery")));
+        assert_eq!(history[2].source, "operator");
+        assert_eq!(history[2].cleared_at_ms, None);
+
+        latch.reset();
+        assert_eq!(latch.history()[2].cleared_by, Some("operator"));
+    }
+
+    #[test]
+    fn test_hard_stop_latch_auto_reset_requires_allowed_reasons() {
+        let latch = HardStopLatch::new();
+        let allowed = [HardStopReason::Rejections, HardStopReason::PositionDrift];
+        assert!(latch.try_auto_reset(&allowed).is_none());
+
+        latch.trigger_with(
+            HardStopLevel::PauseEntries,
+            HardStopReason::Rejections,
+            "rejections",
+        );
+        latch.trigger_with(
+            HardStopLevel::FullStop,
+            HardStopReason::PositionDrift,
+            "drift",
+        );
+        assert_eq!(
+            latch.try_auto_reset(&allowed),
+            Some(vec![
+                HardStopReason::Rejections,
+                HardStopReason::PositionDrift
+            ])
+        );
+        assert!(!latch.is_triggered());
+
+        // A loss trigger that does not raise the level still pins the latch
+        latch.trigger_with(
+            HardStopLevel::FullStop,
+            HardStopReason::PositionDrift,
+            "drift",
+        );
+        latch.trigger_with(
+            HardStopLevel::PauseEntries,
+            HardStopReason::ConsecutiveLosses,
+            "losses",
+        );
+        assert!(latch.try_auto_reset(&allowed).is_none());
+        assert!(latch.is_full_stop());
+    }
+
     // ========================================================================
     // RiskMonitor Tests
     // ========================================================================
@@ -577,11 +1238,56 @@ mod tests {
             } else {
                 let result = monitor.process_event(event);
                 assert!(result.is_some());
-                assert!(result.unwrap().contains("Cumulative loss"));
+                let (level, _, reason) = result.unwrap();
+                assert_eq!(level, HardStopLevel::FullStop);
+                assert!(reason.contains("Cumulative loss"));
             }
         }
     }
 
+    #[test]
+    fn test_risk_monitor_settings_update() {
+        let (_, monitor, _) = create_test_monitor();
+        let log = RiskEventLog::new(0);
+        let settings =
+            RiskMonitorSettings::new(RiskMonitorConfig::default()).with_event_log(log.clone());
+        let mut monitor = monitor.with_settings(settings.clone());
+
+        let loss = || ExecutionEvent::PositionClosed {
+            market: sample_market(),
+            realized_pnl: dec!(-8),
+        };
+        assert!(monitor.process_event(loss()).is_none());
+
+        // Invalid thresholds are refused as a whole
+        let invalid = RiskMonitorConfigUpdate {
+            max_cumulative_loss: Some(dec!(5)),
+            slippage_consecutive_threshold: Some(0),
+            ..Default::default()
+        };
+        assert!(settings.update(&invalid, "typo").is_err());
+        assert_eq!(settings.get(), RiskMonitorConfig::default());
+
+        // Tightened loss limit trips on the next loss
+        let tighten = RiskMonitorConfigUpdate {
+            max_cumulative_loss: Some(dec!(10)),
+            ..Default::default()
+        };
+        let config = settings.update(&tighten, "bad session").unwrap();
+        assert_eq!(config.max_cumulative_loss, dec!(10));
+        let (level, kind, _) = monitor.process_event(loss()).unwrap();
+        assert_eq!(level, HardStopLevel::FullStop);
+        assert_eq!(kind, HardStopReason::CumulativeLoss);
+
+        let events = log.take();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].kind, RiskEventKind::RiskConfigChanged);
+        assert_eq!(
+            events[0].reason,
+            "max_cumulative_loss: 20 -> 10 (bad session)"
+        );
+    }
+
     #[test]
     fn test_risk_monitor_consecutive_losses() {
         let (_, mut monitor, _) = create_test_monitor();
@@ -598,9 +1304,36 @@ mod tests {
             } else {
                 let result = monitor.process_event(event);
                 assert!(result.is_some());
-                assert!(result.unwrap().contains("Consecutive losses"));
+                let (level, _, reason) = result.unwrap();
+                assert_eq!(level, HardStopLevel::PauseEntries);
+                assert!(reason.contains("Consecutive losses"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_risk_monitor_escalates_to_full_stop() {
+        let (_, mut monitor, latch) = create_test_monitor();
+
+        // Losses 6..=10 pause entries, the 11th (> 2 × 5) escalates
+        for i in 1..=11 {
+            let event = ExecutionEvent::PositionClosed {
+                market: sample_market(),
+                realized_pnl: dec!(-1),
+            };
+            if let Some((level, kind, reason)) = monitor.process_event(event) {
+                latch.trigger_with(level, kind, &reason);
+            }
+            match i {
+                1..=5 => assert_eq!(latch.level(), HardStopLevel::Clear),
+                6..=10 => assert_eq!(latch.level(), HardStopLevel::PauseEntries),
+                _ => assert!(latch.is_full_stop()),
             }
         }
+
+        // Lower levels never downgrade the latch
+        latch.trigger_level(HardStopLevel::PauseEntries, "later");
+        assert!(latch.is_full_stop());
     }
 
     #[test]
@@ -650,11 +1383,29 @@ mod tests {
             } else {
                 let result = monitor.process_event(event);
                 assert!(result.is_some());
-                assert!(result.unwrap().contains("Flatten failed"));
+                let (level, _, reason) = result.unwrap();
+                assert_eq!(level, HardStopLevel::FullStop);
+                assert!(reason.contains("Flatten failed"));
             }
         }
     }
 
+    #[test]
+    fn test_risk_monitor_liquidation_full_stop() {
+        let (_, mut monitor, _) = create_test_monitor();
+
+        let (level, reason, message) = monitor
+            .process_event(ExecutionEvent::Liquidation {
+                notional: dec!(1500),
+                account_value: dec!(42),
+            })
+            .unwrap();
+        assert_eq!(level, HardStopLevel::FullStop);
+        assert_eq!(reason, HardStopReason::Liquidation);
+        assert!(!reason.may_auto_recover());
+        assert!(message.contains("liquidated"));
+    }
+
     #[test]
     fn test_risk_monitor_slippage() {
         let (_, mut monitor, _) = create_test_monitor();
@@ -672,7 +1423,9 @@ mod tests {
             } else {
                 let result = monitor.process_event(event);
                 assert!(result.is_some());
-                assert!(result.unwrap().contains("Slippage exceeded"));
+                let (level, _, reason) = result.unwrap();
+                assert_eq!(level, HardStopLevel::PauseEntries);
+                assert!(reason.contains("Slippage exceeded"));
             }
         }
     }
@@ -0,0 +1,317 @@
+//! Risk event audit trail.
+//!
+//! Everything that turns trading off or back on is recorded in a shared
+//! [`RiskEventLog`]: hard stop triggers (including RiskMonitor breaches that
+//! do not raise the level), resets and auto-recoveries, operator entry
+//! pauses, RiskMonitor threshold changes, and gate blocks of signals and MM
+//! quotes (including the blocks observe-only gates would have made). The
+//! application drains the log into the risk event files of
+//! `hip3-persistence`.
+//!
+//! Gate blocks repeat on every signal or requote while a gate is closed, so
+//! repeats of the same gate and market within `dedupe_ms` are only counted
+//! and reported as `suppressed` on the next record of that block.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use hip3_core::MarketKey;
+use parking_lot::Mutex;
+
+use crate::risk::{HardStopLevel, HardStopReason};
+
+/// What happened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RiskEventKind {
+    /// A gate rejected a signal or MM quote.
+    GateBlock,
+    /// An observe-only gate would have rejected a signal or MM quote.
+    GateObserve,
+    /// The hard stop latch was triggered (level raised or a repeat breach).
+    HardStopTrigger,
+    /// The hard stop latch was reset by the operator.
+    HardStopReset,
+    /// The hard stop latch cleared itself.
+    HardStopRecovered,
+    /// New entries were paused by the operator.
+    EntriesPaused,
+    /// New entries were resumed by the operator.
+    EntriesResumed,
+    /// RiskMonitor thresholds were changed by the operator.
+    RiskConfigChanged,
+}
+
+impl RiskEventKind {
+    /// Label for records and logs.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::GateBlock => "gate_block",
+            Self::GateObserve => "gate_observe",
+            Self::HardStopTrigger => "hard_stop_trigger",
+            Self::HardStopReset => "hard_stop_reset",
+            Self::HardStopRecovered => "hard_stop_recovered",
+            Self::EntriesPaused => "entries_paused",
+            Self::EntriesResumed => "entries_resumed",
+            Self::RiskConfigChanged => "risk_config_changed",
+        }
+    }
+}
+
+/// One risk event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RiskEvent {
+    /// Time of the event (Unix ms).
+    pub timestamp_ms: i64,
+    /// What happened.
+    pub kind: RiskEventKind,
+    /// What caused it: the gate (e.g. "MaxDrawdown"), the hard stop reason
+    /// (e.g. "rejections") or "operator".
+    pub source: String,
+    /// Component that raised a hard stop trigger (e.g. "risk_monitor").
+    pub component: Option<&'static str>,
+    /// Market the event applies to (None = all markets).
+    pub market: Option<MarketKey>,
+    /// Hard stop level after the event (hard stop events only).
+    pub level: Option<HardStopLevel>,
+    /// Hard stop level before the event (hard stop events only).
+    pub previous_level: Option<HardStopLevel>,
+    /// Free-form detail (trigger reason, pause reason, cleared reasons).
+    pub reason: String,
+    /// Repeats of this gate block not recorded since its previous record.
+    pub suppressed: u32,
+}
+
+#[derive(Debug, Default)]
+struct RiskEventLogInner {
+    entries: Vec<RiskEvent>,
+    /// Last recorded time (Unix ms) and suppressed repeats per gate block.
+    last_blocks: HashMap<(RiskEventKind, String, Option<MarketKey>), (i64, u32)>,
+}
+
+/// Shared buffer of risk events, drained by the application.
+#[derive(Debug, Clone)]
+pub struct RiskEventLog {
+    inner: Arc<Mutex<RiskEventLogInner>>,
+    /// Window in which repeats of a gate block are only counted (ms).
+    dedupe_ms: i64,
+}
+
+impl RiskEventLog {
+    /// Create an empty log collapsing repeated gate blocks within
+    /// `dedupe_ms`.
+    #[must_use]
+    pub fn new(dedupe_ms: u64) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(RiskEventLogInner::default())),
+            dedupe_ms: i64::try_from(dedupe_ms).unwrap_or(i64::MAX),
+        }
+    }
+
+    /// Record a gate block of a signal or MM quote.
+    pub fn gate_block(&self, gate: &str, market: Option<MarketKey>, reason: &str) {
+        self.gate_block_at(gate, market, reason, chrono::Utc::now().timestamp_millis());
+    }
+
+    /// Record a gate block at `timestamp_ms`.
+    pub fn gate_block_at(
+        &self,
+        gate: &str,
+        market: Option<MarketKey>,
+        reason: &str,
+        timestamp_ms: i64,
+    ) {
+        self.block_at(RiskEventKind::GateBlock, gate, market, reason, timestamp_ms);
+    }
+
+    /// Record a block an observe-only gate would have made at
+    /// `timestamp_ms`.
+    pub fn gate_observe_at(
+        &self,
+        gate: &str,
+        market: Option<MarketKey>,
+        reason: &str,
+        timestamp_ms: i64,
+    ) {
+        self.block_at(
+            RiskEventKind::GateObserve,
+            gate,
+            market,
+            reason,
+            timestamp_ms,
+        );
+    }
+
+    fn block_at(
+        &self,
+        kind: RiskEventKind,
+        gate: &str,
+        market: Option<MarketKey>,
+        reason: &str,
+        timestamp_ms: i64,
+    ) {
+        let mut inner = self.inner.lock();
+        let key = (kind, gate.to_string(), market);
+        let suppressed = match inner.last_blocks.get_mut(&key) {
+            Some((last_ms, suppressed)) if timestamp_ms - *last_ms < self.dedupe_ms => {
+                *suppressed += 1;
+                return;
+            }
+            Some((_, suppressed)) => *suppressed,
+            None => 0,
+        };
+        inner.last_blocks.insert(key, (timestamp_ms, 0));
+        inner.entries.push(RiskEvent {
+            timestamp_ms,
+            kind,
+            source: gate.to_string(),
+            component: None,
+            market,
+            level: None,
+            previous_level: None,
+            reason: reason.to_string(),
+            suppressed,
+        });
+    }
+
+    /// Record a hard stop trigger, whether or not it raised the level.
+    pub fn hard_stop_trigger(
+        &self,
+        kind: HardStopReason,
+        level: HardStopLevel,
+        previous_level: HardStopLevel,
+        reason: &str,
+    ) {
+        self.push(
+            RiskEventKind::HardStopTrigger,
+            kind.as_str(),
+            Some(kind.source()),
+            Some(level),
+            Some(previous_level),
+            reason,
+        );
+    }
+
+    /// Record a hard stop clear (operator reset or auto-recovery).
+    pub fn hard_stop_cleared(
+        &self,
+        kind: RiskEventKind,
+        previous_level: HardStopLevel,
+        reason: &str,
+    ) {
+        let source = match kind {
+            RiskEventKind::HardStopRecovered => "auto_recovery",
+            _ => "operator",
+        };
+        self.push(
+            kind,
+            source,
+            None,
+            Some(HardStopLevel::Clear),
+            Some(previous_level),
+            reason,
+        );
+    }
+
+    /// Record an operator pause or resume of new entries.
+    pub fn entry_pause(&self, paused: bool, reason: &str) {
+        let kind = if paused {
+            RiskEventKind::EntriesPaused
+        } else {
+            RiskEventKind::EntriesResumed
+        };
+        self.push(kind, "operator", None, None, None, reason);
+    }
+
+    /// Record an operator change of the RiskMonitor thresholds.
+    pub fn risk_config_changed(&self, changes: &str) {
+        self.push(
+            RiskEventKind::RiskConfigChanged,
+            "operator",
+            None,
+            None,
+            None,
+            changes,
+        );
+    }
+
+    /// Drain the events recorded since the last call.
+    pub fn take(&self) -> Vec<RiskEvent> {
+        std::mem::take(&mut self.inner.lock().entries)
+    }
+
+    fn push(
+        &self,
+        kind: RiskEventKind,
+        source: &str,
+        component: Option<&'static str>,
+        level: Option<HardStopLevel>,
+        previous_level: Option<HardStopLevel>,
+        reason: &str,
+    ) {
+        self.inner.lock().entries.push(RiskEvent {
+            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+            kind,
+            source: source.to_string(),
+            component,
+            market: None,
+            level,
+            previous_level,
+            reason: reason.to_string(),
+            suppressed: 0,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::risk::{EntryPause, HardStopLatch};
+    use hip3_core::{AssetId, DexId};
+
+    #[test]
+    fn test_latch_pause_and_deduped_gate_blocks() {
+        let log = RiskEventLog::new(60_000);
+        let latch = HardStopLatch::new().with_event_log(log.clone());
+        latch.trigger_with(
+            HardStopLevel::PauseEntries,
+            HardStopReason::Rejections,
+            "Rejections: 11/hour",
+        );
+        // A breach at the current level is still recorded
+        latch.trigger_with(
+            HardStopLevel::PauseEntries,
+            HardStopReason::Slippage,
+            "Slippage",
+        );
+        latch.reset();
+        let pause = EntryPause::new().with_event_log(log.clone());
+        pause.pause("maintenance");
+
+        let market = Some(MarketKey::new(DexId::XYZ, AssetId::new(0)));
+        log.gate_block_at("MaxDrawdown", market, "MaxDrawdown", 1_000);
+        log.gate_block_at("MaxDrawdown", market, "MaxDrawdown", 2_000);
+        log.gate_block_at("MaxDrawdown", market, "MaxDrawdown", 3_000);
+        log.gate_block_at("MaxDrawdown", market, "MaxDrawdown", 61_000);
+
+        let events = log.take();
+        let kinds: Vec<_> = events.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                RiskEventKind::HardStopTrigger,
+                RiskEventKind::HardStopTrigger,
+                RiskEventKind::HardStopReset,
+                RiskEventKind::EntriesPaused,
+                RiskEventKind::GateBlock,
+                RiskEventKind::GateBlock,
+            ]
+        );
+        assert_eq!(events[0].source, "rejections");
+        assert_eq!(events[0].component, Some("risk_monitor"));
+        assert_eq!(events[1].previous_level, Some(HardStopLevel::PauseEntries));
+        assert_eq!(events[2].level, Some(HardStopLevel::Clear));
+        assert_eq!(events[5].suppressed, 2);
+        assert!(log.take().is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+//! Dead man's switch via the exchange's scheduled cancel.
+//!
+//! A `scheduleCancel` action tells the exchange to cancel every open order
+//! of the account at a given time. While the bot is healthy, `ExecutorLoop`
+//! keeps pushing that time `timeout_ms` into the future every
+//! `refresh_interval_ms`. If the process dies or hangs, the refreshes stop
+//! and the resting quotes are pulled by the exchange, instead of waiting for
+//! the next startup's orphan cleanup.
+//!
+//! [`ScheduledCancel`] only keeps the refresh schedule and the post IDs of
+//! refreshes in flight; signing and sending happen in `ExecutorLoop`.
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Earliest cancel time the exchange accepts, relative to now (ms).
+pub const MIN_SCHEDULE_AHEAD_MS: u64 = 5_000;
+
+/// Configuration for the scheduled-cancel dead man's switch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledCancelConfig {
+    /// Whether the dead man's switch is armed. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// How far ahead the cancel is scheduled (ms). Default: 30,000.
+    /// Clamped to at least 5,000 (exchange minimum).
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+    /// How often the schedule is pushed forward (ms). Default: 10,000.
+    #[serde(default = "default_refresh_interval_ms")]
+    pub refresh_interval_ms: u64,
+}
+
+fn default_timeout_ms() -> u64 {
+    30_000
+}
+
+fn default_refresh_interval_ms() -> u64 {
+    10_000
+}
+
+impl Default for ScheduledCancelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            timeout_ms: default_timeout_ms(),
+            refresh_interval_ms: default_refresh_interval_ms(),
+        }
+    }
+}
+
+/// Refresh schedule and in-flight refreshes of the dead man's switch.
+#[derive(Debug)]
+pub struct ScheduledCancel {
+    config: ScheduledCancelConfig,
+    /// Time of the last fully sent refresh (0 = never).
+    last_refresh_ms: Mutex<u64>,
+    /// Refresh post IDs awaiting a response, with their send time.
+    inflight: Mutex<HashMap<u64, u64>>,
+}
+
+impl ScheduledCancel {
+    /// Create a schedule from config.
+    #[must_use]
+    pub fn new(config: ScheduledCancelConfig) -> Self {
+        Self {
+            config,
+            last_refresh_ms: Mutex::new(0),
+            inflight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether a refresh is due.
+    #[must_use]
+    pub fn is_due(&self, now_ms: u64) -> bool {
+        let last = *self.last_refresh_ms.lock();
+        last == 0 || now_ms.saturating_sub(last) >= self.config.refresh_interval_ms
+    }
+
+    /// Cancel time to schedule for a refresh sent now.
+    #[must_use]
+    pub fn deadline(&self, now_ms: u64) -> u64 {
+        now_ms + self.config.timeout_ms.max(MIN_SCHEDULE_AHEAD_MS)
+    }
+
+    /// Record a sent refresh post.
+    pub fn on_sent(&self, post_id: u64, now_ms: u64) {
+        self.inflight.lock().insert(post_id, now_ms);
+    }
+
+    /// Record that every account was refreshed.
+    pub fn on_refreshed(&self, now_ms: u64) {
+        *self.last_refresh_ms.lock() = now_ms;
+        // Responses older than a full timeout are not coming
+        let timeout_ms = self.config.timeout_ms;
+        self.inflight
+            .lock()
+            .retain(|_, sent_at| now_ms.saturating_sub(*sent_at) < timeout_ms);
+    }
+
+    /// Take a post ID if it belongs to a refresh.
+    ///
+    /// Returns false for regular order posts.
+    pub fn take_response(&self, post_id: u64) -> bool {
+        self.inflight.lock().remove(&post_id).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refresh_schedule() {
+        let schedule = ScheduledCancel::new(ScheduledCancelConfig {
+            enabled: true,
+            timeout_ms: 30_000,
+            refresh_interval_ms: 10_000,
+        });
+
+        assert!(schedule.is_due(1_000));
+        assert_eq!(schedule.deadline(1_000), 31_000);
+
+        schedule.on_sent(7, 1_000);
+        schedule.on_refreshed(1_000);
+        assert!(!schedule.is_due(10_999));
+        assert!(schedule.is_due(11_000));
+
+        assert!(schedule.take_response(7));
+        assert!(!schedule.take_response(7));
+        assert!(!schedule.take_response(8));
+    }
+
+    #[test]
+    fn test_deadline_respects_exchange_minimum() {
+        let schedule = ScheduledCancel::new(ScheduledCancelConfig {
+            enabled: true,
+            timeout_ms: 1_000,
+            refresh_interval_ms: 500,
+        });
+        assert_eq!(schedule.deadline(0), MIN_SCHEDULE_AHEAD_MS);
+    }
+}
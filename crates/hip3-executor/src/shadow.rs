@@ -0,0 +1,128 @@
+//! Shadow execution: everything but the send.
+//!
+//! In shadow mode `ExecutorLoop` runs the full pipeline (gates, batching,
+//! HardStop filtering, slippage guard, signing) and stops right before the
+//! WebSocket post. The exact wire payload is logged together with the
+//! outcome predicted from the latest BBO in [`MarketStateCache`], and the
+//! batch is released as if it had never been queued: no order reaches the
+//! exchange and no position is opened.
+//!
+//! Unlike Paper mode, nothing is simulated after the send, so shadow runs
+//! never build up positions or exits of their own.
+
+use crate::executor::MarketStateCache;
+use hip3_core::{OrderSide, PendingOrder, Price, TimeInForce};
+use std::fmt;
+
+/// Outcome an order would likely have had on the exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShadowOutcome {
+    /// Crosses the touch: fills at (about) this price.
+    Fill(Price),
+    /// Does not cross: rests on the book (GTC / ALO).
+    Rest,
+    /// IOC that does not cross: rejected with no match.
+    NoMatch,
+    /// ALO that crosses: rejected as post-only.
+    WouldCross,
+    /// No quote cached for the market.
+    Unknown,
+}
+
+impl fmt::Display for ShadowOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fill(px) => write!(f, "fill@{px}"),
+            Self::Rest => f.write_str("rest"),
+            Self::NoMatch => f.write_str("reject:no_match"),
+            Self::WouldCross => f.write_str("reject:post_only_cross"),
+            Self::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+/// Predict an order's outcome against the latest cached BBO.
+#[must_use]
+pub fn predict_outcome(order: &PendingOrder, quotes: &MarketStateCache) -> ShadowOutcome {
+    let Some(quote) = quotes.get_quote(&order.market) else {
+        return ShadowOutcome::Unknown;
+    };
+    let touch = match order.side {
+        OrderSide::Buy => quote.best_ask,
+        OrderSide::Sell => quote.best_bid,
+    };
+    if touch.is_zero() {
+        return ShadowOutcome::Unknown;
+    }
+    let crosses = match order.side {
+        OrderSide::Buy => order.price >= touch,
+        OrderSide::Sell => order.price <= touch,
+    };
+
+    match (order.tif, crosses) {
+        (TimeInForce::AddLiquidityOnly, true) => ShadowOutcome::WouldCross,
+        (TimeInForce::ImmediateOrCancel, false) => ShadowOutcome::NoMatch,
+        (_, true) => ShadowOutcome::Fill(touch),
+        (_, false) => ShadowOutcome::Rest,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, ClientOrderId, DexId, MarketKey, Size};
+    use rust_decimal_macros::dec;
+
+    fn market() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn order(side: OrderSide, px: rust_decimal::Decimal, tif: TimeInForce) -> PendingOrder {
+        let mut order = PendingOrder::new(
+            ClientOrderId::new(),
+            market(),
+            side,
+            Price::new(px),
+            Size::new(dec!(1)),
+            false,
+            0,
+        );
+        order.tif = tif;
+        order
+    }
+
+    #[test]
+    fn test_predict_outcome() {
+        let quotes = MarketStateCache::new();
+        let ioc = TimeInForce::ImmediateOrCancel;
+        assert_eq!(
+            predict_outcome(&order(OrderSide::Buy, dec!(100), ioc), &quotes),
+            ShadowOutcome::Unknown
+        );
+
+        quotes.update_quote(
+            &market(),
+            Price::new(dec!(100)),
+            Price::new(dec!(99.9)),
+            Price::new(dec!(100.1)),
+            0,
+        );
+        assert_eq!(
+            predict_outcome(&order(OrderSide::Buy, dec!(100.2), ioc), &quotes),
+            ShadowOutcome::Fill(Price::new(dec!(100.1)))
+        );
+        assert_eq!(
+            predict_outcome(&order(OrderSide::Sell, dec!(100), ioc), &quotes),
+            ShadowOutcome::NoMatch
+        );
+        let alo = TimeInForce::AddLiquidityOnly;
+        assert_eq!(
+            predict_outcome(&order(OrderSide::Sell, dec!(99.9), alo), &quotes),
+            ShadowOutcome::WouldCross
+        );
+        assert_eq!(
+            predict_outcome(&order(OrderSide::Sell, dec!(100), alo), &quotes),
+            ShadowOutcome::Rest
+        );
+    }
+}
@@ -6,6 +6,7 @@
 //!
 //! Reference: hyperliquid-python-sdk/hyperliquid/utils/signing.py
 
+use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -15,7 +16,9 @@ use alloy::signers::Signer as AlloySigner;
 use alloy::sol;
 use alloy::sol_types::eip712_domain;
 use alloy::sol_types::SolStruct;
-use serde::Serialize;
+use parking_lot::{Mutex, RwLock};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use zeroize::Zeroizing;
 
@@ -32,16 +35,67 @@ pub enum KeySource {
     File { path: PathBuf },
 }
 
+impl KeySource {
+    /// Load the key from this source.
+    fn load(&self) -> Result<PrivateKeySigner, KeyError> {
+        // Parse hex key from string (supports 0x prefix and whitespace trimming)
+        fn parse_hex_key(hex_str: &str) -> Result<Zeroizing<Vec<u8>>, KeyError> {
+            let trimmed = hex_str.trim().trim_start_matches("0x");
+            Ok(Zeroizing::new(hex::decode(trimmed)?))
+        }
+
+        let secret_bytes: Zeroizing<Vec<u8>> = match self {
+            KeySource::EnvVar { var_name } => {
+                let hex = std::env::var(var_name)
+                    .map_err(|_| KeyError::EnvVarNotFound(var_name.clone()))?;
+                parse_hex_key(&hex)?
+            }
+            KeySource::File { path } => {
+                let content = std::fs::read_to_string(path)?;
+                parse_hex_key(&content)?
+            }
+        };
+
+        PrivateKeySigner::from_slice(&secret_bytes).map_err(|e| KeyError::InvalidKey(e.to_string()))
+    }
+}
+
+/// What a trading key signs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyPurpose {
+    /// IOC orders and cancels, and any purpose without a dedicated key.
+    Taker,
+    /// Resting orders (ALO/GTC) and amendments.
+    Maker,
+}
+
+impl std::fmt::Display for KeyPurpose {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Taker => write!(f, "taker"),
+            Self::Maker => write!(f, "maker"),
+        }
+    }
+}
+
 /// Manages trading and observation keys.
 ///
+/// The primary trading key signs as [`KeyPurpose::Taker`]; other purposes
+/// may get a dedicated key and otherwise fall back to it. Standby keys are
+/// held back until [`KeyManager::rotate`] promotes one, after which every
+/// subsequent action for that purpose is signed with the new key.
+///
 /// Security notes:
 /// - Private keys are stored in `PrivateKeySigner` which handles secure memory.
-/// - Keys are loaded once at startup; no runtime key rotation.
+/// - Keys are loaded at startup; rotation only swaps among loaded keys.
 /// - Never log private key material.
 pub struct KeyManager {
-    trading_signer: Option<PrivateKeySigner>,
+    /// Active key per purpose (`Taker` is the primary trading key).
+    keys: RwLock<HashMap<KeyPurpose, Arc<PrivateKeySigner>>>,
+    /// Standby keys, promoted in order by `rotate`.
+    standby: Mutex<VecDeque<Arc<PrivateKeySigner>>>,
     observation_address: Address,
-    trading_address: Option<Address>,
 }
 
 impl KeyManager {
@@ -63,26 +117,7 @@ impl KeyManager {
         expected_trading_address: Option<Address>,
     ) -> Result<Self, KeyError> {
         let trading_signer = if let Some(source) = trading_source {
-            // Parse hex key from string (supports 0x prefix and whitespace trimming)
-            fn parse_hex_key(hex_str: &str) -> Result<Zeroizing<Vec<u8>>, KeyError> {
-                let trimmed = hex_str.trim().trim_start_matches("0x");
-                Ok(Zeroizing::new(hex::decode(trimmed)?))
-            }
-
-            let secret_bytes: Zeroizing<Vec<u8>> = match source {
-                KeySource::EnvVar { ref var_name } => {
-                    let hex = std::env::var(var_name)
-                        .map_err(|_| KeyError::EnvVarNotFound(var_name.clone()))?;
-                    parse_hex_key(&hex)?
-                }
-                KeySource::File { ref path } => {
-                    let content = std::fs::read_to_string(path)?;
-                    parse_hex_key(&content)?
-                }
-            };
-
-            let signer = PrivateKeySigner::from_slice(&secret_bytes)
-                .map_err(|e| KeyError::InvalidKey(e.to_string()))?;
+            let signer = source.load()?;
 
             // Verify address matches expected
             if let Some(expected) = expected_trading_address {
@@ -99,13 +134,7 @@ impl KeyManager {
             None
         };
 
-        Ok(Self {
-            trading_address: trading_signer
-                .as_ref()
-                .map(|s: &PrivateKeySigner| s.address()),
-            trading_signer,
-            observation_address: Address::ZERO, // TODO: Set separately if needed
-        })
+        Ok(Self::with_trading_signer(trading_signer))
     }
 
     /// Load from raw bytes (test-only, no environment variable dependency).
@@ -127,21 +156,103 @@ impl KeyManager {
             }
         }
 
-        Ok(Self {
-            trading_address: Some(signer.address()),
-            trading_signer: Some(signer),
-            observation_address: Address::ZERO,
-        })
+        Ok(Self::with_trading_signer(Some(signer)))
+    }
+
+    /// Create a manager with a freshly generated, never-funded trading key.
+    ///
+    /// Used by paper trading, where actions are signed but never reach the
+    /// exchange.
+    pub fn ephemeral() -> Self {
+        Self::with_trading_signer(Some(PrivateKeySigner::random()))
+    }
+
+    fn with_trading_signer(trading_signer: Option<PrivateKeySigner>) -> Self {
+        let mut keys = HashMap::new();
+        if let Some(signer) = trading_signer {
+            keys.insert(KeyPurpose::Taker, Arc::new(signer));
+        }
+        Self {
+            keys: RwLock::new(keys),
+            standby: Mutex::new(VecDeque::new()),
+            observation_address: Address::ZERO, // TODO: Set separately if needed
+        }
+    }
+
+    /// Load a dedicated key for a purpose.
+    ///
+    /// # Errors
+    /// Returns `KeyError` if the key cannot be loaded.
+    pub fn with_purpose_key(
+        self,
+        purpose: KeyPurpose,
+        source: KeySource,
+    ) -> Result<Self, KeyError> {
+        let signer = source.load()?;
+        self.keys.write().insert(purpose, Arc::new(signer));
+        Ok(self)
+    }
+
+    /// Load a standby key for rotation.
+    ///
+    /// # Errors
+    /// Returns `KeyError` if the key cannot be loaded.
+    pub fn with_standby_key(self, source: KeySource) -> Result<Self, KeyError> {
+        let signer = source.load()?;
+        self.standby.lock().push_back(Arc::new(signer));
+        Ok(self)
     }
 
     /// Get the trading signer (if available).
-    pub fn trading_signer(&self) -> Option<&PrivateKeySigner> {
-        self.trading_signer.as_ref()
+    pub fn trading_signer(&self) -> Option<Arc<PrivateKeySigner>> {
+        self.signer_for(KeyPurpose::Taker)
+    }
+
+    /// Get the active signer for a purpose, falling back to the trading key.
+    pub fn signer_for(&self, purpose: KeyPurpose) -> Option<Arc<PrivateKeySigner>> {
+        let keys = self.keys.read();
+        keys.get(&purpose)
+            .or_else(|| keys.get(&KeyPurpose::Taker))
+            .cloned()
     }
 
     /// Get the trading address (if available).
     pub fn trading_address(&self) -> Option<Address> {
-        self.trading_address
+        self.address_for(KeyPurpose::Taker)
+    }
+
+    /// Get the active address for a purpose.
+    pub fn address_for(&self, purpose: KeyPurpose) -> Option<Address> {
+        self.signer_for(purpose).map(|s| s.address())
+    }
+
+    /// Replace the active key for a purpose with the next standby key.
+    ///
+    /// Rotating `Taker` also moves purposes without a dedicated key.
+    /// Returns the new address.
+    ///
+    /// # Errors
+    /// Returns `KeyError::NoStandbyKey` if no standby key is left.
+    pub fn rotate(&self, purpose: KeyPurpose) -> Result<Address, KeyError> {
+        let next = self
+            .standby
+            .lock()
+            .pop_front()
+            .ok_or(KeyError::NoStandbyKey)?;
+        let address = next.address();
+        let previous = self.keys.write().insert(purpose, next);
+        tracing::warn!(
+            %purpose,
+            old_address = ?previous.map(|s| s.address()),
+            new_address = ?address,
+            "Signing key rotated"
+        );
+        Ok(address)
+    }
+
+    /// Number of standby keys left.
+    pub fn standby_count(&self) -> usize {
+        self.standby.lock().len()
     }
 
     /// Get the observation address.
@@ -166,6 +277,9 @@ pub enum KeyError {
     #[error("Address mismatch: expected {expected}, got {actual}")]
     AddressMismatch { expected: Address, actual: Address },
 
+    #[error("No standby key left to rotate to")]
+    NoStandbyKey,
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -194,6 +308,10 @@ pub struct Action {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cancels: Option<Vec<CancelWire>>,
 
+    /// Amendments for type=batchModify (omit key if None)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modifies: Option<Vec<ModifyWire>>,
+
     /// Order grouping (required for type=order).
     /// SDK: order_wires_to_order_action() sets "na" for single orders.
     /// "na" = not applicable, "normalTpsl" = TP/SL linked, etc.
@@ -203,17 +321,62 @@ pub struct Action {
     /// Builder info (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub builder: Option<BuilderInfo>,
+
+    /// Cancel time for type=scheduleCancel (ms; omitted = unschedule)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<u64>,
+}
+
+impl Action {
+    /// Build a scheduleCancel action (dead man's switch).
+    ///
+    /// At `time` the exchange cancels every open order of the account;
+    /// `None` removes the schedule.
+    #[must_use]
+    pub fn schedule_cancel(time: Option<u64>) -> Self {
+        Self {
+            action_type: "scheduleCancel".to_string(),
+            orders: None,
+            cancels: None,
+            modifies: None,
+            grouping: None,
+            builder: None,
+            time,
+        }
+    }
 }
 
 /// Builder information (optional).
-#[derive(Debug, Clone, Serialize)]
+///
+/// Attached to order actions before signing: the builder is part of the
+/// signed action hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct BuilderInfo {
+    /// Builder address (lowercase 0x hex).
     #[serde(rename = "b")]
     pub address: String,
+    /// Builder fee in tenths of a basis point (10 = 1 bp).
     #[serde(rename = "f")]
     pub fee: u64,
 }
 
+impl BuilderInfo {
+    /// Create builder info for an address and fee (tenths of a bp).
+    #[must_use]
+    pub fn new(address: Address, fee: u64) -> Self {
+        Self {
+            address: format!("{address:#x}"),
+            fee,
+        }
+    }
+
+    /// Builder fee in basis points.
+    #[must_use]
+    pub fn fee_bps(&self) -> Decimal {
+        Decimal::new(self.fee as i64, 1)
+    }
+}
+
 /// Order wire format (matches SDK's order_spec_to_order_wire).
 ///
 /// Reference: hyperliquid-python-sdk/hyperliquid/utils/types.py - OrderWire
@@ -367,6 +530,33 @@ pub struct CancelWire {
     pub oid: u64,
 }
 
+/// Modify wire format (matches SDK).
+///
+/// Reference: hyperliquid-python-sdk/hyperliquid/exchange.py - bulk_modify_orders_new()
+///
+/// SDK example: {"oid": 123456789, "order": {"a": 5, "b": true, ...}}
+#[derive(Debug, Clone, Serialize)]
+pub struct ModifyWire {
+    /// Exchange order ID of the resting order
+    pub oid: u64,
+
+    /// Replacement order
+    pub order: OrderWire,
+}
+
+impl ModifyWire {
+    /// Create a ModifyWire from a PendingModify with proper precision formatting.
+    pub fn from_pending_modify(
+        modify: &hip3_core::PendingModify,
+        spec: &hip3_core::MarketSpec,
+    ) -> Self {
+        Self {
+            oid: modify.oid,
+            order: OrderWire::from_pending_order(&modify.order, spec),
+        }
+    }
+}
+
 // =============================================================================
 // SigningInput and action_hash
 // =============================================================================
@@ -398,43 +588,65 @@ impl SigningInput {
     /// # Errors
     /// Returns `SignerError::SerializationFailed` if msgpack serialization fails.
     pub fn action_hash(&self) -> Result<B256, SignerError> {
-        let mut data = Vec::new();
-
-        // 1. Serialize Action with msgpack (named/map format)
-        //    Using rmp_serde::to_vec_named for key-value map format
-        //    Python SDK: msgpack.packb(action)
-        let action_bytes = rmp_serde::to_vec_named(&self.action)
-            .map_err(|e| SignerError::SerializationFailed(e.to_string()))?;
-        data.extend_from_slice(&action_bytes);
-
-        // 2. nonce as big-endian 8 bytes
-        //    Python SDK: nonce.to_bytes(8, "big")
-        data.extend_from_slice(&self.nonce.to_be_bytes());
-
-        // 3. vault_address tag
-        //    None: 0x00 (1 byte)
-        //    Some: 0x01 + address (21 bytes)
-        //    NOTE: Even None has the 0x00 byte
-        match &self.vault_address {
-            None => data.push(0x00),
-            Some(addr) => {
-                data.push(0x01);
-                data.extend_from_slice(addr.as_slice());
-            }
-        }
+        compute_action_hash(
+            &self.action,
+            self.nonce,
+            self.vault_address,
+            self.expires_after,
+        )
+    }
+}
 
-        // 4. expires_after tag (SDK compliant)
-        //    None: nothing added (tag itself doesn't exist)
-        //    Some: 0x00 + big-endian 8 bytes (9 bytes total)
-        //    NOTE: Different from vault_address behavior
-        if let Some(expires) = self.expires_after {
-            data.push(0x00);
-            data.extend_from_slice(&expires.to_be_bytes());
+/// Action hash of any serializable action representation.
+///
+/// Used by [`SigningInput::action_hash`] and to re-derive the hash of an
+/// action recorded as JSON (the msgpack encoding of a JSON object with the
+/// same key order is identical to that of the `Action` struct).
+///
+/// # Errors
+/// Returns `SignerError::SerializationFailed` if msgpack serialization fails.
+pub fn compute_action_hash<T: Serialize + ?Sized>(
+    action: &T,
+    nonce: u64,
+    vault_address: Option<Address>,
+    expires_after: Option<u64>,
+) -> Result<B256, SignerError> {
+    let mut data = Vec::new();
+
+    // 1. Serialize Action with msgpack (named/map format)
+    //    Using rmp_serde::to_vec_named for key-value map format
+    //    Python SDK: msgpack.packb(action)
+    let action_bytes = rmp_serde::to_vec_named(action)
+        .map_err(|e| SignerError::SerializationFailed(e.to_string()))?;
+    data.extend_from_slice(&action_bytes);
+
+    // 2. nonce as big-endian 8 bytes
+    //    Python SDK: nonce.to_bytes(8, "big")
+    data.extend_from_slice(&nonce.to_be_bytes());
+
+    // 3. vault_address tag
+    //    None: 0x00 (1 byte)
+    //    Some: 0x01 + address (21 bytes)
+    //    NOTE: Even None has the 0x00 byte
+    match &vault_address {
+        None => data.push(0x00),
+        Some(addr) => {
+            data.push(0x01);
+            data.extend_from_slice(addr.as_slice());
         }
-        // None case: add nothing
+    }
 
-        Ok(keccak256(&data))
+    // 4. expires_after tag (SDK compliant)
+    //    None: nothing added (tag itself doesn't exist)
+    //    Some: 0x00 + big-endian 8 bytes (9 bytes total)
+    //    NOTE: Different from vault_address behavior
+    if let Some(expires) = expires_after {
+        data.push(0x00);
+        data.extend_from_slice(&expires.to_be_bytes());
     }
+    // None case: add nothing
+
+    Ok(keccak256(&data))
 }
 
 // =============================================================================
@@ -563,10 +775,25 @@ impl Signer {
     pub async fn sign_action(
         &self,
         input: SigningInput,
+    ) -> Result<PrimitiveSignature, SignerError> {
+        self.sign_action_as(KeyPurpose::Taker, input).await
+    }
+
+    /// Sign an action with the active key for a purpose.
+    ///
+    /// The key is looked up on every call, so actions signed after a
+    /// rotation use the new key.
+    ///
+    /// # Errors
+    /// Returns `SignerError` if signing fails or action serialization fails.
+    pub async fn sign_action_as(
+        &self,
+        purpose: KeyPurpose,
+        input: SigningInput,
     ) -> Result<PrimitiveSignature, SignerError> {
         let signer = self
             .key_manager
-            .trading_signer()
+            .signer_for(purpose)
             .ok_or(SignerError::NoTradingKey)?;
 
         // Step 1: Calculate action_hash (returns Result now)
@@ -576,7 +803,7 @@ impl Signer {
         let phantom_agent = PhantomAgent::new(action_hash, self.is_mainnet);
 
         // NOTE: Do not log signature as it contains sensitive information
-        let signature = phantom_agent.sign(signer).await?;
+        let signature = phantom_agent.sign(signer.as_ref()).await?;
 
         Ok(signature)
     }
@@ -586,6 +813,11 @@ impl Signer {
         self.key_manager.trading_address()
     }
 
+    /// Get the key manager.
+    pub fn key_manager(&self) -> &Arc<KeyManager> {
+        &self.key_manager
+    }
+
     /// Check if this is mainnet mode.
     pub fn is_mainnet(&self) -> bool {
         self.is_mainnet
@@ -627,6 +859,64 @@ mod tests {
         assert!(matches!(result, Err(KeyError::AddressMismatch { .. })));
     }
 
+    #[test]
+    fn test_key_manager_purpose_fallback_and_rotation() {
+        let manager = KeyManager::from_bytes(&test_key_bytes(), None).unwrap();
+        let trading = manager.trading_address().unwrap();
+
+        // No dedicated maker key: maker falls back to the trading key
+        assert_eq!(manager.address_for(KeyPurpose::Maker), Some(trading));
+        assert!(matches!(
+            manager.rotate(KeyPurpose::Maker),
+            Err(KeyError::NoStandbyKey)
+        ));
+
+        let standby = Arc::new(PrivateKeySigner::random());
+        let standby_address = standby.address();
+        manager.standby.lock().push_back(standby);
+
+        // Rotating maker gives it its own key and leaves taker alone
+        assert_eq!(manager.rotate(KeyPurpose::Maker).unwrap(), standby_address);
+        assert_eq!(
+            manager.address_for(KeyPurpose::Maker),
+            Some(standby_address)
+        );
+        assert_eq!(manager.trading_address(), Some(trading));
+        assert_eq!(manager.standby_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sign_after_rotation_uses_new_key() {
+        let manager = Arc::new(KeyManager::from_bytes(&test_key_bytes(), None).unwrap());
+        let standby = Arc::new(PrivateKeySigner::random());
+        let standby_address = standby.address();
+        manager.standby.lock().push_back(standby.clone());
+        let signer = Signer::new(manager.clone(), false).unwrap();
+
+        let input = || SigningInput {
+            action: Action {
+                action_type: "cancel".to_string(),
+                orders: None,
+                cancels: Some(vec![]),
+                modifies: None,
+                grouping: None,
+                builder: None,
+                time: None,
+            },
+            nonce: 1_700_000_000_000,
+            vault_address: None,
+            expires_after: None,
+        };
+        let expected = PhantomAgent::new(input().action_hash().unwrap(), false)
+            .sign(standby.as_ref())
+            .await
+            .unwrap();
+
+        manager.rotate(KeyPurpose::Taker).unwrap();
+        assert_eq!(signer.sign_action(input()).await.unwrap(), expected);
+        assert_eq!(signer.trading_address(), Some(standby_address));
+    }
+
     #[test]
     fn test_order_type_wire_serialization() {
         // Test IOC serialization
@@ -640,20 +930,50 @@ mod tests {
         assert_eq!(json, r#"{"limit":{"tif":"Gtc"}}"#);
     }
 
+    #[test]
+    fn test_action_serialization_with_builder() {
+        let builder = BuilderInfo::new(
+            "0xABCDEF0123456789ABCDEF0123456789ABCDEF01"
+                .parse::<Address>()
+                .unwrap(),
+            10,
+        );
+        assert_eq!(builder.fee_bps(), Decimal::ONE);
+
+        let action = Action {
+            action_type: "order".to_string(),
+            orders: Some(vec![]),
+            cancels: None,
+            modifies: None,
+            grouping: Some("na".to_string()),
+            builder: Some(builder),
+            time: None,
+        };
+
+        let json = serde_json::to_string(&action).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"order","orders":[],"grouping":"na","builder":{"b":"0xabcdef0123456789abcdef0123456789abcdef01","f":10}}"#
+        );
+    }
+
     #[test]
     fn test_action_serialization_skips_none() {
         let action = Action {
             action_type: "order".to_string(),
             orders: Some(vec![]),
-            cancels: None, // Should be omitted
+            cancels: None,  // Should be omitted
+            modifies: None, // Should be omitted
             grouping: Some("na".to_string()),
             builder: None, // Should be omitted
+            time: None,
         };
 
         let json = serde_json::to_string(&action).unwrap();
 
         // Verify None fields are not present
         assert!(!json.contains("cancels"));
+        assert!(!json.contains("modifies"));
         assert!(!json.contains("builder"));
         assert!(json.contains("orders"));
         assert!(json.contains("grouping"));
@@ -674,8 +994,10 @@ mod tests {
                 cloid: None,
             }]),
             cancels: None,
+            modifies: None,
             grouping: Some("na".to_string()),
             builder: None,
+            time: None,
         };
 
         let input = SigningInput {
@@ -711,8 +1033,10 @@ mod tests {
                 cloid: Some("0x0de3e244a8f44fc28a6b7bc852d66d19".to_string()),
             }]),
             cancels: None,
+            modifies: None,
             grouping: Some("na".to_string()),
             builder: None,
+            time: None,
         };
 
         // Serialize to msgpack
@@ -778,8 +1102,10 @@ mod tests {
                 cloid: Some("0x0de3e244a8f44fc28a6b7bc852d66d19".to_string()),
             }]),
             cancels: None,
+            modifies: None,
             grouping: Some("na".to_string()),
             builder: None,
+            time: None,
         };
 
         // Direct JSON serialization (should preserve struct field order)
@@ -801,14 +1127,35 @@ mod tests {
         println!("Field order preserved: {}", json_direct == json_via_value);
     }
 
+    #[test]
+    fn test_schedule_cancel_msgpack() {
+        // Python SDK: {"type": "scheduleCancel", "time": 1700000000000}
+        let action = Action::schedule_cancel(Some(1_700_000_000_000));
+        let msgpack_bytes = rmp_serde::to_vec_named(&action).unwrap();
+        assert_eq!(
+            hex::encode(&msgpack_bytes),
+            "82a474797065ae7363686564756c6543616e63656ca474696d65cf0000018bcfe56800"
+        );
+
+        // Unscheduling omits the time key
+        let action = Action::schedule_cancel(None);
+        let msgpack_bytes = rmp_serde::to_vec_named(&action).unwrap();
+        assert_eq!(
+            hex::encode(&msgpack_bytes),
+            "81a474797065ae7363686564756c6543616e63656c"
+        );
+    }
+
     #[test]
     fn test_action_hash_with_vault() {
         let action = Action {
             action_type: "cancel".to_string(),
             orders: None,
             cancels: Some(vec![CancelWire { asset: 5, oid: 123 }]),
+            modifies: None,
             grouping: None,
             builder: None,
+            time: None,
         };
 
         let vault_addr = Address::repeat_byte(0x42);
@@ -841,8 +1188,10 @@ mod tests {
             action_type: "order".to_string(),
             orders: Some(vec![]),
             cancels: None,
+            modifies: None,
             grouping: Some("na".to_string()),
             builder: None,
+            time: None,
         };
 
         let input_no_expires = SigningInput {
@@ -1051,8 +1400,10 @@ mod tests {
                 cloid: Some("test-123".to_string()),
             }]),
             cancels: None,
+            modifies: None,
             grouping: Some("na".to_string()),
             builder: None,
+            time: None,
         };
 
         let input = SigningInput {
@@ -1071,11 +1422,7 @@ mod tests {
 
     #[test]
     fn test_signer_no_trading_key() {
-        let manager = Arc::new(KeyManager {
-            trading_signer: None,
-            trading_address: None,
-            observation_address: Address::ZERO,
-        });
+        let manager = Arc::new(KeyManager::with_trading_signer(None));
 
         let result = Signer::new(manager, true);
         assert!(matches!(result, Err(SignerError::NoTradingKey)));
@@ -1129,6 +1476,56 @@ mod tests {
         assert_eq!(wire.sz, "1.234");
     }
 
+    #[test]
+    fn test_batch_modify_serialization() {
+        use hip3_core::{
+            execution::{PendingModify, PendingOrder},
+            market::{AssetId, DexId, MarketKey, MarketSpec},
+            order::ClientOrderId,
+            OrderSide, Price, Size,
+        };
+        use rust_decimal_macros::dec;
+
+        let spec = MarketSpec {
+            tick_size: Price::new(dec!(0.01)),
+            sz_decimals: 3,
+            lot_size: Size::new(dec!(0.001)),
+            ..Default::default()
+        };
+        let order = PendingOrder::new(
+            ClientOrderId::new(),
+            MarketKey::new(DexId::XYZ, AssetId::new(5)),
+            OrderSide::Sell,
+            Price::new(dec!(101.5)),
+            Size::new(dec!(0.25)),
+            false,
+            1234567890,
+        );
+        let modify = PendingModify::new(987654, ClientOrderId::new(), order);
+
+        let action = Action {
+            action_type: "batchModify".to_string(),
+            orders: None,
+            cancels: None,
+            modifies: Some(vec![ModifyWire::from_pending_modify(&modify, &spec)]),
+            grouping: None,
+            builder: None,
+            time: None,
+        };
+        let json = serde_json::to_value(&action).unwrap();
+        assert_eq!(json["type"], "batchModify");
+        assert_eq!(json["modifies"][0]["oid"], 987654);
+        assert_eq!(json["modifies"][0]["order"]["a"], 5);
+        assert_eq!(json["modifies"][0]["order"]["b"], false);
+        assert_eq!(json["modifies"][0]["order"]["p"], "101.5");
+        assert_eq!(
+            json["modifies"][0]["order"]["c"],
+            modify.order.cloid.to_string()
+        );
+        assert!(json.get("orders").is_none());
+        assert!(json.get("grouping").is_none());
+    }
+
     #[test]
     fn test_from_pending_order_sell_rounds_down() {
         use hip3_core::{
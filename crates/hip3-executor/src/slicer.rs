@@ -0,0 +1,414 @@
+//! Parent-order slicing for large signal entries.
+//!
+//! Crossing with the full suggested size takes the whole touch and then
+//! pays for the levels behind it. [`OrderSlicer`] turns such an entry into a
+//! parent order worked as a series of child IOC slices spread over a fixed
+//! duration, giving the book time to refill between children.
+//!
+//! The slicer only keeps the parent state machine; the executor sends the
+//! children (see `Executor::tick_slices`) and re-checks the live quote edge
+//! before each one, abandoning the parent once the dislocation has closed.
+//!
+//! ```text
+//! Active ──(last slice sent)──► Completed
+//!   │
+//!   ├──(edge below minimum)───► EdgeLost
+//!   └──(HardStop / flatten)───► Stopped
+//! ```
+
+use hip3_core::{MarketKey, OrderSide, PendingOrder, Price, Size};
+use parking_lot::Mutex;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Finished parents kept for the dashboard.
+const MAX_FINISHED: usize = 20;
+
+/// Upper bound on children per parent, whatever the notional.
+const MAX_SLICES: u32 = 20;
+
+/// Configuration for entry slicing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlicingConfig {
+    /// Whether large IOC entries are sliced. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Entries above this notional (USD) are split into children of at most
+    /// this size. Keep above the exchange minimum order value. Default: 20.
+    #[serde(default = "default_max_slice_notional")]
+    pub max_slice_notional: Decimal,
+    /// Time over which the children are spread (ms). Default: 1000.
+    #[serde(default = "default_duration_ms")]
+    pub duration_ms: u64,
+}
+
+fn default_max_slice_notional() -> Decimal {
+    Decimal::from(20)
+}
+
+fn default_duration_ms() -> u64 {
+    1_000
+}
+
+impl Default for SlicingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_slice_notional: default_max_slice_notional(),
+            duration_ms: default_duration_ms(),
+        }
+    }
+}
+
+/// Parent order lifecycle state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParentOrderState {
+    /// Children still to be sent.
+    Active,
+    /// Every child was sent.
+    Completed,
+    /// Abandoned because the quote edge fell below the minimum.
+    EdgeLost,
+    /// Abandoned by HardStop or a flatten on the market.
+    Stopped,
+}
+
+impl ParentOrderState {
+    /// Returns the state as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Completed => "completed",
+            Self::EdgeLost => "edge_lost",
+            Self::Stopped => "stopped",
+        }
+    }
+}
+
+/// A sliced entry and its progress.
+///
+/// `sent_size` counts child size sent, not filled: IOC children may fill
+/// partially, and fills are tracked by the position tracker as usual.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParentOrder {
+    /// Sequential parent id.
+    pub id: u64,
+    /// Target market.
+    pub market: MarketKey,
+    /// Entry side.
+    pub side: OrderSide,
+    /// Limit price shared by every child.
+    pub limit_price: Price,
+    /// Total size to work.
+    pub total_size: Size,
+    /// Size sent so far across children.
+    pub sent_size: Size,
+    /// Planned number of children.
+    pub slices_total: u32,
+    /// Children sent so far.
+    pub slices_sent: u32,
+    /// Quote edge (bps) that must still hold before each child.
+    pub min_edge_bps: Decimal,
+    /// Gap between children (ms).
+    pub interval_ms: u64,
+    /// When the parent started (Unix milliseconds).
+    pub created_at: u64,
+    /// When the next child is due (Unix milliseconds).
+    pub next_slice_at: u64,
+    /// When the parent left `Active` (Unix milliseconds).
+    pub finished_at: Option<u64>,
+    /// Lifecycle state.
+    pub state: ParentOrderState,
+}
+
+impl ParentOrder {
+    /// Size of the next child; the last one takes the rounding remainder.
+    #[must_use]
+    pub fn next_slice_size(&self) -> Size {
+        let remaining = self.total_size.inner() - self.sent_size.inner();
+        if self.slices_sent + 1 >= self.slices_total {
+            return Size::new(remaining.max(Decimal::ZERO));
+        }
+        let slice = self.total_size.inner() / Decimal::from(self.slices_total);
+        Size::new(slice.min(remaining))
+    }
+}
+
+#[derive(Default)]
+struct SlicerInner {
+    /// Active parents, at most one per market.
+    active: HashMap<MarketKey, ParentOrder>,
+    /// Recently finished parents (newest last).
+    finished: VecDeque<ParentOrder>,
+}
+
+/// Parent-order registry for sliced entries.
+///
+/// Thread-safe; shared by the executor (writer) and the dashboard (reader).
+pub struct OrderSlicer {
+    config: SlicingConfig,
+    next_id: AtomicU64,
+    inner: Mutex<SlicerInner>,
+}
+
+impl OrderSlicer {
+    /// Create a new slicer.
+    #[must_use]
+    pub fn new(config: SlicingConfig) -> Self {
+        Self {
+            config,
+            next_id: AtomicU64::new(1),
+            inner: Mutex::new(SlicerInner::default()),
+        }
+    }
+
+    /// Create a new slicer wrapped in Arc for sharing.
+    #[must_use]
+    pub fn new_shared(config: SlicingConfig) -> Arc<Self> {
+        Arc::new(Self::new(config))
+    }
+
+    /// Get the configuration.
+    #[must_use]
+    pub fn config(&self) -> &SlicingConfig {
+        &self.config
+    }
+
+    /// Number of children an entry of `notional` (USD) is split into.
+    ///
+    /// 1 means the entry goes out whole.
+    #[must_use]
+    pub fn slice_count(&self, notional: Decimal) -> u32 {
+        let max_slice = self.config.max_slice_notional;
+        if !self.config.enabled || max_slice <= Decimal::ZERO || notional <= max_slice {
+            return 1;
+        }
+        (notional / max_slice)
+            .ceil()
+            .to_u32()
+            .unwrap_or(MAX_SLICES)
+            .clamp(1, MAX_SLICES)
+    }
+
+    /// Start a parent whose first child was just queued.
+    ///
+    /// Returns the parent id, or None if the market already has an active
+    /// parent.
+    pub fn start(
+        &self,
+        first_child: &PendingOrder,
+        total_size: Size,
+        slices_total: u32,
+        min_edge_bps: Decimal,
+    ) -> Option<u64> {
+        let mut inner = self.inner.lock();
+        if inner.active.contains_key(&first_child.market) {
+            return None;
+        }
+        let now_ms = first_child.created_at;
+        let interval_ms = self.config.duration_ms / u64::from(slices_total.max(1));
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        inner.active.insert(
+            first_child.market,
+            ParentOrder {
+                id,
+                market: first_child.market,
+                side: first_child.side,
+                limit_price: first_child.price,
+                total_size,
+                sent_size: first_child.size,
+                slices_total,
+                slices_sent: 1,
+                min_edge_bps,
+                interval_ms,
+                created_at: now_ms,
+                next_slice_at: now_ms + interval_ms,
+                finished_at: None,
+                state: ParentOrderState::Active,
+            },
+        );
+        Some(id)
+    }
+
+    /// Active parents whose next child is due at `now_ms`.
+    #[must_use]
+    pub fn due(&self, now_ms: u64) -> Vec<ParentOrder> {
+        self.inner
+            .lock()
+            .active
+            .values()
+            .filter(|p| p.next_slice_at <= now_ms)
+            .cloned()
+            .collect()
+    }
+
+    /// Record a child sent for `market`; completes the parent on the last one.
+    pub fn record_slice(&self, market: &MarketKey, size: Size, now_ms: u64) {
+        let mut inner = self.inner.lock();
+        let Some(parent) = inner.active.get_mut(market) else {
+            return;
+        };
+        parent.slices_sent += 1;
+        parent.sent_size = Size::new(parent.sent_size.inner() + size.inner());
+        parent.next_slice_at = now_ms + parent.interval_ms;
+        if parent.slices_sent >= parent.slices_total {
+            Self::finish_locked(&mut inner, market, ParentOrderState::Completed, now_ms);
+        }
+    }
+
+    /// End the active parent for `market` in `state`.
+    pub fn finish(&self, market: &MarketKey, state: ParentOrderState, now_ms: u64) {
+        Self::finish_locked(&mut self.inner.lock(), market, state, now_ms);
+    }
+
+    /// End every active parent in `state`. Returns how many were ended.
+    pub fn finish_all(&self, state: ParentOrderState, now_ms: u64) -> usize {
+        let mut inner = self.inner.lock();
+        let markets: Vec<MarketKey> = inner.active.keys().copied().collect();
+        for market in &markets {
+            Self::finish_locked(&mut inner, market, state, now_ms);
+        }
+        markets.len()
+    }
+
+    fn finish_locked(
+        inner: &mut SlicerInner,
+        market: &MarketKey,
+        state: ParentOrderState,
+        now_ms: u64,
+    ) {
+        let Some(mut parent) = inner.active.remove(market) else {
+            return;
+        };
+        parent.state = state;
+        parent.finished_at = Some(now_ms);
+        inner.finished.push_back(parent);
+        while inner.finished.len() > MAX_FINISHED {
+            inner.finished.pop_front();
+        }
+    }
+
+    /// Whether `market` has a parent still being worked.
+    #[must_use]
+    pub fn has_active(&self, market: &MarketKey) -> bool {
+        self.inner.lock().active.contains_key(market)
+    }
+
+    /// Number of active parents.
+    #[must_use]
+    pub fn active_count(&self) -> usize {
+        self.inner.lock().active.len()
+    }
+
+    /// Active parents followed by recently finished ones (newest first).
+    #[must_use]
+    pub fn snapshot(&self) -> Vec<ParentOrder> {
+        let inner = self.inner.lock();
+        let mut parents: Vec<ParentOrder> = inner.active.values().cloned().collect();
+        parents.sort_by_key(|p| p.id);
+        parents.extend(inner.finished.iter().rev().cloned());
+        parents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, ClientOrderId, DexId};
+    use rust_decimal_macros::dec;
+
+    fn market() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn slicer() -> OrderSlicer {
+        OrderSlicer::new(SlicingConfig {
+            enabled: true,
+            max_slice_notional: dec!(20),
+            duration_ms: 900,
+        })
+    }
+
+    fn child(size: Decimal, now_ms: u64) -> PendingOrder {
+        PendingOrder::new(
+            ClientOrderId::new(),
+            market(),
+            OrderSide::Buy,
+            Price::new(dec!(100)),
+            Size::new(size),
+            false,
+            now_ms,
+        )
+    }
+
+    #[test]
+    fn test_slice_count() {
+        let slicer = slicer();
+        assert_eq!(slicer.slice_count(dec!(15)), 1);
+        assert_eq!(slicer.slice_count(dec!(20)), 1);
+        assert_eq!(slicer.slice_count(dec!(50)), 3);
+        assert_eq!(slicer.slice_count(dec!(100000)), MAX_SLICES);
+
+        let disabled = OrderSlicer::new(SlicingConfig::default());
+        assert_eq!(disabled.slice_count(dec!(1000)), 1);
+    }
+
+    #[test]
+    fn test_parent_lifecycle_completes() {
+        let slicer = slicer();
+        // 0.5 @ 100 = $50 → 3 children of ~0.1667, 300ms apart
+        let id = slicer.start(
+            &child(dec!(0.5) / dec!(3), 1_000),
+            Size::new(dec!(0.5)),
+            3,
+            dec!(5),
+        );
+        assert_eq!(id, Some(1));
+        assert!(slicer.has_active(&market()));
+        assert!(slicer
+            .start(&child(dec!(0.1), 1_000), Size::new(dec!(0.5)), 3, dec!(5))
+            .is_none());
+
+        assert!(slicer.due(1_299).is_empty());
+        let due = slicer.due(1_300);
+        assert_eq!(due.len(), 1);
+        let second = due[0].next_slice_size();
+        slicer.record_slice(&market(), second, 1_300);
+
+        let parent = &slicer.due(1_600)[0];
+        assert_eq!(parent.slices_sent, 2);
+        // Last child takes the remainder so the total is exact
+        let last = parent.next_slice_size();
+        assert_eq!(parent.sent_size.inner() + last.inner(), dec!(0.5));
+        slicer.record_slice(&market(), last, 1_600);
+
+        assert!(!slicer.has_active(&market()));
+        let snapshot = slicer.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].state, ParentOrderState::Completed);
+        assert_eq!(snapshot[0].finished_at, Some(1_600));
+    }
+
+    #[test]
+    fn test_parent_abandoned() {
+        let slicer = slicer();
+        slicer.start(&child(dec!(0.2), 0), Size::new(dec!(0.6)), 3, dec!(5));
+        slicer.finish(&market(), ParentOrderState::EdgeLost, 300);
+        assert_eq!(slicer.active_count(), 0);
+        assert!(slicer.due(10_000).is_empty());
+        assert_eq!(slicer.snapshot()[0].state, ParentOrderState::EdgeLost);
+
+        slicer.start(&child(dec!(0.2), 400), Size::new(dec!(0.6)), 3, dec!(5));
+        assert_eq!(slicer.finish_all(ParentOrderState::Stopped, 500), 1);
+        let snapshot = slicer.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].state, ParentOrderState::Stopped);
+        assert_eq!(snapshot[0].id, 2);
+    }
+}
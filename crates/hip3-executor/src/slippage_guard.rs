@@ -0,0 +1,218 @@
+//! Submit-time slippage guard.
+//!
+//! Signals are priced when they are detected; by the time the order is
+//! signed the market may have moved. [`SlippageGuard`] recomputes the
+//! implied slippage of each new IOC order's limit price against the latest
+//! mark (oracle when no mark is cached) right before submission, and either
+//! drops the order with `RejectReason::SlippageExceeded` or reprices it to
+//! the per-market limit.
+//!
+//! Reduce-only orders are never touched: exits must go out. Resting (GTC /
+//! ALO) quotes are not checked either, since they never pay through.
+
+use hip3_core::{MarketKey, OrderSide, PendingOrder, Price, RejectReason, TimeInForce};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for the submit-time slippage guard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlippageGuardConfig {
+    /// Whether orders are checked before submission. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Maximum slippage of the limit price vs mark (bps), unless overridden
+    /// per market. Default: 50.
+    #[serde(default = "default_max_slippage_bps")]
+    pub max_slippage_bps: Decimal,
+    /// Reprice offending orders to the limit instead of dropping them.
+    /// Default: false.
+    #[serde(default)]
+    pub reprice: bool,
+}
+
+fn default_max_slippage_bps() -> Decimal {
+    Decimal::from(50)
+}
+
+impl Default for SlippageGuardConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_slippage_bps: default_max_slippage_bps(),
+            reprice: false,
+        }
+    }
+}
+
+/// Outcome of a slippage check that failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlippageBreach {
+    /// Implied slippage of the limit price (bps, positive = paying through).
+    pub slippage_bps: Decimal,
+    /// Limit for the market (bps).
+    pub max_slippage_bps: Decimal,
+    /// Reference price the slippage was measured against.
+    pub reference_px: Price,
+    /// Limit price at exactly the maximum slippage (before tick rounding).
+    pub capped_px: Price,
+}
+
+/// Order dropped by the guard, for persistence and metrics.
+#[derive(Debug, Clone)]
+pub struct SlippageRejection {
+    /// The dropped order.
+    pub order: PendingOrder,
+    /// Always `RejectReason::SlippageExceeded`.
+    pub reason: RejectReason,
+    /// The failed check.
+    pub breach: SlippageBreach,
+}
+
+/// Per-market maximum slippage check.
+#[derive(Debug, Clone)]
+pub struct SlippageGuard {
+    config: SlippageGuardConfig,
+    /// Per-market limits overriding `config.max_slippage_bps`.
+    per_market: HashMap<MarketKey, Decimal>,
+}
+
+impl SlippageGuard {
+    /// Create a guard with per-market overrides.
+    #[must_use]
+    pub fn new(config: SlippageGuardConfig, per_market: HashMap<MarketKey, Decimal>) -> Self {
+        Self { config, per_market }
+    }
+
+    /// Whether offending orders are repriced instead of dropped.
+    #[must_use]
+    pub fn reprice(&self) -> bool {
+        self.config.reprice
+    }
+
+    /// Whether the order is subject to the check.
+    #[must_use]
+    pub fn applies_to(order: &PendingOrder) -> bool {
+        !order.reduce_only && order.tif == TimeInForce::ImmediateOrCancel
+    }
+
+    /// Maximum slippage for a market (bps).
+    #[must_use]
+    pub fn max_slippage_bps(&self, market: &MarketKey) -> Decimal {
+        self.per_market
+            .get(market)
+            .copied()
+            .unwrap_or(self.config.max_slippage_bps)
+    }
+
+    /// Check an order's limit price against a reference price.
+    ///
+    /// Returns None if the order is within its market's limit.
+    #[must_use]
+    pub fn check(&self, order: &PendingOrder, reference_px: Price) -> Option<SlippageBreach> {
+        let reference = reference_px.inner();
+        if reference.is_zero() {
+            return None;
+        }
+        let bps = Decimal::from(10_000);
+        let through = match order.side {
+            OrderSide::Buy => order.price.inner() - reference,
+            OrderSide::Sell => reference - order.price.inner(),
+        };
+        let slippage_bps = through / reference * bps;
+        let max_slippage_bps = self.max_slippage_bps(&order.market);
+        if slippage_bps <= max_slippage_bps {
+            return None;
+        }
+
+        let offset = reference * max_slippage_bps / bps;
+        let capped_px = match order.side {
+            OrderSide::Buy => reference + offset,
+            OrderSide::Sell => reference - offset,
+        };
+        Some(SlippageBreach {
+            slippage_bps,
+            max_slippage_bps,
+            reference_px,
+            capped_px: Price::new(capped_px),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, ClientOrderId, DexId, Size};
+    use rust_decimal_macros::dec;
+
+    fn market(idx: u32) -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(idx))
+    }
+
+    fn order(idx: u32, side: OrderSide, px: Decimal) -> PendingOrder {
+        PendingOrder::new(
+            ClientOrderId::new(),
+            market(idx),
+            side,
+            Price::new(px),
+            Size::new(dec!(1)),
+            false,
+            0,
+        )
+    }
+
+    fn guard() -> SlippageGuard {
+        SlippageGuard::new(
+            SlippageGuardConfig {
+                enabled: true,
+                max_slippage_bps: dec!(50),
+                reprice: false,
+            },
+            HashMap::from([(market(1), dec!(10))]),
+        )
+    }
+
+    #[test]
+    fn test_check_buy_and_sell() {
+        let guard = guard();
+        let mark = Price::new(dec!(100));
+
+        // Buying below mark is never slippage
+        assert!(guard
+            .check(&order(0, OrderSide::Buy, dec!(99)), mark)
+            .is_none());
+        // 40 bps through: within the 50 bps default
+        assert!(guard
+            .check(&order(0, OrderSide::Buy, dec!(100.4)), mark)
+            .is_none());
+
+        let breach = guard
+            .check(&order(0, OrderSide::Sell, dec!(99.4)), mark)
+            .unwrap();
+        assert_eq!(breach.slippage_bps, dec!(60));
+        assert_eq!(breach.capped_px, Price::new(dec!(99.5)));
+    }
+
+    #[test]
+    fn test_per_market_override() {
+        let guard = guard();
+        let mark = Price::new(dec!(100));
+
+        let breach = guard
+            .check(&order(1, OrderSide::Buy, dec!(100.2)), mark)
+            .unwrap();
+        assert_eq!(breach.max_slippage_bps, dec!(10));
+        assert_eq!(breach.capped_px, Price::new(dec!(100.1)));
+    }
+
+    #[test]
+    fn test_applies_only_to_new_ioc_orders() {
+        let mut o = order(0, OrderSide::Buy, dec!(100));
+        assert!(SlippageGuard::applies_to(&o));
+        o.reduce_only = true;
+        assert!(!SlippageGuard::applies_to(&o));
+        o.reduce_only = false;
+        o.tif = TimeInForce::AddLiquidityOnly;
+        assert!(!SlippageGuard::applies_to(&o));
+    }
+}
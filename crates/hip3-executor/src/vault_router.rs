@@ -0,0 +1,239 @@
+//! Per-strategy vault (sub-account) routing.
+//!
+//! Every action carries at most one `vaultAddress`, and the signature covers
+//! it. [`VaultRouter`] maps each order strategy (taker, maker) to the account
+//! its orders go to and splits a scheduler batch into one batch per account,
+//! so `ExecutorLoop` can sign and post each with its own vault address.
+//!
+//! Routing is per strategy: an order's amendments and cancels carry the
+//! order's strategy, so a resting order is always cancelled through the
+//! account it rests in. Strategies without a route use the default vault
+//! (None = the signing user's own account).
+
+use alloy::primitives::Address;
+use hip3_core::{ActionBatch, OrderStrategy};
+use std::collections::BTreeMap;
+
+/// Maps order strategies to the vault address their actions are sent for.
+#[derive(Debug, Clone, Default)]
+pub struct VaultRouter {
+    /// Vault for strategies without a route (None = personal account).
+    default_vault: Option<Address>,
+    /// Per-strategy vault overrides.
+    routes: BTreeMap<OrderStrategy, Address>,
+}
+
+impl VaultRouter {
+    /// Create a router with a default vault and per-strategy routes.
+    #[must_use]
+    pub fn new(default_vault: Option<Address>, routes: BTreeMap<OrderStrategy, Address>) -> Self {
+        Self {
+            default_vault,
+            routes,
+        }
+    }
+
+    /// Vault address for a strategy.
+    #[must_use]
+    pub fn route(&self, strategy: OrderStrategy) -> Option<Address> {
+        self.routes.get(&strategy).copied().or(self.default_vault)
+    }
+
+    /// Account address a strategy trades on, formatted as the exchange
+    /// reports it (`user_address` for the personal account).
+    #[must_use]
+    pub fn account(&self, strategy: OrderStrategy, user_address: &str) -> String {
+        self.route(strategy)
+            .map_or_else(|| user_address.to_string(), |vault| format!("{vault:#x}"))
+    }
+
+    /// Whether `strategy` trades on an account no other strategy uses.
+    #[must_use]
+    pub fn is_dedicated(&self, strategy: OrderStrategy) -> bool {
+        let vault = self.route(strategy);
+        OrderStrategy::ALL
+            .into_iter()
+            .filter(|other| *other != strategy)
+            .all(|other| self.route(other) != vault)
+    }
+
+    /// Strategy whose orders rest in the account of `vault` (taker if the
+    /// account is shared or unused).
+    #[must_use]
+    pub fn strategy_of(&self, vault: Option<Address>) -> OrderStrategy {
+        OrderStrategy::ALL
+            .into_iter()
+            .find(|strategy| self.route(*strategy) == vault)
+            .unwrap_or_default()
+    }
+
+    /// Vault for strategies without a route.
+    #[must_use]
+    pub fn default_vault(&self) -> Option<Address> {
+        self.default_vault
+    }
+
+    /// Whether any strategy is routed away from the default vault.
+    #[must_use]
+    pub fn has_routes(&self) -> bool {
+        self.routes.values().any(|v| Some(*v) != self.default_vault)
+    }
+
+    /// Distinct vaults strategies are routed to, excluding the default.
+    #[must_use]
+    pub fn routed_vaults(&self) -> Vec<Address> {
+        let mut vaults: Vec<Address> = self
+            .routes
+            .values()
+            .copied()
+            .filter(|v| Some(*v) != self.default_vault)
+            .collect();
+        vaults.sort_unstable();
+        vaults.dedup();
+        vaults
+    }
+
+    /// Split a batch into one batch per vault.
+    ///
+    /// Items keep their relative order; groups are ordered by the first item
+    /// routed to each vault.
+    #[must_use]
+    pub fn split(&self, batch: ActionBatch) -> Vec<(Option<Address>, ActionBatch)> {
+        match batch {
+            ActionBatch::Orders(orders) => self
+                .group(orders, |o| o.strategy)
+                .into_iter()
+                .map(|(vault, items)| (vault, ActionBatch::Orders(items)))
+                .collect(),
+            ActionBatch::Cancels(cancels) => self
+                .group(cancels, |c| c.strategy)
+                .into_iter()
+                .map(|(vault, items)| (vault, ActionBatch::Cancels(items)))
+                .collect(),
+            ActionBatch::Modifies(modifies) => self
+                .group(modifies, |m| m.order.strategy)
+                .into_iter()
+                .map(|(vault, items)| (vault, ActionBatch::Modifies(items)))
+                .collect(),
+        }
+    }
+
+    fn group<T>(
+        &self,
+        items: Vec<T>,
+        strategy: impl Fn(&T) -> OrderStrategy,
+    ) -> Vec<(Option<Address>, Vec<T>)> {
+        let mut groups: Vec<(Option<Address>, Vec<T>)> = Vec::new();
+        for item in items {
+            let vault = self.route(strategy(&item));
+            match groups.iter_mut().find(|(v, _)| *v == vault) {
+                Some((_, group)) => group.push(item),
+                None => groups.push((vault, vec![item])),
+            }
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{
+        AssetId, ClientOrderId, DexId, MarketKey, OrderSide, PendingCancel, PendingOrder, Price,
+        Size,
+    };
+    use rust_decimal_macros::dec;
+
+    fn market(idx: u32) -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(idx))
+    }
+
+    fn order(idx: u32, strategy: OrderStrategy) -> PendingOrder {
+        PendingOrder::new(
+            ClientOrderId::new(),
+            market(idx),
+            OrderSide::Buy,
+            Price::new(dec!(100)),
+            Size::new(dec!(1)),
+            false,
+            0,
+        )
+        .with_strategy(strategy)
+    }
+
+    fn mm_vault() -> Address {
+        Address::repeat_byte(0x11)
+    }
+
+    fn maker_routes() -> BTreeMap<OrderStrategy, Address> {
+        BTreeMap::from([(OrderStrategy::Maker, mm_vault())])
+    }
+
+    #[test]
+    fn test_route_falls_back_to_default() {
+        let default = Address::repeat_byte(0x22);
+        let router = VaultRouter::new(Some(default), maker_routes());
+
+        assert_eq!(router.route(OrderStrategy::Maker), Some(mm_vault()));
+        assert_eq!(router.route(OrderStrategy::Taker), Some(default));
+        assert!(router.has_routes());
+        assert_eq!(router.routed_vaults(), vec![mm_vault()]);
+        assert!(!VaultRouter::default().has_routes());
+    }
+
+    #[test]
+    fn test_accounts_by_strategy() {
+        let router = VaultRouter::new(None, maker_routes());
+        assert_eq!(router.account(OrderStrategy::Taker, "0xuser"), "0xuser");
+        assert_eq!(
+            router.account(OrderStrategy::Maker, "0xuser"),
+            format!("{:#x}", mm_vault())
+        );
+        assert!(router.is_dedicated(OrderStrategy::Maker));
+        assert_eq!(router.strategy_of(Some(mm_vault())), OrderStrategy::Maker);
+        assert_eq!(router.strategy_of(None), OrderStrategy::Taker);
+
+        // One shared account: nothing is dedicated, orders there are taker's
+        let shared = VaultRouter::new(Some(mm_vault()), maker_routes());
+        assert!(!shared.is_dedicated(OrderStrategy::Maker));
+        assert_eq!(shared.strategy_of(Some(mm_vault())), OrderStrategy::Taker);
+    }
+
+    #[test]
+    fn test_split_groups_by_strategy_preserving_order() {
+        let router = VaultRouter::new(None, maker_routes());
+        // Taker entries in a quoted market stay on the taker account
+        let orders = vec![
+            order(1, OrderStrategy::Taker),
+            order(1, OrderStrategy::Maker),
+            order(2, OrderStrategy::Taker),
+            order(2, OrderStrategy::Maker),
+        ];
+        let cloids: Vec<_> = orders.iter().map(|o| o.cloid.clone()).collect();
+
+        let split = router.split(ActionBatch::Orders(orders));
+        assert_eq!(split.len(), 2);
+
+        let (vault, ActionBatch::Orders(personal)) = &split[0] else {
+            panic!("expected orders");
+        };
+        assert_eq!(*vault, None);
+        assert_eq!(personal[0].cloid, cloids[0]);
+        assert_eq!(personal[1].cloid, cloids[2]);
+
+        let (vault, ActionBatch::Orders(mm)) = &split[1] else {
+            panic!("expected orders");
+        };
+        assert_eq!(*vault, Some(mm_vault()));
+        assert_eq!(mm[0].cloid, cloids[1]);
+        assert_eq!(mm[1].cloid, cloids[3]);
+
+        let cancels = router.split(ActionBatch::Cancels(vec![
+            PendingCancel::new(market(1), 7, 0).with_strategy(OrderStrategy::Maker),
+            PendingCancel::new(market(1), 8, 0),
+        ]));
+        assert_eq!(cancels.len(), 2);
+        assert_eq!(cancels[0].0, Some(mm_vault()));
+        assert_eq!(cancels[1].0, None);
+    }
+}
@@ -9,6 +9,8 @@
 use std::pin::Pin;
 use std::sync::Arc;
 
+use alloy::primitives::Address;
+
 use crate::signer::Action;
 
 /// Boxed future for dyn-compatible async trait methods.
@@ -52,6 +54,8 @@ pub struct SignedAction {
     pub signature: ActionSignature,
     /// Post ID for response correlation.
     pub post_id: u64,
+    /// Vault/active_pool address the action was signed for (None = personal).
+    pub vault_address: Option<Address>,
 }
 
 /// EIP-712 signature components.
@@ -166,6 +170,7 @@ pub struct SignedActionBuilder {
     action: Action,
     nonce: u64,
     post_id: u64,
+    vault_address: Option<Address>,
 }
 
 impl SignedActionBuilder {
@@ -175,9 +180,17 @@ impl SignedActionBuilder {
             action,
             nonce,
             post_id,
+            vault_address: None,
         }
     }
 
+    /// Set the vault address the action is signed for.
+    #[must_use]
+    pub fn vault_address(mut self, vault_address: Option<Address>) -> Self {
+        self.vault_address = vault_address;
+        self
+    }
+
     /// Build with signature bytes.
     pub fn with_signature(self, sig_bytes: &[u8; 65]) -> SignedAction {
         SignedAction {
@@ -185,6 +198,7 @@ impl SignedActionBuilder {
             nonce: self.nonce,
             signature: ActionSignature::from_bytes(sig_bytes),
             post_id: self.post_id,
+            vault_address: self.vault_address,
         }
     }
 
@@ -195,6 +209,7 @@ impl SignedActionBuilder {
             nonce: self.nonce,
             signature: ActionSignature { r, s, v },
             post_id: self.post_id,
+            vault_address: self.vault_address,
         }
     }
 }
@@ -208,8 +223,10 @@ mod tests {
             action_type: "order".to_string(),
             orders: Some(vec![]),
             cancels: None,
+            modifies: None,
             grouping: Some("na".to_string()),
             builder: None,
+            time: None,
         }
     }
 
@@ -226,6 +243,7 @@ mod tests {
                 v: 27,
             },
             post_id: 1,
+            vault_address: None,
         };
 
         let result = sender.send(signed).await;
@@ -247,6 +265,7 @@ mod tests {
                 v: 27,
             },
             post_id: 1,
+            vault_address: None,
         };
 
         let result = sender.send(signed).await;
@@ -0,0 +1,169 @@
+//! Short per-market BBO history.
+//!
+//! Keeps the last few hundred milliseconds of best bid/ask per market so
+//! consumers can ask how far a quote has moved recently. The detector uses
+//! this to tell a stale quote (our edge) from a market maker who is already
+//! repricing toward the oracle (chasing).
+
+use dashmap::DashMap;
+use hip3_core::{MarketKey, OrderSide, Price};
+use rust_decimal::Decimal;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// Hard cap on samples kept per market, independent of the window.
+const MAX_SAMPLES: usize = 512;
+
+#[derive(Debug, Clone, Copy)]
+struct BboSample {
+    timestamp_ms: u64,
+    bid: Price,
+    ask: Price,
+}
+
+/// Ring buffer of recent BBOs per market.
+///
+/// Thread-safe via DashMap so the feed handler can write while the detector reads.
+pub struct BboHistory {
+    window_ms: u64,
+    samples: DashMap<MarketKey, VecDeque<BboSample>>,
+}
+
+impl BboHistory {
+    /// Create a history that retains samples for `window_ms`.
+    #[must_use]
+    pub fn new(window_ms: u64) -> Self {
+        Self {
+            window_ms,
+            samples: DashMap::new(),
+        }
+    }
+
+    /// Create a history wrapped in Arc for sharing.
+    #[must_use]
+    pub fn new_shared(window_ms: u64) -> Arc<Self> {
+        Arc::new(Self::new(window_ms))
+    }
+
+    /// Record a BBO observed at `now_ms`.
+    ///
+    /// One-sided or empty books are ignored.
+    pub fn record(&self, key: MarketKey, bid: Price, ask: Price, now_ms: u64) {
+        if bid.is_zero() || ask.is_zero() {
+            return;
+        }
+        let mut buf = self.samples.entry(key).or_default();
+        buf.push_back(BboSample {
+            timestamp_ms: now_ms,
+            bid,
+            ask,
+        });
+        let cutoff = now_ms.saturating_sub(self.window_ms);
+        while buf.len() > MAX_SAMPLES || buf.front().is_some_and(|s| s.timestamp_ms < cutoff) {
+            buf.pop_front();
+        }
+    }
+
+    /// Signed move (bps) of one side's quote over the last `lookback_ms`.
+    ///
+    /// `side` selects the quote a taker would hit: ask for Buy, bid for Sell.
+    /// Measured from the oldest sample inside the lookback to the latest.
+    /// None when fewer than two samples fall inside the lookback.
+    #[must_use]
+    pub fn move_bps(
+        &self,
+        key: &MarketKey,
+        side: OrderSide,
+        lookback_ms: u64,
+        now_ms: u64,
+    ) -> Option<Decimal> {
+        let buf = self.samples.get(key)?;
+        let cutoff = now_ms.saturating_sub(lookback_ms);
+        let first = buf.iter().find(|s| s.timestamp_ms >= cutoff)?;
+        let last = buf.back()?;
+        if last.timestamp_ms <= first.timestamp_ms {
+            return None;
+        }
+        let quote = |s: &BboSample| match side {
+            OrderSide::Buy => s.ask,
+            OrderSide::Sell => s.bid,
+        };
+        let from = quote(first).inner();
+        Some((quote(last).inner() - from) / from * Decimal::from(10000))
+    }
+
+    /// Drop the history for a market.
+    pub fn clear(&self, key: &MarketKey) {
+        self.samples.remove(key);
+    }
+}
+
+/// Thread-safe handle to BboHistory.
+pub type BboHistoryHandle = Arc<BboHistory>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+
+    fn key() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn px(v: Decimal) -> Price {
+        Price::new(v)
+    }
+
+    #[test]
+    fn test_move_bps_over_lookback() {
+        let history = BboHistory::new(1_000);
+        assert!(history.move_bps(&key(), OrderSide::Buy, 500, 0).is_none());
+
+        history.record(key(), px(dec!(99)), px(dec!(100)), 1_000);
+        // A single sample is not a move
+        assert!(history
+            .move_bps(&key(), OrderSide::Buy, 500, 1_000)
+            .is_none());
+
+        history.record(key(), px(dec!(99.05)), px(dec!(100.05)), 1_200);
+        history.record(key(), px(dec!(98.90)), px(dec!(100.10)), 1_400);
+
+        // Ask up 10 bps since 1_000; bid down ~10 bps
+        assert_eq!(
+            history.move_bps(&key(), OrderSide::Buy, 500, 1_400),
+            Some(dec!(10))
+        );
+        let bid_move = history
+            .move_bps(&key(), OrderSide::Sell, 500, 1_400)
+            .unwrap();
+        assert!(bid_move < dec!(-10) && bid_move > dec!(-11));
+
+        // Shorter lookback starts from the 1_200 sample
+        let ask_move = history
+            .move_bps(&key(), OrderSide::Buy, 300, 1_400)
+            .unwrap();
+        assert!(ask_move > dec!(4.99) && ask_move < dec!(5));
+    }
+
+    #[test]
+    fn test_old_samples_pruned() {
+        let history = BboHistory::new(500);
+        history.record(key(), px(dec!(99)), px(dec!(100)), 0);
+        history.record(key(), px(dec!(99)), px(dec!(101)), 1_000);
+        history.record(key(), px(dec!(99)), px(dec!(101)), 1_100);
+
+        // The 0ms sample was pruned: no move within the window
+        assert_eq!(
+            history.move_bps(&key(), OrderSide::Buy, 5_000, 1_100),
+            Some(dec!(0))
+        );
+
+        // One-sided books are ignored
+        history.record(key(), px(dec!(0)), px(dec!(105)), 1_200);
+        assert_eq!(
+            history.move_bps(&key(), OrderSide::Buy, 5_000, 1_200),
+            Some(dec!(0))
+        );
+    }
+}
@@ -0,0 +1,96 @@
+//! Exchange/local clock skew estimation.
+//!
+//! Each timestamped message gives one offset sample: local receive time
+//! minus exchange server time, i.e. clock skew plus delivery delay. The
+//! smallest offset over a sliding window is the least-delayed message, so
+//! it is taken as the skew (including the minimum delay). Measuring against
+//! that estimate keeps exchange-time ages correct while the local clock
+//! drifts, and what remains of an offset is the extra delivery latency.
+
+use std::collections::VecDeque;
+
+/// Window of the sliding-minimum skew estimate (ms).
+pub const CLOCK_SKEW_WINDOW_MS: u64 = 60_000;
+
+/// Sliding-window minimum of local-minus-exchange clock offsets.
+#[derive(Debug)]
+pub struct ClockSkewEstimator {
+    window_ms: u64,
+    /// (local receive ms, offset ms), offsets strictly increasing, so the
+    /// front is the window minimum.
+    samples: VecDeque<(u64, i64)>,
+}
+
+impl ClockSkewEstimator {
+    /// Create an estimator over `window_ms`.
+    #[must_use]
+    pub fn new(window_ms: u64) -> Self {
+        Self {
+            window_ms,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Record a message stamped `server_time_ms` by the exchange and
+    /// received at `local_ms` (wall clock). Returns its delivery latency
+    /// above the fastest message of the window (ms).
+    pub fn record(&mut self, server_time_ms: i64, local_ms: u64) -> i64 {
+        let offset = local_ms as i64 - server_time_ms;
+        while self.samples.back().is_some_and(|&(_, o)| o >= offset) {
+            self.samples.pop_back();
+        }
+        self.samples.push_back((local_ms, offset));
+        let cutoff = local_ms.saturating_sub(self.window_ms);
+        while self.samples.front().is_some_and(|&(at, _)| at < cutoff) {
+            self.samples.pop_front();
+        }
+        offset - self.skew_ms().unwrap_or(offset)
+    }
+
+    /// Estimated local clock lead over the exchange (ms, incl. the minimum
+    /// delivery delay). None before the first sample.
+    #[must_use]
+    pub fn skew_ms(&self) -> Option<i64> {
+        self.samples.front().map(|&(_, offset)| offset)
+    }
+
+    /// Age (ms) of a message stamped `server_time_ms`, in exchange time at
+    /// local wall time `local_ms`. None before the first sample.
+    #[must_use]
+    pub fn exchange_age_ms(&self, server_time_ms: i64, local_ms: u64) -> Option<i64> {
+        let skew = self.skew_ms()?;
+        Some((local_ms as i64 - skew - server_time_ms).max(0))
+    }
+}
+
+impl Default for ClockSkewEstimator {
+    fn default() -> Self {
+        Self::new(CLOCK_SKEW_WINDOW_MS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_skew_tracks_drift() {
+        let mut clock = ClockSkewEstimator::new(10_000);
+        assert!(clock.skew_ms().is_none());
+
+        // Local clock 500ms ahead, delays 20-80ms
+        assert_eq!(clock.record(1_000, 1_580), 0);
+        assert_eq!(clock.record(2_000, 2_520), 0);
+        assert_eq!(clock.record(3_000, 3_560), 40);
+        assert_eq!(clock.skew_ms(), Some(520));
+
+        // A BBO stamped at 3000 is 1000ms old at local 4520, whatever the skew
+        assert_eq!(clock.exchange_age_ms(3_000, 4_520), Some(1_000));
+
+        // Local clock drifts 2s ahead: once the old samples leave the window
+        // the estimate follows
+        clock.record(20_000, 22_530);
+        assert_eq!(clock.skew_ms(), Some(2_530));
+        assert_eq!(clock.exchange_age_ms(20_000, 22_630), Some(100));
+    }
+}
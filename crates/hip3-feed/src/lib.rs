@@ -6,17 +6,31 @@
 //! # Key Components
 //!
 //! - [`MarketState`]: Aggregates BBO and AssetCtx per market
+//! - [`BboHistory`]: Short per-market BBO ring buffer for quote momentum
+//! - [`ClockSkewEstimator`]: Exchange/local clock skew from server timestamps
 //! - [`MessageParser`]: Parses WebSocket messages into market events
 //! - [`OracleMovementTracker`]: Tracks consecutive oracle price movements
+//! - [`RegimeClassifier`]: Classifies calm / normal / turbulent volatility regimes
+//! - [`TradeTape`]: Rolling public trade prints per market (volume, VWAP, imbalance)
 
+pub mod bbo_history;
+pub mod clock_skew;
 pub mod error;
 pub mod market_state;
 pub mod oracle_tracker;
 pub mod parser;
+pub mod reference;
+pub mod regime;
+pub mod trade_tape;
 
+pub use bbo_history::{BboHistory, BboHistoryHandle};
+pub use clock_skew::{ClockSkewEstimator, CLOCK_SKEW_WINDOW_MS};
 pub use error::{FeedError, FeedResult};
 pub use market_state::MarketState;
 pub use oracle_tracker::{
     MoveDirection, OracleMovementTracker, OracleTrackerConfig, OracleTrackerHandle,
 };
 pub use parser::{MarketEvent, MessageParser};
+pub use reference::{ReferencePriceHandle, ReferencePriceStore};
+pub use regime::{RegimeClassifier, RegimeConfig, RegimeHandle, RegimeStats, VolRegime};
+pub use trade_tape::{TradeStats, TradeTape, TRADE_TAPE_RETENTION_MS};
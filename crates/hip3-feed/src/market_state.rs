@@ -3,11 +3,13 @@
 //! Combines BBO, AssetCtx, and other market data into a unified
 //! state per market.
 
+use crate::trade_tape::{TradeStats, TradeTape};
 use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use hip3_core::types::MarketSnapshot;
-use hip3_core::{AssetCtx, Bbo, MarketKey, Price};
+use hip3_core::{AssetCtx, Bbo, L2Book, MarketKey, OrderSide, Price, Size, TradePrint};
 use parking_lot::RwLock;
+use rust_decimal::Decimal;
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::debug;
@@ -31,6 +33,13 @@ pub struct MarketStateEntry {
     pub ctx_recv_mono: Option<Instant>,
     /// BBO server time from WebSocket (for TimeRegression P0-16).
     pub bbo_server_time: Option<i64>,
+    /// Delivery delay of the BBO above the fastest message of the clock-skew
+    /// window (ms), measured on receipt.
+    pub bbo_delay_ms: Option<i64>,
+    /// L2 order book (only populated when l2Book is subscribed).
+    pub book: Option<Arc<L2Book>>,
+    /// Recent public trades (only populated when trades is subscribed).
+    pub trades: TradeTape,
 }
 
 impl MarketStateEntry {
@@ -44,6 +53,9 @@ impl MarketStateEntry {
             bbo_recv_mono: None,
             ctx_recv_mono: None,
             bbo_server_time: None,
+            bbo_delay_ms: None,
+            book: None,
+            trades: TradeTape::default(),
         }
     }
 
@@ -55,7 +67,9 @@ impl MarketStateEntry {
     /// Get market snapshot if complete.
     pub fn snapshot(&self) -> Option<MarketSnapshot> {
         match (&self.bbo, &self.ctx) {
-            (Some(bbo), Some(ctx)) => Some(MarketSnapshot::new(bbo.clone(), ctx.clone())),
+            (Some(bbo), Some(ctx)) => {
+                Some(MarketSnapshot::new(bbo.clone(), ctx.clone()).with_book(self.book.clone()))
+            }
             _ => None,
         }
     }
@@ -70,6 +84,22 @@ impl MarketStateEntry {
         self.last_update = Utc::now();
         self.bbo_recv_mono = Some(Instant::now());
         self.bbo_server_time = server_time;
+        self.bbo_delay_ms = None;
+    }
+
+    /// Update L2 order book.
+    ///
+    /// l2Book pushes full snapshots, so the book is replaced; a snapshot
+    /// older than the current one (by exchange time) is ignored.
+    pub fn update_book(&mut self, book: L2Book) {
+        let current_time = self.book.as_ref().and_then(|b| b.server_time);
+        if let (Some(current), Some(new)) = (current_time, book.server_time) {
+            if new < current {
+                return;
+            }
+        }
+        self.book = Some(Arc::new(book));
+        self.last_update = Utc::now();
     }
 
     /// Update asset context with oracle tracking.
@@ -102,6 +132,14 @@ impl MarketStateEntry {
         self.bbo_recv_mono.map(|t| t.elapsed().as_millis() as i64)
     }
 
+    /// BBO age in exchange time: monotonic receive age plus the delivery
+    /// delay measured on receipt. A wall-clock step after receipt does not
+    /// move it. Falls back to the receive age without a measured delay.
+    pub fn bbo_exchange_age_ms(&self) -> Option<i64> {
+        self.bbo_age_ms()
+            .map(|age| age + self.bbo_delay_ms.unwrap_or(0).max(0))
+    }
+
     /// Get AssetCtx age in milliseconds (P0-12: monotonic).
     pub fn ctx_age_ms(&self) -> Option<i64> {
         self.ctx_recv_mono.map(|t| t.elapsed().as_millis() as i64)
@@ -120,6 +158,8 @@ type StateEntry = Arc<RwLock<MarketStateEntry>>;
 pub struct MarketState {
     /// Per-market state.
     markets: DashMap<MarketKey, StateEntry>,
+    /// Report BBO ages in exchange time (receive age plus delivery delay).
+    skew_corrected_freshness: bool,
 }
 
 impl MarketState {
@@ -127,9 +167,18 @@ impl MarketState {
     pub fn new() -> Self {
         Self {
             markets: DashMap::new(),
+            skew_corrected_freshness: false,
         }
     }
 
+    /// Report BBO ages in exchange time: the receive age plus the delivery
+    /// delay recorded with [`Self::update_bbo_with_delay`].
+    #[must_use]
+    pub fn with_skew_corrected_freshness(mut self, enabled: bool) -> Self {
+        self.skew_corrected_freshness = enabled;
+        self
+    }
+
     /// Get or create market entry.
     fn get_or_create(&self, key: MarketKey) -> StateEntry {
         self.markets
@@ -149,12 +198,72 @@ impl MarketState {
         entry.write().update_bbo(bbo, server_time);
     }
 
+    /// Update BBO along with its delivery delay above the clock-skew
+    /// minimum (see `ClockSkewEstimator::record`).
+    pub fn update_bbo_with_delay(
+        &self,
+        key: MarketKey,
+        bbo: Bbo,
+        server_time: Option<i64>,
+        delay_ms: Option<i64>,
+    ) {
+        let entry = self.get_or_create(key);
+        let mut guard = entry.write();
+        guard.update_bbo(bbo, server_time);
+        guard.bbo_delay_ms = delay_ms;
+    }
+
     /// Update asset context for a market.
     pub fn update_ctx(&self, key: MarketKey, ctx: AssetCtx) {
         let entry = self.get_or_create(key);
         entry.write().update_ctx(ctx);
     }
 
+    /// Update L2 order book for a market.
+    pub fn update_book(&self, key: MarketKey, book: L2Book) {
+        let entry = self.get_or_create(key);
+        entry.write().update_book(book);
+    }
+
+    /// Record public trade prints for a market.
+    pub fn record_trades(&self, key: MarketKey, prints: &[TradePrint]) {
+        let entry = self.get_or_create(key);
+        entry.write().trades.record(prints);
+    }
+
+    /// Trade statistics of a market over the last `window_ms` (capped by
+    /// the tape retention). None before the first trade.
+    pub fn trade_stats(&self, key: &MarketKey, window_ms: u64, now_ms: u64) -> Option<TradeStats> {
+        self.markets.get(key).and_then(|entry| {
+            let guard = entry.read();
+            guard.trades.stats(window_ms, now_ms)
+        })
+    }
+
+    /// Get L2 order book for a market.
+    pub fn get_book(&self, key: &MarketKey) -> Option<Arc<L2Book>> {
+        self.markets.get(key).and_then(|entry| {
+            let guard = entry.read();
+            guard.book.clone()
+        })
+    }
+
+    /// Size and notional a taker on `side` can trade within `within_bps` of
+    /// the best price, from the L2 book no older than `max_age_ms`.
+    pub fn book_depth(
+        &self,
+        key: &MarketKey,
+        side: OrderSide,
+        within_bps: Decimal,
+        max_age_ms: i64,
+    ) -> Option<(Size, Decimal)> {
+        self.markets.get(key).and_then(|entry| {
+            let guard = entry.read();
+            let book = guard.book.as_ref().filter(|b| b.age_ms() <= max_age_ms)?;
+            Some(book.depth_within_bps(side, within_bps))
+        })
+    }
+
     /// Get market snapshot.
     pub fn get_snapshot(&self, key: &MarketKey) -> Option<MarketSnapshot> {
         self.markets.get(key).and_then(|entry| {
@@ -188,10 +297,16 @@ impl MarketState {
     }
 
     /// Get BBO age for a market (P0-12: monotonic).
+    ///
+    /// With skew-corrected freshness, the exchange-time age instead.
     pub fn get_bbo_age_ms(&self, key: &MarketKey) -> Option<i64> {
         self.markets.get(key).and_then(|entry| {
             let guard = entry.read();
-            guard.bbo_age_ms()
+            if self.skew_corrected_freshness {
+                guard.bbo_exchange_age_ms()
+            } else {
+                guard.bbo_age_ms()
+            }
         })
     }
 
@@ -317,6 +432,25 @@ mod tests {
         assert_eq!(server_time, Some(1000));
     }
 
+    #[test]
+    fn test_skew_corrected_bbo_age() {
+        let key = test_key();
+        let plain = MarketState::new();
+        let corrected = MarketState::new().with_skew_corrected_freshness(true);
+        for state in [&plain, &corrected] {
+            state.update_bbo_with_delay(key, test_bbo(), Some(1000), Some(5_000));
+        }
+
+        // Receive age plus the delivery delay, independent of the wall clock
+        assert!(plain.get_bbo_age_ms(&key).unwrap() < 5_000);
+        let age = corrected.get_bbo_age_ms(&key).unwrap();
+        assert!((5_000..6_000).contains(&age));
+
+        // A BBO without a measured delay falls back to the receive age
+        corrected.update_bbo(key, test_bbo(), None);
+        assert!(corrected.get_bbo_age_ms(&key).unwrap() < 5_000);
+    }
+
     #[test]
     fn test_ctx_age_tracking() {
         let state = MarketState::new();
@@ -329,4 +463,63 @@ mod tests {
         assert!(age.is_some());
         assert!(age.unwrap() >= 0);
     }
+
+    #[test]
+    fn test_snapshot_carries_book() {
+        use hip3_core::BookLevel;
+
+        let state = MarketState::new();
+        let key = test_key();
+
+        state.update_bbo(key, test_bbo(), None);
+        state.update_ctx(key, test_ctx());
+        assert!(state.get_snapshot(&key).unwrap().book.is_none());
+
+        let book = L2Book::new(
+            vec![BookLevel::new(Price::new(dec!(50000)), Size::new(dec!(1)))],
+            vec![BookLevel::new(Price::new(dec!(50010)), Size::new(dec!(1)))],
+        );
+        state.update_book(key, book.clone());
+        assert_eq!(state.get_snapshot(&key).unwrap().book, Some(Arc::new(book)));
+    }
+
+    #[test]
+    fn test_book_ordering_and_depth() {
+        use hip3_core::{BookLevel, OrderSide};
+
+        let state = MarketState::new();
+        let key = test_key();
+        let level = |px, sz| BookLevel::new(Price::new(px), Size::new(sz));
+        let book = L2Book::new(
+            vec![level(dec!(99.9), dec!(1)), level(dec!(99.5), dec!(2))],
+            vec![
+                level(dec!(100.1), dec!(1)),
+                level(dec!(100.15), dec!(2)),
+                level(dec!(100.5), dec!(4)),
+            ],
+        )
+        .with_server_time(Some(2000));
+        state.update_book(key, book.clone());
+
+        // An older snapshot arriving late is ignored
+        let stale =
+            L2Book::new(vec![level(dec!(99), dec!(1))], Vec::new()).with_server_time(Some(1000));
+        state.update_book(key, stale);
+        assert_eq!(state.get_book(&key), Some(Arc::new(book.clone())));
+        assert_eq!(book.mid(), Some(Price::new(dec!(100))));
+
+        // Buy takes the asks: 10 bps of 100.1 covers the first two levels
+        let (size, notional) = state
+            .book_depth(&key, OrderSide::Buy, dec!(10), 60_000)
+            .unwrap();
+        assert_eq!(size, Size::new(dec!(3)));
+        assert_eq!(notional, dec!(300.4));
+        let (size, _) = state
+            .book_depth(&key, OrderSide::Sell, dec!(100), 60_000)
+            .unwrap();
+        assert_eq!(size, Size::new(dec!(3)));
+        assert!(state
+            .book_depth(&test_key(), OrderSide::Buy, dec!(10), -1)
+            .is_none());
+    }
 }
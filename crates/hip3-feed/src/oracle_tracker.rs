@@ -77,6 +77,8 @@ struct OracleHistory {
     /// Last recorded oracle change in basis points (absolute value).
     /// Used for velocity-based sizing (P2-1).
     last_change_bps: Decimal,
+    /// Time of the last Up/Down move (Unix ms). None until the first move.
+    last_move_ms: Option<u64>,
 }
 
 impl OracleHistory {
@@ -86,6 +88,7 @@ impl OracleHistory {
             consecutive_up: 0,
             consecutive_down: 0,
             last_change_bps: Decimal::ZERO,
+            last_move_ms: None,
         }
     }
 }
@@ -157,6 +160,13 @@ impl OracleMovementTracker {
     /// - But it doesn't negate the previous trend
     /// - The "stale liquidity" from previous moves may still exist
     pub fn record_move(&self, key: MarketKey, oracle_px: Price) -> MoveDirection {
+        let now_ms = chrono::Utc::now().timestamp_millis().max(0) as u64;
+        self.record_move_at(key, oracle_px, now_ms)
+    }
+
+    /// [`Self::record_move`] with an explicit observation time (Unix ms),
+    /// e.g. when replaying recorded data.
+    pub fn record_move_at(&self, key: MarketKey, oracle_px: Price, now_ms: u64) -> MoveDirection {
         let mut entry = self.histories.entry(key).or_insert_with(|| {
             // First observation: no direction yet
             OracleHistory::new(oracle_px)
@@ -192,11 +202,13 @@ impl OracleMovementTracker {
                 history.consecutive_up += 1;
                 history.consecutive_down = 0;
                 history.last_change_bps = change_bps;
+                history.last_move_ms = Some(now_ms);
             }
             MoveDirection::Down => {
                 history.consecutive_down += 1;
                 history.consecutive_up = 0;
                 history.last_change_bps = change_bps;
+                history.last_move_ms = Some(now_ms);
             }
             MoveDirection::Unchanged => {
                 // Preserve counts - see design decision above
@@ -277,6 +289,38 @@ impl OracleMovementTracker {
             .unwrap_or(Decimal::ZERO)
     }
 
+    /// Time since the last Up/Down move (ms), or None if none recorded.
+    #[must_use]
+    pub fn move_age_ms(&self, key: &MarketKey, now_ms: u64) -> Option<u64> {
+        self.histories
+            .get(key)
+            .and_then(|h| h.last_move_ms)
+            .map(|moved_ms| now_ms.saturating_sub(moved_ms))
+    }
+
+    /// Markets with recorded history.
+    #[must_use]
+    pub fn markets(&self) -> Vec<MarketKey> {
+        self.histories.iter().map(|h| *h.key()).collect()
+    }
+
+    /// Restore last price and consecutive counts (warm start).
+    ///
+    /// Does not overwrite a market that has already recorded a move.
+    pub fn restore(
+        &self,
+        key: MarketKey,
+        last_px: Price,
+        consecutive_up: u32,
+        consecutive_down: u32,
+    ) {
+        self.histories.entry(key).or_insert_with(|| OracleHistory {
+            consecutive_up,
+            consecutive_down,
+            ..OracleHistory::new(last_px)
+        });
+    }
+
     /// Clear tracking data for a market (e.g., on reconnect).
     pub fn clear(&self, key: &MarketKey) {
         self.histories.remove(key);
@@ -487,4 +531,39 @@ mod tests {
         assert!(!MoveDirection::Up.is_favorable_for(OrderSide::Sell));
         assert!(!MoveDirection::Unchanged.is_favorable_for(OrderSide::Sell));
     }
+
+    #[test]
+    fn test_restore_counts() {
+        let tracker = OracleMovementTracker::new(config());
+        let key = test_key();
+
+        tracker.restore(key, Price::new(dec!(100)), 2, 0);
+        assert_eq!(tracker.markets(), vec![key]);
+        assert_eq!(tracker.consecutive_counts(&key), (2, 0));
+
+        // Next up move extends the restored streak
+        tracker.record_move(key, Price::new(dec!(100.10)));
+        assert_eq!(tracker.consecutive(&key, MoveDirection::Up), 3);
+
+        // Restore never overwrites live history
+        tracker.restore(key, Price::new(dec!(50)), 0, 9);
+        assert_eq!(tracker.consecutive_counts(&key), (3, 0));
+    }
+
+    #[test]
+    fn test_move_age_ms() {
+        let tracker = OracleMovementTracker::new(config());
+        let key = test_key();
+
+        // First observation is not a move
+        tracker.record_move_at(key, Price::new(dec!(100)), 1_000);
+        assert_eq!(tracker.move_age_ms(&key, 2_000), None);
+
+        tracker.record_move_at(key, Price::new(dec!(100.10)), 3_000);
+        assert_eq!(tracker.move_age_ms(&key, 3_500), Some(500));
+
+        // Unchanged ticks keep the last move time
+        tracker.record_move_at(key, Price::new(dec!(100.10)), 4_000);
+        assert_eq!(tracker.move_age_ms(&key, 4_000), Some(1_000));
+    }
 }
@@ -8,10 +8,13 @@
 //! 2. Hyperliquid format: "bbo" with coin in data ({"coin": "BTC", "bbo": ...})
 
 use crate::error::{FeedError, FeedResult};
-use hip3_core::{AssetCtx, AssetId, Bbo, DexId, MarketKey, OracleData, Price, Size};
+use hip3_core::{
+    AssetCtx, AssetId, Bbo, BookLevel, DexId, L2Book, MarketKey, OracleData, OrderSide, Price,
+    Size, TradePrint,
+};
 use rust_decimal::Decimal;
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicU64, Ordering};
 use tracing::{debug, warn};
 
@@ -71,6 +74,31 @@ pub struct HyperliquidLevel {
     pub n: i32,
 }
 
+/// Hyperliquid l2Book message format.
+/// Format: {"coin": "BTC", "time": 123456789, "levels": [[bids...], [asks...]]}
+#[derive(Debug, Deserialize)]
+pub struct HyperliquidL2Book {
+    pub coin: String,
+    #[serde(default)]
+    pub time: Option<i64>,
+    /// Book levels: [bids (best first), asks (best first)]
+    pub levels: (Vec<HyperliquidLevel>, Vec<HyperliquidLevel>),
+}
+
+/// Hyperliquid trades message element.
+/// Format: [{"coin": "BTC", "side": "B", "px": "...", "sz": "...", "time": 123456789, ...}]
+#[derive(Debug, Deserialize)]
+pub struct HyperliquidTrade {
+    pub coin: String,
+    /// Aggressor side: "B" = buy, "A" = sell.
+    pub side: String,
+    pub px: String,
+    pub sz: String,
+    pub time: u64,
+    /// Trade id, unique per trade.
+    pub tid: u64,
+}
+
 /// Hyperliquid activeAssetCtx message format.
 /// Format: {"coin": "BTC", "ctx": {...}}
 #[derive(Debug, Deserialize)]
@@ -120,6 +148,15 @@ pub enum MarketEvent {
     BboUpdate { key: MarketKey, bbo: Bbo },
     /// Asset context update.
     CtxUpdate { key: MarketKey, ctx: AssetCtx },
+    /// L2 order book update.
+    BookUpdate { key: MarketKey, book: L2Book },
+    /// Public trade prints (oldest first).
+    TradesUpdate {
+        key: MarketKey,
+        trades: Vec<TradePrint>,
+    },
+    /// Oracle update for a reference coin that is watched but not traded.
+    ReferenceUpdate { coin: String, oracle_px: Price },
 }
 
 /// Channel type extracted from channel name.
@@ -142,6 +179,9 @@ pub struct MessageParser {
     /// Coin symbol to asset index mapping (for Hyperliquid format).
     /// For xyz markets, asset_idx = 100000 + perp_dex_id * 10000 + local_index.
     coin_to_idx: HashMap<String, u32>,
+    /// Reference coins (uppercase) whose asset contexts are accepted even
+    /// though they are not in `coin_to_idx` (lead-lag inputs).
+    reference_coins: HashSet<String>,
 }
 
 impl MessageParser {
@@ -151,6 +191,7 @@ impl MessageParser {
             dex_id: DexId::XYZ,
             spot_stats: SpotRejectionStats::default(),
             coin_to_idx: HashMap::new(),
+            reference_coins: HashSet::new(),
         }
     }
 
@@ -160,6 +201,7 @@ impl MessageParser {
             dex_id: DexId::XYZ,
             spot_stats: SpotRejectionStats::default(),
             coin_to_idx: coin_mapping,
+            reference_coins: HashSet::new(),
         }
     }
 
@@ -168,6 +210,13 @@ impl MessageParser {
         self.coin_to_idx.insert(coin.to_uppercase(), asset_idx);
     }
 
+    /// Accept asset contexts for a coin outside the traded set.
+    ///
+    /// Updates for it are emitted as [`MarketEvent::ReferenceUpdate`].
+    pub fn add_reference_coin(&mut self, coin: &str) {
+        self.reference_coins.insert(coin.to_uppercase());
+    }
+
     /// Set the DEX ID for market keys.
     ///
     /// This should be called after discovering the actual xyz DEX index
@@ -202,6 +251,14 @@ impl MessageParser {
             return self.parse_hyperliquid_asset_ctx(data);
         }
 
+        if channel == "l2Book" {
+            return self.parse_hyperliquid_l2_book(data);
+        }
+
+        if channel == "trades" {
+            return self.parse_hyperliquid_trades(data);
+        }
+
         // P0-30: Validate channel type (perps only) for internal format
         let channel_type = self.extract_channel_type(channel);
 
@@ -260,7 +317,7 @@ impl MessageParser {
             None => (Price::new(Decimal::ZERO), Size::new(Decimal::ZERO)),
         };
 
-        let bbo = Bbo::new(bid_price, bid_size, ask_price, ask_size);
+        let bbo = Bbo::new(bid_price, bid_size, ask_price, ask_size).with_server_time(hl_bbo.time);
 
         self.spot_stats.record_accepted();
 
@@ -274,25 +331,132 @@ impl MessageParser {
         Ok(Some(MarketEvent::BboUpdate { key, bbo }))
     }
 
-    /// Parse Hyperliquid activeAssetCtx format.
-    fn parse_hyperliquid_asset_ctx(
+    /// Parse Hyperliquid l2Book format.
+    fn parse_hyperliquid_l2_book(
         &self,
         data: &serde_json::Value,
     ) -> FeedResult<Option<MarketEvent>> {
-        let hl_ctx: HyperliquidAssetCtx = serde_json::from_value(data.clone())
-            .map_err(|e| FeedError::ParseError(format!("Invalid Hyperliquid AssetCtx: {e}")))?;
+        let hl_book: HyperliquidL2Book = serde_json::from_value(data.clone())
+            .map_err(|e| FeedError::ParseError(format!("Invalid Hyperliquid l2Book: {e}")))?;
 
         // Look up asset index from coin
         let asset_idx = self
             .coin_to_idx
-            .get(&hl_ctx.coin.to_uppercase())
+            .get(&hl_book.coin.to_uppercase())
             .copied()
             .ok_or_else(|| {
-                FeedError::ParseError(format!("Unknown coin: {} (not in mapping)", hl_ctx.coin))
+                FeedError::ParseError(format!("Unknown coin: {} (not in mapping)", hl_book.coin))
             })?;
 
         let key = MarketKey::new(self.dex_id, AssetId::new(asset_idx));
 
+        let bids = self.parse_book_side(&hl_book.levels.0)?;
+        let asks = self.parse_book_side(&hl_book.levels.1)?;
+        let book = L2Book::new(bids, asks).with_server_time(hl_book.time);
+
+        self.spot_stats.record_accepted();
+
+        debug!(
+            ?key,
+            coin = %hl_book.coin,
+            bid_levels = book.bids.len(),
+            ask_levels = book.asks.len(),
+            "Hyperliquid l2Book update"
+        );
+        Ok(Some(MarketEvent::BookUpdate { key, book }))
+    }
+
+    /// Parse Hyperliquid trades format.
+    fn parse_hyperliquid_trades(
+        &self,
+        data: &serde_json::Value,
+    ) -> FeedResult<Option<MarketEvent>> {
+        let hl_trades: Vec<HyperliquidTrade> = serde_json::from_value(data.clone())
+            .map_err(|e| FeedError::ParseError(format!("Invalid Hyperliquid trades: {e}")))?;
+
+        // Subscriptions are per coin, so one message carries one coin
+        let Some(first) = hl_trades.first() else {
+            return Ok(None);
+        };
+
+        // Look up asset index from coin
+        let asset_idx = self
+            .coin_to_idx
+            .get(&first.coin.to_uppercase())
+            .copied()
+            .ok_or_else(|| {
+                FeedError::ParseError(format!("Unknown coin: {} (not in mapping)", first.coin))
+            })?;
+
+        let key = MarketKey::new(self.dex_id, AssetId::new(asset_idx));
+
+        let mut trades = hl_trades
+            .iter()
+            .map(|trade| {
+                let aggressor = match trade.side.as_str() {
+                    "B" => OrderSide::Buy,
+                    "A" => OrderSide::Sell,
+                    other => {
+                        return Err(FeedError::ParseError(format!(
+                            "Invalid trade side: {other}"
+                        )))
+                    }
+                };
+                Ok(TradePrint {
+                    price: self.parse_price(&trade.px)?,
+                    size: self.parse_size(&trade.sz)?,
+                    aggressor,
+                    time_ms: trade.time,
+                    tid: trade.tid,
+                })
+            })
+            .collect::<FeedResult<Vec<_>>>()?;
+        trades.sort_by_key(|t| t.time_ms);
+
+        debug!(
+            ?key,
+            coin = %first.coin,
+            count = trades.len(),
+            "Hyperliquid trades update"
+        );
+        Ok(Some(MarketEvent::TradesUpdate { key, trades }))
+    }
+
+    fn parse_book_side(&self, levels: &[HyperliquidLevel]) -> FeedResult<Vec<BookLevel>> {
+        levels
+            .iter()
+            .map(|level| {
+                Ok(BookLevel::new(
+                    self.parse_price(&level.px)?,
+                    self.parse_size(&level.sz)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Parse Hyperliquid activeAssetCtx format.
+    fn parse_hyperliquid_asset_ctx(
+        &self,
+        data: &serde_json::Value,
+    ) -> FeedResult<Option<MarketEvent>> {
+        let hl_ctx: HyperliquidAssetCtx = serde_json::from_value(data.clone())
+            .map_err(|e| FeedError::ParseError(format!("Invalid Hyperliquid AssetCtx: {e}")))?;
+
+        // Look up asset index from coin
+        let coin = hl_ctx.coin.to_uppercase();
+        let Some(asset_idx) = self.coin_to_idx.get(&coin).copied() else {
+            if self.reference_coins.contains(&coin) {
+                let oracle_px = self.parse_price(&hl_ctx.ctx.oracle_px)?;
+                return Ok(Some(MarketEvent::ReferenceUpdate { coin, oracle_px }));
+            }
+            return Err(FeedError::ParseError(format!(
+                "Unknown coin: {} (not in mapping)",
+                hl_ctx.coin
+            )));
+        };
+
+        let key = MarketKey::new(self.dex_id, AssetId::new(asset_idx));
+
         // Parse string values to Decimal
         let oracle_px = self.parse_price(&hl_ctx.ctx.oracle_px)?;
         let mark_px = self.parse_price(&hl_ctx.ctx.mark_px)?;
@@ -515,6 +679,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_hyperliquid_l2_book() {
+        let mut parser = MessageParser::new();
+        parser.add_coin_mapping("xyz:SILVER".to_string(), 110027);
+        let data = json!({
+            "coin": "xyz:SILVER",
+            "time": 1700000000000_i64,
+            "levels": [
+                [{"px": "30.00", "sz": "10", "n": 2}, {"px": "29.99", "sz": "5", "n": 1}],
+                [{"px": "30.01", "sz": "7", "n": 1}]
+            ]
+        });
+
+        let result = parser.parse_channel_message("l2Book", &data).unwrap();
+        if let Some(MarketEvent::BookUpdate { key, book }) = result {
+            assert_eq!(key.asset.index(), 110027);
+            assert_eq!(book.bids.len(), 2);
+            assert_eq!(book.asks.len(), 1);
+            assert_eq!(book.bids[1].price.to_string(), "29.99");
+        } else {
+            panic!("Expected BookUpdate");
+        }
+    }
+
+    #[test]
+    fn test_parse_hyperliquid_trades() {
+        let mut parser = MessageParser::new();
+        parser.add_coin_mapping("xyz:SILVER".to_string(), 110027);
+        let data = json!([
+            {"coin": "xyz:SILVER", "side": "A", "px": "30.01", "sz": "2", "time": 1700000000500_u64,
+             "hash": "0x0", "tid": 2, "users": ["0xa", "0xb"]},
+            {"coin": "xyz:SILVER", "side": "B", "px": "30.00", "sz": "1.5", "time": 1700000000000_u64,
+             "hash": "0x0", "tid": 1, "users": ["0xa", "0xb"]}
+        ]);
+
+        let result = parser.parse_channel_message("trades", &data).unwrap();
+        if let Some(MarketEvent::TradesUpdate { key, trades }) = result {
+            assert_eq!(key.asset.index(), 110027);
+            assert_eq!(trades.len(), 2);
+            assert_eq!(trades[0].aggressor, OrderSide::Buy);
+            assert_eq!(trades[0].price.to_string(), "30.00");
+            assert_eq!(trades[1].aggressor, OrderSide::Sell);
+            assert_eq!(trades[1].tid, 2);
+        } else {
+            panic!("Expected TradesUpdate");
+        }
+
+        assert!(parser
+            .parse_channel_message("trades", &json!([]))
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_reference_asset_ctx() {
+        let mut parser = MessageParser::new();
+        parser.add_reference_coin("btc");
+        let data = json!({
+            "coin": "BTC",
+            "ctx": {
+                "oraclePx": "97000.0",
+                "markPx": "97010.0",
+                "funding": "0.0000125",
+                "openInterest": "1000.0"
+            }
+        });
+
+        let result = parser
+            .parse_channel_message("activeAssetCtx", &data)
+            .unwrap();
+        if let Some(MarketEvent::ReferenceUpdate { coin, oracle_px }) = result {
+            assert_eq!(coin, "BTC");
+            assert_eq!(oracle_px.to_string(), "97000.0");
+        } else {
+            panic!("Expected ReferenceUpdate");
+        }
+
+        // Coins that are neither mapped nor referenced are still rejected
+        let data = json!({
+            "coin": "ETH",
+            "ctx": {"oraclePx": "1", "markPx": "1", "funding": "0", "openInterest": "0"}
+        });
+        assert!(parser
+            .parse_channel_message("activeAssetCtx", &data)
+            .is_err());
+    }
+
     // === P0-30: Perps/Spot混在封じ tests ===
 
     #[test]
@@ -0,0 +1,91 @@
+//! Secondary reference prices for oracle sanity checks.
+//!
+//! A bad oracle print shows up as a large, one-sided dislocation against a
+//! perfectly normal book. Cross-checking the oracle against an independent
+//! price (an external index, or the same underlying on another venue) lets
+//! the detector tell a real stale quote from a phantom edge.
+//!
+//! The store is source-agnostic: whoever polls or streams the reference
+//! calls [`ReferencePriceStore::update`], and readers ask for a price no
+//! older than their own freshness limit.
+
+use dashmap::DashMap;
+use hip3_core::{MarketKey, Price};
+use std::sync::Arc;
+
+/// Latest reference price per market.
+///
+/// Thread-safe via DashMap so a polling task can write while the detector reads.
+#[derive(Default)]
+pub struct ReferencePriceStore {
+    prices: DashMap<MarketKey, (Price, u64)>,
+}
+
+impl ReferencePriceStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty store wrapped in Arc for sharing.
+    #[must_use]
+    pub fn new_shared() -> Arc<Self> {
+        Arc::new(Self::new())
+    }
+
+    /// Record a reference price observed at `now_ms`.
+    pub fn update(&self, key: MarketKey, px: Price, now_ms: u64) {
+        if px.is_zero() {
+            return;
+        }
+        self.prices.insert(key, (px, now_ms));
+    }
+
+    /// Latest reference price and its timestamp (ms).
+    #[must_use]
+    pub fn get(&self, key: &MarketKey) -> Option<(Price, u64)> {
+        self.prices.get(key).map(|e| *e)
+    }
+
+    /// Reference price if updated within `max_age_ms` of `now_ms` (0 = no limit).
+    #[must_use]
+    pub fn fresh(&self, key: &MarketKey, now_ms: u64, max_age_ms: u64) -> Option<Price> {
+        let (px, updated_ms) = self.get(key)?;
+        (max_age_ms == 0 || now_ms.saturating_sub(updated_ms) <= max_age_ms).then_some(px)
+    }
+
+    /// Clear the reference price for a market.
+    pub fn clear(&self, key: &MarketKey) {
+        self.prices.remove(key);
+    }
+}
+
+/// Thread-safe handle to ReferencePriceStore.
+pub type ReferencePriceHandle = Arc<ReferencePriceStore>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_fresh_respects_max_age() {
+        let store = ReferencePriceStore::new();
+        let key = MarketKey::new(DexId::XYZ, AssetId::new(0));
+        assert!(store.fresh(&key, 0, 1_000).is_none());
+
+        store.update(key, Price::new(dec!(100)), 10_000);
+        assert_eq!(
+            store.fresh(&key, 10_500, 1_000),
+            Some(Price::new(dec!(100)))
+        );
+        assert!(store.fresh(&key, 11_001, 1_000).is_none());
+        assert!(store.fresh(&key, 99_000, 0).is_some());
+
+        // Zero prices are ignored
+        store.update(key, Price::new(dec!(0)), 20_000);
+        assert_eq!(store.get(&key), Some((Price::new(dec!(100)), 10_000)));
+    }
+}
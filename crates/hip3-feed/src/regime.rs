@@ -0,0 +1,356 @@
+//! Volatility regime classification (calm / normal / turbulent).
+//!
+//! Samples each market's mid price into fixed-interval bars and computes,
+//! over a rolling window:
+//!
+//! - **Realized volatility**: square root of the summed squared bar returns (bps)
+//! - **Wick**: price range not explained by the net move, i.e.
+//!   `(high - low) - |last - first|` relative to the window mean (bps)
+//!
+//! Large wicks with little net movement are the signature of liquidity
+//! sweeps and stop runs, which is exactly when stale-quote edges are least
+//! reliable. Either statistic can push a market into `Turbulent`; both must
+//! be quiet for `Calm`.
+//!
+//! The detector consumes the regime to scale its entry threshold and cap
+//! position size, so the bot demands more edge in turbulent markets without
+//! manual config changes.
+
+use dashmap::DashMap;
+use hip3_core::{MarketKey, Price};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+use tracing::info;
+
+/// Volatility regime of a market.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VolRegime {
+    /// Low realized volatility and small wicks.
+    Calm,
+    /// Neither calm nor turbulent (also used until enough samples exist).
+    #[default]
+    Normal,
+    /// High realized volatility or large wicks.
+    Turbulent,
+}
+
+impl fmt::Display for VolRegime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Calm => write!(f, "calm"),
+            Self::Normal => write!(f, "normal"),
+            Self::Turbulent => write!(f, "turbulent"),
+        }
+    }
+}
+
+/// Configuration for volatility regime classification.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegimeConfig {
+    /// Rolling window length in ms.
+    #[serde(default = "default_window_ms")]
+    pub window_ms: u64,
+    /// Minimum spacing between samples in ms (bar length).
+    #[serde(default = "default_sample_interval_ms")]
+    pub sample_interval_ms: u64,
+    /// Samples required before leaving `Normal`.
+    #[serde(default = "default_min_samples")]
+    pub min_samples: usize,
+    /// Calm when realized vol is at or below this (bps).
+    #[serde(default = "default_calm_max_vol_bps")]
+    pub calm_max_vol_bps: Decimal,
+    /// Calm also requires the wick to be at or below this (bps).
+    #[serde(default = "default_calm_max_wick_bps")]
+    pub calm_max_wick_bps: Decimal,
+    /// Turbulent when realized vol is at or above this (bps).
+    #[serde(default = "default_turbulent_min_vol_bps")]
+    pub turbulent_min_vol_bps: Decimal,
+    /// Turbulent when the wick is at or above this (bps).
+    #[serde(default = "default_turbulent_min_wick_bps")]
+    pub turbulent_min_wick_bps: Decimal,
+}
+
+fn default_window_ms() -> u64 {
+    60_000 // 1 min
+}
+
+fn default_sample_interval_ms() -> u64 {
+    1_000 // 1s bars
+}
+
+fn default_min_samples() -> usize {
+    20
+}
+
+fn default_calm_max_vol_bps() -> Decimal {
+    Decimal::from(5)
+}
+
+fn default_calm_max_wick_bps() -> Decimal {
+    Decimal::from(10)
+}
+
+fn default_turbulent_min_vol_bps() -> Decimal {
+    Decimal::from(30)
+}
+
+fn default_turbulent_min_wick_bps() -> Decimal {
+    Decimal::from(50)
+}
+
+impl Default for RegimeConfig {
+    fn default() -> Self {
+        Self {
+            window_ms: default_window_ms(),
+            sample_interval_ms: default_sample_interval_ms(),
+            min_samples: default_min_samples(),
+            calm_max_vol_bps: default_calm_max_vol_bps(),
+            calm_max_wick_bps: default_calm_max_wick_bps(),
+            turbulent_min_vol_bps: default_turbulent_min_vol_bps(),
+            turbulent_min_wick_bps: default_turbulent_min_wick_bps(),
+        }
+    }
+}
+
+/// Window statistics behind a regime classification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegimeStats {
+    /// Realized volatility over the window (bps).
+    pub realized_vol_bps: Decimal,
+    /// Wick size over the window (bps).
+    pub wick_bps: Decimal,
+    /// Number of samples in the window.
+    pub samples: usize,
+}
+
+/// Per-market sample history.
+#[derive(Debug, Default)]
+struct RegimeHistory {
+    /// (timestamp_ms, mid price), oldest first.
+    samples: VecDeque<(u64, Decimal)>,
+    /// Last classified regime.
+    regime: VolRegime,
+}
+
+/// Classifies each market's volatility regime from sampled mid prices.
+///
+/// Thread-safe via DashMap, like [`OracleMovementTracker`](crate::OracleMovementTracker).
+pub struct RegimeClassifier {
+    config: RegimeConfig,
+    histories: DashMap<MarketKey, RegimeHistory>,
+}
+
+impl RegimeClassifier {
+    /// Create a new classifier with the given configuration.
+    #[must_use]
+    pub fn new(config: RegimeConfig) -> Self {
+        Self {
+            config,
+            histories: DashMap::new(),
+        }
+    }
+
+    /// Create a new classifier wrapped in Arc for sharing.
+    #[must_use]
+    pub fn new_shared(config: RegimeConfig) -> Arc<Self> {
+        Arc::new(Self::new(config))
+    }
+
+    /// Record a mid price and return the (possibly updated) regime.
+    ///
+    /// Samples closer than `sample_interval_ms` to the previous one are
+    /// ignored, so this can be called on every BBO update.
+    pub fn record(&self, key: MarketKey, mid: Price, now_ms: u64) -> VolRegime {
+        let mut entry = self.histories.entry(key).or_default();
+        let history = entry.value_mut();
+
+        if let Some(&(last_ms, _)) = history.samples.back() {
+            if now_ms.saturating_sub(last_ms) < self.config.sample_interval_ms {
+                return history.regime;
+            }
+        }
+        if mid.is_zero() {
+            return history.regime;
+        }
+
+        history.samples.push_back((now_ms, mid.inner()));
+        let cutoff = now_ms.saturating_sub(self.config.window_ms);
+        while history.samples.front().is_some_and(|&(ts, _)| ts < cutoff) {
+            history.samples.pop_front();
+        }
+
+        let regime = match Self::compute_stats(&history.samples) {
+            Some(stats) if stats.samples >= self.config.min_samples => self.classify(&stats),
+            _ => VolRegime::Normal,
+        };
+        if regime != history.regime {
+            info!(
+                market = %key,
+                from = %history.regime,
+                to = %regime,
+                "Volatility regime changed"
+            );
+            history.regime = regime;
+        }
+        regime
+    }
+
+    /// Current regime for a market (`Normal` if unknown).
+    #[must_use]
+    pub fn regime(&self, key: &MarketKey) -> VolRegime {
+        self.histories
+            .get(key)
+            .map(|h| h.regime)
+            .unwrap_or_default()
+    }
+
+    /// Current window statistics for a market.
+    #[must_use]
+    pub fn stats(&self, key: &MarketKey) -> Option<RegimeStats> {
+        self.histories
+            .get(key)
+            .and_then(|h| Self::compute_stats(&h.samples))
+    }
+
+    /// Realized volatility over the window (bps), once `min_samples` exist.
+    #[must_use]
+    pub fn realized_vol_bps(&self, key: &MarketKey) -> Option<Decimal> {
+        self.stats(key)
+            .filter(|s| s.samples >= self.config.min_samples)
+            .map(|s| s.realized_vol_bps)
+    }
+
+    /// Clear tracking data for a market (e.g., on reconnect).
+    pub fn clear(&self, key: &MarketKey) {
+        self.histories.remove(key);
+    }
+
+    fn classify(&self, stats: &RegimeStats) -> VolRegime {
+        if stats.realized_vol_bps >= self.config.turbulent_min_vol_bps
+            || stats.wick_bps >= self.config.turbulent_min_wick_bps
+        {
+            VolRegime::Turbulent
+        } else if stats.realized_vol_bps <= self.config.calm_max_vol_bps
+            && stats.wick_bps <= self.config.calm_max_wick_bps
+        {
+            VolRegime::Calm
+        } else {
+            VolRegime::Normal
+        }
+    }
+
+    fn compute_stats(samples: &VecDeque<(u64, Decimal)>) -> Option<RegimeStats> {
+        let (_, first) = *samples.front()?;
+        let (_, last) = *samples.back()?;
+        let bps = Decimal::from(10000);
+
+        let mut sum_sq = Decimal::ZERO;
+        let mut sum = Decimal::ZERO;
+        let mut high = first;
+        let mut low = first;
+        let mut prev = first;
+        for &(_, px) in samples {
+            let ret_bps = (px - prev) / prev * bps;
+            sum_sq += ret_bps * ret_bps;
+            sum += px;
+            high = high.max(px);
+            low = low.min(px);
+            prev = px;
+        }
+
+        let realized_vol_bps = sum_sq
+            .to_f64()
+            .map(f64::sqrt)
+            .and_then(Decimal::from_f64)
+            .unwrap_or(Decimal::ZERO);
+        let mean = sum / Decimal::from(samples.len());
+        let wick = (high - low) - (last - first).abs();
+        let wick_bps = wick / mean * bps;
+
+        Some(RegimeStats {
+            realized_vol_bps,
+            wick_bps,
+            samples: samples.len(),
+        })
+    }
+}
+
+/// Thread-safe handle to RegimeClassifier.
+pub type RegimeHandle = Arc<RegimeClassifier>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+
+    fn key() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn config() -> RegimeConfig {
+        RegimeConfig {
+            min_samples: 5,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_normal_until_min_samples() {
+        let classifier = RegimeClassifier::new(config());
+        for i in 0..4 {
+            classifier.record(key(), Price::new(dec!(100)), i * 1_000);
+        }
+        assert_eq!(classifier.regime(&key()), VolRegime::Normal);
+        assert_eq!(classifier.realized_vol_bps(&key()), None);
+
+        // Fifth flat sample → calm
+        let regime = classifier.record(key(), Price::new(dec!(100)), 4_000);
+        assert_eq!(regime, VolRegime::Calm);
+        assert_eq!(classifier.realized_vol_bps(&key()), Some(Decimal::ZERO));
+    }
+
+    #[test]
+    fn test_samples_throttled_by_interval() {
+        let classifier = RegimeClassifier::new(config());
+        for i in 0..10 {
+            classifier.record(key(), Price::new(dec!(100)), i * 100);
+        }
+        assert_eq!(classifier.stats(&key()).unwrap().samples, 1);
+    }
+
+    #[test]
+    fn test_wick_without_net_move_is_turbulent() {
+        let classifier = RegimeClassifier::new(config());
+        // 100 → spike to 100.6 → back to 100: 60 bps wick, 0 net
+        let prices = [dec!(100), dec!(100), dec!(100.6), dec!(100), dec!(100)];
+        for (i, px) in prices.into_iter().enumerate() {
+            classifier.record(key(), Price::new(px), i as u64 * 1_000);
+        }
+        let stats = classifier.stats(&key()).unwrap();
+        assert!(stats.wick_bps >= dec!(50), "wick={}", stats.wick_bps);
+        assert_eq!(classifier.regime(&key()), VolRegime::Turbulent);
+    }
+
+    #[test]
+    fn test_old_samples_leave_window() {
+        let classifier = RegimeClassifier::new(config());
+        let prices = [dec!(100), dec!(101), dec!(100), dec!(101), dec!(100)];
+        for (i, px) in prices.into_iter().enumerate() {
+            classifier.record(key(), Price::new(px), i as u64 * 1_000);
+        }
+        assert_eq!(classifier.regime(&key()), VolRegime::Turbulent);
+
+        // A quiet minute later, the choppy samples have aged out
+        for i in 0..5 {
+            classifier.record(key(), Price::new(dec!(100)), 70_000 + i * 1_000);
+        }
+        assert_eq!(classifier.stats(&key()).unwrap().samples, 5);
+        assert_eq!(classifier.regime(&key()), VolRegime::Calm);
+    }
+}
@@ -0,0 +1,175 @@
+//! Rolling public trade tape per market.
+//!
+//! Keeps the last few minutes of trade prints from the trades channel so the
+//! detector and MM spread logic can ask what the market has actually traded:
+//! volume, VWAP, aggressor imbalance and the last print. Stats are computed
+//! over a caller-chosen window, so each consumer picks its own horizon.
+//!
+//! Prints are deduplicated by trade id: the snapshot sent on every
+//! (re)subscription repeats trades already on the tape.
+
+use hip3_core::{OrderSide, Price, Size, TradePrint};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::{HashSet, VecDeque};
+
+/// Prints older than this (ms) are dropped, whatever the requested window.
+pub const TRADE_TAPE_RETENTION_MS: u64 = 300_000;
+
+/// Hard cap on prints kept per market, independent of the retention.
+const MAX_PRINTS: usize = 4096;
+
+/// Trade statistics of one market over a window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TradeStats {
+    /// Number of prints in the window.
+    pub count: usize,
+    /// Traded size.
+    pub volume: Size,
+    /// Traded notional (USD).
+    pub notional: Decimal,
+    /// Size bought by aggressors.
+    pub buy_volume: Size,
+    /// Size sold by aggressors.
+    pub sell_volume: Size,
+    /// Volume-weighted average price. None without volume.
+    pub vwap: Option<Price>,
+    /// Last trade price (may be outside the window).
+    pub last_px: Price,
+    /// Age of the last trade (ms).
+    pub last_age_ms: u64,
+}
+
+impl TradeStats {
+    /// Aggressor imbalance in [-1, 1]: +1 = all aggressive buys, -1 = all
+    /// aggressive sells. 0 without volume.
+    #[must_use]
+    pub fn imbalance(&self) -> f64 {
+        let total = self.volume.inner();
+        if total.is_zero() {
+            return 0.0;
+        }
+        ((self.buy_volume.inner() - self.sell_volume.inner()) / total)
+            .to_f64()
+            .unwrap_or(0.0)
+    }
+}
+
+/// Recent trade prints of one market, oldest first.
+#[derive(Debug, Default)]
+pub struct TradeTape {
+    prints: VecDeque<TradePrint>,
+    /// Trade ids of `prints`.
+    tids: HashSet<u64>,
+}
+
+impl TradeTape {
+    /// Add prints in time order, skipping trade ids already on the tape.
+    /// Prints older than the retention are dropped.
+    pub fn record(&mut self, prints: &[TradePrint]) {
+        for print in prints {
+            if !self.tids.insert(print.tid) {
+                continue;
+            }
+            let at = self.prints.partition_point(|p| p.time_ms <= print.time_ms);
+            self.prints.insert(at, *print);
+        }
+        let Some(latest) = self.prints.back().map(|p| p.time_ms) else {
+            return;
+        };
+        let cutoff = latest.saturating_sub(TRADE_TAPE_RETENTION_MS);
+        while self.prints.len() > MAX_PRINTS
+            || self.prints.front().is_some_and(|p| p.time_ms < cutoff)
+        {
+            if let Some(old) = self.prints.pop_front() {
+                self.tids.remove(&old.tid);
+            }
+        }
+    }
+
+    /// Statistics of the prints within `window_ms` of `now_ms`. None before
+    /// the first print.
+    #[must_use]
+    pub fn stats(&self, window_ms: u64, now_ms: u64) -> Option<TradeStats> {
+        let last = self.prints.back()?;
+        let cutoff = now_ms.saturating_sub(window_ms);
+        let mut stats = TradeStats {
+            count: 0,
+            volume: Size::ZERO,
+            notional: Decimal::ZERO,
+            buy_volume: Size::ZERO,
+            sell_volume: Size::ZERO,
+            vwap: None,
+            last_px: last.price,
+            last_age_ms: now_ms.saturating_sub(last.time_ms),
+        };
+        for print in self.prints.iter().rev().take_while(|p| p.time_ms >= cutoff) {
+            stats.count += 1;
+            stats.volume = stats.volume + print.size;
+            stats.notional += print.price.inner() * print.size.inner();
+            match print.aggressor {
+                OrderSide::Buy => stats.buy_volume = stats.buy_volume + print.size,
+                OrderSide::Sell => stats.sell_volume = stats.sell_volume + print.size,
+            }
+        }
+        if !stats.volume.is_zero() {
+            stats.vwap = Some(Price::new(stats.notional / stats.volume.inner()));
+        }
+        Some(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn print(px: Decimal, sz: Decimal, aggressor: OrderSide, time_ms: u64) -> TradePrint {
+        TradePrint {
+            price: Price::new(px),
+            size: Size::new(sz),
+            aggressor,
+            time_ms,
+            tid: time_ms,
+        }
+    }
+
+    #[test]
+    fn test_trade_tape_stats() {
+        let mut tape = TradeTape::default();
+        assert!(tape.stats(1000, 0).is_none());
+
+        tape.record(&[
+            print(dec!(100), dec!(1), OrderSide::Sell, 1_000),
+            print(dec!(101), dec!(3), OrderSide::Buy, 10_000),
+            print(dec!(102), dec!(1), OrderSide::Sell, 10_500),
+        ]);
+        // A resubscription snapshot repeats known trades
+        tape.record(&[
+            print(dec!(101), dec!(3), OrderSide::Buy, 10_000),
+            print(dec!(102), dec!(1), OrderSide::Sell, 10_500),
+        ]);
+        // A late print is kept in time order
+        tape.record(&[print(dec!(99), dec!(1), OrderSide::Sell, 8_500)]);
+
+        let stats = tape.stats(2_000, 11_000).unwrap();
+        assert_eq!(stats.count, 2);
+        assert_eq!(stats.volume, Size::new(dec!(4)));
+        assert_eq!(stats.notional, dec!(405));
+        assert_eq!(stats.vwap, Some(Price::new(dec!(101.25))));
+        assert!((stats.imbalance() - 0.5).abs() < 1e-9);
+        assert_eq!(stats.last_px, Price::new(dec!(102)));
+        assert_eq!(stats.last_age_ms, 500);
+
+        // Quiet window: no volume, last print still reported
+        let stats = tape.stats(100, 20_000).unwrap();
+        assert_eq!(stats.count, 0);
+        assert!(stats.vwap.is_none());
+        assert_eq!(stats.imbalance(), 0.0);
+        assert_eq!(stats.last_age_ms, 9_500);
+
+        // Retention is measured from the latest print
+        tape.record(&[print(dec!(103), dec!(1), OrderSide::Buy, 301_001)]);
+        assert_eq!(tape.stats(u64::MAX, 301_001).unwrap().count, 4);
+    }
+}
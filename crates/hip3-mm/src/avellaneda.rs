@@ -0,0 +1,305 @@
+//! Avellaneda–Stoikov quote model.
+//!
+//! Alternative to the offset-based [`crate::quote_engine`]: quotes are
+//! centered on a reservation price that moves against inventory, with an
+//! optimal half spread from the risk aversion and order arrival intensity.
+//! Everything is expressed in bps of the oracle price:
+//!
+//! - reservation shift = q × γ × σ² × τ
+//! - half spread = γ × σ² × τ / 2 + ln(1 + γ / k) / γ
+//!
+//! where q is the inventory ratio (-1 to 1), σ the volatility in bps per
+//! √s (from the 1-second wick distribution), τ the horizon in seconds, γ the
+//! risk aversion and k the order intensity decay.
+//!
+//! Levels follow the ladder when one is configured (the half spread is its
+//! L0), else outer levels add `level_spacing_bps` per level. The toxic flow
+//! and time decay boosts scale q, velocity skew applies as in the offset
+//! model, and each side stays at least `fee_buffer_bps` from the oracle.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use hip3_core::Price;
+
+use crate::config::{LadderSideConfig, MakerConfig};
+use crate::quote_engine::{QuoteLevel, QuotePair};
+use crate::volatility::VolatilityStats;
+
+/// Volatility estimate (bps per √s): the P90 1-second wick spans roughly
+/// two standard deviations of the 1-second move.
+fn sigma_bps(config: &MakerConfig, volatility: Option<&VolatilityStats>) -> f64 {
+    match volatility {
+        Some(vol) if vol.is_valid && vol.p90_wick_bps > 0.0 => vol.p90_wick_bps / 2.0,
+        _ => config.as_default_sigma_bps.to_f64().unwrap_or(0.0),
+    }
+}
+
+/// Reservation shift and optimal half spread (bps) for a market.
+///
+/// The shift is positive when long (reservation price below the oracle).
+fn reservation_and_half_spread(
+    inventory_ratio: Decimal,
+    config: &MakerConfig,
+    volatility: Option<&VolatilityStats>,
+    skew_boost: Decimal,
+) -> (Decimal, Decimal) {
+    let gamma = config.as_risk_aversion.to_f64().unwrap_or(0.0);
+    let k = config.as_order_intensity.to_f64().unwrap_or(0.0);
+    let tau = config.as_horizon_secs.to_f64().unwrap_or(0.0);
+    let sigma = sigma_bps(config, volatility);
+    let q = (inventory_ratio.max(dec!(-1)).min(dec!(1)) * skew_boost.max(dec!(1)))
+        .to_f64()
+        .unwrap_or(0.0);
+
+    let variance_term = gamma * sigma * sigma * tau;
+    let intensity_term = if gamma > 0.0 && k > 0.0 {
+        (1.0 + gamma / k).ln() / gamma
+    } else {
+        0.0
+    };
+    let to_dec = |v: f64| {
+        Decimal::from_f64_retain(v)
+            .unwrap_or(Decimal::ZERO)
+            .round_dp(4)
+    };
+    (
+        to_dec(q * variance_term),
+        to_dec(variance_term / 2.0 + intensity_term),
+    )
+}
+
+/// Calculate Avellaneda–Stoikov quotes for a single market.
+///
+/// `spread_multiplier` widens the half spread (adverse selection, latency,
+/// toxic flow), which is floored at `min_offset_bps` and `fee_buffer_bps`.
+/// `velocity_trend` and `skew_boost` are as for
+/// [`crate::quote_engine::compute_quotes`].
+pub fn compute_quotes_as(
+    oracle_price: Price,
+    inventory_ratio: Decimal,
+    config: &MakerConfig,
+    spread_multiplier: Decimal,
+    volatility: Option<&VolatilityStats>,
+    velocity_trend: Decimal,
+    skew_boost: Decimal,
+) -> QuotePair {
+    let oracle = oracle_price.inner();
+    let bps_divisor = dec!(10000);
+    let (shift, half_spread) =
+        reservation_and_half_spread(inventory_ratio, config, volatility, skew_boost);
+    let half_spread = (half_spread * spread_multiplier.max(dec!(1)))
+        .max(config.min_offset_bps)
+        .max(config.fee_buffer_bps);
+    let vel_skew = if config.velocity_skew_enabled {
+        config.velocity_skew_factor * velocity_trend.max(dec!(-1)).min(dec!(1))
+    } else {
+        Decimal::ZERO
+    };
+    let side_floor = config.fee_buffer_bps.max(dec!(1));
+
+    // Half spread and size of a level, from the ladder side if any
+    let level_half = |side: Option<&LadderSideConfig>, level: u32| match side {
+        Some(side) => side.offset_bps(level, half_spread),
+        None => half_spread + config.level_spacing_bps * Decimal::from(level),
+    };
+    let level_size = |side: Option<&LadderSideConfig>, level: u32| {
+        side.map_or(config.size_per_level_usd, |side| {
+            side.size_usd(level, config.size_per_level_usd)
+        })
+    };
+    let num_levels = |side: Option<&LadderSideConfig>| {
+        side.map_or(config.num_levels, LadderSideConfig::num_levels)
+    };
+
+    let bid_side = config.ladder.as_ref().map(|l| &l.bids);
+    let bids = (0..num_levels(bid_side))
+        .map(|level| {
+            let offset_bps =
+                ((level_half(bid_side, level) + shift) * (dec!(1) + vel_skew)).max(side_floor);
+            QuoteLevel {
+                price: Price::new(oracle * (dec!(1) - offset_bps / bps_divisor)),
+                size_usd: level_size(bid_side, level),
+                level,
+            }
+        })
+        .collect();
+    let ask_side = config.ladder.as_ref().map(|l| &l.asks);
+    let asks = (0..num_levels(ask_side))
+        .map(|level| {
+            let offset_bps =
+                ((level_half(ask_side, level) - shift) * (dec!(1) - vel_skew)).max(side_floor);
+            QuoteLevel {
+                price: Price::new(oracle * (dec!(1) + offset_bps / bps_divisor)),
+                size_usd: level_size(ask_side, level),
+                level,
+            }
+        })
+        .collect();
+
+    QuotePair { bids, asks }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LadderConfig;
+
+    fn as_config() -> MakerConfig {
+        MakerConfig {
+            num_levels: 2,
+            min_offset_bps: dec!(5),
+            level_spacing_bps: dec!(10),
+            size_per_level_usd: dec!(50),
+            as_risk_aversion: dec!(0.1),
+            as_order_intensity: dec!(1.5),
+            as_horizon_secs: dec!(60),
+            as_default_sigma_bps: dec!(2),
+            fee_buffer_bps: dec!(2),
+            ..Default::default()
+        }
+    }
+
+    fn quotes(
+        inventory_ratio: Decimal,
+        config: &MakerConfig,
+        spread_multiplier: Decimal,
+        volatility: Option<&VolatilityStats>,
+    ) -> QuotePair {
+        compute_quotes_as(
+            Price::new(dec!(100)),
+            inventory_ratio,
+            config,
+            spread_multiplier,
+            volatility,
+            Decimal::ZERO,
+            dec!(1),
+        )
+    }
+
+    #[test]
+    fn test_reservation_price_and_spread() {
+        let config = as_config();
+
+        // Flat: symmetric around the oracle.
+        // γσ²τ = 0.1 × 4 × 60 = 24 → half spread = 12 + ln(1 + 0.1/1.5)/0.1 ≈ 12.65
+        let flat = quotes(dec!(0), &config, dec!(1), None);
+        let bid_bps = (dec!(100) - flat.bids[0].price.inner()) / dec!(100) * dec!(10000);
+        let ask_bps = (flat.asks[0].price.inner() - dec!(100)) / dec!(100) * dec!(10000);
+        assert_eq!(bid_bps, ask_bps);
+        assert!(bid_bps > dec!(12.6) && bid_bps < dec!(12.7));
+        assert_eq!(flat.bids[1].level, 1);
+        assert_eq!(
+            flat.bids[0].price.inner() - flat.bids[1].price.inner(),
+            dec!(0.1)
+        );
+
+        // Quarter long: reservation 6 bps below the oracle
+        let long = quotes(dec!(0.25), &config, dec!(1), None);
+        assert_eq!(
+            flat.bids[0].price.inner() - long.bids[0].price.inner(),
+            dec!(0.06)
+        );
+        assert_eq!(
+            flat.asks[0].price.inner() - long.asks[0].price.inner(),
+            dec!(0.06)
+        );
+
+        // Wick statistics replace the default volatility
+        let vol = VolatilityStats {
+            p90_wick_bps: 8.0,
+            is_valid: true,
+            ..Default::default()
+        };
+        let volatile = quotes(dec!(0), &config, dec!(1), Some(&vol));
+        assert!(volatile.bids[0].price < flat.bids[0].price);
+
+        // Spread multiplier and min offset floor
+        let wide = quotes(dec!(0), &config, dec!(2), None);
+        assert!(wide.asks[0].price.inner() > dec!(100.25));
+        let floored = MakerConfig {
+            min_offset_bps: dec!(30),
+            ..as_config()
+        };
+        let quotes = quotes(dec!(0), &floored, dec!(1), None);
+        assert_eq!(quotes.asks[0].price.inner(), dec!(100.3));
+    }
+
+    #[test]
+    fn test_fee_floor_boosts_velocity_and_ladder() {
+        let config = as_config();
+        let offsets = |q: &QuotePair| {
+            (
+                (dec!(100) - q.bids[0].price.inner()) * dec!(100),
+                (q.asks[0].price.inner() - dec!(100)) * dec!(100),
+            )
+        };
+
+        // Half long: the ask (12.65 - 12) is floored at the fee buffer
+        let fee_floored = MakerConfig {
+            fee_buffer_bps: dec!(5),
+            ..as_config()
+        };
+        let (_, ask) = offsets(&quotes(dec!(0.5), &fee_floored, dec!(1), None));
+        assert_eq!(ask, dec!(5));
+
+        // A 2× skew boost shifts the reservation price as for twice the inventory
+        let boosted = compute_quotes_as(
+            Price::new(dec!(100)),
+            dec!(0.25),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(2),
+        );
+        assert_eq!(
+            offsets(&boosted),
+            offsets(&quotes(dec!(0.5), &config, dec!(1), None))
+        );
+
+        // Oracle rising: the ask tightens, the bid widens
+        let velocity = MakerConfig {
+            velocity_skew_enabled: true,
+            velocity_skew_factor: dec!(0.5),
+            ..as_config()
+        };
+        let trending = compute_quotes_as(
+            Price::new(dec!(100)),
+            dec!(0),
+            &velocity,
+            dec!(1),
+            None,
+            dec!(1),
+            dec!(1),
+        );
+        let (flat_bid, flat_ask) = offsets(&quotes(dec!(0), &config, dec!(1), None));
+        let (bid, ask) = offsets(&trending);
+        assert!(bid > flat_bid && ask < flat_ask);
+
+        // Ladder: per-side level counts, offsets from the half spread and sizes
+        let laddered = MakerConfig {
+            ladder: Some(LadderConfig {
+                bids: LadderSideConfig {
+                    levels: 3,
+                    ratio: dec!(2),
+                    sizes_usd: vec![dec!(20)],
+                    ..Default::default()
+                },
+                asks: LadderSideConfig {
+                    levels: 1,
+                    ..Default::default()
+                },
+            }),
+            ..as_config()
+        };
+        let q = quotes(dec!(0), &laddered, dec!(1), None);
+        assert_eq!((q.bids.len(), q.asks.len()), (3, 1));
+        assert_eq!(q.bids[2].size_usd, dec!(20));
+        assert_eq!(q.asks[0].size_usd, dec!(50));
+        let l0 = dec!(100) - q.bids[0].price.inner();
+        let l2 = dec!(100) - q.bids[2].price.inner();
+        assert_eq!(l2, l0 * dec!(4));
+    }
+}
@@ -1,8 +1,35 @@
 //! Market making configuration.
 
+use std::collections::HashMap;
+
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::session::MmSessionWindow;
+
+/// Quote pricing model.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteModel {
+    /// Oracle ± offset with inventory skew (`quote_engine`).
+    #[default]
+    Offset,
+    /// Avellaneda–Stoikov reservation price and optimal spread
+    /// (`avellaneda`).
+    AvellanedaStoikov,
+}
+
+impl QuoteModel {
+    /// Label for metrics and stats.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Offset => "offset",
+            Self::AvellanedaStoikov => "avellaneda_stoikov",
+        }
+    }
+}
+
 /// Level distribution strategy.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -25,6 +52,136 @@ pub enum SizeDistribution {
     Convex,
 }
 
+/// Inventory skew curve: how the skew grows with the inventory ratio.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SkewCurve {
+    /// Skew proportional to the inventory ratio.
+    #[default]
+    Linear,
+    /// Skew proportional to ratio × |ratio|: gentle near flat, steep near max.
+    Quadratic,
+    /// Linear interpolation over `inventory_skew_points`.
+    Piecewise,
+}
+
+/// Response to toxic flow (strongly negative recent markouts).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ToxicFadeAction {
+    /// Widen the spread by `toxic_fade_spread_multiplier`.
+    #[default]
+    Widen,
+    /// Cancel the market's quotes and stop quoting it.
+    Pull,
+}
+
+/// Ladder level spacing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LadderSpacing {
+    /// Offset of level i = L0 × ratio^i.
+    #[default]
+    Geometric,
+    /// Explicit offset per level (`offsets_bps`).
+    Custom,
+}
+
+/// One side of a quote ladder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderSideConfig {
+    /// Level spacing.
+    #[serde(default)]
+    pub spacing: LadderSpacing,
+
+    /// Number of levels (geometric spacing).
+    #[serde(default = "default_ladder_levels")]
+    pub levels: u32,
+
+    /// Offset ratio between consecutive levels (geometric spacing).
+    #[serde(default = "default_ladder_ratio")]
+    pub ratio: Decimal,
+
+    /// Offset from oracle per level in bps (custom spacing), floored at L0.
+    #[serde(default)]
+    pub offsets_bps: Vec<Decimal>,
+
+    /// Size per level in USD. The last entry repeats for outer levels;
+    /// empty = `size_per_level_usd`.
+    #[serde(default)]
+    pub sizes_usd: Vec<Decimal>,
+
+    /// Price drift (bps) that requotes a level. The last entry repeats for
+    /// outer levels; empty = `min_requote_change_bps`.
+    #[serde(default)]
+    pub requote_bps: Vec<Decimal>,
+}
+
+impl Default for LadderSideConfig {
+    fn default() -> Self {
+        Self {
+            spacing: LadderSpacing::default(),
+            levels: default_ladder_levels(),
+            ratio: default_ladder_ratio(),
+            offsets_bps: Vec::new(),
+            sizes_usd: Vec::new(),
+            requote_bps: Vec::new(),
+        }
+    }
+}
+
+impl LadderSideConfig {
+    /// Number of levels on this side.
+    #[must_use]
+    pub fn num_levels(&self) -> u32 {
+        match self.spacing {
+            LadderSpacing::Geometric => self.levels,
+            LadderSpacing::Custom => self.offsets_bps.len() as u32,
+        }
+    }
+
+    /// Offset of `level` from oracle in bps, given the L0 offset.
+    #[must_use]
+    pub fn offset_bps(&self, level: u32, l0_bps: Decimal) -> Decimal {
+        match self.spacing {
+            LadderSpacing::Geometric => (0..level).fold(l0_bps, |offset, _| offset * self.ratio),
+            LadderSpacing::Custom => self
+                .offsets_bps
+                .get(level as usize)
+                .copied()
+                .unwrap_or(l0_bps)
+                .max(l0_bps),
+        }
+    }
+
+    /// Size of `level` in USD (`default` if no sizes are set).
+    #[must_use]
+    pub fn size_usd(&self, level: u32, default: Decimal) -> Decimal {
+        Self::per_level(&self.sizes_usd, level).unwrap_or(default)
+    }
+
+    /// Requote threshold of `level` in bps (`default` if none are set).
+    #[must_use]
+    pub fn requote_bps(&self, level: u32, default: Decimal) -> Decimal {
+        Self::per_level(&self.requote_bps, level).unwrap_or(default)
+    }
+
+    fn per_level(values: &[Decimal], level: u32) -> Option<Decimal> {
+        values.get(level as usize).or(values.last()).copied()
+    }
+}
+
+/// Ladder quoting: per-side level offsets, sizes and requote thresholds.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LadderConfig {
+    /// Bid side.
+    #[serde(default)]
+    pub bids: LadderSideConfig,
+    /// Ask side.
+    #[serde(default)]
+    pub asks: LadderSideConfig,
+}
+
 /// Market making configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MakerConfig {
@@ -36,6 +193,13 @@ pub struct MakerConfig {
     #[serde(default = "default_true")]
     pub weekend_only: bool,
 
+    /// Session windows (UTC) per market or asset class. When set, they
+    /// replace `weekend_only` and the Sunday shutdown window: a market is
+    /// quoted only inside one of its windows, and leaving it cancels its
+    /// quotes and flattens its inventory.
+    #[serde(default)]
+    pub sessions: Vec<MmSessionWindow>,
+
     /// Number of quote levels per side.
     #[serde(default = "default_num_levels")]
     pub num_levels: u32,
@@ -72,6 +236,11 @@ pub struct MakerConfig {
     #[serde(default = "default_true")]
     pub use_alo: bool,
 
+    /// Amend resting quotes in place (batchModify) instead of cancel-replace.
+    /// Falls back to cancel-replace when an amendment is rejected.
+    #[serde(default)]
+    pub amend_quotes: bool,
+
     /// Markets to make (by market name, e.g. "GOLD").
     /// Empty = no markets.
     #[serde(default)]
@@ -82,6 +251,31 @@ pub struct MakerConfig {
     #[serde(default = "default_min_requote_change_bps")]
     pub min_requote_change_bps: Decimal,
 
+    /// Quote center: 0 = oracle, 1 = mark, in between = blend
+    /// (oracle + weight × (mark − oracle)).
+    #[serde(default)]
+    pub quote_center_mark_weight: Decimal,
+
+    /// Pull a market's quotes while |mark − oracle| exceeds this (bps of
+    /// oracle). 0 = off.
+    #[serde(default)]
+    pub max_mark_oracle_divergence_bps: Decimal,
+
+    /// Quote hysteresis: a resting quote is only moved once its desired
+    /// price is at least this far (bps) from its resting price. 0 = off.
+    #[serde(default)]
+    pub requote_hysteresis_bps: Decimal,
+
+    /// Quote hysteresis in tick sizes; with both thresholds set, reaching
+    /// either one moves the quote. 0 = off.
+    #[serde(default)]
+    pub requote_hysteresis_ticks: u32,
+
+    /// Minimum time a quote level rests before it is requoted again (ms).
+    /// 0 = off.
+    #[serde(default)]
+    pub min_level_requote_interval_ms: u64,
+
     /// Emergency flatten slippage in basis points.
     #[serde(default = "default_flatten_slippage_bps")]
     pub flatten_slippage_bps: u64,
@@ -97,6 +291,29 @@ pub struct MakerConfig {
     #[serde(default = "default_inventory_emergency_ratio")]
     pub inventory_emergency_ratio: Decimal,
 
+    // --- Inventory time decay ---
+    /// Inventory unchanged for longer than this (s) starts leaning the
+    /// inventory skew harder, to work it back to zero. 0 = off.
+    #[serde(default)]
+    pub inventory_decay_after_secs: u64,
+
+    /// Time (s) over which the decay ramps up to its full skew multiplier.
+    #[serde(default = "default_inventory_decay_ramp_secs")]
+    pub inventory_decay_ramp_secs: u64,
+
+    /// Inventory skew multiplier at full decay.
+    #[serde(default = "default_inventory_decay_max_skew_multiplier")]
+    pub inventory_decay_max_skew_multiplier: Decimal,
+
+    /// Inventory unchanged for longer than this (s) is hedged with a
+    /// reduce-only IOC, retried every period while it sits. 0 = off.
+    #[serde(default)]
+    pub inventory_decay_hedge_after_secs: u64,
+
+    /// Fraction of the inventory hedged at a time.
+    #[serde(default = "default_inventory_decay_hedge_fraction")]
+    pub inventory_decay_hedge_fraction: Decimal,
+
     // --- P2-2: Stale quote detection ---
     /// Timeout (ms) for cancel acknowledgement. If a cancel is not acked
     /// within this period, all MM quoting is halted.
@@ -109,6 +326,17 @@ pub struct MakerConfig {
     #[serde(default = "default_stale_cancel_max_age_ms")]
     pub stale_cancel_max_age_ms: u64,
 
+    /// Hard cap on resting MM orders per market: tracked quotes plus cancels
+    /// not acked yet. New quotes over the cap are dropped, outer levels
+    /// first. 0 = off.
+    #[serde(default)]
+    pub max_resting_orders_per_market: usize,
+
+    /// Interval (s) of the reconciliation of tracked quotes against the
+    /// exchange's open orders. 0 = off.
+    #[serde(default)]
+    pub reconcile_interval_secs: u64,
+
     // --- P2-3: Adverse selection protection ---
     /// Number of consecutive same-side fills that triggers spread widening.
     #[serde(default = "default_adverse_consecutive_fills")]
@@ -141,6 +369,17 @@ pub struct MakerConfig {
     #[serde(default = "default_fee_buffer_bps")]
     pub fee_buffer_bps: Decimal,
 
+    /// Ceiling on the dynamic L0 (bps). The floors (`min_offset_bps`,
+    /// `fee_buffer_bps`) still win. 0 = no ceiling.
+    #[serde(default)]
+    pub dynamic_offset_max_bps: Decimal,
+
+    /// Half-life (seconds) of the per-market EMA applied to the optimal
+    /// wick before it sets L0, so one choppy minute doesn't jump the
+    /// spread. 0 = no smoothing.
+    #[serde(default)]
+    pub dynamic_offset_half_life_secs: u64,
+
     /// Percentile stats cache TTL in milliseconds.
     #[serde(default = "default_wick_cache_ttl_ms")]
     pub wick_cache_ttl_ms: u64,
@@ -210,6 +449,109 @@ pub struct MakerConfig {
     /// Number of recent oracle updates to track for velocity calculation.
     #[serde(default = "default_velocity_window")]
     pub velocity_window: usize,
+
+    // --- Inventory skew curve + markout skew ---
+    /// Shape of the inventory skew. Whatever the curve, beyond
+    /// `inventory_warn_ratio` the side that adds exposure is not quoted
+    /// (one-sided zone).
+    #[serde(default)]
+    pub inventory_skew_curve: SkewCurve,
+
+    /// Piecewise curve breakpoints: (|inventory ratio|, skew fraction),
+    /// ascending, starting from (0, 0). Flat after the last point.
+    /// E.g. [[0.3, 0.1], [0.6, 0.5], [0.8, 1.0]].
+    #[serde(default)]
+    pub inventory_skew_points: Vec<(Decimal, Decimal)>,
+
+    /// Extra inventory skew while recent fill markouts are negative (toxic
+    /// flow): skew × (1 + factor). 0 = disabled.
+    #[serde(default)]
+    pub markout_skew_factor: Decimal,
+
+    /// Half-life (s) of the recent markout mean behind the markout skew and
+    /// toxic fading: without new fills it fades toward zero, then expires.
+    #[serde(default = "default_markout_half_life_secs")]
+    pub markout_half_life_secs: u64,
+
+    /// Horizons after each MM fill at which markout analytics measure the
+    /// mid and oracle move (ms). The shortest one drives quoting (markout
+    /// skew, toxic fading, fill stats). Empty = analytics disabled.
+    #[serde(default = "default_markout_horizons_ms")]
+    pub markout_horizons_ms: Vec<u64>,
+
+    // --- Quote-level fill probability ---
+    /// Width (bps) of the offset buckets in which quote fill probability
+    /// and markout (at the shortest markout horizon) are tracked. 0 = disabled.
+    #[serde(default)]
+    pub fill_stats_bucket_bps: Decimal,
+
+    /// Minimum quotes placed in a bucket before it is ranked for offsets.
+    #[serde(default = "default_fill_stats_min_quotes")]
+    pub fill_stats_min_quotes: u64,
+
+    /// Quote at the most profitable offsets from the fill statistics once
+    /// every level has one, instead of the configured level layout (offset
+    /// model without a ladder only).
+    #[serde(default)]
+    pub fill_stats_offsets: bool,
+
+    // --- Toxic flow fading ---
+    /// Fade a market when its mean recent markout (see
+    /// `markout_half_life_secs`) drops to -threshold bps or below. 0 = disabled.
+    #[serde(default)]
+    pub toxic_fade_threshold_bps: Decimal,
+
+    /// Minimum measured fills before a market can be faded.
+    #[serde(default = "default_toxic_fade_min_fills")]
+    pub toxic_fade_min_fills: usize,
+
+    /// Widen or pull the faded market's quotes.
+    #[serde(default)]
+    pub toxic_fade_action: ToxicFadeAction,
+
+    /// Spread multiplier while faded (`toxic_fade_action` = widen).
+    #[serde(default = "default_toxic_fade_spread_multiplier")]
+    pub toxic_fade_spread_multiplier: Decimal,
+
+    /// How long a market stays faded (seconds).
+    #[serde(default = "default_toxic_fade_cooldown_secs")]
+    pub toxic_fade_cooldown_secs: u64,
+
+    // --- Quote model ---
+    /// Quote pricing model of all markets.
+    #[serde(default)]
+    pub quote_model: QuoteModel,
+
+    /// Per-market model overrides, by market name (e.g. GOLD =
+    /// "avellaneda_stoikov"), for A/B comparison.
+    #[serde(default)]
+    pub quote_model_markets: HashMap<String, QuoteModel>,
+
+    /// Avellaneda–Stoikov risk aversion γ (per bps). Higher = stronger
+    /// inventory shift and wider spread.
+    #[serde(default = "default_as_risk_aversion")]
+    pub as_risk_aversion: Decimal,
+
+    /// Avellaneda–Stoikov order arrival intensity decay k (per bps).
+    /// Higher = fills fall off faster with distance, tighter spread.
+    #[serde(default = "default_as_order_intensity")]
+    pub as_order_intensity: Decimal,
+
+    /// Avellaneda–Stoikov horizon T - t (seconds), held constant.
+    #[serde(default = "default_as_horizon_secs")]
+    pub as_horizon_secs: Decimal,
+
+    /// Volatility (bps per √s) used until the wick tracker has enough
+    /// samples.
+    #[serde(default = "default_as_default_sigma_bps")]
+    pub as_default_sigma_bps: Decimal,
+
+    // --- Ladder quoting ---
+    /// Per-side quote ladder. When set, replaces `num_levels`, the level and
+    /// size distributions, and requotes only the levels that drifted past
+    /// their threshold instead of the whole quote set.
+    #[serde(default)]
+    pub ladder: Option<LadderConfig>,
 }
 
 impl Default for MakerConfig {
@@ -217,6 +559,7 @@ impl Default for MakerConfig {
         Self {
             enabled: false,
             weekend_only: true,
+            sessions: Vec::new(),
             num_levels: default_num_levels(),
             min_offset_bps: default_min_offset_bps(),
             level_spacing_bps: default_level_spacing_bps(),
@@ -225,13 +568,26 @@ impl Default for MakerConfig {
             inventory_skew_factor: default_inventory_skew_factor(),
             requote_interval_ms: default_requote_interval_ms(),
             use_alo: true,
+            amend_quotes: false,
             markets: Vec::new(),
             min_requote_change_bps: default_min_requote_change_bps(),
+            quote_center_mark_weight: Decimal::ZERO,
+            max_mark_oracle_divergence_bps: Decimal::ZERO,
+            requote_hysteresis_bps: Decimal::ZERO,
+            requote_hysteresis_ticks: 0,
+            min_level_requote_interval_ms: 0,
             flatten_slippage_bps: default_flatten_slippage_bps(),
             inventory_warn_ratio: default_inventory_warn_ratio(),
             inventory_emergency_ratio: default_inventory_emergency_ratio(),
+            inventory_decay_after_secs: 0,
+            inventory_decay_ramp_secs: default_inventory_decay_ramp_secs(),
+            inventory_decay_max_skew_multiplier: default_inventory_decay_max_skew_multiplier(),
+            inventory_decay_hedge_after_secs: 0,
+            inventory_decay_hedge_fraction: default_inventory_decay_hedge_fraction(),
             stale_cancel_timeout_ms: default_stale_cancel_timeout_ms(),
             stale_cancel_max_age_ms: default_stale_cancel_max_age_ms(),
+            max_resting_orders_per_market: 0,
+            reconcile_interval_secs: 0,
             adverse_consecutive_fills: default_adverse_consecutive_fills(),
             adverse_spread_multiplier: default_adverse_spread_multiplier(),
             dynamic_offset_enabled: false,
@@ -239,6 +595,8 @@ impl Default for MakerConfig {
             wick_min_samples: default_wick_min_samples(),
             l0_wick_multiplier: default_l0_wick_multiplier(),
             fee_buffer_bps: default_fee_buffer_bps(),
+            dynamic_offset_max_bps: Decimal::ZERO,
+            dynamic_offset_half_life_secs: 0,
             wick_cache_ttl_ms: default_wick_cache_ttl_ms(),
             breakpoint_min_jump_ratio: default_breakpoint_min_jump_ratio(),
             level_distribution: LevelDistribution::default(),
@@ -254,10 +612,97 @@ impl Default for MakerConfig {
             velocity_skew_enabled: false,
             velocity_skew_factor: default_velocity_skew_factor(),
             velocity_window: default_velocity_window(),
+            inventory_skew_curve: SkewCurve::default(),
+            inventory_skew_points: Vec::new(),
+            markout_skew_factor: Decimal::ZERO,
+            markout_half_life_secs: default_markout_half_life_secs(),
+            markout_horizons_ms: default_markout_horizons_ms(),
+            fill_stats_bucket_bps: Decimal::ZERO,
+            fill_stats_min_quotes: default_fill_stats_min_quotes(),
+            fill_stats_offsets: false,
+            toxic_fade_threshold_bps: Decimal::ZERO,
+            toxic_fade_min_fills: default_toxic_fade_min_fills(),
+            toxic_fade_action: ToxicFadeAction::default(),
+            toxic_fade_spread_multiplier: default_toxic_fade_spread_multiplier(),
+            toxic_fade_cooldown_secs: default_toxic_fade_cooldown_secs(),
+            quote_model: QuoteModel::default(),
+            quote_model_markets: HashMap::new(),
+            as_risk_aversion: default_as_risk_aversion(),
+            as_order_intensity: default_as_order_intensity(),
+            as_horizon_secs: default_as_horizon_secs(),
+            as_default_sigma_bps: default_as_default_sigma_bps(),
+            ladder: None,
         }
     }
 }
 
+impl MakerConfig {
+    /// Quote model of a market by name ("GOLD" or "xyz:GOLD").
+    #[must_use]
+    pub fn quote_model_for(&self, market_name: &str) -> QuoteModel {
+        let name = market_name
+            .rsplit_once(':')
+            .map_or(market_name, |(_, name)| name);
+        self.quote_model_markets
+            .get(name)
+            .copied()
+            .unwrap_or(self.quote_model)
+    }
+
+    /// Markout horizon (ms) that drives quoting: the shortest configured.
+    #[must_use]
+    pub fn quote_markout_horizon_ms(&self) -> Option<u64> {
+        self.markout_horizons_ms.iter().min().copied()
+    }
+
+    /// Validate configuration values.
+    ///
+    /// Returns Err if:
+    /// - `inventory_skew_points` are not strictly ascending in inventory
+    ///   ratio within (0, 1], or their skew fractions decrease or leave [0, 1]
+    /// - the piecewise curve has no points
+    /// - markout skew, toxic fading or fill stats are on without a markout
+    ///   horizon
+    pub fn validate(&self) -> Result<(), String> {
+        let mut prev = (Decimal::ZERO, Decimal::ZERO);
+        for &(ratio, skew) in &self.inventory_skew_points {
+            if ratio <= prev.0 || ratio > Decimal::ONE {
+                return Err(format!(
+                    "inventory_skew_points ratios must be strictly ascending within (0, 1]: \
+                     {ratio} after {}",
+                    prev.0
+                ));
+            }
+            if skew < prev.1 || skew > Decimal::ONE {
+                return Err(format!(
+                    "inventory_skew_points skews must be non-decreasing within [0, 1]: \
+                     {skew} after {}",
+                    prev.1
+                ));
+            }
+            prev = (ratio, skew);
+        }
+        if self.inventory_skew_curve == SkewCurve::Piecewise
+            && self.inventory_skew_points.is_empty()
+        {
+            return Err("inventory_skew_curve = piecewise needs inventory_skew_points".into());
+        }
+
+        let reads_markouts = !self.markout_skew_factor.is_zero()
+            || !self.toxic_fade_threshold_bps.is_zero()
+            || !self.fill_stats_bucket_bps.is_zero();
+        if reads_markouts && self.markout_horizons_ms.is_empty() {
+            return Err(
+                "markout_skew_factor, toxic_fade_threshold_bps and fill_stats_bucket_bps \
+                 need markout_horizons_ms"
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
 fn default_true() -> bool {
     true
 }
@@ -294,6 +739,15 @@ fn default_inventory_warn_ratio() -> Decimal {
 fn default_inventory_emergency_ratio() -> Decimal {
     Decimal::new(95, 2) // 0.95 = 95%
 }
+fn default_inventory_decay_ramp_secs() -> u64 {
+    1800 // 30 minutes
+}
+fn default_inventory_decay_max_skew_multiplier() -> Decimal {
+    Decimal::from(3)
+}
+fn default_inventory_decay_hedge_fraction() -> Decimal {
+    Decimal::new(5, 1) // 0.5 = half the inventory per hedge
+}
 fn default_stale_cancel_timeout_ms() -> u64 {
     10_000 // 10 seconds
 }
@@ -351,6 +805,42 @@ fn default_velocity_skew_factor() -> Decimal {
 fn default_velocity_window() -> usize {
     5 // last 5 oracle updates
 }
+fn default_markout_half_life_secs() -> u64 {
+    300 // 5 minutes
+}
+fn default_markout_horizons_ms() -> Vec<u64> {
+    vec![1_000, 5_000, 30_000] // T+1s, T+5s, T+30s
+}
+fn default_fill_stats_min_quotes() -> u64 {
+    50
+}
+fn default_toxic_fade_min_fills() -> usize {
+    5
+}
+fn default_toxic_fade_spread_multiplier() -> Decimal {
+    Decimal::from(2)
+}
+fn default_toxic_fade_cooldown_secs() -> u64 {
+    300 // 5 minutes
+}
+fn default_as_risk_aversion() -> Decimal {
+    Decimal::new(1, 1) // 0.1
+}
+fn default_as_order_intensity() -> Decimal {
+    Decimal::new(15, 1) // 1.5
+}
+fn default_as_horizon_secs() -> Decimal {
+    Decimal::from(60)
+}
+fn default_as_default_sigma_bps() -> Decimal {
+    Decimal::from(2)
+}
+fn default_ladder_levels() -> u32 {
+    3
+}
+fn default_ladder_ratio() -> Decimal {
+    Decimal::new(15, 1) // each level 1.5x further out
+}
 
 #[cfg(test)]
 mod tests {
@@ -368,6 +858,7 @@ mod tests {
         assert_eq!(config.max_position_usd, dec!(25));
         assert_eq!(config.inventory_skew_factor, dec!(0.3));
         assert!(config.use_alo);
+        assert!(!config.amend_quotes);
         assert!(config.markets.is_empty());
         // P3-1: Dynamic offset defaults
         assert!(!config.dynamic_offset_enabled);
@@ -392,6 +883,50 @@ mod tests {
         assert!(!config.velocity_skew_enabled);
         assert_eq!(config.velocity_skew_factor, dec!(0.3));
         assert_eq!(config.velocity_window, 5);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_skew_points_and_markouts() {
+        let piecewise = |points: Vec<(Decimal, Decimal)>| MakerConfig {
+            inventory_skew_curve: SkewCurve::Piecewise,
+            inventory_skew_points: points,
+            ..MakerConfig::default()
+        };
+        assert!(
+            piecewise(vec![(dec!(0.3), dec!(0.1)), (dec!(0.8), dec!(1))])
+                .validate()
+                .is_ok()
+        );
+        assert!(piecewise(vec![]).validate().is_err());
+        // Out of order, repeated, beyond 1, decreasing skew
+        assert!(
+            piecewise(vec![(dec!(0.8), dec!(1)), (dec!(0.3), dec!(0.1))])
+                .validate()
+                .is_err()
+        );
+        assert!(
+            piecewise(vec![(dec!(0.3), dec!(0.1)), (dec!(0.3), dec!(0.5))])
+                .validate()
+                .is_err()
+        );
+        assert!(piecewise(vec![(dec!(1.5), dec!(1))]).validate().is_err());
+        assert!(
+            piecewise(vec![(dec!(0.3), dec!(0.5)), (dec!(0.8), dec!(0.2))])
+                .validate()
+                .is_err()
+        );
+
+        let config = MakerConfig {
+            toxic_fade_threshold_bps: dec!(5),
+            markout_horizons_ms: Vec::new(),
+            ..MakerConfig::default()
+        };
+        assert!(config.validate().is_err());
+        assert_eq!(
+            MakerConfig::default().quote_markout_horizon_ms(),
+            Some(1_000)
+        );
     }
 
     #[test]
@@ -0,0 +1,343 @@
+//! Quote-level fill probability by offset bucket.
+//!
+//! Extends the P3-1 wick analysis into placement: for every quote placed,
+//! records its distance from the quote center in `bucket_bps` buckets, then
+//! counts how many of them get filled and what the fill's markout is once
+//! `horizon_ms` has passed. The markout is measured from the fill price, so
+//! it includes the captured edge: positive = profitable fill.
+//!
+//! A bucket's expected value per quote is its fill probability times its
+//! mean markout; [`FillProbabilityTracker::best_offsets`] ranks the buckets
+//! by it to pick the most profitable ladder offsets.
+
+use std::collections::{HashMap, VecDeque};
+
+use hip3_core::{ClientOrderId, MarketKey, OrderSide, Price};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// Quotes unfilled for this long (ms) are forgotten (cancelled or replaced).
+const OPEN_QUOTE_TTL_MS: u64 = 3_600_000;
+
+/// Fill statistics of one offset bucket of a market.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OffsetBucketStats {
+    /// Lower edge of the bucket (bps from the quote center).
+    pub offset_bps: Decimal,
+    /// Quotes placed in the bucket.
+    pub quotes: u64,
+    /// Quotes filled.
+    pub fills: u64,
+    /// Fills with a measured markout.
+    pub markouts: u64,
+    /// Mean markout of the fills vs their fill price (bps, positive = profit).
+    pub mean_markout_bps: f64,
+}
+
+impl OffsetBucketStats {
+    /// Share of the bucket's quotes that got filled.
+    #[must_use]
+    pub fn fill_probability(&self) -> f64 {
+        if self.quotes == 0 {
+            return 0.0;
+        }
+        self.fills as f64 / self.quotes as f64
+    }
+
+    /// Expected markout per quote placed (bps). None before any markout.
+    #[must_use]
+    pub fn expected_bps(&self) -> Option<f64> {
+        (self.markouts > 0).then(|| self.fill_probability() * self.mean_markout_bps)
+    }
+}
+
+#[derive(Debug, Default)]
+struct BucketSums {
+    quotes: u64,
+    fills: u64,
+    markouts: u64,
+    sum_markout_bps: f64,
+}
+
+#[derive(Debug)]
+struct OpenQuote {
+    market: MarketKey,
+    bucket: u32,
+    side: OrderSide,
+    placed_at_ms: u64,
+}
+
+#[derive(Debug)]
+struct PendingFill {
+    bucket: u32,
+    side: OrderSide,
+    fill_px: Decimal,
+    at_ms: u64,
+}
+
+/// Tracks fill probability and markout per market and offset bucket.
+#[derive(Debug)]
+pub struct FillProbabilityTracker {
+    bucket_bps: Decimal,
+    horizon_ms: u64,
+    open: HashMap<ClientOrderId, OpenQuote>,
+    pending: HashMap<MarketKey, VecDeque<PendingFill>>,
+    buckets: HashMap<(MarketKey, u32), BucketSums>,
+}
+
+impl FillProbabilityTracker {
+    /// Create a tracker with `bucket_bps` wide offset buckets, measuring
+    /// markouts `horizon_ms` after each fill.
+    #[must_use]
+    pub fn new(bucket_bps: Decimal, horizon_ms: u64) -> Self {
+        Self {
+            bucket_bps,
+            horizon_ms,
+            open: HashMap::new(),
+            pending: HashMap::new(),
+            buckets: HashMap::new(),
+        }
+    }
+
+    fn bucket_of(&self, offset_bps: Decimal) -> u32 {
+        if self.bucket_bps <= Decimal::ZERO {
+            return 0;
+        }
+        (offset_bps.max(Decimal::ZERO) / self.bucket_bps)
+            .floor()
+            .to_u32()
+            .unwrap_or(u32::MAX)
+    }
+
+    /// Record a quote placed `offset_bps` away from the quote center.
+    pub fn record_quote(
+        &mut self,
+        market: MarketKey,
+        cloid: ClientOrderId,
+        side: OrderSide,
+        offset_bps: Decimal,
+        now_ms: u64,
+    ) {
+        let bucket = self.bucket_of(offset_bps);
+        self.buckets.entry((market, bucket)).or_default().quotes += 1;
+        self.open.insert(
+            cloid,
+            OpenQuote {
+                market,
+                bucket,
+                side,
+                placed_at_ms: now_ms,
+            },
+        );
+    }
+
+    /// Record the amendment of the quote `replaces` into `cloid`, now
+    /// `offset_bps` from the quote center. The amended quote is the same
+    /// quote: it is counted again only if it moves to another bucket.
+    pub fn record_amend(
+        &mut self,
+        replaces: &ClientOrderId,
+        cloid: ClientOrderId,
+        side: OrderSide,
+        offset_bps: Decimal,
+        now_ms: u64,
+    ) {
+        let Some(mut quote) = self.open.remove(replaces) else {
+            return;
+        };
+        let bucket = self.bucket_of(offset_bps);
+        if bucket != quote.bucket {
+            if let Some(sums) = self.buckets.get_mut(&(quote.market, quote.bucket)) {
+                sums.quotes = sums.quotes.saturating_sub(1);
+            }
+            self.buckets
+                .entry((quote.market, bucket))
+                .or_default()
+                .quotes += 1;
+            quote.bucket = bucket;
+        }
+        quote.side = side;
+        quote.placed_at_ms = now_ms;
+        self.open.insert(cloid, quote);
+    }
+
+    /// Record the fill of a tracked quote. Later partial fills of the same
+    /// quote are not counted again.
+    pub fn record_fill(&mut self, cloid: &ClientOrderId, fill_px: Price, now_ms: u64) {
+        let Some(quote) = self.open.remove(cloid) else {
+            return;
+        };
+        self.buckets
+            .entry((quote.market, quote.bucket))
+            .or_default()
+            .fills += 1;
+        if !fill_px.is_zero() {
+            self.pending
+                .entry(quote.market)
+                .or_default()
+                .push_back(PendingFill {
+                    bucket: quote.bucket,
+                    side: quote.side,
+                    fill_px: fill_px.inner(),
+                    at_ms: now_ms,
+                });
+        }
+    }
+
+    /// Measure the markouts of `market`'s fills past the horizon against the
+    /// current oracle, and forget quotes that stayed unfilled too long.
+    pub fn on_oracle(&mut self, market: MarketKey, oracle_px: Price, now_ms: u64) {
+        self.open
+            .retain(|_, q| now_ms.saturating_sub(q.placed_at_ms) < OPEN_QUOTE_TTL_MS);
+
+        let Some(fills) = self.pending.get_mut(&market) else {
+            return;
+        };
+        while let Some(fill) = fills.front() {
+            if now_ms.saturating_sub(fill.at_ms) < self.horizon_ms {
+                break;
+            }
+            let move_bps = (oracle_px.inner() - fill.fill_px) / fill.fill_px * Decimal::from(10000);
+            let markout_bps = match fill.side {
+                OrderSide::Buy => move_bps,
+                OrderSide::Sell => -move_bps,
+            };
+            let sums = self.buckets.entry((market, fill.bucket)).or_default();
+            sums.markouts += 1;
+            sums.sum_markout_bps += markout_bps.to_f64().unwrap_or(0.0);
+            fills.pop_front();
+        }
+    }
+
+    /// Statistics of `market`'s offset buckets, tightest first.
+    #[must_use]
+    pub fn bucket_stats(&self, market: &MarketKey) -> Vec<OffsetBucketStats> {
+        let mut stats: Vec<OffsetBucketStats> = self
+            .buckets
+            .iter()
+            .filter(|((m, _), _)| m == market)
+            .map(|(&(_, bucket), sums)| OffsetBucketStats {
+                offset_bps: self.bucket_bps * Decimal::from(bucket),
+                quotes: sums.quotes,
+                fills: sums.fills,
+                markouts: sums.markouts,
+                mean_markout_bps: if sums.markouts == 0 {
+                    0.0
+                } else {
+                    sums.sum_markout_bps / sums.markouts as f64
+                },
+            })
+            .collect();
+        stats.sort_by_key(|s| s.offset_bps);
+        stats
+    }
+
+    /// The `n` most profitable offsets of `market` (bucket centers in bps,
+    /// ascending), among buckets with at least `min_quotes` quotes and a
+    /// positive expected value. Empty until enough data.
+    #[must_use]
+    pub fn best_offsets(&self, market: &MarketKey, n: usize, min_quotes: u64) -> Vec<Decimal> {
+        let mut ranked: Vec<(f64, Decimal)> = self
+            .bucket_stats(market)
+            .into_iter()
+            .filter(|s| s.quotes >= min_quotes)
+            .filter_map(|s| {
+                let expected = s.expected_bps().filter(|&e| e > 0.0)?;
+                Some((expected, s.offset_bps + self.bucket_bps / Decimal::from(2)))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+        let mut offsets: Vec<Decimal> = ranked.into_iter().take(n).map(|(_, o)| o).collect();
+        offsets.sort();
+        offsets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+
+    fn market() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    #[test]
+    fn test_fill_probability_by_offset_bucket() {
+        let mut tracker = FillProbabilityTracker::new(dec!(5), 1000);
+
+        // 4 bids at 7 bps (bucket 5-10): 2 filled at 99.93, oracle then 100
+        // → +7 bps each
+        let tight: Vec<ClientOrderId> = (0..4).map(|_| ClientOrderId::new()).collect();
+        for cloid in &tight {
+            tracker.record_quote(market(), cloid.clone(), OrderSide::Buy, dec!(7), 0);
+        }
+        tracker.record_fill(&tight[0], Price::new(dec!(99.93)), 100);
+        tracker.record_fill(&tight[1], Price::new(dec!(99.93)), 100);
+        tracker.record_fill(&tight[1], Price::new(dec!(99.93)), 150);
+
+        // 2 asks at 22 bps (bucket 20-25): 1 filled at 100.22, oracle then
+        // 100.5 → picked off
+        let wide: Vec<ClientOrderId> = (0..2).map(|_| ClientOrderId::new()).collect();
+        for cloid in &wide {
+            tracker.record_quote(market(), cloid.clone(), OrderSide::Sell, dec!(22), 0);
+        }
+        tracker.record_fill(&wide[0], Price::new(dec!(100.22)), 100);
+
+        // Before the horizon: fills counted, no markout yet
+        tracker.on_oracle(market(), Price::new(dec!(100)), 500);
+        assert!(tracker.best_offsets(&market(), 2, 1).is_empty());
+
+        tracker.on_oracle(market(), Price::new(dec!(100)), 1100);
+        let stats = tracker.bucket_stats(&market());
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].offset_bps, dec!(5));
+        assert_eq!(
+            (stats[0].quotes, stats[0].fills, stats[0].markouts),
+            (4, 2, 2)
+        );
+        assert!((stats[0].fill_probability() - 0.5).abs() < 1e-9);
+        assert!((stats[0].expected_bps().unwrap() - 3.5).abs() < 0.01);
+        assert!(stats[1].expected_bps().unwrap() > 0.0);
+
+        tracker.record_quote(
+            market(),
+            ClientOrderId::new(),
+            OrderSide::Sell,
+            dec!(22),
+            2000,
+        );
+        tracker.record_fill(&wide[1], Price::new(dec!(100.22)), 2000);
+        tracker.on_oracle(market(), Price::new(dec!(100.5)), 3000);
+        let stats = tracker.bucket_stats(&market());
+        assert!(stats[1].expected_bps().unwrap() < 0.0);
+
+        // Only the profitable bucket is picked, and only with enough quotes
+        assert_eq!(tracker.best_offsets(&market(), 2, 1), vec![dec!(7.5)]);
+        assert!(tracker.best_offsets(&market(), 2, 5).is_empty());
+    }
+
+    #[test]
+    fn test_amend_is_not_a_new_quote() {
+        let mut tracker = FillProbabilityTracker::new(dec!(5), 1000);
+        let placed = ClientOrderId::new();
+        tracker.record_quote(market(), placed.clone(), OrderSide::Buy, dec!(7), 0);
+
+        // Amended within the bucket: still one quote, fills via the new cloid
+        let amended = ClientOrderId::new();
+        tracker.record_amend(&placed, amended.clone(), OrderSide::Buy, dec!(8), 100);
+        assert_eq!(tracker.bucket_stats(&market())[0].quotes, 1);
+
+        // Amended into the next bucket: the quote moves there
+        let moved = ClientOrderId::new();
+        tracker.record_amend(&amended, moved.clone(), OrderSide::Buy, dec!(12), 200);
+        let stats = tracker.bucket_stats(&market());
+        assert_eq!((stats[0].quotes, stats[1].quotes), (0, 1));
+
+        tracker.record_fill(&amended, Price::new(dec!(99.88)), 300);
+        tracker.record_fill(&moved, Price::new(dec!(99.88)), 300);
+        let stats = tracker.bucket_stats(&market());
+        assert_eq!((stats[0].fills, stats[1].fills), (0, 1));
+    }
+}
@@ -2,6 +2,11 @@
 //!
 //! Tracks net position per market and computes inventory ratio
 //! for skew calculations.
+//!
+//! PnL is also split into spread capture (edge of each fill vs the reference
+//! price at fill time) and inventory PnL (drift of the reference price while
+//! holding inventory): inventory PnL is the PnL of the same fills done at
+//! the reference price, so the two always add up to realized + unrealized.
 
 use std::collections::HashMap;
 
@@ -21,6 +26,20 @@ pub struct MarketInventory {
     pub fill_count: u64,
     /// Realized PnL in USD.
     pub realized_pnl: Decimal,
+    /// Edge earned at fill vs the reference price, in USD.
+    pub spread_capture: Decimal,
+    /// Cash flow of the fills valued at their reference prices (USD).
+    reference_cash: Decimal,
+    /// Time of the last fill (ms), for inventory time decay. None for fills
+    /// recorded without a time.
+    changed_at_ms: Option<u64>,
+}
+
+impl MarketInventory {
+    /// Inventory PnL in USD with the inventory marked at `mark_px`.
+    pub fn inventory_pnl(&self, mark_px: Price) -> Decimal {
+        self.reference_cash + self.net_size * mark_px.inner()
+    }
 }
 
 impl Default for MarketInventory {
@@ -30,6 +49,9 @@ impl Default for MarketInventory {
             avg_entry: Decimal::ZERO,
             fill_count: 0,
             realized_pnl: Decimal::ZERO,
+            spread_capture: Decimal::ZERO,
+            reference_cash: Decimal::ZERO,
+            changed_at_ms: None,
         }
     }
 }
@@ -53,7 +75,37 @@ impl InventoryManager {
     }
 
     /// Record a fill and update inventory.
+    ///
+    /// The fill price is its own reference: no spread capture is attributed.
+    /// Untimed: the inventory doesn't age (see [`Self::held_for_ms`]).
     pub fn record_fill(&mut self, market: MarketKey, side: OrderSide, price: Price, size: Size) {
+        self.apply_fill(market, side, price, size, price)
+            .changed_at_ms = None;
+    }
+
+    /// Record a fill at `now_ms` and update inventory, attributing its edge
+    /// vs `reference` (e.g. the oracle at fill time) to spread capture.
+    pub fn record_fill_at_reference(
+        &mut self,
+        market: MarketKey,
+        side: OrderSide,
+        price: Price,
+        size: Size,
+        reference: Price,
+        now_ms: u64,
+    ) {
+        self.apply_fill(market, side, price, size, reference)
+            .changed_at_ms = Some(now_ms);
+    }
+
+    fn apply_fill(
+        &mut self,
+        market: MarketKey,
+        side: OrderSide,
+        price: Price,
+        size: Size,
+        reference: Price,
+    ) -> &mut MarketInventory {
         let inv = self.inventories.entry(market).or_default();
         let fill_size = size.inner();
         let fill_price = price.inner();
@@ -63,6 +115,10 @@ impl InventoryManager {
             OrderSide::Sell => -fill_size,
         };
 
+        // Buying below / selling above the reference is captured spread
+        inv.spread_capture += (reference.inner() - fill_price) * signed_size;
+        inv.reference_cash -= reference.inner() * signed_size;
+
         let old_size = inv.net_size;
         let new_size = old_size + signed_size;
 
@@ -101,6 +157,7 @@ impl InventoryManager {
 
         inv.net_size = new_size;
         inv.fill_count += 1;
+        inv
     }
 
     /// Get the inventory ratio for a market.
@@ -138,6 +195,16 @@ impl InventoryManager {
             .unwrap_or(Decimal::ZERO)
     }
 
+    /// How long the market's inventory has sat unchanged (ms). None when flat
+    /// or when the last fill was recorded without a time.
+    pub fn held_for_ms(&self, market: &MarketKey, now_ms: u64) -> Option<u64> {
+        let inv = self.inventories.get(market)?;
+        if inv.net_size.is_zero() {
+            return None;
+        }
+        inv.changed_at_ms.map(|at| now_ms.saturating_sub(at))
+    }
+
     /// Get notional value of inventory for a market.
     pub fn notional_usd(&self, market: &MarketKey, mark_px: Price) -> Decimal {
         self.net_size(market) * mark_px.inner()
@@ -411,4 +478,48 @@ mod tests {
         // Market 1: +5, Market 2: +5 = Total: +10
         assert_eq!(mgr.total_realized_pnl(), dec!(10));
     }
+
+    #[test]
+    fn test_spread_capture_and_inventory_pnl() {
+        let mut mgr = InventoryManager::new(dec!(1000));
+        // Bought 2 @ 99 with oracle 100: 2 USD captured
+        mgr.record_fill_at_reference(
+            mk(),
+            OrderSide::Buy,
+            Price::new(dec!(99)),
+            Size::new(dec!(2)),
+            Price::new(dec!(100)),
+            1000,
+        );
+        // Oracle fell to 97, sold 1 @ 98: 1 USD captured
+        mgr.record_fill_at_reference(
+            mk(),
+            OrderSide::Sell,
+            Price::new(dec!(98)),
+            Size::new(dec!(1)),
+            Price::new(dec!(97)),
+            2000,
+        );
+
+        let inv = mgr.get(&mk()).unwrap();
+        assert_eq!(inv.spread_capture, dec!(3));
+        // Held 2 while the reference fell 3, then 1 down to 96: -6 - 1 = -7
+        assert_eq!(inv.inventory_pnl(Price::new(dec!(96))), dec!(-7));
+        // Components add up to realized + unrealized
+        let unrealized = mgr.total_unrealized_pnl(|_| Some(Price::new(dec!(96))));
+        assert_eq!(
+            inv.spread_capture + inv.inventory_pnl(Price::new(dec!(96))),
+            inv.realized_pnl + unrealized
+        );
+
+        // Held unchanged since the last fill
+        assert_eq!(mgr.held_for_ms(&mk(), 5000), Some(3000));
+        mgr.record_fill(
+            mk(),
+            OrderSide::Sell,
+            Price::new(dec!(96)),
+            Size::new(dec!(1)),
+        );
+        assert_eq!(mgr.held_for_ms(&mk(), 6000), None);
+    }
 }
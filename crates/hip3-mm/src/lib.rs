@@ -1,9 +1,11 @@
 //! Market making strategy for HIP-3 bot.
 //!
 //! Provides weekend market making capabilities:
-//! - Quote calculation with inventory skew
+//! - Quote calculation with inventory skew (offset or Avellaneda–Stoikov model)
 //! - Quote lifecycle management (place/cancel/replace)
 //! - Inventory tracking with PnL calculation
+//! - Session schedule (weekend or per-market windows)
+//! - Fill probability and markout per quote offset bucket
 //!
 //! # Architecture
 //!
@@ -16,14 +18,27 @@
 //!                  Executor.on_mm_quote() (bypasses taker gates)
 //! ```
 
+pub mod avellaneda;
 pub mod config;
+pub mod fill_stats;
 pub mod inventory;
+pub mod markout;
 pub mod quote_engine;
 pub mod quote_manager;
+pub mod session;
 pub mod volatility;
 
-pub use config::{LevelDistribution, MakerConfig, SizeDistribution};
+pub use avellaneda::compute_quotes_as;
+pub use config::{
+    LadderConfig, LadderSideConfig, LadderSpacing, LevelDistribution, MakerConfig, QuoteModel,
+    SizeDistribution, SkewCurve, ToxicFadeAction,
+};
+pub use fill_stats::{FillProbabilityTracker, OffsetBucketStats};
 pub use inventory::InventoryManager;
-pub use quote_engine::{compute_quotes, QuoteLevel, QuotePair};
-pub use quote_manager::{ActiveQuote, MakerAction, QuoteManager};
+pub use markout::{MarkoutAnalytics, MarkoutSample, MarkoutStats};
+pub use quote_engine::{
+    compute_quotes, compute_quotes_at_offsets, inventory_decay_boost, QuoteLevel, QuotePair,
+};
+pub use quote_manager::{ActiveQuote, ExchangeOrder, MakerAction, QuoteManager, ReconcileReport};
+pub use session::{in_session, MmSessionWindow};
 pub use volatility::{VolatilityStats, WickTracker};
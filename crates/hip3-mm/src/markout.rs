@@ -0,0 +1,272 @@
+//! Markout analytics of MM fills (adverse selection).
+//!
+//! For every MM fill, records the fill price and the oracle at fill time and
+//! measures where the mid and oracle are at each configured horizon (default
+//! T+1s/5s/30s). The markout is signed from our side of the fill: positive = the price moved
+//! in our favor (bought before a rise, sold before a drop), negative = we
+//! were picked off.
+//!
+//! Per-market, per-horizon statistics are the key input for spread sizing
+//! and for deciding whether a market is too toxic to quote.
+
+use std::collections::{HashMap, VecDeque};
+
+use hip3_core::{MarketKey, OrderSide, Price};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+/// One resolved markout of a fill at one horizon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkoutSample {
+    /// Market of the fill.
+    pub market: MarketKey,
+    /// Side of the fill.
+    pub side: OrderSide,
+    /// Horizon after the fill (ms).
+    pub horizon_ms: u64,
+    /// Mid move vs the fill price (bps, positive = favorable).
+    pub mid_bps: f64,
+    /// Oracle move since fill time (bps, positive = favorable).
+    pub oracle_bps: f64,
+}
+
+/// Aggregate markout statistics of one market at one horizon.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarkoutStats {
+    /// Number of measured fills.
+    pub count: u64,
+    /// Mean mid markout (bps).
+    pub mean_mid_bps: f64,
+    /// Mean oracle markout (bps).
+    pub mean_oracle_bps: f64,
+    /// Share of fills with a negative mid markout.
+    pub adverse_ratio: f64,
+}
+
+/// Running sums behind [`MarkoutStats`].
+#[derive(Debug, Default)]
+struct MarkoutSums {
+    count: u64,
+    sum_mid_bps: f64,
+    sum_oracle_bps: f64,
+    adverse: u64,
+}
+
+/// A fill awaiting its markouts.
+#[derive(Debug)]
+struct PendingFill {
+    side: OrderSide,
+    fill_px: Decimal,
+    oracle_px: Decimal,
+    at_ms: u64,
+    /// Index of the next horizon to measure.
+    next_horizon: usize,
+}
+
+/// Tracks markouts of MM fills per market and horizon.
+#[derive(Debug)]
+pub struct MarkoutAnalytics {
+    /// Horizons (ms), ascending.
+    horizons_ms: Vec<u64>,
+    pending: HashMap<MarketKey, VecDeque<PendingFill>>,
+    stats: HashMap<(MarketKey, u64), MarkoutSums>,
+}
+
+impl MarkoutAnalytics {
+    /// Create a tracker measuring markouts at `horizons_ms` after each fill.
+    #[must_use]
+    pub fn new(mut horizons_ms: Vec<u64>) -> Self {
+        horizons_ms.sort_unstable();
+        horizons_ms.dedup();
+        Self {
+            horizons_ms,
+            pending: HashMap::new(),
+            stats: HashMap::new(),
+        }
+    }
+
+    /// Configured horizons (ms), ascending.
+    #[must_use]
+    pub fn horizons_ms(&self) -> &[u64] {
+        &self.horizons_ms
+    }
+
+    /// Record an MM fill with the oracle price at fill time.
+    pub fn record_fill(
+        &mut self,
+        market: MarketKey,
+        side: OrderSide,
+        fill_px: Price,
+        oracle_px: Price,
+        now_ms: u64,
+    ) {
+        if self.horizons_ms.is_empty() || fill_px.is_zero() || oracle_px.is_zero() {
+            return;
+        }
+        self.pending
+            .entry(market)
+            .or_default()
+            .push_back(PendingFill {
+                side,
+                fill_px: fill_px.inner(),
+                oracle_px: oracle_px.inner(),
+                at_ms: now_ms,
+                next_horizon: 0,
+            });
+    }
+
+    /// Measure the markouts of `market`'s fills whose horizons have passed,
+    /// against the current mid and oracle.
+    pub fn on_price(
+        &mut self,
+        market: MarketKey,
+        mid_px: Price,
+        oracle_px: Price,
+        now_ms: u64,
+    ) -> Vec<MarkoutSample> {
+        let mut samples = Vec::new();
+        let Some(fills) = self.pending.get_mut(&market) else {
+            return samples;
+        };
+        for fill in fills.iter_mut() {
+            while let Some(&horizon_ms) = self.horizons_ms.get(fill.next_horizon) {
+                if now_ms.saturating_sub(fill.at_ms) < horizon_ms {
+                    break;
+                }
+                fill.next_horizon += 1;
+                let sign = match fill.side {
+                    OrderSide::Buy => Decimal::ONE,
+                    OrderSide::Sell => -Decimal::ONE,
+                };
+                let mid_bps = move_bps(fill.fill_px, mid_px.inner()) * sign;
+                let oracle_bps = move_bps(fill.oracle_px, oracle_px.inner()) * sign;
+                samples.push(MarkoutSample {
+                    market,
+                    side: fill.side,
+                    horizon_ms,
+                    mid_bps: mid_bps.to_f64().unwrap_or(0.0),
+                    oracle_bps: oracle_bps.to_f64().unwrap_or(0.0),
+                });
+            }
+        }
+        let horizons = self.horizons_ms.len();
+        fills.retain(|fill| fill.next_horizon < horizons);
+
+        for sample in &samples {
+            let sums = self.stats.entry((market, sample.horizon_ms)).or_default();
+            sums.count += 1;
+            sums.sum_mid_bps += sample.mid_bps;
+            sums.sum_oracle_bps += sample.oracle_bps;
+            if sample.mid_bps < 0.0 {
+                sums.adverse += 1;
+            }
+        }
+        samples
+    }
+
+    /// Markout statistics of `market` at `horizon_ms`.
+    #[must_use]
+    pub fn stats(&self, market: &MarketKey, horizon_ms: u64) -> Option<MarkoutStats> {
+        let sums = self.stats.get(&(*market, horizon_ms))?;
+        if sums.count == 0 {
+            return None;
+        }
+        let n = sums.count as f64;
+        Some(MarkoutStats {
+            count: sums.count,
+            mean_mid_bps: sums.sum_mid_bps / n,
+            mean_oracle_bps: sums.sum_oracle_bps / n,
+            adverse_ratio: sums.adverse as f64 / n,
+        })
+    }
+
+    /// Number of fills still awaiting a markout.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.pending.values().map(VecDeque::len).sum()
+    }
+}
+
+/// Move from `from` to `to` in bps of `from`.
+fn move_bps(from: Decimal, to: Decimal) -> Decimal {
+    if from.is_zero() {
+        return Decimal::ZERO;
+    }
+    (to - from) / from * Decimal::from(10000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+
+    fn market(idx: u32) -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(idx))
+    }
+
+    #[test]
+    fn test_markouts_per_horizon() {
+        let mut analytics = MarkoutAnalytics::new(vec![5000, 1000, 30000]);
+        assert_eq!(analytics.horizons_ms(), &[1000, 5000, 30000]);
+
+        // Bought at 100 (oracle 100), sold at 100 (oracle 100)
+        analytics.record_fill(
+            market(0),
+            OrderSide::Buy,
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            0,
+        );
+        analytics.record_fill(
+            market(0),
+            OrderSide::Sell,
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            0,
+        );
+
+        // Nothing due yet; other markets untouched
+        assert!(analytics
+            .on_price(market(0), Price::new(dec!(101)), Price::new(dec!(101)), 999)
+            .is_empty());
+        assert!(analytics
+            .on_price(
+                market(1),
+                Price::new(dec!(101)),
+                Price::new(dec!(101)),
+                1000
+            )
+            .is_empty());
+
+        // T+1s: price rose 10 bps -> buy +10, sell -10
+        let samples = analytics.on_price(
+            market(0),
+            Price::new(dec!(100.1)),
+            Price::new(dec!(100.2)),
+            1000,
+        );
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].horizon_ms, 1000);
+        assert!((samples[0].mid_bps - 10.0).abs() < 1e-9);
+        assert!((samples[0].oracle_bps - 20.0).abs() < 1e-9);
+        assert!((samples[1].mid_bps + 10.0).abs() < 1e-9);
+
+        let stats = analytics.stats(&market(0), 1000).unwrap();
+        assert_eq!(stats.count, 2);
+        assert!(stats.mean_mid_bps.abs() < 1e-9);
+        assert!((stats.adverse_ratio - 0.5).abs() < 1e-9);
+
+        // A late update resolves every passed horizon at once, then drops the fills
+        let samples = analytics.on_price(
+            market(0),
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            40_000,
+        );
+        assert_eq!(samples.len(), 4);
+        assert_eq!(analytics.pending_count(), 0);
+        assert_eq!(analytics.stats(&market(0), 30000).unwrap().count, 2);
+        assert!(analytics.stats(&market(1), 1000).is_none());
+    }
+}
@@ -3,14 +3,20 @@
 //! Computes bid/ask prices based on:
 //! - Oracle price (source of truth)
 //! - Fixed offset (min_offset_bps)
-//! - Inventory skew (shift quotes to reduce exposure)
+//! - Inventory skew (shift quotes to reduce exposure), shaped by a skew
+//!   curve and leaning harder after toxic fills and on inventory that sits
+//!   unchanged (time decay)
+//! - Level layout: linear/exponential levels, a per-side ladder, or the
+//!   offsets learned from fill statistics
 
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
 use hip3_core::Price;
 
-use crate::config::{LevelDistribution, MakerConfig, SizeDistribution};
+use crate::config::{
+    LadderSideConfig, LevelDistribution, MakerConfig, SizeDistribution, SkewCurve,
+};
 use crate::volatility::VolatilityStats;
 
 /// A single quote level (one side).
@@ -42,6 +48,87 @@ fn decimal_pow(base: Decimal, exp: Decimal) -> Decimal {
     Decimal::from_f64_retain(b.powf(e)).unwrap_or(Decimal::ZERO)
 }
 
+/// Shape the clamped inventory ratio by the configured skew curve.
+fn shaped_inventory(clamped_inv: Decimal, config: &MakerConfig) -> Decimal {
+    match config.inventory_skew_curve {
+        SkewCurve::Linear => clamped_inv,
+        SkewCurve::Quadratic => clamped_inv * clamped_inv.abs(),
+        SkewCurve::Piecewise => {
+            let x = clamped_inv.abs();
+            let mut prev = (Decimal::ZERO, Decimal::ZERO);
+            let mut skew = None;
+            for &(px, py) in &config.inventory_skew_points {
+                if x <= px {
+                    let span = px - prev.0;
+                    skew = Some(if span.is_zero() {
+                        py
+                    } else {
+                        prev.1 + (py - prev.1) * (x - prev.0) / span
+                    });
+                    break;
+                }
+                prev = (px, py);
+            }
+            let skew = skew.unwrap_or(prev.1);
+            if clamped_inv.is_sign_negative() {
+                -skew
+            } else {
+                skew
+            }
+        }
+    }
+}
+
+/// Inventory skew multiplier from time decay (1.0 = none).
+///
+/// Once the inventory has sat unchanged for `inventory_decay_after_secs`,
+/// ramps linearly to `inventory_decay_max_skew_multiplier` over
+/// `inventory_decay_ramp_secs`.
+pub fn inventory_decay_boost(held_for_ms: Option<u64>, config: &MakerConfig) -> Decimal {
+    let after_ms = config.inventory_decay_after_secs * 1000;
+    let Some(held_ms) = held_for_ms.filter(|&ms| after_ms > 0 && ms > after_ms) else {
+        return dec!(1);
+    };
+    let max = config.inventory_decay_max_skew_multiplier.max(dec!(1));
+    let ramp_ms = config.inventory_decay_ramp_secs * 1000;
+    if ramp_ms == 0 {
+        return max;
+    }
+    let progress = (Decimal::from(held_ms - after_ms) / Decimal::from(ramp_ms)).min(dec!(1));
+    dec!(1) + (max - dec!(1)) * progress
+}
+
+/// Apply inventory and velocity skew to a level's base offset.
+///
+/// Returns (bid_offset_bps, ask_offset_bps), each at least 1 bps.
+fn skewed_offsets(
+    base_offset: Decimal,
+    skew_inv: Decimal,
+    velocity_trend: Decimal,
+    config: &MakerConfig,
+) -> (Decimal, Decimal) {
+    // Inventory skew: when long, widen bid (less aggressive buy) and tighten ask
+    // Multiplicative skew: offset * (1 + skew * inventory_ratio)
+    let inv_skew = config.inventory_skew_factor * skew_inv;
+
+    let mut bid_offset_bps = base_offset * (dec!(1) + inv_skew);
+    let mut ask_offset_bps = base_offset * (dec!(1) - inv_skew);
+
+    // Phase C: Oracle velocity skew
+    // Oracle rising (velocity > 0): tighten ask (sell into strength), widen bid
+    // Oracle falling (velocity < 0): tighten bid (buy into weakness), widen ask
+    if config.velocity_skew_enabled {
+        let clamped_vel = velocity_trend.max(dec!(-1)).min(dec!(1));
+        let vel_skew = config.velocity_skew_factor * clamped_vel;
+        // Positive vel_skew (oracle rising): ask *= (1 - vel_skew), bid *= (1 + vel_skew)
+        bid_offset_bps *= dec!(1) + vel_skew;
+        ask_offset_bps *= dec!(1) - vel_skew;
+    }
+
+    // Ensure minimum offset is never negative
+    (bid_offset_bps.max(dec!(1)), ask_offset_bps.max(dec!(1))) // at least 1 bps
+}
+
 /// Calculate quotes for a single market.
 ///
 /// # Arguments
@@ -56,6 +143,8 @@ fn decimal_pow(base: Decimal, exp: Decimal) -> Decimal {
 /// * `velocity_trend` - Phase C: Oracle directional trend in [-1.0, 1.0].
 ///   Positive = oracle rising, negative = falling.
 ///   When `velocity_skew_enabled`, tightens quotes in trend direction.
+/// * `skew_boost` - Inventory skew multiplier (1.0 = none): recent toxic
+///   fill markouts (`markout_skew_factor`) and inventory time decay.
 ///
 /// # Returns
 /// QuotePair with bid and ask levels.
@@ -66,15 +155,15 @@ pub fn compute_quotes(
     spread_multiplier: Decimal,
     volatility: Option<&VolatilityStats>,
     velocity_trend: Decimal,
+    skew_boost: Decimal,
 ) -> QuotePair {
     let oracle = oracle_price.inner();
     let bps_divisor = dec!(10000);
 
     // Clamp inventory ratio to [-1, 1]
     let clamped_inv = inventory_ratio.max(dec!(-1)).min(dec!(1));
-
-    let mut bids = Vec::with_capacity(config.num_levels as usize);
-    let mut asks = Vec::with_capacity(config.num_levels as usize);
+    // Skew curve, leaning harder against toxic or stale inventory
+    let skew_inv = shaped_inventory(clamped_inv, config) * skew_boost.max(dec!(1));
 
     // Clamp spread multiplier to at least 1.0
     let multiplier = spread_multiplier.max(dec!(1));
@@ -85,9 +174,13 @@ pub fn compute_quotes(
             Some(vol) if vol.is_valid => {
                 let optimal =
                     Decimal::from_f64_retain(vol.optimal_wick_bps).unwrap_or(Decimal::ZERO);
-                (optimal * config.l0_wick_multiplier)
-                    .max(config.min_offset_bps)
-                    .max(config.fee_buffer_bps)
+                let dynamic = optimal * config.l0_wick_multiplier;
+                let capped = if config.dynamic_offset_max_bps > Decimal::ZERO {
+                    dynamic.min(config.dynamic_offset_max_bps)
+                } else {
+                    dynamic
+                };
+                capped.max(config.min_offset_bps).max(config.fee_buffer_bps)
             }
             _ => config.min_offset_bps, // insufficient data → fixed fallback
         }
@@ -95,6 +188,38 @@ pub fn compute_quotes(
         config.min_offset_bps // disabled → fixed offset
     };
 
+    // Ladder quoting: per-side offsets and sizes from the ladder config
+    if let Some(ref ladder) = config.ladder {
+        let level_offset = |side: &LadderSideConfig, level: u32| {
+            side.offset_bps(level, effective_min_offset) * multiplier
+        };
+        let bids = (0..ladder.bids.num_levels())
+            .map(|level| {
+                let base_offset = level_offset(&ladder.bids, level);
+                let (bid_offset_bps, _) =
+                    skewed_offsets(base_offset, skew_inv, velocity_trend, config);
+                QuoteLevel {
+                    price: Price::new(oracle * (dec!(1) - bid_offset_bps / bps_divisor)),
+                    size_usd: ladder.bids.size_usd(level, config.size_per_level_usd),
+                    level,
+                }
+            })
+            .collect();
+        let asks = (0..ladder.asks.num_levels())
+            .map(|level| {
+                let base_offset = level_offset(&ladder.asks, level);
+                let (_, ask_offset_bps) =
+                    skewed_offsets(base_offset, skew_inv, velocity_trend, config);
+                QuoteLevel {
+                    price: Price::new(oracle * (dec!(1) + ask_offset_bps / bps_divisor)),
+                    size_usd: ladder.asks.size_usd(level, config.size_per_level_usd),
+                    level,
+                }
+            })
+            .collect();
+        return QuotePair { bids, asks };
+    }
+
     // Phase B: Compute range upper bound for exponential distribution
     let use_exponential =
         config.level_distribution == LevelDistribution::Exponential && config.num_levels > 1;
@@ -113,12 +238,9 @@ pub fn compute_quotes(
         Decimal::ZERO // unused in linear mode
     };
 
-    // Phase B: Convex size distribution
-    let use_convex = config.size_distribution == SizeDistribution::Convex && config.num_levels > 1;
-
-    for level in 0..config.num_levels {
+    level_quotes(oracle, config, skew_inv, velocity_trend, |level| {
         // Phase B: Level offset calculation
-        let base_offset = if use_exponential {
+        if use_exponential {
             let t = Decimal::from(level) / Decimal::from(config.num_levels - 1);
             let t_exp = decimal_pow(t, config.level_exponent);
             (effective_min_offset + t_exp * (range_upper - effective_min_offset)) * multiplier
@@ -126,29 +248,59 @@ pub fn compute_quotes(
             // Linear (original behavior)
             let level_offset = config.level_spacing_bps * Decimal::from(level);
             (effective_min_offset + level_offset) * multiplier
-        };
+        }
+    })
+}
 
-        // Inventory skew: when long, widen bid (less aggressive buy) and tighten ask
-        // Multiplicative skew: offset * (1 + skew * inventory_ratio)
-        let inv_skew = config.inventory_skew_factor * clamped_inv;
+/// Compute quotes at explicit per-level base offsets (bps), e.g. the most
+/// profitable offsets from the fill statistics. One level per offset; skew,
+/// spread multiplier and sizes apply as in [`compute_quotes`]. Offsets are
+/// floored at `fee_buffer_bps`.
+pub fn compute_quotes_at_offsets(
+    oracle_price: Price,
+    inventory_ratio: Decimal,
+    config: &MakerConfig,
+    spread_multiplier: Decimal,
+    offsets_bps: &[Decimal],
+    velocity_trend: Decimal,
+    skew_boost: Decimal,
+) -> QuotePair {
+    let clamped_inv = inventory_ratio.max(dec!(-1)).min(dec!(1));
+    let skew_inv = shaped_inventory(clamped_inv, config) * skew_boost.max(dec!(1));
+    let multiplier = spread_multiplier.max(dec!(1));
+    let config = MakerConfig {
+        num_levels: offsets_bps.len() as u32,
+        ..config.clone()
+    };
+    level_quotes(
+        oracle_price.inner(),
+        &config,
+        skew_inv,
+        velocity_trend,
+        |level| offsets_bps[level as usize].max(config.fee_buffer_bps) * multiplier,
+    )
+}
 
-        let mut bid_offset_bps = base_offset * (dec!(1) + inv_skew);
-        let mut ask_offset_bps = base_offset * (dec!(1) - inv_skew);
+/// Bid and ask of each of `config.num_levels` levels around `oracle`, at
+/// `base_offset(level)` bps before skew.
+fn level_quotes(
+    oracle: Decimal,
+    config: &MakerConfig,
+    skew_inv: Decimal,
+    velocity_trend: Decimal,
+    base_offset: impl Fn(u32) -> Decimal,
+) -> QuotePair {
+    let bps_divisor = dec!(10000);
+    let mut bids = Vec::with_capacity(config.num_levels as usize);
+    let mut asks = Vec::with_capacity(config.num_levels as usize);
 
-        // Phase C: Oracle velocity skew
-        // Oracle rising (velocity > 0): tighten ask (sell into strength), widen bid
-        // Oracle falling (velocity < 0): tighten bid (buy into weakness), widen ask
-        if config.velocity_skew_enabled {
-            let clamped_vel = velocity_trend.max(dec!(-1)).min(dec!(1));
-            let vel_skew = config.velocity_skew_factor * clamped_vel;
-            // Positive vel_skew (oracle rising): ask *= (1 - vel_skew), bid *= (1 + vel_skew)
-            bid_offset_bps *= dec!(1) + vel_skew;
-            ask_offset_bps *= dec!(1) - vel_skew;
-        }
+    // Phase B: Convex size distribution
+    let use_convex = config.size_distribution == SizeDistribution::Convex && config.num_levels > 1;
 
-        // Ensure minimum offset is never negative
-        let bid_offset_bps = bid_offset_bps.max(dec!(1)); // at least 1 bps
-        let ask_offset_bps = ask_offset_bps.max(dec!(1));
+    for level in 0..config.num_levels {
+        let base_offset = base_offset(level);
+        let (bid_offset_bps, ask_offset_bps) =
+            skewed_offsets(base_offset, skew_inv, velocity_trend, config);
 
         let bid_price = oracle * (dec!(1) - bid_offset_bps / bps_divisor);
         let ask_price = oracle * (dec!(1) + ask_offset_bps / bps_divisor);
@@ -200,7 +352,15 @@ mod tests {
     fn test_symmetric_quotes_no_inventory() {
         let config = test_config();
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         assert_eq!(quotes.bids.len(), 1);
         assert_eq!(quotes.asks.len(), 1);
@@ -217,7 +377,15 @@ mod tests {
         let config = test_config();
         let oracle = Price::new(dec!(100));
         // Full long inventory (ratio = 1.0)
-        let quotes = compute_quotes(oracle, dec!(1.0), &config, dec!(1), None, Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(1.0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         // Long inventory: bid offset should be wider (less aggressive buy)
         // bid_offset = 20 * (1 + 0.3 * 1.0) = 20 * 1.3 = 26 bps
@@ -241,7 +409,15 @@ mod tests {
         let config = test_config();
         let oracle = Price::new(dec!(100));
         // Full short inventory (ratio = -1.0)
-        let quotes = compute_quotes(oracle, dec!(-1.0), &config, dec!(1), None, Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(-1.0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         // Short inventory: ask offset should be wider (less aggressive sell)
         let bid = quotes.bids[0].price.inner();
@@ -269,7 +445,15 @@ mod tests {
         };
 
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         assert_eq!(quotes.bids.len(), 3);
         assert_eq!(quotes.asks.len(), 3);
@@ -302,7 +486,15 @@ mod tests {
         };
 
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(1.0), &config, dec!(1), None, Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(1.0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         // ask_offset = 5 * (1 - 0.9 * 1.0) = 5 * 0.1 = 0.5 bps
         // This is above 1 bps minimum so it stays
@@ -316,7 +508,15 @@ mod tests {
             inventory_skew_factor: dec!(1.5), // extreme
             ..Default::default()
         };
-        let quotes2 = compute_quotes(oracle, dec!(1.0), &config2, dec!(1), None, Decimal::ZERO);
+        let quotes2 = compute_quotes(
+            oracle,
+            dec!(1.0),
+            &config2,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
         // ask_offset = 5 * (1 - 1.5) = 5 * (-0.5) = -2.5 → clamped to 1 bps
         let ask2 = quotes2.asks[0].price.inner();
         assert!(ask2 > oracle.inner());
@@ -328,8 +528,24 @@ mod tests {
         let oracle = Price::new(dec!(100));
 
         // Ratio > 1 should be clamped to 1
-        let q1 = compute_quotes(oracle, dec!(2.0), &config, dec!(1), None, Decimal::ZERO);
-        let q2 = compute_quotes(oracle, dec!(1.0), &config, dec!(1), None, Decimal::ZERO);
+        let q1 = compute_quotes(
+            oracle,
+            dec!(2.0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
+        let q2 = compute_quotes(
+            oracle,
+            dec!(1.0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
         assert_eq!(q1.bids[0].price, q2.bids[0].price);
         assert_eq!(q1.asks[0].price, q2.asks[0].price);
     }
@@ -339,8 +555,24 @@ mod tests {
         let config = test_config();
         let oracle = Price::new(dec!(100));
 
-        let normal = compute_quotes(oracle, dec!(0), &config, dec!(1), None, Decimal::ZERO);
-        let doubled = compute_quotes(oracle, dec!(0), &config, dec!(2), None, Decimal::ZERO);
+        let normal = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
+        let doubled = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(2),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         // Normal: offset = 20 bps → bid = 99.80, ask = 100.20
         assert_eq!(normal.bids[0].price.inner(), dec!(99.80));
@@ -367,7 +599,15 @@ mod tests {
             ..Default::default()
         };
         // Even with valid volatility stats, disabled → uses fixed 20 bps
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), Some(&vol), Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            Some(&vol),
+            Decimal::ZERO,
+            dec!(1),
+        );
         assert_eq!(quotes.bids[0].price.inner(), dec!(99.80));
         assert_eq!(quotes.asks[0].price.inner(), dec!(100.20));
     }
@@ -388,7 +628,15 @@ mod tests {
             ..Default::default()
         };
         // optimal(10) × mult(2) = 20 bps → same as min_offset (floor)
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), Some(&vol), Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            Some(&vol),
+            Decimal::ZERO,
+            dec!(1),
+        );
         assert_eq!(quotes.bids[0].price.inner(), dec!(99.80));
         assert_eq!(quotes.asks[0].price.inner(), dec!(100.20));
     }
@@ -409,11 +657,47 @@ mod tests {
             ..Default::default()
         };
         // Should use min_offset_bps (20) as floor
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), Some(&vol), Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            Some(&vol),
+            Decimal::ZERO,
+            dec!(1),
+        );
         assert_eq!(quotes.bids[0].price.inner(), dec!(99.80));
         assert_eq!(quotes.asks[0].price.inner(), dec!(100.20));
     }
 
+    #[test]
+    fn test_dynamic_offset_respects_ceiling() {
+        let config = MakerConfig {
+            dynamic_offset_enabled: true,
+            min_offset_bps: dec!(20),
+            l0_wick_multiplier: dec!(2),
+            dynamic_offset_max_bps: dec!(30),
+            ..test_config()
+        };
+        let oracle = Price::new(dec!(100));
+        let vol = VolatilityStats {
+            optimal_wick_bps: 50.0, // 50 × 2 = 100 bps > ceiling(30)
+            is_valid: true,
+            ..Default::default()
+        };
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            Some(&vol),
+            Decimal::ZERO,
+            dec!(1),
+        );
+        assert_eq!(quotes.bids[0].price.inner(), dec!(99.70));
+        assert_eq!(quotes.asks[0].price.inner(), dec!(100.30));
+    }
+
     #[test]
     fn test_dynamic_offset_invalid_fallback() {
         let config = MakerConfig {
@@ -429,7 +713,15 @@ mod tests {
             ..Default::default()
         };
         // is_valid=false → falls back to fixed min_offset_bps (20)
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), Some(&vol), Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            Some(&vol),
+            Decimal::ZERO,
+            dec!(1),
+        );
         assert_eq!(quotes.bids[0].price.inner(), dec!(99.80));
         assert_eq!(quotes.asks[0].price.inner(), dec!(100.20));
     }
@@ -453,7 +745,15 @@ mod tests {
             ..Default::default()
         };
         // L0 = 25 × 2.0 = 50 bps (if we used P99 fixed: 10 × 2 = 20 bps)
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), Some(&vol), Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            Some(&vol),
+            Decimal::ZERO,
+            dec!(1),
+        );
         // bid = 100 * (1 - 50/10000) = 99.50
         assert_eq!(quotes.bids[0].price.inner(), dec!(99.50));
         // ask = 100 * (1 + 50/10000) = 100.50
@@ -474,7 +774,15 @@ mod tests {
             ..Default::default()
         };
         let oracle = Price::new(dec!(100));
-        let linear = compute_quotes(oracle, dec!(0), &config, dec!(1), None, Decimal::ZERO);
+        let linear = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         // With exponent=1.0, exponential is linear: offset[i] = L0 + (i/(N-1))*(upper-L0)
         // For 3 levels: L0=20, upper = L0 + min_range_width(10) = 30
@@ -488,7 +796,15 @@ mod tests {
             size_per_level_usd: dec!(5),
             ..Default::default()
         };
-        let exp = compute_quotes(oracle, dec!(0), &exp_config, dec!(1), None, Decimal::ZERO);
+        let exp = compute_quotes(
+            oracle,
+            dec!(0),
+            &exp_config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         // Both should produce 3 levels with monotonic offsets
         assert_eq!(exp.bids.len(), 3);
@@ -522,7 +838,15 @@ mod tests {
             ..Default::default()
         };
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), Some(&vol), Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            Some(&vol),
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         // range_upper = max(50*1.2=60, 20+10=30) = 60
         // offset[i] = 20 + (i/4)^2 * (60-20)
@@ -561,7 +885,15 @@ mod tests {
             ..Default::default()
         };
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), Some(&vol), Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            Some(&vol),
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         // Bids should be monotonically decreasing (further from oracle)
         for i in 1..quotes.bids.len() {
@@ -604,7 +936,15 @@ mod tests {
             ..Default::default()
         };
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), Some(&vol), Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            Some(&vol),
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         // L0 = max(3*1, 20, 8) = 20 bps
         // range_upper = max(5*1.2=6, 20+10=30) = 30 bps
@@ -637,7 +977,15 @@ mod tests {
             ..Default::default()
         };
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         assert_eq!(quotes.bids.len(), 5);
 
@@ -673,7 +1021,15 @@ mod tests {
             ..Default::default()
         };
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         let total_size: Decimal = quotes.bids.iter().map(|q| q.size_usd).sum();
         let avg_size = total_size / Decimal::from(quotes.bids.len() as u64);
@@ -694,7 +1050,15 @@ mod tests {
             ..Default::default()
         };
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         for bid in &quotes.bids {
             assert_eq!(bid.size_usd, dec!(5));
@@ -716,7 +1080,15 @@ mod tests {
             ..Default::default()
         };
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, Decimal::ZERO);
+        let quotes = compute_quotes(
+            oracle,
+            dec!(0),
+            &config,
+            dec!(1),
+            None,
+            Decimal::ZERO,
+            dec!(1),
+        );
 
         // Falls back to linear for single level
         assert_eq!(quotes.bids.len(), 1);
@@ -735,7 +1107,7 @@ mod tests {
             ..test_config()
         };
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, dec!(1.0));
+        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, dec!(1.0), dec!(1));
 
         // bid_offset = 20 * (1 + 0.3*1.0) = 20 * 1.3 = 26 bps
         // ask_offset = 20 * (1 - 0.3*1.0) = 20 * 0.7 = 14 bps
@@ -761,7 +1133,7 @@ mod tests {
             ..test_config()
         };
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, dec!(-1.0));
+        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, dec!(-1.0), dec!(1));
 
         // bid_offset = 20 * (1 + 0.3*(-1.0)) = 20 * 0.7 = 14 bps
         // ask_offset = 20 * (1 - 0.3*(-1.0)) = 20 * 1.3 = 26 bps
@@ -787,9 +1159,77 @@ mod tests {
             ..test_config()
         };
         let oracle = Price::new(dec!(100));
-        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, dec!(0));
+        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, dec!(0), dec!(1));
 
         assert_eq!(quotes.bids[0].price.inner(), dec!(99.80));
         assert_eq!(quotes.asks[0].price.inner(), dec!(100.20));
     }
+
+    #[test]
+    fn test_ladder_geometric_and_custom_sides() {
+        use crate::config::{LadderConfig, LadderSideConfig, LadderSpacing};
+
+        let config = MakerConfig {
+            inventory_skew_factor: dec!(0),
+            ladder: Some(LadderConfig {
+                bids: LadderSideConfig {
+                    levels: 3,
+                    ratio: dec!(2),
+                    sizes_usd: vec![dec!(5), dec!(10)],
+                    ..Default::default()
+                },
+                asks: LadderSideConfig {
+                    spacing: LadderSpacing::Custom,
+                    offsets_bps: vec![dec!(10), dec!(30)],
+                    ..Default::default()
+                },
+            }),
+            ..test_config()
+        };
+        let oracle = Price::new(dec!(100));
+        let quotes = compute_quotes(oracle, dec!(0), &config, dec!(1), None, dec!(0), dec!(1));
+
+        // Geometric bids from L0 = 20 bps: 20, 40, 80 bps; last size repeats
+        let bids: Vec<_> = quotes.bids.iter().map(|q| q.price.inner()).collect();
+        assert_eq!(bids, vec![dec!(99.80), dec!(99.60), dec!(99.20)]);
+        let sizes: Vec<_> = quotes.bids.iter().map(|q| q.size_usd).collect();
+        assert_eq!(sizes, vec![dec!(5), dec!(10), dec!(10)]);
+
+        // Custom asks floored at L0: 20, 30 bps
+        let asks: Vec<_> = quotes.asks.iter().map(|q| q.price.inner()).collect();
+        assert_eq!(asks, vec![dec!(100.20), dec!(100.30)]);
+        assert_eq!(quotes.asks[1].size_usd, config.size_per_level_usd);
+    }
+
+    #[test]
+    fn test_skew_curves_and_toxicity_boost() {
+        let oracle = Price::new(dec!(100));
+
+        // Quadratic: 0.5 → 0.25, skew 0.3 × 0.25 = 7.5%
+        let config = MakerConfig {
+            inventory_skew_curve: SkewCurve::Quadratic,
+            ..test_config()
+        };
+        let quotes = compute_quotes(oracle, dec!(0.5), &config, dec!(1), None, dec!(0), dec!(1));
+        assert_eq!(quotes.bids[0].price.inner(), dec!(99.785));
+        assert_eq!(quotes.asks[0].price.inner(), dec!(100.185));
+
+        // Piecewise: 0.6 between (0.4, 0.1) and (0.8, 1.0) → 0.55, mirrored when short
+        let config = MakerConfig {
+            inventory_skew_curve: SkewCurve::Piecewise,
+            inventory_skew_points: vec![(dec!(0.4), dec!(0.1)), (dec!(0.8), dec!(1.0))],
+            ..test_config()
+        };
+        let quotes = compute_quotes(oracle, dec!(0.6), &config, dec!(1), None, dec!(0), dec!(1));
+        assert_eq!(quotes.bids[0].price.inner(), dec!(99.767));
+        assert_eq!(quotes.asks[0].price.inner(), dec!(100.167));
+        let short = compute_quotes(oracle, dec!(-0.6), &config, dec!(1), None, dec!(0), dec!(1));
+        assert_eq!(short.asks[0].price.inner(), dec!(100.233));
+
+        // Toxicity boost doubles the linear skew: 0.3 × 0.5 × 2 = 30%
+        let config = test_config();
+        let quotes = compute_quotes(oracle, dec!(0.5), &config, dec!(1), None, dec!(0), dec!(2));
+        assert_eq!(quotes.bids[0].price.inner(), dec!(99.74));
+        assert_eq!(quotes.asks[0].price.inner(), dec!(100.14));
+    }
 }
@@ -2,27 +2,46 @@
 //!
 //! Manages the full lifecycle of MM quotes:
 //! - Place initial quotes (GTC/ALO)
-//! - Detect when requote is needed (oracle moved)
-//! - Generate cancel + re-place actions
+//! - Detect when requote is needed (oracle moved, quotes outside the
+//!   hysteresis band and past the per-level minimum interval)
+//! - Generate cancel + re-place actions (or in-place amendments)
+//! - Ladder quoting: diff the desired levels against the active quotes and
+//!   touch only the levels that drifted
 //! - Track active quotes per market
 //!
 //! Safety features:
 //! - P2-1: Inventory skew protection (one-sided stop + emergency flatten)
 //! - P2-2: Stale cancel detection (halt on unacked cancels)
 //! - P2-3: Adverse selection detection (spread widening on consecutive fills)
+//! - Toxic flow fading (widen or pull a market on negative markouts)
+//! - Inventory time decay (lean the skew harder on inventory that sits
+//!   unchanged, then hedge it with reduce-only IOCs)
+//! - Resting order cap per market, and periodic reconciliation against the
+//!   exchange's open orders (cancel unknown orders, re-register unacked
+//!   quotes, forget quotes gone from the book)
+//!
+//! Fill probability and markout are tracked per quote offset bucket, to
+//! suggest the most profitable offsets (quoted at with `fill_stats_offsets`).
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use hip3_core::{
-    ClientOrderId, MarketKey, OrderSide, PendingCancel, PendingOrder, Price, Size, TimeInForce,
+    ClientOrderId, MarketKey, OrderSide, PendingCancel, PendingModify, PendingOrder, Price, Size,
+    TimeInForce,
 };
+use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use tracing::{debug, info, warn};
 
-use crate::config::MakerConfig;
+use crate::avellaneda::compute_quotes_as;
+use crate::config::{MakerConfig, QuoteModel, ToxicFadeAction};
+use crate::fill_stats::FillProbabilityTracker;
 use crate::inventory::InventoryManager;
-use crate::quote_engine::{compute_quotes, QuotePair};
+use crate::markout::MarkoutSample;
+use crate::quote_engine::{
+    compute_quotes, compute_quotes_at_offsets, inventory_decay_boost, QuotePair,
+};
 use crate::volatility::{VolatilityStats, WickTracker};
 
 /// An active quote tracked by the manager.
@@ -44,6 +63,32 @@ pub struct ActiveQuote {
     pub placed_at_ms: u64,
 }
 
+/// An open order listed by the exchange (openOrders).
+#[derive(Debug, Clone)]
+pub struct ExchangeOrder {
+    pub market: MarketKey,
+    /// Exchange order ID.
+    pub oid: u64,
+    /// Client order ID, if listed.
+    pub cloid: Option<ClientOrderId>,
+    pub side: OrderSide,
+    /// Price as signed (rounded to the tick).
+    pub price: Price,
+    /// Remaining size.
+    pub size: Size,
+}
+
+/// Outcome of a reconciliation against the exchange's open orders.
+#[derive(Debug, Default)]
+pub struct ReconcileReport {
+    /// Cancels of exchange orders unknown to the quote manager.
+    pub unknown_cancels: Vec<PendingCancel>,
+    /// Unacked quotes matched to an exchange order and given its oid.
+    pub registered: usize,
+    /// Quotes forgotten after missing from two listings in a row.
+    pub dropped: usize,
+}
+
 /// Actions that the quote manager wants the executor to perform.
 #[derive(Debug, Clone)]
 pub enum MakerAction {
@@ -56,6 +101,8 @@ pub enum MakerAction {
         cancels: Vec<PendingCancel>,
         new_orders: Vec<PendingOrder>,
     },
+    /// Amend resting quotes in place (price/size only).
+    Modify(Vec<PendingModify>),
     /// Cancel all quotes and flatten position.
     FlattenAll {
         cancels: Vec<PendingCancel>,
@@ -70,8 +117,8 @@ struct MarketQuoteState {
     bids: Vec<ActiveQuote>,
     /// Active ask quotes.
     asks: Vec<ActiveQuote>,
-    /// Oracle price at last quote.
-    last_oracle: Option<Price>,
+    /// Quote center (oracle, or its blend with mark) at last quote.
+    last_center: Option<Price>,
     /// Timestamp of last requote.
     last_requote_ms: u64,
 }
@@ -81,7 +128,7 @@ impl MarketQuoteState {
         Self {
             bids: Vec::new(),
             asks: Vec::new(),
-            last_oracle: None,
+            last_center: None,
             last_requote_ms: 0,
         }
     }
@@ -89,7 +136,7 @@ impl MarketQuoteState {
     fn clear(&mut self) {
         self.bids.clear();
         self.asks.clear();
-        self.last_oracle = None;
+        self.last_center = None;
     }
 }
 
@@ -121,6 +168,63 @@ impl AdverseSelectionState {
     }
 }
 
+/// Exchange minimum order value (USD).
+const MIN_ORDER_NOTIONAL_USD: Decimal = dec!(10);
+
+/// How long a decay hedge's cloid stays an MM order (ms), to catch its fills.
+const HEDGE_ORDER_TTL_MS: u64 = 60_000;
+
+/// Decayed weight below which a market's markout mean has expired.
+const MARKOUT_MIN_WEIGHT: f64 = 0.1;
+
+/// Per-market recent fill markouts at the quoting horizon, as measured by
+/// [`crate::MarkoutAnalytics`].
+///
+/// Markouts are weighted by age (`markout_half_life_secs`). The mean is
+/// the weighted sum over at least one fill's weight, so once fills stop it
+/// fades toward zero and expires below [`MARKOUT_MIN_WEIGHT`].
+#[derive(Debug, Default)]
+struct MarkoutTracker {
+    /// Decayed sum of markouts (bps, positive = favorable).
+    sum_bps: f64,
+    /// Decayed number of markouts.
+    weight: f64,
+    /// Time the sums were last decayed to (ms).
+    at_ms: u64,
+    /// Markouts recorded since the last reset.
+    fills: usize,
+}
+
+impl MarkoutTracker {
+    /// Decay factor from the last update to `now_ms`.
+    fn decay(&self, now_ms: u64, half_life_ms: u64) -> f64 {
+        if half_life_ms == 0 {
+            return 1.0;
+        }
+        let elapsed = now_ms.saturating_sub(self.at_ms) as f64;
+        0.5_f64.powf(elapsed / half_life_ms as f64)
+    }
+
+    /// Add a markout measured at `now_ms`.
+    fn record(&mut self, markout_bps: f64, now_ms: u64, half_life_ms: u64) {
+        let decay = self.decay(now_ms, half_life_ms);
+        self.sum_bps = self.sum_bps * decay + markout_bps;
+        self.weight = self.weight * decay + 1.0;
+        self.at_ms = self.at_ms.max(now_ms);
+        self.fills += 1;
+    }
+
+    /// Decayed mean markout (bps) at `now_ms`, None once expired.
+    fn mean_bps(&self, now_ms: u64, half_life_ms: u64) -> Option<Decimal> {
+        let decay = self.decay(now_ms, half_life_ms);
+        let weight = self.weight * decay;
+        if self.fills == 0 || weight < MARKOUT_MIN_WEIGHT {
+            return None;
+        }
+        Decimal::from_f64(self.sum_bps * decay / weight.max(1.0))
+    }
+}
+
 /// Phase C: Per-market oracle velocity tracker.
 #[derive(Debug)]
 struct OracleVelocityTracker {
@@ -183,6 +287,29 @@ pub struct QuoteManager {
     wick_tracker: WickTracker,
     /// Phase C: Per-market oracle velocity tracker.
     velocity_trackers: HashMap<MarketKey, OracleVelocityTracker>,
+    /// Per-market smoothed optimal wick (bps) and its update time (ms).
+    smoothed_wicks: HashMap<MarketKey, (f64, u64)>,
+    /// Per-market fill markouts (toxic flow skew and fading).
+    markouts: HashMap<MarketKey, MarkoutTracker>,
+    /// Markets faded for toxic flow, with the fade end (ms).
+    toxic_fades: HashMap<MarketKey, u64>,
+    /// Per-market tick size (quote hysteresis in ticks).
+    tick_sizes: HashMap<MarketKey, Price>,
+    /// Per-market quote model (default `quote_model`).
+    quote_models: HashMap<MarketKey, QuoteModel>,
+    /// Oids of tracked quotes missing from the last reconciliation listing.
+    reconcile_missing: HashSet<u64>,
+    /// Time of the last inventory decay hedge per market (ms).
+    decay_hedges: HashMap<MarketKey, u64>,
+    /// Cloids of recent decay hedges (MM orders), with their time (ms).
+    hedge_orders: HashMap<ClientOrderId, u64>,
+    /// Per-market lot size (hedge sizes).
+    lot_sizes: HashMap<MarketKey, Size>,
+    /// Fill probability per quote offset bucket (None = disabled).
+    fill_stats: Option<FillProbabilityTracker>,
+    /// Spread multiplier set by the bot (e.g. latency degradation), applied
+    /// on top of adverse selection widening.
+    external_spread_multiplier: Decimal,
 }
 
 impl QuoteManager {
@@ -194,6 +321,12 @@ impl QuoteManager {
             config.wick_cache_ttl_ms,
             config.breakpoint_min_jump_ratio,
         );
+        let fill_stats = (config.fill_stats_bucket_bps > Decimal::ZERO).then(|| {
+            FillProbabilityTracker::new(
+                config.fill_stats_bucket_bps,
+                config.quote_markout_horizon_ms().unwrap_or_default(),
+            )
+        });
         Self {
             config,
             states: HashMap::new(),
@@ -202,6 +335,17 @@ impl QuoteManager {
             adverse_selection: HashMap::new(),
             wick_tracker,
             velocity_trackers: HashMap::new(),
+            smoothed_wicks: HashMap::new(),
+            markouts: HashMap::new(),
+            toxic_fades: HashMap::new(),
+            tick_sizes: HashMap::new(),
+            quote_models: HashMap::new(),
+            reconcile_missing: HashSet::new(),
+            decay_hedges: HashMap::new(),
+            hedge_orders: HashMap::new(),
+            lot_sizes: HashMap::new(),
+            fill_stats,
+            external_spread_multiplier: dec!(1),
         }
     }
 
@@ -237,14 +381,43 @@ impl QuoteManager {
             return self.build_emergency_flatten(market, inventory, mark_price, now_ms);
         }
 
+        // Inventory time decay: hedge inventory that sat unchanged too long
+        let held_for_ms = inventory.held_for_ms(&market, now_ms);
+        if let Some(hedge) =
+            self.build_decay_hedge(market, inventory, held_for_ms, mark_price, now_ms)
+        {
+            return Some(MakerAction::PlaceOrders(vec![hedge]));
+        }
+        let decay_boost = inventory_decay_boost(held_for_ms, &self.config);
+
+        // Lean harder against inventory built from toxic (negative markout) fills
+        let toxicity_boost = self.markout_skew_boost(&market, now_ms);
+
+        // Toxic flow: widen or pull the market for the cooldown window
+        let fading = self.update_toxic_fade(market, now_ms);
+        if fading && self.config.toxic_fade_action == ToxicFadeAction::Pull {
+            return self.pull_market(market, now_ms);
+        }
+
         // P2-3: Get spread multiplier before borrowing states
-        let spread_multiplier =
-            Self::calc_spread_multiplier(&self.adverse_selection, &market, &self.config);
+        let mut spread_multiplier =
+            Self::calc_spread_multiplier(&self.adverse_selection, &market, &self.config)
+                * self.external_spread_multiplier;
+        if fading {
+            spread_multiplier *= self.config.toxic_fade_spread_multiplier;
+        }
 
         // P3-1: Record oracle price for wick tracking and get volatility stats
         self.wick_tracker
             .record_oracle(market, oracle_price.inner(), now_ms);
-        let vol_stats = self.wick_tracker.get_stats(&market, now_ms);
+        if let Some(ref mut fill_stats) = self.fill_stats {
+            fill_stats.on_oracle(market, oracle_price, now_ms);
+        }
+        let mut vol_stats = self.wick_tracker.get_stats(&market, now_ms);
+        if vol_stats.is_valid {
+            vol_stats.optimal_wick_bps =
+                self.smooth_optimal_wick(market, vol_stats.optimal_wick_bps, now_ms);
+        }
         let vol_ref = if self.config.dynamic_offset_enabled {
             Some(&vol_stats)
         } else {
@@ -263,20 +436,86 @@ impl QuoteManager {
             Decimal::ZERO
         };
 
+        // Mark/oracle divergence guard: quoting is dangerous while they disagree
+        let divergence_bps = if oracle_price.is_zero() {
+            Decimal::ZERO
+        } else {
+            ((mark_price.inner() - oracle_price.inner()) / oracle_price.inner() * dec!(10000)).abs()
+        };
+        if !self.config.max_mark_oracle_divergence_bps.is_zero()
+            && divergence_bps > self.config.max_mark_oracle_divergence_bps
+        {
+            debug!(
+                market = %market,
+                divergence_bps = %divergence_bps.round_dp(2),
+                threshold = %self.config.max_mark_oracle_divergence_bps,
+                "MM quotes pulled: mark/oracle divergence"
+            );
+            return self.pull_market(market, now_ms);
+        }
+
+        // Quote center: oracle, mark, or a blend (`quote_center_mark_weight`)
+        let mark_weight = self
+            .config
+            .quote_center_mark_weight
+            .max(Decimal::ZERO)
+            .min(Decimal::ONE);
+        let quote_center = if mark_weight.is_zero() || mark_price.is_zero() {
+            oracle_price
+        } else {
+            Price::new(
+                oracle_price.inner() + (mark_price.inner() - oracle_price.inner()) * mark_weight,
+            )
+        };
+
         // P2-1: Compute filtered quotes before borrowing states
         let tif = if self.config.use_alo {
             TimeInForce::AddLiquidityOnly
         } else {
             TimeInForce::GoodTilCancelled
         };
-        let quotes = compute_quotes(
-            oracle_price,
-            inventory_ratio,
-            &self.config,
-            spread_multiplier,
-            vol_ref,
-            velocity_trend,
-        );
+        // Learned offsets replace the level layout once every level has one
+        let learned_offsets = if self.config.fill_stats_offsets && self.config.ladder.is_none() {
+            let offsets = self.suggested_offsets(&market);
+            if offsets.len() == self.config.num_levels as usize {
+                offsets
+            } else {
+                Vec::new()
+            }
+        } else {
+            Vec::new()
+        };
+        let quotes = match self.quote_model(&market) {
+            QuoteModel::Offset if !learned_offsets.is_empty() => compute_quotes_at_offsets(
+                quote_center,
+                inventory_ratio,
+                &self.config,
+                spread_multiplier,
+                &learned_offsets,
+                velocity_trend,
+                toxicity_boost * decay_boost,
+            ),
+            QuoteModel::Offset => compute_quotes(
+                quote_center,
+                inventory_ratio,
+                &self.config,
+                spread_multiplier,
+                vol_ref,
+                velocity_trend,
+                toxicity_boost * decay_boost,
+            ),
+            // Toxicity and time decay shift the reservation price as for a
+            // larger inventory
+            QuoteModel::AvellanedaStoikov => compute_quotes_as(
+                quote_center,
+                inventory_ratio,
+                &self.config,
+                spread_multiplier,
+                Some(&vol_stats),
+                velocity_trend,
+                toxicity_boost * decay_boost,
+            ),
+        };
         let filtered = Self::apply_inventory_warn(&self.config, quotes, inventory_ratio);
         let orders_with_levels = Self::make_orders(market, &filtered, mark_price, tif, now_ms);
         // Build level map before extracting orders (for ActiveQuote tracking)
@@ -292,14 +531,66 @@ impl QuoteManager {
             .entry(market)
             .or_insert_with(MarketQuoteState::new);
 
+        let tick_size = self.tick_sizes.get(&market).copied();
+
+        // Ladder quoting: requote only the levels that drifted
+        if self.config.ladder.is_some() {
+            let action = Self::diff_levels(
+                market,
+                state,
+                &self.config,
+                new_orders,
+                &level_map,
+                tick_size,
+                now_ms,
+            )?;
+            Self::track_pending_cancels(&mut self.pending_cancels, market, &action, now_ms);
+            state.last_center = Some(quote_center);
+            state.last_requote_ms = now_ms;
+            return self.finish_requote(market, quote_center, action, now_ms);
+        }
+
         // Check if requote is needed
-        if !Self::check_requote(&self.config, state, oracle_price, now_ms) {
+        if !Self::check_requote(&self.config, state, quote_center, now_ms) {
             return None;
         }
 
         // Check if we have active quotes to cancel
         let has_active = !state.bids.is_empty() || !state.asks.is_empty();
 
+        // Hysteresis: move only the levels that drifted past it and rested
+        // their minimum interval
+        let hysteresis = !self.config.requote_hysteresis_bps.is_zero()
+            || self.config.requote_hysteresis_ticks > 0
+            || self.config.min_level_requote_interval_ms > 0;
+        if has_active && hysteresis {
+            state.last_center = Some(quote_center);
+            state.last_requote_ms = now_ms;
+            let Some(action) = Self::diff_levels(
+                market,
+                state,
+                &self.config,
+                new_orders,
+                &level_map,
+                tick_size,
+                now_ms,
+            ) else {
+                debug!(market = %market, "MM requote skipped: quotes within hysteresis");
+                return None;
+            };
+            Self::track_pending_cancels(&mut self.pending_cancels, market, &action, now_ms);
+            return self.finish_requote(market, quote_center, action, now_ms);
+        }
+
+        if has_active && self.config.amend_quotes {
+            if let Some(modifies) = Self::build_modifies(state, &new_orders, &level_map) {
+                state.last_center = Some(quote_center);
+                state.last_requote_ms = now_ms;
+                let action = MakerAction::Modify(modifies);
+                return self.finish_requote(market, quote_center, action, now_ms);
+            }
+        }
+
         let action = if has_active {
             let cancels = Self::build_cancels_static(market, state);
 
@@ -332,7 +623,7 @@ impl QuoteManager {
         };
 
         // Update state
-        state.last_oracle = Some(oracle_price);
+        state.last_center = Some(quote_center);
         state.last_requote_ms = now_ms;
 
         // Track new orders as active quotes (with level from QuoteEngine)
@@ -359,7 +650,7 @@ impl QuoteManager {
             }
         }
 
-        Some(action)
+        self.finish_requote(market, quote_center, action, now_ms)
     }
 
     /// Record that an order has been confirmed resting with an oid.
@@ -375,6 +666,62 @@ impl QuoteManager {
         }
     }
 
+    /// Handle an amendment the exchange (or the executor) did not apply.
+    ///
+    /// The amended quote is dropped and replaced by cancel-replace: the
+    /// original order is cancelled and the target order placed under a fresh
+    /// cloid. If the quote is no longer tracked (filled or cancelled in the
+    /// meantime) only the cancel of the original order is returned.
+    pub fn on_modify_rejected(
+        &mut self,
+        modify: &PendingModify,
+        now_ms: u64,
+    ) -> Option<MakerAction> {
+        let market = modify.market();
+        // Not tracked for P2-2: the usual cause of a rejected amendment is
+        // that the original order already left the book, so this cancel may
+        // never be acknowledged.
+        let cancel = PendingCancel::new(market, modify.oid, now_ms);
+
+        let state = self.states.get_mut(&market);
+        let quote = state.and_then(|state| {
+            let quotes = match modify.order.side {
+                OrderSide::Buy => &mut state.bids,
+                OrderSide::Sell => &mut state.asks,
+            };
+            let idx = quotes.iter().position(|q| q.cloid == modify.order.cloid)?;
+            Some((quotes.remove(idx), quotes))
+        });
+
+        let Some((old, quotes)) = quote else {
+            debug!(market = %market, oid = modify.oid, "Modify rejected for untracked quote");
+            return Some(MakerAction::CancelOrders(vec![cancel]));
+        };
+
+        let mut order = modify.order.clone();
+        order.cloid = ClientOrderId::new();
+        order.created_at = now_ms;
+        quotes.push(ActiveQuote {
+            cloid: order.cloid.clone(),
+            oid: None,
+            side: order.side,
+            price: order.price,
+            size: order.size,
+            level: old.level,
+            placed_at_ms: now_ms,
+        });
+        info!(
+            market = %market,
+            oid = modify.oid,
+            "Quote amendment rejected, falling back to cancel-replace"
+        );
+
+        Some(MakerAction::CancelAndReplace {
+            cancels: vec![cancel],
+            new_orders: vec![order],
+        })
+    }
+
     /// Record that a quote was filled (remove from active quotes).
     ///
     /// Returns an optional counter-order (mean reversion) if `counter_order_enabled`.
@@ -402,6 +749,10 @@ impl QuoteManager {
             state.asks.retain(|q| &q.cloid != cloid);
         }
 
+        if let (Some(fill_stats), Some(price)) = (self.fill_stats.as_mut(), fill_price) {
+            fill_stats.record_fill(cloid, price, now_ms);
+        }
+
         // P2-3: Track adverse selection
         if let Some(side) = fill_side {
             self.adverse_selection
@@ -547,6 +898,9 @@ impl QuoteManager {
     /// Used by P2-4 to distinguish MM fills from taker fills,
     /// so that MM P&L is excluded from the taker drawdown gate.
     pub fn is_mm_order(&self, cloid: &ClientOrderId) -> bool {
+        if self.hedge_orders.contains_key(cloid) {
+            return true;
+        }
         for state in self.states.values() {
             for quote in state.bids.iter().chain(state.asks.iter()) {
                 if &quote.cloid == cloid {
@@ -610,10 +964,140 @@ impl QuoteManager {
         self.pending_cancels.clear();
         self.adverse_selection.clear();
         self.velocity_trackers.clear();
+        self.smoothed_wicks.clear();
+        self.toxic_fades.clear();
+        self.decay_hedges.clear();
 
         actions
     }
 
+    /// Generate cancel + flatten for one market, e.g. when its session ends,
+    /// and forget its quote state.
+    pub fn shutdown_market(
+        &mut self,
+        market: MarketKey,
+        inventory: &InventoryManager,
+        mark_price: Price,
+        now_ms: u64,
+    ) -> Option<MakerAction> {
+        let action = self.build_emergency_flatten(market, inventory, mark_price, now_ms);
+
+        self.states.remove(&market);
+        self.pending_cancels.retain(|c| c.market != market);
+        self.adverse_selection.remove(&market);
+        self.velocity_trackers.remove(&market);
+        self.smoothed_wicks.remove(&market);
+        self.toxic_fades.remove(&market);
+        self.decay_hedges.remove(&market);
+
+        action
+    }
+
+    /// Pull every quote of `market`, e.g. while its underlying is closed.
+    ///
+    /// Returns the cancels of the resting quotes, if any.
+    pub fn pull_market(&mut self, market: MarketKey, now_ms: u64) -> Option<MakerAction> {
+        let state = self.states.get_mut(&market)?;
+        let cancels = Self::build_cancels_static(market, state);
+        state.clear();
+        if cancels.is_empty() {
+            return None;
+        }
+        for c in &cancels {
+            self.pending_cancels.push(PendingCancelInfo {
+                oid: c.oid,
+                market,
+                sent_at_ms: now_ms,
+            });
+        }
+        Some(MakerAction::CancelOrders(cancels))
+    }
+
+    /// Reconcile tracked quotes with the exchange's open orders.
+    ///
+    /// `open_orders` is the listing of the MM markets; orders for which
+    /// `is_foreign` holds (e.g. taker exits) are left alone. Unknown orders
+    /// are returned as cancels, unacked quotes matching an order get its
+    /// oid, and acked quotes missing from two listings in a row (filled or
+    /// cancelled unnoticed) are forgotten.
+    ///
+    /// Quotes are matched by cloid. A listing without cloid falls back to
+    /// the unacked quote of the same side nearest in price within one tick,
+    /// since quote prices are only rounded when signed.
+    pub fn reconcile(
+        &mut self,
+        open_orders: &[ExchangeOrder],
+        is_foreign: impl Fn(&ExchangeOrder) -> bool,
+        now_ms: u64,
+    ) -> ReconcileReport {
+        let mut report = ReconcileReport::default();
+        let mut known: HashSet<u64> = self.pending_cancels.iter().map(|c| c.oid).collect();
+        known.extend(
+            self.states
+                .values()
+                .flat_map(|s| s.bids.iter().chain(s.asks.iter()))
+                .filter_map(|q| q.oid),
+        );
+
+        for order in open_orders {
+            if known.contains(&order.oid) || is_foreign(order) {
+                continue;
+            }
+            let tick = self
+                .tick_sizes
+                .get(&order.market)
+                .map_or(Decimal::ZERO, |t| t.inner());
+            let unacked = self.states.get_mut(&order.market).and_then(|state| {
+                let mut candidates = state
+                    .bids
+                    .iter_mut()
+                    .chain(state.asks.iter_mut())
+                    .filter(|q| q.oid.is_none());
+                match order.cloid {
+                    Some(ref cloid) => candidates.find(|q| q.cloid == *cloid),
+                    None => candidates
+                        .filter(|q| q.side == order.side)
+                        .map(|q| ((q.price.inner() - order.price.inner()).abs(), q))
+                        .filter(|(distance, _)| *distance < tick || distance.is_zero())
+                        .min_by_key(|(distance, _)| *distance)
+                        .map(|(_, q)| q),
+                }
+            });
+            if let Some(quote) = unacked {
+                quote.oid = Some(order.oid);
+                report.registered += 1;
+                info!(market = %order.market, oid = order.oid, "MM reconcile: registered unacked quote");
+            } else {
+                report
+                    .unknown_cancels
+                    .push(PendingCancel::new(order.market, order.oid, now_ms));
+                warn!(market = %order.market, oid = order.oid, "MM reconcile: cancelling unknown order");
+            }
+        }
+
+        let listed: HashSet<u64> = open_orders.iter().map(|o| o.oid).collect();
+        let mut missing = HashSet::new();
+        for (market, state) in &mut self.states {
+            for quotes in [&mut state.bids, &mut state.asks] {
+                quotes.retain(|q| match q.oid {
+                    Some(oid) if !listed.contains(&oid) => {
+                        if self.reconcile_missing.contains(&oid) {
+                            report.dropped += 1;
+                            warn!(market = %market, oid, "MM reconcile: dropped quote gone from the book");
+                            return false;
+                        }
+                        missing.insert(oid);
+                        true
+                    }
+                    _ => true,
+                });
+            }
+        }
+        self.reconcile_missing = missing;
+
+        report
+    }
+
     /// Check if any market has active quotes.
     pub fn has_active_quotes(&self) -> bool {
         self.states
@@ -629,6 +1113,46 @@ impl QuoteManager {
             .unwrap_or(0)
     }
 
+    /// Set the tick size of a market, used by the tick-based quote hysteresis.
+    pub fn set_tick_size(&mut self, market: MarketKey, tick_size: Price) {
+        self.tick_sizes.insert(market, tick_size);
+    }
+
+    /// Set the lot size of a market, used to round hedge sizes.
+    pub fn set_lot_size(&mut self, market: MarketKey, lot_size: Size) {
+        self.lot_sizes.insert(market, lot_size);
+    }
+
+    /// Set the quote model of a market (see `quote_model_markets`).
+    pub fn set_quote_model(&mut self, market: MarketKey, model: QuoteModel) {
+        if self.quote_models.insert(market, model) != Some(model) {
+            info!(market = %market, model = ?model, "MM quote model set");
+        }
+    }
+
+    /// Quote model of a market.
+    pub fn quote_model(&self, market: &MarketKey) -> QuoteModel {
+        self.quote_models
+            .get(market)
+            .copied()
+            .unwrap_or(self.config.quote_model)
+    }
+
+    /// Set the external spread multiplier (values below 1 are treated as 1).
+    ///
+    /// Takes effect at the next requote.
+    pub fn set_external_spread_multiplier(&mut self, multiplier: Decimal) {
+        let multiplier = multiplier.max(dec!(1));
+        if multiplier != self.external_spread_multiplier {
+            info!(
+                from = %self.external_spread_multiplier,
+                to = %multiplier,
+                "External spread multiplier changed"
+            );
+            self.external_spread_multiplier = multiplier;
+        }
+    }
+
     /// P2-2: Whether quoting is halted due to stale cancels.
     pub fn is_stale_halted(&self) -> bool {
         self.stale_halt
@@ -663,8 +1187,158 @@ impl QuoteManager {
             .count()
     }
 
+    /// Feed fill markouts resolved at `now_ms` by
+    /// [`crate::MarkoutAnalytics`]. Samples at the shortest markout horizon
+    /// drive the markout skew and toxic flow fading; others are ignored.
+    pub fn record_markouts(&mut self, samples: &[MarkoutSample], now_ms: u64) {
+        let Some(horizon_ms) = self.config.quote_markout_horizon_ms() else {
+            return;
+        };
+        if !self.markouts_enabled() {
+            return;
+        }
+        let half_life_ms = self.config.markout_half_life_secs * 1000;
+        for sample in samples.iter().filter(|s| s.horizon_ms == horizon_ms) {
+            self.markouts.entry(sample.market).or_default().record(
+                sample.mid_bps,
+                now_ms,
+                half_life_ms,
+            );
+        }
+    }
+
+    /// Whether quoting reads fill markouts (markout skew or toxic fading on).
+    fn markouts_enabled(&self) -> bool {
+        !self.config.markout_skew_factor.is_zero()
+            || !self.config.toxic_fade_threshold_bps.is_zero()
+    }
+
+    /// Mean recent fill markout (bps, positive = favorable) of a market at
+    /// `now_ms`. None without recent fills.
+    pub fn mean_markout_bps(&self, market: &MarketKey, now_ms: u64) -> Option<Decimal> {
+        let half_life_ms = self.config.markout_half_life_secs * 1000;
+        self.markouts.get(market)?.mean_bps(now_ms, half_life_ms)
+    }
+
+    /// Fill probability and markout per quote offset bucket (None =
+    /// disabled).
+    pub fn fill_stats(&self) -> Option<&FillProbabilityTracker> {
+        self.fill_stats.as_ref()
+    }
+
+    /// The most profitable quote offsets (bps) for `market`, up to one per
+    /// level, from the fill statistics. Empty until enough data.
+    pub fn suggested_offsets(&self, market: &MarketKey) -> Vec<Decimal> {
+        self.fill_stats
+            .as_ref()
+            .map_or_else(Vec::new, |fill_stats| {
+                fill_stats.best_offsets(
+                    market,
+                    self.config.num_levels as usize,
+                    self.config.fill_stats_min_quotes,
+                )
+            })
+    }
+
+    /// Markets faded for toxic flow, with the fade end (Unix ms).
+    pub fn toxic_fades(&self, now_ms: u64) -> Vec<(MarketKey, u64)> {
+        self.toxic_fades
+            .iter()
+            .filter(|(_, &until_ms)| now_ms < until_ms)
+            .map(|(market, &until_ms)| (*market, until_ms))
+            .collect()
+    }
+
+    /// Smoothed optimal wick (bps) of a market, the input of the dynamic L0.
+    pub fn smoothed_wick_bps(&self, market: &MarketKey) -> Option<f64> {
+        self.smoothed_wicks.get(market).map(|(bps, _)| *bps)
+    }
+
     // === Private helpers ===
 
+    /// Inventory skew multiplier: 1 + `markout_skew_factor` while the mean
+    /// recent markout is negative.
+    fn markout_skew_boost(&self, market: &MarketKey, now_ms: u64) -> Decimal {
+        if self.config.markout_skew_factor.is_zero() {
+            return dec!(1);
+        }
+        match self.mean_markout_bps(market, now_ms) {
+            Some(mean) if mean < Decimal::ZERO => dec!(1) + self.config.markout_skew_factor,
+            _ => dec!(1),
+        }
+    }
+
+    /// Start, keep or end the toxic flow fade of a market. Returns whether
+    /// the market is faded.
+    ///
+    /// A fade starts when at least `toxic_fade_min_fills` markouts average
+    /// -`toxic_fade_threshold_bps` or worse, and lasts the cooldown. The
+    /// markouts that triggered it are discarded, so a new fade needs fresh
+    /// evidence.
+    fn update_toxic_fade(&mut self, market: MarketKey, now_ms: u64) -> bool {
+        if self.config.toxic_fade_threshold_bps.is_zero() {
+            return false;
+        }
+        if let Some(&until_ms) = self.toxic_fades.get(&market) {
+            if now_ms < until_ms {
+                return true;
+            }
+            self.toxic_fades.remove(&market);
+            self.force_requote(&market);
+            info!(market = %market, "MM toxic flow fade ended");
+        }
+
+        let Some(tracker) = self.markouts.get_mut(&market) else {
+            return false;
+        };
+        if tracker.fills < self.config.toxic_fade_min_fills.max(1) {
+            return false;
+        }
+        let half_life_ms = self.config.markout_half_life_secs * 1000;
+        let Some(mean) = tracker.mean_bps(now_ms, half_life_ms) else {
+            return false;
+        };
+        if mean > -self.config.toxic_fade_threshold_bps {
+            return false;
+        }
+        *tracker = MarkoutTracker::default();
+        let until_ms = now_ms + self.config.toxic_fade_cooldown_secs * 1000;
+        self.toxic_fades.insert(market, until_ms);
+        self.force_requote(&market);
+        warn!(
+            market = %market,
+            mean_markout_bps = %mean.round_dp(2),
+            action = ?self.config.toxic_fade_action,
+            cooldown_secs = self.config.toxic_fade_cooldown_secs,
+            "MM toxic flow: fading market"
+        );
+        true
+    }
+
+    /// Requote a market at its next update regardless of the requote
+    /// interval and price move.
+    fn force_requote(&mut self, market: &MarketKey) {
+        if let Some(state) = self.states.get_mut(market) {
+            state.last_center = None;
+        }
+    }
+
+    /// Fold a fresh optimal wick into the market's EMA
+    /// (`dynamic_offset_half_life_secs`) and return the smoothed value.
+    fn smooth_optimal_wick(&mut self, market: MarketKey, optimal_bps: f64, now_ms: u64) -> f64 {
+        let half_life_ms = self.config.dynamic_offset_half_life_secs * 1000;
+        let smoothed = match self.smoothed_wicks.get(&market) {
+            Some(&(prev, at_ms)) if half_life_ms > 0 => {
+                let elapsed = now_ms.saturating_sub(at_ms) as f64;
+                let alpha = 1.0 - 0.5_f64.powf(elapsed / half_life_ms as f64);
+                prev + alpha * (optimal_bps - prev)
+            }
+            _ => optimal_bps,
+        };
+        self.smoothed_wicks.insert(market, (smoothed, now_ms));
+        smoothed
+    }
+
     /// P2-1: Filter quotes based on inventory warning threshold.
     /// When inventory is above warn_ratio, remove quotes on the side that increases exposure.
     fn apply_inventory_warn(
@@ -749,6 +1423,78 @@ impl QuoteManager {
         })
     }
 
+    /// Reduce-only IOC hedging `inventory_decay_hedge_fraction` of the
+    /// inventory once it sat unchanged for `inventory_decay_hedge_after_secs`,
+    /// at most once per period while it keeps sitting. The size is rounded
+    /// down to the lot; hedges under the exchange minimum order value are
+    /// skipped.
+    fn build_decay_hedge(
+        &mut self,
+        market: MarketKey,
+        inventory: &InventoryManager,
+        held_for_ms: Option<u64>,
+        mark_price: Price,
+        now_ms: u64,
+    ) -> Option<PendingOrder> {
+        let period_ms = self.config.inventory_decay_hedge_after_secs * 1000;
+        if period_ms == 0 || held_for_ms? < period_ms || mark_price.is_zero() {
+            return None;
+        }
+        if self
+            .decay_hedges
+            .get(&market)
+            .is_some_and(|&at| now_ms.saturating_sub(at) < period_ms)
+        {
+            return None;
+        }
+
+        let net = inventory.net_size(&market);
+        let fraction = self
+            .config
+            .inventory_decay_hedge_fraction
+            .max(Decimal::ZERO)
+            .min(Decimal::ONE);
+        let lot_size = self.lot_sizes.get(&market).copied().unwrap_or(Size::ZERO);
+        let size = Size::new(net.abs() * fraction).round_to_lot(lot_size);
+        if size.is_zero() || size.inner() * mark_price.inner() < MIN_ORDER_NOTIONAL_USD {
+            return None;
+        }
+        let side = if net > Decimal::ZERO {
+            OrderSide::Sell
+        } else {
+            OrderSide::Buy
+        };
+        let slippage = Decimal::from(self.config.flatten_slippage_bps) / dec!(10000);
+        let price = match side {
+            OrderSide::Buy => Price::new(mark_price.inner() * (Decimal::ONE + slippage)),
+            OrderSide::Sell => Price::new(mark_price.inner() * (Decimal::ONE - slippage)),
+        };
+
+        self.decay_hedges.insert(market, now_ms);
+        // Keep the cloid an MM order until the IOC's fills are in
+        self.hedge_orders
+            .retain(|_, &mut at| now_ms.saturating_sub(at) < HEDGE_ORDER_TTL_MS);
+        let cloid = ClientOrderId::new();
+        self.hedge_orders.insert(cloid.clone(), now_ms);
+        info!(
+            market = %market,
+            net = %net,
+            hedge_size = %size,
+            held_secs = held_for_ms.unwrap_or(0) / 1000,
+            "MM inventory decay hedge"
+        );
+        Some(PendingOrder::with_tif(
+            cloid,
+            market,
+            side,
+            price,
+            size,
+            true,
+            now_ms,
+            TimeInForce::ImmediateOrCancel,
+        ))
+    }
+
     /// P2-2: Check for stale (unacknowledged) cancels.
     ///
     /// Auto-expires pending cancels that exceed `stale_cancel_max_age_ms`
@@ -837,11 +1583,11 @@ impl QuoteManager {
     fn check_requote(
         config: &MakerConfig,
         state: &MarketQuoteState,
-        oracle_price: Price,
+        center: Price,
         now_ms: u64,
     ) -> bool {
         // First quote: always place
-        let last_oracle = match state.last_oracle {
+        let last_center = match state.last_center {
             Some(p) => p,
             None => return true,
         };
@@ -852,14 +1598,14 @@ impl QuoteManager {
             return true;
         }
 
-        // Price-based: requote if oracle moved significantly
-        let oracle_change_bps = if last_oracle.inner().is_zero() {
+        // Price-based: requote if the quote center moved significantly
+        let center_change_bps = if last_center.inner().is_zero() {
             dec!(0)
         } else {
-            ((oracle_price.inner() - last_oracle.inner()) / last_oracle.inner() * dec!(10000)).abs()
+            ((center.inner() - last_center.inner()) / last_center.inner() * dec!(10000)).abs()
         };
 
-        oracle_change_bps >= config.min_requote_change_bps
+        center_change_bps >= config.min_requote_change_bps
     }
 
     /// Build pending orders from quote levels.
@@ -924,37 +1670,372 @@ impl QuoteManager {
         orders
     }
 
-    fn build_cancels_static(market: MarketKey, state: &MarketQuoteState) -> Vec<PendingCancel> {
-        let now = chrono::Utc::now().timestamp_millis() as u64;
-        state
-            .bids
-            .iter()
-            .chain(state.asks.iter())
-            .filter_map(|q| q.oid.map(|oid| PendingCancel::new(market, oid, now)))
-            .collect()
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use hip3_core::{AssetId, DexId};
-    use rust_decimal_macros::dec;
-
-    fn mk() -> MarketKey {
-        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    /// Whether a quote should move to `new_price`: it has rested
+    /// `min_level_requote_interval_ms` and its drift reaches either
+    /// `min_bps`/`requote_hysteresis_bps` or `requote_hysteresis_ticks`
+    /// (any drift with both off).
+    fn level_needs_requote(
+        config: &MakerConfig,
+        quote: &ActiveQuote,
+        new_price: Price,
+        min_bps: Decimal,
+        tick_size: Option<Price>,
+        now_ms: u64,
+    ) -> bool {
+        if now_ms.saturating_sub(quote.placed_at_ms) < config.min_level_requote_interval_ms {
+            return false;
+        }
+        let old = quote.price.inner();
+        if old.is_zero() {
+            return true;
+        }
+        let diff = (new_price.inner() - old).abs();
+        let min_bps = min_bps.max(config.requote_hysteresis_bps);
+        let min_ticks = tick_size
+            .filter(|_| config.requote_hysteresis_ticks > 0)
+            .map(|tick| tick.inner() * Decimal::from(config.requote_hysteresis_ticks));
+        let bps_reached = min_bps > Decimal::ZERO && diff / old * dec!(10000) >= min_bps;
+        let ticks_reached = min_ticks.is_some_and(|min| diff >= min);
+        if min_bps.is_zero() && min_ticks.is_none() {
+            return true;
+        }
+        bps_reached || ticks_reached
     }
 
-    fn test_config() -> MakerConfig {
-        MakerConfig {
-            enabled: true,
-            num_levels: 1,
-            min_offset_bps: dec!(20),
-            size_per_level_usd: dec!(10),
-            requote_interval_ms: 2000,
-            min_requote_change_bps: dec!(5),
-            use_alo: true,
-            ..Default::default()
+    /// P2-2: Track the cancels of `action` as pending.
+    fn track_pending_cancels(
+        pending_cancels: &mut Vec<PendingCancelInfo>,
+        market: MarketKey,
+        action: &MakerAction,
+        now_ms: u64,
+    ) {
+        if let MakerAction::CancelOrders(cancels) | MakerAction::CancelAndReplace { cancels, .. } =
+            action
+        {
+            for c in cancels {
+                pending_cancels.push(PendingCancelInfo {
+                    oid: c.oid,
+                    market,
+                    sent_at_ms: now_ms,
+                });
+            }
+        }
+    }
+
+    /// Pair each resting quote with the new order for the same side and level.
+    ///
+    /// Returns None (use cancel-replace) unless every active quote is resting
+    /// and the new quote set has exactly the same side/level layout. On
+    /// success the active quotes are updated to the amended cloid/price/size.
+    fn build_modifies(
+        state: &mut MarketQuoteState,
+        new_orders: &[PendingOrder],
+        level_map: &HashMap<ClientOrderId, u32>,
+    ) -> Option<Vec<PendingModify>> {
+        let active = state.bids.len() + state.asks.len();
+        if active != new_orders.len() {
+            return None;
+        }
+
+        let mut pairs = Vec::with_capacity(active);
+        for order in new_orders {
+            let level = level_map.get(&order.cloid).copied().unwrap_or(0);
+            let quotes = match order.side {
+                OrderSide::Buy => &state.bids,
+                OrderSide::Sell => &state.asks,
+            };
+            let idx = quotes.iter().position(|q| q.level == level)?;
+            let oid = quotes[idx].oid?;
+            pairs.push((order, idx, oid));
+        }
+
+        let mut modifies = Vec::with_capacity(active);
+        for (order, idx, oid) in pairs {
+            let quote = match order.side {
+                OrderSide::Buy => &mut state.bids[idx],
+                OrderSide::Sell => &mut state.asks[idx],
+            };
+            modifies.push(PendingModify::new(oid, quote.cloid.clone(), order.clone()));
+            quote.cloid = order.cloid.clone();
+            quote.oid = None;
+            quote.price = order.price;
+            quote.size = order.size;
+            quote.placed_at_ms = order.created_at;
+        }
+        Some(modifies)
+    }
+
+    /// Diff the desired levels (ladder, or the plain levels under quote
+    /// hysteresis) against the active quotes of a market.
+    ///
+    /// Levels within their requote threshold (ladder only) and the quote
+    /// hysteresis are kept. Drifted levels are amended (`amend_quotes`, when no level is
+    /// added or removed) or cancel-replaced; missing levels are placed and
+    /// surplus ones cancelled. Quotes not yet resting are left alone until
+    /// their oid is known. The active quotes are updated to the result.
+    fn diff_levels(
+        market: MarketKey,
+        state: &mut MarketQuoteState,
+        config: &MakerConfig,
+        new_orders: Vec<PendingOrder>,
+        level_map: &HashMap<ClientOrderId, u32>,
+        tick_size: Option<Price>,
+        now_ms: u64,
+    ) -> Option<MakerAction> {
+        let ladder = config.ladder.as_ref();
+        let level_of = |order: &PendingOrder| level_map.get(&order.cloid).copied().unwrap_or(0);
+        let desired: HashSet<(OrderSide, u32)> =
+            new_orders.iter().map(|o| (o.side, level_of(o))).collect();
+
+        // Drifted levels: (new order, resting oid, resting cloid)
+        let mut replaced = Vec::new();
+        let mut places = Vec::new();
+        for order in new_orders {
+            let level = level_of(&order);
+            let (quotes, side) = match order.side {
+                OrderSide::Buy => (&state.bids, ladder.map(|l| &l.bids)),
+                OrderSide::Sell => (&state.asks, ladder.map(|l| &l.asks)),
+            };
+            let Some(quote) = quotes.iter().find(|q| q.level == level) else {
+                places.push(order);
+                continue;
+            };
+            let threshold = side.map_or(Decimal::ZERO, |side| {
+                side.requote_bps(level, config.min_requote_change_bps)
+            });
+            let drifted =
+                Self::level_needs_requote(config, quote, order.price, threshold, tick_size, now_ms);
+            if let (true, Some(oid)) = (drifted, quote.oid) {
+                replaced.push((order, oid, quote.cloid.clone()));
+            }
+        }
+
+        // Surplus levels (ladder shrank, side pulled by the inventory warn)
+        let mut cancels = Vec::new();
+        for quotes in [&mut state.bids, &mut state.asks] {
+            quotes.retain(|q| match q.oid {
+                Some(oid) if !desired.contains(&(q.side, q.level)) => {
+                    cancels.push(PendingCancel::new(market, oid, now_ms));
+                    false
+                }
+                _ => true,
+            });
+        }
+
+        if replaced.is_empty() && cancels.is_empty() && places.is_empty() {
+            return None;
+        }
+
+        if config.amend_quotes && cancels.is_empty() && places.is_empty() {
+            let mut modifies = Vec::with_capacity(replaced.len());
+            for (order, oid, cloid) in replaced {
+                let quotes = match order.side {
+                    OrderSide::Buy => &mut state.bids,
+                    OrderSide::Sell => &mut state.asks,
+                };
+                if let Some(quote) = quotes.iter_mut().find(|q| q.cloid == cloid) {
+                    quote.cloid = order.cloid.clone();
+                    quote.oid = None;
+                    quote.price = order.price;
+                    quote.size = order.size;
+                    quote.placed_at_ms = now_ms;
+                }
+                modifies.push(PendingModify::new(oid, cloid, order));
+            }
+            return Some(MakerAction::Modify(modifies));
+        }
+
+        for (order, oid, cloid) in replaced {
+            cancels.push(PendingCancel::new(market, oid, now_ms));
+            state.bids.retain(|q| q.cloid != cloid);
+            state.asks.retain(|q| q.cloid != cloid);
+            places.push(order);
+        }
+        for order in &places {
+            let quote = ActiveQuote {
+                cloid: order.cloid.clone(),
+                oid: None,
+                side: order.side,
+                price: order.price,
+                size: order.size,
+                level: level_of(order),
+                placed_at_ms: now_ms,
+            };
+            match order.side {
+                OrderSide::Buy => state.bids.push(quote),
+                OrderSide::Sell => state.asks.push(quote),
+            }
+        }
+
+        Some(if cancels.is_empty() {
+            MakerAction::PlaceOrders(places)
+        } else if places.is_empty() {
+            MakerAction::CancelOrders(cancels)
+        } else {
+            MakerAction::CancelAndReplace {
+                cancels,
+                new_orders: places,
+            }
+        })
+    }
+
+    /// Apply the resting order cap to a requote and record the offsets of
+    /// its new quotes for fill probability tracking.
+    fn finish_requote(
+        &mut self,
+        market: MarketKey,
+        quote_center: Price,
+        action: MakerAction,
+        now_ms: u64,
+    ) -> Option<MakerAction> {
+        let action = self.enforce_resting_cap(market, action)?;
+        let Some(ref mut fill_stats) = self.fill_stats else {
+            return Some(action);
+        };
+        if quote_center.is_zero() {
+            return Some(action);
+        }
+        let orders: Vec<&PendingOrder> = match &action {
+            MakerAction::PlaceOrders(orders)
+            | MakerAction::CancelAndReplace {
+                new_orders: orders, ..
+            } => orders.iter().collect(),
+            _ => Vec::new(),
+        };
+        let center = quote_center.inner();
+        let offset_bps =
+            |order: &PendingOrder| ((order.price.inner() - center) / center * dec!(10000)).abs();
+        if let MakerAction::Modify(modifies) = &action {
+            for m in modifies {
+                let order = &m.order;
+                fill_stats.record_amend(
+                    &m.replaces,
+                    order.cloid.clone(),
+                    order.side,
+                    offset_bps(order),
+                    now_ms,
+                );
+            }
+            return Some(action);
+        }
+        for order in orders {
+            fill_stats.record_quote(
+                market,
+                order.cloid.clone(),
+                order.side,
+                offset_bps(order),
+                now_ms,
+            );
+        }
+        Some(action)
+    }
+
+    /// Drop the new quotes of `action` that take the market over
+    /// `max_resting_orders_per_market`, outer levels first. The new quotes
+    /// are already tracked in the market state.
+    fn enforce_resting_cap(
+        &mut self,
+        market: MarketKey,
+        action: MakerAction,
+    ) -> Option<MakerAction> {
+        let cap = self.config.max_resting_orders_per_market;
+        let Some(state) = self.states.get_mut(&market).filter(|_| cap > 0) else {
+            return Some(action);
+        };
+        let (cancels, mut orders) = match action {
+            MakerAction::PlaceOrders(orders) => (Vec::new(), orders),
+            MakerAction::CancelAndReplace {
+                cancels,
+                new_orders,
+            } => (cancels, new_orders),
+            other => return Some(other),
+        };
+
+        let unacked_cancels = self
+            .pending_cancels
+            .iter()
+            .filter(|c| c.market == market)
+            .count();
+        let resting = state.bids.len() + state.asks.len() + unacked_cancels;
+        let excess = resting.saturating_sub(cap).min(orders.len());
+        if excess > 0 {
+            let level_of = |order: &PendingOrder| {
+                state
+                    .bids
+                    .iter()
+                    .chain(state.asks.iter())
+                    .find(|q| q.cloid == order.cloid)
+                    .map_or(0, |q| q.level)
+            };
+            orders.sort_by_key(|order| level_of(order));
+            let dropped: HashSet<ClientOrderId> = orders
+                .split_off(orders.len() - excess)
+                .into_iter()
+                .map(|order| order.cloid)
+                .collect();
+            state.bids.retain(|q| !dropped.contains(&q.cloid));
+            state.asks.retain(|q| !dropped.contains(&q.cloid));
+            warn!(
+                market = %market,
+                resting,
+                cap,
+                dropped = excess,
+                "MM resting order cap reached, dropping new quotes"
+            );
+        }
+
+        match (cancels.is_empty(), orders.is_empty()) {
+            (true, true) => None,
+            (true, false) => Some(MakerAction::PlaceOrders(orders)),
+            (false, true) => Some(MakerAction::CancelOrders(cancels)),
+            (false, false) => Some(MakerAction::CancelAndReplace {
+                cancels,
+                new_orders: orders,
+            }),
+        }
+    }
+
+    fn build_cancels_static(market: MarketKey, state: &MarketQuoteState) -> Vec<PendingCancel> {
+        let now = chrono::Utc::now().timestamp_millis() as u64;
+        state
+            .bids
+            .iter()
+            .chain(state.asks.iter())
+            .filter_map(|q| q.oid.map(|oid| PendingCancel::new(market, oid, now)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+
+    fn mk() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn markout(mid_bps: f64) -> MarkoutSample {
+        MarkoutSample {
+            market: mk(),
+            side: OrderSide::Buy,
+            horizon_ms: 1000,
+            mid_bps,
+            oracle_bps: mid_bps,
+        }
+    }
+
+    fn test_config() -> MakerConfig {
+        MakerConfig {
+            enabled: true,
+            num_levels: 1,
+            min_offset_bps: dec!(20),
+            size_per_level_usd: dec!(10),
+            requote_interval_ms: 2000,
+            min_requote_change_bps: dec!(5),
+            use_alo: true,
+            ..Default::default()
         }
     }
 
@@ -1189,6 +2270,33 @@ mod tests {
         assert!(!mgr.has_active_quotes());
     }
 
+    #[test]
+    fn test_pull_market() {
+        let mut mgr = QuoteManager::new(test_config());
+        let inv = InventoryManager::new(dec!(100));
+        assert!(mgr.pull_market(mk(), 1000).is_none());
+
+        let action = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            1000,
+            &inv,
+        );
+        let Some(MakerAction::PlaceOrders(orders)) = &action else {
+            panic!("Expected PlaceOrders");
+        };
+        mgr.record_resting(&mk(), &orders[0].cloid, 300);
+
+        // Only the resting quote has an oid to cancel
+        let Some(MakerAction::CancelOrders(cancels)) = mgr.pull_market(mk(), 2000) else {
+            panic!("Expected CancelOrders");
+        };
+        assert_eq!(cancels.len(), 1);
+        assert_eq!(cancels[0].oid, 300);
+        assert_eq!(mgr.active_quote_count(&mk()), 0);
+    }
+
     #[test]
     fn test_gtc_tif_when_alo_disabled() {
         let config = MakerConfig {
@@ -1733,6 +2841,21 @@ mod tests {
         assert!(stats.sample_count > 0);
     }
 
+    #[test]
+    fn test_optimal_wick_smoothing() {
+        let config = MakerConfig {
+            dynamic_offset_half_life_secs: 60,
+            ..test_config()
+        };
+        let mut mgr = QuoteManager::new(config);
+
+        // First sample is taken as is; one half-life later the EMA is halfway
+        assert_eq!(mgr.smooth_optimal_wick(mk(), 10.0, 0), 10.0);
+        let smoothed = mgr.smooth_optimal_wick(mk(), 30.0, 60_000);
+        assert!((smoothed - 20.0).abs() < 1e-9);
+        assert_eq!(mgr.smoothed_wick_bps(&mk()), Some(smoothed));
+    }
+
     #[test]
     fn test_dynamic_offset_observation_mode() {
         let config = MakerConfig {
@@ -1763,6 +2886,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_quote_model_routing_per_market() {
+        let config = MakerConfig {
+            as_risk_aversion: dec!(0.1),
+            as_order_intensity: dec!(1.5),
+            as_horizon_secs: dec!(200),
+            as_default_sigma_bps: dec!(2),
+            ..test_config()
+        };
+        let mut mgr = QuoteManager::new(config);
+        let inv = InventoryManager::new(dec!(100));
+        let as_market = MarketKey::new(DexId::XYZ, AssetId::new(1));
+        mgr.set_quote_model(as_market, QuoteModel::AvellanedaStoikov);
+        assert_eq!(mgr.quote_model(&mk()), QuoteModel::Offset);
+        assert_eq!(mgr.quote_model(&as_market), QuoteModel::AvellanedaStoikov);
+
+        let mut best_bid = |market: MarketKey| {
+            let Some(MakerAction::PlaceOrders(orders)) = mgr.on_market_update(
+                market,
+                Price::new(dec!(100)),
+                Price::new(dec!(100)),
+                1000,
+                &inv,
+            ) else {
+                panic!("Expected PlaceOrders");
+            };
+            orders
+                .iter()
+                .find(|o| o.side == OrderSide::Buy)
+                .unwrap()
+                .price
+                .inner()
+        };
+
+        // Offset: 20 bps. A-S: γσ²τ/2 + ln(1 + γ/k)/γ ≈ 40.65 bps
+        assert_eq!(best_bid(mk()), dec!(99.80));
+        let as_bid = best_bid(as_market);
+        assert!(as_bid > dec!(99.59) && as_bid < dec!(99.60));
+    }
+
+    #[test]
+    fn test_fill_stats_offsets_drive_quotes() {
+        let bid_at = |fill_stats_offsets: bool| {
+            let config = MakerConfig {
+                fill_stats_bucket_bps: dec!(5),
+                fill_stats_min_quotes: 1,
+                fill_stats_offsets,
+                markout_horizons_ms: vec![1000],
+                ..test_config()
+            };
+            let mut mgr = QuoteManager::new(config);
+            let inv = InventoryManager::new(dec!(100));
+            // A bid 32 bps out (bucket 30-35) filled at 99.68, oracle back at 100
+            let cloid = ClientOrderId::new();
+            let fill_stats = mgr.fill_stats.as_mut().unwrap();
+            fill_stats.record_quote(mk(), cloid.clone(), OrderSide::Buy, dec!(32), 0);
+            fill_stats.record_fill(&cloid, Price::new(dec!(99.68)), 0);
+
+            let Some(MakerAction::PlaceOrders(orders)) = mgr.on_market_update(
+                mk(),
+                Price::new(dec!(100)),
+                Price::new(dec!(100)),
+                5000,
+                &inv,
+            ) else {
+                panic!("Expected PlaceOrders");
+            };
+            orders
+                .iter()
+                .find(|o| o.side == OrderSide::Buy)
+                .unwrap()
+                .price
+                .inner()
+        };
+
+        // Quoted at the bucket center (32.5 bps) only with the flag on
+        assert_eq!(bid_at(true), dec!(99.675));
+        assert_eq!(bid_at(false), dec!(99.80));
+    }
+
     // === Phase C: Counter-order + velocity tests ===
 
     #[test]
@@ -1884,4 +3087,632 @@ mod tests {
         let result = mgr.record_fill(&mk(), &cloids[0], Price::new(dec!(100)), 2000);
         assert!(result.is_none(), "No counter-order when disabled");
     }
+
+    #[test]
+    fn test_amend_quotes_and_fallback_on_reject() {
+        let config = MakerConfig {
+            amend_quotes: true,
+            ..test_config()
+        };
+        let mut mgr = QuoteManager::new(config);
+        let inv = InventoryManager::new(dec!(100));
+
+        let action = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            1000,
+            &inv,
+        );
+        let cloids: Vec<ClientOrderId> = if let Some(MakerAction::PlaceOrders(orders)) = &action {
+            orders.iter().map(|o| o.cloid.clone()).collect()
+        } else {
+            panic!("Expected PlaceOrders");
+        };
+        mgr.record_resting(&mk(), &cloids[0], 100);
+        mgr.record_resting(&mk(), &cloids[1], 101);
+
+        // Requote amends both resting quotes in place
+        let action = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(101)),
+            Price::new(dec!(101)),
+            4000,
+            &inv,
+        );
+        let modifies = if let Some(MakerAction::Modify(modifies)) = action {
+            modifies
+        } else {
+            panic!("Expected Modify");
+        };
+        assert_eq!(modifies.len(), 2);
+        assert_eq!(modifies[0].oid, 100);
+        assert_eq!(modifies[0].replaces, cloids[0]);
+        assert_eq!(modifies[0].order.side, OrderSide::Buy);
+        assert_eq!(modifies[1].oid, 101);
+        assert!(mgr.is_mm_order(&modifies[0].order.cloid));
+        assert!(!mgr.is_mm_order(&cloids[0]));
+        assert_eq!(mgr.active_quote_count(&mk()), 2);
+
+        // Rejected amendment falls back to cancel-replace under a new cloid
+        let action = mgr.on_modify_rejected(&modifies[0], 4100);
+        if let Some(MakerAction::CancelAndReplace {
+            cancels,
+            new_orders,
+        }) = action
+        {
+            assert_eq!(cancels.len(), 1);
+            assert_eq!(cancels[0].oid, 100);
+            assert_eq!(new_orders.len(), 1);
+            assert_eq!(new_orders[0].price, modifies[0].order.price);
+            assert_ne!(new_orders[0].cloid, modifies[0].order.cloid);
+            assert!(mgr.is_mm_order(&new_orders[0].cloid));
+        } else {
+            panic!("Expected CancelAndReplace");
+        }
+        assert!(!mgr.is_mm_order(&modifies[0].order.cloid));
+        assert_eq!(mgr.active_quote_count(&mk()), 2);
+
+        // Quote not confirmed resting yet: next requote uses cancel-replace path
+        let action = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(102)),
+            Price::new(dec!(102)),
+            7000,
+            &inv,
+        );
+        assert!(!matches!(action, Some(MakerAction::Modify(_))));
+    }
+
+    #[test]
+    fn test_ladder_requotes_only_drifted_levels() {
+        use crate::config::{LadderConfig, LadderSideConfig};
+
+        let config = MakerConfig {
+            ladder: Some(LadderConfig {
+                bids: LadderSideConfig {
+                    levels: 2,
+                    ratio: dec!(2),
+                    requote_bps: vec![dec!(5), dec!(50)],
+                    ..Default::default()
+                },
+                asks: LadderSideConfig {
+                    levels: 1,
+                    ..Default::default()
+                },
+            }),
+            ..test_config()
+        };
+        let mut mgr = QuoteManager::new(config);
+        let inv = InventoryManager::new(dec!(100));
+
+        let action = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            1000,
+            &inv,
+        );
+        let Some(MakerAction::PlaceOrders(orders)) = action else {
+            panic!("Expected PlaceOrders");
+        };
+        assert_eq!(orders.len(), 3);
+        for (oid, order) in (1..).zip(&orders) {
+            mgr.record_resting(&mk(), &order.cloid, oid);
+        }
+
+        // 10 bps oracle move: L0 bid (5 bps) and ask (default 5 bps) requote,
+        // the outer bid (50 bps) stays
+        let action = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100.1)),
+            Price::new(dec!(100.1)),
+            1500,
+            &inv,
+        );
+        let Some(MakerAction::CancelAndReplace {
+            cancels,
+            new_orders,
+        }) = action
+        else {
+            panic!("Expected CancelAndReplace");
+        };
+        let mut oids: Vec<u64> = cancels.iter().map(|c| c.oid).collect();
+        oids.sort_unstable();
+        assert_eq!(oids, vec![1, 3]);
+        assert_eq!(new_orders.len(), 2);
+        assert_eq!(mgr.active_quote_count(&mk()), 3);
+
+        // Nothing drifted: no action
+        assert!(mgr
+            .on_market_update(
+                mk(),
+                Price::new(dec!(100.1)),
+                Price::new(dec!(100.1)),
+                1600,
+                &inv,
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_negative_markouts_boost_skew() {
+        let config = MakerConfig {
+            markout_skew_factor: dec!(1),
+            markout_horizons_ms: vec![1000, 5000],
+            markout_half_life_secs: 60,
+            ..test_config()
+        };
+        let mut mgr = QuoteManager::new(config);
+
+        // Only the shortest horizon counts
+        mgr.record_markouts(
+            &[MarkoutSample {
+                horizon_ms: 5000,
+                ..markout(-50.0)
+            }],
+            0,
+        );
+        assert_eq!(mgr.markout_skew_boost(&mk(), 0), dec!(1));
+
+        // Mid fell below the bid fill: negative markout, skew doubled
+        mgr.record_markouts(&[markout(-50.0)], 0);
+        assert_eq!(mgr.markout_skew_boost(&mk(), 0), dec!(2));
+        assert_eq!(mgr.mean_markout_bps(&mk(), 0), Some(dec!(-50)));
+    }
+
+    #[test]
+    fn test_markout_mean_decays_without_fills() {
+        let config = MakerConfig {
+            markout_skew_factor: dec!(1),
+            markout_horizons_ms: vec![1000],
+            markout_half_life_secs: 60,
+            ..test_config()
+        };
+        let mut mgr = QuoteManager::new(config);
+        mgr.record_markouts(&[markout(-40.0), markout(-40.0)], 0);
+        assert_eq!(mgr.mean_markout_bps(&mk(), 0), Some(dec!(-40)));
+
+        // Two half-lives: half a fill's weight left, the mean halves
+        let mean = mgr.mean_markout_bps(&mk(), 120_000).unwrap();
+        assert!((mean - dec!(-20)).abs() < dec!(0.001), "{mean}");
+
+        // Long after the last fill the mean expires and the boost ends
+        assert_eq!(mgr.mean_markout_bps(&mk(), 600_000), None);
+        assert_eq!(mgr.markout_skew_boost(&mk(), 600_000), dec!(1));
+    }
+
+    #[test]
+    fn test_toxic_flow_pulls_market_for_cooldown() {
+        let config = MakerConfig {
+            markout_horizons_ms: vec![1000],
+            toxic_fade_threshold_bps: dec!(5),
+            toxic_fade_min_fills: 1,
+            toxic_fade_action: ToxicFadeAction::Pull,
+            toxic_fade_cooldown_secs: 60,
+            ..test_config()
+        };
+        let mut mgr = QuoteManager::new(config);
+        let inv = InventoryManager::new(dec!(100));
+
+        let Some(MakerAction::PlaceOrders(orders)) = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            1000,
+            &inv,
+        ) else {
+            panic!("Expected PlaceOrders");
+        };
+        let bid = orders.iter().find(|o| o.side == OrderSide::Buy).unwrap();
+        let ask = orders.iter().find(|o| o.side == OrderSide::Sell).unwrap();
+        mgr.record_resting(&mk(), &ask.cloid, 400);
+        mgr.record_fill(&mk(), &bid.cloid, Price::new(dec!(100)), 1000);
+        mgr.record_markouts(&[markout(-100.0)], 1000);
+
+        // Mid dropped well below the bid fill: the market is pulled
+        let Some(MakerAction::CancelOrders(cancels)) =
+            mgr.on_market_update(mk(), Price::new(dec!(99)), Price::new(dec!(99)), 2000, &inv)
+        else {
+            panic!("Expected CancelOrders");
+        };
+        assert_eq!(cancels[0].oid, 400);
+        mgr.record_cancel_acked(400);
+        assert_eq!(mgr.toxic_fades(2000), vec![(mk(), 62_000)]);
+
+        // No quotes during the cooldown, quoting resumes after it
+        assert!(mgr
+            .on_market_update(
+                mk(),
+                Price::new(dec!(99)),
+                Price::new(dec!(99)),
+                30_000,
+                &inv
+            )
+            .is_none());
+        assert!(matches!(
+            mgr.on_market_update(
+                mk(),
+                Price::new(dec!(99)),
+                Price::new(dec!(99)),
+                62_000,
+                &inv
+            ),
+            Some(MakerAction::PlaceOrders(_))
+        ));
+        assert!(mgr.toxic_fades(62_000).is_empty());
+    }
+
+    #[test]
+    fn test_requote_hysteresis_and_level_interval() {
+        let config = MakerConfig {
+            requote_hysteresis_ticks: 3,
+            min_level_requote_interval_ms: 5000,
+            ..test_config()
+        };
+        let mut mgr = QuoteManager::new(config);
+        mgr.set_tick_size(mk(), Price::new(dec!(0.1)));
+        let inv = InventoryManager::new(dec!(100));
+
+        let Some(MakerAction::PlaceOrders(orders)) = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            1000,
+            &inv,
+        ) else {
+            panic!("Expected PlaceOrders");
+        };
+        let bid = orders.iter().find(|o| o.side == OrderSide::Buy).unwrap();
+        let ask = orders.iter().find(|o| o.side == OrderSide::Sell).unwrap();
+        mgr.record_resting(&mk(), &bid.cloid, 1);
+        mgr.record_resting(&mk(), &ask.cloid, 2);
+
+        // Past the requote interval and oracle threshold, but the quotes
+        // haven't rested 5s yet
+        assert!(mgr
+            .on_market_update(
+                mk(),
+                Price::new(dec!(101)),
+                Price::new(dec!(101)),
+                4000,
+                &inv
+            )
+            .is_none());
+
+        // Rested long enough, but a 0.2 move is under 3 ticks
+        assert!(mgr
+            .on_market_update(
+                mk(),
+                Price::new(dec!(100.2)),
+                Price::new(dec!(100.2)),
+                7000,
+                &inv
+            )
+            .is_none());
+        assert_eq!(mgr.active_quote_count(&mk()), 2);
+
+        // The bid fills: only it is placed again, the ask stays
+        mgr.record_fill(&mk(), &bid.cloid, Price::new(dec!(100.2)), 7500);
+        let Some(MakerAction::PlaceOrders(orders)) = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100.2)),
+            Price::new(dec!(100.2)),
+            10_000,
+            &inv,
+        ) else {
+            panic!("Expected PlaceOrders");
+        };
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].side, OrderSide::Buy);
+        mgr.record_resting(&mk(), &orders[0].cloid, 3);
+
+        // A 1.0 move requotes the ask; the new bid hasn't rested 5s yet
+        let Some(MakerAction::CancelAndReplace {
+            cancels,
+            new_orders,
+        }) = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(101)),
+            Price::new(dec!(101)),
+            13_000,
+            &inv,
+        )
+        else {
+            panic!("Expected CancelAndReplace");
+        };
+        assert_eq!(cancels.len(), 1);
+        assert_eq!(cancels[0].oid, 2);
+        assert_eq!(new_orders.len(), 1);
+        assert_eq!(new_orders[0].side, OrderSide::Sell);
+        assert_eq!(mgr.active_quote_count(&mk()), 2);
+    }
+
+    #[test]
+    fn test_requote_hysteresis_bps_or_ticks() {
+        let config = MakerConfig {
+            requote_hysteresis_bps: dec!(30),
+            requote_hysteresis_ticks: 2,
+            ..MakerConfig::default()
+        };
+        let quote = ActiveQuote {
+            cloid: ClientOrderId::new(),
+            oid: Some(1),
+            side: OrderSide::Buy,
+            price: Price::new(dec!(100)),
+            size: Size::new(dec!(1)),
+            level: 0,
+            placed_at_ms: 0,
+        };
+        let moves = |to: Decimal, tick: Option<Decimal>| {
+            QuoteManager::level_needs_requote(
+                &config,
+                &quote,
+                Price::new(to),
+                Decimal::ZERO,
+                tick.map(Price::new),
+                1000,
+            )
+        };
+
+        // 2 ticks of 0.1 (20 bps) are enough on their own
+        assert!(moves(dec!(100.2), Some(dec!(0.1))));
+        // 30 bps are enough on their own, whatever the tick count
+        assert!(moves(dec!(100.3), Some(dec!(1))));
+        // Neither reached
+        assert!(!moves(dec!(100.1), Some(dec!(0.1))));
+        assert!(!moves(dec!(100.2), None));
+    }
+
+    #[test]
+    fn test_quote_center_blend_and_divergence_guard() {
+        let config = MakerConfig {
+            quote_center_mark_weight: dec!(0.5),
+            max_mark_oracle_divergence_bps: dec!(50),
+            ..test_config()
+        };
+        let mut mgr = QuoteManager::new(config);
+        let inv = InventoryManager::new(dec!(100));
+
+        // Centered halfway between oracle 100 and mark 100.2: 100.1 ± 20 bps
+        let Some(MakerAction::PlaceOrders(orders)) = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100)),
+            Price::new(dec!(100.2)),
+            1000,
+            &inv,
+        ) else {
+            panic!("Expected PlaceOrders");
+        };
+        let bid = orders.iter().find(|o| o.side == OrderSide::Buy).unwrap();
+        let ask = orders.iter().find(|o| o.side == OrderSide::Sell).unwrap();
+        assert_eq!(bid.price.inner(), dec!(100.1) * dec!(0.998));
+        assert_eq!(ask.price.inner(), dec!(100.1) * dec!(1.002));
+        mgr.record_resting(&mk(), &bid.cloid, 500);
+
+        // Mark 1% away from oracle: quotes pulled
+        let Some(MakerAction::CancelOrders(cancels)) = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100)),
+            Price::new(dec!(101)),
+            2000,
+            &inv,
+        ) else {
+            panic!("Expected CancelOrders");
+        };
+        assert_eq!(cancels[0].oid, 500);
+        assert_eq!(mgr.active_quote_count(&mk()), 0);
+    }
+
+    #[test]
+    fn test_resting_cap_and_reconcile() {
+        let config = MakerConfig {
+            num_levels: 2,
+            max_resting_orders_per_market: 3,
+            ..test_config()
+        };
+        let mut mgr = QuoteManager::new(config);
+        let inv = InventoryManager::new(dec!(100));
+
+        // 4 desired quotes, cap 3: one outer level is dropped
+        let action = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            1000,
+            &inv,
+        );
+        let Some(MakerAction::PlaceOrders(orders)) = action else {
+            panic!("Expected PlaceOrders");
+        };
+        assert_eq!(orders.len(), 3);
+        assert_eq!(mgr.active_quote_count(&mk()), 3);
+        mgr.record_resting(&mk(), &orders[0].cloid, 1);
+        mgr.record_resting(&mk(), &orders[1].cloid, 2);
+
+        let listed = |oid: u64, order: &PendingOrder| ExchangeOrder {
+            market: mk(),
+            oid,
+            cloid: Some(order.cloid.clone()),
+            side: order.side,
+            price: order.price,
+            size: order.size,
+        };
+        let open_orders = vec![
+            listed(1, &orders[0]),
+            // Unacked quote: its oid is learned from the listing, even at
+            // the rounded price and a partially filled size
+            ExchangeOrder {
+                price: Price::new(orders[2].price.inner().round_dp(1)),
+                size: Size::new(orders[2].size.inner() / dec!(2)),
+                ..listed(9, &orders[2])
+            },
+            ExchangeOrder {
+                cloid: None,
+                price: Price::new(dec!(90)),
+                ..listed(50, &orders[0])
+            },
+            ExchangeOrder {
+                cloid: None,
+                price: Price::new(dec!(80)),
+                ..listed(77, &orders[0])
+            },
+        ];
+        let is_foreign = |o: &ExchangeOrder| o.oid == 77;
+
+        let report = mgr.reconcile(&open_orders, is_foreign, 2000);
+        assert_eq!(report.registered, 1);
+        assert_eq!(report.unknown_cancels.len(), 1);
+        assert_eq!(report.unknown_cancels[0].oid, 50);
+        // Oid 2 is missing once: kept for now
+        assert_eq!(report.dropped, 0);
+        assert_eq!(mgr.active_quote_count(&mk()), 3);
+
+        let report = mgr.reconcile(&open_orders[..2], is_foreign, 3000);
+        assert!(report.unknown_cancels.is_empty());
+        assert_eq!(report.dropped, 1);
+        assert_eq!(mgr.active_quote_count(&mk()), 2);
+
+        // Without a listed cloid: the same-side quote within one tick
+        let mut mgr = QuoteManager::new(test_config());
+        mgr.set_tick_size(mk(), Price::new(dec!(0.1)));
+        let Some(MakerAction::PlaceOrders(orders)) = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            1000,
+            &inv,
+        ) else {
+            panic!("Expected PlaceOrders");
+        };
+        let bid = orders.iter().find(|o| o.side == OrderSide::Buy).unwrap();
+        let rounded = |oid: u64, px: Decimal| ExchangeOrder {
+            market: mk(),
+            oid,
+            cloid: None,
+            side: OrderSide::Buy,
+            price: Price::new(px),
+            size: bid.size,
+        };
+        let report = mgr.reconcile(
+            &[
+                rounded(5, (bid.price.inner() * dec!(10)).ceil() / dec!(10)),
+                rounded(6, bid.price.inner() - dec!(1)),
+            ],
+            |_| false,
+            2000,
+        );
+        assert_eq!(report.registered, 1);
+        assert_eq!(report.unknown_cancels.len(), 1);
+        assert_eq!(report.unknown_cancels[0].oid, 6);
+    }
+
+    #[test]
+    fn test_inventory_time_decay() {
+        let config = MakerConfig {
+            inventory_decay_after_secs: 60,
+            inventory_decay_ramp_secs: 60,
+            inventory_decay_max_skew_multiplier: dec!(3),
+            inventory_decay_hedge_after_secs: 300,
+            inventory_decay_hedge_fraction: dec!(0.5),
+            inventory_skew_factor: dec!(0.3),
+            ..test_config()
+        };
+        assert_eq!(inventory_decay_boost(Some(60_000), &config), dec!(1));
+        assert_eq!(inventory_decay_boost(Some(90_000), &config), dec!(2));
+        assert_eq!(inventory_decay_boost(Some(600_000), &config), dec!(3));
+
+        // Long 0.2 since t=1s
+        let mut inv = InventoryManager::new(dec!(100));
+        inv.record_fill_at_reference(
+            mk(),
+            OrderSide::Buy,
+            Price::new(dec!(100)),
+            Size::new(dec!(0.2)),
+            Price::new(dec!(100)),
+            1000,
+        );
+        let best_ask = |now_ms: u64| {
+            let mut mgr = QuoteManager::new(config.clone());
+            let Some(MakerAction::PlaceOrders(orders)) = mgr.on_market_update(
+                mk(),
+                Price::new(dec!(100)),
+                Price::new(dec!(100)),
+                now_ms,
+                &inv,
+            ) else {
+                panic!("Expected PlaceOrders");
+            };
+            orders
+                .iter()
+                .find(|o| o.side == OrderSide::Sell)
+                .unwrap()
+                .price
+        };
+        // Stale inventory: the ask leans closer to the oracle to sell it off
+        assert!(best_ask(121_000) < best_ask(31_000));
+
+        // Past the hedge delay: half the inventory goes out as a reduce-only IOC
+        let mut mgr = QuoteManager::new(config.clone());
+        let Some(MakerAction::PlaceOrders(orders)) = mgr.on_market_update(
+            mk(),
+            Price::new(dec!(100)),
+            Price::new(dec!(100)),
+            301_000,
+            &inv,
+        ) else {
+            panic!("Expected hedge");
+        };
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].side, OrderSide::Sell);
+        assert_eq!(orders[0].size.inner(), dec!(0.1));
+        assert!(orders[0].reduce_only);
+        assert_eq!(orders[0].tif, TimeInForce::ImmediateOrCancel);
+        // Its fills are MM fills
+        assert!(mgr.is_mm_order(&orders[0].cloid));
+
+        // Not again within the hedge period
+        let held = inv.held_for_ms(&mk(), 400_000);
+        let mark = Price::new(dec!(100));
+        assert!(mgr
+            .build_decay_hedge(mk(), &inv, held, mark, 400_000)
+            .is_none());
+        assert!(mgr
+            .build_decay_hedge(mk(), &inv, held, mark, 601_000)
+            .is_some());
+    }
+
+    #[test]
+    fn test_decay_hedge_lot_and_min_notional() {
+        let config = MakerConfig {
+            inventory_decay_hedge_after_secs: 300,
+            inventory_decay_hedge_fraction: dec!(0.5),
+            ..test_config()
+        };
+        // Long 0.35 since t=0: half is 0.175
+        let mut inv = InventoryManager::new(dec!(100));
+        inv.record_fill_at_reference(
+            mk(),
+            OrderSide::Buy,
+            Price::new(dec!(100)),
+            Size::new(dec!(0.35)),
+            Price::new(dec!(100)),
+            0,
+        );
+        let held = inv.held_for_ms(&mk(), 400_000);
+        let hedge = |lot: Decimal, mark: Decimal| {
+            let mut mgr = QuoteManager::new(config.clone());
+            mgr.set_lot_size(mk(), Size::new(lot));
+            mgr.build_decay_hedge(mk(), &inv, held, Price::new(mark), 400_000)
+                .map(|o| o.size.inner())
+        };
+
+        // Rounded down to the lot
+        assert_eq!(hedge(dec!(0.1), dec!(100)), Some(dec!(0.1)));
+        assert_eq!(hedge(dec!(0.01), dec!(100)), Some(dec!(0.17)));
+        // 0.17 at 50 is $8.50: under the minimum order value
+        assert_eq!(hedge(dec!(0.01), dec!(50)), None);
+        // Less than a lot
+        assert_eq!(hedge(dec!(1), dec!(100)), None);
+    }
 }
@@ -0,0 +1,123 @@
+//! MM session schedule.
+//!
+//! `weekend_only` quotes every market during one fixed weekly window.
+//! Session windows are more flexible: weekly UTC windows per market or
+//! asset class, e.g. the weekend for equities plus a weekday overnight
+//! window (low liquidity) for metals. A window whose end is at or before its
+//! start runs past midnight into the next day.
+
+use chrono::{DateTime, Datelike, NaiveTime, Utc, Weekday};
+use hip3_core::AssetClass;
+use serde::{Deserialize, Serialize};
+
+/// A weekly MM session window (UTC).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MmSessionWindow {
+    /// Days the window opens on (e.g. ["Mon", "Tue"]). Empty = every day.
+    #[serde(default)]
+    pub days: Vec<Weekday>,
+    /// Open time (UTC), e.g. "21:00:00".
+    pub start: NaiveTime,
+    /// Close time (UTC). At or before `start` = closes the next day.
+    pub end: NaiveTime,
+    /// Markets the window applies to, by name (e.g. "GOLD").
+    #[serde(default)]
+    pub markets: Vec<String>,
+    /// Asset classes the window applies to (see `trading_calendar.markets`).
+    /// With no markets and no asset classes, the window applies to all MM
+    /// markets.
+    #[serde(default)]
+    pub asset_classes: Vec<AssetClass>,
+}
+
+impl MmSessionWindow {
+    /// Whether the window applies to a market ("GOLD" or "xyz:GOLD").
+    #[must_use]
+    pub fn applies_to(&self, market_name: &str, asset_class: Option<AssetClass>) -> bool {
+        if self.markets.is_empty() && self.asset_classes.is_empty() {
+            return true;
+        }
+        let name = market_name
+            .rsplit_once(':')
+            .map_or(market_name, |(_, name)| name);
+        self.markets.iter().any(|m| m == name)
+            || asset_class.is_some_and(|class| self.asset_classes.contains(&class))
+    }
+
+    /// Whether the window is open at `dt`.
+    #[must_use]
+    pub fn is_open_at(&self, dt: DateTime<Utc>) -> bool {
+        let time = dt.time();
+        let weekday = dt.weekday();
+        let opens_on = |day: Weekday| self.days.is_empty() || self.days.contains(&day);
+        if self.start < self.end {
+            opens_on(weekday) && self.start <= time && time < self.end
+        } else {
+            // Overnight: opened today, or opened yesterday and not closed yet
+            (opens_on(weekday) && time >= self.start)
+                || (opens_on(weekday.pred()) && time < self.end)
+        }
+    }
+}
+
+/// Whether a market is inside one of its session windows at `dt`.
+#[must_use]
+pub fn in_session(
+    sessions: &[MmSessionWindow],
+    market_name: &str,
+    asset_class: Option<AssetClass>,
+    dt: DateTime<Utc>,
+) -> bool {
+    sessions
+        .iter()
+        .any(|s| s.applies_to(market_name, asset_class) && s.is_open_at(dt))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_session_windows() {
+        let sessions: Vec<MmSessionWindow> = toml::from_str::<toml::Value>(
+            r#"
+            [[sessions]]
+            days = ["Fri"]
+            start = "21:00:00"
+            end = "21:00:00"
+            asset_classes = ["equities"]
+
+            [[sessions]]
+            days = ["Mon", "Tue", "Wed", "Thu"]
+            start = "22:00:00"
+            end = "06:00:00"
+            markets = ["GOLD"]
+            "#,
+        )
+        .unwrap()["sessions"]
+            .clone()
+            .try_into()
+            .unwrap();
+
+        let open = |name: &str, class, dt| in_session(&sessions, name, class, dt);
+
+        // 2026-02-13 is Friday: the equities window runs 24h from 21:00
+        let equities = Some(AssetClass::Equities);
+        assert!(!open("xyz:AAPL", equities, utc(2026, 2, 13, 20, 59)));
+        assert!(open("xyz:AAPL", equities, utc(2026, 2, 14, 20, 59)));
+        assert!(!open("xyz:AAPL", equities, utc(2026, 2, 14, 21, 0)));
+        assert!(!open("xyz:AAPL", None, utc(2026, 2, 14, 12, 0)));
+
+        // GOLD overnight: Thursday 22:00 opens into Friday, Friday 22:00 doesn't
+        assert!(open("xyz:GOLD", None, utc(2026, 2, 13, 5, 59)));
+        assert!(!open("xyz:GOLD", None, utc(2026, 2, 13, 6, 0)));
+        assert!(!open("xyz:GOLD", None, utc(2026, 2, 13, 23, 0)));
+        assert!(open("GOLD", None, utc(2026, 2, 9, 22, 0)));
+    }
+}
@@ -0,0 +1,338 @@
+//! Tamper-evident log of signed actions.
+//!
+//! One [`SignedActionRecord`] per action we sign, appended to daily
+//! `signed_actions_YYYY-MM-DD.jsonl` files by [`AuditWriter`]. Records are
+//! hash-chained: each carries the previous record's hash and a
+//! `record_hash` = keccak256(prev_hash ‖ record JSON without `record_hash`),
+//! so editing, dropping or reordering a line breaks the chain.
+//!
+//! [`verify_audit_file`] re-walks the chain; the action hash itself is
+//! re-derived by the caller (it needs the executor's msgpack encoding).
+//!
+//! Records are written and flushed one at a time, but only once the
+//! application drains them from the executor's `SignedActionLog` (after each
+//! handled WebSocket message, and on shutdown). A crash loses the actions
+//! signed since the last drain.
+
+use crate::error::{PersistenceError, PersistenceResult};
+use alloy::primitives::keccak256;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use tracing::{info, warn};
+
+/// `prev_hash` of the first record of a file.
+pub const GENESIS_HASH: &str = "0x0000000000000000000000000000000000000000000000000000000000000000";
+
+/// One signed action.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignedActionRecord {
+    /// Position in the file's chain (0-based).
+    pub seq: u64,
+    /// Time the action was signed (Unix ms).
+    pub timestamp_ms: i64,
+    /// Post ID the action was sent (or would have been sent) with.
+    pub post_id: u64,
+    /// Nonce the action was signed with.
+    pub nonce: u64,
+    /// Action type (order, cancel, batchModify, scheduleCancel).
+    pub action_type: String,
+    /// The action exactly as signed.
+    pub action: serde_json::Value,
+    /// Vault the action was signed for (None = personal account).
+    pub vault_address: Option<String>,
+    /// Action hash the signature commits to (0x-prefixed hex).
+    pub action_hash: String,
+    /// Signature r component (hex).
+    pub signature_r: String,
+    /// Signature s component (hex).
+    pub signature_s: String,
+    /// Signature v component (27 or 28).
+    pub signature_v: u8,
+    /// Client order IDs of the orders in the action.
+    pub cloids: Vec<String>,
+    /// What happened to the action (sent, rate_limited, rest_ok, ...).
+    pub result: String,
+    /// `record_hash` of the previous record.
+    pub prev_hash: String,
+    /// Hash of this record, chaining it to `prev_hash`.
+    pub record_hash: String,
+}
+
+/// keccak256 of `prev_hash` and the record with an empty `record_hash`.
+///
+/// # Errors
+/// Returns an error if the record cannot be serialized.
+pub fn record_hash(record: &SignedActionRecord) -> PersistenceResult<String> {
+    let mut unhashed = record.clone();
+    unhashed.record_hash = String::new();
+    let mut data = record.prev_hash.as_bytes().to_vec();
+    data.extend_from_slice(&serde_json::to_vec(&unhashed)?);
+    Ok(format!("{}", keccak256(&data)))
+}
+
+/// Active file of an [`AuditWriter`].
+struct ActiveAuditFile {
+    file: File,
+    date: String,
+    next_seq: u64,
+    last_hash: String,
+}
+
+/// Append-only, hash-chained writer of signed actions.
+pub struct AuditWriter {
+    /// Base directory for output files.
+    base_dir: String,
+    /// Active file (open until date rotation).
+    active: Option<ActiveAuditFile>,
+}
+
+impl AuditWriter {
+    /// Create a new audit writer.
+    pub fn new(base_dir: &str) -> Self {
+        if let Err(e) = std::fs::create_dir_all(base_dir) {
+            warn!(?e, "Failed to create directory: {}", base_dir);
+        }
+        Self {
+            base_dir: base_dir.to_string(),
+            active: None,
+        }
+    }
+
+    /// Chain a record onto the log and write it.
+    ///
+    /// `seq`, `prev_hash` and `record_hash` are filled in here. Returns the
+    /// record as written.
+    pub fn append(
+        &mut self,
+        mut record: SignedActionRecord,
+    ) -> PersistenceResult<SignedActionRecord> {
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        if self.active.as_ref().is_some_and(|a| a.date != today) {
+            self.active = None;
+        }
+        if self.active.is_none() {
+            self.active = Some(self.open(&today)?);
+        }
+        let active = self
+            .active
+            .as_mut()
+            .expect("BUG: active audit file is None after open");
+
+        record.seq = active.next_seq;
+        record.prev_hash = active.last_hash.clone();
+        record.record_hash = record_hash(&record)?;
+
+        writeln!(active.file, "{}", serde_json::to_string(&record)?)?;
+        active.file.flush()?;
+        active.next_seq += 1;
+        active.last_hash = record.record_hash.clone();
+        Ok(record)
+    }
+
+    /// Open the day's file, resuming its chain if it already has records.
+    fn open(&self, date: &str) -> PersistenceResult<ActiveAuditFile> {
+        let filename = format!("{}/signed_actions_{}.jsonl", self.base_dir, date);
+
+        let (next_seq, last_hash) = match last_record(Path::new(&filename))? {
+            Some(last) => (last.seq + 1, last.record_hash),
+            None => (0, GENESIS_HASH.to_string()),
+        };
+        info!(filename = %filename, next_seq, "Opening signed-action audit log");
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&filename)?;
+        Ok(ActiveAuditFile {
+            file,
+            date: date.to_string(),
+            next_seq,
+            last_hash,
+        })
+    }
+}
+
+/// Last record of an audit file, if it exists and has any.
+fn last_record(path: &Path) -> PersistenceResult<Option<SignedActionRecord>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let reader = BufReader::new(File::open(path)?);
+    let mut last = None;
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            last = Some(line);
+        }
+    }
+    last.map(|line| serde_json::from_str(&line).map_err(PersistenceError::from))
+        .transpose()
+}
+
+/// Outcome of verifying an audit file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditVerification {
+    /// Records checked before the first failure (all of them if valid).
+    pub records: usize,
+    /// First failure: (1-based line number, reason).
+    pub failure: Option<(usize, String)>,
+}
+
+impl AuditVerification {
+    /// Whether every record checked out.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Verify an audit file's hash chain and each record's action hash.
+///
+/// `action_hash` re-derives the action hash of a record (None if the action
+/// cannot be decoded). Verification stops at the first failure.
+///
+/// # Errors
+/// Returns an error if the file cannot be read.
+pub fn verify_audit_file(
+    path: &Path,
+    action_hash: impl Fn(&SignedActionRecord) -> Option<String>,
+) -> PersistenceResult<AuditVerification> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut result = AuditVerification::default();
+    let mut prev_hash = GENESIS_HASH.to_string();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line_no = index + 1;
+        let fail = |reason: String| Some((line_no, reason));
+
+        let record: SignedActionRecord = match serde_json::from_str(&line) {
+            Ok(record) => record,
+            Err(e) => {
+                result.failure = fail(format!("unparseable record: {e}"));
+                break;
+            }
+        };
+        if record.seq != result.records as u64 {
+            result.failure = fail(format!("seq {} (expected {})", record.seq, result.records));
+            break;
+        }
+        if record.prev_hash != prev_hash {
+            result.failure = fail("prev_hash does not match the previous record".to_string());
+            break;
+        }
+        if record_hash(&record)? != record.record_hash {
+            result.failure = fail("record_hash mismatch (record modified)".to_string());
+            break;
+        }
+        match action_hash(&record) {
+            Some(hash) if hash == record.action_hash => {}
+            Some(hash) => {
+                result.failure = fail(format!(
+                    "action_hash mismatch: recorded {}, derived {hash}",
+                    record.action_hash
+                ));
+                break;
+            }
+            None => {
+                result.failure = fail("action cannot be decoded".to_string());
+                break;
+            }
+        }
+
+        prev_hash = record.record_hash;
+        result.records += 1;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn record(post_id: u64) -> SignedActionRecord {
+        SignedActionRecord {
+            seq: 0,
+            timestamp_ms: 1_000,
+            post_id,
+            nonce: 1_700_000_000_000 + post_id,
+            action_type: "order".to_string(),
+            action: json!({"type": "order", "orders": [], "grouping": "na"}),
+            vault_address: None,
+            action_hash: format!("0xhash{post_id}"),
+            signature_r: "0x01".to_string(),
+            signature_s: "0x02".to_string(),
+            signature_v: 27,
+            cloids: vec!["0xabc".to_string()],
+            result: "sent".to_string(),
+            prev_hash: String::new(),
+            record_hash: String::new(),
+        }
+    }
+
+    fn today_file(dir: &TempDir) -> std::path::PathBuf {
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        dir.path().join(format!("signed_actions_{today}.jsonl"))
+    }
+
+    fn recorded_hash(record: &SignedActionRecord) -> Option<String> {
+        Some(record.action_hash.clone())
+    }
+
+    #[test]
+    fn test_chain_resumes_and_verifies() {
+        let dir = TempDir::new().unwrap();
+        let base = dir.path().to_str().unwrap();
+        {
+            let mut writer = AuditWriter::new(base);
+            let first = writer.append(record(1)).unwrap();
+            assert_eq!(first.prev_hash, GENESIS_HASH);
+            writer.append(record(2)).unwrap();
+        }
+        // A restart continues the same chain
+        let third = AuditWriter::new(base).append(record(3)).unwrap();
+        assert_eq!(third.seq, 2);
+
+        let result = verify_audit_file(&today_file(&dir), recorded_hash).unwrap();
+        assert!(result.is_valid(), "{result:?}");
+        assert_eq!(result.records, 3);
+    }
+
+    #[test]
+    fn test_tampering_detected() {
+        let dir = TempDir::new().unwrap();
+        let mut writer = AuditWriter::new(dir.path().to_str().unwrap());
+        for post_id in 1..=3 {
+            writer.append(record(post_id)).unwrap();
+        }
+        let path = today_file(&dir);
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+
+        // Edited result on line 2
+        let edited = lines[1].replace("\"sent\"", "\"rejected\"");
+        std::fs::write(&path, format!("{}\n{edited}\n{}\n", lines[0], lines[2])).unwrap();
+        let result = verify_audit_file(&path, recorded_hash).unwrap();
+        assert_eq!(result.records, 1);
+        assert_eq!(result.failure.unwrap().0, 2);
+
+        // Dropped line 2
+        std::fs::write(&path, format!("{}\n{}\n", lines[0], lines[2])).unwrap();
+        let result = verify_audit_file(&path, recorded_hash).unwrap();
+        assert_eq!(result.failure.unwrap().0, 2);
+
+        // Action hash no longer matches the action
+        std::fs::write(&path, &content).unwrap();
+        let result = verify_audit_file(&path, |_| Some("0xother".to_string())).unwrap();
+        assert_eq!(result.records, 0);
+        assert!(result.failure.unwrap().1.contains("action_hash mismatch"));
+    }
+}
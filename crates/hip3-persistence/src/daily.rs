@@ -0,0 +1,282 @@
+//! Daily rotating JSON Lines writer.
+//!
+//! [`DailyJsonlWriter`] buffers records and appends them to
+//! `{prefix}_YYYY-MM-DD.jsonl` in its base directory, starting a new file
+//! at each UTC date change. With a size cap, files also rotate when they
+//! reach it: `{prefix}_YYYY-MM-DD_NNN.jsonl`. A restart appends to the
+//! current file. The record type names the file through [`DailyRecord`].
+
+use crate::error::PersistenceResult;
+use chrono::Utc;
+use serde::Serialize;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use tracing::{debug, info, warn};
+
+/// A record type written to daily JSON Lines files.
+pub trait DailyRecord: Serialize {
+    /// File name prefix (e.g. "trades" for `trades_YYYY-MM-DD.jsonl`).
+    const FILE_PREFIX: &'static str;
+}
+
+/// Active file writer state.
+struct ActiveFile {
+    writer: BufWriter<File>,
+    date: String,
+    seq: u32,
+    bytes_written: u64,
+    records_written: usize,
+}
+
+/// Buffered JSON Lines writer rotating daily (and by size, if capped).
+pub struct DailyJsonlWriter<T: DailyRecord> {
+    /// Base directory for output files.
+    base_dir: String,
+    /// Buffer of pending records.
+    buffer: Vec<T>,
+    /// Maximum buffer size before flush.
+    max_buffer_size: usize,
+    /// File size (bytes) after which the next file is started (0 = none).
+    max_file_bytes: u64,
+    /// Active writer (open until rotation).
+    active_writer: Option<ActiveFile>,
+}
+
+impl<T: DailyRecord> DailyJsonlWriter<T> {
+    /// Create a writer flushing every `max_buffer_size` records.
+    pub fn new(base_dir: &str, max_buffer_size: usize) -> Self {
+        if let Err(e) = std::fs::create_dir_all(base_dir) {
+            warn!(?e, "Failed to create directory: {}", base_dir);
+        }
+
+        Self {
+            base_dir: base_dir.to_string(),
+            buffer: Vec::with_capacity(max_buffer_size),
+            max_buffer_size,
+            max_file_bytes: 0,
+            active_writer: None,
+        }
+    }
+
+    /// Also rotate files once they reach `max_file_bytes` (numbered files).
+    #[must_use]
+    pub fn with_max_file_bytes(mut self, max_file_bytes: u64) -> Self {
+        self.max_file_bytes = max_file_bytes;
+        self
+    }
+
+    /// Add a record to the buffer, flushing when it is full.
+    pub fn add_record(&mut self, record: T) -> PersistenceResult<()> {
+        self.buffer.push(record);
+
+        if self.buffer.len() >= self.max_buffer_size {
+            self.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Records buffered and not yet written.
+    #[must_use]
+    pub fn pending(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn path(&self, date: &str, seq: u32) -> String {
+        if self.max_file_bytes == 0 {
+            format!("{}/{}_{}.jsonl", self.base_dir, T::FILE_PREFIX, date)
+        } else {
+            format!(
+                "{}/{}_{}_{:03}.jsonl",
+                self.base_dir,
+                T::FILE_PREFIX,
+                date,
+                seq
+            )
+        }
+    }
+
+    /// Close the active writer.
+    fn close_active_writer(&mut self) -> PersistenceResult<()> {
+        if let Some(mut active) = self.active_writer.take() {
+            if let Err(e) = active.writer.flush() {
+                warn!(
+                    ?e,
+                    kind = T::FILE_PREFIX,
+                    "Failed to flush JSON Lines writer on close"
+                );
+            }
+            info!(
+                kind = T::FILE_PREFIX,
+                date = %active.date,
+                seq = active.seq,
+                records = active.records_written,
+                "Closed JSON Lines writer"
+            );
+        }
+        Ok(())
+    }
+
+    /// Open the first file of `date` from `seq` on that is below the size cap
+    /// (a restart appends to the last one).
+    fn create_new_writer(&mut self, date: &str, mut seq: u32) -> PersistenceResult<()> {
+        let mut filename = self.path(date, seq);
+        let mut existing = std::fs::metadata(&filename).map_or(0, |m| m.len());
+        while self.max_file_bytes > 0 && existing >= self.max_file_bytes {
+            seq += 1;
+            filename = self.path(date, seq);
+            existing = std::fs::metadata(&filename).map_or(0, |m| m.len());
+        }
+
+        info!(filename = %filename, "Opening JSON Lines writer (append mode)");
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&filename)?;
+
+        self.active_writer = Some(ActiveFile {
+            writer: BufWriter::new(file),
+            date: date.to_string(),
+            seq,
+            bytes_written: existing,
+            records_written: 0,
+        });
+
+        Ok(())
+    }
+
+    /// Flush the buffer to the active file, rotating by date and size.
+    pub fn flush(&mut self) -> PersistenceResult<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let mut next_seq = 0;
+        if let Some(active) = self.active_writer.as_ref() {
+            if active.date != today {
+                self.close_active_writer()?;
+            } else if self.max_file_bytes > 0 && active.bytes_written >= self.max_file_bytes {
+                next_seq = active.seq + 1;
+                self.close_active_writer()?;
+            }
+        }
+
+        if self.active_writer.is_none() {
+            self.create_new_writer(&today, next_seq)?;
+        }
+
+        let record_count = self.buffer.len();
+
+        {
+            let active = self
+                .active_writer
+                .as_mut()
+                .expect("BUG: active_writer is None after create_new_writer");
+
+            for record in &self.buffer {
+                let json = serde_json::to_string(record)?;
+                writeln!(active.writer, "{}", json)?;
+                active.bytes_written += json.len() as u64 + 1;
+            }
+
+            active.writer.flush()?;
+            active.records_written += record_count;
+        }
+
+        debug!(
+            kind = T::FILE_PREFIX,
+            date = %today,
+            records = record_count,
+            "Flushed records to JSON Lines"
+        );
+
+        self.buffer.clear();
+
+        Ok(())
+    }
+
+    /// Close the writer, flushing any pending data.
+    pub fn close(&mut self) -> PersistenceResult<()> {
+        self.flush()?;
+        self.close_active_writer()
+    }
+}
+
+impl<T: DailyRecord> Drop for DailyJsonlWriter<T> {
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            warn!(
+                ?e,
+                kind = T::FILE_PREFIX,
+                "Failed to flush JSON Lines buffer on drop"
+            );
+        }
+        if let Err(e) = self.close_active_writer() {
+            warn!(
+                ?e,
+                kind = T::FILE_PREFIX,
+                "Failed to close JSON Lines writer on drop"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use tempfile::TempDir;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Line {
+        n: u32,
+    }
+
+    impl DailyRecord for Line {
+        const FILE_PREFIX: &'static str = "lines";
+    }
+
+    fn read(path: std::path::PathBuf) -> Vec<Line> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_buffers_and_rotates_by_size() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path().to_str().unwrap();
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+
+        // Flushed when the buffer fills, the rest on drop
+        {
+            let mut writer = DailyJsonlWriter::new(dir, 2);
+            for n in 0..3 {
+                writer.add_record(Line { n }).unwrap();
+            }
+            assert_eq!(writer.pending(), 1);
+        }
+        let path = temp_dir.path().join(format!("lines_{today}.jsonl"));
+        assert_eq!(read(path).len(), 3);
+
+        // Each 8-byte line fills a 8-byte file: one line per numbered file
+        {
+            let mut writer = DailyJsonlWriter::new(dir, 1).with_max_file_bytes(8);
+            writer.add_record(Line { n: 0 }).unwrap();
+            writer.add_record(Line { n: 1 }).unwrap();
+        }
+        // A restart continues after the full files
+        {
+            let mut writer = DailyJsonlWriter::new(dir, 1).with_max_file_bytes(8);
+            writer.add_record(Line { n: 2 }).unwrap();
+        }
+        for n in 0..3 {
+            let path = temp_dir.path().join(format!("lines_{today}_{n:03}.jsonl"));
+            assert_eq!(read(path), vec![Line { n }]);
+        }
+    }
+}
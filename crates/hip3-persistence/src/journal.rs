@@ -0,0 +1,164 @@
+//! Position lifecycle journal.
+//!
+//! One [`PositionJournalRecord`] per fill that changes a position: open,
+//! scale-in, partial close, close, or flip. [`PositionJournalWriter`]
+//! appends them to daily `positions_YYYY-MM-DD.jsonl` files, so a position
+//! can be followed from open to close without reconstructing it from logs.
+
+use crate::daily::{DailyJsonlWriter, DailyRecord};
+use hip3_core::OrderSide;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// What a fill did to a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PositionEvent {
+    /// Opened a new position.
+    Open,
+    /// Added to the position on the same side.
+    Scale,
+    /// Reduced the position without closing it.
+    PartialClose,
+    /// Closed the position exactly.
+    Close,
+    /// Closed the position and opened one on the other side.
+    Flip,
+}
+
+impl PositionEvent {
+    /// Classify a fill against the position before it (side and size).
+    #[must_use]
+    pub fn classify(
+        before: Option<(OrderSide, Decimal)>,
+        fill_side: OrderSide,
+        fill_size: Decimal,
+    ) -> Self {
+        match before {
+            None => Self::Open,
+            Some((_, size)) if size.is_zero() => Self::Open,
+            Some((side, _)) if side == fill_side => Self::Scale,
+            Some((_, size)) if fill_size < size => Self::PartialClose,
+            Some((_, size)) if fill_size == size => Self::Close,
+            Some(_) => Self::Flip,
+        }
+    }
+
+    /// Whether the fill reduced the position.
+    #[must_use]
+    pub fn is_reducing(&self) -> bool {
+        matches!(self, Self::PartialClose | Self::Close | Self::Flip)
+    }
+}
+
+/// One position lifecycle event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PositionJournalRecord {
+    /// Exchange fill time (Unix ms).
+    pub timestamp_ms: u64,
+    /// What the fill did to the position.
+    pub event: PositionEvent,
+    /// Market key (e.g., "xyz:0").
+    pub market_key: String,
+    /// Position side after the fill (long/short), or the closed side on close.
+    pub side: String,
+    /// Client order ID of the filled order, if known.
+    pub cloid: Option<String>,
+    /// How the position was entered (taker, mm).
+    pub entry_source: String,
+
+    /// Fill price.
+    pub fill_px: f64,
+    /// Fill size.
+    pub fill_size: f64,
+    /// Position size before the fill.
+    pub size_before: f64,
+    /// Position size after the fill.
+    pub size_after: f64,
+    /// Average entry price after the fill (before it on close).
+    pub entry_px: f64,
+    /// Signal edge at entry (bps), if the position came from a signal.
+    pub entry_edge_bps: Option<f64>,
+
+    /// Exit reason (reducing fills only).
+    pub exit_reason: Option<String>,
+    /// Realized PnL before fees (USD, reducing fills only).
+    pub realized_pnl_usd: Option<f64>,
+}
+
+impl DailyRecord for PositionJournalRecord {
+    const FILE_PREFIX: &'static str = "positions";
+}
+
+/// JSON Lines writer for the position journal.
+pub type PositionJournalWriter = DailyJsonlWriter<PositionJournalRecord>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_classify_events() {
+        let long = Some((OrderSide::Buy, dec!(2)));
+        assert_eq!(
+            PositionEvent::classify(None, OrderSide::Buy, dec!(1)),
+            PositionEvent::Open
+        );
+        assert_eq!(
+            PositionEvent::classify(long, OrderSide::Buy, dec!(1)),
+            PositionEvent::Scale
+        );
+        assert_eq!(
+            PositionEvent::classify(long, OrderSide::Sell, dec!(1)),
+            PositionEvent::PartialClose
+        );
+        assert_eq!(
+            PositionEvent::classify(long, OrderSide::Sell, dec!(2)),
+            PositionEvent::Close
+        );
+        assert_eq!(
+            PositionEvent::classify(long, OrderSide::Sell, dec!(3)),
+            PositionEvent::Flip
+        );
+        assert!(!PositionEvent::Scale.is_reducing());
+        assert!(PositionEvent::Flip.is_reducing());
+    }
+
+    #[test]
+    fn test_records_appended_to_daily_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let record = PositionJournalRecord {
+            timestamp_ms: 31_000,
+            event: PositionEvent::PartialClose,
+            market_key: "xyz:0".to_string(),
+            side: "long".to_string(),
+            cloid: Some("0xabc".to_string()),
+            entry_source: "taker".to_string(),
+            fill_px: 2637.0,
+            fill_size: 0.01,
+            size_before: 0.02,
+            size_after: 0.01,
+            entry_px: 2634.5,
+            entry_edge_bps: Some(42.0),
+            exit_reason: Some("TakeProfit".to_string()),
+            realized_pnl_usd: Some(0.025),
+        };
+        {
+            let mut writer = PositionJournalWriter::new(temp_dir.path().to_str().unwrap(), 10);
+            writer.add_record(record.clone()).unwrap();
+        }
+
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let content =
+            std::fs::read_to_string(temp_dir.path().join(format!("positions_{today}.jsonl")))
+                .unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains(r#""event":"partial_close""#));
+        let read: PositionJournalRecord = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(read, record);
+    }
+}
@@ -8,8 +8,40 @@
 //! - Partial file corruption only affects individual lines
 //! - Can be read even if write was interrupted
 
+pub mod audit;
+pub mod daily;
 pub mod error;
+pub mod feed;
+pub mod frames;
+pub mod journal;
+pub mod loss_limits;
+pub mod nonce;
+pub mod positions;
+pub mod risk_events;
+pub mod state;
+pub mod tca;
+pub mod trades;
 pub mod writer;
 
+pub use audit::{
+    record_hash, verify_audit_file, AuditVerification, AuditWriter, SignedActionRecord,
+    GENESIS_HASH,
+};
+pub use daily::{DailyJsonlWriter, DailyRecord};
 pub use error::{PersistenceError, PersistenceResult};
-pub use writer::{FollowupRecord, FollowupWriter, JsonLinesWriter, ParquetWriter, SignalRecord};
+pub use feed::{FeedReader, FeedRecord, FeedWriter};
+pub use frames::{FrameReader, FrameRecord, FrameWriter};
+pub use journal::{PositionEvent, PositionJournalRecord, PositionJournalWriter};
+pub use loss_limits::{LossLimitState, LossLimitStore, MarketLossRecord};
+pub use nonce::{NonceSnapshot, NonceStore};
+pub use positions::{
+    FlattenClaim, PendingOrderRecord, PositionRecord, PositionState, PositionStateStore,
+};
+pub use risk_events::{read_risk_events, RiskEventRecord, RiskEventWriter};
+pub use state::{MarketWarmState, StateStore, WarmState};
+pub use tca::{TcaRecord, TcaWriter};
+pub use trades::{TradeLedgerWriter, TradeRecord};
+pub use writer::{
+    FollowupRecord, FollowupWriter, JsonLinesWriter, ParquetWriter, SignalRecord,
+    SignalRejectRecord, SignalRejectWriter,
+};
@@ -0,0 +1,132 @@
+//! Loss limit state snapshot.
+//!
+//! Daily and weekly loss limits, and per-market daily loss budgets, latch
+//! on realized PnL the bot accumulates itself. A restart that starts them from zero quietly lifts a latched
+//! limit, so [`LossLimitStore`] keeps that state in a small JSON file that is
+//! replaced atomically, like the warm-state snapshot. Unlike the warm state it is restored at any age:
+//! the gates roll the day and week from `saved_at_ms` themselves.
+
+use crate::error::PersistenceResult;
+use hip3_core::MarketKey;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+
+/// Daily loss of one market against its loss budget.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketLossRecord {
+    /// Market key.
+    pub market: MarketKey,
+    /// Realized PnL of the day (USD).
+    pub pnl_usd: Decimal,
+    /// Loss budget exhausted for the day.
+    pub exhausted: bool,
+}
+
+/// Snapshot of loss limit state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LossLimitState {
+    /// Time the snapshot was taken (Unix ms).
+    pub saved_at_ms: i64,
+    /// Realized PnL of the UTC day (USD).
+    #[serde(default)]
+    pub daily_pnl_usd: f64,
+    /// Realized PnL of the UTC week (USD).
+    #[serde(default)]
+    pub weekly_pnl_usd: f64,
+    /// Daily loss limit latched.
+    #[serde(default)]
+    pub daily_latched: bool,
+    /// Weekly loss limit latched.
+    #[serde(default)]
+    pub weekly_latched: bool,
+    /// Per-market daily losses.
+    #[serde(default)]
+    pub market_losses: Vec<MarketLossRecord>,
+}
+
+/// Reads and writes the loss limit snapshot file.
+pub struct LossLimitStore {
+    path: PathBuf,
+}
+
+impl LossLimitStore {
+    /// Create a store for the given snapshot path.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Snapshot file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Atomically replace the snapshot file.
+    pub fn save(&self, state: &LossLimitState) -> PersistenceResult<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec(state)?)?;
+        fs::rename(&tmp, &self.path)?;
+        debug!(
+            path = %self.path.display(),
+            daily_latched = state.daily_latched,
+            weekly_latched = state.weekly_latched,
+            "Saved loss limit state"
+        );
+        Ok(())
+    }
+
+    /// Load the snapshot, or `Ok(None)` if there is none.
+    pub fn load(&self) -> PersistenceResult<Option<LossLimitState>> {
+        let bytes = match fs::read(&self.path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let state: LossLimitState = serde_json::from_slice(&bytes)?;
+        info!(
+            path = %self.path.display(),
+            saved_at_ms = state.saved_at_ms,
+            daily_latched = state.daily_latched,
+            weekly_latched = state.weekly_latched,
+            markets = state.market_losses.len(),
+            "Loaded loss limit state"
+        );
+        Ok(Some(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = LossLimitStore::new(temp_dir.path().join("state/loss_limits.json"));
+
+        assert_eq!(store.load().unwrap(), None);
+
+        let state = LossLimitState {
+            saved_at_ms: 1_704_276_000_000,
+            daily_pnl_usd: -25.0,
+            weekly_pnl_usd: -60.0,
+            daily_latched: true,
+            weekly_latched: true,
+            market_losses: vec![MarketLossRecord {
+                market: MarketKey::new(DexId::XYZ, AssetId::new(3)),
+                pnl_usd: dec!(-20),
+                exhausted: true,
+            }],
+        };
+        store.save(&state).unwrap();
+        assert_eq!(store.load().unwrap(), Some(state));
+    }
+}
@@ -0,0 +1,92 @@
+//! Last-used nonce snapshot.
+//!
+//! The exchange rejects nonces at or below ones it has already seen. A fast
+//! restart can start the nonce counter below the previous run's last nonce,
+//! so [`NonceStore`] keeps that nonce in a small JSON file that is replaced
+//! atomically, like the warm-state snapshot.
+
+use crate::error::PersistenceResult;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+
+/// Persisted nonce state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonceSnapshot {
+    /// Last nonce issued by the previous run.
+    pub last_nonce: u64,
+    /// Time the snapshot was taken (Unix ms).
+    pub saved_at_ms: i64,
+}
+
+/// Reads and writes the nonce snapshot file.
+pub struct NonceStore {
+    path: PathBuf,
+}
+
+impl NonceStore {
+    /// Create a store for the given snapshot path.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Snapshot file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Atomically replace the snapshot file.
+    pub fn save(&self, snapshot: &NonceSnapshot) -> PersistenceResult<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec(snapshot)?)?;
+        fs::rename(&tmp, &self.path)?;
+        debug!(
+            path = %self.path.display(),
+            last_nonce = snapshot.last_nonce,
+            "Saved nonce"
+        );
+        Ok(())
+    }
+
+    /// Load the snapshot, or `Ok(None)` if there is none.
+    pub fn load(&self) -> PersistenceResult<Option<NonceSnapshot>> {
+        let bytes = match fs::read(&self.path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let snapshot: NonceSnapshot = serde_json::from_slice(&bytes)?;
+        info!(
+            path = %self.path.display(),
+            last_nonce = snapshot.last_nonce,
+            saved_at_ms = snapshot.saved_at_ms,
+            "Loaded nonce"
+        );
+        Ok(Some(snapshot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = NonceStore::new(temp_dir.path().join("state/nonce.json"));
+
+        assert_eq!(store.load().unwrap(), None);
+
+        let snapshot = NonceSnapshot {
+            last_nonce: 1_700_000_000_123,
+            saved_at_ms: 1_700_000_000_100,
+        };
+        store.save(&snapshot).unwrap();
+        assert_eq!(store.load().unwrap(), Some(snapshot));
+    }
+}
@@ -0,0 +1,212 @@
+//! Position state snapshot for crash recovery.
+//!
+//! The exchange knows a position's size and average entry, but not what the
+//! bot knew about it: when it was opened, the edge of the signal behind it,
+//! its exit profile, or which reduce-only orders were closing it. After a
+//! crash the API sync alone resets all of that to defaults.
+//! [`PositionStateStore`] periodically writes that state to a small JSON file,
+//! replaced atomically like the warm-state snapshot, so startup can merge it
+//! back into the API positions.
+
+use crate::error::PersistenceResult;
+use hip3_core::{ExitProfile, MarketKey, OrderSide, Price, Size, TrackedOrder};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+/// Per-position metadata.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PositionRecord {
+    /// Market key.
+    pub market: MarketKey,
+    /// Position side.
+    pub side: OrderSide,
+    /// Position size.
+    pub size: Size,
+    /// Average entry price.
+    pub entry_price: Price,
+    /// Time the position was opened (Unix ms).
+    pub entry_timestamp_ms: u64,
+    /// Entry edge of the opening signal (bps).
+    #[serde(default)]
+    pub entry_edge_bps: Option<Decimal>,
+    /// Exit profile of the opening signal.
+    #[serde(default)]
+    pub exit_profile: Option<ExitProfile>,
+}
+
+/// A pending order and its exchange order ID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingOrderRecord {
+    /// Tracked order state.
+    pub order: TrackedOrder,
+    /// Exchange order ID, once assigned.
+    #[serde(default)]
+    pub oid: Option<u64>,
+}
+
+/// An active flatten claim and its exit reason.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlattenClaim {
+    /// Market being flattened.
+    pub market: MarketKey,
+    /// Exit reason of the claim.
+    pub reason: String,
+}
+
+/// Snapshot of position tracker state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PositionState {
+    /// Time the snapshot was taken (Unix ms).
+    pub saved_at_ms: i64,
+    /// Open positions.
+    #[serde(default)]
+    pub positions: Vec<PositionRecord>,
+    /// Pending orders.
+    #[serde(default)]
+    pub pending_orders: Vec<PendingOrderRecord>,
+    /// Active flatten claims.
+    #[serde(default)]
+    pub flattening: Vec<FlattenClaim>,
+}
+
+impl PositionState {
+    /// Metadata of the position in `market`, if it was open.
+    pub fn position(&self, market: &MarketKey) -> Option<&PositionRecord> {
+        self.positions.iter().find(|p| p.market == *market)
+    }
+}
+
+/// Reads and writes the position state snapshot file.
+pub struct PositionStateStore {
+    path: PathBuf,
+}
+
+impl PositionStateStore {
+    /// Create a store for the given snapshot path.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Snapshot file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Atomically replace the snapshot file.
+    pub fn save(&self, state: &PositionState) -> PersistenceResult<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec(state)?)?;
+        fs::rename(&tmp, &self.path)?;
+        debug!(
+            path = %self.path.display(),
+            positions = state.positions.len(),
+            pending_orders = state.pending_orders.len(),
+            "Saved position state"
+        );
+        Ok(())
+    }
+
+    /// Load the snapshot if it exists and is at most `max_age_ms` old.
+    ///
+    /// Returns `Ok(None)` for a missing or stale snapshot.
+    pub fn load(&self, now_ms: i64, max_age_ms: i64) -> PersistenceResult<Option<PositionState>> {
+        let bytes = match fs::read(&self.path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let state: PositionState = serde_json::from_slice(&bytes)?;
+
+        let age_ms = now_ms - state.saved_at_ms;
+        if age_ms > max_age_ms {
+            warn!(
+                path = %self.path.display(),
+                age_ms,
+                max_age_ms,
+                "Position state too old, ignoring"
+            );
+            return Ok(None);
+        }
+
+        info!(
+            path = %self.path.display(),
+            age_ms,
+            positions = state.positions.len(),
+            pending_orders = state.pending_orders.len(),
+            "Loaded position state"
+        );
+        Ok(Some(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, ClientOrderId, DexId, OrderStrategy, PendingOrder, TimeInForce};
+    use rust_decimal_macros::dec;
+    use tempfile::TempDir;
+
+    fn make_state(saved_at_ms: i64) -> PositionState {
+        let market = MarketKey::new(DexId::XYZ, AssetId::new(2));
+        let order = PendingOrder {
+            cloid: ClientOrderId::new(),
+            market,
+            side: OrderSide::Sell,
+            price: Price::new(dec!(99.5)),
+            size: Size::new(dec!(1)),
+            reduce_only: true,
+            created_at: 1_000,
+            tif: TimeInForce::GoodTilCancelled,
+            validity: None,
+            strategy: OrderStrategy::default(),
+        };
+        PositionState {
+            saved_at_ms,
+            positions: vec![PositionRecord {
+                market,
+                side: OrderSide::Buy,
+                size: Size::new(dec!(1)),
+                entry_price: Price::new(dec!(100)),
+                entry_timestamp_ms: 500,
+                entry_edge_bps: Some(dec!(22)),
+                exit_profile: Some(ExitProfile::Runner),
+            }],
+            pending_orders: vec![PendingOrderRecord {
+                order: TrackedOrder::from_pending(order),
+                oid: Some(42),
+            }],
+            flattening: vec![FlattenClaim {
+                market,
+                reason: "TimeStop".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = PositionStateStore::new(temp_dir.path().join("state/positions.json"));
+
+        assert_eq!(store.load(0, 60_000).unwrap(), None);
+
+        let state = make_state(1_000_000);
+        store.save(&state).unwrap();
+        let loaded = store.load(1_030_000, 60_000).unwrap().unwrap();
+        assert_eq!(loaded, state);
+        assert_eq!(
+            loaded
+                .position(&MarketKey::new(DexId::XYZ, AssetId::new(2)))
+                .and_then(|p| p.exit_profile),
+            Some(ExitProfile::Runner)
+        );
+
+        // Stale snapshots are ignored
+        assert_eq!(store.load(1_060_001, 60_000).unwrap(), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! Risk event audit trail.
+//!
+//! One [`RiskEventRecord`] per gate block, hard stop trigger, reset or
+//! recovery, and operator entry pause or resume. [`RiskEventWriter`]
+//! appends them to daily `risk_events_YYYY-MM-DD.jsonl` files and
+//! [`read_risk_events`] reads back a time window, so the reason trading was
+//! off at any time can be reconstructed without the tracing logs.
+//!
+//! The schema is flat (one JSON object per line, strings and numbers only)
+//! so the files load directly into a dataframe.
+
+use crate::daily::{DailyJsonlWriter, DailyRecord};
+use crate::error::PersistenceResult;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use tracing::debug;
+
+/// One risk event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RiskEventRecord {
+    /// Time of the event (Unix ms).
+    pub timestamp_ms: i64,
+    /// What happened: gate_block, gate_observe, hard_stop_trigger, hard_stop_reset,
+    /// hard_stop_recovered, entries_paused, entries_resumed, risk_config_changed.
+    pub event: String,
+    /// What caused it: the gate (e.g. "MaxDrawdown"), the hard stop reason
+    /// (e.g. "rejections"), "operator" or "auto_recovery".
+    pub source: String,
+    /// Component that raised a hard stop trigger (e.g. "risk_monitor").
+    #[serde(default)]
+    pub component: Option<String>,
+    /// Market key (e.g., "xyz:0"), None for events of all markets.
+    pub market_key: Option<String>,
+    /// Hard stop level after the event (hard stop events only).
+    pub level: Option<String>,
+    /// Hard stop level before the event (hard stop events only).
+    pub previous_level: Option<String>,
+    /// Detail (trigger reason, pause reason, cleared reasons).
+    pub reason: String,
+    /// Repeats of this gate block not recorded since its previous record.
+    pub suppressed: u32,
+}
+
+impl DailyRecord for RiskEventRecord {
+    const FILE_PREFIX: &'static str = "risk_events";
+}
+
+/// JSON Lines writer for risk events.
+pub type RiskEventWriter = DailyJsonlWriter<RiskEventRecord>;
+
+/// Read the risk events in `[from_ms, to_ms]` from the daily files in
+/// `base_dir`, in file order.
+///
+/// Records are filed under the date they were flushed, so the file of the
+/// day after `to_ms` is read too. Missing daily files are skipped, as are
+/// lines that fail to parse (e.g. a torn final line after a crash).
+pub fn read_risk_events(
+    base_dir: impl AsRef<Path>,
+    from_ms: i64,
+    to_ms: i64,
+) -> PersistenceResult<Vec<RiskEventRecord>> {
+    let mut records = Vec::new();
+    let (Some(from), Some(to)) = (
+        DateTime::<Utc>::from_timestamp_millis(from_ms),
+        DateTime::<Utc>::from_timestamp_millis(to_ms),
+    ) else {
+        return Ok(records);
+    };
+
+    let mut date = from.date_naive();
+    let last = to.date_naive() + Duration::days(1);
+    while date <= last {
+        let path = base_dir.as_ref().join(format!(
+            "{}_{}.jsonl",
+            RiskEventRecord::FILE_PREFIX,
+            date.format("%Y-%m-%d")
+        ));
+        date += Duration::days(1);
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str::<RiskEventRecord>(&line) {
+                Ok(record) if (from_ms..=to_ms).contains(&record.timestamp_ms) => {
+                    records.push(record);
+                }
+                Ok(_) => {}
+                Err(e) => debug!(?e, "Skipping malformed risk event line"),
+            }
+        }
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(timestamp_ms: i64, event: &str, source: &str) -> RiskEventRecord {
+        RiskEventRecord {
+            timestamp_ms,
+            event: event.to_string(),
+            source: source.to_string(),
+            component: None,
+            market_key: None,
+            level: None,
+            previous_level: None,
+            reason: String::new(),
+            suppressed: 0,
+        }
+    }
+
+    #[test]
+    fn test_write_and_read_window() {
+        let temp_dir = TempDir::new().unwrap();
+        let base_dir = temp_dir.path().to_str().unwrap();
+        let now_ms = Utc::now().timestamp_millis();
+
+        let trigger = RiskEventRecord {
+            level: Some("full_stop".to_string()),
+            previous_level: Some("clear".to_string()),
+            reason: "Cumulative loss $25 exceeds $20".to_string(),
+            ..record(now_ms - 2_000, "hard_stop_trigger", "cumulative_loss")
+        };
+        let block = RiskEventRecord {
+            market_key: Some("xyz:0".to_string()),
+            suppressed: 3,
+            ..record(now_ms - 1_000, "gate_block", "HardStop")
+        };
+        {
+            let mut writer = RiskEventWriter::new(base_dir, 10);
+            writer
+                .add_record(record(now_ms - 60_000, "entries_paused", "operator"))
+                .unwrap();
+            writer.add_record(trigger.clone()).unwrap();
+            writer.add_record(block.clone()).unwrap();
+        }
+
+        let read = read_risk_events(base_dir, now_ms - 5_000, now_ms).unwrap();
+        assert_eq!(read, vec![trigger, block]);
+        // A window spanning days without files is not an error
+        let read = read_risk_events(base_dir, now_ms - 3 * 86_400_000, now_ms).unwrap();
+        assert_eq!(read.len(), 3);
+    }
+}
@@ -0,0 +1,180 @@
+//! Warm-start state snapshot.
+//!
+//! Slow-moving estimators (spread EWMAs, cross durations, oracle move counts)
+//! take minutes to converge after a cold start, and gates built on them are
+//! noisy until they do. [`StateStore`] periodically writes a small JSON
+//! snapshot of that state and reloads it on startup, discarding snapshots
+//! older than a configured age.
+//!
+//! Unlike the signal logs, the snapshot is a single file that is replaced
+//! atomically (write to `.tmp`, then rename), so a crash mid-write leaves the
+//! previous snapshot intact.
+
+use crate::error::PersistenceResult;
+use hip3_core::{MarketKey, OrderSide, Price};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+/// Per-market warm state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketWarmState {
+    /// Market key.
+    pub market: MarketKey,
+    /// Detector spread EWMA (bps).
+    #[serde(default)]
+    pub spread_ewma_bps: Option<Decimal>,
+    /// RiskGate spread EWMA (bps).
+    #[serde(default)]
+    pub risk_spread_ewma_bps: Option<Decimal>,
+    /// Side of the active cross (CrossDurationTracker).
+    #[serde(default)]
+    pub cross_side: Option<OrderSide>,
+    /// Ticks the active cross has persisted.
+    #[serde(default)]
+    pub cross_ticks: u64,
+    /// Last oracle price seen by the oracle tracker.
+    #[serde(default)]
+    pub oracle_last_px: Option<Price>,
+    /// Consecutive oracle up moves.
+    #[serde(default)]
+    pub oracle_consecutive_up: u32,
+    /// Consecutive oracle down moves.
+    #[serde(default)]
+    pub oracle_consecutive_down: u32,
+}
+
+impl MarketWarmState {
+    /// Empty state for a market.
+    pub fn new(market: MarketKey) -> Self {
+        Self {
+            market,
+            spread_ewma_bps: None,
+            risk_spread_ewma_bps: None,
+            cross_side: None,
+            cross_ticks: 0,
+            oracle_last_px: None,
+            oracle_consecutive_up: 0,
+            oracle_consecutive_down: 0,
+        }
+    }
+}
+
+/// Snapshot of estimator state across all markets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WarmState {
+    /// Time the snapshot was taken (Unix ms).
+    pub saved_at_ms: i64,
+    /// Per-market state.
+    #[serde(default)]
+    pub markets: Vec<MarketWarmState>,
+}
+
+/// Reads and writes the warm-state snapshot file.
+pub struct StateStore {
+    path: PathBuf,
+}
+
+impl StateStore {
+    /// Create a store for the given snapshot path.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Snapshot file path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Atomically replace the snapshot file.
+    pub fn save(&self, state: &WarmState) -> PersistenceResult<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec(state)?)?;
+        fs::rename(&tmp, &self.path)?;
+        debug!(
+            path = %self.path.display(),
+            markets = state.markets.len(),
+            "Saved warm state"
+        );
+        Ok(())
+    }
+
+    /// Load the snapshot if it exists and is at most `max_age_ms` old.
+    ///
+    /// Returns `Ok(None)` for a missing or stale snapshot.
+    pub fn load(&self, now_ms: i64, max_age_ms: i64) -> PersistenceResult<Option<WarmState>> {
+        let bytes = match fs::read(&self.path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let state: WarmState = serde_json::from_slice(&bytes)?;
+
+        let age_ms = now_ms - state.saved_at_ms;
+        if age_ms > max_age_ms {
+            warn!(
+                path = %self.path.display(),
+                age_ms,
+                max_age_ms,
+                "Warm state too old, starting cold"
+            );
+            return Ok(None);
+        }
+
+        info!(
+            path = %self.path.display(),
+            age_ms,
+            markets = state.markets.len(),
+            "Loaded warm state"
+        );
+        Ok(Some(state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+    use tempfile::TempDir;
+
+    fn make_state(saved_at_ms: i64) -> WarmState {
+        let mut market = MarketWarmState::new(MarketKey::new(DexId::XYZ, AssetId::new(3)));
+        market.spread_ewma_bps = Some(dec!(12.5));
+        market.risk_spread_ewma_bps = Some(dec!(8));
+        market.cross_side = Some(OrderSide::Sell);
+        market.cross_ticks = 4;
+        market.oracle_last_px = Some(Price::new(dec!(101.25)));
+        market.oracle_consecutive_down = 2;
+        WarmState {
+            saved_at_ms,
+            markets: vec![market],
+        }
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = StateStore::new(temp_dir.path().join("state/warm.json"));
+
+        assert_eq!(store.load(0, 60_000).unwrap(), None);
+
+        let state = make_state(1_000_000);
+        store.save(&state).unwrap();
+        assert_eq!(store.load(1_030_000, 60_000).unwrap(), Some(state));
+    }
+
+    #[test]
+    fn test_stale_snapshot_ignored() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = StateStore::new(temp_dir.path().join("warm.json"));
+
+        store.save(&make_state(1_000_000)).unwrap();
+        assert_eq!(store.load(1_060_001, 60_000).unwrap(), None);
+    }
+}
@@ -0,0 +1,113 @@
+//! Transaction cost analysis records.
+//!
+//! One [`TcaRecord`] per entry fill, joining the fill back to the signal
+//! that produced the order. [`TcaWriter`] appends them to daily
+//! `tca_YYYY-MM-DD.jsonl` files for offline analysis.
+
+use crate::daily::{DailyJsonlWriter, DailyRecord};
+use serde::{Deserialize, Serialize};
+
+/// Execution quality of one entry fill.
+///
+/// Cost fields are signed so that positive always means worse for us.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcaRecord {
+    /// Signal that produced the order.
+    pub signal_id: String,
+    /// Client order ID of the filled order.
+    pub cloid: String,
+    /// Market key (e.g., "xyz:0").
+    pub market_key: String,
+    /// Trade side (buy/sell).
+    pub side: String,
+
+    /// Signal detection time (Unix ms).
+    pub signal_timestamp_ms: i64,
+    /// Time the order was queued (Unix ms).
+    pub queued_at_ms: i64,
+    /// Exchange fill time (Unix ms).
+    pub fill_timestamp_ms: i64,
+
+    /// Oracle price at signal time.
+    pub oracle_px: f64,
+    /// Best price at signal time.
+    pub signal_best_px: f64,
+    /// Fill price.
+    pub fill_px: f64,
+    /// Fill size.
+    pub fill_size: f64,
+    /// Fill notional (USD).
+    pub notional_usd: f64,
+
+    /// Raw edge at signal time (bps).
+    pub signal_edge_bps: f64,
+    /// Fill price vs signal best_px (bps, positive = paid through).
+    pub slippage_bps: f64,
+    /// Fee paid (USD).
+    pub fee_usd: f64,
+    /// Fee as a fraction of notional (bps).
+    pub fee_bps: f64,
+    /// Edge left after slippage and fee (bps).
+    pub captured_edge_bps: f64,
+    /// captured_edge_bps / signal_edge_bps (0 if the signal had no edge).
+    pub edge_capture_ratio: f64,
+
+    /// Signal detection to order queued (ms).
+    pub signal_to_queue_ms: i64,
+    /// Signal detection to exchange fill (ms).
+    pub signal_to_fill_ms: i64,
+}
+
+impl DailyRecord for TcaRecord {
+    const FILE_PREFIX: &'static str = "tca";
+}
+
+/// JSON Lines writer for TCA records.
+pub type TcaWriter = DailyJsonlWriter<TcaRecord>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_records_appended_to_daily_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let record = TcaRecord {
+            signal_id: "sig-1".to_string(),
+            cloid: "0xabc".to_string(),
+            market_key: "xyz:0".to_string(),
+            side: "buy".to_string(),
+            signal_timestamp_ms: 1_000,
+            queued_at_ms: 1_002,
+            fill_timestamp_ms: 1_150,
+            oracle_px: 2650.0,
+            signal_best_px: 2634.0,
+            fill_px: 2634.5,
+            fill_size: 0.01,
+            notional_usd: 26.345,
+            signal_edge_bps: 60.4,
+            slippage_bps: 1.9,
+            fee_usd: 0.0119,
+            fee_bps: 4.5,
+            captured_edge_bps: 54.0,
+            edge_capture_ratio: 0.894,
+            signal_to_queue_ms: 2,
+            signal_to_fill_ms: 150,
+        };
+        {
+            let mut writer = TcaWriter::new(temp_dir.path().to_str().unwrap(), 10);
+            writer.add_record(record.clone()).unwrap();
+            writer.add_record(record.clone()).unwrap();
+        }
+
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let content =
+            std::fs::read_to_string(temp_dir.path().join(format!("tca_{today}.jsonl"))).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let read: TcaRecord = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(read, record);
+    }
+}
@@ -0,0 +1,113 @@
+//! Trade ledger.
+//!
+//! One [`TradeRecord`] per closing fill, attributing realized PnL to how
+//! the position was entered and what closed it. [`TradeLedgerWriter`]
+//! appends them to daily `trades_YYYY-MM-DD.jsonl` files.
+
+use crate::daily::{DailyJsonlWriter, DailyRecord};
+use serde::{Deserialize, Serialize};
+
+/// Realized PnL of one closing fill.
+///
+/// A position closed by several fills produces several records; fees
+/// include the entry fees of the closed share of the position.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeRecord {
+    /// Market key (e.g., "xyz:0").
+    pub market_key: String,
+    /// Position side (long/short).
+    pub side: String,
+    /// How the position was entered (taker, mm).
+    pub entry_source: String,
+    /// What closed it (TimeStop, MarkRegression, OracleReversal, HardStop, Manual, ...).
+    pub exit_reason: String,
+    /// Client order ID of the closing order, if known.
+    pub cloid: Option<String>,
+
+    /// Position open time (Unix ms).
+    pub opened_at_ms: u64,
+    /// Closing fill time (Unix ms).
+    pub closed_at_ms: u64,
+    /// Time the position was held (ms).
+    pub hold_time_ms: u64,
+
+    /// Average entry price.
+    pub entry_px: f64,
+    /// Closing fill price.
+    pub exit_px: f64,
+    /// Closed size.
+    pub size: f64,
+    /// Closed notional at the exit price (USD).
+    pub notional_usd: f64,
+
+    /// PnL before fees and funding (USD).
+    pub gross_pnl_usd: f64,
+    /// Gross PnL relative to entry (bps).
+    pub gross_pnl_bps: f64,
+    /// Entry and exit fees (USD).
+    pub fees_usd: f64,
+    /// Funding paid while open (USD, positive = paid).
+    pub funding_usd: f64,
+    /// gross - fees - funding (USD).
+    pub net_pnl_usd: f64,
+
+    /// Max adverse excursion: worst unrealized PnL of the position between
+    /// entry and this fill, at the exit-side BBO (bps, <= 0).
+    #[serde(default)]
+    pub mae_bps: f64,
+    /// Max adverse excursion of the whole position (USD, <= 0).
+    #[serde(default)]
+    pub mae_usd: f64,
+}
+
+impl DailyRecord for TradeRecord {
+    const FILE_PREFIX: &'static str = "trades";
+}
+
+/// JSON Lines writer for the trade ledger.
+pub type TradeLedgerWriter = DailyJsonlWriter<TradeRecord>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_trades_appended_to_daily_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let record = TradeRecord {
+            market_key: "xyz:0".to_string(),
+            side: "long".to_string(),
+            entry_source: "taker".to_string(),
+            exit_reason: "TimeStop".to_string(),
+            cloid: Some("0xabc".to_string()),
+            opened_at_ms: 1_000,
+            closed_at_ms: 31_000,
+            hold_time_ms: 30_000,
+            entry_px: 2634.5,
+            exit_px: 2637.0,
+            size: 0.01,
+            notional_usd: 26.37,
+            gross_pnl_usd: 0.025,
+            gross_pnl_bps: 9.49,
+            fees_usd: 0.0237,
+            funding_usd: 0.0,
+            net_pnl_usd: 0.0013,
+            mae_bps: -6.2,
+            mae_usd: -0.0163,
+        };
+        {
+            let mut writer = TradeLedgerWriter::new(temp_dir.path().to_str().unwrap(), 10);
+            writer.add_record(record.clone()).unwrap();
+        }
+
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let content =
+            std::fs::read_to_string(temp_dir.path().join(format!("trades_{today}.jsonl"))).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 1);
+        let read: TradeRecord = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(read, record);
+    }
+}
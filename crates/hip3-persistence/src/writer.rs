@@ -6,9 +6,11 @@
 //! - Can be read even if write was interrupted
 //! - Easy to convert to Parquet later if needed
 
+use crate::daily::{DailyJsonlWriter, DailyRecord};
 use crate::error::PersistenceResult;
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs::{File, OpenOptions};
 use std::io::{BufWriter, Write};
 use tracing::{debug, info, warn};
@@ -27,6 +29,54 @@ pub struct SignalRecord {
     pub best_size: f64,
     pub suggested_size: f64,
     pub signal_id: String,
+    /// Detector that produced the signal ("dislocation", "lead_lag").
+    #[serde(default)]
+    pub source: String,
+    /// Signal confidence score (0.0-1.0).
+    #[serde(default)]
+    pub confidence: f64,
+    /// Confidence feature vector by name (for offline model training).
+    #[serde(default)]
+    pub features: BTreeMap<String, f64>,
+    /// Entry threshold the edge was judged against (side-specific when
+    /// long/short thresholds are configured).
+    #[serde(default)]
+    pub threshold_bps: f64,
+    /// Signed book size imbalance at detection (-1 .. 1).
+    #[serde(default)]
+    pub book_imbalance: f64,
+    /// How long the cross had persisted at signal time (ms).
+    #[serde(default)]
+    pub cross_duration_ms: u64,
+}
+
+/// Order rejected at submit time, after its signal was recorded.
+///
+/// Joined to [`SignalRecord`] by `signal_id` (empty for orders without a
+/// signal, e.g. entry slices).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalRejectRecord {
+    /// Rejection time (milliseconds since epoch).
+    pub timestamp_ms: i64,
+    /// Signal that produced the order.
+    pub signal_id: String,
+    /// Client order ID of the rejected order.
+    pub cloid: String,
+    /// Market key (e.g., "xyz:0").
+    pub market_key: String,
+    /// Trade side (buy/sell).
+    pub side: String,
+    /// Reject reason (e.g., "SlippageExceeded", "SignalExpired(ttl)").
+    pub reason: String,
+    /// Order limit price.
+    pub limit_px: f64,
+    /// Reference price the slippage was measured against (0 unless
+    /// SlippageExceeded).
+    pub reference_px: f64,
+    /// Implied slippage of the limit price (bps, 0 unless SlippageExceeded).
+    pub slippage_bps: f64,
+    /// Maximum allowed slippage (bps, 0 unless SlippageExceeded).
+    pub max_slippage_bps: f64,
 }
 
 /// Followup snapshot record for signal validation.
@@ -387,6 +437,13 @@ impl Drop for FollowupWriter {
     }
 }
 
+impl DailyRecord for SignalRejectRecord {
+    const FILE_PREFIX: &'static str = "signal_rejects";
+}
+
+/// JSON Lines writer for submit-time signal rejections.
+pub type SignalRejectWriter = DailyJsonlWriter<SignalRejectRecord>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -405,6 +462,12 @@ mod tests {
             best_size: 1.0,
             suggested_size: 0.01,
             signal_id: format!("test_{}", id),
+            source: "dislocation".to_string(),
+            confidence: 0.5,
+            features: BTreeMap::from([("edge".to_string(), 0.25)]),
+            threshold_bps: 10.0,
+            book_imbalance: 0.25,
+            cross_duration_ms: 150,
         }
     }
 
@@ -519,4 +582,35 @@ mod tests {
             .collect();
         assert!(entries.is_empty());
     }
+
+    #[test]
+    fn test_signal_reject_writer() {
+        let temp_dir = TempDir::new().unwrap();
+        let record = SignalRejectRecord {
+            timestamp_ms: 1234567890000,
+            signal_id: "test_1".to_string(),
+            cloid: "0xabc".to_string(),
+            market_key: "xyz:0".to_string(),
+            side: "buy".to_string(),
+            reason: "SlippageExceeded".to_string(),
+            limit_px: 50100.0,
+            reference_px: 50000.0,
+            slippage_bps: 20.0,
+            max_slippage_bps: 10.0,
+        };
+        {
+            let mut writer = SignalRejectWriter::new(temp_dir.path().to_str().unwrap(), 10);
+            writer.add_record(record.clone()).unwrap();
+        }
+
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let content = std::fs::read_to_string(
+            temp_dir
+                .path()
+                .join(format!("signal_rejects_{today}.jsonl")),
+        )
+        .unwrap();
+        let read: SignalRejectRecord = serde_json::from_str(content.trim()).unwrap();
+        assert_eq!(read, record);
+    }
 }
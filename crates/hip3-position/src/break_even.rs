@@ -0,0 +1,162 @@
+//! Break-even stop.
+//!
+//! Once a position's unrealized PnL has reached the activation threshold of
+//! its [`ExitProfile`], the stop is armed: any regression back to entry plus
+//! fees flattens the position immediately, so a trade that was clearly in
+//! profit never turns into a loss.
+//!
+//! PnL is measured at the price the position would exit at (bid for longs,
+//! ask for shorts). The stop level is `fee_bps` above entry, which covers
+//! the round-trip fees. Evaluation lives in `ExitWatcher`, which sees every
+//! BBO update.
+
+use hip3_core::ExitProfile;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Break-even stop configuration, per exit profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BreakEvenConfig {
+    /// Enable the break-even stop. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Activation threshold (bps) for `ExitProfile::Standard`. 0 = never arm.
+    #[serde(default = "default_standard_activation_bps")]
+    pub standard_activation_bps: Decimal,
+
+    /// Activation threshold (bps) for `ExitProfile::Scalper`. 0 = never arm.
+    #[serde(default = "default_scalper_activation_bps")]
+    pub scalper_activation_bps: Decimal,
+
+    /// Activation threshold (bps) for `ExitProfile::Runner`. 0 = never arm.
+    #[serde(default = "default_runner_activation_bps")]
+    pub runner_activation_bps: Decimal,
+
+    /// Stop level above entry (bps), covering round-trip fees.
+    /// Should stay below every activation threshold.
+    #[serde(default = "default_fee_bps")]
+    pub fee_bps: Decimal,
+}
+
+fn default_standard_activation_bps() -> Decimal {
+    Decimal::from(15)
+}
+
+fn default_scalper_activation_bps() -> Decimal {
+    Decimal::from(12)
+}
+
+fn default_runner_activation_bps() -> Decimal {
+    Decimal::from(20)
+}
+
+fn default_fee_bps() -> Decimal {
+    Decimal::from(8) // 4 bps taker fee, in and out
+}
+
+impl Default for BreakEvenConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            standard_activation_bps: default_standard_activation_bps(),
+            scalper_activation_bps: default_scalper_activation_bps(),
+            runner_activation_bps: default_runner_activation_bps(),
+            fee_bps: default_fee_bps(),
+        }
+    }
+}
+
+impl BreakEvenConfig {
+    /// Activation threshold for an exit profile (None if the profile never arms).
+    #[must_use]
+    pub fn activation_bps(&self, profile: ExitProfile) -> Option<Decimal> {
+        let bps = match profile {
+            ExitProfile::Standard => self.standard_activation_bps,
+            ExitProfile::Scalper => self.scalper_activation_bps,
+            ExitProfile::Runner => self.runner_activation_bps,
+        };
+        (bps > Decimal::ZERO).then_some(bps)
+    }
+}
+
+/// Break-even stop state of one position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BreakEvenStop {
+    /// Exit profile the position was opened with.
+    pub profile: ExitProfile,
+    /// Whether the activation threshold has been reached.
+    pub armed: bool,
+}
+
+impl BreakEvenStop {
+    /// Unarmed stop for a new position.
+    #[must_use]
+    pub fn new(profile: ExitProfile) -> Self {
+        Self {
+            profile,
+            armed: false,
+        }
+    }
+
+    /// Update with the current PnL (bps).
+    ///
+    /// Arms the stop once PnL reaches the profile's activation threshold and
+    /// returns true once an armed stop is hit.
+    pub fn update(&mut self, config: &BreakEvenConfig, pnl_bps: Decimal) -> bool {
+        if !self.armed {
+            match config.activation_bps(self.profile) {
+                Some(activation) if pnl_bps >= activation => self.armed = true,
+                _ => return false,
+            }
+        }
+        pnl_bps <= config.fee_bps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_arms_then_stops_at_fees() {
+        let config = BreakEvenConfig::default();
+        let mut stop = BreakEvenStop::new(ExitProfile::Standard);
+
+        // Dips below fees before activation do not exit
+        assert!(!stop.update(&config, dec!(-5)));
+        assert!(!stop.update(&config, dec!(14)));
+        assert!(!stop.armed);
+
+        assert!(!stop.update(&config, dec!(15)));
+        assert!(stop.armed);
+        assert!(!stop.update(&config, dec!(9)));
+        assert!(stop.update(&config, dec!(8)));
+        assert!(stop.update(&config, dec!(-3)));
+    }
+
+    #[test]
+    fn test_profile_thresholds() {
+        let config: BreakEvenConfig = toml::from_str(
+            r#"
+            enabled = true
+            scalper_activation_bps = 0
+            runner_activation_bps = 30
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.activation_bps(ExitProfile::Scalper), None);
+        assert_eq!(config.activation_bps(ExitProfile::Runner), Some(dec!(30)));
+
+        let mut scalper = BreakEvenStop::new(ExitProfile::Scalper);
+        assert!(!scalper.update(&config, dec!(50)));
+        assert!(!scalper.update(&config, dec!(0)));
+
+        let mut runner = BreakEvenStop::new(ExitProfile::Runner);
+        assert!(!runner.update(&config, dec!(20)));
+        assert!(!runner.update(&config, dec!(5)));
+        assert!(!runner.update(&config, dec!(30)));
+        assert!(runner.update(&config, dec!(5)));
+    }
+}
@@ -0,0 +1,567 @@
+//! Pluggable exit strategies.
+//!
+//! [`ExitStrategy`] is the extension point for exit logic that does not
+//! need a dedicated monitor: a strategy sees every market update and a
+//! periodic tick for each open position and may answer with a
+//! [`FlattenRequest`]. Strategies are registered per [`ExitProfile`] in an
+//! [`ExitStrategyRegistry`], so profiles can combine different exits.
+//!
+//! [`ExitStrategyWatcher`] runs the registry: it is fed from the WS handler
+//! like `ExitWatcher`, ticks on its own task, claims the
+//! `SharedFlatteningGuard` with the strategy name and sends the reduce-only
+//! order. New strategies are added in [`ExitStrategyRegistry::from_config`]
+//! without further wiring in the app; the built-in one is the stop-loss
+//! (see [`crate::stop_loss`]).
+//!
+//! The existing monitors (TimeStop, MarkRegression, ExitWatcher,
+//! OracleExitWatcher) keep their dedicated tasks.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::{debug, info, trace, warn};
+
+use hip3_core::types::MarketSnapshot;
+use hip3_core::{ExitProfile, MarketKey, OrderSide, PendingOrder, Price};
+use hip3_feed::RegimeHandle;
+
+use crate::flatten::FlattenRequest;
+use crate::stop_loss::{StopLoss, StopLossConfig};
+use crate::time_stop::{FlattenOrderBuilder, PriceProvider};
+use crate::tracker::{Position, PositionTrackerHandle, SharedFlatteningGuard};
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Configuration of the pluggable exit strategies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExitStrategiesConfig {
+    /// Run the exit strategy watcher. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Interval of `ExitStrategy::on_tick` (ms). Default: 1,000.
+    #[serde(default = "default_tick_interval_ms")]
+    pub tick_interval_ms: u64,
+
+    /// Slippage tolerance for flatten orders (bps). Default: 50.
+    #[serde(default = "default_slippage_bps")]
+    pub slippage_bps: u64,
+
+    /// Stop-loss per exit profile.
+    #[serde(default)]
+    pub stop_loss: StopLossConfig,
+}
+
+fn default_tick_interval_ms() -> u64 {
+    1_000
+}
+
+fn default_slippage_bps() -> u64 {
+    50
+}
+
+impl Default for ExitStrategiesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            tick_interval_ms: default_tick_interval_ms(),
+            slippage_bps: default_slippage_bps(),
+            stop_loss: StopLossConfig::default(),
+        }
+    }
+}
+
+// ============================================================================
+// ExitStrategy
+// ============================================================================
+
+/// Exit logic evaluated for every open position of its exit profile.
+///
+/// Both hooks default to no exit, so a strategy implements only the one it
+/// needs. Per-position state is keyed by market and dropped in
+/// [`ExitStrategy::on_position_closed`].
+pub trait ExitStrategy: Send {
+    /// Name used as the exit reason (guard claim, metrics, trade ledger).
+    fn name(&self) -> &'static str;
+
+    /// Evaluate a position on a BBO or oracle update of its market.
+    fn on_market_update(
+        &mut self,
+        _position: &Position,
+        _snapshot: &MarketSnapshot,
+        _now_ms: u64,
+    ) -> Option<FlattenRequest> {
+        None
+    }
+
+    /// Evaluate a position on the periodic tick.
+    fn on_tick(&mut self, _position: &Position, _now_ms: u64) -> Option<FlattenRequest> {
+        None
+    }
+
+    /// Drop the state of a closed position.
+    fn on_position_closed(&mut self, _market: &MarketKey) {}
+}
+
+// ============================================================================
+// ExitStrategyRegistry
+// ============================================================================
+
+/// Exit strategies per exit profile.
+///
+/// Positions not registered via `on_position_opened` use
+/// `ExitProfile::Standard`. All strategies of the profile are evaluated in
+/// registration order (so stateful ones stay up to date); the first request
+/// wins.
+#[derive(Default)]
+pub struct ExitStrategyRegistry {
+    strategies: HashMap<ExitProfile, Vec<Box<dyn ExitStrategy>>>,
+    profiles: HashMap<MarketKey, ExitProfile>,
+}
+
+impl ExitStrategyRegistry {
+    /// Create an empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the registry of the built-in strategies enabled in `config`.
+    ///
+    /// `regime` widens the stop-loss in turbulent markets.
+    #[must_use]
+    pub fn from_config(config: &ExitStrategiesConfig, regime: Option<RegimeHandle>) -> Self {
+        let mut registry = Self::new();
+        for profile in [
+            ExitProfile::Standard,
+            ExitProfile::Scalper,
+            ExitProfile::Runner,
+        ] {
+            if let Some(bps) = config.stop_loss.stop_bps(profile) {
+                let stop = match regime {
+                    Some(ref regime) if config.stop_loss.widens() => StopLoss::new(bps)
+                        .with_turbulent_widening(
+                            config.stop_loss.turbulent_widen_factor,
+                            regime.clone(),
+                        ),
+                    _ => StopLoss::new(bps),
+                };
+                registry.register(profile, Box::new(stop));
+            }
+        }
+        registry
+    }
+
+    /// Add a strategy to an exit profile.
+    pub fn register(&mut self, profile: ExitProfile, strategy: Box<dyn ExitStrategy>) {
+        self.strategies.entry(profile).or_default().push(strategy);
+    }
+
+    /// Names of the strategies of an exit profile, in evaluation order.
+    #[must_use]
+    pub fn strategy_names(&self, profile: ExitProfile) -> Vec<&'static str> {
+        self.strategies
+            .get(&profile)
+            .map(|s| s.iter().map(|s| s.name()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Whether no strategy is registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.strategies.values().all(Vec::is_empty)
+    }
+
+    /// Record the exit profile of a newly opened position.
+    pub fn on_position_opened(&mut self, market: MarketKey, profile: ExitProfile) {
+        self.on_position_closed(&market);
+        self.profiles.insert(market, profile);
+    }
+
+    /// Forget a closed position.
+    pub fn on_position_closed(&mut self, market: &MarketKey) {
+        self.profiles.remove(market);
+        for strategy in self.strategies.values_mut().flatten() {
+            strategy.on_position_closed(market);
+        }
+    }
+
+    /// Evaluate the strategies of a position on a market update.
+    pub fn on_market_update(
+        &mut self,
+        position: &Position,
+        snapshot: &MarketSnapshot,
+        now_ms: u64,
+    ) -> Option<(&'static str, FlattenRequest)> {
+        self.evaluate(position, |s| s.on_market_update(position, snapshot, now_ms))
+    }
+
+    /// Evaluate the strategies of a position on the periodic tick.
+    pub fn on_tick(
+        &mut self,
+        position: &Position,
+        now_ms: u64,
+    ) -> Option<(&'static str, FlattenRequest)> {
+        self.evaluate(position, |s| s.on_tick(position, now_ms))
+    }
+
+    fn evaluate(
+        &mut self,
+        position: &Position,
+        mut hook: impl FnMut(&mut dyn ExitStrategy) -> Option<FlattenRequest>,
+    ) -> Option<(&'static str, FlattenRequest)> {
+        let profile = self
+            .profiles
+            .get(&position.market)
+            .copied()
+            .unwrap_or_default();
+        let strategies = self.strategies.get_mut(&profile)?;
+        let mut exit = None;
+        for strategy in strategies.iter_mut() {
+            let request = hook(strategy.as_mut());
+            if exit.is_none() {
+                exit = request.map(|r| (strategy.name(), r));
+            }
+        }
+        exit
+    }
+}
+
+// ============================================================================
+// ExitStrategyWatcher
+// ============================================================================
+
+/// Runs an [`ExitStrategyRegistry`] against the open positions.
+pub struct ExitStrategyWatcher {
+    /// Strategies per exit profile.
+    registry: Mutex<ExitStrategyRegistry>,
+
+    /// Handle to position tracker for position lookups.
+    position_handle: PositionTrackerHandle,
+
+    /// Channel to send flatten orders (non-blocking try_send).
+    flatten_tx: mpsc::Sender<PendingOrder>,
+
+    /// Price for flatten orders requested on a tick.
+    price_provider: Arc<dyn PriceProvider>,
+
+    /// Slippage tolerance for flatten orders (bps).
+    slippage_bps: u64,
+
+    /// Local tracking of markets with pending flatten orders.
+    local_flattening: RwLock<HashSet<MarketKey>>,
+
+    /// Shared guard across all exit monitors to prevent duplicate flatten requests.
+    shared_flattening: Option<SharedFlatteningGuard>,
+}
+
+impl ExitStrategyWatcher {
+    /// Create a new ExitStrategyWatcher.
+    #[must_use]
+    pub fn new(
+        registry: ExitStrategyRegistry,
+        position_handle: PositionTrackerHandle,
+        flatten_tx: mpsc::Sender<PendingOrder>,
+        price_provider: Arc<dyn PriceProvider>,
+        slippage_bps: u64,
+        shared_flattening: Option<SharedFlatteningGuard>,
+    ) -> Self {
+        Self {
+            registry: Mutex::new(registry),
+            position_handle,
+            flatten_tx,
+            price_provider,
+            slippage_bps,
+            local_flattening: RwLock::new(HashSet::new()),
+            shared_flattening,
+        }
+    }
+
+    /// Record the exit profile of a newly opened position.
+    pub fn on_position_opened(&self, market: MarketKey, profile: ExitProfile) {
+        self.registry.lock().on_position_opened(market, profile);
+    }
+
+    /// Called when market data is updated (BBO or Oracle).
+    pub fn on_market_update(&self, key: MarketKey, snapshot: &MarketSnapshot) {
+        let Some(position) = self.position_handle.get_position(&key) else {
+            return;
+        };
+        if self.is_flattening(&key) {
+            return;
+        }
+
+        let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+        let exit = self
+            .registry
+            .lock()
+            .on_market_update(&position, snapshot, now_ms);
+        if let Some((name, request)) = exit {
+            let price = match position.side {
+                OrderSide::Buy => snapshot.bbo.bid_price,
+                OrderSide::Sell => snapshot.bbo.ask_price,
+            };
+            self.submit(&position, name, &request, price, now_ms);
+        }
+    }
+
+    /// Run the periodic tick until the flatten channel is closed.
+    pub async fn run(self: Arc<Self>, tick_interval_ms: u64) {
+        info!(tick_interval_ms, "ExitStrategyWatcher started");
+
+        let interval = tokio::time::Duration::from_millis(tick_interval_ms);
+        let mut ticker = tokio::time::interval(interval);
+
+        while !self.flatten_tx.is_closed() {
+            ticker.tick().await;
+
+            let now_ms = chrono::Utc::now().timestamp_millis() as u64;
+            let positions = self.position_handle.positions_snapshot();
+            self.sync_flattening_state(&positions);
+
+            for position in &positions {
+                if self.is_flattening(&position.market) {
+                    continue;
+                }
+                let exit = self.registry.lock().on_tick(position, now_ms);
+                let Some((name, request)) = exit else {
+                    continue;
+                };
+                let Some(price) = self.price_provider.get_price(&position.market) else {
+                    warn!(market = %position.market, strategy = name,
+                        "ExitStrategyWatcher: no price available, skipping flatten");
+                    continue;
+                };
+                self.submit(position, name, &request, price, now_ms);
+            }
+        }
+        info!("Flatten channel closed, stopping ExitStrategyWatcher");
+    }
+
+    /// Clear local flattening state for a market.
+    pub fn clear_flattening(&self, market: &MarketKey) {
+        if self.local_flattening.write().remove(market) {
+            debug!(market = %market, "ExitStrategyWatcher: cleared flattening state");
+        }
+    }
+
+    fn is_flattening(&self, market: &MarketKey) -> bool {
+        if self.local_flattening.read().contains(market) {
+            trace!(market = %market, "ExitStrategyWatcher: already flattening (local)");
+            return true;
+        }
+        self.position_handle.is_flattening(market)
+    }
+
+    /// Drop strategy and flattening state of markets without a position.
+    fn sync_flattening_state(&self, positions: &[Position]) {
+        let position_markets: HashSet<MarketKey> = positions.iter().map(|p| p.market).collect();
+
+        let mut registry = self.registry.lock();
+        let closed: Vec<MarketKey> = registry
+            .profiles
+            .keys()
+            .filter(|m| !position_markets.contains(m))
+            .copied()
+            .collect();
+        for market in &closed {
+            registry.on_position_closed(market);
+        }
+        drop(registry);
+
+        self.local_flattening
+            .write()
+            .retain(|m| position_markets.contains(m) && self.position_handle.is_flattening(m));
+    }
+
+    /// Claim the market and send the reduce-only order of `request`.
+    fn submit(
+        &self,
+        position: &Position,
+        name: &'static str,
+        request: &FlattenRequest,
+        price: Price,
+        now_ms: u64,
+    ) {
+        if let Some(ref guard) = self.shared_flattening {
+            if !guard.try_claim(&position.market, name) {
+                trace!(market = %position.market, strategy = name,
+                    "ExitStrategyWatcher: flatten already claimed by another monitor");
+                return;
+            }
+        }
+        self.local_flattening.write().insert(position.market);
+
+        let order =
+            FlattenOrderBuilder::create_request_order(request, price, self.slippage_bps, now_ms);
+        let held_ms = now_ms.saturating_sub(position.entry_timestamp_ms);
+        hip3_telemetry::Metrics::position_holding_time(
+            &position.market.to_string(),
+            name,
+            held_ms as f64,
+        );
+
+        info!(
+            market = %position.market,
+            side = ?position.side,
+            strategy = name,
+            size = %request.size,
+            held_ms,
+            cloid = %order.cloid,
+            "ExitStrategyWatcher: exit triggered"
+        );
+
+        match self.flatten_tx.try_send(order) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(_)) => {
+                warn!(
+                    market = %position.market,
+                    "ExitStrategyWatcher: flatten channel full, retrying on next update"
+                );
+                self.local_flattening.write().remove(&position.market);
+                if let Some(ref guard) = self.shared_flattening {
+                    guard.release(&position.market);
+                }
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                warn!("ExitStrategyWatcher: flatten channel closed");
+            }
+        }
+    }
+}
+
+/// Thread-safe handle to ExitStrategyWatcher for use from App.
+pub type ExitStrategyWatcherHandle = Arc<ExitStrategyWatcher>;
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flatten::FlattenReason;
+    use hip3_core::{AssetCtx, AssetId, Bbo, DexId, OracleData, Size};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn market() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn long(entry: Decimal) -> Position {
+        Position::new(
+            market(),
+            OrderSide::Buy,
+            Size::new(dec!(1)),
+            Price::new(entry),
+            1_000,
+        )
+    }
+
+    fn snapshot(bid: Decimal, ask: Decimal) -> MarketSnapshot {
+        let bbo = Bbo::new(
+            Price::new(bid),
+            Size::new(dec!(1)),
+            Price::new(ask),
+            Size::new(dec!(1)),
+        );
+        let ctx = AssetCtx::new(
+            OracleData::new(Price::new(bid), Price::new(bid)),
+            dec!(0.0001),
+        );
+        MarketSnapshot::new(bbo, ctx)
+    }
+
+    /// Exits on the first tick after `after_ms` of holding time.
+    struct HoldLimit {
+        after_ms: u64,
+    }
+
+    impl ExitStrategy for HoldLimit {
+        fn name(&self) -> &'static str {
+            "HoldLimit"
+        }
+
+        fn on_tick(&mut self, position: &Position, now_ms: u64) -> Option<FlattenRequest> {
+            (now_ms.saturating_sub(position.entry_timestamp_ms) >= self.after_ms).then(|| {
+                FlattenRequest::full(
+                    position,
+                    FlattenReason::Strategy {
+                        name: self.name().to_string(),
+                    },
+                    now_ms,
+                )
+            })
+        }
+    }
+
+    #[test]
+    fn test_stop_loss_per_profile() {
+        let config = ExitStrategiesConfig::default();
+        let mut registry = ExitStrategyRegistry::from_config(&config, None);
+        assert_eq!(
+            registry.strategy_names(ExitProfile::Scalper),
+            vec![StopLoss::NAME]
+        );
+
+        let position = long(dec!(100));
+        // 30 bps loss: within Standard's 40 bps limit
+        assert!(registry
+            .on_market_update(&position, &snapshot(dec!(99.70), dec!(99.80)), 2_000)
+            .is_none());
+
+        // Same loss exceeds Scalper's 25 bps limit
+        registry.on_position_opened(market(), ExitProfile::Scalper);
+        let (name, request) = registry
+            .on_market_update(&position, &snapshot(dec!(99.70), dec!(99.80)), 2_000)
+            .unwrap();
+        assert_eq!(name, "StopLoss");
+        assert_eq!(request.side, OrderSide::Sell);
+        assert_eq!(request.size, Size::new(dec!(1)));
+        assert!(!request.is_partial());
+    }
+
+    #[test]
+    fn test_registry_combines_strategies_in_order() {
+        let mut registry = ExitStrategyRegistry::new();
+        assert!(registry.is_empty());
+        registry.register(ExitProfile::Runner, Box::new(HoldLimit { after_ms: 5_000 }));
+        registry.register(ExitProfile::Runner, Box::new(StopLoss::new(dec!(50))));
+        assert_eq!(
+            registry.strategy_names(ExitProfile::Runner),
+            vec!["HoldLimit", StopLoss::NAME]
+        );
+
+        let position = long(dec!(100));
+        // Standard profile has no strategies
+        assert!(registry.on_tick(&position, 10_000).is_none());
+
+        registry.on_position_opened(market(), ExitProfile::Runner);
+        assert!(registry.on_tick(&position, 3_000).is_none());
+        let (name, request) = registry.on_tick(&position, 6_000).unwrap();
+        assert_eq!(name, "HoldLimit");
+        assert_eq!(
+            request.reason,
+            FlattenReason::Strategy {
+                name: "HoldLimit".to_string()
+            }
+        );
+
+        // Market-driven strategies are not affected by the tick
+        assert!(registry
+            .on_market_update(&position, &snapshot(dec!(99.60), dec!(99.70)), 6_000)
+            .is_none());
+        assert!(registry
+            .on_market_update(&position, &snapshot(dec!(99.40), dec!(99.50)), 6_000)
+            .is_some());
+
+        registry.on_position_closed(&market());
+        assert!(registry.on_tick(&position, 10_000).is_none());
+    }
+}
@@ -24,8 +24,15 @@
 //!                     ↓ [immediate check]
 //!              Exit condition met → flatten_tx.try_send()
 //! ```
+//!
+//! # Break-Even Stop
+//!
+//! With `break_even.enabled`, each position also carries a
+//! [`BreakEvenStop`]: once its PnL reaches the activation threshold of its
+//! exit profile, a regression back to entry plus fees flattens it
+//! regardless of the holding time (see [`crate::break_even`]).
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use parking_lot::RwLock;
@@ -34,11 +41,12 @@ use tokio::sync::mpsc;
 use tracing::{debug, info, trace, warn};
 
 use hip3_core::types::MarketSnapshot;
-use hip3_core::{MarketKey, OrderSide, PendingOrder};
+use hip3_core::{ExitProfile, MarketKey, OrderSide, PendingOrder};
 
+use crate::break_even::BreakEvenStop;
 use crate::mark_regression::MarkRegressionConfig;
 use crate::time_stop::{FlattenOrderBuilder, TIME_STOP_MS};
-use crate::tracker::{Position, PositionTrackerHandle, SharedFlatteningGuard};
+use crate::tracker::{exit_side_pnl_bps, Position, PositionTrackerHandle, SharedFlatteningGuard};
 
 // ============================================================================
 // ExitWatcher
@@ -65,6 +73,10 @@ pub struct ExitWatcher {
     /// Shared guard across all exit monitors to prevent duplicate flatten requests.
     shared_flattening: Option<SharedFlatteningGuard>,
 
+    /// Break-even stop state per position.
+    /// Positions not registered via `on_position_opened` use `ExitProfile::Standard`.
+    break_even: RwLock<HashMap<MarketKey, BreakEvenStop>>,
+
     /// Counter for exit triggers (for metrics/debugging).
     exit_count: std::sync::atomic::AtomicU64,
 }
@@ -81,6 +93,7 @@ impl ExitWatcher {
         info!(
             exit_threshold_bps = %config.exit_threshold_bps,
             min_holding_time_ms = config.min_holding_time_ms,
+            break_even = config.break_even.enabled,
             "ExitWatcher initialized (WS-driven)"
         );
 
@@ -90,10 +103,20 @@ impl ExitWatcher {
             flatten_tx,
             local_flattening: RwLock::new(HashSet::new()),
             shared_flattening,
+            break_even: RwLock::new(HashMap::new()),
             exit_count: std::sync::atomic::AtomicU64::new(0),
         }
     }
 
+    /// Record the exit profile of a newly opened position.
+    ///
+    /// Resets the break-even stop for the market.
+    pub fn on_position_opened(&self, key: MarketKey, exit_profile: ExitProfile) {
+        self.break_even
+            .write()
+            .insert(key, BreakEvenStop::new(exit_profile));
+    }
+
     /// Called when market data is updated (BBO or Oracle).
     ///
     /// This is the main entry point, called from `App::handle_market_event()`
@@ -129,12 +152,18 @@ impl ExitWatcher {
             return;
         }
 
-        // 4. Check exit condition
+        // 4. Check exit condition (break-even stop first)
         let now_ms = chrono::Utc::now().timestamp_millis() as u64;
-        if let Some(edge_bps) = self.check_exit(&position, snapshot, now_ms) {
+        let exit = match self.check_break_even(&position, snapshot) {
+            Some(pnl_bps) => Some((pnl_bps, "BreakEven")),
+            None => self
+                .check_exit(&position, snapshot, now_ms)
+                .map(|edge_bps| (edge_bps, "MarkRegression")),
+        };
+        if let Some((edge_bps, exit_reason)) = exit {
             // 4b. Shared guard: prevent cross-monitor duplicates
             if let Some(ref guard) = self.shared_flattening {
-                if !guard.try_claim(&key) {
+                if !guard.try_claim(&key, exit_reason) {
                     trace!(market = %key, "ExitWatcher: flatten already claimed by another monitor");
                     return;
                 }
@@ -147,8 +176,41 @@ impl ExitWatcher {
             }
 
             // 6. Trigger exit (non-blocking)
-            self.trigger_exit(&position, edge_bps, snapshot, now_ms);
+            self.trigger_exit(&position, edge_bps, exit_reason, snapshot, now_ms);
+        }
+    }
+
+    /// Update the break-even stop of a position.
+    ///
+    /// Returns the PnL (bps, at the exit-side BBO) if the armed stop is hit.
+    fn check_break_even(&self, position: &Position, snapshot: &MarketSnapshot) -> Option<Decimal> {
+        let config = &self.config.break_even;
+        if !config.enabled {
+            return None;
         }
+
+        let pnl_bps = exit_side_pnl_bps(position, position.exit_side_price(&snapshot.bbo))?;
+
+        let mut stops = self.break_even.write();
+        let stop = stops
+            .entry(position.market)
+            .or_insert_with(|| BreakEvenStop::new(ExitProfile::Standard));
+        let was_armed = stop.armed;
+        let hit = stop.update(config, pnl_bps);
+        if stop.armed && !was_armed {
+            info!(
+                market = %position.market,
+                side = ?position.side,
+                profile = %stop.profile,
+                pnl_bps = %pnl_bps,
+                "ExitWatcher: break-even stop armed"
+            );
+        }
+        if hit {
+            debug!(market = %position.market, %pnl_bps,
+                "ExitWatcher: break-even stop hit");
+        }
+        hit.then_some(pnl_bps)
     }
 
     /// Check if a losing trade should skip MarkRegression exit.
@@ -337,6 +399,7 @@ impl ExitWatcher {
         &self,
         position: &Position,
         edge_bps: Decimal,
+        exit_reason_str: &str,
         snapshot: &MarketSnapshot,
         now_ms: u64,
     ) {
@@ -359,9 +422,8 @@ impl ExitWatcher {
             .exit_count
             .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
 
-        // P1-4: Record exit metrics for MarkRegression
+        // P1-4: Record exit metrics for MarkRegression / BreakEven
         let market_str = position.market.to_string();
-        let exit_reason_str = "MarkRegression";
         hip3_telemetry::Metrics::position_holding_time(
             &market_str,
             exit_reason_str,
@@ -436,10 +498,16 @@ impl ExitWatcher {
     /// Removes markets from local_flattening if:
     /// - No longer has a position
     /// - No longer flattening in position tracker
+    ///
+    /// Also drops break-even state of markets without a position.
     pub fn sync_flattening_state(&self) {
         let positions = self.position_handle.positions_snapshot();
         let position_markets: HashSet<MarketKey> = positions.iter().map(|p| p.market).collect();
 
+        self.break_even
+            .write()
+            .retain(|m, _| position_markets.contains(m));
+
         let mut flattening = self.local_flattening.write();
 
         // Remove markets with no position
@@ -486,6 +554,7 @@ pub fn new_exit_watcher(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::break_even::BreakEvenConfig;
     use hip3_core::{AssetCtx, AssetId, Bbo, DexId, OracleData, Price, Size};
     use rust_decimal_macros::dec;
 
@@ -525,6 +594,7 @@ mod tests {
             time_decay_enabled: false,
             decay_start_ms: 5000,
             min_decay_factor: 0.2,
+            break_even: BreakEvenConfig::default(),
         }
     }
 
@@ -1,10 +1,16 @@
 //! Position flattening (exit) management.
 //!
 //! Handles the process of closing positions via reduce-only orders,
-//! tracking flatten state, and detecting timeouts.
+//! tracking flatten state, and detecting timeouts. A request may close only
+//! part of a position (partial take-profit, see [`crate::take_profit`]).
+//!
+//! Repeated attempts on a market escalate along [`FlattenEscalationConfig`]:
+//! limit orders at progressively wider slippage from mark, then orders that
+//! cross the spread, and only then manual intervention.
 
 use crate::tracker::Position;
-use hip3_core::{ClientOrderId, MarketKey, OrderSide, Size};
+use hip3_core::{ClientOrderId, MarketKey, OrderSide, Price, Size};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -24,6 +30,16 @@ pub enum FlattenReason {
     HardStop,
     /// Manual flatten request from operator.
     Manual,
+    /// Partial take-profit ladder rung reached.
+    TakeProfit {
+        /// Index of the rung (0-based).
+        rung: usize,
+    },
+    /// Pluggable exit strategy (see [`crate::exit_strategy`]).
+    Strategy {
+        /// Name of the strategy.
+        name: String,
+    },
 }
 
 impl std::fmt::Display for FlattenReason {
@@ -32,6 +48,8 @@ impl std::fmt::Display for FlattenReason {
             Self::TimeStop { elapsed_ms } => write!(f, "TimeStop({}ms)", elapsed_ms),
             Self::HardStop => write!(f, "HardStop"),
             Self::Manual => write!(f, "Manual"),
+            Self::TakeProfit { rung } => write!(f, "TakeProfit(rung {})", rung),
+            Self::Strategy { name } => write!(f, "{}", name),
         }
     }
 }
@@ -47,12 +65,35 @@ pub struct FlattenRequest {
     pub side: OrderSide,
     /// Size to close.
     pub size: Size,
+    /// Position size left once the request fills (zero for a full close).
+    pub remaining: Size,
     /// Reason for flattening.
     pub reason: FlattenReason,
     /// Timestamp when the request was created.
     pub requested_at: u64,
 }
 
+impl FlattenRequest {
+    /// Full close of a position.
+    #[must_use]
+    pub fn full(position: &Position, reason: FlattenReason, now_ms: u64) -> Self {
+        Self {
+            market: position.market,
+            side: position.side.opposite(),
+            size: position.size,
+            remaining: Size::ZERO,
+            reason,
+            requested_at: now_ms,
+        }
+    }
+
+    /// Whether the request leaves part of the position open.
+    #[must_use]
+    pub fn is_partial(&self) -> bool {
+        !self.remaining.is_zero()
+    }
+}
+
 /// State of the flattening process for a market.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FlattenState {
@@ -65,6 +106,13 @@ pub enum FlattenState {
         /// Timestamp when flatten started.
         started_at: u64,
     },
+    /// Partial close filled, the rest of the position is still open.
+    PartiallyClosed {
+        /// Position size left open.
+        remaining: Size,
+        /// Timestamp when the partial close filled.
+        closed_at: u64,
+    },
     /// Flatten completed successfully (position = 0).
     Completed {
         /// Timestamp when flatten completed.
@@ -91,15 +139,140 @@ impl FlattenState {
     }
 }
 
+/// Escalation ladder for repeated flatten attempts on a market.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlattenEscalationConfig {
+    /// Slippage from mark (bps) of the first attempt. Default: 50.
+    #[serde(default = "default_initial_slippage_bps")]
+    pub initial_slippage_bps: u64,
+
+    /// Extra slippage (bps) added on each further limit attempt. Default: 50.
+    #[serde(default = "default_step_bps")]
+    pub step_bps: u64,
+
+    /// Limit attempts priced from mark. Default: 3.
+    #[serde(default = "default_limit_attempts")]
+    pub limit_attempts: u32,
+
+    /// Slippage (bps) beyond the far touch once crossing the spread.
+    /// Default: 100.
+    #[serde(default = "default_cross_slippage_bps")]
+    pub cross_slippage_bps: u64,
+
+    /// Attempts crossing the spread before manual intervention. Default: 2.
+    #[serde(default = "default_cross_attempts")]
+    pub cross_attempts: u32,
+}
+
+fn default_initial_slippage_bps() -> u64 {
+    50
+}
+
+fn default_step_bps() -> u64 {
+    50
+}
+
+fn default_limit_attempts() -> u32 {
+    3
+}
+
+fn default_cross_slippage_bps() -> u64 {
+    100
+}
+
+fn default_cross_attempts() -> u32 {
+    2
+}
+
+impl Default for FlattenEscalationConfig {
+    fn default() -> Self {
+        Self {
+            initial_slippage_bps: default_initial_slippage_bps(),
+            step_bps: default_step_bps(),
+            limit_attempts: default_limit_attempts(),
+            cross_slippage_bps: default_cross_slippage_bps(),
+            cross_attempts: default_cross_attempts(),
+        }
+    }
+}
+
+impl FlattenEscalationConfig {
+    /// Pricing of the `attempt`-th flatten attempt (0-based).
+    #[must_use]
+    pub fn pricing(&self, attempt: u32) -> FlattenPricing {
+        if attempt < self.limit_attempts {
+            FlattenPricing::Limit {
+                slippage_bps: self.initial_slippage_bps + self.step_bps * u64::from(attempt),
+            }
+        } else if attempt < self.limit_attempts + self.cross_attempts {
+            FlattenPricing::Cross {
+                slippage_bps: self.cross_slippage_bps,
+            }
+        } else {
+            FlattenPricing::Exhausted
+        }
+    }
+}
+
+/// How a flatten attempt is priced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlattenPricing {
+    /// Limit at `slippage_bps` from mark.
+    Limit {
+        /// Slippage from mark (bps).
+        slippage_bps: u64,
+    },
+    /// Cross the spread: `slippage_bps` beyond the far touch.
+    Cross {
+        /// Slippage beyond the far touch (bps).
+        slippage_bps: u64,
+    },
+    /// Ladder exhausted: manual intervention required.
+    Exhausted,
+}
+
+impl FlattenPricing {
+    /// Limit price of a flatten order on `side`.
+    ///
+    /// `far_touch` is the ask for a buy and the bid for a sell; crossing
+    /// falls back to mark without it. None once exhausted or without a
+    /// reference price.
+    #[must_use]
+    pub fn limit_price(
+        &self,
+        side: OrderSide,
+        mark: Option<Price>,
+        far_touch: Option<Price>,
+    ) -> Option<Price> {
+        let (reference, slippage_bps) = match *self {
+            Self::Limit { slippage_bps } => (mark?, slippage_bps),
+            Self::Cross { slippage_bps } => (far_touch.or(mark)?, slippage_bps),
+            Self::Exhausted => return None,
+        };
+        let slippage = Decimal::from(slippage_bps) / Decimal::from(10000);
+        let multiplier = match side {
+            OrderSide::Buy => Decimal::ONE + slippage,
+            OrderSide::Sell => (Decimal::ONE - slippage).max(Decimal::ZERO),
+        };
+        Some(Price::new(reference.inner() * multiplier))
+    }
+}
+
 /// Manages the flattening process for positions.
 ///
-/// Tracks flatten state per market and handles timeouts.
+/// Tracks flatten state per market and handles timeouts. Attempts per
+/// market are counted until the flatten completes, escalating the pricing
+/// of each retry (see [`Self::next_pricing`]).
 #[derive(Debug)]
 pub struct Flattener {
     /// Flatten state per market.
     states: HashMap<MarketKey, FlattenState>,
     /// Timeout threshold for reduce-only orders.
     timeout_ms: u64,
+    /// Escalation ladder of repeated attempts.
+    escalation: FlattenEscalationConfig,
+    /// Attempts per market since the last completed flatten.
+    attempts: HashMap<MarketKey, u32>,
 }
 
 impl Flattener {
@@ -108,9 +281,31 @@ impl Flattener {
         Self {
             states: HashMap::new(),
             timeout_ms,
+            escalation: FlattenEscalationConfig::default(),
+            attempts: HashMap::new(),
         }
     }
 
+    /// Set the escalation ladder of repeated attempts.
+    #[must_use]
+    pub fn with_escalation(mut self, escalation: FlattenEscalationConfig) -> Self {
+        self.escalation = escalation;
+        self
+    }
+
+    /// Pricing of the next attempt on `market`, counting the attempt.
+    pub fn next_pricing(&mut self, market: &MarketKey) -> FlattenPricing {
+        let attempt = self.attempts.entry(*market).or_insert(0);
+        let pricing = self.escalation.pricing(*attempt);
+        *attempt = attempt.saturating_add(1);
+        pricing
+    }
+
+    /// Attempts on `market` since the last completed flatten.
+    pub fn attempts(&self, market: &MarketKey) -> u32 {
+        self.attempts.get(market).copied().unwrap_or(0)
+    }
+
     /// Create a Flattener with default timeout (REDUCE_ONLY_TIMEOUT_MS = 60s).
     pub fn with_default() -> Self {
         Self::new(REDUCE_ONLY_TIMEOUT_MS)
@@ -165,13 +360,41 @@ impl Flattener {
         self.states
             .insert(position.market, FlattenState::NotStarted);
 
-        Some(FlattenRequest {
-            market: position.market,
-            side: position.side.opposite(),
-            size: position.size,
-            reason,
-            requested_at: now_ms,
-        })
+        Some(FlattenRequest::full(position, reason, now_ms))
+    }
+
+    /// Initiate closing `fraction` of a position.
+    ///
+    /// Same rules as [`Self::start_flatten`]; a fraction of 1 or more closes
+    /// the whole position. See [`partial_flatten_request`].
+    ///
+    /// # Returns
+    /// * `Some(FlattenRequest)` if the partial close can be started
+    /// * `None` if a flatten is in progress, the position size is zero, or
+    ///   the fraction is not positive
+    pub fn start_partial_flatten(
+        &mut self,
+        position: &Position,
+        fraction: Decimal,
+        reason: FlattenReason,
+        now_ms: u64,
+    ) -> Option<FlattenRequest> {
+        if self
+            .states
+            .get(&position.market)
+            .is_some_and(FlattenState::is_in_progress)
+        {
+            tracing::debug!(
+                market = %position.market,
+                "Flatten already in progress, ignoring partial close request"
+            );
+            return None;
+        }
+
+        let request = partial_flatten_request(position, fraction, reason, now_ms)?;
+        self.states
+            .insert(position.market, FlattenState::NotStarted);
+        Some(request)
     }
 
     /// Mark that a reduce-only order has been submitted.
@@ -204,6 +427,7 @@ impl Flattener {
     /// * `market` - The market that was flattened
     /// * `now_ms` - Current timestamp
     pub fn mark_completed(&mut self, market: &MarketKey, now_ms: u64) {
+        self.attempts.remove(market);
         self.states.insert(
             *market,
             FlattenState::Completed {
@@ -216,6 +440,29 @@ impl Flattener {
         );
     }
 
+    /// Mark that a flatten order filled, leaving `remaining` open.
+    ///
+    /// A zero `remaining` completes the flatten; otherwise the market moves
+    /// to `PartiallyClosed` and further flattens may start.
+    pub fn mark_filled(&mut self, market: &MarketKey, remaining: Size, now_ms: u64) {
+        if remaining.is_zero() {
+            self.mark_completed(market, now_ms);
+            return;
+        }
+        self.states.insert(
+            *market,
+            FlattenState::PartiallyClosed {
+                remaining,
+                closed_at: now_ms,
+            },
+        );
+        tracing::info!(
+            market = %market,
+            remaining = %remaining,
+            "Partial flatten filled"
+        );
+    }
+
     /// Check for timed-out flatten attempts and mark them as failed.
     ///
     /// # Arguments
@@ -264,6 +511,7 @@ impl Flattener {
     /// Use with caution - typically only at startup or after a full reset.
     pub fn clear(&mut self) {
         self.states.clear();
+        self.attempts.clear();
     }
 
     /// Get all markets currently in progress.
@@ -276,6 +524,9 @@ impl Flattener {
     }
 
     /// Get count of markets in each state.
+    ///
+    /// Partially closed markets count as not started (position open, no
+    /// flatten active).
     pub fn state_counts(&self) -> (usize, usize, usize, usize) {
         let mut not_started = 0;
         let mut in_progress = 0;
@@ -284,7 +535,7 @@ impl Flattener {
 
         for state in self.states.values() {
             match state {
-                FlattenState::NotStarted => not_started += 1,
+                FlattenState::NotStarted | FlattenState::PartiallyClosed { .. } => not_started += 1,
                 FlattenState::InProgress { .. } => in_progress += 1,
                 FlattenState::Completed { .. } => completed += 1,
                 FlattenState::Failed { .. } => failed += 1,
@@ -318,16 +569,41 @@ pub fn flatten_all_positions(
     positions
         .iter()
         .filter(|p| !p.size.is_zero())
-        .map(|p| FlattenRequest {
-            market: p.market,
-            side: p.side.opposite(),
-            size: p.size,
-            reason: reason.clone(),
-            requested_at: now_ms,
-        })
+        .map(|p| FlattenRequest::full(p, reason.clone(), now_ms))
         .collect()
 }
 
+/// Request closing `fraction` of a position (e.g., a take-profit rung).
+///
+/// The close size is `position.size * fraction`; it is rounded to the lot
+/// size when the order is signed. A fraction of 1 or more closes the whole
+/// position.
+///
+/// # Returns
+/// `None` if the position size is zero or the fraction is not positive
+pub fn partial_flatten_request(
+    position: &Position,
+    fraction: Decimal,
+    reason: FlattenReason,
+    now_ms: u64,
+) -> Option<FlattenRequest> {
+    if position.size.is_zero() || fraction <= Decimal::ZERO {
+        return None;
+    }
+    if fraction >= Decimal::ONE {
+        return Some(FlattenRequest::full(position, reason, now_ms));
+    }
+    let size = position.size.inner() * fraction;
+    Some(FlattenRequest {
+        market: position.market,
+        side: position.side.opposite(),
+        size: Size::new(size),
+        remaining: Size::new(position.size.inner() - size),
+        reason,
+        requested_at: now_ms,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -522,6 +798,43 @@ mod tests {
         assert_eq!(req1.size, Size::new(dec!(2)));
     }
 
+    #[test]
+    fn test_partial_flatten() {
+        let mut flattener = Flattener::with_default();
+        let position = make_position(market(0), OrderSide::Buy, Size::new(dec!(2)));
+        let reason = FlattenReason::TakeProfit { rung: 0 };
+
+        let request = flattener
+            .start_partial_flatten(&position, dec!(0.25), reason.clone(), 100)
+            .unwrap();
+        assert_eq!(request.side, OrderSide::Sell);
+        assert_eq!(request.size, Size::new(dec!(0.5)));
+        assert_eq!(request.remaining, Size::new(dec!(1.5)));
+        assert!(request.is_partial());
+        assert_eq!(reason.to_string(), "TakeProfit(rung 0)");
+
+        // Partially closed: position open, next flatten allowed
+        flattener.mark_in_progress(&market(0), ClientOrderId::new(), 100);
+        assert!(flattener
+            .start_partial_flatten(&position, dec!(0.5), reason.clone(), 150)
+            .is_none());
+        flattener.mark_filled(&market(0), request.remaining, 200);
+        let state = flattener.get_state(&market(0)).unwrap();
+        assert!(!state.is_in_progress() && !state.is_terminal());
+        assert_eq!(flattener.state_counts(), (1, 0, 0, 0));
+
+        let rest = make_position(market(0), OrderSide::Buy, request.remaining);
+        let full = flattener
+            .start_partial_flatten(&rest, Decimal::ONE, FlattenReason::Manual, 300)
+            .unwrap();
+        assert_eq!(full.size, rest.size);
+        assert!(!full.is_partial());
+        flattener.mark_filled(&market(0), full.remaining, 400);
+        assert!(flattener.get_state(&market(0)).unwrap().is_terminal());
+
+        assert!(partial_flatten_request(&rest, Decimal::ZERO, reason, 0).is_none());
+    }
+
     #[test]
     fn test_flatten_all_positions_empty() {
         let positions: Vec<Position> = vec![];
@@ -596,4 +909,51 @@ mod tests {
         assert!(flattener.in_progress_markets().is_empty());
         assert!(flattener.get_state(&market(0)).is_none());
     }
+
+    #[test]
+    fn test_escalation_ladder() {
+        use hip3_core::Price;
+
+        let mut flattener = Flattener::with_default();
+        let m = market(0);
+        let mark = Some(Price::new(dec!(100)));
+        let ask = Some(Price::new(dec!(101)));
+
+        let mut prices = Vec::new();
+        loop {
+            let pricing = flattener.next_pricing(&m);
+            match pricing.limit_price(OrderSide::Buy, mark, ask) {
+                Some(price) => prices.push(price.inner()),
+                None => {
+                    assert_eq!(pricing, FlattenPricing::Exhausted);
+                    break;
+                }
+            }
+        }
+        // Limits at 50/100/150 bps from mark, then 100 bps through the ask
+        assert_eq!(
+            prices,
+            vec![
+                dec!(100.5),
+                dec!(101),
+                dec!(101.5),
+                dec!(102.01),
+                dec!(102.01)
+            ]
+        );
+        assert_eq!(flattener.attempts(&m), 6);
+
+        // Crossing falls back to mark without a book
+        assert_eq!(
+            FlattenPricing::Cross { slippage_bps: 100 }.limit_price(OrderSide::Sell, mark, None),
+            Some(Price::new(dec!(99)))
+        );
+
+        flattener.mark_completed(&m, 1_000);
+        assert_eq!(flattener.attempts(&m), 0);
+        assert_eq!(
+            flattener.next_pricing(&m),
+            FlattenPricing::Limit { slippage_bps: 50 }
+        );
+    }
 }
@@ -0,0 +1,760 @@
+//! Hedging of xyz exposure on L1 perps.
+//!
+//! When the net exposure to an underlying grows past a band and the xyz
+//! book is too wide to flatten cheaply, the exposure is offset on the
+//! corresponding L1 perp (e.g. PAXG for xyz:GOLD). The hedge is unwound once
+//! the xyz exposure is back within half the band.
+//!
+//! Several xyz markets may share one hedge: exposure is netted per hedge
+//! market (`size * ratio`, longs positive). Hedge orders are IOC and go
+//! through the executor like any other order, but their fills (from
+//! `userFills`) are booked here, in a [`HedgeBook`] per hedge market, not in
+//! the `PositionTracker`: hedges never show up as xyz positions and their PnL
+//! is tracked apart. The book size is re-synced from the L1
+//! `clearinghouseState`, so a restart or a lost fill does not leave the
+//! hedge position unknown.
+//!
+//! [`HedgeManager::plan`] is pure bookkeeping: the caller supplies positions,
+//! flatten costs and hedge mids, and queues the returned orders (reduce-only
+//! unwinds on the reduce-only queue, so they pass a hard stop).
+//! [`HedgeManager::plan_flatten`] unwinds every hedge on a hard stop.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use hip3_core::{ClientOrderId, MarketKey, OrderSide, PendingOrder, Price, Size};
+
+use crate::tracker::Position;
+
+/// A hedge order without a result after this long is considered dead (ms).
+pub const HEDGE_ORDER_TIMEOUT_MS: u64 = 10_000;
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Hedging configuration.
+///
+/// ```toml
+/// [hedge]
+/// enabled = true
+/// band_usd = 500
+///
+/// [[hedge.pairs]]
+/// coin = "xyz:GOLD"
+/// hedge_coin = "PAXG"
+/// hedge_asset = 187
+/// sz_decimals = 3
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HedgeConfig {
+    /// Enable hedging. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Net exposure per hedge market (USD) above which a hedge is placed.
+    #[serde(default = "default_band_usd")]
+    pub band_usd: Decimal,
+
+    /// Only hedge when flattening on xyz costs at least this much (spread, bps).
+    #[serde(default = "default_min_flatten_cost_bps")]
+    pub min_flatten_cost_bps: Decimal,
+
+    /// Maximum hedge notional per hedge market (USD).
+    #[serde(default = "default_max_hedge_usd")]
+    pub max_hedge_usd: Decimal,
+
+    /// Smallest hedge adjustment worth an order (USD).
+    #[serde(default = "default_min_order_usd")]
+    pub min_order_usd: Decimal,
+
+    /// Limit price distance from the hedge mid (bps).
+    #[serde(default = "default_slippage_bps")]
+    pub slippage_bps: Decimal,
+
+    /// L1 taker fee (bps), booked as hedge cost.
+    #[serde(default = "default_taker_fee_bps")]
+    pub taker_fee_bps: Decimal,
+
+    /// Check interval (ms).
+    #[serde(default = "default_check_interval_ms")]
+    pub check_interval_ms: u64,
+
+    /// xyz market → L1 hedge pairs.
+    #[serde(default)]
+    pub pairs: Vec<HedgePairConfig>,
+}
+
+/// One xyz market and the L1 perp that hedges it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HedgePairConfig {
+    /// xyz market coin (e.g. "xyz:GOLD").
+    pub coin: String,
+    /// L1 perp coin (e.g. "PAXG"), as in `allMids`.
+    pub hedge_coin: String,
+    /// L1 asset index of the hedge perp.
+    pub hedge_asset: u32,
+    /// Size decimals of the hedge perp.
+    pub sz_decimals: u8,
+    /// Hedge units per xyz unit. Default: 1.
+    #[serde(default = "default_ratio")]
+    pub ratio: Decimal,
+}
+
+fn default_band_usd() -> Decimal {
+    Decimal::from(500)
+}
+
+fn default_min_flatten_cost_bps() -> Decimal {
+    Decimal::from(10)
+}
+
+fn default_max_hedge_usd() -> Decimal {
+    Decimal::from(5_000)
+}
+
+fn default_min_order_usd() -> Decimal {
+    Decimal::from(10) // exchange minimum order value
+}
+
+fn default_slippage_bps() -> Decimal {
+    Decimal::from(20)
+}
+
+fn default_taker_fee_bps() -> Decimal {
+    Decimal::new(45, 1) // 0.045%
+}
+
+fn default_check_interval_ms() -> u64 {
+    1_000
+}
+
+fn default_ratio() -> Decimal {
+    Decimal::ONE
+}
+
+impl Default for HedgeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            band_usd: default_band_usd(),
+            min_flatten_cost_bps: default_min_flatten_cost_bps(),
+            max_hedge_usd: default_max_hedge_usd(),
+            min_order_usd: default_min_order_usd(),
+            slippage_bps: default_slippage_bps(),
+            taker_fee_bps: default_taker_fee_bps(),
+            check_interval_ms: default_check_interval_ms(),
+            pairs: Vec::new(),
+        }
+    }
+}
+
+/// A hedge pair resolved to market keys.
+#[derive(Debug, Clone)]
+pub struct HedgeLeg {
+    /// xyz market.
+    pub market: MarketKey,
+    /// L1 hedge market.
+    pub hedge_market: MarketKey,
+    /// L1 hedge coin.
+    pub hedge_coin: String,
+    /// Size decimals of the hedge perp.
+    pub sz_decimals: u8,
+    /// Hedge units per xyz unit.
+    pub ratio: Decimal,
+}
+
+// ============================================================================
+// HedgeBook
+// ============================================================================
+
+/// Hedge position and PnL on one L1 market.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HedgeBook {
+    /// Signed size (long positive).
+    pub size: Decimal,
+    /// Average entry price of the open size.
+    pub avg_px: Decimal,
+    /// Realized PnL (USD, before fees).
+    pub realized_pnl: Decimal,
+    /// Fees paid (USD).
+    pub fees: Decimal,
+}
+
+impl HedgeBook {
+    /// Book a fill that paid `fee` (USD).
+    pub fn apply_fill(&mut self, side: OrderSide, px: Decimal, size: Decimal, fee: Decimal) {
+        let qty = match side {
+            OrderSide::Buy => size,
+            OrderSide::Sell => -size,
+        };
+        self.fees += fee;
+
+        if self.size.is_zero() || self.size.is_sign_positive() == qty.is_sign_positive() {
+            let total = self.size.abs() + size;
+            self.avg_px = (self.avg_px * self.size.abs() + px * size) / total;
+            self.size += qty;
+            return;
+        }
+
+        let closed = size.min(self.size.abs());
+        let direction = if self.size.is_sign_positive() {
+            Decimal::ONE
+        } else {
+            -Decimal::ONE
+        };
+        self.realized_pnl += closed * (px - self.avg_px) * direction;
+        self.size += qty;
+        if self.size.is_zero() {
+            self.avg_px = Decimal::ZERO;
+        } else if self.size.is_sign_positive() != direction.is_sign_positive() {
+            // Flipped: the remainder was opened at this fill
+            self.avg_px = px;
+        }
+    }
+
+    /// Unrealized PnL at `mid` (USD).
+    #[must_use]
+    pub fn unrealized_pnl(&self, mid: Decimal) -> Decimal {
+        self.size * (mid - self.avg_px)
+    }
+
+    /// Net PnL at `mid` (USD): realized + unrealized - fees.
+    #[must_use]
+    pub fn net_pnl(&self, mid: Decimal) -> Decimal {
+        self.realized_pnl + self.unrealized_pnl(mid) - self.fees
+    }
+}
+
+/// Hedge state of one L1 market, for reporting.
+#[derive(Debug, Clone)]
+pub struct HedgeSummary {
+    /// L1 hedge market.
+    pub hedge_market: MarketKey,
+    /// L1 hedge coin.
+    pub hedge_coin: String,
+    /// Position and PnL.
+    pub book: HedgeBook,
+}
+
+// ============================================================================
+// HedgeManager
+// ============================================================================
+
+/// In-flight hedge order.
+#[derive(Debug, Clone, Copy)]
+struct PendingHedge {
+    hedge_market: MarketKey,
+    sent_at_ms: u64,
+}
+
+#[derive(Debug, Default)]
+struct HedgeState {
+    books: HashMap<MarketKey, HedgeBook>,
+    pending: HashMap<ClientOrderId, PendingHedge>,
+}
+
+/// Computes hedge orders from xyz exposure and books their fills.
+pub struct HedgeManager {
+    config: HedgeConfig,
+    legs: Vec<HedgeLeg>,
+    state: Mutex<HedgeState>,
+}
+
+impl HedgeManager {
+    /// Create a new HedgeManager.
+    #[must_use]
+    pub fn new(config: HedgeConfig, legs: Vec<HedgeLeg>) -> Self {
+        info!(
+            legs = legs.len(),
+            band_usd = %config.band_usd,
+            min_flatten_cost_bps = %config.min_flatten_cost_bps,
+            "HedgeManager initialized"
+        );
+        Self {
+            config,
+            legs,
+            state: Mutex::new(HedgeState::default()),
+        }
+    }
+
+    /// Configuration.
+    #[must_use]
+    pub fn config(&self) -> &HedgeConfig {
+        &self.config
+    }
+
+    /// Resolved legs.
+    #[must_use]
+    pub fn legs(&self) -> &[HedgeLeg] {
+        &self.legs
+    }
+
+    /// Whether an order is an in-flight hedge order.
+    #[must_use]
+    pub fn is_hedge_order(&self, cloid: &ClientOrderId) -> bool {
+        self.state.lock().pending.contains_key(cloid)
+    }
+
+    /// Hedge market traded under `coin` (as in `userFills`), if any.
+    #[must_use]
+    pub fn hedge_market(&self, coin: &str) -> Option<MarketKey> {
+        self.legs
+            .iter()
+            .find(|l| l.hedge_coin == coin)
+            .map(|l| l.hedge_market)
+    }
+
+    /// Net exposure per hedge market, in hedge units (long positive).
+    #[must_use]
+    pub fn net_exposure(&self, positions: &[Position]) -> HashMap<MarketKey, Decimal> {
+        let mut exposure: HashMap<MarketKey, Decimal> = HashMap::new();
+        for leg in &self.legs {
+            let units = positions
+                .iter()
+                .filter(|p| p.market == leg.market)
+                .map(|p| match p.side {
+                    OrderSide::Buy => p.size.inner(),
+                    OrderSide::Sell => -p.size.inner(),
+                })
+                .sum::<Decimal>();
+            *exposure.entry(leg.hedge_market).or_default() += units * leg.ratio;
+        }
+        exposure
+    }
+
+    /// Hedge orders that bring each hedge towards its target.
+    ///
+    /// `flatten_cost_bps` is the cost of flattening an xyz market (its
+    /// spread), `hedge_mid` the mid of a hedge market. Markets with an order
+    /// in flight are skipped; the returned orders are recorded as in flight.
+    pub fn plan(
+        &self,
+        positions: &[Position],
+        flatten_cost_bps: impl Fn(&MarketKey) -> Option<Decimal>,
+        hedge_mid: impl Fn(&MarketKey) -> Option<Decimal>,
+        now_ms: u64,
+    ) -> Vec<PendingOrder> {
+        let exposure = self.net_exposure(positions);
+        let mut state = self.state.lock();
+        Self::expire_pending(&mut state, now_ms);
+
+        let mut orders = Vec::new();
+        for (&hedge_market, &units) in &exposure {
+            let Some(mid) = hedge_mid(&hedge_market).filter(|m| !m.is_zero()) else {
+                continue;
+            };
+
+            // Costliest exit among the xyz markets with exposure
+            let cost_bps = self
+                .legs
+                .iter()
+                .filter(|l| l.hedge_market == hedge_market)
+                .filter(|l| positions.iter().any(|p| p.market == l.market))
+                .filter_map(|l| flatten_cost_bps(&l.market))
+                .max();
+
+            let current = state
+                .books
+                .get(&hedge_market)
+                .map_or(Decimal::ZERO, |b| b.size);
+            let target = self.target_hedge(units, current, mid, cost_bps);
+            if let Some(order) =
+                self.order_towards(&mut state, hedge_market, current, target, mid, now_ms)
+            {
+                info!(
+                    market = %hedge_market,
+                    exposure = %units,
+                    cost_bps = ?cost_bps,
+                    cloid = %order.cloid,
+                    "Hedge adjustment"
+                );
+                orders.push(order);
+            }
+        }
+        orders
+    }
+
+    /// Reduce-only orders closing every open hedge (hard stop flatten).
+    pub fn plan_flatten(
+        &self,
+        hedge_mid: impl Fn(&MarketKey) -> Option<Decimal>,
+        now_ms: u64,
+    ) -> Vec<PendingOrder> {
+        let mut state = self.state.lock();
+        Self::expire_pending(&mut state, now_ms);
+
+        let open: Vec<(MarketKey, Decimal)> = state
+            .books
+            .iter()
+            .filter(|(_, book)| !book.size.is_zero())
+            .map(|(market, book)| (*market, book.size))
+            .collect();
+        let mut orders = Vec::new();
+        for (hedge_market, current) in open {
+            let Some(mid) = hedge_mid(&hedge_market).filter(|m| !m.is_zero()) else {
+                warn!(market = %hedge_market, "Cannot flatten hedge: no mid");
+                continue;
+            };
+            if let Some(order) = self.order_towards(
+                &mut state,
+                hedge_market,
+                current,
+                Decimal::ZERO,
+                mid,
+                now_ms,
+            ) {
+                warn!(market = %hedge_market, cloid = %order.cloid, "Hedge flatten");
+                orders.push(order);
+            }
+        }
+        orders
+    }
+
+    fn expire_pending(state: &mut HedgeState, now_ms: u64) {
+        state.pending.retain(|cloid, pending| {
+            let alive = now_ms.saturating_sub(pending.sent_at_ms) < HEDGE_ORDER_TIMEOUT_MS;
+            if !alive {
+                debug!(cloid = %cloid, market = %pending.hedge_market, "Hedge order expired");
+            }
+            alive
+        });
+    }
+
+    /// IOC order moving the hedge of `hedge_market` from `current` to
+    /// `target`, recorded as in flight. None while an order is in flight or
+    /// when the adjustment is below the lot size or minimum order value.
+    fn order_towards(
+        &self,
+        state: &mut HedgeState,
+        hedge_market: MarketKey,
+        current: Decimal,
+        target: Decimal,
+        mid: Decimal,
+        now_ms: u64,
+    ) -> Option<PendingOrder> {
+        if state
+            .pending
+            .values()
+            .any(|p| p.hedge_market == hedge_market)
+        {
+            return None;
+        }
+        let leg = self.legs.iter().find(|l| l.hedge_market == hedge_market)?;
+        let delta = (target - current)
+            .abs()
+            .round_dp_with_strategy(u32::from(leg.sz_decimals), RoundingStrategy::ToZero);
+        if delta.is_zero() || delta * mid < self.config.min_order_usd {
+            return None;
+        }
+
+        let side = if target > current {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+        let slip = self.config.slippage_bps / Decimal::from(10000);
+        let price = match side {
+            OrderSide::Buy => mid * (Decimal::ONE + slip),
+            OrderSide::Sell => mid * (Decimal::ONE - slip),
+        };
+        let reduce_only = target.abs() < current.abs()
+            && (target.is_zero() || target.is_sign_positive() == current.is_sign_positive());
+        let order = PendingOrder::new(
+            ClientOrderId::new(),
+            hedge_market,
+            side,
+            Price::new(price),
+            Size::new(delta),
+            reduce_only,
+            now_ms,
+        );
+
+        info!(
+            hedge = %leg.hedge_coin,
+            current = %current,
+            target = %target,
+            side = ?side,
+            size = %delta,
+            reduce_only,
+            cloid = %order.cloid,
+            "Hedge order"
+        );
+        state.pending.insert(
+            order.cloid.clone(),
+            PendingHedge {
+                hedge_market,
+                sent_at_ms: now_ms,
+            },
+        );
+        Some(order)
+    }
+
+    /// Target hedge (signed hedge units) for a net exposure.
+    fn target_hedge(
+        &self,
+        units: Decimal,
+        current: Decimal,
+        mid: Decimal,
+        cost_bps: Option<Decimal>,
+    ) -> Decimal {
+        let exposure_usd = units.abs() * mid;
+        let expensive = cost_bps.is_some_and(|c| c >= self.config.min_flatten_cost_bps);
+
+        if exposure_usd > self.config.band_usd && expensive {
+            let cap = self.config.max_hedge_usd / mid;
+            return (-units).clamp(-cap, cap);
+        }
+        if exposure_usd <= self.config.band_usd / Decimal::from(2) {
+            return Decimal::ZERO;
+        }
+        // Inside the hysteresis band: keep the hedge, but never over-hedge
+        if current.is_zero() || current.is_sign_positive() == units.is_sign_positive() {
+            Decimal::ZERO
+        } else if current.abs() > units.abs() {
+            -units
+        } else {
+            current
+        }
+    }
+
+    /// Book a `userFills` fill on a hedge market.
+    ///
+    /// `fee` is the fee paid (USD); None books the configured taker fee. A
+    /// fill of an in-flight order also releases it.
+    pub fn on_fill(
+        &self,
+        hedge_market: MarketKey,
+        cloid: Option<&ClientOrderId>,
+        side: OrderSide,
+        px: Price,
+        size: Size,
+        fee: Option<Decimal>,
+    ) {
+        let fee = fee.unwrap_or_else(|| {
+            px.inner() * size.inner() * self.config.taker_fee_bps / Decimal::from(10000)
+        });
+        let mut state = self.state.lock();
+        if let Some(cloid) = cloid {
+            state.pending.remove(cloid);
+        }
+        let book = state.books.entry(hedge_market).or_default();
+        book.apply_fill(side, px.inner(), size.inner(), fee);
+        info!(
+            market = %hedge_market,
+            side = ?side,
+            px = %px,
+            size = %size,
+            hedge_size = %book.size,
+            realized_pnl = %book.realized_pnl,
+            "Hedge fill booked"
+        );
+    }
+
+    /// Release a hedge order that ended without a fill.
+    ///
+    /// A filled order stays in flight until its `userFills` fill is booked
+    /// (or it expires), so no hedge is re-planned on a stale size.
+    pub fn on_order_done(&self, cloid: &ClientOrderId) {
+        self.state.lock().pending.remove(cloid);
+    }
+
+    /// Set the hedge position of `hedge_market` from the exchange
+    /// (`clearinghouseState`, signed size). Skipped while an order on the
+    /// market is in flight; PnL and fees booked so far are kept.
+    pub fn sync_position(&self, hedge_market: MarketKey, size: Decimal, entry_px: Decimal) {
+        let mut state = self.state.lock();
+        if state
+            .pending
+            .values()
+            .any(|p| p.hedge_market == hedge_market)
+        {
+            return;
+        }
+        let book = state.books.entry(hedge_market).or_default();
+        if book.size == size {
+            return;
+        }
+        warn!(
+            market = %hedge_market,
+            local = %book.size,
+            exchange = %size,
+            entry_px = %entry_px,
+            "Hedge position synced from exchange"
+        );
+        book.size = size;
+        book.avg_px = if size.is_zero() {
+            Decimal::ZERO
+        } else {
+            entry_px
+        };
+    }
+
+    /// Position and PnL of every hedge market that traded.
+    #[must_use]
+    pub fn summaries(&self) -> Vec<HedgeSummary> {
+        let state = self.state.lock();
+        state
+            .books
+            .iter()
+            .map(|(market, book)| HedgeSummary {
+                hedge_market: *market,
+                hedge_coin: self
+                    .legs
+                    .iter()
+                    .find(|l| l.hedge_market == *market)
+                    .map_or_else(|| market.to_string(), |l| l.hedge_coin.clone()),
+                book: *book,
+            })
+            .collect()
+    }
+}
+
+/// Thread-safe handle to HedgeManager.
+pub type HedgeHandle = Arc<HedgeManager>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+    use rust_decimal_macros::dec;
+
+    fn gold() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(110_026))
+    }
+
+    fn paxg() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(187))
+    }
+
+    fn manager() -> HedgeManager {
+        let config = HedgeConfig {
+            enabled: true,
+            ..HedgeConfig::default()
+        };
+        let leg = HedgeLeg {
+            market: gold(),
+            hedge_market: paxg(),
+            hedge_coin: "PAXG".to_string(),
+            sz_decimals: 3,
+            ratio: Decimal::ONE,
+        };
+        HedgeManager::new(config, vec![leg])
+    }
+
+    fn long_gold(size: Decimal) -> Position {
+        Position::new(
+            gold(),
+            OrderSide::Buy,
+            Size::new(size),
+            Price::new(dec!(2000)),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_hedges_only_expensive_exposure_above_band() {
+        let hedger = manager();
+        let mid = |_: &MarketKey| Some(dec!(2000));
+
+        // 0.2 oz = $400: inside the band
+        let small = [long_gold(dec!(0.2))];
+        assert!(hedger.plan(&small, |_| Some(dec!(30)), mid, 0).is_empty());
+
+        // 0.5 oz = $1000 but the xyz book is tight: flatten instead
+        let large = [long_gold(dec!(0.5))];
+        assert!(hedger.plan(&large, |_| Some(dec!(4)), mid, 0).is_empty());
+
+        let orders = hedger.plan(&large, |_| Some(dec!(30)), mid, 0);
+        assert_eq!(orders.len(), 1);
+        let order = &orders[0];
+        assert_eq!(order.market, paxg());
+        assert_eq!(order.side, OrderSide::Sell);
+        assert_eq!(order.size.inner(), dec!(0.5));
+        assert!(!order.reduce_only);
+        assert!(hedger.is_hedge_order(&order.cloid));
+
+        // One order in flight per hedge market
+        assert!(hedger.plan(&large, |_| Some(dec!(30)), mid, 0).is_empty());
+
+        // The fill (from userFills) books the hedge and releases the order
+        assert_eq!(hedger.hedge_market("PAXG"), Some(paxg()));
+        hedger.on_fill(
+            paxg(),
+            Some(&order.cloid),
+            OrderSide::Sell,
+            Price::new(dec!(2000)),
+            order.size,
+            Some(dec!(0.45)),
+        );
+        assert!(!hedger.is_hedge_order(&order.cloid));
+
+        // Exposure back within half the band: unwind the hedge (reduce-only)
+        let orders = hedger.plan(&[long_gold(dec!(0.1))], |_| Some(dec!(30)), mid, 1_000);
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].side, OrderSide::Buy);
+        assert_eq!(orders[0].size.inner(), dec!(0.5));
+        assert!(orders[0].reduce_only);
+    }
+
+    #[test]
+    fn test_sync_from_exchange_and_flatten() {
+        let hedger = manager();
+        let mid = |_: &MarketKey| Some(dec!(2000));
+
+        // After a restart the hedge is only known from clearinghouseState
+        hedger.sync_position(paxg(), dec!(-0.4), dec!(1990));
+        let book = hedger.summaries()[0].book;
+        assert_eq!((book.size, book.avg_px), (dec!(-0.4), dec!(1990)));
+
+        // Hard stop: buy the hedge back, reduce-only
+        let orders = hedger.plan_flatten(mid, 0);
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].side, OrderSide::Buy);
+        assert_eq!(orders[0].size.inner(), dec!(0.4));
+        assert!(orders[0].reduce_only);
+
+        // No resync (and no second order) while the flatten is in flight
+        hedger.sync_position(paxg(), dec!(-0.3), dec!(1990));
+        assert_eq!(hedger.summaries()[0].book.size, dec!(-0.4));
+        assert!(hedger.plan_flatten(mid, 1_000).is_empty());
+
+        // A rejected order is released; the fill of the retry books the close
+        hedger.on_order_done(&orders[0].cloid);
+        let retry = hedger.plan_flatten(mid, 2_000);
+        hedger.on_fill(
+            paxg(),
+            Some(&retry[0].cloid),
+            OrderSide::Buy,
+            Price::new(dec!(1980)),
+            retry[0].size,
+            None,
+        );
+        let book = hedger.summaries()[0].book;
+        assert!(book.size.is_zero());
+        assert_eq!(book.realized_pnl, dec!(4));
+        assert_eq!(book.fees, dec!(0.3564));
+        assert!(hedger.plan_flatten(mid, 3_000).is_empty());
+    }
+
+    #[test]
+    fn test_hedge_book_pnl() {
+        let mut book = HedgeBook::default();
+        book.apply_fill(OrderSide::Sell, dec!(2000), dec!(1), dec!(0.9));
+        book.apply_fill(OrderSide::Sell, dec!(2010), dec!(1), dec!(0.9045));
+        assert_eq!((book.size, book.avg_px), (dec!(-2), dec!(2005)));
+        assert_eq!(book.unrealized_pnl(dec!(1995)), dec!(20));
+
+        // Buy back 3: close 2 at a 10/unit profit, flip long 1 at 1995
+        book.apply_fill(OrderSide::Buy, dec!(1995), dec!(3), dec!(2.69325));
+        assert_eq!(book.realized_pnl, dec!(20));
+        assert_eq!((book.size, book.avg_px), (dec!(1), dec!(1995)));
+        assert_eq!(book.fees, dec!(4.49775));
+        assert_eq!(book.net_pnl(dec!(1995)), dec!(15.50225));
+    }
+}
@@ -10,9 +10,11 @@
 //! - [`PositionTrackerHandle`]: Handle for interacting with position tracker
 //! - [`TimeStop`]: Monitors position holding time for timeout detection
 //! - [`TimeStopConfig`]: Configuration for time-based exit parameters
+//! - [`VolScaling`]: Volatility scaling of the time stop threshold
 //! - [`TimeStopManager`]: Batch checking of multiple positions (legacy)
 //! - [`FlattenOrderBuilder`]: Creates reduce-only orders to close positions
 //! - [`Flattener`]: Manages the flatten (close) process state machine
+//! - [`FlattenEscalationConfig`]: Escalating pricing of repeated flatten attempts
 //! - [`FlattenRequest`]: Request to close a position
 //! - [`FlattenReason`]: Why a position is being flattened
 //! - [`PriceProvider`]: Trait for providing current market prices
@@ -20,31 +22,60 @@
 //! - [`MarkRegressionMonitor`]: Background task for profit-taking when BBO returns to Oracle (polling)
 //! - [`ExitWatcher`]: WS-driven exit for immediate mark regression detection (< 1ms latency)
 //! - [`OracleExitWatcher`]: Oracle-driven exit based on consecutive price movements
+//! - [`TakeProfitLadderConfig`]: Partial take-profit rungs per exit profile
+//! - [`BreakEvenConfig`]: Break-even stop with per-profile activation thresholds
+//! - [`ExitStrategy`]: Pluggable exit logic, combined per exit profile by [`ExitStrategyRegistry`]
+//! - [`StopLoss`]: Stop-loss on unrealized loss per exit profile
+//! - [`OcoBook`]: Resting take-profit linked to a synthetic stop (OCO exits)
+//! - [`reconcile`]: Diffs local positions against the exchange before a resync
+//! - [`HedgeManager`]: Hedges xyz exposure on the corresponding L1 perps
 
+pub mod break_even;
 pub mod error;
+pub mod exit_strategy;
 pub mod exit_watcher;
 pub mod flatten;
+pub mod hedge;
 pub mod mark_regression;
+pub mod oco;
 pub mod oracle_exit;
+pub mod reconcile;
+pub mod stop_loss;
+pub mod take_profit;
 pub mod time_stop;
 pub mod tracker;
 
+pub use break_even::{BreakEvenConfig, BreakEvenStop};
 pub use error::{PositionError, PositionResult};
+pub use exit_strategy::{
+    ExitStrategiesConfig, ExitStrategy, ExitStrategyRegistry, ExitStrategyWatcher,
+    ExitStrategyWatcherHandle,
+};
 pub use exit_watcher::{new_exit_watcher, ExitWatcher, ExitWatcherHandle};
 pub use flatten::{
-    flatten_all_positions, FlattenReason, FlattenRequest, FlattenState, Flattener,
-    REDUCE_ONLY_TIMEOUT_MS,
+    flatten_all_positions, partial_flatten_request, FlattenEscalationConfig, FlattenPricing,
+    FlattenReason, FlattenRequest, FlattenState, Flattener, REDUCE_ONLY_TIMEOUT_MS,
+};
+pub use hedge::{
+    HedgeBook, HedgeConfig, HedgeHandle, HedgeLeg, HedgeManager, HedgePairConfig, HedgeSummary,
+    HEDGE_ORDER_TIMEOUT_MS,
 };
 pub use mark_regression::{MarkRegressionConfig, MarkRegressionMonitor};
+pub use oco::{OcoAction, OcoBook, OcoConfig, OcoPair, TakeProfitLeg};
 pub use oracle_exit::{
     new_oracle_exit_watcher, OracleExitConfig, OracleExitMetrics, OracleExitReason,
     OracleExitWatcher, OracleExitWatcherHandle,
 };
+pub use reconcile::{
+    reconcile, DriftConfirmer, DriftKind, PositionDrift, ReconcileConfig, ReconcileReport,
+};
+pub use stop_loss::{StopLoss, StopLossConfig};
+pub use take_profit::{LadderDecision, LadderProgress, TakeProfitLadderConfig, TakeProfitRung};
 pub use time_stop::{
     FlattenOrderBuilder, PriceProvider, TimeStop, TimeStopConfig, TimeStopManager, TimeStopMonitor,
-    TIME_STOP_MS,
+    VolScaling, TIME_STOP_MS,
 };
 pub use tracker::{
-    spawn_position_tracker, Position, PositionTrackerHandle, PositionTrackerMsg,
-    PositionTrackerTask, SharedFlatteningGuard,
+    exit_side_pnl_bps, spawn_position_tracker, Position, PositionTrackerHandle, PositionTrackerMsg,
+    PositionTrackerTask, SharedFlatteningGuard, Tranche,
 };
@@ -18,6 +18,7 @@ use tracing::{debug, info};
 use hip3_core::{MarketKey, OrderSide, PendingOrder};
 use hip3_feed::MarketState;
 
+use crate::break_even::BreakEvenConfig;
 use crate::time_stop::{FlattenOrderBuilder, TIME_STOP_MS};
 use crate::tracker::{Position, PositionTrackerHandle, SharedFlatteningGuard};
 
@@ -116,6 +117,12 @@ pub struct MarkRegressionConfig {
     /// Default: 0.2 (20%).
     #[serde(default = "default_min_decay_factor")]
     pub min_decay_factor: f64,
+
+    // --- Break-even stop (ExitWatcher only) ---
+    /// Flatten on regression to entry plus fees once PnL reached the
+    /// profile's activation threshold. Default: disabled.
+    #[serde(default)]
+    pub break_even: BreakEvenConfig,
 }
 
 fn default_enabled() -> bool {
@@ -181,6 +188,7 @@ impl Default for MarkRegressionConfig {
             time_decay_enabled: false,
             decay_start_ms: default_decay_start_ms(),
             min_decay_factor: default_min_decay_factor(),
+            break_even: BreakEvenConfig::default(),
         }
     }
 }
@@ -339,7 +347,7 @@ impl MarkRegressionMonitor {
                 if let Some(edge_bps) = self.check_exit(&position, now_ms) {
                     // Shared guard: prevent cross-monitor duplicates
                     if let Some(ref guard) = self.shared_flattening {
-                        if !guard.try_claim(&position.market) {
+                        if !guard.try_claim(&position.market, "MarkRegression") {
                             debug!(
                                 market = %position.market,
                                 "MarkRegression: flatten already claimed by another monitor"
@@ -0,0 +1,417 @@
+//! OCO exits: a resting take-profit linked to a synthetic stop.
+//!
+//! Instead of waiting for a reactive flatten, each open position gets a
+//! reduce-only ALO take-profit resting `take_profit_bps` beyond its entry,
+//! and a stop `stop_bps` against it that is only tracked locally. The two
+//! legs cancel each other: a stop hit cancels the resting take-profit and
+//! flattens, while a take-profit fill closes the position and so drops the
+//! stop. A position closed by any other exit cancels its take-profit.
+//!
+//! [`OcoBook`] is pure bookkeeping, like `HedgeManager`: the caller feeds it
+//! BBO and order updates and queues the returned orders and cancels through
+//! the BatchScheduler. The take-profit is sized to the position when the
+//! pair is armed; later add-ons are covered by the stop only.
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use hip3_core::{
+    Bbo, ClientOrderId, MarketKey, OrderSide, OrderState, PendingCancel, PendingOrder, Price,
+    TimeInForce,
+};
+
+use crate::flatten::{FlattenReason, FlattenRequest};
+use crate::tracker::Position;
+
+/// OCO exit configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OcoConfig {
+    /// Enable OCO exits. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Take-profit distance beyond entry (bps). 0 = stop only.
+    #[serde(default = "default_take_profit_bps")]
+    pub take_profit_bps: Decimal,
+
+    /// Stop distance against entry (bps). 0 = take-profit only.
+    #[serde(default = "default_stop_bps")]
+    pub stop_bps: Decimal,
+
+    /// Slippage tolerance of the stop's flatten order (bps).
+    #[serde(default = "default_stop_slippage_bps")]
+    pub stop_slippage_bps: u64,
+}
+
+fn default_take_profit_bps() -> Decimal {
+    Decimal::from(30)
+}
+
+fn default_stop_bps() -> Decimal {
+    Decimal::from(40)
+}
+
+fn default_stop_slippage_bps() -> u64 {
+    50
+}
+
+impl Default for OcoConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            take_profit_bps: default_take_profit_bps(),
+            stop_bps: default_stop_bps(),
+            stop_slippage_bps: default_stop_slippage_bps(),
+        }
+    }
+}
+
+/// Resting take-profit leg of a pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TakeProfitLeg {
+    /// Client order ID of the take-profit.
+    pub cloid: ClientOrderId,
+    /// Exchange order ID, once the order rests.
+    pub oid: Option<u64>,
+    /// Limit price.
+    pub price: Price,
+}
+
+/// OCO pair of an open position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcoPair {
+    /// Position side (Buy = long).
+    pub side: OrderSide,
+    /// Take-profit leg (None once filled, cancelled or rejected).
+    pub take_profit: Option<TakeProfitLeg>,
+    /// Stop price (None if the stop is disabled).
+    pub stop_price: Option<Price>,
+    /// Whether the stop has fired.
+    pub stop_triggered: bool,
+}
+
+/// Order action returned by the [`OcoBook`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcoAction {
+    /// Place the take-profit of a newly armed pair.
+    PlaceTakeProfit(PendingOrder),
+    /// Cancel the take-profit of a position that is gone.
+    CancelTakeProfit(PendingCancel),
+    /// Stop hit: flatten, cancelling the take-profit if it rests.
+    StopTriggered {
+        /// Flatten request of the position.
+        request: FlattenRequest,
+        /// Cancel of the take-profit (None if its oid is not known yet).
+        cancel: Option<PendingCancel>,
+    },
+}
+
+/// OCO pairs per market.
+#[derive(Debug, Default)]
+pub struct OcoBook {
+    config: OcoConfig,
+    pairs: HashMap<MarketKey, OcoPair>,
+}
+
+impl OcoBook {
+    /// Exit reason of a stop hit.
+    pub const STOP_NAME: &'static str = "OcoStop";
+    /// Exit reason of a take-profit fill.
+    pub const TAKE_PROFIT_NAME: &'static str = "OcoTakeProfit";
+
+    /// Create an empty book.
+    #[must_use]
+    pub fn new(config: OcoConfig) -> Self {
+        Self {
+            config,
+            pairs: HashMap::new(),
+        }
+    }
+
+    /// Configuration.
+    #[must_use]
+    pub fn config(&self) -> &OcoConfig {
+        &self.config
+    }
+
+    /// Pair of a market, if armed.
+    #[must_use]
+    pub fn pair(&self, market: &MarketKey) -> Option<&OcoPair> {
+        self.pairs.get(market)
+    }
+
+    /// Whether `cloid` is the take-profit of the pair in `market`.
+    #[must_use]
+    pub fn is_take_profit(&self, market: &MarketKey, cloid: &ClientOrderId) -> bool {
+        self.pairs
+            .get(market)
+            .and_then(|pair| pair.take_profit.as_ref())
+            .is_some_and(|tp| tp.cloid == *cloid)
+    }
+
+    /// Evaluate the pair of `market` on a BBO update.
+    ///
+    /// Arms a pair for a position without one, fires the stop once the
+    /// exit-side price crosses it, and drops the pair (cancelling the
+    /// take-profit) of a closed or flipped position. Positions being
+    /// flattened by another exit are left alone.
+    pub fn on_market_update(
+        &mut self,
+        market: MarketKey,
+        position: Option<&Position>,
+        bbo: &Bbo,
+        flattening: bool,
+        now_ms: u64,
+    ) -> Option<OcoAction> {
+        let armed_side = self.pairs.get(&market).map(|pair| pair.side);
+        let position = match position {
+            Some(p) if armed_side.unwrap_or(p.side) == p.side => p,
+            _ => {
+                let pair = self.pairs.remove(&market)?;
+                debug!(%market, "OCO pair dropped: position closed");
+                let oid = pair.take_profit.and_then(|tp| tp.oid)?;
+                return Some(OcoAction::CancelTakeProfit(PendingCancel::new(
+                    market, oid, now_ms,
+                )));
+            }
+        };
+        if flattening {
+            return None;
+        }
+
+        let Some(pair) = self.pairs.get_mut(&market) else {
+            return self.arm(position, now_ms);
+        };
+        if pair.stop_triggered {
+            return None;
+        }
+        let stop = pair.stop_price?.inner();
+        let hit = match position.side {
+            OrderSide::Buy => !bbo.bid_price.is_zero() && bbo.bid_price.inner() <= stop,
+            OrderSide::Sell => !bbo.ask_price.is_zero() && bbo.ask_price.inner() >= stop,
+        };
+        if !hit {
+            return None;
+        }
+
+        pair.stop_triggered = true;
+        let cancel = pair
+            .take_profit
+            .as_ref()
+            .and_then(|tp| tp.oid)
+            .map(|oid| PendingCancel::new(market, oid, now_ms));
+        Some(OcoAction::StopTriggered {
+            request: FlattenRequest::full(
+                position,
+                FlattenReason::Strategy {
+                    name: Self::STOP_NAME.to_string(),
+                },
+                now_ms,
+            ),
+            cancel,
+        })
+    }
+
+    /// Record an order update of a take-profit.
+    ///
+    /// Returns the cancel of a take-profit whose oid arrives after the stop
+    /// already fired.
+    pub fn on_order_update(
+        &mut self,
+        market: MarketKey,
+        cloid: &ClientOrderId,
+        oid: u64,
+        state: OrderState,
+        now_ms: u64,
+    ) -> Option<PendingCancel> {
+        let pair = self.pairs.get_mut(&market)?;
+        let tp = pair.take_profit.as_mut().filter(|tp| tp.cloid == *cloid)?;
+        if state.is_terminal() {
+            debug!(%market, %cloid, ?state, "OCO take-profit done");
+            pair.take_profit = None;
+            return None;
+        }
+        tp.oid = Some(oid);
+        pair.stop_triggered
+            .then(|| PendingCancel::new(market, oid, now_ms))
+    }
+
+    /// Arm a pair for `position`, returning its take-profit order.
+    fn arm(&mut self, position: &Position, now_ms: u64) -> Option<OcoAction> {
+        let entry = position.entry_price.inner();
+        if entry.is_zero() {
+            return None;
+        }
+        let sign = match position.side {
+            OrderSide::Buy => Decimal::ONE,
+            OrderSide::Sell => -Decimal::ONE,
+        };
+        let bps = Decimal::from(10000);
+        let stop_price = (self.config.stop_bps > Decimal::ZERO)
+            .then(|| Price::new(entry * (Decimal::ONE - sign * self.config.stop_bps / bps)));
+
+        let order = (self.config.take_profit_bps > Decimal::ZERO).then(|| {
+            PendingOrder::with_tif(
+                ClientOrderId::new(),
+                position.market,
+                position.side.opposite(),
+                Price::new(entry * (Decimal::ONE + sign * self.config.take_profit_bps / bps)),
+                position.size,
+                true, // reduce_only
+                now_ms,
+                TimeInForce::AddLiquidityOnly,
+            )
+        });
+
+        self.pairs.insert(
+            position.market,
+            OcoPair {
+                side: position.side,
+                take_profit: order.as_ref().map(|o| TakeProfitLeg {
+                    cloid: o.cloid.clone(),
+                    oid: None,
+                    price: o.price,
+                }),
+                stop_price,
+                stop_triggered: false,
+            },
+        );
+        debug!(market = %position.market, ?stop_price, "OCO pair armed");
+        order.map(OcoAction::PlaceTakeProfit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId, Size};
+    use rust_decimal_macros::dec;
+
+    fn market() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn long(entry: Decimal) -> Position {
+        Position::new(
+            market(),
+            OrderSide::Buy,
+            Size::new(dec!(2)),
+            Price::new(entry),
+            1_000,
+        )
+    }
+
+    fn bbo(bid: Decimal, ask: Decimal) -> Bbo {
+        Bbo::new(
+            Price::new(bid),
+            Size::new(dec!(1)),
+            Price::new(ask),
+            Size::new(dec!(1)),
+        )
+    }
+
+    fn place(book: &mut OcoBook, position: &Position) -> PendingOrder {
+        match book.on_market_update(
+            market(),
+            Some(position),
+            &bbo(dec!(100), dec!(100.1)),
+            false,
+            2_000,
+        ) {
+            Some(OcoAction::PlaceTakeProfit(order)) => order,
+            other => panic!("expected take-profit, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_stop_cancels_resting_take_profit() {
+        let mut book = OcoBook::new(OcoConfig::default());
+        let position = long(dec!(100));
+
+        // Arming places a reduce-only ALO take-profit 30 bps above entry
+        let tp = place(&mut book, &position);
+        assert_eq!(tp.side, OrderSide::Sell);
+        assert_eq!(tp.price, Price::new(dec!(100.3)));
+        assert_eq!(tp.size, Size::new(dec!(2)));
+        assert!(tp.reduce_only);
+        assert_eq!(tp.tif, TimeInForce::AddLiquidityOnly);
+        assert!(book.is_take_profit(&market(), &tp.cloid));
+        assert_eq!(
+            book.on_order_update(market(), &tp.cloid, 7, OrderState::Open, 2_100),
+            None
+        );
+
+        // Bid 99.70 is above the 40 bps stop (99.60)
+        let update = bbo(dec!(99.7), dec!(99.8));
+        assert_eq!(
+            book.on_market_update(market(), Some(&position), &update, false, 3_000),
+            None
+        );
+
+        // Bid 99.60 hits the stop: flatten and cancel the take-profit
+        let update = bbo(dec!(99.6), dec!(99.7));
+        let Some(OcoAction::StopTriggered { request, cancel }) =
+            book.on_market_update(market(), Some(&position), &update, false, 4_000)
+        else {
+            panic!("expected stop");
+        };
+        assert_eq!(request.side, OrderSide::Sell);
+        assert_eq!(request.size, Size::new(dec!(2)));
+        assert_eq!(cancel, Some(PendingCancel::new(market(), 7, 4_000)));
+        // Fires once
+        assert_eq!(
+            book.on_market_update(market(), Some(&position), &update, false, 5_000),
+            None
+        );
+    }
+
+    #[test]
+    fn test_closed_position_cancels_take_profit() {
+        let mut book = OcoBook::new(OcoConfig::default());
+        let position = long(dec!(100));
+
+        // Stop fires before the take-profit rests: cancelled on its oid
+        let tp = place(&mut book, &position);
+        let update = bbo(dec!(99), dec!(99.1));
+        assert!(matches!(
+            book.on_market_update(market(), Some(&position), &update, false, 3_000),
+            Some(OcoAction::StopTriggered { cancel: None, .. })
+        ));
+        assert_eq!(
+            book.on_order_update(market(), &tp.cloid, 9, OrderState::Open, 3_100),
+            Some(PendingCancel::new(market(), 9, 3_100))
+        );
+
+        // Position closed by another exit: the take-profit is cancelled
+        let mut book = OcoBook::new(OcoConfig::default());
+        let tp = place(&mut book, &position);
+        book.on_order_update(market(), &tp.cloid, 11, OrderState::Open, 2_100);
+        assert_eq!(
+            book.on_market_update(market(), None, &update, false, 6_000),
+            Some(OcoAction::CancelTakeProfit(PendingCancel::new(
+                market(),
+                11,
+                6_000
+            )))
+        );
+        assert!(book.pair(&market()).is_none());
+
+        // Take-profit filled: the pair goes with the position, nothing to cancel
+        let tp = place(&mut book, &position);
+        book.on_order_update(market(), &tp.cloid, 12, OrderState::Filled, 7_000);
+        assert_eq!(
+            book.on_market_update(market(), None, &update, false, 8_000),
+            None
+        );
+
+        // No pair is armed while another exit is flattening
+        assert_eq!(
+            book.on_market_update(market(), Some(&position), &update, true, 9_000),
+            None
+        );
+        assert!(book.pair(&market()).is_none());
+    }
+}
@@ -17,6 +17,11 @@
 //!    - MMs are catching up, edge is narrowing
 //!    - Take profit before spread compresses
 //!
+//! 3. **Partial Take Profit (TakeProfit / LadderStop)**: optional ladder per
+//!    exit profile (see [`crate::take_profit`])
+//!    - Close part of the position at each profit rung
+//!    - Exit the remainder if PnL falls back to the tightened stop
+//!
 //! # Architecture
 //!
 //! ```text
@@ -44,8 +49,10 @@ use hip3_core::types::MarketSnapshot;
 use hip3_core::{ExitProfile, MarketKey, OrderSide, PendingOrder};
 use hip3_feed::OracleMovementTracker;
 
+use crate::flatten::{partial_flatten_request, FlattenReason};
+use crate::take_profit::{LadderDecision, LadderProgress, TakeProfitLadderConfig};
 use crate::time_stop::FlattenOrderBuilder;
-use crate::tracker::{Position, PositionTrackerHandle, SharedFlatteningGuard};
+use crate::tracker::{exit_side_pnl_bps, Position, PositionTrackerHandle, SharedFlatteningGuard};
 
 // ============================================================================
 // Oracle Baseline
@@ -73,6 +80,8 @@ struct OracleBaseline {
     trail_activated: bool,
     /// Sprint 4 P2-F: Exit profile for this position.
     exit_profile: ExitProfile,
+    /// Take-profit ladder progress (rungs fired, tightened stop).
+    ladder: LadderProgress,
 }
 
 // ============================================================================
@@ -149,6 +158,10 @@ pub struct OracleExitConfig {
     /// the ExitProfile assigned at signal generation time.
     #[serde(default)]
     pub exit_profile_enabled: bool,
+
+    /// Partial take-profit ladder, per exit profile.
+    #[serde(default)]
+    pub take_profit: TakeProfitLadderConfig,
 }
 
 fn default_enabled() -> bool {
@@ -202,6 +215,7 @@ impl Default for OracleExitConfig {
             activation_bps: default_activation_bps(),
             trail_bps: default_trail_bps(),
             exit_profile_enabled: false,
+            take_profit: TakeProfitLadderConfig::default(),
         }
     }
 }
@@ -244,6 +258,39 @@ pub enum OracleExitReason {
         /// Profile time limit (ms).
         limit_ms: u64,
     },
+
+    /// Take-profit ladder rung reached: close part of the position.
+    TakeProfit {
+        /// Index of the rung (0-based).
+        rung: usize,
+        /// Fraction of the remaining position to close.
+        close_fraction: Decimal,
+        /// Unrealized PnL in bps when the rung fired.
+        pnl_bps: Decimal,
+    },
+
+    /// PnL fell back to the stop tightened by the take-profit ladder.
+    LadderStop {
+        /// Stop level (PnL bps).
+        stop_bps: i32,
+        /// Unrealized PnL in bps when the stop fired.
+        pnl_bps: Decimal,
+    },
+}
+
+impl OracleExitReason {
+    /// Short label for metrics and PnL attribution.
+    #[must_use]
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::OracleReversal { .. } => "OracleReversal",
+            Self::OracleCatchup { .. } => "OracleCatchup",
+            Self::TrailingStop { .. } => "TrailingStop",
+            Self::ProfileTimeStop { .. } => "ProfileTimeStop",
+            Self::TakeProfit { .. } => "TakeProfit",
+            Self::LadderStop { .. } => "LadderStop",
+        }
+    }
 }
 
 impl std::fmt::Display for OracleExitReason {
@@ -272,6 +319,24 @@ impl std::fmt::Display for OracleExitReason {
                     held_ms, limit_ms
                 )
             }
+            Self::TakeProfit {
+                rung,
+                close_fraction,
+                pnl_bps,
+            } => {
+                write!(
+                    f,
+                    "TakeProfit(rung {}, close {} at {} bps)",
+                    rung, close_fraction, pnl_bps
+                )
+            }
+            Self::LadderStop { stop_bps, pnl_bps } => {
+                write!(
+                    f,
+                    "LadderStop(stop={} bps, current={} bps)",
+                    stop_bps, pnl_bps
+                )
+            }
         }
     }
 }
@@ -393,6 +458,7 @@ impl OracleExitWatcher {
             best_pnl_bps: Decimal::ZERO,
             trail_activated: false,
             exit_profile,
+            ladder: LadderProgress::default(),
         };
 
         debug!(
@@ -469,9 +535,13 @@ impl OracleExitWatcher {
         if self.config.exit_profile_enabled {
             if let Some(time_stop_ms) = self.profile_time_stop_ms(&key) {
                 if held_ms >= time_stop_ms {
+                    let reason = OracleExitReason::ProfileTimeStop {
+                        held_ms,
+                        limit_ms: time_stop_ms,
+                    };
                     // Shared guard: prevent cross-monitor duplicates
                     if let Some(ref guard) = self.shared_flattening {
-                        if !guard.try_claim(&key) {
+                        if !guard.try_claim(&key, reason.label()) {
                             trace!(market = %key, "OracleExit: flatten already claimed by another monitor");
                             return;
                         }
@@ -480,22 +550,37 @@ impl OracleExitWatcher {
                         let mut flattening = self.local_flattening.write();
                         flattening.insert(key);
                     }
-                    let reason = OracleExitReason::ProfileTimeStop {
-                        held_ms,
-                        limit_ms: time_stop_ms,
-                    };
                     self.trigger_exit(&position, reason, snapshot, now_ms);
                     return;
                 }
             }
         }
 
+        // 5.5. Partial take-profit ladder (rungs and tightened stop)
+        if self.config.take_profit.enabled {
+            if let Some(reason) = self.check_take_profit(&position, snapshot) {
+                // Shared guard: prevent cross-monitor duplicates
+                if let Some(ref guard) = self.shared_flattening {
+                    if !guard.try_claim(&key, reason.label()) {
+                        trace!(market = %key, "OracleExit: flatten already claimed by another monitor");
+                        return;
+                    }
+                }
+                {
+                    let mut flattening = self.local_flattening.write();
+                    flattening.insert(key);
+                }
+                self.trigger_exit(&position, reason, snapshot, now_ms);
+                return;
+            }
+        }
+
         // 6. P3-2: Update trailing stop state and check for trail exit
         if self.config.trailing_stop {
             if let Some(reason) = self.update_trailing_state(&key, &position, snapshot) {
                 // Shared guard: prevent cross-monitor duplicates
                 if let Some(ref guard) = self.shared_flattening {
-                    if !guard.try_claim(&key) {
+                    if !guard.try_claim(&key, reason.label()) {
                         trace!(market = %key, "OracleExit: flatten already claimed by another monitor");
                         return;
                     }
@@ -513,7 +598,7 @@ impl OracleExitWatcher {
         if let Some(reason) = self.check_oracle_exit(&position) {
             // Shared guard: prevent cross-monitor duplicates
             if let Some(ref guard) = self.shared_flattening {
-                if !guard.try_claim(&key) {
+                if !guard.try_claim(&key, reason.label()) {
                     trace!(market = %key, "OracleExit: flatten already claimed by another monitor");
                     return;
                 }
@@ -598,6 +683,60 @@ impl OracleExitWatcher {
         None
     }
 
+    /// Check the take-profit ladder at the price the position would exit at.
+    ///
+    /// Does not advance the ladder; `trigger_exit` does once the order is sent.
+    fn check_take_profit(
+        &self,
+        position: &Position,
+        snapshot: &MarketSnapshot,
+    ) -> Option<OracleExitReason> {
+        let pnl_bps = exit_side_pnl_bps(position, position.exit_side_price(&snapshot.bbo))?;
+
+        let baselines = self.position_baselines.read();
+        let baseline = baselines.get(&position.market)?;
+        match self
+            .config
+            .take_profit
+            .evaluate(baseline.exit_profile, &baseline.ladder, pnl_bps)?
+        {
+            LadderDecision::TakeProfit {
+                rung,
+                close_fraction,
+                pnl_bps,
+            } => Some(OracleExitReason::TakeProfit {
+                rung,
+                close_fraction,
+                pnl_bps,
+            }),
+            LadderDecision::Stop { stop_bps, pnl_bps } => {
+                Some(OracleExitReason::LadderStop { stop_bps, pnl_bps })
+            }
+        }
+    }
+
+    /// Record that a take-profit rung was sent for a position.
+    fn advance_ladder(&self, key: &MarketKey, rung: usize) {
+        let mut baselines = self.position_baselines.write();
+        let Some(baseline) = baselines.get_mut(key) else {
+            return;
+        };
+        if let Some(config_rung) = self
+            .config
+            .take_profit
+            .rungs(baseline.exit_profile)
+            .get(rung)
+        {
+            baseline.ladder.advance(rung, config_rung);
+            debug!(
+                market = %key,
+                rung,
+                stop_bps = ?baseline.ladder.stop_bps,
+                "OracleExitWatcher: take-profit rung sent, stop tightened"
+            );
+        }
+    }
+
     /// Check if position should exit based on oracle movements since entry.
     ///
     /// Uses delta from baseline (not global count) to correctly measure
@@ -695,13 +834,34 @@ impl OracleExitWatcher {
             OrderSide::Sell => snapshot.bbo.ask_price, // Buy at ask
         };
 
-        // Create reduce-only order
-        let order = FlattenOrderBuilder::create_flatten_order(
-            position,
-            price,
-            self.config.slippage_bps,
-            now_ms,
-        );
+        // Create reduce-only order (part of the position for a take-profit rung)
+        let partial = match reason {
+            OracleExitReason::TakeProfit {
+                rung,
+                close_fraction,
+                ..
+            } => partial_flatten_request(
+                position,
+                close_fraction,
+                FlattenReason::TakeProfit { rung },
+                now_ms,
+            ),
+            _ => None,
+        };
+        let order = match partial {
+            Some(ref request) => FlattenOrderBuilder::create_request_order(
+                request,
+                price,
+                self.config.slippage_bps,
+                now_ms,
+            ),
+            None => FlattenOrderBuilder::create_flatten_order(
+                position,
+                price,
+                self.config.slippage_bps,
+                now_ms,
+            ),
+        };
 
         let held_ms = now_ms.saturating_sub(position.entry_timestamp_ms);
 
@@ -721,15 +881,18 @@ impl OracleExitWatcher {
                 // Profile time stop: counts as reversal (defensive exit)
                 self.reversal_count.fetch_add(1, Ordering::Relaxed);
             }
+            OracleExitReason::TakeProfit { .. } => {
+                // Partial take profit counts as a catchup (profit-take variant)
+                self.catchup_count.fetch_add(1, Ordering::Relaxed);
+            }
+            OracleExitReason::LadderStop { .. } => {
+                // Ladder stop: counts as reversal (defensive exit of the remainder)
+                self.reversal_count.fetch_add(1, Ordering::Relaxed);
+            }
         }
 
         // P1-4: Record exit metrics
-        let exit_reason_str = match reason {
-            OracleExitReason::OracleReversal { .. } => "OracleReversal",
-            OracleExitReason::OracleCatchup { .. } => "OracleCatchup",
-            OracleExitReason::TrailingStop { .. } => "TrailingStop",
-            OracleExitReason::ProfileTimeStop { .. } => "ProfileTimeStop",
-        };
+        let exit_reason_str = reason.label();
         let market_str = position.market.to_string();
         hip3_telemetry::Metrics::position_holding_time(
             &market_str,
@@ -763,6 +926,7 @@ impl OracleExitWatcher {
             held_ms = held_ms,
             exit_reason = exit_reason_str,
             cloid = %order.cloid,
+            size = %order.size,
             reversal_count = self.reversal_count.load(Ordering::Relaxed),
             catchup_count = self.catchup_count.load(Ordering::Relaxed),
             "OracleExitWatcher: exit triggered"
@@ -772,6 +936,9 @@ impl OracleExitWatcher {
         match self.flatten_tx.try_send(order) {
             Ok(()) => {
                 debug!(market = %position.market, "OracleExitWatcher: flatten order sent");
+                if let OracleExitReason::TakeProfit { rung, .. } = reason {
+                    self.advance_ladder(&position.market, rung);
+                }
             }
             Err(mpsc::error::TrySendError::Full(_)) => {
                 warn!(
@@ -1046,6 +1213,7 @@ mod tests {
             best_pnl_bps: Decimal::ZERO,
             trail_activated: false,
             exit_profile: ExitProfile::Standard,
+            ladder: LadderProgress::default(),
         };
         assert_eq!(baseline.entry_edge_bps, Some(Decimal::from(45)));
         assert_eq!(baseline.entry_oracle_px, Some(dec!(100)));
@@ -1059,6 +1227,7 @@ mod tests {
             best_pnl_bps: Decimal::ZERO,
             trail_activated: false,
             exit_profile: ExitProfile::Runner,
+            ladder: LadderProgress::default(),
         };
         assert_eq!(baseline_no_edge.entry_edge_bps, None);
         assert_eq!(baseline_no_edge.entry_oracle_px, None);
@@ -1119,6 +1288,41 @@ mod tests {
         assert!(config.exit_profile_enabled);
     }
 
+    #[test]
+    fn test_take_profit_config() {
+        // Existing TOML without take_profit keeps the ladder off
+        let config: OracleExitConfig = toml::from_str("enabled = true").unwrap();
+        assert!(!config.take_profit.enabled);
+
+        let toml = r#"
+            enabled = true
+
+            [take_profit]
+            enabled = true
+            runner = [
+                { trigger_bps = 8, close_fraction = "0.5", stop_bps = 0 },
+                { trigger_bps = 16, close_fraction = "0.5", stop_bps = 8 },
+            ]
+        "#;
+        let config: OracleExitConfig = toml::from_str(toml).unwrap();
+        assert!(config.take_profit.enabled);
+        assert_eq!(config.take_profit.rungs(ExitProfile::Runner).len(), 2);
+        assert_eq!(config.take_profit.rungs(ExitProfile::Runner)[1].stop_bps, 8);
+        assert!(config.take_profit.rungs(ExitProfile::Scalper).is_empty());
+
+        let tp = OracleExitReason::TakeProfit {
+            rung: 0,
+            close_fraction: dec!(0.5),
+            pnl_bps: dec!(12),
+        };
+        assert_eq!(tp.to_string(), "TakeProfit(rung 0, close 0.5 at 12 bps)");
+        let stop = OracleExitReason::LadderStop {
+            stop_bps: 0,
+            pnl_bps: dec!(-1),
+        };
+        assert_eq!(stop.to_string(), "LadderStop(stop=0 bps, current=-1 bps)");
+    }
+
     #[test]
     fn test_oracle_baseline_exit_profiles() {
         // Test all three exit profiles in OracleBaseline
@@ -1130,6 +1334,7 @@ mod tests {
             best_pnl_bps: Decimal::ZERO,
             trail_activated: false,
             exit_profile: ExitProfile::Scalper,
+            ladder: LadderProgress::default(),
         };
         assert_eq!(scalper.exit_profile, ExitProfile::Scalper);
 
@@ -1141,6 +1346,7 @@ mod tests {
             best_pnl_bps: Decimal::ZERO,
             trail_activated: false,
             exit_profile: ExitProfile::Runner,
+            ladder: LadderProgress::default(),
         };
         assert_eq!(runner.exit_profile, ExitProfile::Runner);
     }
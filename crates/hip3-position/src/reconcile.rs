@@ -0,0 +1,374 @@
+//! Position reconciliation against the exchange.
+//!
+//! The periodic resync replaces local `PositionTracker` state with the
+//! clearinghouseState positions. [`reconcile`] diffs the two first, so a
+//! missed fill, a position opened outside the bot, or a stale local entry
+//! shows up as drift instead of being overwritten silently.
+//!
+//! Drift is measured as the notional of the exposure difference (signed size
+//! difference at the exchange entry price). An entry price difference beyond
+//! `entry_tolerance_bps` is reported but carries no notional.
+//!
+//! Positions are keyed by (account, market): the local tracker holds the
+//! taker account, so a position in another account is drift, never merged
+//! into the taker's. A fill landing between the two snapshots drifts for one
+//! pass only, so [`DriftConfirmer`] reports a drift once it persisted over
+//! `confirm_passes` consecutive passes.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use hip3_core::{MarketKey, OrderSide};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::tracker::Position;
+
+/// Reconciliation configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconcileConfig {
+    /// Entry price difference (bps) tolerated before it is reported.
+    /// Default: 5.
+    #[serde(default = "default_entry_tolerance_bps")]
+    pub entry_tolerance_bps: Decimal,
+
+    /// Trigger a hard stop when the total drift notional (USD) exceeds this.
+    /// 0 = disabled (default).
+    #[serde(default)]
+    pub hard_stop_drift_notional: Decimal,
+
+    /// Consecutive passes a position must drift before it is reported.
+    /// Default: 2.
+    #[serde(default = "default_confirm_passes")]
+    pub confirm_passes: u32,
+}
+
+fn default_entry_tolerance_bps() -> Decimal {
+    Decimal::from(5)
+}
+
+fn default_confirm_passes() -> u32 {
+    2
+}
+
+impl Default for ReconcileConfig {
+    fn default() -> Self {
+        Self {
+            entry_tolerance_bps: default_entry_tolerance_bps(),
+            hard_stop_drift_notional: Decimal::ZERO,
+            confirm_passes: default_confirm_passes(),
+        }
+    }
+}
+
+impl ReconcileConfig {
+    /// Whether `report` breaches the hard stop threshold.
+    #[must_use]
+    pub fn breaches_hard_stop(&self, report: &ReconcileReport) -> bool {
+        self.hard_stop_drift_notional > Decimal::ZERO
+            && report.drift_notional() > self.hard_stop_drift_notional
+    }
+}
+
+/// Kind of difference between local and exchange state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftKind {
+    /// Position on the exchange, none locally.
+    MissingLocal,
+    /// Position tracked locally, none on the exchange.
+    MissingRemote,
+    /// Positions on opposite sides.
+    Side,
+    /// Same side, different size.
+    Size,
+    /// Same side and size, entry price beyond tolerance.
+    EntryPrice,
+}
+
+impl DriftKind {
+    /// Metric label of the kind.
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MissingLocal => "missing_local",
+            Self::MissingRemote => "missing_remote",
+            Self::Side => "side",
+            Self::Size => "size",
+            Self::EntryPrice => "entry_price",
+        }
+    }
+}
+
+impl fmt::Display for DriftKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Difference for one account and market.
+#[derive(Debug, Clone)]
+pub struct PositionDrift {
+    /// Account holding the position (lowercase).
+    pub account: String,
+    /// Market of the position.
+    pub market: MarketKey,
+    /// Kind of difference.
+    pub kind: DriftKind,
+    /// Local signed size (positive = long).
+    pub local_size: Decimal,
+    /// Exchange signed size (positive = long).
+    pub remote_size: Decimal,
+    /// Local entry price (zero if no local position).
+    pub local_entry: Decimal,
+    /// Exchange entry price (zero if no exchange position).
+    pub remote_entry: Decimal,
+    /// Notional of the exposure difference (USD).
+    pub notional: Decimal,
+}
+
+/// Result of a reconciliation pass.
+#[derive(Debug, Clone, Default)]
+pub struct ReconcileReport {
+    /// Markets whose local state differs from the exchange.
+    pub drifts: Vec<PositionDrift>,
+}
+
+impl ReconcileReport {
+    /// Whether local state matches the exchange.
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.drifts.is_empty()
+    }
+
+    /// Total notional of the exposure differences (USD).
+    #[must_use]
+    pub fn drift_notional(&self) -> Decimal {
+        self.drifts.iter().map(|d| d.notional).sum()
+    }
+}
+
+fn signed_size(position: &Position) -> Decimal {
+    match position.side {
+        OrderSide::Buy => position.size.inner(),
+        OrderSide::Sell => -position.size.inner(),
+    }
+}
+
+/// Diff the local positions of `local_account` against the exchange
+/// positions of every account.
+///
+/// Drifts are ordered by account and market.
+#[must_use]
+pub fn reconcile(
+    local_account: &str,
+    local: &[Position],
+    remote: &[(String, Position)],
+    config: &ReconcileConfig,
+) -> ReconcileReport {
+    let local_account = local_account.to_ascii_lowercase();
+    let local: HashMap<(String, MarketKey), &Position> = local
+        .iter()
+        .map(|p| ((local_account.clone(), p.market), p))
+        .collect();
+    let remote: HashMap<(String, MarketKey), &Position> = remote
+        .iter()
+        .map(|(account, p)| ((account.to_ascii_lowercase(), p.market), p))
+        .collect();
+
+    let mut keys: Vec<(String, MarketKey)> = local.keys().chain(remote.keys()).cloned().collect();
+    keys.sort_by_key(|(account, m)| (account.clone(), m.dex.index(), m.asset.index()));
+    keys.dedup();
+
+    let mut drifts = Vec::new();
+    for key in keys {
+        let local_pos = local.get(&key).copied();
+        let remote_pos = remote.get(&key).copied();
+        let (account, market) = key;
+        let local_size = local_pos.map_or(Decimal::ZERO, signed_size);
+        let remote_size = remote_pos.map_or(Decimal::ZERO, signed_size);
+        let local_entry = local_pos.map_or(Decimal::ZERO, |p| p.entry_price.inner());
+        let remote_entry = remote_pos.map_or(Decimal::ZERO, |p| p.entry_price.inner());
+
+        let kind = match (local_pos, remote_pos) {
+            (None, None) => continue,
+            (None, Some(_)) => DriftKind::MissingLocal,
+            (Some(_), None) => DriftKind::MissingRemote,
+            (Some(l), Some(r)) if l.side != r.side => DriftKind::Side,
+            (Some(_), Some(_)) if local_size != remote_size => DriftKind::Size,
+            (Some(_), Some(_)) => {
+                if remote_entry.is_zero() {
+                    continue;
+                }
+                let diff_bps =
+                    (local_entry - remote_entry).abs() / remote_entry * Decimal::from(10000);
+                if diff_bps <= config.entry_tolerance_bps {
+                    continue;
+                }
+                DriftKind::EntryPrice
+            }
+        };
+
+        let price = if remote_entry.is_zero() {
+            local_entry
+        } else {
+            remote_entry
+        };
+        drifts.push(PositionDrift {
+            account,
+            market,
+            kind,
+            local_size,
+            remote_size,
+            local_entry,
+            remote_entry,
+            notional: (remote_size - local_size).abs() * price,
+        });
+    }
+
+    ReconcileReport { drifts }
+}
+
+/// Keeps the drifts that persisted over consecutive reconciliation passes.
+#[derive(Debug, Default)]
+pub struct DriftConfirmer {
+    /// (account, market) → consecutive passes drifting.
+    streaks: HashMap<(String, MarketKey), u32>,
+}
+
+impl DriftConfirmer {
+    /// Record a pass and return its drifts seen in at least
+    /// `config.confirm_passes` consecutive passes. A pass without drift for
+    /// a position resets its count.
+    pub fn confirm(
+        &mut self,
+        report: ReconcileReport,
+        config: &ReconcileConfig,
+    ) -> ReconcileReport {
+        let mut streaks = HashMap::new();
+        let drifts = report
+            .drifts
+            .into_iter()
+            .filter(|drift| {
+                let key = (drift.account.clone(), drift.market);
+                let passes = self.streaks.get(&key).copied().unwrap_or(0) + 1;
+                streaks.insert(key, passes);
+                passes >= config.confirm_passes
+            })
+            .collect();
+        self.streaks = streaks;
+        ReconcileReport { drifts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId, Price, Size};
+    use rust_decimal_macros::dec;
+
+    fn position(idx: u32, side: OrderSide, size: Decimal, entry: Decimal) -> Position {
+        Position::new(
+            MarketKey::new(DexId::XYZ, AssetId::new(idx)),
+            side,
+            Size::new(size),
+            Price::new(entry),
+            0,
+        )
+    }
+
+    const TAKER: &str = "0xabc";
+
+    fn remote(positions: Vec<Position>) -> Vec<(String, Position)> {
+        positions
+            .into_iter()
+            .map(|p| (TAKER.to_string(), p))
+            .collect()
+    }
+
+    #[test]
+    fn test_matching_state_is_clean() {
+        let local = vec![position(0, OrderSide::Buy, dec!(1), dec!(100))];
+        // Entry within tolerance (2 bps)
+        let remote = remote(vec![position(0, OrderSide::Buy, dec!(1), dec!(100.02))]);
+        let report = reconcile("0xABC", &local, &remote, &ReconcileConfig::default());
+        assert!(report.is_clean());
+        assert_eq!(report.drift_notional(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_drift_kinds_and_notional() {
+        let local = vec![
+            position(0, OrderSide::Buy, dec!(1), dec!(100)),
+            position(1, OrderSide::Buy, dec!(2), dec!(50)),
+            position(2, OrderSide::Sell, dec!(1), dec!(10)),
+            position(3, OrderSide::Buy, dec!(1), dec!(20)),
+        ];
+        let remote = remote(vec![
+            position(0, OrderSide::Buy, dec!(1), dec!(101)),
+            position(1, OrderSide::Buy, dec!(3), dec!(50)),
+            position(2, OrderSide::Buy, dec!(1), dec!(10)),
+            position(4, OrderSide::Sell, dec!(5), dec!(4)),
+        ]);
+        let config = ReconcileConfig {
+            hard_stop_drift_notional: dec!(100),
+            ..Default::default()
+        };
+        let report = reconcile(TAKER, &local, &remote, &config);
+
+        let kinds: Vec<DriftKind> = report.drifts.iter().map(|d| d.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                DriftKind::EntryPrice,
+                DriftKind::Size,
+                DriftKind::Side,
+                DriftKind::MissingRemote,
+                DriftKind::MissingLocal,
+            ]
+        );
+        let notionals: Vec<Decimal> = report.drifts.iter().map(|d| d.notional).collect();
+        assert_eq!(
+            notionals,
+            vec![dec!(0), dec!(50), dec!(20), dec!(20), dec!(20)]
+        );
+        assert_eq!(report.drift_notional(), dec!(110));
+        assert!(config.breaches_hard_stop(&report));
+        assert!(!ReconcileConfig::default().breaches_hard_stop(&report));
+    }
+
+    #[test]
+    fn test_other_account_is_not_merged() {
+        let local = vec![position(0, OrderSide::Buy, dec!(1), dec!(100))];
+        let mut remote = remote(vec![position(0, OrderSide::Buy, dec!(1), dec!(100))]);
+        remote.push((
+            "0xdef".to_string(),
+            position(0, OrderSide::Buy, dec!(2), dec!(100)),
+        ));
+        let report = reconcile(TAKER, &local, &remote, &ReconcileConfig::default());
+
+        assert_eq!(report.drifts.len(), 1);
+        assert_eq!(report.drifts[0].account, "0xdef");
+        assert_eq!(report.drifts[0].kind, DriftKind::MissingLocal);
+    }
+
+    #[test]
+    fn test_confirmer_reports_persistent_drift() {
+        let config = ReconcileConfig::default();
+        let local = vec![position(0, OrderSide::Buy, dec!(1), dec!(100))];
+        let drifted = remote(vec![position(0, OrderSide::Buy, dec!(2), dec!(100))]);
+        let matching = remote(vec![position(0, OrderSide::Buy, dec!(1), dec!(100))]);
+        let mut confirmer = DriftConfirmer::default();
+
+        // A fill in flight drifts for one pass only
+        let pass = reconcile(TAKER, &local, &drifted, &config);
+        assert!(confirmer.confirm(pass, &config).is_clean());
+        let pass = reconcile(TAKER, &local, &matching, &config);
+        assert!(confirmer.confirm(pass, &config).is_clean());
+
+        // A drift over two consecutive passes is reported
+        let pass = reconcile(TAKER, &local, &drifted, &config);
+        assert!(confirmer.confirm(pass, &config).is_clean());
+        let pass = reconcile(TAKER, &local, &drifted, &config);
+        assert_eq!(confirmer.confirm(pass, &config).drifts.len(), 1);
+    }
+}
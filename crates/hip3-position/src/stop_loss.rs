@@ -0,0 +1,243 @@
+//! Stop-loss on unrealized loss.
+//!
+//! A plain price stop: [`StopLoss`] flattens a position once its unrealized
+//! PnL at the exit-side BBO (bid for longs, ask for shorts) breaches the
+//! stop of its [`ExitProfile`]. In a turbulent volatility regime the stop
+//! is widened by `turbulent_widen_factor`, so normal noise in a fast market
+//! does not stop the position out.
+//!
+//! The stop is an [`ExitStrategy`] run by `ExitStrategyWatcher`, so it
+//! shares the flatten channel, the `SharedFlatteningGuard` and the
+//! reduce-only order path of the other exits.
+
+use hip3_core::types::MarketSnapshot;
+use hip3_core::ExitProfile;
+use hip3_feed::{RegimeHandle, VolRegime};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::exit_strategy::ExitStrategy;
+use crate::flatten::{FlattenReason, FlattenRequest};
+use crate::tracker::{exit_side_pnl_bps, Position};
+
+/// Stop-loss configuration, per exit profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StopLossConfig {
+    /// Stop (bps of unrealized loss) for `ExitProfile::Standard`. 0 = disabled.
+    #[serde(default = "default_standard_stop_bps")]
+    pub standard_stop_bps: Decimal,
+
+    /// Stop (bps of unrealized loss) for `ExitProfile::Scalper`. 0 = disabled.
+    #[serde(default = "default_scalper_stop_bps")]
+    pub scalper_stop_bps: Decimal,
+
+    /// Stop (bps of unrealized loss) for `ExitProfile::Runner`. 0 = disabled.
+    #[serde(default = "default_runner_stop_bps")]
+    pub runner_stop_bps: Decimal,
+
+    /// Stop multiplier in a turbulent regime (requires the regime
+    /// classifier). Default: 1.0 (no widening).
+    #[serde(default = "default_turbulent_widen_factor")]
+    pub turbulent_widen_factor: Decimal,
+}
+
+fn default_standard_stop_bps() -> Decimal {
+    Decimal::from(40)
+}
+
+fn default_scalper_stop_bps() -> Decimal {
+    Decimal::from(25)
+}
+
+fn default_runner_stop_bps() -> Decimal {
+    Decimal::from(60)
+}
+
+fn default_turbulent_widen_factor() -> Decimal {
+    Decimal::ONE
+}
+
+impl Default for StopLossConfig {
+    fn default() -> Self {
+        Self {
+            standard_stop_bps: default_standard_stop_bps(),
+            scalper_stop_bps: default_scalper_stop_bps(),
+            runner_stop_bps: default_runner_stop_bps(),
+            turbulent_widen_factor: default_turbulent_widen_factor(),
+        }
+    }
+}
+
+impl StopLossConfig {
+    /// Stop for an exit profile (None if disabled for the profile).
+    #[must_use]
+    pub fn stop_bps(&self, profile: ExitProfile) -> Option<Decimal> {
+        let bps = match profile {
+            ExitProfile::Standard => self.standard_stop_bps,
+            ExitProfile::Scalper => self.scalper_stop_bps,
+            ExitProfile::Runner => self.runner_stop_bps,
+        };
+        (bps > Decimal::ZERO).then_some(bps)
+    }
+
+    /// Whether the stop is widened in turbulent markets.
+    #[must_use]
+    pub fn widens(&self) -> bool {
+        self.turbulent_widen_factor > Decimal::ONE
+    }
+}
+
+/// Flattens a position once its unrealized loss breaches the stop.
+pub struct StopLoss {
+    stop_bps: Decimal,
+    turbulent_widen_factor: Decimal,
+    regime: Option<RegimeHandle>,
+}
+
+impl StopLoss {
+    /// Exit reason of the strategy.
+    pub const NAME: &'static str = "StopLoss";
+
+    /// Create a stop at `stop_bps` below entry.
+    #[must_use]
+    pub fn new(stop_bps: Decimal) -> Self {
+        Self {
+            stop_bps,
+            turbulent_widen_factor: Decimal::ONE,
+            regime: None,
+        }
+    }
+
+    /// Widen the stop by `factor` while the market's regime is turbulent.
+    #[must_use]
+    pub fn with_turbulent_widening(mut self, factor: Decimal, regime: RegimeHandle) -> Self {
+        self.turbulent_widen_factor = factor;
+        self.regime = Some(regime);
+        self
+    }
+
+    /// Stop (bps) for a position, widened in a turbulent regime.
+    #[must_use]
+    pub fn effective_stop_bps(&self, position: &Position) -> Decimal {
+        match self.regime {
+            Some(ref regime) if regime.regime(&position.market) == VolRegime::Turbulent => {
+                self.stop_bps * self.turbulent_widen_factor
+            }
+            _ => self.stop_bps,
+        }
+    }
+}
+
+impl ExitStrategy for StopLoss {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn on_market_update(
+        &mut self,
+        position: &Position,
+        snapshot: &MarketSnapshot,
+        now_ms: u64,
+    ) -> Option<FlattenRequest> {
+        let pnl_bps = exit_side_pnl_bps(position, position.exit_side_price(&snapshot.bbo))?;
+
+        let stop_bps = self.effective_stop_bps(position);
+        if pnl_bps > -stop_bps {
+            return None;
+        }
+        debug!(market = %position.market, %pnl_bps, %stop_bps, "StopLoss hit");
+        Some(FlattenRequest::full(
+            position,
+            FlattenReason::Strategy {
+                name: Self::NAME.to_string(),
+            },
+            now_ms,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetCtx, AssetId, Bbo, DexId, MarketKey, OracleData, OrderSide, Price, Size};
+    use hip3_feed::{RegimeClassifier, RegimeConfig};
+    use rust_decimal_macros::dec;
+
+    fn market() -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(0))
+    }
+
+    fn short(entry: Decimal) -> Position {
+        Position::new(
+            market(),
+            OrderSide::Sell,
+            Size::new(dec!(1)),
+            Price::new(entry),
+            1_000,
+        )
+    }
+
+    fn snapshot(bid: Decimal, ask: Decimal) -> MarketSnapshot {
+        let bbo = Bbo::new(
+            Price::new(bid),
+            Size::new(dec!(1)),
+            Price::new(ask),
+            Size::new(dec!(1)),
+        );
+        let ctx = AssetCtx::new(
+            OracleData::new(Price::new(bid), Price::new(bid)),
+            dec!(0.0001),
+        );
+        MarketSnapshot::new(bbo, ctx)
+    }
+
+    #[test]
+    fn test_stop_loss_on_exit_side_price() {
+        let config = StopLossConfig::default();
+        assert_eq!(config.stop_bps(ExitProfile::Scalper), Some(dec!(25)));
+        assert!(!config.widens());
+
+        let mut stop = StopLoss::new(dec!(25));
+        let position = short(dec!(100));
+        // Ask 100.20: 20 bps loss
+        assert!(stop
+            .on_market_update(&position, &snapshot(dec!(100.10), dec!(100.20)), 2_000)
+            .is_none());
+        // Ask 100.30: 30 bps loss
+        let request = stop
+            .on_market_update(&position, &snapshot(dec!(100.20), dec!(100.30)), 2_000)
+            .unwrap();
+        assert_eq!(request.side, OrderSide::Buy);
+        assert_eq!(
+            request.reason,
+            FlattenReason::Strategy {
+                name: "StopLoss".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_stop_widened_in_turbulent_regime() {
+        let regime = RegimeClassifier::new_shared(RegimeConfig {
+            min_samples: 3,
+            ..Default::default()
+        });
+        let mut stop = StopLoss::new(dec!(25)).with_turbulent_widening(dec!(2), regime.clone());
+        let position = short(dec!(100));
+        assert_eq!(stop.effective_stop_bps(&position), dec!(25));
+
+        // Swing the mid by 1% per second: turbulent
+        for (i, mid) in [dec!(100), dec!(101), dec!(100), dec!(101)]
+            .iter()
+            .enumerate()
+        {
+            regime.record(market(), Price::new(*mid), i as u64 * 1_000);
+        }
+        assert_eq!(regime.regime(&market()), VolRegime::Turbulent);
+        assert_eq!(stop.effective_stop_bps(&position), dec!(50));
+        assert!(stop
+            .on_market_update(&position, &snapshot(dec!(100.20), dec!(100.30)), 5_000)
+            .is_none());
+    }
+}
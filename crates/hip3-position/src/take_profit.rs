@@ -0,0 +1,236 @@
+//! Partial take-profit ladder.
+//!
+//! Without a ladder every exit closes the whole position. A ladder is a list
+//! of rungs per [`ExitProfile`]: when the position's unrealized PnL reaches a
+//! rung's `trigger_bps`, `close_fraction` of the remaining position is closed
+//! with a reduce-only order and the stop on the remainder is tightened to the
+//! rung's `stop_bps`. The remainder then rides until the next rung, the
+//! tightened stop, or any other exit.
+//!
+//! Rungs fire in order and at most once each. PnL is measured at the price
+//! the position would exit at (bid for longs, ask for shorts).
+//!
+//! Evaluation lives in `OracleExitWatcher`, which already tracks the exit
+//! profile of each position.
+
+use hip3_core::ExitProfile;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// One rung of a take-profit ladder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TakeProfitRung {
+    /// Unrealized PnL (bps) at which the rung fires.
+    pub trigger_bps: u32,
+    /// Fraction of the remaining position to close (0, 1].
+    pub close_fraction: Decimal,
+    /// Stop on the remainder once the rung fired, as PnL in bps
+    /// (0 = break-even, negative = below entry).
+    #[serde(default)]
+    pub stop_bps: i32,
+}
+
+/// Take-profit ladder configuration, per exit profile.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TakeProfitLadderConfig {
+    /// Enable partial take-profits. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Rungs for `ExitProfile::Standard`, by ascending trigger.
+    #[serde(default = "default_standard_rungs")]
+    pub standard: Vec<TakeProfitRung>,
+
+    /// Rungs for `ExitProfile::Scalper` (none: scalps exit in one go).
+    #[serde(default)]
+    pub scalper: Vec<TakeProfitRung>,
+
+    /// Rungs for `ExitProfile::Runner`.
+    #[serde(default = "default_runner_rungs")]
+    pub runner: Vec<TakeProfitRung>,
+}
+
+fn default_standard_rungs() -> Vec<TakeProfitRung> {
+    // Half off at +10 bps, remainder stopped at break-even
+    vec![TakeProfitRung {
+        trigger_bps: 10,
+        close_fraction: Decimal::new(5, 1),
+        stop_bps: 0,
+    }]
+}
+
+fn default_runner_rungs() -> Vec<TakeProfitRung> {
+    // A third off at +10 bps, half the rest at +20 bps with the stop at +10
+    vec![
+        TakeProfitRung {
+            trigger_bps: 10,
+            close_fraction: Decimal::new(33, 2),
+            stop_bps: 0,
+        },
+        TakeProfitRung {
+            trigger_bps: 20,
+            close_fraction: Decimal::new(5, 1),
+            stop_bps: 10,
+        },
+    ]
+}
+
+impl Default for TakeProfitLadderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            standard: default_standard_rungs(),
+            scalper: Vec::new(),
+            runner: default_runner_rungs(),
+        }
+    }
+}
+
+impl TakeProfitLadderConfig {
+    /// Rungs for an exit profile.
+    #[must_use]
+    pub fn rungs(&self, profile: ExitProfile) -> &[TakeProfitRung] {
+        match profile {
+            ExitProfile::Standard => &self.standard,
+            ExitProfile::Scalper => &self.scalper,
+            ExitProfile::Runner => &self.runner,
+        }
+    }
+
+    /// Decide the next ladder action for a position at `pnl_bps`.
+    ///
+    /// The tightened stop takes precedence over the next rung.
+    #[must_use]
+    pub fn evaluate(
+        &self,
+        profile: ExitProfile,
+        progress: &LadderProgress,
+        pnl_bps: Decimal,
+    ) -> Option<LadderDecision> {
+        if let Some(stop_bps) = progress.stop_bps {
+            if pnl_bps <= Decimal::from(stop_bps) {
+                return Some(LadderDecision::Stop { stop_bps, pnl_bps });
+            }
+        }
+        let rung = self.rungs(profile).get(progress.next_rung)?;
+        (pnl_bps >= Decimal::from(rung.trigger_bps)).then_some(LadderDecision::TakeProfit {
+            rung: progress.next_rung,
+            close_fraction: rung.close_fraction,
+            pnl_bps,
+        })
+    }
+}
+
+/// Progress of one position through its ladder.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LadderProgress {
+    /// Index of the next rung to fire.
+    pub next_rung: usize,
+    /// Stop on the remainder (PnL bps), once a rung fired.
+    pub stop_bps: Option<i32>,
+}
+
+impl LadderProgress {
+    /// Record that `rung` fired: move to the next rung and tighten the stop.
+    ///
+    /// The stop only ever moves up.
+    pub fn advance(&mut self, rung: usize, config_rung: &TakeProfitRung) {
+        self.next_rung = rung + 1;
+        self.stop_bps = Some(
+            self.stop_bps
+                .map_or(config_rung.stop_bps, |s| s.max(config_rung.stop_bps)),
+        );
+    }
+}
+
+/// Ladder action for a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LadderDecision {
+    /// Close part of the position.
+    TakeProfit {
+        /// Index of the rung that fired.
+        rung: usize,
+        /// Fraction of the remaining position to close.
+        close_fraction: Decimal,
+        /// Unrealized PnL (bps) when the rung fired.
+        pnl_bps: Decimal,
+    },
+    /// Close the remainder: PnL fell back to the tightened stop.
+    Stop {
+        /// Stop level (PnL bps).
+        stop_bps: i32,
+        /// Unrealized PnL (bps) when the stop fired.
+        pnl_bps: Decimal,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_ladder_fires_rungs_in_order_then_stops() {
+        let config = TakeProfitLadderConfig::default();
+        let mut progress = LadderProgress::default();
+        let profile = ExitProfile::Runner;
+
+        // Below the first rung, no stop yet
+        assert_eq!(config.evaluate(profile, &progress, dec!(5)), None);
+        assert_eq!(config.evaluate(profile, &progress, dec!(-20)), None);
+
+        let Some(LadderDecision::TakeProfit {
+            rung,
+            close_fraction,
+            ..
+        }) = config.evaluate(profile, &progress, dec!(12))
+        else {
+            panic!("first rung should fire");
+        };
+        assert_eq!((rung, close_fraction), (0, dec!(0.33)));
+        progress.advance(rung, &config.runner[rung]);
+        assert_eq!(progress.stop_bps, Some(0));
+
+        // Still above the break-even stop, second rung not reached
+        assert_eq!(config.evaluate(profile, &progress, dec!(12)), None);
+        assert!(matches!(
+            config.evaluate(profile, &progress, dec!(21)),
+            Some(LadderDecision::TakeProfit { rung: 1, .. })
+        ));
+        progress.advance(1, &config.runner[1]);
+
+        // Stop tightened to +10, no rungs left
+        assert_eq!(config.evaluate(profile, &progress, dec!(50)), None);
+        assert_eq!(
+            config.evaluate(profile, &progress, dec!(9)),
+            Some(LadderDecision::Stop {
+                stop_bps: 10,
+                pnl_bps: dec!(9)
+            })
+        );
+    }
+
+    #[test]
+    fn test_profile_rungs() {
+        let config: TakeProfitLadderConfig = toml::from_str(
+            r#"
+            enabled = true
+            scalper = [{ trigger_bps = 4, close_fraction = "0.5" }]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.rungs(ExitProfile::Standard),
+            default_standard_rungs()
+        );
+        assert_eq!(config.rungs(ExitProfile::Scalper)[0].stop_bps, 0);
+        assert_eq!(
+            config.evaluate(ExitProfile::Scalper, &LadderProgress::default(), dec!(4)),
+            Some(LadderDecision::TakeProfit {
+                rung: 0,
+                close_fraction: dec!(0.5),
+                pnl_bps: dec!(4)
+            })
+        );
+    }
+}
@@ -7,6 +7,11 @@
 //! - `TimeStopMonitor`: Background task for monitoring and triggering flattens
 //!
 //! Phase B parameter: TIME_STOP_MS = 30 seconds.
+//!
+//! With [`VolScaling`] the threshold follows each market's realized
+//! volatility (from the feed's `RegimeClassifier`): turbulent markets are
+//! exited faster, quiet markets are given more time, within a floor and a
+//! ceiling.
 
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -16,7 +21,9 @@ use tokio::sync::mpsc;
 use tracing::{debug, info, trace, warn};
 
 use hip3_core::{ClientOrderId, MarketKey, OrderSide, PendingOrder, Price, TrackedOrder};
+use hip3_feed::{RegimeHandle, RegimeStats};
 
+use crate::flatten::FlattenRequest;
 use crate::tracker::{Position, PositionTrackerHandle, SharedFlatteningGuard};
 
 /// Default time stop threshold: 30 seconds.
@@ -41,6 +48,9 @@ pub struct TimeStopConfig {
     /// Timeout for reduce-only orders before retry (Unix milliseconds).
     /// Default: 60,000 ms (60 seconds)
     pub reduce_only_timeout_ms: u64,
+
+    /// Volatility scaling of `threshold_ms` (None = static threshold).
+    pub vol_scaling: Option<VolScaling>,
 }
 
 impl Default for TimeStopConfig {
@@ -48,6 +58,7 @@ impl Default for TimeStopConfig {
         Self {
             threshold_ms: TIME_STOP_MS,
             reduce_only_timeout_ms: REDUCE_ONLY_TIMEOUT_MS,
+            vol_scaling: None,
         }
     }
 }
@@ -59,7 +70,48 @@ impl TimeStopConfig {
         Self {
             threshold_ms,
             reduce_only_timeout_ms,
+            vol_scaling: None,
+        }
+    }
+
+    /// Scale the threshold with realized volatility.
+    #[must_use]
+    pub fn with_vol_scaling(mut self, vol_scaling: VolScaling) -> Self {
+        self.vol_scaling = Some(vol_scaling);
+        self
+    }
+}
+
+/// Volatility scaling of the time stop threshold.
+///
+/// The threshold is `threshold_ms * reference_vol_bps / realized_vol_bps`,
+/// clamped to `[min_threshold_ms, max_threshold_ms]`. Markets without
+/// enough samples keep the static threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VolScaling {
+    /// Realized volatility (bps) at which the threshold is unchanged.
+    pub reference_vol_bps: Decimal,
+    /// Floor of the scaled threshold (ms).
+    pub min_threshold_ms: u64,
+    /// Ceiling of the scaled threshold (ms).
+    pub max_threshold_ms: u64,
+    /// Samples required before scaling a market.
+    pub min_samples: usize,
+}
+
+impl VolScaling {
+    /// Scale `threshold_ms` by the market's volatility statistics.
+    #[must_use]
+    pub fn scale(&self, threshold_ms: u64, stats: Option<&RegimeStats>) -> u64 {
+        let Some(stats) = stats.filter(|s| s.samples >= self.min_samples) else {
+            return threshold_ms;
+        };
+        if stats.realized_vol_bps <= Decimal::ZERO {
+            return self.max_threshold_ms;
         }
+        let scaled = Decimal::from(threshold_ms) * self.reference_vol_bps / stats.realized_vol_bps;
+        let scaled = u64::try_from(scaled.round()).unwrap_or(self.max_threshold_ms);
+        scaled.clamp(self.min_threshold_ms, self.max_threshold_ms)
     }
 }
 
@@ -87,6 +139,9 @@ pub struct TimeStop {
 
     /// Timeout for reduce-only orders (Unix milliseconds).
     reduce_only_timeout_ms: u64,
+
+    /// Volatility scaling of `threshold_ms`.
+    vol_scaling: Option<VolScaling>,
 }
 
 impl TimeStop {
@@ -96,13 +151,17 @@ impl TimeStop {
         Self {
             threshold_ms,
             reduce_only_timeout_ms,
+            vol_scaling: None,
         }
     }
 
     /// Create a TimeStop from a configuration.
     #[must_use]
     pub fn from_config(config: &TimeStopConfig) -> Self {
-        Self::new(config.threshold_ms, config.reduce_only_timeout_ms)
+        Self {
+            vol_scaling: config.vol_scaling,
+            ..Self::new(config.threshold_ms, config.reduce_only_timeout_ms)
+        }
     }
 
     /// Get the threshold in milliseconds.
@@ -117,6 +176,17 @@ impl TimeStop {
         self.reduce_only_timeout_ms
     }
 
+    /// Threshold for a market with the given volatility statistics.
+    ///
+    /// Equals [`Self::threshold_ms`] without volatility scaling.
+    #[must_use]
+    pub fn threshold_ms_for(&self, stats: Option<&RegimeStats>) -> u64 {
+        match self.vol_scaling {
+            Some(ref scaling) => scaling.scale(self.threshold_ms, stats),
+            None => self.threshold_ms,
+        }
+    }
+
     /// Check which positions have exceeded the holding time threshold.
     ///
     /// Returns a list of market keys for positions that need to be flattened.
@@ -129,11 +199,23 @@ impl TimeStop {
     /// Vector of `MarketKey` for positions exceeding the threshold
     #[must_use]
     pub fn check(&self, positions: &[Position], now_ms: u64) -> Vec<MarketKey> {
+        self.check_with_stats(positions, now_ms, |_| None)
+    }
+
+    /// Like [`Self::check`], with a per-market threshold scaled by the
+    /// volatility statistics `stats` returns for the market.
+    #[must_use]
+    pub fn check_with_stats(
+        &self,
+        positions: &[Position],
+        now_ms: u64,
+        stats: impl Fn(&MarketKey) -> Option<RegimeStats>,
+    ) -> Vec<MarketKey> {
         positions
             .iter()
             .filter(|pos| {
                 let holding_time = now_ms.saturating_sub(pos.entry_timestamp_ms);
-                holding_time > self.threshold_ms
+                holding_time > self.threshold_ms_for(stats(&pos.market).as_ref())
             })
             .map(|pos| pos.market)
             .collect()
@@ -261,6 +343,35 @@ impl FlattenOrderBuilder {
             now_ms,
         )
     }
+
+    /// Create the reduce-only order for a [`FlattenRequest`], which may
+    /// close only part of a position.
+    ///
+    /// Priced like [`Self::create_flatten_order`]: sells below and buys
+    /// above `current_price` by `slippage_bps`.
+    #[must_use]
+    pub fn create_request_order(
+        request: &FlattenRequest,
+        current_price: Price,
+        slippage_bps: u64,
+        now_ms: u64,
+    ) -> PendingOrder {
+        let slippage_factor = Decimal::from(slippage_bps) / Decimal::from(10_000);
+        let factor = match request.side {
+            OrderSide::Sell => Decimal::ONE - slippage_factor,
+            OrderSide::Buy => Decimal::ONE + slippage_factor,
+        };
+
+        PendingOrder::new(
+            ClientOrderId::new(),
+            request.market,
+            request.side,
+            Price::new(current_price.inner() * factor),
+            request.size,
+            true, // reduce_only = true
+            now_ms,
+        )
+    }
 }
 
 // ============================================================================
@@ -314,6 +425,9 @@ pub struct TimeStopMonitor<P: PriceProvider> {
 
     /// Shared guard across all exit monitors to prevent duplicate flatten requests.
     shared_flattening: Option<SharedFlatteningGuard>,
+
+    /// Volatility source for the scaled threshold.
+    regime_classifier: Option<RegimeHandle>,
 }
 
 impl<P: PriceProvider + 'static> TimeStopMonitor<P> {
@@ -346,9 +460,18 @@ impl<P: PriceProvider + 'static> TimeStopMonitor<P> {
             check_interval_ms,
             local_flattening: HashSet::new(),
             shared_flattening,
+            regime_classifier: None,
         }
     }
 
+    /// Scale the threshold with the classifier's realized volatility
+    /// (requires `TimeStopConfig::vol_scaling`).
+    #[must_use]
+    pub fn with_regime_classifier(mut self, classifier: RegimeHandle) -> Self {
+        self.regime_classifier = Some(classifier);
+        self
+    }
+
     /// Create with default slippage (50 bps) and check interval (1 second).
     #[must_use]
     pub fn with_defaults(
@@ -426,7 +549,12 @@ impl<P: PriceProvider + 'static> TimeStopMonitor<P> {
             }
 
             // Check for positions exceeding threshold
-            let expired_markets = self.time_stop.check(&positions, now_ms);
+            let expired_markets = match self.regime_classifier {
+                Some(ref classifier) => self
+                    .time_stop
+                    .check_with_stats(&positions, now_ms, |m| classifier.stats(m)),
+                None => self.time_stop.check(&positions, now_ms),
+            };
 
             for market in expired_markets {
                 // Re-check position exists before creating flatten order
@@ -515,7 +643,7 @@ impl<P: PriceProvider + 'static> TimeStopMonitor<P> {
 
                 // Shared guard: prevent cross-monitor duplicates
                 if let Some(ref guard) = self.shared_flattening {
-                    if !guard.try_claim(&market) {
+                    if !guard.try_claim(&market, exit_reason_str) {
                         trace!(
                             market = %market,
                             "TimeStopMonitor: flatten already claimed by another monitor"
@@ -878,6 +1006,31 @@ mod tests {
         assert_eq!(order.price, expected_price);
     }
 
+    #[test]
+    fn test_flatten_partial_request() {
+        let position = sample_position(sample_market(), OrderSide::Buy, 50_000);
+        let request = crate::flatten::partial_flatten_request(
+            &position,
+            dec!(0.5),
+            crate::flatten::FlattenReason::TakeProfit { rung: 0 },
+            100_000,
+        )
+        .unwrap();
+
+        let order = FlattenOrderBuilder::create_request_order(
+            &request,
+            Price::new(dec!(50000)),
+            50,
+            100_000,
+        );
+
+        // Same pricing as a full flatten, half the size
+        assert_eq!(order.side, OrderSide::Sell);
+        assert!(order.reduce_only);
+        assert_eq!(order.size, Size::new(position.size.inner() / dec!(2)));
+        assert_eq!(order.price, Price::new(dec!(49750)));
+    }
+
     #[test]
     fn test_flatten_zero_slippage() {
         let position = sample_position(sample_market(), OrderSide::Buy, 50_000);
@@ -934,6 +1087,67 @@ mod tests {
         assert_eq!(time_stop.reduce_only_timeout_ms(), 45_000);
     }
 
+    fn vol_stats(realized_vol_bps: Decimal, samples: usize) -> RegimeStats {
+        RegimeStats {
+            realized_vol_bps,
+            wick_bps: Decimal::ZERO,
+            samples,
+        }
+    }
+
+    #[test]
+    fn test_vol_scaled_threshold() {
+        let config = TimeStopConfig::new(30_000, 60_000).with_vol_scaling(VolScaling {
+            reference_vol_bps: dec!(15),
+            min_threshold_ms: 10_000,
+            max_threshold_ms: 90_000,
+            min_samples: 20,
+        });
+        let time_stop = TimeStop::from_config(&config);
+
+        // Unknown or thin data keeps the static threshold
+        assert_eq!(time_stop.threshold_ms_for(None), 30_000);
+        assert_eq!(
+            time_stop.threshold_ms_for(Some(&vol_stats(dec!(30), 5))),
+            30_000
+        );
+
+        // Twice the reference vol halves the threshold, half doubles it
+        assert_eq!(
+            time_stop.threshold_ms_for(Some(&vol_stats(dec!(30), 20))),
+            15_000
+        );
+        assert_eq!(
+            time_stop.threshold_ms_for(Some(&vol_stats(dec!(7.5), 20))),
+            60_000
+        );
+
+        // Floor and ceiling
+        assert_eq!(
+            time_stop.threshold_ms_for(Some(&vol_stats(dec!(150), 20))),
+            10_000
+        );
+        assert_eq!(
+            time_stop.threshold_ms_for(Some(&vol_stats(Decimal::ZERO, 20))),
+            90_000
+        );
+
+        // Per-market check: only the turbulent market is expired at 20s
+        let positions = vec![
+            sample_position(sample_market(), OrderSide::Buy, 0),
+            sample_position(sample_market_2(), OrderSide::Buy, 0),
+        ];
+        let expired = time_stop.check_with_stats(&positions, 20_000, |m| {
+            let vol = if *m == sample_market() {
+                dec!(30)
+            } else {
+                dec!(7.5)
+            };
+            Some(vol_stats(vol, 20))
+        });
+        assert_eq!(expired, vec![sample_market()]);
+    }
+
     // ========================================================================
     // Legacy API Tests (TimeStopManager)
     // ========================================================================
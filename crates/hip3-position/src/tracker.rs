@@ -44,7 +44,7 @@
 //! If order creation fails AFTER cache update (e.g., QueueFull), use
 //! `rollback_order_caches()` to restore consistency.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
@@ -54,12 +54,27 @@ use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tracing::{debug, trace};
 
-use hip3_core::{ClientOrderId, MarketKey, OrderSide, OrderState, Price, Size, TrackedOrder};
+use hip3_core::{Bbo, ClientOrderId, MarketKey, OrderSide, OrderState, Price, Size, TrackedOrder};
 
 // ============================================================================
 // Position
 // ============================================================================
 
+/// One entry (the initial one or an add-on) of a scaled position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tranche {
+    /// Size of the entry still open.
+    pub size: Size,
+    /// Entry price of the tranche.
+    pub price: Price,
+    /// Timestamp of the entry fill (Unix ms).
+    pub timestamp_ms: u64,
+    /// Entry edge in bps of the signal behind the entry.
+    pub entry_edge_bps: Option<Decimal>,
+    /// Order whose fills built the tranche (None if its fill carried no cloid).
+    pub cloid: Option<ClientOrderId>,
+}
+
 /// An open position in a market.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Position {
@@ -78,6 +93,12 @@ pub struct Position {
     /// Entry edge in bps at the time of position opening (Phase C).
     /// Used for dynamic exit threshold scaling.
     pub entry_edge_bps: Option<Decimal>,
+    /// Funding paid since open (USD, positive = paid, negative = received).
+    /// Scaled down with the position on partial closes.
+    pub funding_usd: Decimal,
+    /// Entries that built the position, oldest first. Sizes sum to `size`;
+    /// partial closes scale every tranche down proportionally.
+    pub tranches: Vec<Tranche>,
 }
 
 impl Position {
@@ -98,7 +119,39 @@ impl Position {
             entry_timestamp_ms: timestamp_ms,
             last_update_ms: timestamp_ms,
             entry_edge_bps: None,
+            funding_usd: Decimal::ZERO,
+            tranches: vec![Tranche {
+                size,
+                price,
+                timestamp_ms,
+                entry_edge_bps: None,
+                cloid: None,
+            }],
+        }
+    }
+
+    /// Create a position from an initial fill with the entry edge of its signal.
+    #[must_use]
+    pub fn with_entry_edge(mut self, entry_edge_bps: Option<Decimal>) -> Self {
+        self.entry_edge_bps = entry_edge_bps;
+        for tranche in &mut self.tranches {
+            tranche.entry_edge_bps = entry_edge_bps;
         }
+        self
+    }
+
+    /// Attribute the initial tranche to the order that filled it.
+    fn with_entry_order(mut self, cloid: Option<&ClientOrderId>) -> Self {
+        for tranche in &mut self.tranches {
+            tranche.cloid = cloid.cloned();
+        }
+        self
+    }
+
+    /// Number of add-on entries on top of the initial one.
+    #[must_use]
+    pub fn add_on_count(&self) -> usize {
+        self.tranches.len().saturating_sub(1)
     }
 
     /// Calculate the notional value of the position.
@@ -124,6 +177,32 @@ impl Position {
     pub fn is_short(&self) -> bool {
         self.side == OrderSide::Sell && !self.is_empty()
     }
+
+    /// BBO price the position exits at (bid for longs, ask for shorts).
+    #[must_use]
+    pub fn exit_side_price(&self, bbo: &Bbo) -> Price {
+        match self.side {
+            OrderSide::Buy => bbo.bid_price,
+            OrderSide::Sell => bbo.ask_price,
+        }
+    }
+}
+
+/// Unrealized PnL (bps of entry) of `position` at `exit_px`, a price on its
+/// exit side.
+///
+/// None while the entry or exit price is zero.
+#[must_use]
+pub fn exit_side_pnl_bps(position: &Position, exit_px: Price) -> Option<Decimal> {
+    let entry = position.entry_price.inner();
+    let exit_px = exit_px.inner();
+    if entry.is_zero() || exit_px.is_zero() {
+        return None;
+    }
+    Some(match position.side {
+        OrderSide::Buy => (exit_px - entry) / entry * Decimal::from(10000),
+        OrderSide::Sell => (entry - exit_px) / entry * Decimal::from(10000),
+    })
 }
 
 // ============================================================================
@@ -151,7 +230,7 @@ pub enum PositionTrackerMsg {
         oid: Option<u64>,
     },
 
-    /// Process a fill from userFills feed.
+    /// Process a fill from userFills feed (size of this fill only).
     Fill {
         /// Market of the fill.
         market: MarketKey,
@@ -169,6 +248,32 @@ pub enum PositionTrackerMsg {
         entry_edge_bps: Option<Decimal>,
     },
 
+    /// Process the total filled size of an order (post response, reconcile).
+    OrderFilled {
+        /// Market of the order.
+        market: MarketKey,
+        /// Order side.
+        side: OrderSide,
+        /// Average fill price.
+        price: Price,
+        /// Cumulative filled size of the order.
+        total_size: Size,
+        /// Fill timestamp (Unix ms).
+        timestamp_ms: u64,
+        /// Client order ID of the order.
+        cloid: ClientOrderId,
+    },
+
+    /// Funding payment on an open position (userFundings feed).
+    Funding {
+        /// Market of the position.
+        market: MarketKey,
+        /// Funding paid (USD, positive = paid, negative = received).
+        paid_usd: Decimal,
+        /// Funding time (Unix ms).
+        timestamp_ms: u64,
+    },
+
     /// Begin snapshot processing (buffer subsequent messages).
     SnapshotStart,
 
@@ -214,9 +319,46 @@ pub struct PositionTrackerTask {
     /// Full position data for notional calculations.
     positions_data: Arc<DashMap<MarketKey, Position>>,
 
-    /// Recent fill cloids for deduplication.
+    /// Fill accounting per recent cloid, reconciling per-fill (userFills)
+    /// and cumulative (post response) reports of the same order.
     /// Clears when size exceeds threshold to prevent memory leak.
-    recent_fill_cloids: HashSet<ClientOrderId>,
+    fill_progress: HashMap<ClientOrderId, FillProgress>,
+}
+
+/// Fill accounting for one order.
+///
+/// An order can be reported as several userFills (one per price level, or
+/// per partial fill of a reduce-only exit) and as one cumulative total from
+/// the post response, in either order. Only the size not yet applied to the
+/// position is applied, so nothing is dropped or counted twice.
+#[derive(Debug, Clone, Copy, Default)]
+struct FillProgress {
+    /// Size applied to the position so far.
+    applied: Decimal,
+    /// Sum of the per-fill reports received so far.
+    reported: Decimal,
+}
+
+impl FillProgress {
+    /// Account for a per-fill report; returns the size to apply.
+    fn on_fill(&mut self, size: Decimal) -> Decimal {
+        self.reported += size;
+        self.apply_up_to(self.reported)
+    }
+
+    /// Account for a cumulative report; returns the size to apply.
+    fn on_total(&mut self, total: Decimal) -> Decimal {
+        self.apply_up_to(total)
+    }
+
+    fn apply_up_to(&mut self, total: Decimal) -> Decimal {
+        let delta = total - self.applied;
+        if delta <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        self.applied = total;
+        delta
+    }
 }
 
 impl PositionTrackerTask {
@@ -274,6 +416,19 @@ impl PositionTrackerTask {
                 cloid,
                 entry_edge_bps,
             ),
+            PositionTrackerMsg::OrderFilled {
+                market,
+                side,
+                price,
+                total_size,
+                timestamp_ms,
+                cloid,
+            } => self.on_order_filled(market, side, price, total_size, timestamp_ms, cloid),
+            PositionTrackerMsg::Funding {
+                market,
+                paid_usd,
+                timestamp_ms,
+            } => self.on_funding(market, paid_usd, timestamp_ms),
             PositionTrackerMsg::SnapshotStart => {
                 debug!("Snapshot processing started");
                 self.in_snapshot = true;
@@ -362,24 +517,18 @@ impl PositionTrackerTask {
         cloid: Option<ClientOrderId>,
         entry_edge_bps: Option<Decimal>,
     ) {
-        // Cloid-based deduplication
-        if let Some(ref id) = cloid {
-            if self.recent_fill_cloids.contains(id) {
-                debug!("Skipping duplicate fill: cloid={}", id);
-                return;
-            }
-            self.recent_fill_cloids.insert(id.clone());
-
-            // Prevent memory leak: clear when size exceeds threshold
-            // 1000 cloids ~= 10 seconds at 100 fills/sec
-            if self.recent_fill_cloids.len() > 1000 {
-                debug!(
-                    "Clearing recent_fill_cloids (size exceeded 1000): {}",
-                    self.recent_fill_cloids.len()
-                );
-                self.recent_fill_cloids.clear();
+        // Cloid-based deduplication against the order's cumulative report
+        let size = match cloid {
+            Some(ref id) => {
+                let new_size = self.fill_progress_mut(id).on_fill(size.inner());
+                if new_size.is_zero() {
+                    debug!("Skipping fill already applied: cloid={}", id);
+                    return;
+                }
+                Size::new(new_size)
             }
-        }
+            None => size,
+        };
 
         trace!(
             "Fill: market={}, side={:?}, price={}, size={}, ts={}, cloid={:?}",
@@ -391,9 +540,109 @@ impl PositionTrackerTask {
             cloid
         );
 
+        self.apply_fill(
+            market,
+            side,
+            price,
+            size,
+            timestamp_ms,
+            cloid.as_ref(),
+            entry_edge_bps,
+        );
+    }
+
+    /// Handle OrderFilled message.
+    fn on_order_filled(
+        &mut self,
+        market: MarketKey,
+        side: OrderSide,
+        price: Price,
+        total_size: Size,
+        timestamp_ms: u64,
+        cloid: ClientOrderId,
+    ) {
+        let size = self.fill_progress_mut(&cloid).on_total(total_size.inner());
+        if size.is_zero() {
+            debug!("Skipping order fill already applied: cloid={}", cloid);
+            return;
+        }
+
+        trace!(
+            "Order filled: market={}, side={:?}, price={}, total={}, new={}, cloid={}",
+            market,
+            side,
+            price,
+            total_size,
+            size,
+            cloid
+        );
+
+        self.apply_fill(
+            market,
+            side,
+            price,
+            Size::new(size),
+            timestamp_ms,
+            Some(&cloid),
+            None,
+        );
+    }
+
+    /// Handle Funding message: accrue funding on the open position.
+    fn on_funding(&mut self, market: MarketKey, paid_usd: Decimal, timestamp_ms: u64) {
+        let Some(pos) = self.positions.get_mut(&market) else {
+            debug!(
+                "Funding for market without position: market={}, paid_usd={}",
+                market, paid_usd
+            );
+            return;
+        };
+        pos.funding_usd += paid_usd;
+        trace!(
+            "Funding: market={}, paid_usd={}, total={}, ts={}",
+            market,
+            paid_usd,
+            pos.funding_usd,
+            timestamp_ms
+        );
+        self.positions_data.insert(market, pos.clone());
+    }
+
+    /// Fill accounting for a cloid, created on first use.
+    fn fill_progress_mut(&mut self, cloid: &ClientOrderId) -> &mut FillProgress {
+        // Prevent memory leak: clear when size exceeds threshold
+        // 1000 cloids ~= 10 seconds at 100 fills/sec
+        if self.fill_progress.len() > 1000 && !self.fill_progress.contains_key(cloid) {
+            debug!(
+                "Clearing fill_progress (size exceeded 1000): {}",
+                self.fill_progress.len()
+            );
+            self.fill_progress.clear();
+        }
+        self.fill_progress.entry(cloid.clone()).or_default()
+    }
+
+    /// Apply a fill (already deduplicated) to the position.
+    #[allow(clippy::too_many_arguments)]
+    fn apply_fill(
+        &mut self,
+        market: MarketKey,
+        side: OrderSide,
+        price: Price,
+        size: Size,
+        timestamp_ms: u64,
+        cloid: Option<&ClientOrderId>,
+        entry_edge_bps: Option<Decimal>,
+    ) {
         if let Some(pos) = self.positions.get_mut(&market) {
             // Update existing position
-            Self::update_position_static(pos, side, price, size, timestamp_ms);
+            let is_add_on = pos.side == side;
+            Self::update_position_static(pos, side, price, size, timestamp_ms, cloid);
+            if is_add_on && entry_edge_bps.is_some() {
+                if let Some(tranche) = pos.tranches.last_mut() {
+                    tranche.entry_edge_bps = entry_edge_bps;
+                }
+            }
 
             // Update caches
             let has_position = !pos.is_empty();
@@ -408,8 +657,9 @@ impl PositionTrackerTask {
             }
         } else {
             // Create new position
-            let mut pos = Position::new(market, side, size, price, timestamp_ms);
-            pos.entry_edge_bps = entry_edge_bps;
+            let pos = Position::new(market, side, size, price, timestamp_ms)
+                .with_entry_edge(entry_edge_bps)
+                .with_entry_order(cloid);
             self.positions.insert(market, pos.clone());
             self.positions_cache.insert(market, true);
             self.positions_data.insert(market, pos);
@@ -427,6 +677,7 @@ impl PositionTrackerTask {
         fill_price: Price,
         fill_size: Size,
         timestamp_ms: u64,
+        cloid: Option<&ClientOrderId>,
     ) {
         pos.last_update_ms = timestamp_ms;
 
@@ -441,6 +692,29 @@ impl PositionTrackerTask {
                 pos.entry_price = Price::new((old_notional + fill_notional) / new_size);
             }
             pos.size = Size::new(new_size);
+            // Further fills of an order extend its tranche, whenever they arrive
+            let order_tranche = cloid.and_then(|cloid| {
+                pos.tranches
+                    .iter_mut()
+                    .rev()
+                    .find(|t| t.cloid.as_ref() == Some(cloid))
+            });
+            match order_tranche {
+                Some(tranche) => {
+                    let size = tranche.size.inner() + fill_size.inner();
+                    tranche.price = Price::new(
+                        (tranche.size.inner() * tranche.price.inner() + fill_notional) / size,
+                    );
+                    tranche.size = Size::new(size);
+                }
+                None => pos.tranches.push(Tranche {
+                    size: fill_size,
+                    price: fill_price,
+                    timestamp_ms,
+                    entry_edge_bps: None,
+                    cloid: cloid.cloned(),
+                }),
+            }
         } else {
             // Opposite side: reduce or flip position
             let fill_amount = fill_size.inner();
@@ -458,10 +732,23 @@ impl PositionTrackerTask {
                     pos.size = Size::new(remaining);
                     pos.entry_price = fill_price;
                     pos.entry_timestamp_ms = timestamp_ms;
+                    pos.tranches = vec![Tranche {
+                        size: pos.size,
+                        price: fill_price,
+                        timestamp_ms,
+                        entry_edge_bps: None,
+                        cloid: cloid.cloned(),
+                    }];
                 }
+                pos.funding_usd = Decimal::ZERO;
             } else {
-                // Partial reduction, keep same side
-                pos.size = Size::new(current_size - fill_amount);
+                // Partial reduction, keep same side; funding stays with the remainder
+                let remaining = current_size - fill_amount;
+                pos.funding_usd = pos.funding_usd * remaining / current_size;
+                pos.size = Size::new(remaining);
+                for tranche in &mut pos.tranches {
+                    tranche.size = Size::new(tranche.size.inner() * remaining / current_size);
+                }
             }
         }
     }
@@ -501,6 +788,14 @@ impl PositionTrackerTask {
                     // Preserve tracking state from existing position
                     pos.entry_timestamp_ms = existing.entry_timestamp_ms;
                     pos.entry_edge_bps = existing.entry_edge_bps;
+                    // Tranches survive while they still add up to the synced size
+                    if existing.side == pos.side && existing.size == pos.size {
+                        pos.tranches = existing.tranches.clone();
+                    }
+                    // Funding reported by the API (sinceOpen) wins over accrued events
+                    if pos.funding_usd.is_zero() {
+                        pos.funding_usd = existing.funding_usd;
+                    }
                 }
                 self.positions_cache.insert(market, true);
                 self.positions_data.insert(market, pos.clone());
@@ -726,12 +1021,12 @@ impl PositionTrackerHandle {
             .await;
     }
 
-    /// Send a fill update.
+    /// Send a fill update (the size of this fill only, e.g. from userFills).
     ///
     /// # Arguments
-    /// * `cloid` - Optional client order ID for deduplication. If the same cloid
-    ///   is received multiple times (e.g., from both post response and
-    ///   userFills), only the first fill is processed.
+    /// * `cloid` - Optional client order ID for deduplication. Fills of the
+    ///   same cloid add up, but size already applied from the order's total
+    ///   (see [`Self::order_filled`]) is not applied again.
     #[allow(clippy::too_many_arguments)]
     pub async fn fill(
         &self,
@@ -760,6 +1055,33 @@ impl PositionTrackerHandle {
             .await;
     }
 
+    /// Send the cumulative filled size of an order (e.g., from the post
+    /// response or reconciliation).
+    ///
+    /// Only the part of `total_size` not yet applied through [`Self::fill`]
+    /// or an earlier total is applied to the position.
+    pub async fn order_filled(
+        &self,
+        market: MarketKey,
+        side: OrderSide,
+        price: Price,
+        total_size: Size,
+        timestamp_ms: u64,
+        cloid: ClientOrderId,
+    ) {
+        let _ = self
+            .tx
+            .send(PositionTrackerMsg::OrderFilled {
+                market,
+                side,
+                price,
+                total_size,
+                timestamp_ms,
+                cloid,
+            })
+            .await;
+    }
+
     /// Signal start of snapshot processing.
     pub async fn snapshot_start(&self) {
         let _ = self.tx.send(PositionTrackerMsg::SnapshotStart).await;
@@ -775,6 +1097,18 @@ impl PositionTrackerHandle {
         let _ = self.tx.send(PositionTrackerMsg::Shutdown).await;
     }
 
+    /// Accrue a funding payment on the open position in `market`.
+    pub async fn funding(&self, market: MarketKey, paid_usd: Decimal, timestamp_ms: u64) {
+        let _ = self
+            .tx
+            .send(PositionTrackerMsg::Funding {
+                market,
+                paid_usd,
+                timestamp_ms,
+            })
+            .await;
+    }
+
     /// Sync positions from external source (e.g., Hyperliquid API).
     ///
     /// Replaces all current positions with the provided list.
@@ -818,7 +1152,15 @@ impl PositionTrackerHandle {
         if self.has_position(market) {
             return false;
         }
+        self.try_mark_pending_add_on(market)
+    }
 
+    /// Try to atomically mark a market as pending for an add-on entry into
+    /// its open position.
+    ///
+    /// Same as [`Self::try_mark_pending_market`] without the position check.
+    #[must_use]
+    pub fn try_mark_pending_add_on(&self, market: &MarketKey) -> bool {
         // Atomically check-and-mark using DashMap entry API
         // This ensures no race condition between contains_key and insert
         use dashmap::mapref::entry::Entry;
@@ -938,6 +1280,15 @@ impl PositionTrackerHandle {
             .collect()
     }
 
+    /// Get a snapshot of all pending orders.
+    #[must_use]
+    pub fn pending_orders(&self) -> Vec<TrackedOrder> {
+        self.pending_orders_data
+            .iter()
+            .map(|r| r.value().clone())
+            .collect()
+    }
+
     /// Get the number of pending orders.
     #[must_use]
     pub fn pending_order_count(&self) -> usize {
@@ -1040,7 +1391,7 @@ pub fn spawn_position_tracker(capacity: usize) -> (PositionTrackerHandle, JoinHa
         in_snapshot: false,
         positions_cache: positions_cache.clone(),
         positions_data: positions_data.clone(),
-        recent_fill_cloids: HashSet::new(),
+        fill_progress: HashMap::new(),
     };
 
     let handle = PositionTrackerHandle {
@@ -1070,36 +1421,53 @@ pub fn spawn_position_tracker(capacity: usize) -> (PositionTrackerHandle, JoinHa
 /// detect an exit condition for the same market, only the first to call `try_claim()`
 /// will succeed. This prevents 3+ redundant flatten orders and the resulting
 /// "Reduce only" rejects from the exchange.
+///
+/// The claim records the exit reason of the winning monitor, which is
+/// handed back on `release()` so the closing fill can be attributed.
 #[derive(Clone)]
 pub struct SharedFlatteningGuard {
-    active: Arc<parking_lot::RwLock<HashSet<MarketKey>>>,
+    active: Arc<parking_lot::RwLock<HashMap<MarketKey, String>>>,
 }
 
 impl SharedFlatteningGuard {
     /// Create a new SharedFlatteningGuard.
     pub fn new() -> Self {
         Self {
-            active: Arc::new(parking_lot::RwLock::new(HashSet::new())),
+            active: Arc::new(parking_lot::RwLock::new(HashMap::new())),
         }
     }
 
-    /// Try to claim flatten for this market. Returns true if claimed (first caller wins).
-    pub fn try_claim(&self, market: &MarketKey) -> bool {
+    /// Try to claim flatten for this market with the exit reason.
+    /// Returns true if claimed (first caller wins, keeping its reason).
+    pub fn try_claim(&self, market: &MarketKey, reason: &str) -> bool {
         let mut guard = self.active.write();
-        guard.insert(*market) // true if newly inserted
+        match guard.entry(*market) {
+            std::collections::hash_map::Entry::Occupied(_) => false,
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                entry.insert(reason.to_string());
+                true
+            }
+        }
     }
 
     /// Release flatten claim when position is closed.
-    pub fn release(&self, market: &MarketKey) {
+    /// Returns the exit reason of the claim, if any.
+    pub fn release(&self, market: &MarketKey) -> Option<String> {
         let mut guard = self.active.write();
-        guard.remove(market);
+        guard.remove(market)
     }
 
     /// Check if a market is claimed.
     #[allow(dead_code)]
     pub fn is_claimed(&self, market: &MarketKey) -> bool {
         let guard = self.active.read();
-        guard.contains(market)
+        guard.contains_key(market)
+    }
+
+    /// Snapshot of the active claims and their exit reasons.
+    pub fn claims(&self) -> Vec<(MarketKey, String)> {
+        let guard = self.active.read();
+        guard.iter().map(|(m, r)| (*m, r.clone())).collect()
     }
 }
 
@@ -1304,6 +1672,277 @@ mod tests {
         handle.shutdown().await;
     }
 
+    #[tokio::test]
+    async fn test_funding_accrues_and_follows_partial_close() {
+        let (handle, _join) = spawn_position_tracker(100);
+        let market = sample_market();
+
+        // Funding without a position is ignored
+        handle.funding(market, dec!(1), 1000).await;
+        handle
+            .fill(
+                market,
+                OrderSide::Buy,
+                Price::new(dec!(100)),
+                Size::new(dec!(2)),
+                1001,
+                None,
+                None,
+            )
+            .await;
+        handle.funding(market, dec!(0.3), 2000).await;
+        handle.funding(market, dec!(-0.1), 3000).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        assert_eq!(handle.get_position(&market).unwrap().funding_usd, dec!(0.2));
+
+        // Half closed: half the funding stays with the remainder
+        handle
+            .fill(
+                market,
+                OrderSide::Sell,
+                Price::new(dec!(101)),
+                Size::new(dec!(1)),
+                4000,
+                None,
+                None,
+            )
+            .await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        assert_eq!(handle.get_position(&market).unwrap().funding_usd, dec!(0.1));
+
+        handle.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_add_on_tranches_and_average_entry() {
+        let (handle, _join) = spawn_position_tracker(100);
+        let market = sample_market();
+        let fill = |side, price, size, ts, cloid: &ClientOrderId, edge| {
+            handle.fill(
+                market,
+                side,
+                Price::new(price),
+                Size::new(size),
+                ts,
+                Some(cloid.clone()),
+                edge,
+            )
+        };
+        let (entry, add_on, close) = (
+            ClientOrderId::new(),
+            ClientOrderId::new(),
+            ClientOrderId::new(),
+        );
+
+        fill(
+            OrderSide::Buy,
+            dec!(100),
+            dec!(1),
+            1000,
+            &entry,
+            Some(dec!(10)),
+        )
+        .await;
+        // Add-on in two fills of the same order
+        fill(
+            OrderSide::Buy,
+            dec!(103),
+            dec!(1),
+            2000,
+            &add_on,
+            Some(dec!(20)),
+        )
+        .await;
+        fill(
+            OrderSide::Buy,
+            dec!(106),
+            dec!(1),
+            2000,
+            &add_on,
+            Some(dec!(20)),
+        )
+        .await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+
+        let pos = handle.get_position(&market).unwrap();
+        assert_eq!(pos.size, Size::new(dec!(3)));
+        assert_eq!(pos.entry_price, Price::new(dec!(103)));
+        assert_eq!(pos.entry_edge_bps, Some(dec!(10)));
+        assert_eq!(pos.add_on_count(), 1);
+        assert_eq!(pos.tranches[1].size, Size::new(dec!(2)));
+        assert_eq!(pos.tranches[1].price, Price::new(dec!(104.5)));
+        assert_eq!(pos.tranches[1].entry_edge_bps, Some(dec!(20)));
+
+        // A third closed: every tranche shrinks by a third
+        fill(OrderSide::Sell, dec!(110), dec!(1), 3000, &close, None).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        let pos = handle.get_position(&market).unwrap();
+        assert_eq!(pos.entry_price, Price::new(dec!(103)));
+        let sizes: Vec<Decimal> = pos.tranches.iter().map(|t| t.size.inner()).collect();
+        assert_eq!(sizes[1], dec!(2) * dec!(2) / dec!(3));
+
+        handle.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_tranches_keyed_by_order() {
+        let (handle, _join) = spawn_position_tracker(100);
+        let market = sample_market();
+        let (entry, add_on, other) = (
+            ClientOrderId::new(),
+            ClientOrderId::new(),
+            ClientOrderId::new(),
+        );
+
+        handle
+            .order_filled(
+                market,
+                OrderSide::Buy,
+                Price::new(dec!(100)),
+                Size::new(dec!(1)),
+                1_000,
+                entry.clone(),
+            )
+            .await;
+        // One add-on order filled in pieces at different times, one piece
+        // reported both by the post response and by userFills
+        handle
+            .order_filled(
+                market,
+                OrderSide::Buy,
+                Price::new(dec!(102)),
+                Size::new(dec!(1)),
+                2_000,
+                add_on.clone(),
+            )
+            .await;
+        handle
+            .fill(
+                market,
+                OrderSide::Buy,
+                Price::new(dec!(102)),
+                Size::new(dec!(1)),
+                2_003,
+                Some(add_on.clone()),
+                None,
+            )
+            .await;
+        handle
+            .fill(
+                market,
+                OrderSide::Buy,
+                Price::new(dec!(104)),
+                Size::new(dec!(1)),
+                2_450,
+                Some(add_on.clone()),
+                None,
+            )
+            .await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+
+        let pos = handle.get_position(&market).unwrap();
+        assert_eq!(pos.size, Size::new(dec!(3)));
+        assert_eq!(pos.add_on_count(), 1);
+        assert_eq!(pos.tranches[1].cloid, Some(add_on));
+        assert_eq!(pos.tranches[1].size, Size::new(dec!(2)));
+        assert_eq!(pos.tranches[1].price, Price::new(dec!(103)));
+
+        // Another order filled in the same ms is its own tranche
+        handle
+            .fill(
+                market,
+                OrderSide::Buy,
+                Price::new(dec!(105)),
+                Size::new(dec!(1)),
+                2_450,
+                Some(other),
+                None,
+            )
+            .await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        assert_eq!(handle.get_position(&market).unwrap().add_on_count(), 2);
+
+        handle.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_partial_reduce_fills_reconcile_with_order_totals() {
+        let (handle, _join) = spawn_position_tracker(100);
+        let market = sample_market();
+        let px = Price::new(dec!(100));
+
+        handle
+            .order_filled(
+                market,
+                OrderSide::Buy,
+                px,
+                Size::new(dec!(4)),
+                1,
+                ClientOrderId::new(),
+            )
+            .await;
+
+        // Take-profit 1: post response total first, then its two userFills
+        let tp1 = ClientOrderId::new();
+        handle
+            .order_filled(
+                market,
+                OrderSide::Sell,
+                Price::new(dec!(101)),
+                Size::new(dec!(2)),
+                2,
+                tp1.clone(),
+            )
+            .await;
+        for sz in [dec!(1.5), dec!(0.5)] {
+            handle
+                .fill(
+                    market,
+                    OrderSide::Sell,
+                    Price::new(dec!(101)),
+                    Size::new(sz),
+                    3,
+                    Some(tp1.clone()),
+                    None,
+                )
+                .await;
+        }
+
+        // Take-profit 2: one userFill first, then a larger total
+        let tp2 = ClientOrderId::new();
+        handle
+            .fill(
+                market,
+                OrderSide::Sell,
+                Price::new(dec!(102)),
+                Size::new(dec!(0.25)),
+                4,
+                Some(tp2.clone()),
+                None,
+            )
+            .await;
+        handle
+            .order_filled(
+                market,
+                OrderSide::Sell,
+                Price::new(dec!(102)),
+                Size::new(dec!(1)),
+                5,
+                tp2,
+            )
+            .await;
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+
+        // 4 - 2 - 1 = 1 left, long, entry price unchanged
+        let position = handle.get_position(&market).unwrap();
+        assert_eq!(position.side, OrderSide::Buy);
+        assert_eq!(position.size, Size::new(dec!(1)));
+        assert_eq!(position.entry_price, px);
+
+        handle.shutdown().await;
+    }
+
     #[tokio::test]
     async fn test_try_mark_pending_market() {
         let (handle, _join) = spawn_position_tracker(100);
@@ -1571,11 +2210,12 @@ mod tests {
         let market = MarketKey::new(DexId::XYZ, AssetId::new(0));
 
         // First claim succeeds
-        assert!(guard.try_claim(&market));
+        assert!(guard.try_claim(&market, "TimeStop"));
         assert!(guard.is_claimed(&market));
 
-        // Second claim fails (already claimed)
-        assert!(!guard.try_claim(&market));
+        // Second claim fails (already claimed) and keeps the first reason
+        assert!(!guard.try_claim(&market, "MarkRegression"));
+        assert_eq!(guard.release(&market).as_deref(), Some("TimeStop"));
     }
 
     #[test]
@@ -1583,12 +2223,13 @@ mod tests {
         let guard = SharedFlatteningGuard::new();
         let market = MarketKey::new(DexId::XYZ, AssetId::new(0));
 
-        assert!(guard.try_claim(&market));
+        assert!(guard.try_claim(&market, "TimeStop"));
         guard.release(&market);
         assert!(!guard.is_claimed(&market));
+        assert_eq!(guard.release(&market), None);
 
         // Can claim again after release
-        assert!(guard.try_claim(&market));
+        assert!(guard.try_claim(&market, "TimeStop"));
     }
 
     #[test]
@@ -1597,9 +2238,9 @@ mod tests {
         let market_a = MarketKey::new(DexId::XYZ, AssetId::new(0));
         let market_b = MarketKey::new(DexId::XYZ, AssetId::new(1));
 
-        assert!(guard.try_claim(&market_a));
+        assert!(guard.try_claim(&market_a, "TimeStop"));
         // Different market is independent
-        assert!(guard.try_claim(&market_b));
+        assert!(guard.try_claim(&market_b, "TimeStop"));
 
         assert!(guard.is_claimed(&market_a));
         assert!(guard.is_claimed(&market_b));
@@ -5,10 +5,12 @@
 
 use crate::error::{RegistryError, RegistryResult};
 use crate::preflight::PerpDexsResponse;
-use crate::user_state::ClearinghouseStateResponse;
+use crate::user_state::{ClearinghouseStateResponse, ParsedUserFees, RawUserFeesResponse};
 use reqwest::Client;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
 use std::time::Duration;
 use tracing::{debug, info, warn};
 
@@ -46,6 +48,71 @@ struct InfoRequestWithUserAndDex {
     dex: Option<String>,
 }
 
+/// Request type for orderStatus (lookup by oid or cloid).
+#[derive(Debug, Serialize)]
+struct OrderStatusRequest {
+    #[serde(rename = "type")]
+    request_type: String,
+    /// User address (0x...).
+    user: String,
+    /// Exchange order ID or client order ID (0x-prefixed hex).
+    oid: serde_json::Value,
+}
+
+/// Request type for maxBuilderFee (a user's approved builder fee).
+#[derive(Debug, Serialize)]
+struct MaxBuilderFeeRequest {
+    #[serde(rename = "type")]
+    request_type: String,
+    /// User address (0x...).
+    user: String,
+    /// Builder address (0x...).
+    builder: String,
+}
+
+/// Raw orderStatus response (`status` is "order" or "unknownOid").
+#[derive(Debug, Deserialize)]
+struct RawOrderStatusResponse {
+    status: String,
+    #[serde(default)]
+    order: Option<RawOrderStatusEntry>,
+}
+
+/// Order entry of an orderStatus response.
+#[derive(Debug, Deserialize)]
+struct RawOrderStatusEntry {
+    order: RawStatusOrder,
+    status: String,
+}
+
+/// Order details of an orderStatus response.
+#[derive(Debug, Deserialize)]
+struct RawStatusOrder {
+    oid: u64,
+    #[serde(rename = "limitPx")]
+    limit_px: String,
+    sz: String,
+    #[serde(rename = "origSz")]
+    orig_sz: String,
+}
+
+/// Order status from the exchange API.
+///
+/// Returned by `fetch_order_status()` for orders the exchange knows about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderStatusInfo {
+    /// Exchange order ID.
+    pub oid: u64,
+    /// Order status (e.g., "open", "filled", "canceled", "rejected").
+    pub status: String,
+    /// Limit price as decimal string.
+    pub limit_px: String,
+    /// Remaining size as decimal string.
+    pub sz: String,
+    /// Original size as decimal string.
+    pub orig_sz: String,
+}
+
 /// Raw perpDex entry from API.
 #[derive(Debug, Deserialize)]
 struct RawPerpDexEntry {
@@ -75,6 +142,12 @@ pub struct OpenOrder {
     pub sz: String,
     /// Order timestamp in milliseconds.
     pub timestamp: u64,
+    /// Client order ID (hex), if the order was placed with one.
+    #[serde(default)]
+    pub cloid: Option<String>,
+    /// Whether the order is reduce-only.
+    #[serde(rename = "reduceOnly", default)]
+    pub reduce_only: bool,
 }
 
 /// Client for fetching exchange metadata.
@@ -314,6 +387,8 @@ impl MetaClient {
     ///
     /// Returns all open orders on the specified DEX.
     /// Used at startup to detect and cancel orphaned orders from previous sessions.
+    /// Queries `frontendOpenOrders`, which adds each order's cloid and
+    /// reduce-only flag to the `openOrders` fields.
     ///
     /// # Arguments
     /// * `user_address` - User's Ethereum address (0x...).
@@ -331,7 +406,7 @@ impl MetaClient {
         );
 
         let request = InfoRequestWithUserAndDex {
-            request_type: "openOrders".to_string(),
+            request_type: "frontendOpenOrders".to_string(),
             user: user_address.to_string(),
             dex: dex.map(|s| s.to_string()),
         };
@@ -363,6 +438,178 @@ impl MetaClient {
         Ok(orders)
     }
 
+    /// Fetch the status of an order by client order ID.
+    ///
+    /// Returns None if the exchange does not know the order (`unknownOid`),
+    /// i.e. the order never reached it.
+    ///
+    /// # Arguments
+    /// * `user_address` - Address of the account that owns the order (0x...).
+    /// * `cloid` - Client order ID (0x-prefixed hex).
+    pub async fn fetch_order_status(
+        &self,
+        user_address: &str,
+        cloid: &str,
+    ) -> RegistryResult<Option<OrderStatusInfo>> {
+        debug!(user = %user_address, cloid = %cloid, "Fetching orderStatus from exchange");
+
+        let request = OrderStatusRequest {
+            request_type: "orderStatus".to_string(),
+            user: user_address.to_string(),
+            oid: serde_json::Value::String(cloid.to_string()),
+        };
+
+        let response = self
+            .client
+            .post(&self.info_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| RegistryError::HttpClient(format!("HTTP request failed: {e}")))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(RegistryError::HttpClient(format!("HTTP {status}: {body}")));
+        }
+
+        let raw: RawOrderStatusResponse = response
+            .json()
+            .await
+            .map_err(|e| RegistryError::HttpClient(format!("Failed to parse orderStatus: {e}")))?;
+
+        Ok(parse_order_status(raw))
+    }
+
+    /// Fetch the maximum builder fee a user has approved for a builder.
+    ///
+    /// Returns the fee in tenths of a basis point (0 = not approved).
+    /// Orders carrying a higher builder fee are rejected by the exchange.
+    ///
+    /// # Arguments
+    /// * `user_address` - Address of the trading account (0x...).
+    /// * `builder` - Builder address (0x...).
+    pub async fn fetch_max_builder_fee(
+        &self,
+        user_address: &str,
+        builder: &str,
+    ) -> RegistryResult<u64> {
+        debug!(user = %user_address, builder = %builder, "Fetching maxBuilderFee from exchange");
+
+        let request = MaxBuilderFeeRequest {
+            request_type: "maxBuilderFee".to_string(),
+            user: user_address.to_string(),
+            builder: builder.to_lowercase(),
+        };
+
+        let response = self
+            .client
+            .post(&self.info_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| RegistryError::HttpClient(format!("HTTP request failed: {e}")))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(RegistryError::HttpClient(format!("HTTP {status}: {body}")));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| RegistryError::HttpClient(format!("Failed to parse maxBuilderFee: {e}")))
+    }
+
+    /// Fetch mid prices for all coins (`allMids`).
+    ///
+    /// Polled frequently (e.g., as an oracle sanity reference), so logs at
+    /// debug level. Entries whose price fails to parse are skipped.
+    ///
+    /// # Arguments
+    /// * `dex` - Optional DEX name. If None, returns L1 perp mids.
+    pub async fn fetch_all_mids(
+        &self,
+        dex: Option<&str>,
+    ) -> RegistryResult<HashMap<String, Decimal>> {
+        let request = match dex {
+            Some(dex) => serde_json::to_value(InfoRequestWithDex {
+                request_type: "allMids".to_string(),
+                dex: dex.to_string(),
+            }),
+            None => serde_json::to_value(InfoRequest {
+                request_type: "allMids".to_string(),
+            }),
+        }
+        .map_err(|e| RegistryError::HttpClient(format!("Failed to build allMids request: {e}")))?;
+
+        let response = self
+            .client
+            .post(&self.info_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| RegistryError::HttpClient(format!("HTTP request failed: {e}")))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(RegistryError::HttpClient(format!("HTTP {status}: {body}")));
+        }
+
+        let raw: HashMap<String, String> = response
+            .json()
+            .await
+            .map_err(|e| RegistryError::HttpClient(format!("Failed to parse allMids: {e}")))?;
+        let mids = parse_all_mids(raw);
+
+        debug!(count = mids.len(), dex = ?dex, "Fetched allMids");
+        Ok(mids)
+    }
+
+    /// Fetch a user's current fee rates (`userFees`).
+    ///
+    /// Rates reflect the account's volume tier and can change during the week.
+    ///
+    /// # Arguments
+    /// * `user_address` - User's Ethereum address (0x...).
+    pub async fn fetch_user_fees(&self, user_address: &str) -> RegistryResult<ParsedUserFees> {
+        let request = InfoRequestWithUserAndDex {
+            request_type: "userFees".to_string(),
+            user: user_address.to_string(),
+            dex: None,
+        };
+
+        let response = self
+            .client
+            .post(&self.info_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| RegistryError::HttpClient(format!("HTTP request failed: {e}")))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(RegistryError::HttpClient(format!("HTTP {status}: {body}")));
+        }
+
+        let raw: RawUserFeesResponse = response
+            .json()
+            .await
+            .map_err(|e| RegistryError::HttpClient(format!("Failed to parse userFees: {e}")))?;
+        let fees = ParsedUserFees::from_response(&raw)
+            .map_err(|e| RegistryError::ParseError(format!("Invalid userFees rate: {e}")))?;
+
+        debug!(
+            taker_bps = %fees.taker_bps,
+            maker_bps = %fees.maker_bps,
+            "Fetched userFees"
+        );
+        Ok(fees)
+    }
+
     /// Fetch clearinghouse state for a user.
     ///
     /// Contains account summary and open positions.
@@ -420,6 +667,33 @@ impl MetaClient {
     }
 }
 
+/// Parse `allMids` string prices, skipping unparseable entries.
+fn parse_all_mids(raw: HashMap<String, String>) -> HashMap<String, Decimal> {
+    raw.into_iter()
+        .filter_map(|(coin, px)| match Decimal::from_str(&px) {
+            Ok(px) => Some((coin, px)),
+            Err(e) => {
+                warn!(coin = %coin, px = %px, ?e, "Skipping unparseable allMids price");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Flatten an orderStatus response (None for `unknownOid`).
+fn parse_order_status(raw: RawOrderStatusResponse) -> Option<OrderStatusInfo> {
+    if raw.status != "order" {
+        return None;
+    }
+    raw.order.map(|entry| OrderStatusInfo {
+        oid: entry.order.oid,
+        status: entry.status,
+        limit_px: entry.order.limit_px,
+        sz: entry.order.sz,
+        orig_sz: entry.order.orig_sz,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -471,4 +745,43 @@ mod tests {
         let orders: Vec<OpenOrder> = serde_json::from_str(json).unwrap();
         assert!(orders.is_empty());
     }
+
+    #[test]
+    fn test_parse_all_mids() {
+        let json = r#"{"BTC": "97123.5", "ETH": "3210.25", "BAD": "n/a"}"#;
+        let raw: HashMap<String, String> = serde_json::from_str(json).unwrap();
+        let mids = parse_all_mids(raw);
+
+        assert_eq!(mids.len(), 2);
+        assert_eq!(mids["BTC"], rust_decimal_macros::dec!(97123.5));
+        assert_eq!(mids["ETH"], rust_decimal_macros::dec!(3210.25));
+    }
+
+    #[test]
+    fn test_parse_order_status() {
+        let json = r#"{
+            "status": "order",
+            "order": {
+                "order": {
+                    "coin": "xyz:SILVER",
+                    "side": "B",
+                    "limitPx": "31.50",
+                    "sz": "0.0",
+                    "oid": 12345678,
+                    "timestamp": 1707400000000,
+                    "origSz": "0.40",
+                    "cloid": "0x0de3e244a8f44fc28a6b7bc852d66d19"
+                },
+                "status": "filled",
+                "statusTimestamp": 1707400000100
+            }
+        }"#;
+        let info = parse_order_status(serde_json::from_str(json).unwrap()).unwrap();
+        assert_eq!(info.oid, 12345678);
+        assert_eq!(info.status, "filled");
+        assert_eq!(info.orig_sz, "0.40");
+
+        let unknown = r#"{"status": "unknownOid"}"#;
+        assert!(parse_order_status(serde_json::from_str(unknown).unwrap()).is_none());
+    }
 }
@@ -0,0 +1,149 @@
+//! Periodic user fee tier refresh.
+//!
+//! Fee rates depend on the account's rolling volume tier, which can change
+//! mid-week. [`FeeRefresher`] polls `userFees` and publishes changes on a
+//! `watch` channel; the consumer owns the `FeeCalculator` and swaps the new
+//! rates in between detector ticks, so edge math never mixes old and new fees.
+
+use crate::client::MetaClient;
+use crate::user_state::ParsedUserFees;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::{info, warn};
+
+/// Configuration for periodic fee refresh.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeRefreshConfig {
+    /// Whether to poll user fees. Requires `user_address`. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Poll interval (seconds). Default: 3600.
+    #[serde(default = "default_interval_secs")]
+    pub interval_secs: u64,
+    /// Pause new entries for this long after a fee change (ms, 0 = no pause).
+    #[serde(default)]
+    pub pause_on_change_ms: u64,
+}
+
+fn default_interval_secs() -> u64 {
+    3_600 // 1 hour
+}
+
+impl Default for FeeRefreshConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_secs: default_interval_secs(),
+            pause_on_change_ms: 0,
+        }
+    }
+}
+
+/// Polls a user's fee rates and publishes changes.
+pub struct FeeRefresher {
+    client: MetaClient,
+    user_address: String,
+    interval: Duration,
+    tx: watch::Sender<Option<ParsedUserFees>>,
+}
+
+impl FeeRefresher {
+    /// Create a refresher and the receiver for fee updates.
+    ///
+    /// The receiver starts at `None` and is updated on the first successful
+    /// fetch and on every change after that.
+    pub fn new(
+        client: MetaClient,
+        user_address: impl Into<String>,
+        interval: Duration,
+    ) -> (Self, watch::Receiver<Option<ParsedUserFees>>) {
+        let (tx, rx) = watch::channel(None);
+        let refresher = Self {
+            client,
+            user_address: user_address.into(),
+            interval,
+            tx,
+        };
+        (refresher, rx)
+    }
+
+    /// Poll until every receiver is dropped.
+    pub async fn run(self) {
+        let mut interval = tokio::time::interval(self.interval);
+        loop {
+            interval.tick().await;
+            if self.tx.is_closed() {
+                return;
+            }
+            match self.client.fetch_user_fees(&self.user_address).await {
+                Ok(fees) => self.publish(fees),
+                Err(e) => warn!(?e, "User fee refresh failed; keeping current fees"),
+            }
+        }
+    }
+
+    /// Publish fees if they differ from the last published value.
+    fn publish(&self, fees: ParsedUserFees) {
+        self.tx.send_if_modified(|current| {
+            if current.as_ref() == Some(&fees) {
+                return false;
+            }
+            match current {
+                Some(old) => info!(
+                    old_tier = %old.tier,
+                    new_tier = %fees.tier,
+                    old_taker_bps = %old.taker_bps,
+                    new_taker_bps = %fees.taker_bps,
+                    old_maker_bps = %old.maker_bps,
+                    new_maker_bps = %fees.maker_bps,
+                    "User fee tier changed"
+                ),
+                None => info!(
+                    tier = %fees.tier,
+                    taker_bps = %fees.taker_bps,
+                    maker_bps = %fees.maker_bps,
+                    "User fees loaded"
+                ),
+            }
+            *current = Some(fees);
+            true
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn fees(taker_bps: rust_decimal::Decimal) -> ParsedUserFees {
+        ParsedUserFees {
+            taker_bps,
+            ..ParsedUserFees::default_fees()
+        }
+    }
+
+    #[test]
+    fn test_publish_only_on_change() {
+        let client = MetaClient::new("http://localhost").unwrap();
+        let (refresher, mut rx) = FeeRefresher::new(client, "0x1234", Duration::from_secs(1));
+        assert!(rx.borrow_and_update().is_none());
+
+        refresher.publish(fees(dec!(3.5)));
+        assert!(rx.has_changed().unwrap());
+        assert_eq!(
+            rx.borrow_and_update().as_ref().unwrap().taker_bps,
+            dec!(3.5)
+        );
+
+        // Same rates again: no notification
+        refresher.publish(fees(dec!(3.5)));
+        assert!(!rx.has_changed().unwrap());
+
+        // Tier change
+        refresher.publish(fees(dec!(3)));
+        assert!(rx.has_changed().unwrap());
+        assert_eq!(rx.borrow_and_update().as_ref().unwrap().taker_bps, dec!(3));
+    }
+}
@@ -3,17 +3,20 @@
 //! Manages market specifications from perpDexs, detects parameter changes,
 //! and maintains the spec cache.
 //!
-//! P0-24: Includes user state and fee fetching for HIP-3 2x fee calculation.
+//! P0-24: Includes user state and fee fetching for HIP-3 2x fee calculation,
+//! with periodic refresh when the account's fee tier changes.
 //! P0-15: Automatic market discovery from perpDexs API.
 
 pub mod client;
 pub mod error;
+pub mod fee_refresh;
 pub mod preflight;
 pub mod spec_cache;
 pub mod user_state;
 
-pub use client::{MetaClient, OpenOrder};
+pub use client::{MetaClient, OpenOrder, OrderStatusInfo};
 pub use error::{RegistryError, RegistryResult};
+pub use fee_refresh::{FeeRefreshConfig, FeeRefresher};
 pub use preflight::{
     validate_market_keys, DiscoveredMarket, PerpDexInfo, PerpDexsResponse, PerpMarketInfo,
     PreflightChecker, PreflightResult,
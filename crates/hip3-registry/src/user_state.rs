@@ -16,10 +16,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RawUserFeesResponse {
     /// Taker fee rate as string (e.g., "0.0002" for 2 bps).
-    #[serde(rename = "takerRate")]
+    /// `userFees` reports this as `userCrossRate`.
+    #[serde(rename = "takerRate", alias = "userCrossRate")]
     pub taker_rate: String,
     /// Maker fee rate as string (can be negative for rebates).
-    #[serde(rename = "makerRate")]
+    /// `userFees` reports this as `userAddRate`.
+    #[serde(rename = "makerRate", alias = "userAddRate")]
     pub maker_rate: String,
     /// Fee tier name (e.g., "tier1", "vip1").
     #[serde(default)]
@@ -53,7 +55,7 @@ impl RawUserFeesResponse {
 /// Parsed user fees for use with FeeCalculator.
 ///
 /// Use `from_response()` to convert from REST API response.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParsedUserFees {
     /// Taker fee in basis points.
     pub taker_bps: Decimal,
@@ -300,6 +302,17 @@ mod tests {
         assert_eq!(parsed.tier, "vip1");
     }
 
+    #[test]
+    fn test_parse_user_fees_endpoint_fields() {
+        let json = r#"{"userCrossRate": "0.00035", "userAddRate": "0.0001", "dailyUserVlm": []}"#;
+        let response: RawUserFeesResponse = serde_json::from_str(json).unwrap();
+
+        let parsed = ParsedUserFees::from_response(&response).unwrap();
+        assert_eq!(parsed.taker_bps, dec!(3.5));
+        assert_eq!(parsed.maker_bps, dec!(1));
+        assert_eq!(parsed.tier, "default");
+    }
+
     #[test]
     fn test_default_fees() {
         let fees = ParsedUserFees::default_fees();
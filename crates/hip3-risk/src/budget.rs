@@ -0,0 +1,445 @@
+//! Per-market risk budget allocation.
+//!
+//! Each market gets a share of the total notional limit and of the daily
+//! loss budget. Open positions and pending entries consume the notional
+//! share; realized losses consume the loss share. A market that exhausts its
+//! loss share is blocked for the rest of the UTC day while the others keep
+//! trading; notional is released as positions close.
+//!
+//! Markets without an explicit share use the defaults (1 = the whole
+//! budget, i.e. only the portfolio-wide limits apply).
+
+use std::collections::HashMap;
+
+use hip3_core::{MarketKey, Price, RejectReason};
+use hip3_position::PositionTrackerHandle;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+const DAY_MS: u64 = 86_400_000;
+
+/// Budget shares of one market.
+///
+/// ```toml
+/// [[risk_budget.markets]]
+/// market = "GOLD"
+/// notional_share = 0.4
+/// loss_share = 0.3
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketBudgetDef {
+    /// Coin name.
+    pub market: String,
+    /// Share of the total notional limit (0..=1).
+    #[serde(default = "default_share")]
+    pub notional_share: Decimal,
+    /// Share of the daily loss budget (0..=1).
+    #[serde(default = "default_share")]
+    pub loss_share: Decimal,
+}
+
+fn default_share() -> Decimal {
+    Decimal::ONE
+}
+
+/// Configuration for per-market risk budgets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskBudgetConfig {
+    /// Enable per-market budgets. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Total daily loss budget (USD) split across markets. 0 = no loss budget.
+    #[serde(default)]
+    pub daily_loss_budget_usd: Decimal,
+    /// Notional share of markets not listed in `markets`. Default: 1.
+    #[serde(default = "default_share")]
+    pub default_notional_share: Decimal,
+    /// Loss share of markets not listed in `markets`. Default: 1.
+    #[serde(default = "default_share")]
+    pub default_loss_share: Decimal,
+    /// Per-market shares.
+    #[serde(default)]
+    pub markets: Vec<MarketBudgetDef>,
+}
+
+impl Default for RiskBudgetConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            daily_loss_budget_usd: Decimal::ZERO,
+            default_notional_share: default_share(),
+            default_loss_share: default_share(),
+            markets: Vec::new(),
+        }
+    }
+}
+
+/// Budget shares of one market with a resolved `MarketKey`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarketBudget {
+    /// Share of the total notional limit.
+    pub notional_share: Decimal,
+    /// Share of the daily loss budget.
+    pub loss_share: Decimal,
+}
+
+/// Daily loss of one market against its loss budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarketDailyLoss {
+    /// Market key.
+    pub market: MarketKey,
+    /// Realized PnL of the day (USD).
+    pub pnl_usd: Decimal,
+    /// Loss budget exhausted for the day.
+    pub exhausted: bool,
+}
+
+/// Realized PnL of a market on the current UTC day.
+#[derive(Debug, Default)]
+struct DailyLoss {
+    /// UTC day index (days since epoch).
+    day: u64,
+    /// Realized PnL (USD).
+    pnl_usd: Decimal,
+    /// Loss budget exhausted for the day.
+    exhausted: bool,
+}
+
+/// Per-market notional and daily loss budget gate.
+pub struct RiskBudgetGate {
+    /// Per-market shares (markets not listed use `default_budget`).
+    budgets: HashMap<MarketKey, MarketBudget>,
+    /// Shares of unlisted markets.
+    default_budget: MarketBudget,
+    /// Total notional limit (USD) the notional shares apply to.
+    total_notional: Decimal,
+    /// Daily loss budget (USD) the loss shares apply to.
+    daily_loss_budget: Decimal,
+    /// Position tracker handle.
+    position_handle: PositionTrackerHandle,
+    /// Realized PnL per market for the current day.
+    losses: Mutex<HashMap<MarketKey, DailyLoss>>,
+}
+
+impl RiskBudgetGate {
+    /// Create a new `RiskBudgetGate`.
+    ///
+    /// `total_notional` is the portfolio notional limit the shares divide.
+    #[must_use]
+    pub fn new(
+        budgets: HashMap<MarketKey, MarketBudget>,
+        config: &RiskBudgetConfig,
+        total_notional: Decimal,
+        position_handle: PositionTrackerHandle,
+    ) -> Self {
+        Self {
+            budgets,
+            default_budget: MarketBudget {
+                notional_share: config.default_notional_share,
+                loss_share: config.default_loss_share,
+            },
+            total_notional,
+            daily_loss_budget: config.daily_loss_budget_usd,
+            position_handle,
+            losses: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Budget shares of `market`.
+    #[must_use]
+    pub fn budget(&self, market: &MarketKey) -> MarketBudget {
+        self.budgets
+            .get(market)
+            .copied()
+            .unwrap_or(self.default_budget)
+    }
+
+    /// Notional budget of `market` (USD).
+    #[must_use]
+    pub fn notional_budget(&self, market: &MarketKey) -> Decimal {
+        self.total_notional * self.budget(market).notional_share
+    }
+
+    /// Daily loss budget of `market` (USD). 0 = no loss budget.
+    #[must_use]
+    pub fn loss_budget(&self, market: &MarketKey) -> Decimal {
+        self.daily_loss_budget * self.budget(market).loss_share
+    }
+
+    /// Report realized PnL of a close in `market`.
+    pub fn report_pnl(&self, market: &MarketKey, pnl_usd: Decimal) {
+        self.report_pnl_at(market, pnl_usd, now_ms());
+    }
+
+    /// Report realized PnL of a close in `market` at `now_ms` (Unix ms).
+    pub fn report_pnl_at(&self, market: &MarketKey, pnl_usd: Decimal, now_ms: u64) {
+        let loss_budget = self.loss_budget(market);
+        let mut losses = self.losses.lock();
+        let entry = losses.entry(*market).or_default();
+        Self::roll(entry, now_ms);
+        entry.pnl_usd += pnl_usd;
+
+        if loss_budget > Decimal::ZERO && !entry.exhausted && -entry.pnl_usd >= loss_budget {
+            entry.exhausted = true;
+            warn!(
+                market = %market,
+                pnl_usd = %entry.pnl_usd,
+                loss_budget_usd = %loss_budget,
+                "RiskBudgetGate: market loss budget exhausted, blocked until next UTC day"
+            );
+        }
+    }
+
+    /// Share of the daily loss budget used by `market` today (0 if no budget).
+    #[must_use]
+    pub fn loss_used_ratio(&self, market: &MarketKey) -> Decimal {
+        self.loss_used_ratio_at(market, now_ms())
+    }
+
+    /// Share of the daily loss budget used by `market` on the day of `now_ms`.
+    #[must_use]
+    pub fn loss_used_ratio_at(&self, market: &MarketKey, now_ms: u64) -> Decimal {
+        let loss_budget = self.loss_budget(market);
+        if loss_budget.is_zero() {
+            return Decimal::ZERO;
+        }
+        let mut losses = self.losses.lock();
+        let Some(entry) = losses.get_mut(market) else {
+            return Decimal::ZERO;
+        };
+        Self::roll(entry, now_ms);
+        (-entry.pnl_usd).max(Decimal::ZERO) / loss_budget
+    }
+
+    /// Daily losses of the markets that realized PnL today.
+    #[must_use]
+    pub fn daily_losses(&self) -> Vec<MarketDailyLoss> {
+        self.daily_losses_at(now_ms())
+    }
+
+    /// Daily losses on the day of `now_ms` (Unix ms).
+    #[must_use]
+    pub fn daily_losses_at(&self, now_ms: u64) -> Vec<MarketDailyLoss> {
+        let mut losses = self.losses.lock();
+        losses
+            .iter_mut()
+            .filter_map(|(market, entry)| {
+                Self::roll(entry, now_ms);
+                (!entry.pnl_usd.is_zero() || entry.exhausted).then_some(MarketDailyLoss {
+                    market: *market,
+                    pnl_usd: entry.pnl_usd,
+                    exhausted: entry.exhausted,
+                })
+            })
+            .collect()
+    }
+
+    /// Restore daily losses saved at `saved_at_ms` (Unix ms), e.g. by a
+    /// previous run. Losses of an earlier day are reset as usual.
+    pub fn restore_daily_losses(&self, restored: &[MarketDailyLoss], saved_at_ms: u64) {
+        let day = saved_at_ms / DAY_MS;
+        let mut losses = self.losses.lock();
+        for loss in restored {
+            losses.insert(
+                loss.market,
+                DailyLoss {
+                    day,
+                    pnl_usd: loss.pnl_usd,
+                    exhausted: loss.exhausted,
+                },
+            );
+        }
+    }
+
+    /// Check if an entry of `order_notional` in `market` fits its budgets.
+    ///
+    /// Existing exposure (positions and pending entries) is valued at
+    /// `mark_px`.
+    pub fn check(
+        &self,
+        market: &MarketKey,
+        order_notional: Decimal,
+        mark_px: Price,
+    ) -> Result<(), RejectReason> {
+        self.check_at(market, order_notional, mark_px, now_ms())
+    }
+
+    /// Check if an entry fits the budgets of `market` at `now_ms` (Unix ms).
+    pub fn check_at(
+        &self,
+        market: &MarketKey,
+        order_notional: Decimal,
+        mark_px: Price,
+        now_ms: u64,
+    ) -> Result<(), RejectReason> {
+        {
+            let mut losses = self.losses.lock();
+            if let Some(entry) = losses.get_mut(market) {
+                Self::roll(entry, now_ms);
+                if entry.exhausted {
+                    debug!(
+                        market = %market,
+                        pnl_usd = %entry.pnl_usd,
+                        "RiskBudgetGate blocked: loss budget exhausted"
+                    );
+                    return Err(RejectReason::RiskBudgetExhausted);
+                }
+            }
+        }
+
+        let notional_budget = self.notional_budget(market);
+        let position_notional = self.position_handle.get_notional(market, mark_px).inner();
+        let pending_notional = self
+            .position_handle
+            .get_pending_notional_excluding_reduce_only(market, mark_px)
+            .inner();
+        let projected = position_notional + pending_notional + order_notional;
+        if projected > notional_budget {
+            debug!(
+                market = %market,
+                projected = %projected,
+                budget = %notional_budget,
+                "RiskBudgetGate blocked: notional budget exceeded"
+            );
+            return Err(RejectReason::RiskBudgetExhausted);
+        }
+
+        Ok(())
+    }
+
+    /// Start a new day for `entry` if `now_ms` is past its day.
+    fn roll(entry: &mut DailyLoss, now_ms: u64) {
+        let day = now_ms / DAY_MS;
+        if day != entry.day {
+            if entry.exhausted {
+                info!("RiskBudgetGate: market loss budget reset for new UTC day");
+            }
+            *entry = DailyLoss {
+                day,
+                ..Default::default()
+            };
+        }
+    }
+}
+
+fn now_ms() -> u64 {
+    chrono::Utc::now().timestamp_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId, OrderSide, Size};
+    use hip3_position::{spawn_position_tracker, Position};
+    use rust_decimal_macros::dec;
+
+    fn market(idx: u32) -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(idx))
+    }
+
+    #[tokio::test]
+    async fn test_notional_and_loss_budgets() {
+        let (handle, _join) = spawn_position_tracker(100);
+        handle
+            .sync_positions(vec![Position::new(
+                market(0),
+                OrderSide::Buy,
+                Size::new(dec!(3)),
+                Price::new(dec!(100)),
+                0,
+            )])
+            .await;
+        // The tracker applies the sync on its own task
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while !handle.has_position(&market(0)) {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("position synced");
+
+        let config = RiskBudgetConfig {
+            enabled: true,
+            daily_loss_budget_usd: dec!(100),
+            default_notional_share: dec!(0.5),
+            default_loss_share: dec!(0.5),
+            markets: Vec::new(),
+        };
+        let budgets = [(
+            market(0),
+            MarketBudget {
+                notional_share: dec!(0.4),
+                loss_share: dec!(0.2),
+            },
+        )]
+        .into_iter()
+        .collect();
+        let gate = RiskBudgetGate::new(budgets, &config, dec!(1000), handle.clone());
+        let px = Price::new(dec!(100));
+        // 2024-01-03 10:00 UTC
+        let now_ms = 1_704_276_000_000;
+
+        // Market 0: 300 held + 100 = 400 of a 400 budget
+        assert!(gate.check_at(&market(0), dec!(100), px, now_ms).is_ok());
+        assert_eq!(
+            gate.check_at(&market(0), dec!(150), px, now_ms),
+            Err(RejectReason::RiskBudgetExhausted)
+        );
+
+        // A $20 loss exhausts market 0's $20 share for the day
+        gate.report_pnl_at(&market(0), dec!(-20), now_ms);
+        assert_eq!(gate.loss_used_ratio_at(&market(0), now_ms), Decimal::ONE);
+        assert_eq!(
+            gate.check_at(&market(0), dec!(10), px, now_ms),
+            Err(RejectReason::RiskBudgetExhausted)
+        );
+        // Other markets keep trading
+        assert!(gate.check_at(&market(1), dec!(500), px, now_ms).is_ok());
+
+        // Next UTC day
+        assert!(gate
+            .check_at(&market(0), dec!(10), px, now_ms + DAY_MS)
+            .is_ok());
+
+        handle.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_daily_losses_survive_restore() {
+        let (handle, _join) = spawn_position_tracker(100);
+        let config = RiskBudgetConfig {
+            enabled: true,
+            daily_loss_budget_usd: dec!(100),
+            default_loss_share: dec!(0.2),
+            ..Default::default()
+        };
+        let gate = RiskBudgetGate::new(HashMap::new(), &config, dec!(1000), handle.clone());
+        let px = Price::new(dec!(100));
+        // 2024-01-03 10:00 UTC
+        let now_ms = 1_704_276_000_000;
+        gate.report_pnl_at(&market(0), dec!(-20), now_ms);
+        gate.report_pnl_at(&market(1), dec!(-5), now_ms);
+        let saved = gate.daily_losses_at(now_ms);
+
+        let restarted = RiskBudgetGate::new(HashMap::new(), &config, dec!(1000), handle.clone());
+        restarted.restore_daily_losses(&saved, now_ms);
+        assert_eq!(
+            restarted.check_at(&market(0), dec!(10), px, now_ms + 1_000),
+            Err(RejectReason::RiskBudgetExhausted)
+        );
+        // Market 1's loss keeps counting: $16 more exhausts its $20 share
+        restarted.report_pnl_at(&market(1), dec!(-16), now_ms + 1_000);
+        assert_eq!(
+            restarted.check_at(&market(1), dec!(10), px, now_ms + 2_000),
+            Err(RejectReason::RiskBudgetExhausted)
+        );
+        // Restored losses end with their day
+        assert!(restarted
+            .check_at(&market(0), dec!(10), px, now_ms + DAY_MS)
+            .is_ok());
+
+        handle.shutdown().await;
+    }
+}
@@ -0,0 +1,289 @@
+//! Equity drawdown gate driven by the live account value.
+//!
+//! `MaxDrawdownGate` only sees the PnL the bot reports for its own closes,
+//! so fees, funding and anything done manually on the account never reach
+//! it. [`EquityDrawdownGate`] instead tracks the clearinghouseState account
+//! value, polled by the bot, against the session high-water mark and blocks
+//! new entries once the drawdown from that mark exceeds `max_drawdown_pct`.
+//!
+//! If equity stops updating for longer than `max_equity_age_secs` the gate
+//! blocks as well: a drawdown it cannot see is not a reason to keep trading.
+//!
+//! Deposits, withdrawals and transfers are not PnL: the bot reports them via
+//! [`EquityDrawdownGate::apply_transfer`], which shifts the high-water mark
+//! and equity together.
+
+use hip3_core::RejectReason;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+/// Configuration for the equity drawdown gate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquityDrawdownConfig {
+    /// Enable the gate. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Block entries when equity is this far below the session high-water
+    /// mark (percent). Default: 5.
+    #[serde(default = "default_max_drawdown_pct")]
+    pub max_drawdown_pct: Decimal,
+
+    /// Account value poll interval (seconds). Default: 30.
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+
+    /// Block entries when the last equity sample is older than this
+    /// (seconds). 0 = never stale. Default: 300.
+    #[serde(default = "default_max_equity_age_secs")]
+    pub max_equity_age_secs: u64,
+}
+
+fn default_max_drawdown_pct() -> Decimal {
+    Decimal::from(5)
+}
+
+fn default_poll_interval_secs() -> u64 {
+    30
+}
+
+fn default_max_equity_age_secs() -> u64 {
+    300
+}
+
+impl Default for EquityDrawdownConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_drawdown_pct: default_max_drawdown_pct(),
+            poll_interval_secs: default_poll_interval_secs(),
+            max_equity_age_secs: default_max_equity_age_secs(),
+        }
+    }
+}
+
+/// Latest equity sample and session high-water mark.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EquityStatus {
+    /// Latest account value (USD).
+    pub equity: Decimal,
+    /// Highest account value seen this session (USD).
+    pub high_water_mark: Decimal,
+    /// Time of the latest sample (Unix ms, 0 = none yet).
+    pub updated_at_ms: u64,
+}
+
+impl EquityStatus {
+    /// Drawdown from the high-water mark (percent), once sampled.
+    #[must_use]
+    pub fn drawdown_pct(&self) -> Option<Decimal> {
+        if self.high_water_mark <= Decimal::ZERO {
+            return None;
+        }
+        Some((self.high_water_mark - self.equity) / self.high_water_mark * Decimal::ONE_HUNDRED)
+    }
+}
+
+/// Blocks new entries when account equity falls too far below its session
+/// high-water mark.
+pub struct EquityDrawdownGate {
+    config: EquityDrawdownConfig,
+    status: Mutex<EquityStatus>,
+}
+
+impl EquityDrawdownGate {
+    /// Create a new gate.
+    #[must_use]
+    pub fn new(config: EquityDrawdownConfig) -> Self {
+        Self {
+            config,
+            status: Mutex::new(EquityStatus::default()),
+        }
+    }
+
+    /// Check if the gate is enabled.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled && self.config.max_drawdown_pct > Decimal::ZERO
+    }
+
+    /// Gate configuration.
+    #[must_use]
+    pub fn config(&self) -> &EquityDrawdownConfig {
+        &self.config
+    }
+
+    /// Record a polled account value.
+    ///
+    /// Non-positive values (failed or empty responses) are ignored.
+    pub fn update(&self, equity: Decimal, now_ms: u64) {
+        if equity <= Decimal::ZERO {
+            return;
+        }
+        let mut status = self.status.lock();
+        let was_breached = self.is_breached(&status);
+        status.equity = equity;
+        status.updated_at_ms = now_ms;
+        if equity > status.high_water_mark {
+            status.high_water_mark = equity;
+        }
+
+        let breached = self.is_breached(&status);
+        if breached && !was_breached {
+            warn!(
+                equity = %equity,
+                high_water_mark = %status.high_water_mark,
+                drawdown_pct = ?status.drawdown_pct(),
+                max_drawdown_pct = %self.config.max_drawdown_pct,
+                "EquityDrawdownGate: drawdown limit exceeded, entries paused"
+            );
+        } else if was_breached && !breached {
+            info!(
+                equity = %equity,
+                high_water_mark = %status.high_water_mark,
+                "EquityDrawdownGate: equity recovered, entries resumed"
+            );
+        }
+    }
+
+    /// Shift equity and the high-water mark by a balance transfer (USD,
+    /// positive = into the account), so the transfer is not a drawdown or a
+    /// new high. No-op before the first sample.
+    pub fn apply_transfer(&self, delta: Decimal) {
+        let mut status = self.status.lock();
+        if status.updated_at_ms == 0 {
+            return;
+        }
+        status.equity = (status.equity + delta).max(Decimal::ZERO);
+        status.high_water_mark = (status.high_water_mark + delta).max(Decimal::ZERO);
+        info!(
+            delta = %delta,
+            equity = %status.equity,
+            high_water_mark = %status.high_water_mark,
+            "EquityDrawdownGate: high-water mark shifted by transfer"
+        );
+    }
+
+    /// Latest equity sample and high-water mark.
+    #[must_use]
+    pub fn status(&self) -> EquityStatus {
+        *self.status.lock()
+    }
+
+    /// Check if new entries are allowed.
+    pub fn check(&self) -> Result<(), RejectReason> {
+        self.check_at(chrono::Utc::now().timestamp_millis() as u64)
+    }
+
+    /// Check if new entries are allowed at `now_ms` (Unix ms).
+    ///
+    /// Passes until the first sample arrives.
+    pub fn check_at(&self, now_ms: u64) -> Result<(), RejectReason> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+        let status = self.status.lock();
+        if status.updated_at_ms == 0 {
+            return Ok(());
+        }
+
+        let max_age_ms = self.config.max_equity_age_secs * 1000;
+        let age_ms = now_ms.saturating_sub(status.updated_at_ms);
+        if max_age_ms > 0 && age_ms > max_age_ms {
+            debug!(
+                age_ms,
+                max_age_ms, "EquityDrawdownGate blocked: equity stale"
+            );
+            return Err(RejectReason::EquityDrawdown);
+        }
+
+        if self.is_breached(&status) {
+            debug!(
+                equity = %status.equity,
+                high_water_mark = %status.high_water_mark,
+                drawdown_pct = ?status.drawdown_pct(),
+                "EquityDrawdownGate blocked: drawdown exceeded"
+            );
+            return Err(RejectReason::EquityDrawdown);
+        }
+
+        Ok(())
+    }
+
+    fn is_breached(&self, status: &EquityStatus) -> bool {
+        status
+            .drawdown_pct()
+            .is_some_and(|dd| dd > self.config.max_drawdown_pct)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_blocks_on_drawdown_from_high_water_mark() {
+        let gate = EquityDrawdownGate::new(EquityDrawdownConfig {
+            enabled: true,
+            max_drawdown_pct: dec!(5),
+            ..Default::default()
+        });
+        // No sample yet
+        assert!(gate.check_at(0).is_ok());
+
+        gate.update(dec!(1000), 1_000);
+        gate.update(dec!(1100), 2_000);
+        // 1050 is 4.5% below the 1100 mark
+        gate.update(dec!(1050), 3_000);
+        assert!(gate.check_at(3_000).is_ok());
+
+        // 1040 is 5.45% below the mark, though above the starting equity
+        gate.update(dec!(1040), 4_000);
+        assert_eq!(gate.check_at(4_000), Err(RejectReason::EquityDrawdown));
+        assert_eq!(gate.status().high_water_mark, dec!(1100));
+
+        // Failed polls are ignored; recovery resumes entries
+        gate.update(Decimal::ZERO, 5_000);
+        gate.update(dec!(1060), 6_000);
+        assert!(gate.check_at(6_000).is_ok());
+    }
+
+    #[test]
+    fn test_transfers_shift_high_water_mark() {
+        let gate = EquityDrawdownGate::new(EquityDrawdownConfig {
+            enabled: true,
+            max_drawdown_pct: dec!(5),
+            ..Default::default()
+        });
+        gate.apply_transfer(dec!(-500));
+        assert_eq!(gate.status().high_water_mark, Decimal::ZERO);
+
+        gate.update(dec!(1000), 1_000);
+        // Withdrawing half the account is not a 50% drawdown
+        gate.apply_transfer(dec!(-500));
+        gate.update(dec!(500), 2_000);
+        assert!(gate.check_at(2_000).is_ok());
+        assert_eq!(gate.status().high_water_mark, dec!(500));
+
+        // A deposit is not a new high to draw down from
+        gate.apply_transfer(dec!(1000));
+        gate.update(dec!(1480), 3_000);
+        assert!(gate.check_at(3_000).is_ok());
+        assert_eq!(gate.status().high_water_mark, dec!(1500));
+    }
+
+    #[test]
+    fn test_stale_equity_blocks() {
+        let gate = EquityDrawdownGate::new(EquityDrawdownConfig {
+            enabled: true,
+            max_equity_age_secs: 60,
+            ..Default::default()
+        });
+        gate.update(dec!(1000), 1_000);
+        assert!(gate.check_at(61_000).is_ok());
+        assert_eq!(gate.check_at(61_001), Err(RejectReason::EquityDrawdown));
+    }
+}
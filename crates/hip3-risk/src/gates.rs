@@ -12,6 +12,9 @@
 //! - TimeRegression: No backwards time detected (P0-16)
 //! - MarkMidDivergence: Mark-Mid gap within threshold
 //! - SpreadShock: Spread not abnormally wide
+//! - RealizedVol: Short-horizon realized volatility within threshold
+//! - PriceJump: No recent statistical outlier in oracle or BBO mid prints
+//! - MarketHours: Underlying market of the asset class open
 //!
 //! ## Position Gates
 //! - OiCap: Open interest below limit
@@ -23,16 +26,22 @@
 //! - Halt: Market not halted
 //! - BufferLow: Liquidation buffer adequate
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::error::{RiskError, RiskResult};
 use chrono::{NaiveTime, Timelike, Utc};
 use hip3_core::types::MarketSnapshot;
-use hip3_core::{MarketKey, MarketSpec, OrderSide, Price, RejectReason, Size};
+use hip3_core::{
+    AssetClass, MarketKey, MarketSpec, OrderSide, Price, RejectReason, Size, TradingCalendar,
+};
 use hip3_position::PositionTrackerHandle;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use tracing::{debug, trace, warn};
+use tracing::{debug, info, trace, warn};
+
+/// Maximum prints kept per price series for the PriceJump gate.
+const MAX_PRICE_PRINTS: usize = 500;
 
 /// Time window for trading blackout.
 ///
@@ -97,6 +106,13 @@ pub struct RiskGateConfig {
     /// Maximum AssetCtx age in milliseconds (P0-12: monotonic freshness).
     #[serde(default = "default_max_ctx_age_ms")]
     pub max_ctx_age_ms: i64,
+    /// Measure BBO age in exchange time (monotonic receive age plus the
+    /// delivery delay above the clock-skew minimum) instead of local receive
+    /// time, so feed delay counts and a wall-clock step doesn't. Applies to
+    /// every BBO-age consumer (gates, metrics, dashboard). AssetCtx carries
+    /// no server time and keeps its receive age.
+    #[serde(default)]
+    pub skew_corrected_freshness: bool,
     /// Trading blackout windows (UTC).
     ///
     /// Trading Philosophy: During market open times (e.g., US Pre-market 09:00 UTC),
@@ -104,6 +120,55 @@ pub struct RiskGateConfig {
     /// Block new entries during these high-risk periods.
     #[serde(default = "default_blackout_windows")]
     pub blackout_windows: Vec<BlackoutWindow>,
+    /// Realized volatility threshold in bps (0 = disabled).
+    ///
+    /// The oracle/mark edge assumes the oracle leads a slow book; in fast
+    /// markets that breaks down. Above the threshold entries are size-reduced,
+    /// above twice the threshold they are blocked. Markets can override it.
+    #[serde(default)]
+    pub max_realized_vol_bps: Decimal,
+    /// Size factor applied while realized volatility is elevated.
+    #[serde(default = "default_realized_vol_size_factor")]
+    pub realized_vol_size_factor: Decimal,
+    /// Price jump threshold in standard deviations (0 = disabled).
+    ///
+    /// A bad oracle print or flash move shows up as a phantom dislocation.
+    /// An oracle or BBO mid print this many standard deviations from its
+    /// short-horizon mean blocks entries for `price_jump_cooldown_ms`.
+    #[serde(default)]
+    pub price_jump_sigma: Decimal,
+    /// Window of the short-horizon mean and deviation (ms).
+    #[serde(default = "default_price_jump_window_ms")]
+    pub price_jump_window_ms: i64,
+    /// Prints needed in the window before jumps are checked.
+    #[serde(default = "default_price_jump_min_samples")]
+    pub price_jump_min_samples: usize,
+    /// Smallest move from the mean (bps) counted as a jump, so ticks of a
+    /// quiet market are not outliers.
+    #[serde(default = "default_price_jump_min_move_bps")]
+    pub price_jump_min_move_bps: Decimal,
+    /// Entry block after a jump (ms).
+    #[serde(default = "default_price_jump_cooldown_ms")]
+    pub price_jump_cooldown_ms: i64,
+    /// Evaluation order of the `check_all` gates.
+    ///
+    /// Gates missing from the list run after the listed ones, in default
+    /// order. Keep the data-freshness gates ahead of `spread_shock`: it
+    /// updates its EWMA with whatever snapshot reaches it.
+    #[serde(default = "default_gate_order")]
+    pub gate_order: Vec<GateId>,
+    /// Gates skipped by `check_all`.
+    ///
+    /// Emergency switch for a misbehaving gate; every disabled gate is
+    /// logged as a warning at startup.
+    #[serde(default)]
+    pub disabled_gates: Vec<GateId>,
+    /// Gates whose blocks `check_all` records instead of returning.
+    ///
+    /// Drain the recorded blocks with `RiskGate::take_observed_blocks`;
+    /// every observe-only gate is logged as a warning at startup.
+    #[serde(default)]
+    pub observe_only_gates: Vec<GateId>,
 }
 
 fn default_max_bbo_age_ms() -> i64 {
@@ -118,6 +183,30 @@ fn default_blackout_windows() -> Vec<BlackoutWindow> {
     Vec::new() // Empty by default for backwards compatibility
 }
 
+fn default_realized_vol_size_factor() -> Decimal {
+    Decimal::new(5, 1) // 0.5
+}
+
+fn default_price_jump_window_ms() -> i64 {
+    30_000
+}
+
+fn default_price_jump_min_samples() -> usize {
+    20
+}
+
+fn default_price_jump_min_move_bps() -> Decimal {
+    Decimal::from(10)
+}
+
+fn default_price_jump_cooldown_ms() -> i64 {
+    30_000
+}
+
+fn default_gate_order() -> Vec<GateId> {
+    GateId::ALL.to_vec()
+}
+
 impl Default for RiskGateConfig {
     fn default() -> Self {
         Self {
@@ -128,11 +217,184 @@ impl Default for RiskGateConfig {
             max_oi_fraction: Decimal::new(1, 2),   // 0.01 = 1%
             max_bbo_age_ms: 2000,                  // P0-12: 2 seconds
             max_ctx_age_ms: 8000,                  // P0-12: 8 seconds (matches oracle)
+            skew_corrected_freshness: false,       // Receive-time BBO age
             blackout_windows: Vec::new(),          // Empty by default
+            max_realized_vol_bps: Decimal::ZERO,   // Disabled
+            realized_vol_size_factor: default_realized_vol_size_factor(),
+            price_jump_sigma: Decimal::ZERO, // Disabled
+            price_jump_window_ms: default_price_jump_window_ms(),
+            price_jump_min_samples: default_price_jump_min_samples(),
+            price_jump_min_move_bps: default_price_jump_min_move_bps(),
+            price_jump_cooldown_ms: default_price_jump_cooldown_ms(),
+            gate_order: default_gate_order(),
+            disabled_gates: Vec::new(),
+            observe_only_gates: Vec::new(),
         }
     }
 }
 
+impl RiskGateConfig {
+    /// Enabled gates in evaluation order.
+    ///
+    /// Listed gates come first (duplicates dropped), then any gate missing
+    /// from `gate_order` in default order; disabled gates are removed.
+    #[must_use]
+    pub fn gate_plan(&self) -> Vec<GateId> {
+        let mut plan: Vec<GateId> = Vec::with_capacity(GateId::ALL.len());
+        for id in self.gate_order.iter().chain(GateId::ALL.iter()) {
+            if !plan.contains(id) {
+                plan.push(*id);
+            }
+        }
+        plan.retain(|id| !self.disabled_gates.contains(id));
+        plan
+    }
+
+    /// Warn about gates that are disabled or reordered by configuration.
+    ///
+    /// Call once at startup.
+    pub fn log_gate_overrides(&self) {
+        for id in GateId::ALL {
+            if self.disabled_gates.contains(&id) {
+                warn!(
+                    gate = id.as_str(),
+                    "RISK GATE DISABLED by config: entries are not checked against it"
+                );
+            } else if self.observe_only_gates.contains(&id) {
+                warn!(
+                    gate = id.as_str(),
+                    "Risk gate observe-only by config: blocks are recorded, not enforced"
+                );
+            }
+        }
+        let plan = self.gate_plan();
+        let enabled_default: Vec<GateId> = GateId::ALL
+            .into_iter()
+            .filter(|id| plan.contains(id))
+            .collect();
+        if plan != enabled_default {
+            let order: Vec<&str> = plan.iter().map(|id| id.as_str()).collect();
+            warn!(?order, "Risk gate order overridden by config");
+        }
+        let first_shock = plan.iter().position(|id| *id == GateId::SpreadShock);
+        let late_prerequisite = plan.iter().rposition(|id| id.is_prerequisite());
+        if let (Some(shock), Some(prereq)) = (first_shock, late_prerequisite) {
+            if shock < prereq {
+                warn!("spread_shock runs before a data-freshness gate: stale snapshots can reach its EWMA");
+            }
+        }
+    }
+}
+
+/// Gates evaluated by [`RiskGate::check_all`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GateId {
+    /// BBO freshness (P0-12).
+    BboUpdate,
+    /// AssetCtx freshness, also covers oracle freshness (P0-12).
+    CtxUpdate,
+    /// BBO server time went backwards (P0-16).
+    TimeRegression,
+    /// Mark-mid divergence (BBO validity).
+    MarkMidDivergence,
+    /// Spread vs its EWMA (updates the EWMA).
+    SpreadShock,
+    /// Position vs open interest cap.
+    OiCap,
+    /// Tick/lot/fee change detected.
+    ParamChange,
+    /// Market halted.
+    Halt,
+    /// Blackout windows.
+    TimeOfDay,
+    /// Underlying market closed (weekend, after hours, holiday).
+    MarketHours,
+    /// Cool-down after an outlier oracle/BBO mid print (samples prints).
+    PriceJump,
+    /// Short-horizon realized volatility (may reduce size).
+    RealizedVol,
+}
+
+impl GateId {
+    /// All gates in default evaluation order.
+    pub const ALL: [GateId; 12] = [
+        GateId::BboUpdate,
+        GateId::CtxUpdate,
+        GateId::TimeRegression,
+        GateId::MarkMidDivergence,
+        GateId::SpreadShock,
+        GateId::OiCap,
+        GateId::ParamChange,
+        GateId::Halt,
+        GateId::TimeOfDay,
+        GateId::MarketHours,
+        GateId::PriceJump,
+        GateId::RealizedVol,
+    ];
+
+    /// Gate name, as used in config and `RiskError::GateBlocked`.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            GateId::BboUpdate => "bbo_update",
+            GateId::CtxUpdate => "ctx_update",
+            GateId::TimeRegression => "time_regression",
+            GateId::MarkMidDivergence => "mark_mid_divergence",
+            GateId::SpreadShock => "spread_shock",
+            GateId::OiCap => "oi_cap",
+            GateId::ParamChange => "param_change",
+            GateId::Halt => "halt",
+            GateId::TimeOfDay => "time_of_day",
+            GateId::MarketHours => "market_hours",
+            GateId::PriceJump => "price_jump",
+            GateId::RealizedVol => "realized_vol",
+        }
+    }
+
+    /// Data freshness/validity gate that must run before `SpreadShock`.
+    #[must_use]
+    pub fn is_prerequisite(self) -> bool {
+        matches!(
+            self,
+            GateId::BboUpdate
+                | GateId::CtxUpdate
+                | GateId::TimeRegression
+                | GateId::MarkMidDivergence
+        )
+    }
+}
+
+/// Per-evaluation inputs of the `check_all` gates that are not part of the
+/// market snapshot.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GateInputs<'a> {
+    /// Short-horizon realized volatility (bps); None until enough samples.
+    pub realized_vol_bps: Option<Decimal>,
+    /// Per-market override of `max_realized_vol_bps`.
+    pub max_realized_vol_bps: Option<Decimal>,
+    /// Trading calendar and the market's asset class; None skips the
+    /// market-hours check.
+    pub calendar: Option<(&'a TradingCalendar, Option<AssetClass>)>,
+    /// Evaluation time (Unix ms), for market hours and the price-jump
+    /// window and cool-down.
+    pub now_ms: i64,
+}
+
+/// Observed blocks kept until drained; later ones are dropped.
+const MAX_OBSERVED_BLOCKS: usize = 10_000;
+
+/// A block an observe-only `check_all` gate would have made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObservedGateBlock {
+    /// Time of the block (Unix ms).
+    pub timestamp_ms: i64,
+    /// Gate that would have blocked.
+    pub gate: GateId,
+    /// Reason the gate would have blocked with.
+    pub reason: String,
+}
+
 /// Result of a gate check.
 #[derive(Debug, Clone)]
 pub enum GateResult {
@@ -158,6 +420,9 @@ impl GateResult {
 ///
 /// CRITICAL: All gates must pass for trading to be allowed.
 /// When in doubt, block.
+///
+/// Spread EWMA, time regression and halt/param-change state describe a
+/// single market's feed: hold one `RiskGate` per `MarketKey`.
 pub struct RiskGate {
     config: RiskGateConfig,
     /// EWMA of spread for shock detection.
@@ -172,12 +437,21 @@ pub struct RiskGate {
     last_bbo_time: Option<i64>,
     /// Time regression detected.
     time_regression_detected: bool,
+    /// Enabled `check_all` gates in evaluation order.
+    gate_plan: Vec<GateId>,
+    /// Recent oracle and BBO mid prints (Unix ms, price) for PriceJump.
+    price_prints: [VecDeque<(i64, Decimal)>; 2],
+    /// Last price jump and the time (Unix ms) its entry block ends.
+    price_jump: Option<(String, i64)>,
+    /// Blocks of observe-only gates not yet drained.
+    observed_blocks: Vec<ObservedGateBlock>,
 }
 
 impl RiskGate {
     /// Create a new risk gate with configuration.
     pub fn new(config: RiskGateConfig) -> Self {
         Self {
+            gate_plan: config.gate_plan(),
             config,
             spread_ewma: Decimal::ZERO,
             ewma_alpha: Decimal::new(5, 2), // 0.05 = slow adaptation
@@ -185,6 +459,9 @@ impl RiskGate {
             halt_detected: false,
             last_bbo_time: None,
             time_regression_detected: false,
+            price_prints: Default::default(),
+            price_jump: None,
+            observed_blocks: Vec::new(),
         }
     }
 
@@ -194,7 +471,10 @@ impl RiskGate {
     /// Prerequisite gates are checked first; if they block, gates with
     /// side effects (like spread_shock EWMA update) are NOT executed.
     ///
-    /// Returns Ok(()) if all gates pass, or the first blocking error.
+    /// Returns each evaluated gate's result if none blocks, or the first
+    /// blocking error. `ReduceSize` results are returned with their gate.
+    /// Blocks of observe-only gates are recorded for
+    /// [`Self::take_observed_blocks`] and reported as passing.
     ///
     /// # Gate Evaluation Order (P0-2, BUG-002 fix)
     /// 1. bbo_update - prerequisite (data freshness, P0-12)
@@ -206,6 +486,12 @@ impl RiskGate {
     /// 7. param_change - market change detection
     /// 8. halt - market status
     /// 9. time_of_day - blackout windows for high-risk periods
+    /// 10. market_hours - underlying market closed
+    /// 11. price_jump - cool-down after an outlier print (samples prints)
+    /// 12. realized_vol - blocks in fast markets, reduces size when elevated
+    ///
+    /// This is the default; `RiskGateConfig::gate_order` and
+    /// `RiskGateConfig::disabled_gates` reorder or skip gates.
     ///
     /// NOTE: oracle_fresh gate was removed (BUG-002). ctx_update gate now covers
     /// oracle freshness because it checks when we last received an AssetCtx update,
@@ -219,6 +505,8 @@ impl RiskGate {
     /// - `ctx_age_ms`: AssetCtx age in milliseconds (monotonic, P0-12)
     /// - `bbo_server_time`: BBO server time (for TimeRegression, P0-16)
     /// - `position_size`: Current position size
+    /// - `inputs`: Inputs of the gates not derived from the snapshot
+    #[allow(clippy::too_many_arguments)]
     pub fn check_all(
         &mut self,
         snapshot: &MarketSnapshot,
@@ -227,124 +515,70 @@ impl RiskGate {
         ctx_age_ms: i64,
         bbo_server_time: Option<i64>,
         position_size: Option<Size>,
-    ) -> RiskResult<Vec<GateResult>> {
-        let mut results = Vec::with_capacity(9);
-
-        // P0-2: Phase 1 - Prerequisite gates (early return on block)
-        // These gates check data freshness and integrity.
-        // If any fails, we MUST NOT update EWMA with stale/invalid data.
-
-        // Gate 1: BBO Update (P0-12)
-        // BUG-003 fix: Use trace! instead of warn! to reduce log spam.
-        // Logging is handled by app.rs with market context and sampling.
-        let gate1 = self.check_bbo_update(bbo_age_ms);
-        if let GateResult::Block(reason) = &gate1 {
-            trace!(gate = "bbo_update", reason, "prerequisite failed");
-            return Err(RiskError::GateBlocked {
-                gate: "bbo_update".to_string(),
-                reason: reason.clone(),
-            });
-        }
-        results.push(gate1);
-
-        // Gate 2: AssetCtx Update (P0-12)
-        // BUG-002 fix: This gate now covers oracle freshness.
-        // ctx_age_ms measures time since we last received an AssetCtx update,
-        // which includes the oracle price. This is the correct metric for freshness.
-        let gate2 = self.check_ctx_update(ctx_age_ms);
-        if let GateResult::Block(reason) = &gate2 {
-            trace!(gate = "ctx_update", reason, "prerequisite failed");
-            return Err(RiskError::GateBlocked {
-                gate: "ctx_update".to_string(),
-                reason: reason.clone(),
-            });
-        }
-        results.push(gate2);
-
-        // Gate 3: Time Regression (P0-16)
-        let gate3 = self.check_time_regression(bbo_server_time);
-        if let GateResult::Block(reason) = &gate3 {
-            trace!(gate = "time_regression", reason, "prerequisite failed");
-            return Err(RiskError::GateBlocked {
-                gate: "time_regression".to_string(),
-                reason: reason.clone(),
-            });
-        }
-        results.push(gate3);
-
-        // P0-2: Phase 2 - BBO validity check (before EWMA update)
-        // Gate 4: Mark-Mid Divergence
-        let gate4 = self.check_mark_mid_divergence(snapshot);
-        if let GateResult::Block(reason) = &gate4 {
-            trace!(gate = "mark_mid_divergence", reason, "BBO invalid");
-            return Err(RiskError::GateBlocked {
-                gate: "mark_mid_divergence".to_string(),
-                reason: reason.clone(),
-            });
-        }
-        results.push(gate4);
-
-        // P0-2: Phase 3 - Gates with side effects (EWMA update)
-        // Only execute after all prerequisites pass
-        // Gate 5: Spread Shock (updates EWMA)
-        let gate5 = self.check_spread_shock(snapshot);
-        if let GateResult::Block(reason) = &gate5 {
-            trace!(gate = "spread_shock", reason, "spread shock detected");
-            return Err(RiskError::GateBlocked {
-                gate: "spread_shock".to_string(),
-                reason: reason.clone(),
-            });
-        }
-        results.push(gate5);
-
-        // P0-2: Phase 4 - Position and market status gates
-        // Gate 6: OI Cap
-        let gate6 = self.check_oi_cap(snapshot, spec, position_size);
-        if let GateResult::Block(reason) = &gate6 {
-            trace!(gate = "oi_cap", reason, "OI cap reached");
-            return Err(RiskError::GateBlocked {
-                gate: "oi_cap".to_string(),
-                reason: reason.clone(),
-            });
-        }
-        results.push(gate6);
-
-        // Gate 7: Param Change
-        let gate7 = self.check_param_change();
-        if let GateResult::Block(reason) = &gate7 {
-            trace!(gate = "param_change", reason, "parameter change detected");
-            return Err(RiskError::GateBlocked {
-                gate: "param_change".to_string(),
-                reason: reason.clone(),
-            });
-        }
-        results.push(gate7);
-
-        // Gate 8: Halt
-        let gate8 = self.check_halt(spec);
-        if let GateResult::Block(reason) = &gate8 {
-            trace!(gate = "halt", reason, "market halted");
-            return Err(RiskError::GateBlocked {
-                gate: "halt".to_string(),
-                reason: reason.clone(),
-            });
-        }
-        results.push(gate8);
-
-        // Gate 9: Time of Day (Blackout Windows)
-        let gate9 = self.check_time_of_day();
-        if let GateResult::Block(reason) = &gate9 {
-            trace!(gate = "time_of_day", reason, "trading blackout");
-            return Err(RiskError::GateBlocked {
-                gate: "time_of_day".to_string(),
-                reason: reason.clone(),
-            });
-        }
-        results.push(gate9);
+        inputs: &GateInputs<'_>,
+    ) -> RiskResult<Vec<(GateId, GateResult)>> {
+        let mut results = Vec::with_capacity(self.gate_plan.len());
+
+        for id in self.gate_plan.clone() {
+            let result = match id {
+                GateId::BboUpdate => self.check_bbo_update(bbo_age_ms),
+                GateId::CtxUpdate => self.check_ctx_update(ctx_age_ms),
+                GateId::TimeRegression => self.check_time_regression(bbo_server_time),
+                GateId::MarkMidDivergence => self.check_mark_mid_divergence(snapshot),
+                GateId::SpreadShock => self.check_spread_shock(snapshot),
+                GateId::OiCap => self.check_oi_cap(snapshot, spec, position_size),
+                GateId::ParamChange => self.check_param_change(),
+                GateId::Halt => self.check_halt(spec),
+                GateId::TimeOfDay => self.check_time_of_day(),
+                GateId::MarketHours => match inputs.calendar {
+                    Some((calendar, asset_class)) => {
+                        let now = chrono::DateTime::from_timestamp_millis(inputs.now_ms)
+                            .unwrap_or_else(Utc::now);
+                        self.check_market_hours(calendar, asset_class, now)
+                    }
+                    None => GateResult::Pass,
+                },
+                GateId::PriceJump => self.check_price_jump(snapshot, inputs.now_ms),
+                GateId::RealizedVol => {
+                    self.check_realized_vol(inputs.realized_vol_bps, inputs.max_realized_vol_bps)
+                }
+            };
+            // BUG-003 fix: Use trace! instead of warn! to reduce log spam.
+            // Logging is handled by app.rs with market context and sampling.
+            if let GateResult::Block(reason) = result {
+                if self.config.observe_only_gates.contains(&id) {
+                    debug!(
+                        gate = id.as_str(),
+                        reason, "Gate block observed (observe-only)"
+                    );
+                    if self.observed_blocks.len() < MAX_OBSERVED_BLOCKS {
+                        self.observed_blocks.push(ObservedGateBlock {
+                            timestamp_ms: Utc::now().timestamp_millis(),
+                            gate: id,
+                            reason,
+                        });
+                    }
+                    results.push((id, GateResult::Pass));
+                    continue;
+                }
+                trace!(gate = id.as_str(), reason, "gate blocked");
+                return Err(RiskError::GateBlocked {
+                    gate: id.as_str().to_string(),
+                    reason,
+                });
+            }
+            results.push((id, result));
+        }
 
         Ok(results)
     }
 
+    /// Drain the blocks observe-only gates would have made since the last
+    /// call.
+    pub fn take_observed_blocks(&mut self) -> Vec<ObservedGateBlock> {
+        std::mem::take(&mut self.observed_blocks)
+    }
+
     /// Oracle Freshness check (DEPRECATED - BUG-002).
     ///
     /// This gate was removed from `check_all()` because `oracle_age_ms` was
@@ -637,6 +871,146 @@ impl RiskGate {
         GateResult::Pass
     }
 
+    /// Market Hours
+    ///
+    /// Block entries while the underlying market of `asset_class` is closed
+    /// (outside its hours or on a holiday), when the oracle no longer
+    /// tracks a live price. Markets without an asset class always pass.
+    pub fn check_market_hours(
+        &self,
+        calendar: &TradingCalendar,
+        asset_class: Option<AssetClass>,
+        now: chrono::DateTime<Utc>,
+    ) -> GateResult {
+        match asset_class {
+            Some(class) if !calendar.is_open_at(class, now) => {
+                GateResult::Block(format!("Underlying market closed: {class}"))
+            }
+            _ => GateResult::Pass,
+        }
+    }
+
+    /// Realized Volatility
+    ///
+    /// Reduce size or block if short-horizon realized volatility is elevated.
+    /// `max_vol_bps` is a per-market override of `max_realized_vol_bps`.
+    /// Passes until enough samples exist (`realized_vol_bps` is None).
+    pub fn check_realized_vol(
+        &self,
+        realized_vol_bps: Option<Decimal>,
+        max_vol_bps: Option<Decimal>,
+    ) -> GateResult {
+        let threshold = max_vol_bps.unwrap_or(self.config.max_realized_vol_bps);
+        let Some(vol_bps) = realized_vol_bps else {
+            return GateResult::Pass;
+        };
+        if threshold.is_zero() {
+            return GateResult::Pass;
+        }
+
+        if vol_bps > threshold * Decimal::from(2) {
+            return GateResult::Block(format!(
+                "Realized vol: {:.1} bps > {:.1} bps (2x threshold)",
+                vol_bps, threshold
+            ));
+        }
+
+        if vol_bps > threshold {
+            return GateResult::ReduceSize {
+                factor: self.config.realized_vol_size_factor,
+                reason: format!(
+                    "Realized vol elevated: {:.1} bps > {:.1} bps threshold",
+                    vol_bps, threshold
+                ),
+            };
+        }
+
+        GateResult::Pass
+    }
+
+    /// Price Jump
+    ///
+    /// Block entries for `price_jump_cooldown_ms` after an oracle or BBO mid
+    /// print lands `price_jump_sigma` standard deviations (and at least
+    /// `price_jump_min_move_bps`) from its mean over `price_jump_window_ms`.
+    /// The jumping series restarts from the new print, so a genuine level
+    /// shift is accepted once the cool-down has passed.
+    pub fn check_price_jump(&mut self, snapshot: &MarketSnapshot, now_ms: i64) -> GateResult {
+        let sigma_limit = self.config.price_jump_sigma;
+        if sigma_limit.is_zero() {
+            return GateResult::Pass;
+        }
+
+        let prints = [
+            ("oracle", Some(snapshot.ctx.oracle.oracle_px.inner())),
+            ("mid", snapshot.bbo.mid_price().map(|px| px.inner())),
+        ];
+        for (series, (source, px)) in self.price_prints.iter_mut().zip(prints) {
+            let Some(px) = px.filter(|px| *px > Decimal::ZERO) else {
+                continue;
+            };
+            while series
+                .front()
+                .is_some_and(|&(at, _)| now_ms - at > self.config.price_jump_window_ms)
+            {
+                series.pop_front();
+            }
+            // Only new prints are checked (and sampled)
+            if series.back().is_some_and(|&(_, last)| last == px) {
+                continue;
+            }
+
+            if series.len() >= self.config.price_jump_min_samples.max(2) {
+                let n = Decimal::from(series.len());
+                let mean = series.iter().map(|&(_, p)| p).sum::<Decimal>() / n;
+                let variance = series
+                    .iter()
+                    .map(|&(_, p)| (p - mean) * (p - mean))
+                    .sum::<Decimal>()
+                    / n;
+                let sigma = variance
+                    .to_f64()
+                    .map(f64::sqrt)
+                    .and_then(Decimal::from_f64)
+                    .unwrap_or(Decimal::ZERO);
+                let deviation = (px - mean).abs();
+                let move_bps = deviation / mean * Decimal::from(10000);
+                if move_bps >= self.config.price_jump_min_move_bps
+                    && deviation >= sigma * sigma_limit
+                {
+                    let reason = if sigma.is_zero() {
+                        format!("{source} {move_bps:.1} bps from flat mean")
+                    } else {
+                        format!(
+                            "{source} {move_bps:.1} bps ({:.1} sigma) from mean",
+                            deviation / sigma
+                        )
+                    };
+                    warn!(
+                        %reason,
+                        cooldown_ms = self.config.price_jump_cooldown_ms,
+                        "Price jump detected, blocking entries"
+                    );
+                    self.price_jump = Some((reason, now_ms + self.config.price_jump_cooldown_ms));
+                    series.clear();
+                }
+            }
+
+            if series.len() >= MAX_PRICE_PRINTS {
+                series.pop_front();
+            }
+            series.push_back((now_ms, px));
+        }
+
+        match &self.price_jump {
+            Some((reason, until_ms)) if now_ms < *until_ms => GateResult::Block(format!(
+                "Price jump: {reason} ({}ms cool-down left)",
+                until_ms - now_ms
+            )),
+            _ => GateResult::Pass,
+        }
+    }
+
     /// Check if any critical flag is set.
     pub fn has_critical_block(&self) -> bool {
         self.param_change_detected || self.halt_detected || self.time_regression_detected
@@ -647,6 +1021,15 @@ impl RiskGate {
         self.spread_ewma
     }
 
+    /// Seed the spread EWMA from a warm-state snapshot.
+    ///
+    /// Ignored once the EWMA has been initialized from live data.
+    pub fn restore_spread_ewma(&mut self, ewma_bps: Decimal) {
+        if self.spread_ewma.is_zero() {
+            self.spread_ewma = ewma_bps;
+        }
+    }
+
     /// Get current config.
     pub fn config(&self) -> &RiskGateConfig {
         &self.config
@@ -864,6 +1247,246 @@ mod tests {
         assert!(result.is_pass(), "Should pass outside blackout window");
     }
 
+    #[test]
+    fn test_realized_vol_gate() {
+        // Disabled by default
+        let gate = RiskGate::new(RiskGateConfig::default());
+        assert!(gate.check_realized_vol(Some(dec!(500)), None).is_pass());
+
+        let config = RiskGateConfig {
+            max_realized_vol_bps: dec!(20),
+            ..Default::default()
+        };
+        let gate = RiskGate::new(config);
+        assert!(gate.check_realized_vol(None, None).is_pass());
+        assert!(gate.check_realized_vol(Some(dec!(15)), None).is_pass());
+        assert!(matches!(
+            gate.check_realized_vol(Some(dec!(30)), None),
+            GateResult::ReduceSize { factor, .. } if factor == dec!(0.5)
+        ));
+        assert!(gate.check_realized_vol(Some(dec!(41)), None).is_block());
+
+        // Per-market override
+        assert!(gate
+            .check_realized_vol(Some(dec!(30)), Some(dec!(40)))
+            .is_pass());
+    }
+
+    #[test]
+    fn test_check_all_realized_vol() {
+        let config = RiskGateConfig {
+            max_realized_vol_bps: dec!(20),
+            ..Default::default()
+        };
+        let mut gate = RiskGate::new(config);
+        let spec = MarketSpec::default();
+        let elevated = GateInputs {
+            realized_vol_bps: Some(dec!(30)),
+            ..Default::default()
+        };
+
+        let results = gate
+            .check_all(&test_snapshot(), &spec, 500, 500, None, None, &elevated)
+            .unwrap();
+        assert!(results.iter().any(|(id, result)| *id == GateId::RealizedVol
+            && matches!(result, GateResult::ReduceSize { factor, .. } if *factor == dec!(0.5))));
+
+        let fast = GateInputs {
+            realized_vol_bps: Some(dec!(41)),
+            ..Default::default()
+        };
+        let err = gate
+            .check_all(&test_snapshot(), &spec, 500, 500, None, None, &fast)
+            .unwrap_err();
+        assert!(matches!(err, RiskError::GateBlocked { gate, .. } if gate == "realized_vol"));
+    }
+
+    #[test]
+    fn test_price_jump_gate() {
+        let snapshot_at = |oracle: Decimal, mid: Decimal| {
+            let bbo = Bbo::new(
+                Price::new(mid - dec!(5)),
+                Size::new(dec!(1)),
+                Price::new(mid + dec!(5)),
+                Size::new(dec!(1)),
+            );
+            let oracle = OracleData::new(Price::new(oracle), Price::new(oracle));
+            MarketSnapshot::new(bbo, AssetCtx::new(oracle, dec!(0.0001)))
+        };
+
+        // Disabled by default
+        let mut gate = RiskGate::new(RiskGateConfig::default());
+        assert!(gate
+            .check_price_jump(&snapshot_at(dec!(50000), dec!(50000)), 0)
+            .is_pass());
+
+        let config = RiskGateConfig {
+            price_jump_sigma: dec!(4),
+            price_jump_min_samples: 10,
+            price_jump_cooldown_ms: 5_000,
+            ..Default::default()
+        };
+        let mut gate = RiskGate::new(config);
+        // Quiet oracle and mid wobbling by 1-2 bps
+        for i in 0..20 {
+            let px = dec!(50000) + Decimal::from(i % 3) * dec!(5);
+            assert!(gate
+                .check_price_jump(&snapshot_at(px, px + dec!(2)), i * 100)
+                .is_pass());
+        }
+
+        // A 100 bps oracle print blocks entries for the cool-down
+        assert!(gate
+            .check_price_jump(&snapshot_at(dec!(50500), dec!(50007)), 2_000)
+            .is_block());
+        assert!(gate
+            .check_price_jump(&snapshot_at(dec!(50500), dec!(50007)), 6_999)
+            .is_block());
+        // The new level is accepted once the cool-down has passed
+        assert!(gate
+            .check_price_jump(&snapshot_at(dec!(50500), dec!(50007)), 7_000)
+            .is_pass());
+    }
+
+    #[test]
+    fn test_check_all_observe_only_gate() {
+        let config = RiskGateConfig {
+            observe_only_gates: vec![GateId::CtxUpdate],
+            ..Default::default()
+        };
+        let mut gate = RiskGate::new(config);
+        let spec = MarketSpec::default();
+
+        // Stale ctx is recorded and passes
+        let results = gate
+            .check_all(
+                &test_snapshot(),
+                &spec,
+                500,
+                10000,
+                None,
+                None,
+                &GateInputs::default(),
+            )
+            .unwrap();
+        assert!(results.iter().all(|(_, result)| !result.is_block()));
+        let observed = gate.take_observed_blocks();
+        assert_eq!(observed.len(), 1);
+        assert_eq!(observed[0].gate, GateId::CtxUpdate);
+        assert!(gate.take_observed_blocks().is_empty());
+
+        // Other gates still block
+        let err = gate
+            .check_all(
+                &test_snapshot(),
+                &spec,
+                5000,
+                500,
+                None,
+                None,
+                &GateInputs::default(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, RiskError::GateBlocked { gate, .. } if gate == "bbo_update"));
+    }
+
+    #[test]
+    fn test_check_all_market_hours() {
+        let mut gate = RiskGate::new(RiskGateConfig::default());
+        let spec = MarketSpec::default();
+        let calendar = TradingCalendar::default();
+        let at = |day: u32| {
+            chrono::NaiveDate::from_ymd_opt(2026, 10, day)
+                .and_then(|date| date.and_hms_opt(15, 0, 0))
+                .unwrap()
+                .and_utc()
+                .timestamp_millis()
+        };
+        let inputs = |now_ms| GateInputs {
+            calendar: Some((&calendar, Some(AssetClass::Equities))),
+            now_ms,
+            ..Default::default()
+        };
+
+        // Tuesday 11:00 ET: open
+        assert!(gate
+            .check_all(
+                &test_snapshot(),
+                &spec,
+                500,
+                500,
+                None,
+                None,
+                &inputs(at(13))
+            )
+            .is_ok());
+        // Saturday: closed
+        let err = gate
+            .check_all(
+                &test_snapshot(),
+                &spec,
+                500,
+                500,
+                None,
+                None,
+                &inputs(at(17)),
+            )
+            .unwrap_err();
+        assert!(matches!(err, RiskError::GateBlocked { gate, .. } if gate == "market_hours"));
+    }
+
+    #[test]
+    fn test_check_all_price_jump() {
+        let snapshot_at = |px: Decimal| {
+            let bbo = Bbo::new(
+                Price::new(px - dec!(5)),
+                Size::new(dec!(1)),
+                Price::new(px + dec!(5)),
+                Size::new(dec!(1)),
+            );
+            let oracle = OracleData::new(Price::new(px), Price::new(px));
+            MarketSnapshot::new(bbo, AssetCtx::new(oracle, dec!(0.0001)))
+        };
+        let config = RiskGateConfig {
+            price_jump_sigma: dec!(4),
+            price_jump_min_samples: 10,
+            ..Default::default()
+        };
+        let mut gate = RiskGate::new(config);
+        let spec = MarketSpec::default();
+        let inputs_at = |now_ms| GateInputs {
+            now_ms,
+            ..Default::default()
+        };
+
+        for i in 0..20 {
+            let px = dec!(50000) + Decimal::from(i % 3) * dec!(5);
+            assert!(gate
+                .check_all(
+                    &snapshot_at(px),
+                    &spec,
+                    500,
+                    500,
+                    None,
+                    None,
+                    &inputs_at(i * 100)
+                )
+                .is_ok());
+        }
+        let err = gate
+            .check_all(
+                &snapshot_at(dec!(50300)),
+                &spec,
+                500,
+                500,
+                None,
+                None,
+                &inputs_at(2_000),
+            )
+            .unwrap_err();
+        assert!(matches!(err, RiskError::GateBlocked { gate, .. } if gate == "price_jump"));
+    }
+
     // === P0-2: Early Return tests ===
 
     /// P0-2: Verify EWMA is not updated when ctx is stale.
@@ -878,7 +1501,15 @@ mod tests {
         let spec = MarketSpec::default();
 
         // Valid call to initialize EWMA
-        let _ = gate.check_all(&snapshot, &spec, 500, 500, Some(1000), None);
+        let _ = gate.check_all(
+            &snapshot,
+            &spec,
+            500,
+            500,
+            Some(1000),
+            None,
+            &GateInputs::default(),
+        );
         let ewma_after_valid = gate.spread_ewma();
         assert!(
             ewma_after_valid > Decimal::ZERO,
@@ -886,7 +1517,15 @@ mod tests {
         );
 
         // Now try with stale ctx - should fail early
-        let result = gate.check_all(&snapshot, &spec, 500, 10000, Some(2000), None);
+        let result = gate.check_all(
+            &snapshot,
+            &spec,
+            500,
+            10000,
+            Some(2000),
+            None,
+            &GateInputs::default(),
+        );
         assert!(result.is_err(), "Should fail due to stale ctx");
 
         // EWMA should not have changed
@@ -906,11 +1545,27 @@ mod tests {
         let spec = MarketSpec::default();
 
         // Valid call to initialize EWMA
-        let _ = gate.check_all(&snapshot, &spec, 500, 500, Some(1000), None);
+        let _ = gate.check_all(
+            &snapshot,
+            &spec,
+            500,
+            500,
+            Some(1000),
+            None,
+            &GateInputs::default(),
+        );
         let ewma_after_valid = gate.spread_ewma();
 
         // Now try with stale BBO - should fail early
-        let result = gate.check_all(&snapshot, &spec, 5000, 500, Some(2000), None);
+        let result = gate.check_all(
+            &snapshot,
+            &spec,
+            5000,
+            500,
+            Some(2000),
+            None,
+            &GateInputs::default(),
+        );
         assert!(result.is_err(), "Should fail due to stale BBO");
 
         // EWMA should not have changed
@@ -931,7 +1586,15 @@ mod tests {
         let spec = MarketSpec::default();
 
         // Stale BBO should cause early return (first gate)
-        let result = gate.check_all(&snapshot, &spec, 5000, 500, None, None);
+        let result = gate.check_all(
+            &snapshot,
+            &spec,
+            5000,
+            500,
+            None,
+            None,
+            &GateInputs::default(),
+        );
 
         // Verify error is from bbo_update gate (first in order after BUG-002 fix)
         match result {
@@ -947,7 +1610,15 @@ mod tests {
         }
 
         // Stale ctx should cause early return (BBO OK, ctx stale)
-        let result2 = gate.check_all(&snapshot, &spec, 500, 10000, None, None);
+        let result2 = gate.check_all(
+            &snapshot,
+            &spec,
+            500,
+            10000,
+            None,
+            None,
+            &GateInputs::default(),
+        );
         match result2 {
             Err(RiskError::GateBlocked {
                 gate: gate_name, ..
@@ -958,8 +1629,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_gate_plan_order_and_disable() {
+        let config = RiskGateConfig {
+            gate_order: vec![GateId::Halt, GateId::BboUpdate, GateId::Halt],
+            disabled_gates: vec![GateId::CtxUpdate],
+            ..Default::default()
+        };
+        let plan = config.gate_plan();
+        assert_eq!(plan.len(), 11);
+        assert_eq!(
+            &plan[..3],
+            &[GateId::Halt, GateId::BboUpdate, GateId::TimeRegression]
+        );
+        assert!(!plan.contains(&GateId::CtxUpdate));
+
+        // Stale ctx no longer blocks
+        let mut gate = RiskGate::new(config);
+        let result = gate.check_all(
+            &test_snapshot(),
+            &MarketSpec::default(),
+            500,
+            10000,
+            None,
+            None,
+            &GateInputs::default(),
+        );
+        assert_eq!(result.unwrap().len(), 11);
+    }
+
     /// P0-2: Verify all gates pass with valid data.
-    /// BUG-002: oracle_fresh gate was removed; now 9 gates total (incl. time_of_day).
+    /// BUG-002: oracle_fresh gate was removed; now 12 gates total (incl. market_hours).
     #[test]
     fn test_all_gates_pass_with_valid_data() {
         let config = RiskGateConfig::default();
@@ -967,17 +1667,25 @@ mod tests {
         let snapshot = test_snapshot();
         let spec = MarketSpec::default();
 
-        let result = gate.check_all(&snapshot, &spec, 500, 500, Some(1000), None);
+        let result = gate.check_all(
+            &snapshot,
+            &spec,
+            500,
+            500,
+            Some(1000),
+            None,
+            &GateInputs::default(),
+        );
         assert!(result.is_ok(), "All gates should pass with valid data");
 
         let results = result.unwrap();
         assert_eq!(
             results.len(),
-            9,
-            "Should have 9 gate results (BUG-002: oracle_fresh removed, time_of_day added)"
+            12,
+            "Should have 12 gate results (BUG-002: oracle_fresh removed, market_hours added)"
         );
 
-        for r in &results {
+        for (_, r) in &results {
             assert!(!r.is_block(), "No gate should block with valid data");
         }
     }
@@ -1183,6 +1891,8 @@ fn default_max_weighted_positions() -> f64 {
 
 /// A single correlation group definition from config.
 ///
+/// Also defines the netting groups of `NetExposureGate`.
+///
 /// Example TOML:
 /// ```toml
 /// [[correlation_position.groups]]
@@ -1200,6 +1910,14 @@ pub struct CorrelationGroupDef {
     /// Default: 1.5
     #[serde(default = "default_correlation_weight")]
     pub weight: f64,
+    /// Share of opposite-side notional that offsets within the group when
+    /// netting (0..=1). Default: 1
+    #[serde(default = "default_netting_offset")]
+    pub offset: Decimal,
+}
+
+fn default_netting_offset() -> Decimal {
+    Decimal::ONE
 }
 
 /// P3-3: Configuration for correlation-weighted position limits.
@@ -1233,7 +1951,7 @@ impl Default for CorrelationPositionConfig {
 /// A resolved correlation group with `MarketKey`s (runtime representation).
 ///
 /// Created at startup by resolving coin names from config to `MarketKey`s.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ResolvedCorrelationGroup {
     /// Group name.
     pub name: String,
@@ -1241,6 +1959,8 @@ pub struct ResolvedCorrelationGroup {
     pub markets: HashSet<MarketKey>,
     /// Correlation weight.
     pub weight: Decimal,
+    /// Share of opposite-side notional that offsets when netting.
+    pub offset: Decimal,
 }
 
 /// P3-3: Correlation-weighted position limit gate.
@@ -1591,12 +2311,70 @@ pub struct MaxDrawdownConfig {
     /// Set to 0 to disable this gate.
     #[serde(default)]
     pub max_hourly_drawdown_usd: f64,
+    /// Maximum loss per UTC day in USD. Latches until the next UTC day.
+    /// Set to 0 to disable.
+    #[serde(default)]
+    pub max_daily_loss_usd: f64,
+    /// Maximum loss per UTC week (Monday start) in USD. Latches until
+    /// cleared manually. Set to 0 to disable.
+    #[serde(default)]
+    pub max_weekly_loss_usd: f64,
 }
 
 impl Default for MaxDrawdownConfig {
     fn default() -> Self {
         Self {
             max_hourly_drawdown_usd: 0.0, // Disabled by default
+            max_daily_loss_usd: 0.0,
+            max_weekly_loss_usd: 0.0,
+        }
+    }
+}
+
+/// Realized PnL and latch state of the daily and weekly loss limits.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LossLimitStatus {
+    /// Realized PnL of the current UTC day (USD).
+    pub daily_pnl_usd: f64,
+    /// Realized PnL of the current UTC week (USD).
+    pub weekly_pnl_usd: f64,
+    /// Daily limit hit; entries paused until the next UTC day.
+    pub daily_latched: bool,
+    /// Weekly limit hit; entries paused until cleared manually.
+    pub weekly_latched: bool,
+}
+
+/// Daily/weekly accumulation periods.
+#[derive(Debug, Default)]
+struct LossPeriods {
+    /// UTC day index (days since epoch).
+    day: u64,
+    /// UTC week index (weeks since the Monday before the epoch).
+    week: u64,
+    status: LossLimitStatus,
+}
+
+const DAY_MS: u64 = 86_400_000;
+
+impl LossPeriods {
+    /// Start a new day/week if `now_ms` is past the current one.
+    ///
+    /// The daily latch ends with its day; the weekly latch outlives its week.
+    fn roll(&mut self, now_ms: u64) {
+        let day = now_ms / DAY_MS;
+        if day != self.day {
+            self.day = day;
+            self.status.daily_pnl_usd = 0.0;
+            if self.status.daily_latched {
+                info!("MaxDrawdownGate: daily loss limit reset for new UTC day");
+            }
+            self.status.daily_latched = false;
+        }
+        // 1970-01-01 was a Thursday: shift so weeks start on Monday
+        let week = (day + 3) / 7;
+        if week != self.week {
+            self.week = week;
+            self.status.weekly_pnl_usd = 0.0;
         }
     }
 }
@@ -1607,6 +2385,10 @@ impl Default for MaxDrawdownConfig {
 /// the configured threshold, new entries are blocked while existing position
 /// management continues.
 ///
+/// Also enforces daily (UTC day) and weekly (UTC week) loss limits. Unlike
+/// the rolling window these latch: a daily breach pauses entries until the
+/// next UTC day, a weekly breach until [`MaxDrawdownGate::clear_weekly_latch`].
+///
 /// Thread-safe: Uses AtomicI64 for PnL tracking (units = cents, i.e. USD * 100).
 pub struct MaxDrawdownGate {
     config: MaxDrawdownConfig,
@@ -1617,6 +2399,8 @@ pub struct MaxDrawdownGate {
     window_start_ms: std::sync::atomic::AtomicU64,
     /// Window duration in milliseconds (1 hour = 3_600_000).
     window_duration_ms: u64,
+    /// Daily/weekly loss limit state.
+    periods: parking_lot::Mutex<LossPeriods>,
 }
 
 impl MaxDrawdownGate {
@@ -1629,6 +2413,7 @@ impl MaxDrawdownGate {
             cumulative_pnl_cents: std::sync::atomic::AtomicI64::new(0),
             window_start_ms: std::sync::atomic::AtomicU64::new(now_ms),
             window_duration_ms: 3_600_000, // 1 hour
+            periods: parking_lot::Mutex::new(LossPeriods::default()),
         }
     }
 
@@ -1637,17 +2422,72 @@ impl MaxDrawdownGate {
     /// # Arguments
     /// - `pnl_usd`: PnL in USD (positive = profit, negative = loss)
     pub fn report_pnl(&self, pnl_usd: f64) {
+        self.report_pnl_at(pnl_usd, chrono::Utc::now().timestamp_millis() as u64);
+    }
+
+    /// Report a realized PnL at `now_ms` (Unix ms).
+    pub fn report_pnl_at(&self, pnl_usd: f64, now_ms: u64) {
         self.maybe_reset_window();
         let pnl_cents = (pnl_usd * 100.0) as i64;
         self.cumulative_pnl_cents
             .fetch_add(pnl_cents, std::sync::atomic::Ordering::Relaxed);
+
+        let mut periods = self.periods.lock();
+        periods.roll(now_ms);
+        let status = &mut periods.status;
+        status.daily_pnl_usd += pnl_usd;
+        status.weekly_pnl_usd += pnl_usd;
+
+        let daily_limit = self.config.max_daily_loss_usd;
+        if daily_limit > 0.0 && !status.daily_latched && status.daily_pnl_usd <= -daily_limit {
+            status.daily_latched = true;
+            warn!(
+                daily_pnl_usd = status.daily_pnl_usd,
+                limit_usd = daily_limit,
+                "MaxDrawdownGate: daily loss limit hit, entries paused until next UTC day"
+            );
+        }
+        let weekly_limit = self.config.max_weekly_loss_usd;
+        if weekly_limit > 0.0 && !status.weekly_latched && status.weekly_pnl_usd <= -weekly_limit {
+            status.weekly_latched = true;
+            warn!(
+                weekly_pnl_usd = status.weekly_pnl_usd,
+                limit_usd = weekly_limit,
+                "MaxDrawdownGate: weekly loss limit hit, entries paused until cleared"
+            );
+        }
     }
 
     /// Check if new entries should be blocked due to drawdown.
     ///
-    /// Returns `Ok(())` if allowed, `Err(RejectReason::MaxDrawdown)` if blocked.
+    /// Returns `Ok(())` if allowed, or `Err` with `WeeklyLossLimit`,
+    /// `DailyLossLimit` or `MaxDrawdown` (in that order) if blocked.
     pub fn check(&self) -> Result<(), RejectReason> {
-        // Gate disabled when threshold is 0
+        self.check_at(chrono::Utc::now().timestamp_millis() as u64)
+    }
+
+    /// Check if new entries should be blocked at `now_ms` (Unix ms).
+    pub fn check_at(&self, now_ms: u64) -> Result<(), RejectReason> {
+        if self.config.max_daily_loss_usd > 0.0 || self.config.max_weekly_loss_usd > 0.0 {
+            let mut periods = self.periods.lock();
+            periods.roll(now_ms);
+            if periods.status.weekly_latched {
+                debug!(
+                    weekly_pnl_usd = periods.status.weekly_pnl_usd,
+                    "MaxDrawdownGate blocked: weekly loss limit latched"
+                );
+                return Err(RejectReason::WeeklyLossLimit);
+            }
+            if periods.status.daily_latched {
+                debug!(
+                    daily_pnl_usd = periods.status.daily_pnl_usd,
+                    "MaxDrawdownGate blocked: daily loss limit latched"
+                );
+                return Err(RejectReason::DailyLossLimit);
+            }
+        }
+
+        // Hourly gate disabled when threshold is 0
         if self.config.max_hourly_drawdown_usd <= 0.0 {
             return Ok(());
         }
@@ -1706,10 +2546,55 @@ impl MaxDrawdownGate {
         cents as f64 / 100.0
     }
 
+    /// Daily/weekly loss limit state.
+    #[must_use]
+    pub fn loss_limit_status(&self) -> LossLimitStatus {
+        let mut periods = self.periods.lock();
+        periods.roll(chrono::Utc::now().timestamp_millis() as u64);
+        periods.status
+    }
+
+    /// Restore daily/weekly loss limit state saved at `saved_at_ms` (Unix
+    /// ms), e.g. by a previous run.
+    ///
+    /// The saved day and week roll over as usual: a daily latch from an
+    /// earlier day ends, a weekly latch holds until cleared.
+    pub fn restore_loss_limits(&self, status: LossLimitStatus, saved_at_ms: u64) {
+        let mut periods = self.periods.lock();
+        let day = saved_at_ms / DAY_MS;
+        periods.day = day;
+        periods.week = (day + 3) / 7;
+        periods.status = status;
+        if status.daily_latched || status.weekly_latched {
+            info!(
+                daily_latched = status.daily_latched,
+                weekly_latched = status.weekly_latched,
+                "MaxDrawdownGate: restored latched loss limit"
+            );
+        }
+    }
+
+    /// Clear the weekly loss latch (manual operator action).
+    ///
+    /// Also resets the weekly PnL so the next loss does not re-latch at once.
+    pub fn clear_weekly_latch(&self) {
+        let mut periods = self.periods.lock();
+        if periods.status.weekly_latched {
+            info!(
+                weekly_pnl_usd = periods.status.weekly_pnl_usd,
+                "MaxDrawdownGate: weekly loss limit cleared"
+            );
+        }
+        periods.status.weekly_latched = false;
+        periods.status.weekly_pnl_usd = 0.0;
+    }
+
     /// Check if the gate is enabled.
     #[must_use]
     pub fn is_enabled(&self) -> bool {
         self.config.max_hourly_drawdown_usd > 0.0
+            || self.config.max_daily_loss_usd > 0.0
+            || self.config.max_weekly_loss_usd > 0.0
     }
 }
 
@@ -2265,6 +3150,7 @@ mod drawdown_tests {
     fn test_drawdown_gate_blocks_on_loss() {
         let config = MaxDrawdownConfig {
             max_hourly_drawdown_usd: 10.0,
+            ..Default::default()
         };
         let gate = MaxDrawdownGate::new(config);
 
@@ -2281,6 +3167,7 @@ mod drawdown_tests {
     fn test_drawdown_gate_profits_offset_losses() {
         let config = MaxDrawdownConfig {
             max_hourly_drawdown_usd: 10.0,
+            ..Default::default()
         };
         let gate = MaxDrawdownGate::new(config);
 
@@ -2301,6 +3188,7 @@ mod drawdown_tests {
     fn test_drawdown_gate_cumulative_pnl() {
         let config = MaxDrawdownConfig {
             max_hourly_drawdown_usd: 10.0,
+            ..Default::default()
         };
         let gate = MaxDrawdownGate::new(config);
 
@@ -2308,6 +3196,98 @@ mod drawdown_tests {
         gate.report_pnl(1.0);
         assert!((gate.cumulative_pnl_usd() - (-2.5)).abs() < 0.01);
     }
+
+    #[test]
+    fn test_daily_loss_limit_latches_until_next_day() {
+        let config = MaxDrawdownConfig {
+            max_daily_loss_usd: 20.0,
+            ..Default::default()
+        };
+        let gate = MaxDrawdownGate::new(config);
+        assert!(gate.is_enabled());
+        // 2024-01-03 (Wednesday) 10:00 UTC
+        let day_ms = 1_704_276_000_000;
+
+        gate.report_pnl_at(-25.0, day_ms);
+        assert_eq!(gate.check_at(day_ms), Err(RejectReason::DailyLossLimit));
+        // Profits later in the day do not release the latch
+        gate.report_pnl_at(30.0, day_ms + 1_000);
+        assert_eq!(
+            gate.check_at(day_ms + 2_000),
+            Err(RejectReason::DailyLossLimit)
+        );
+
+        // Next UTC day
+        assert!(gate.check_at(day_ms + DAY_MS).is_ok());
+    }
+
+    #[test]
+    fn test_weekly_loss_limit_requires_manual_clear() {
+        let config = MaxDrawdownConfig {
+            max_weekly_loss_usd: 50.0,
+            ..Default::default()
+        };
+        let gate = MaxDrawdownGate::new(config);
+        // 2024-01-03 (Wednesday) 10:00 UTC
+        let wed_ms = 1_704_276_000_000;
+
+        gate.report_pnl_at(-30.0, wed_ms);
+        gate.report_pnl_at(-30.0, wed_ms + DAY_MS);
+        assert_eq!(
+            gate.check_at(wed_ms + DAY_MS),
+            Err(RejectReason::WeeklyLossLimit)
+        );
+
+        // Still latched in the following week
+        assert_eq!(
+            gate.check_at(wed_ms + 7 * DAY_MS),
+            Err(RejectReason::WeeklyLossLimit)
+        );
+
+        gate.clear_weekly_latch();
+        assert!(gate.check_at(wed_ms + 7 * DAY_MS).is_ok());
+    }
+
+    #[test]
+    fn test_loss_limits_survive_restore() {
+        let config = MaxDrawdownConfig {
+            max_daily_loss_usd: 20.0,
+            max_weekly_loss_usd: 50.0,
+            ..Default::default()
+        };
+        // 2024-01-03 (Wednesday) 10:00 UTC
+        let wed_ms = 1_704_276_000_000;
+        let gate = MaxDrawdownGate::new(config.clone());
+        gate.report_pnl_at(-25.0, wed_ms);
+        let saved = gate.periods.lock().status;
+
+        // Daily latch restored the same day, ends the next day
+        let restarted = MaxDrawdownGate::new(config.clone());
+        restarted.restore_loss_limits(saved, wed_ms);
+        assert_eq!(
+            restarted.check_at(wed_ms + 1_000),
+            Err(RejectReason::DailyLossLimit)
+        );
+        assert!(restarted.check_at(wed_ms + DAY_MS).is_ok());
+
+        // The day's loss still counts toward the week after a restart
+        let restarted = MaxDrawdownGate::new(config.clone());
+        restarted.restore_loss_limits(saved, wed_ms);
+        restarted.report_pnl_at(-30.0, wed_ms + DAY_MS);
+        assert_eq!(
+            restarted.check_at(wed_ms + DAY_MS),
+            Err(RejectReason::WeeklyLossLimit)
+        );
+
+        // Weekly latch restored in a later week still holds
+        let weekly = restarted.periods.lock().status;
+        let restarted = MaxDrawdownGate::new(config);
+        restarted.restore_loss_limits(weekly, wed_ms + DAY_MS);
+        assert_eq!(
+            restarted.check_at(wed_ms + 14 * DAY_MS),
+            Err(RejectReason::WeeklyLossLimit)
+        );
+    }
 }
 
 #[cfg(test)]
@@ -2363,6 +3343,7 @@ mod correlation_position_tests {
             name: name.to_string(),
             markets: indices.iter().map(|&i| market(i)).collect(),
             weight,
+            offset: Decimal::ONE,
         }
     }
 
@@ -0,0 +1,413 @@
+//! Latency degradation gate.
+//!
+//! The edge of a signal decays within milliseconds; once the bot gets slow,
+//! its fills are the ones faster traders left behind. [`LatencyGate`] keeps
+//! the recent signal-to-order and WebSocket post round-trip samples (the
+//! same observations fed to the telemetry latency histograms) and, while the
+//! p95 of either exceeds its limit, blocks new entries and reports a spread
+//! multiplier for MM quotes.
+//!
+//! p95 is only evaluated once `min_samples` samples fall in the window, so a
+//! single slow response cannot trip the gate.
+
+use std::collections::VecDeque;
+
+use hip3_core::RejectReason;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+/// Configuration for the latency degradation gate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyGateConfig {
+    /// Enable the gate. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Block entries while signal-to-order p95 exceeds this (ms).
+    /// 0 = not checked. Default: 50.
+    #[serde(default = "default_max_signal_to_order_p95_ms")]
+    pub max_signal_to_order_p95_ms: f64,
+
+    /// Block entries while WebSocket post round-trip p95 exceeds this (ms).
+    /// 0 = not checked. Default: 500.
+    #[serde(default = "default_max_ws_round_trip_p95_ms")]
+    pub max_ws_round_trip_p95_ms: f64,
+
+    /// Samples older than this are dropped (seconds). Default: 60.
+    #[serde(default = "default_window_secs")]
+    pub window_secs: u64,
+
+    /// Minimum samples in the window before p95 is evaluated. Default: 20.
+    #[serde(default = "default_min_samples")]
+    pub min_samples: usize,
+
+    /// MM spread multiplier while degraded (1 = no widening). Default: 1.
+    #[serde(default = "default_mm_spread_multiplier")]
+    pub mm_spread_multiplier: Decimal,
+}
+
+fn default_max_signal_to_order_p95_ms() -> f64 {
+    50.0
+}
+
+fn default_max_ws_round_trip_p95_ms() -> f64 {
+    500.0
+}
+
+fn default_window_secs() -> u64 {
+    60
+}
+
+fn default_min_samples() -> usize {
+    20
+}
+
+fn default_mm_spread_multiplier() -> Decimal {
+    Decimal::ONE
+}
+
+impl Default for LatencyGateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_signal_to_order_p95_ms: default_max_signal_to_order_p95_ms(),
+            max_ws_round_trip_p95_ms: default_max_ws_round_trip_p95_ms(),
+            window_secs: default_window_secs(),
+            min_samples: default_min_samples(),
+            mm_spread_multiplier: default_mm_spread_multiplier(),
+        }
+    }
+}
+
+/// Latency measured by the gate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatencyKind {
+    /// Signal detection to order submission.
+    SignalToOrder,
+    /// WebSocket post sent to its response received.
+    WsRoundTrip,
+}
+
+impl LatencyKind {
+    /// Both kinds.
+    pub const ALL: [Self; 2] = [Self::SignalToOrder, Self::WsRoundTrip];
+
+    /// Label for logs and metrics.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::SignalToOrder => "signal_to_order",
+            Self::WsRoundTrip => "ws_round_trip",
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Samples in the window and the last evaluated state.
+#[derive(Debug, Default)]
+struct LatencyState {
+    /// (time Unix ms, latency ms) per [`LatencyKind`].
+    samples: [VecDeque<(u64, f64)>; 2],
+    /// Whether the last evaluation was degraded.
+    degraded: bool,
+}
+
+/// Blocks new entries while p95 latency is above its limit.
+pub struct LatencyGate {
+    config: LatencyGateConfig,
+    state: Mutex<LatencyState>,
+}
+
+impl LatencyGate {
+    /// Create a new gate.
+    #[must_use]
+    pub fn new(config: LatencyGateConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(LatencyState::default()),
+        }
+    }
+
+    /// Check if the gate is enabled.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Gate configuration.
+    #[must_use]
+    pub fn config(&self) -> &LatencyGateConfig {
+        &self.config
+    }
+
+    /// Record a latency sample.
+    pub fn record(&self, kind: LatencyKind, latency_ms: f64, now_ms: u64) {
+        if !latency_ms.is_finite() || latency_ms < 0.0 {
+            return;
+        }
+        let mut state = self.state.lock();
+        let samples = &mut state.samples[kind.index()];
+        samples.push_back((now_ms, latency_ms));
+        Self::prune(samples, now_ms, self.config.window_secs * 1000);
+    }
+
+    /// p95 of `kind` over the window, once `min_samples` are in it.
+    #[must_use]
+    pub fn p95(&self, kind: LatencyKind, now_ms: u64) -> Option<f64> {
+        let mut state = self.state.lock();
+        self.p95_locked(&mut state, kind, now_ms)
+    }
+
+    /// Check if new entries are allowed.
+    pub fn check(&self) -> Result<(), RejectReason> {
+        self.check_at(chrono::Utc::now().timestamp_millis() as u64)
+    }
+
+    /// Check if new entries are allowed at `now_ms` (Unix ms).
+    pub fn check_at(&self, now_ms: u64) -> Result<(), RejectReason> {
+        if self.is_degraded_at(now_ms) {
+            return Err(RejectReason::LatencyDegraded);
+        }
+        Ok(())
+    }
+
+    /// Spread multiplier for MM quotes at `now_ms` (1 when not degraded).
+    #[must_use]
+    pub fn spread_multiplier_at(&self, now_ms: u64) -> Decimal {
+        if self.is_degraded_at(now_ms) {
+            self.config.mm_spread_multiplier.max(Decimal::ONE)
+        } else {
+            Decimal::ONE
+        }
+    }
+
+    /// Evaluate the gate at `now_ms`, logging state changes.
+    #[must_use]
+    pub fn is_degraded_at(&self, now_ms: u64) -> bool {
+        if !self.is_enabled() {
+            return false;
+        }
+        let mut state = self.state.lock();
+        let mut breach = None;
+        for kind in LatencyKind::ALL {
+            let limit = self.limit(kind);
+            if limit <= 0.0 {
+                continue;
+            }
+            if let Some(p95) = self.p95_locked(&mut state, kind, now_ms) {
+                if p95 > limit {
+                    breach = Some((kind, p95, limit));
+                    break;
+                }
+            }
+        }
+
+        let degraded = breach.is_some();
+        if degraded != state.degraded {
+            state.degraded = degraded;
+            match breach {
+                Some((kind, p95, limit)) => warn!(
+                    kind = kind.as_str(),
+                    p95_ms = p95,
+                    limit_ms = limit,
+                    "LatencyGate: p95 latency degraded, entries paused"
+                ),
+                None => info!("LatencyGate: latency recovered, entries resumed"),
+            }
+        } else if let Some((kind, p95, _)) = breach {
+            debug!(
+                kind = kind.as_str(),
+                p95_ms = p95,
+                "LatencyGate blocked: p95 latency degraded"
+            );
+        }
+        degraded
+    }
+
+    fn limit(&self, kind: LatencyKind) -> f64 {
+        match kind {
+            LatencyKind::SignalToOrder => self.config.max_signal_to_order_p95_ms,
+            LatencyKind::WsRoundTrip => self.config.max_ws_round_trip_p95_ms,
+        }
+    }
+
+    fn p95_locked(&self, state: &mut LatencyState, kind: LatencyKind, now_ms: u64) -> Option<f64> {
+        let samples = &mut state.samples[kind.index()];
+        Self::prune(samples, now_ms, self.config.window_secs * 1000);
+        if samples.is_empty() || samples.len() < self.config.min_samples {
+            return None;
+        }
+        let mut sorted: Vec<f64> = samples.iter().map(|&(_, ms)| ms).collect();
+        sorted.sort_by(f64::total_cmp);
+        // Nearest-rank p95
+        let rank = (sorted.len() * 95).div_ceil(100);
+        Some(sorted[rank.saturating_sub(1)])
+    }
+
+    fn prune(samples: &mut VecDeque<(u64, f64)>, now_ms: u64, window_ms: u64) {
+        while samples
+            .front()
+            .is_some_and(|&(at, _)| now_ms.saturating_sub(at) > window_ms)
+        {
+            samples.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_blocks_while_p95_above_limit() {
+        let gate = LatencyGate::new(LatencyGateConfig {
+            enabled: true,
+            max_signal_to_order_p95_ms: 50.0,
+            window_secs: 10,
+            min_samples: 20,
+            mm_spread_multiplier: dec!(2),
+            ..Default::default()
+        });
+
+        // 19 fast samples and a slow one: p95 is still fast
+        for i in 0..19 {
+            gate.record(LatencyKind::SignalToOrder, 10.0, i);
+        }
+        gate.record(LatencyKind::SignalToOrder, 400.0, 19);
+        assert_eq!(gate.p95(LatencyKind::SignalToOrder, 19), Some(10.0));
+        assert!(gate.check_at(19).is_ok());
+
+        // A second slow sample reaches the p95 rank
+        gate.record(LatencyKind::SignalToOrder, 300.0, 20);
+        assert_eq!(gate.check_at(20), Err(RejectReason::LatencyDegraded));
+        assert_eq!(gate.spread_multiplier_at(20), dec!(2));
+
+        // The slow samples age out of the window; too few samples remain
+        assert!(gate.check_at(10_020).is_ok());
+        assert_eq!(gate.spread_multiplier_at(10_020), Decimal::ONE);
+    }
+
+    fn enabled_config() -> LatencyGateConfig {
+        LatencyGateConfig {
+            enabled: true,
+            max_signal_to_order_p95_ms: 50.0,
+            max_ws_round_trip_p95_ms: 500.0,
+            window_secs: 10,
+            min_samples: 20,
+            mm_spread_multiplier: dec!(2),
+        }
+    }
+
+    #[test]
+    fn test_p95_only_counts_samples_in_window() {
+        let gate = LatencyGate::new(enabled_config());
+
+        // 20 slow samples at t=0, then 20 fast ones at t=5s
+        for i in 0..20 {
+            gate.record(LatencyKind::WsRoundTrip, 900.0, i);
+        }
+        for i in 0..20 {
+            gate.record(LatencyKind::WsRoundTrip, 100.0, 5_000 + i);
+        }
+        assert_eq!(gate.p95(LatencyKind::WsRoundTrip, 5_019), Some(900.0));
+
+        // Once the slow batch leaves the 10s window only the fast one counts
+        assert_eq!(gate.p95(LatencyKind::WsRoundTrip, 10_100), Some(100.0));
+        assert!(gate.check_at(10_100).is_ok());
+
+        // Kinds are windowed separately
+        assert_eq!(gate.p95(LatencyKind::SignalToOrder, 10_100), None);
+    }
+
+    #[test]
+    fn test_unblocks_when_p95_falls_below_limit() {
+        let gate = LatencyGate::new(enabled_config());
+
+        for i in 0..20 {
+            gate.record(LatencyKind::SignalToOrder, 80.0, i);
+        }
+        assert_eq!(gate.check_at(20), Err(RejectReason::LatencyDegraded));
+        assert!(gate.is_degraded_at(20));
+
+        // Fast samples push the slow ones below the p95 rank while they are
+        // still in the window
+        for i in 0..400 {
+            gate.record(LatencyKind::SignalToOrder, 20.0, 100 + i);
+        }
+        assert_eq!(gate.p95(LatencyKind::SignalToOrder, 500), Some(20.0));
+        assert!(gate.check_at(500).is_ok());
+        assert!(!gate.is_degraded_at(500));
+
+        // And it blocks again when latency degrades again
+        for i in 0..100 {
+            gate.record(LatencyKind::SignalToOrder, 120.0, 600 + i);
+        }
+        assert_eq!(gate.check_at(700), Err(RejectReason::LatencyDegraded));
+    }
+
+    #[test]
+    fn test_spread_multiplier_at() {
+        let gate = LatencyGate::new(enabled_config());
+        assert_eq!(gate.spread_multiplier_at(0), Decimal::ONE);
+
+        for i in 0..20 {
+            gate.record(LatencyKind::WsRoundTrip, 800.0, i);
+        }
+        assert_eq!(gate.spread_multiplier_at(20), dec!(2));
+
+        // A multiplier below 1 never narrows quotes
+        let gate = LatencyGate::new(LatencyGateConfig {
+            mm_spread_multiplier: dec!(0.5),
+            ..enabled_config()
+        });
+        for i in 0..20 {
+            gate.record(LatencyKind::WsRoundTrip, 800.0, i);
+        }
+        assert_eq!(gate.spread_multiplier_at(20), Decimal::ONE);
+
+        // Disabled: never degraded
+        let gate = LatencyGate::new(LatencyGateConfig {
+            enabled: false,
+            ..enabled_config()
+        });
+        for i in 0..20 {
+            gate.record(LatencyKind::WsRoundTrip, 800.0, i);
+        }
+        assert_eq!(gate.spread_multiplier_at(20), Decimal::ONE);
+        assert!(gate.check_at(20).is_ok());
+    }
+
+    #[test]
+    fn test_empty_or_too_few_samples() {
+        let gate = LatencyGate::new(enabled_config());
+        assert_eq!(gate.p95(LatencyKind::SignalToOrder, 0), None);
+        assert!(gate.check_at(0).is_ok());
+
+        // One short of min_samples, all slow
+        for i in 0..19 {
+            gate.record(LatencyKind::SignalToOrder, 1_000.0, i);
+        }
+        assert_eq!(gate.p95(LatencyKind::SignalToOrder, 19), None);
+        assert!(gate.check_at(19).is_ok());
+
+        // Invalid samples are not counted
+        gate.record(LatencyKind::SignalToOrder, f64::NAN, 19);
+        gate.record(LatencyKind::SignalToOrder, -1.0, 19);
+        assert_eq!(gate.p95(LatencyKind::SignalToOrder, 19), None);
+
+        // min_samples = 0 still needs a sample
+        let gate = LatencyGate::new(LatencyGateConfig {
+            min_samples: 0,
+            ..enabled_config()
+        });
+        assert_eq!(gate.p95(LatencyKind::WsRoundTrip, 0), None);
+        gate.record(LatencyKind::WsRoundTrip, 700.0, 0);
+        assert_eq!(gate.p95(LatencyKind::WsRoundTrip, 0), Some(700.0));
+    }
+}
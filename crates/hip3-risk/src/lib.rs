@@ -14,21 +14,48 @@
 //! Also provides:
 //! - HardStopLatch: Emergency stop mechanism
 //! - RiskMonitor: Execution event monitoring for risk violations
+//! - LiquidationMonitor: Liquidation distance and emergency de-risk selection
+//! - NetExposureGate: Net exposure limits per underlying/correlation group
+//! - EquityDrawdownGate: Account equity drawdown from the session high-water mark
+//! - RiskBudgetGate: Per-market shares of the notional limit and daily loss budget
+//! - LatencyGate: Entry block while p95 signal-to-order or WS round-trip latency degrades
+//! - OpenOrderGate: New order block on open order count or cancel failures per market
+//! - RejectBreaker: Per-market trading disable after repeated exchange rejections
 
+pub mod budget;
+pub mod equity;
 pub mod error;
 pub mod gates;
 pub mod hard_stop;
+pub mod latency;
+pub mod liquidation;
 pub mod market_health;
+pub mod netting;
+pub mod open_orders;
+pub mod reject_breaker;
 
+pub use budget::{
+    MarketBudget, MarketBudgetDef, MarketDailyLoss, RiskBudgetConfig, RiskBudgetGate,
+};
+pub use equity::{EquityDrawdownConfig, EquityDrawdownGate, EquityStatus};
 pub use error::{RiskError, RiskResult};
 pub use gates::{
     BlackoutWindow, BurstSignalConfig, BurstSignalGate, CorrelationCooldownConfig,
-    CorrelationCooldownGate, CorrelationPositionConfig, CorrelationPositionGate, GateResult,
-    MaxDrawdownConfig, MaxDrawdownGate, MaxPositionPerMarketGate, MaxPositionTotalGate,
+    CorrelationCooldownGate, CorrelationGroupDef, CorrelationPositionConfig,
+    CorrelationPositionGate, GateId, GateInputs, GateResult, LossLimitStatus, MaxDrawdownConfig,
+    MaxDrawdownGate, MaxPositionPerMarketGate, MaxPositionTotalGate, ObservedGateBlock,
     ReEntryDelayConfig, ReEntryDelayGate, ResolvedCorrelationGroup, RiskGate, RiskGateConfig,
     TiltGuardConfig, TiltGuardGate,
 };
 pub use hard_stop::{
     ExecutionEvent, HardStopLatch, HardStopReason, RiskMonitor, RiskMonitorConfig,
 };
+pub use latency::{LatencyGate, LatencyGateConfig, LatencyKind};
+pub use liquidation::{
+    AccountMargin, LiquidationConfig, LiquidationMonitor, LiquidationMonitorHandle,
+    LiquidationStop, PositionMargin,
+};
 pub use market_health::{MarketHealthConfig, MarketHealthTracker, TradeOutcome};
+pub use netting::{net_exposure, GroupExposure, NetExposure, NetExposureGate, NettingConfig};
+pub use open_orders::{OpenOrderGate, OpenOrderGateConfig};
+pub use reject_breaker::{RejectBreaker, RejectBreakerConfig};
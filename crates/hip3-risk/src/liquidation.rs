@@ -0,0 +1,541 @@
+//! Liquidation distance monitor and emergency de-risk.
+//!
+//! Computes, from clearinghouseState margin data, how far each account is
+//! from liquidation (the share of account value above the maintenance
+//! margin) and how far each position's mark is from its liquidation price.
+//!
+//! When an account's buffer falls below `min_buffer_ratio`, its largest
+//! losing positions are selected for de-risking; a position closer to its
+//! liquidation price than `min_distance_bps` is selected on its own. The
+//! selection is flattened by [`LiquidationStop`], an exit strategy run by
+//! `ExitStrategyWatcher`, before the exchange liquidates it.
+//!
+//! Margin data is taken from each position resync and carried forward in
+//! between by mark updates and fills, so the selection follows the market
+//! rather than the resync cadence. A fill that grows a position drops its
+//! liquidation price until the next resync (the account buffer still
+//! applies). Only markets newly selected are reported.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use hip3_core::types::MarketSnapshot;
+use hip3_core::{MarketKey, OrderSide};
+use hip3_position::{ExitStrategy, FlattenReason, FlattenRequest, Position};
+use parking_lot::RwLock;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the liquidation monitor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidationConfig {
+    /// Enable emergency de-risking. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// De-risk an account when (account value - maintenance margin) /
+    /// account value falls below this. Default: 0.3.
+    #[serde(default = "default_min_buffer_ratio")]
+    pub min_buffer_ratio: Decimal,
+
+    /// De-risk a position whose mark is closer than this to its
+    /// liquidation price (bps). Default: 300.
+    #[serde(default = "default_min_distance_bps")]
+    pub min_distance_bps: Decimal,
+
+    /// Losing positions de-risked per account on a low buffer. Default: 1.
+    #[serde(default = "default_max_derisk_positions")]
+    pub max_derisk_positions: usize,
+}
+
+fn default_min_buffer_ratio() -> Decimal {
+    Decimal::new(3, 1) // 0.3
+}
+
+fn default_min_distance_bps() -> Decimal {
+    Decimal::from(300)
+}
+
+fn default_max_derisk_positions() -> usize {
+    1
+}
+
+impl Default for LiquidationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_buffer_ratio: default_min_buffer_ratio(),
+            min_distance_bps: default_min_distance_bps(),
+            max_derisk_positions: default_max_derisk_positions(),
+        }
+    }
+}
+
+/// Margin state of one position.
+#[derive(Debug, Clone)]
+pub struct PositionMargin {
+    /// Market of the position.
+    pub market: MarketKey,
+    /// Position side.
+    pub side: OrderSide,
+    /// Position size (absolute).
+    pub size: Decimal,
+    /// Mark price.
+    pub mark_px: Decimal,
+    /// Liquidation price (None if the position cannot be liquidated).
+    pub liquidation_px: Option<Decimal>,
+    /// Unrealized PnL (USD).
+    pub unrealized_pnl: Decimal,
+}
+
+impl PositionMargin {
+    fn signed_size(&self) -> Decimal {
+        match self.side {
+            OrderSide::Buy => self.size,
+            OrderSide::Sell => -self.size,
+        }
+    }
+
+    /// Distance from mark to the liquidation price (bps).
+    ///
+    /// Zero once the mark is at or beyond the liquidation price.
+    #[must_use]
+    pub fn distance_bps(&self) -> Option<Decimal> {
+        let liq = self.liquidation_px.filter(|px| *px > Decimal::ZERO)?;
+        if self.mark_px.is_zero() {
+            return None;
+        }
+        let distance = match self.side {
+            OrderSide::Buy => self.mark_px - liq,
+            OrderSide::Sell => liq - self.mark_px,
+        };
+        Some((distance / self.mark_px * Decimal::from(10000)).max(Decimal::ZERO))
+    }
+}
+
+/// Margin state of one trading account.
+#[derive(Debug, Clone)]
+pub struct AccountMargin {
+    /// Account address.
+    pub account: String,
+    /// Account value (USD).
+    pub account_value: Decimal,
+    /// Maintenance margin in use (USD).
+    pub maintenance_margin: Decimal,
+    /// Open positions of the account.
+    pub positions: Vec<PositionMargin>,
+}
+
+impl AccountMargin {
+    /// Share of account value above the maintenance margin.
+    ///
+    /// None for an empty account.
+    #[must_use]
+    pub fn buffer_ratio(&self) -> Option<Decimal> {
+        (self.account_value > Decimal::ZERO)
+            .then(|| (self.account_value - self.maintenance_margin) / self.account_value)
+    }
+
+    fn notional(&self) -> Decimal {
+        self.positions.iter().map(|p| p.size * p.mark_px).sum()
+    }
+
+    /// Rescale the maintenance margin to the position notional after `f`
+    /// changed positions (margin per unit of notional is kept).
+    fn with_margin_rescaled(&mut self, f: impl FnOnce(&mut Self)) {
+        let before = self.notional();
+        f(self);
+        if before > Decimal::ZERO {
+            self.maintenance_margin = self.maintenance_margin * self.notional() / before;
+        }
+    }
+
+    /// Reprice the position in `market` at a new mark.
+    fn apply_mark(&mut self, market: &MarketKey, mark_px: Decimal) {
+        self.with_margin_rescaled(|account| {
+            let mut delta = Decimal::ZERO;
+            for position in account.positions.iter_mut().filter(|p| p.market == *market) {
+                let pnl = (mark_px - position.mark_px) * position.signed_size();
+                position.unrealized_pnl += pnl;
+                position.mark_px = mark_px;
+                delta += pnl;
+            }
+            account.account_value += delta;
+        });
+    }
+
+    /// Apply a fill (signed size: + buy, - sell) at `price`.
+    ///
+    /// Account value moves by the fill's value at the mark; the position's
+    /// entry is averaged on an increase and kept on a reduction.
+    fn apply_fill(&mut self, market: MarketKey, side: OrderSide, price: Decimal, size: Decimal) {
+        let fill = match side {
+            OrderSide::Buy => size,
+            OrderSide::Sell => -size,
+        };
+        self.with_margin_rescaled(|account| {
+            let index = account.positions.iter().position(|p| p.market == market);
+            let (before, mark, entry, liquidation_px) = match index {
+                Some(i) => {
+                    let p = &account.positions[i];
+                    let signed = p.signed_size();
+                    (
+                        signed,
+                        p.mark_px,
+                        p.mark_px - p.unrealized_pnl / signed,
+                        p.liquidation_px,
+                    )
+                }
+                None => (Decimal::ZERO, price, price, None),
+            };
+            account.account_value += (mark - price) * fill;
+
+            let after = before + fill;
+            let reduces =
+                !before.is_zero() && after.abs() < before.abs() && after * before >= Decimal::ZERO;
+            let entry = if before * after <= Decimal::ZERO {
+                price
+            } else if reduces {
+                entry
+            } else {
+                (entry * before + price * fill) / after
+            };
+            let updated = (!after.is_zero()).then(|| PositionMargin {
+                market,
+                side: if after > Decimal::ZERO {
+                    OrderSide::Buy
+                } else {
+                    OrderSide::Sell
+                },
+                size: after.abs(),
+                mark_px: mark,
+                liquidation_px: liquidation_px.filter(|_| reduces),
+                unrealized_pnl: (mark - entry) * after,
+            });
+            match (index, updated) {
+                (Some(i), Some(updated)) => account.positions[i] = updated,
+                (Some(i), None) => {
+                    account.positions.remove(i);
+                }
+                (None, Some(updated)) => account.positions.push(updated),
+                (None, None) => {}
+            }
+        });
+    }
+}
+
+/// Selects positions to de-risk from the latest margin data.
+#[derive(Debug)]
+pub struct LiquidationMonitor {
+    config: LiquidationConfig,
+    /// Margin state of the last resync, carried forward by marks and fills.
+    accounts: RwLock<Vec<AccountMargin>>,
+    derisk: RwLock<HashSet<MarketKey>>,
+}
+
+impl LiquidationMonitor {
+    /// Create a new monitor.
+    #[must_use]
+    pub fn new(config: LiquidationConfig) -> Self {
+        Self {
+            config,
+            accounts: RwLock::new(Vec::new()),
+            derisk: RwLock::new(HashSet::new()),
+        }
+    }
+
+    /// Replace the margin state with fresh data and reselect.
+    ///
+    /// Returns the markets newly selected for de-risking.
+    pub fn evaluate(&self, accounts: &[AccountMargin]) -> Vec<MarketKey> {
+        *self.accounts.write() = accounts.to_vec();
+        self.reselect()
+    }
+
+    /// Reprice positions in `market` at a new mark and reselect.
+    ///
+    /// Returns the markets newly selected for de-risking.
+    pub fn on_mark(&self, market: &MarketKey, mark_px: Decimal) -> Vec<MarketKey> {
+        {
+            let mut accounts = self.accounts.write();
+            if !accounts.iter().any(|a| {
+                a.positions
+                    .iter()
+                    .any(|p| p.market == *market && p.mark_px != mark_px)
+            }) {
+                return Vec::new();
+            }
+            for account in accounts.iter_mut() {
+                account.apply_mark(market, mark_px);
+            }
+        }
+        self.reselect()
+    }
+
+    /// Apply a fill of `account` and reselect. Fills of accounts not in the
+    /// last resync are ignored.
+    ///
+    /// Returns the markets newly selected for de-risking.
+    pub fn on_fill(
+        &self,
+        account: &str,
+        market: MarketKey,
+        side: OrderSide,
+        price: Decimal,
+        size: Decimal,
+    ) -> Vec<MarketKey> {
+        {
+            let mut accounts = self.accounts.write();
+            let Some(margin) = accounts
+                .iter_mut()
+                .find(|a| a.account.eq_ignore_ascii_case(account))
+            else {
+                return Vec::new();
+            };
+            margin.apply_fill(market, side, price, size);
+        }
+        self.reselect()
+    }
+
+    /// Select from the current margin state; returns the markets not
+    /// selected before.
+    fn reselect(&self) -> Vec<MarketKey> {
+        let accounts = self.accounts.read();
+        let mut selected = Vec::new();
+        for account in accounts.iter() {
+            for position in &account.positions {
+                if position
+                    .distance_bps()
+                    .is_some_and(|d| d < self.config.min_distance_bps)
+                {
+                    selected.push(position.market);
+                }
+            }
+
+            let low_buffer = account
+                .buffer_ratio()
+                .is_some_and(|r| r < self.config.min_buffer_ratio);
+            if low_buffer {
+                let mut losing: Vec<&PositionMargin> = account
+                    .positions
+                    .iter()
+                    .filter(|p| p.unrealized_pnl < Decimal::ZERO)
+                    .collect();
+                losing.sort_by_key(|p| p.unrealized_pnl);
+                selected.extend(
+                    losing
+                        .into_iter()
+                        .take(self.config.max_derisk_positions)
+                        .map(|p| p.market),
+                );
+            }
+        }
+        let mut derisk = HashSet::new();
+        selected.retain(|m| derisk.insert(*m));
+
+        let mut current = self.derisk.write();
+        selected.retain(|m| !current.contains(m));
+        *current = derisk;
+        selected
+    }
+
+    /// Whether the position in `market` is selected for de-risking.
+    #[must_use]
+    pub fn should_derisk(&self, market: &MarketKey) -> bool {
+        self.derisk.read().contains(market)
+    }
+
+    /// Drop a market from the selection (e.g., once flattened).
+    pub fn clear(&self, market: &MarketKey) {
+        self.derisk.write().remove(market);
+    }
+}
+
+/// Thread-safe handle to LiquidationMonitor.
+pub type LiquidationMonitorHandle = Arc<LiquidationMonitor>;
+
+/// Exit strategy flattening the positions selected by a [`LiquidationMonitor`].
+pub struct LiquidationStop {
+    monitor: LiquidationMonitorHandle,
+}
+
+impl LiquidationStop {
+    /// Exit reason of the strategy.
+    pub const NAME: &'static str = "LiquidationDerisk";
+
+    /// Create a stop driven by `monitor`.
+    #[must_use]
+    pub fn new(monitor: LiquidationMonitorHandle) -> Self {
+        Self { monitor }
+    }
+
+    fn check(&self, position: &Position, now_ms: u64) -> Option<FlattenRequest> {
+        self.monitor.should_derisk(&position.market).then(|| {
+            FlattenRequest::full(
+                position,
+                FlattenReason::Strategy {
+                    name: Self::NAME.to_string(),
+                },
+                now_ms,
+            )
+        })
+    }
+}
+
+impl ExitStrategy for LiquidationStop {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn on_market_update(
+        &mut self,
+        position: &Position,
+        _snapshot: &MarketSnapshot,
+        now_ms: u64,
+    ) -> Option<FlattenRequest> {
+        self.check(position, now_ms)
+    }
+
+    fn on_tick(&mut self, position: &Position, now_ms: u64) -> Option<FlattenRequest> {
+        self.check(position, now_ms)
+    }
+
+    fn on_position_closed(&mut self, market: &MarketKey) {
+        self.monitor.clear(market);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId, Price, Size};
+    use rust_decimal_macros::dec;
+
+    fn market(idx: u32) -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(idx))
+    }
+
+    fn position(idx: u32, liq: Option<Decimal>, pnl: Decimal) -> PositionMargin {
+        PositionMargin {
+            market: market(idx),
+            side: OrderSide::Buy,
+            size: dec!(1),
+            mark_px: dec!(100),
+            liquidation_px: liq,
+            unrealized_pnl: pnl,
+        }
+    }
+
+    fn account(account_value: Decimal, positions: Vec<PositionMargin>) -> AccountMargin {
+        AccountMargin {
+            account: "0xabc".to_string(),
+            account_value,
+            maintenance_margin: dec!(80),
+            positions,
+        }
+    }
+
+    #[test]
+    fn test_distance_and_buffer() {
+        let long = position(0, Some(dec!(90)), dec!(-1));
+        assert_eq!(long.distance_bps(), Some(dec!(1000)));
+        let short = PositionMargin {
+            side: OrderSide::Sell,
+            ..position(0, Some(dec!(90)), dec!(-1))
+        };
+        assert_eq!(short.distance_bps(), Some(Decimal::ZERO));
+        assert_eq!(position(0, None, dec!(-1)).distance_bps(), None);
+
+        assert_eq!(account(dec!(200), vec![]).buffer_ratio(), Some(dec!(0.6)));
+        assert_eq!(account(Decimal::ZERO, vec![]).buffer_ratio(), None);
+    }
+
+    #[test]
+    fn test_low_buffer_selects_largest_loser() {
+        let config = LiquidationConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let monitor = Arc::new(LiquidationMonitor::new(config));
+        let positions = vec![
+            position(0, Some(dec!(50)), dec!(-2)),
+            position(1, Some(dec!(50)), dec!(-5)),
+            position(2, Some(dec!(50)), dec!(3)),
+        ];
+
+        // Buffer 0.6: nothing to do
+        assert!(monitor
+            .evaluate(&[account(dec!(200), positions.clone())])
+            .is_empty());
+
+        // Buffer 0.2: the largest loser goes
+        let selected = monitor.evaluate(&[account(dec!(100), positions.clone())]);
+        assert_eq!(selected, vec![market(1)]);
+        assert!(monitor.should_derisk(&market(1)));
+        assert!(!monitor.should_derisk(&market(0)));
+
+        // A position near its liquidation price goes regardless of the buffer
+        let mut near = positions;
+        near[2].liquidation_px = Some(dec!(98));
+        let selected = monitor.evaluate(&[account(dec!(200), near)]);
+        assert_eq!(selected, vec![market(2)]);
+        assert!(!monitor.should_derisk(&market(1)));
+
+        let mut stop = LiquidationStop::new(monitor.clone());
+        let held = Position::new(
+            market(2),
+            OrderSide::Buy,
+            Size::new(dec!(1)),
+            Price::new(dec!(100)),
+            0,
+        );
+        let request = stop.on_tick(&held, 1_000).unwrap();
+        assert_eq!(request.side, OrderSide::Sell);
+        assert_eq!(
+            request.reason,
+            FlattenReason::Strategy {
+                name: "LiquidationDerisk".to_string()
+            }
+        );
+        stop.on_position_closed(&market(2));
+        assert!(stop.on_tick(&held, 2_000).is_none());
+    }
+
+    #[test]
+    fn test_marks_and_fills_drive_selection() {
+        let monitor = LiquidationMonitor::new(LiquidationConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        let near = vec![position(0, Some(dec!(58)), dec!(-2))];
+        assert!(monitor.evaluate(&[account(dec!(200), near)]).is_empty());
+
+        // The mark closes in on the liquidation price: selected once
+        assert!(monitor.on_mark(&market(0), dec!(70)).is_empty());
+        assert_eq!(monitor.on_mark(&market(0), dec!(59.5)), vec![market(0)]);
+        assert!(monitor.on_mark(&market(0), dec!(59)).is_empty());
+        assert!(monitor.should_derisk(&market(0)));
+        {
+            let accounts = monitor.accounts.read();
+            assert_eq!(accounts[0].account_value, dec!(159));
+            assert_eq!(accounts[0].positions[0].unrealized_pnl, dec!(-43));
+            assert_eq!(accounts[0].maintenance_margin, dec!(47.2));
+        }
+
+        // Other accounts' fills are ignored; closing the position clears it
+        assert!(monitor
+            .on_fill("0xother", market(0), OrderSide::Sell, dec!(59), dec!(1))
+            .is_empty());
+        assert!(monitor.should_derisk(&market(0)));
+        monitor.on_fill("0xabc", market(0), OrderSide::Sell, dec!(59), dec!(1));
+        assert!(!monitor.should_derisk(&market(0)));
+        assert!(monitor.accounts.read()[0].positions.is_empty());
+
+        // A new position has no liquidation price until the next resync
+        monitor.on_fill("0xabc", market(1), OrderSide::Sell, dec!(10), dec!(2));
+        let accounts = monitor.accounts.read();
+        let short = &accounts[0].positions[0];
+        assert_eq!((short.side, short.size), (OrderSide::Sell, dec!(2)));
+        assert!(short.liquidation_px.is_none());
+    }
+}
@@ -0,0 +1,361 @@
+//! Net exposure per underlying / correlation group.
+//!
+//! Gross limits count a long GOLD and a short SILVER as two exposures, even
+//! though they partially offset. A netting group lists markets that move
+//! together; within a group, opposite-side notional offsets by `offset`
+//! (1 = full offset, 0 = none):
+//!
+//! `net = long + short - 2 * offset * min(long, short)`
+//!
+//! Markets outside any group net only against themselves. [`NetExposureGate`]
+//! rejects entries that would push a group's net notional, or the total
+//! across groups, past its limit; entries that reduce the net are always
+//! allowed. The same view is shown on the dashboard.
+
+use hip3_core::{MarketKey, OrderSide, Price, RejectReason};
+use hip3_position::PositionTrackerHandle;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, trace};
+
+use crate::gates::{CorrelationGroupDef, ResolvedCorrelationGroup};
+
+/// Configuration for net exposure limits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NettingConfig {
+    /// Enable net exposure limits. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Netting group definitions (`weight` is unused).
+    #[serde(default)]
+    pub groups: Vec<CorrelationGroupDef>,
+    /// Maximum net notional per group (USD). 0 = disabled.
+    #[serde(default)]
+    pub max_group_net_notional: Decimal,
+    /// Maximum net notional summed across groups (USD). 0 = disabled.
+    #[serde(default)]
+    pub max_total_net_notional: Decimal,
+    /// Apply the total limit to net instead of gross exposure: the gross
+    /// MaxPositionTotal gate is skipped. Default: false.
+    #[serde(default)]
+    pub replace_gross_total: bool,
+}
+
+impl Default for NettingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            groups: Vec::new(),
+            max_group_net_notional: Decimal::ZERO,
+            max_total_net_notional: Decimal::ZERO,
+            replace_gross_total: false,
+        }
+    }
+}
+
+/// Exposure of one netting group (or ungrouped market).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupExposure {
+    /// Group name (the market key for an ungrouped market).
+    pub name: String,
+    /// Long notional (USD).
+    pub long_notional: Decimal,
+    /// Short notional (USD).
+    pub short_notional: Decimal,
+    /// Net notional after offsetting (USD).
+    pub net_notional: Decimal,
+}
+
+/// Net exposure across all groups.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetExposure {
+    /// Exposure per group, ordered by name.
+    pub groups: Vec<GroupExposure>,
+}
+
+impl NetExposure {
+    /// Net notional summed across groups (USD).
+    #[must_use]
+    pub fn total_net(&self) -> Decimal {
+        self.groups.iter().map(|g| g.net_notional).sum()
+    }
+
+    /// Gross notional summed across groups (USD).
+    #[must_use]
+    pub fn total_gross(&self) -> Decimal {
+        self.groups
+            .iter()
+            .map(|g| g.long_notional + g.short_notional)
+            .sum()
+    }
+
+    /// Exposure of the group named `name`.
+    #[must_use]
+    pub fn group(&self, name: &str) -> Option<&GroupExposure> {
+        self.groups.iter().find(|g| g.name == name)
+    }
+}
+
+/// Aggregate per-market exposures into net exposure per group.
+///
+/// `exposures` are (market, side, notional) entries; several entries for one
+/// market (position and pending orders) add up.
+#[must_use]
+pub fn net_exposure(
+    groups: &[ResolvedCorrelationGroup],
+    exposures: &[(MarketKey, OrderSide, Decimal)],
+) -> NetExposure {
+    let mut by_group: Vec<(String, Decimal, Decimal, Decimal)> = Vec::new();
+    for &(market, side, notional) in exposures {
+        let (name, offset) = match groups.iter().find(|g| g.markets.contains(&market)) {
+            Some(group) => (group.name.clone(), group.offset),
+            None => (market.to_string(), Decimal::ONE),
+        };
+        let index = match by_group.iter().position(|(n, ..)| *n == name) {
+            Some(i) => i,
+            None => {
+                by_group.push((name, offset, Decimal::ZERO, Decimal::ZERO));
+                by_group.len() - 1
+            }
+        };
+        let entry = &mut by_group[index];
+        match side {
+            OrderSide::Buy => entry.2 += notional,
+            OrderSide::Sell => entry.3 += notional,
+        }
+    }
+
+    let mut groups: Vec<GroupExposure> = by_group
+        .into_iter()
+        .map(|(name, offset, long, short)| GroupExposure {
+            name,
+            long_notional: long,
+            short_notional: short,
+            net_notional: long + short - Decimal::TWO * offset * long.min(short),
+        })
+        .collect();
+    groups.sort_by(|a, b| a.name.cmp(&b.name));
+    NetExposure { groups }
+}
+
+/// Net exposure limit gate.
+pub struct NetExposureGate {
+    /// Resolved netting groups.
+    groups: Vec<ResolvedCorrelationGroup>,
+    /// Position tracker handle.
+    position_handle: PositionTrackerHandle,
+    /// Maximum net notional per group (0 = disabled).
+    max_group_net: Decimal,
+    /// Maximum total net notional (0 = disabled).
+    max_total_net: Decimal,
+    /// Whether the gross total gate is replaced by the net total.
+    replace_gross_total: bool,
+}
+
+impl NetExposureGate {
+    /// Create a new `NetExposureGate`.
+    #[must_use]
+    pub fn new(
+        groups: Vec<ResolvedCorrelationGroup>,
+        position_handle: PositionTrackerHandle,
+        config: &NettingConfig,
+    ) -> Self {
+        Self {
+            groups,
+            position_handle,
+            max_group_net: config.max_group_net_notional,
+            max_total_net: config.max_total_net_notional,
+            replace_gross_total: config.replace_gross_total,
+        }
+    }
+
+    /// Whether the gross MaxPositionTotal gate is replaced by the net total.
+    #[must_use]
+    pub fn replaces_gross_total(&self) -> bool {
+        self.replace_gross_total && self.max_total_net > Decimal::ZERO
+    }
+
+    /// Current net exposure of positions and pending entry orders.
+    ///
+    /// Returns `None` if a mark price is unavailable.
+    pub fn exposure(&self, mark_px: impl Fn(&MarketKey) -> Option<Price>) -> Option<NetExposure> {
+        let entries = self.entries(&mark_px)?;
+        Some(net_exposure(&self.groups, &entries))
+    }
+
+    /// Check if an entry of `notional` on `side` in `market` stays within
+    /// the net limits.
+    ///
+    /// Fails closed with `MarketDataUnavailable` if a mark price is missing.
+    pub fn check(
+        &self,
+        market: &MarketKey,
+        side: OrderSide,
+        notional: Decimal,
+        mark_px: impl Fn(&MarketKey) -> Option<Price>,
+    ) -> Result<(), RejectReason> {
+        let mut entries = self
+            .entries(&mark_px)
+            .ok_or(RejectReason::MarketDataUnavailable)?;
+        let before = net_exposure(&self.groups, &entries);
+        entries.push((*market, side, notional));
+        let after = net_exposure(&self.groups, &entries);
+
+        let name = match self.groups.iter().find(|g| g.markets.contains(market)) {
+            Some(group) => group.name.clone(),
+            None => market.to_string(),
+        };
+        let group_before = before
+            .group(&name)
+            .map_or(Decimal::ZERO, |g| g.net_notional);
+        let group_after = after.group(&name).map_or(Decimal::ZERO, |g| g.net_notional);
+        // Entries that reduce the net are always allowed
+        if group_after <= group_before {
+            return Ok(());
+        }
+
+        if self.max_group_net > Decimal::ZERO && group_after > self.max_group_net {
+            debug!(
+                market = %market,
+                group = %name,
+                group_net = %group_after,
+                max = %self.max_group_net,
+                "NetExposureGate blocked: group net limit"
+            );
+            return Err(RejectReason::NetExposureLimit);
+        }
+        let total_after = after.total_net();
+        if self.max_total_net > Decimal::ZERO && total_after > self.max_total_net {
+            debug!(
+                market = %market,
+                total_net = %total_after,
+                max = %self.max_total_net,
+                "NetExposureGate blocked: total net limit"
+            );
+            return Err(RejectReason::NetExposureLimit);
+        }
+
+        trace!(
+            market = %market,
+            group = %name,
+            group_net = %group_after,
+            total_net = %total_after,
+            "NetExposureGate passed"
+        );
+        Ok(())
+    }
+
+    /// Exposure entries of open positions and pending entry orders.
+    fn entries(
+        &self,
+        mark_px: &impl Fn(&MarketKey) -> Option<Price>,
+    ) -> Option<Vec<(MarketKey, OrderSide, Decimal)>> {
+        let mut entries = Vec::new();
+        for position in self.position_handle.positions_snapshot() {
+            let px = mark_px(&position.market)?;
+            entries.push((
+                position.market,
+                position.side,
+                position.notional(px).inner(),
+            ));
+        }
+        for order in self.position_handle.pending_orders() {
+            if order.reduce_only {
+                continue;
+            }
+            let px = mark_px(&order.market)?;
+            entries.push((
+                order.market,
+                order.side,
+                order.remaining_size().inner() * px.inner(),
+            ));
+        }
+        Some(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId, Size};
+    use hip3_position::{spawn_position_tracker, Position};
+    use rust_decimal_macros::dec;
+
+    fn market(idx: u32) -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(idx))
+    }
+
+    fn metals(offset: Decimal) -> Vec<ResolvedCorrelationGroup> {
+        vec![ResolvedCorrelationGroup {
+            name: "metals".to_string(),
+            markets: [market(0), market(1)].into_iter().collect(),
+            weight: Decimal::ONE,
+            offset,
+        }]
+    }
+
+    #[test]
+    fn test_partial_offset_within_group() {
+        let exposures = vec![
+            (market(0), OrderSide::Buy, dec!(1000)),
+            (market(1), OrderSide::Sell, dec!(600)),
+            (market(2), OrderSide::Sell, dec!(300)),
+        ];
+
+        let view = net_exposure(&metals(dec!(0.5)), &exposures);
+        // 1000 + 600 - 2 * 0.5 * 600
+        assert_eq!(view.group("metals").unwrap().net_notional, dec!(1000));
+        assert_eq!(
+            view.group(&market(2).to_string()).unwrap().net_notional,
+            dec!(300)
+        );
+        assert_eq!(view.total_net(), dec!(1300));
+        assert_eq!(view.total_gross(), dec!(1900));
+
+        // Full offset: |1000 - 600|
+        let view = net_exposure(&metals(Decimal::ONE), &exposures);
+        assert_eq!(view.group("metals").unwrap().net_notional, dec!(400));
+    }
+
+    #[tokio::test]
+    async fn test_gate_allows_offsetting_entries() {
+        let (handle, _join) = spawn_position_tracker(100);
+        handle
+            .sync_positions(vec![Position::new(
+                market(0),
+                OrderSide::Buy,
+                Size::new(dec!(10)),
+                Price::new(dec!(100)),
+                0,
+            )])
+            .await;
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+
+        let config = NettingConfig {
+            enabled: true,
+            max_group_net_notional: dec!(1500),
+            ..Default::default()
+        };
+        let gate = NetExposureGate::new(metals(Decimal::ONE), handle.clone(), &config);
+        let mark = |_: &MarketKey| Some(Price::new(dec!(100)));
+
+        // Long 1000 + long 600 = 1600 net: blocked
+        assert_eq!(
+            gate.check(&market(1), OrderSide::Buy, dec!(600), mark),
+            Err(RejectReason::NetExposureLimit)
+        );
+        // Short 600 offsets: net 400, allowed
+        assert_eq!(
+            gate.check(&market(1), OrderSide::Sell, dec!(600), mark),
+            Ok(())
+        );
+        // Missing mark price fails closed
+        assert_eq!(
+            gate.check(&market(1), OrderSide::Sell, dec!(600), |_| None),
+            Err(RejectReason::MarketDataUnavailable)
+        );
+        assert_eq!(gate.exposure(mark).unwrap().total_net(), dec!(1000));
+
+        handle.shutdown().await;
+    }
+}
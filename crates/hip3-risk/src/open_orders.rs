@@ -0,0 +1,256 @@
+//! Open order count and cancel failure gate.
+//!
+//! An order-leak bug (quotes replaced without their cancels landing, retries
+//! that never resolve) builds up stale orders on the exchange without any
+//! single action looking wrong. [`OpenOrderGate`] blocks new quotes and
+//! entries of a market while its tracked open orders (MM quotes, entries and
+//! reduce-only exits) exceed a cap, or while it has had too many cancel
+//! failures recently. Cancels and exits keep going out, so the count can
+//! drain back under the cap.
+
+use std::collections::{HashMap, VecDeque};
+
+use hip3_core::{MarketKey, RejectReason};
+use hip3_position::PositionTrackerHandle;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+/// Configuration for the open order gate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenOrderGateConfig {
+    /// Enable the gate. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Maximum tracked open orders per market. 0 = no cap. Default: 20.
+    #[serde(default = "default_max_open_orders_per_market")]
+    pub max_open_orders_per_market: usize,
+    /// Maximum tracked open orders across all markets. 0 = no cap.
+    /// Default: 100.
+    #[serde(default = "default_max_open_orders_total")]
+    pub max_open_orders_total: usize,
+    /// Maximum cancel failures per market within the window. 0 = not
+    /// checked. Default: 5.
+    #[serde(default = "default_max_cancel_failures")]
+    pub max_cancel_failures: usize,
+    /// Cancel failure window (seconds). Default: 300.
+    #[serde(default = "default_cancel_failure_window_secs")]
+    pub cancel_failure_window_secs: u64,
+}
+
+fn default_max_open_orders_per_market() -> usize {
+    20
+}
+
+fn default_max_open_orders_total() -> usize {
+    100
+}
+
+fn default_max_cancel_failures() -> usize {
+    5
+}
+
+fn default_cancel_failure_window_secs() -> u64 {
+    300
+}
+
+impl Default for OpenOrderGateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_open_orders_per_market: default_max_open_orders_per_market(),
+            max_open_orders_total: default_max_open_orders_total(),
+            max_cancel_failures: default_max_cancel_failures(),
+            cancel_failure_window_secs: default_cancel_failure_window_secs(),
+        }
+    }
+}
+
+/// Blocks new orders of a market with too many open orders or cancel
+/// failures.
+pub struct OpenOrderGate {
+    config: OpenOrderGateConfig,
+    /// Position tracker handle (source of the open order count).
+    position_handle: PositionTrackerHandle,
+    /// Recent cancel failure times (Unix ms) per market.
+    cancel_failures: Mutex<HashMap<MarketKey, VecDeque<u64>>>,
+}
+
+impl OpenOrderGate {
+    /// Create a new gate.
+    #[must_use]
+    pub fn new(config: OpenOrderGateConfig, position_handle: PositionTrackerHandle) -> Self {
+        Self {
+            config,
+            position_handle,
+            cancel_failures: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Check if the gate is enabled.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Gate configuration.
+    #[must_use]
+    pub fn config(&self) -> &OpenOrderGateConfig {
+        &self.config
+    }
+
+    /// Tracked open orders of `market` and of all markets.
+    #[must_use]
+    pub fn open_orders(&self, market: &MarketKey) -> (usize, usize) {
+        let mut in_market = 0;
+        let mut total = 0;
+        for entry in self.position_handle.pending_orders_snapshot_iter() {
+            total += 1;
+            if &entry.value().0 == market {
+                in_market += 1;
+            }
+        }
+        (in_market, total)
+    }
+
+    /// Record a failed cancel in `market`.
+    pub fn record_cancel_failure(&self, market: &MarketKey, now_ms: u64) {
+        let window_ms = self.config.cancel_failure_window_secs * 1000;
+        let mut failures = self.cancel_failures.lock();
+        let times = failures.entry(*market).or_default();
+        Self::prune(times, now_ms, window_ms);
+        times.push_back(now_ms);
+        if self.config.max_cancel_failures > 0 && times.len() == self.config.max_cancel_failures {
+            warn!(
+                market = %market,
+                failures = times.len(),
+                window_secs = self.config.cancel_failure_window_secs,
+                "OpenOrderGate: cancel failure limit reached, new orders blocked"
+            );
+        }
+    }
+
+    /// Cancel failures of `market` within the window.
+    #[must_use]
+    pub fn cancel_failures_at(&self, market: &MarketKey, now_ms: u64) -> usize {
+        let window_ms = self.config.cancel_failure_window_secs * 1000;
+        let mut failures = self.cancel_failures.lock();
+        failures.get_mut(market).map_or(0, |times| {
+            Self::prune(times, now_ms, window_ms);
+            times.len()
+        })
+    }
+
+    /// Check if new orders are allowed in `market`.
+    pub fn check(&self, market: &MarketKey) -> Result<(), RejectReason> {
+        self.check_at(market, chrono::Utc::now().timestamp_millis() as u64)
+    }
+
+    /// Check if new orders are allowed in `market` at `now_ms` (Unix ms).
+    pub fn check_at(&self, market: &MarketKey, now_ms: u64) -> Result<(), RejectReason> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        let (in_market, total) = self.open_orders(market);
+        let per_market_cap = self.config.max_open_orders_per_market;
+        let total_cap = self.config.max_open_orders_total;
+        if (per_market_cap > 0 && in_market >= per_market_cap)
+            || (total_cap > 0 && total >= total_cap)
+        {
+            debug!(
+                market = %market,
+                in_market,
+                total,
+                "OpenOrderGate blocked: open order cap reached"
+            );
+            return Err(RejectReason::OpenOrderLimit);
+        }
+
+        let max_failures = self.config.max_cancel_failures;
+        if max_failures > 0 {
+            let failures = self.cancel_failures_at(market, now_ms);
+            if failures >= max_failures {
+                debug!(
+                    market = %market,
+                    failures,
+                    "OpenOrderGate blocked: recent cancel failures"
+                );
+                return Err(RejectReason::OpenOrderLimit);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn prune(times: &mut VecDeque<u64>, now_ms: u64, window_ms: u64) {
+        while times
+            .front()
+            .is_some_and(|&at| now_ms.saturating_sub(at) > window_ms)
+        {
+            times.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{
+        AssetId, ClientOrderId, DexId, OrderSide, PendingOrder, Price, Size, TrackedOrder,
+    };
+    use hip3_position::spawn_position_tracker;
+    use rust_decimal_macros::dec;
+
+    fn market(idx: u32) -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(idx))
+    }
+
+    #[tokio::test]
+    async fn test_open_order_cap_and_cancel_failures() {
+        let (handle, _join) = spawn_position_tracker(100);
+        for _ in 0..3 {
+            let order = PendingOrder::new(
+                ClientOrderId::new(),
+                market(0),
+                OrderSide::Buy,
+                Price::new(dec!(100)),
+                Size::new(dec!(1)),
+                false,
+                0,
+            );
+            handle
+                .register_order(TrackedOrder::from_pending(order))
+                .await;
+        }
+
+        let gate = OpenOrderGate::new(
+            OpenOrderGateConfig {
+                enabled: true,
+                max_open_orders_per_market: 3,
+                max_open_orders_total: 0,
+                max_cancel_failures: 2,
+                cancel_failure_window_secs: 60,
+            },
+            handle.clone(),
+        );
+
+        assert_eq!(gate.open_orders(&market(0)), (3, 3));
+        assert_eq!(
+            gate.check_at(&market(0), 0),
+            Err(RejectReason::OpenOrderLimit)
+        );
+        assert!(gate.check_at(&market(1), 0).is_ok());
+
+        // Two cancel failures block market 1 until they leave the window
+        gate.record_cancel_failure(&market(1), 1_000);
+        gate.record_cancel_failure(&market(1), 2_000);
+        assert_eq!(
+            gate.check_at(&market(1), 2_000),
+            Err(RejectReason::OpenOrderLimit)
+        );
+        assert!(gate.check_at(&market(1), 61_500).is_ok());
+
+        handle.shutdown().await;
+    }
+}
@@ -0,0 +1,327 @@
+//! Consecutive rejection circuit breaker per market.
+//!
+//! A market that keeps rejecting our orders (tick, margin, size rejections)
+//! usually has something structurally wrong: a stale spec, a margin
+//! requirement we don't model. [`RejectBreaker`] disables new orders of a
+//! market after too many exchange rejections within a window. Once tripped,
+//! one probe order is let through per probe interval; an accepted probe
+//! closes the breaker, a rejected one keeps it open. The operator can also
+//! clear it manually.
+
+use std::collections::{HashMap, VecDeque};
+
+use hip3_core::{MarketKey, RejectReason};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+/// Rejections that are part of normal operation and never count: post-only
+/// quotes that would have crossed.
+const IGNORED_REJECTS: &[&str] = &[
+    "badAloPxRejected",
+    "Post only order would have immediately matched",
+];
+
+/// Configuration for the rejection breaker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RejectBreakerConfig {
+    /// Enable the breaker. Default: false.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Rejections per market within the window that trip the breaker.
+    /// Default: 5.
+    #[serde(default = "default_max_rejects")]
+    pub max_rejects: usize,
+    /// Rejection window (seconds). Default: 300.
+    #[serde(default = "default_window_secs")]
+    pub window_secs: u64,
+    /// Interval between probe orders while tripped (seconds). 0 = no
+    /// probes, manual clear only. Default: 600.
+    #[serde(default = "default_probe_interval_secs")]
+    pub probe_interval_secs: u64,
+}
+
+fn default_max_rejects() -> usize {
+    5
+}
+
+fn default_window_secs() -> u64 {
+    300
+}
+
+fn default_probe_interval_secs() -> u64 {
+    600
+}
+
+impl Default for RejectBreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_rejects: default_max_rejects(),
+            window_secs: default_window_secs(),
+            probe_interval_secs: default_probe_interval_secs(),
+        }
+    }
+}
+
+/// Rejection history and breaker state of one market.
+#[derive(Debug, Default)]
+struct MarketRejects {
+    /// Recent rejection times (Unix ms).
+    times: VecDeque<u64>,
+    /// When the breaker tripped or the last probe went out (Unix ms).
+    tripped_at_ms: Option<u64>,
+}
+
+/// Disables new orders of a market after repeated exchange rejections.
+pub struct RejectBreaker {
+    config: RejectBreakerConfig,
+    markets: Mutex<HashMap<MarketKey, MarketRejects>>,
+}
+
+impl RejectBreaker {
+    /// Create a new breaker.
+    #[must_use]
+    pub fn new(config: RejectBreakerConfig) -> Self {
+        Self {
+            config,
+            markets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Check if the breaker is enabled.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Breaker configuration.
+    #[must_use]
+    pub fn config(&self) -> &RejectBreakerConfig {
+        &self.config
+    }
+
+    /// Record an exchange rejection of an order in `market`.
+    pub fn record_reject(&self, market: &MarketKey, reason: &str, now_ms: u64) {
+        if IGNORED_REJECTS.iter().any(|r| reason.contains(r)) {
+            return;
+        }
+        let window_ms = self.config.window_secs * 1000;
+        let mut markets = self.markets.lock();
+        let state = markets.entry(*market).or_default();
+        while state
+            .times
+            .front()
+            .is_some_and(|&at| now_ms.saturating_sub(at) > window_ms)
+        {
+            state.times.pop_front();
+        }
+        state.times.push_back(now_ms);
+        if state.tripped_at_ms.is_some() {
+            debug!(market = %market, reason, "RejectBreaker: probe rejected, market stays disabled");
+        } else if self.config.max_rejects > 0 && state.times.len() >= self.config.max_rejects {
+            state.tripped_at_ms = Some(now_ms);
+            warn!(
+                market = %market,
+                rejects = state.times.len(),
+                window_secs = self.config.window_secs,
+                reason,
+                "RejectBreaker tripped: market disabled after repeated rejections"
+            );
+        }
+    }
+
+    /// Record an order of `market` accepted by the exchange (resting or
+    /// filled). Closes a tripped breaker.
+    pub fn record_accept(&self, market: &MarketKey) {
+        let mut markets = self.markets.lock();
+        if let Some(state) = markets.get_mut(market) {
+            if state.tripped_at_ms.take().is_some() {
+                info!(market = %market, "RejectBreaker closed: probe order accepted");
+            }
+            state.times.clear();
+        }
+    }
+
+    /// Markets whose breaker is tripped.
+    #[must_use]
+    pub fn tripped_markets(&self) -> Vec<MarketKey> {
+        self.markets
+            .lock()
+            .iter()
+            .filter(|(_, state)| state.tripped_at_ms.is_some())
+            .map(|(market, _)| *market)
+            .collect()
+    }
+
+    /// Clear the breaker of `market` (operator). Returns false if it was
+    /// not tripped.
+    pub fn clear(&self, market: &MarketKey) -> bool {
+        let cleared = self
+            .markets
+            .lock()
+            .remove(market)
+            .is_some_and(|state| state.tripped_at_ms.is_some());
+        if cleared {
+            info!(market = %market, "RejectBreaker cleared manually");
+        }
+        cleared
+    }
+
+    /// Check if new orders are allowed in `market`.
+    pub fn check(&self, market: &MarketKey) -> Result<(), RejectReason> {
+        self.check_at(market, chrono::Utc::now().timestamp_millis() as u64)
+    }
+
+    /// Check if new orders are allowed in `market` at `now_ms` (Unix ms).
+    ///
+    /// While tripped, one check per probe interval passes (the probe).
+    pub fn check_at(&self, market: &MarketKey, now_ms: u64) -> Result<(), RejectReason> {
+        if !self.is_enabled() {
+            return Ok(());
+        }
+
+        let mut markets = self.markets.lock();
+        let Some(tripped_at_ms) = markets
+            .get_mut(market)
+            .and_then(|s| s.tripped_at_ms.as_mut())
+        else {
+            return Ok(());
+        };
+        let probe_interval_ms = self.config.probe_interval_secs * 1000;
+        if probe_interval_ms > 0 && now_ms.saturating_sub(*tripped_at_ms) >= probe_interval_ms {
+            *tripped_at_ms = now_ms;
+            info!(market = %market, "RejectBreaker: letting a probe order through");
+            return Ok(());
+        }
+        debug!(market = %market, "RejectBreaker blocked: market disabled");
+        Err(RejectReason::RejectBreakerOpen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hip3_core::{AssetId, DexId};
+
+    fn market(idx: u32) -> MarketKey {
+        MarketKey::new(DexId::XYZ, AssetId::new(idx))
+    }
+
+    #[test]
+    fn test_trip_probe_and_clear() {
+        let breaker = RejectBreaker::new(RejectBreakerConfig {
+            enabled: true,
+            max_rejects: 2,
+            window_secs: 60,
+            probe_interval_secs: 120,
+        });
+
+        // ALO crosses don't count; rejections outside the window expire
+        breaker.record_reject(&market(0), "badAloPxRejected", 0);
+        breaker.record_reject(&market(0), "tickRejected", 0);
+        breaker.record_reject(&market(0), "marginRejected", 61_000);
+        assert!(breaker.check_at(&market(0), 61_000).is_ok());
+
+        breaker.record_reject(&market(0), "marginRejected", 62_000);
+        assert_eq!(
+            breaker.check_at(&market(0), 62_000),
+            Err(RejectReason::RejectBreakerOpen)
+        );
+        assert!(breaker.check_at(&market(1), 62_000).is_ok());
+
+        // One probe per interval; a rejected probe keeps it open
+        assert!(breaker.check_at(&market(0), 182_000).is_ok());
+        assert!(breaker.check_at(&market(0), 182_001).is_err());
+        breaker.record_reject(&market(0), "marginRejected", 182_100);
+        assert!(breaker.check_at(&market(0), 200_000).is_err());
+
+        // An accepted probe closes it
+        assert!(breaker.check_at(&market(0), 302_000).is_ok());
+        breaker.record_accept(&market(0));
+        assert!(breaker.check_at(&market(0), 302_001).is_ok());
+
+        // Manual clear
+        breaker.record_reject(&market(1), "tickRejected", 0);
+        breaker.record_reject(&market(1), "tickRejected", 0);
+        assert_eq!(breaker.tripped_markets(), vec![market(1)]);
+        assert!(breaker.clear(&market(1)));
+        assert!(breaker.check_at(&market(1), 1).is_ok());
+    }
+
+    fn tripped_breaker(probe_interval_secs: u64) -> RejectBreaker {
+        let breaker = RejectBreaker::new(RejectBreakerConfig {
+            enabled: true,
+            max_rejects: 2,
+            window_secs: 60,
+            probe_interval_secs,
+        });
+        breaker.record_reject(&market(0), "tickRejected", 0);
+        breaker.record_reject(&market(0), "tickRejected", 1_000);
+        assert_eq!(breaker.tripped_markets(), vec![market(0)]);
+        breaker
+    }
+
+    #[test]
+    fn test_single_probe_per_interval() {
+        let breaker = tripped_breaker(120);
+
+        assert!(breaker.check_at(&market(0), 120_999).is_err());
+        assert!(breaker.check_at(&market(0), 121_000).is_ok());
+
+        // Only the one probe: the next interval counts from the probe
+        assert!(breaker.check_at(&market(0), 121_000).is_err());
+        assert!(breaker.check_at(&market(0), 240_999).is_err());
+        assert!(breaker.check_at(&market(0), 241_000).is_ok());
+    }
+
+    #[test]
+    fn test_accepted_probe_reenables_market() {
+        let breaker = tripped_breaker(120);
+
+        assert!(breaker.check_at(&market(0), 121_000).is_ok());
+        breaker.record_accept(&market(0));
+        assert!(breaker.tripped_markets().is_empty());
+        for now_ms in [121_001, 121_002, 121_003] {
+            assert!(breaker.check_at(&market(0), now_ms).is_ok());
+        }
+
+        // The rejection history went with it: one more reject doesn't re-trip
+        breaker.record_reject(&market(0), "tickRejected", 122_000);
+        assert!(breaker.check_at(&market(0), 122_000).is_ok());
+    }
+
+    #[test]
+    fn test_rejected_probe_keeps_market_disabled() {
+        let breaker = tripped_breaker(120);
+
+        assert!(breaker.check_at(&market(0), 121_000).is_ok());
+        breaker.record_reject(&market(0), "marginRejected", 121_050);
+        assert_eq!(breaker.tripped_markets(), vec![market(0)]);
+        assert_eq!(
+            breaker.check_at(&market(0), 121_100),
+            Err(RejectReason::RejectBreakerOpen)
+        );
+
+        // Next probe waits a full interval from the failed one
+        assert!(breaker.check_at(&market(0), 240_000).is_err());
+        assert!(breaker.check_at(&market(0), 241_000).is_ok());
+    }
+
+    #[test]
+    fn test_admin_clear() {
+        // No probes: only the operator can reopen the market
+        let breaker = tripped_breaker(0);
+        assert!(breaker.check_at(&market(0), 10_000_000).is_err());
+
+        assert!(!breaker.clear(&market(1)));
+        assert!(breaker.clear(&market(0)));
+        assert!(breaker.tripped_markets().is_empty());
+        assert!(breaker.check_at(&market(0), 10_000_000).is_ok());
+
+        // Clearing again is a no-op; the old rejections are gone
+        assert!(!breaker.clear(&market(0)));
+        breaker.record_reject(&market(0), "tickRejected", 10_000_000);
+        assert!(breaker.check_at(&market(0), 10_000_000).is_ok());
+    }
+}
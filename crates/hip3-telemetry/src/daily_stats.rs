@@ -6,6 +6,11 @@
 //! - ctx_age_ms: AssetCtx delay distribution (P50/P95/P99)
 //! - bbo_age_ms: BBO delay distribution (P50/P95/P99)
 //! - cross_duration_ticks: Cross duration distribution
+//!
+//! Also aggregates realized PnL of closed trades by entry source and exit
+//! reason, MM fill markouts by market and horizon, and MM PnL split into
+//! spread capture and inventory PnL by market, each tagged with the quote
+//! model the market ran.
 
 use crate::metrics::{
     BBO_AGE_HIST_MS, BBO_NULL_TOTAL, BBO_UPDATE_TOTAL, CROSS_COUNT_TOTAL, CROSS_DURATION_TICKS,
@@ -34,10 +39,82 @@ pub struct MarketDailyStats {
     pub cross_duration_avg_ticks: f64,
 }
 
+/// Realized PnL of closed trades for one (entry source, exit reason) pair.
+#[derive(Debug, Clone, Default)]
+pub struct PnlAttribution {
+    /// How the position was entered (taker, mm).
+    pub entry_source: String,
+    /// What closed it (TimeStop, MarkRegression, OracleReversal, HardStop, ...).
+    pub exit_reason: String,
+    /// Number of closing fills.
+    pub trades: u64,
+    /// Closing fills with positive net PnL.
+    pub wins: u64,
+    /// PnL before fees and funding (USD).
+    pub gross_pnl_usd: f64,
+    /// Entry and exit fees (USD).
+    pub fees_usd: f64,
+    /// Funding paid while open (USD, positive = paid).
+    pub funding_usd: f64,
+    /// gross - fees - funding (USD).
+    pub net_pnl_usd: f64,
+}
+
+/// Markouts of MM fills for one (market, horizon) pair.
+#[derive(Debug, Clone, Default)]
+pub struct MarkoutSummary {
+    pub market_key: String,
+    /// Quote model of the market.
+    pub model: String,
+    /// Horizon after the fill (ms).
+    pub horizon_ms: u64,
+    /// Number of measured fills.
+    pub fills: u64,
+    /// Fills with a negative markout (picked off).
+    pub adverse: u64,
+    /// Sum of mid markouts (bps, positive = favorable).
+    pub sum_mid_bps: f64,
+    /// Sum of oracle markouts (bps, positive = favorable).
+    pub sum_oracle_bps: f64,
+}
+
+impl MarkoutSummary {
+    /// Mean mid markout (bps).
+    pub fn mean_mid_bps(&self) -> f64 {
+        if self.fills == 0 {
+            return 0.0;
+        }
+        self.sum_mid_bps / self.fills as f64
+    }
+
+    /// Mean oracle markout (bps).
+    pub fn mean_oracle_bps(&self) -> f64 {
+        if self.fills == 0 {
+            return 0.0;
+        }
+        self.sum_oracle_bps / self.fills as f64
+    }
+}
+
+/// MM PnL of one market, split into spread capture and inventory PnL.
+#[derive(Debug, Clone, Default)]
+pub struct MmPnlSummary {
+    pub market_key: String,
+    /// Quote model of the market.
+    pub model: String,
+    /// Edge at fill vs the reference price (USD).
+    pub spread_capture_usd: f64,
+    /// Reference price drift on held inventory (USD).
+    pub inventory_pnl_usd: f64,
+}
+
 /// Daily statistics reporter.
 pub struct DailyStatsReporter {
     markets: Vec<String>,
     start_time: DateTime<Utc>,
+    attribution: HashMap<(String, String), PnlAttribution>,
+    markouts: HashMap<(String, String, u64), MarkoutSummary>,
+    mm_pnl: HashMap<String, MmPnlSummary>,
 }
 
 impl DailyStatsReporter {
@@ -46,7 +123,128 @@ impl DailyStatsReporter {
         Self {
             markets,
             start_time: Utc::now(),
+            attribution: HashMap::new(),
+            markouts: HashMap::new(),
+            mm_pnl: HashMap::new(),
+        }
+    }
+
+    /// Record the realized PnL of a closed trade.
+    pub fn record_trade(
+        &mut self,
+        entry_source: &str,
+        exit_reason: &str,
+        gross_pnl_usd: f64,
+        fees_usd: f64,
+        funding_usd: f64,
+    ) {
+        let entry = self
+            .attribution
+            .entry((entry_source.to_string(), exit_reason.to_string()))
+            .or_insert_with(|| PnlAttribution {
+                entry_source: entry_source.to_string(),
+                exit_reason: exit_reason.to_string(),
+                ..Default::default()
+            });
+        let net_pnl_usd = gross_pnl_usd - fees_usd - funding_usd;
+        entry.trades += 1;
+        if net_pnl_usd > 0.0 {
+            entry.wins += 1;
+        }
+        entry.gross_pnl_usd += gross_pnl_usd;
+        entry.fees_usd += fees_usd;
+        entry.funding_usd += funding_usd;
+        entry.net_pnl_usd += net_pnl_usd;
+    }
+
+    /// PnL attribution of closed trades, by entry source then exit reason.
+    pub fn pnl_attribution(&self) -> Vec<PnlAttribution> {
+        let mut rows: Vec<PnlAttribution> = self.attribution.values().cloned().collect();
+        rows.sort_by(|a, b| {
+            (&a.entry_source, &a.exit_reason).cmp(&(&b.entry_source, &b.exit_reason))
+        });
+        rows
+    }
+
+    /// Record the markout of an MM fill at a horizon, by quote model.
+    pub fn record_markout(
+        &mut self,
+        market_key: &str,
+        model: &str,
+        horizon_ms: u64,
+        mid_bps: f64,
+        oracle_bps: f64,
+    ) {
+        let entry = self
+            .markouts
+            .entry((market_key.to_string(), model.to_string(), horizon_ms))
+            .or_insert_with(|| MarkoutSummary {
+                market_key: market_key.to_string(),
+                model: model.to_string(),
+                horizon_ms,
+                ..Default::default()
+            });
+        entry.fills += 1;
+        if mid_bps < 0.0 {
+            entry.adverse += 1;
+        }
+        entry.sum_mid_bps += mid_bps;
+        entry.sum_oracle_bps += oracle_bps;
+    }
+
+    /// MM fill markouts, by market, model then horizon.
+    pub fn markout_summary(&self) -> Vec<MarkoutSummary> {
+        let mut rows: Vec<MarkoutSummary> = self.markouts.values().cloned().collect();
+        rows.sort_by(|a, b| {
+            (&a.market_key, &a.model, a.horizon_ms).cmp(&(&b.market_key, &b.model, b.horizon_ms))
+        });
+        rows
+    }
+
+    /// Set the MM PnL decomposition of a market (running totals) and the
+    /// quote model it runs.
+    pub fn set_mm_pnl(
+        &mut self,
+        market_key: &str,
+        model: &str,
+        spread_capture_usd: f64,
+        inventory_pnl_usd: f64,
+    ) {
+        self.mm_pnl.insert(
+            market_key.to_string(),
+            MmPnlSummary {
+                market_key: market_key.to_string(),
+                model: model.to_string(),
+                spread_capture_usd,
+                inventory_pnl_usd,
+            },
+        );
+    }
+
+    /// MM PnL decomposition, by market.
+    pub fn mm_pnl_summary(&self) -> Vec<MmPnlSummary> {
+        let mut rows: Vec<MmPnlSummary> = self.mm_pnl.values().cloned().collect();
+        rows.sort_by(|a, b| a.market_key.cmp(&b.market_key));
+        rows
+    }
+
+    /// MM PnL decomposition summed by quote model (market_key = model).
+    pub fn mm_pnl_by_model(&self) -> Vec<MmPnlSummary> {
+        let mut by_model: HashMap<&str, MmPnlSummary> = HashMap::new();
+        for p in self.mm_pnl.values() {
+            let entry = by_model
+                .entry(p.model.as_str())
+                .or_insert_with(|| MmPnlSummary {
+                    market_key: p.model.clone(),
+                    model: p.model.clone(),
+                    ..Default::default()
+                });
+            entry.spread_capture_usd += p.spread_capture_usd;
+            entry.inventory_pnl_usd += p.inventory_pnl_usd;
         }
+        let mut rows: Vec<MmPnlSummary> = by_model.into_values().collect();
+        rows.sort_by(|a, b| a.model.cmp(&b.model));
+        rows
     }
 
     /// Get current statistics for all markets.
@@ -253,6 +451,65 @@ impl DailyStatsReporter {
             );
         }
 
+        let attribution = self.pnl_attribution();
+        if !attribution.is_empty() {
+            info!("--- PnL attribution (entry / exit) ---");
+            for a in &attribution {
+                info!(
+                    "  {} / {}: trades={} wins={} gross=${:.2} fees=${:.2} funding=${:.2} net=${:.2}",
+                    a.entry_source,
+                    a.exit_reason,
+                    a.trades,
+                    a.wins,
+                    a.gross_pnl_usd,
+                    a.fees_usd,
+                    a.funding_usd,
+                    a.net_pnl_usd
+                );
+            }
+        }
+
+        let markouts = self.markout_summary();
+        if !markouts.is_empty() {
+            info!("--- MM markouts (market [model] / horizon) ---");
+            for m in &markouts {
+                info!(
+                    "  {} [{}] / {}ms: fills={} adverse={} mid={:.2}bps oracle={:.2}bps",
+                    m.market_key,
+                    m.model,
+                    m.horizon_ms,
+                    m.fills,
+                    m.adverse,
+                    m.mean_mid_bps(),
+                    m.mean_oracle_bps()
+                );
+            }
+        }
+
+        let mm_pnl = self.mm_pnl_summary();
+        if !mm_pnl.is_empty() {
+            info!("--- MM PnL (spread capture / inventory) ---");
+            for p in &mm_pnl {
+                info!(
+                    "  {} [{}]: spread_capture=${:.2} inventory=${:.2} total=${:.2}",
+                    p.market_key,
+                    p.model,
+                    p.spread_capture_usd,
+                    p.inventory_pnl_usd,
+                    p.spread_capture_usd + p.inventory_pnl_usd
+                );
+            }
+            for p in &self.mm_pnl_by_model() {
+                info!(
+                    "  model {}: spread_capture=${:.2} inventory=${:.2} total=${:.2}",
+                    p.model,
+                    p.spread_capture_usd,
+                    p.inventory_pnl_usd,
+                    p.spread_capture_usd + p.inventory_pnl_usd
+                );
+            }
+        }
+
         info!("==============================================");
     }
 
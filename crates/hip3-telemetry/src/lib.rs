@@ -11,7 +11,9 @@ pub mod error;
 pub mod logging;
 pub mod metrics;
 
-pub use daily_stats::{DailyStatsReporter, MarketDailyStats};
+pub use daily_stats::{
+    DailyStatsReporter, MarketDailyStats, MarkoutSummary, MmPnlSummary, PnlAttribution,
+};
 pub use error::{TelemetryError, TelemetryResult};
 pub use logging::init_logging;
 pub use metrics::Metrics;
@@ -17,8 +17,9 @@
 
 use once_cell::sync::Lazy;
 use prometheus::{
-    register_counter_vec, register_gauge, register_gauge_vec, register_histogram_vec,
-    register_int_gauge, CounterVec, Gauge, GaugeVec, HistogramVec, IntGauge,
+    register_counter_vec, register_gauge, register_gauge_vec, register_histogram,
+    register_histogram_vec, register_int_gauge, CounterVec, Gauge, GaugeVec, Histogram,
+    HistogramVec, IntGauge,
 };
 
 /// WebSocket connection state (1 = connected, 0 = disconnected).
@@ -134,6 +135,26 @@ pub static SPREAD_BPS: Lazy<GaugeVec> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Effective entry threshold in basis points (after auto-calibration).
+pub static EFFECTIVE_THRESHOLD_BPS: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_effective_threshold_bps",
+        "Effective dislocation entry threshold in basis points",
+        &["market_key"]
+    )
+    .unwrap()
+});
+
+/// Learned slippage estimate in basis points.
+pub static SLIPPAGE_ESTIMATE_BPS: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_slippage_estimate_bps",
+        "Slippage estimate learned from entry fills in basis points",
+        &["market_key"]
+    )
+    .unwrap()
+});
+
 /// Oracle age in milliseconds.
 pub static ORACLE_AGE_MS: Lazy<GaugeVec> = Lazy::new(|| {
     register_gauge_vec!(
@@ -361,6 +382,27 @@ pub static ENTRY_EDGE_BPS: Lazy<HistogramVec> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Signals dropped at execution time for staleness.
+/// Labels: market, reason (ttl/edge_gone)
+pub static SIGNALS_EXPIRED_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_signals_expired_total",
+        "Signals dropped at execution time because they expired",
+        &["market", "reason"]
+    )
+    .unwrap()
+});
+
+/// Signals suppressed because the oracle disagreed with the reference price.
+pub static ORACLE_SANITY_REJECTED_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_oracle_sanity_rejected_total",
+        "Signals suppressed because the oracle deviated from the reference price",
+        &["market"]
+    )
+    .unwrap()
+});
+
 /// Signal-to-order latency in milliseconds.
 pub static SIGNAL_TO_ORDER_LATENCY_MS: Lazy<HistogramVec> = Lazy::new(|| {
     register_histogram_vec!(
@@ -372,6 +414,333 @@ pub static SIGNAL_TO_ORDER_LATENCY_MS: Lazy<HistogramVec> = Lazy::new(|| {
     .unwrap()
 });
 
+/// WebSocket post round-trip latency in milliseconds.
+pub static WS_POST_ROUND_TRIP_MS: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "hip3_ws_post_round_trip_ms",
+        "Latency from WebSocket post send to its response in milliseconds",
+        vec![5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0]
+    )
+    .unwrap()
+});
+
+/// WebSocket ping/pong round trip in milliseconds, by connection.
+pub static WS_RTT_MS: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "hip3_ws_rtt_ms",
+        "WebSocket ping/pong round-trip time in milliseconds",
+        &["connection"],
+        vec![5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0]
+    )
+    .unwrap()
+});
+
+/// Market-data messages dropped by the inbound queue under overload.
+/// Labels: channel
+pub static WS_QUEUE_DROPPED_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_ws_queue_dropped_total",
+        "Market-data messages dropped (oldest first) by the inbound message queue",
+        &["channel"]
+    )
+    .unwrap()
+});
+
+/// Raw frames the frame recorder dropped because its queue was full.
+pub static FRAME_RECORDER_DROPPED_TOTAL: Lazy<prometheus::IntCounter> = Lazy::new(|| {
+    prometheus::register_int_counter!(
+        "hip3_frame_recorder_dropped_total",
+        "Inbound WebSocket frames not recorded because the recorder queue was full"
+    )
+    .unwrap()
+});
+
+/// Estimated local clock lead over exchange server time in milliseconds.
+pub static CLOCK_SKEW_MS: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "hip3_clock_skew_ms",
+        "Local clock minus exchange server time (incl. minimum feed delay) in milliseconds"
+    )
+    .unwrap()
+});
+
+/// p95 latency seen by the latency gate, by kind.
+pub static LATENCY_GATE_P95_MS: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_latency_gate_p95_ms",
+        "Windowed p95 latency evaluated by the latency gate (ms)",
+        &["kind"]
+    )
+    .unwrap()
+});
+
+/// Whether the latency gate is blocking entries.
+pub static LATENCY_DEGRADED: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "hip3_latency_degraded",
+        "Latency gate state (1=degraded, entries blocked)"
+    )
+    .unwrap()
+});
+
+/// Remaining per-market action budget in the current interval.
+pub static ACTION_BUDGET_REMAINING: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_action_budget_remaining",
+        "New orders left in the market's own quota this budget interval",
+        &["market"]
+    )
+    .unwrap()
+});
+
+/// Remaining shared overflow budget in the current interval.
+pub static ACTION_BUDGET_SHARED_REMAINING: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "hip3_action_budget_shared_remaining",
+        "New orders left in the shared overflow pool this budget interval"
+    )
+    .unwrap()
+});
+
+/// Current batch scheduler tick interval in milliseconds.
+pub static BATCH_INTERVAL_MS: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "hip3_batch_interval_ms",
+        "Effective batch scheduler tick interval in milliseconds"
+    )
+    .unwrap()
+});
+
+/// Batch scheduler queue depth.
+/// Labels: queue (cancel/modify/reduce_only/new_order)
+pub static BATCH_QUEUE_DEPTH: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_batch_queue_depth",
+        "Items waiting in each batch scheduler queue",
+        &["queue"]
+    )
+    .unwrap()
+});
+
+/// Entry fill slippage vs the signal's best price (TCA).
+pub static TCA_SLIPPAGE_BPS: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "hip3_tca_slippage_bps",
+        "Entry fill price vs signal best price in basis points (positive = worse)",
+        &["market"],
+        vec![-10.0, -5.0, -2.0, 0.0, 2.0, 5.0, 10.0, 20.0, 50.0]
+    )
+    .unwrap()
+});
+
+/// Signal detection to exchange fill latency (TCA).
+pub static TCA_SIGNAL_TO_FILL_MS: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "hip3_tca_signal_to_fill_ms",
+        "Latency from signal detection to exchange fill in milliseconds",
+        &["market"],
+        vec![10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0, 5000.0]
+    )
+    .unwrap()
+});
+
+/// Mean share of signal edge kept after slippage and fees (TCA).
+pub static TCA_EDGE_CAPTURE_RATIO: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_tca_edge_capture_ratio",
+        "Mean fraction of signal edge captured after slippage and fees",
+        &["market"]
+    )
+    .unwrap()
+});
+
+/// Fees paid on signal entries (TCA).
+pub static TCA_FEES_USD_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_tca_fees_usd_total",
+        "Fees paid on signal entry fills in USD",
+        &["market"]
+    )
+    .unwrap()
+});
+
+/// Inflight post items resolved after a WebSocket reconnect.
+/// Labels: outcome (confirmed/resubmitted/expired)
+pub static INFLIGHT_RECONCILED_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_inflight_reconciled_total",
+        "Inflight post items resolved by reconciliation after a reconnect",
+        &["outcome"]
+    )
+    .unwrap()
+});
+
+/// Orders dropped by the submit-time slippage guard.
+/// Labels: market
+pub static SLIPPAGE_REJECTED_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_slippage_rejected_total",
+        "Orders dropped at submit time for exceeding max slippage",
+        &["market"]
+    )
+    .unwrap()
+});
+
+/// Stale pending orders checked by the order sweeper.
+/// Labels: outcome (open/resolved/expired/unresolved)
+pub static ORDERS_SWEPT_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_orders_swept_total",
+        "Pending orders checked against the exchange after missing their orderUpdate",
+        &["outcome"]
+    )
+    .unwrap()
+});
+
+/// Margin buffer before liquidation: (account value - maintenance margin) / account value.
+/// Labels: account
+pub static LIQUIDATION_BUFFER_RATIO: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_liquidation_buffer_ratio",
+        "Share of account value above the maintenance margin",
+        &["account"]
+    )
+    .unwrap()
+});
+
+/// Distance from mark price to liquidation price in basis points.
+/// Labels: market
+pub static LIQUIDATION_DISTANCE_BPS: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_liquidation_distance_bps",
+        "Distance from mark to liquidation price in basis points",
+        &["market"]
+    )
+    .unwrap()
+});
+
+/// Positions selected for emergency de-risking.
+/// Labels: market
+pub static DERISK_TRIGGERED_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_derisk_triggered_total",
+        "Positions selected for emergency de-risk on a low liquidation buffer",
+        &["market"]
+    )
+    .unwrap()
+});
+
+/// Position drift found by reconciliation.
+/// Labels: market, kind
+pub static POSITION_DRIFT_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_position_drift_total",
+        "Differences between local and exchange positions found on resync",
+        &["market", "kind"]
+    )
+    .unwrap()
+});
+
+/// Total drift notional of the last reconciliation (USD).
+pub static POSITION_DRIFT_NOTIONAL: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "hip3_position_drift_notional",
+        "Notional of the exposure difference between local and exchange positions"
+    )
+    .unwrap()
+});
+
+/// Realized PnL of the current loss-limit period (USD).
+/// Labels: period (daily/weekly)
+pub static LOSS_PERIOD_PNL_USD: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_loss_period_pnl_usd",
+        "Realized PnL of the current UTC day/week for loss limits",
+        &["period"]
+    )
+    .unwrap()
+});
+
+/// Loss limit latch state (1 = latched).
+/// Labels: period (daily/weekly)
+pub static LOSS_LIMIT_LATCHED: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_loss_limit_latched",
+        "Loss limit latch state (1=entries paused)",
+        &["period"]
+    )
+    .unwrap()
+});
+
+/// Latest polled account equity (USD).
+pub static ACCOUNT_EQUITY_USD: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "hip3_account_equity_usd",
+        "Account value from clearinghouseState across trading accounts"
+    )
+    .unwrap()
+});
+
+/// Equity drawdown from the session high-water mark (percent).
+pub static EQUITY_DRAWDOWN_PCT: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "hip3_equity_drawdown_pct",
+        "Account equity drawdown from the session high-water mark (percent)"
+    )
+    .unwrap()
+});
+
+/// Hard stop level (0 = clear, 1 = entries blocked, 2 = full stop).
+pub static HARD_STOP_LEVEL: Lazy<Gauge> = Lazy::new(|| {
+    register_gauge!(
+        "hip3_hard_stop_level",
+        "Hard stop level (0=clear, 1=entries blocked, 2=full stop)"
+    )
+    .unwrap()
+});
+
+/// Hard stops cleared by auto-recovery, by trigger reason.
+pub static HARD_STOP_AUTO_RECOVERIES_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_hard_stop_auto_recoveries_total",
+        "Hard stops cleared by auto-recovery",
+        &["reason"]
+    )
+    .unwrap()
+});
+
+/// Blocks observe-only gates would have made.
+pub static GATE_OBSERVED_BLOCKS_TOTAL: Lazy<CounterVec> = Lazy::new(|| {
+    register_counter_vec!(
+        "hip3_gate_observed_blocks_total",
+        "Blocks observe-only gates would have made",
+        &["gate"]
+    )
+    .unwrap()
+});
+
+/// Share of a market's daily loss budget used today (1 = exhausted).
+pub static RISK_BUDGET_LOSS_USED_RATIO: Lazy<GaugeVec> = Lazy::new(|| {
+    register_gauge_vec!(
+        "hip3_risk_budget_loss_used_ratio",
+        "Share of the market's daily loss budget used (1=blocked for the day)",
+        &["market"]
+    )
+    .unwrap()
+});
+
+/// Markout of MM fills in basis points (positive = favorable).
+/// Labels: market, horizon (e.g. "1s", "5s", "30s"), model (quote model)
+pub static MM_MARKOUT_BPS: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "hip3_mm_markout_bps",
+        "Mid markout of MM fills in basis points (positive = favorable)",
+        &["market", "horizon", "model"],
+        vec![-50.0, -20.0, -10.0, -5.0, -2.0, 0.0, 2.0, 5.0, 10.0, 20.0, 50.0]
+    )
+    .unwrap()
+});
+
 /// Metrics facade for easy access.
 pub struct Metrics;
 
@@ -460,6 +829,20 @@ impl Metrics {
         SPREAD_BPS.with_label_values(&[market_key]).set(spread_bps);
     }
 
+    /// Update effective entry threshold.
+    pub fn effective_threshold(market_key: &str, threshold_bps: f64) {
+        EFFECTIVE_THRESHOLD_BPS
+            .with_label_values(&[market_key])
+            .set(threshold_bps);
+    }
+
+    /// Update learned slippage estimate.
+    pub fn slippage_estimate(market_key: &str, slippage_bps: f64) {
+        SLIPPAGE_ESTIMATE_BPS
+            .with_label_values(&[market_key])
+            .set(slippage_bps);
+    }
+
     /// Update oracle age.
     pub fn oracle_age(market_key: &str, age_ms: f64) {
         ORACLE_AGE_MS.with_label_values(&[market_key]).set(age_ms);
@@ -602,10 +985,198 @@ impl Metrics {
             .observe(edge_bps);
     }
 
+    /// Record a signal dropped for staleness before execution.
+    pub fn signal_expired(market: &str, reason: &str) {
+        SIGNALS_EXPIRED_TOTAL
+            .with_label_values(&[market, reason])
+            .inc();
+    }
+
+    /// Record a signal suppressed by the oracle sanity check.
+    pub fn oracle_sanity_rejected(market: &str) {
+        ORACLE_SANITY_REJECTED_TOTAL
+            .with_label_values(&[market])
+            .inc();
+    }
+
     /// Record signal-to-order latency in milliseconds.
     pub fn signal_to_order_latency(market: &str, latency_ms: f64) {
         SIGNAL_TO_ORDER_LATENCY_MS
             .with_label_values(&[market])
             .observe(latency_ms);
     }
+
+    /// Record WebSocket post round-trip latency in milliseconds.
+    pub fn ws_post_round_trip(latency_ms: f64) {
+        WS_POST_ROUND_TRIP_MS.observe(latency_ms);
+    }
+
+    /// Record a WebSocket ping/pong round trip of a connection.
+    pub fn ws_rtt(connection: &str, rtt_ms: f64) {
+        WS_RTT_MS.with_label_values(&[connection]).observe(rtt_ms);
+    }
+
+    /// Record a market-data or trade message dropped by the inbound queue.
+    pub fn ws_queue_dropped(channel: &str) {
+        WS_QUEUE_DROPPED_TOTAL.with_label_values(&[channel]).inc();
+    }
+
+    /// Record a raw frame dropped by the frame recorder.
+    pub fn frame_recorder_dropped() {
+        FRAME_RECORDER_DROPPED_TOTAL.inc();
+    }
+
+    /// Set the estimated local/exchange clock skew.
+    pub fn clock_skew(skew_ms: f64) {
+        CLOCK_SKEW_MS.set(skew_ms);
+    }
+
+    /// Set the latency gate's p95 of a latency kind and its blocking state.
+    pub fn latency_gate(kind: &str, p95_ms: Option<f64>, degraded: bool) {
+        LATENCY_GATE_P95_MS
+            .with_label_values(&[kind])
+            .set(p95_ms.unwrap_or(0.0));
+        LATENCY_DEGRADED.set(if degraded { 1.0 } else { 0.0 });
+    }
+
+    /// Set the remaining per-market action budget.
+    pub fn action_budget_remaining(market: &str, remaining: f64) {
+        ACTION_BUDGET_REMAINING
+            .with_label_values(&[market])
+            .set(remaining);
+    }
+
+    /// Set the remaining shared action budget.
+    pub fn action_budget_shared_remaining(remaining: f64) {
+        ACTION_BUDGET_SHARED_REMAINING.set(remaining);
+    }
+
+    /// Set the effective batch interval.
+    pub fn batch_interval(interval_ms: f64) {
+        BATCH_INTERVAL_MS.set(interval_ms);
+    }
+
+    /// Set a batch scheduler queue depth.
+    pub fn batch_queue_depth(queue: &str, depth: f64) {
+        BATCH_QUEUE_DEPTH.with_label_values(&[queue]).set(depth);
+    }
+
+    /// Record an entry fill joined to its signal.
+    pub fn tca_fill(market: &str, slippage_bps: f64, signal_to_fill_ms: f64, fee_usd: f64) {
+        TCA_SLIPPAGE_BPS
+            .with_label_values(&[market])
+            .observe(slippage_bps);
+        TCA_SIGNAL_TO_FILL_MS
+            .with_label_values(&[market])
+            .observe(signal_to_fill_ms);
+        if fee_usd > 0.0 {
+            TCA_FEES_USD_TOTAL
+                .with_label_values(&[market])
+                .inc_by(fee_usd);
+        }
+    }
+
+    /// Set the mean edge capture ratio.
+    pub fn tca_edge_capture_ratio(market: &str, ratio: f64) {
+        TCA_EDGE_CAPTURE_RATIO
+            .with_label_values(&[market])
+            .set(ratio);
+    }
+
+    /// Record an order dropped by the submit-time slippage guard.
+    pub fn slippage_rejected(market: &str) {
+        SLIPPAGE_REJECTED_TOTAL.with_label_values(&[market]).inc();
+    }
+
+    /// Record inflight items resolved by reconnect reconciliation.
+    pub fn inflight_reconciled(outcome: &str, count: u64) {
+        INFLIGHT_RECONCILED_TOTAL
+            .with_label_values(&[outcome])
+            .inc_by(count as f64);
+    }
+
+    /// Record stale pending orders checked by the order sweeper.
+    pub fn orders_swept(outcome: &str, count: u64) {
+        ORDERS_SWEPT_TOTAL
+            .with_label_values(&[outcome])
+            .inc_by(count as f64);
+    }
+
+    /// Set the margin buffer ratio of an account.
+    pub fn liquidation_buffer_ratio(account: &str, ratio: f64) {
+        LIQUIDATION_BUFFER_RATIO
+            .with_label_values(&[account])
+            .set(ratio);
+    }
+
+    /// Set the distance to liquidation of a position.
+    pub fn liquidation_distance_bps(market: &str, distance_bps: f64) {
+        LIQUIDATION_DISTANCE_BPS
+            .with_label_values(&[market])
+            .set(distance_bps);
+    }
+
+    /// Record a position selected for emergency de-risking.
+    pub fn derisk_triggered(market: &str) {
+        DERISK_TRIGGERED_TOTAL.with_label_values(&[market]).inc();
+    }
+
+    /// Record a position drift found by reconciliation.
+    pub fn position_drift(market: &str, kind: &str) {
+        POSITION_DRIFT_TOTAL
+            .with_label_values(&[market, kind])
+            .inc();
+    }
+
+    /// Set the drift notional of the last reconciliation.
+    pub fn position_drift_notional(notional: f64) {
+        POSITION_DRIFT_NOTIONAL.set(notional);
+    }
+
+    /// Set the polled account equity and its drawdown from the high-water mark.
+    pub fn account_equity(equity_usd: f64, drawdown_pct: f64) {
+        ACCOUNT_EQUITY_USD.set(equity_usd);
+        EQUITY_DRAWDOWN_PCT.set(drawdown_pct);
+    }
+
+    /// Set the realized PnL and latch state of a loss-limit period.
+    pub fn loss_limit(period: &str, pnl_usd: f64, latched: bool) {
+        LOSS_PERIOD_PNL_USD
+            .with_label_values(&[period])
+            .set(pnl_usd);
+        LOSS_LIMIT_LATCHED
+            .with_label_values(&[period])
+            .set(if latched { 1.0 } else { 0.0 });
+    }
+
+    /// Set the hard stop level.
+    pub fn hard_stop_level(level: u8) {
+        HARD_STOP_LEVEL.set(f64::from(level));
+    }
+
+    /// Record a hard stop cleared by auto-recovery.
+    pub fn hard_stop_auto_recovered(reason: &str) {
+        HARD_STOP_AUTO_RECOVERIES_TOTAL
+            .with_label_values(&[reason])
+            .inc();
+    }
+
+    /// Record a block an observe-only gate would have made.
+    pub fn gate_observed_block(gate: &str) {
+        GATE_OBSERVED_BLOCKS_TOTAL.with_label_values(&[gate]).inc();
+    }
+
+    /// Set the share of a market's daily loss budget used.
+    pub fn risk_budget_loss_used(market: &str, ratio: f64) {
+        RISK_BUDGET_LOSS_USED_RATIO
+            .with_label_values(&[market])
+            .set(ratio);
+    }
+
+    /// Record the markout of an MM fill at a horizon, by quote model.
+    pub fn mm_markout(market: &str, horizon: &str, model: &str, markout_bps: f64) {
+        MM_MARKOUT_BPS
+            .with_label_values(&[market, horizon, model])
+            .observe(markout_bps);
+    }
 }
@@ -2,10 +2,24 @@
 //!
 //! Handles connection lifecycle, automatic reconnection with exponential backoff,
 //! and subscription restoration after reconnection.
+//!
+//! With `dual_connection`, a standby connection runs alongside the primary
+//! with the same subscriptions. Both forward into the same message channel
+//! (deduplicated), so market data and order updates keep flowing while one
+//! of them reconnects, and posts fail over to the standby when the primary
+//! degrades.
+//!
+//! With `split_order_flow`, posts and the trading subscriptions (orderUpdates,
+//! userFills, userFundings) move to a dedicated order-flow connection, so a
+//! market-data flood cannot delay order acks and a market-data reconnect
+//! leaves READY-TRADING intact. Combined with `dual_connection`, each of the
+//! two connections gets its own standby.
 
+use crate::dedup::{MessageDeduplicator, DEDUP_WINDOW_MS};
 use crate::error::{WsError, WsResult};
 use crate::heartbeat::HeartbeatManager;
 use crate::message::{extract_subscription_type, WsMessage, WsRequest};
+use crate::queue::MessageSender;
 use crate::rate_limiter::RateLimiter;
 use crate::subscription::{ReadyState, SubscriptionManager};
 use crate::ws_write_handle::{WsOutbound, WsWriteHandle};
@@ -45,9 +59,33 @@ pub struct ConnectionConfig {
     pub heartbeat_timeout_ms: u64,
     /// Markets to subscribe to (coin symbols with asset indices).
     pub subscriptions: Vec<SubscriptionTarget>,
-    /// User address for trading subscriptions (orderUpdates, userFills).
+    /// User address for trading subscriptions (orderUpdates, userFills, userFundings).
     /// If None, trading subscriptions are skipped and READY-TRADING cannot be achieved.
     pub user_address: Option<String>,
+    /// Additional accounts (vault sub-accounts) whose orderUpdates and
+    /// userFills are also subscribed. Ignored without `user_address`.
+    pub extra_user_addresses: Vec<String>,
+    /// Also subscribe to l2Book per market (for depth-weighted detection).
+    pub subscribe_l2_book: bool,
+    /// Also subscribe to the public trades channel per market (trade tape).
+    pub subscribe_trades: bool,
+    /// Extra coins subscribed to activeAssetCtx only (lead-lag reference prices).
+    pub reference_coins: Vec<String>,
+    /// Subscribe to userEvents (liquidations, funding) and non-funding ledger
+    /// updates (deposits, withdrawals, transfers) instead of userFundings.
+    pub subscribe_user_events: bool,
+    /// Ping on every heartbeat check, even while messages flow, to sample
+    /// round-trip time (otherwise pings are only sent on idle connections).
+    pub rtt_probe: bool,
+    /// Run a hot-standby connection alongside the primary.
+    pub dual_connection: bool,
+    /// Standby endpoint (None = same as `url`).
+    pub standby_url: Option<String>,
+    /// Carry posts and trading subscriptions on a dedicated connection,
+    /// separate from market data.
+    pub split_order_flow: bool,
+    /// Order-flow endpoint (None = same as `url`).
+    pub order_flow_url: Option<String>,
 }
 
 impl Default for ConnectionConfig {
@@ -61,10 +99,46 @@ impl Default for ConnectionConfig {
             heartbeat_timeout_ms: 10000,
             subscriptions: Vec::new(),
             user_address: None,
+            extra_user_addresses: Vec::new(),
+            subscribe_l2_book: false,
+            subscribe_trades: false,
+            reference_coins: Vec::new(),
+            subscribe_user_events: false,
+            rtt_probe: false,
+            dual_connection: false,
+            standby_url: None,
+            split_order_flow: false,
+            order_flow_url: None,
         }
     }
 }
 
+impl ConnectionConfig {
+    /// Subscription set of the market-data connection(s) with split order
+    /// flow: everything but the trading channels.
+    fn market_data_only(&self) -> Self {
+        let mut config = self.clone();
+        config.user_address = None;
+        config.extra_user_addresses.clear();
+        config.split_order_flow = false;
+        config
+    }
+
+    /// Subscription set of the order-flow connection: trading channels only.
+    fn order_flow_only(&self) -> Self {
+        let mut config = self.clone();
+        if let Some(ref url) = self.order_flow_url {
+            config.url = url.clone();
+        }
+        config.subscriptions.clear();
+        config.reference_coins.clear();
+        config.subscribe_l2_book = false;
+        config.subscribe_trades = false;
+        config.split_order_flow = false;
+        config
+    }
+}
+
 /// Connection state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConnectionState {
@@ -82,7 +156,8 @@ pub struct ConnectionManager {
     /// Rate limiter for Phase B (execution).
     rate_limiter: Arc<RateLimiter>,
     heartbeat: Arc<HeartbeatManager>,
-    message_tx: mpsc::Sender<WsMessage>,
+    /// Inbound message queue (market data may be dropped under overload).
+    message_tx: MessageSender,
     reconnect_count: Arc<RwLock<u32>>,
     /// Outbound message sender (for WsWriteHandle).
     outbound_tx: mpsc::Sender<WsOutbound>,
@@ -90,11 +165,139 @@ pub struct ConnectionManager {
     outbound_rx: Arc<TokioMutex<mpsc::Receiver<WsOutbound>>>,
     /// Cancellation token for graceful shutdown.
     shutdown_token: CancellationToken,
+    /// Callbacks run when an established connection drops.
+    disconnect_hooks: RwLock<Vec<DisconnectHook>>,
+    /// Callbacks run with each measured ping/pong round trip.
+    rtt_hooks: RwLock<Vec<RttHook>>,
+    /// Callbacks run with every inbound text frame.
+    frame_hooks: RwLock<Vec<FrameHook>>,
+    /// Link name in logs and deduplication ("primary" / "standby" / "orders"
+    /// / "orders_standby").
+    role: &'static str,
+    /// Carries only the trading channels (order-flow connection or its
+    /// standby): readiness needs orderUpdates, not market data.
+    carries_order_flow_only: bool,
+    /// Hot-standby connection (primary or order-flow link with
+    /// `dual_connection` only).
+    standby: Option<Arc<ConnectionManager>>,
+    /// Dedicated order-flow connection (primary with `split_order_flow` only).
+    order_flow: Option<Arc<ConnectionManager>>,
+    /// Readiness of the other connection of a dual pair.
+    peer: Option<LinkHealth>,
+    /// Drops messages the other connection already forwarded.
+    dedup: Option<Arc<MessageDeduplicator>>,
+}
+
+/// Readiness view of one connection, shared with its failover peer.
+#[derive(Clone)]
+struct LinkHealth {
+    state: Arc<RwLock<ConnectionState>>,
+    subscriptions: Arc<SubscriptionManager>,
+}
+
+impl LinkHealth {
+    fn is_ready(&self) -> bool {
+        *self.state.read() == ConnectionState::Connected && self.subscriptions.is_ready()
+    }
+
+    fn is_md_ready(&self) -> bool {
+        *self.state.read() == ConnectionState::Connected && self.subscriptions.is_md_ready()
+    }
+
+    /// Order-flow connection readiness: orderUpdates ACKed, no market data.
+    fn is_order_flow_ready(&self) -> bool {
+        *self.state.read() == ConnectionState::Connected
+            && self.subscriptions.ready_state().order_updates_ready
+    }
+
+    fn order_updates_acked(&self) -> bool {
+        self.subscriptions.ready_state().order_updates_ready
+    }
 }
 
+fn now_ms() -> u64 {
+    chrono::Utc::now().timestamp_millis().max(0) as u64
+}
+
+/// Callback invoked when an established connection drops.
+pub type DisconnectHook = Arc<dyn Fn() + Send + Sync>;
+
+/// Callback invoked with the connection role ("primary" / "standby" /
+/// "orders" / "orders_standby") and a measured ping/pong round trip (ms).
+pub type RttHook = Arc<dyn Fn(&'static str, i64) + Send + Sync>;
+
+/// Callback invoked with the connection role and every inbound text frame,
+/// before parsing or deduplication.
+pub type FrameHook = Arc<dyn Fn(&'static str, &str) + Send + Sync>;
+
 impl ConnectionManager {
     /// Create a new connection manager.
-    pub fn new(config: ConnectionConfig, message_tx: mpsc::Sender<WsMessage>) -> Self {
+    ///
+    /// With `config.dual_connection`, also creates the standby connection,
+    /// and with `config.split_order_flow` the order-flow connection (with a
+    /// standby of its own if dual), all forwarding into the same
+    /// `message_tx`.
+    pub fn new(config: ConnectionConfig, message_tx: MessageSender) -> Self {
+        let order_flow = config.split_order_flow.then(|| {
+            Arc::new(Self::new_pair(
+                config.order_flow_only(),
+                message_tx.clone(),
+                true,
+            ))
+        });
+        let config = if order_flow.is_some() {
+            config.market_data_only()
+        } else {
+            config
+        };
+
+        let mut primary = Self::new_pair(config, message_tx, false);
+        primary.order_flow = order_flow;
+        primary
+    }
+
+    /// A connection plus, with `config.dual_connection`, its hot standby.
+    fn new_pair(config: ConnectionConfig, message_tx: MessageSender, order_flow: bool) -> Self {
+        let (role, standby_role) = if order_flow {
+            ("orders", "orders_standby")
+        } else {
+            ("primary", "standby")
+        };
+        let mut primary = Self::new_link(config.clone(), message_tx.clone(), role, order_flow);
+        if config.dual_connection {
+            let mut standby_config = config.clone();
+            standby_config.dual_connection = false;
+            if let Some(url) = config.standby_url {
+                standby_config.url = url;
+            }
+            let dedup = Arc::new(MessageDeduplicator::new(DEDUP_WINDOW_MS));
+            let mut standby = Self::new_link(standby_config, message_tx, standby_role, order_flow);
+            standby.peer = Some(primary.health());
+            standby.dedup = Some(dedup.clone());
+            primary.peer = Some(standby.health());
+            primary.dedup = Some(dedup);
+            primary.standby = Some(Arc::new(standby));
+        }
+        primary
+    }
+
+    /// A never-connected manager with every link (primary, standby, order
+    /// flow and its standby) for [`replay_frame`](Self::replay_frame).
+    pub fn for_replay(message_tx: MessageSender) -> Self {
+        let config = ConnectionConfig {
+            dual_connection: true,
+            split_order_flow: true,
+            ..Default::default()
+        };
+        Self::new(config, message_tx)
+    }
+
+    fn new_link(
+        config: ConnectionConfig,
+        message_tx: MessageSender,
+        role: &'static str,
+        carries_order_flow_only: bool,
+    ) -> Self {
         let (outbound_tx, outbound_rx) = mpsc::channel(100);
         Self {
             config: config.clone(),
@@ -110,20 +313,105 @@ impl ConnectionManager {
             outbound_tx,
             outbound_rx: Arc::new(TokioMutex::new(outbound_rx)),
             shutdown_token: CancellationToken::new(),
+            disconnect_hooks: RwLock::new(Vec::new()),
+            rtt_hooks: RwLock::new(Vec::new()),
+            frame_hooks: RwLock::new(Vec::new()),
+            role,
+            carries_order_flow_only,
+            standby: None,
+            order_flow: None,
+            peer: None,
+            dedup: None,
+        }
+    }
+
+    fn health(&self) -> LinkHealth {
+        LinkHealth {
+            state: self.state.clone(),
+            subscriptions: self.subscriptions.clone(),
+        }
+    }
+
+    /// Register a callback run each time an established connection drops.
+    ///
+    /// Hooks run on the reconnect task before the backoff, so they should
+    /// only spawn work. Not called on shutdown or on failed connect attempts.
+    /// With a standby, a drop only counts while the other connection is not
+    /// ready (order updates are lost only then). With split order flow, only
+    /// drops of the order-flow connection (or pair) count.
+    pub fn on_disconnect(&self, hook: DisconnectHook) {
+        if let Some(ref order_flow) = self.order_flow {
+            order_flow.on_disconnect(hook);
+            return;
+        }
+        if let Some(ref standby) = self.standby {
+            standby.on_disconnect(hook.clone());
+        }
+        self.disconnect_hooks.write().push(hook);
+    }
+
+    /// Register a callback run with each measured ping/pong round trip
+    /// (of every connection with a standby or split order flow).
+    pub fn on_rtt(&self, hook: RttHook) {
+        for link in self.standby.iter().chain(self.order_flow.iter()) {
+            link.on_rtt(hook.clone());
+        }
+        self.rtt_hooks.write().push(hook);
+    }
+
+    /// Register a callback run with every inbound text frame (of every
+    /// connection), e.g. to record them for replay.
+    ///
+    /// Hooks run on the message loop, so they must not block.
+    pub fn on_frame(&self, hook: FrameHook) {
+        for link in self.standby.iter().chain(self.order_flow.iter()) {
+            link.on_frame(hook.clone());
         }
+        self.frame_hooks.write().push(hook);
+    }
+
+    fn on_pong(&self) {
+        if let Some(rtt_ms) = self.heartbeat.record_pong() {
+            for hook in self.rtt_hooks.read().iter() {
+                hook(self.role, rtt_ms);
+            }
+        }
+    }
+
+    /// Standby connection, if dual connection is enabled.
+    pub fn standby(&self) -> Option<&Arc<ConnectionManager>> {
+        self.standby.as_ref()
+    }
+
+    /// Order-flow connection, if order flow is split from market data.
+    pub fn order_flow(&self) -> Option<&Arc<ConnectionManager>> {
+        self.order_flow.as_ref()
     }
 
     /// Get a write handle for sending messages.
     ///
     /// The write handle can be cloned and shared across tasks.
     /// It provides a channel-based API that is reconnect-safe.
+    /// With split order flow, posts go over the order-flow connection.
     pub fn write_handle(&self) -> WsWriteHandle {
-        WsWriteHandle::new(
+        if let Some(ref order_flow) = self.order_flow {
+            return order_flow.write_handle().order_flow_only();
+        }
+        let handle = WsWriteHandle::new(
             self.outbound_tx.clone(),
             self.rate_limiter.clone(),
             self.state.clone(),
             self.subscriptions.clone(),
-        )
+        );
+        match self.standby {
+            // Fail over within one heartbeat interval of primary silence
+            Some(ref standby) => handle.with_standby(
+                standby.write_handle(),
+                self.heartbeat.clone(),
+                self.config.heartbeat_interval_ms,
+            ),
+            None => handle,
+        }
     }
 
     /// Get current connection state.
@@ -132,13 +420,53 @@ impl ConnectionManager {
     }
 
     /// Get ready state (all subscriptions ready).
+    ///
+    /// With split order flow, `order_updates_ready` is the order-flow
+    /// connection's.
     pub fn ready_state(&self) -> ReadyState {
-        self.subscriptions.ready_state()
+        let mut state = self.subscriptions.ready_state();
+        if let Some(ref order_flow) = self.order_flow {
+            state.order_updates_ready = order_flow.health().order_updates_acked()
+                || order_flow
+                    .peer
+                    .as_ref()
+                    .is_some_and(LinkHealth::order_updates_acked);
+        }
+        state
     }
 
     /// Check if connection is ready for trading.
+    ///
+    /// With a standby, either connection being ready suffices. With split
+    /// order flow, only the order-flow connection (or its standby) counts: a
+    /// market-data reconnect does not revoke READY-TRADING (market-data
+    /// freshness is gated separately).
     pub fn is_ready(&self) -> bool {
-        self.state() == ConnectionState::Connected && self.subscriptions.is_ready()
+        if let Some(ref order_flow) = self.order_flow {
+            return order_flow.is_ready();
+        }
+        let own = if self.carries_order_flow_only {
+            self.health().is_order_flow_ready()
+        } else {
+            self.health().is_ready()
+        };
+        own || self.is_peer_ready()
+    }
+
+    /// Whether the other connection of a dual pair can carry trading.
+    fn is_peer_ready(&self) -> bool {
+        self.peer.as_ref().is_some_and(|peer| {
+            if self.carries_order_flow_only {
+                peer.is_order_flow_ready()
+            } else {
+                peer.is_ready()
+            }
+        })
+    }
+
+    /// Check if connection has market data (READY-MD, no orderUpdates needed).
+    pub fn is_md_ready(&self) -> bool {
+        self.health().is_md_ready() || self.peer.as_ref().is_some_and(LinkHealth::is_md_ready)
     }
 
     /// Signal graceful shutdown.
@@ -146,8 +474,11 @@ impl ConnectionManager {
     /// Cancels the shutdown token, which will cause both the message loop
     /// and reconnect loop to exit promptly.
     pub fn shutdown(&self) {
-        info!("ConnectionManager shutdown requested");
+        info!(role = self.role, "ConnectionManager shutdown requested");
         self.shutdown_token.cancel();
+        for link in self.standby.iter().chain(self.order_flow.iter()) {
+            link.shutdown();
+        }
     }
 
     /// Check if shutdown has been requested.
@@ -156,8 +487,31 @@ impl ConnectionManager {
     }
 
     /// Connect to WebSocket and run message loop.
+    ///
+    /// With a standby or split order flow, runs every connection until all
+    /// of them exit.
     pub async fn connect(&self) -> WsResult<()> {
-        self.connect_with_retry().await
+        let standby = async {
+            match self.standby {
+                Some(ref standby) => {
+                    info!(standby_url = %standby.config.url, "Dual WebSocket connection enabled");
+                    standby.connect_with_retry().await
+                }
+                None => Ok(()),
+            }
+        };
+        let order_flow = async {
+            match self.order_flow {
+                Some(ref order_flow) => {
+                    info!(order_flow_url = %order_flow.config.url, "Split order-flow connection enabled");
+                    Box::pin(order_flow.connect()).await
+                }
+                None => Ok(()),
+            }
+        };
+        let (primary, standby, order_flow) =
+            tokio::join!(self.connect_with_retry(), standby, order_flow);
+        primary.and(standby).and(order_flow)
     }
 
     async fn connect_with_retry(&self) -> WsResult<()> {
@@ -182,6 +536,7 @@ impl ConnectionManager {
                     error!(?e, "WebSocket connection error");
                 }
             }
+            let was_connected = self.state() == ConnectionState::Connected;
 
             // Check shutdown flag before reconnect attempt
             if self.is_shutdown() {
@@ -190,6 +545,18 @@ impl ConnectionManager {
                 return Ok(());
             }
 
+            if was_connected && self.is_peer_ready() {
+                warn!(
+                    role = self.role,
+                    "Connection dropped, peer connection still ready"
+                );
+            } else if was_connected {
+                let hooks = self.disconnect_hooks.read().clone();
+                for hook in hooks {
+                    hook();
+                }
+            }
+
             // Check if we should reconnect
             attempt += 1;
             *self.reconnect_count.write() = attempt;
@@ -227,7 +594,7 @@ impl ConnectionManager {
     }
 
     async fn try_connect(&self) -> WsResult<()> {
-        info!(url = %self.config.url, "Connecting to WebSocket");
+        info!(role = self.role, url = %self.config.url, "Connecting to WebSocket");
 
         // P2-8: TCP_NODELAY for lower latency (disable Nagle's algorithm)
         let (ws_stream, _response) =
@@ -267,7 +634,7 @@ impl ConnectionManager {
                 msg = read.next() => {
                     match msg {
                         Some(Ok(Message::Text(text))) => {
-                            self.handle_text_message(&text).await?;
+                            self.handle_text_message(&text, now_ms())?;
                         }
                         Some(Ok(Message::Ping(data))) => {
                             debug!("Received ping, sending pong");
@@ -275,7 +642,7 @@ impl ConnectionManager {
                         }
                         Some(Ok(Message::Pong(_))) => {
                             debug!("Received pong");
-                            self.heartbeat.record_pong();
+                            self.on_pong();
                         }
                         Some(Ok(Message::Close(frame))) => {
                             let (code, reason) = frame
@@ -327,7 +694,10 @@ impl ConnectionManager {
                     }
 
                     // P0-3: Only send ping if actually needed (not waiting for pong and no recent messages)
-                    if self.heartbeat.should_send_heartbeat() {
+                    // RTT probe: ping anyway, unless one is outstanding
+                    if self.heartbeat.should_send_heartbeat()
+                        || (self.config.rtt_probe && !self.heartbeat.is_waiting_for_pong())
+                    {
                         let ping = WsRequest::ping();
                         let msg = serde_json::to_string(&ping)?;
                         write.send(Message::Text(msg)).await?;
@@ -339,19 +709,50 @@ impl ConnectionManager {
         }
     }
 
-    async fn handle_text_message(&self, text: &str) -> WsResult<()> {
+    /// Feed a recorded frame through the inbound path of the link that
+    /// received it, as if it had arrived at `received_ms`: pongs and
+    /// subscription ACKs are consumed, the other link's copies deduplicated,
+    /// and the rest forwarded to the message channel.
+    ///
+    /// `connection` is the recorded link role; unknown roles go to this
+    /// connection. Links exist only as configured, so a replay manager is
+    /// built with [`ConnectionManager::for_replay`].
+    pub fn replay_frame(
+        &self,
+        connection: &str,
+        text: &str,
+        received_ms: u64,
+    ) -> Result<(), serde_json::Error> {
+        let order_flow = self.order_flow.as_deref();
+        let link = [
+            Some(self),
+            self.standby.as_deref(),
+            order_flow,
+            order_flow.and_then(|o| o.standby.as_deref()),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|link| link.role == connection)
+        .unwrap_or(self);
+        link.handle_text_message(text, received_ms)
+    }
+
+    fn handle_text_message(&self, text: &str, received_ms: u64) -> Result<(), serde_json::Error> {
         self.heartbeat.record_message();
+        for hook in self.frame_hooks.read().iter() {
+            hook(self.role, text);
+        }
 
         // Parse message
-        let msg: WsMessage = serde_json::from_str(text)?;
+        let mut msg: WsMessage = serde_json::from_str(text)?;
 
         // Handle different message types
-        match &msg {
+        match &mut msg {
             WsMessage::Pong(pong_msg) => {
                 // Application-level pong from Hyperliquid
                 if pong_msg.is_pong() {
                     debug!("Received application-level pong");
-                    self.heartbeat.record_pong();
+                    self.on_pong();
                 }
                 // Don't forward pong to message channel
                 return Ok(());
@@ -383,11 +784,24 @@ impl ConnectionManager {
                 }
                 // Update subscription state for market data channels
                 self.subscriptions.handle_message(&channel_msg.channel);
+
+                // Dual connection: drop the copy the peer already forwarded
+                if let Some(ref dedup) = self.dedup {
+                    if dedup.is_duplicate(
+                        self.role,
+                        &channel_msg.channel,
+                        text,
+                        &mut channel_msg.data,
+                        received_ms,
+                    ) {
+                        return Ok(());
+                    }
+                }
             }
         }
 
         // Forward data messages to message channel
-        if self.message_tx.send(msg).await.is_err() {
+        if self.message_tx.send(msg).is_err() {
             warn!("Message receiver dropped");
         }
 
@@ -417,7 +831,11 @@ impl ConnectionManager {
         tokio::time::sleep(Duration::from_millis(1000)).await;
         info!("Starting subscriptions after initial delay");
 
-        let total_subs = self.config.subscriptions.len() * 2; // bbo + activeAssetCtx per target
+        // bbo + activeAssetCtx (+ l2Book, trades) per target
+        let subs_per_target = 2
+            + usize::from(self.config.subscribe_l2_book)
+            + usize::from(self.config.subscribe_trades);
+        let total_subs = self.config.subscriptions.len() * subs_per_target;
         let mut subs_sent = 0;
 
         for target in self.config.subscriptions.iter() {
@@ -454,6 +872,40 @@ impl ConnectionManager {
             // Drain response and wait before next subscription
             self.drain_and_wait(write, read, 100).await?;
 
+            // Subscribe to l2Book for this coin (depth-weighted detection only)
+            if self.config.subscribe_l2_book {
+                let book_sub = serde_json::json!({
+                    "type": "l2Book",
+                    "coin": target.coin
+                });
+                let book_req = WsRequest::subscribe(book_sub);
+                let book_msg = serde_json::to_string(&book_req)?;
+                write.send(Message::Text(book_msg)).await?;
+                subs_sent += 1;
+
+                self.subscriptions
+                    .add_subscription(format!("l2Book:{}", target.coin));
+
+                self.drain_and_wait(write, read, 100).await?;
+            }
+
+            // Subscribe to public trades for this coin (trade tape only)
+            if self.config.subscribe_trades {
+                let trades_sub = serde_json::json!({
+                    "type": "trades",
+                    "coin": target.coin
+                });
+                let trades_req = WsRequest::subscribe(trades_sub);
+                let trades_msg = serde_json::to_string(&trades_req)?;
+                write.send(Message::Text(trades_msg)).await?;
+                subs_sent += 1;
+
+                self.subscriptions
+                    .add_subscription(format!("trades:{}", target.coin));
+
+                self.drain_and_wait(write, read, 100).await?;
+            }
+
             if subs_sent % 10 == 0 {
                 info!(
                     progress = format!("{}/{}", subs_sent, total_subs),
@@ -463,8 +915,25 @@ impl ConnectionManager {
             }
         }
 
+        // Reference coins: oracle only, no BBO
+        for coin in self.config.reference_coins.iter() {
+            let ctx_sub = serde_json::json!({
+                "type": "activeAssetCtx",
+                "coin": coin
+            });
+            let ctx_req = WsRequest::subscribe(ctx_sub);
+            let ctx_msg = serde_json::to_string(&ctx_req)?;
+            write.send(Message::Text(ctx_msg)).await?;
+
+            self.subscriptions
+                .add_subscription(format!("activeAssetCtx:{}", coin));
+
+            self.drain_and_wait(write, read, 100).await?;
+        }
+
         info!(
             total = total_subs,
+            references = self.config.reference_coins.len(),
             "All market data subscriptions sent and responses drained"
         );
 
@@ -472,6 +941,9 @@ impl ConnectionManager {
         if let Some(ref user_address) = self.config.user_address {
             self.subscribe_trading_channels(write, read, user_address)
                 .await?;
+            for extra in &self.config.extra_user_addresses {
+                self.subscribe_trading_channels(write, read, extra).await?;
+            }
         } else {
             info!("No user_address configured, skipping trading subscriptions");
         }
@@ -479,7 +951,8 @@ impl ConnectionManager {
         Ok(())
     }
 
-    /// Subscribe to orderUpdates and userFills for a user.
+    /// Subscribe to orderUpdates, userFills and userFundings (or userEvents
+    /// and ledger updates) for a user.
     /// Call after market data subscriptions to achieve READY-TRADING.
     async fn subscribe_trading_channels(
         &self,
@@ -516,6 +989,35 @@ impl ConnectionManager {
         // Drain response and wait
         self.drain_and_wait(write, read, 100).await?;
 
+        if self.config.subscribe_user_events {
+            // userEvents carries funding too: subscribing to userFundings as
+            // well would deliver every payment twice
+            let user_events_req =
+                SubscriptionManager::user_events_subscription_request(user_address);
+            write.send(Message::Text(user_events_req)).await?;
+            self.subscriptions
+                .add_subscription("userEvents".to_string());
+
+            self.drain_and_wait(write, read, 100).await?;
+
+            let ledger_req = SubscriptionManager::ledger_updates_subscription_request(user_address);
+            write.send(Message::Text(ledger_req)).await?;
+            self.subscriptions
+                .add_subscription("userNonFundingLedgerUpdates".to_string());
+
+            self.drain_and_wait(write, read, 100).await?;
+        } else {
+            // Subscribe to userFundings
+            let user_fundings_req =
+                SubscriptionManager::user_fundings_subscription_request(user_address);
+            write.send(Message::Text(user_fundings_req)).await?;
+            self.subscriptions
+                .add_subscription("userFundings".to_string());
+
+            // Drain response and wait
+            self.drain_and_wait(write, read, 100).await?;
+        }
+
         info!(user = %user_address, "Trading subscriptions sent");
         Ok(())
     }
@@ -553,7 +1055,7 @@ impl ConnectionManager {
                             // Use the same handler as the main loop to ensure consistent state updates
                             // (heartbeat, pong, subscriptions, inflight, message forwarding)
                             // Propagate errors to caller for proper reconnection handling
-                            self.handle_text_message(&text).await?;
+                            self.handle_text_message(&text, now_ms())?;
                         }
                         Some(Ok(Message::Ping(data))) => {
                             write.send(Message::Pong(data)).await?;
@@ -662,6 +1164,87 @@ mod tests {
         assert_eq!(config.heartbeat_interval_ms, 45000);
     }
 
+    #[test]
+    fn test_split_order_flow_readiness() {
+        let (message_tx, _message_rx) = crate::queue::message_queue(16);
+        let config = ConnectionConfig {
+            url: "wss://md.example".to_string(),
+            subscriptions: vec![SubscriptionTarget {
+                coin: "xyz:GOLD".to_string(),
+                asset_idx: 110026,
+            }],
+            user_address: Some("0xabc".to_string()),
+            split_order_flow: true,
+            order_flow_url: Some("wss://orders.example".to_string()),
+            ..Default::default()
+        };
+        let manager = ConnectionManager::new(config, message_tx);
+        let orders = manager.order_flow().unwrap();
+
+        // Per-connection subscription sets
+        assert!(manager.config.user_address.is_none());
+        assert_eq!(manager.config.subscriptions.len(), 1);
+        assert_eq!(orders.config.url, "wss://orders.example");
+        assert!(orders.config.subscriptions.is_empty());
+        assert_eq!(orders.config.user_address.as_deref(), Some("0xabc"));
+
+        // Market-data reconnect leaves READY-TRADING intact
+        *orders.state.write() = ConnectionState::Connected;
+        orders.subscriptions.mark_order_updates_ready();
+        *manager.state.write() = ConnectionState::Reconnecting;
+        assert!(manager.is_ready());
+        assert!(!manager.is_md_ready());
+        assert!(manager.ready_state().order_updates_ready);
+        assert!(manager.write_handle().is_ready());
+
+        // Order-flow reconnect revokes it
+        *orders.state.write() = ConnectionState::Reconnecting;
+        assert!(!manager.is_ready());
+        assert!(!manager.write_handle().is_ready());
+    }
+
+    #[test]
+    fn test_split_order_flow_with_dual_connection() {
+        let (message_tx, _message_rx) = crate::queue::message_queue(16);
+        let config = ConnectionConfig {
+            url: "wss://md.example".to_string(),
+            subscriptions: vec![SubscriptionTarget {
+                coin: "xyz:GOLD".to_string(),
+                asset_idx: 110026,
+            }],
+            user_address: Some("0xabc".to_string()),
+            dual_connection: true,
+            standby_url: Some("wss://standby.example".to_string()),
+            split_order_flow: true,
+            order_flow_url: Some("wss://orders.example".to_string()),
+            ..Default::default()
+        };
+        let manager = ConnectionManager::new(config, message_tx);
+        let orders = manager.order_flow().unwrap();
+        let orders_standby = orders.standby().expect("order flow has a standby");
+        assert!(manager.standby().is_some());
+        assert_eq!(orders_standby.role, "orders_standby");
+        assert_eq!(orders_standby.config.url, "wss://standby.example");
+        assert!(orders_standby.config.subscriptions.is_empty());
+        assert_eq!(orders_standby.config.user_address.as_deref(), Some("0xabc"));
+
+        // Order-flow primary reconnecting: trading fails over to its standby
+        *orders.state.write() = ConnectionState::Reconnecting;
+        *orders_standby.state.write() = ConnectionState::Connected;
+        orders_standby.subscriptions.mark_order_updates_ready();
+        assert!(manager.is_ready());
+        assert!(manager.ready_state().order_updates_ready);
+        assert!(orders.is_peer_ready());
+        let handle = manager.write_handle();
+        assert!(handle.is_ready());
+        assert!(handle.is_failed_over());
+
+        // Both order-flow links down
+        *orders_standby.state.write() = ConnectionState::Reconnecting;
+        assert!(!manager.is_ready());
+        assert!(!manager.write_handle().is_ready());
+    }
+
     // ========================================================================
     // process_subscription_response tests
     // ========================================================================
@@ -739,4 +1322,36 @@ mod tests {
         assert!(result, "Should handle fallback format");
         assert!(subs.ready_state().order_updates_ready);
     }
+
+    #[test]
+    fn test_replay_frame_through_inbound_path() {
+        let (message_tx, mut message_rx) = crate::queue::message_queue(16);
+        let manager = ConnectionManager::for_replay(message_tx);
+        let bbo = r#"{"channel":"bbo","data":{"coin":"xyz:GOLD","time":1000,"bbo":[null,null]}}"#;
+        let ack = r#"{"channel":"subscriptionResponse","data":{"method":"subscribe","subscription":{"type":"orderUpdates","user":"0xabc"}}}"#;
+
+        // Pongs and ACKs are consumed; the ACK reaches the order-flow link
+        manager
+            .replay_frame("primary", r#"{"channel":"pong"}"#, 0)
+            .unwrap();
+        manager.replay_frame("orders", ack, 0).unwrap();
+        assert!(message_rx.try_recv().is_none());
+        assert!(
+            manager
+                .order_flow()
+                .unwrap()
+                .ready_state()
+                .order_updates_ready
+        );
+
+        // The standby's copy is dropped at the recorded receive time
+        manager.replay_frame("primary", bbo, 1_000).unwrap();
+        manager.replay_frame("standby", bbo, 1_010).unwrap();
+        assert_eq!(message_rx.try_recv().unwrap().channel(), Some("bbo"));
+        assert!(message_rx.try_recv().is_none());
+        manager.replay_frame("standby", bbo, 10_000).unwrap();
+        assert!(message_rx.try_recv().is_some());
+
+        assert!(manager.replay_frame("primary", "not json", 0).is_err());
+    }
 }
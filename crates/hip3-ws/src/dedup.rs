@@ -0,0 +1,267 @@
+//! Cross-connection message deduplication.
+//!
+//! With a standby connection, every subscribed channel message arrives
+//! twice. Both links forward into the same message channel, so the copy
+//! seen second is dropped here. Only copies from the *other* link are
+//! duplicates: a link repeating an identical message (e.g. an unchanged
+//! activeAssetCtx) still passes, so freshness tracking downstream is kept.
+//!
+//! Market data is keyed by the raw frame text within [`DEDUP_WINDOW_MS`],
+//! in hash-sharded state so the links rarely contend. User channel events
+//! are keyed one by one by exchange ids (fill tid, order oid and status)
+//! within [`USER_DEDUP_WINDOW_MS`]: a standby lagging by seconds, or
+//! batching the same fills into other messages, must not deliver a fill
+//! twice.
+
+use parking_lot::Mutex;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+
+use crate::message::is_order_updates_channel;
+
+/// Window (ms) within which the other link's copy counts as a duplicate.
+pub const DEDUP_WINDOW_MS: u64 = 2000;
+
+/// Window (ms) for user channel events.
+pub const USER_DEDUP_WINDOW_MS: u64 = 600_000;
+
+/// Market data state shards.
+const SHARDS: usize = 16;
+
+#[derive(Debug, Default)]
+struct DedupState {
+    /// Message key → (link that delivered it first, receive time).
+    seen: HashMap<u64, (&'static str, u64)>,
+    /// (receive time, key), oldest first, for expiry.
+    order: VecDeque<(u64, u64)>,
+}
+
+impl DedupState {
+    fn is_duplicate(&mut self, link: &'static str, key: u64, window_ms: u64, now_ms: u64) -> bool {
+        let cutoff = now_ms.saturating_sub(window_ms);
+        while let Some(&(at_ms, old)) = self.order.front() {
+            if at_ms >= cutoff {
+                break;
+            }
+            self.order.pop_front();
+            if self
+                .seen
+                .get(&old)
+                .is_some_and(|&(_, seen_ms)| seen_ms == at_ms)
+            {
+                self.seen.remove(&old);
+            }
+        }
+
+        if let Some(&(first_link, _)) = self.seen.get(&key) {
+            if first_link != link {
+                return true;
+            }
+        }
+        self.seen.insert(key, (link, now_ms));
+        self.order.push_back((now_ms, key));
+        false
+    }
+}
+
+/// Drops messages already delivered by the peer connection.
+#[derive(Debug)]
+pub struct MessageDeduplicator {
+    window_ms: u64,
+    shards: Vec<Mutex<DedupState>>,
+    user: Mutex<DedupState>,
+}
+
+impl MessageDeduplicator {
+    /// Create a deduplicator with the given market data window.
+    #[must_use]
+    pub fn new(window_ms: u64) -> Self {
+        Self {
+            window_ms,
+            shards: (0..SHARDS).map(|_| Mutex::default()).collect(),
+            user: Mutex::default(),
+        }
+    }
+
+    /// Whether `link` received a message the other link already delivered
+    /// within the window. Non-duplicates are recorded as seen on `link`.
+    ///
+    /// `text` is the raw frame; market data payloads carry the exchange
+    /// time, so identical frames are the same event. User channel events
+    /// are deduplicated one by one, since the links may batch them
+    /// differently: events the other link delivered are removed from
+    /// `data`, and the message is a duplicate once none is left.
+    pub fn is_duplicate(
+        &self,
+        link: &'static str,
+        channel: &str,
+        text: &str,
+        data: &mut serde_json::Value,
+        now_ms: u64,
+    ) -> bool {
+        if is_user_channel(channel) {
+            let mut user = self.user.lock();
+            let user_key = data
+                .get("user")
+                .and_then(|u| u.as_str())
+                .unwrap_or("")
+                .to_string();
+            let events = if is_order_updates_channel(channel) {
+                data.as_array_mut()
+            } else {
+                data.get_mut("fills").and_then(|f| f.as_array_mut())
+            };
+            let Some(events) = events.filter(|events| {
+                events
+                    .iter()
+                    .any(|event| user_event_key(channel, &user_key, event).is_some())
+            }) else {
+                return user.is_duplicate(link, hash_of(text), USER_DEDUP_WINDOW_MS, now_ms);
+            };
+            events.retain(|event| {
+                user_event_key(channel, &user_key, event).map_or(true, |key| {
+                    !user.is_duplicate(link, key, USER_DEDUP_WINDOW_MS, now_ms)
+                })
+            });
+            return events.is_empty();
+        }
+        let key = hash_of(text);
+        self.shards[key as usize % SHARDS]
+            .lock()
+            .is_duplicate(link, key, self.window_ms, now_ms)
+    }
+}
+
+fn is_user_channel(channel: &str) -> bool {
+    is_order_updates_channel(channel)
+        || matches!(
+            channel,
+            "userFills" | "user" | "userFundings" | "userNonFundingLedgerUpdates"
+        )
+}
+
+fn hash_of(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Exchange id of a user channel event: (user, tid) of a fill, or
+/// (oid, status, status time) of an order update. None if it has none.
+fn user_event_key(channel: &str, user: &str, event: &serde_json::Value) -> Option<u64> {
+    let id = if is_order_updates_channel(channel) {
+        format!(
+            "{}:{}:{}",
+            event.get("order")?.get("oid")?.as_u64()?,
+            event.get("status")?.as_str()?,
+            event.get("statusTimestamp")?.as_u64()?
+        )
+    } else {
+        // Both sides of a trade between two own accounts share the tid
+        format!("{user}:{}", event.get("tid")?.as_u64()?)
+    };
+    Some(hash_of((channel, id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn is_dup(
+        dedup: &MessageDeduplicator,
+        link: &'static str,
+        channel: &str,
+        data: &serde_json::Value,
+        now_ms: u64,
+    ) -> bool {
+        let text = json!({"channel": channel, "data": data}).to_string();
+        dedup.is_duplicate(link, channel, &text, &mut data.clone(), now_ms)
+    }
+
+    #[test]
+    fn test_dedup_across_links_only() {
+        let dedup = MessageDeduplicator::new(DEDUP_WINDOW_MS);
+        let bbo = json!({"coin": "BTC", "time": 1000, "bbo": [null, null]});
+        let ctx = json!({"coin": "BTC", "ctx": {"oraclePx": "100"}});
+
+        // Standby's copy of the primary's message is dropped
+        assert!(!is_dup(&dedup, "primary", "bbo", &bbo, 0));
+        assert!(is_dup(&dedup, "standby", "bbo", &bbo, 15));
+        // Same payload on another channel is distinct
+        assert!(!is_dup(&dedup, "standby", "l2Book", &bbo, 20));
+
+        // A link repeating its own message is not deduplicated
+        assert!(!is_dup(&dedup, "primary", "activeAssetCtx", &ctx, 100));
+        assert!(!is_dup(&dedup, "primary", "activeAssetCtx", &ctx, 1100));
+        assert!(is_dup(&dedup, "standby", "activeAssetCtx", &ctx, 1150));
+
+        // Outside the window the other link's copy passes
+        assert!(!is_dup(&dedup, "standby", "bbo", &bbo, 5000));
+        assert!(is_dup(&dedup, "primary", "bbo", &bbo, 5010));
+    }
+
+    #[test]
+    fn test_user_channels_dedup_by_exchange_id() {
+        let dedup = MessageDeduplicator::new(DEDUP_WINDOW_MS);
+        let fills = json!({"user": "0xa", "fills": [{"tid": 7, "px": "100"}]});
+
+        // A standby lagging 30 s still has its copy of the fill dropped
+        assert!(!is_dup(&dedup, "primary", "userFills", &fills, 0));
+        assert!(is_dup(&dedup, "standby", "userFills", &fills, 30_000));
+
+        // Same fill with a differently serialized frame (snapshot flag)
+        let snapshot =
+            json!({"user": "0xa", "isSnapshot": true, "fills": [{"tid": 7, "px": "100"}]});
+        assert!(is_dup(&dedup, "standby", "userFills", &snapshot, 31_000));
+
+        // The other side of a trade between own accounts shares the tid
+        let other_side = json!({"user": "0xb", "fills": [{"tid": 7, "px": "100"}]});
+        assert!(!is_dup(&dedup, "standby", "userFills", &other_side, 32_000));
+
+        // Order updates by oid and status
+        let open = json!([{"order": {"oid": 9}, "status": "open", "statusTimestamp": 5}]);
+        let filled = json!([{"order": {"oid": 9}, "status": "filled", "statusTimestamp": 6}]);
+        assert!(!is_dup(&dedup, "primary", "orderUpdates", &open, 0));
+        assert!(!is_dup(&dedup, "standby", "orderUpdates", &filled, 10));
+        assert!(is_dup(&dedup, "standby", "orderUpdates", &open, 20_000));
+    }
+
+    #[test]
+    fn test_user_events_batched_differently_across_links() {
+        let dedup = MessageDeduplicator::new(DEDUP_WINDOW_MS);
+        let fill = |tid: u64| json!({"tid": tid, "px": "100"});
+
+        // Primary delivers fills 1 and 2 together, then fill 3 alone
+        let first = json!({"user": "0xa", "fills": [fill(1), fill(2)]});
+        let second = json!({"user": "0xa", "fills": [fill(3)]});
+        assert!(!is_dup(&dedup, "primary", "userFills", &first, 0));
+        assert!(!is_dup(&dedup, "primary", "userFills", &second, 10));
+
+        // Standby batches 2 and 3 with a new fill 4: only 4 passes
+        let mut batch = json!({"user": "0xa", "fills": [fill(2), fill(3), fill(4)]});
+        let text = json!({"channel": "userFills", "data": batch}).to_string();
+        assert!(!dedup.is_duplicate("standby", "userFills", &text, &mut batch, 20));
+        assert_eq!(batch["fills"], json!([fill(4)]));
+
+        // Standby's single copy of fill 1 is dropped whole
+        let single = json!({"user": "0xa", "fills": [fill(1)]});
+        assert!(is_dup(&dedup, "standby", "userFills", &single, 30));
+
+        // Order updates split differently
+        let open =
+            |oid: u64| json!({"order": {"oid": oid}, "status": "open", "statusTimestamp": 5});
+        assert!(!is_dup(
+            &dedup,
+            "primary",
+            "orderUpdates",
+            &json!([open(1)]),
+            0
+        ));
+        let mut updates = json!([open(1), open(2)]);
+        let text = json!({"channel": "orderUpdates", "data": updates}).to_string();
+        assert!(!dedup.is_duplicate("standby", "orderUpdates", &text, &mut updates, 10));
+        assert_eq!(updates, json!([open(2)]));
+    }
+}
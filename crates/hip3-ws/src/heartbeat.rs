@@ -23,6 +23,8 @@ pub struct HeartbeatManager {
     last_message: Arc<RwLock<DateTime<Utc>>>,
     /// Whether we're waiting for pong.
     waiting_for_pong: Arc<RwLock<bool>>,
+    /// Last ping/pong round trip (ms).
+    last_rtt_ms: Arc<RwLock<Option<i64>>>,
 }
 
 impl HeartbeatManager {
@@ -35,6 +37,7 @@ impl HeartbeatManager {
             last_pong: Arc::new(RwLock::new(None)),
             last_message: Arc::new(RwLock::new(Utc::now())),
             waiting_for_pong: Arc::new(RwLock::new(false)),
+            last_rtt_ms: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -55,16 +58,24 @@ impl HeartbeatManager {
     }
 
     /// Record that a pong was received.
-    pub fn record_pong(&self) {
+    ///
+    /// Returns the round-trip time (ms) when it answers an outstanding ping.
+    pub fn record_pong(&self) -> Option<i64> {
         let now = Utc::now();
         *self.last_pong.write() = Some(now);
-        *self.waiting_for_pong.write() = false;
+        let was_waiting = std::mem::replace(&mut *self.waiting_for_pong.write(), false);
 
         // Calculate round-trip time
-        if let Some(ping_time) = *self.last_ping.read() {
-            let rtt_ms = (now - ping_time).num_milliseconds();
-            debug!(rtt_ms, "Received pong");
-        }
+        let ping_time = (*self.last_ping.read()).filter(|_| was_waiting)?;
+        let rtt_ms = (now - ping_time).num_milliseconds();
+        *self.last_rtt_ms.write() = Some(rtt_ms);
+        debug!(rtt_ms, "Received pong");
+        Some(rtt_ms)
+    }
+
+    /// Whether a ping is awaiting its pong.
+    pub fn is_waiting_for_pong(&self) -> bool {
+        *self.waiting_for_pong.read()
     }
 
     /// Record that any message was received.
@@ -115,6 +126,7 @@ impl HeartbeatManager {
             last_message: *self.last_message.read(),
             waiting_for_pong: *self.waiting_for_pong.read(),
             time_since_last_message_ms: self.time_since_last_message_ms(),
+            last_rtt_ms: *self.last_rtt_ms.read(),
         }
     }
 }
@@ -127,6 +139,7 @@ pub struct HeartbeatStats {
     pub last_message: DateTime<Utc>,
     pub waiting_for_pong: bool,
     pub time_since_last_message_ms: i64,
+    pub last_rtt_ms: Option<i64>,
 }
 
 #[cfg(test)]
@@ -147,7 +160,11 @@ mod tests {
         hb.record_ping();
         assert!(*hb.waiting_for_pong.read());
 
-        hb.record_pong();
+        assert!(hb.record_pong().is_some_and(|rtt| rtt >= 0));
         assert!(!*hb.waiting_for_pong.read());
+
+        // Unsolicited pong: no round trip to measure
+        assert!(hb.record_pong().is_none());
+        assert!(hb.stats().last_rtt_ms.is_some());
     }
 }
@@ -5,23 +5,36 @@
 //! - Subscription management and READY state tracking
 //! - Heartbeat monitoring (45s ping, pong timeout detection)
 //! - Rate limiting (2000 msg/min, 100 inflight posts)
-//! - Channel-based message routing
+//! - Channel-based message routing with latest-wins backpressure for market data
+//! - Optional hot-standby connection with post failover
 
 pub mod connection;
+pub mod dedup;
 pub mod error;
 pub mod heartbeat;
 pub mod message;
+pub mod queue;
 pub mod rate_limiter;
 pub mod subscription;
 pub mod ws_write_handle;
 
-pub use connection::{ConnectionConfig, ConnectionManager, ConnectionState, SubscriptionTarget};
+pub use connection::{
+    ConnectionConfig, ConnectionManager, ConnectionState, DisconnectHook, FrameHook, RttHook,
+    SubscriptionTarget,
+};
+pub use dedup::MessageDeduplicator;
 pub use error::{WsError, WsResult};
 pub use message::{
     extract_subscription_type, is_order_updates_channel, ActionResponseDetails,
-    ActionResponsePayload, ChannelMessage, FillPayload, OrderInfo, OrderResponseStatus,
-    OrderUpdatePayload, OrderUpdatesResult, PongMessage, PostPayload, PostRequest, PostRequestBody,
-    PostResponseBody, PostResponseData, SignaturePayload, WsMessage, WsRequest,
+    ActionResponsePayload, ChannelMessage, FillPayload, FundingPayload, LedgerDelta,
+    LedgerUpdatePayload, LedgerUpdatesPayload, LiquidationPayload, NonUserCancelPayload, OrderInfo,
+    OrderResponseStatus, OrderUpdatePayload, OrderUpdatesResult, PongMessage, PostPayload,
+    PostRequest, PostRequestBody, PostResponseBody, PostResponseData, SignaturePayload,
+    UserEventPayload, UserFundingsPayload, WsMessage, WsRequest,
+};
+pub use queue::{
+    is_market_data_channel, is_trades_channel, message_queue, DropHook, MessageReceiver,
+    MessageSender,
 };
 pub use subscription::{ReadyState, SubscriptionManager};
 pub use ws_write_handle::{PostError, WsOutbound, WsWriteHandle};
@@ -387,6 +387,146 @@ pub struct UserFillsPayload {
     pub fills: Vec<FillPayload>,
 }
 
+/// Funding payment from userFundings subscription.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FundingPayload {
+    /// Funding time (milliseconds).
+    pub time: u64,
+    /// Coin symbol.
+    pub coin: String,
+    /// USDC delta (negative = paid, positive = received).
+    pub usdc: String,
+    /// Signed position size the funding applied to.
+    pub szi: String,
+    /// Funding rate.
+    #[serde(rename = "fundingRate")]
+    pub funding_rate: String,
+}
+
+/// userFundings subscription response from Hyperliquid.
+/// Format: { "isSnapshot"?: bool, "user": string, "fundings": [FundingPayload, ...] }
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserFundingsPayload {
+    /// True for initial snapshot. Missing (defaults to false) for streaming updates.
+    #[serde(rename = "isSnapshot", default)]
+    pub is_snapshot: bool,
+    /// User address.
+    pub user: String,
+    /// Array of funding payments.
+    pub fundings: Vec<FundingPayload>,
+}
+
+/// Liquidation of the subscribed account (userEvents).
+#[derive(Debug, Clone, Deserialize)]
+pub struct LiquidationPayload {
+    /// Liquidation ID.
+    pub lid: u64,
+    /// Liquidator address.
+    pub liquidator: String,
+    /// Liquidated account.
+    pub liquidated_user: String,
+    /// Notional of the liquidated positions (USD).
+    pub liquidated_ntl_pos: String,
+    /// Account value at liquidation (USD).
+    pub liquidated_account_value: String,
+}
+
+/// Order cancelled by the exchange rather than the user (userEvents).
+#[derive(Debug, Clone, Deserialize)]
+pub struct NonUserCancelPayload {
+    /// Coin symbol.
+    pub coin: String,
+    /// Exchange order ID.
+    pub oid: u64,
+}
+
+/// userEvents message from Hyperliquid (channel "user").
+/// Format: exactly one of `{ "fills": [...] }`, `{ "funding": {...} }`,
+/// `{ "liquidation": {...} }`, `{ "nonUserCancel": [...] }`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UserEventPayload {
+    /// Fills (also delivered by userFills).
+    Fills(Vec<FillPayload>),
+    /// Funding payment.
+    Funding(FundingPayload),
+    /// The account was liquidated.
+    Liquidation(LiquidationPayload),
+    /// Orders cancelled by the exchange.
+    NonUserCancel(Vec<NonUserCancelPayload>),
+}
+
+/// Balance change outside trading and funding (deposits, withdrawals,
+/// transfers). Other ledger entries are `Other`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum LedgerDelta {
+    /// USDC deposit.
+    Deposit {
+        /// Amount (USDC).
+        usdc: String,
+    },
+    /// USDC withdrawal.
+    Withdraw {
+        /// Amount (USDC).
+        usdc: String,
+    },
+    /// Transfer between accounts.
+    InternalTransfer {
+        /// Amount (USDC).
+        usdc: String,
+        /// Sender.
+        user: String,
+        /// Recipient.
+        destination: String,
+    },
+    /// Transfer between a master account and a sub-account.
+    SubAccountTransfer {
+        /// Amount (USDC).
+        usdc: String,
+        /// Sender.
+        user: String,
+        /// Recipient.
+        destination: String,
+    },
+    /// Transfer between the spot and perp balances of the account.
+    AccountClassTransfer {
+        /// Amount (USDC).
+        usdc: String,
+        /// True for spot → perp.
+        #[serde(rename = "toPerp")]
+        to_perp: bool,
+    },
+    /// Any other ledger entry type.
+    #[serde(other)]
+    Other,
+}
+
+/// One non-funding ledger entry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LedgerUpdatePayload {
+    /// Entry time (milliseconds).
+    pub time: u64,
+    /// Transaction hash.
+    pub hash: String,
+    /// Balance change.
+    pub delta: LedgerDelta,
+}
+
+/// userNonFundingLedgerUpdates subscription response from Hyperliquid.
+/// Format: { "isSnapshot"?: bool, "user": string, "nonFundingLedgerUpdates": [...] }
+#[derive(Debug, Clone, Deserialize)]
+pub struct LedgerUpdatesPayload {
+    /// True for initial snapshot (history). Missing for streaming updates.
+    #[serde(rename = "isSnapshot", default)]
+    pub is_snapshot: bool,
+    /// User address the ledger belongs to.
+    pub user: String,
+    /// Ledger entries.
+    #[serde(rename = "nonFundingLedgerUpdates")]
+    pub updates: Vec<LedgerUpdatePayload>,
+}
+
 // ============================================================================
 // Subscription Response Helpers
 // ============================================================================
@@ -427,6 +567,7 @@ pub fn is_order_updates_channel(channel: &str) -> bool {
 /// - "post": Post action response
 /// - "orderUpdates:*": Order state updates
 /// - "userFills": Fill notifications
+/// - "userFundings": Funding payments
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WsMessage {
@@ -571,6 +712,34 @@ impl WsMessage {
         }
     }
 
+    /// Try to parse as userFundings payload.
+    pub fn as_user_fundings(&self) -> Option<UserFundingsPayload> {
+        match self {
+            Self::Channel(c) if c.channel == "userFundings" => {
+                serde_json::from_value(c.data.clone()).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// Try to parse as a userEvents payload.
+    pub fn as_user_event(&self) -> Option<UserEventPayload> {
+        match self {
+            Self::Channel(c) if c.channel == "user" => serde_json::from_value(c.data.clone()).ok(),
+            _ => None,
+        }
+    }
+
+    /// Try to parse as a userNonFundingLedgerUpdates payload.
+    pub fn as_ledger_updates(&self) -> Option<LedgerUpdatesPayload> {
+        match self {
+            Self::Channel(c) if c.channel == "userNonFundingLedgerUpdates" => {
+                serde_json::from_value(c.data.clone()).ok()
+            }
+            _ => None,
+        }
+    }
+
     /// Try to parse as fill payload (single fill - DEPRECATED).
     /// Use as_user_fills() instead for correct parsing of Hyperliquid format.
     #[deprecated(note = "Use as_user_fills() which handles the array format correctly")]
@@ -1245,6 +1414,35 @@ mod tests {
         assert!(user_fills.fills[1].is_sell());
     }
 
+    #[test]
+    fn test_ws_message_user_fundings() {
+        let json = json!({
+            "channel": "userFundings",
+            "data": {
+                "user": "0xabcdef",
+                "fundings": [
+                    {
+                        "time": 1700000000000_u64,
+                        "coin": "xyz:GOLD",
+                        "usdc": "-0.0123",
+                        "szi": "0.5",
+                        "fundingRate": "0.0000125"
+                    }
+                ]
+            }
+        });
+
+        let msg: WsMessage = serde_json::from_value(json).unwrap();
+        assert!(msg.as_user_fills().is_none());
+        let user_fundings = msg.as_user_fundings().unwrap();
+
+        assert!(!user_fundings.is_snapshot);
+        assert_eq!(user_fundings.fundings.len(), 1);
+        assert_eq!(user_fundings.fundings[0].coin, "xyz:GOLD");
+        assert_eq!(user_fundings.fundings[0].usdc, "-0.0123");
+        assert_eq!(user_fundings.fundings[0].funding_rate, "0.0000125");
+    }
+
     #[test]
     fn test_ws_message_user_fills_empty() {
         // Test empty fills array (common after subscription)
@@ -1382,4 +1580,67 @@ mod tests {
         assert!(!is_order_updates_channel("allMids"));
         assert!(!is_order_updates_channel("orderUpdate")); // no 's'
     }
+
+    #[test]
+    fn test_user_events_and_ledger_updates() {
+        let channel = |channel: &str, data: serde_json::Value| {
+            WsMessage::Channel(ChannelMessage {
+                channel: channel.to_string(),
+                data,
+            })
+        };
+
+        let liquidation = channel(
+            "user",
+            json!({"liquidation": {
+                "lid": 7,
+                "liquidator": "0xliq",
+                "liquidated_user": "0xabc",
+                "liquidated_ntl_pos": "1500.5",
+                "liquidated_account_value": "42.1"
+            }}),
+        );
+        match liquidation.as_user_event() {
+            Some(UserEventPayload::Liquidation(liq)) => {
+                assert_eq!(liq.lid, 7);
+                assert_eq!(liq.liquidated_ntl_pos, "1500.5");
+            }
+            other => panic!("expected liquidation, got {other:?}"),
+        }
+
+        let funding = channel(
+            "user",
+            json!({"funding": {
+                "time": 1000, "coin": "xyz:GOLD", "usdc": "-0.5", "szi": "2", "fundingRate": "0.0001"
+            }}),
+        );
+        assert!(matches!(
+            funding.as_user_event(),
+            Some(UserEventPayload::Funding(f)) if f.usdc == "-0.5"
+        ));
+        assert!(liquidation.as_ledger_updates().is_none());
+
+        let ledger = channel(
+            "userNonFundingLedgerUpdates",
+            json!({
+                "user": "0xabc",
+                "nonFundingLedgerUpdates": [
+                    {"time": 1, "hash": "0x1", "delta": {"type": "deposit", "usdc": "100"}},
+                    {"time": 2, "hash": "0x2", "delta": {
+                        "type": "internalTransfer", "usdc": "25", "user": "0xabc",
+                        "destination": "0xdef", "fee": "0"
+                    }},
+                    {"time": 3, "hash": "0x3", "delta": {"type": "vaultCreate", "vault": "0x9"}}
+                ]
+            }),
+        );
+        let ledger = ledger.as_ledger_updates().unwrap();
+        assert!(!ledger.is_snapshot);
+        assert!(matches!(&ledger.updates[0].delta, LedgerDelta::Deposit { usdc } if usdc == "100"));
+        assert!(matches!(
+            &ledger.updates[1].delta,
+            LedgerDelta::InternalTransfer { destination, .. } if destination == "0xdef"
+        ));
+        assert!(matches!(ledger.updates[2].delta, LedgerDelta::Other));
+    }
 }
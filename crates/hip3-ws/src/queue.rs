@@ -0,0 +1,333 @@
+//! Inbound message queue with an explicit backpressure policy.
+//!
+//! Connections forward parsed messages into three lanes:
+//! - Market-data snapshots (bbo, l2Book, activeAssetCtx, allMids) go to a
+//!   bounded, latest-wins lane. A message replaces the queued one of the
+//!   same channel and coin in place, since a newer snapshot supersedes it.
+//!   When the lane is full of distinct markets, the *oldest* is dropped.
+//! - Trade prints go to a bounded FIFO lane. Prints are increments, not
+//!   snapshots, so none is coalesced; when the lane is full the *oldest*
+//!   is dropped.
+//! - Everything else (post responses, orderUpdates, userFills, fundings)
+//!   goes to an unbounded lane and is never dropped: losing an order or fill
+//!   message desynchronizes order and position tracking.
+//!
+//! The receiver serves the priority lane first, then snapshots, then trades.
+//! Drops are counted per channel and reported through [`DropHook`]s;
+//! superseded snapshots are counted separately as coalesced.
+
+use crate::message::WsMessage;
+use parking_lot::{Mutex, RwLock};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc::error::SendError;
+use tokio::sync::{mpsc, Notify};
+
+/// Callback invoked with the channel of each dropped market-data or trade
+/// message.
+pub type DropHook = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Whether a channel carries market-data snapshots, where only the latest
+/// message per coin matters.
+#[inline]
+pub fn is_market_data_channel(channel: &str) -> bool {
+    channel.starts_with("bbo")
+        || channel.starts_with("l2Book")
+        || channel.starts_with("activeAssetCtx")
+        || channel.starts_with("assetCtx")
+        || channel == "allMids"
+}
+
+/// Whether a channel carries public trade prints.
+#[inline]
+pub fn is_trades_channel(channel: &str) -> bool {
+    channel == "trades"
+}
+
+/// Coalescing key of a snapshot: (channel, coin). Channels without a coin
+/// (allMids) coalesce as a whole.
+type SnapshotKey = (String, Option<String>);
+
+fn snapshot_key(msg: &WsMessage) -> SnapshotKey {
+    match msg {
+        WsMessage::Channel(m) => (
+            m.channel.clone(),
+            m.data
+                .get("coin")
+                .and_then(|c| c.as_str())
+                .map(str::to_string),
+        ),
+        WsMessage::Pong(p) => (p.channel.clone(), None),
+    }
+}
+
+/// Latest-wins snapshot lane: keys in arrival order, one message per key.
+#[derive(Default)]
+struct SnapshotLane {
+    order: VecDeque<SnapshotKey>,
+    latest: HashMap<SnapshotKey, WsMessage>,
+}
+
+impl SnapshotLane {
+    fn pop_front(&mut self) -> Option<WsMessage> {
+        let key = self.order.pop_front()?;
+        self.latest.remove(&key)
+    }
+}
+
+struct Shared {
+    capacity: usize,
+    market_data: Mutex<SnapshotLane>,
+    trades: Mutex<VecDeque<WsMessage>>,
+    notify: Notify,
+    /// Dropped market-data messages by channel.
+    dropped: Mutex<HashMap<String, u64>>,
+    /// Snapshots superseded by a newer one of the same channel and coin.
+    coalesced: AtomicU64,
+    drop_hooks: RwLock<Vec<DropHook>>,
+}
+
+/// Create a message queue whose market-data and trade lanes each hold
+/// `market_data_capacity` messages.
+pub fn message_queue(market_data_capacity: usize) -> (MessageSender, MessageReceiver) {
+    let (priority_tx, priority_rx) = mpsc::unbounded_channel();
+    let shared = Arc::new(Shared {
+        capacity: market_data_capacity.max(1),
+        market_data: Mutex::new(SnapshotLane::default()),
+        trades: Mutex::new(VecDeque::new()),
+        notify: Notify::new(),
+        dropped: Mutex::new(HashMap::new()),
+        coalesced: AtomicU64::new(0),
+        drop_hooks: RwLock::new(Vec::new()),
+    });
+    (
+        MessageSender {
+            shared: shared.clone(),
+            priority_tx,
+        },
+        MessageReceiver {
+            shared,
+            priority_rx,
+        },
+    )
+}
+
+/// Sending half of the message queue. Cheap to clone.
+#[derive(Clone)]
+pub struct MessageSender {
+    shared: Arc<Shared>,
+    priority_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl MessageSender {
+    /// Queue a message without waiting. Fails only once the receiver is gone.
+    pub fn send(&self, msg: WsMessage) -> Result<(), SendError<WsMessage>> {
+        let channel = msg.channel().unwrap_or_default();
+        let trades = is_trades_channel(channel);
+        if !trades && !is_market_data_channel(channel) {
+            return self.priority_tx.send(msg);
+        }
+        if self.priority_tx.is_closed() {
+            return Err(SendError(msg));
+        }
+        if trades {
+            let evicted = {
+                let mut lane = self.shared.trades.lock();
+                let evicted = (lane.len() >= self.shared.capacity)
+                    .then(|| lane.pop_front())
+                    .flatten();
+                lane.push_back(msg);
+                evicted
+            };
+            self.shared.notify.notify_one();
+            if let Some(evicted) = evicted {
+                self.shared.record_drop(&evicted);
+            }
+            return Ok(());
+        }
+
+        let key = snapshot_key(&msg);
+        let evicted = {
+            let mut lane = self.shared.market_data.lock();
+            if let Some(queued) = lane.latest.get_mut(&key) {
+                // Keeps the queue position of the superseded snapshot
+                *queued = msg;
+                self.shared.coalesced.fetch_add(1, Ordering::Relaxed);
+                return Ok(());
+            }
+            let evicted = (lane.order.len() >= self.shared.capacity)
+                .then(|| lane.pop_front())
+                .flatten();
+            lane.order.push_back(key.clone());
+            lane.latest.insert(key, msg);
+            evicted
+        };
+        self.shared.notify.notify_one();
+
+        if let Some(evicted) = evicted {
+            self.shared.record_drop(&evicted);
+        }
+        Ok(())
+    }
+}
+
+impl Shared {
+    /// Count a dropped message and run the drop hooks.
+    fn record_drop(&self, evicted: &WsMessage) {
+        let channel = evicted.channel().unwrap_or_default();
+        *self.dropped.lock().entry(channel.to_string()).or_default() += 1;
+        for hook in self.drop_hooks.read().iter() {
+            hook(channel);
+        }
+    }
+
+    /// Next bounded-lane message: snapshots first, then trades.
+    fn pop_market_data(&self) -> Option<WsMessage> {
+        self.market_data
+            .lock()
+            .pop_front()
+            .or_else(|| self.trades.lock().pop_front())
+    }
+}
+
+/// Receiving half of the message queue.
+pub struct MessageReceiver {
+    shared: Arc<Shared>,
+    priority_rx: mpsc::UnboundedReceiver<WsMessage>,
+}
+
+impl MessageReceiver {
+    /// Next message, priority lane first. Returns None once every sender is
+    /// gone and every lane is drained.
+    pub async fn recv(&mut self) -> Option<WsMessage> {
+        loop {
+            if let Ok(msg) = self.priority_rx.try_recv() {
+                return Some(msg);
+            }
+            if let Some(msg) = self.shared.pop_market_data() {
+                return Some(msg);
+            }
+            tokio::select! {
+                biased;
+                msg = self.priority_rx.recv() => {
+                    return msg.or_else(|| self.shared.pop_market_data());
+                }
+                _ = self.shared.notify.notified() => {}
+            }
+        }
+    }
+
+    /// Next message if one is queued, priority lane first.
+    pub fn try_recv(&mut self) -> Option<WsMessage> {
+        self.priority_rx
+            .try_recv()
+            .ok()
+            .or_else(|| self.shared.pop_market_data())
+    }
+
+    /// Register a callback run for each dropped market-data or trade message.
+    ///
+    /// Hooks run on the sending connection's message loop, so they must not
+    /// block.
+    pub fn on_drop(&self, hook: DropHook) {
+        self.shared.drop_hooks.write().push(hook);
+    }
+
+    /// Dropped market-data and trade messages by channel since creation.
+    pub fn dropped(&self) -> HashMap<String, u64> {
+        self.shared.dropped.lock().clone()
+    }
+
+    /// Snapshots superseded while queued since creation.
+    pub fn coalesced(&self) -> u64 {
+        self.shared.coalesced.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message::ChannelMessage;
+    use serde_json::json;
+
+    fn msg(channel: &str, n: u64) -> WsMessage {
+        WsMessage::Channel(ChannelMessage {
+            channel: channel.to_string(),
+            data: json!({ "n": n }),
+        })
+    }
+
+    fn coin_msg(channel: &str, coin: &str, n: u64) -> WsMessage {
+        WsMessage::Channel(ChannelMessage {
+            channel: channel.to_string(),
+            data: json!({ "coin": coin, "n": n }),
+        })
+    }
+
+    async fn next(rx: &mut MessageReceiver) -> (String, u64) {
+        match rx.recv().await.unwrap() {
+            WsMessage::Channel(m) => (m.channel, m.data["n"].as_u64().unwrap()),
+            WsMessage::Pong(_) => panic!("unexpected pong"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drops_oldest_market_data_only() {
+        let (tx, mut rx) = message_queue(2);
+        let hook_drops = Arc::new(Mutex::new(Vec::new()));
+        let sink = hook_drops.clone();
+        rx.on_drop(Arc::new(move |channel| {
+            sink.lock().push(channel.to_string())
+        }));
+
+        tx.send(coin_msg("bbo", "xyz:GOLD", 1)).unwrap();
+        tx.send(coin_msg("l2Book", "xyz:GOLD", 2)).unwrap();
+        tx.send(coin_msg("bbo", "xyz:SILVER", 3)).unwrap();
+        for n in 0..5 {
+            tx.send(msg("orderUpdates", n)).unwrap();
+        }
+        tx.send(msg("post", 9)).unwrap();
+
+        // Priority lane first and complete, then the newest market data
+        for n in 0..5 {
+            assert_eq!(next(&mut rx).await, ("orderUpdates".to_string(), n));
+        }
+        assert_eq!(next(&mut rx).await, ("post".to_string(), 9));
+        assert_eq!(next(&mut rx).await, ("l2Book".to_string(), 2));
+        assert_eq!(next(&mut rx).await, ("bbo".to_string(), 3));
+
+        assert_eq!(rx.dropped(), HashMap::from([("bbo".to_string(), 1)]));
+        assert_eq!(*hook_drops.lock(), vec!["bbo".to_string()]);
+
+        // Closed once the senders are gone and the lanes are drained
+        tx.send(msg("bbo", 4)).unwrap();
+        drop(tx);
+        assert_eq!(next(&mut rx).await, ("bbo".to_string(), 4));
+        assert!(rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_coalesces_snapshots_and_bounds_trades() {
+        let (tx, mut rx) = message_queue(2);
+
+        tx.send(coin_msg("bbo", "xyz:GOLD", 1)).unwrap();
+        tx.send(coin_msg("bbo", "xyz:SILVER", 2)).unwrap();
+        // Supersedes GOLD 1 in place: no eviction despite a full lane
+        tx.send(coin_msg("bbo", "xyz:GOLD", 3)).unwrap();
+        for n in 10..15 {
+            tx.send(msg("trades", n)).unwrap();
+        }
+        tx.send(msg("userFills", 20)).unwrap();
+
+        // Fills first, snapshots before trades, the newest trades in order
+        assert_eq!(next(&mut rx).await, ("userFills".to_string(), 20));
+        assert_eq!(next(&mut rx).await, ("bbo".to_string(), 3));
+        assert_eq!(next(&mut rx).await, ("bbo".to_string(), 2));
+        assert_eq!(next(&mut rx).await, ("trades".to_string(), 13));
+        assert_eq!(next(&mut rx).await, ("trades".to_string(), 14));
+        assert!(rx.try_recv().is_none());
+        assert_eq!(rx.dropped(), HashMap::from([("trades".to_string(), 3)]));
+        assert_eq!(rx.coalesced(), 1);
+    }
+}
@@ -461,6 +461,51 @@ impl SubscriptionManager {
         });
         serde_json::to_string(&request).expect("JSON serialization should not fail")
     }
+
+    /// Create userFundings subscription request JSON.
+    ///
+    /// Returns the JSON string for subscribing to funding payments for a user.
+    /// Use with `WsWriteHandle::send_text()` to send.
+    pub fn user_fundings_subscription_request(user_address: &str) -> String {
+        let request = serde_json::json!({
+            "method": "subscribe",
+            "subscription": {
+                "type": "userFundings",
+                "user": user_address
+            }
+        });
+        serde_json::to_string(&request).expect("JSON serialization should not fail")
+    }
+
+    /// Create userEvents subscription request JSON.
+    ///
+    /// Returns the JSON string for subscribing to liquidation, funding,
+    /// fill and exchange-cancel events for a user (channel "user").
+    pub fn user_events_subscription_request(user_address: &str) -> String {
+        let request = serde_json::json!({
+            "method": "subscribe",
+            "subscription": {
+                "type": "userEvents",
+                "user": user_address
+            }
+        });
+        serde_json::to_string(&request).expect("JSON serialization should not fail")
+    }
+
+    /// Create userNonFundingLedgerUpdates subscription request JSON.
+    ///
+    /// Returns the JSON string for subscribing to deposits, withdrawals and
+    /// transfers for a user.
+    pub fn ledger_updates_subscription_request(user_address: &str) -> String {
+        let request = serde_json::json!({
+            "method": "subscribe",
+            "subscription": {
+                "type": "userNonFundingLedgerUpdates",
+                "user": user_address
+            }
+        });
+        serde_json::to_string(&request).expect("JSON serialization should not fail")
+    }
 }
 
 impl Default for SubscriptionManager {
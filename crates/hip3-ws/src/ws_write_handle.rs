@@ -2,8 +2,14 @@
 //!
 //! Provides fire-and-forget sending API. Response tracking is handled
 //! by the executor's PostRequestManager.
+//!
+//! With a standby connection, posts fail over to it while the primary is
+//! not ready or has been silent for longer than the heartbeat interval.
+//! A handle of a dedicated order-flow connection is ready on orderUpdates
+//! alone, as that connection carries no market data.
 
 use crate::connection::ConnectionState;
+use crate::heartbeat::HeartbeatManager;
 use crate::rate_limiter::RateLimiter;
 use crate::subscription::SubscriptionManager;
 use parking_lot::RwLock;
@@ -66,6 +72,20 @@ pub struct WsWriteHandle {
     rate_limiter: Arc<RateLimiter>,
     state: Arc<RwLock<ConnectionState>>,
     subscriptions: Arc<SubscriptionManager>,
+    /// Standby connection posts fail over to (dual connection only).
+    standby: Option<StandbyRoute>,
+    /// Readiness requires market data (false on an order-flow connection).
+    requires_market_data: bool,
+}
+
+/// Failover target of a primary write handle.
+#[derive(Clone)]
+struct StandbyRoute {
+    handle: Box<WsWriteHandle>,
+    /// Primary heartbeat, for silence detection.
+    primary_heartbeat: Arc<HeartbeatManager>,
+    /// Primary silence (ms) after which it counts as degraded.
+    stale_ms: u64,
 }
 
 impl WsWriteHandle {
@@ -81,7 +101,44 @@ impl WsWriteHandle {
             rate_limiter,
             state,
             subscriptions,
+            standby: None,
+            requires_market_data: true,
+        }
+    }
+
+    /// Treat this handle's connection (and its standby's) as order-flow
+    /// only: ready once orderUpdates is ACKed, without market data.
+    pub fn order_flow_only(mut self) -> Self {
+        self.requires_market_data = false;
+        if let Some(ref mut route) = self.standby {
+            route.handle.requires_market_data = false;
         }
+        self
+    }
+
+    /// Fail posts over to `standby` while this (primary) connection is not
+    /// ready or has received nothing for `stale_ms`.
+    pub fn with_standby(
+        mut self,
+        standby: WsWriteHandle,
+        primary_heartbeat: Arc<HeartbeatManager>,
+        stale_ms: u64,
+    ) -> Self {
+        self.standby = Some(StandbyRoute {
+            handle: Box::new(standby),
+            primary_heartbeat,
+            stale_ms,
+        });
+        self
+    }
+
+    /// Whether posts currently go to the standby connection.
+    pub fn is_failed_over(&self) -> bool {
+        self.standby.as_ref().is_some_and(|route| {
+            let primary_healthy = self.is_link_ready()
+                && route.primary_heartbeat.time_since_last_message_ms() <= route.stale_ms as i64;
+            !primary_healthy && route.handle.is_ready()
+        })
     }
 
     /// Send a post request (fire-and-forget).
@@ -102,8 +159,14 @@ impl WsWriteHandle {
     /// `record_post_response()` is called by ConnectionManager when
     /// a post response is received.
     pub async fn post(&self, post_id: u64, payload: String) -> Result<(), PostError> {
+        // 0. Primary degraded: route through the standby connection
+        if let Some(route) = self.standby.as_ref().filter(|_| self.is_failed_over()) {
+            debug!(post_id, "Primary connection degraded, posting via standby");
+            return Box::pin(route.handle.post(post_id, payload)).await;
+        }
+
         // 1. Check connection state and READY-TRADING
-        if !self.is_ready() {
+        if !self.is_link_ready() {
             return Err(PostError::NotReady);
         }
 
@@ -156,11 +219,25 @@ impl WsWriteHandle {
     ///
     /// Note: Rate limit is NOT checked here. It is checked separately in
     /// `post()` to allow distinguishing `NotReady` from `RateLimited`.
+    ///
+    /// With a standby connection, either connection being ready suffices.
     pub fn is_ready(&self) -> bool {
+        self.is_link_ready()
+            || self
+                .standby
+                .as_ref()
+                .is_some_and(|route| route.handle.is_ready())
+    }
+
+    /// Whether this handle's own connection is ready.
+    fn is_link_ready(&self) -> bool {
         let state = *self.state.read();
-        state == ConnectionState::Connected
-            && self.subscriptions.is_ready() // READY-TRADING
-            && !self.tx.is_closed()
+        let trading_ready = if self.requires_market_data {
+            self.subscriptions.is_ready() // READY-TRADING
+        } else {
+            self.subscriptions.ready_state().order_updates_ready
+        };
+        state == ConnectionState::Connected && trading_ready && !self.tx.is_closed()
     }
 
     /// Check if connected (for subscriptions, doesn't require READY-TRADING).
@@ -254,6 +331,43 @@ mod tests {
         assert_eq!(result, Err(PostError::NotReady));
     }
 
+    #[tokio::test]
+    async fn test_post_fails_over_to_standby() {
+        let (standby, mut standby_rx) = create_test_handle();
+        let (primary_tx, mut primary_rx) = mpsc::channel(100);
+        let primary_state = Arc::new(RwLock::new(ConnectionState::Connected));
+        let primary_subs = Arc::new(SubscriptionManager::new());
+        primary_subs.handle_message("bbo:BTC");
+        primary_subs.handle_message("activeAssetCtx:perp:0");
+        primary_subs.handle_message("orderUpdates:user:test");
+        let heartbeat = Arc::new(HeartbeatManager::new(45000, 10000));
+        let primary = WsWriteHandle::new(
+            primary_tx,
+            Arc::new(RateLimiter::new(2000, 60)),
+            primary_state.clone(),
+            primary_subs,
+        )
+        .with_standby(standby, heartbeat, 45000);
+
+        // Healthy primary keeps the post path
+        primary.post(1, "a".to_string()).await.unwrap();
+        assert!(matches!(
+            primary_rx.try_recv(),
+            Ok(WsOutbound::Post { post_id: 1, .. })
+        ));
+
+        // Primary reconnecting: posts go to the standby
+        *primary_state.write() = ConnectionState::Reconnecting;
+        assert!(primary.is_ready());
+        assert!(primary.is_failed_over());
+        primary.post(2, "b".to_string()).await.unwrap();
+        assert!(matches!(
+            standby_rx.try_recv(),
+            Ok(WsOutbound::Post { post_id: 2, .. })
+        ));
+        assert!(primary_rx.try_recv().is_err());
+    }
+
     #[tokio::test]
     async fn test_send_text_success() {
         let (handle, mut rx) = create_test_handle();